@@ -0,0 +1,71 @@
+//! Compares releasing temporary objects one at a time against releasing them
+//! in bulk with `AutoreleaseArena`.
+//!
+//! Run with:
+//!
+//! ```sh
+//! cargo bench --bench autorelease_arena --features foundation
+//! ```
+
+use fruity::{
+    core::Arc,
+    foundation::NSString,
+    objc::{autoreleasepool, AutoreleaseArena},
+};
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+const ITERATIONS: u32 = 10_000;
+const OBJECTS_PER_ITERATION: usize = 32;
+
+fn bench(name: &str, mut f: impl FnMut()) {
+    // Warm up caches and the autorelease pool page allocator.
+    for _ in 0..ITERATIONS / 10 {
+        f();
+    }
+
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    let elapsed = start.elapsed();
+
+    let per_iter = elapsed / ITERATIONS;
+    let per_object =
+        Duration::from_nanos(per_iter.as_nanos() as u64 / OBJECTS_PER_ITERATION as u64);
+    println!(
+        "{:<24} {:>10?}/iter {:>10?}/object",
+        name, per_iter, per_object
+    );
+}
+
+fn new_string() -> Arc<NSString<'static>> {
+    NSString::from_str("fruity")
+}
+
+fn main() {
+    bench("drop each", || {
+        for _ in 0..OBJECTS_PER_ITERATION {
+            black_box(new_string());
+        }
+    });
+
+    bench("autoreleasepool", || {
+        autoreleasepool(|_| {
+            for _ in 0..OBJECTS_PER_ITERATION {
+                black_box(new_string());
+            }
+        })
+    });
+
+    let mut arena = AutoreleaseArena::with_capacity(OBJECTS_PER_ITERATION);
+    bench("arena", || {
+        arena.scope(|arena| {
+            for _ in 0..OBJECTS_PER_ITERATION {
+                black_box(arena.track(new_string()));
+            }
+        })
+    });
+}
@@ -0,0 +1,75 @@
+//! Compares the ways of visiting every object of an `NSArray`: indexing,
+//! fetching in one batch, fast enumeration, and `NSEnumerator`.
+//!
+//! Run with:
+//!
+//! ```sh
+//! cargo bench --bench collection_iteration --features foundation
+//! ```
+
+use fruity::{core::Arc, foundation::NSArray, foundation::NSString};
+use std::{hint::black_box, time::Instant};
+
+const ITERATIONS: u32 = 10_000;
+const LEN: usize = 256;
+
+fn bench(name: &str, mut f: impl FnMut()) {
+    for _ in 0..ITERATIONS / 10 {
+        f();
+    }
+
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    let elapsed = start.elapsed();
+
+    let per_iter = elapsed / ITERATIONS;
+    println!(
+        "{:<24} {:>10?}/iter {:>10?}/object",
+        name,
+        per_iter,
+        per_iter / LEN as u32
+    );
+}
+
+fn main() {
+    let strings: Vec<Arc<NSString<'static>>> = (0..LEN)
+        .map(|i| NSString::from_str(&i.to_string()))
+        .collect();
+    let refs: Vec<&NSString<'static>> = strings.iter().map(|s| &**s).collect();
+
+    let array: Arc<NSArray<NSString<'static>>> = NSArray::from_slice(&refs);
+    let array = black_box(&*array);
+
+    bench("get", || {
+        for i in 0..array.len() {
+            black_box(array.get(i));
+        }
+    });
+
+    let mut buf = vec![None; LEN];
+    bench("get_objects", || {
+        let count = array.get_objects(0, &mut buf);
+        for object in &buf[..count] {
+            black_box(object);
+        }
+    });
+
+    bench("iter", || {
+        for object in array.iter() {
+            black_box(object);
+        }
+    });
+
+    bench("object_enumerator", || {
+        let enumerator = array.object_enumerator();
+        while let Some(object) = enumerator.next_object() {
+            black_box(object);
+        }
+    });
+
+    bench("to_vec", || {
+        black_box(array.to_vec());
+    });
+}
@@ -0,0 +1,102 @@
+//! Compares the cost of sending `-[NSString length]` and
+//! `-[NSString UTF8String]` with a freshly-registered selector, a cached
+//! selector, a static selector, and a cached `IMP` that bypasses
+//! `objc_msgSend`.
+//!
+//! Run with:
+//!
+//! ```sh
+//! cargo bench --bench selector_cache --features foundation
+//! ```
+
+use fruity::{
+    cached_selector,
+    foundation::NSString,
+    objc::{CachedImp, NSUInteger, Sel},
+    selector, static_selector,
+};
+use std::{hint::black_box, mem, os::raw::c_char, time::Instant};
+
+const ITERATIONS: u32 = 1_000_000;
+
+fn bench(name: &str, mut f: impl FnMut()) {
+    for _ in 0..ITERATIONS / 10 {
+        f();
+    }
+
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    let elapsed = start.elapsed();
+
+    println!("{:<32} {:>10?}/iter", name, elapsed / ITERATIONS);
+}
+
+#[link(name = "objc", kind = "dylib")]
+extern "C" {
+    fn objc_msgSend();
+}
+
+unsafe fn send_length(string: &NSString, sel: Sel) -> NSUInteger {
+    let send: unsafe extern "C" fn(&NSString, Sel) -> NSUInteger =
+        mem::transmute(objc_msgSend as unsafe extern "C" fn());
+    send(string, sel)
+}
+
+unsafe fn send_utf8(string: &NSString, sel: Sel) -> *const c_char {
+    let send: unsafe extern "C" fn(&NSString, Sel) -> *const c_char =
+        mem::transmute(objc_msgSend as unsafe extern "C" fn());
+    send(string, sel)
+}
+
+fn main() {
+    let string = NSString::from_str("The quick brown fox jumps over the lazy dog");
+    let string = black_box(&*string);
+
+    bench("length: selector!", || unsafe {
+        black_box(send_length(string, selector!(length)));
+    });
+
+    bench("length: cached_selector!", || unsafe {
+        black_box(send_length(string, cached_selector!(length)));
+    });
+
+    bench("length: static_selector!", || unsafe {
+        black_box(send_length(string, static_selector!(length)));
+    });
+
+    bench("length: CachedImp", || unsafe {
+        static IMP: CachedImp = CachedImp::new();
+
+        let sel = cached_selector!(length);
+        let imp = IMP.get(string.object_class(), sel);
+        let imp: unsafe extern "C" fn(&NSString, Sel) -> NSUInteger = mem::transmute(imp);
+        black_box(imp(string, sel));
+    });
+
+    bench("UTF8String: selector!", || unsafe {
+        black_box(send_utf8(string, selector!(UTF8String)));
+    });
+
+    bench("UTF8String: cached_selector!", || unsafe {
+        black_box(send_utf8(string, cached_selector!(UTF8String)));
+    });
+
+    bench("UTF8String: static_selector!", || unsafe {
+        black_box(send_utf8(string, static_selector!(UTF8String)));
+    });
+
+    bench("UTF8String: CachedImp", || unsafe {
+        static IMP: CachedImp = CachedImp::new();
+
+        let sel = cached_selector!(UTF8String);
+        let imp = IMP.get(string.object_class(), sel);
+        let imp: unsafe extern "C" fn(&NSString, Sel) -> *const c_char = mem::transmute(imp);
+        black_box(imp(string, sel));
+    });
+
+    bench("NSString::to_str", || {
+        black_box(unsafe { string.to_str() });
+    });
+}
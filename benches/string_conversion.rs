@@ -0,0 +1,60 @@
+//! Compares the ways of converting between Rust strings and `NSString`,
+//! including the no-copy and borrowing fast paths.
+//!
+//! Run with:
+//!
+//! ```sh
+//! cargo bench --bench string_conversion --features foundation
+//! ```
+
+use fruity::foundation::NSString;
+use std::{hint::black_box, time::Instant};
+
+const ITERATIONS: u32 = 100_000;
+
+const SHORT: &str = "fruity";
+const LONG: &str = "The quick brown fox jumps over the lazy dog. \
+                    The quick brown fox jumps over the lazy dog. \
+                    The quick brown fox jumps over the lazy dog.";
+
+fn bench(name: &str, mut f: impl FnMut()) {
+    for _ in 0..ITERATIONS / 10 {
+        f();
+    }
+
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    let elapsed = start.elapsed();
+
+    println!("{:<32} {:>10?}/iter", name, elapsed / ITERATIONS);
+}
+
+fn main() {
+    for &(label, s) in &[("short", SHORT), ("long", LONG)] {
+        bench(&format!("{}: from_str", label), || {
+            black_box(NSString::from_str(black_box(s)));
+        });
+
+        bench(&format!("{}: from_str_no_copy", label), || {
+            black_box(NSString::from_str_no_copy(black_box(s)));
+        });
+
+        let string = NSString::from_str(s);
+        let string = black_box(&*string);
+
+        bench(&format!("{}: to_string", label), || {
+            black_box(string.to_string());
+        });
+
+        bench(&format!("{}: to_str", label), || unsafe {
+            black_box(string.to_str());
+        });
+
+        // Only succeeds without copying if the string is stored as UTF-8.
+        bench(&format!("{}: as_str", label), || unsafe {
+            black_box(string.as_str());
+        });
+    }
+}
@@ -1,12 +1,121 @@
-use std::env;
+use std::{
+    env,
+    path::{Path, PathBuf},
+    process::Command,
+};
 
 fn main() {
+    println!("cargo:rustc-check-cfg=cfg(docsrs)");
+    println!("cargo:rustc-check-cfg=cfg(mac_catalyst)");
+
     if let Ok(target_env) = env::var("TARGET") {
-        match target_env.as_str() {
-            "x86_64-apple-ios-macabi" => {
-                println!("cargo:rustc-cfg=mac_catalyst");
-            }
-            _ => {}
+        if target_env == "x86_64-apple-ios-macabi" {
+            println!("cargo:rustc-cfg=mac_catalyst");
         }
     }
+
+    if env::var_os("CARGO_FEATURE_OBJC").is_some() {
+        build_exception_shim();
+    }
+}
+
+/// Compiles `src/objc/exception/catch.m` into a static library, since
+/// catching Objective-C exceptions requires `@try`/`@catch`.
+fn build_exception_shim() {
+    const SOURCE: &str = "src/objc/exception/catch.m";
+    println!("cargo:rerun-if-changed={}", SOURCE);
+
+    let target = env::var("TARGET").unwrap_or_default();
+    let (clang_target, sdk) = match clang_target(&target) {
+        Some(target) => target,
+        None => return,
+    };
+
+    let out_dir = PathBuf::from(env::var_os("OUT_DIR").unwrap());
+    let object = out_dir.join("catch.o");
+    let library = out_dir.join("libfruity_objc_exception.a");
+
+    let mut compile = clang(sdk);
+    compile
+        .args([
+            "-c",
+            "-x",
+            "objective-c",
+            "-fobjc-exceptions",
+            "-fexceptions",
+            "-fno-objc-arc",
+        ])
+        .args(["-O2", "-fPIC", "-target", &clang_target])
+        .arg(Path::new(SOURCE))
+        .arg("-o")
+        .arg(&object);
+
+    let mut archive = Command::new("ar");
+    archive.arg("crs").arg(&library).arg(&object);
+
+    let archived = succeeds(&mut compile) && succeeds(&mut archive);
+
+    if archived {
+        println!("cargo:rustc-link-search=native={}", out_dir.display());
+        println!("cargo:rustc-link-lib=static=fruity_objc_exception");
+    } else if env::var_os("DOCS_RS").is_none() {
+        // Nothing is linked when documenting on docs.rs, so a missing Apple
+        // toolchain is only an error elsewhere. Failing here is clearer than
+        // the link error that would follow.
+        panic!(
+            "could not compile {}, which `objc::catch_exception` requires",
+            SOURCE
+        );
+    }
+}
+
+fn succeeds(command: &mut Command) -> bool {
+    matches!(command.status(), Ok(status) if status.success())
+}
+
+/// Returns a command for running Clang with the SDK for `sdk`.
+fn clang(sdk: &str) -> Command {
+    if let Some(cc) = env::var_os("CC") {
+        return Command::new(cc);
+    }
+    let mut command = Command::new("xcrun");
+    command.args(["--sdk", sdk, "clang"]);
+    command
+}
+
+/// Returns the Clang target triple and SDK name for a Rust target triple.
+fn clang_target(target: &str) -> Option<(String, &'static str)> {
+    let mut parts = target.splitn(3, '-');
+    let arch = match parts.next()? {
+        "aarch64" => "arm64",
+        "arm64_32" => "arm64_32",
+        "armv7" => "armv7",
+        "armv7k" => "armv7k",
+        "armv7s" => "armv7s",
+        "i386" | "i686" => "i386",
+        "x86_64" => "x86_64",
+        _ => return None,
+    };
+
+    if parts.next()? != "apple" {
+        return None;
+    }
+
+    let simulator = arch == "x86_64" || arch == "i386";
+    let (os, sdk) = match parts.next()? {
+        "darwin" => ("macosx", "macosx"),
+        "ios-macabi" => ("ios-macabi", "macosx"),
+        "ios-sim" => ("ios-simulator", "iphonesimulator"),
+        "ios" if simulator => ("ios-simulator", "iphonesimulator"),
+        "ios" => ("ios", "iphoneos"),
+        "tvos-sim" => ("tvos-simulator", "appletvsimulator"),
+        "tvos" if simulator => ("tvos-simulator", "appletvsimulator"),
+        "tvos" => ("tvos", "appletvos"),
+        "watchos-sim" => ("watchos-simulator", "watchsimulator"),
+        "watchos" if simulator => ("watchos-simulator", "watchsimulator"),
+        "watchos" => ("watchos", "watchos"),
+        _ => return None,
+    };
+
+    Some((format!("{}-apple-{}", arch, os), sdk))
 }
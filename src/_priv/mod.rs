@@ -3,3 +3,6 @@ pub use std::ffi::c_void;
 
 #[cfg(feature = "foundation")]
 pub mod cfstring;
+
+#[cfg(feature = "objc")]
+pub mod objc;
@@ -0,0 +1,33 @@
+use crate::objc::{ImageInfo, ImageInfoFlags, Sel};
+use std::{cell::UnsafeCell, ptr};
+
+/// The image info that clang emits for Objective-C code, which the runtime
+/// requires before it fixes up an image's selector references.
+pub const IMAGE_INFO: ImageInfo = ImageInfo {
+    version: 0,
+    flags: ImageInfoFlags::from_bits(0).with_category_class_properties(true),
+};
+
+/// A selector reference placed in `__DATA,__objc_selrefs`.
+///
+/// It initially points to the method name, and the runtime overwrites it with
+/// the registered selector when the image is loaded.
+#[repr(transparent)]
+pub struct SelRef(UnsafeCell<*const u8>);
+
+// Required to place in a `static`. Only the runtime writes to it, before any
+// Rust code runs.
+unsafe impl Sync for SelRef {}
+
+impl SelRef {
+    pub const fn new(name: *const u8) -> Self {
+        Self(UnsafeCell::new(name))
+    }
+
+    #[inline]
+    pub fn get(&self) -> Sel {
+        // The read must not be folded into the method name pointer, since the
+        // value is changed outside of Rust.
+        unsafe { Sel::from_ptr(ptr::read_volatile(self.0.get()).cast()) }
+    }
+}
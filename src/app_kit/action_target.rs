@@ -0,0 +1,111 @@
+//! A private Objective-C class whose action method calls a Rust closure, for
+//! APIs that report changes with target-action.
+
+use crate::core::Arc;
+use crate::objc::{Class, ClassType, LazyClass, NSObject, Sel};
+use std::{
+    ffi::{c_void, CStr},
+    fmt,
+    panic::{self, AssertUnwindSafe},
+    process, ptr,
+};
+
+type ActionHandler = dyn FnMut(&NSObject<'static>) + Send;
+
+/// An object that calls a closure with the sender of each action sent to it.
+///
+/// The closure stops being called when this is dropped, even if the object
+/// is still retained as the target of a control.
+pub(super) struct ActionTarget {
+    object: Arc<NSObject<'static>>,
+    // Referenced by `object` until dropped.
+    #[allow(dead_code)]
+    handler: Box<Box<ActionHandler>>,
+}
+
+impl fmt::Debug for ActionTarget {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ActionTarget").finish()
+    }
+}
+
+impl Drop for ActionTarget {
+    fn drop(&mut self) {
+        unsafe { *handler_slot(&self.object) = ptr::null_mut() };
+    }
+}
+
+impl ActionTarget {
+    /// Creates a target that calls `handler` with each sender.
+    pub fn new<F>(handler: F) -> Self
+    where
+        F: FnMut(&NSObject<'static>) + Send + 'static,
+    {
+        let handler: Box<Box<ActionHandler>> = Box::new(Box::new(handler));
+        let object: Arc<NSObject<'static>> = unsafe { target_class().alloc_init() };
+        unsafe { *handler_slot(&object) = &*handler as *const Box<ActionHandler> as *mut c_void };
+
+        Self { object, handler }
+    }
+
+    /// Returns the object to pass to `setTarget:`.
+    #[inline]
+    pub fn object(&self) -> &NSObject<'static> {
+        &self.object
+    }
+
+    /// Returns the selector to pass to `setAction:`.
+    #[inline]
+    pub fn action() -> Sel {
+        selector!(performRustAction:)
+    }
+
+    /// Returns `true` if `target` is this object.
+    #[inline]
+    pub fn is(&self, target: *const NSObject) -> bool {
+        ptr::eq(target, &*self.object)
+    }
+}
+
+/// The name of the instance variable that points to a `Box<ActionHandler>`.
+const HANDLER_IVAR: &[u8] = b"rustActionHandler\0";
+
+#[inline]
+fn handler_slot(target: &NSObject<'static>) -> *mut *mut c_void {
+    let name = unsafe { CStr::from_bytes_with_nul_unchecked(HANDLER_IVAR) };
+    target.ivar_ptr::<*mut c_void>(name)
+}
+
+/// Returns the class used for action targets, registering it with the
+/// Objective-C runtime on first use.
+fn target_class() -> &'static Class {
+    static CLASS: LazyClass = LazyClass::new("FruityActionTarget");
+
+    CLASS.get(NSObject::class(), |builder| {
+        builder.add_ivar::<*mut c_void>(CStr::from_bytes_with_nul(HANDLER_IVAR).unwrap());
+
+        unsafe {
+            builder.add_method(
+                selector!(performRustAction:),
+                perform_action as PerformAction,
+            );
+        }
+    })
+}
+
+type PerformAction = extern "C" fn(&NSObject<'static>, Sel, *const NSObject<'static>);
+
+extern "C" fn perform_action(this: &NSObject<'static>, _: Sel, sender: *const NSObject<'static>) {
+    unsafe {
+        let handler = *handler_slot(this) as *mut Box<ActionHandler>;
+        let sender = match sender.as_ref() {
+            Some(sender) if !handler.is_null() => sender,
+            _ => return,
+        };
+
+        // Unwinding into Objective-C is undefined behavior.
+        if panic::catch_unwind(AssertUnwindSafe(|| (*handler)(sender))).is_err() {
+            process::abort();
+        }
+    }
+}
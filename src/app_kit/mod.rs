@@ -8,8 +8,81 @@
 // `mac_catalyst` is enabled by `build.rs` for `x86_64-apple-ios-macabi`.
 #![cfg(all(feature = "app_kit", any(target_os = "macos", mac_catalyst)))]
 
+mod action_target;
+mod ns_accessibility;
+mod ns_animation_context;
+mod ns_application;
+mod ns_bitmap_image_rep;
+mod ns_cell_image_position;
+mod ns_collection_view;
+mod ns_collection_view_item;
+mod ns_collection_view_layout;
+mod ns_color;
+mod ns_color_panel;
+mod ns_document;
+mod ns_document_controller;
+mod ns_event;
+mod ns_font;
+mod ns_font_panel;
+mod ns_image;
+mod ns_layout_anchor;
+mod ns_layout_constraint;
+mod ns_line_break_mode;
+mod ns_nib;
+mod ns_pasteboard;
+mod ns_pasteboard_watcher;
+mod ns_popover;
+mod ns_print_info;
+mod ns_print_operation;
+mod ns_screen;
+mod ns_scroll_view;
+mod ns_services;
+mod ns_status_item;
+mod ns_storyboard;
+mod ns_string_drawing;
+mod ns_view;
+mod ns_view_controller;
+mod ns_window;
+mod ns_window_controller;
+mod ns_workspace;
 mod version;
 
+pub use ns_accessibility::*;
+pub use ns_animation_context::*;
+pub use ns_application::*;
+pub use ns_bitmap_image_rep::*;
+pub use ns_cell_image_position::*;
+pub use ns_collection_view::*;
+pub use ns_collection_view_item::*;
+pub use ns_collection_view_layout::*;
+pub use ns_color::*;
+pub use ns_color_panel::*;
+pub use ns_document::*;
+pub use ns_document_controller::*;
+pub use ns_event::*;
+pub use ns_font::*;
+pub use ns_font_panel::*;
+pub use ns_image::*;
+pub use ns_layout_anchor::*;
+pub use ns_layout_constraint::*;
+pub use ns_line_break_mode::*;
+pub use ns_nib::*;
+pub use ns_pasteboard::*;
+pub use ns_pasteboard_watcher::*;
+pub use ns_popover::*;
+pub use ns_print_info::*;
+pub use ns_print_operation::*;
+pub use ns_screen::*;
+pub use ns_scroll_view::*;
+pub use ns_services::*;
+pub use ns_status_item::*;
+pub use ns_storyboard::*;
+pub use ns_string_drawing::*;
+pub use ns_view::*;
+pub use ns_view_controller::*;
+pub use ns_window::*;
+pub use ns_window_controller::*;
+pub use ns_workspace::*;
 pub use version::*;
 
 #[doc(inline)]
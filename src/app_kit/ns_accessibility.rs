@@ -0,0 +1,160 @@
+use crate::objc::Sel;
+use std::fmt;
+
+/// The type of an accessibility element, which determines how assistive
+/// apps such as VoiceOver describe it and which attributes they read.
+///
+/// See [documentation](https://developer.apple.com/documentation/appkit/nsaccessibility/role).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NSAccessibilityRole(&'static str);
+
+impl fmt::Display for NSAccessibilityRole {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+impl NSAccessibilityRole {
+    /// A button.
+    #[doc(alias = "NSAccessibilityButtonRole")]
+    pub const BUTTON: Self = Self("AXButton");
+
+    /// A check box.
+    #[doc(alias = "NSAccessibilityCheckBoxRole")]
+    pub const CHECK_BOX: Self = Self("AXCheckBox");
+
+    /// A radio button.
+    #[doc(alias = "NSAccessibilityRadioButtonRole")]
+    pub const RADIO_BUTTON: Self = Self("AXRadioButton");
+
+    /// A slider, whose value can be incremented and decremented.
+    #[doc(alias = "NSAccessibilitySliderRole")]
+    pub const SLIDER: Self = Self("AXSlider");
+
+    /// A progress indicator.
+    #[doc(alias = "NSAccessibilityProgressIndicatorRole")]
+    pub const PROGRESS_INDICATOR: Self = Self("AXProgressIndicator");
+
+    /// Text that cannot be edited.
+    #[doc(alias = "NSAccessibilityStaticTextRole")]
+    pub const STATIC_TEXT: Self = Self("AXStaticText");
+
+    /// A field of editable text.
+    #[doc(alias = "NSAccessibilityTextFieldRole")]
+    pub const TEXT_FIELD: Self = Self("AXTextField");
+
+    /// A link to other content.
+    #[doc(alias = "NSAccessibilityLinkRole")]
+    pub const LINK: Self = Self("AXLink");
+
+    /// An image.
+    #[doc(alias = "NSAccessibilityImageRole")]
+    pub const IMAGE: Self = Self("AXImage");
+
+    /// A list of items.
+    #[doc(alias = "NSAccessibilityListRole")]
+    pub const LIST: Self = Self("AXList");
+
+    /// A group of related elements.
+    #[doc(alias = "NSAccessibilityGroupRole")]
+    pub const GROUP: Self = Self("AXGroup");
+
+    /// An element whose type is unknown.
+    #[doc(alias = "NSAccessibilityUnknownRole")]
+    pub const UNKNOWN: Self = Self("AXUnknown");
+
+    /// Returns the role with the given `AX` name, such as `"AXButton"`.
+    #[inline]
+    pub const fn from_name(name: &'static str) -> Self {
+        Self(name)
+    }
+
+    /// Returns the `AX` name of the role.
+    #[inline]
+    pub const fn name(self) -> &'static str {
+        self.0
+    }
+}
+
+/// An action that assistive apps can perform on an accessibility element.
+///
+/// See [documentation](https://developer.apple.com/documentation/appkit/nsaccessibility/action).
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct NSAccessibilityAction(u8);
+
+impl fmt::Debug for NSAccessibilityAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("NSAccessibilityAction")
+            .field(&self.name())
+            .finish()
+    }
+}
+
+impl NSAccessibilityAction {
+    /// Presses the element, like clicking a button.
+    #[doc(alias = "NSAccessibilityPressAction")]
+    pub const PRESS: Self = Self(0);
+
+    /// Increments the value of the element.
+    #[doc(alias = "NSAccessibilityIncrementAction")]
+    pub const INCREMENT: Self = Self(1);
+
+    /// Decrements the value of the element.
+    #[doc(alias = "NSAccessibilityDecrementAction")]
+    pub const DECREMENT: Self = Self(2);
+
+    /// Confirms the element, like pressing Return in a text field.
+    #[doc(alias = "NSAccessibilityConfirmAction")]
+    pub const CONFIRM: Self = Self(3);
+
+    /// Cancels the element, like pressing Escape.
+    #[doc(alias = "NSAccessibilityCancelAction")]
+    pub const CANCEL: Self = Self(4);
+
+    /// Shows the contextual menu of the element.
+    #[doc(alias = "NSAccessibilityShowMenuAction")]
+    pub const SHOW_MENU: Self = Self(5);
+
+    /// Selects the element, such as a menu item.
+    #[doc(alias = "NSAccessibilityPickAction")]
+    pub const PICK: Self = Self(6);
+
+    /// Brings the element, such as a window, to the front.
+    #[doc(alias = "NSAccessibilityRaiseAction")]
+    pub const RAISE: Self = Self(7);
+
+    /// Deletes the element.
+    #[doc(alias = "NSAccessibilityDeleteAction")]
+    pub const DELETE: Self = Self(8);
+
+    /// Returns the `AX` name of the action, such as `"AXPress"`.
+    pub fn name(self) -> &'static str {
+        match self.0 {
+            0 => "AXPress",
+            1 => "AXIncrement",
+            2 => "AXDecrement",
+            3 => "AXConfirm",
+            4 => "AXCancel",
+            5 => "AXShowMenu",
+            6 => "AXPick",
+            7 => "AXRaise",
+            _ => "AXDelete",
+        }
+    }
+
+    /// Returns the `NSAccessibility` method that performs the action.
+    pub(crate) fn selector(self) -> Sel {
+        match self.0 {
+            0 => selector!(accessibilityPerformPress),
+            1 => selector!(accessibilityPerformIncrement),
+            2 => selector!(accessibilityPerformDecrement),
+            3 => selector!(accessibilityPerformConfirm),
+            4 => selector!(accessibilityPerformCancel),
+            5 => selector!(accessibilityPerformShowMenu),
+            6 => selector!(accessibilityPerformPick),
+            7 => selector!(accessibilityPerformRaise),
+            _ => selector!(accessibilityPerformDelete),
+        }
+    }
+}
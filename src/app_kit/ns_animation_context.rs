@@ -0,0 +1,213 @@
+use crate::core::Arc;
+use crate::foundation::NSTimeInterval;
+use crate::objc::{Block, ClassType, NSObject, RcBlock, StackBlock, BOOL};
+use std::{cell::Cell, ptr};
+
+#[cfg(feature = "core_animation")]
+use crate::core_animation::CAMediaTimingFunction;
+
+objc_subclass! {
+    /// The settings of a group of animations, such as the changes made to
+    /// views and windows through their `animator()` proxies.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fruity::app_kit::{NSAnimationContext, NSView};
+    ///
+    /// # let view: &NSView = todo!();
+    /// NSAnimationContext::run_animation_group_with_completion(
+    ///     |context| {
+    ///         context.set_duration(0.25);
+    ///         view.animator().set_alpha_value(0.0);
+    ///     },
+    ///     || println!("faded out"),
+    /// );
+    /// ```
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsanimationcontext).
+    pub class NSAnimationContext: NSObject<'static>;
+}
+
+impl NSAnimationContext {
+    /// Returns the context of the current animation group on this thread.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsanimationcontext/current).
+    #[inline]
+    #[doc(alias = "currentContext")]
+    pub fn current() -> Arc<Self> {
+        unsafe { Arc::retain_raw(_msg_send_any![Self::class(), currentContext => *const Self]) }
+    }
+
+    /// Starts a new animation group, which is animated when the outermost
+    /// group is ended with [`end_grouping`](Self::end_grouping).
+    ///
+    /// Prefer [`run_animation_group`](Self::run_animation_group), which
+    /// cannot leave a group unbalanced.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsanimationcontext/begingrouping()).
+    #[inline]
+    #[doc(alias = "beginGrouping")]
+    pub fn begin_grouping() {
+        unsafe { _msg_send_any![Self::class(), beginGrouping => ()] }
+    }
+
+    /// Ends the animation group started by the matching
+    /// [`begin_grouping`](Self::begin_grouping).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsanimationcontext/endgrouping()).
+    #[inline]
+    #[doc(alias = "endGrouping")]
+    pub fn end_grouping() {
+        unsafe { _msg_send_any![Self::class(), endGrouping => ()] }
+    }
+
+    /// Calls `changes` within a new animation group, whose context it may
+    /// configure before making animated changes.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsanimationcontext/runanimationgroup(_:)).
+    #[inline]
+    #[doc(alias = "runAnimationGroup:")]
+    pub fn run_animation_group<C>(changes: C)
+    where
+        C: FnOnce(&NSAnimationContext),
+    {
+        unsafe { Self::run_animation_group_raw(changes, ptr::null()) }
+    }
+
+    /// Calls `changes` within a new animation group, and `completion` on the
+    /// main thread once all of its animations have finished.
+    ///
+    /// It is safe to panic within `changes` and `completion`. Panics will
+    /// abort the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsanimationcontext/runanimationgroup(_:completionhandler:)).
+    #[inline]
+    #[doc(alias = "runAnimationGroup:completionHandler:")]
+    pub fn run_animation_group_with_completion<C, F>(changes: C, completion: F)
+    where
+        C: FnOnce(&NSAnimationContext),
+        F: FnOnce() + 'static,
+    {
+        let completion = once_block(completion);
+        unsafe { Self::run_animation_group_raw(changes, &*completion) }
+    }
+
+    unsafe fn run_animation_group_raw<C>(changes: C, completion: *const Block<(), ()>)
+    where
+        C: FnOnce(&NSAnimationContext),
+    {
+        let changes = Cell::new(Some(changes));
+        let changes = &changes;
+        let block = StackBlock::new(move |context: *const NSAnimationContext| {
+            if let (Some(changes), Some(context)) = (changes.take(), context.as_ref()) {
+                changes(context);
+            }
+        });
+        let block: &Block<_, ()> = &block;
+
+        _msg_send_any![
+            Self::class(),
+            runAnimationGroup: block
+            completionHandler: completion
+            => ()
+        ]
+    }
+
+    /// Returns the duration of the group's animations, in seconds.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsanimationcontext/duration).
+    #[inline]
+    pub fn duration(&self) -> NSTimeInterval {
+        unsafe { _msg_send_any![self, duration] }
+    }
+
+    /// Sets the duration of the group's animations, in seconds.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsanimationcontext/duration).
+    #[inline]
+    #[doc(alias = "setDuration:")]
+    pub fn set_duration(&self, duration: NSTimeInterval) {
+        unsafe { _msg_send_any![self, setDuration: duration => ()] }
+    }
+
+    /// Returns the pacing of the group's animations, if one was set.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsanimationcontext/timingfunction).
+    #[cfg(feature = "core_animation")]
+    #[inline]
+    #[doc(alias = "timingFunction")]
+    pub fn timing_function(&self) -> Option<Arc<CAMediaTimingFunction>> {
+        unsafe {
+            _msg_send_any![self, timingFunction => *const CAMediaTimingFunction]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Sets the pacing of the group's animations, or restores the default
+    /// pacing if `function` is [`None`].
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsanimationcontext/timingfunction).
+    #[cfg(feature = "core_animation")]
+    #[inline]
+    #[doc(alias = "setTimingFunction:")]
+    pub fn set_timing_function(&self, function: Option<&CAMediaTimingFunction>) {
+        let function = match function {
+            Some(function) => function,
+            None => ptr::null(),
+        };
+        unsafe { _msg_send_any![self, setTimingFunction: function => ()] }
+    }
+
+    /// Returns `true` if changes to layer-backed views in the group are
+    /// animated without going through their `animator()` proxies.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsanimationcontext/allowsimplicitanimation).
+    #[inline]
+    #[doc(alias = "allowsImplicitAnimation")]
+    pub fn allows_implicit_animation(&self) -> bool {
+        unsafe { _msg_send_any![self, allowsImplicitAnimation => BOOL] }.into()
+    }
+
+    /// Sets whether changes to layer-backed views in the group are animated
+    /// without going through their `animator()` proxies.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsanimationcontext/allowsimplicitanimation).
+    #[inline]
+    #[doc(alias = "setAllowsImplicitAnimation:")]
+    pub fn set_allows_implicit_animation(&self, value: bool) {
+        unsafe { _msg_send_any![self, setAllowsImplicitAnimation: BOOL::from(value) => ()] }
+    }
+
+    /// Sets `completion` to be called on the main thread once all of the
+    /// group's animations have finished.
+    ///
+    /// This must be set before the group ends with
+    /// [`end_grouping`](Self::end_grouping).
+    ///
+    /// It is safe to panic within `completion`. Panics will abort the
+    /// process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsanimationcontext/completionhandler).
+    #[inline]
+    #[doc(alias = "setCompletionHandler:")]
+    pub fn set_completion_handler<F>(&self, completion: F)
+    where
+        F: FnOnce() + 'static,
+    {
+        let completion = once_block(completion);
+        let completion: &Block<(), ()> = &completion;
+        unsafe { _msg_send_any![self, setCompletionHandler: completion => ()] }
+    }
+}
+
+/// Wraps `f` in a block that calls it at most once.
+fn once_block<F: FnOnce() + 'static>(f: F) -> RcBlock<(), ()> {
+    let f = Cell::new(Some(f));
+    RcBlock::new(move || {
+        if let Some(f) = f.take() {
+            f();
+        }
+    })
+}
@@ -0,0 +1,66 @@
+use crate::objc::{ClassType, NSObject};
+
+#[cfg(feature = "dispatch")]
+use crate::{
+    core::Arc,
+    dispatch::{DispatchQosClass, DispatchQueue, DispatchSource, Signal},
+};
+
+objc_subclass! {
+    /// The object that runs the main event loop of an app.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsapplication).
+    pub class NSApplication: NSObject<'static>;
+}
+
+impl NSApplication {
+    /// Returns the application, creating it if it does not exist yet.
+    ///
+    /// This must be called on the main thread.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsapplication/shared).
+    #[inline]
+    #[doc(alias = "sharedApplication")]
+    pub fn shared() -> &'static Self {
+        unsafe { _msg_send_any![Self::class(), sharedApplication] }
+    }
+
+    /// Terminates the app, after asking the application delegate and open
+    /// documents whether it should.
+    ///
+    /// This must be called on the main thread. If the app terminates, this
+    /// does not return.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsapplication/terminate(_:)).
+    #[inline]
+    #[doc(alias = "terminate:")]
+    pub fn terminate(&self, sender: Option<&NSObject>) {
+        unsafe { _msg_send_any![self, terminate: sender => ()] }
+    }
+}
+
+/// Terminating on UNIX signals.
+#[cfg(feature = "dispatch")]
+impl NSApplication {
+    /// Terminates the app on the main thread each time the process receives
+    /// one of `signals`, instead of letting them kill the process.
+    ///
+    /// Unlike the default action of [`Signal::TERM`], this lets the
+    /// application delegate and documents save their state, as when the user
+    /// quits the app. The returned sources keep handling the signals until
+    /// they are [cancelled](DispatchSource::cancel).
+    ///
+    /// This is the AppKit counterpart of `CFRunLoop::stop_on_signals`, which
+    /// does not end `-[NSApplication run]`.
+    pub fn terminate_on_signals(signals: &[Signal]) -> Vec<Arc<DispatchSource>> {
+        let queue = DispatchQueue::global_with_qos(DispatchQosClass::Default);
+        signals
+            .iter()
+            .map(|&signal| {
+                DispatchSource::signal(queue, signal, |_| {
+                    DispatchQueue::main().spawn_async(|| NSApplication::shared().terminate(None));
+                })
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,99 @@
+use crate::core::Arc;
+use crate::foundation::{NSData, NSDictionary};
+use crate::objc::{NSInteger, NSObject, NSUInteger};
+
+#[cfg(feature = "core_foundation")]
+use crate::core_graphics::CGImage;
+
+objc_subclass! {
+    /// An image representation that stores pixels in a bitmap, such as a
+    /// [snapshot](super::NSView::snapshot_rep) of a view.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsbitmapimagerep).
+    pub class NSBitmapImageRep: NSObject<'static>;
+}
+
+impl NSBitmapImageRep {
+    /// Returns the width of the bitmap in pixels.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsimagerep/pixelswide).
+    #[inline]
+    #[doc(alias = "pixelsWide")]
+    pub fn pixels_wide(&self) -> NSInteger {
+        unsafe { _msg_send_any![self, pixelsWide] }
+    }
+
+    /// Returns the height of the bitmap in pixels.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsimagerep/pixelshigh).
+    #[inline]
+    #[doc(alias = "pixelsHigh")]
+    pub fn pixels_high(&self) -> NSInteger {
+        unsafe { _msg_send_any![self, pixelsHigh] }
+    }
+
+    /// Returns the bitmap as a `CGImage`, or [`None`] if it could not be
+    /// created.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsbitmapimagerep/cgimage).
+    #[cfg(feature = "core_foundation")]
+    #[inline]
+    #[doc(alias = "CGImage")]
+    pub fn cg_image(&self) -> Option<Arc<CGImage>> {
+        unsafe {
+            let image: *const CGImage = _msg_send_any![self, CGImage];
+            image.as_ref().map(Arc::retain)
+        }
+    }
+
+    /// Encodes the bitmap in `file_type` with default properties, or returns
+    /// [`None`] if it could not be encoded.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsbitmapimagerep/representation(using:properties:)).
+    #[inline]
+    #[doc(alias = "representationUsingType:properties:")]
+    pub fn representation(&self, file_type: NSBitmapImageFileType) -> Option<Arc<NSData>> {
+        let properties = NSDictionary::<NSObject<'static>, NSObject<'static>>::new();
+        unsafe {
+            let data: Option<&NSData> = _msg_send_any![
+                self,
+                representationUsingType: file_type
+                properties: &*properties
+            ];
+            data.map(Arc::retain)
+        }
+    }
+}
+
+/// A file format that an [`NSBitmapImageRep`] can be encoded in.
+///
+/// See [documentation](https://developer.apple.com/documentation/appkit/nsbitmapimagerep/filetype).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NSBitmapImageFileType(pub NSUInteger);
+
+impl NSBitmapImageFileType {
+    /// Tagged Image File Format (TIFF).
+    #[doc(alias = "NSBitmapImageFileTypeTIFF")]
+    pub const TIFF: Self = Self(0);
+
+    /// Windows bitmap image (BMP) format.
+    #[doc(alias = "NSBitmapImageFileTypeBMP")]
+    pub const BMP: Self = Self(1);
+
+    /// Graphics Image Format (GIF).
+    #[doc(alias = "NSBitmapImageFileTypeGIF")]
+    pub const GIF: Self = Self(2);
+
+    /// Joint Photographic Experts Group (JPEG) format.
+    #[doc(alias = "NSBitmapImageFileTypeJPEG")]
+    pub const JPEG: Self = Self(3);
+
+    /// Portable Network Graphics (PNG) format.
+    #[doc(alias = "NSBitmapImageFileTypePNG")]
+    pub const PNG: Self = Self(4);
+
+    /// JPEG 2000 file format.
+    #[doc(alias = "NSBitmapImageFileTypeJPEG2000")]
+    pub const JPEG2000: Self = Self(5);
+}
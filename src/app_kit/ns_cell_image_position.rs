@@ -0,0 +1,46 @@
+use crate::objc::NSUInteger;
+
+/// Where a control, such as a button, draws its image relative to its title.
+///
+/// See [documentation](https://developer.apple.com/documentation/appkit/nscell/imageposition).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NSCellImagePosition(pub NSUInteger);
+
+impl NSCellImagePosition {
+    /// Only the title is drawn.
+    #[doc(alias = "NSNoImage")]
+    pub const NO_IMAGE: Self = Self(0);
+
+    /// Only the image is drawn.
+    #[doc(alias = "NSImageOnly")]
+    pub const IMAGE_ONLY: Self = Self(1);
+
+    /// The image is to the left of the title.
+    #[doc(alias = "NSImageLeft")]
+    pub const IMAGE_LEFT: Self = Self(2);
+
+    /// The image is to the right of the title.
+    #[doc(alias = "NSImageRight")]
+    pub const IMAGE_RIGHT: Self = Self(3);
+
+    /// The image is below the title.
+    #[doc(alias = "NSImageBelow")]
+    pub const IMAGE_BELOW: Self = Self(4);
+
+    /// The image is above the title.
+    #[doc(alias = "NSImageAbove")]
+    pub const IMAGE_ABOVE: Self = Self(5);
+
+    /// The image is drawn behind the title.
+    #[doc(alias = "NSImageOverlaps")]
+    pub const IMAGE_OVERLAPS: Self = Self(6);
+
+    /// The image is before the title in the user interface layout direction.
+    #[doc(alias = "NSImageLeading")]
+    pub const IMAGE_LEADING: Self = Self(7);
+
+    /// The image is after the title in the user interface layout direction.
+    #[doc(alias = "NSImageTrailing")]
+    pub const IMAGE_TRAILING: Self = Self(8);
+}
@@ -0,0 +1,605 @@
+use super::{NSCollectionViewItem, NSCollectionViewLayout, NSView};
+use crate::core::Arc;
+use crate::foundation::{NSArray, NSIndexPath, NSRect, NSString};
+use crate::objc::{Class, ClassType, LazyClass, NSInteger, NSObject, Protocol, Sel, BOOL};
+use std::{
+    ffi::{c_void, CStr},
+    fmt,
+    panic::{self, AssertUnwindSafe},
+    process, ptr,
+};
+
+objc_subclass! {
+    /// A view that shows a grid or other layout of items, such as an image
+    /// browser.
+    ///
+    /// Items are [registered](Self::register_item_class) as reusable classes
+    /// and created by a [data source](Self::set_data_source). A collection
+    /// view is typically the document view of an
+    /// [`NSScrollView`](super::NSScrollView).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fruity::app_kit::*;
+    /// use fruity::foundation::{NSRect, NSSize};
+    /// use fruity::objc::ClassType;
+    /// use std::ffi::CStr;
+    ///
+    /// # fn thumbnail() -> fruity::core::Arc<NSView> { unimplemented!() }
+    /// let name = CStr::from_bytes_with_nul(b"ThumbnailItem\0").unwrap();
+    /// let item_class = NSViewControllerClassBuilder::with_superclass(name, NSCollectionViewItem::class())
+    ///     .expect("class already exists")
+    ///     .load_view(|_item| thumbnail())
+    ///     .register();
+    ///
+    /// let layout = NSCollectionViewFlowLayout::new();
+    /// layout.set_item_size(NSSize::new(120.0, 90.0));
+    ///
+    /// let collection_view = NSCollectionView::new(NSRect::default());
+    /// collection_view.set_collection_view_layout(&layout);
+    /// collection_view.set_selectable(true);
+    /// collection_view.register_item_class(item_class, fruity::ns_string!("thumbnail"));
+    ///
+    /// let data_source = collection_view.set_data_source(
+    ///     NSCollectionViewDataSourceBuilder::new(
+    ///         |_, _section| 100,
+    ///         |collection_view, index_path| {
+    ///             collection_view.make_item(fruity::ns_string!("thumbnail"), index_path)
+    ///         },
+    ///     )
+    ///     .did_select_items(|_, index_paths| {
+    ///         println!("selected {:?}", index_paths);
+    ///     }),
+    /// );
+    /// ```
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nscollectionview).
+    pub class NSCollectionView: NSView;
+}
+
+impl NSCollectionView {
+    /// Creates an empty collection view with the location and size of
+    /// `frame`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsview/init(frame:)).
+    #[inline]
+    #[doc(alias = "initWithFrame:")]
+    pub fn new(frame: NSRect) -> Arc<Self> {
+        unsafe {
+            let obj: Arc<Self> = Self::class().alloc();
+
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(
+                    obj: Arc<NSCollectionView>,
+                    sel: Sel,
+                    frame: NSRect,
+                ) -> Arc<NSCollectionView>;
+            }
+
+            objc_msgSend(obj, selector!(initWithFrame:), frame)
+        }
+    }
+
+    /// Returns the layout that positions the items.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nscollectionview/collectionviewlayout).
+    #[inline]
+    #[doc(alias = "collectionViewLayout")]
+    pub fn collection_view_layout(&self) -> Option<&NSCollectionViewLayout> {
+        unsafe { _msg_send_any![self, collectionViewLayout] }
+    }
+
+    /// Sets the layout that positions the items, such as an
+    /// [`NSCollectionViewFlowLayout`](super::NSCollectionViewFlowLayout).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nscollectionview/collectionviewlayout).
+    #[inline]
+    #[doc(alias = "setCollectionViewLayout:")]
+    pub fn set_collection_view_layout(&self, layout: &NSCollectionViewLayout) {
+        unsafe { _msg_send_any![self, setCollectionViewLayout: layout => ()] }
+    }
+
+    /// Registers `class` to be created by [`make_item`](Self::make_item)
+    /// for `identifier`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `class` is not a subclass of [`NSCollectionViewItem`].
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nscollectionview/register(_:foritemwithidentifier:)-8cp7j).
+    #[inline]
+    #[doc(alias = "registerClass:forItemWithIdentifier:")]
+    pub fn register_item_class(&self, class: &Class, identifier: &NSString) {
+        assert!(
+            class.is_subclass_of(NSCollectionViewItem::class()),
+            "{:?} is not a subclass of NSCollectionViewItem",
+            class.name(),
+        );
+        unsafe {
+            _msg_send_any![
+                self,
+                registerClass: class
+                forItemWithIdentifier: identifier
+                => ()
+            ]
+        }
+    }
+
+    /// Returns an item of the class registered for `identifier`, reusing one
+    /// that scrolled out of view if possible.
+    ///
+    /// This is called by the data source when asked for an item.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nscollectionview/makeitem(withidentifier:for:)).
+    #[inline]
+    #[doc(alias = "makeItemWithIdentifier:forIndexPath:")]
+    pub fn make_item(
+        &self,
+        identifier: &NSString,
+        index_path: &NSIndexPath,
+    ) -> Arc<NSCollectionViewItem> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![
+                self,
+                makeItemWithIdentifier: identifier
+                forIndexPath: index_path
+                => *const NSCollectionViewItem
+            ])
+        }
+    }
+
+    /// Returns the item at `index_path` if it is in view.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nscollectionview/item(at:)-8dbkt).
+    #[inline]
+    #[doc(alias = "itemAtIndexPath:")]
+    pub fn item_at(&self, index_path: &NSIndexPath) -> Option<Arc<NSCollectionViewItem>> {
+        unsafe {
+            _msg_send_any![self, itemAtIndexPath: index_path => *const NSCollectionViewItem]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Asks the data source for all sections and items again.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nscollectionview/reloaddata()).
+    #[inline]
+    #[doc(alias = "reloadData")]
+    pub fn reload_data(&self) {
+        unsafe { _msg_send_any![self, reloadData => ()] }
+    }
+
+    /// Returns the number of sections.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nscollectionview/numberofsections).
+    #[inline]
+    #[doc(alias = "numberOfSections")]
+    pub fn number_of_sections(&self) -> usize {
+        unsafe { _msg_send_any![self, numberOfSections => NSInteger] as usize }
+    }
+
+    /// Returns the number of items in `section`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nscollectionview/numberofitems(insection:)).
+    #[inline]
+    #[doc(alias = "numberOfItemsInSection:")]
+    pub fn number_of_items_in_section(&self, section: usize) -> usize {
+        let section = section as NSInteger;
+        unsafe { _msg_send_any![self, numberOfItemsInSection: section => NSInteger] as usize }
+    }
+
+    /// Returns `true` if the user can select items.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nscollectionview/isselectable).
+    #[inline]
+    #[doc(alias = "isSelectable")]
+    pub fn is_selectable(&self) -> bool {
+        unsafe { _msg_send_any![self, isSelectable => BOOL] }.into()
+    }
+
+    /// Sets whether the user can select items, which is `false` by default.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nscollectionview/isselectable).
+    #[inline]
+    #[doc(alias = "setSelectable:")]
+    pub fn set_selectable(&self, value: bool) {
+        unsafe { _msg_send_any![self, setSelectable: BOOL::from(value) => ()] }
+    }
+
+    /// Returns `true` if the user can select more than one item.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nscollectionview/allowsmultipleselection).
+    #[inline]
+    #[doc(alias = "allowsMultipleSelection")]
+    pub fn allows_multiple_selection(&self) -> bool {
+        unsafe { _msg_send_any![self, allowsMultipleSelection => BOOL] }.into()
+    }
+
+    /// Sets whether the user can select more than one item.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nscollectionview/allowsmultipleselection).
+    #[inline]
+    #[doc(alias = "setAllowsMultipleSelection:")]
+    pub fn set_allows_multiple_selection(&self, value: bool) {
+        unsafe { _msg_send_any![self, setAllowsMultipleSelection: BOOL::from(value) => ()] }
+    }
+
+    /// Returns the index paths of the selected items, in no particular
+    /// order.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nscollectionview/selectionindexpaths).
+    #[inline]
+    #[doc(alias = "selectionIndexPaths")]
+    pub fn selection_index_paths(&self) -> Vec<Arc<NSIndexPath>> {
+        unsafe {
+            let set = _msg_send_any![self, selectionIndexPaths => *const NSObject<'static>];
+            index_paths_of_set(&*set)
+        }
+    }
+
+    /// Makes `builder` provide the items of `self` and receive its selection
+    /// changes, until the returned value is dropped.
+    ///
+    /// This replaces the data source and delegate of `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nscollectionview/datasource).
+    #[doc(alias = "setDataSource:")]
+    #[doc(alias = "setDelegate:")]
+    pub fn set_data_source(
+        &self,
+        builder: NSCollectionViewDataSourceBuilder,
+    ) -> NSCollectionViewDataSource {
+        let handlers = Box::new(builder);
+        let object: Arc<NSObject<'static>> = unsafe { data_source_class().alloc_init() };
+
+        unsafe {
+            *handlers_slot(&object) =
+                &*handlers as *const NSCollectionViewDataSourceBuilder as *mut c_void;
+            _msg_send_any![self, setDataSource: &*object => ()];
+            _msg_send_any![self, setDelegate: &*object => ()];
+        }
+
+        NSCollectionViewDataSource {
+            collection_view: Arc::retain(self),
+            object,
+            handlers,
+        }
+    }
+}
+
+/// AppKit additions for index paths into collection views.
+impl NSIndexPath {
+    /// Creates an index path to `item` in `section`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsindexpath/init(item:section:)).
+    #[inline]
+    #[doc(alias = "indexPathForItem:inSection:")]
+    pub fn for_item(item: usize, section: usize) -> Arc<Self> {
+        let item = item as NSInteger;
+        let section = section as NSInteger;
+        unsafe {
+            Arc::retain_raw(_msg_send_any![
+                Self::class(),
+                indexPathForItem: item
+                inSection: section
+                => *const Self
+            ])
+        }
+    }
+
+    /// Returns the index of the item in its section.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsindexpath/item).
+    #[inline]
+    pub fn item(&self) -> usize {
+        unsafe { _msg_send_any![self, item => NSInteger] as usize }
+    }
+
+    /// Returns the index of the section.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsindexpath/section).
+    #[inline]
+    pub fn section(&self) -> usize {
+        unsafe { _msg_send_any![self, section => NSInteger] as usize }
+    }
+}
+
+/// Returns the index paths in an `NSSet<NSIndexPath *>`.
+unsafe fn index_paths_of_set(set: &NSObject<'static>) -> Vec<Arc<NSIndexPath>> {
+    let array = _msg_send_any![set, allObjects => *const NSArray<NSIndexPath>];
+    (*array).to_vec()
+}
+
+type NumberOfSections = dyn Fn(&NSCollectionView) -> usize + Send;
+
+type NumberOfItems = dyn Fn(&NSCollectionView, usize) -> usize + Send;
+
+type ItemForIndexPath = dyn Fn(&NSCollectionView, &NSIndexPath) -> Arc<NSCollectionViewItem> + Send;
+
+type SelectionChanged = dyn Fn(&NSCollectionView, &[Arc<NSIndexPath>]) + Send;
+
+/// The closures that provide the items of an [`NSCollectionView`] and handle
+/// its selection, attached with [`NSCollectionView::set_data_source`].
+///
+/// The closures are called on the main thread. It is safe to panic within
+/// them. Panics will abort the process.
+pub struct NSCollectionViewDataSourceBuilder {
+    number_of_sections: Option<Box<NumberOfSections>>,
+    number_of_items: Box<NumberOfItems>,
+    item_for_index_path: Box<ItemForIndexPath>,
+    did_select_items: Option<Box<SelectionChanged>>,
+    did_deselect_items: Option<Box<SelectionChanged>>,
+}
+
+impl fmt::Debug for NSCollectionViewDataSourceBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("NSCollectionViewDataSourceBuilder").finish()
+    }
+}
+
+impl NSCollectionViewDataSourceBuilder {
+    /// Creates a data source with one section, where `number_of_items`
+    /// returns the number of items in a section and `item_for_index_path`
+    /// returns the item at an index path, typically from
+    /// [`NSCollectionView::make_item`].
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nscollectionviewdatasource).
+    #[inline]
+    #[doc(alias = "collectionView:numberOfItemsInSection:")]
+    #[doc(alias = "collectionView:itemForRepresentedObjectAtIndexPath:")]
+    pub fn new<N, I>(number_of_items: N, item_for_index_path: I) -> Self
+    where
+        N: Fn(&NSCollectionView, usize) -> usize + Send + 'static,
+        I: Fn(&NSCollectionView, &NSIndexPath) -> Arc<NSCollectionViewItem> + Send + 'static,
+    {
+        Self {
+            number_of_sections: None,
+            number_of_items: Box::new(number_of_items),
+            item_for_index_path: Box::new(item_for_index_path),
+            did_select_items: None,
+            did_deselect_items: None,
+        }
+    }
+
+    /// Sets the closure that returns the number of sections, which is 1 by
+    /// default.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nscollectionviewdatasource/numberofsections(in:)).
+    #[inline]
+    #[doc(alias = "numberOfSectionsInCollectionView:")]
+    pub fn number_of_sections<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&NSCollectionView) -> usize + Send + 'static,
+    {
+        self.number_of_sections = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the closure called with the index paths of items that the user
+    /// selected.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nscollectionviewdelegate/collectionview(_:didselectitemsat:)).
+    #[inline]
+    #[doc(alias = "collectionView:didSelectItemsAtIndexPaths:")]
+    pub fn did_select_items<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&NSCollectionView, &[Arc<NSIndexPath>]) + Send + 'static,
+    {
+        self.did_select_items = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the closure called with the index paths of items that the user
+    /// deselected.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nscollectionviewdelegate/collectionview(_:diddeselectitemsat:)).
+    #[inline]
+    #[doc(alias = "collectionView:didDeselectItemsAtIndexPaths:")]
+    pub fn did_deselect_items<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&NSCollectionView, &[Arc<NSIndexPath>]) + Send + 'static,
+    {
+        self.did_deselect_items = Some(Box::new(f));
+        self
+    }
+}
+
+/// A data source set by [`NSCollectionView::set_data_source`], which is
+/// removed when dropped.
+#[must_use = "The data source is immediately removed if unused"]
+pub struct NSCollectionViewDataSource {
+    collection_view: Arc<NSCollectionView>,
+    object: Arc<NSObject<'static>>,
+    // Referenced by `object` until dropped.
+    #[allow(dead_code)]
+    handlers: Box<NSCollectionViewDataSourceBuilder>,
+}
+
+impl fmt::Debug for NSCollectionViewDataSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("NSCollectionViewDataSource").finish()
+    }
+}
+
+impl Drop for NSCollectionViewDataSource {
+    fn drop(&mut self) {
+        unsafe {
+            *handlers_slot(&self.object) = ptr::null_mut();
+
+            // Only detach if the data source was not replaced.
+            let view: &NSCollectionView = &self.collection_view;
+            let object: *const NSObject = &*self.object;
+            if ptr::eq(_msg_send_any![view, dataSource => *const NSObject], object) {
+                _msg_send_any![view, setDataSource: ptr::null::<NSObject>() => ()];
+            }
+            if ptr::eq(_msg_send_any![view, delegate => *const NSObject], object) {
+                _msg_send_any![view, setDelegate: ptr::null::<NSObject>() => ()];
+            }
+        }
+    }
+}
+
+/// The name of the instance variable that points to the
+/// `NSCollectionViewDataSourceBuilder`.
+const HANDLERS_IVAR: &[u8] = b"rustDataSource\0";
+
+#[inline]
+fn handlers_slot(object: &NSObject<'static>) -> *mut *mut c_void {
+    let name = unsafe { CStr::from_bytes_with_nul_unchecked(HANDLERS_IVAR) };
+    object.ivar_ptr::<*mut c_void>(name)
+}
+
+/// Returns the handlers of `object`, or [`None`] if its
+/// [`NSCollectionViewDataSource`] was dropped.
+#[inline]
+fn handlers<'a>(object: &'a NSObject<'static>) -> Option<&'a NSCollectionViewDataSourceBuilder> {
+    unsafe { (*handlers_slot(object) as *const NSCollectionViewDataSourceBuilder).as_ref() }
+}
+
+/// Calls `f`, aborting if it panics, since unwinding into Objective-C is
+/// undefined behavior.
+#[inline]
+fn abort_on_panic<T>(f: impl FnOnce() -> T) -> T {
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(value) => value,
+        Err(_) => process::abort(),
+    }
+}
+
+/// Returns the class of data source objects, registering it with the
+/// Objective-C runtime on first use.
+fn data_source_class() -> &'static Class {
+    static CLASS: LazyClass = LazyClass::new("FruityCollectionViewDataSource");
+
+    CLASS.get(NSObject::class(), |builder| {
+        builder.add_ivar::<*mut c_void>(CStr::from_bytes_with_nul(HANDLERS_IVAR).unwrap());
+
+        for protocol in &[
+            &b"NSCollectionViewDataSource\0"[..],
+            b"NSCollectionViewDelegate\0",
+        ] {
+            if let Some(protocol) = Protocol::get(CStr::from_bytes_with_nul(protocol).unwrap()) {
+                builder.add_protocol(protocol);
+            }
+        }
+
+        unsafe {
+            builder.add_method(
+                selector!(numberOfSectionsInCollectionView:),
+                number_of_sections as NumberOfSectionsImp,
+            );
+            builder.add_method(
+                selector!(collectionView:numberOfItemsInSection:),
+                number_of_items as NumberOfItemsImp,
+            );
+            builder.add_method(
+                selector!(collectionView:itemForRepresentedObjectAtIndexPath:),
+                item_for_index_path as ItemForIndexPathImp,
+            );
+            builder.add_method(
+                selector!(collectionView:didSelectItemsAtIndexPaths:),
+                did_select_items as SelectionChangedImp,
+            );
+            builder.add_method(
+                selector!(collectionView:didDeselectItemsAtIndexPaths:),
+                did_deselect_items as SelectionChangedImp,
+            );
+        }
+    })
+}
+
+type NumberOfSectionsImp =
+    extern "C" fn(&NSObject<'static>, Sel, *const NSCollectionView) -> NSInteger;
+
+extern "C" fn number_of_sections(
+    this: &NSObject<'static>,
+    _: Sel,
+    collection_view: *const NSCollectionView,
+) -> NSInteger {
+    let collection_view = unsafe { &*collection_view };
+    let count = match handlers(this).and_then(|h| h.number_of_sections.as_ref()) {
+        Some(f) => abort_on_panic(|| f(collection_view)),
+        None => 1,
+    };
+    count as NSInteger
+}
+
+type NumberOfItemsImp =
+    extern "C" fn(&NSObject<'static>, Sel, *const NSCollectionView, NSInteger) -> NSInteger;
+
+extern "C" fn number_of_items(
+    this: &NSObject<'static>,
+    _: Sel,
+    collection_view: *const NSCollectionView,
+    section: NSInteger,
+) -> NSInteger {
+    let collection_view = unsafe { &*collection_view };
+    let count = match handlers(this) {
+        Some(h) => abort_on_panic(|| (h.number_of_items)(collection_view, section as usize)),
+        None => 0,
+    };
+    count as NSInteger
+}
+
+type ItemForIndexPathImp = extern "C" fn(
+    &NSObject<'static>,
+    Sel,
+    *const NSCollectionView,
+    *const NSIndexPath,
+) -> *const NSCollectionViewItem;
+
+extern "C" fn item_for_index_path(
+    this: &NSObject<'static>,
+    _: Sel,
+    collection_view: *const NSCollectionView,
+    index_path: *const NSIndexPath,
+) -> *const NSCollectionViewItem {
+    extern "C" {
+        fn objc_autoreleaseReturnValue(obj: *mut c_void) -> *mut c_void;
+    }
+
+    // Items are only requested while the data source is attached.
+    let handlers = match handlers(this) {
+        Some(handlers) => handlers,
+        None => process::abort(),
+    };
+    let (collection_view, index_path) = unsafe { (&*collection_view, &*index_path) };
+    let item = abort_on_panic(|| (handlers.item_for_index_path)(collection_view, index_path));
+
+    unsafe {
+        objc_autoreleaseReturnValue(Arc::into_raw(item) as *mut c_void)
+            as *const NSCollectionViewItem
+    }
+}
+
+type SelectionChangedImp =
+    extern "C" fn(&NSObject<'static>, Sel, *const NSCollectionView, *const NSObject<'static>);
+
+extern "C" fn did_select_items(
+    this: &NSObject<'static>,
+    _: Sel,
+    collection_view: *const NSCollectionView,
+    index_paths: *const NSObject<'static>,
+) {
+    let collection_view = unsafe { &*collection_view };
+    if let Some(f) = handlers(this).and_then(|h| h.did_select_items.as_ref()) {
+        let index_paths = unsafe { index_paths_of_set(&*index_paths) };
+        abort_on_panic(|| f(collection_view, &index_paths));
+    }
+}
+
+extern "C" fn did_deselect_items(
+    this: &NSObject<'static>,
+    _: Sel,
+    collection_view: *const NSCollectionView,
+    index_paths: *const NSObject<'static>,
+) {
+    let collection_view = unsafe { &*collection_view };
+    if let Some(f) = handlers(this).and_then(|h| h.did_deselect_items.as_ref()) {
+        let index_paths = unsafe { index_paths_of_set(&*index_paths) };
+        abort_on_panic(|| f(collection_view, &index_paths));
+    }
+}
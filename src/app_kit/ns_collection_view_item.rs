@@ -0,0 +1,36 @@
+use super::{NSCollectionView, NSViewController};
+use crate::objc::BOOL;
+
+objc_subclass! {
+    /// The controller of an element in an [`NSCollectionView`].
+    ///
+    /// Item classes are declared with
+    /// [`NSViewControllerClassBuilder::with_superclass`](super::NSViewControllerClassBuilder::with_superclass)
+    /// and registered with [`NSCollectionView::register_item_class`], which
+    /// lets the collection view reuse items as they scroll out of view. An
+    /// item shows its [represented object](NSViewController::set_represented_object),
+    /// which the data source sets when the item is reused.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nscollectionviewitem).
+    pub class NSCollectionViewItem: NSViewController;
+}
+
+impl NSCollectionViewItem {
+    /// Returns the collection view that shows `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nscollectionviewitem/collectionview).
+    #[inline]
+    #[doc(alias = "collectionView")]
+    pub fn collection_view(&self) -> Option<&NSCollectionView> {
+        unsafe { _msg_send_any![self, collectionView] }
+    }
+
+    /// Returns `true` if the item is selected.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nscollectionviewitem/isselected).
+    #[inline]
+    #[doc(alias = "isSelected")]
+    pub fn is_selected(&self) -> bool {
+        unsafe { _msg_send_any![self, isSelected => BOOL] }.into()
+    }
+}
@@ -0,0 +1,139 @@
+use crate::core::Arc;
+use crate::core_graphics::CGFloat;
+use crate::foundation::{NSEdgeInsets, NSSize};
+use crate::objc::{ClassType, NSInteger, NSObject};
+
+objc_subclass! {
+    /// Positions the items of an [`NSCollectionView`](super::NSCollectionView).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nscollectionviewlayout).
+    pub class NSCollectionViewLayout: NSObject<'static>;
+}
+
+objc_subclass! {
+    /// A layout that places items in rows that wrap, like a grid of
+    /// thumbnails.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nscollectionviewflowlayout).
+    pub class NSCollectionViewFlowLayout: NSCollectionViewLayout;
+}
+
+impl NSCollectionViewFlowLayout {
+    /// Creates a vertically scrolling layout with the default item size and
+    /// spacing.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nscollectionviewflowlayout).
+    #[inline]
+    pub fn new() -> Arc<Self> {
+        unsafe { Self::class().alloc_init() }
+    }
+
+    /// Returns the size of each item.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nscollectionviewflowlayout/itemsize).
+    #[inline]
+    #[doc(alias = "itemSize")]
+    pub fn item_size(&self) -> NSSize {
+        unsafe { _msg_send_any![self, itemSize] }
+    }
+
+    /// Sets the size of each item.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nscollectionviewflowlayout/itemsize).
+    #[inline]
+    #[doc(alias = "setItemSize:")]
+    pub fn set_item_size(&self, size: NSSize) {
+        unsafe { _msg_send_any![self, setItemSize: size => ()] }
+    }
+
+    /// Returns the minimum spacing between rows, or between columns when
+    /// scrolling horizontally.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nscollectionviewflowlayout/minimumlinespacing).
+    #[inline]
+    #[doc(alias = "minimumLineSpacing")]
+    pub fn minimum_line_spacing(&self) -> CGFloat {
+        unsafe { _msg_send_any![self, minimumLineSpacing] }
+    }
+
+    /// Sets the minimum spacing between rows, or between columns when
+    /// scrolling horizontally.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nscollectionviewflowlayout/minimumlinespacing).
+    #[inline]
+    #[doc(alias = "setMinimumLineSpacing:")]
+    pub fn set_minimum_line_spacing(&self, spacing: CGFloat) {
+        unsafe { _msg_send_any![self, setMinimumLineSpacing: spacing => ()] }
+    }
+
+    /// Returns the minimum spacing between items in the same row.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nscollectionviewflowlayout/minimuminteritemspacing).
+    #[inline]
+    #[doc(alias = "minimumInteritemSpacing")]
+    pub fn minimum_interitem_spacing(&self) -> CGFloat {
+        unsafe { _msg_send_any![self, minimumInteritemSpacing] }
+    }
+
+    /// Sets the minimum spacing between items in the same row.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nscollectionviewflowlayout/minimuminteritemspacing).
+    #[inline]
+    #[doc(alias = "setMinimumInteritemSpacing:")]
+    pub fn set_minimum_interitem_spacing(&self, spacing: CGFloat) {
+        unsafe { _msg_send_any![self, setMinimumInteritemSpacing: spacing => ()] }
+    }
+
+    /// Returns the margins around the items of each section.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nscollectionviewflowlayout/sectioninset).
+    #[inline]
+    #[doc(alias = "sectionInset")]
+    pub fn section_inset(&self) -> NSEdgeInsets {
+        unsafe { _msg_send_any![self, sectionInset] }
+    }
+
+    /// Sets the margins around the items of each section.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nscollectionviewflowlayout/sectioninset).
+    #[inline]
+    #[doc(alias = "setSectionInset:")]
+    pub fn set_section_inset(&self, inset: NSEdgeInsets) {
+        unsafe { _msg_send_any![self, setSectionInset: inset => ()] }
+    }
+
+    /// Returns the direction in which the layout scrolls.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nscollectionviewflowlayout/scrolldirection).
+    #[inline]
+    #[doc(alias = "scrollDirection")]
+    pub fn scroll_direction(&self) -> NSCollectionViewScrollDirection {
+        unsafe { _msg_send_any![self, scrollDirection] }
+    }
+
+    /// Sets the direction in which the layout scrolls.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nscollectionviewflowlayout/scrolldirection).
+    #[inline]
+    #[doc(alias = "setScrollDirection:")]
+    pub fn set_scroll_direction(&self, direction: NSCollectionViewScrollDirection) {
+        unsafe { _msg_send_any![self, setScrollDirection: direction => ()] }
+    }
+}
+
+/// The direction in which an [`NSCollectionViewFlowLayout`] scrolls.
+///
+/// See [documentation](https://developer.apple.com/documentation/appkit/nscollectionview/scrolldirection).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NSCollectionViewScrollDirection(pub NSInteger);
+
+impl NSCollectionViewScrollDirection {
+    /// Items fill rows, which scroll vertically.
+    #[doc(alias = "NSCollectionViewScrollDirectionVertical")]
+    pub const VERTICAL: Self = Self(0);
+
+    /// Items fill columns, which scroll horizontally.
+    #[doc(alias = "NSCollectionViewScrollDirectionHorizontal")]
+    pub const HORIZONTAL: Self = Self(1);
+}
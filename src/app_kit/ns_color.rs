@@ -0,0 +1,93 @@
+use crate::core::Arc;
+use crate::core_graphics::CGFloat;
+use crate::objc::{Class, ClassType, NSObject};
+use std::ffi::CStr;
+
+#[cfg(feature = "core_foundation")]
+use crate::core_graphics::CGColor;
+
+objc_subclass! {
+    /// A color, such as one picked with an
+    /// [`NSColorPanel`](super::NSColorPanel).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nscolor).
+    pub class NSColor: NSObject<'static>;
+}
+
+impl NSColor {
+    /// Creates a color from components in the sRGB color space.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nscolor/init(srgbred:green:blue:alpha:)).
+    #[inline]
+    #[doc(alias = "colorWithSRGBRed:green:blue:alpha:")]
+    pub fn from_srgb(red: CGFloat, green: CGFloat, blue: CGFloat, alpha: CGFloat) -> Arc<Self> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![
+                Self::class(),
+                colorWithSRGBRed: red
+                green: green
+                blue: blue
+                alpha: alpha
+                => *const Self
+            ])
+        }
+    }
+
+    /// Creates a color from `color`, or returns [`None`] if it cannot be
+    /// represented.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nscolor/init(cgcolor:)).
+    #[cfg(feature = "core_foundation")]
+    #[inline]
+    #[doc(alias = "colorWithCGColor:")]
+    pub fn from_cg_color(color: &CGColor) -> Option<Arc<Self>> {
+        unsafe {
+            _msg_send_any![Self::class(), colorWithCGColor: color => *const Self]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Returns `self` as a Core Graphics color, or [`None`] if it cannot be
+    /// represented as one, such as a pattern color.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nscolor/cgcolor).
+    #[cfg(feature = "core_foundation")]
+    #[inline]
+    #[doc(alias = "CGColor")]
+    pub fn cg_color(&self) -> Option<Arc<CGColor>> {
+        unsafe {
+            _msg_send_any![self, CGColor => *const CGColor]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Returns the red, green, blue, and alpha components of `self` in the
+    /// sRGB color space, or [`None`] if it cannot be converted.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nscolor/getred(_:green:blue:alpha:)).
+    #[doc(alias = "colorUsingColorSpace:")]
+    #[doc(alias = "getRed:green:blue:alpha:")]
+    pub fn srgb_components(&self) -> Option<[CGFloat; 4]> {
+        let color_space_class =
+            unsafe { Class::get(CStr::from_bytes_with_nul_unchecked(b"NSColorSpace\0"))? };
+
+        unsafe {
+            let srgb: &NSObject = _msg_send_any![color_space_class, sRGBColorSpace];
+            let color = _msg_send_any![self, colorUsingColorSpace: srgb => *const Self].as_ref()?;
+
+            let mut components: [CGFloat; 4] = [0.0; 4];
+            let [red, green, blue, alpha] = &mut components;
+            _msg_send_any![
+                color,
+                getRed: red as *mut CGFloat
+                green: green as *mut CGFloat
+                blue: blue as *mut CGFloat
+                alpha: alpha as *mut CGFloat
+                => ()
+            ];
+            Some(components)
+        }
+    }
+}
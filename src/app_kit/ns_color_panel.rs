@@ -0,0 +1,165 @@
+use super::{action_target::ActionTarget, NSColor, NSWindow};
+use crate::core::Arc;
+use crate::objc::{ClassType, NSObject, BOOL};
+use std::{
+    fmt, ptr,
+    sync::atomic::{AtomicPtr, Ordering},
+};
+
+/// The target set by the most recent change handler, since the panel has no
+/// getter for its target.
+static CURRENT_TARGET: AtomicPtr<NSObject<'static>> = AtomicPtr::new(ptr::null_mut());
+
+objc_subclass! {
+    /// The standard panel for picking a color, shared by the whole app.
+    ///
+    /// The panel is shown and hidden with the methods of [`NSWindow`], such
+    /// as [`order_front`](NSWindow::order_front) and
+    /// [`order_out`](NSWindow::order_out).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fruity::app_kit::NSColorPanel;
+    ///
+    /// let panel = NSColorPanel::shared();
+    /// let _handler = panel.set_change_handler(|color| {
+    ///     println!("picked {:?}", color.srgb_components());
+    /// });
+    /// panel.order_front();
+    /// ```
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nscolorpanel).
+    pub class NSColorPanel: NSWindow;
+}
+
+impl NSColorPanel {
+    /// Returns the app's color panel, creating it if needed.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nscolorpanel/shared).
+    #[inline]
+    #[doc(alias = "sharedColorPanel")]
+    pub fn shared() -> &'static Self {
+        unsafe { _msg_send_any![Self::class(), sharedColorPanel] }
+    }
+
+    /// Returns the color currently picked in the panel.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nscolorpanel/color).
+    #[inline]
+    pub fn color(&self) -> Arc<NSColor> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, color => *const NSColor]) }
+    }
+
+    /// Sets the color picked in the panel, such as the color of the current
+    /// selection.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nscolorpanel/color).
+    #[inline]
+    #[doc(alias = "setColor:")]
+    pub fn set_color(&self, color: &NSColor) {
+        unsafe { _msg_send_any![self, setColor: color => ()] }
+    }
+
+    /// Returns `true` if the panel lets the user pick opacity.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nscolorpanel/showsalpha).
+    #[inline]
+    #[doc(alias = "showsAlpha")]
+    pub fn shows_alpha(&self) -> bool {
+        unsafe { _msg_send_any![self, showsAlpha => BOOL] }.into()
+    }
+
+    /// Sets whether the panel lets the user pick opacity.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nscolorpanel/showsalpha).
+    #[inline]
+    #[doc(alias = "setShowsAlpha:")]
+    pub fn set_shows_alpha(&self, value: bool) {
+        unsafe { _msg_send_any![self, setShowsAlpha: BOOL::from(value) => ()] }
+    }
+
+    /// Returns `true` if changes are reported continuously while the user
+    /// drags, rather than only when they release.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nscolorpanel/iscontinuous).
+    #[inline]
+    #[doc(alias = "isContinuous")]
+    pub fn is_continuous(&self) -> bool {
+        unsafe { _msg_send_any![self, isContinuous => BOOL] }.into()
+    }
+
+    /// Sets whether changes are reported continuously while the user drags,
+    /// rather than only when they release.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nscolorpanel/iscontinuous).
+    #[inline]
+    #[doc(alias = "setContinuous:")]
+    pub fn set_continuous(&self, value: bool) {
+        unsafe { _msg_send_any![self, setContinuous: BOOL::from(value) => ()] }
+    }
+
+    /// Calls `handler` on the main thread with each color the user picks,
+    /// until the returned value is dropped.
+    ///
+    /// Setting another handler replaces this one.
+    ///
+    /// It is safe to panic within `handler`. Panics will abort the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nscolorpanel/settarget(_:)).
+    #[doc(alias = "setTarget:")]
+    #[doc(alias = "setAction:")]
+    pub fn set_change_handler<F>(&self, mut handler: F) -> NSColorPanelChangeHandler
+    where
+        F: FnMut(&NSColor) + Send + 'static,
+    {
+        let target = ActionTarget::new(move |sender| {
+            // The sender is the panel.
+            let panel = unsafe { &*(sender as *const NSObject as *const NSColorPanel) };
+            handler(&panel.color());
+        });
+
+        unsafe {
+            _msg_send_any![self, setTarget: target.object() => ()];
+            _msg_send_any![self, setAction: ActionTarget::action() => ()];
+        }
+        CURRENT_TARGET.store(
+            target.object() as *const NSObject as *mut NSObject,
+            Ordering::Release,
+        );
+
+        NSColorPanelChangeHandler {
+            panel: Arc::retain(self),
+            target,
+        }
+    }
+}
+
+/// A change handler set by [`NSColorPanel::set_change_handler`], which is
+/// removed when dropped.
+#[must_use = "The handler is immediately removed if unused"]
+pub struct NSColorPanelChangeHandler {
+    panel: Arc<NSColorPanel>,
+    target: ActionTarget,
+}
+
+impl fmt::Debug for NSColorPanelChangeHandler {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("NSColorPanelChangeHandler").finish()
+    }
+}
+
+impl Drop for NSColorPanelChangeHandler {
+    fn drop(&mut self) {
+        let target = self.target.object() as *const NSObject as *mut NSObject;
+
+        // Only detach if the handler was not replaced. The panel does not
+        // retain its target, so it must not outlive `self`.
+        let detach = CURRENT_TARGET
+            .compare_exchange(target, ptr::null_mut(), Ordering::AcqRel, Ordering::Acquire)
+            .is_ok();
+        if detach {
+            unsafe { _msg_send_any![self.panel, setTarget: ptr::null::<NSObject>() => ()] }
+        }
+    }
+}
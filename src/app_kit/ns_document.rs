@@ -0,0 +1,512 @@
+use super::NSWindowController;
+use crate::core::Arc;
+use crate::foundation::{NSData, NSError, NSString, NSURL};
+use crate::objc::{
+    Block, Class, ClassBuilder, ClassType, NSInteger, NSObject, ObjectType, RcBlock, Sel, BOOL,
+};
+use std::{
+    cell::Cell,
+    ffi::{c_void, CStr},
+    fmt,
+    panic::{self, AssertUnwindSafe},
+    process, ptr,
+    sync::{
+        atomic::{AtomicPtr, Ordering},
+        Mutex, Once,
+    },
+};
+
+objc_subclass! {
+    /// A document that can be read from and written to files, with support
+    /// for autosaving, versions, and undo.
+    ///
+    /// Document types are declared as subclasses, which are created with
+    /// [`NSDocumentClassBuilder`].
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsdocument).
+    pub class NSDocument: NSObject<'static>;
+}
+
+impl fmt::Debug for NSDocument {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("NSDocument")
+            .field("file_url", &self.file_url())
+            .field("file_type", &self.file_type())
+            .field("is_document_edited", &self.is_document_edited())
+            .finish()
+    }
+}
+
+impl NSDocument {
+    /// Returns the location of the document on disk, or [`None`] if it has
+    /// never been saved.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsdocument/fileurl).
+    #[inline]
+    #[doc(alias = "fileURL")]
+    pub fn file_url(&self) -> Option<Arc<NSURL>> {
+        unsafe {
+            _msg_send_any![self, fileURL => *const NSURL]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Returns the type name of the document, as declared in the app's
+    /// `Info.plist`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsdocument/filetype).
+    #[inline]
+    #[doc(alias = "fileType")]
+    pub fn file_type(&self) -> Option<Arc<NSString<'static>>> {
+        unsafe {
+            _msg_send_any![self, fileType => *const NSString<'static>]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Returns the name of the document shown in window titles.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsdocument/displayname).
+    #[inline]
+    #[doc(alias = "displayName")]
+    pub fn display_name(&self) -> Arc<NSString<'static>> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, displayName => *const NSString<'static>]) }
+    }
+
+    /// Returns `true` if the document has changes that have not been saved.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsdocument/isdocumentedited).
+    #[inline]
+    #[doc(alias = "isDocumentEdited")]
+    pub fn is_document_edited(&self) -> bool {
+        unsafe { _msg_send_any![self, isDocumentEdited => BOOL] }.into()
+    }
+
+    /// Returns `true` if the document has changes that have not been
+    /// autosaved.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsdocument/hasunautosavedchanges).
+    #[inline]
+    #[doc(alias = "hasUnautosavedChanges")]
+    pub fn has_unautosaved_changes(&self) -> bool {
+        unsafe { _msg_send_any![self, hasUnautosavedChanges => BOOL] }.into()
+    }
+
+    /// Records that the document was edited or saved.
+    ///
+    /// Marking the document as [done](NSDocumentChangeType::DONE) schedules
+    /// an autosave and marks its window as edited.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsdocument/updatechangecount(_:)).
+    #[inline]
+    #[doc(alias = "updateChangeCount:")]
+    pub fn update_change_count(&self, change: NSDocumentChangeType) {
+        unsafe { _msg_send_any![self, updateChangeCount: change => ()] }
+    }
+
+    /// Saves the document, asking the user for a location if it has never
+    /// been saved.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsdocument/savedocument(_:)).
+    #[inline]
+    #[doc(alias = "saveDocument:")]
+    pub fn save(&self) {
+        unsafe { _msg_send_any![self, saveDocument: ptr::null::<NSObject>() => ()] }
+    }
+
+    /// Autosaves the document, calling `completion` on the main thread when
+    /// done.
+    ///
+    /// If `implicitly_cancellable` is `true`, the autosave may be cancelled
+    /// if the user makes further edits, in which case `completion` receives
+    /// a cancellation error.
+    ///
+    /// It is safe to panic within `completion`. Panics will abort the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsdocument/autosave(withimplicitcancellability:completionhandler:)).
+    #[inline]
+    #[doc(alias = "autosaveWithImplicitCancellability:completionHandler:")]
+    pub fn autosave<F>(&self, implicitly_cancellable: bool, completion: F)
+    where
+        F: FnOnce(Result<(), Arc<NSError<'static>>>) + Send + 'static,
+    {
+        let completion = Cell::new(Some(completion));
+        let block = RcBlock::new(move |error: *const NSError<'static>| {
+            if let Some(completion) = completion.take() {
+                completion(match unsafe { error.as_ref() } {
+                    Some(error) => Err(Arc::retain(error)),
+                    None => Ok(()),
+                });
+            }
+        });
+        let block: &Block<_, ()> = &block;
+
+        unsafe {
+            _msg_send_any![
+                self,
+                autosaveWithImplicitCancellability: BOOL::from(implicitly_cancellable)
+                completionHandler: block
+                => ()
+            ]
+        }
+    }
+
+    /// Adds a controller of one of the document's windows, which the
+    /// document keeps alive until it is closed.
+    ///
+    /// This is typically called from
+    /// [`make_window_controllers`](NSDocumentClassBuilder::make_window_controllers).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsdocument/addwindowcontroller(_:)).
+    #[inline]
+    #[doc(alias = "addWindowController:")]
+    pub fn add_window_controller(&self, controller: &NSWindowController) {
+        unsafe { _msg_send_any![self, addWindowController: controller => ()] }
+    }
+
+    /// Closes the document and its windows without asking to save changes.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsdocument/close()).
+    #[inline]
+    pub fn close(&self) {
+        unsafe { _msg_send_any![self, close => ()] }
+    }
+
+    /// Returns `true` if documents of this class are saved in place
+    /// periodically and when closed, rather than asking the user to save.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsdocument/autosavesinplace).
+    #[inline]
+    #[doc(alias = "autosavesInPlace")]
+    pub fn autosaves_in_place(&self) -> bool {
+        unsafe { _msg_send_any![self.class(), autosavesInPlace => BOOL] }.into()
+    }
+}
+
+/// A kind of change recorded with [`NSDocument::update_change_count`].
+///
+/// See [documentation](https://developer.apple.com/documentation/appkit/nsdocument/changetype).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NSDocumentChangeType(pub NSInteger);
+
+impl NSDocumentChangeType {
+    /// A change was made.
+    #[doc(alias = "NSChangeDone")]
+    pub const DONE: Self = Self(0);
+
+    /// A change was undone.
+    #[doc(alias = "NSChangeUndone")]
+    pub const UNDONE: Self = Self(1);
+
+    /// A change was redone.
+    #[doc(alias = "NSChangeRedone")]
+    pub const REDONE: Self = Self(5);
+
+    /// The document was saved, so it has no unsaved changes.
+    #[doc(alias = "NSChangeCleared")]
+    pub const CLEARED: Self = Self(2);
+
+    /// The document was reverted to its saved state.
+    #[doc(alias = "NSChangeReadOtherContents")]
+    pub const READ_OTHER_CONTENTS: Self = Self(3);
+
+    /// The document was autosaved.
+    #[doc(alias = "NSChangeAutosaved")]
+    pub const AUTOSAVED: Self = Self(4);
+
+    /// The document was changed in a way that cannot be autosaved, such as
+    /// by discarding changes.
+    #[doc(alias = "NSChangeDiscardable")]
+    pub const DISCARDABLE: Self = Self(256);
+}
+
+type ReadFromData =
+    dyn Fn(&NSDocument, &NSData, &NSString) -> Result<(), Arc<NSError<'static>>> + Send + Sync;
+
+type DataOfType =
+    dyn Fn(&NSDocument, &NSString) -> Result<Arc<NSData>, Arc<NSError<'static>>> + Send + Sync;
+
+type MakeWindowControllers = dyn Fn(&NSDocument) + Send + Sync;
+
+/// The closures of a class created by [`NSDocumentClassBuilder`].
+#[derive(Default)]
+struct DocumentHooks {
+    read_from_data: Option<Box<ReadFromData>>,
+    data_of_type: Option<Box<DataOfType>>,
+    make_window_controllers: Option<Box<MakeWindowControllers>>,
+    autosaves_in_place: bool,
+}
+
+/// Declares a subclass of [`NSDocument`] whose reading and writing is
+/// implemented by Rust closures.
+///
+/// The class name must match the `NSDocumentClass` of a document type in the
+/// app's `Info.plist`, so that
+/// [`NSDocumentController`](super::NSDocumentController) creates instances
+/// of it when opening files of that type. The class must be registered
+/// before the app finishes launching.
+///
+/// It is safe to panic within the closures. Panics will abort the process.
+///
+/// # Examples
+///
+/// ```no_run
+/// use fruity::app_kit::NSDocumentClassBuilder;
+/// use fruity::foundation::NSData;
+/// use std::ffi::CStr;
+///
+/// let name = CStr::from_bytes_with_nul(b"TextDocument\0").unwrap();
+///
+/// NSDocumentClassBuilder::new(name)
+///     .expect("class already exists")
+///     .read_from_data(|document, data, file_type| {
+///         // Parse `data` into the document's model.
+///         Ok(())
+///     })
+///     .data_of_type(|document, file_type| {
+///         // Serialize the document's model.
+///         Ok(NSData::from_bytes(b"hello"))
+///     })
+///     .autosaves_in_place(true)
+///     .register();
+/// ```
+pub struct NSDocumentClassBuilder {
+    builder: ClassBuilder,
+    hooks: DocumentHooks,
+}
+
+impl fmt::Debug for NSDocumentClassBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("NSDocumentClassBuilder")
+            .field("builder", &self.builder)
+            .field("autosaves_in_place", &self.hooks.autosaves_in_place)
+            .finish()
+    }
+}
+
+impl NSDocumentClassBuilder {
+    /// Starts declaring a subclass of [`NSDocument`] named `name`, or
+    /// returns [`None`] if a class with that name already exists.
+    #[inline]
+    pub fn new(name: &CStr) -> Option<Self> {
+        Some(Self {
+            builder: ClassBuilder::new(name, <NSDocument as ClassType>::class())?,
+            hooks: DocumentHooks::default(),
+        })
+    }
+
+    /// Sets the closure that loads the document from the contents of a file
+    /// of the given type.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsdocument/read(from:oftype:)-3fmoz).
+    #[inline]
+    #[doc(alias = "readFromData:ofType:error:")]
+    pub fn read_from_data<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&NSDocument, &NSData, &NSString) -> Result<(), Arc<NSError<'static>>>,
+        F: Send + Sync + 'static,
+    {
+        self.hooks.read_from_data = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the closure that returns the contents of the document as a file
+    /// of the given type.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsdocument/data(oftype:)).
+    #[inline]
+    #[doc(alias = "dataOfType:error:")]
+    pub fn data_of_type<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&NSDocument, &NSString) -> Result<Arc<NSData>, Arc<NSError<'static>>>,
+        F: Send + Sync + 'static,
+    {
+        self.hooks.data_of_type = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the closure that creates the windows of the document when it is
+    /// opened.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsdocument/makewindowcontrollers()).
+    #[inline]
+    #[doc(alias = "makeWindowControllers")]
+    pub fn make_window_controllers<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&NSDocument) + Send + Sync + 'static,
+    {
+        self.hooks.make_window_controllers = Some(Box::new(f));
+        self
+    }
+
+    /// Sets whether documents are saved in place periodically and when
+    /// closed, rather than asking the user to save. This is `false` by
+    /// default.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsdocument/autosavesinplace).
+    #[inline]
+    #[doc(alias = "autosavesInPlace")]
+    pub fn autosaves_in_place(mut self, value: bool) -> Self {
+        self.hooks.autosaves_in_place = value;
+        self
+    }
+
+    /// Registers the class with the Objective-C runtime.
+    pub fn register(self) -> &'static Class {
+        let Self { mut builder, hooks } = self;
+
+        unsafe {
+            if hooks.read_from_data.is_some() {
+                builder.add_method(
+                    selector!(readFromData:ofType:error:),
+                    read_from_data as ReadFromDataImp,
+                );
+            }
+            if hooks.data_of_type.is_some() {
+                builder.add_method(selector!(dataOfType:error:), data_of_type as DataOfTypeImp);
+            }
+            if hooks.make_window_controllers.is_some() {
+                builder.add_method(
+                    selector!(makeWindowControllers),
+                    make_window_controllers as MakeWindowControllersImp,
+                );
+            }
+            builder.add_class_method(
+                selector!(autosavesInPlace),
+                autosaves_in_place as AutosavesInPlaceImp,
+            );
+        }
+
+        let class = builder.register();
+
+        // Classes are never unregistered, so neither are their hooks.
+        let hooks: &'static DocumentHooks = Box::leak(Box::new(hooks));
+        let mut registry = match registry().lock() {
+            Ok(registry) => registry,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        registry.push((class as *const Class as usize, hooks));
+
+        class
+    }
+}
+
+/// Returns the hooks of each class registered by [`NSDocumentClassBuilder`].
+fn registry() -> &'static Mutex<Vec<(usize, &'static DocumentHooks)>> {
+    static REGISTRY: AtomicPtr<Mutex<Vec<(usize, &'static DocumentHooks)>>> =
+        AtomicPtr::new(ptr::null_mut());
+    static INIT: Once = Once::new();
+
+    INIT.call_once(|| {
+        let registry = Box::into_raw(Box::new(Mutex::new(Vec::new())));
+        REGISTRY.store(registry, Ordering::Release);
+    });
+    unsafe { &*REGISTRY.load(Ordering::Acquire) }
+}
+
+/// Returns the hooks of `class` or its nearest registered superclass.
+fn hooks_for(class: &Class) -> &'static DocumentHooks {
+    let registry = match registry().lock() {
+        Ok(registry) => registry,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+    std::iter::once(class)
+        .chain(class.superclass_iter())
+        .find_map(|class| {
+            let class = class as *const Class as usize;
+            registry
+                .iter()
+                .find(|(registered, _)| *registered == class)
+                .map(|(_, hooks)| *hooks)
+        })
+        .expect("document class was not registered by NSDocumentClassBuilder")
+}
+
+/// Calls `f`, aborting if it panics, since unwinding into Objective-C is
+/// undefined behavior.
+#[inline]
+fn abort_on_panic<T>(f: impl FnOnce() -> T) -> T {
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(value) => value,
+        Err(_) => process::abort(),
+    }
+}
+
+/// Stores `error` in the caller's `NSError **` out-parameter, if provided.
+unsafe fn set_error_out(out: *mut c_void, error: Arc<NSError<'static>>) {
+    extern "C" {
+        fn objc_autorelease(obj: *mut c_void) -> *mut c_void;
+    }
+
+    let out = out as *mut *const NSError<'static>;
+    if !out.is_null() {
+        *out = objc_autorelease(Arc::into_raw(error) as *mut c_void) as *const _;
+    }
+}
+
+type ReadFromDataImp =
+    extern "C" fn(&NSDocument, Sel, *const NSData, *const NSString<'static>, *mut c_void) -> BOOL;
+
+extern "C" fn read_from_data(
+    this: &NSDocument,
+    _: Sel,
+    data: *const NSData,
+    file_type: *const NSString<'static>,
+    error_out: *mut c_void,
+) -> BOOL {
+    let hooks = hooks_for(this.class());
+    let read = hooks.read_from_data.as_ref().unwrap();
+
+    match abort_on_panic(|| unsafe { read(this, &*data, &*file_type) }) {
+        Ok(()) => BOOL::YES,
+        Err(error) => {
+            unsafe { set_error_out(error_out, error) };
+            BOOL::NO
+        }
+    }
+}
+
+type DataOfTypeImp =
+    extern "C" fn(&NSDocument, Sel, *const NSString<'static>, *mut c_void) -> *const NSData;
+
+extern "C" fn data_of_type(
+    this: &NSDocument,
+    _: Sel,
+    file_type: *const NSString<'static>,
+    error_out: *mut c_void,
+) -> *const NSData {
+    extern "C" {
+        fn objc_autoreleaseReturnValue(obj: *mut c_void) -> *mut c_void;
+    }
+
+    let hooks = hooks_for(this.class());
+    let write = hooks.data_of_type.as_ref().unwrap();
+
+    match abort_on_panic(|| unsafe { write(this, &*file_type) }) {
+        Ok(data) => unsafe {
+            objc_autoreleaseReturnValue(Arc::into_raw(data) as *mut c_void) as *const NSData
+        },
+        Err(error) => {
+            unsafe { set_error_out(error_out, error) };
+            ptr::null()
+        }
+    }
+}
+
+type MakeWindowControllersImp = extern "C" fn(&NSDocument, Sel);
+
+extern "C" fn make_window_controllers(this: &NSDocument, _: Sel) {
+    let hooks = hooks_for(this.class());
+    let make = hooks.make_window_controllers.as_ref().unwrap();
+    abort_on_panic(|| make(this));
+}
+
+type AutosavesInPlaceImp = extern "C" fn(&Class, Sel) -> BOOL;
+
+extern "C" fn autosaves_in_place(class: &Class, _: Sel) -> BOOL {
+    hooks_for(class).autosaves_in_place.into()
+}
@@ -0,0 +1,181 @@
+use super::NSDocument;
+use crate::core::Arc;
+use crate::foundation::{NSArray, NSError, NSString, NSTimeInterval, NSURL};
+use crate::objc::{Block, ClassType, NSObject, RcBlock, BOOL};
+use std::{cell::Cell, ptr};
+
+objc_subclass! {
+    /// Manages the open documents of a document-based app, including
+    /// opening files and the Open Recent menu.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsdocumentcontroller).
+    pub class NSDocumentController: NSObject<'static>;
+}
+
+impl NSDocumentController {
+    /// Returns the document controller of the app, creating it on first use.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsdocumentcontroller/shared).
+    #[inline]
+    #[doc(alias = "sharedDocumentController")]
+    pub fn shared() -> &'static NSDocumentController {
+        unsafe { _msg_send_any![Self::class(), sharedDocumentController] }
+    }
+
+    /// Returns the open documents.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsdocumentcontroller/documents).
+    #[inline]
+    pub fn documents(&self) -> Arc<NSArray<NSDocument>> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, documents => *const NSArray<NSDocument>]) }
+    }
+
+    /// Returns the document of the main window, or [`None`] if it has no
+    /// document.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsdocumentcontroller/currentdocument).
+    #[inline]
+    #[doc(alias = "currentDocument")]
+    pub fn current_document(&self) -> Option<Arc<NSDocument>> {
+        unsafe {
+            _msg_send_any![self, currentDocument => *const NSDocument]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Returns the open document for the file at `url`, if any.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsdocumentcontroller/document(for:)).
+    #[inline]
+    #[doc(alias = "documentForURL:")]
+    pub fn document_for_url(&self, url: &NSURL) -> Option<Arc<NSDocument>> {
+        unsafe {
+            _msg_send_any![self, documentForURL: url => *const NSDocument]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Returns the type name used for new documents, which is the first
+    /// editable type in the app's `Info.plist`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsdocumentcontroller/defaulttype).
+    #[inline]
+    #[doc(alias = "defaultType")]
+    pub fn default_type(&self) -> Option<Arc<NSString<'static>>> {
+        unsafe {
+            _msg_send_any![self, defaultType => *const NSString<'static>]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Opens the document at `url`, calling `completion` on the main thread
+    /// with the document and whether it was already open.
+    ///
+    /// If `display` is `true`, the document's windows are shown.
+    ///
+    /// It is safe to panic within `completion`. Panics will abort the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsdocumentcontroller/opendocument(withcontentsof:display:completionhandler:)).
+    #[inline]
+    #[doc(alias = "openDocumentWithContentsOfURL:display:completionHandler:")]
+    pub fn open_document<F>(&self, url: &NSURL, display: bool, completion: F)
+    where
+        F: FnOnce(Result<(Arc<NSDocument>, bool), Arc<NSError<'static>>>) + Send + 'static,
+    {
+        let completion = Cell::new(Some(completion));
+        let block = RcBlock::new(
+            move |document: *const NSDocument,
+                  already_open: BOOL,
+                  error: *const NSError<'static>| {
+                if let Some(completion) = completion.take() {
+                    completion(unsafe {
+                        match document.as_ref() {
+                            Some(document) => Ok((Arc::retain(document), already_open.into())),
+                            None => Err(Arc::retain_raw(error)),
+                        }
+                    });
+                }
+            },
+        );
+        let block: &Block<_, ()> = &block;
+
+        unsafe {
+            _msg_send_any![
+                self,
+                openDocumentWithContentsOfURL: url
+                display: BOOL::from(display)
+                completionHandler: block
+                => ()
+            ]
+        }
+    }
+
+    /// Returns the files in the Open Recent menu, most recent first.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsdocumentcontroller/recentdocumenturls).
+    #[inline]
+    #[doc(alias = "recentDocumentURLs")]
+    pub fn recent_document_urls(&self) -> Arc<NSArray<NSURL>> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![self, recentDocumentURLs => *const NSArray<NSURL>])
+        }
+    }
+
+    /// Adds `url` to the Open Recent menu.
+    ///
+    /// Documents are added automatically when opened or saved, so this is
+    /// only needed for files opened by other means.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsdocumentcontroller/notenewrecentdocumenturl(_:)).
+    #[inline]
+    #[doc(alias = "noteNewRecentDocumentURL:")]
+    pub fn note_new_recent_document_url(&self, url: &NSURL) {
+        unsafe { _msg_send_any![self, noteNewRecentDocumentURL: url => ()] }
+    }
+
+    /// Removes all files from the Open Recent menu.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsdocumentcontroller/clearrecentdocuments(_:)).
+    #[inline]
+    #[doc(alias = "clearRecentDocuments:")]
+    pub fn clear_recent_documents(&self) {
+        unsafe { _msg_send_any![self, clearRecentDocuments: ptr::null::<NSObject>() => ()] }
+    }
+
+    /// Returns the maximum number of files in the Open Recent menu, which is
+    /// set by the user in System Preferences.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsdocumentcontroller/maximumrecentdocumentcount).
+    #[inline]
+    #[doc(alias = "maximumRecentDocumentCount")]
+    pub fn maximum_recent_document_count(&self) -> usize {
+        unsafe { _msg_send_any![self, maximumRecentDocumentCount] }
+    }
+
+    /// Returns how long after an edit documents are autosaved, or 0 if
+    /// periodic autosaving is disabled.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsdocumentcontroller/autosavingdelay).
+    #[inline]
+    #[doc(alias = "autosavingDelay")]
+    pub fn autosaving_delay(&self) -> NSTimeInterval {
+        unsafe { _msg_send_any![self, autosavingDelay] }
+    }
+
+    /// Sets how long after an edit documents are autosaved, or disables
+    /// periodic autosaving if 0.
+    ///
+    /// This does not apply to documents that
+    /// [autosave in place](NSDocument::autosaves_in_place), whose autosaving
+    /// is scheduled by the system.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsdocumentcontroller/autosavingdelay).
+    #[inline]
+    #[doc(alias = "setAutosavingDelay:")]
+    pub fn set_autosaving_delay(&self, delay: NSTimeInterval) {
+        unsafe { _msg_send_any![self, setAutosavingDelay: delay => ()] }
+    }
+}
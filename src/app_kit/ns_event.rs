@@ -0,0 +1,562 @@
+use super::NSView;
+use crate::core::Arc;
+use crate::core_graphics::{CGEventFlags, CGFloat, CGKeyCode};
+use crate::foundation::{NSArray, NSPoint, NSSize, NSString, NSTimeInterval};
+use crate::objc::{NSInteger, NSObject, NSUInteger, BOOL};
+use std::{fmt, ops};
+
+objc_subclass! {
+    /// An input event, such as a mouse click or a trackpad gesture.
+    ///
+    /// Views receive gesture and touch events through the closures of
+    /// [`NSViewClassBuilder`](super::NSViewClassBuilder).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsevent).
+    pub class NSEvent: NSObject<'static>;
+}
+
+impl fmt::Debug for NSEvent {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("NSEvent")
+            .field("type", &self.event_type())
+            .field("phase", &self.phase())
+            .finish()
+    }
+}
+
+impl NSEvent {
+    /// Returns the kind of the event.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsevent/type).
+    #[inline]
+    #[doc(alias = "type")]
+    pub fn event_type(&self) -> NSEventType {
+        unsafe { _msg_send_any![self, type] }
+    }
+
+    /// Returns the modifier keys that were held during the event.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsevent/modifierflags).
+    #[inline]
+    #[doc(alias = "modifierFlags")]
+    pub fn modifier_flags(&self) -> NSEventModifierFlags {
+        unsafe { _msg_send_any![self, modifierFlags] }
+    }
+
+    /// Returns the time of the event in seconds since the system started.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsevent/timestamp).
+    #[inline]
+    pub fn timestamp(&self) -> NSTimeInterval {
+        unsafe { _msg_send_any![self, timestamp] }
+    }
+
+    /// Returns the location of the pointer in the coordinate system of the
+    /// window.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsevent/locationinwindow).
+    #[inline]
+    #[doc(alias = "locationInWindow")]
+    pub fn location_in_window(&self) -> NSPoint {
+        unsafe { _msg_send_any![self, locationInWindow] }
+    }
+
+    /// Returns the stage of a continuous gesture, such as a magnification,
+    /// or [`NSEventPhase::NONE`] for other events.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsevent/phase).
+    #[inline]
+    pub fn phase(&self) -> NSEventPhase {
+        unsafe { _msg_send_any![self, phase] }
+    }
+
+    /// Returns the change in scale of a magnification gesture, to be added
+    /// to the current scale.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsevent/magnification).
+    #[inline]
+    pub fn magnification(&self) -> CGFloat {
+        unsafe { _msg_send_any![self, magnification] }
+    }
+
+    /// Returns the change in angle of a rotation gesture, in degrees
+    /// counterclockwise.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsevent/rotation).
+    #[inline]
+    pub fn rotation(&self) -> f32 {
+        unsafe { _msg_send_any![self, rotation] }
+    }
+
+    /// Returns the horizontal direction of a swipe, which is `1.0` for left
+    /// and `-1.0` for right, or the horizontal change of a scroll.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsevent/deltax).
+    #[inline]
+    #[doc(alias = "deltaX")]
+    pub fn delta_x(&self) -> CGFloat {
+        unsafe { _msg_send_any![self, deltaX] }
+    }
+
+    /// Returns the vertical direction of a swipe, which is `1.0` for up and
+    /// `-1.0` for down, or the vertical change of a scroll.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsevent/deltay).
+    #[inline]
+    #[doc(alias = "deltaY")]
+    pub fn delta_y(&self) -> CGFloat {
+        unsafe { _msg_send_any![self, deltaY] }
+    }
+
+    /// Returns the pressure of a click, from 0 to 1 within the current
+    /// [stage](Self::stage).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsevent/pressure).
+    #[inline]
+    pub fn pressure(&self) -> f32 {
+        unsafe { _msg_send_any![self, pressure] }
+    }
+
+    /// Returns the stage of a Force Touch click: 0 for no click, 1 for a
+    /// normal click, and 2 for a force click.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsevent/stage).
+    #[inline]
+    pub fn stage(&self) -> NSInteger {
+        unsafe { _msg_send_any![self, stage] }
+    }
+
+    /// Returns the progress toward the next or previous stage, from -1 to 1,
+    /// for animating feedback before the stage changes.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsevent/stagetransition).
+    #[inline]
+    #[doc(alias = "stageTransition")]
+    pub fn stage_transition(&self) -> CGFloat {
+        unsafe { _msg_send_any![self, stageTransition] }
+    }
+
+    /// Returns the touches on the trackpad that are in `phase`, with
+    /// positions relative to `view`, or to the window if it is [`None`].
+    ///
+    /// Views only receive touches that they
+    /// [allow](NSView::set_allowed_touch_types).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsevent/touches(matching:in:)).
+    #[inline]
+    #[doc(alias = "touchesMatchingPhase:inView:")]
+    pub fn touches_matching_phase(
+        &self,
+        phase: NSTouchPhase,
+        view: Option<&NSView>,
+    ) -> Arc<NSArray<NSTouch>> {
+        unsafe {
+            let set: &NSObject = _msg_send_any![self, touchesMatchingPhase: phase inView: view];
+            Arc::retain_raw(_msg_send_any![set, allObjects => *const NSArray<NSTouch>])
+        }
+    }
+}
+
+/// Keyboard events.
+impl NSEvent {
+    /// Returns the virtual key code of the key that was pressed or released,
+    /// which does not depend on the keyboard layout.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsevent/keycode).
+    #[inline]
+    #[doc(alias = "keyCode")]
+    pub fn key_code(&self) -> CGKeyCode {
+        CGKeyCode(unsafe { _msg_send_any![self, keyCode => u16] })
+    }
+
+    /// Returns the characters typed by the key, with modifiers applied, using
+    /// the current keyboard layout.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsevent/characters).
+    #[inline]
+    pub fn characters(&self) -> Option<Arc<NSString<'static>>> {
+        unsafe {
+            _msg_send_any![self, characters => *const NSString<'static>]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Returns the characters typed by the key, with only Shift applied, using
+    /// the current keyboard layout.
+    ///
+    /// This is what keyboard shortcuts are matched against, such as `"z"` for
+    /// Command-Z.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsevent/charactersignoringmodifiers).
+    #[inline]
+    #[doc(alias = "charactersIgnoringModifiers")]
+    pub fn characters_ignoring_modifiers(&self) -> Option<Arc<NSString<'static>>> {
+        unsafe {
+            _msg_send_any![self, charactersIgnoringModifiers => *const NSString<'static>]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Returns `true` if the event was generated by holding the key down.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsevent/isarepeat).
+    #[inline]
+    #[doc(alias = "isARepeat")]
+    pub fn is_repeat(&self) -> bool {
+        unsafe { _msg_send_any![self, isARepeat => BOOL] }.into()
+    }
+}
+
+/// The kind of an [`NSEvent`].
+///
+/// See [documentation](https://developer.apple.com/documentation/appkit/nsevent/eventtype).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NSEventType(pub NSUInteger);
+
+impl NSEventType {
+    /// The left mouse button was pressed.
+    #[doc(alias = "NSEventTypeLeftMouseDown")]
+    pub const LEFT_MOUSE_DOWN: Self = Self(1);
+
+    /// The left mouse button was released.
+    #[doc(alias = "NSEventTypeLeftMouseUp")]
+    pub const LEFT_MOUSE_UP: Self = Self(2);
+
+    /// The right mouse button was pressed.
+    #[doc(alias = "NSEventTypeRightMouseDown")]
+    pub const RIGHT_MOUSE_DOWN: Self = Self(3);
+
+    /// The right mouse button was released.
+    #[doc(alias = "NSEventTypeRightMouseUp")]
+    pub const RIGHT_MOUSE_UP: Self = Self(4);
+
+    /// The mouse moved.
+    #[doc(alias = "NSEventTypeMouseMoved")]
+    pub const MOUSE_MOVED: Self = Self(5);
+
+    /// A key was pressed.
+    #[doc(alias = "NSEventTypeKeyDown")]
+    pub const KEY_DOWN: Self = Self(10);
+
+    /// A key was released.
+    #[doc(alias = "NSEventTypeKeyUp")]
+    pub const KEY_UP: Self = Self(11);
+
+    /// A rotation gesture.
+    #[doc(alias = "NSEventTypeRotate")]
+    pub const ROTATE: Self = Self(18);
+
+    /// The mouse wheel or trackpad scrolled.
+    #[doc(alias = "NSEventTypeScrollWheel")]
+    pub const SCROLL_WHEEL: Self = Self(22);
+
+    /// Touches on the trackpad began, moved, or ended.
+    #[doc(alias = "NSEventTypeGesture")]
+    pub const GESTURE: Self = Self(29);
+
+    /// A magnification gesture.
+    #[doc(alias = "NSEventTypeMagnify")]
+    pub const MAGNIFY: Self = Self(30);
+
+    /// A swipe gesture.
+    #[doc(alias = "NSEventTypeSwipe")]
+    pub const SWIPE: Self = Self(31);
+
+    /// A smart zoom gesture, which is a two-finger double tap.
+    #[doc(alias = "NSEventTypeSmartMagnify")]
+    pub const SMART_MAGNIFY: Self = Self(32);
+
+    /// The pressure of a Force Touch click changed.
+    #[doc(alias = "NSEventTypePressure")]
+    pub const PRESSURE: Self = Self(34);
+}
+
+/// The stage of a continuous gesture in an [`NSEvent`].
+///
+/// See [documentation](https://developer.apple.com/documentation/appkit/nsevent/phase-swift.struct).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NSEventPhase(pub NSUInteger);
+
+impl ops::BitOr for NSEventPhase {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl NSEventPhase {
+    /// The event is not part of a continuous gesture.
+    #[doc(alias = "NSEventPhaseNone")]
+    pub const NONE: Self = Self(0);
+
+    /// The gesture began.
+    #[doc(alias = "NSEventPhaseBegan")]
+    pub const BEGAN: Self = Self(1 << 0);
+
+    /// The gesture is in progress without changing.
+    #[doc(alias = "NSEventPhaseStationary")]
+    pub const STATIONARY: Self = Self(1 << 1);
+
+    /// The gesture changed.
+    #[doc(alias = "NSEventPhaseChanged")]
+    pub const CHANGED: Self = Self(1 << 2);
+
+    /// The gesture ended.
+    #[doc(alias = "NSEventPhaseEnded")]
+    pub const ENDED: Self = Self(1 << 3);
+
+    /// The gesture was cancelled.
+    #[doc(alias = "NSEventPhaseCancelled")]
+    pub const CANCELLED: Self = Self(1 << 4);
+
+    /// The gesture may begin, such as when fingers rest on the trackpad.
+    #[doc(alias = "NSEventPhaseMayBegin")]
+    pub const MAY_BEGIN: Self = Self(1 << 5);
+
+    /// Returns `true` if all flags in `other` are set in `self`.
+    #[inline]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+objc_subclass! {
+    /// A finger on a trackpad, returned by
+    /// [`NSEvent::touches_matching_phase`].
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nstouch).
+    pub class NSTouch: NSObject<'static>;
+}
+
+impl fmt::Debug for NSTouch {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("NSTouch")
+            .field("phase", &self.phase())
+            .field("normalized_position", &self.normalized_position())
+            .finish()
+    }
+}
+
+impl NSTouch {
+    /// Returns the stage of the touch.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nstouch/phase-swift.property).
+    #[inline]
+    pub fn phase(&self) -> NSTouchPhase {
+        unsafe { _msg_send_any![self, phase] }
+    }
+
+    /// Returns an object that is equal for the same finger across events,
+    /// for tracking it as it moves.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nstouch/identity).
+    #[inline]
+    pub fn identity(&self) -> &NSObject<'static> {
+        unsafe { _msg_send_any![self, identity] }
+    }
+
+    /// Returns the position of the touch on the trackpad, from (0, 0) at the
+    /// bottom left to (1, 1) at the top right.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nstouch/normalizedposition).
+    #[inline]
+    #[doc(alias = "normalizedPosition")]
+    pub fn normalized_position(&self) -> NSPoint {
+        unsafe { _msg_send_any![self, normalizedPosition] }
+    }
+
+    /// Returns the size of the trackpad in points.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nstouch/devicesize).
+    #[inline]
+    #[doc(alias = "deviceSize")]
+    pub fn device_size(&self) -> NSSize {
+        unsafe { _msg_send_any![self, deviceSize] }
+    }
+
+    /// Returns `true` if the finger is resting on the trackpad rather than
+    /// taking part in a gesture.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nstouch/isresting).
+    #[inline]
+    #[doc(alias = "isResting")]
+    pub fn is_resting(&self) -> bool {
+        unsafe { _msg_send_any![self, isResting => BOOL] }.into()
+    }
+}
+
+/// The stages of [`NSTouch`]es to match.
+///
+/// See [documentation](https://developer.apple.com/documentation/appkit/nstouch/phase-swift.struct).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NSTouchPhase(pub NSUInteger);
+
+impl ops::BitOr for NSTouchPhase {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl NSTouchPhase {
+    /// The finger touched the trackpad.
+    #[doc(alias = "NSTouchPhaseBegan")]
+    pub const BEGAN: Self = Self(1 << 0);
+
+    /// The finger moved.
+    #[doc(alias = "NSTouchPhaseMoved")]
+    pub const MOVED: Self = Self(1 << 1);
+
+    /// The finger is touching the trackpad without moving.
+    #[doc(alias = "NSTouchPhaseStationary")]
+    pub const STATIONARY: Self = Self(1 << 2);
+
+    /// The finger was lifted.
+    #[doc(alias = "NSTouchPhaseEnded")]
+    pub const ENDED: Self = Self(1 << 3);
+
+    /// The touch was cancelled, such as when the view was removed.
+    #[doc(alias = "NSTouchPhaseCancelled")]
+    pub const CANCELLED: Self = Self(1 << 4);
+
+    /// Fingers that are on the trackpad.
+    #[doc(alias = "NSTouchPhaseTouching")]
+    pub const TOUCHING: Self = Self(Self::BEGAN.0 | Self::MOVED.0 | Self::STATIONARY.0);
+
+    /// All touches.
+    #[doc(alias = "NSTouchPhaseAny")]
+    pub const ANY: Self = Self(NSUInteger::MAX);
+
+    /// Returns `true` if all flags in `other` are set in `self`.
+    #[inline]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+/// The modifier keys held during an [`NSEvent`].
+///
+/// See [documentation](https://developer.apple.com/documentation/appkit/nsevent/modifierflags-swift.struct).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NSEventModifierFlags(pub NSUInteger);
+
+impl ops::BitOr for NSEventModifierFlags {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl ops::BitAnd for NSEventModifierFlags {
+    type Output = Self;
+
+    #[inline]
+    fn bitand(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+}
+
+impl From<CGEventFlags> for NSEventModifierFlags {
+    #[inline]
+    fn from(flags: CGEventFlags) -> Self {
+        Self(flags.0 as NSUInteger) & Self::DEVICE_INDEPENDENT_FLAGS_MASK
+    }
+}
+
+impl From<NSEventModifierFlags> for CGEventFlags {
+    #[inline]
+    fn from(flags: NSEventModifierFlags) -> Self {
+        Self(flags.0 as u64)
+    }
+}
+
+impl NSEventModifierFlags {
+    /// Caps Lock is on.
+    #[doc(alias = "NSEventModifierFlagCapsLock")]
+    pub const CAPS_LOCK: Self = Self(1 << 16);
+
+    /// Shift is held.
+    #[doc(alias = "NSEventModifierFlagShift")]
+    pub const SHIFT: Self = Self(1 << 17);
+
+    /// Control is held.
+    #[doc(alias = "NSEventModifierFlagControl")]
+    pub const CONTROL: Self = Self(1 << 18);
+
+    /// Option (Alt) is held.
+    #[doc(alias = "NSEventModifierFlagOption")]
+    pub const OPTION: Self = Self(1 << 19);
+
+    /// Command is held.
+    #[doc(alias = "NSEventModifierFlagCommand")]
+    pub const COMMAND: Self = Self(1 << 20);
+
+    /// The key is on the numeric keypad, or is an arrow key.
+    #[doc(alias = "NSEventModifierFlagNumericPad")]
+    pub const NUMERIC_PAD: Self = Self(1 << 21);
+
+    /// Help is held.
+    #[doc(alias = "NSEventModifierFlagHelp")]
+    pub const HELP: Self = Self(1 << 22);
+
+    /// Fn is held, or the key is a function or navigation key.
+    #[doc(alias = "NSEventModifierFlagFunction")]
+    pub const FUNCTION: Self = Self(1 << 23);
+
+    /// The flags that do not depend on the keyboard, for masking out
+    /// device-specific bits before comparing modifiers.
+    #[doc(alias = "NSEventModifierFlagDeviceIndependentFlagsMask")]
+    pub const DEVICE_INDEPENDENT_FLAGS_MASK: Self = Self(0xffff_0000);
+
+    /// Returns `true` if all flags in `other` are set in `self`.
+    #[inline]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+/// The kinds of touches that an [`NSView`] receives.
+///
+/// See [documentation](https://developer.apple.com/documentation/appkit/nstouch/typemask).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NSTouchTypeMask(pub NSUInteger);
+
+impl ops::BitOr for NSTouchTypeMask {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl NSTouchTypeMask {
+    /// Touches on a screen, such as the Touch Bar.
+    #[doc(alias = "NSTouchTypeMaskDirect")]
+    pub const DIRECT: Self = Self(1 << 0);
+
+    /// Touches on a trackpad.
+    #[doc(alias = "NSTouchTypeMaskIndirect")]
+    pub const INDIRECT: Self = Self(1 << 1);
+
+    /// Returns `true` if all flags in `other` are set in `self`.
+    #[inline]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
@@ -0,0 +1,161 @@
+use crate::core::Arc;
+use crate::core_graphics::CGFloat;
+use crate::foundation::NSString;
+use crate::objc::{ClassType, NSObject};
+
+objc_subclass! {
+    /// A font face at a specific size, such as one picked with the
+    /// [`NSFontPanel`](super::NSFontPanel).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsfont).
+    pub class NSFont: NSObject<'static>;
+}
+
+impl NSFont {
+    /// Returns the standard interface font at `size` points.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsfont/systemfont(ofsize:)).
+    #[inline]
+    #[doc(alias = "systemFontOfSize:")]
+    pub fn system(size: CGFloat) -> Arc<Self> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![Self::class(), systemFontOfSize: size => *const Self])
+        }
+    }
+
+    /// Returns the standard interface font at `size` points and `weight`,
+    /// whose digits all have the same width so that changing numbers do not
+    /// shift surrounding text.
+    ///
+    /// Weights range from -1.0 to 1.0, where 0.0 is regular and 0.3 is
+    /// semibold.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsfont/monospaceddigitsystemfont(ofsize:weight:)).
+    #[inline]
+    #[doc(alias = "monospacedDigitSystemFontOfSize:weight:")]
+    pub fn monospaced_digit_system(size: CGFloat, weight: CGFloat) -> Arc<Self> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![
+                Self::class(),
+                monospacedDigitSystemFontOfSize: size
+                weight: weight
+                => *const Self
+            ])
+        }
+    }
+
+    /// Returns the font with the PostScript or full name `name` at `size`
+    /// points, or [`None`] if it is not installed.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsfont/init(name:size:)).
+    #[inline]
+    #[doc(alias = "fontWithName:size:")]
+    pub fn with_name(name: &NSString, size: CGFloat) -> Option<Arc<Self>> {
+        unsafe {
+            _msg_send_any![Self::class(), fontWithName: name size: size => *const Self]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Returns the PostScript name of the font, such as `"Helvetica-Bold"`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsfont/fontname).
+    #[inline]
+    #[doc(alias = "fontName")]
+    pub fn font_name(&self) -> Arc<NSString<'static>> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, fontName => *const NSString<'static>]) }
+    }
+
+    /// Returns the localized name of the font for showing to the user, such
+    /// as `"Helvetica Bold"`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsfont/displayname).
+    #[inline]
+    #[doc(alias = "displayName")]
+    pub fn display_name(&self) -> Option<Arc<NSString<'static>>> {
+        unsafe {
+            _msg_send_any![self, displayName => *const NSString<'static>]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Returns the size of the font, in points.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsfont/pointsize).
+    #[inline]
+    #[doc(alias = "pointSize")]
+    pub fn point_size(&self) -> CGFloat {
+        unsafe { _msg_send_any![self, pointSize] }
+    }
+}
+
+/// Line metrics.
+///
+/// Distances are in points, measured from the baseline, and upward for
+/// positive values.
+impl NSFont {
+    /// Returns the distance from the baseline to the top of the tallest
+    /// glyphs.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsfont/ascender).
+    #[inline]
+    pub fn ascender(&self) -> CGFloat {
+        unsafe { _msg_send_any![self, ascender] }
+    }
+
+    /// Returns the distance from the baseline to the bottom of the lowest
+    /// glyphs, which is negative.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsfont/descender).
+    #[inline]
+    pub fn descender(&self) -> CGFloat {
+        unsafe { _msg_send_any![self, descender] }
+    }
+
+    /// Returns the extra space to put between lines.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsfont/leading).
+    #[inline]
+    pub fn leading(&self) -> CGFloat {
+        unsafe { _msg_send_any![self, leading] }
+    }
+
+    /// Returns the height of capital letters, such as "H".
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsfont/capheight).
+    #[inline]
+    #[doc(alias = "capHeight")]
+    pub fn cap_height(&self) -> CGFloat {
+        unsafe { _msg_send_any![self, capHeight] }
+    }
+
+    /// Returns the height of lowercase letters without ascenders, such as
+    /// "x".
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsfont/xheight).
+    #[inline]
+    #[doc(alias = "xHeight")]
+    pub fn x_height(&self) -> CGFloat {
+        unsafe { _msg_send_any![self, xHeight] }
+    }
+
+    /// Returns the height of a line of text in this font, including
+    /// [leading](Self::leading).
+    #[inline]
+    pub fn line_height(&self) -> CGFloat {
+        self.ascender() - self.descender() + self.leading()
+    }
+
+    /// Returns the height above the bottom of a box that is `height` points
+    /// tall at which to place the baseline, so that capital letters are
+    /// centered vertically in it.
+    ///
+    /// This is how text lines up optically with icons of the same height,
+    /// such as in a status item.
+    #[inline]
+    pub fn centered_baseline(&self, height: CGFloat) -> CGFloat {
+        (height - self.cap_height()) / 2.0
+    }
+}
@@ -0,0 +1,165 @@
+use super::{action_target::ActionTarget, NSFont, NSWindow};
+use crate::core::Arc;
+use crate::objc::{ClassType, NSObject, BOOL};
+use std::{fmt, ptr};
+
+objc_subclass! {
+    /// The standard panel for picking a font, shared by the whole app.
+    ///
+    /// The panel reports changes through the [`NSFontManager`], and is shown
+    /// with [`NSFontManager::order_front_font_panel`] or the methods of
+    /// [`NSWindow`].
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsfontpanel).
+    pub class NSFontPanel: NSWindow;
+}
+
+impl NSFontPanel {
+    /// Returns the app's font panel, creating it if needed.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsfontpanel/shared).
+    #[inline]
+    #[doc(alias = "sharedFontPanel")]
+    pub fn shared() -> &'static Self {
+        unsafe { _msg_send_any![Self::class(), sharedFontPanel] }
+    }
+}
+
+objc_subclass! {
+    /// The app's center for font selection, which tracks the selected font
+    /// and reports changes made in the [`NSFontPanel`].
+    ///
+    /// Changes are relative to the selected font, so the new font is
+    /// obtained by [converting](Self::convert_font) the font of the current
+    /// selection:
+    ///
+    /// ```no_run
+    /// use fruity::app_kit::{NSFont, NSFontManager};
+    ///
+    /// let manager = NSFontManager::shared();
+    /// manager.set_selected_font(&NSFont::system(13.0), false);
+    ///
+    /// let _handler = manager.set_change_handler(|manager| {
+    ///     let font = manager.convert_font(&NSFont::system(13.0));
+    ///     println!("picked {}", font.font_name());
+    /// });
+    /// manager.order_front_font_panel();
+    /// ```
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsfontmanager).
+    pub class NSFontManager: NSObject<'static>;
+}
+
+impl NSFontManager {
+    /// Returns the app's font manager.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsfontmanager/shared).
+    #[inline]
+    #[doc(alias = "sharedFontManager")]
+    pub fn shared() -> &'static Self {
+        unsafe { _msg_send_any![Self::class(), sharedFontManager] }
+    }
+
+    /// Returns the font shown as selected in the font panel.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsfontmanager/selectedfont).
+    #[inline]
+    #[doc(alias = "selectedFont")]
+    pub fn selected_font(&self) -> Option<Arc<NSFont>> {
+        unsafe {
+            _msg_send_any![self, selectedFont => *const NSFont]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Sets the font shown as selected in the font panel, such as the font
+    /// of the current text selection, and whether the selection has
+    /// multiple fonts.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsfontmanager/setselectedfont(_:ismultiple:)).
+    #[inline]
+    #[doc(alias = "setSelectedFont:isMultiple:")]
+    pub fn set_selected_font(&self, font: &NSFont, is_multiple: bool) {
+        unsafe {
+            _msg_send_any![self, setSelectedFont: font isMultiple: BOOL::from(is_multiple) => ()]
+        }
+    }
+
+    /// Returns `font` with the change the user just made in the font panel
+    /// applied, such as a new family or size.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsfontmanager/convert(_:)-2ibwx).
+    #[inline]
+    #[doc(alias = "convertFont:")]
+    pub fn convert_font(&self, font: &NSFont) -> Arc<NSFont> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, convertFont: font => *const NSFont]) }
+    }
+
+    /// Shows the font panel in front of other windows.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsfontmanager/orderfrontfontpanel(_:)).
+    #[inline]
+    #[doc(alias = "orderFrontFontPanel:")]
+    pub fn order_front_font_panel(&self) {
+        unsafe { _msg_send_any![self, orderFrontFontPanel: ptr::null::<NSObject>() => ()] }
+    }
+
+    /// Calls `handler` on the main thread each time the user changes the
+    /// font in the font panel, until the returned value is dropped.
+    ///
+    /// This takes the place of the `changeFont:` message normally sent up
+    /// the responder chain. Setting another handler replaces this one.
+    ///
+    /// It is safe to panic within `handler`. Panics will abort the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsfontmanager/target).
+    #[doc(alias = "setTarget:")]
+    #[doc(alias = "setAction:")]
+    pub fn set_change_handler<F>(&self, mut handler: F) -> NSFontManagerChangeHandler
+    where
+        F: FnMut(&NSFontManager) + Send + 'static,
+    {
+        let target = ActionTarget::new(move |sender| {
+            // The sender is the font manager.
+            handler(unsafe { &*(sender as *const NSObject as *const NSFontManager) });
+        });
+
+        unsafe {
+            _msg_send_any![self, setTarget: target.object() => ()];
+            _msg_send_any![self, setAction: ActionTarget::action() => ()];
+        }
+
+        NSFontManagerChangeHandler {
+            manager: Arc::retain(self),
+            target,
+        }
+    }
+}
+
+/// A change handler set by [`NSFontManager::set_change_handler`], which is
+/// removed when dropped.
+#[must_use = "The handler is immediately removed if unused"]
+pub struct NSFontManagerChangeHandler {
+    manager: Arc<NSFontManager>,
+    target: ActionTarget,
+}
+
+impl fmt::Debug for NSFontManagerChangeHandler {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("NSFontManagerChangeHandler").finish()
+    }
+}
+
+impl Drop for NSFontManagerChangeHandler {
+    fn drop(&mut self) {
+        unsafe {
+            // Only detach if the handler was not replaced.
+            let current = _msg_send_any![self.manager, target => *const NSObject];
+            if self.target.is(current) {
+                _msg_send_any![self.manager, setTarget: ptr::null::<NSObject>() => ()];
+                _msg_send_any![self.manager, setAction: selector!(changeFont:) => ()];
+            }
+        }
+    }
+}
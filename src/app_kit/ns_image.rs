@@ -0,0 +1,178 @@
+use super::{NSBitmapImageRep, NSPasteboard};
+use crate::core::Arc;
+use crate::foundation::{NSData, NSSize, NSString, NSURL};
+use crate::objc::{ClassType, NSObject, Sel};
+use std::ffi::c_void;
+
+#[cfg(feature = "core_foundation")]
+use crate::core_graphics::{CGImage, PixelSource, Pixels, PixelsError};
+#[cfg(feature = "core_foundation")]
+use std::ptr;
+
+objc_subclass! {
+    /// A high-level image that may have several representations, such as
+    /// bitmaps at different resolutions or PDF data.
+    ///
+    /// Images can be converted to RGBA8 or BGRA8 pixels with
+    /// [`Pixels`](crate::core_graphics::Pixels), which renders the
+    /// representation that best matches the requested size.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsimage).
+    pub class NSImage: NSObject<'static>;
+}
+
+impl NSImage {
+    /// Creates an empty image of `size` points, to which representations can
+    /// be added with [`add_representation`](Self::add_representation).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsimage/init(size:)).
+    #[inline]
+    #[doc(alias = "initWithSize:")]
+    pub fn new(size: NSSize) -> Arc<Self> {
+        unsafe {
+            let obj: Arc<Self> = Self::class().alloc();
+
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(obj: Arc<NSImage>, sel: Sel, size: NSSize) -> Arc<NSImage>;
+            }
+
+            objc_msgSend(obj, selector!(initWithSize:), size)
+        }
+    }
+
+    /// Loads the image at `path`, or returns [`None`] if it could not be
+    /// read.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsimage/init(contentsoffile:)).
+    #[inline]
+    #[doc(alias = "initWithContentsOfFile:")]
+    pub fn from_file(path: &NSString) -> Option<Arc<Self>> {
+        unsafe { Self::init_with(selector!(initWithContentsOfFile:), path) }
+    }
+
+    /// Loads the image at `url`, or returns [`None`] if it could not be read.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsimage/init(contentsof:)).
+    #[inline]
+    #[doc(alias = "initWithContentsOfURL:")]
+    pub fn from_url(url: &NSURL) -> Option<Arc<Self>> {
+        unsafe { Self::init_with(selector!(initWithContentsOfURL:), url) }
+    }
+
+    /// Decodes an image from `data` in any supported file format, or returns
+    /// [`None`] if it is not an image.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsimage/init(data:)).
+    #[inline]
+    #[doc(alias = "initWithData:")]
+    pub fn from_data(data: &NSData) -> Option<Arc<Self>> {
+        unsafe { Self::init_with(selector!(initWithData:), data) }
+    }
+
+    /// Reads an image from `pasteboard`, or returns [`None`] if it does not
+    /// have one.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsimage/init(pasteboard:)).
+    #[inline]
+    #[doc(alias = "initWithPasteboard:")]
+    pub fn from_pasteboard(pasteboard: &NSPasteboard) -> Option<Arc<Self>> {
+        unsafe { Self::init_with(selector!(initWithPasteboard:), pasteboard) }
+    }
+
+    unsafe fn init_with<T>(sel: Sel, arg: &T) -> Option<Arc<Self>> {
+        let image: Arc<Self> = Self::class().alloc();
+
+        #[allow(clashing_extern_declarations)]
+        extern "C" {
+            fn objc_msgSend(
+                obj: Arc<NSImage>,
+                sel: Sel,
+                arg: *const c_void,
+            ) -> Option<Arc<NSImage>>;
+        }
+
+        objc_msgSend(image, sel, (arg as *const T).cast())
+    }
+
+    /// Creates an image of `size` points from `image`.
+    ///
+    /// If `size` is zero, the size of `image` in pixels is used.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsimage/init(cgimage:size:)).
+    #[cfg(feature = "core_foundation")]
+    #[inline]
+    #[doc(alias = "initWithCGImage:size:")]
+    pub fn from_cg_image(image: &CGImage, size: NSSize) -> Arc<Self> {
+        unsafe {
+            let obj: Arc<Self> = Self::class().alloc();
+
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(
+                    obj: Arc<NSImage>,
+                    sel: Sel,
+                    image: &CGImage,
+                    size: NSSize,
+                ) -> Arc<NSImage>;
+            }
+
+            objc_msgSend(obj, selector!(initWithCGImage:size:), image, size)
+        }
+    }
+
+    /// Returns the size of the image in points.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsimage/size).
+    #[inline]
+    pub fn size(&self) -> NSSize {
+        unsafe { _msg_send_any![self, size] }
+    }
+
+    /// Adds `rep` to the representations that the image chooses from when
+    /// drawing.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsimage/addrepresentation(_:)).
+    #[inline]
+    #[doc(alias = "addRepresentation:")]
+    pub fn add_representation(&self, rep: &NSBitmapImageRep) {
+        unsafe { _msg_send_any![self, addRepresentation: rep => ()] }
+    }
+
+    /// Returns a bitmap of the image at its natural size, or [`None`] if it
+    /// could not be rendered.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsimage/cgimage(forproposedrect:context:hints:)).
+    #[cfg(feature = "core_foundation")]
+    #[inline]
+    #[doc(alias = "CGImageForProposedRect:context:hints:")]
+    pub fn cg_image(&self) -> Option<Arc<CGImage>> {
+        unsafe {
+            let image: *const CGImage = _msg_send_any![
+                self,
+                CGImageForProposedRect: ptr::null_mut::<c_void>()
+                context: ptr::null::<c_void>()
+                hints: ptr::null::<c_void>()
+            ];
+            image.as_ref().map(Arc::retain)
+        }
+    }
+}
+
+#[cfg(feature = "core_foundation")]
+impl PixelSource for NSImage {
+    #[inline]
+    fn pixel_size(&self) -> (usize, usize) {
+        match self.cg_image() {
+            Some(image) => image.pixel_size(),
+            None => (0, 0),
+        }
+    }
+
+    fn render_pixels(&self, pixels: &Pixels, buffer: &mut [u8]) -> Result<(), PixelsError> {
+        match self.cg_image() {
+            Some(image) => image.render_pixels(pixels, buffer),
+            None => Err(PixelsError::UnsupportedSource),
+        }
+    }
+}
@@ -0,0 +1,178 @@
+use super::NSLayoutConstraint;
+use crate::core::Arc;
+use crate::core_graphics::CGFloat;
+use crate::objc::NSObject;
+
+objc_subclass! {
+    /// An edge, center, or dimension of a view, from which
+    /// [constraints](NSLayoutConstraint) are created.
+    ///
+    /// Anchors are only related to anchors of the same kind, which the
+    /// [`NSLayoutXAxisAnchor`], [`NSLayoutYAxisAnchor`], and
+    /// [`NSLayoutDimension`] types enforce.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nslayoutanchor).
+    pub class NSLayoutAnchor: NSObject<'static>;
+}
+
+objc_subclass! {
+    /// A horizontal position, such as the leading edge of a view.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nslayoutxaxisanchor).
+    pub class NSLayoutXAxisAnchor: NSLayoutAnchor;
+}
+
+objc_subclass! {
+    /// A vertical position, such as the top edge of a view.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nslayoutyaxisanchor).
+    pub class NSLayoutYAxisAnchor: NSLayoutAnchor;
+}
+
+objc_subclass! {
+    /// A width or height of a view.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nslayoutdimension).
+    pub class NSLayoutDimension: NSLayoutAnchor;
+}
+
+macro_rules! anchor_relations {
+    ($anchor:ty) => {
+        impl $anchor {
+            /// Creates an inactive constraint that `self` equals `other`
+            /// plus `constant`.
+            ///
+            /// See [documentation](https://developer.apple.com/documentation/appkit/nslayoutanchor/constraint(equalto:constant:)).
+            #[inline]
+            #[doc(alias = "constraintEqualToAnchor:constant:")]
+            pub fn equal_to(&self, other: &Self, constant: CGFloat) -> Arc<NSLayoutConstraint> {
+                unsafe {
+                    Arc::retain_raw(_msg_send_any![
+                        self,
+                        constraintEqualToAnchor: other
+                        constant: constant
+                        => *const NSLayoutConstraint
+                    ])
+                }
+            }
+
+            /// Creates an inactive constraint that `self` is at least `other`
+            /// plus `constant`.
+            ///
+            /// See [documentation](https://developer.apple.com/documentation/appkit/nslayoutanchor/constraint(greaterthanorequalto:constant:)).
+            #[inline]
+            #[doc(alias = "constraintGreaterThanOrEqualToAnchor:constant:")]
+            pub fn greater_than_or_equal_to(
+                &self,
+                other: &Self,
+                constant: CGFloat,
+            ) -> Arc<NSLayoutConstraint> {
+                unsafe {
+                    Arc::retain_raw(_msg_send_any![
+                        self,
+                        constraintGreaterThanOrEqualToAnchor: other
+                        constant: constant
+                        => *const NSLayoutConstraint
+                    ])
+                }
+            }
+
+            /// Creates an inactive constraint that `self` is at most `other`
+            /// plus `constant`.
+            ///
+            /// See [documentation](https://developer.apple.com/documentation/appkit/nslayoutanchor/constraint(lessthanorequalto:constant:)).
+            #[inline]
+            #[doc(alias = "constraintLessThanOrEqualToAnchor:constant:")]
+            pub fn less_than_or_equal_to(
+                &self,
+                other: &Self,
+                constant: CGFloat,
+            ) -> Arc<NSLayoutConstraint> {
+                unsafe {
+                    Arc::retain_raw(_msg_send_any![
+                        self,
+                        constraintLessThanOrEqualToAnchor: other
+                        constant: constant
+                        => *const NSLayoutConstraint
+                    ])
+                }
+            }
+        }
+    };
+}
+
+anchor_relations!(NSLayoutXAxisAnchor);
+anchor_relations!(NSLayoutYAxisAnchor);
+anchor_relations!(NSLayoutDimension);
+
+impl NSLayoutDimension {
+    /// Creates an inactive constraint that `self` equals `other` times
+    /// `multiplier` plus `constant`, such as to keep an aspect ratio.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nslayoutdimension/constraint(equalto:multiplier:constant:)).
+    #[inline]
+    #[doc(alias = "constraintEqualToAnchor:multiplier:constant:")]
+    pub fn equal_to_multiple_of(
+        &self,
+        other: &Self,
+        multiplier: CGFloat,
+        constant: CGFloat,
+    ) -> Arc<NSLayoutConstraint> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![
+                self,
+                constraintEqualToAnchor: other
+                multiplier: multiplier
+                constant: constant
+                => *const NSLayoutConstraint
+            ])
+        }
+    }
+
+    /// Creates an inactive constraint that `self` equals `constant` points.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nslayoutdimension/constraint(equaltoconstant:)).
+    #[inline]
+    #[doc(alias = "constraintEqualToConstant:")]
+    pub fn equal_to_constant(&self, constant: CGFloat) -> Arc<NSLayoutConstraint> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![
+                self,
+                constraintEqualToConstant: constant
+                => *const NSLayoutConstraint
+            ])
+        }
+    }
+
+    /// Creates an inactive constraint that `self` is at least `constant`
+    /// points.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nslayoutdimension/constraint(greaterthanorequaltoconstant:)).
+    #[inline]
+    #[doc(alias = "constraintGreaterThanOrEqualToConstant:")]
+    pub fn greater_than_or_equal_to_constant(&self, constant: CGFloat) -> Arc<NSLayoutConstraint> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![
+                self,
+                constraintGreaterThanOrEqualToConstant: constant
+                => *const NSLayoutConstraint
+            ])
+        }
+    }
+
+    /// Creates an inactive constraint that `self` is at most `constant`
+    /// points.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nslayoutdimension/constraint(lessthanorequaltoconstant:)).
+    #[inline]
+    #[doc(alias = "constraintLessThanOrEqualToConstant:")]
+    pub fn less_than_or_equal_to_constant(&self, constant: CGFloat) -> Arc<NSLayoutConstraint> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![
+                self,
+                constraintLessThanOrEqualToConstant: constant
+                => *const NSLayoutConstraint
+            ])
+        }
+    }
+}
@@ -0,0 +1,287 @@
+use super::{NSDirectionalEdgeInsets, NSView};
+use crate::core::Arc;
+use crate::core_graphics::CGFloat;
+use crate::foundation::{NSArray, NSSize};
+use crate::objc::{ClassType, NSObject, BOOL};
+use std::fmt;
+
+objc_subclass! {
+    /// A relationship between two view [anchors](super::NSLayoutAnchor), or
+    /// between an anchor and a constant, that Auto Layout satisfies when
+    /// positioning views.
+    ///
+    /// Constraints are created from anchors such as
+    /// [`NSView::leading_anchor`], and have no effect until they are
+    /// [activated](Self::activate). [`NSLayoutConstraintSet`] creates and
+    /// activates constraints for common layouts.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nslayoutconstraint).
+    pub class NSLayoutConstraint: NSObject<'static>;
+}
+
+impl fmt::Debug for NSLayoutConstraint {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("NSLayoutConstraint")
+            .field("is_active", &self.is_active())
+            .field("constant", &self.constant())
+            .field("multiplier", &self.multiplier())
+            .field("priority", &self.priority())
+            .finish()
+    }
+}
+
+impl NSLayoutConstraint {
+    /// Activates all of `constraints` at once, which is faster than
+    /// activating them individually.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nslayoutconstraint/activate(_:)).
+    #[inline]
+    #[doc(alias = "activateConstraints:")]
+    pub fn activate(constraints: &[&NSLayoutConstraint]) {
+        let constraints = NSArray::from_slice(constraints);
+        let constraints: &NSArray<NSLayoutConstraint> = &constraints;
+        unsafe { _msg_send_any![Self::class(), activateConstraints: constraints => ()] }
+    }
+
+    /// Deactivates all of `constraints` at once.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nslayoutconstraint/deactivate(_:)).
+    #[inline]
+    #[doc(alias = "deactivateConstraints:")]
+    pub fn deactivate(constraints: &[&NSLayoutConstraint]) {
+        let constraints = NSArray::from_slice(constraints);
+        let constraints: &NSArray<NSLayoutConstraint> = &constraints;
+        unsafe { _msg_send_any![Self::class(), deactivateConstraints: constraints => ()] }
+    }
+
+    /// Returns `true` if Auto Layout satisfies `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nslayoutconstraint/isactive).
+    #[inline]
+    #[doc(alias = "isActive")]
+    pub fn is_active(&self) -> bool {
+        unsafe { _msg_send_any![self, isActive => BOOL] }.into()
+    }
+
+    /// Sets whether Auto Layout satisfies `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nslayoutconstraint/isactive).
+    #[inline]
+    #[doc(alias = "setActive:")]
+    pub fn set_active(&self, value: bool) {
+        unsafe { _msg_send_any![self, setActive: BOOL::from(value) => ()] }
+    }
+
+    /// Returns the constant added to the related anchor.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nslayoutconstraint/constant).
+    #[inline]
+    pub fn constant(&self) -> CGFloat {
+        unsafe { _msg_send_any![self, constant] }
+    }
+
+    /// Sets the constant added to the related anchor.
+    ///
+    /// This is the only part of an active constraint that can change, which
+    /// makes it the way to animate or adjust a layout.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nslayoutconstraint/constant).
+    #[inline]
+    #[doc(alias = "setConstant:")]
+    pub fn set_constant(&self, constant: CGFloat) {
+        unsafe { _msg_send_any![self, setConstant: constant => ()] }
+    }
+
+    /// Returns the multiplier of the related anchor.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nslayoutconstraint/multiplier).
+    #[inline]
+    pub fn multiplier(&self) -> CGFloat {
+        unsafe { _msg_send_any![self, multiplier] }
+    }
+
+    /// Returns how strongly Auto Layout tries to satisfy `self` when it
+    /// conflicts with other constraints.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nslayoutconstraint/priority).
+    #[inline]
+    pub fn priority(&self) -> NSLayoutPriority {
+        unsafe { _msg_send_any![self, priority] }
+    }
+
+    /// Sets how strongly Auto Layout tries to satisfy `self`.
+    ///
+    /// The priority cannot change between [`NSLayoutPriority::REQUIRED`] and
+    /// other priorities once `self` is active.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nslayoutconstraint/priority).
+    #[inline]
+    #[doc(alias = "setPriority:")]
+    pub fn set_priority(&self, priority: NSLayoutPriority) {
+        unsafe { _msg_send_any![self, setPriority: priority => ()] }
+    }
+}
+
+/// How strongly Auto Layout tries to satisfy a [`NSLayoutConstraint`], from
+/// 1 to 1000.
+///
+/// See [documentation](https://developer.apple.com/documentation/appkit/nslayoutconstraint/priority-swift.struct).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct NSLayoutPriority(pub f32);
+
+impl NSLayoutPriority {
+    /// The constraint must be satisfied, which is the default.
+    #[doc(alias = "NSLayoutPriorityRequired")]
+    pub const REQUIRED: Self = Self(1000.0);
+
+    /// The priority with which a button resists shrinking its content.
+    #[doc(alias = "NSLayoutPriorityDefaultHigh")]
+    pub const DEFAULT_HIGH: Self = Self(750.0);
+
+    /// The priority with which dragging a split view divider resizes the
+    /// window.
+    #[doc(alias = "NSLayoutPriorityDragThatCanResizeWindow")]
+    pub const DRAG_THAT_CAN_RESIZE_WINDOW: Self = Self(510.0);
+
+    /// The priority with which the window keeps its size.
+    #[doc(alias = "NSLayoutPriorityWindowSizeStayPut")]
+    pub const WINDOW_SIZE_STAY_PUT: Self = Self(500.0);
+
+    /// The priority with which a button hugs its content.
+    #[doc(alias = "NSLayoutPriorityDefaultLow")]
+    pub const DEFAULT_LOW: Self = Self(250.0);
+
+    /// The priority with which views shrink to their smallest size when
+    /// the layout is fitted.
+    #[doc(alias = "NSLayoutPriorityFittingSizeCompression")]
+    pub const FITTING_SIZE_COMPRESSION: Self = Self(50.0);
+}
+
+/// A list of [constraints](NSLayoutConstraint) that are activated and
+/// deactivated together.
+///
+/// Methods that relate a view to others also make the view
+/// [positioned by constraints](NSView::set_translates_autoresizing_mask_into_constraints),
+/// since forgetting to is a common source of conflicts.
+///
+/// # Examples
+///
+/// ```no_run
+/// use fruity::app_kit::*;
+///
+/// # fn views() -> (fruity::core::Arc<NSView>, fruity::core::Arc<NSView>) { unimplemented!() }
+/// let (container, content) = views();
+/// container.add_subview(&content);
+///
+/// let layout = NSLayoutConstraintSet::new()
+///     .pin_edges(&content, &container, NSDirectionalEdgeInsets::all(20.0))
+///     .with(content.width_anchor().greater_than_or_equal_to_constant(300.0))
+///     .with_priority(
+///         content.height_anchor().equal_to_multiple_of(content.width_anchor(), 0.75, 0.0),
+///         NSLayoutPriority::DEFAULT_HIGH,
+///     );
+///
+/// layout.activate();
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct NSLayoutConstraintSet {
+    constraints: Vec<Arc<NSLayoutConstraint>>,
+}
+
+impl NSLayoutConstraintSet {
+    /// Creates an empty set.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the constraints in the order they were added.
+    #[inline]
+    pub fn constraints(&self) -> &[Arc<NSLayoutConstraint>] {
+        &self.constraints
+    }
+
+    /// Adds `constraint`.
+    #[inline]
+    pub fn with(mut self, constraint: Arc<NSLayoutConstraint>) -> Self {
+        self.constraints.push(constraint);
+        self
+    }
+
+    /// Adds `constraint` with `priority`, for constraints that may be broken
+    /// to satisfy others.
+    #[inline]
+    pub fn with_priority(
+        self,
+        constraint: Arc<NSLayoutConstraint>,
+        priority: NSLayoutPriority,
+    ) -> Self {
+        constraint.set_priority(priority);
+        self.with(constraint)
+    }
+
+    /// Adds constraints that keep the edges of `view` inset from the edges
+    /// of `container`, in top, leading, bottom, trailing order.
+    ///
+    /// Positive insets move `view` inward.
+    pub fn pin_edges(
+        self,
+        view: &NSView,
+        container: &NSView,
+        insets: NSDirectionalEdgeInsets,
+    ) -> Self {
+        view.set_translates_autoresizing_mask_into_constraints(false);
+
+        let top = view.top_anchor();
+        let leading = view.leading_anchor();
+        let bottom = view.bottom_anchor();
+        let trailing = view.trailing_anchor();
+
+        self.with(top.equal_to(container.top_anchor(), insets.top))
+            .with(leading.equal_to(container.leading_anchor(), insets.leading))
+            .with(bottom.equal_to(container.bottom_anchor(), -insets.bottom))
+            .with(trailing.equal_to(container.trailing_anchor(), -insets.trailing))
+    }
+
+    /// Adds constraints that keep the center of `view` at the center of
+    /// `container`, in horizontal, vertical order.
+    pub fn center(self, view: &NSView, container: &NSView) -> Self {
+        view.set_translates_autoresizing_mask_into_constraints(false);
+
+        let x = view.center_x_anchor();
+        let y = view.center_y_anchor();
+
+        self.with(x.equal_to(container.center_x_anchor(), 0.0))
+            .with(y.equal_to(container.center_y_anchor(), 0.0))
+    }
+
+    /// Adds constraints that give `view` a fixed size, in width, height
+    /// order.
+    pub fn size(self, view: &NSView, size: NSSize) -> Self {
+        view.set_translates_autoresizing_mask_into_constraints(false);
+        self.with(view.width_anchor().equal_to_constant(size.width))
+            .with(view.height_anchor().equal_to_constant(size.height))
+    }
+
+    /// Activates all constraints in the set.
+    #[inline]
+    #[doc(alias = "activateConstraints:")]
+    pub fn activate(&self) {
+        NSLayoutConstraint::activate(&self.as_refs());
+    }
+
+    /// Deactivates all constraints in the set, such as to switch to another
+    /// layout.
+    #[inline]
+    #[doc(alias = "deactivateConstraints:")]
+    pub fn deactivate(&self) {
+        NSLayoutConstraint::deactivate(&self.as_refs());
+    }
+
+    #[inline]
+    fn as_refs(&self) -> Vec<&NSLayoutConstraint> {
+        self.constraints.iter().map(|c| &**c).collect()
+    }
+}
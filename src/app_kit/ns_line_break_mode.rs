@@ -0,0 +1,35 @@
+use crate::objc::NSUInteger;
+
+/// How text that does not fit its bounds is wrapped or truncated.
+///
+/// See [documentation](https://developer.apple.com/documentation/appkit/nslinebreakmode).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NSLineBreakMode(pub NSUInteger);
+
+impl NSLineBreakMode {
+    /// Lines wrap at word boundaries.
+    #[doc(alias = "NSLineBreakByWordWrapping")]
+    pub const WORD_WRAPPING: Self = Self(0);
+
+    /// Lines wrap at character boundaries.
+    #[doc(alias = "NSLineBreakByCharWrapping")]
+    pub const CHAR_WRAPPING: Self = Self(1);
+
+    /// Text past the bounds is not drawn.
+    #[doc(alias = "NSLineBreakByClipping")]
+    pub const CLIPPING: Self = Self(2);
+
+    /// The start of the text is replaced with an ellipsis, as in `"…xyz"`.
+    #[doc(alias = "NSLineBreakByTruncatingHead")]
+    pub const TRUNCATING_HEAD: Self = Self(3);
+
+    /// The end of the text is replaced with an ellipsis, as in `"abc…"`.
+    #[doc(alias = "NSLineBreakByTruncatingTail")]
+    pub const TRUNCATING_TAIL: Self = Self(4);
+
+    /// The middle of the text is replaced with an ellipsis, as in
+    /// `"ab…yz"`.
+    #[doc(alias = "NSLineBreakByTruncatingMiddle")]
+    pub const TRUNCATING_MIDDLE: Self = Self(5);
+}
@@ -0,0 +1,191 @@
+use crate::core::Arc;
+use crate::foundation::{NSArray, NSData, NSString, NSURL};
+use crate::objc::{Class, ClassType, NSObject, ObjectType, Sel, BOOL};
+use std::{ffi::CStr, fmt, ptr};
+
+objc_subclass! {
+    /// An Interface Builder archive (`.xib` compiled to `.nib`) whose objects
+    /// can be instantiated, for apps that keep existing interface files.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fruity::app_kit::{NSNib, NSView, NSWindow};
+    ///
+    /// let nib = NSNib::new(fruity::ns_string!("Inspector")).expect("nib not found");
+    /// let objects = nib.instantiate(None).expect("failed to load nib");
+    ///
+    /// let window = objects.first_of::<NSWindow>().expect("no window in nib");
+    /// window.make_key_and_order_front();
+    /// ```
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsnib).
+    pub class NSNib: NSObject<'static>;
+}
+
+impl NSNib {
+    /// Returns the nib named `name` in the main bundle, or [`None`] if it
+    /// does not exist.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsnib/init(nibnamed:bundle:)).
+    #[inline]
+    #[doc(alias = "initWithNibNamed:bundle:")]
+    pub fn new(name: &NSString) -> Option<Arc<Self>> {
+        unsafe { Self::init_named(name, ptr::null()) }
+    }
+
+    /// Returns the nib named `name` in the bundle at `bundle_url`, or
+    /// [`None`] if either does not exist.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsnib/init(nibnamed:bundle:)).
+    #[inline]
+    #[doc(alias = "initWithNibNamed:bundle:")]
+    pub fn in_bundle_at(name: &NSString, bundle_url: &NSURL) -> Option<Arc<Self>> {
+        let bundle = bundle_at(bundle_url)?;
+        unsafe { Self::init_named(name, &*bundle) }
+    }
+
+    unsafe fn init_named(name: &NSString, bundle: *const NSObject<'static>) -> Option<Arc<Self>> {
+        let obj: Arc<Self> = <Self as ClassType>::class().alloc();
+
+        #[allow(clashing_extern_declarations)]
+        extern "C" {
+            fn objc_msgSend(
+                obj: Arc<NSNib>,
+                sel: Sel,
+                name: &NSString,
+                bundle: *const NSObject<'static>,
+            ) -> Option<Arc<NSNib>>;
+        }
+
+        objc_msgSend(obj, selector!(initWithNibNamed:bundle:), name, bundle)
+    }
+
+    /// Creates a nib from the contents of a `.nib` file, whose resources are
+    /// looked up in the main bundle.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsnib/init(nibdata:bundle:)).
+    #[inline]
+    #[doc(alias = "initWithNibData:bundle:")]
+    pub fn from_data(data: &NSData) -> Arc<Self> {
+        unsafe {
+            let obj: Arc<Self> = <Self as ClassType>::class().alloc();
+
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(
+                    obj: Arc<NSNib>,
+                    sel: Sel,
+                    data: &NSData,
+                    bundle: *const NSObject<'static>,
+                ) -> Arc<NSNib>;
+            }
+
+            objc_msgSend(obj, selector!(initWithNibData:bundle:), data, ptr::null())
+        }
+    }
+
+    /// Creates the objects of the nib, connecting the outlets and actions of
+    /// the File's Owner placeholder to `owner`.
+    ///
+    /// Returns [`None`] if the nib could not be loaded.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsnib/instantiate(withowner:toplevelobjects:)).
+    #[doc(alias = "instantiateWithOwner:topLevelObjects:")]
+    pub fn instantiate(&self, owner: Option<&NSObject>) -> Option<NSNibTopLevelObjects> {
+        let owner = match owner {
+            Some(owner) => owner,
+            None => ptr::null(),
+        };
+        let mut objects: *const NSArray<NSObject<'static>> = ptr::null();
+
+        let loaded: bool = unsafe {
+            _msg_send_any![
+                self,
+                instantiateWithOwner: owner
+                topLevelObjects: &mut objects
+                => BOOL
+            ]
+        }
+        .into();
+
+        if !loaded {
+            return None;
+        }
+
+        // The array is autoreleased.
+        let objects = unsafe { objects.as_ref() }?;
+        Some(NSNibTopLevelObjects {
+            objects: objects.to_vec(),
+        })
+    }
+}
+
+/// The objects at the top level of an instantiated [`NSNib`], such as its
+/// windows and controllers.
+///
+/// These are the only references that keep the objects alive, so the ones
+/// that are used must be kept.
+#[derive(Clone)]
+pub struct NSNibTopLevelObjects {
+    objects: Vec<Arc<NSObject<'static>>>,
+}
+
+impl fmt::Debug for NSNibTopLevelObjects {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list()
+            .entries(self.objects.iter().map(|object| object.class().name()))
+            .finish()
+    }
+}
+
+impl NSNibTopLevelObjects {
+    /// Returns all top-level objects, in no particular order.
+    #[inline]
+    pub fn as_slice(&self) -> &[Arc<NSObject<'static>>] {
+        &self.objects
+    }
+
+    /// Returns the first object that is an instance of `T` or a subclass.
+    #[inline]
+    pub fn first_of<T: ClassType<'static>>(&self) -> Option<Arc<T>> {
+        self.objects.iter().find_map(|object| downcast(object))
+    }
+
+    /// Returns all objects that are instances of `T` or a subclass.
+    #[inline]
+    pub fn all_of<T: ClassType<'static>>(&self) -> Vec<Arc<T>> {
+        self.objects
+            .iter()
+            .filter_map(|object| downcast(object))
+            .collect()
+    }
+
+    /// Returns the top-level objects.
+    #[inline]
+    pub fn into_vec(self) -> Vec<Arc<NSObject<'static>>> {
+        self.objects
+    }
+}
+
+/// Returns `object` as `T` if it is an instance of `T` or a subclass.
+pub(super) fn downcast<T: ClassType<'static>>(object: &NSObject<'static>) -> Option<Arc<T>> {
+    if object.is_kind_of_class(<T as ClassType>::class()) {
+        Some(Arc::retain(unsafe {
+            &*(object as *const NSObject as *const T)
+        }))
+    } else {
+        None
+    }
+}
+
+/// Returns the `NSBundle` at `url`, or [`None`] if there is none.
+pub(super) fn bundle_at(url: &NSURL) -> Option<Arc<NSObject<'static>>> {
+    let bundle_class = unsafe { Class::get(CStr::from_bytes_with_nul_unchecked(b"NSBundle\0"))? };
+
+    unsafe {
+        _msg_send_any![bundle_class, bundleWithURL: url => *const NSObject<'static>]
+            .as_ref()
+            .map(Arc::retain)
+    }
+}
@@ -0,0 +1,424 @@
+use crate::core::Arc;
+use crate::foundation::{
+    NSArray, NSData, NSDictionary, NSNumber, NSString, NSTimeInterval, NSTimer, NSURL,
+};
+use crate::objc::{Class, ClassType, NSInteger, NSObject, NSUInteger, BOOL};
+use std::{fmt, ops};
+
+objc_subclass! {
+    /// A store of data that is shared between apps, such as the clipboard
+    /// used for copy and paste.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fruity::app_kit::{NSPasteboard, NSPasteboardType};
+    /// use fruity::foundation::NSString;
+    ///
+    /// let pasteboard = NSPasteboard::general();
+    ///
+    /// pasteboard.clear_contents();
+    /// pasteboard.set_string(&NSString::from_str("hello"), NSPasteboardType::string());
+    ///
+    /// let string = pasteboard.string_for_type(NSPasteboardType::string());
+    /// ```
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nspasteboard).
+    pub class NSPasteboard: NSObject<'static>;
+}
+
+impl fmt::Debug for NSPasteboard {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("NSPasteboard")
+            .field("name", &self.name())
+            .field("change_count", &self.change_count())
+            .finish()
+    }
+}
+
+impl NSPasteboard {
+    /// Returns the pasteboard used for copy and paste.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nspasteboard/general).
+    #[inline]
+    #[doc(alias = "generalPasteboard")]
+    pub fn general() -> Arc<Self> {
+        unsafe { Arc::retain_raw(_msg_send_any![Self::class(), generalPasteboard => *const Self]) }
+    }
+
+    /// Returns the pasteboard called `name`, creating it if it does not
+    /// exist.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nspasteboard/init(name:)).
+    #[inline]
+    #[doc(alias = "pasteboardWithName:")]
+    pub fn with_name(name: &NSString) -> Arc<Self> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![
+                Self::class(),
+                pasteboardWithName: name
+                => *const Self
+            ])
+        }
+    }
+
+    /// Returns the name of the pasteboard.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nspasteboard/name).
+    #[inline]
+    pub fn name(&self) -> Arc<NSString<'static>> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, name => *const NSString<'static>]) }
+    }
+
+    /// Returns a number that increases whenever the contents of the
+    /// pasteboard change.
+    ///
+    /// There are no notifications for pasteboard changes, so this should be
+    /// polled to detect them.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nspasteboard/changecount).
+    #[inline]
+    #[doc(alias = "changeCount")]
+    pub fn change_count(&self) -> NSInteger {
+        unsafe { _msg_send_any![self, changeCount] }
+    }
+
+    /// Removes the contents of the pasteboard, returning the new
+    /// [change count](Self::change_count).
+    ///
+    /// This must be called before writing new contents.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nspasteboard/clearcontents()).
+    #[inline]
+    #[doc(alias = "clearContents")]
+    pub fn clear_contents(&self) -> NSInteger {
+        unsafe { _msg_send_any![self, clearContents] }
+    }
+
+    /// Removes the contents of the pasteboard like
+    /// [`clear_contents`](Self::clear_contents), and controls how the new
+    /// contents are shared with `options`.
+    ///
+    /// Writing after preparing with
+    /// [`CURRENT_HOST_ONLY`](NSPasteboardContentsOptions::CURRENT_HOST_ONLY)
+    /// keeps the contents off other devices through Universal Clipboard,
+    /// which suits generated or sensitive contents.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nspasteboard/preparefornewcontents(with:)).
+    #[inline]
+    #[doc(alias = "prepareForNewContentsWithOptions:")]
+    pub fn prepare_for_new_contents(&self, options: NSPasteboardContentsOptions) -> NSInteger {
+        unsafe { _msg_send_any![self, prepareForNewContentsWithOptions: options.0] }
+    }
+
+    /// Clears the contents of the pasteboard after `interval` seconds, unless
+    /// they were replaced before then.
+    ///
+    /// AppKit has no expiration date for pasteboard contents, so this
+    /// schedules a timer on the current run loop in the default mode, which
+    /// should be the main run loop. The contents are kept if the timer is
+    /// [invalidated](NSTimer::invalidate) before it fires.
+    ///
+    /// This should be called right after writing, since the contents that
+    /// expire are those current when it is called.
+    pub fn expire_contents_after(&self, interval: NSTimeInterval) -> Arc<NSTimer> {
+        let pasteboard = Arc::retain(self);
+        let change_count = pasteboard.change_count();
+
+        NSTimer::scheduled_with_block(interval, false, move |_| {
+            if pasteboard.change_count() == change_count {
+                pasteboard.clear_contents();
+            }
+        })
+    }
+
+    /// Returns the types of data on the pasteboard, with the most
+    /// descriptive first.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nspasteboard/types).
+    #[inline]
+    pub fn types(&self) -> Option<Arc<NSArray<NSPasteboardType>>> {
+        unsafe {
+            _msg_send_any![self, types => *const NSArray<NSPasteboardType>]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Returns `true` if the pasteboard has data of `kind`.
+    #[inline]
+    pub fn has_type(&self, kind: &NSPasteboardType) -> bool {
+        match self.types() {
+            Some(types) => types.iter().any(|t| t == kind),
+            None => false,
+        }
+    }
+
+    /// Returns `true` if the contents were copied on another device and
+    /// arrived through Universal Clipboard, rather than being copied on this
+    /// Mac.
+    ///
+    /// See [`NSPasteboardType::remote_clipboard`].
+    #[inline]
+    pub fn is_remote(&self) -> bool {
+        self.has_type(NSPasteboardType::remote_clipboard())
+    }
+
+    /// Returns the string of `kind`, or [`None`] if the pasteboard does not
+    /// have it.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nspasteboard/string(fortype:)).
+    #[inline]
+    #[doc(alias = "stringForType:")]
+    pub fn string_for_type(&self, kind: &NSPasteboardType) -> Option<Arc<NSString<'static>>> {
+        unsafe {
+            _msg_send_any![self, stringForType: kind => *const NSString<'static>]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Writes `string` as `kind`, returning `false` if it could not be
+    /// written.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nspasteboard/setstring(_:fortype:)).
+    #[inline]
+    #[doc(alias = "setString:forType:")]
+    pub fn set_string(&self, string: &NSString, kind: &NSPasteboardType) -> bool {
+        unsafe { _msg_send_any![self, setString: string forType: kind => BOOL] }.into()
+    }
+
+    /// Returns the data of `kind`, or [`None`] if the pasteboard does not
+    /// have it.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nspasteboard/data(fortype:)).
+    #[inline]
+    #[doc(alias = "dataForType:")]
+    pub fn data_for_type(&self, kind: &NSPasteboardType) -> Option<Arc<NSData>> {
+        unsafe {
+            _msg_send_any![self, dataForType: kind => *const NSData]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Writes `data` as `kind`, returning `false` if it could not be
+    /// written.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nspasteboard/setdata(_:fortype:)).
+    #[inline]
+    #[doc(alias = "setData:forType:")]
+    pub fn set_data(&self, data: &NSData, kind: &NSPasteboardType) -> bool {
+        unsafe { _msg_send_any![self, setData: data forType: kind => BOOL] }.into()
+    }
+
+    /// Returns the file URLs on the pasteboard, such as files copied in
+    /// Finder.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nspasteboard/readobjects(forclasses:options:)).
+    #[inline]
+    #[doc(alias = "readObjectsForClasses:options:")]
+    #[doc(alias = "NSPasteboardURLReadingFileURLsOnlyKey")]
+    pub fn file_urls(&self) -> Arc<NSArray<NSURL>> {
+        self.read_urls(true)
+    }
+
+    /// Returns the URLs on the pasteboard, including both file URLs and web
+    /// URLs such as links copied in a browser.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nspasteboard/readobjects(forclasses:options:)).
+    #[inline]
+    #[doc(alias = "readObjectsForClasses:options:")]
+    pub fn urls(&self) -> Arc<NSArray<NSURL>> {
+        self.read_urls(false)
+    }
+
+    fn read_urls(&self, file_urls_only: bool) -> Arc<NSArray<NSURL>> {
+        extern "C" {
+            static NSPasteboardURLReadingFileURLsOnlyKey: &'static NSString<'static>;
+        }
+
+        let file_urls_only = NSNumber::from_bool(file_urls_only);
+
+        unsafe {
+            // Classes are objects, so they can be stored in an array.
+            let url_class = &*(NSURL::class() as *const Class as *const NSObject);
+            let classes: Arc<NSArray<NSObject>> = NSArray::from_slice(&[url_class]);
+
+            let options: Arc<NSDictionary<NSString, NSNumber>> = NSDictionary::from_slices(
+                &[NSPasteboardURLReadingFileURLsOnlyKey],
+                &[&file_urls_only],
+            );
+
+            let urls: *const NSArray<NSURL> = _msg_send_any![
+                self,
+                readObjectsForClasses: &*classes
+                options: &*options
+            ];
+            match urls.as_ref() {
+                Some(urls) => Arc::retain(urls),
+                None => NSArray::new(),
+            }
+        }
+    }
+
+    /// Writes `urls`, such as for pasting files in Finder, returning `false`
+    /// if they could not be written.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nspasteboard/writeobjects(_:)).
+    #[inline]
+    #[doc(alias = "writeObjects:")]
+    pub fn write_urls(&self, urls: &[&NSURL]) -> bool {
+        let urls: Arc<NSArray<NSURL>> = NSArray::from_slice(urls);
+        unsafe { _msg_send_any![self, writeObjects: &*urls => BOOL] }.into()
+    }
+}
+
+/// Options for [`NSPasteboard::prepare_for_new_contents`].
+///
+/// See [documentation](https://developer.apple.com/documentation/appkit/nspasteboard/contentsoptions).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NSPasteboardContentsOptions(pub NSUInteger);
+
+impl ops::BitOr for NSPasteboardContentsOptions {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl NSPasteboardContentsOptions {
+    /// The contents are only available on this Mac, and are not shared with
+    /// other devices through Universal Clipboard.
+    #[doc(alias = "NSPasteboardContentsCurrentHostOnly")]
+    pub const CURRENT_HOST_ONLY: Self = Self(1 << 0);
+
+    /// Returns `true` if all flags in `other` are set in `self`.
+    #[inline]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+objc_object_wrapper! {
+    /// A kind of data on an [`NSPasteboard`], which is a uniform type
+    /// identifier such as `public.utf8-plain-text`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nspasteboard/pasteboardtype).
+    #[derive(Ord, PartialOrd, Eq, PartialEq)]
+    pub wrapper NSPasteboardType: NSString<'static>;
+}
+
+impl fmt::Debug for NSPasteboardType {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl fmt::Display for NSPasteboardType {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+macro_rules! pasteboard_type {
+    ($(#[$docs:meta])+ $fn:ident = $value:literal) => {
+        $(#[$docs])+
+        #[inline]
+        #[doc(alias = $value)]
+        pub fn $fn() -> &'static NSPasteboardType {
+            extern "C" {
+                #[link_name = $value]
+                static VALUE: &'static NSPasteboardType;
+            }
+            unsafe { VALUE }
+        }
+    };
+}
+
+impl NSPasteboardType {
+    pasteboard_type! {
+        /// Plain text.
+        string = "NSPasteboardTypeString"
+    }
+
+    pasteboard_type! {
+        /// A URL of a file.
+        file_url = "NSPasteboardTypeFileURL"
+    }
+
+    pasteboard_type! {
+        /// A URL.
+        url = "NSPasteboardTypeURL"
+    }
+
+    pasteboard_type! {
+        /// HTML text.
+        html = "NSPasteboardTypeHTML"
+    }
+
+    pasteboard_type! {
+        /// Rich Text Format (RTF) text.
+        rtf = "NSPasteboardTypeRTF"
+    }
+
+    pasteboard_type! {
+        /// A PNG image.
+        png = "NSPasteboardTypePNG"
+    }
+
+    pasteboard_type! {
+        /// A TIFF image.
+        tiff = "NSPasteboardTypeTIFF"
+    }
+
+    pasteboard_type! {
+        /// A PDF document.
+        pdf = "NSPasteboardTypePDF"
+    }
+
+    pasteboard_type! {
+        /// Tab-separated values.
+        tabular_text = "NSPasteboardTypeTabularText"
+    }
+
+    /// Marks contents that should not be recorded by clipboard managers, such
+    /// as passwords.
+    ///
+    /// See [documentation](http://nspasteboard.org).
+    #[inline]
+    pub fn concealed() -> &'static Self {
+        Self::from_uti(crate::ns_string!("org.nspasteboard.ConcealedType"))
+    }
+
+    /// Marks contents that are only on the pasteboard briefly, such as those
+    /// written by an app to paste into another app on the user's behalf.
+    ///
+    /// See [documentation](http://nspasteboard.org).
+    #[inline]
+    pub fn transient() -> &'static Self {
+        Self::from_uti(crate::ns_string!("org.nspasteboard.TransientType"))
+    }
+
+    /// Marks contents that were copied on another device and arrived through
+    /// Universal Clipboard.
+    ///
+    /// The system adds this type itself, so it should not be written.
+    #[inline]
+    pub fn remote_clipboard() -> &'static Self {
+        Self::from_uti(crate::ns_string!("com.apple.is-remote-clipboard"))
+    }
+
+    /// Returns the type for data with the uniform type identifier `uti`, such
+    /// as `com.example.custom`.
+    #[inline]
+    pub fn from_uti<'a>(uti: &'a NSString<'static>) -> &'a Self {
+        unsafe { &*(uti as *const NSString<'static> as *const Self) }
+    }
+}
@@ -0,0 +1,239 @@
+use super::{NSImage, NSPasteboard, NSPasteboardType};
+use crate::core::Arc;
+use crate::foundation::{NSArray, NSString, NSTimeInterval, NSTimer, NSURL};
+use std::{
+    fmt,
+    sync::{
+        self,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+
+/// The contents of an [`NSPasteboard`], read as the most specific of the
+/// kinds that clipboard managers keep.
+#[derive(Clone)]
+pub enum NSPasteboardContents {
+    /// Files, such as those copied in Finder.
+    FileURLs(Arc<NSArray<NSURL>>),
+
+    /// An image, such as one copied from a browser or screenshot.
+    Image(Arc<NSImage>),
+
+    /// Web URLs, such as a link copied in a browser.
+    URLs(Arc<NSArray<NSURL>>),
+
+    /// Plain text.
+    String(Arc<NSString<'static>>),
+}
+
+impl fmt::Debug for NSPasteboardContents {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::FileURLs(urls) => f.debug_tuple("FileURLs").field(&urls.len()).finish(),
+            Self::Image(image) => f.debug_tuple("Image").field(&image.size()).finish(),
+            Self::URLs(urls) => f.debug_tuple("URLs").field(&urls.len()).finish(),
+            Self::String(string) => f.debug_tuple("String").field(string).finish(),
+        }
+    }
+}
+
+impl PartialEq for NSPasteboardContents {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::FileURLs(a), Self::FileURLs(b)) | (Self::URLs(a), Self::URLs(b)) => **a == **b,
+            (Self::Image(a), Self::Image(b)) => ***a == ***b,
+            (Self::String(a), Self::String(b)) => **a == **b,
+            _ => false,
+        }
+    }
+}
+
+impl NSPasteboardContents {
+    /// Reads the contents of `pasteboard`, or returns [`None`] if it has none
+    /// of the kinds of [`NSPasteboardContents`].
+    ///
+    /// Pasteboards often have several kinds of data at once, such as the
+    /// file name, icon, and URL of a copied file. The most specific kind is
+    /// returned, in the order of the variants.
+    pub fn read(pasteboard: &NSPasteboard) -> Option<Self> {
+        let file_urls = pasteboard.file_urls();
+        if !file_urls.is_empty() {
+            return Some(Self::FileURLs(file_urls));
+        }
+        if let Some(image) = NSImage::from_pasteboard(pasteboard) {
+            return Some(Self::Image(image));
+        }
+        let urls = pasteboard.urls();
+        if !urls.is_empty() {
+            return Some(Self::URLs(urls));
+        }
+        pasteboard
+            .string_for_type(NSPasteboardType::string())
+            .map(Self::String)
+    }
+}
+
+/// The contents last passed to the handler of an [`NSPasteboardWatcher`].
+struct Previous(Option<NSPasteboardContents>);
+
+// The timer only calls its closure on the run loop it is scheduled on.
+unsafe impl Send for Previous {}
+
+/// State shared between an [`NSPasteboardWatcher`] and its timer.
+struct Shared {
+    is_paused: AtomicBool,
+}
+
+/// Watches an [`NSPasteboard`] for new contents by polling its
+/// [change count](NSPasteboard::change_count), which is the only way that
+/// AppKit reports pasteboard changes.
+///
+/// Each time the contents change, they are read as
+/// [`NSPasteboardContents`] and passed to the handler. Contents that are the
+/// same as the previous contents, such as text copied twice, are not passed
+/// again (images are always passed, since they are only equal to
+/// themselves), and neither are contents marked as
+/// [concealed](NSPasteboardType::concealed) or
+/// [transient](NSPasteboardType::transient).
+///
+/// The watcher polls on a timer scheduled on the run loop of the thread that
+/// creates it, which should be the main thread. It stops when dropped.
+///
+/// # Examples
+///
+/// ```no_run
+/// use fruity::app_kit::{NSPasteboard, NSPasteboardContents, NSPasteboardWatcher};
+///
+/// let watcher = NSPasteboardWatcher::new(NSPasteboard::general(), 0.5, |contents| {
+///     if let NSPasteboardContents::String(string) = contents {
+///         println!("copied: {}", string);
+///     }
+/// });
+///
+/// // Don't record the app's own writes.
+/// watcher.pause();
+/// // ...
+/// watcher.resume();
+/// ```
+pub struct NSPasteboardWatcher {
+    pasteboard: Arc<NSPasteboard>,
+    timer: Arc<NSTimer>,
+    shared: sync::Arc<Shared>,
+}
+
+impl fmt::Debug for NSPasteboardWatcher {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("NSPasteboardWatcher")
+            .field("pasteboard", &self.pasteboard)
+            .field("interval", &self.timer.time_interval())
+            .field("is_paused", &self.is_paused())
+            .finish()
+    }
+}
+
+impl Drop for NSPasteboardWatcher {
+    #[inline]
+    fn drop(&mut self) {
+        self.timer.invalidate();
+    }
+}
+
+impl NSPasteboardWatcher {
+    /// Starts checking `pasteboard` for new contents every `interval`
+    /// seconds, calling `handler` with them.
+    ///
+    /// The current contents are not passed to `handler`.
+    ///
+    /// It is safe to panic within `handler`. Panics will abort the process.
+    pub fn new<F>(pasteboard: Arc<NSPasteboard>, interval: NSTimeInterval, mut handler: F) -> Self
+    where
+        F: FnMut(&NSPasteboardContents) + Send + 'static,
+    {
+        let shared = sync::Arc::new(Shared {
+            is_paused: AtomicBool::new(false),
+        });
+
+        let mut change_count = pasteboard.change_count();
+        let mut previous = Previous(NSPasteboardContents::read(&pasteboard));
+
+        let timer = {
+            let pasteboard = Arc::clone(&pasteboard);
+            let shared = sync::Arc::clone(&shared);
+            NSTimer::scheduled_with_block(interval, true, move |_| {
+                let new_count = pasteboard.change_count();
+                if new_count == change_count {
+                    return;
+                }
+                change_count = new_count;
+
+                // Changes made while paused are skipped rather than
+                // delivered on resume.
+                if shared.is_paused.load(Ordering::Acquire) || is_private(&pasteboard) {
+                    return;
+                }
+
+                let contents = NSPasteboardContents::read(&pasteboard);
+                if contents != previous.0 {
+                    if let Some(contents) = &contents {
+                        handler(contents);
+                    }
+                    previous.0 = contents;
+                }
+            })
+        };
+
+        // Polling does not need to be precise, so let the system coalesce
+        // the timer with others to save energy.
+        timer.set_tolerance(interval / 10.0);
+
+        Self {
+            pasteboard,
+            timer,
+            shared,
+        }
+    }
+
+    /// Returns the pasteboard being watched.
+    #[inline]
+    pub fn pasteboard(&self) -> &NSPasteboard {
+        &self.pasteboard
+    }
+
+    /// Stops passing new contents to the handler until
+    /// [`resume`](Self::resume) is called.
+    ///
+    /// Contents that are copied while paused are never passed to the
+    /// handler, which lets an app write to the pasteboard without seeing its
+    /// own writes.
+    #[inline]
+    pub fn pause(&self) {
+        self.shared.is_paused.store(true, Ordering::Release);
+    }
+
+    /// Resumes passing new contents to the handler after
+    /// [`pause`](Self::pause).
+    #[inline]
+    pub fn resume(&self) {
+        self.shared.is_paused.store(false, Ordering::Release);
+    }
+
+    /// Returns `true` if the watcher is [paused](Self::pause).
+    #[inline]
+    pub fn is_paused(&self) -> bool {
+        self.shared.is_paused.load(Ordering::Acquire)
+    }
+}
+
+/// Returns `true` if the contents of `pasteboard` are marked as not to be
+/// recorded.
+fn is_private(pasteboard: &NSPasteboard) -> bool {
+    let types = match pasteboard.types() {
+        Some(types) => types,
+        None => return false,
+    };
+    let concealed = NSPasteboardType::concealed();
+    let transient = NSPasteboardType::transient();
+    types
+        .iter()
+        .any(|kind| kind == concealed || kind == transient)
+}
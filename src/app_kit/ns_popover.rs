@@ -0,0 +1,225 @@
+use super::{NSStatusItem, NSView, NSViewController};
+use crate::core::Arc;
+use crate::foundation::{NSRect, NSRectEdge, NSSize};
+use crate::objc::{ClassType, NSInteger, NSObject, NSUInteger, BOOL};
+use std::ptr;
+
+objc_subclass! {
+    /// A view shown in a bubble that points at content it relates to, such
+    /// as a status item in the menu bar.
+    ///
+    /// # Examples
+    ///
+    /// A menu bar app shows its content in a popover when its status item is
+    /// clicked:
+    ///
+    /// ```no_run
+    /// use fruity::app_kit::*;
+    /// use fruity::foundation::NSRectEdge;
+    ///
+    /// # fn content() -> fruity::core::Arc<NSView> { unimplemented!() }
+    /// let popover = NSPopover::new();
+    /// popover.set_behavior(NSPopoverBehavior::TRANSIENT);
+    /// popover.set_content_view(&content());
+    ///
+    /// let item = NSStatusBar::system().status_item(NSStatusItem::VARIABLE_LENGTH);
+    /// item.set_title(fruity::ns_string!("☕️"));
+    ///
+    /// let (shown_popover, shown_item) = (popover.clone(), item.clone());
+    /// let _handler = item.set_click_handler(move || {
+    ///     shown_popover.toggle_relative_to_status_item(&shown_item, NSRectEdge::MinY);
+    /// });
+    /// ```
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nspopover).
+    pub class NSPopover: NSObject<'static>;
+}
+
+impl NSPopover {
+    /// Creates a popover with no content.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nspopover/init()).
+    #[inline]
+    pub fn new() -> Arc<Self> {
+        unsafe { Self::class().alloc_init() }
+    }
+
+    /// Returns the controller of the popover's content.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nspopover/contentviewcontroller).
+    #[inline]
+    #[doc(alias = "contentViewController")]
+    pub fn content_view_controller(&self) -> Option<Arc<NSViewController>> {
+        unsafe {
+            _msg_send_any![self, contentViewController => *const NSViewController]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Sets the controller of the popover's content.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nspopover/contentviewcontroller).
+    #[inline]
+    #[doc(alias = "setContentViewController:")]
+    pub fn set_content_view_controller(&self, controller: Option<&NSViewController>) {
+        let controller = match controller {
+            Some(controller) => controller,
+            None => ptr::null(),
+        };
+        unsafe { _msg_send_any![self, setContentViewController: controller => ()] }
+    }
+
+    /// Shows `view` in the popover, with a new controller that manages it.
+    ///
+    /// The popover takes the size of `view` unless
+    /// [`set_content_size`](Self::set_content_size) is called afterwards.
+    #[inline]
+    pub fn set_content_view(&self, view: &NSView) {
+        let controller = NSViewController::with_view(view);
+        controller.set_preferred_content_size(view.frame().size);
+        self.set_content_view_controller(Some(&controller));
+    }
+
+    /// Returns the size of the popover's content.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nspopover/contentsize).
+    #[inline]
+    #[doc(alias = "contentSize")]
+    pub fn content_size(&self) -> NSSize {
+        unsafe { _msg_send_any![self, contentSize] }
+    }
+
+    /// Sets the size of the popover's content.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nspopover/contentsize).
+    #[inline]
+    #[doc(alias = "setContentSize:")]
+    pub fn set_content_size(&self, size: NSSize) {
+        unsafe { _msg_send_any![self, setContentSize: size => ()] }
+    }
+
+    /// Returns how the popover is closed.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nspopover/behavior-swift.property).
+    #[inline]
+    pub fn behavior(&self) -> NSPopoverBehavior {
+        unsafe { _msg_send_any![self, behavior] }
+    }
+
+    /// Sets how the popover is closed.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nspopover/behavior-swift.property).
+    #[inline]
+    #[doc(alias = "setBehavior:")]
+    pub fn set_behavior(&self, behavior: NSPopoverBehavior) {
+        unsafe { _msg_send_any![self, setBehavior: behavior => ()] }
+    }
+
+    /// Returns `true` if the popover animates when shown and closed.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nspopover/animates).
+    #[inline]
+    pub fn animates(&self) -> bool {
+        unsafe { _msg_send_any![self, animates => BOOL] }.into()
+    }
+
+    /// Sets whether the popover animates when shown and closed, which is the
+    /// default.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nspopover/animates).
+    #[inline]
+    #[doc(alias = "setAnimates:")]
+    pub fn set_animates(&self, value: bool) {
+        unsafe { _msg_send_any![self, setAnimates: BOOL::from(value) => ()] }
+    }
+
+    /// Returns `true` if the popover is on screen.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nspopover/isshown).
+    #[inline]
+    #[doc(alias = "isShown")]
+    pub fn is_shown(&self) -> bool {
+        unsafe { _msg_send_any![self, isShown => BOOL] }.into()
+    }
+
+    /// Shows the popover pointing at `rect` in the coordinate system of
+    /// `view`, on the `preferred_edge` of the rectangle if there is room.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nspopover/show(relativeto:of:preferrededge:)).
+    #[inline]
+    #[doc(alias = "showRelativeToRect:ofView:preferredEdge:")]
+    pub fn show_relative_to(&self, rect: NSRect, view: &NSView, preferred_edge: NSRectEdge) {
+        unsafe {
+            _msg_send_any![
+                self,
+                showRelativeToRect: rect
+                ofView: view
+                preferredEdge: preferred_edge as NSUInteger
+                => ()
+            ]
+        }
+    }
+
+    /// Shows the popover pointing at the button of `item`, typically below it
+    /// with [`NSRectEdge::MinY`].
+    ///
+    /// Does nothing if `item` has no button.
+    #[inline]
+    pub fn show_relative_to_status_item(&self, item: &NSStatusItem, preferred_edge: NSRectEdge) {
+        if let Some(button) = item.button() {
+            self.show_relative_to(button.bounds(), button, preferred_edge);
+        }
+    }
+
+    /// Closes the popover if it is shown, or otherwise shows it pointing at
+    /// the button of `item`.
+    #[inline]
+    pub fn toggle_relative_to_status_item(&self, item: &NSStatusItem, preferred_edge: NSRectEdge) {
+        if self.is_shown() {
+            self.perform_close();
+        } else {
+            self.show_relative_to_status_item(item, preferred_edge);
+        }
+    }
+
+    /// Closes the popover with its closing animation.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nspopover/performclose(_:)).
+    #[inline]
+    #[doc(alias = "performClose:")]
+    pub fn perform_close(&self) {
+        unsafe { _msg_send_any![self, performClose: ptr::null::<NSObject>() => ()] }
+    }
+
+    /// Closes the popover immediately.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nspopover/close()).
+    #[inline]
+    pub fn close(&self) {
+        unsafe { _msg_send_any![self, close => ()] }
+    }
+}
+
+/// How an [`NSPopover`] is closed.
+///
+/// See [documentation](https://developer.apple.com/documentation/appkit/nspopover/behavior-swift.enum).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NSPopoverBehavior(pub NSInteger);
+
+impl NSPopoverBehavior {
+    /// The popover is only closed by the app, which is the default.
+    #[doc(alias = "NSPopoverBehaviorApplicationDefined")]
+    pub const APPLICATION_DEFINED: Self = Self(0);
+
+    /// The popover is closed when the user interacts with anything outside of
+    /// it, which suits menu bar apps.
+    #[doc(alias = "NSPopoverBehaviorTransient")]
+    pub const TRANSIENT: Self = Self(1);
+
+    /// The popover is closed when the user interacts with the window that
+    /// contains the popover's anchor.
+    #[doc(alias = "NSPopoverBehaviorSemitransient")]
+    pub const SEMITRANSIENT: Self = Self(2);
+}
@@ -0,0 +1,209 @@
+use crate::core::Arc;
+use crate::foundation::{NSEdgeInsets, NSMutableDictionary, NSNumber, NSSize, NSString};
+use crate::objc::{ClassType, NSInteger, NSObject};
+use std::{fmt, ops::RangeInclusive};
+
+objc_subclass! {
+    /// Settings for printing, such as the paper size, margins, and pages.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsprintinfo).
+    pub class NSPrintInfo: NSObject<'static>;
+}
+
+impl fmt::Debug for NSPrintInfo {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("NSPrintInfo")
+            .field("paper_size", &self.paper_size())
+            .field("orientation", &self.orientation())
+            .field("page_range", &self.page_range())
+            .finish()
+    }
+}
+
+#[link(name = "AppKit", kind = "framework")]
+extern "C" {
+    static NSPrintAllPages: &'static NSString<'static>;
+    static NSPrintFirstPage: &'static NSString<'static>;
+    static NSPrintLastPage: &'static NSString<'static>;
+}
+
+impl NSPrintInfo {
+    /// Returns the settings used by default for the app.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsprintinfo/shared).
+    #[inline]
+    #[doc(alias = "sharedPrintInfo")]
+    pub fn shared() -> Arc<Self> {
+        unsafe { Arc::retain_raw(_msg_send_any![Self::class(), sharedPrintInfo => *const Self]) }
+    }
+
+    /// Creates settings with the default printer's paper size and margins.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsprintinfo/init()).
+    #[inline]
+    pub fn new() -> Arc<Self> {
+        unsafe { Self::class().alloc_init() }
+    }
+
+    /// Returns the size of the paper in points.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsprintinfo/papersize).
+    #[inline]
+    #[doc(alias = "paperSize")]
+    pub fn paper_size(&self) -> NSSize {
+        unsafe { _msg_send_any![self, paperSize] }
+    }
+
+    /// Sets the size of the paper in points, such as 612 × 792 for US Letter.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsprintinfo/papersize).
+    #[inline]
+    #[doc(alias = "setPaperSize:")]
+    pub fn set_paper_size(&self, size: NSSize) {
+        unsafe { _msg_send_any![self, setPaperSize: size => ()] }
+    }
+
+    /// Returns the name of the paper, such as `A4`, if known.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsprintinfo/papername).
+    #[inline]
+    #[doc(alias = "paperName")]
+    pub fn paper_name(&self) -> Option<Arc<NSString<'static>>> {
+        unsafe {
+            _msg_send_any![self, paperName => *const NSString<'static>]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Sets the paper by name, such as `A4` or `Letter`, which also sets the
+    /// [paper size](Self::paper_size).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsprintinfo/papername).
+    #[inline]
+    #[doc(alias = "setPaperName:")]
+    pub fn set_paper_name(&self, name: &NSString) {
+        unsafe { _msg_send_any![self, setPaperName: name => ()] }
+    }
+
+    /// Returns the distances in points between the edges of the paper and
+    /// the printed content.
+    #[inline]
+    #[doc(alias = "topMargin")]
+    #[doc(alias = "leftMargin")]
+    #[doc(alias = "bottomMargin")]
+    #[doc(alias = "rightMargin")]
+    pub fn margins(&self) -> NSEdgeInsets {
+        unsafe {
+            NSEdgeInsets {
+                top: _msg_send_any![self, topMargin],
+                left: _msg_send_any![self, leftMargin],
+                bottom: _msg_send_any![self, bottomMargin],
+                right: _msg_send_any![self, rightMargin],
+            }
+        }
+    }
+
+    /// Sets the distances in points between the edges of the paper and the
+    /// printed content.
+    #[inline]
+    #[doc(alias = "setTopMargin:")]
+    #[doc(alias = "setLeftMargin:")]
+    #[doc(alias = "setBottomMargin:")]
+    #[doc(alias = "setRightMargin:")]
+    pub fn set_margins(&self, margins: NSEdgeInsets) {
+        unsafe {
+            _msg_send_any![self, setTopMargin: margins.top => ()];
+            _msg_send_any![self, setLeftMargin: margins.left => ()];
+            _msg_send_any![self, setBottomMargin: margins.bottom => ()];
+            _msg_send_any![self, setRightMargin: margins.right => ()];
+        }
+    }
+
+    /// Returns the orientation of pages.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsprintinfo/orientation).
+    #[inline]
+    pub fn orientation(&self) -> NSPaperOrientation {
+        unsafe { _msg_send_any![self, orientation] }
+    }
+
+    /// Sets the orientation of pages.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsprintinfo/orientation).
+    #[inline]
+    #[doc(alias = "setOrientation:")]
+    pub fn set_orientation(&self, orientation: NSPaperOrientation) {
+        unsafe { _msg_send_any![self, setOrientation: orientation => ()] }
+    }
+
+    /// Returns the pages to print, numbered from 1, or [`None`] if all pages
+    /// are printed.
+    #[inline]
+    #[doc(alias = "NSPrintFirstPage")]
+    #[doc(alias = "NSPrintLastPage")]
+    pub fn page_range(&self) -> Option<RangeInclusive<NSInteger>> {
+        let dictionary = self.dictionary();
+        unsafe {
+            if let Some(all) = dictionary.get(NSPrintAllPages) {
+                if all.bool_value() {
+                    return None;
+                }
+            }
+            let first = dictionary.get(NSPrintFirstPage)?.integer_value();
+            let last = dictionary.get(NSPrintLastPage)?.integer_value();
+            Some(first..=last)
+        }
+    }
+
+    /// Sets the pages to print, numbered from 1, or prints all pages if
+    /// `range` is [`None`].
+    #[inline]
+    #[doc(alias = "NSPrintAllPages")]
+    pub fn set_page_range(&self, range: Option<RangeInclusive<NSInteger>>) {
+        let dictionary = self.dictionary();
+        // SAFETY: values of the dictionary are not borrowed beyond a single
+        // call.
+        unsafe {
+            match range {
+                Some(range) => {
+                    dictionary.insert(NSPrintAllPages, &NSNumber::from_bool(false));
+                    dictionary.insert(NSPrintFirstPage, &NSNumber::from_integer(*range.start()));
+                    dictionary.insert(NSPrintLastPage, &NSNumber::from_integer(*range.end()));
+                }
+                None => {
+                    dictionary.insert(NSPrintAllPages, &NSNumber::from_bool(true));
+                }
+            }
+        }
+    }
+
+    /// Returns the mutable attributes backing `self`.
+    #[inline]
+    fn dictionary(&self) -> Arc<NSMutableDictionary<NSString<'static>, NSNumber>> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![
+                self,
+                dictionary => *const NSMutableDictionary<NSString<'static>, NSNumber>
+            ])
+        }
+    }
+}
+
+/// The orientation of printed pages.
+///
+/// See [documentation](https://developer.apple.com/documentation/appkit/nsprintinfo/paperorientation).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NSPaperOrientation(pub NSInteger);
+
+impl NSPaperOrientation {
+    /// Pages are taller than they are wide.
+    #[doc(alias = "NSPaperOrientationPortrait")]
+    pub const PORTRAIT: Self = Self(0);
+
+    /// Pages are wider than they are tall.
+    #[doc(alias = "NSPaperOrientationLandscape")]
+    pub const LANDSCAPE: Self = Self(1);
+}
@@ -0,0 +1,141 @@
+use super::{NSPrintInfo, NSView};
+use crate::core::Arc;
+use crate::foundation::{NSData, NSString};
+use crate::objc::{Class, ClassType, NSInteger, NSObject, Sel, BOOL};
+use std::ffi::CStr;
+
+objc_subclass! {
+    /// Prints a view or document, optionally showing the print panel.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fruity::app_kit::{NSPaperOrientation, NSPrintInfo, NSPrintOperation};
+    /// use fruity::foundation::NSData;
+    ///
+    /// # let pdf: fruity::core::Arc<NSData> = unimplemented!();
+    /// let info = NSPrintInfo::new();
+    /// info.set_orientation(NSPaperOrientation::LANDSCAPE);
+    /// info.set_page_range(Some(1..=2));
+    ///
+    /// let operation = NSPrintOperation::with_pdf_data(&pdf, &info).unwrap();
+    /// operation.set_shows_print_panel(false);
+    /// operation.set_shows_progress_panel(false);
+    /// operation.run();
+    /// ```
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsprintoperation).
+    pub class NSPrintOperation: NSObject<'static>;
+}
+
+#[link(name = "PDFKit", kind = "framework")]
+extern "C" {}
+
+impl NSPrintOperation {
+    /// Creates an operation that prints the contents of `view`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsprintoperation/init(view:printinfo:)).
+    #[inline]
+    #[doc(alias = "printOperationWithView:printInfo:")]
+    pub fn with_view(view: &NSView, print_info: &NSPrintInfo) -> Arc<Self> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![
+                Self::class(),
+                printOperationWithView: view
+                printInfo: print_info
+                => *const Self
+            ])
+        }
+    }
+
+    /// Creates an operation that prints a PDF document, scaling down pages
+    /// that do not fit the paper and rotating them to match its orientation.
+    ///
+    /// Returns [`None`] if `data` is not a PDF document.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/pdfkit/pdfdocument/printoperation(for:scalingmode:autorotate:)).
+    #[doc(alias = "printOperationForPrintInfo:scalingMode:autoRotate:")]
+    pub fn with_pdf_data(data: &NSData, print_info: &NSPrintInfo) -> Option<Arc<Self>> {
+        // `kPDFPrintPageScaleDownToFit`.
+        const SCALE_DOWN_TO_FIT: NSInteger = 2;
+
+        unsafe {
+            let class = Class::get(CStr::from_bytes_with_nul_unchecked(b"PDFDocument\0"))?;
+            let document: Arc<NSObject> = class.alloc();
+
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(
+                    obj: Arc<NSObject<'static>>,
+                    sel: Sel,
+                    data: &NSData,
+                ) -> Option<Arc<NSObject<'static>>>;
+            }
+
+            let document = objc_msgSend(document, selector!(initWithData:), data)?;
+
+            let operation: *const Self = _msg_send_any![
+                &*document,
+                printOperationForPrintInfo: print_info
+                scalingMode: SCALE_DOWN_TO_FIT
+                autoRotate: BOOL::YES
+            ];
+            operation.as_ref().map(Arc::retain)
+        }
+    }
+
+    /// Returns the settings used for printing.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsprintoperation/printinfo).
+    #[inline]
+    #[doc(alias = "printInfo")]
+    pub fn print_info(&self) -> Arc<NSPrintInfo> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, printInfo => *const NSPrintInfo]) }
+    }
+
+    /// Sets whether the print panel is shown so that the user can choose a
+    /// printer and change settings, which is the default.
+    ///
+    /// Otherwise, the document is printed immediately to the default
+    /// printer.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsprintoperation/showsprintpanel).
+    #[inline]
+    #[doc(alias = "setShowsPrintPanel:")]
+    pub fn set_shows_print_panel(&self, value: bool) {
+        unsafe { _msg_send_any![self, setShowsPrintPanel: BOOL::from(value) => ()] }
+    }
+
+    /// Sets whether a panel showing the progress of printing is shown, which
+    /// is the default.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsprintoperation/showsprogresspanel).
+    #[inline]
+    #[doc(alias = "setShowsProgressPanel:")]
+    pub fn set_shows_progress_panel(&self, value: bool) {
+        unsafe { _msg_send_any![self, setShowsProgressPanel: BOOL::from(value) => ()] }
+    }
+
+    /// Sets the name of the job shown in the print queue.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsprintoperation/jobtitle).
+    #[inline]
+    #[doc(alias = "setJobTitle:")]
+    pub fn set_job_title(&self, title: &NSString) {
+        unsafe { _msg_send_any![self, setJobTitle: title => ()] }
+    }
+
+    /// Prints the document, blocking until printing finishes or the user
+    /// cancels the print panel.
+    ///
+    /// Returns `true` if the document was printed.
+    ///
+    /// This must be called on the main thread.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsprintoperation/run()).
+    #[inline]
+    #[doc(alias = "runOperation")]
+    pub fn run(&self) -> bool {
+        unsafe { _msg_send_any![self, runOperation => BOOL] }.into()
+    }
+}
@@ -0,0 +1,91 @@
+use crate::core::Arc;
+use crate::foundation::{
+    NSArray, NSNotificationCenter, NSNotificationName, NSNotificationObserver, NSRect,
+};
+use crate::objc::{ClassType, NSObject};
+
+#[cfg(feature = "core_foundation")]
+use crate::core_graphics::CGDirectDisplayID;
+#[cfg(feature = "core_foundation")]
+use crate::foundation::{NSDictionary, NSNumber, NSString};
+
+objc_subclass! {
+    /// A display attached to the system, as seen by AppKit.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsscreen).
+    pub class NSScreen: NSObject<'static>;
+}
+
+impl NSScreen {
+    /// Returns the screen containing the window with keyboard focus.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsscreen/main).
+    #[inline]
+    #[doc(alias = "mainScreen")]
+    pub fn main() -> Option<Arc<Self>> {
+        unsafe {
+            _msg_send_any![Self::class(), mainScreen => *const Self]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Returns all screens, starting with the one with the menu bar.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsscreen/screens).
+    #[inline]
+    pub fn screens() -> Arc<NSArray<NSScreen>> {
+        unsafe { Arc::retain_raw(_msg_send_any![Self::class(), screens => *const NSArray<Self>]) }
+    }
+
+    /// Returns the location and size of the screen, in points.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsscreen/frame).
+    #[inline]
+    pub fn frame(&self) -> NSRect {
+        unsafe { _msg_send_any![self, frame] }
+    }
+
+    /// Returns the Core Graphics identifier of the screen's display, whose
+    /// [color space](CGDirectDisplayID::color_space) and ICC profile can be
+    /// queried.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsscreen/devicedescription).
+    #[cfg(feature = "core_foundation")]
+    #[doc(alias = "deviceDescription")]
+    #[doc(alias = "NSScreenNumber")]
+    pub fn display_id(&self) -> CGDirectDisplayID {
+        let description: &NSDictionary<NSString, NSNumber> =
+            unsafe { _msg_send_any![self, deviceDescription] };
+
+        // Every screen has a number, which is its `CGDirectDisplayID`.
+        let number = description
+            .get(crate::ns_string!("NSScreenNumber"))
+            .expect("screen has no display number");
+        CGDirectDisplayID(number.unsigned_int_value())
+    }
+
+    /// Calls `f` with each screen whose color space changes, such as when
+    /// the user picks a different display profile, until the returned
+    /// observer is dropped.
+    ///
+    /// Notifications are posted on the main thread, so its run loop must be
+    /// running for `f` to be called.
+    ///
+    /// It is safe to panic within `f`. Panics will abort the process.
+    #[inline]
+    pub fn observe_color_space_changes<F>(f: F) -> NSNotificationObserver
+    where
+        F: Fn(&NSScreen) + Send + Sync + 'static,
+    {
+        NSNotificationCenter::default().add_observer(
+            Some(NSNotificationName::screen_color_space_did_change()),
+            None,
+            move |notification| {
+                if let Some(screen) = notification.object() {
+                    f(unsafe { &*(&*screen as *const NSObject as *const NSScreen) });
+                }
+            },
+        )
+    }
+}
@@ -0,0 +1,95 @@
+use super::NSView;
+use crate::core::Arc;
+use crate::foundation::NSRect;
+use crate::objc::{ClassType, Sel, BOOL};
+use std::ptr;
+
+objc_subclass! {
+    /// A view that scrolls its document view, such as an
+    /// [`NSCollectionView`](super::NSCollectionView).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsscrollview).
+    pub class NSScrollView: NSView;
+}
+
+impl NSScrollView {
+    /// Creates an empty scroll view with the location and size of `frame`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsview/init(frame:)).
+    #[inline]
+    #[doc(alias = "initWithFrame:")]
+    pub fn new(frame: NSRect) -> Arc<Self> {
+        unsafe {
+            let obj: Arc<Self> = Self::class().alloc();
+
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(
+                    obj: Arc<NSScrollView>,
+                    sel: Sel,
+                    frame: NSRect,
+                ) -> Arc<NSScrollView>;
+            }
+
+            objc_msgSend(obj, selector!(initWithFrame:), frame)
+        }
+    }
+
+    /// Returns the view that is scrolled.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsscrollview/documentview).
+    #[inline]
+    #[doc(alias = "documentView")]
+    pub fn document_view(&self) -> Option<&NSView> {
+        unsafe { _msg_send_any![self, documentView] }
+    }
+
+    /// Sets the view that is scrolled.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsscrollview/documentview).
+    #[inline]
+    #[doc(alias = "setDocumentView:")]
+    pub fn set_document_view(&self, view: Option<&NSView>) {
+        let view = match view {
+            Some(view) => view,
+            None => ptr::null(),
+        };
+        unsafe { _msg_send_any![self, setDocumentView: view => ()] }
+    }
+
+    /// Returns `true` if the scroll view has a vertical scroller.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsscrollview/hasverticalscroller).
+    #[inline]
+    #[doc(alias = "hasVerticalScroller")]
+    pub fn has_vertical_scroller(&self) -> bool {
+        unsafe { _msg_send_any![self, hasVerticalScroller => BOOL] }.into()
+    }
+
+    /// Sets whether the scroll view has a vertical scroller.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsscrollview/hasverticalscroller).
+    #[inline]
+    #[doc(alias = "setHasVerticalScroller:")]
+    pub fn set_has_vertical_scroller(&self, value: bool) {
+        unsafe { _msg_send_any![self, setHasVerticalScroller: BOOL::from(value) => ()] }
+    }
+
+    /// Returns `true` if the scroll view has a horizontal scroller.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsscrollview/hashorizontalscroller).
+    #[inline]
+    #[doc(alias = "hasHorizontalScroller")]
+    pub fn has_horizontal_scroller(&self) -> bool {
+        unsafe { _msg_send_any![self, hasHorizontalScroller => BOOL] }.into()
+    }
+
+    /// Sets whether the scroll view has a horizontal scroller.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsscrollview/hashorizontalscroller).
+    #[inline]
+    #[doc(alias = "setHasHorizontalScroller:")]
+    pub fn set_has_horizontal_scroller(&self, value: bool) {
+        unsafe { _msg_send_any![self, setHasHorizontalScroller: BOOL::from(value) => ()] }
+    }
+}
@@ -0,0 +1,404 @@
+use super::NSPasteboard;
+use crate::core::Arc;
+use crate::foundation::{NSArray, NSDictionary, NSString};
+use crate::objc::{Class, ClassBuilder, ClassType, NSObject, ObjectType, Sel};
+use std::{
+    error::Error,
+    ffi::{c_void, CStr, CString},
+    fmt,
+    panic::{self, AssertUnwindSafe},
+    process, ptr,
+    sync::{
+        atomic::{AtomicPtr, Ordering},
+        Mutex, Once,
+    },
+};
+
+/// Registers `provider` to handle the services of the port `name`, which is
+/// the `NSPortName` of the services in `Info.plist`.
+///
+/// Apps typically use their own name as the port name.
+///
+/// See [documentation](https://developer.apple.com/documentation/appkit/nsregisterservicesprovider(_:_:)).
+#[inline]
+#[allow(non_snake_case)]
+pub fn NSRegisterServicesProvider(provider: &NSObject, name: &NSString) {
+    extern "C" {
+        fn NSRegisterServicesProvider(provider: &NSObject, name: &NSString);
+    }
+    unsafe { NSRegisterServicesProvider(provider, name) }
+}
+
+/// Stops the provider of the port `name` from handling services.
+///
+/// See [documentation](https://developer.apple.com/documentation/appkit/nsunregisterservicesprovider(_:)).
+#[inline]
+#[allow(non_snake_case)]
+pub fn NSUnregisterServicesProvider(name: &NSString) {
+    extern "C" {
+        fn NSUnregisterServicesProvider(name: &NSString);
+    }
+    unsafe { NSUnregisterServicesProvider(name) }
+}
+
+/// Makes the system rescan the services of all apps, so that changes to
+/// `Info.plist` take effect without logging out.
+///
+/// See [documentation](https://developer.apple.com/documentation/appkit/nsupdatedynamicservices()).
+#[inline]
+#[allow(non_snake_case)]
+pub fn NSUpdateDynamicServices() {
+    extern "C" {
+        fn NSUpdateDynamicServices();
+    }
+    unsafe { NSUpdateDynamicServices() }
+}
+
+type ServiceFn =
+    dyn Fn(&NSPasteboard, Option<&NSString>) -> Result<(), Arc<NSString<'static>>> + Send + Sync;
+
+/// Declares a class whose services in the Services menu are implemented by
+/// closures, and creates the object that provides them.
+///
+/// Each service is named by its `NSMessage` in the `NSServices` array of
+/// `Info.plist`. When the user chooses the service, its closure is called on
+/// the main thread with the pasteboard holding the selected data and the
+/// `NSUserData` of the service. Results are returned by writing them to the
+/// pasteboard.
+///
+/// # Examples
+///
+/// ```no_run
+/// use fruity::app_kit::{NSPasteboardType, NSRegisterServicesProvider, NSServicesProviderBuilder};
+/// use fruity::foundation::NSString;
+/// use std::ffi::CStr;
+///
+/// let name = CStr::from_bytes_with_nul(b"MyServicesProvider\0").unwrap();
+/// let message = CStr::from_bytes_with_nul(b"uppercase\0").unwrap();
+///
+/// let provider = NSServicesProviderBuilder::new(name)
+///     .expect("class already exists")
+///     .service(message, |pasteboard, _user_data| {
+///         let kind = NSPasteboardType::string();
+///         let text = pasteboard
+///             .string_for_type(kind)
+///             .ok_or_else(|| NSString::from_str("No text was selected."))?;
+///
+///         pasteboard.clear_contents();
+///         pasteboard.set_string(&NSString::from_str(&text.to_string().to_uppercase()), kind);
+///         Ok(())
+///     })
+///     .register();
+///
+/// NSRegisterServicesProvider(&provider, &NSString::from_str("MyApp"));
+/// ```
+///
+/// See [documentation](https://developer.apple.com/documentation/appkit/nsapplication/servicesprovider).
+pub struct NSServicesProviderBuilder {
+    builder: ClassBuilder,
+    services: Vec<(Sel, Box<ServiceFn>)>,
+}
+
+impl fmt::Debug for NSServicesProviderBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("NSServicesProviderBuilder")
+            .field("builder", &self.builder)
+            .field(
+                "services",
+                &self.services.iter().map(|(sel, _)| sel).collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+impl NSServicesProviderBuilder {
+    /// Starts declaring a subclass of [`NSObject`] named `name`, or returns
+    /// [`None`] if a class with that name already exists.
+    #[inline]
+    pub fn new(name: &CStr) -> Option<Self> {
+        Some(Self {
+            builder: ClassBuilder::new(name, <NSObject as ClassType>::class())?,
+            services: Vec::new(),
+        })
+    }
+
+    /// Adds the service whose `NSMessage` is `message`, implemented by `f`.
+    ///
+    /// The closure receives the pasteboard and `NSUserData` of the service,
+    /// and returns a message that is shown to the user on failure.
+    ///
+    /// It is safe to panic within `f`. Panics will abort the process.
+    pub fn service<F>(mut self, message: &CStr, f: F) -> Self
+    where
+        F: Fn(&NSPasteboard, Option<&NSString>) -> Result<(), Arc<NSString<'static>>>,
+        F: Send + Sync + 'static,
+    {
+        let sel = service_selector(message);
+        self.services.retain(|(existing, _)| *existing != sel);
+        self.services.push((sel, Box::new(f)));
+        self
+    }
+
+    /// Registers the class with the Objective-C runtime and returns a new
+    /// instance of it to use as a services provider.
+    pub fn register(self) -> Arc<NSObject<'static>> {
+        let Self {
+            mut builder,
+            services,
+        } = self;
+
+        for (sel, _) in &services {
+            unsafe { builder.add_method(*sel, perform_service as PerformServiceImp) };
+        }
+
+        let class = builder.register();
+
+        {
+            let mut registry = match registry().lock() {
+                Ok(registry) => registry,
+                Err(poisoned) => poisoned.into_inner(),
+            };
+            let class = class as *const Class as usize;
+            for (sel, service) in services {
+                // Classes are never unregistered, so neither are their
+                // services.
+                let service: &'static ServiceFn = Box::leak(service);
+                registry.push((class, sel, service));
+            }
+        }
+
+        unsafe { class.alloc_init() }
+    }
+}
+
+/// Returns the selector that services with `message` are sent as.
+fn service_selector(message: &CStr) -> Sel {
+    let mut name = message.to_bytes().to_vec();
+    name.extend_from_slice(b":userData:error:");
+
+    let name = CString::new(name).expect("service message contains a null byte");
+    unsafe { Sel::register(name.as_ptr()) }
+}
+
+type Registry = Mutex<Vec<(usize, Sel, &'static ServiceFn)>>;
+
+/// Returns the services of each class registered by
+/// [`NSServicesProviderBuilder`].
+fn registry() -> &'static Registry {
+    static REGISTRY: AtomicPtr<Registry> = AtomicPtr::new(ptr::null_mut());
+    static INIT: Once = Once::new();
+
+    INIT.call_once(|| {
+        let registry = Box::into_raw(Box::new(Mutex::new(Vec::new())));
+        REGISTRY.store(registry, Ordering::Release);
+    });
+    unsafe { &*REGISTRY.load(Ordering::Acquire) }
+}
+
+type PerformServiceImp = extern "C" fn(
+    &NSObject<'static>,
+    Sel,
+    *const NSPasteboard,
+    *const NSString<'static>,
+    *mut c_void,
+);
+
+extern "C" fn perform_service(
+    this: &NSObject<'static>,
+    sel: Sel,
+    pasteboard: *const NSPasteboard,
+    user_data: *const NSString<'static>,
+    error_out: *mut c_void,
+) {
+    let service = {
+        let registry = match registry().lock() {
+            Ok(registry) => registry,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        let class = this.class() as *const Class as usize;
+        registry
+            .iter()
+            .find(|(registered, registered_sel, _)| *registered == class && *registered_sel == sel)
+            .map(|(_, _, service)| *service)
+            .expect("service was not registered by NSServicesProviderBuilder")
+    };
+
+    // Unwinding into Objective-C is undefined behavior.
+    let result = panic::catch_unwind(AssertUnwindSafe(|| unsafe {
+        service(&*pasteboard, user_data.as_ref())
+    }));
+
+    match result {
+        Ok(Ok(())) => {}
+        Ok(Err(error)) => unsafe {
+            extern "C" {
+                fn objc_autorelease(obj: *mut c_void) -> *mut c_void;
+            }
+
+            let out = error_out as *mut *const NSString<'static>;
+            if !out.is_null() {
+                *out = objc_autorelease(Arc::into_raw(error) as *mut c_void) as *const _;
+            }
+        },
+        Err(_) => process::abort(),
+    }
+}
+
+/// A problem with the `NSServices` of `Info.plist` found by
+/// [`validate_services_info`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum NSServicesInfoError {
+    /// The main bundle has no `NSServices` array.
+    MissingServices,
+
+    /// The service at `index` is not a dictionary.
+    InvalidService {
+        /// The index of the service in `NSServices`.
+        index: usize,
+    },
+
+    /// The service at `index` does not have the required `key`.
+    MissingKey {
+        /// The index of the service in `NSServices`.
+        index: usize,
+        /// The name of the missing key, such as `NSMessage`.
+        key: &'static str,
+    },
+
+    /// The provider has no method for the `NSMessage` of the service at
+    /// `index`.
+    UnhandledMessage {
+        /// The index of the service in `NSServices`.
+        index: usize,
+        /// The `NSMessage` of the service.
+        message: String,
+    },
+}
+
+impl fmt::Display for NSServicesInfoError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::MissingServices => f.write_str("Info.plist has no NSServices array"),
+            Self::InvalidService { index } => {
+                write!(f, "NSServices item {} is not a dictionary", index)
+            }
+            Self::MissingKey { index, key } => {
+                write!(f, "NSServices item {} is missing {}", index, key)
+            }
+            Self::UnhandledMessage { index, message } => write!(
+                f,
+                "NSServices item {} sends {}:userData:error:, which the provider does not implement",
+                index, message
+            ),
+        }
+    }
+}
+
+impl Error for NSServicesInfoError {}
+
+/// Checks that the `NSServices` of the main bundle's `Info.plist` are
+/// well-formed and handled by `provider`, returning every problem found.
+///
+/// The system silently ignores invalid services, so this helps catch
+/// mistakes during development. Each service must have `NSMessage`,
+/// `NSPortName`, and an `NSMenuItem` with a `default` title, and `provider`
+/// must respond to the selector of its `NSMessage`.
+///
+/// See [documentation](https://developer.apple.com/library/archive/documentation/Cocoa/Conceptual/SysServices/Articles/properties.html).
+pub fn validate_services_info(provider: &NSObject) -> Result<(), Vec<NSServicesInfoError>> {
+    let services = match main_bundle_services() {
+        Some(services) => services,
+        None => return Err(vec![NSServicesInfoError::MissingServices]),
+    };
+
+    let mut errors = Vec::new();
+
+    for (index, service) in services.iter().enumerate() {
+        if !service.is_kind_of_class(<NSDictionary<NSObject, NSObject> as ClassType>::class()) {
+            errors.push(NSServicesInfoError::InvalidService { index });
+            continue;
+        }
+
+        // SAFETY: Property list dictionaries have string keys.
+        let service: &NSDictionary<NSString, NSObject> =
+            unsafe { &*(service as *const NSObject as *const NSDictionary<NSString, NSObject>) };
+
+        let string_value = |key: &str| {
+            service
+                .get(&NSString::from_str(key))
+                .filter(|value| value.is_kind_of_class(<NSString as ClassType>::class()))
+                .map(|value| unsafe { &*(value as *const NSObject as *const NSString) })
+        };
+
+        match string_value("NSMessage") {
+            Some(message) => {
+                let message = message.to_string();
+                let selector = CString::new(message.as_bytes())
+                    .ok()
+                    .map(|message| service_selector(&message));
+
+                if !selector.is_some_and(|sel| provider.responds_to_selector(sel)) {
+                    errors.push(NSServicesInfoError::UnhandledMessage { index, message });
+                }
+            }
+            None => errors.push(NSServicesInfoError::MissingKey {
+                index,
+                key: "NSMessage",
+            }),
+        }
+
+        if string_value("NSPortName").is_none() {
+            errors.push(NSServicesInfoError::MissingKey {
+                index,
+                key: "NSPortName",
+            });
+        }
+
+        let has_menu_title = service
+            .get(&NSString::from_str("NSMenuItem"))
+            .filter(|item| {
+                item.is_kind_of_class(<NSDictionary<NSObject, NSObject> as ClassType>::class())
+            })
+            .is_some_and(|item| {
+                let item = unsafe {
+                    &*(item as *const NSObject as *const NSDictionary<NSString, NSObject>)
+                };
+                item.get(&NSString::from_str("default")).is_some()
+            });
+        if !has_menu_title {
+            errors.push(NSServicesInfoError::MissingKey {
+                index,
+                key: "NSMenuItem",
+            });
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Returns the `NSServices` array of the main bundle's `Info.plist`.
+fn main_bundle_services() -> Option<Arc<NSArray<NSObject<'static>>>> {
+    let bundle_class = unsafe { Class::get(CStr::from_bytes_with_nul_unchecked(b"NSBundle\0"))? };
+
+    unsafe {
+        let bundle: &NSObject = _msg_send_any![bundle_class, mainBundle];
+        let services: &NSObject = _msg_send_any![
+            bundle,
+            objectForInfoDictionaryKey: &*NSString::from_str("NSServices")
+            => *const NSObject
+        ]
+        .as_ref()?;
+
+        if services.is_kind_of_class(<NSArray<NSObject> as ClassType>::class()) {
+            Some(Arc::retain(
+                &*(services as *const NSObject as *const NSArray<NSObject>),
+            ))
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,439 @@
+use super::{
+    NSCellImagePosition, NSEvent, NSEventModifierFlags, NSEventType, NSImage, NSLineBreakMode,
+    NSView,
+};
+use crate::core::Arc;
+use crate::core_graphics::CGFloat;
+use crate::foundation::{NSAttributedString, NSString};
+use crate::objc::{Class, ClassType, LazyClass, NSInteger, NSObject, NSUInteger, Sel, BOOL};
+use std::{
+    ffi::{c_void, CStr},
+    fmt, ops,
+    panic::{self, AssertUnwindSafe},
+    process, ptr,
+};
+
+objc_subclass! {
+    /// The menu bar area that holds [`NSStatusItem`]s, on the right side of
+    /// the menu bar.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsstatusbar).
+    pub class NSStatusBar: NSObject<'static>;
+}
+
+impl NSStatusBar {
+    /// Returns the status bar of the menu bar.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsstatusbar/system).
+    #[inline]
+    #[doc(alias = "systemStatusBar")]
+    pub fn system() -> &'static Self {
+        unsafe { _msg_send_any![Self::class(), systemStatusBar] }
+    }
+
+    /// Creates an item of `length` points in the status bar, such as
+    /// [`NSStatusItem::VARIABLE_LENGTH`].
+    ///
+    /// The item stays in the status bar until it is
+    /// [removed](Self::remove_status_item) or deallocated.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsstatusbar/statusitem(withlength:)).
+    #[inline]
+    #[doc(alias = "statusItemWithLength:")]
+    pub fn status_item(&self, length: CGFloat) -> Arc<NSStatusItem> {
+        unsafe {
+            Arc::retain_raw(
+                _msg_send_any![self, statusItemWithLength: length => *const NSStatusItem],
+            )
+        }
+    }
+
+    /// Removes `item` from the status bar.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsstatusbar/removestatusitem(_:)).
+    #[inline]
+    #[doc(alias = "removeStatusItem:")]
+    pub fn remove_status_item(&self, item: &NSStatusItem) {
+        unsafe { _msg_send_any![self, removeStatusItem: item => ()] }
+    }
+}
+
+objc_subclass! {
+    /// An item in the [`NSStatusBar`], such as the icon of a menu bar app.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsstatusitem).
+    pub class NSStatusItem: NSObject<'static>;
+}
+
+impl NSStatusItem {
+    /// The length of an item that fits its content.
+    #[doc(alias = "NSVariableStatusItemLength")]
+    pub const VARIABLE_LENGTH: CGFloat = -1.0;
+
+    /// The length of an item that is as wide as the menu bar is tall.
+    #[doc(alias = "NSSquareStatusItemLength")]
+    pub const SQUARE_LENGTH: CGFloat = -2.0;
+
+    /// Returns the button that displays the item, which popovers can be
+    /// shown relative to.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsstatusitem/button).
+    #[inline]
+    pub fn button(&self) -> Option<&NSView> {
+        unsafe { _msg_send_any![self, button] }
+    }
+
+    /// Returns the length of the item in points.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsstatusitem/length).
+    #[inline]
+    pub fn length(&self) -> CGFloat {
+        unsafe { _msg_send_any![self, length] }
+    }
+
+    /// Sets the length of the item in points.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsstatusitem/length).
+    #[inline]
+    #[doc(alias = "setLength:")]
+    pub fn set_length(&self, length: CGFloat) {
+        unsafe { _msg_send_any![self, setLength: length => ()] }
+    }
+
+    /// Returns `true` if the item is shown in the status bar.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsstatusitem/isvisible).
+    #[inline]
+    #[doc(alias = "isVisible")]
+    pub fn is_visible(&self) -> bool {
+        unsafe { _msg_send_any![self, isVisible => BOOL] }.into()
+    }
+
+    /// Sets whether the item is shown in the status bar.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsstatusitem/isvisible).
+    #[inline]
+    #[doc(alias = "setVisible:")]
+    pub fn set_visible(&self, value: bool) {
+        unsafe { _msg_send_any![self, setVisible: BOOL::from(value) => ()] }
+    }
+
+    /// Sets the text of the item's button.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsbutton/title).
+    #[inline]
+    #[doc(alias = "setTitle:")]
+    pub fn set_title(&self, title: &NSString) {
+        if let Some(button) = self.button() {
+            unsafe { _msg_send_any![button, setTitle: title => ()] }
+        }
+    }
+
+    /// Sets the image of the item's button, which should be a template image
+    /// so that it adapts to the menu bar appearance.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsbutton/image).
+    #[inline]
+    #[doc(alias = "setImage:")]
+    pub fn set_image(&self, image: Option<&NSImage>) {
+        let image = match image {
+            Some(image) => image,
+            None => ptr::null(),
+        };
+        if let Some(button) = self.button() {
+            unsafe { _msg_send_any![button, setImage: image => ()] }
+        }
+    }
+
+    /// Sets the styled text of the item's button, such as text in a
+    /// [monospaced digit font](super::NSFont::monospaced_digit_system) that
+    /// does not shift as numbers change.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsbutton/attributedtitle).
+    #[inline]
+    #[doc(alias = "setAttributedTitle:")]
+    pub fn set_attributed_title(&self, title: &NSAttributedString) {
+        if let Some(button) = self.button() {
+            unsafe { _msg_send_any![button, setAttributedTitle: title => ()] }
+        }
+    }
+
+    /// Sets where the item's button draws its image relative to its title,
+    /// for showing both at once.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsbutton/imageposition).
+    #[inline]
+    #[doc(alias = "setImagePosition:")]
+    pub fn set_image_position(&self, position: NSCellImagePosition) {
+        if let Some(button) = self.button() {
+            unsafe { _msg_send_any![button, setImagePosition: position => ()] }
+        }
+    }
+
+    /// Sets how the title of the item's button is truncated when it does not
+    /// fit.
+    ///
+    /// Titles only need truncating when the item has a fixed
+    /// [length](Self::set_length), since items of
+    /// [`VARIABLE_LENGTH`](Self::VARIABLE_LENGTH) grow to fit their title.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nscontrol/linebreakmode).
+    #[inline]
+    #[doc(alias = "setLineBreakMode:")]
+    pub fn set_line_break_mode(&self, mode: NSLineBreakMode) {
+        if let Some(button) = self.button() {
+            unsafe { _msg_send_any![button, setLineBreakMode: mode => ()] }
+        }
+    }
+
+    /// Returns what the user is allowed to do with the item.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsstatusitem/behavior-swift.property).
+    #[inline]
+    pub fn behavior(&self) -> NSStatusItemBehavior {
+        unsafe { _msg_send_any![self, behavior] }
+    }
+
+    /// Sets what the user is allowed to do with the item.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsstatusitem/behavior-swift.property).
+    #[inline]
+    #[doc(alias = "setBehavior:")]
+    pub fn set_behavior(&self, behavior: NSStatusItemBehavior) {
+        unsafe { _msg_send_any![self, setBehavior: behavior => ()] }
+    }
+
+    /// Calls `handler` on the main thread each time the item is clicked,
+    /// until the returned value is dropped.
+    ///
+    /// This is typically used to toggle a
+    /// [popover](super::NSPopover::toggle_relative_to_status_item). Setting
+    /// another handler replaces this one.
+    ///
+    /// Only left clicks are handled. Use
+    /// [`set_mouse_click_handler`](Self::set_mouse_click_handler) to also
+    /// handle right clicks.
+    ///
+    /// It is safe to panic within `handler`. Panics will abort the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nscontrol/action).
+    #[doc(alias = "setTarget:")]
+    #[doc(alias = "setAction:")]
+    pub fn set_click_handler<F>(&self, mut handler: F) -> NSStatusItemClickHandler
+    where
+        F: FnMut() + Send + 'static,
+    {
+        self.set_handler(LEFT_MOUSE_UP_MASK, Box::new(move |_| handler()))
+    }
+
+    /// Calls `handler` on the main thread with the kind of each left or
+    /// right click on the item, until the returned value is dropped.
+    ///
+    /// This lets an app show its content on left click and a menu of
+    /// settings on right click or Option-click, as many menu bar apps do.
+    /// Setting another handler replaces this one.
+    ///
+    /// It is safe to panic within `handler`. Panics will abort the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nscontrol/sendaction(on:)).
+    #[doc(alias = "sendActionOn:")]
+    pub fn set_mouse_click_handler<F>(&self, handler: F) -> NSStatusItemClickHandler
+    where
+        F: FnMut(NSStatusItemClick) + Send + 'static,
+    {
+        self.set_handler(LEFT_MOUSE_UP_MASK | RIGHT_MOUSE_UP_MASK, Box::new(handler))
+    }
+
+    fn set_handler(
+        &self,
+        mask: NSEventMask,
+        handler: Box<ClickHandler>,
+    ) -> NSStatusItemClickHandler {
+        let handler: Box<Box<ClickHandler>> = Box::new(handler);
+        let target: Arc<NSObject<'static>> = unsafe { target_class().alloc_init() };
+
+        unsafe {
+            *handler_slot(&target) = &*handler as *const Box<ClickHandler> as *mut c_void;
+            if let Some(button) = self.button() {
+                _msg_send_any![button, setTarget: &*target => ()];
+                _msg_send_any![button, setAction: selector!(statusItemClicked:) => ()];
+                _msg_send_any![button, sendActionOn: mask => NSInteger];
+            }
+        }
+
+        NSStatusItemClickHandler {
+            item: Arc::retain(self),
+            target,
+            handler,
+        }
+    }
+}
+
+/// What the user is allowed to do with an [`NSStatusItem`].
+///
+/// See [documentation](https://developer.apple.com/documentation/appkit/nsstatusitem/behavior-swift.struct).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NSStatusItemBehavior(pub NSUInteger);
+
+impl ops::BitOr for NSStatusItemBehavior {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl NSStatusItemBehavior {
+    /// The item can be removed by Command-dragging it out of the menu bar.
+    #[doc(alias = "NSStatusItemBehaviorRemovalAllowed")]
+    pub const REMOVAL_ALLOWED: Self = Self(1 << 1);
+
+    /// The app terminates when the item is removed by the user.
+    #[doc(alias = "NSStatusItemBehaviorTerminationOnRemoval")]
+    pub const TERMINATION_ON_REMOVAL: Self = Self(1 << 2);
+
+    /// Returns `true` if all flags in `other` are set in `self`.
+    #[inline]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+/// The kind of click on an [`NSStatusItem`], passed to
+/// [`NSStatusItem::set_mouse_click_handler`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum NSStatusItemClick {
+    /// A click with the left mouse button.
+    Left,
+
+    /// A click with the right mouse button, or a Control-click, which
+    /// conventionally shows a menu.
+    Right,
+
+    /// An Option-click with the left mouse button, which conventionally
+    /// shows extra or advanced options.
+    OptionLeft,
+}
+
+impl NSStatusItemClick {
+    /// Returns the kind of click that `event` is.
+    fn from_event(event: &NSEvent) -> Self {
+        let flags = event.modifier_flags();
+        if event.event_type() == NSEventType::RIGHT_MOUSE_UP
+            || flags.contains(NSEventModifierFlags::CONTROL)
+        {
+            Self::Right
+        } else if flags.contains(NSEventModifierFlags::OPTION) {
+            Self::OptionLeft
+        } else {
+            Self::Left
+        }
+    }
+
+    /// Returns the kind of the click that is being handled, based on the
+    /// event that the application is processing.
+    fn current() -> Self {
+        let app_class =
+            match Class::get(unsafe { CStr::from_bytes_with_nul_unchecked(b"NSApplication\0") }) {
+                Some(class) => class,
+                None => return Self::Left,
+            };
+        unsafe {
+            let app: &NSObject = _msg_send_any![app_class, sharedApplication];
+            let event: Option<&NSEvent> = _msg_send_any![app, currentEvent];
+            event.map_or(Self::Left, Self::from_event)
+        }
+    }
+}
+
+/// `NSEventMask`, whose type is `unsigned long long` on all platforms.
+type NSEventMask = u64;
+
+const LEFT_MOUSE_UP_MASK: NSEventMask = 1 << 2;
+const RIGHT_MOUSE_UP_MASK: NSEventMask = 1 << 4;
+
+type ClickHandler = dyn FnMut(NSStatusItemClick) + Send;
+
+/// A click handler set by [`NSStatusItem::set_click_handler`] or
+/// [`NSStatusItem::set_mouse_click_handler`], which is removed when dropped.
+#[must_use = "The handler is immediately removed if unused"]
+pub struct NSStatusItemClickHandler {
+    item: Arc<NSStatusItem>,
+    target: Arc<NSObject<'static>>,
+    // Referenced by `target` until dropped.
+    #[allow(dead_code)]
+    handler: Box<Box<ClickHandler>>,
+}
+
+impl fmt::Debug for NSStatusItemClickHandler {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("NSStatusItemClickHandler").finish()
+    }
+}
+
+impl Drop for NSStatusItemClickHandler {
+    fn drop(&mut self) {
+        unsafe {
+            *handler_slot(&self.target) = ptr::null_mut();
+
+            // Only detach if the handler was not replaced.
+            if let Some(button) = self.item.button() {
+                let current = _msg_send_any![button, target => *const NSObject];
+                if ptr::eq(current, &*self.target) {
+                    _msg_send_any![button, setTarget: ptr::null::<NSObject>() => ()];
+                    _msg_send_any![button, sendActionOn: LEFT_MOUSE_UP_MASK => NSInteger];
+                }
+            }
+        }
+    }
+}
+
+/// The name of the instance variable that points to a `Box<ClickHandler>`.
+const HANDLER_IVAR: &[u8] = b"rustClickHandler\0";
+
+#[inline]
+fn handler_slot(target: &NSObject<'static>) -> *mut *mut c_void {
+    let name = unsafe { CStr::from_bytes_with_nul_unchecked(HANDLER_IVAR) };
+    target.ivar_ptr::<*mut c_void>(name)
+}
+
+/// Returns the class used for action targets, registering it with the
+/// Objective-C runtime on first use.
+fn target_class() -> &'static Class {
+    static CLASS: LazyClass = LazyClass::new("FruityStatusItemTarget");
+
+    CLASS.get(NSObject::class(), |builder| {
+        builder.add_ivar::<*mut c_void>(CStr::from_bytes_with_nul(HANDLER_IVAR).unwrap());
+
+        unsafe {
+            builder.add_method(
+                selector!(statusItemClicked:),
+                status_item_clicked as StatusItemClicked,
+            );
+        }
+    })
+}
+
+type StatusItemClicked = extern "C" fn(&NSObject<'static>, Sel, *const NSObject<'static>);
+
+extern "C" fn status_item_clicked(
+    this: &NSObject<'static>,
+    _: Sel,
+    _sender: *const NSObject<'static>,
+) {
+    unsafe {
+        let handler = *handler_slot(this) as *mut Box<ClickHandler>;
+        if handler.is_null() {
+            return;
+        }
+
+        let click = NSStatusItemClick::current();
+
+        // Unwinding into Objective-C is undefined behavior.
+        if panic::catch_unwind(AssertUnwindSafe(|| (*handler)(click))).is_err() {
+            process::abort();
+        }
+    }
+}
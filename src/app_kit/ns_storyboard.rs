@@ -0,0 +1,141 @@
+use super::ns_nib::{bundle_at, downcast};
+use crate::core::Arc;
+use crate::foundation::{NSException, NSString, NSURL};
+use crate::objc::{ClassType, NSObject};
+use std::ptr;
+
+objc_subclass! {
+    /// An Interface Builder storyboard, from which view and window
+    /// controllers are instantiated.
+    ///
+    /// Controllers are typically looked up by the storyboard ID set in
+    /// Interface Builder, and cast to the expected type:
+    ///
+    /// ```no_run
+    /// use fruity::app_kit::{NSStoryboard, NSWindowController};
+    ///
+    /// let storyboard = NSStoryboard::new(fruity::ns_string!("Main")).expect("no storyboard");
+    ///
+    /// let preferences = storyboard
+    ///     .instantiate_controller_of::<NSWindowController>(fruity::ns_string!("Preferences"))
+    ///     .expect("no controller with identifier")
+    ///     .expect("not a window controller");
+    /// preferences.show_window();
+    /// ```
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsstoryboard).
+    pub class NSStoryboard: NSObject<'static>;
+}
+
+impl NSStoryboard {
+    /// Returns the storyboard set as `NSMainStoryboardFile` in the app's
+    /// `Info.plist`, if any.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsstoryboard/main).
+    #[inline]
+    #[doc(alias = "mainStoryboard")]
+    pub fn main() -> Option<&'static Self> {
+        unsafe { _msg_send_any![Self::class(), mainStoryboard] }
+    }
+
+    /// Returns the storyboard named `name` in the main bundle.
+    ///
+    /// # Errors
+    ///
+    /// Returns the exception raised if the storyboard does not exist.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsstoryboard/init(name:bundle:)).
+    #[inline]
+    #[doc(alias = "storyboardWithName:bundle:")]
+    pub fn new(name: &NSString) -> Result<Arc<Self>, Arc<NSException>> {
+        unsafe { Self::with_name(name, ptr::null()) }
+    }
+
+    /// Returns the storyboard named `name` in the bundle at `bundle_url`, or
+    /// [`None`] if there is no bundle at `bundle_url`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the exception raised if the storyboard does not exist.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsstoryboard/init(name:bundle:)).
+    #[inline]
+    #[doc(alias = "storyboardWithName:bundle:")]
+    pub fn in_bundle_at(
+        name: &NSString,
+        bundle_url: &NSURL,
+    ) -> Option<Result<Arc<Self>, Arc<NSException>>> {
+        let bundle = bundle_at(bundle_url)?;
+        Some(unsafe { Self::with_name(name, &*bundle) })
+    }
+
+    unsafe fn with_name(
+        name: &NSString,
+        bundle: *const NSObject<'static>,
+    ) -> Result<Arc<Self>, Arc<NSException>> {
+        NSException::catch(|| {
+            Arc::retain_raw(_msg_send_any![
+                Self::class(),
+                storyboardWithName: name
+                bundle: bundle
+                => *const Self
+            ])
+        })
+    }
+
+    /// Creates the controller marked as the initial controller, if any.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsstoryboard/instantiateinitialcontroller()).
+    #[inline]
+    #[doc(alias = "instantiateInitialController")]
+    pub fn instantiate_initial_controller(&self) -> Option<Arc<NSObject<'static>>> {
+        unsafe {
+            _msg_send_any![self, instantiateInitialController => *const NSObject<'static>]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Creates the initial controller if it is an instance of `T`, such as
+    /// [`NSWindowController`](super::NSWindowController).
+    #[inline]
+    pub fn instantiate_initial_controller_of<T: ClassType<'static>>(&self) -> Option<Arc<T>> {
+        downcast(&*self.instantiate_initial_controller()?)
+    }
+
+    /// Creates the controller with the storyboard ID `identifier`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the exception raised if no controller has `identifier`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsstoryboard/instantiatecontroller(withidentifier:)).
+    #[inline]
+    #[doc(alias = "instantiateControllerWithIdentifier:")]
+    pub fn instantiate_controller(
+        &self,
+        identifier: &NSString,
+    ) -> Result<Arc<NSObject<'static>>, Arc<NSException>> {
+        NSException::catch(|| unsafe {
+            Arc::retain_raw(_msg_send_any![
+                self,
+                instantiateControllerWithIdentifier: identifier
+                => *const NSObject<'static>
+            ])
+        })
+    }
+
+    /// Creates the controller with the storyboard ID `identifier`, or returns
+    /// [`None`] if it is not an instance of `T`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the exception raised if no controller has `identifier`.
+    #[inline]
+    pub fn instantiate_controller_of<T: ClassType<'static>>(
+        &self,
+        identifier: &NSString,
+    ) -> Result<Option<Arc<T>>, Arc<NSException>> {
+        Ok(downcast(&*self.instantiate_controller(identifier)?))
+    }
+}
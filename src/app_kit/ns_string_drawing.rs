@@ -0,0 +1,129 @@
+use crate::core_graphics::CGFloat;
+use crate::foundation::{NSAttributedString, NSAttributedStringKey, NSDictionary, NSRect, NSSize};
+use crate::foundation::{NSPoint, NSString};
+use crate::objc::{NSInteger, NSObject};
+use std::ops;
+
+/// Options for measuring text with [`NSAttributedString::bounding_rect`].
+///
+/// See [documentation](https://developer.apple.com/documentation/appkit/nsstring/drawingoptions).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NSStringDrawingOptions(pub NSInteger);
+
+impl ops::BitOr for NSStringDrawingOptions {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl NSStringDrawingOptions {
+    /// Lays out the text as a paragraph that wraps within the given width,
+    /// with the rectangle's origin at the top-left of the first line.
+    ///
+    /// Without this, the text is measured as a single line whose origin is
+    /// on the baseline.
+    #[doc(alias = "NSStringDrawingUsesLineFragmentOrigin")]
+    pub const USES_LINE_FRAGMENT_ORIGIN: Self = Self(1 << 0);
+
+    /// Includes the font's [leading](super::NSFont::leading) in line
+    /// heights.
+    #[doc(alias = "NSStringDrawingUsesFontLeading")]
+    pub const USES_FONT_LEADING: Self = Self(1 << 1);
+
+    /// Measures the bounds of the drawn glyphs rather than their typographic
+    /// bounds.
+    #[doc(alias = "NSStringDrawingUsesDeviceMetrics")]
+    pub const USES_DEVICE_METRICS: Self = Self(1 << 3);
+
+    /// Truncates and adds an ellipsis to the last line if the text does not
+    /// fit. Ignored without
+    /// [`USES_LINE_FRAGMENT_ORIGIN`](Self::USES_LINE_FRAGMENT_ORIGIN).
+    #[doc(alias = "NSStringDrawingTruncatesLastVisibleLine")]
+    pub const TRUNCATES_LAST_VISIBLE_LINE: Self = Self(1 << 5);
+
+    /// Returns `true` if all options in `other` are set in `self`.
+    #[inline]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+/// Measuring text.
+///
+/// Sizes are fractional, so round them up with [`f64::ceil`] before using
+/// them as the size of a view or [status item](super::NSStatusItem::set_length).
+impl NSAttributedString {
+    /// Returns the size of the text when drawn on a single line, or of its
+    /// lines if it contains line breaks.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsattributedstring/size()).
+    #[inline]
+    pub fn size(&self) -> NSSize {
+        unsafe { _msg_send_any![self, size] }
+    }
+
+    /// Returns the rectangle that the text occupies when laid out within
+    /// `size`, using `options`.
+    ///
+    /// Pass a height of 0 to get the height of text wrapped at a width, with
+    /// [`NSStringDrawingOptions::USES_LINE_FRAGMENT_ORIGIN`].
+    ///
+    /// Without that option, the origin of the rectangle is relative to the
+    /// baseline, so its `y` is the [descender](super::NSFont::descender)
+    /// of the text, which is negative.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsattributedstring/boundingrect(with:options:context:)).
+    #[inline]
+    #[doc(alias = "boundingRectWithSize:options:context:")]
+    pub fn bounding_rect(&self, size: NSSize, options: NSStringDrawingOptions) -> NSRect {
+        let context: *const NSObject = std::ptr::null();
+        unsafe {
+            _msg_send_any![
+                self,
+                boundingRectWithSize: size
+                options: options
+                context: context
+                => NSRect
+            ]
+        }
+    }
+
+    /// Returns the height of the text when wrapped at `width` points.
+    #[inline]
+    pub fn height_for_width(&self, width: CGFloat) -> CGFloat {
+        let size = NSSize { width, height: 0.0 };
+        let options = NSStringDrawingOptions::USES_LINE_FRAGMENT_ORIGIN
+            | NSStringDrawingOptions::USES_FONT_LEADING;
+        self.bounding_rect(size, options).size.height
+    }
+
+    /// Draws the text on a single line with its origin at `point` in the
+    /// current graphics context, such as within a custom view's drawing.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsattributedstring/draw(at:)).
+    #[inline]
+    #[doc(alias = "drawAtPoint:")]
+    pub fn draw_at(&self, point: NSPoint) {
+        unsafe { _msg_send_any![self, drawAtPoint: point => ()] }
+    }
+}
+
+/// Measuring text.
+impl NSString<'_> {
+    /// Returns the size of the string when drawn on a single line with
+    /// `attributes`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsstring/size(withattributes:)).
+    #[inline]
+    #[doc(alias = "sizeWithAttributes:")]
+    pub fn size_with_attributes(
+        &self,
+        attributes: &NSDictionary<NSAttributedStringKey, NSObject<'static>>,
+    ) -> NSSize {
+        unsafe { _msg_send_any![self, sizeWithAttributes: attributes => NSSize] }
+    }
+}
@@ -0,0 +1,779 @@
+use super::{
+    NSAccessibilityAction, NSAccessibilityRole, NSBitmapImageRep, NSEvent, NSImage,
+    NSLayoutDimension, NSLayoutXAxisAnchor, NSLayoutYAxisAnchor, NSTouchTypeMask,
+};
+use crate::core::Arc;
+use crate::core_graphics::CGFloat;
+use crate::foundation::{NSData, NSRect, NSString};
+use crate::objc::{Class, ClassBuilder, ClassType, NSObject, ObjectType, Sel, BOOL};
+use std::{
+    ffi::{c_void, CStr},
+    fmt,
+    panic::{self, AssertUnwindSafe},
+    process, ptr,
+    sync::{
+        atomic::{AtomicPtr, Ordering},
+        Mutex, Once,
+    },
+};
+
+objc_subclass! {
+    /// A rectangular region of a window that draws content and handles
+    /// events.
+    ///
+    /// Views are positioned either by their [frame](Self::frame) or by
+    /// [Auto Layout](super::NSLayoutConstraint) constraints between their
+    /// anchors.
+    ///
+    /// Views whose accessibility is provided by Rust closures are declared
+    /// with [`NSViewClassBuilder`].
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsview).
+    pub class NSView: NSObject<'static>;
+}
+
+impl NSView {
+    /// Creates an empty view with the location and size of `frame`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsview/init(frame:)).
+    #[inline]
+    #[doc(alias = "initWithFrame:")]
+    pub fn new(frame: NSRect) -> Arc<Self> {
+        Self::with_class(<Self as ClassType>::class(), frame)
+    }
+
+    /// Creates an instance of `class`, such as one declared with
+    /// [`NSViewClassBuilder`], with the location and size of `frame`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `class` is not a subclass of `NSView`.
+    #[inline]
+    #[doc(alias = "initWithFrame:")]
+    pub fn with_class(class: &Class, frame: NSRect) -> Arc<Self> {
+        assert!(
+            class.is_subclass_of(<Self as ClassType>::class()),
+            "{:?} is not a subclass of NSView",
+            class.name(),
+        );
+        unsafe {
+            let obj: Arc<Self> = class.alloc();
+
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(obj: Arc<NSView>, sel: Sel, frame: NSRect) -> Arc<NSView>;
+            }
+
+            objc_msgSend(obj, selector!(initWithFrame:), frame)
+        }
+    }
+
+    /// Returns the location and size of `self` in its superview's
+    /// coordinate system.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsview/frame).
+    #[inline]
+    pub fn frame(&self) -> NSRect {
+        unsafe { _msg_send_any![self, frame] }
+    }
+
+    /// Sets the location and size of `self` in its superview's coordinate
+    /// system.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsview/frame).
+    #[inline]
+    #[doc(alias = "setFrame:")]
+    pub fn set_frame(&self, frame: NSRect) {
+        unsafe { _msg_send_any![self, setFrame: frame => ()] }
+    }
+
+    /// Returns the opacity of `self`, from 0 (transparent) to 1 (opaque).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsview/alphavalue).
+    #[inline]
+    #[doc(alias = "alphaValue")]
+    pub fn alpha_value(&self) -> CGFloat {
+        unsafe { _msg_send_any![self, alphaValue] }
+    }
+
+    /// Sets the opacity of `self`, from 0 (transparent) to 1 (opaque).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsview/alphavalue).
+    #[inline]
+    #[doc(alias = "setAlphaValue:")]
+    pub fn set_alpha_value(&self, alpha: CGFloat) {
+        unsafe { _msg_send_any![self, setAlphaValue: alpha => ()] }
+    }
+
+    /// Returns a proxy of `self` whose property changes, such as
+    /// [`set_frame`](Self::set_frame) and
+    /// [`set_alpha_value`](Self::set_alpha_value), are animated with the
+    /// settings of the current [`NSAnimationContext`](super::NSAnimationContext).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsanimatablepropertycontainer/animator()).
+    #[inline]
+    pub fn animator(&self) -> Arc<Self> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, animator => *const Self]) }
+    }
+
+    /// Returns the location and size of `self` in its own coordinate system.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsview/bounds).
+    #[inline]
+    pub fn bounds(&self) -> NSRect {
+        unsafe { _msg_send_any![self, bounds] }
+    }
+
+    /// Returns the view that contains `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsview/superview).
+    #[inline]
+    pub fn superview(&self) -> Option<&NSView> {
+        unsafe { _msg_send_any![self, superview] }
+    }
+
+    /// Adds `view` in front of the other subviews of `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsview/addsubview(_:)).
+    #[inline]
+    #[doc(alias = "addSubview:")]
+    pub fn add_subview(&self, view: &NSView) {
+        unsafe { _msg_send_any![self, addSubview: view => ()] }
+    }
+
+    /// Removes `self` from its superview, along with the constraints that
+    /// relate it to views outside of it.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsview/removefromsuperview()).
+    #[inline]
+    #[doc(alias = "removeFromSuperview")]
+    pub fn remove_from_superview(&self) {
+        unsafe { _msg_send_any![self, removeFromSuperview => ()] }
+    }
+
+    /// Returns `true` if the frame of `self` is converted into constraints,
+    /// which is the default for views created in code.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsview/translatesautoresizingmaskintoconstraints).
+    #[inline]
+    #[doc(alias = "translatesAutoresizingMaskIntoConstraints")]
+    pub fn translates_autoresizing_mask_into_constraints(&self) -> bool {
+        unsafe { _msg_send_any![self, translatesAutoresizingMaskIntoConstraints => BOOL] }.into()
+    }
+
+    /// Sets whether the frame of `self` is converted into constraints.
+    ///
+    /// This must be `false` for views positioned by constraints, or else the
+    /// converted frame conflicts with them.
+    /// [`NSLayoutConstraintSet`](super::NSLayoutConstraintSet) does this for
+    /// the views it positions.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsview/translatesautoresizingmaskintoconstraints).
+    #[inline]
+    #[doc(alias = "setTranslatesAutoresizingMaskIntoConstraints:")]
+    pub fn set_translates_autoresizing_mask_into_constraints(&self, value: bool) {
+        unsafe {
+            _msg_send_any![
+                self,
+                setTranslatesAutoresizingMaskIntoConstraints: BOOL::from(value)
+                => ()
+            ]
+        }
+    }
+
+    /// Returns the anchor of the leading edge, which is the left edge in
+    /// left-to-right languages.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsview/leadinganchor).
+    #[inline]
+    #[doc(alias = "leadingAnchor")]
+    pub fn leading_anchor(&self) -> &NSLayoutXAxisAnchor {
+        unsafe { _msg_send_any![self, leadingAnchor] }
+    }
+
+    /// Returns the anchor of the trailing edge, which is the right edge in
+    /// left-to-right languages.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsview/trailinganchor).
+    #[inline]
+    #[doc(alias = "trailingAnchor")]
+    pub fn trailing_anchor(&self) -> &NSLayoutXAxisAnchor {
+        unsafe { _msg_send_any![self, trailingAnchor] }
+    }
+
+    /// Returns the anchor of the left edge.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsview/leftanchor).
+    #[inline]
+    #[doc(alias = "leftAnchor")]
+    pub fn left_anchor(&self) -> &NSLayoutXAxisAnchor {
+        unsafe { _msg_send_any![self, leftAnchor] }
+    }
+
+    /// Returns the anchor of the right edge.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsview/rightanchor).
+    #[inline]
+    #[doc(alias = "rightAnchor")]
+    pub fn right_anchor(&self) -> &NSLayoutXAxisAnchor {
+        unsafe { _msg_send_any![self, rightAnchor] }
+    }
+
+    /// Returns the anchor of the horizontal center.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsview/centerxanchor).
+    #[inline]
+    #[doc(alias = "centerXAnchor")]
+    pub fn center_x_anchor(&self) -> &NSLayoutXAxisAnchor {
+        unsafe { _msg_send_any![self, centerXAnchor] }
+    }
+
+    /// Returns the anchor of the top edge.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsview/topanchor).
+    #[inline]
+    #[doc(alias = "topAnchor")]
+    pub fn top_anchor(&self) -> &NSLayoutYAxisAnchor {
+        unsafe { _msg_send_any![self, topAnchor] }
+    }
+
+    /// Returns the anchor of the bottom edge.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsview/bottomanchor).
+    #[inline]
+    #[doc(alias = "bottomAnchor")]
+    pub fn bottom_anchor(&self) -> &NSLayoutYAxisAnchor {
+        unsafe { _msg_send_any![self, bottomAnchor] }
+    }
+
+    /// Returns the anchor of the vertical center.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsview/centeryanchor).
+    #[inline]
+    #[doc(alias = "centerYAnchor")]
+    pub fn center_y_anchor(&self) -> &NSLayoutYAxisAnchor {
+        unsafe { _msg_send_any![self, centerYAnchor] }
+    }
+
+    /// Returns the anchor of the width.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsview/widthanchor).
+    #[inline]
+    #[doc(alias = "widthAnchor")]
+    pub fn width_anchor(&self) -> &NSLayoutDimension {
+        unsafe { _msg_send_any![self, widthAnchor] }
+    }
+
+    /// Returns the anchor of the height.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsview/heightanchor).
+    #[inline]
+    #[doc(alias = "heightAnchor")]
+    pub fn height_anchor(&self) -> &NSLayoutDimension {
+        unsafe { _msg_send_any![self, heightAnchor] }
+    }
+
+    /// Returns the kinds of touches that `self` receives.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsview/allowedtouchtypes).
+    #[inline]
+    #[doc(alias = "allowedTouchTypes")]
+    pub fn allowed_touch_types(&self) -> NSTouchTypeMask {
+        unsafe { _msg_send_any![self, allowedTouchTypes] }
+    }
+
+    /// Sets the kinds of touches that `self` receives, which must include
+    /// [`NSTouchTypeMask::INDIRECT`] for trackpad touches.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsview/allowedtouchtypes).
+    #[inline]
+    #[doc(alias = "setAllowedTouchTypes:")]
+    pub fn set_allowed_touch_types(&self, types: NSTouchTypeMask) {
+        unsafe { _msg_send_any![self, setAllowedTouchTypes: types => ()] }
+    }
+
+    /// Returns `rect` of `self` and its subviews rendered as PDF data.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsview/datawithpdf(inside:)).
+    #[inline]
+    #[doc(alias = "dataWithPDFInsideRect:")]
+    pub fn pdf_data(&self, rect: NSRect) -> Arc<NSData> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![
+                self,
+                dataWithPDFInsideRect: rect
+                => *const NSData
+            ])
+        }
+    }
+
+    /// Renders `rect` of `self` and its subviews into a bitmap at the
+    /// resolution of the window's screen, or returns [`None`] if the bitmap
+    /// could not be created.
+    ///
+    /// This draws the views directly rather than reading the screen, so it
+    /// also works for views in windows that are hidden, minimized, or were
+    /// never shown.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsview/cachedisplay(in:to:)).
+    #[doc(alias = "bitmapImageRepForCachingDisplayInRect:")]
+    #[doc(alias = "cacheDisplayInRect:toBitmapImageRep:")]
+    pub fn snapshot_rep(&self, rect: NSRect) -> Option<Arc<NSBitmapImageRep>> {
+        unsafe {
+            let rep: Option<&NSBitmapImageRep> =
+                _msg_send_any![self, bitmapImageRepForCachingDisplayInRect: rect];
+            let rep = rep?;
+            _msg_send_any![self, cacheDisplayInRect: rect toBitmapImageRep: rep => ()];
+            Some(Arc::retain(rep))
+        }
+    }
+
+    /// Renders `rect` of `self` and its subviews into an image of the size of
+    /// `rect`, or returns [`None`] if it could not be rendered.
+    ///
+    /// See [`snapshot_rep`](Self::snapshot_rep) for details.
+    pub fn snapshot(&self, rect: NSRect) -> Option<Arc<NSImage>> {
+        let rep = self.snapshot_rep(rect)?;
+        let image = NSImage::new(rect.size);
+        image.add_representation(&rep);
+        Some(image)
+    }
+}
+
+type Role = dyn Fn(&NSView) -> NSAccessibilityRole + Send + Sync;
+
+type Label = dyn Fn(&NSView) -> Option<Arc<NSString<'static>>> + Send + Sync;
+
+type Value = dyn Fn(&NSView) -> Option<Arc<NSObject<'static>>> + Send + Sync;
+
+type Action = dyn Fn(&NSView) -> bool + Send + Sync;
+
+type EventHandler = dyn Fn(&NSView, &NSEvent) + Send + Sync;
+
+/// The closures of a class created by [`NSViewClassBuilder`].
+#[derive(Default)]
+struct ViewHooks {
+    role: Option<Box<Role>>,
+    label: Option<Box<Label>>,
+    value: Option<Box<Value>>,
+    actions: Vec<(NSAccessibilityAction, Box<Action>)>,
+    events: Vec<(Sel, Box<EventHandler>)>,
+}
+
+impl ViewHooks {
+    #[inline]
+    fn has_accessibility(&self) -> bool {
+        self.role.is_some()
+            || self.label.is_some()
+            || self.value.is_some()
+            || !self.actions.is_empty()
+    }
+}
+
+/// Declares a subclass of [`NSView`] whose accessibility and trackpad
+/// gesture handling are implemented by Rust closures.
+///
+/// Views that draw their own content are otherwise seen by VoiceOver and
+/// other assistive apps as empty groups. If any accessibility closure is set,
+/// instances of the class are accessibility elements with the role, label,
+/// value, and actions returned by the closures, which are read whenever an
+/// assistive app asks for them.
+///
+/// Instances are created with [`NSView::with_class`]. The closures are
+/// shared by all instances of the class.
+///
+/// It is safe to panic within the closures. Panics will abort the process.
+///
+/// # Examples
+///
+/// ```no_run
+/// use fruity::app_kit::{NSAccessibilityAction, NSAccessibilityRole, NSView, NSViewClassBuilder};
+/// use fruity::foundation::{NSRect, NSString};
+/// use std::ffi::CStr;
+///
+/// let name = CStr::from_bytes_with_nul(b"PlayButton\0").unwrap();
+///
+/// let class = NSViewClassBuilder::new(name)
+///     .expect("class already exists")
+///     .accessibility_role(|_view| NSAccessibilityRole::BUTTON)
+///     .accessibility_label(|_view| Some(NSString::from_str("Play")))
+///     .accessibility_action(NSAccessibilityAction::PRESS, |_view| {
+///         // Start playback.
+///         true
+///     })
+///     .register();
+///
+/// let view = NSView::with_class(class, NSRect::default());
+/// ```
+pub struct NSViewClassBuilder {
+    builder: ClassBuilder,
+    hooks: ViewHooks,
+}
+
+impl fmt::Debug for NSViewClassBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let actions: Vec<_> = self
+            .hooks
+            .actions
+            .iter()
+            .map(|(action, _)| action)
+            .collect();
+        f.debug_struct("NSViewClassBuilder")
+            .field("builder", &self.builder)
+            .field("actions", &actions)
+            .finish()
+    }
+}
+
+impl NSViewClassBuilder {
+    /// Starts declaring a subclass of [`NSView`] named `name`, or returns
+    /// [`None`] if a class with that name already exists.
+    #[inline]
+    pub fn new(name: &CStr) -> Option<Self> {
+        Some(Self {
+            builder: ClassBuilder::new(name, <NSView as ClassType>::class())?,
+            hooks: ViewHooks::default(),
+        })
+    }
+
+    /// Sets the closure that returns the type of element that a view is.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsaccessibilityprotocol/accessibilityrole()).
+    #[inline]
+    #[doc(alias = "accessibilityRole")]
+    pub fn accessibility_role<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&NSView) -> NSAccessibilityRole + Send + Sync + 'static,
+    {
+        self.hooks.role = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the closure that returns the short description of a view, such
+    /// as the title of a button, which assistive apps read aloud.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsaccessibilityprotocol/accessibilitylabel()).
+    #[inline]
+    #[doc(alias = "accessibilityLabel")]
+    pub fn accessibility_label<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&NSView) -> Option<Arc<NSString<'static>>> + Send + Sync + 'static,
+    {
+        self.hooks.label = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the closure that returns the value of a view, such as the
+    /// [`NSNumber`](crate::foundation::NSNumber) of a slider or the
+    /// [`NSString`] of a text field.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsaccessibilityprotocol/accessibilityvalue()).
+    #[inline]
+    #[doc(alias = "accessibilityValue")]
+    pub fn accessibility_value<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&NSView) -> Option<Arc<NSObject<'static>>> + Send + Sync + 'static,
+    {
+        self.hooks.value = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the closure that performs `action` on a view and returns whether
+    /// it succeeded.
+    ///
+    /// Assistive apps offer only the actions that have closures. Setting
+    /// the same action again replaces its closure.
+    #[inline]
+    #[doc(alias = "accessibilityPerformPress")]
+    pub fn accessibility_action<F>(mut self, action: NSAccessibilityAction, f: F) -> Self
+    where
+        F: Fn(&NSView) -> bool + Send + Sync + 'static,
+    {
+        self.hooks
+            .actions
+            .retain(|(existing, _)| *existing != action);
+        self.hooks.actions.push((action, Box::new(f)));
+        self
+    }
+
+    fn event_handler<F>(mut self, sel: Sel, f: F) -> Self
+    where
+        F: Fn(&NSView, &NSEvent) + Send + Sync + 'static,
+    {
+        self.hooks.events.retain(|(existing, _)| *existing != sel);
+        self.hooks.events.push((sel, Box::new(f)));
+        self
+    }
+
+    /// Sets the closure called with each event of a pinch gesture, whose
+    /// [magnification](NSEvent::magnification) is the change in scale.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsresponder/magnify(with:)).
+    #[inline]
+    #[doc(alias = "magnifyWithEvent:")]
+    pub fn magnify<F>(self, f: F) -> Self
+    where
+        F: Fn(&NSView, &NSEvent) + Send + Sync + 'static,
+    {
+        self.event_handler(selector!(magnifyWithEvent:), f)
+    }
+
+    /// Sets the closure called with each event of a two-finger rotation
+    /// gesture, whose [rotation](NSEvent::rotation) is the change in angle.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsresponder/rotate(with:)).
+    #[inline]
+    #[doc(alias = "rotateWithEvent:")]
+    pub fn rotate<F>(self, f: F) -> Self
+    where
+        F: Fn(&NSView, &NSEvent) + Send + Sync + 'static,
+    {
+        self.event_handler(selector!(rotateWithEvent:), f)
+    }
+
+    /// Sets the closure called for a swipe gesture, whose direction is given
+    /// by [`delta_x`](NSEvent::delta_x) and [`delta_y`](NSEvent::delta_y).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsresponder/swipe(with:)).
+    #[inline]
+    #[doc(alias = "swipeWithEvent:")]
+    pub fn swipe<F>(self, f: F) -> Self
+    where
+        F: Fn(&NSView, &NSEvent) + Send + Sync + 'static,
+    {
+        self.event_handler(selector!(swipeWithEvent:), f)
+    }
+
+    /// Sets the closure called for a smart zoom gesture, which is a
+    /// two-finger double tap.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsresponder/smartmagnify(with:)).
+    #[inline]
+    #[doc(alias = "smartMagnifyWithEvent:")]
+    pub fn smart_magnify<F>(self, f: F) -> Self
+    where
+        F: Fn(&NSView, &NSEvent) + Send + Sync + 'static,
+    {
+        self.event_handler(selector!(smartMagnifyWithEvent:), f)
+    }
+
+    /// Sets the closure called when the pressure of a Force Touch click
+    /// changes, with its [pressure](NSEvent::pressure) and
+    /// [stage](NSEvent::stage).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsresponder/pressurechange(with:)).
+    #[inline]
+    #[doc(alias = "pressureChangeWithEvent:")]
+    pub fn pressure_change<F>(self, f: F) -> Self
+    where
+        F: Fn(&NSView, &NSEvent) + Send + Sync + 'static,
+    {
+        self.event_handler(selector!(pressureChangeWithEvent:), f)
+    }
+
+    /// Sets the closure called when fingers touch the trackpad.
+    ///
+    /// Touch closures are only called for views that
+    /// [allow](NSView::set_allowed_touch_types) trackpad touches. The
+    /// touches are read with [`NSEvent::touches_matching_phase`].
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsresponder/touchesbegan(with:)).
+    #[inline]
+    #[doc(alias = "touchesBeganWithEvent:")]
+    pub fn touches_began<F>(self, f: F) -> Self
+    where
+        F: Fn(&NSView, &NSEvent) + Send + Sync + 'static,
+    {
+        self.event_handler(selector!(touchesBeganWithEvent:), f)
+    }
+
+    /// Sets the closure called when fingers move on the trackpad.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsresponder/touchesmoved(with:)).
+    #[inline]
+    #[doc(alias = "touchesMovedWithEvent:")]
+    pub fn touches_moved<F>(self, f: F) -> Self
+    where
+        F: Fn(&NSView, &NSEvent) + Send + Sync + 'static,
+    {
+        self.event_handler(selector!(touchesMovedWithEvent:), f)
+    }
+
+    /// Sets the closure called when fingers are lifted from the trackpad.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsresponder/touchesended(with:)).
+    #[inline]
+    #[doc(alias = "touchesEndedWithEvent:")]
+    pub fn touches_ended<F>(self, f: F) -> Self
+    where
+        F: Fn(&NSView, &NSEvent) + Send + Sync + 'static,
+    {
+        self.event_handler(selector!(touchesEndedWithEvent:), f)
+    }
+
+    /// Sets the closure called when touches are cancelled, such as when the
+    /// view is removed from its window.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsresponder/touchescancelled(with:)).
+    #[inline]
+    #[doc(alias = "touchesCancelledWithEvent:")]
+    pub fn touches_cancelled<F>(self, f: F) -> Self
+    where
+        F: Fn(&NSView, &NSEvent) + Send + Sync + 'static,
+    {
+        self.event_handler(selector!(touchesCancelledWithEvent:), f)
+    }
+
+    /// Registers the class with the Objective-C runtime.
+    pub fn register(self) -> &'static Class {
+        let Self { mut builder, hooks } = self;
+
+        unsafe {
+            if hooks.has_accessibility() {
+                builder.add_method(
+                    selector!(isAccessibilityElement),
+                    is_accessibility_element as IsAccessibilityElementImp,
+                );
+            }
+            if hooks.role.is_some() {
+                builder.add_method(
+                    selector!(accessibilityRole),
+                    accessibility_role as ObjectImp,
+                );
+            }
+            if hooks.label.is_some() {
+                builder.add_method(
+                    selector!(accessibilityLabel),
+                    accessibility_label as ObjectImp,
+                );
+            }
+            if hooks.value.is_some() {
+                builder.add_method(
+                    selector!(accessibilityValue),
+                    accessibility_value as ObjectImp,
+                );
+            }
+            for (action, _) in &hooks.actions {
+                builder.add_method(action.selector(), perform_action as PerformActionImp);
+            }
+            for &(sel, _) in &hooks.events {
+                builder.add_method(sel, handle_event as HandleEventImp);
+            }
+        }
+
+        let class = builder.register();
+
+        // Classes are never unregistered, so neither are their hooks.
+        let hooks: &'static ViewHooks = Box::leak(Box::new(hooks));
+        let mut registry = match registry().lock() {
+            Ok(registry) => registry,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        registry.push((class as *const Class as usize, hooks));
+
+        class
+    }
+}
+
+/// Returns the hooks of each class registered by [`NSViewClassBuilder`].
+fn registry() -> &'static Mutex<Vec<(usize, &'static ViewHooks)>> {
+    static REGISTRY: AtomicPtr<Mutex<Vec<(usize, &'static ViewHooks)>>> =
+        AtomicPtr::new(ptr::null_mut());
+    static INIT: Once = Once::new();
+
+    INIT.call_once(|| {
+        let registry = Box::into_raw(Box::new(Mutex::new(Vec::new())));
+        REGISTRY.store(registry, Ordering::Release);
+    });
+    unsafe { &*REGISTRY.load(Ordering::Acquire) }
+}
+
+/// Returns the hooks of `class` or its nearest registered superclass.
+fn hooks_for(class: &Class) -> &'static ViewHooks {
+    let registry = match registry().lock() {
+        Ok(registry) => registry,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+    std::iter::once(class)
+        .chain(class.superclass_iter())
+        .find_map(|class| {
+            let class = class as *const Class as usize;
+            registry
+                .iter()
+                .find(|(registered, _)| *registered == class)
+                .map(|(_, hooks)| *hooks)
+        })
+        .expect("view class was not registered by NSViewClassBuilder")
+}
+
+/// Calls `f`, aborting if it panics, since unwinding into Objective-C is
+/// undefined behavior.
+#[inline]
+fn abort_on_panic<T>(f: impl FnOnce() -> T) -> T {
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(value) => value,
+        Err(_) => process::abort(),
+    }
+}
+
+/// Returns `obj` at +0 to an Objective-C caller.
+fn autorelease_return<T: ObjectType<'static>>(obj: Option<Arc<T>>) -> *const NSObject<'static> {
+    extern "C" {
+        fn objc_autoreleaseReturnValue(obj: *mut c_void) -> *mut c_void;
+    }
+
+    match obj {
+        Some(obj) => unsafe {
+            objc_autoreleaseReturnValue(Arc::into_raw(obj) as *mut c_void) as *const _
+        },
+        None => ptr::null(),
+    }
+}
+
+type IsAccessibilityElementImp = extern "C" fn(&NSView, Sel) -> BOOL;
+
+extern "C" fn is_accessibility_element(_: &NSView, _: Sel) -> BOOL {
+    BOOL::YES
+}
+
+type ObjectImp = extern "C" fn(&NSView, Sel) -> *const NSObject<'static>;
+
+extern "C" fn accessibility_role(this: &NSView, _: Sel) -> *const NSObject<'static> {
+    let role = hooks_for(this.class()).role.as_ref().unwrap();
+    let role = abort_on_panic(|| role(this));
+    autorelease_return(Some(NSString::from_str(role.name())))
+}
+
+extern "C" fn accessibility_label(this: &NSView, _: Sel) -> *const NSObject<'static> {
+    let label = hooks_for(this.class()).label.as_ref().unwrap();
+    autorelease_return(abort_on_panic(|| label(this)))
+}
+
+extern "C" fn accessibility_value(this: &NSView, _: Sel) -> *const NSObject<'static> {
+    let value = hooks_for(this.class()).value.as_ref().unwrap();
+    autorelease_return(abort_on_panic(|| value(this)))
+}
+
+type PerformActionImp = extern "C" fn(&NSView, Sel) -> BOOL;
+
+extern "C" fn perform_action(this: &NSView, sel: Sel) -> BOOL {
+    let hooks = hooks_for(this.class());
+    let (_, perform) = hooks
+        .actions
+        .iter()
+        .find(|(action, _)| action.selector() == sel)
+        .unwrap();
+    abort_on_panic(|| perform(this)).into()
+}
+
+type HandleEventImp = extern "C" fn(&NSView, Sel, *const NSEvent);
+
+extern "C" fn handle_event(this: &NSView, sel: Sel, event: *const NSEvent) {
+    let hooks = hooks_for(this.class());
+    let (_, handle) = hooks
+        .events
+        .iter()
+        .find(|(registered, _)| *registered == sel)
+        .unwrap();
+    abort_on_panic(|| handle(this, unsafe { &*event }));
+}
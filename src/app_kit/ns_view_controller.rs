@@ -0,0 +1,521 @@
+use super::NSView;
+use crate::core::Arc;
+use crate::foundation::{NSArray, NSSize, NSString};
+use crate::objc::{Class, ClassBuilder, ClassType, NSObject, ObjectType, Sel, BOOL};
+use std::{
+    ffi::CStr,
+    fmt, mem,
+    panic::{self, AssertUnwindSafe},
+    process, ptr,
+    sync::{
+        atomic::{AtomicPtr, Ordering},
+        Mutex, Once,
+    },
+};
+
+objc_subclass! {
+    /// Manages a view and its subviews, such as the content of a
+    /// [window](super::NSWindowController) or [popover](super::NSPopover).
+    ///
+    /// Controllers whose view is created and updated by Rust closures are
+    /// declared with [`NSViewControllerClassBuilder`].
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsviewcontroller).
+    pub class NSViewController: NSObject<'static>;
+}
+
+impl NSViewController {
+    /// Creates a controller that manages `view`.
+    ///
+    /// Controllers created without a nib must be given a view before it is
+    /// shown, which this does.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsviewcontroller/init(nibname:bundle:)).
+    #[inline]
+    pub fn with_view(view: &NSView) -> Arc<Self> {
+        let controller: Arc<Self> = unsafe { <Self as ClassType>::class().alloc_init() };
+        controller.set_view(view);
+        controller
+    }
+
+    /// Creates an instance of `class`, such as one declared with
+    /// [`NSViewControllerClassBuilder`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `class` is not a subclass of `NSViewController`.
+    #[inline]
+    pub fn with_class(class: &Class) -> Arc<Self> {
+        assert!(
+            class.is_subclass_of(<Self as ClassType>::class()),
+            "{:?} is not a subclass of NSViewController",
+            class.name(),
+        );
+        unsafe { class.alloc_init() }
+    }
+
+    /// Returns the view managed by `self`, loading it if needed.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsviewcontroller/view).
+    #[inline]
+    pub fn view(&self) -> &NSView {
+        unsafe { _msg_send_any![self, view] }
+    }
+
+    /// Sets the view managed by `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsviewcontroller/view).
+    #[inline]
+    #[doc(alias = "setView:")]
+    pub fn set_view(&self, view: &NSView) {
+        unsafe { _msg_send_any![self, setView: view => ()] }
+    }
+
+    /// Returns `true` if the view has been loaded or set.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsviewcontroller/isviewloaded).
+    #[inline]
+    #[doc(alias = "isViewLoaded")]
+    pub fn is_view_loaded(&self) -> bool {
+        unsafe { _msg_send_any![self, isViewLoaded => BOOL] }.into()
+    }
+
+    /// Returns the title shown by containers such as tab views.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsviewcontroller/title).
+    #[inline]
+    pub fn title(&self) -> Option<Arc<NSString<'static>>> {
+        unsafe {
+            _msg_send_any![self, title => *const NSString<'static>]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Sets the title shown by containers such as tab views.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsviewcontroller/title).
+    #[inline]
+    #[doc(alias = "setTitle:")]
+    pub fn set_title(&self, title: Option<&NSString>) {
+        let title = match title {
+            Some(title) => title,
+            None => ptr::null(),
+        };
+        unsafe { _msg_send_any![self, setTitle: title => ()] }
+    }
+
+    /// Returns the model object that the view displays.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsviewcontroller/representedobject).
+    #[inline]
+    #[doc(alias = "representedObject")]
+    pub fn represented_object(&self) -> Option<Arc<NSObject<'static>>> {
+        unsafe {
+            _msg_send_any![self, representedObject => *const NSObject<'static>]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Sets the model object that the view displays.
+    ///
+    /// Since the closures of [`NSViewControllerClassBuilder`] are shared by
+    /// all instances of a class, this is where per-instance state is kept.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsviewcontroller/representedobject).
+    #[inline]
+    #[doc(alias = "setRepresentedObject:")]
+    pub fn set_represented_object(&self, object: Option<&NSObject>) {
+        let object = match object {
+            Some(object) => object,
+            None => ptr::null(),
+        };
+        unsafe { _msg_send_any![self, setRepresentedObject: object => ()] }
+    }
+
+    /// Returns the size that containers such as popovers give the view.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsviewcontroller/preferredcontentsize).
+    #[inline]
+    #[doc(alias = "preferredContentSize")]
+    pub fn preferred_content_size(&self) -> NSSize {
+        unsafe { _msg_send_any![self, preferredContentSize] }
+    }
+
+    /// Sets the size that containers such as popovers give the view.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsviewcontroller/preferredcontentsize).
+    #[inline]
+    #[doc(alias = "setPreferredContentSize:")]
+    pub fn set_preferred_content_size(&self, size: NSSize) {
+        unsafe { _msg_send_any![self, setPreferredContentSize: size => ()] }
+    }
+
+    /// Returns the controller that `self` is a child of.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsviewcontroller/parent).
+    #[inline]
+    #[doc(alias = "parentViewController")]
+    pub fn parent(&self) -> Option<&NSViewController> {
+        unsafe { _msg_send_any![self, parentViewController] }
+    }
+
+    /// Returns the child controllers of `self`, in the order they were added.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsviewcontroller/children).
+    #[inline]
+    #[doc(alias = "childViewControllers")]
+    pub fn children(&self) -> Arc<NSArray<NSViewController>> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![
+                self,
+                childViewControllers => *const NSArray<NSViewController>
+            ])
+        }
+    }
+
+    /// Makes `child` a child of `self`, which keeps it alive and forwards
+    /// appearance callbacks to it.
+    ///
+    /// This does not add the view of `child` to the view of `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsviewcontroller/addchild(_:)).
+    #[inline]
+    #[doc(alias = "addChildViewController:")]
+    pub fn add_child(&self, child: &NSViewController) {
+        unsafe { _msg_send_any![self, addChildViewController: child => ()] }
+    }
+
+    /// Removes `self` from its parent controller.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsviewcontroller/removefromparent()).
+    #[inline]
+    #[doc(alias = "removeFromParentViewController")]
+    pub fn remove_from_parent(&self) {
+        unsafe { _msg_send_any![self, removeFromParentViewController => ()] }
+    }
+}
+
+type LoadView = dyn Fn(&NSViewController) -> Arc<NSView> + Send + Sync;
+
+type Lifecycle = dyn Fn(&NSViewController) + Send + Sync;
+
+/// The closures of a class created by [`NSViewControllerClassBuilder`].
+struct ViewControllerHooks {
+    superclass: &'static Class,
+    load_view: Option<Box<LoadView>>,
+    view_did_load: Option<Box<Lifecycle>>,
+    view_will_appear: Option<Box<Lifecycle>>,
+    view_did_appear: Option<Box<Lifecycle>>,
+    view_will_disappear: Option<Box<Lifecycle>>,
+    view_did_disappear: Option<Box<Lifecycle>>,
+}
+
+/// Declares a subclass of [`NSViewController`] whose view creation and
+/// lifecycle are implemented by Rust closures.
+///
+/// Instances are created with [`NSViewController::with_class`]. The closures
+/// are shared by all instances of the class, which can keep their own state
+/// in their [represented object](NSViewController::set_represented_object).
+///
+/// It is safe to panic within the closures. Panics will abort the process.
+///
+/// # Examples
+///
+/// ```no_run
+/// use fruity::app_kit::{NSViewController, NSViewControllerClassBuilder};
+/// use std::ffi::CStr;
+///
+/// # fn make_view() -> fruity::core::Arc<fruity::app_kit::NSView> { unimplemented!() }
+/// let name = CStr::from_bytes_with_nul(b"SettingsViewController\0").unwrap();
+///
+/// let class = NSViewControllerClassBuilder::new(name)
+///     .expect("class already exists")
+///     .load_view(|_controller| make_view())
+///     .view_will_appear(|controller| {
+///         // Refresh the view from the model.
+///     })
+///     .register();
+///
+/// let controller = NSViewController::with_class(class);
+/// ```
+pub struct NSViewControllerClassBuilder {
+    builder: ClassBuilder,
+    hooks: ViewControllerHooks,
+}
+
+impl fmt::Debug for NSViewControllerClassBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("NSViewControllerClassBuilder")
+            .field("builder", &self.builder)
+            .finish()
+    }
+}
+
+impl NSViewControllerClassBuilder {
+    /// Starts declaring a subclass of [`NSViewController`] named `name`, or
+    /// returns [`None`] if a class with that name already exists.
+    #[inline]
+    pub fn new(name: &CStr) -> Option<Self> {
+        Self::with_superclass(name, <NSViewController as ClassType>::class())
+    }
+
+    /// Starts declaring a subclass of `superclass` named `name`, or returns
+    /// [`None`] if a class with that name already exists.
+    ///
+    /// This is used for specialized controllers such as
+    /// [`NSCollectionViewItem`](super::NSCollectionViewItem).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `superclass` is not a subclass of `NSViewController`.
+    pub fn with_superclass(name: &CStr, superclass: &'static Class) -> Option<Self> {
+        assert!(
+            superclass.is_subclass_of(<NSViewController as ClassType>::class()),
+            "{:?} is not a subclass of NSViewController",
+            superclass.name(),
+        );
+        Some(Self {
+            builder: ClassBuilder::new(name, superclass)?,
+            hooks: ViewControllerHooks {
+                superclass,
+                load_view: None,
+                view_did_load: None,
+                view_will_appear: None,
+                view_did_appear: None,
+                view_will_disappear: None,
+                view_did_disappear: None,
+            },
+        })
+    }
+
+    /// Sets the closure that creates the view when it is first needed.
+    ///
+    /// Without this, the view must be [set](NSViewController::set_view)
+    /// before it is shown.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsviewcontroller/loadview()).
+    #[inline]
+    #[doc(alias = "loadView")]
+    pub fn load_view<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&NSViewController) -> Arc<NSView> + Send + Sync + 'static,
+    {
+        self.hooks.load_view = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the closure called once the view has been loaded.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsviewcontroller/viewdidload()).
+    #[inline]
+    #[doc(alias = "viewDidLoad")]
+    pub fn view_did_load<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&NSViewController) + Send + Sync + 'static,
+    {
+        self.hooks.view_did_load = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the closure called each time before the view is added to a
+    /// window.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsviewcontroller/viewwillappear()).
+    #[inline]
+    #[doc(alias = "viewWillAppear")]
+    pub fn view_will_appear<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&NSViewController) + Send + Sync + 'static,
+    {
+        self.hooks.view_will_appear = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the closure called each time after the view is added to a
+    /// window.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsviewcontroller/viewdidappear()).
+    #[inline]
+    #[doc(alias = "viewDidAppear")]
+    pub fn view_did_appear<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&NSViewController) + Send + Sync + 'static,
+    {
+        self.hooks.view_did_appear = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the closure called each time before the view is removed from a
+    /// window.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsviewcontroller/viewwilldisappear()).
+    #[inline]
+    #[doc(alias = "viewWillDisappear")]
+    pub fn view_will_disappear<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&NSViewController) + Send + Sync + 'static,
+    {
+        self.hooks.view_will_disappear = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the closure called each time after the view is removed from a
+    /// window.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsviewcontroller/viewdiddisappear()).
+    #[inline]
+    #[doc(alias = "viewDidDisappear")]
+    pub fn view_did_disappear<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&NSViewController) + Send + Sync + 'static,
+    {
+        self.hooks.view_did_disappear = Some(Box::new(f));
+        self
+    }
+
+    /// Registers the class with the Objective-C runtime.
+    pub fn register(self) -> &'static Class {
+        let Self { mut builder, hooks } = self;
+
+        unsafe {
+            if hooks.load_view.is_some() {
+                builder.add_method(selector!(loadView), load_view as LifecycleImp);
+            }
+            if hooks.view_did_load.is_some() {
+                builder.add_method(selector!(viewDidLoad), view_did_load as LifecycleImp);
+            }
+            if hooks.view_will_appear.is_some() {
+                builder.add_method(selector!(viewWillAppear), view_will_appear as LifecycleImp);
+            }
+            if hooks.view_did_appear.is_some() {
+                builder.add_method(selector!(viewDidAppear), view_did_appear as LifecycleImp);
+            }
+            if hooks.view_will_disappear.is_some() {
+                builder.add_method(
+                    selector!(viewWillDisappear),
+                    view_will_disappear as LifecycleImp,
+                );
+            }
+            if hooks.view_did_disappear.is_some() {
+                builder.add_method(
+                    selector!(viewDidDisappear),
+                    view_did_disappear as LifecycleImp,
+                );
+            }
+        }
+
+        let class = builder.register();
+
+        // Classes are never unregistered, so neither are their hooks.
+        let hooks: &'static ViewControllerHooks = Box::leak(Box::new(hooks));
+        let mut registry = match registry().lock() {
+            Ok(registry) => registry,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        registry.push((class as *const Class as usize, hooks));
+
+        class
+    }
+}
+
+type Registry = Mutex<Vec<(usize, &'static ViewControllerHooks)>>;
+
+/// Returns the hooks of each class registered by
+/// [`NSViewControllerClassBuilder`].
+fn registry() -> &'static Registry {
+    static REGISTRY: AtomicPtr<Registry> = AtomicPtr::new(ptr::null_mut());
+    static INIT: Once = Once::new();
+
+    INIT.call_once(|| {
+        let registry = Box::into_raw(Box::new(Mutex::new(Vec::new())));
+        REGISTRY.store(registry, Ordering::Release);
+    });
+    unsafe { &*REGISTRY.load(Ordering::Acquire) }
+}
+
+/// Returns the hooks of the nearest class in the hierarchy of `class` that
+/// has the hook selected by `f`, along with that hook.
+fn find_hook<H: ?Sized>(
+    class: &Class,
+    f: impl Fn(&'static ViewControllerHooks) -> Option<&'static H>,
+) -> (&'static ViewControllerHooks, &'static H) {
+    let registry = match registry().lock() {
+        Ok(registry) => registry,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+    std::iter::once(class)
+        .chain(class.superclass_iter())
+        .find_map(|class| {
+            let class = class as *const Class as usize;
+            let (_, hooks) = registry
+                .iter()
+                .find(|(registered, _)| *registered == class)?;
+            Some((*hooks, f(hooks)?))
+        })
+        .expect("view controller class was not registered by NSViewControllerClassBuilder")
+}
+
+/// Calls the superclass implementation of `sel` on `this`, as overriding
+/// methods are expected to.
+#[inline]
+fn call_super(hooks: &ViewControllerHooks, this: &NSViewController, sel: Sel) {
+    unsafe {
+        let imp = hooks.superclass.method_implementation(sel);
+        let imp: LifecycleImp = mem::transmute(imp);
+        imp(this, sel);
+    }
+}
+
+/// Calls `f`, aborting if it panics, since unwinding into Objective-C is
+/// undefined behavior.
+#[inline]
+fn abort_on_panic<T>(f: impl FnOnce() -> T) -> T {
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(value) => value,
+        Err(_) => process::abort(),
+    }
+}
+
+type LifecycleImp = extern "C" fn(&NSViewController, Sel);
+
+extern "C" fn load_view(this: &NSViewController, _: Sel) {
+    let (_, load) = find_hook(this.class(), |hooks| hooks.load_view.as_deref());
+
+    let view = abort_on_panic(|| load(this));
+    this.set_view(&view);
+}
+
+/// Calls the superclass implementation of `sel`, then the hook selected by
+/// `f`.
+#[inline]
+fn call_lifecycle(
+    this: &NSViewController,
+    sel: Sel,
+    f: impl Fn(&'static ViewControllerHooks) -> Option<&'static Lifecycle>,
+) {
+    let (hooks, hook) = find_hook(this.class(), f);
+    call_super(hooks, this, sel);
+    abort_on_panic(|| hook(this));
+}
+
+extern "C" fn view_did_load(this: &NSViewController, sel: Sel) {
+    call_lifecycle(this, sel, |hooks| hooks.view_did_load.as_deref());
+}
+
+extern "C" fn view_will_appear(this: &NSViewController, sel: Sel) {
+    call_lifecycle(this, sel, |hooks| hooks.view_will_appear.as_deref());
+}
+
+extern "C" fn view_did_appear(this: &NSViewController, sel: Sel) {
+    call_lifecycle(this, sel, |hooks| hooks.view_did_appear.as_deref());
+}
+
+extern "C" fn view_will_disappear(this: &NSViewController, sel: Sel) {
+    call_lifecycle(this, sel, |hooks| hooks.view_will_disappear.as_deref());
+}
+
+extern "C" fn view_did_disappear(this: &NSViewController, sel: Sel) {
+    call_lifecycle(this, sel, |hooks| hooks.view_did_disappear.as_deref());
+}
@@ -0,0 +1,567 @@
+use super::{NSBitmapImageRep, NSImage, NSView, NSViewController};
+use crate::core::Arc;
+use crate::core_graphics::CGFloat;
+use crate::foundation::{NSRect, NSString};
+use crate::objc::{ClassType, NSObject, NSUInteger, Sel, BOOL};
+use std::{ops, ptr};
+
+#[cfg(target_os = "macos")]
+use crate::foundation::{
+    NSNotification, NSNotificationCenter, NSNotificationName, NSNotificationObserver,
+};
+#[cfg(target_os = "macos")]
+use std::sync;
+
+objc_subclass! {
+    /// A window on screen, whose content is typically managed by an
+    /// [`NSViewController`] and which is owned by an
+    /// [`NSWindowController`](super::NSWindowController).
+    ///
+    /// Windows created by this crate are not released when closed, so they
+    /// live exactly as long as their [`Arc`] references.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nswindow).
+    pub class NSWindow: NSObject<'static>;
+}
+
+impl NSWindow {
+    /// Creates a hidden window whose content area has the size and screen
+    /// position of `content_rect`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nswindow/init(contentrect:stylemask:backing:defer:)).
+    #[inline]
+    #[doc(alias = "initWithContentRect:styleMask:backing:defer:")]
+    pub fn new(content_rect: NSRect, style: NSWindowStyleMask) -> Arc<Self> {
+        // `NSBackingStoreBuffered`, the only backing that is not deprecated.
+        const BACKING: NSUInteger = 2;
+
+        let window = unsafe {
+            let obj: Arc<Self> = Self::class().alloc();
+
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(
+                    obj: Arc<NSWindow>,
+                    sel: Sel,
+                    content_rect: NSRect,
+                    style: NSWindowStyleMask,
+                    backing: NSUInteger,
+                    defer: BOOL,
+                ) -> Arc<NSWindow>;
+            }
+
+            objc_msgSend(
+                obj,
+                selector!(initWithContentRect:styleMask:backing:defer:),
+                content_rect,
+                style,
+                BACKING,
+                BOOL::NO,
+            )
+        };
+        window.set_released_when_closed(false);
+        window
+    }
+
+    /// Creates a hidden window with a title bar that shows the view of
+    /// `controller`, sized to fit it.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nswindow/init(contentviewcontroller:)).
+    #[inline]
+    #[doc(alias = "windowWithContentViewController:")]
+    pub fn with_content_view_controller(controller: &NSViewController) -> Arc<Self> {
+        let window: Arc<Self> = unsafe {
+            Arc::retain_raw(_msg_send_any![
+                Self::class(),
+                windowWithContentViewController: controller
+                => *const Self
+            ])
+        };
+        window.set_released_when_closed(false);
+        window
+    }
+
+    /// Sets whether the window releases itself when closed.
+    ///
+    /// This must stay `false` while the window is referenced by an [`Arc`],
+    /// which is why windows created by this crate disable it.
+    #[inline]
+    #[doc(alias = "setReleasedWhenClosed:")]
+    fn set_released_when_closed(&self, value: bool) {
+        unsafe { _msg_send_any![self, setReleasedWhenClosed: BOOL::from(value) => ()] }
+    }
+
+    /// Returns the text in the title bar.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nswindow/title).
+    #[inline]
+    pub fn title(&self) -> Arc<NSString<'static>> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, title => *const NSString<'static>]) }
+    }
+
+    /// Sets the text in the title bar.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nswindow/title).
+    #[inline]
+    #[doc(alias = "setTitle:")]
+    pub fn set_title(&self, title: &NSString) {
+        unsafe { _msg_send_any![self, setTitle: title => ()] }
+    }
+
+    /// Returns the style of the window.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nswindow/stylemask-swift.property).
+    #[inline]
+    #[doc(alias = "styleMask")]
+    pub fn style_mask(&self) -> NSWindowStyleMask {
+        unsafe { _msg_send_any![self, styleMask] }
+    }
+
+    /// Returns the controller of the window's content, which the window
+    /// keeps alive.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nswindow/contentviewcontroller).
+    #[inline]
+    #[doc(alias = "contentViewController")]
+    pub fn content_view_controller(&self) -> Option<Arc<NSViewController>> {
+        unsafe {
+            _msg_send_any![self, contentViewController => *const NSViewController]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Sets the controller of the window's content, which also makes its
+    /// view the [content view](Self::content_view).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nswindow/contentviewcontroller).
+    #[inline]
+    #[doc(alias = "setContentViewController:")]
+    pub fn set_content_view_controller(&self, controller: Option<&NSViewController>) {
+        let controller = match controller {
+            Some(controller) => controller,
+            None => ptr::null(),
+        };
+        unsafe { _msg_send_any![self, setContentViewController: controller => ()] }
+    }
+
+    /// Returns the view that fills the window's content area.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nswindow/contentview).
+    #[inline]
+    #[doc(alias = "contentView")]
+    pub fn content_view(&self) -> Option<&NSView> {
+        unsafe { _msg_send_any![self, contentView] }
+    }
+
+    /// Sets the view that fills the window's content area.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nswindow/contentview).
+    #[inline]
+    #[doc(alias = "setContentView:")]
+    pub fn set_content_view(&self, view: &NSView) {
+        unsafe { _msg_send_any![self, setContentView: view => ()] }
+    }
+
+    /// Renders the window, including its title bar, into a bitmap, or returns
+    /// [`None`] if it has no content view or could not be rendered.
+    ///
+    /// Like [`NSView::snapshot_rep`], this works for windows that are not on
+    /// screen, but it does not include the window's shadow or the content of
+    /// other windows behind it.
+    pub fn snapshot_rep(&self) -> Option<Arc<NSBitmapImageRep>> {
+        let view = self.frame_view()?;
+        view.snapshot_rep(view.bounds())
+    }
+
+    /// Renders the window, including its title bar, into an image, or returns
+    /// [`None`] if it could not be rendered.
+    ///
+    /// See [`snapshot_rep`](Self::snapshot_rep) for details.
+    pub fn snapshot(&self) -> Option<Arc<NSImage>> {
+        let view = self.frame_view()?;
+        view.snapshot(view.bounds())
+    }
+
+    /// Returns the view that draws the title bar and contains the content
+    /// view.
+    fn frame_view(&self) -> Option<&NSView> {
+        let content_view = self.content_view()?;
+        Some(content_view.superview().unwrap_or(content_view))
+    }
+
+    /// Returns the frame of the window, including its title bar, in screen
+    /// coordinates.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nswindow/frame).
+    #[inline]
+    pub fn frame(&self) -> NSRect {
+        unsafe { _msg_send_any![self, frame] }
+    }
+
+    /// Moves and resizes the window to `frame`, redrawing its views if
+    /// `display` is `true`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nswindow/setframe(_:display:)).
+    #[inline]
+    #[doc(alias = "setFrame:display:")]
+    pub fn set_frame(&self, frame: NSRect, display: bool) {
+        unsafe { _msg_send_any![self, setFrame: frame display: BOOL::from(display) => ()] }
+    }
+
+    /// Returns the opacity of the window, from 0 (transparent) to 1
+    /// (opaque).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nswindow/alphavalue).
+    #[inline]
+    #[doc(alias = "alphaValue")]
+    pub fn alpha_value(&self) -> CGFloat {
+        unsafe { _msg_send_any![self, alphaValue] }
+    }
+
+    /// Sets the opacity of the window, from 0 (transparent) to 1 (opaque).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nswindow/alphavalue).
+    #[inline]
+    #[doc(alias = "setAlphaValue:")]
+    pub fn set_alpha_value(&self, alpha: CGFloat) {
+        unsafe { _msg_send_any![self, setAlphaValue: alpha => ()] }
+    }
+
+    /// Returns a proxy of the window whose property changes, such as
+    /// [`set_frame`](Self::set_frame) and
+    /// [`set_alpha_value`](Self::set_alpha_value), are animated with the
+    /// settings of the current [`NSAnimationContext`](super::NSAnimationContext).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsanimatablepropertycontainer/animator()).
+    #[inline]
+    pub fn animator(&self) -> Arc<Self> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, animator => *const Self]) }
+    }
+
+    /// Moves the window to the center of its screen.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nswindow/center()).
+    #[inline]
+    pub fn center(&self) {
+        unsafe { _msg_send_any![self, center => ()] }
+    }
+
+    /// Shows the window in front of other windows and makes it receive
+    /// keyboard input.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nswindow/makekeyandorderfront(_:)).
+    #[inline]
+    #[doc(alias = "makeKeyAndOrderFront:")]
+    pub fn make_key_and_order_front(&self) {
+        unsafe { _msg_send_any![self, makeKeyAndOrderFront: ptr::null::<NSObject>() => ()] }
+    }
+
+    /// Shows the window in front of other windows without making it receive
+    /// keyboard input.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nswindow/orderfront(_:)).
+    #[inline]
+    #[doc(alias = "orderFront:")]
+    pub fn order_front(&self) {
+        unsafe { _msg_send_any![self, orderFront: ptr::null::<NSObject>() => ()] }
+    }
+
+    /// Hides the window without closing it.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nswindow/orderout(_:)).
+    #[inline]
+    #[doc(alias = "orderOut:")]
+    pub fn order_out(&self) {
+        unsafe { _msg_send_any![self, orderOut: ptr::null::<NSObject>() => ()] }
+    }
+
+    /// Returns `true` if the window is on screen.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nswindow/isvisible).
+    #[inline]
+    #[doc(alias = "isVisible")]
+    pub fn is_visible(&self) -> bool {
+        unsafe { _msg_send_any![self, isVisible => BOOL] }.into()
+    }
+
+    /// Returns `true` if the window receives keyboard input.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nswindow/iskeywindow).
+    #[inline]
+    #[doc(alias = "isKeyWindow")]
+    pub fn is_key_window(&self) -> bool {
+        unsafe { _msg_send_any![self, isKeyWindow => BOOL] }.into()
+    }
+
+    /// Returns `true` if the window is the main window of the app, which is
+    /// the target of menu commands.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nswindow/ismainwindow).
+    #[inline]
+    #[doc(alias = "isMainWindow")]
+    pub fn is_main_window(&self) -> bool {
+        unsafe { _msg_send_any![self, isMainWindow => BOOL] }.into()
+    }
+
+    /// Returns `true` if the window is minimized to the Dock.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nswindow/isminiaturized).
+    #[inline]
+    #[doc(alias = "isMiniaturized")]
+    pub fn is_miniaturized(&self) -> bool {
+        unsafe { _msg_send_any![self, isMiniaturized => BOOL] }.into()
+    }
+
+    /// Returns whether any part of the window can be seen by the user.
+    ///
+    /// Unlike [`is_visible`](Self::is_visible), this accounts for other
+    /// windows covering the window, the screen being locked, and the window
+    /// being on another space.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nswindow/occlusionstate).
+    #[inline]
+    #[doc(alias = "occlusionState")]
+    pub fn occlusion_state(&self) -> NSWindowOcclusionState {
+        unsafe { _msg_send_any![self, occlusionState] }
+    }
+
+    /// Removes the window from the screen.
+    ///
+    /// The window can be shown again, since windows created by this crate
+    /// are not released when closed.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nswindow/close()).
+    #[inline]
+    pub fn close(&self) {
+        unsafe { _msg_send_any![self, close => ()] }
+    }
+}
+
+/// Visibility and focus tracking.
+#[cfg(target_os = "macos")]
+#[cfg_attr(docsrs, doc(cfg(target_os = "macos")))]
+impl NSWindow {
+    /// Calls `f` with each [`NSWindowActivityEvent`] of the window and the
+    /// app, until the returned observers are dropped.
+    ///
+    /// This is for pausing rendering, animations, and timers while their
+    /// results cannot be seen, which saves energy. Use
+    /// [`NSWindowActivityEvent::Occlusion`] to tell whether the window can be
+    /// seen at all, and the other events to tell whether the user is
+    /// interacting with it.
+    ///
+    /// Notifications are posted on the main thread, so its run loop must be
+    /// running for `f` to be called.
+    ///
+    /// It is safe to panic within `f`. Panics will abort the process.
+    pub fn observe_activity<F>(&self, f: F) -> Vec<NSNotificationObserver>
+    where
+        F: FnMut(NSWindowActivityEvent) + Send + 'static,
+    {
+        let f = sync::Arc::new(sync::Mutex::new(f));
+        let center = NSNotificationCenter::default();
+
+        let window_events: [(&NSNotificationName, MakeEvent); 7] = [
+            (
+                NSNotificationName::window_did_change_occlusion_state(),
+                |window| NSWindowActivityEvent::Occlusion(window.occlusion_state()),
+            ),
+            (NSNotificationName::window_did_become_key(), |_| {
+                NSWindowActivityEvent::DidBecomeKey
+            }),
+            (NSNotificationName::window_did_resign_key(), |_| {
+                NSWindowActivityEvent::DidResignKey
+            }),
+            (NSNotificationName::window_did_become_main(), |_| {
+                NSWindowActivityEvent::DidBecomeMain
+            }),
+            (NSNotificationName::window_did_resign_main(), |_| {
+                NSWindowActivityEvent::DidResignMain
+            }),
+            (NSNotificationName::window_did_miniaturize(), |_| {
+                NSWindowActivityEvent::DidMiniaturize
+            }),
+            (NSNotificationName::window_did_deminiaturize(), |_| {
+                NSWindowActivityEvent::DidDeminiaturize
+            }),
+        ];
+
+        let app_events = [
+            (
+                NSNotificationName::application_did_become_active(),
+                NSWindowActivityEvent::ApplicationDidBecomeActive,
+            ),
+            (
+                NSNotificationName::application_did_resign_active(),
+                NSWindowActivityEvent::ApplicationDidResignActive,
+            ),
+            (
+                NSNotificationName::application_did_hide(),
+                NSWindowActivityEvent::ApplicationDidHide,
+            ),
+            (
+                NSNotificationName::application_did_unhide(),
+                NSWindowActivityEvent::ApplicationDidUnhide,
+            ),
+        ];
+
+        let call = move |f: &sync::Mutex<F>, event| {
+            let mut f = match f.lock() {
+                Ok(f) => f,
+                Err(poisoned) => poisoned.into_inner(),
+            };
+            (*f)(event)
+        };
+
+        let window_observers = window_events.iter().map(|&(name, make_event)| {
+            let f = f.clone();
+            center.add_observer(Some(name), Some(self), move |notification| {
+                if let Some(window) = notification_window(notification) {
+                    call(&f, make_event(&window));
+                }
+            })
+        });
+
+        let app_observers = app_events.iter().map(|&(name, event)| {
+            let f = f.clone();
+            center.add_observer(Some(name), None, move |_| call(&f, event))
+        });
+
+        window_observers.chain(app_observers).collect()
+    }
+}
+
+#[cfg(target_os = "macos")]
+type MakeEvent = fn(&NSWindow) -> NSWindowActivityEvent;
+
+/// Returns the window that posted `notification`.
+#[cfg(target_os = "macos")]
+fn notification_window(notification: &NSNotification) -> Option<Arc<NSWindow>> {
+    // Window notifications are only observed from windows.
+    notification
+        .object()
+        .map(|object| unsafe { Arc::cast_unchecked(object) })
+}
+
+/// A change in the visibility or focus of an [`NSWindow`] or its app,
+/// reported by [`NSWindow::observe_activity`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum NSWindowActivityEvent {
+    /// The window became more or less visible, with its new state.
+    #[doc(alias = "NSWindowDidChangeOcclusionStateNotification")]
+    Occlusion(NSWindowOcclusionState),
+
+    /// The window started receiving keyboard input.
+    #[doc(alias = "NSWindowDidBecomeKeyNotification")]
+    DidBecomeKey,
+
+    /// The window stopped receiving keyboard input.
+    #[doc(alias = "NSWindowDidResignKeyNotification")]
+    DidResignKey,
+
+    /// The window became the main window of the app.
+    #[doc(alias = "NSWindowDidBecomeMainNotification")]
+    DidBecomeMain,
+
+    /// The window stopped being the main window of the app.
+    #[doc(alias = "NSWindowDidResignMainNotification")]
+    DidResignMain,
+
+    /// The window was minimized to the Dock.
+    #[doc(alias = "NSWindowDidMiniaturizeNotification")]
+    DidMiniaturize,
+
+    /// The window was restored from the Dock.
+    #[doc(alias = "NSWindowDidDeminiaturizeNotification")]
+    DidDeminiaturize,
+
+    /// The app became the active app.
+    #[doc(alias = "NSApplicationDidBecomeActiveNotification")]
+    ApplicationDidBecomeActive,
+
+    /// The app stopped being the active app.
+    #[doc(alias = "NSApplicationDidResignActiveNotification")]
+    ApplicationDidResignActive,
+
+    /// The app was hidden.
+    #[doc(alias = "NSApplicationDidHideNotification")]
+    ApplicationDidHide,
+
+    /// The app was made visible after being hidden.
+    #[doc(alias = "NSApplicationDidUnhideNotification")]
+    ApplicationDidUnhide,
+}
+
+/// Whether an [`NSWindow`] can be seen by the user.
+///
+/// See [documentation](https://developer.apple.com/documentation/appkit/nswindow/occlusionstate-swift.struct).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NSWindowOcclusionState(pub NSUInteger);
+
+impl NSWindowOcclusionState {
+    /// Some part of the window can be seen.
+    #[doc(alias = "NSWindowOcclusionStateVisible")]
+    pub const VISIBLE: Self = Self(1 << 1);
+
+    /// Returns `true` if all flags in `other` are set in `self`.
+    #[inline]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns `true` if some part of the window can be seen.
+    #[inline]
+    pub const fn is_visible(self) -> bool {
+        self.contains(Self::VISIBLE)
+    }
+}
+
+/// The parts and behaviors of an [`NSWindow`].
+///
+/// See [documentation](https://developer.apple.com/documentation/appkit/nswindow/stylemask-swift.struct).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NSWindowStyleMask(pub NSUInteger);
+
+impl ops::BitOr for NSWindowStyleMask {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl NSWindowStyleMask {
+    /// The window has no title bar or border.
+    #[doc(alias = "NSWindowStyleMaskBorderless")]
+    pub const BORDERLESS: Self = Self(0);
+
+    /// The window has a title bar.
+    #[doc(alias = "NSWindowStyleMaskTitled")]
+    pub const TITLED: Self = Self(1 << 0);
+
+    /// The window has a close button.
+    #[doc(alias = "NSWindowStyleMaskClosable")]
+    pub const CLOSABLE: Self = Self(1 << 1);
+
+    /// The window has a minimize button.
+    #[doc(alias = "NSWindowStyleMaskMiniaturizable")]
+    pub const MINIATURIZABLE: Self = Self(1 << 2);
+
+    /// The window can be resized by the user.
+    #[doc(alias = "NSWindowStyleMaskResizable")]
+    pub const RESIZABLE: Self = Self(1 << 3);
+
+    /// The content view extends under the title bar.
+    #[doc(alias = "NSWindowStyleMaskFullSizeContentView")]
+    pub const FULL_SIZE_CONTENT_VIEW: Self = Self(1 << 15);
+
+    /// Returns `true` if all flags in `other` are set in `self`.
+    #[inline]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
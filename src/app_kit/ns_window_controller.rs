@@ -0,0 +1,106 @@
+use super::{NSViewController, NSWindow};
+use crate::core::Arc;
+use crate::objc::{ClassType, NSObject, Sel};
+use std::ptr;
+
+objc_subclass! {
+    /// Manages a window and the controller of its content.
+    ///
+    /// # Ownership
+    ///
+    /// The window controller owns its window, which owns its
+    /// [content view controller](NSWindow::content_view_controller), which
+    /// owns its [children](NSViewController::children). Keeping the window
+    /// controller alive, such as in app state or with
+    /// [`NSDocument::add_window_controller`](super::NSDocument::add_window_controller),
+    /// therefore keeps the whole interface alive.
+    ///
+    /// AppKit does not keep shown windows alive, so the window controller
+    /// must outlive the time its window is on screen.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nswindowcontroller).
+    pub class NSWindowController: NSObject<'static>;
+}
+
+impl NSWindowController {
+    /// Creates a controller that manages `window`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nswindowcontroller/init(window:)).
+    #[inline]
+    #[doc(alias = "initWithWindow:")]
+    pub fn with_window(window: &NSWindow) -> Arc<Self> {
+        unsafe {
+            let obj: Arc<Self> = Self::class().alloc();
+
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(
+                    obj: Arc<NSWindowController>,
+                    sel: Sel,
+                    window: &NSWindow,
+                ) -> Arc<NSWindowController>;
+            }
+
+            objc_msgSend(obj, selector!(initWithWindow:), window)
+        }
+    }
+
+    /// Creates a controller that manages a new window showing the view of
+    /// `controller`.
+    #[inline]
+    pub fn with_content_view_controller(controller: &NSViewController) -> Arc<Self> {
+        Self::with_window(&NSWindow::with_content_view_controller(controller))
+    }
+
+    /// Returns the window managed by `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nswindowcontroller/window).
+    #[inline]
+    pub fn window(&self) -> Option<&NSWindow> {
+        unsafe { _msg_send_any![self, window] }
+    }
+
+    /// Returns the controller of the window's content.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nswindowcontroller/contentviewcontroller).
+    #[inline]
+    #[doc(alias = "contentViewController")]
+    pub fn content_view_controller(&self) -> Option<Arc<NSViewController>> {
+        unsafe {
+            _msg_send_any![self, contentViewController => *const NSViewController]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Sets the controller of the window's content.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nswindowcontroller/contentviewcontroller).
+    #[inline]
+    #[doc(alias = "setContentViewController:")]
+    pub fn set_content_view_controller(&self, controller: Option<&NSViewController>) {
+        let controller = match controller {
+            Some(controller) => controller,
+            None => ptr::null(),
+        };
+        unsafe { _msg_send_any![self, setContentViewController: controller => ()] }
+    }
+
+    /// Shows the window in front of other windows and makes it receive
+    /// keyboard input.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nswindowcontroller/showwindow(_:)).
+    #[inline]
+    #[doc(alias = "showWindow:")]
+    pub fn show_window(&self) {
+        unsafe { _msg_send_any![self, showWindow: ptr::null::<NSObject>() => ()] }
+    }
+
+    /// Closes the window.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nswindowcontroller/close()).
+    #[inline]
+    pub fn close(&self) {
+        unsafe { _msg_send_any![self, close => ()] }
+    }
+}
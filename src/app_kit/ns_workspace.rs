@@ -0,0 +1,391 @@
+use crate::core::Arc;
+use crate::foundation::{
+    NSArray, NSDictionary, NSError, NSNotificationCenter, NSNotificationName,
+    NSNotificationObserver, NSNumber, NSProgress, NSProgressFileOperationKind, NSProgressKind,
+    NSProgressUserInfoKey, NSString, NSURL,
+};
+use crate::objc::{Block, ClassType, NSInteger, NSObject, RcBlock};
+use std::{cell::Cell, fmt};
+
+objc_subclass! {
+    /// The environment of running apps, which reports system-wide events such
+    /// as sleep and wake.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsworkspace).
+    pub class NSWorkspace: NSObject<'static>;
+}
+
+impl NSWorkspace {
+    /// Returns the shared workspace.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsworkspace/shared).
+    #[inline]
+    #[doc(alias = "sharedWorkspace")]
+    pub fn shared() -> &'static NSWorkspace {
+        unsafe { _msg_send_any![Self::class(), sharedWorkspace] }
+    }
+
+    /// Returns the notification center that workspace notifications, such as
+    /// [`NSWorkspacePowerEvent`]s, are posted to.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsworkspace/notificationcenter).
+    #[inline]
+    #[doc(alias = "notificationCenter")]
+    pub fn notification_center(&self) -> &NSNotificationCenter {
+        unsafe { _msg_send_any![self, notificationCenter] }
+    }
+
+    /// Calls `f` each time `event` occurs, until the returned observer is
+    /// dropped.
+    ///
+    /// Notifications are posted on the main thread, so its run loop must be
+    /// running for `f` to be called, such as within `NSApplication` or
+    /// `CFRunLoopRun`.
+    ///
+    /// It is safe to panic within `f`. Panics will abort the process.
+    #[inline]
+    pub fn observe_power_event<F>(
+        &self,
+        event: NSWorkspacePowerEvent,
+        f: F,
+    ) -> NSNotificationObserver
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        self.notification_center()
+            .add_observer(Some(event.notification_name()), None, move |_| f())
+    }
+
+    /// Calls `f` with each [`NSWorkspacePowerEvent`] that occurs, until the
+    /// returned observers are dropped.
+    ///
+    /// See [`observe_power_event`](Self::observe_power_event) for how
+    /// notifications are delivered.
+    pub fn observe_power_events<F>(&self, f: F) -> Vec<NSNotificationObserver>
+    where
+        F: FnMut(NSWorkspacePowerEvent) + Send + 'static,
+    {
+        let f = std::sync::Arc::new(std::sync::Mutex::new(f));
+
+        NSWorkspacePowerEvent::ALL
+            .iter()
+            .map(|&event| {
+                let f = f.clone();
+                self.observe_power_event(event, move || {
+                    let mut f = match f.lock() {
+                        Ok(f) => f,
+                        Err(poisoned) => poisoned.into_inner(),
+                    };
+                    (*f)(event)
+                })
+            })
+            .collect()
+    }
+}
+
+/// Default applications for URLs.
+///
+/// See also [`core_services::url_scheme`](crate::core_services::url_scheme)
+/// for working with bundle identifiers on earlier versions of macOS.
+impl NSWorkspace {
+    /// Returns the URL of the application with `bundle_id`, or [`None`] if
+    /// it is not installed.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsworkspace/urlforapplication(withbundleidentifier:)).
+    #[inline]
+    #[doc(alias = "URLForApplicationWithBundleIdentifier:")]
+    pub fn url_for_application_with_bundle_id(&self, bundle_id: &NSString) -> Option<Arc<NSURL>> {
+        unsafe {
+            _msg_send_any![
+                self,
+                URLForApplicationWithBundleIdentifier: bundle_id
+                => *const NSURL
+            ]
+            .as_ref()
+            .map(Arc::retain)
+        }
+    }
+
+    /// Returns the URL of the default application for opening `url`, or
+    /// [`None`] if no application can open it.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsworkspace/urlforapplication(toopen:)).
+    #[inline]
+    #[doc(alias = "URLForApplicationToOpenURL:")]
+    pub fn url_for_application_to_open_url(&self, url: &NSURL) -> Option<Arc<NSURL>> {
+        unsafe {
+            _msg_send_any![self, URLForApplicationToOpenURL: url => *const NSURL]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Returns the URLs of all applications that can open `url`.
+    ///
+    /// Requires macOS 12 or later.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsworkspace/urlsforapplications(toopen:)).
+    #[inline]
+    #[doc(alias = "URLsForApplicationsToOpenURL:")]
+    pub fn urls_for_applications_to_open_url(&self, url: &NSURL) -> Arc<NSArray<NSURL>> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![
+                self,
+                URLsForApplicationsToOpenURL: url
+                => *const NSArray<NSURL>
+            ])
+        }
+    }
+
+    /// Makes the application at `application_url` the default for opening
+    /// URLs with `scheme`, calling `completion` on an arbitrary thread when
+    /// done.
+    ///
+    /// The system may ask the user to confirm the change, in which case
+    /// `completion` is called after they respond.
+    ///
+    /// Requires macOS 12 or later.
+    ///
+    /// It is safe to panic within `completion`. Panics will abort the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsworkspace/setdefaultapplication(at:toopenurlswithscheme:completionhandler:)).
+    #[inline]
+    #[doc(alias = "setDefaultApplicationAtURL:toOpenURLsWithScheme:completionHandler:")]
+    pub fn set_default_application_for_scheme<F>(
+        &self,
+        application_url: &NSURL,
+        scheme: &NSString,
+        completion: F,
+    ) where
+        F: FnOnce(Result<(), Arc<NSError<'static>>>) + Send + 'static,
+    {
+        let completion = Cell::new(Some(completion));
+        let block = RcBlock::new(move |error: *const NSError<'static>| {
+            if let Some(completion) = completion.take() {
+                completion(match unsafe { error.as_ref() } {
+                    Some(error) => Err(Arc::retain(error)),
+                    None => Ok(()),
+                });
+            }
+        });
+        let block: &Block<_, ()> = &block;
+
+        unsafe {
+            _msg_send_any![
+                self,
+                setDefaultApplicationAtURL: application_url
+                toOpenURLsWithScheme: scheme
+                completionHandler: block
+                => ()
+            ]
+        }
+    }
+
+    /// Duplicates the files at `urls` next to the originals, as Finder's
+    /// Duplicate command does, calling `completion` when done.
+    ///
+    /// The returned progress counts the files and completes when they have
+    /// all been handled. It can be added to a parent progress with
+    /// [`NSProgress::add_child`] to show several operations as one.
+    ///
+    /// It is safe to panic within `completion`. Panics will abort the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsworkspace/duplicate(_:completionhandler:)).
+    #[doc(alias = "duplicateURLs:completionHandler:")]
+    pub fn duplicate_urls<F>(&self, urls: &NSArray<NSURL>, completion: F) -> Arc<NSProgress>
+    where
+        F: FnOnce(NSWorkspaceFileOperationResult) + Send + 'static,
+    {
+        let (progress, block) = file_operation(
+            urls,
+            Some(NSProgressFileOperationKind::copying()),
+            completion,
+        );
+        let block: &Block<_, ()> = &block;
+
+        unsafe { _msg_send_any![self, duplicateURLs: urls completionHandler: block => ()] }
+        progress
+    }
+
+    /// Moves the files at `urls` to the Trash, calling `completion` when
+    /// done.
+    ///
+    /// The returned progress counts the files and completes when they have
+    /// all been handled. It can be added to a parent progress with
+    /// [`NSProgress::add_child`] to show several operations as one.
+    ///
+    /// It is safe to panic within `completion`. Panics will abort the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/appkit/nsworkspace/recycle(_:completionhandler:)).
+    #[doc(alias = "recycleURLs:completionHandler:")]
+    pub fn recycle_urls<F>(&self, urls: &NSArray<NSURL>, completion: F) -> Arc<NSProgress>
+    where
+        F: FnOnce(NSWorkspaceFileOperationResult) + Send + 'static,
+    {
+        let (progress, block) = file_operation(urls, None, completion);
+        let block: &Block<_, ()> = &block;
+
+        unsafe { _msg_send_any![self, recycleURLs: urls completionHandler: block => ()] }
+        progress
+    }
+}
+
+/// The outcome of an [`NSWorkspace`] file operation, such as
+/// [`duplicate_urls`](NSWorkspace::duplicate_urls), for each of its files.
+#[derive(Clone)]
+pub struct NSWorkspaceFileOperationResult {
+    /// The files that succeeded, each with its new location, such as the
+    /// duplicate or the file in the Trash.
+    pub new_urls: Vec<(Arc<NSURL>, Arc<NSURL>)>,
+
+    /// The files that failed.
+    pub failed_urls: Vec<Arc<NSURL>>,
+
+    /// The reason that files failed, if any did.
+    pub error: Option<Arc<NSError<'static>>>,
+}
+
+impl fmt::Debug for NSWorkspaceFileOperationResult {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("NSWorkspaceFileOperationResult")
+            .field("new_urls", &self.new_urls)
+            .field("failed_urls", &self.failed_urls)
+            .field(
+                "error",
+                &self
+                    .error
+                    .as_ref()
+                    .map(|error| error.localized_description()),
+            )
+            .finish()
+    }
+}
+
+impl NSWorkspaceFileOperationResult {
+    /// Returns `true` if every file succeeded.
+    #[inline]
+    pub fn is_success(&self) -> bool {
+        self.failed_urls.is_empty() && self.error.is_none()
+    }
+}
+
+type FileOperationBlock = RcBlock<(*const NSDictionary<NSURL, NSURL>, *const NSError<'static>), ()>;
+
+/// Returns the progress for an operation on `urls`, and the completion block
+/// that updates it and calls `completion`.
+fn file_operation<F>(
+    urls: &NSArray<NSURL>,
+    operation_kind: Option<&NSProgressFileOperationKind>,
+    completion: F,
+) -> (Arc<NSProgress>, FileOperationBlock)
+where
+    F: FnOnce(NSWorkspaceFileOperationResult) + Send + 'static,
+{
+    let count = urls.len();
+
+    let progress = NSProgress::discrete(count as i64);
+    progress.set_kind(Some(NSProgressKind::file()));
+    progress.set_file_operation_kind(operation_kind);
+    progress.set_cancellable(false);
+    progress.set_user_info_object(
+        Some(&NSNumber::from_integer(count as NSInteger)),
+        NSProgressUserInfoKey::file_total_count(),
+    );
+
+    let urls = urls.to_vec();
+    let completion = Cell::new(Some((completion, Arc::clone(&progress), urls)));
+    let block = RcBlock::new(
+        move |new_urls: *const NSDictionary<NSURL, NSURL>, error: *const NSError<'static>| {
+            let (completion, progress, urls) = match completion.take() {
+                Some(state) => state,
+                None => return,
+            };
+
+            let mut result = NSWorkspaceFileOperationResult {
+                new_urls: Vec::with_capacity(urls.len()),
+                failed_urls: Vec::new(),
+                error: unsafe { error.as_ref() }.map(Arc::retain),
+            };
+            let new_urls = unsafe { new_urls.as_ref() };
+            for url in urls {
+                match new_urls.and_then(|new_urls| new_urls.get(&url)) {
+                    Some(new_url) => result.new_urls.push((url, Arc::retain(new_url))),
+                    None => result.failed_urls.push(url),
+                }
+            }
+
+            progress.set_user_info_object(
+                Some(&NSNumber::from_integer(result.new_urls.len() as NSInteger)),
+                NSProgressUserInfoKey::file_completed_count(),
+            );
+            progress.set_completed_unit_count(progress.total_unit_count());
+
+            completion(result);
+        },
+    );
+
+    (progress, block)
+}
+
+/// A power or session change reported by [`NSWorkspace`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum NSWorkspacePowerEvent {
+    /// The system is about to sleep.
+    #[doc(alias = "NSWorkspaceWillSleepNotification")]
+    WillSleep,
+
+    /// The system woke from sleep.
+    #[doc(alias = "NSWorkspaceDidWakeNotification")]
+    DidWake,
+
+    /// The displays went to sleep.
+    #[doc(alias = "NSWorkspaceScreensDidSleepNotification")]
+    ScreensDidSleep,
+
+    /// The displays woke from sleep.
+    #[doc(alias = "NSWorkspaceScreensDidWakeNotification")]
+    ScreensDidWake,
+
+    /// The user's session became active.
+    #[doc(alias = "NSWorkspaceSessionDidBecomeActiveNotification")]
+    SessionDidBecomeActive,
+
+    /// The user's session became inactive, such as by switching users.
+    #[doc(alias = "NSWorkspaceSessionDidResignActiveNotification")]
+    SessionDidResignActive,
+
+    /// The user requested a logout or power off.
+    #[doc(alias = "NSWorkspaceWillPowerOffNotification")]
+    WillPowerOff,
+}
+
+impl NSWorkspacePowerEvent {
+    /// All events, in declaration order.
+    pub const ALL: [Self; 7] = [
+        Self::WillSleep,
+        Self::DidWake,
+        Self::ScreensDidSleep,
+        Self::ScreensDidWake,
+        Self::SessionDidBecomeActive,
+        Self::SessionDidResignActive,
+        Self::WillPowerOff,
+    ];
+
+    /// Returns the name of the notification posted for `self`.
+    #[inline]
+    pub fn notification_name(self) -> &'static NSNotificationName {
+        match self {
+            Self::WillSleep => NSNotificationName::workspace_will_sleep(),
+            Self::DidWake => NSNotificationName::workspace_did_wake(),
+            Self::ScreensDidSleep => NSNotificationName::workspace_screens_did_sleep(),
+            Self::ScreensDidWake => NSNotificationName::workspace_screens_did_wake(),
+            Self::SessionDidBecomeActive => {
+                NSNotificationName::workspace_session_did_become_active()
+            }
+            Self::SessionDidResignActive => {
+                NSNotificationName::workspace_session_did_resign_active()
+            }
+            Self::WillPowerOff => NSNotificationName::workspace_will_power_off(),
+        }
+    }
+}
@@ -0,0 +1,146 @@
+use crate::core::Arc;
+use crate::foundation::{NSData, NSString};
+use crate::objc::{NSInteger, NSObject};
+use std::fmt;
+
+objc_subclass! {
+    /// The result of signing in with an Apple ID.
+    ///
+    /// The user's name and email are only provided the first time they sign
+    /// in to the app, so they should be stored right away.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/authenticationservices/asauthorizationappleidcredential).
+    pub class ASAuthorizationAppleIDCredential: NSObject<'static>;
+}
+
+impl fmt::Debug for ASAuthorizationAppleIDCredential {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ASAuthorizationAppleIDCredential")
+            .field("user", &self.user())
+            .field("email", &self.email())
+            .field("real_user_status", &self.real_user_status())
+            .finish()
+    }
+}
+
+impl ASAuthorizationAppleIDCredential {
+    /// Returns the identifier of the user, which is stable across devices
+    /// for all apps of the same developer team.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/authenticationservices/asauthorizationappleidcredential/user).
+    #[inline]
+    pub fn user(&self) -> &NSString<'static> {
+        unsafe { _msg_send_any![self, user] }
+    }
+
+    /// Returns the JSON Web Token that a server verifies with Apple's public
+    /// keys to authenticate the user.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/authenticationservices/asauthorizationappleidcredential/identitytoken).
+    #[inline]
+    #[doc(alias = "identityToken")]
+    pub fn identity_token(&self) -> Option<Arc<NSData>> {
+        unsafe {
+            _msg_send_any![self, identityToken => *const NSData]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Returns the identity token as a string, such as for sending in a
+    /// request body.
+    #[inline]
+    pub fn identity_token_string(&self) -> Option<String> {
+        let token = self.identity_token()?;
+        String::from_utf8(token.to_vec()).ok()
+    }
+
+    /// Returns the short-lived code that a server exchanges with Apple for
+    /// refresh tokens.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/authenticationservices/asauthorizationappleidcredential/authorizationcode).
+    #[inline]
+    #[doc(alias = "authorizationCode")]
+    pub fn authorization_code(&self) -> Option<Arc<NSData>> {
+        unsafe {
+            _msg_send_any![self, authorizationCode => *const NSData]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Returns the user's email address, if it was requested and this is the
+    /// first time they signed in.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/authenticationservices/asauthorizationappleidcredential/email).
+    #[inline]
+    pub fn email(&self) -> Option<&NSString<'static>> {
+        unsafe { _msg_send_any![self, email] }
+    }
+
+    /// Returns the user's given name, if it was requested and this is the
+    /// first time they signed in.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/authenticationservices/asauthorizationappleidcredential/fullname).
+    #[inline]
+    #[doc(alias = "fullName")]
+    pub fn given_name(&self) -> Option<&NSString<'static>> {
+        unsafe {
+            let name = _msg_send_any![self, fullName => *const NSObject<'static>].as_ref()?;
+            _msg_send_any![name, givenName]
+        }
+    }
+
+    /// Returns the user's family name, if it was requested and this is the
+    /// first time they signed in.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/authenticationservices/asauthorizationappleidcredential/fullname).
+    #[inline]
+    #[doc(alias = "fullName")]
+    pub fn family_name(&self) -> Option<&NSString<'static>> {
+        unsafe {
+            let name = _msg_send_any![self, fullName => *const NSObject<'static>].as_ref()?;
+            _msg_send_any![name, familyName]
+        }
+    }
+
+    /// Returns the state set with
+    /// [`ASAuthorizationAppleIDRequest::set_state`](super::ASAuthorizationAppleIDRequest::set_state).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/authenticationservices/asauthorizationappleidcredential/state).
+    #[inline]
+    pub fn state(&self) -> Option<&NSString<'static>> {
+        unsafe { _msg_send_any![self, state] }
+    }
+
+    /// Returns whether the user appears to be a real person.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/authenticationservices/asauthorizationappleidcredential/realuserstatus).
+    #[inline]
+    #[doc(alias = "realUserStatus")]
+    pub fn real_user_status(&self) -> ASUserDetectionStatus {
+        unsafe { _msg_send_any![self, realUserStatus] }
+    }
+}
+
+/// Whether the user of a credential appears to be a real person, for
+/// deciding whether to add friction such as a CAPTCHA.
+///
+/// See [documentation](https://developer.apple.com/documentation/authenticationservices/asuserdetectionstatus).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ASUserDetectionStatus(pub NSInteger);
+
+impl ASUserDetectionStatus {
+    /// Detection is not supported on this platform.
+    #[doc(alias = "ASUserDetectionStatusUnsupported")]
+    pub const UNSUPPORTED: Self = Self(0);
+
+    /// Detection could not determine whether the user is real.
+    #[doc(alias = "ASUserDetectionStatusUnknown")]
+    pub const UNKNOWN: Self = Self(1);
+
+    /// The user is likely a real person.
+    #[doc(alias = "ASUserDetectionStatusLikelyReal")]
+    pub const LIKELY_REAL: Self = Self(2);
+}
@@ -0,0 +1,188 @@
+use crate::core::Arc;
+use crate::foundation::{NSArray, NSError, NSString};
+use crate::objc::{Block, ClassType, NSInteger, NSObject, RcBlock};
+use std::{cell::Cell, fmt};
+
+objc_subclass! {
+    /// Creates Sign in with Apple requests, and checks whether a user's
+    /// credential is still valid.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/authenticationservices/asauthorizationappleidprovider).
+    pub class ASAuthorizationAppleIDProvider: NSObject<'static>;
+}
+
+impl ASAuthorizationAppleIDProvider {
+    /// Creates a provider.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/authenticationservices/asauthorizationappleidprovider).
+    #[inline]
+    pub fn new() -> Arc<Self> {
+        unsafe { Self::class().alloc_init() }
+    }
+
+    /// Creates a request to sign in, which is performed with
+    /// [`ASAuthorizationController::perform`](super::ASAuthorizationController::perform).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/authenticationservices/asauthorizationappleidprovider/createrequest()).
+    #[inline]
+    #[doc(alias = "createRequest")]
+    pub fn create_request(&self) -> Arc<ASAuthorizationAppleIDRequest> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![
+                self,
+                createRequest => *const ASAuthorizationAppleIDRequest
+            ])
+        }
+    }
+
+    /// Calls `completion` with the state of the credential of the user
+    /// identified by `user_id`, which should be checked on every launch.
+    ///
+    /// It is safe to panic within `completion`. Panics will abort the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/authenticationservices/asauthorizationappleidprovider/getcredentialstate(foruserid:completion:)).
+    #[doc(alias = "getCredentialStateForUserID:completion:")]
+    pub fn get_credential_state<F>(&self, user_id: &NSString, completion: F)
+    where
+        F: FnOnce(Result<ASAuthorizationAppleIDProviderCredentialState, Arc<NSError<'static>>>)
+            + Send
+            + 'static,
+    {
+        let completion = Cell::new(Some(completion));
+        let block = RcBlock::new(
+            move |state: ASAuthorizationAppleIDProviderCredentialState,
+                  error: *const NSError<'static>| {
+                if let Some(completion) = completion.take() {
+                    completion(match unsafe { error.as_ref() } {
+                        Some(error) => Err(Arc::retain(error)),
+                        None => Ok(state),
+                    });
+                }
+            },
+        );
+        let block: &Block<_, ()> = &block;
+
+        unsafe {
+            _msg_send_any![
+                self,
+                getCredentialStateForUserID: user_id
+                completion: block
+                => ()
+            ]
+        }
+    }
+}
+
+/// The state of a user's Sign in with Apple credential.
+///
+/// See [documentation](https://developer.apple.com/documentation/authenticationservices/asauthorizationappleidprovider/credentialstate).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ASAuthorizationAppleIDProviderCredentialState(pub NSInteger);
+
+impl ASAuthorizationAppleIDProviderCredentialState {
+    /// The user stopped using Sign in with Apple for the app, so they
+    /// should be signed out.
+    #[doc(alias = "ASAuthorizationAppleIDProviderCredentialRevoked")]
+    pub const REVOKED: Self = Self(0);
+
+    /// The credential is valid.
+    #[doc(alias = "ASAuthorizationAppleIDProviderCredentialAuthorized")]
+    pub const AUTHORIZED: Self = Self(1);
+
+    /// The user was not found, such as when they never signed in.
+    #[doc(alias = "ASAuthorizationAppleIDProviderCredentialNotFound")]
+    pub const NOT_FOUND: Self = Self(2);
+
+    /// The app was transferred to another developer team, so the user
+    /// identifier must be migrated.
+    #[doc(alias = "ASAuthorizationAppleIDProviderCredentialTransferred")]
+    pub const TRANSFERRED: Self = Self(3);
+}
+
+objc_subclass! {
+    /// A request to sign in with an Apple ID, created by
+    /// [`ASAuthorizationAppleIDProvider::create_request`].
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/authenticationservices/asauthorizationappleidrequest).
+    pub class ASAuthorizationAppleIDRequest: NSObject<'static>;
+}
+
+impl ASAuthorizationAppleIDRequest {
+    /// Sets the information to request from the user, which is only
+    /// provided the first time they sign in to the app.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/authenticationservices/asauthorizationopenidrequest/requestedscopes).
+    #[inline]
+    #[doc(alias = "setRequestedScopes:")]
+    pub fn set_requested_scopes(&self, scopes: &[&ASAuthorizationScope]) {
+        let scopes = NSArray::from_slice(scopes);
+        unsafe { _msg_send_any![self, setRequestedScopes: &*scopes => ()] }
+    }
+
+    /// Sets the value included in the identity token, which a server uses to
+    /// prevent replay attacks.
+    ///
+    /// A hash of a random value, such as SHA-256, should be used, and the
+    /// value sent to the server.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/authenticationservices/asauthorizationopenidrequest/nonce).
+    #[inline]
+    #[doc(alias = "setNonce:")]
+    pub fn set_nonce(&self, nonce: Option<&NSString>) {
+        unsafe { _msg_send_any![self, setNonce: nonce => ()] }
+    }
+
+    /// Sets the value returned in the credential's
+    /// [`state`](super::ASAuthorizationAppleIDCredential::state), to verify
+    /// that the credential belongs to this request.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/authenticationservices/asauthorizationopenidrequest/state).
+    #[inline]
+    #[doc(alias = "setState:")]
+    pub fn set_state(&self, state: Option<&NSString>) {
+        unsafe { _msg_send_any![self, setState: state => ()] }
+    }
+}
+
+objc_object_wrapper! {
+    /// Information about the user that a Sign in with Apple request may ask
+    /// for.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/authenticationservices/asauthorization/scope).
+    #[derive(Ord, PartialOrd, Eq, PartialEq)]
+    pub wrapper ASAuthorizationScope: NSString<'static>;
+}
+
+impl fmt::Debug for ASAuthorizationScope {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl ASAuthorizationScope {
+    /// The user's name.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/authenticationservices/asauthorization/scope/fullname).
+    #[inline]
+    #[doc(alias = "ASAuthorizationScopeFullName")]
+    pub fn full_name() -> &'static Self {
+        extern "C" {
+            static ASAuthorizationScopeFullName: &'static ASAuthorizationScope;
+        }
+        unsafe { ASAuthorizationScopeFullName }
+    }
+
+    /// The user's email address, which may be a private relay address.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/authenticationservices/asauthorization/scope/email).
+    #[inline]
+    #[doc(alias = "ASAuthorizationScopeEmail")]
+    pub fn email() -> &'static Self {
+        extern "C" {
+            static ASAuthorizationScopeEmail: &'static ASAuthorizationScope;
+        }
+        unsafe { ASAuthorizationScopeEmail }
+    }
+}
@@ -0,0 +1,237 @@
+use super::{ASAuthorizationAppleIDCredential, ASAuthorizationAppleIDRequest};
+use crate::core::Arc;
+use crate::foundation::{NSArray, NSError};
+use crate::objc::{Class, ClassType, LazyClass, NSObject, Protocol, Sel};
+use std::{
+    ffi::{c_void, CStr},
+    panic::{self, AssertUnwindSafe},
+    process, ptr,
+};
+
+/// The result of signing in with an Apple ID.
+pub type ASAuthorizationAppleIDResult =
+    Result<Arc<ASAuthorizationAppleIDCredential>, Arc<NSError<'static>>>;
+
+objc_subclass! {
+    /// Presents the system sheet for an authorization request.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fruity::authentication_services::*;
+    /// # let window: &fruity::objc::NSObject<'static> = todo!();
+    ///
+    /// let request = ASAuthorizationAppleIDProvider::new().create_request();
+    /// request.set_requested_scopes(&[
+    ///     ASAuthorizationScope::full_name(),
+    ///     ASAuthorizationScope::email(),
+    /// ]);
+    ///
+    /// ASAuthorizationController::perform(&request, window, |result| match result {
+    ///     Ok(credential) => println!("signed in as {}", credential.user()),
+    ///     Err(error) => eprintln!("failed to sign in: {}", error),
+    /// });
+    /// ```
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/authenticationservices/asauthorizationcontroller).
+    pub class ASAuthorizationController: NSObject<'static>;
+}
+
+impl ASAuthorizationController {
+    /// Presents the sheet for `request` over the window `anchor`, calling
+    /// `completion` on the main thread with the credential, or an error in
+    /// the `ASAuthorizationErrorDomain` such as
+    /// [`ASAuthorizationErrorCanceled`](super::ASAuthorizationErrorCanceled).
+    ///
+    /// The anchor is an `NSWindow` on macOS and a `UIWindow` on iOS. This
+    /// must be called on the main thread.
+    ///
+    /// It is safe to panic within `completion`. Panics will abort the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/authenticationservices/asauthorizationcontroller/performrequests()).
+    #[doc(alias = "initWithAuthorizationRequests:")]
+    #[doc(alias = "performRequests")]
+    pub fn perform<F>(
+        request: &ASAuthorizationAppleIDRequest,
+        anchor: &NSObject<'static>,
+        completion: F,
+    ) where
+        F: FnOnce(ASAuthorizationAppleIDResult) + Send + 'static,
+    {
+        let requests = NSArray::from_slice(&[request]);
+
+        unsafe {
+            let controller: Arc<Self> = Self::class().alloc();
+
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(
+                    obj: Arc<ASAuthorizationController>,
+                    sel: Sel,
+                    requests: &NSArray<ASAuthorizationAppleIDRequest>,
+                ) -> Arc<ASAuthorizationController>;
+            }
+
+            let controller = objc_msgSend(
+                controller,
+                selector!(initWithAuthorizationRequests:),
+                &requests,
+            );
+            let delegate: Arc<NSObject<'static>> = delegate_class().alloc_init();
+
+            // The controller only weakly references its delegate, so both are
+            // kept alive by the state until the request completes.
+            let state = Box::new(DelegateState {
+                controller: controller.clone(),
+                delegate: delegate.clone(),
+                anchor: Arc::retain(anchor),
+                completion: Box::new(completion),
+            });
+            *state_slot(&delegate) = Box::into_raw(state) as *mut c_void;
+
+            _msg_send_any![&*controller, setDelegate: &*delegate => ()];
+            _msg_send_any![&*controller, setPresentationContextProvider: &*delegate => ()];
+            _msg_send_any![&*controller, performRequests => ()];
+        }
+    }
+}
+
+type Completion = dyn FnOnce(ASAuthorizationAppleIDResult) + Send;
+
+/// The state of a pending request, owned by its delegate object.
+struct DelegateState {
+    controller: Arc<ASAuthorizationController>,
+    delegate: Arc<NSObject<'static>>,
+    anchor: Arc<NSObject<'static>>,
+    completion: Box<Completion>,
+}
+
+/// The name of the instance variable that points to the `DelegateState`.
+const STATE_IVAR: &[u8] = b"rustAuthorizationState\0";
+
+#[inline]
+fn state_slot(object: &NSObject<'static>) -> *mut *mut c_void {
+    let name = unsafe { CStr::from_bytes_with_nul_unchecked(STATE_IVAR) };
+    object.ivar_ptr::<*mut c_void>(name)
+}
+
+/// Calls the completion of the request handled by `delegate`, if it has not
+/// been called.
+fn complete(delegate: &NSObject<'static>, result: ASAuthorizationAppleIDResult) {
+    let state = unsafe { state_slot(delegate).replace(ptr::null_mut()) } as *mut DelegateState;
+    if state.is_null() {
+        return;
+    }
+    let state = unsafe { Box::from_raw(state) };
+
+    let DelegateState {
+        controller,
+        delegate,
+        anchor,
+        completion,
+    } = *state;
+
+    // The controller and delegate are in use by the caller, so they are
+    // released once it returns.
+    extern "C" {
+        fn objc_autorelease(obj: *mut c_void) -> *mut c_void;
+    }
+    unsafe {
+        objc_autorelease(Arc::into_raw(controller) as *mut c_void);
+        objc_autorelease(Arc::into_raw(delegate) as *mut c_void);
+    }
+    drop(anchor);
+
+    // Unwinding into Objective-C is undefined behavior.
+    if panic::catch_unwind(AssertUnwindSafe(|| completion(result))).is_err() {
+        process::abort();
+    }
+}
+
+/// Returns the class of delegate objects, registering it with the
+/// Objective-C runtime on first use.
+fn delegate_class() -> &'static Class {
+    static CLASS: LazyClass = LazyClass::new("FruityAuthorizationControllerDelegate");
+
+    CLASS.get(NSObject::class(), |builder| {
+        builder.add_ivar::<*mut c_void>(CStr::from_bytes_with_nul(STATE_IVAR).unwrap());
+
+        for protocol in &[
+            &b"ASAuthorizationControllerDelegate\0"[..],
+            b"ASAuthorizationControllerPresentationContextProviding\0",
+        ] {
+            if let Some(protocol) = Protocol::get(CStr::from_bytes_with_nul(protocol).unwrap()) {
+                builder.add_protocol(protocol);
+            }
+        }
+
+        unsafe {
+            builder.add_method(
+                selector!(authorizationController:didCompleteWithAuthorization:),
+                did_complete_with_authorization as DidCompleteWithAuthorizationImp,
+            );
+            builder.add_method(
+                selector!(authorizationController:didCompleteWithError:),
+                did_complete_with_error as DidCompleteWithErrorImp,
+            );
+            builder.add_method(
+                selector!(presentationAnchorForAuthorizationController:),
+                presentation_anchor as PresentationAnchorImp,
+            );
+        }
+    })
+}
+
+type DidCompleteWithAuthorizationImp = extern "C" fn(
+    &NSObject<'static>,
+    Sel,
+    *const ASAuthorizationController,
+    *const NSObject<'static>,
+);
+
+extern "C" fn did_complete_with_authorization(
+    this: &NSObject<'static>,
+    _: Sel,
+    _controller: *const ASAuthorizationController,
+    authorization: *const NSObject<'static>,
+) {
+    let credential = unsafe {
+        let authorization = &*authorization;
+        &*_msg_send_any![authorization, credential => *const ASAuthorizationAppleIDCredential]
+    };
+    complete(this, Ok(Arc::retain(credential)));
+}
+
+type DidCompleteWithErrorImp = extern "C" fn(
+    &NSObject<'static>,
+    Sel,
+    *const ASAuthorizationController,
+    *const NSError<'static>,
+);
+
+extern "C" fn did_complete_with_error(
+    this: &NSObject<'static>,
+    _: Sel,
+    _controller: *const ASAuthorizationController,
+    error: *const NSError<'static>,
+) {
+    complete(this, Err(Arc::retain(unsafe { &*error })));
+}
+
+type PresentationAnchorImp = extern "C" fn(
+    &NSObject<'static>,
+    Sel,
+    *const ASAuthorizationController,
+) -> *const NSObject<'static>;
+
+extern "C" fn presentation_anchor(
+    this: &NSObject<'static>,
+    _: Sel,
+    _controller: *const ASAuthorizationController,
+) -> *const NSObject<'static> {
+    let state = unsafe { *state_slot(this) as *const DelegateState };
+    match unsafe { state.as_ref() } {
+        Some(state) => &*state.anchor,
+        None => ptr::null(),
+    }
+}
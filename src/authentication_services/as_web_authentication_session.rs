@@ -0,0 +1,308 @@
+use crate::core::Arc;
+use crate::foundation::{NSError, NSErrorDomain, NSString, NSURL};
+use crate::objc::{Block, Class, ClassType, LazyClass, NSObject, Protocol, RcBlock, Sel, BOOL};
+use std::{
+    cell::Cell,
+    error::Error,
+    ffi::{c_void, CStr},
+    fmt,
+};
+
+objc_subclass! {
+    /// A browser session in which the user signs in to a web service, such as
+    /// for an OAuth authorization code flow.
+    ///
+    /// The session shares cookies with Safari unless it is
+    /// [ephemeral](Self::set_prefers_ephemeral_session). It completes when
+    /// the page redirects to a URL with the callback scheme, whose query
+    /// typically contains the authorization code.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fruity::authentication_services::ASWebAuthenticationSession;
+    /// use fruity::foundation::NSURL;
+    /// use fruity::ns_string;
+    /// # let window: &fruity::objc::NSObject<'static> = todo!();
+    ///
+    /// let url = NSURL::from_string(ns_string!(
+    ///     "https://example.com/oauth/authorize?client_id=app&redirect_uri=myapp://callback"
+    /// ))
+    /// .unwrap();
+    ///
+    /// let session = ASWebAuthenticationSession::new(&url, Some(ns_string!("myapp")), |result| {
+    ///     match result {
+    ///         Ok(callback_url) => println!("redirected to {:?}", callback_url),
+    ///         Err(error) => eprintln!("failed to sign in: {}", error),
+    ///     }
+    /// });
+    /// session.set_presentation_anchor(window);
+    /// session.start();
+    /// ```
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/authenticationservices/aswebauthenticationsession).
+    pub class ASWebAuthenticationSession: NSObject<'static>;
+}
+
+impl ASWebAuthenticationSession {
+    /// Creates a session that loads `url`, and calls `completion` on the main
+    /// thread with the URL that the page redirects to with the scheme
+    /// `callback_scheme`.
+    ///
+    /// Without a callback scheme, the session only completes when it is
+    /// canceled. The returned session must be kept until it completes.
+    ///
+    /// It is safe to panic within `completion`. Panics will abort the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/authenticationservices/aswebauthenticationsession/init(url:callbackurlscheme:completionhandler:)).
+    #[doc(alias = "initWithURL:callbackURLScheme:completionHandler:")]
+    pub fn new<F>(url: &NSURL, callback_scheme: Option<&NSString>, completion: F) -> Arc<Self>
+    where
+        F: FnOnce(Result<Arc<NSURL>, ASWebAuthenticationSessionError>) + Send + 'static,
+    {
+        let completion = Cell::new(Some(completion));
+        let block = RcBlock::new(
+            move |callback_url: *const NSURL, error: *const NSError<'static>| {
+                if let Some(completion) = completion.take() {
+                    completion(unsafe {
+                        match callback_url.as_ref() {
+                            Some(callback_url) => Ok(Arc::retain(callback_url)),
+                            None => Err(ASWebAuthenticationSessionError::from_error(&*error)),
+                        }
+                    });
+                }
+            },
+        );
+        let block: &Block<_, ()> = &block;
+
+        unsafe {
+            let obj: Arc<Self> = Self::class().alloc();
+
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(
+                    obj: Arc<ASWebAuthenticationSession>,
+                    sel: Sel,
+                    url: &NSURL,
+                    callback_scheme: Option<&NSString>,
+                    completion: &Block<(*const NSURL, *const NSError<'static>), ()>,
+                ) -> Arc<ASWebAuthenticationSession>;
+            }
+
+            objc_msgSend(
+                obj,
+                selector!(initWithURL:callbackURLScheme:completionHandler:),
+                url,
+                callback_scheme,
+                block,
+            )
+        }
+    }
+
+    /// Returns `true` if the session does not share cookies or other browsing
+    /// data with Safari.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/authenticationservices/aswebauthenticationsession/prefersephemeralwebbrowsersession).
+    #[inline]
+    #[doc(alias = "prefersEphemeralWebBrowserSession")]
+    pub fn prefers_ephemeral_session(&self) -> bool {
+        unsafe { _msg_send_any![self, prefersEphemeralWebBrowserSession => BOOL] }.into()
+    }
+
+    /// Sets whether the session does not share cookies or other browsing data
+    /// with Safari, so that the user is not signed in automatically.
+    ///
+    /// This must be set before the session is started.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/authenticationservices/aswebauthenticationsession/prefersephemeralwebbrowsersession).
+    #[inline]
+    #[doc(alias = "setPrefersEphemeralWebBrowserSession:")]
+    pub fn set_prefers_ephemeral_session(&self, value: bool) {
+        unsafe {
+            _msg_send_any![self, setPrefersEphemeralWebBrowserSession: BOOL::from(value) => ()]
+        }
+    }
+
+    /// Sets the window that the session is presented over, which is an
+    /// `NSWindow` on macOS and a `UIWindow` on iOS.
+    ///
+    /// On macOS, this must be set before the session is started.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/authenticationservices/aswebauthenticationsession/presentationcontextprovider).
+    #[doc(alias = "setPresentationContextProvider:")]
+    pub fn set_presentation_anchor(&self, anchor: &NSObject<'static>) {
+        unsafe {
+            let provider: Arc<NSObject<'static>> = provider_class().alloc_init();
+
+            // The session only weakly references its provider, so the session
+            // retains the provider, which retains the anchor.
+            set_associated_object(&provider, &ANCHOR_KEY, anchor);
+            set_associated_object(self, &PROVIDER_KEY, &provider);
+
+            _msg_send_any![self, setPresentationContextProvider: &*provider => ()];
+        }
+    }
+
+    /// Returns `true` if the session can be started.
+    ///
+    /// Requires macOS 10.15.4 or later.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/authenticationservices/aswebauthenticationsession/canstart).
+    #[inline]
+    #[doc(alias = "canStart")]
+    pub fn can_start(&self) -> bool {
+        unsafe { _msg_send_any![self, canStart => BOOL] }.into()
+    }
+
+    /// Presents the session, returning `true` if it started.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/authenticationservices/aswebauthenticationsession/start()).
+    #[inline]
+    pub fn start(&self) -> bool {
+        unsafe { _msg_send_any![self, start => BOOL] }.into()
+    }
+
+    /// Dismisses the session, which completes with
+    /// [`ASWebAuthenticationSessionError::Canceled`].
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/authenticationservices/aswebauthenticationsession/cancel()).
+    #[inline]
+    pub fn cancel(&self) {
+        unsafe { _msg_send_any![self, cancel => ()] }
+    }
+}
+
+/// The reason an [`ASWebAuthenticationSession`] completed without a callback
+/// URL.
+///
+/// See [documentation](https://developer.apple.com/documentation/authenticationservices/aswebauthenticationsessionerror-swift.struct/code).
+#[derive(Clone)]
+pub enum ASWebAuthenticationSessionError {
+    /// The user dismissed the session, or it was
+    /// [canceled](ASWebAuthenticationSession::cancel).
+    #[doc(alias = "ASWebAuthenticationSessionErrorCodeCanceledLogin")]
+    Canceled,
+    /// No [presentation anchor](ASWebAuthenticationSession::set_presentation_anchor)
+    /// was set.
+    #[doc(alias = "ASWebAuthenticationSessionErrorCodePresentationContextNotProvided")]
+    PresentationContextNotProvided,
+    /// The presentation anchor cannot present the session, such as a window
+    /// that is not visible.
+    #[doc(alias = "ASWebAuthenticationSessionErrorCodePresentationContextInvalid")]
+    PresentationContextInvalid,
+    /// Another error occurred.
+    Other(Arc<NSError<'static>>),
+}
+
+impl fmt::Debug for ASWebAuthenticationSessionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Canceled => f.write_str("Canceled"),
+            Self::PresentationContextNotProvided => f.write_str("PresentationContextNotProvided"),
+            Self::PresentationContextInvalid => f.write_str("PresentationContextInvalid"),
+            Self::Other(error) => f.debug_tuple("Other").field(&error.code()).finish(),
+        }
+    }
+}
+
+impl fmt::Display for ASWebAuthenticationSessionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Canceled => f.write_str("web authentication session was canceled"),
+            Self::PresentationContextNotProvided => {
+                f.write_str("web authentication session has no presentation anchor")
+            }
+            Self::PresentationContextInvalid => {
+                f.write_str("web authentication session presentation anchor is invalid")
+            }
+            Self::Other(error) => error.fmt(f),
+        }
+    }
+}
+
+impl Error for ASWebAuthenticationSessionError {}
+
+impl ASWebAuthenticationSessionError {
+    fn from_error(error: &NSError<'static>) -> Self {
+        extern "C" {
+            static ASWebAuthenticationSessionErrorDomain: &'static NSErrorDomain;
+        }
+
+        let domain = error.domain();
+        let is_session_error = unsafe { *domain == *ASWebAuthenticationSessionErrorDomain };
+
+        match (is_session_error, error.code()) {
+            (true, 1) => Self::Canceled,
+            (true, 2) => Self::PresentationContextNotProvided,
+            (true, 3) => Self::PresentationContextInvalid,
+            _ => Self::Other(Arc::retain(error)),
+        }
+    }
+}
+
+/// `OBJC_ASSOCIATION_RETAIN_NONATOMIC`.
+const OBJC_ASSOCIATION_RETAIN_NONATOMIC: usize = 1;
+
+/// The key of the anchor associated with a provider.
+static ANCHOR_KEY: u8 = 0;
+
+/// The key of the provider associated with a session.
+static PROVIDER_KEY: u8 = 0;
+
+extern "C" {
+    fn objc_setAssociatedObject(
+        object: &NSObject<'static>,
+        key: *const c_void,
+        value: &NSObject<'static>,
+        policy: usize,
+    );
+    fn objc_getAssociatedObject(
+        object: &NSObject<'static>,
+        key: *const c_void,
+    ) -> *const NSObject<'static>;
+}
+
+#[inline]
+unsafe fn set_associated_object(
+    object: &NSObject<'static>,
+    key: &'static u8,
+    value: &NSObject<'static>,
+) {
+    let key = key as *const u8 as *const c_void;
+    objc_setAssociatedObject(object, key, value, OBJC_ASSOCIATION_RETAIN_NONATOMIC);
+}
+
+/// Returns the class of presentation context providers, registering it with
+/// the Objective-C runtime on first use.
+fn provider_class() -> &'static Class {
+    static CLASS: LazyClass = LazyClass::new("FruityWebAuthenticationPresentationContextProvider");
+
+    CLASS.get(NSObject::class(), |builder| {
+        let protocol = b"ASWebAuthenticationPresentationContextProviding\0";
+        if let Some(protocol) = Protocol::get(CStr::from_bytes_with_nul(protocol).unwrap()) {
+            builder.add_protocol(protocol);
+        }
+
+        unsafe {
+            builder.add_method(
+                selector!(presentationAnchorForWebAuthenticationSession:),
+                presentation_anchor as PresentationAnchorImp,
+            );
+        }
+    })
+}
+
+type PresentationAnchorImp = extern "C" fn(
+    &NSObject<'static>,
+    Sel,
+    *const ASWebAuthenticationSession,
+) -> *const NSObject<'static>;
+
+extern "C" fn presentation_anchor(
+    this: &NSObject<'static>,
+    _: Sel,
+    _session: *const ASWebAuthenticationSession,
+) -> *const NSObject<'static> {
+    let key = &ANCHOR_KEY as *const u8 as *const c_void;
+    unsafe { objc_getAssociatedObject(this, key) }
+}
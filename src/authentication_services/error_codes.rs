@@ -0,0 +1,21 @@
+//! `NSError` codes in the `ASAuthorizationErrorDomain`.
+
+#![allow(non_upper_case_globals)]
+
+use crate::objc::NSInteger;
+
+/// The authorization failed for an unknown reason.
+pub const ASAuthorizationErrorUnknown: NSInteger = 1000;
+
+/// The user canceled the authorization.
+pub const ASAuthorizationErrorCanceled: NSInteger = 1001;
+
+/// The authorization request received an invalid response.
+pub const ASAuthorizationErrorInvalidResponse: NSInteger = 1002;
+
+/// The authorization request was not handled.
+pub const ASAuthorizationErrorNotHandled: NSInteger = 1003;
+
+/// The authorization failed, such as when the app is missing the Sign in
+/// with Apple entitlement.
+pub const ASAuthorizationErrorFailed: NSInteger = 1004;
@@ -0,0 +1,36 @@
+//! [Authentication Services](https://developer.apple.com/documentation/authenticationservices)
+//! framework.
+//!
+//! # Feature Flag
+//!
+//! This module corresponds to the **`authentication_services`**
+//! [feature flag](../index.html#feature-flags).
+//!
+//! It also transitively enables [`foundation`](crate::foundation).
+//!
+//! # Scope
+//!
+//! These bindings cover Sign in with Apple: requesting an
+//! [`ASAuthorizationAppleIDCredential`] with [`ASAuthorizationController`],
+//! and checking whether it is still valid with
+//! [`ASAuthorizationAppleIDProvider`]. The app must be signed with the
+//! `com.apple.developer.applesignin` entitlement.
+//!
+//! Sign in with Apple requires macOS 10.15 or later.
+
+#![cfg(feature = "authentication_services")]
+
+mod as_authorization_apple_id_credential;
+mod as_authorization_apple_id_provider;
+mod as_authorization_controller;
+mod as_web_authentication_session;
+mod error_codes;
+
+pub use as_authorization_apple_id_credential::*;
+pub use as_authorization_apple_id_provider::*;
+pub use as_authorization_controller::*;
+pub use as_web_authentication_session::*;
+pub use error_codes::*;
+
+#[link(name = "AuthenticationServices", kind = "framework")]
+extern "C" {}
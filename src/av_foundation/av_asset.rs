@@ -0,0 +1,326 @@
+use super::{AVMediaType, CMTime, CMTimeRange};
+use crate::core::Arc;
+use crate::core_graphics::CGSize;
+use crate::foundation::{NSArray, NSData, NSNumber, NSString, NSURL};
+use crate::objc::{ClassType, NSObject, BOOL};
+use std::{fmt, ptr};
+
+objc_subclass! {
+    /// Timed media, such as a movie or audio file, made up of tracks.
+    ///
+    /// Properties are loaded on first access, which blocks until the media
+    /// is read. Assets should therefore be inspected off the main thread.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avasset).
+    pub class AVAsset: NSObject<'static>;
+}
+
+impl fmt::Debug for AVAsset {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AVAsset")
+            .field("duration", &self.duration())
+            .finish()
+    }
+}
+
+impl AVAsset {
+    /// Returns the length of the asset.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avasset/duration).
+    #[inline]
+    pub fn duration(&self) -> CMTime {
+        unsafe { _msg_send_any![self, duration] }
+    }
+
+    /// Returns all tracks of the asset.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avasset/tracks).
+    #[inline]
+    pub fn tracks(&self) -> Arc<NSArray<AVAssetTrack>> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, tracks => *const NSArray<AVAssetTrack>]) }
+    }
+
+    /// Returns the tracks of the asset that contain `media_type`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avasset/tracks(withmediatype:)).
+    #[inline]
+    #[doc(alias = "tracksWithMediaType:")]
+    pub fn tracks_with_media_type(&self, media_type: &AVMediaType) -> Arc<NSArray<AVAssetTrack>> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![
+                self,
+                tracksWithMediaType: media_type
+                => *const NSArray<AVAssetTrack>
+            ])
+        }
+    }
+
+    /// Returns the metadata of the asset in all formats, such as ID3 or
+    /// QuickTime metadata.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avasset/metadata).
+    #[inline]
+    pub fn metadata(&self) -> Arc<NSArray<AVMetadataItem>> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, metadata => *const NSArray<AVMetadataItem>]) }
+    }
+
+    /// Returns the metadata items that have a format-independent
+    /// [`common_key`](AVMetadataItem::common_key), such as `title` or
+    /// `artist`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avasset/commonmetadata).
+    #[inline]
+    #[doc(alias = "commonMetadata")]
+    pub fn common_metadata(&self) -> Arc<NSArray<AVMetadataItem>> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![self, commonMetadata => *const NSArray<AVMetadataItem>])
+        }
+    }
+
+    /// Returns `true` if the asset can be played.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avasset/isplayable).
+    #[inline]
+    #[doc(alias = "isPlayable")]
+    pub fn is_playable(&self) -> bool {
+        unsafe { _msg_send_any![self, isPlayable => BOOL] }.into()
+    }
+
+    /// Returns `true` if the asset can be read with an
+    /// [`AVAssetReader`](super::AVAssetReader).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avasset/isreadable).
+    #[inline]
+    #[doc(alias = "isReadable")]
+    pub fn is_readable(&self) -> bool {
+        unsafe { _msg_send_any![self, isReadable => BOOL] }.into()
+    }
+}
+
+objc_subclass! {
+    /// An asset loaded from a URL, such as a local file.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avurlasset).
+    pub class AVURLAsset: AVAsset;
+}
+
+impl fmt::Debug for AVURLAsset {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AVURLAsset")
+            .field("url", &self.url())
+            .finish()
+    }
+}
+
+impl AVURLAsset {
+    /// Creates an asset for the media at `url`.
+    ///
+    /// This does not fail if `url` cannot be read. Instead, the asset has no
+    /// tracks and is not [readable](AVAsset::is_readable).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avurlasset/init(url:options:)).
+    #[inline]
+    #[doc(alias = "URLAssetWithURL:options:")]
+    pub fn new(url: &NSURL) -> Arc<Self> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![
+                Self::class(),
+                URLAssetWithURL: url
+                options: ptr::null::<NSObject>()
+                => *const Self
+            ])
+        }
+    }
+
+    /// Returns the URL that the asset was created with.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avurlasset/url).
+    #[inline]
+    #[doc(alias = "URL")]
+    pub fn url(&self) -> Arc<NSURL> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, URL => *const NSURL]) }
+    }
+}
+
+objc_subclass! {
+    /// A track of a single media type within an [`AVAsset`].
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avassettrack).
+    pub class AVAssetTrack: NSObject<'static>;
+}
+
+impl fmt::Debug for AVAssetTrack {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AVAssetTrack")
+            .field("track_id", &self.track_id())
+            .field("media_type", &self.media_type())
+            .field("time_range", &self.time_range())
+            .finish()
+    }
+}
+
+impl AVAssetTrack {
+    /// Returns the identifier of the track within its asset.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avassettrack/trackid).
+    #[inline]
+    #[doc(alias = "trackID")]
+    pub fn track_id(&self) -> i32 {
+        unsafe { _msg_send_any![self, trackID] }
+    }
+
+    /// Returns the kind of media in the track.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avassettrack/mediatype).
+    #[inline]
+    #[doc(alias = "mediaType")]
+    pub fn media_type(&self) -> Arc<AVMediaType> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, mediaType => *const AVMediaType]) }
+    }
+
+    /// Returns the range of the asset's timeline covered by the track.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avassettrack/timerange).
+    #[inline]
+    #[doc(alias = "timeRange")]
+    pub fn time_range(&self) -> CMTimeRange {
+        unsafe { _msg_send_any![self, timeRange] }
+    }
+
+    /// Returns the size of video frames before any transform is applied, or
+    /// zero for tracks without video.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avassettrack/naturalsize).
+    #[inline]
+    #[doc(alias = "naturalSize")]
+    pub fn natural_size(&self) -> CGSize {
+        unsafe { _msg_send_any![self, naturalSize] }
+    }
+
+    /// Returns the frame rate of a video track in frames per second.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avassettrack/nominalframerate).
+    #[inline]
+    #[doc(alias = "nominalFrameRate")]
+    pub fn nominal_frame_rate(&self) -> f32 {
+        unsafe { _msg_send_any![self, nominalFrameRate] }
+    }
+
+    /// Returns the estimated data rate of the track in bits per second.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avassettrack/estimateddatarate).
+    #[inline]
+    #[doc(alias = "estimatedDataRate")]
+    pub fn estimated_data_rate(&self) -> f32 {
+        unsafe { _msg_send_any![self, estimatedDataRate] }
+    }
+
+    /// Returns the metadata of the track.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avassettrack/metadata).
+    #[inline]
+    pub fn metadata(&self) -> Arc<NSArray<AVMetadataItem>> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, metadata => *const NSArray<AVMetadataItem>]) }
+    }
+}
+
+objc_subclass! {
+    /// A metadata entry of an asset or track, such as its title or artwork.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avmetadataitem).
+    pub class AVMetadataItem: NSObject<'static>;
+}
+
+impl fmt::Debug for AVMetadataItem {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AVMetadataItem")
+            .field("identifier", &self.identifier())
+            .field("string_value", &self.string_value())
+            .finish()
+    }
+}
+
+impl AVMetadataItem {
+    /// Returns the key and format of the item combined, such as
+    /// `id3/TIT2`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avmetadataitem/identifier).
+    #[inline]
+    pub fn identifier(&self) -> Option<Arc<NSString<'static>>> {
+        unsafe {
+            _msg_send_any![self, identifier => *const NSString<'static>]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Returns the format-independent key of the item, such as `title`, or
+    /// [`None`] if it has none.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avmetadataitem/commonkey).
+    #[inline]
+    #[doc(alias = "commonKey")]
+    pub fn common_key(&self) -> Option<Arc<NSString<'static>>> {
+        unsafe {
+            _msg_send_any![self, commonKey => *const NSString<'static>]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Returns the value of the item, whatever its type.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avmetadataitem/value).
+    #[inline]
+    pub fn value(&self) -> Option<Arc<NSObject<'static>>> {
+        unsafe {
+            _msg_send_any![self, value => *const NSObject<'static>]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Returns the value as a string, or [`None`] if it cannot be converted.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avmetadataitem/stringvalue).
+    #[inline]
+    #[doc(alias = "stringValue")]
+    pub fn string_value(&self) -> Option<Arc<NSString<'static>>> {
+        unsafe {
+            _msg_send_any![self, stringValue => *const NSString<'static>]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Returns the value as a number, or [`None`] if it cannot be converted.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avmetadataitem/numbervalue).
+    #[inline]
+    #[doc(alias = "numberValue")]
+    pub fn number_value(&self) -> Option<Arc<NSNumber>> {
+        unsafe {
+            _msg_send_any![self, numberValue => *const NSNumber]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Returns the value as data, such as encoded artwork, or [`None`] if it
+    /// cannot be converted.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avmetadataitem/datavalue).
+    #[inline]
+    #[doc(alias = "dataValue")]
+    pub fn data_value(&self) -> Option<Arc<NSData>> {
+        unsafe {
+            _msg_send_any![self, dataValue => *const NSData]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+}
@@ -0,0 +1,115 @@
+use super::{AVAsset, CMTime};
+use crate::core::Arc;
+use crate::core_graphics::{CGImage, CGSize};
+use crate::foundation::NSError;
+use crate::objc::{ClassType, NSObject, BOOL};
+use std::ptr;
+
+objc_subclass! {
+    /// Renders still images of an [`AVAsset`], such as for thumbnails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fruity::av_foundation::{AVAssetImageGenerator, AVURLAsset, CMTime};
+    /// use fruity::core_graphics::CGSize;
+    /// use fruity::foundation::{NSString, NSURL};
+    ///
+    /// let url = NSURL::from_file_path(&NSString::from_str("movie.mov"));
+    /// let asset = AVURLAsset::new(&url);
+    ///
+    /// let generator = AVAssetImageGenerator::new(&asset);
+    /// generator.set_applies_preferred_track_transform(true);
+    /// generator.set_maximum_size(CGSize { width: 320.0, height: 320.0 });
+    ///
+    /// let (image, actual_time) = generator
+    ///     .copy_cg_image(CMTime::from_seconds(10.0, 600))
+    ///     .unwrap();
+    /// ```
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avassetimagegenerator).
+    pub class AVAssetImageGenerator: NSObject<'static>;
+}
+
+impl AVAssetImageGenerator {
+    /// Creates a generator for the video tracks of `asset`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avassetimagegenerator/init(asset:)).
+    #[inline]
+    #[doc(alias = "assetImageGeneratorWithAsset:")]
+    pub fn new(asset: &AVAsset) -> Arc<Self> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![
+                Self::class(),
+                assetImageGeneratorWithAsset: asset
+                => *const Self
+            ])
+        }
+    }
+
+    /// Sets whether the track's preferred transform is applied, so that
+    /// images have the orientation in which video is displayed.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avassetimagegenerator/appliespreferredtracktransform).
+    #[inline]
+    #[doc(alias = "setAppliesPreferredTrackTransform:")]
+    pub fn set_applies_preferred_track_transform(&self, value: bool) {
+        unsafe { _msg_send_any![self, setAppliesPreferredTrackTransform: BOOL::from(value) => ()] }
+    }
+
+    /// Sets the size that images are scaled down to fit within, preserving
+    /// aspect ratio. A zero size leaves images at their natural size.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avassetimagegenerator/maximumsize).
+    #[inline]
+    #[doc(alias = "setMaximumSize:")]
+    pub fn set_maximum_size(&self, size: CGSize) {
+        unsafe { _msg_send_any![self, setMaximumSize: size => ()] }
+    }
+
+    /// Sets how far before and after a requested time an image may be
+    /// taken from.
+    ///
+    /// Larger tolerances are faster, since the nearest key frame can be used
+    /// without decoding the frames after it. Use [`CMTime::ZERO`] for both to
+    /// get the exact frame.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avassetimagegenerator/requestedtimetolerancebefore).
+    #[inline]
+    #[doc(alias = "setRequestedTimeToleranceBefore:")]
+    #[doc(alias = "setRequestedTimeToleranceAfter:")]
+    pub fn set_requested_time_tolerance(&self, before: CMTime, after: CMTime) {
+        unsafe {
+            _msg_send_any![self, setRequestedTimeToleranceBefore: before => ()];
+            _msg_send_any![self, setRequestedTimeToleranceAfter: after => ()];
+        }
+    }
+
+    /// Renders the image at `time`, returning it along with the time it was
+    /// actually taken from.
+    ///
+    /// This blocks until the image is rendered.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avassetimagegenerator/copycgimage(at:actualtime:)).
+    #[doc(alias = "copyCGImageAtTime:actualTime:error:")]
+    pub fn copy_cg_image(
+        &self,
+        time: CMTime,
+    ) -> Result<(Arc<CGImage>, CMTime), Arc<NSError<'static>>> {
+        let mut actual_time = CMTime::INVALID;
+        let mut error: *const NSError<'static> = ptr::null();
+        unsafe {
+            let image: *const CGImage = _msg_send_any![
+                self,
+                copyCGImageAtTime: time
+                actualTime: &mut actual_time
+                error: &mut error
+            ];
+            if image.is_null() {
+                Err(Arc::retain_raw(error))
+            } else {
+                Ok((Arc::from_raw(image), actual_time))
+            }
+        }
+    }
+}
@@ -0,0 +1,300 @@
+use super::{sys, AVAsset, AVAssetTrack, CMSampleBuffer, CMTimeRange};
+use crate::core::Arc;
+use crate::core_video::CVPixelFormatType;
+use crate::foundation::{NSDictionary, NSError, NSNumber, NSString};
+use crate::objc::{ClassType, NSInteger, NSObject, BOOL};
+use std::{fmt, ptr};
+
+objc_subclass! {
+    /// Reads decoded media samples from an [`AVAsset`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fruity::av_foundation::{AVAssetReader, AVAssetReaderTrackOutput, AVMediaType, AVURLAsset};
+    /// use fruity::core_video::CVPixelFormatType;
+    /// use fruity::foundation::{NSString, NSURL};
+    ///
+    /// let url = NSURL::from_file_path(&NSString::from_str("movie.mov"));
+    /// let asset = AVURLAsset::new(&url);
+    /// let tracks = asset.tracks_with_media_type(AVMediaType::video());
+    /// let track = tracks.first().unwrap();
+    ///
+    /// let reader = AVAssetReader::new(&asset).unwrap();
+    /// let output = AVAssetReaderTrackOutput::video(track, CVPixelFormatType::BGRA32);
+    /// assert!(reader.add_output(&output));
+    /// assert!(reader.start_reading());
+    ///
+    /// while let Some(sample) = output.copy_next_sample_buffer() {
+    ///     let time = sample.presentation_time_stamp();
+    ///     let frame = sample.image_buffer().unwrap();
+    ///     // ...
+    /// }
+    /// ```
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avassetreader).
+    pub class AVAssetReader: NSObject<'static>;
+}
+
+impl fmt::Debug for AVAssetReader {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AVAssetReader")
+            .field("status", &self.status())
+            .finish()
+    }
+}
+
+impl AVAssetReader {
+    /// Creates a reader for `asset`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avassetreader/init(asset:)).
+    #[inline]
+    #[doc(alias = "assetReaderWithAsset:error:")]
+    pub fn new(asset: &AVAsset) -> Result<Arc<Self>, Arc<NSError<'static>>> {
+        let mut error: *const NSError<'static> = ptr::null();
+        unsafe {
+            let reader: *const Self = _msg_send_any![
+                Self::class(),
+                assetReaderWithAsset: asset
+                error: &mut error
+            ];
+            match reader.as_ref() {
+                Some(reader) => Ok(Arc::retain(reader)),
+                None => Err(Arc::retain_raw(error)),
+            }
+        }
+    }
+
+    /// Returns the asset being read.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avassetreader/asset).
+    #[inline]
+    pub fn asset(&self) -> Arc<AVAsset> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, asset => *const AVAsset]) }
+    }
+
+    /// Returns `true` if `output` can be added.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avassetreader/canadd(_:)).
+    #[inline]
+    #[doc(alias = "canAddOutput:")]
+    pub fn can_add_output(&self, output: &AVAssetReaderOutput) -> bool {
+        unsafe { _msg_send_any![self, canAddOutput: output => BOOL] }.into()
+    }
+
+    /// Adds `output` if it [can be added](Self::can_add_output), returning
+    /// whether it was.
+    ///
+    /// Outputs must be added before reading starts.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avassetreader/add(_:)).
+    #[inline]
+    #[doc(alias = "addOutput:")]
+    pub fn add_output(&self, output: &AVAssetReaderOutput) -> bool {
+        if !self.can_add_output(output) {
+            return false;
+        }
+        unsafe { _msg_send_any![self, addOutput: output => ()] }
+        true
+    }
+
+    /// Limits reading to `time_range` of the asset's timeline.
+    ///
+    /// This must be called before reading starts.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avassetreader/timerange).
+    #[inline]
+    #[doc(alias = "setTimeRange:")]
+    pub fn set_time_range(&self, time_range: CMTimeRange) {
+        unsafe { _msg_send_any![self, setTimeRange: time_range => ()] }
+    }
+
+    /// Prepares outputs to provide samples, returning `false` if reading
+    /// failed to start.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avassetreader/startreading()).
+    #[inline]
+    #[doc(alias = "startReading")]
+    pub fn start_reading(&self) -> bool {
+        unsafe { _msg_send_any![self, startReading => BOOL] }.into()
+    }
+
+    /// Stops reading before all samples have been read.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avassetreader/cancelreading()).
+    #[inline]
+    #[doc(alias = "cancelReading")]
+    pub fn cancel_reading(&self) {
+        unsafe { _msg_send_any![self, cancelReading => ()] }
+    }
+
+    /// Returns the state of reading.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avassetreader/status).
+    #[inline]
+    pub fn status(&self) -> AVAssetReaderStatus {
+        unsafe { _msg_send_any![self, status] }
+    }
+
+    /// Returns the reason that reading [failed](AVAssetReaderStatus::FAILED).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avassetreader/error).
+    #[inline]
+    pub fn error(&self) -> Option<Arc<NSError<'static>>> {
+        unsafe {
+            _msg_send_any![self, error => *const NSError<'static>]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+}
+
+/// The state of an [`AVAssetReader`].
+///
+/// See [documentation](https://developer.apple.com/documentation/avfoundation/avassetreader/status-swift.enum).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct AVAssetReaderStatus(pub NSInteger);
+
+impl AVAssetReaderStatus {
+    /// Reading has not started.
+    #[doc(alias = "AVAssetReaderStatusUnknown")]
+    pub const UNKNOWN: Self = Self(0);
+
+    /// Samples are available from outputs.
+    #[doc(alias = "AVAssetReaderStatusReading")]
+    pub const READING: Self = Self(1);
+
+    /// All samples have been read.
+    #[doc(alias = "AVAssetReaderStatusCompleted")]
+    pub const COMPLETED: Self = Self(2);
+
+    /// Reading stopped because of an [error](AVAssetReader::error).
+    #[doc(alias = "AVAssetReaderStatusFailed")]
+    pub const FAILED: Self = Self(3);
+
+    /// Reading was [cancelled](AVAssetReader::cancel_reading).
+    #[doc(alias = "AVAssetReaderStatusCancelled")]
+    pub const CANCELLED: Self = Self(4);
+}
+
+objc_subclass! {
+    /// A source of samples added to an [`AVAssetReader`].
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avassetreaderoutput).
+    pub class AVAssetReaderOutput: NSObject<'static>;
+}
+
+impl AVAssetReaderOutput {
+    /// Returns the next sample, or [`None`] once all samples have been read
+    /// or reading failed.
+    ///
+    /// This blocks until the sample is decoded.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avassetreaderoutput/copynextsamplebuffer()).
+    #[inline]
+    #[doc(alias = "copyNextSampleBuffer")]
+    pub fn copy_next_sample_buffer(&self) -> Option<Arc<CMSampleBuffer>> {
+        unsafe {
+            let sample: *const CMSampleBuffer = _msg_send_any![self, copyNextSampleBuffer];
+            if sample.is_null() {
+                None
+            } else {
+                Some(Arc::from_raw(sample))
+            }
+        }
+    }
+
+    /// Sets whether samples are copied before being returned, which is the
+    /// default.
+    ///
+    /// Disabling this avoids a copy, but samples must then not be modified.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avassetreaderoutput/alwayscopiessampledata).
+    #[inline]
+    #[doc(alias = "setAlwaysCopiesSampleData:")]
+    pub fn set_always_copies_sample_data(&self, value: bool) {
+        unsafe { _msg_send_any![self, setAlwaysCopiesSampleData: BOOL::from(value) => ()] }
+    }
+}
+
+objc_subclass! {
+    /// Reads samples from a single [`AVAssetTrack`].
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avassetreadertrackoutput).
+    pub class AVAssetReaderTrackOutput: AVAssetReaderOutput;
+}
+
+impl AVAssetReaderTrackOutput {
+    /// Creates an output that decodes `track` according to `settings`, or
+    /// provides samples in their stored format if `settings` is [`None`].
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avassetreadertrackoutput/init(track:outputsettings:)).
+    #[inline]
+    #[doc(alias = "assetReaderTrackOutputWithTrack:outputSettings:")]
+    pub fn new(
+        track: &AVAssetTrack,
+        settings: Option<&NSDictionary<NSString<'static>, NSObject<'static>>>,
+    ) -> Arc<Self> {
+        let settings = settings.map_or(ptr::null(), |settings| settings as *const _);
+        unsafe {
+            Arc::retain_raw(_msg_send_any![
+                Self::class(),
+                assetReaderTrackOutputWithTrack: track
+                outputSettings: settings
+                => *const Self
+            ])
+        }
+    }
+
+    /// Creates an output that decodes video frames of `track` into pixel
+    /// buffers of `format`.
+    #[inline]
+    pub fn video(track: &AVAssetTrack, format: CVPixelFormatType) -> Arc<Self> {
+        let format = NSNumber::from_unsigned_int(format.0);
+        unsafe {
+            let settings =
+                NSDictionary::from_slices(&[sys::kCVPixelBufferPixelFormatTypeKey], &[&***format]);
+            Self::new(track, Some(&settings))
+        }
+    }
+
+    /// Creates an output that decodes audio of `track` into interleaved
+    /// native-endian 32-bit float samples.
+    #[inline]
+    pub fn audio_f32(track: &AVAssetTrack) -> Arc<Self> {
+        let format_id = NSNumber::from_unsigned_int(sys::kAudioFormatLinearPCM);
+        let bit_depth = NSNumber::from_int(32);
+        let is_float = NSNumber::from_bool(true);
+        let is_big_endian = NSNumber::from_bool(cfg!(target_endian = "big"));
+        let is_non_interleaved = NSNumber::from_bool(false);
+        unsafe {
+            let settings = NSDictionary::from_slices(
+                &[
+                    sys::AVFormatIDKey,
+                    sys::AVLinearPCMBitDepthKey,
+                    sys::AVLinearPCMIsFloatKey,
+                    sys::AVLinearPCMIsBigEndianKey,
+                    sys::AVLinearPCMIsNonInterleaved,
+                ],
+                &[
+                    &***format_id,
+                    &***bit_depth,
+                    &***is_float,
+                    &***is_big_endian,
+                    &***is_non_interleaved,
+                ],
+            );
+            Self::new(track, Some(&settings))
+        }
+    }
+
+    /// Returns the track that samples are read from.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avassetreadertrackoutput/track).
+    #[inline]
+    pub fn track(&self) -> Arc<AVAssetTrack> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, track => *const AVAssetTrack]) }
+    }
+}
@@ -0,0 +1,366 @@
+use super::{AVCaptureDeviceFormat, AVMediaType};
+use crate::core::Arc;
+use crate::foundation::{NSArray, NSError, NSString};
+use crate::objc::{Block, ClassType, NSInteger, NSObject, RcBlock, BOOL};
+use std::{cell::Cell, fmt, ptr};
+
+objc_subclass! {
+    /// A camera, microphone, or other device that provides media for
+    /// capture.
+    ///
+    /// Devices are found with
+    /// [`default_for_media_type`](Self::default_for_media_type) or an
+    /// [`AVCaptureDeviceDiscoverySession`], and are added to an
+    /// [`AVCaptureSession`](super::AVCaptureSession) through an
+    /// [`AVCaptureDeviceInput`](super::AVCaptureDeviceInput).
+    ///
+    /// Capturing requires the user's permission, which should be requested
+    /// with [`request_access`](Self::request_access) beforehand. On macOS,
+    /// the app's `Info.plist` must include `NSCameraUsageDescription` or
+    /// `NSMicrophoneUsageDescription`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturedevice).
+    pub class AVCaptureDevice: NSObject<'static>;
+}
+
+impl fmt::Debug for AVCaptureDevice {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AVCaptureDevice")
+            .field("unique_id", &self.unique_id())
+            .field("localized_name", &self.localized_name())
+            .finish()
+    }
+}
+
+/// Finding devices.
+impl AVCaptureDevice {
+    /// Returns the system's default device for `media_type`, or [`None`] if
+    /// there are no devices for it.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturedevice/default(for:)).
+    #[inline]
+    #[doc(alias = "defaultDeviceWithMediaType:")]
+    pub fn default_for_media_type(media_type: &AVMediaType) -> Option<Arc<Self>> {
+        unsafe {
+            _msg_send_any![Self::class(), defaultDeviceWithMediaType: media_type => *const Self]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Returns the device whose [`unique_id`](Self::unique_id) is
+    /// `unique_id`, or [`None`] if it is not available.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturedevice/init(uniqueid:)).
+    #[inline]
+    #[doc(alias = "deviceWithUniqueID:")]
+    pub fn with_unique_id(unique_id: &NSString) -> Option<Arc<Self>> {
+        unsafe {
+            _msg_send_any![Self::class(), deviceWithUniqueID: unique_id => *const Self]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+}
+
+/// Authorization.
+impl AVCaptureDevice {
+    /// Returns whether the app may capture `media_type`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturedevice/authorizationstatus(for:)).
+    #[inline]
+    #[doc(alias = "authorizationStatusForMediaType:")]
+    pub fn authorization_status(media_type: &AVMediaType) -> AVAuthorizationStatus {
+        unsafe { _msg_send_any![Self::class(), authorizationStatusForMediaType: media_type] }
+    }
+
+    /// Asks the user for permission to capture `media_type` if they have not
+    /// been asked yet, then calls `completion` on an arbitrary thread with
+    /// whether access is granted.
+    ///
+    /// It is safe to panic within `completion`. Panics will abort the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturedevice/requestaccess(for:completionhandler:)).
+    #[inline]
+    #[doc(alias = "requestAccessForMediaType:completionHandler:")]
+    pub fn request_access<F>(media_type: &AVMediaType, completion: F)
+    where
+        F: FnOnce(bool) + Send + 'static,
+    {
+        let completion = Cell::new(Some(completion));
+        let block = RcBlock::new(move |granted: BOOL| {
+            if let Some(completion) = completion.take() {
+                completion(granted.into());
+            }
+        });
+        let block: &Block<(BOOL,), ()> = &block;
+
+        unsafe {
+            _msg_send_any![
+                Self::class(),
+                requestAccessForMediaType: media_type
+                completionHandler: block
+                => ()
+            ]
+        }
+    }
+}
+
+/// Device information.
+impl AVCaptureDevice {
+    /// Returns an identifier that persists across connections and restarts.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturedevice/uniqueid).
+    #[inline]
+    #[doc(alias = "uniqueID")]
+    pub fn unique_id(&self) -> Arc<NSString<'static>> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, uniqueID => *const NSString<'static>]) }
+    }
+
+    /// Returns the name of the device for display to the user.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturedevice/localizedname).
+    #[inline]
+    #[doc(alias = "localizedName")]
+    pub fn localized_name(&self) -> Arc<NSString<'static>> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, localizedName => *const NSString<'static>]) }
+    }
+
+    /// Returns an identifier for the model of the device.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturedevice/modelid).
+    #[inline]
+    #[doc(alias = "modelID")]
+    pub fn model_id(&self) -> Arc<NSString<'static>> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, modelID => *const NSString<'static>]) }
+    }
+
+    /// Returns where the device is located on the hardware.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturedevice/position-swift.property).
+    #[inline]
+    pub fn position(&self) -> AVCaptureDevicePosition {
+        unsafe { _msg_send_any![self, position] }
+    }
+
+    /// Returns `true` if the device provides `media_type`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturedevice/hasmediatype(_:)).
+    #[inline]
+    #[doc(alias = "hasMediaType:")]
+    pub fn has_media_type(&self, media_type: &AVMediaType) -> bool {
+        unsafe { _msg_send_any![self, hasMediaType: media_type => BOOL] }.into()
+    }
+
+    /// Returns `true` if the device is connected and can be used.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturedevice/isconnected).
+    #[inline]
+    #[doc(alias = "isConnected")]
+    pub fn is_connected(&self) -> bool {
+        unsafe { _msg_send_any![self, isConnected => BOOL] }.into()
+    }
+}
+
+/// Configuration.
+impl AVCaptureDevice {
+    /// Returns the formats that the device can capture in.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturedevice/formats).
+    #[inline]
+    pub fn formats(&self) -> Arc<NSArray<AVCaptureDeviceFormat>> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![self, formats => *const NSArray<AVCaptureDeviceFormat>])
+        }
+    }
+
+    /// Returns the format that the device currently captures in.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturedevice/activeformat).
+    #[inline]
+    #[doc(alias = "activeFormat")]
+    pub fn active_format(&self) -> Arc<AVCaptureDeviceFormat> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![self, activeFormat => *const AVCaptureDeviceFormat])
+        }
+    }
+
+    /// Sets the format that the device captures in to one of its
+    /// [`formats`](Self::formats).
+    ///
+    /// The device must be locked with
+    /// [`lock_for_configuration`](Self::lock_for_configuration). Setting a
+    /// format overrides the
+    /// [session preset](super::AVCaptureSession::set_session_preset) with
+    /// [`input_priority`](super::AVCaptureSessionPreset::input_priority).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturedevice/activeformat).
+    #[inline]
+    #[doc(alias = "setActiveFormat:")]
+    pub fn set_active_format(&self, format: &AVCaptureDeviceFormat) {
+        unsafe { _msg_send_any![self, setActiveFormat: format] }
+    }
+
+    /// Requests exclusive access to the device's configuration, which is
+    /// needed to change properties such as the
+    /// [active format](Self::set_active_format).
+    ///
+    /// The lock is held until
+    /// [`unlock_for_configuration`](Self::unlock_for_configuration) is called.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturedevice/lockforconfiguration()).
+    #[inline]
+    #[doc(alias = "lockForConfiguration:")]
+    pub fn lock_for_configuration(&self) -> Result<(), Arc<NSError<'static>>> {
+        unsafe {
+            let mut error: *const NSError<'static> = ptr::null();
+            let locked: bool =
+                _msg_send_any![self, lockForConfiguration: &mut error => BOOL].into();
+
+            if locked {
+                Ok(())
+            } else {
+                Err(Arc::retain_raw(error))
+            }
+        }
+    }
+
+    /// Releases the lock acquired with
+    /// [`lock_for_configuration`](Self::lock_for_configuration).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturedevice/unlockforconfiguration()).
+    #[inline]
+    #[doc(alias = "unlockForConfiguration")]
+    pub fn unlock_for_configuration(&self) {
+        unsafe { _msg_send_any![self, unlockForConfiguration] }
+    }
+}
+
+objc_subclass! {
+    /// A query for capture devices of certain types.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturedevice/discoverysession).
+    pub class AVCaptureDeviceDiscoverySession: NSObject<'static>;
+}
+
+impl fmt::Debug for AVCaptureDeviceDiscoverySession {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AVCaptureDeviceDiscoverySession")
+            .field("devices", &self.devices())
+            .finish()
+    }
+}
+
+impl AVCaptureDeviceDiscoverySession {
+    /// Finds devices of `device_types` that provide `media_type`, if any, and
+    /// are at `position`.
+    ///
+    /// Use [`AVCaptureDevicePosition::UNSPECIFIED`] to find devices at any
+    /// position.
+    ///
+    /// Requires macOS 10.15 or iOS 10.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturedevice/discoverysession/init(devicetypes:mediatype:position:)).
+    #[inline]
+    #[doc(alias = "discoverySessionWithDeviceTypes:mediaType:position:")]
+    pub fn new(
+        device_types: &[&AVCaptureDeviceType],
+        media_type: Option<&AVMediaType>,
+        position: AVCaptureDevicePosition,
+    ) -> Arc<Self> {
+        let device_types = NSArray::from_slice(device_types);
+        unsafe {
+            Arc::retain_raw(_msg_send_any![
+                Self::class(),
+                discoverySessionWithDeviceTypes: &*device_types
+                mediaType: media_type
+                position: position
+                => *const Self
+            ])
+        }
+    }
+
+    /// Returns the devices that are currently available.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturedevice/discoverysession/devices).
+    #[inline]
+    pub fn devices(&self) -> Arc<NSArray<AVCaptureDevice>> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, devices => *const NSArray<AVCaptureDevice>]) }
+    }
+}
+
+av_string_wrapper! {
+    /// A kind of capture device, used to find devices with an
+    /// [`AVCaptureDeviceDiscoverySession`].
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturedevice/devicetype-swift.struct).
+    pub wrapper AVCaptureDeviceType;
+}
+
+impl AVCaptureDeviceType {
+    av_string_constant! {
+        /// A built-in camera, such as a FaceTime camera.
+        built_in_wide_angle_camera: AVCaptureDeviceType = "AVCaptureDeviceTypeBuiltInWideAngleCamera"
+    }
+
+    av_string_constant! {
+        /// An external camera, such as a USB webcam.
+        ///
+        /// Only available on macOS 10.15 and later.
+        external_unknown: AVCaptureDeviceType = "AVCaptureDeviceTypeExternalUnknown"
+    }
+
+    av_string_constant! {
+        /// A built-in microphone.
+        built_in_microphone: AVCaptureDeviceType = "AVCaptureDeviceTypeBuiltInMicrophone"
+    }
+}
+
+/// Where a capture device is located on the hardware.
+///
+/// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturedevice/position-swift.enum).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct AVCaptureDevicePosition(pub NSInteger);
+
+impl AVCaptureDevicePosition {
+    /// The position is not known, or any position when finding devices.
+    #[doc(alias = "AVCaptureDevicePositionUnspecified")]
+    pub const UNSPECIFIED: Self = Self(0);
+
+    /// On the back of the device, facing away from the user.
+    #[doc(alias = "AVCaptureDevicePositionBack")]
+    pub const BACK: Self = Self(1);
+
+    /// On the front of the device, facing the user.
+    #[doc(alias = "AVCaptureDevicePositionFront")]
+    pub const FRONT: Self = Self(2);
+}
+
+/// Whether the user allows the app to capture a kind of media.
+///
+/// See [documentation](https://developer.apple.com/documentation/avfoundation/avauthorizationstatus).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AVAuthorizationStatus(pub NSInteger);
+
+impl AVAuthorizationStatus {
+    /// The user has not been asked yet.
+    #[doc(alias = "AVAuthorizationStatusNotDetermined")]
+    pub const NOT_DETERMINED: Self = Self(0);
+
+    /// Capture is not allowed by a policy the user cannot change, such as
+    /// parental controls.
+    #[doc(alias = "AVAuthorizationStatusRestricted")]
+    pub const RESTRICTED: Self = Self(1);
+
+    /// The user denied access.
+    #[doc(alias = "AVAuthorizationStatusDenied")]
+    pub const DENIED: Self = Self(2);
+
+    /// The user allowed access.
+    #[doc(alias = "AVAuthorizationStatusAuthorized")]
+    pub const AUTHORIZED: Self = Self(3);
+}
@@ -0,0 +1,146 @@
+use super::{sys, AVMediaType};
+use crate::core::Arc;
+use crate::core_video::CVPixelFormatType;
+use crate::foundation::NSArray;
+use crate::objc::NSObject;
+use std::fmt;
+
+objc_subclass! {
+    /// A combination of media settings supported by a capture device, such
+    /// as a resolution, pixel format, and range of frame rates.
+    ///
+    /// Formats are listed by [`AVCaptureDevice::formats`](super::AVCaptureDevice::formats)
+    /// and selected with
+    /// [`AVCaptureDevice::set_active_format`](super::AVCaptureDevice::set_active_format).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturedevice/format).
+    pub class AVCaptureDeviceFormat: NSObject<'static>;
+}
+
+impl fmt::Debug for AVCaptureDeviceFormat {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AVCaptureDeviceFormat")
+            .field("media_type", &self.media_type())
+            .field("dimensions", &self.dimensions())
+            .field("pixel_format_type", &self.pixel_format_type())
+            .finish()
+    }
+}
+
+impl AVCaptureDeviceFormat {
+    /// Returns the kind of media captured in this format.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturedevice/format/mediatype).
+    #[inline]
+    #[doc(alias = "mediaType")]
+    pub fn media_type(&self) -> Arc<AVMediaType> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, mediaType => *const AVMediaType]) }
+    }
+
+    /// Returns the width and height of video frames, or zero for formats
+    /// without video.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coremedia/cmvideoformatdescriptiongetdimensions(_:)).
+    #[inline]
+    #[doc(alias = "CMVideoFormatDescriptionGetDimensions")]
+    pub fn dimensions(&self) -> CMVideoDimensions {
+        unsafe {
+            let desc = self.format_description();
+            if desc.is_null() {
+                CMVideoDimensions::default()
+            } else {
+                sys::CMVideoFormatDescriptionGetDimensions(desc)
+            }
+        }
+    }
+
+    /// Returns the pixel format that video frames are captured in.
+    ///
+    /// This is usually a YCbCr format such as
+    /// [`YCBCR_420_BIPLANAR_VIDEO_RANGE`](CVPixelFormatType::YCBCR_420_BIPLANAR_VIDEO_RANGE).
+    /// [`AVCaptureVideoDataOutput`](super::AVCaptureVideoDataOutput) can
+    /// convert frames to another format.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coremedia/cmformatdescriptiongetmediasubtype(_:)).
+    #[inline]
+    #[doc(alias = "CMFormatDescriptionGetMediaSubType")]
+    pub fn pixel_format_type(&self) -> CVPixelFormatType {
+        unsafe {
+            let desc = self.format_description();
+            if desc.is_null() {
+                CVPixelFormatType(0)
+            } else {
+                CVPixelFormatType(sys::CMFormatDescriptionGetMediaSubType(desc))
+            }
+        }
+    }
+
+    /// Returns the ranges of frame rates supported by this format.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturedevice/format/videosupportedframerateranges).
+    #[inline]
+    #[doc(alias = "videoSupportedFrameRateRanges")]
+    pub fn frame_rate_ranges(&self) -> Arc<NSArray<AVFrameRateRange>> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![
+                self,
+                videoSupportedFrameRateRanges => *const NSArray<AVFrameRateRange>
+            ])
+        }
+    }
+
+    #[inline]
+    unsafe fn format_description(&self) -> sys::CMFormatDescriptionRef {
+        _msg_send_any![self, formatDescription => sys::CMFormatDescriptionRef]
+    }
+}
+
+objc_subclass! {
+    /// A range of frame rates supported by an [`AVCaptureDeviceFormat`].
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avframeraterange).
+    pub class AVFrameRateRange: NSObject<'static>;
+}
+
+impl fmt::Debug for AVFrameRateRange {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AVFrameRateRange")
+            .field("min_frame_rate", &self.min_frame_rate())
+            .field("max_frame_rate", &self.max_frame_rate())
+            .finish()
+    }
+}
+
+impl AVFrameRateRange {
+    /// Returns the lowest frame rate in frames per second.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avframeraterange/minframerate).
+    #[inline]
+    #[doc(alias = "minFrameRate")]
+    pub fn min_frame_rate(&self) -> f64 {
+        unsafe { _msg_send_any![self, minFrameRate] }
+    }
+
+    /// Returns the highest frame rate in frames per second.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avframeraterange/maxframerate).
+    #[inline]
+    #[doc(alias = "maxFrameRate")]
+    pub fn max_frame_rate(&self) -> f64 {
+        unsafe { _msg_send_any![self, maxFrameRate] }
+    }
+}
+
+/// The width and height of video frames in pixels.
+///
+/// See [documentation](https://developer.apple.com/documentation/coremedia/cmvideodimensions).
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CMVideoDimensions {
+    /// The width in pixels.
+    pub width: i32,
+    /// The height in pixels.
+    pub height: i32,
+}
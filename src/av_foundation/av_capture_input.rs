@@ -0,0 +1,63 @@
+use super::AVCaptureDevice;
+use crate::core::Arc;
+use crate::foundation::NSError;
+use crate::objc::{ClassType, NSObject};
+use std::{fmt, ptr};
+
+objc_subclass! {
+    /// A source of media for an [`AVCaptureSession`](super::AVCaptureSession).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcaptureinput).
+    pub class AVCaptureInput: NSObject<'static>;
+}
+
+objc_subclass! {
+    /// An input that provides media from an [`AVCaptureDevice`].
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturedeviceinput).
+    pub class AVCaptureDeviceInput: AVCaptureInput;
+}
+
+impl fmt::Debug for AVCaptureDeviceInput {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AVCaptureDeviceInput")
+            .field("device", &self.device())
+            .finish()
+    }
+}
+
+impl AVCaptureDeviceInput {
+    /// Creates an input for `device`.
+    ///
+    /// This fails if the user has not allowed capture from the device or if
+    /// the device is in use by another app.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturedeviceinput/init(device:)).
+    #[inline]
+    #[doc(alias = "deviceInputWithDevice:error:")]
+    pub fn with_device(device: &AVCaptureDevice) -> Result<Arc<Self>, Arc<NSError<'static>>> {
+        unsafe {
+            let mut error: *const NSError<'static> = ptr::null();
+            let input = _msg_send_any![
+                Self::class(),
+                deviceInputWithDevice: device
+                error: &mut error
+                => *const Self
+            ];
+
+            match input.as_ref() {
+                Some(input) => Ok(Arc::retain(input)),
+                None => Err(Arc::retain_raw(error)),
+            }
+        }
+    }
+
+    /// Returns the device that media is provided from.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturedeviceinput/device).
+    #[inline]
+    pub fn device(&self) -> Arc<AVCaptureDevice> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, device => *const AVCaptureDevice]) }
+    }
+}
@@ -0,0 +1,9 @@
+use crate::objc::NSObject;
+
+objc_subclass! {
+    /// A destination for media captured by an
+    /// [`AVCaptureSession`](super::AVCaptureSession).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcaptureoutput).
+    pub class AVCaptureOutput: NSObject<'static>;
+}
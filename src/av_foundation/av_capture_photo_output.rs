@@ -0,0 +1,231 @@
+use super::AVCaptureOutput;
+use crate::core::Arc;
+use crate::core_video::CVPixelBuffer;
+use crate::foundation::{NSData, NSError};
+use crate::objc::{Class, ClassType, LazyClass, NSObject, Protocol, Sel};
+use std::{
+    ffi::{c_void, CStr},
+    mem,
+    panic::{self, AssertUnwindSafe},
+    process, ptr,
+};
+
+/// The result of a photo capture.
+pub type AVCapturePhotoResult = Result<Arc<AVCapturePhoto>, Arc<NSError<'static>>>;
+
+objc_subclass! {
+    /// An output that captures still photos from an
+    /// [`AVCaptureSession`](super::AVCaptureSession).
+    ///
+    /// Requires macOS 10.15 or iOS 10.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturephotooutput).
+    pub class AVCapturePhotoOutput: AVCaptureOutput;
+}
+
+impl AVCapturePhotoOutput {
+    /// Creates a photo output.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturephotooutput/init()).
+    #[inline]
+    pub fn new() -> Arc<Self> {
+        unsafe { Self::class().alloc_init() }
+    }
+
+    /// Captures a photo with `settings`, then calls `completion` on an
+    /// arbitrary thread with the result.
+    ///
+    /// The output must be part of a running session. Each capture needs new
+    /// `settings`.
+    ///
+    /// `completion` is called once, unless the capture ends without a photo
+    /// or an error, in which case it is dropped.
+    ///
+    /// It is safe to panic within `completion`. Panics will abort the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturephotooutput/capturephoto(with:delegate:)).
+    #[doc(alias = "capturePhotoWithSettings:delegate:")]
+    pub fn capture_photo<F>(&self, settings: &AVCapturePhotoSettings, completion: F)
+    where
+        F: FnOnce(AVCapturePhotoResult) + Send + 'static,
+    {
+        let completion: Box<Option<Box<Completion>>> = Box::new(Some(Box::new(completion)));
+        let delegate: Arc<NSObject<'static>> = unsafe { delegate_class().alloc_init() };
+
+        unsafe {
+            *completion_slot(&delegate) = Box::into_raw(completion).cast();
+            _msg_send_any![
+                self,
+                capturePhotoWithSettings: settings
+                delegate: &*delegate
+                => ()
+            ];
+        }
+
+        // Released by `did_finish_capture`, which is always the last message
+        // sent to the delegate.
+        mem::forget(delegate);
+    }
+}
+
+objc_subclass! {
+    /// Settings for a single capture with an [`AVCapturePhotoOutput`].
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturephotosettings).
+    pub class AVCapturePhotoSettings: NSObject<'static>;
+}
+
+impl AVCapturePhotoSettings {
+    /// Creates settings for capturing in the output's default format,
+    /// usually JPEG or HEIF.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturephotosettings/init()).
+    #[inline]
+    #[doc(alias = "photoSettings")]
+    pub fn new() -> Arc<Self> {
+        unsafe { Arc::retain_raw(_msg_send_any![Self::class(), photoSettings => *const Self]) }
+    }
+
+    /// Returns an identifier that is unique to these settings.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturephotosettings/uniqueid).
+    #[inline]
+    #[doc(alias = "uniqueID")]
+    pub fn unique_id(&self) -> i64 {
+        unsafe { _msg_send_any![self, uniqueID] }
+    }
+}
+
+objc_subclass! {
+    /// A photo captured by an [`AVCapturePhotoOutput`].
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturephoto).
+    pub class AVCapturePhoto: NSObject<'static>;
+}
+
+impl AVCapturePhoto {
+    /// Returns the photo encoded as a file, such as a JPEG or HEIF image,
+    /// with its metadata.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturephoto/filedatarepresentation()).
+    #[inline]
+    #[doc(alias = "fileDataRepresentation")]
+    pub fn file_data_representation(&self) -> Option<Arc<NSData>> {
+        unsafe {
+            _msg_send_any![self, fileDataRepresentation => *const NSData]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Returns the uncompressed image, or [`None`] if the photo was captured
+    /// in a compressed format.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturephoto/pixelbuffer).
+    #[inline]
+    #[doc(alias = "pixelBuffer")]
+    pub fn pixel_buffer(&self) -> Option<Arc<CVPixelBuffer>> {
+        unsafe {
+            _msg_send_any![self, pixelBuffer => *const CVPixelBuffer]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+}
+
+type Completion = dyn FnOnce(AVCapturePhotoResult) + Send;
+
+/// The name of the instance variable that points to a
+/// `Box<Option<Box<Completion>>>`.
+const COMPLETION_IVAR: &[u8] = b"rustCompletion\0";
+
+#[inline]
+fn completion_slot(delegate: &NSObject<'static>) -> *mut *mut c_void {
+    let name = unsafe { CStr::from_bytes_with_nul_unchecked(COMPLETION_IVAR) };
+    delegate.ivar_ptr::<*mut c_void>(name)
+}
+
+/// Calls the completion of `delegate` with `result` if it was not called
+/// already.
+unsafe fn complete(delegate: &NSObject<'static>, result: AVCapturePhotoResult) {
+    let completion = *completion_slot(delegate) as *mut Option<Box<Completion>>;
+    if let Some(completion) = completion.as_mut().and_then(Option::take) {
+        // Unwinding into Objective-C is undefined behavior.
+        if panic::catch_unwind(AssertUnwindSafe(|| completion(result))).is_err() {
+            process::abort();
+        }
+    }
+}
+
+/// Returns the class used for delegate objects, registering it with the
+/// Objective-C runtime on first use.
+fn delegate_class() -> &'static Class {
+    static CLASS: LazyClass = LazyClass::new("FruityPhotoCaptureDelegate");
+
+    CLASS.get(NSObject::class(), |builder| {
+        builder.add_ivar::<*mut c_void>(CStr::from_bytes_with_nul(COMPLETION_IVAR).unwrap());
+
+        let protocol = b"AVCapturePhotoCaptureDelegate\0";
+        if let Some(protocol) = Protocol::get(CStr::from_bytes_with_nul(protocol).unwrap()) {
+            builder.add_protocol(protocol);
+        }
+
+        unsafe {
+            builder.add_method(
+                selector!(captureOutput:didFinishProcessingPhoto:error:),
+                did_finish_processing_photo as DelegateMethod,
+            );
+            builder.add_method(
+                selector!(captureOutput:didFinishCaptureForResolvedSettings:error:),
+                did_finish_capture as DelegateMethod,
+            );
+        }
+    })
+}
+
+type DelegateMethod = extern "C" fn(
+    &NSObject<'static>,
+    Sel,
+    *const NSObject<'static>,
+    *const NSObject<'static>,
+    *const NSError<'static>,
+);
+
+extern "C" fn did_finish_processing_photo(
+    this: &NSObject<'static>,
+    _: Sel,
+    _output: *const NSObject<'static>,
+    photo: *const NSObject<'static>,
+    error: *const NSError<'static>,
+) {
+    unsafe {
+        let result = match error.as_ref() {
+            Some(error) => Err(Arc::retain(error)),
+            None => Ok(Arc::retain(&*photo.cast::<AVCapturePhoto>())),
+        };
+        complete(this, result);
+    }
+}
+
+extern "C" fn did_finish_capture(
+    this: &NSObject<'static>,
+    _: Sel,
+    _output: *const NSObject<'static>,
+    _settings: *const NSObject<'static>,
+    error: *const NSError<'static>,
+) {
+    unsafe {
+        if let Some(error) = error.as_ref() {
+            complete(this, Err(Arc::retain(error)));
+        }
+
+        let slot = completion_slot(this);
+        let completion = mem::replace(&mut *slot, ptr::null_mut());
+        if !completion.is_null() {
+            drop(Box::from_raw(completion as *mut Option<Box<Completion>>));
+        }
+
+        // Balances the reference leaked by `capture_photo`.
+        drop(Arc::from_raw(this));
+    }
+}
@@ -0,0 +1,292 @@
+use super::{AVCaptureInput, AVCaptureOutput};
+use crate::core::Arc;
+use crate::foundation::NSArray;
+use crate::objc::{ClassType, NSObject, BOOL};
+use std::fmt;
+
+objc_subclass! {
+    /// Coordinates the flow of media from capture inputs, such as cameras,
+    /// to outputs, such as video frames or photos.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fruity::av_foundation::*;
+    /// use fruity::dispatch::DispatchQueue;
+    ///
+    /// let camera = AVCaptureDevice::default_for_media_type(AVMediaType::video()).unwrap();
+    /// let input = AVCaptureDeviceInput::with_device(&camera).unwrap();
+    ///
+    /// let output = AVCaptureVideoDataOutput::new();
+    /// let queue = DispatchQueue::builder().build();
+    /// let _handler = output.set_frame_handler(&queue, |frame| {
+    ///     println!("{}x{}", frame.width(), frame.height());
+    /// });
+    ///
+    /// let session = AVCaptureSession::new();
+    /// session.set_session_preset(AVCaptureSessionPreset::hd1280x720());
+    /// session.add_input(&input);
+    /// session.add_output(&output);
+    /// session.start_running();
+    /// ```
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturesession).
+    pub class AVCaptureSession: NSObject<'static>;
+}
+
+impl fmt::Debug for AVCaptureSession {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AVCaptureSession")
+            .field("session_preset", &self.session_preset())
+            .field("is_running", &self.is_running())
+            .finish()
+    }
+}
+
+impl AVCaptureSession {
+    /// Creates a session without inputs or outputs.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturesession/init()).
+    #[inline]
+    pub fn new() -> Arc<Self> {
+        unsafe { Self::class().alloc_init() }
+    }
+}
+
+/// Inputs and outputs.
+impl AVCaptureSession {
+    /// Returns the inputs that media is captured from.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturesession/inputs).
+    #[inline]
+    pub fn inputs(&self) -> Arc<NSArray<AVCaptureInput>> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, inputs => *const NSArray<AVCaptureInput>]) }
+    }
+
+    /// Returns `true` if `input` can be added to the session.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturesession/canaddinput(_:)).
+    #[inline]
+    #[doc(alias = "canAddInput:")]
+    pub fn can_add_input(&self, input: &AVCaptureInput) -> bool {
+        unsafe { _msg_send_any![self, canAddInput: input => BOOL] }.into()
+    }
+
+    /// Adds `input` to the session, returning `false` if it
+    /// [cannot be added](Self::can_add_input).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturesession/addinput(_:)).
+    #[inline]
+    #[doc(alias = "addInput:")]
+    pub fn add_input(&self, input: &AVCaptureInput) -> bool {
+        // `addInput:` throws an exception for inputs that cannot be added.
+        if !self.can_add_input(input) {
+            return false;
+        }
+        unsafe { _msg_send_any![self, addInput: input => ()] };
+        true
+    }
+
+    /// Removes `input` from the session.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturesession/removeinput(_:)).
+    #[inline]
+    #[doc(alias = "removeInput:")]
+    pub fn remove_input(&self, input: &AVCaptureInput) {
+        unsafe { _msg_send_any![self, removeInput: input => ()] }
+    }
+
+    /// Returns the outputs that media is captured to.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturesession/outputs).
+    #[inline]
+    pub fn outputs(&self) -> Arc<NSArray<AVCaptureOutput>> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, outputs => *const NSArray<AVCaptureOutput>]) }
+    }
+
+    /// Returns `true` if `output` can be added to the session.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturesession/canaddoutput(_:)).
+    #[inline]
+    #[doc(alias = "canAddOutput:")]
+    pub fn can_add_output(&self, output: &AVCaptureOutput) -> bool {
+        unsafe { _msg_send_any![self, canAddOutput: output => BOOL] }.into()
+    }
+
+    /// Adds `output` to the session, returning `false` if it
+    /// [cannot be added](Self::can_add_output).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturesession/addoutput(_:)).
+    #[inline]
+    #[doc(alias = "addOutput:")]
+    pub fn add_output(&self, output: &AVCaptureOutput) -> bool {
+        // `addOutput:` throws an exception for outputs that cannot be added.
+        if !self.can_add_output(output) {
+            return false;
+        }
+        unsafe { _msg_send_any![self, addOutput: output => ()] };
+        true
+    }
+
+    /// Removes `output` from the session.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturesession/removeoutput(_:)).
+    #[inline]
+    #[doc(alias = "removeOutput:")]
+    pub fn remove_output(&self, output: &AVCaptureOutput) {
+        unsafe { _msg_send_any![self, removeOutput: output => ()] }
+    }
+}
+
+/// Configuration.
+impl AVCaptureSession {
+    /// Returns the quality level of captured media.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturesession/sessionpreset).
+    #[inline]
+    #[doc(alias = "sessionPreset")]
+    pub fn session_preset(&self) -> Arc<AVCaptureSessionPreset> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![self, sessionPreset => *const AVCaptureSessionPreset])
+        }
+    }
+
+    /// Returns `true` if `preset` is supported by the session's inputs and
+    /// outputs.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturesession/cansetsessionpreset(_:)).
+    #[inline]
+    #[doc(alias = "canSetSessionPreset:")]
+    pub fn can_set_session_preset(&self, preset: &AVCaptureSessionPreset) -> bool {
+        unsafe { _msg_send_any![self, canSetSessionPreset: preset => BOOL] }.into()
+    }
+
+    /// Sets the quality level of captured media, returning `false` if it is
+    /// [not supported](Self::can_set_session_preset).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturesession/sessionpreset).
+    #[inline]
+    #[doc(alias = "setSessionPreset:")]
+    pub fn set_session_preset(&self, preset: &AVCaptureSessionPreset) -> bool {
+        // `setSessionPreset:` throws an exception for unsupported presets.
+        if !self.can_set_session_preset(preset) {
+            return false;
+        }
+        unsafe { _msg_send_any![self, setSessionPreset: preset => ()] };
+        true
+    }
+
+    /// Calls `f`, applying the changes it makes to the session's inputs,
+    /// outputs, and preset together once it returns.
+    ///
+    /// This allows reconfiguring a running session without interrupting it
+    /// for each change.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturesession/beginconfiguration()).
+    #[doc(alias = "beginConfiguration")]
+    #[doc(alias = "commitConfiguration")]
+    pub fn configure<F, T>(&self, f: F) -> T
+    where
+        F: FnOnce(&Self) -> T,
+    {
+        struct Commit<'a>(&'a AVCaptureSession);
+
+        impl Drop for Commit<'_> {
+            #[inline]
+            fn drop(&mut self) {
+                unsafe { _msg_send_any![self.0, commitConfiguration => ()] }
+            }
+        }
+
+        unsafe { _msg_send_any![self, beginConfiguration => ()] };
+        let commit = Commit(self);
+        f(commit.0)
+    }
+}
+
+/// Running.
+impl AVCaptureSession {
+    /// Starts the flow of media from inputs to outputs.
+    ///
+    /// This blocks until the session has started, so it should not be called
+    /// on the main thread.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturesession/startrunning()).
+    #[inline]
+    #[doc(alias = "startRunning")]
+    pub fn start_running(&self) {
+        unsafe { _msg_send_any![self, startRunning => ()] }
+    }
+
+    /// Stops the flow of media from inputs to outputs.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturesession/stoprunning()).
+    #[inline]
+    #[doc(alias = "stopRunning")]
+    pub fn stop_running(&self) {
+        unsafe { _msg_send_any![self, stopRunning => ()] }
+    }
+
+    /// Returns `true` if media is flowing from inputs to outputs.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturesession/isrunning).
+    #[inline]
+    #[doc(alias = "isRunning")]
+    pub fn is_running(&self) -> bool {
+        unsafe { _msg_send_any![self, isRunning => BOOL] }.into()
+    }
+}
+
+av_string_wrapper! {
+    /// The quality level of media captured by an [`AVCaptureSession`].
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturesession/preset).
+    pub wrapper AVCaptureSessionPreset;
+}
+
+impl AVCaptureSessionPreset {
+    av_string_constant! {
+        /// The highest quality supported by the device.
+        high: AVCaptureSessionPreset = "AVCaptureSessionPresetHigh"
+    }
+
+    av_string_constant! {
+        /// Quality suitable for sharing over Wi-Fi.
+        medium: AVCaptureSessionPreset = "AVCaptureSessionPresetMedium"
+    }
+
+    av_string_constant! {
+        /// Quality suitable for sharing over a cellular network.
+        low: AVCaptureSessionPreset = "AVCaptureSessionPresetLow"
+    }
+
+    av_string_constant! {
+        /// Full resolution photos.
+        photo: AVCaptureSessionPreset = "AVCaptureSessionPresetPhoto"
+    }
+
+    av_string_constant! {
+        /// 640 by 480 pixel video.
+        vga640x480: AVCaptureSessionPreset = "AVCaptureSessionPreset640x480"
+    }
+
+    av_string_constant! {
+        /// 1280 by 720 pixel video.
+        hd1280x720: AVCaptureSessionPreset = "AVCaptureSessionPreset1280x720"
+    }
+
+    av_string_constant! {
+        /// 1920 by 1080 pixel video.
+        ///
+        /// Only available on macOS 10.15 and later.
+        hd1920x1080: AVCaptureSessionPreset = "AVCaptureSessionPreset1920x1080"
+    }
+
+    av_string_constant! {
+        /// The quality is determined by the
+        /// [active format](super::AVCaptureDevice::set_active_format) of each
+        /// input device.
+        input_priority: AVCaptureSessionPreset = "AVCaptureSessionPresetInputPriority"
+    }
+}
@@ -0,0 +1,265 @@
+use super::{sys, AVCaptureOutput};
+use crate::core::Arc;
+use crate::core_video::{CVPixelBuffer, CVPixelFormatType};
+use crate::dispatch::DispatchQueue;
+use crate::foundation::{NSArray, NSDictionary, NSNumber, NSString};
+use crate::objc::{Class, ClassType, LazyClass, NSObject, Protocol, Sel, BOOL};
+use std::{
+    ffi::{c_void, CStr},
+    fmt,
+    panic::{self, AssertUnwindSafe},
+    process, ptr,
+};
+
+objc_subclass! {
+    /// An output that delivers uncompressed video frames from an
+    /// [`AVCaptureSession`](super::AVCaptureSession) to a closure.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturevideodataoutput).
+    pub class AVCaptureVideoDataOutput: AVCaptureOutput;
+}
+
+impl fmt::Debug for AVCaptureVideoDataOutput {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AVCaptureVideoDataOutput")
+            .field("pixel_format_type", &self.pixel_format_type())
+            .finish()
+    }
+}
+
+impl AVCaptureVideoDataOutput {
+    /// Creates an output that delivers frames in the device's native format.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturevideodataoutput/init()).
+    #[inline]
+    pub fn new() -> Arc<Self> {
+        unsafe { Self::class().alloc_init() }
+    }
+
+    /// Returns the pixel formats that frames can be converted to, with the
+    /// most efficient first.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturevideodataoutput/availablevideopixelformattypes).
+    #[doc(alias = "availableVideoCVPixelFormatTypes")]
+    pub fn available_pixel_format_types(&self) -> Vec<CVPixelFormatType> {
+        let types = unsafe {
+            Arc::retain_raw(_msg_send_any![
+                self,
+                availableVideoCVPixelFormatTypes => *const NSArray<NSNumber>
+            ])
+        };
+        types
+            .iter()
+            .map(|ty| CVPixelFormatType(ty.unsigned_int_value()))
+            .collect()
+    }
+
+    /// Returns the pixel format that frames are converted to, or [`None`] if
+    /// frames are delivered in the device's native format.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturevideodataoutput/videosettings).
+    #[doc(alias = "videoSettings")]
+    pub fn pixel_format_type(&self) -> Option<CVPixelFormatType> {
+        unsafe {
+            let settings = _msg_send_any![
+                self,
+                videoSettings => *const NSDictionary<NSString<'static>, NSNumber>
+            ];
+            let format = settings
+                .as_ref()?
+                .get(sys::kCVPixelBufferPixelFormatTypeKey)?;
+            Some(CVPixelFormatType(format.unsigned_int_value()))
+        }
+    }
+
+    /// Converts frames to `format`, which should be one of the
+    /// [available formats](Self::available_pixel_format_types).
+    ///
+    /// Converting to [`BGRA32`](CVPixelFormatType::BGRA32) allows frames to
+    /// be read with [`Pixels`](crate::core_graphics::Pixels).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturevideodataoutput/videosettings).
+    #[doc(alias = "setVideoSettings:")]
+    pub fn set_pixel_format_type(&self, format: CVPixelFormatType) {
+        let format = NSNumber::from_unsigned_int(format.0);
+        let settings = unsafe {
+            NSDictionary::from_slices(&[sys::kCVPixelBufferPixelFormatTypeKey], &[&*format])
+        };
+        unsafe { _msg_send_any![self, setVideoSettings: &*settings => ()] }
+    }
+
+    /// Returns `true` if frames that arrive while the frame handler is still
+    /// running are dropped.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturevideodataoutput/alwaysdiscardslatevideoframes).
+    #[inline]
+    #[doc(alias = "alwaysDiscardsLateVideoFrames")]
+    pub fn always_discards_late_frames(&self) -> bool {
+        unsafe { _msg_send_any![self, alwaysDiscardsLateVideoFrames => BOOL] }.into()
+    }
+
+    /// Sets whether frames that arrive while the frame handler is still
+    /// running are dropped, which is the default.
+    ///
+    /// Otherwise, frames are queued, which increases memory use and latency
+    /// if the handler cannot keep up.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturevideodataoutput/alwaysdiscardslatevideoframes).
+    #[inline]
+    #[doc(alias = "setAlwaysDiscardsLateVideoFrames:")]
+    pub fn set_always_discards_late_frames(&self, value: bool) {
+        unsafe { _msg_send_any![self, setAlwaysDiscardsLateVideoFrames: BOOL::from(value) => ()] }
+    }
+
+    /// Calls `handler` on `queue` with each captured frame, until the
+    /// returned value is dropped.
+    ///
+    /// `queue` must be serial, so that frames are delivered in order. Setting
+    /// another handler replaces this one.
+    ///
+    /// Dropping the returned value waits for a running call to `handler` to
+    /// return, so it must not be dropped within `handler` or otherwise on
+    /// `queue`.
+    ///
+    /// It is safe to panic within `handler`. Panics will abort the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avcapturevideodataoutput/setsamplebufferdelegate(_:queue:)).
+    #[doc(alias = "setSampleBufferDelegate:queue:")]
+    #[doc(alias = "captureOutput:didOutputSampleBuffer:fromConnection:")]
+    pub fn set_frame_handler<F>(&self, queue: &DispatchQueue, handler: F) -> AVCaptureFrameHandler
+    where
+        F: FnMut(&CVPixelBuffer) + Send + 'static,
+    {
+        let handler: Box<Box<FrameHandler>> = Box::new(Box::new(handler));
+        let delegate: Arc<NSObject<'static>> = unsafe { delegate_class().alloc_init() };
+
+        unsafe {
+            *handler_slot(&delegate) = &*handler as *const Box<FrameHandler> as *mut c_void;
+            _msg_send_any![
+                self,
+                setSampleBufferDelegate: &*delegate
+                queue: queue
+                => ()
+            ]
+        };
+
+        AVCaptureFrameHandler {
+            output: Arc::retain(self),
+            queue: Arc::retain(queue),
+            delegate,
+            handler,
+        }
+    }
+}
+
+type FrameHandler = dyn FnMut(&CVPixelBuffer) + Send;
+
+/// A frame handler set by [`AVCaptureVideoDataOutput::set_frame_handler`],
+/// which is removed when dropped.
+#[must_use = "The handler is immediately removed if unused"]
+pub struct AVCaptureFrameHandler {
+    output: Arc<AVCaptureVideoDataOutput>,
+    queue: Arc<DispatchQueue>,
+    delegate: Arc<NSObject<'static>>,
+    // Referenced by `delegate` until dropped.
+    #[allow(dead_code)]
+    handler: Box<Box<FrameHandler>>,
+}
+
+impl fmt::Debug for AVCaptureFrameHandler {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AVCaptureFrameHandler")
+            .field("output", &self.output)
+            .field("queue", &self.queue)
+            .finish()
+    }
+}
+
+impl Drop for AVCaptureFrameHandler {
+    fn drop(&mut self) {
+        unsafe {
+            // Only detach if the handler was not replaced.
+            let current = _msg_send_any![&*self.output, sampleBufferDelegate => *const NSObject];
+            if ptr::eq(current, &*self.delegate) {
+                _msg_send_any![
+                    &*self.output,
+                    setSampleBufferDelegate: ptr::null::<NSObject>()
+                    queue: ptr::null::<DispatchQueue>()
+                    => ()
+                ];
+            }
+
+            // Frames already submitted to the queue may still reference the
+            // delegate, so it is cleared in order with them.
+            let slot = handler_slot(&self.delegate) as usize;
+            self.queue.spawn_sync(move || {
+                *(slot as *mut *mut c_void) = ptr::null_mut();
+            });
+        }
+    }
+}
+
+/// The name of the instance variable that points to a `Box<FrameHandler>`.
+const HANDLER_IVAR: &[u8] = b"rustFrameHandler\0";
+
+#[inline]
+fn handler_slot(delegate: &NSObject<'static>) -> *mut *mut c_void {
+    let name = unsafe { CStr::from_bytes_with_nul_unchecked(HANDLER_IVAR) };
+    delegate.ivar_ptr::<*mut c_void>(name)
+}
+
+/// Returns the class used for delegate objects, registering it with the
+/// Objective-C runtime on first use.
+fn delegate_class() -> &'static Class {
+    static CLASS: LazyClass = LazyClass::new("FruityVideoDataOutputDelegate");
+
+    CLASS.get(NSObject::class(), |builder| {
+        builder.add_ivar::<*mut c_void>(CStr::from_bytes_with_nul(HANDLER_IVAR).unwrap());
+
+        let protocol = b"AVCaptureVideoDataOutputSampleBufferDelegate\0";
+        if let Some(protocol) = Protocol::get(CStr::from_bytes_with_nul(protocol).unwrap()) {
+            builder.add_protocol(protocol);
+        }
+
+        unsafe {
+            builder.add_method(
+                selector!(captureOutput:didOutputSampleBuffer:fromConnection:),
+                did_output_sample_buffer as DidOutputSampleBuffer,
+            );
+        }
+    })
+}
+
+type DidOutputSampleBuffer = extern "C" fn(
+    &NSObject<'static>,
+    Sel,
+    *const NSObject<'static>,
+    *const c_void,
+    *const NSObject<'static>,
+);
+
+extern "C" fn did_output_sample_buffer(
+    this: &NSObject<'static>,
+    _: Sel,
+    _output: *const NSObject<'static>,
+    sample_buffer: *const c_void,
+    _connection: *const NSObject<'static>,
+) {
+    unsafe {
+        let handler = *handler_slot(this) as *mut Box<FrameHandler>;
+        if handler.is_null() {
+            return;
+        }
+
+        let frame = match sys::CMSampleBufferGetImageBuffer(sample_buffer.cast()).as_ref() {
+            Some(frame) => frame,
+            None => return,
+        };
+
+        // Unwinding into Objective-C is undefined behavior.
+        if panic::catch_unwind(AssertUnwindSafe(|| (*handler)(frame))).is_err() {
+            process::abort();
+        }
+    }
+}
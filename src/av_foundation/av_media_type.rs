@@ -0,0 +1,23 @@
+av_string_wrapper! {
+    /// The kind of media provided by a capture device or asset track.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/avfoundation/avmediatype).
+    pub wrapper AVMediaType;
+}
+
+impl AVMediaType {
+    av_string_constant! {
+        /// Video, such as from a camera.
+        video: AVMediaType = "AVMediaTypeVideo"
+    }
+
+    av_string_constant! {
+        /// Audio, such as from a microphone.
+        audio: AVMediaType = "AVMediaTypeAudio"
+    }
+
+    av_string_constant! {
+        /// Interleaved audio and video, such as from a DV camera.
+        muxed: AVMediaType = "AVMediaTypeMuxed"
+    }
+}
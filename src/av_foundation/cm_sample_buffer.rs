@@ -0,0 +1,100 @@
+use super::{sys, CMTime};
+use crate::core_foundation::{CFType, CFTypeID};
+use crate::core_video::CVPixelBuffer;
+use std::fmt;
+
+subclass! {
+    /// Media samples, such as a decoded video frame or a run of audio
+    /// frames, with their timing.
+    ///
+    /// Video samples are read with [`image_buffer`](Self::image_buffer), and
+    /// audio samples with [`copy_data_bytes`](Self::copy_data_bytes).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coremedia/cmsamplebuffer).
+    #[derive(PartialEq, Hash)]
+    pub class CMSampleBuffer: CFType<'static>;
+}
+
+impl fmt::Debug for CMSampleBuffer {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CMSampleBuffer")
+            .field("presentation_time_stamp", &self.presentation_time_stamp())
+            .field("duration", &self.duration())
+            .field("num_samples", &self.num_samples())
+            .finish()
+    }
+}
+
+impl CMSampleBuffer {
+    /// Returns the type identifier for `CMSampleBuffer`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coremedia/cmsamplebuffergettypeid()).
+    #[inline]
+    #[doc(alias = "CMSampleBufferGetTypeID")]
+    pub fn type_id() -> CFTypeID {
+        unsafe { sys::CMSampleBufferGetTypeID() }
+    }
+
+    /// Returns when the first sample should be presented.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coremedia/cmsamplebuffergetpresentationtimestamp(_:)).
+    #[inline]
+    #[doc(alias = "CMSampleBufferGetPresentationTimeStamp")]
+    pub fn presentation_time_stamp(&self) -> CMTime {
+        unsafe { sys::CMSampleBufferGetPresentationTimeStamp(self) }
+    }
+
+    /// Returns the total duration of the samples.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coremedia/cmsamplebuffergetduration(_:)).
+    #[inline]
+    #[doc(alias = "CMSampleBufferGetDuration")]
+    pub fn duration(&self) -> CMTime {
+        unsafe { sys::CMSampleBufferGetDuration(self) }
+    }
+
+    /// Returns the number of samples, such as 1 for a video frame or the
+    /// number of audio frames.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coremedia/cmsamplebuffergetnumsamples(_:)).
+    #[inline]
+    #[doc(alias = "CMSampleBufferGetNumSamples")]
+    pub fn num_samples(&self) -> usize {
+        unsafe { sys::CMSampleBufferGetNumSamples(self) }.max(0) as usize
+    }
+
+    /// Returns the decoded image of a video sample, or [`None`] for other
+    /// kinds of samples.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coremedia/cmsamplebuffergetimagebuffer(_:)).
+    #[inline]
+    #[doc(alias = "CMSampleBufferGetImageBuffer")]
+    pub fn image_buffer(&self) -> Option<&CVPixelBuffer> {
+        unsafe { sys::CMSampleBufferGetImageBuffer(self).as_ref() }
+    }
+
+    /// Returns a copy of the sample data, such as interleaved PCM audio, or
+    /// [`None`] if the samples have no data buffer.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coremedia/cmblockbuffercopydatabytes(_:atoffset:datalength:destination:)).
+    #[doc(alias = "CMSampleBufferGetDataBuffer")]
+    #[doc(alias = "CMBlockBufferCopyDataBytes")]
+    pub fn copy_data_bytes(&self) -> Option<Vec<u8>> {
+        unsafe {
+            let block = sys::CMSampleBufferGetDataBuffer(self);
+            if block.is_null() {
+                return None;
+            }
+
+            let len = sys::CMBlockBufferGetDataLength(block);
+            let mut bytes = Vec::<u8>::with_capacity(len);
+            let status = sys::CMBlockBufferCopyDataBytes(block, 0, len, bytes.as_mut_ptr().cast());
+            if status != 0 {
+                return None;
+            }
+            bytes.set_len(len);
+            Some(bytes)
+        }
+    }
+}
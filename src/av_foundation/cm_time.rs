@@ -0,0 +1,223 @@
+use super::sys;
+use std::{cmp::Ordering, fmt, ops};
+
+/// A rational time value, such as the position of a sample in a track.
+///
+/// Times are stored as `value / timescale` seconds to avoid rounding errors.
+/// Times may also be invalid, infinite, or indefinite, as reported by
+/// [`flags`](Self::flags).
+///
+/// See [documentation](https://developer.apple.com/documentation/coremedia/cmtime-api).
+#[repr(C)]
+#[derive(Copy, Clone, Default)]
+pub struct CMTime {
+    /// The numerator of the time in seconds.
+    pub value: i64,
+    /// The denominator of the time in seconds.
+    pub timescale: i32,
+    /// Whether the time is valid, rounded, or not numeric.
+    pub flags: CMTimeFlags,
+    /// Distinguishes times that are otherwise equal, such as in loops.
+    pub epoch: i64,
+}
+
+impl fmt::Debug for CMTime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_numeric() {
+            write!(f, "{}/{}", self.value, self.timescale)
+        } else if self.flags.contains(CMTimeFlags::POSITIVE_INFINITY) {
+            f.write_str("+inf")
+        } else if self.flags.contains(CMTimeFlags::NEGATIVE_INFINITY) {
+            f.write_str("-inf")
+        } else if self.flags.contains(CMTimeFlags::INDEFINITE) {
+            f.write_str("indefinite")
+        } else {
+            f.write_str("invalid")
+        }
+    }
+}
+
+impl PartialEq for CMTime {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for CMTime {}
+
+impl PartialOrd for CMTime {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for CMTime {
+    /// Compares times numerically. Invalid times are less than all other
+    /// times.
+    #[inline]
+    #[doc(alias = "CMTimeCompare")]
+    fn cmp(&self, other: &Self) -> Ordering {
+        unsafe { sys::CMTimeCompare(*self, *other) }.cmp(&0)
+    }
+}
+
+impl ops::Add for CMTime {
+    type Output = Self;
+
+    #[inline]
+    #[doc(alias = "CMTimeAdd")]
+    fn add(self, other: Self) -> Self {
+        unsafe { sys::CMTimeAdd(self, other) }
+    }
+}
+
+impl ops::Sub for CMTime {
+    type Output = Self;
+
+    #[inline]
+    #[doc(alias = "CMTimeSubtract")]
+    fn sub(self, other: Self) -> Self {
+        unsafe { sys::CMTimeSubtract(self, other) }
+    }
+}
+
+impl CMTime {
+    /// An invalid time, such as for a sample without a timestamp.
+    #[doc(alias = "kCMTimeInvalid")]
+    pub const INVALID: Self = Self {
+        value: 0,
+        timescale: 0,
+        flags: CMTimeFlags(0),
+        epoch: 0,
+    };
+
+    /// Zero seconds.
+    #[doc(alias = "kCMTimeZero")]
+    pub const ZERO: Self = Self::new(0, 1);
+
+    /// A time greater than all numeric times.
+    #[doc(alias = "kCMTimePositiveInfinity")]
+    pub const POSITIVE_INFINITY: Self = Self {
+        value: 0,
+        timescale: 0,
+        flags: CMTimeFlags(CMTimeFlags::VALID.0 | CMTimeFlags::POSITIVE_INFINITY.0),
+        epoch: 0,
+    };
+
+    /// Creates a valid time of `value / timescale` seconds.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coremedia/cmtimemake(value:timescale:)).
+    #[inline]
+    #[doc(alias = "CMTimeMake")]
+    pub const fn new(value: i64, timescale: i32) -> Self {
+        Self {
+            value,
+            timescale,
+            flags: CMTimeFlags::VALID,
+            epoch: 0,
+        }
+    }
+
+    /// Creates the time closest to `seconds` with `timescale`, such as 600
+    /// for video.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coremedia/cmtimemakewithseconds(_:preferredtimescale:)).
+    #[inline]
+    #[doc(alias = "CMTimeMakeWithSeconds")]
+    pub fn from_seconds(seconds: f64, timescale: i32) -> Self {
+        unsafe { sys::CMTimeMakeWithSeconds(seconds, timescale) }
+    }
+
+    /// Returns the time in seconds, or NaN if it is invalid or indefinite.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coremedia/cmtimegetseconds(_:)).
+    #[inline]
+    #[doc(alias = "CMTimeGetSeconds")]
+    pub fn seconds(self) -> f64 {
+        unsafe { sys::CMTimeGetSeconds(self) }
+    }
+
+    /// Returns `true` if the time is valid.
+    #[inline]
+    #[doc(alias = "CMTIME_IS_VALID")]
+    pub const fn is_valid(self) -> bool {
+        self.flags.contains(CMTimeFlags::VALID)
+    }
+
+    /// Returns `true` if the time is valid and a number of seconds, rather
+    /// than infinite or indefinite.
+    #[inline]
+    #[doc(alias = "CMTIME_IS_NUMERIC")]
+    pub const fn is_numeric(self) -> bool {
+        let non_numeric = CMTimeFlags::POSITIVE_INFINITY.0
+            | CMTimeFlags::NEGATIVE_INFINITY.0
+            | CMTimeFlags::INDEFINITE.0;
+        self.is_valid() && self.flags.0 & non_numeric == 0
+    }
+}
+
+/// Flags that describe a [`CMTime`].
+///
+/// See [documentation](https://developer.apple.com/documentation/coremedia/cmtimeflags).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CMTimeFlags(pub u32);
+
+impl ops::BitOr for CMTimeFlags {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl CMTimeFlags {
+    /// The time is valid.
+    #[doc(alias = "kCMTimeFlags_Valid")]
+    pub const VALID: Self = Self(1 << 0);
+
+    /// The time was rounded when converted from another timescale.
+    #[doc(alias = "kCMTimeFlags_HasBeenRounded")]
+    pub const HAS_BEEN_ROUNDED: Self = Self(1 << 1);
+
+    /// The time is positive infinity.
+    #[doc(alias = "kCMTimeFlags_PositiveInfinity")]
+    pub const POSITIVE_INFINITY: Self = Self(1 << 2);
+
+    /// The time is negative infinity.
+    #[doc(alias = "kCMTimeFlags_NegativeInfinity")]
+    pub const NEGATIVE_INFINITY: Self = Self(1 << 3);
+
+    /// The time is not known, such as the duration of a live stream.
+    #[doc(alias = "kCMTimeFlags_Indefinite")]
+    pub const INDEFINITE: Self = Self(1 << 4);
+
+    /// Returns `true` if all flags in `other` are set in `self`.
+    #[inline]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+/// A range of time, such as the extent of a track within an asset.
+///
+/// See [documentation](https://developer.apple.com/documentation/coremedia/cmtimerange).
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct CMTimeRange {
+    /// The beginning of the range.
+    pub start: CMTime,
+    /// The length of the range.
+    pub duration: CMTime,
+}
+
+impl CMTimeRange {
+    /// Returns the time after the end of the range.
+    #[inline]
+    pub fn end(&self) -> CMTime {
+        self.start + self.duration
+    }
+}
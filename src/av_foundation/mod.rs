@@ -0,0 +1,93 @@
+//! [AVFoundation](https://developer.apple.com/documentation/avfoundation)
+//! framework.
+//!
+//! # Feature Flag
+//!
+//! This module corresponds to the **`av_foundation`**
+//! [feature flag](../index.html#feature-flags).
+//!
+//! It also transitively enables [`foundation`](crate::foundation),
+//! [`core_graphics`](crate::core_graphics), [`core_video`](crate::core_video),
+//! and [`dispatch`](crate::dispatch).
+
+#![cfg(feature = "av_foundation")]
+
+#[link(name = "AVFoundation", kind = "framework")]
+extern "C" {}
+
+#[link(name = "CoreMedia", kind = "framework")]
+extern "C" {}
+
+/// Declares a wrapper over string constants exported by AVFoundation.
+///
+/// `ns_string_wrapper!` is not in scope here, since this module is declared
+/// before `foundation`.
+macro_rules! av_string_wrapper {
+    ($(#[$docs:meta])+ pub wrapper $name:ident;) => {
+        objc_object_wrapper! {
+            $(#[$docs])+
+            #[derive(Ord, PartialOrd, Eq, PartialEq)]
+            pub wrapper $name: crate::foundation::NSString<'static>;
+        }
+
+        impl std::fmt::Debug for $name {
+            #[inline]
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            #[inline]
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+    };
+}
+
+/// Declares an accessor for a string constant exported by AVFoundation.
+macro_rules! av_string_constant {
+    ($(#[$docs:meta])+ $fn:ident: $ty:ty = $value:literal) => {
+        $(#[$docs])+
+        #[inline]
+        #[doc(alias = $value)]
+        pub fn $fn() -> &'static $ty {
+            extern "C" {
+                #[link_name = $value]
+                static VALUE: &'static $ty;
+            }
+            unsafe { VALUE }
+        }
+    };
+}
+
+mod av_asset;
+mod av_asset_image_generator;
+mod av_asset_reader;
+mod av_capture_device;
+mod av_capture_device_format;
+mod av_capture_input;
+mod av_capture_output;
+mod av_capture_photo_output;
+mod av_capture_session;
+mod av_capture_video_data_output;
+mod av_media_type;
+mod cm_sample_buffer;
+mod cm_time;
+
+pub use av_asset::*;
+pub use av_asset_image_generator::*;
+pub use av_asset_reader::*;
+pub use av_capture_device::*;
+pub use av_capture_device_format::*;
+pub use av_capture_input::*;
+pub use av_capture_output::*;
+pub use av_capture_photo_output::*;
+pub use av_capture_session::*;
+pub use av_capture_video_data_output::*;
+pub use av_media_type::*;
+pub use cm_sample_buffer::*;
+pub use cm_time::*;
+
+pub mod sys;
@@ -0,0 +1,70 @@
+//! Raw unsafe C functions and constants exposed by CoreMedia and CoreVideo
+//! for reading captured and decoded samples.
+
+use super::{CMSampleBuffer, CMTime, CMVideoDimensions};
+use crate::core_foundation::CFTypeID;
+use crate::core_video::CVPixelBuffer;
+use crate::foundation::NSString;
+use std::ffi::c_void;
+
+/// An opaque `CMFormatDescription`.
+#[allow(non_camel_case_types)]
+pub type CMFormatDescriptionRef = *const c_void;
+
+/// An opaque `CMBlockBuffer`.
+#[allow(non_camel_case_types)]
+pub type CMBlockBufferRef = *const c_void;
+
+/// A status code returned by CoreMedia functions, where 0 is success.
+#[allow(non_camel_case_types)]
+pub type OSStatus = i32;
+
+/// The four-character code of `kAudioFormatLinearPCM`.
+#[allow(non_upper_case_globals)]
+pub const kAudioFormatLinearPCM: u32 = u32::from_be_bytes(*b"lpcm");
+
+#[allow(missing_docs)]
+extern "C" {
+    pub fn CMTimeMakeWithSeconds(seconds: f64, preferred_timescale: i32) -> CMTime;
+    pub fn CMTimeGetSeconds(time: CMTime) -> f64;
+    pub fn CMTimeCompare(time1: CMTime, time2: CMTime) -> i32;
+    pub fn CMTimeAdd(lhs: CMTime, rhs: CMTime) -> CMTime;
+    pub fn CMTimeSubtract(lhs: CMTime, rhs: CMTime) -> CMTime;
+
+    pub fn CMSampleBufferGetTypeID() -> CFTypeID;
+    pub fn CMSampleBufferGetPresentationTimeStamp(sbuf: *const CMSampleBuffer) -> CMTime;
+    pub fn CMSampleBufferGetDuration(sbuf: *const CMSampleBuffer) -> CMTime;
+    pub fn CMSampleBufferGetNumSamples(sbuf: *const CMSampleBuffer) -> isize;
+    pub fn CMSampleBufferGetImageBuffer(sbuf: *const CMSampleBuffer) -> *const CVPixelBuffer;
+    pub fn CMSampleBufferGetDataBuffer(sbuf: *const CMSampleBuffer) -> CMBlockBufferRef;
+
+    pub fn CMBlockBufferGetDataLength(buffer: CMBlockBufferRef) -> usize;
+    pub fn CMBlockBufferCopyDataBytes(
+        buffer: CMBlockBufferRef,
+        offset_to_data: usize,
+        data_length: usize,
+        destination: *mut c_void,
+    ) -> OSStatus;
+
+    pub fn CMFormatDescriptionGetMediaSubType(desc: CMFormatDescriptionRef) -> u32;
+    pub fn CMVideoFormatDescriptionGetDimensions(
+        video_desc: CMFormatDescriptionRef,
+    ) -> CMVideoDimensions;
+}
+
+#[link(name = "CoreVideo", kind = "framework")]
+extern "C" {
+    /// The `CFString` key of the pixel format in buffer attributes, which is
+    /// toll-free bridged with `NSString`.
+    pub static kCVPixelBufferPixelFormatTypeKey: &'static NSString<'static>;
+}
+
+#[link(name = "AVFoundation", kind = "framework")]
+#[allow(non_upper_case_globals, missing_docs)]
+extern "C" {
+    pub static AVFormatIDKey: &'static NSString<'static>;
+    pub static AVLinearPCMBitDepthKey: &'static NSString<'static>;
+    pub static AVLinearPCMIsFloatKey: &'static NSString<'static>;
+    pub static AVLinearPCMIsBigEndianKey: &'static NSString<'static>;
+    pub static AVLinearPCMIsNonInterleaved: &'static NSString<'static>;
+}
@@ -17,5 +17,11 @@
 
 #![cfg(feature = "cf_network")]
 
+mod proxy;
+mod socket_stream;
+
+pub use proxy::*;
+pub use socket_stream::*;
+
 #[link(name = "CFNetwork", kind = "framework")]
 extern "C" {}
@@ -0,0 +1,25 @@
+use crate::{
+    core::Arc,
+    core_foundation::{CFDictionary, CFString},
+};
+
+/// Returns the proxy settings of the system, as configured in the Network
+/// pane of System Preferences.
+///
+/// See [documentation](https://developer.apple.com/documentation/cfnetwork/cfnetworkcopysystemproxysettings()).
+#[inline]
+#[doc(alias = "CFNetworkCopySystemProxySettings")]
+pub fn system_proxy_settings() -> Option<Arc<CFDictionary<CFString>>> {
+    extern "C" {
+        fn CFNetworkCopySystemProxySettings() -> *const CFDictionary<CFString>;
+    }
+
+    unsafe {
+        let settings = CFNetworkCopySystemProxySettings();
+        if settings.is_null() {
+            None
+        } else {
+            Some(Arc::from_raw(settings))
+        }
+    }
+}
@@ -0,0 +1,178 @@
+use crate::core_foundation::{CFStreamPropertyKey, CFString};
+
+macro_rules! constant {
+    ($(#[$docs:meta])+ $fn:ident: $ty:ty = $value:literal) => {
+        $(#[$docs])+
+        #[inline]
+        #[doc(alias = $value)]
+        pub fn $fn() -> &'static $ty {
+            extern "C" {
+                #[link_name = $value]
+                static VALUE: &'static $ty;
+            }
+            unsafe { VALUE }
+        }
+    };
+}
+
+/// Socket stream properties for secure and proxied connections.
+impl CFStreamPropertyKey {
+    constant! {
+        /// A `CFDictionary` of TLS settings, keyed by
+        /// [`CFStreamSSLSettingsKey`].
+        ///
+        /// Setting this before the stream is opened makes the connection
+        /// secure.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/cfnetwork/kcfstreampropertysslsettings).
+        ssl_settings: CFStreamPropertyKey = "kCFStreamPropertySSLSettings"
+    }
+
+    constant! {
+        /// The [`CFStreamSocketSecurityLevel`] of the connection.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/corefoundation/kcfstreampropertysocketsecuritylevel).
+        socket_security_level: CFStreamPropertyKey = "kCFStreamPropertySocketSecurityLevel"
+    }
+
+    constant! {
+        /// A `CFDictionary` of SOCKS proxy settings, keyed by
+        /// [`CFStreamSOCKSProxyKey`].
+        ///
+        /// The dictionary returned by [`system_proxy_settings`](super::system_proxy_settings)
+        /// can be used as-is.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/corefoundation/kcfstreampropertysocksproxy).
+        socks_proxy: CFStreamPropertyKey = "kCFStreamPropertySOCKSProxy"
+    }
+
+    constant! {
+        /// A `CFBoolean` for whether closing the stream also closes the
+        /// native socket it was created with.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/corefoundation/kcfstreampropertyshouldclosenativesocket).
+        should_close_native_socket: CFStreamPropertyKey = "kCFStreamPropertyShouldCloseNativeSocket"
+    }
+}
+
+object_wrapper! {
+    /// A key in the [`ssl_settings`](CFStreamPropertyKey::ssl_settings)
+    /// dictionary of a socket stream.
+    #[derive(PartialEq, Eq, Hash)]
+    pub wrapper CFStreamSSLSettingsKey: CFString;
+}
+
+impl CFStreamSSLSettingsKey {
+    constant! {
+        /// The [`CFStreamSocketSecurityLevel`] of the connection.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/cfnetwork/kcfstreamssllevel).
+        level: CFStreamSSLSettingsKey = "kCFStreamSSLLevel"
+    }
+
+    constant! {
+        /// The `CFString` host name that the server certificate must match,
+        /// or `kCFNull` to skip the check.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/cfnetwork/kcfstreamsslpeername).
+        peer_name: CFStreamSSLSettingsKey = "kCFStreamSSLPeerName"
+    }
+
+    constant! {
+        /// A `CFBoolean` for whether the certificate chain of the peer is
+        /// validated. This defaults to `true`.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/cfnetwork/kcfstreamsslvalidatescertificatechain).
+        validates_certificate_chain: CFStreamSSLSettingsKey = "kCFStreamSSLValidatesCertificateChain"
+    }
+
+    constant! {
+        /// A `CFBoolean` for whether the stream acts as the server side of
+        /// the connection.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/cfnetwork/kcfstreamsslisserver).
+        is_server: CFStreamSSLSettingsKey = "kCFStreamSSLIsServer"
+    }
+
+    constant! {
+        /// A `CFArray` whose first element is the `SecIdentity` of the local
+        /// certificate, followed by its intermediate certificates.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/cfnetwork/kcfstreamsslcertificates).
+        certificates: CFStreamSSLSettingsKey = "kCFStreamSSLCertificates"
+    }
+}
+
+object_wrapper! {
+    /// The security protocol of a socket stream.
+    #[derive(PartialEq, Eq, Hash)]
+    pub wrapper CFStreamSocketSecurityLevel: CFString;
+}
+
+impl CFStreamSocketSecurityLevel {
+    constant! {
+        /// No security.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/corefoundation/kcfstreamsocketsecuritylevelnone).
+        none: CFStreamSocketSecurityLevel = "kCFStreamSocketSecurityLevelNone"
+    }
+
+    constant! {
+        /// TLS.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/corefoundation/kcfstreamsocketsecurityleveltlsv1).
+        tls_v1: CFStreamSocketSecurityLevel = "kCFStreamSocketSecurityLevelTLSv1"
+    }
+
+    constant! {
+        /// The most secure protocol supported by both peers.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/corefoundation/kcfstreamsocketsecuritylevelnegotiatedssl).
+        negotiated_ssl: CFStreamSocketSecurityLevel = "kCFStreamSocketSecurityLevelNegotiatedSSL"
+    }
+}
+
+object_wrapper! {
+    /// A key in the [`socks_proxy`](CFStreamPropertyKey::socks_proxy)
+    /// dictionary of a socket stream.
+    #[derive(PartialEq, Eq, Hash)]
+    pub wrapper CFStreamSOCKSProxyKey: CFString;
+}
+
+impl CFStreamSOCKSProxyKey {
+    constant! {
+        /// The `CFString` host name of the proxy.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/corefoundation/kcfstreampropertysocksproxyhost).
+        host: CFStreamSOCKSProxyKey = "kCFStreamPropertySOCKSProxyHost"
+    }
+
+    constant! {
+        /// The `CFNumber` port of the proxy.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/corefoundation/kcfstreampropertysocksproxyport).
+        port: CFStreamSOCKSProxyKey = "kCFStreamPropertySOCKSProxyPort"
+    }
+
+    constant! {
+        /// The SOCKS protocol version, which is `kCFStreamSocketSOCKSVersion4`
+        /// or `kCFStreamSocketSOCKSVersion5` (the default).
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/corefoundation/kcfstreampropertysocksversion).
+        version: CFStreamSOCKSProxyKey = "kCFStreamPropertySOCKSVersion"
+    }
+
+    constant! {
+        /// The `CFString` user name for the proxy.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/corefoundation/kcfstreampropertysocksuser).
+        user: CFStreamSOCKSProxyKey = "kCFStreamPropertySOCKSUser"
+    }
+
+    constant! {
+        /// The `CFString` password for the proxy.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/corefoundation/kcfstreampropertysockspassword).
+        password: CFStreamSOCKSProxyKey = "kCFStreamPropertySOCKSPassword"
+    }
+}
@@ -0,0 +1,33 @@
+use crate::objc::NSInteger;
+
+/// Whether the user is signed in to an iCloud account that the app can use.
+///
+/// See [documentation](https://developer.apple.com/documentation/cloudkit/ckaccountstatus).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CKAccountStatus(pub NSInteger);
+
+impl CKAccountStatus {
+    /// An error occurred while determining the status.
+    #[doc(alias = "CKAccountStatusCouldNotDetermine")]
+    pub const COULD_NOT_DETERMINE: Self = Self(0);
+
+    /// The user is signed in and the app can access their private database.
+    #[doc(alias = "CKAccountStatusAvailable")]
+    pub const AVAILABLE: Self = Self(1);
+
+    /// Parental controls or device management deny access to iCloud.
+    #[doc(alias = "CKAccountStatusRestricted")]
+    pub const RESTRICTED: Self = Self(2);
+
+    /// The user is not signed in to iCloud.
+    #[doc(alias = "CKAccountStatusNoAccount")]
+    pub const NO_ACCOUNT: Self = Self(3);
+
+    /// The account is signed in but not yet usable, such as while it is
+    /// being verified.
+    ///
+    /// Requires macOS 12 or later.
+    #[doc(alias = "CKAccountStatusTemporarilyUnavailable")]
+    pub const TEMPORARILY_UNAVAILABLE: Self = Self(4);
+}
@@ -0,0 +1,46 @@
+use crate::core::Arc;
+use crate::foundation::NSURL;
+use crate::objc::{ClassType, NSObject, Sel};
+
+objc_subclass! {
+    /// A file stored with a [`CKRecord`](super::CKRecord), for data too large
+    /// to store in a field, such as images.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/cloudkit/ckasset).
+    pub class CKAsset: NSObject<'static>;
+}
+
+impl CKAsset {
+    /// Creates an asset that uploads the file at `file_url` when its record
+    /// is saved.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/cloudkit/ckasset/init(fileurl:)).
+    #[inline]
+    #[doc(alias = "initWithFileURL:")]
+    pub fn new(file_url: &NSURL) -> Arc<Self> {
+        unsafe {
+            let obj: Arc<Self> = Self::class().alloc();
+
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(obj: Arc<CKAsset>, sel: Sel, file_url: &NSURL) -> Arc<CKAsset>;
+            }
+
+            objc_msgSend(obj, selector!(initWithFileURL:), file_url)
+        }
+    }
+
+    /// Returns the URL of the local copy of the file, or [`None`] if it has
+    /// not been downloaded.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/cloudkit/ckasset/fileurl).
+    #[inline]
+    #[doc(alias = "fileURL")]
+    pub fn file_url(&self) -> Option<Arc<NSURL>> {
+        unsafe {
+            _msg_send_any![self, fileURL => *const NSURL]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+}
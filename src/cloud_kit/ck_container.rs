@@ -0,0 +1,111 @@
+use super::{CKAccountStatus, CKDatabase};
+use crate::core::Arc;
+use crate::foundation::{NSError, NSString};
+use crate::objc::{Block, ClassType, NSObject, RcBlock};
+use std::cell::Cell;
+
+objc_subclass! {
+    /// An app's iCloud storage, which contains a public database shared by
+    /// all users and a private database for each user.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/cloudkit/ckcontainer).
+    pub class CKContainer: NSObject<'static>;
+}
+
+impl CKContainer {
+    /// Returns the container whose identifier matches the app's bundle
+    /// identifier.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/cloudkit/ckcontainer/default()).
+    #[inline]
+    #[doc(alias = "defaultContainer")]
+    pub fn default_container() -> Arc<Self> {
+        unsafe { Arc::retain_raw(_msg_send_any![Self::class(), defaultContainer => *const Self]) }
+    }
+
+    /// Returns the container with `identifier`, such as
+    /// `iCloud.com.example.Notes`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/cloudkit/ckcontainer/init(identifier:)).
+    #[inline]
+    #[doc(alias = "containerWithIdentifier:")]
+    pub fn with_identifier(identifier: &NSString) -> Arc<Self> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![
+                Self::class(),
+                containerWithIdentifier: identifier
+                => *const Self
+            ])
+        }
+    }
+
+    /// Returns the identifier of the container.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/cloudkit/ckcontainer/containeridentifier).
+    #[inline]
+    #[doc(alias = "containerIdentifier")]
+    pub fn identifier(&self) -> Option<&NSString<'static>> {
+        unsafe { _msg_send_any![self, containerIdentifier] }
+    }
+
+    /// Returns the database of the current user, which only they can access.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/cloudkit/ckcontainer/privateclouddatabase).
+    #[inline]
+    #[doc(alias = "privateCloudDatabase")]
+    pub fn private_database(&self) -> Arc<CKDatabase> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, privateCloudDatabase => *const CKDatabase]) }
+    }
+
+    /// Returns the database shared by all users of the app.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/cloudkit/ckcontainer/publicclouddatabase).
+    #[inline]
+    #[doc(alias = "publicCloudDatabase")]
+    pub fn public_database(&self) -> Arc<CKDatabase> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, publicCloudDatabase => *const CKDatabase]) }
+    }
+
+    /// Returns the database of records that other users shared with the
+    /// current user.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/cloudkit/ckcontainer/sharedclouddatabase).
+    #[inline]
+    #[doc(alias = "sharedCloudDatabase")]
+    pub fn shared_database(&self) -> Arc<CKDatabase> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, sharedCloudDatabase => *const CKDatabase]) }
+    }
+
+    /// Calls `completion` with whether the user's iCloud account can be used,
+    /// which should be checked before accessing the private database.
+    ///
+    /// It is safe to panic within `completion`. Panics will abort the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/cloudkit/ckcontainer/accountstatus(completionhandler:)).
+    #[doc(alias = "accountStatusWithCompletionHandler:")]
+    pub fn account_status<F>(&self, completion: F)
+    where
+        F: FnOnce(Result<CKAccountStatus, Arc<NSError<'static>>>) + Send + 'static,
+    {
+        let completion = Cell::new(Some(completion));
+        let block = RcBlock::new(
+            move |status: CKAccountStatus, error: *const NSError<'static>| {
+                if let Some(completion) = completion.take() {
+                    completion(match unsafe { error.as_ref() } {
+                        Some(error) => Err(Arc::retain(error)),
+                        None => Ok(status),
+                    });
+                }
+            },
+        );
+        let block: &Block<_, ()> = &block;
+
+        unsafe {
+            _msg_send_any![
+                self,
+                accountStatusWithCompletionHandler: block
+                => ()
+            ]
+        }
+    }
+}
@@ -0,0 +1,338 @@
+use super::{CKQuery, CKRecord, CKRecordID, CKRecordZone, CKRecordZoneID};
+use crate::core::{Arc, FlattenCompletion};
+use crate::foundation::{NSArray, NSError};
+use crate::objc::{Block, NSInteger, NSObject, ObjectType, RcBlock};
+use std::{cell::Cell, ptr};
+
+/// The result of a [`CKDatabase`] request.
+pub type CKResult<T> = Result<Arc<T>, Arc<NSError<'static>>>;
+
+objc_subclass! {
+    /// A public, private, or shared database of a [`CKContainer`](super::CKContainer).
+    ///
+    /// Completion handlers are called on a background queue. Errors are in
+    /// the `CKErrorDomain`, such as when the network is unavailable or the
+    /// record changed on the server since it was fetched.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/cloudkit/ckdatabase).
+    pub class CKDatabase: NSObject<'static>;
+}
+
+/// Returns a block that passes its result to `completion` once.
+fn completion_block<T, F>(completion: F) -> RcBlock<(*const T, *const NSError<'static>), ()>
+where
+    T: ObjectType<'static> + 'static,
+    F: FnOnce(CKResult<T>) + Send + 'static,
+{
+    let completion = Cell::new(Some(completion));
+    RcBlock::new(move |object: *const T, error: *const NSError<'static>| {
+        if let Some(completion) = completion.take() {
+            completion(unsafe {
+                match object.as_ref() {
+                    Some(object) => Ok(Arc::retain(object)),
+                    None => Err(Arc::retain(&*error)),
+                }
+            });
+        }
+    })
+}
+
+/// Records.
+impl CKDatabase {
+    /// Returns whether this is the public, private, or shared database.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/cloudkit/ckdatabase/databasescope).
+    #[inline]
+    #[doc(alias = "databaseScope")]
+    pub fn scope(&self) -> CKDatabaseScope {
+        unsafe { _msg_send_any![self, databaseScope] }
+    }
+
+    /// Saves `record`, calling `completion` with the saved record.
+    ///
+    /// Saving a record that changed on the server since it was fetched fails
+    /// with `CKErrorServerRecordChanged`.
+    ///
+    /// It is safe to panic within `completion`. Panics will abort the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/cloudkit/ckdatabase/save(_:completionhandler:)-3tatz).
+    #[inline]
+    #[doc(alias = "saveRecord:completionHandler:")]
+    pub fn save_record<F>(&self, record: &CKRecord, completion: F)
+    where
+        F: FnOnce(CKResult<CKRecord>) + Send + 'static,
+    {
+        let block = completion_block(completion);
+        let block: &Block<_, ()> = &block;
+
+        unsafe {
+            _msg_send_any![
+                self,
+                saveRecord: record
+                completionHandler: block
+                => ()
+            ]
+        }
+    }
+
+    /// Fetches the record identified by `record_id`, calling `completion`
+    /// with the result.
+    ///
+    /// It is safe to panic within `completion`. Panics will abort the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/cloudkit/ckdatabase/fetch(withrecordid:completionhandler:)).
+    #[inline]
+    #[doc(alias = "fetchRecordWithID:completionHandler:")]
+    pub fn fetch_record<F>(&self, record_id: &CKRecordID, completion: F)
+    where
+        F: FnOnce(CKResult<CKRecord>) + Send + 'static,
+    {
+        let block = completion_block(completion);
+        let block: &Block<_, ()> = &block;
+
+        unsafe {
+            _msg_send_any![
+                self,
+                fetchRecordWithID: record_id
+                completionHandler: block
+                => ()
+            ]
+        }
+    }
+
+    /// Deletes the record identified by `record_id`, calling `completion`
+    /// with the identifier of the deleted record.
+    ///
+    /// It is safe to panic within `completion`. Panics will abort the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/cloudkit/ckdatabase/delete(withrecordid:completionhandler:)).
+    #[inline]
+    #[doc(alias = "deleteRecordWithID:completionHandler:")]
+    pub fn delete_record<F>(&self, record_id: &CKRecordID, completion: F)
+    where
+        F: FnOnce(CKResult<CKRecordID>) + Send + 'static,
+    {
+        let block = completion_block(completion);
+        let block: &Block<_, ()> = &block;
+
+        unsafe {
+            _msg_send_any![
+                self,
+                deleteRecordWithID: record_id
+                completionHandler: block
+                => ()
+            ]
+        }
+    }
+
+    /// Finds the records matching `query` in the zone identified by
+    /// `zone_id`, or in the default zone if [`None`], calling `completion`
+    /// with the matching records.
+    ///
+    /// It is safe to panic within `completion`. Panics will abort the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/cloudkit/ckdatabase/perform(_:inzonewith:completionhandler:)).
+    #[inline]
+    #[doc(alias = "performQuery:inZoneWithID:completionHandler:")]
+    pub fn perform_query<F>(&self, query: &CKQuery, zone_id: Option<&CKRecordZoneID>, completion: F)
+    where
+        F: FnOnce(CKResult<NSArray<CKRecord>>) + Send + 'static,
+    {
+        let zone_id: *const CKRecordZoneID = match zone_id {
+            Some(zone_id) => zone_id,
+            None => ptr::null(),
+        };
+        let block = completion_block(completion);
+        let block: &Block<_, ()> = &block;
+
+        unsafe {
+            _msg_send_any![
+                self,
+                performQuery: query
+                inZoneWithID: zone_id
+                completionHandler: block
+                => ()
+            ]
+        }
+    }
+}
+
+/// Record zones.
+impl CKDatabase {
+    /// Creates or updates `zone`, calling `completion` with the saved zone.
+    ///
+    /// It is safe to panic within `completion`. Panics will abort the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/cloudkit/ckdatabase/save(_:completionhandler:)-5a2ro).
+    #[inline]
+    #[doc(alias = "saveRecordZone:completionHandler:")]
+    pub fn save_record_zone<F>(&self, zone: &CKRecordZone, completion: F)
+    where
+        F: FnOnce(CKResult<CKRecordZone>) + Send + 'static,
+    {
+        let block = completion_block(completion);
+        let block: &Block<_, ()> = &block;
+
+        unsafe {
+            _msg_send_any![
+                self,
+                saveRecordZone: zone
+                completionHandler: block
+                => ()
+            ]
+        }
+    }
+
+    /// Fetches all zones of the database, calling `completion` with the
+    /// result.
+    ///
+    /// It is safe to panic within `completion`. Panics will abort the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/cloudkit/ckdatabase/fetchallrecordzones(completionhandler:)).
+    #[inline]
+    #[doc(alias = "fetchAllRecordZonesWithCompletionHandler:")]
+    pub fn fetch_all_record_zones<F>(&self, completion: F)
+    where
+        F: FnOnce(CKResult<NSArray<CKRecordZone>>) + Send + 'static,
+    {
+        let block = completion_block(completion);
+        let block: &Block<_, ()> = &block;
+
+        unsafe {
+            _msg_send_any![
+                self,
+                fetchAllRecordZonesWithCompletionHandler: block
+                => ()
+            ]
+        }
+    }
+
+    /// Deletes the zone identified by `zone_id` and all of its records,
+    /// calling `completion` with the identifier of the deleted zone.
+    ///
+    /// It is safe to panic within `completion`. Panics will abort the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/cloudkit/ckdatabase/delete(withrecordzoneid:completionhandler:)).
+    #[inline]
+    #[doc(alias = "deleteRecordZoneWithID:completionHandler:")]
+    pub fn delete_record_zone<F>(&self, zone_id: &CKRecordZoneID, completion: F)
+    where
+        F: FnOnce(CKResult<CKRecordZoneID>) + Send + 'static,
+    {
+        let block = completion_block(completion);
+        let block: &Block<_, ()> = &block;
+
+        unsafe {
+            _msg_send_any![
+                self,
+                deleteRecordZoneWithID: zone_id
+                completionHandler: block
+                => ()
+            ]
+        }
+    }
+}
+
+/// Futures.
+///
+/// These resolve to an `NSUserCancelledError` if CloudKit drops the
+/// completion handler without calling it.
+impl CKDatabase {
+    /// Saves `record`, resolving to the saved record.
+    ///
+    /// This is the future form of [`save_record`](Self::save_record).
+    #[inline]
+    pub fn save_record_async(&self, record: &CKRecord) -> FlattenCompletion<CKResult<CKRecord>> {
+        crate::completion_to_future!(self.save_record(record))
+    }
+
+    /// Fetches the record identified by `record_id`.
+    ///
+    /// This is the future form of [`fetch_record`](Self::fetch_record).
+    #[inline]
+    pub fn fetch_record_async(
+        &self,
+        record_id: &CKRecordID,
+    ) -> FlattenCompletion<CKResult<CKRecord>> {
+        crate::completion_to_future!(self.fetch_record(record_id))
+    }
+
+    /// Deletes the record identified by `record_id`, resolving to the
+    /// identifier of the deleted record.
+    ///
+    /// This is the future form of [`delete_record`](Self::delete_record).
+    #[inline]
+    pub fn delete_record_async(
+        &self,
+        record_id: &CKRecordID,
+    ) -> FlattenCompletion<CKResult<CKRecordID>> {
+        crate::completion_to_future!(self.delete_record(record_id))
+    }
+
+    /// Finds the records matching `query` in the zone identified by
+    /// `zone_id`, or in the default zone if [`None`].
+    ///
+    /// This is the future form of [`perform_query`](Self::perform_query).
+    #[inline]
+    pub fn perform_query_async(
+        &self,
+        query: &CKQuery,
+        zone_id: Option<&CKRecordZoneID>,
+    ) -> FlattenCompletion<CKResult<NSArray<CKRecord>>> {
+        crate::completion_to_future!(self.perform_query(query, zone_id))
+    }
+
+    /// Creates or updates `zone`, resolving to the saved zone.
+    ///
+    /// This is the future form of [`save_record_zone`](Self::save_record_zone).
+    #[inline]
+    pub fn save_record_zone_async(
+        &self,
+        zone: &CKRecordZone,
+    ) -> FlattenCompletion<CKResult<CKRecordZone>> {
+        crate::completion_to_future!(self.save_record_zone(zone))
+    }
+
+    /// Fetches all zones of the database.
+    ///
+    /// This is the future form of [`fetch_all_record_zones`](Self::fetch_all_record_zones).
+    #[inline]
+    pub fn fetch_all_record_zones_async(
+        &self,
+    ) -> FlattenCompletion<CKResult<NSArray<CKRecordZone>>> {
+        crate::completion_to_future!(self.fetch_all_record_zones())
+    }
+
+    /// Deletes the zone identified by `zone_id` and all of its records,
+    /// resolving to the identifier of the deleted zone.
+    ///
+    /// This is the future form of [`delete_record_zone`](Self::delete_record_zone).
+    #[inline]
+    pub fn delete_record_zone_async(
+        &self,
+        zone_id: &CKRecordZoneID,
+    ) -> FlattenCompletion<CKResult<CKRecordZoneID>> {
+        crate::completion_to_future!(self.delete_record_zone(zone_id))
+    }
+}
+
+/// The scope of a [`CKDatabase`].
+///
+/// See [documentation](https://developer.apple.com/documentation/cloudkit/ckdatabase/scope).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CKDatabaseScope(pub NSInteger);
+
+impl CKDatabaseScope {
+    /// The database shared by all users of the app.
+    #[doc(alias = "CKDatabaseScopePublic")]
+    pub const PUBLIC: Self = Self(1);
+
+    /// The database of the current user.
+    #[doc(alias = "CKDatabaseScopePrivate")]
+    pub const PRIVATE: Self = Self(2);
+
+    /// The database of records shared with the current user.
+    #[doc(alias = "CKDatabaseScopeShared")]
+    pub const SHARED: Self = Self(3);
+}
@@ -0,0 +1,84 @@
+use crate::core::Arc;
+use crate::foundation::{NSArray, NSPredicate, NSSortDescriptor, NSString};
+use crate::objc::{ClassType, NSObject, Sel};
+use std::fmt;
+
+objc_subclass! {
+    /// Finds the records of a type that match a predicate.
+    ///
+    /// Only a subset of predicate syntax is supported, such as comparisons
+    /// of fields to constant values, `BEGINSWITH`, and `IN`. Fields used in
+    /// queries must be marked as queryable in the CloudKit schema.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/cloudkit/ckquery).
+    pub class CKQuery: NSObject<'static>;
+}
+
+impl fmt::Debug for CKQuery {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CKQuery")
+            .field("record_type", &self.record_type())
+            .field("predicate", &self.predicate())
+            .finish()
+    }
+}
+
+impl CKQuery {
+    /// Creates a query for the records of the type `record_type` that match
+    /// `predicate`.
+    ///
+    /// All records of the type are matched by
+    /// [`NSPredicate::from_value(true)`](NSPredicate::from_value).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/cloudkit/ckquery/init(recordtype:predicate:)).
+    #[inline]
+    #[doc(alias = "initWithRecordType:predicate:")]
+    pub fn new(record_type: &NSString, predicate: &NSPredicate) -> Arc<Self> {
+        unsafe {
+            let obj: Arc<Self> = Self::class().alloc();
+
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(
+                    obj: Arc<CKQuery>,
+                    sel: Sel,
+                    record_type: &NSString,
+                    predicate: &NSPredicate,
+                ) -> Arc<CKQuery>;
+            }
+
+            objc_msgSend(
+                obj,
+                selector!(initWithRecordType:predicate:),
+                record_type,
+                predicate,
+            )
+        }
+    }
+
+    /// Returns the type of the records to find.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/cloudkit/ckquery/recordtype).
+    #[inline]
+    #[doc(alias = "recordType")]
+    pub fn record_type(&self) -> &NSString<'static> {
+        unsafe { _msg_send_any![self, recordType] }
+    }
+
+    /// Returns the condition that records must match.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/cloudkit/ckquery/predicate).
+    #[inline]
+    pub fn predicate(&self) -> &NSPredicate {
+        unsafe { _msg_send_any![self, predicate] }
+    }
+
+    /// Sets the order of the results.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/cloudkit/ckquery/sortdescriptors).
+    #[inline]
+    #[doc(alias = "setSortDescriptors:")]
+    pub fn set_sort_descriptors(&self, sort_descriptors: Option<&NSArray<NSSortDescriptor>>) {
+        unsafe { _msg_send_any![self, setSortDescriptors: sort_descriptors => ()] }
+    }
+}
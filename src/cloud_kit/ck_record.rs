@@ -0,0 +1,187 @@
+use super::{CKRecordID, CKRecordValue};
+use crate::core::Arc;
+use crate::foundation::{NSArray, NSDate, NSString};
+use crate::objc::{ClassType, NSObject, Sel};
+use std::{fmt, ptr};
+
+objc_subclass! {
+    /// A collection of fields stored in a CloudKit database.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fruity::cloud_kit::{CKContainer, CKRecord};
+    /// use fruity::ns_string;
+    ///
+    /// let note = CKRecord::new(ns_string!("Note"));
+    /// note.set(ns_string!("title"), Some("Groceries".into()));
+    /// note.set(ns_string!("pinned"), Some(true.into()));
+    ///
+    /// let database = CKContainer::default_container().private_database();
+    /// database.save_record(&note, |result| match result {
+    ///     Ok(record) => println!("saved {:?}", record.record_id()),
+    ///     Err(error) => eprintln!("failed to save: {}", error),
+    /// });
+    /// ```
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/cloudkit/ckrecord).
+    pub class CKRecord: NSObject<'static>;
+}
+
+impl fmt::Debug for CKRecord {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let keys = self.all_keys();
+
+        f.debug_struct("CKRecord")
+            .field("record_type", &self.record_type())
+            .field("record_id", &self.record_id())
+            .field(
+                "fields",
+                &keys
+                    .iter()
+                    .map(|key| (key, self.get(key)))
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+impl CKRecord {
+    /// Creates a record of the type `record_type` with a unique identifier
+    /// in the default zone.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/cloudkit/ckrecord/init(recordtype:)).
+    #[inline]
+    #[doc(alias = "initWithRecordType:")]
+    pub fn new(record_type: &NSString) -> Arc<Self> {
+        unsafe {
+            let obj: Arc<Self> = Self::class().alloc();
+
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(
+                    obj: Arc<CKRecord>,
+                    sel: Sel,
+                    record_type: &NSString,
+                ) -> Arc<CKRecord>;
+            }
+
+            objc_msgSend(obj, selector!(initWithRecordType:), record_type)
+        }
+    }
+
+    /// Creates a record of the type `record_type` identified by `record_id`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/cloudkit/ckrecord/init(recordtype:recordid:)).
+    #[inline]
+    #[doc(alias = "initWithRecordType:recordID:")]
+    pub fn with_id(record_type: &NSString, record_id: &CKRecordID) -> Arc<Self> {
+        unsafe {
+            let obj: Arc<Self> = Self::class().alloc();
+
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(
+                    obj: Arc<CKRecord>,
+                    sel: Sel,
+                    record_type: &NSString,
+                    record_id: &CKRecordID,
+                ) -> Arc<CKRecord>;
+            }
+
+            objc_msgSend(
+                obj,
+                selector!(initWithRecordType:recordID:),
+                record_type,
+                record_id,
+            )
+        }
+    }
+
+    /// Returns the type of the record, which groups records like a table.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/cloudkit/ckrecord/recordtype).
+    #[inline]
+    #[doc(alias = "recordType")]
+    pub fn record_type(&self) -> &NSString<'static> {
+        unsafe { _msg_send_any![self, recordType] }
+    }
+
+    /// Returns the identifier of the record.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/cloudkit/ckrecord/recordid).
+    #[inline]
+    #[doc(alias = "recordID")]
+    pub fn record_id(&self) -> Arc<CKRecordID> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, recordID => *const CKRecordID]) }
+    }
+
+    /// Returns when the record was first saved, or [`None`] if it has not
+    /// been saved.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/cloudkit/ckrecord/creationdate).
+    #[inline]
+    #[doc(alias = "creationDate")]
+    pub fn creation_date(&self) -> Option<Arc<NSDate>> {
+        unsafe {
+            _msg_send_any![self, creationDate => *const NSDate]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Returns when the record was last saved, or [`None`] if it has not
+    /// been saved.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/cloudkit/ckrecord/modificationdate).
+    #[inline]
+    #[doc(alias = "modificationDate")]
+    pub fn modification_date(&self) -> Option<Arc<NSDate>> {
+        unsafe {
+            _msg_send_any![self, modificationDate => *const NSDate]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Returns the names of the fields that have values.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/cloudkit/ckrecord/allkeys()).
+    #[inline]
+    #[doc(alias = "allKeys")]
+    pub fn all_keys(&self) -> Arc<NSArray<NSString<'static>>> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![
+                self,
+                allKeys => *const NSArray<NSString<'static>>
+            ])
+        }
+    }
+
+    /// Returns the value of the field `key`, or [`None`] if it has no value.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/cloudkit/ckrecord/object(forkey:)).
+    #[inline]
+    #[doc(alias = "objectForKey:")]
+    pub fn get(&self, key: &NSString) -> Option<CKRecordValue> {
+        let object = unsafe {
+            _msg_send_any![self, objectForKey: key => *const NSObject<'static>].as_ref()
+        }?;
+        CKRecordValue::from_object(Arc::retain(object))
+    }
+
+    /// Sets the value of the field `key`, or removes it if `value` is
+    /// [`None`].
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/cloudkit/ckrecord/setobject(_:forkey:)).
+    #[inline]
+    #[doc(alias = "setObject:forKey:")]
+    pub fn set(&self, key: &NSString, value: Option<CKRecordValue>) {
+        let value: *const NSObject = match &value {
+            Some(value) => value.as_object(),
+            None => ptr::null(),
+        };
+
+        unsafe { _msg_send_any![self, setObject: value forKey: key => ()] }
+    }
+}
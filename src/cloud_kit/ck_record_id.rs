@@ -0,0 +1,93 @@
+use super::CKRecordZoneID;
+use crate::core::Arc;
+use crate::foundation::NSString;
+use crate::objc::{ClassType, NSObject, Sel};
+use std::fmt;
+
+objc_subclass! {
+    /// Identifies a [`CKRecord`](super::CKRecord) by its name and zone.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/cloudkit/ckrecord/id).
+    pub class CKRecordID: NSObject<'static>;
+}
+
+impl fmt::Debug for CKRecordID {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CKRecordID")
+            .field("record_name", &self.record_name())
+            .field("zone_id", &self.zone_id())
+            .finish()
+    }
+}
+
+impl CKRecordID {
+    /// Creates an identifier for the record named `record_name` in the
+    /// default zone.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/cloudkit/ckrecord/id/init(recordname:)).
+    #[inline]
+    #[doc(alias = "initWithRecordName:")]
+    pub fn new(record_name: &NSString) -> Arc<Self> {
+        unsafe {
+            let obj: Arc<Self> = Self::class().alloc();
+
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(
+                    obj: Arc<CKRecordID>,
+                    sel: Sel,
+                    record_name: &NSString,
+                ) -> Arc<CKRecordID>;
+            }
+
+            objc_msgSend(obj, selector!(initWithRecordName:), record_name)
+        }
+    }
+
+    /// Creates an identifier for the record named `record_name` in the zone
+    /// identified by `zone_id`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/cloudkit/ckrecord/id/init(recordname:zoneid:)).
+    #[inline]
+    #[doc(alias = "initWithRecordName:zoneID:")]
+    pub fn in_zone(record_name: &NSString, zone_id: &CKRecordZoneID) -> Arc<Self> {
+        unsafe {
+            let obj: Arc<Self> = Self::class().alloc();
+
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(
+                    obj: Arc<CKRecordID>,
+                    sel: Sel,
+                    record_name: &NSString,
+                    zone_id: &CKRecordZoneID,
+                ) -> Arc<CKRecordID>;
+            }
+
+            objc_msgSend(
+                obj,
+                selector!(initWithRecordName:zoneID:),
+                record_name,
+                zone_id,
+            )
+        }
+    }
+
+    /// Returns the name of the record, which is unique within its zone.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/cloudkit/ckrecord/id/recordname).
+    #[inline]
+    #[doc(alias = "recordName")]
+    pub fn record_name(&self) -> &NSString<'static> {
+        unsafe { _msg_send_any![self, recordName] }
+    }
+
+    /// Returns the identifier of the zone containing the record.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/cloudkit/ckrecord/id/zoneid).
+    #[inline]
+    #[doc(alias = "zoneID")]
+    pub fn zone_id(&self) -> Arc<CKRecordZoneID> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, zoneID => *const CKRecordZoneID]) }
+    }
+}
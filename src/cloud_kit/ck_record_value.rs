@@ -0,0 +1,217 @@
+use super::{CKAsset, CKReference};
+use crate::core::Arc;
+use crate::foundation::{NSArray, NSData, NSDate, NSNumber, NSString};
+use crate::objc::{ClassType, NSObject};
+use std::fmt;
+
+#[cfg(feature = "core_location")]
+use crate::core_location::CLLocation;
+
+/// A value that can be stored in a field of a [`CKRecord`](super::CKRecord).
+///
+/// Rust strings, numbers, booleans, and bytes convert into values with
+/// [`From`]. Lists may only contain other record values of the same type.
+/// This is not enforced by the type of [`List`](Self::List), so its elements
+/// should be read with [`from_object`](Self::from_object).
+///
+/// See [documentation](https://developer.apple.com/documentation/cloudkit/ckrecordvalue).
+#[derive(Clone)]
+pub enum CKRecordValue {
+    /// A string.
+    String(Arc<NSString<'static>>),
+    /// An integer, floating-point number, or boolean.
+    Number(Arc<NSNumber>),
+    /// A date.
+    Date(Arc<NSDate>),
+    /// A buffer of bytes, which should be small. Large data should be stored
+    /// as an [`Asset`](Self::Asset).
+    Data(Arc<NSData>),
+    /// A link to another record.
+    Reference(Arc<CKReference>),
+    /// A file stored alongside the record.
+    Asset(Arc<CKAsset>),
+    /// A geographic location.
+    #[cfg(feature = "core_location")]
+    Location(Arc<CLLocation>),
+    /// A list of values.
+    List(Arc<NSArray<NSObject<'static>>>),
+}
+
+impl PartialEq for CKRecordValue {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_object() == other.as_object()
+    }
+}
+
+impl fmt::Debug for CKRecordValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // List elements are untyped, so they are converted for formatting.
+        fn debug_value(value: &NSObject<'static>) -> impl fmt::Debug {
+            CKRecordValue::from_object(Arc::retain(value))
+        }
+
+        match self {
+            Self::String(string) => string.fmt(f),
+            Self::Number(number) => number.fmt(f),
+            Self::Date(date) => date.fmt(f),
+            Self::Data(data) => data.as_bytes().fmt(f),
+            Self::Reference(reference) => reference.fmt(f),
+            Self::Asset(asset) => f.debug_tuple("CKAsset").field(&asset.file_url()).finish(),
+            #[cfg(feature = "core_location")]
+            Self::Location(location) => location.coordinate().fmt(f),
+            Self::List(list) => f
+                .debug_list()
+                .entries(list.iter().map(debug_value))
+                .finish(),
+        }
+    }
+}
+
+macro_rules! impl_from {
+    ($($variant:ident($t:ty),)+) => {
+        $(
+            impl From<Arc<$t>> for CKRecordValue {
+                #[inline]
+                fn from(value: Arc<$t>) -> Self {
+                    Self::$variant(value)
+                }
+            }
+        )+
+    };
+}
+
+impl_from! {
+    String(NSString<'static>),
+    Number(NSNumber),
+    Date(NSDate),
+    Data(NSData),
+    Reference(CKReference),
+    Asset(CKAsset),
+    List(NSArray<NSObject<'static>>),
+}
+
+#[cfg(feature = "core_location")]
+impl_from! {
+    Location(CLLocation),
+}
+
+impl From<&str> for CKRecordValue {
+    #[inline]
+    fn from(value: &str) -> Self {
+        Self::String(NSString::from_str(value))
+    }
+}
+
+impl From<i64> for CKRecordValue {
+    #[inline]
+    fn from(value: i64) -> Self {
+        Self::Number(NSNumber::from_longlong(value))
+    }
+}
+
+impl From<f64> for CKRecordValue {
+    #[inline]
+    fn from(value: f64) -> Self {
+        Self::Number(NSNumber::from_double(value))
+    }
+}
+
+impl From<bool> for CKRecordValue {
+    #[inline]
+    fn from(value: bool) -> Self {
+        Self::Number(NSNumber::from_bool(value))
+    }
+}
+
+impl From<&[u8]> for CKRecordValue {
+    #[inline]
+    fn from(value: &[u8]) -> Self {
+        Self::Data(NSData::from_bytes(value))
+    }
+}
+
+impl CKRecordValue {
+    /// Returns `object` as a record value, or [`None`] if its type cannot be
+    /// stored in a record.
+    pub fn from_object(object: Arc<NSObject<'static>>) -> Option<Self> {
+        let is = |class| object.is_kind_of_class(class);
+
+        unsafe {
+            Some(if is(NSString::class()) {
+                Self::String(Arc::cast_unchecked(object))
+            } else if is(NSNumber::class()) {
+                Self::Number(Arc::cast_unchecked(object))
+            } else if is(NSDate::class()) {
+                Self::Date(Arc::cast_unchecked(object))
+            } else if is(NSData::class()) {
+                Self::Data(Arc::cast_unchecked(object))
+            } else if is(CKReference::class()) {
+                Self::Reference(Arc::cast_unchecked(object))
+            } else if is(CKAsset::class()) {
+                Self::Asset(Arc::cast_unchecked(object))
+            } else if is(NSArray::<NSObject>::class()) {
+                Self::List(Arc::cast_unchecked(object))
+            } else {
+                #[cfg(feature = "core_location")]
+                if is(CLLocation::class()) {
+                    return Some(Self::Location(Arc::cast_unchecked(object)));
+                }
+
+                return None;
+            })
+        }
+    }
+
+    /// Returns the underlying object.
+    #[inline]
+    pub fn as_object(&self) -> &NSObject<'static> {
+        match self {
+            Self::String(string) => string,
+            Self::Number(number) => number,
+            Self::Date(date) => date,
+            Self::Data(data) => data,
+            Self::Reference(reference) => reference,
+            Self::Asset(asset) => asset,
+            #[cfg(feature = "core_location")]
+            Self::Location(location) => location,
+            Self::List(list) => list,
+        }
+    }
+
+    /// Returns the string, if this is one.
+    #[inline]
+    pub fn as_string(&self) -> Option<&NSString<'static>> {
+        match self {
+            Self::String(string) => Some(string),
+            _ => None,
+        }
+    }
+
+    /// Returns the number, if this is one.
+    #[inline]
+    pub fn as_number(&self) -> Option<&NSNumber> {
+        match self {
+            Self::Number(number) => Some(number),
+            _ => None,
+        }
+    }
+
+    /// Returns the date, if this is one.
+    #[inline]
+    pub fn as_date(&self) -> Option<&NSDate> {
+        match self {
+            Self::Date(date) => Some(date),
+            _ => None,
+        }
+    }
+
+    /// Returns the bytes, if this is data.
+    #[inline]
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            Self::Data(data) => Some(data.as_bytes()),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,154 @@
+use crate::core::Arc;
+use crate::foundation::NSString;
+use crate::objc::{ClassType, NSObject, Sel};
+use std::fmt;
+
+objc_subclass! {
+    /// A group of records in a database, which may be fetched and deleted
+    /// together.
+    ///
+    /// Custom zones are only supported in private databases.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/cloudkit/ckrecordzone).
+    pub class CKRecordZone: NSObject<'static>;
+}
+
+impl fmt::Debug for CKRecordZone {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("CKRecordZone")
+            .field(&self.zone_id())
+            .finish()
+    }
+}
+
+impl CKRecordZone {
+    /// Creates a zone named `name` owned by the current user, which is
+    /// created when saved to a database.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/cloudkit/ckrecordzone/init(zonename:)).
+    #[inline]
+    #[doc(alias = "initWithZoneName:")]
+    pub fn new(name: &NSString) -> Arc<Self> {
+        unsafe {
+            let obj: Arc<Self> = Self::class().alloc();
+
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(
+                    obj: Arc<CKRecordZone>,
+                    sel: Sel,
+                    name: &NSString,
+                ) -> Arc<CKRecordZone>;
+            }
+
+            objc_msgSend(obj, selector!(initWithZoneName:), name)
+        }
+    }
+
+    /// Returns the zone that records are saved to by default.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/cloudkit/ckrecordzone/default()).
+    #[inline]
+    #[doc(alias = "defaultRecordZone")]
+    pub fn default_zone() -> Arc<Self> {
+        unsafe { Arc::retain_raw(_msg_send_any![Self::class(), defaultRecordZone => *const Self]) }
+    }
+
+    /// Returns the identifier of the zone.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/cloudkit/ckrecordzone/zoneid).
+    #[inline]
+    #[doc(alias = "zoneID")]
+    pub fn zone_id(&self) -> Arc<CKRecordZoneID> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, zoneID => *const CKRecordZoneID]) }
+    }
+}
+
+objc_subclass! {
+    /// Identifies a [`CKRecordZone`] by its name and owner.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/cloudkit/ckrecordzone/id).
+    pub class CKRecordZoneID: NSObject<'static>;
+}
+
+impl fmt::Debug for CKRecordZoneID {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CKRecordZoneID")
+            .field("zone_name", &self.zone_name())
+            .field("owner_name", &self.owner_name())
+            .finish()
+    }
+}
+
+impl CKRecordZoneID {
+    /// Creates an identifier for the zone named `zone_name` owned by the
+    /// current user.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/cloudkit/ckrecordzone/id/init(zonename:ownername:)).
+    #[inline]
+    #[doc(alias = "initWithZoneName:ownerName:")]
+    pub fn new(zone_name: &NSString) -> Arc<Self> {
+        Self::with_owner(zone_name, Self::current_user_name())
+    }
+
+    /// Creates an identifier for the zone named `zone_name` owned by the user
+    /// with the record name `owner_name`, such as for zones shared with the
+    /// current user.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/cloudkit/ckrecordzone/id/init(zonename:ownername:)).
+    #[inline]
+    #[doc(alias = "initWithZoneName:ownerName:")]
+    pub fn with_owner(zone_name: &NSString, owner_name: &NSString) -> Arc<Self> {
+        unsafe {
+            let obj: Arc<Self> = Self::class().alloc();
+
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(
+                    obj: Arc<CKRecordZoneID>,
+                    sel: Sel,
+                    zone_name: &NSString,
+                    owner_name: &NSString,
+                ) -> Arc<CKRecordZoneID>;
+            }
+
+            objc_msgSend(
+                obj,
+                selector!(initWithZoneName:ownerName:),
+                zone_name,
+                owner_name,
+            )
+        }
+    }
+
+    /// Returns the placeholder owner name that refers to the current user.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/cloudkit/ckcurrentuserdefaultname).
+    #[inline]
+    #[doc(alias = "CKCurrentUserDefaultName")]
+    pub fn current_user_name() -> &'static NSString<'static> {
+        extern "C" {
+            static CKCurrentUserDefaultName: &'static NSString<'static>;
+        }
+        unsafe { CKCurrentUserDefaultName }
+    }
+
+    /// Returns the name of the zone.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/cloudkit/ckrecordzone/id/zonename).
+    #[inline]
+    #[doc(alias = "zoneName")]
+    pub fn zone_name(&self) -> &NSString<'static> {
+        unsafe { _msg_send_any![self, zoneName] }
+    }
+
+    /// Returns the record name of the user who owns the zone.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/cloudkit/ckrecordzone/id/ownername).
+    #[inline]
+    #[doc(alias = "ownerName")]
+    pub fn owner_name(&self) -> &NSString<'static> {
+        unsafe { _msg_send_any![self, ownerName] }
+    }
+}
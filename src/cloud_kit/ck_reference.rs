@@ -0,0 +1,83 @@
+use super::CKRecordID;
+use crate::core::Arc;
+use crate::objc::{ClassType, NSObject, NSUInteger, Sel};
+use std::fmt;
+
+objc_subclass! {
+    /// A link from one record to another, stored in a field of the source
+    /// record.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/cloudkit/ckrecord/reference).
+    pub class CKReference: NSObject<'static>;
+}
+
+impl fmt::Debug for CKReference {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CKReference")
+            .field("record_id", &self.record_id())
+            .field("action", &self.action())
+            .finish()
+    }
+}
+
+impl CKReference {
+    /// Creates a reference to the record identified by `record_id`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/cloudkit/ckrecord/reference/init(recordid:action:)).
+    #[inline]
+    #[doc(alias = "initWithRecordID:action:")]
+    pub fn new(record_id: &CKRecordID, action: CKReferenceAction) -> Arc<Self> {
+        unsafe {
+            let obj: Arc<Self> = Self::class().alloc();
+
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(
+                    obj: Arc<CKReference>,
+                    sel: Sel,
+                    record_id: &CKRecordID,
+                    action: CKReferenceAction,
+                ) -> Arc<CKReference>;
+            }
+
+            objc_msgSend(obj, selector!(initWithRecordID:action:), record_id, action)
+        }
+    }
+
+    /// Returns the identifier of the referenced record.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/cloudkit/ckrecord/reference/recordid).
+    #[inline]
+    #[doc(alias = "recordID")]
+    pub fn record_id(&self) -> Arc<CKRecordID> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, recordID => *const CKRecordID]) }
+    }
+
+    /// Returns what happens to the source record when the referenced record
+    /// is deleted.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/cloudkit/ckrecord/reference/action).
+    #[inline]
+    #[doc(alias = "referenceAction")]
+    pub fn action(&self) -> CKReferenceAction {
+        unsafe { _msg_send_any![self, referenceAction] }
+    }
+}
+
+/// What happens to the source of a [`CKReference`] when the referenced record
+/// is deleted.
+///
+/// See [documentation](https://developer.apple.com/documentation/cloudkit/ckrecord/referenceaction).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CKReferenceAction(pub NSUInteger);
+
+impl CKReferenceAction {
+    /// The source record is kept.
+    #[doc(alias = "CKReferenceActionNone")]
+    pub const NONE: Self = Self(0);
+
+    /// The source record is also deleted, such as for the items of a list.
+    #[doc(alias = "CKReferenceActionDeleteSelf")]
+    pub const DELETE_SELF: Self = Self(1);
+}
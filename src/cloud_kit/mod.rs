@@ -0,0 +1,66 @@
+//! [CloudKit](https://developer.apple.com/documentation/cloudkit) framework.
+//!
+//! # Feature Flag
+//!
+//! This module corresponds to the **`cloud_kit`**
+//! [feature flag](../index.html#feature-flags).
+//!
+//! It also transitively enables [`foundation`](crate::foundation).
+//!
+//! # Scope
+//!
+//! These bindings cover the convenience APIs of [`CKDatabase`] for saving,
+//! fetching, deleting, and querying individual records, which is enough for
+//! simple persistence. Batch operations and subscriptions are not supported.
+//!
+//! Apps must be signed with the iCloud entitlement and a container
+//! identifier, otherwise CloudKit raises an exception on first use.
+//!
+//! # Async
+//!
+//! Methods that take a completion closure have `_async` forms that return a
+//! future, built on [`completion_to_future!`](crate::completion_to_future).
+//! They resolve to an `NSUserCancelledError` if the completion handler is
+//! dropped without being called:
+//!
+//! ```no_run
+//! # async fn example() {
+//! use fruity::cloud_kit::{CKContainer, CKRecordID};
+//! use fruity::ns_string;
+//!
+//! let database = CKContainer::default_container().private_database();
+//! let record_id = CKRecordID::new(ns_string!("settings"));
+//!
+//! match database.fetch_record_async(&record_id).await {
+//!     Ok(record) => println!("fetched {}", record.record_type()),
+//!     Err(error) => eprintln!("{}", error),
+//! }
+//! # }
+//! ```
+
+#![cfg(feature = "cloud_kit")]
+
+mod ck_account_status;
+mod ck_asset;
+mod ck_container;
+mod ck_database;
+mod ck_query;
+mod ck_record;
+mod ck_record_id;
+mod ck_record_value;
+mod ck_record_zone;
+mod ck_reference;
+
+pub use ck_account_status::*;
+pub use ck_asset::*;
+pub use ck_container::*;
+pub use ck_database::*;
+pub use ck_query::*;
+pub use ck_record::*;
+pub use ck_record_id::*;
+pub use ck_record_value::*;
+pub use ck_record_zone::*;
+pub use ck_reference::*;
+
+#[link(name = "CloudKit", kind = "framework")]
+extern "C" {}
@@ -144,3 +144,11 @@ impl<T: ObjectType> Arc<T> {
         Arc::from_raw(Self::into_raw(this).cast())
     }
 }
+
+#[cfg(feature = "serde")]
+impl<T: ObjectType + serde::Serialize> serde::Serialize for Arc<T> {
+    #[inline]
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (**self).serialize(serializer)
+    }
+}
@@ -0,0 +1,344 @@
+use std::{
+    fmt,
+    sync::{self, Mutex, MutexGuard, Weak},
+};
+
+/// A handle for cancelling work, which may be shared across threads and
+/// linked to [cancellable](Cancellable) system objects.
+///
+/// Cancelling a token runs its [handlers](Self::on_cancel) and cancels its
+/// [children](Self::child), but not its parent. This allows a single token,
+/// such as one cancelled from a Ctrl-C handler or a cancel button, to stop
+/// every part of a pipeline.
+///
+/// # Examples
+///
+/// ```
+/// use fruity::core::CancellationToken;
+///
+/// let token = CancellationToken::new();
+/// let child = token.child();
+///
+/// let _registration = child.on_cancel(|| println!("cancelled"));
+///
+/// token.cancel();
+/// assert!(child.is_cancelled());
+/// ```
+#[derive(Clone, Default)]
+pub struct CancellationToken {
+    inner: sync::Arc<Inner>,
+}
+
+#[derive(Default)]
+struct Inner {
+    /// The parent and the handler registered to cancel `self` from it.
+    parent: Option<(sync::Arc<Inner>, u64)>,
+    state: Mutex<State>,
+}
+
+#[derive(Default)]
+struct State {
+    is_cancelled: bool,
+    next_id: u64,
+    handlers: Vec<(u64, Box<dyn FnOnce() + Send>)>,
+}
+
+impl Drop for Inner {
+    fn drop(&mut self) {
+        if let Some((parent, id)) = &self.parent {
+            parent.remove_handler(*id);
+        }
+    }
+}
+
+impl Inner {
+    #[inline]
+    fn state(&self) -> MutexGuard<'_, State> {
+        // Handlers run outside of the lock, so it cannot be poisoned by them.
+        self.state.lock().unwrap_or_else(|error| error.into_inner())
+    }
+
+    /// Adds `handler`, or calls it immediately and returns `None` if already
+    /// cancelled.
+    fn add_handler(&self, handler: Box<dyn FnOnce() + Send>) -> Option<u64> {
+        let mut state = self.state();
+        if state.is_cancelled {
+            drop(state);
+            handler();
+            return None;
+        }
+
+        let id = state.next_id;
+        state.next_id += 1;
+        state.handlers.push((id, handler));
+        Some(id)
+    }
+
+    fn remove_handler(&self, id: u64) {
+        let removed = {
+            let mut state = self.state();
+            let index = state.handlers.iter().position(|(i, _)| *i == id);
+            index.map(|index| state.handlers.swap_remove(index))
+        };
+
+        // Dropping the handler may drop other tokens, so it must happen
+        // outside of the lock.
+        drop(removed);
+    }
+
+    fn cancel(&self) {
+        let handlers = {
+            let mut state = self.state();
+            if state.is_cancelled {
+                return;
+            }
+            state.is_cancelled = true;
+            std::mem::take(&mut state.handlers)
+        };
+
+        // Call in registration order.
+        let mut handlers = handlers;
+        handlers.sort_unstable_by_key(|(id, _)| *id);
+        for (_, handler) in handlers {
+            handler();
+        }
+    }
+}
+
+impl fmt::Debug for CancellationToken {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CancellationToken")
+            .field("is_cancelled", &self.is_cancelled())
+            .finish()
+    }
+}
+
+impl CancellationToken {
+    /// Creates a token that has not been cancelled.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a token that is cancelled when `self` is cancelled.
+    ///
+    /// The child can be cancelled independently without affecting `self`.
+    pub fn child(&self) -> Self {
+        let child = sync::Arc::new_cyclic(|weak: &Weak<Inner>| {
+            let weak = weak.clone();
+            let id = self.inner.add_handler(Box::new(move || {
+                if let Some(child) = weak.upgrade() {
+                    child.cancel();
+                }
+            }));
+
+            Inner {
+                parent: id.map(|id| (self.inner.clone(), id)),
+                state: Mutex::new(State {
+                    is_cancelled: id.is_none(),
+                    ..State::default()
+                }),
+            }
+        });
+
+        Self { inner: child }
+    }
+
+    /// Cancels `self` and its children, calling their handlers.
+    ///
+    /// Calling this more than once has no further effect.
+    #[inline]
+    pub fn cancel(&self) {
+        self.inner.cancel();
+    }
+
+    /// Returns `true` if `self` or an ancestor has been cancelled.
+    #[inline]
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.state().is_cancelled
+    }
+
+    /// Calls `handler` once `self` is cancelled, or immediately if it already
+    /// has been.
+    ///
+    /// `handler` is called on the thread that cancels `self`. It is not
+    /// called if the returned registration is dropped first.
+    pub fn on_cancel<F>(&self, handler: F) -> CancellationRegistration
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        let id = self.inner.add_handler(Box::new(handler));
+        CancellationRegistration {
+            token: sync::Arc::downgrade(&self.inner),
+            id,
+        }
+    }
+
+    /// Cancels `target` once `self` is cancelled, or immediately if it
+    /// already has been.
+    ///
+    /// `target` is kept alive until `self` is cancelled or the returned
+    /// registration is dropped.
+    #[inline]
+    pub fn link<C>(&self, target: C) -> CancellationRegistration
+    where
+        C: Cancellable + Send + 'static,
+    {
+        self.on_cancel(move || target.cancel())
+    }
+}
+
+/// A handler added with [`CancellationToken::on_cancel`], which is removed
+/// when this is dropped.
+#[must_use = "the handler is removed when the registration is dropped"]
+pub struct CancellationRegistration {
+    token: Weak<Inner>,
+    /// `None` if the handler was called immediately.
+    id: Option<u64>,
+}
+
+impl fmt::Debug for CancellationRegistration {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CancellationRegistration")
+            .field("id", &self.id)
+            .finish()
+    }
+}
+
+impl Drop for CancellationRegistration {
+    #[inline]
+    fn drop(&mut self) {
+        if let (Some(token), Some(id)) = (self.token.upgrade(), self.id) {
+            token.remove_handler(id);
+        }
+    }
+}
+
+impl CancellationRegistration {
+    /// Keeps the handler registered for as long as the token exists.
+    #[inline]
+    pub fn detach(mut self) {
+        self.id = None;
+    }
+}
+
+/// Work that can be stopped early, such as a
+/// [dispatch source](crate::dispatch::DispatchSource) or an
+/// [`NSProgress`](crate::foundation::NSProgress).
+///
+/// Implementations can be linked to a [`CancellationToken`] with
+/// [`link`](CancellationToken::link).
+pub trait Cancellable {
+    /// Requests that the work stops.
+    fn cancel(&self);
+}
+
+impl<T: Cancellable + ?Sized> Cancellable for &T {
+    #[inline]
+    fn cancel(&self) {
+        (**self).cancel();
+    }
+}
+
+impl<T: Cancellable + ?Sized> Cancellable for Box<T> {
+    #[inline]
+    fn cancel(&self) {
+        (**self).cancel();
+    }
+}
+
+impl<T: Cancellable + ?Sized> Cancellable for sync::Arc<T> {
+    #[inline]
+    fn cancel(&self) {
+        (**self).cancel();
+    }
+}
+
+impl<T: Cancellable + super::ObjectType> Cancellable for super::Arc<T> {
+    #[inline]
+    fn cancel(&self) {
+        (**self).cancel();
+    }
+}
+
+impl Cancellable for CancellationToken {
+    #[inline]
+    fn cancel(&self) {
+        CancellationToken::cancel(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn counter() -> (sync::Arc<AtomicUsize>, impl FnOnce() + Send + 'static) {
+        let count = sync::Arc::new(AtomicUsize::new(0));
+        let handler = {
+            let count = count.clone();
+            move || {
+                count.fetch_add(1, Ordering::SeqCst);
+            }
+        };
+        (count, handler)
+    }
+
+    #[test]
+    fn cancel_calls_handlers_once() {
+        let token = CancellationToken::new();
+        let (count, handler) = counter();
+        let _registration = token.on_cancel(handler);
+
+        token.cancel();
+        token.cancel();
+
+        assert!(token.is_cancelled());
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn dropped_registration_removes_handler() {
+        let token = CancellationToken::new();
+        let (count, handler) = counter();
+        drop(token.on_cancel(handler));
+
+        token.cancel();
+        assert_eq!(count.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn already_cancelled_calls_immediately() {
+        let token = CancellationToken::new();
+        token.cancel();
+
+        let (count, handler) = counter();
+        let _registration = token.on_cancel(handler);
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+
+        assert!(token.child().is_cancelled());
+    }
+
+    #[test]
+    fn children() {
+        let parent = CancellationToken::new();
+        let child = parent.child();
+        let grandchild = child.child();
+
+        child.cancel();
+        assert!(!parent.is_cancelled());
+        assert!(grandchild.is_cancelled());
+
+        let sibling = parent.child();
+        parent.cancel();
+        assert!(sibling.is_cancelled());
+    }
+
+    #[test]
+    fn dropped_child_is_unregistered() {
+        let parent = CancellationToken::new();
+        drop(parent.child());
+
+        assert!(parent.inner.state().handlers.is_empty());
+    }
+}
@@ -0,0 +1,470 @@
+use super::{Cancellable, CancellationRegistration, CancellationToken};
+use std::{
+    error::Error,
+    fmt,
+    future::Future,
+    pin::Pin,
+    sync::{self, Mutex, MutexGuard},
+    task::{Context, Poll, Waker},
+};
+
+/// A completion handler that can be passed to any method of this crate that
+/// takes an `FnOnce` completion closure.
+pub type CompletionHandler<T> = Box<dyn FnOnce(T) + Send>;
+
+/// Calls `start` with a completion handler and returns a future that resolves
+/// to the value that the handler is called with.
+///
+/// This adapts the completion handlers of Cocoa APIs, such as
+/// `CKDatabase::fetch_record`, to `async` code. Objects retained by the
+/// completion closure are released when it is called, regardless of whether
+/// the future is still alive. See [`completion_to_future!`](crate::completion_to_future)
+/// for handlers that receive a `Result` with an `NSError`.
+///
+/// The future resolves to [`Canceled`] if the handler is dropped without
+/// being called, or if it is cancelled through
+/// [`cancel_with`](Completion::cancel_with) or [`Cancellable::cancel`].
+/// Dropping the future cancels the work given to
+/// [`cancel_on_drop`](Completion::cancel_on_drop), if any. Otherwise, the
+/// result is simply discarded when the handler is eventually called.
+///
+/// # Examples
+///
+/// ```no_run
+/// # async fn example() {
+/// use fruity::core::call_with_completion;
+///
+/// let value = call_with_completion(|done| {
+///     std::thread::spawn(move || done(42));
+/// })
+/// .await;
+///
+/// assert_eq!(value, Ok(42));
+/// # }
+/// ```
+pub fn call_with_completion<T, F>(start: F) -> Completion<T>
+where
+    T: Send + 'static,
+    F: FnOnce(CompletionHandler<T>),
+{
+    let shared = sync::Arc::new(Shared {
+        state: Mutex::new(State {
+            value: None,
+            is_resolved: false,
+            waker: None,
+            target: None,
+        }),
+    });
+
+    let sender = Sender(sync::Arc::clone(&shared));
+    start(Box::new(move |value| sender.complete(value)));
+
+    Completion {
+        shared,
+        registration: None,
+    }
+}
+
+/// Calls a method that takes a completion handler with a `Result` and
+/// returns a future that resolves to that `Result`.
+///
+/// The method call is written without its completion handler argument, which
+/// is appended. A closure that is given the handler can be used instead, for
+/// calls that do not take it last.
+///
+/// This is [`call_with_completion`] with the [`Canceled`] error flattened
+/// into the error type of the handler, which must implement
+/// `From<Canceled>`. With the **`foundation`** feature flag, `NSError`
+/// does, as an `NSUserCancelledError` in the `NSCocoaErrorDomain`.
+///
+/// # Examples
+///
+/// ```no_run
+/// # async fn example() {
+/// use fruity::completion_to_future;
+/// use fruity::core::Canceled;
+///
+/// #[derive(Debug)]
+/// enum Error {
+///     Canceled,
+/// }
+///
+/// impl From<Canceled> for Error {
+///     fn from(_: Canceled) -> Self {
+///         Error::Canceled
+///     }
+/// }
+///
+/// fn fetch(id: u32, done: impl FnOnce(Result<u32, Error>) + Send + 'static) {
+///     std::thread::spawn(move || done(Ok(id)));
+/// }
+///
+/// let value = completion_to_future!(fetch(42)).await;
+/// assert_eq!(value.unwrap(), 42);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! completion_to_future {
+    (|$done:ident| $start:expr) => {
+        $crate::core::call_with_completion(|$done| {
+            $start;
+        })
+        .flatten()
+    };
+    ($($receiver:ident).+ ($($arg:expr),* $(,)?)) => {
+        $crate::completion_to_future!(|done| $($receiver).+($($arg,)* done))
+    };
+}
+
+/// The error that a [`Completion`] resolves to when its handler will not be
+/// called.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Canceled;
+
+impl fmt::Display for Canceled {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("the completion handler was canceled")
+    }
+}
+
+impl Error for Canceled {}
+
+#[cfg(feature = "foundation")]
+impl From<Canceled> for super::Arc<crate::foundation::NSError<'static>> {
+    #[inline]
+    fn from(_: Canceled) -> Self {
+        use crate::foundation::{error_codes::NSUserCancelledError, NSError, NSErrorDomain};
+        NSError::new(NSErrorDomain::cocoa(), NSUserCancelledError)
+    }
+}
+
+/// A future that resolves when its completion handler is called.
+///
+/// This is created by [`call_with_completion`].
+#[must_use = "futures do nothing unless polled"]
+pub struct Completion<T> {
+    shared: sync::Arc<Shared<T>>,
+    registration: Option<CancellationRegistration>,
+}
+
+struct Shared<T> {
+    state: Mutex<State<T>>,
+}
+
+struct State<T> {
+    value: Option<Result<T, Canceled>>,
+    /// Whether `value` has been set, even if it has since been taken.
+    is_resolved: bool,
+    waker: Option<Waker>,
+    /// The work to cancel if the future is cancelled before resolving.
+    target: Option<Box<dyn Cancellable + Send>>,
+}
+
+/// The sending half of a [`Completion`], which resolves it as canceled if
+/// dropped without being called.
+struct Sender<T>(sync::Arc<Shared<T>>);
+
+impl<T> Sender<T> {
+    #[inline]
+    fn complete(self, value: T) {
+        self.0.resolve(Ok(value));
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    #[inline]
+    fn drop(&mut self) {
+        // Does nothing if `complete` resolved it already.
+        self.0.resolve(Err(Canceled));
+    }
+}
+
+impl<T> Shared<T> {
+    #[inline]
+    fn state(&self) -> MutexGuard<'_, State<T>> {
+        // Nothing panics while the lock is held.
+        self.state.lock().unwrap_or_else(|error| error.into_inner())
+    }
+
+    /// Sets the value unless already resolved, returning the target that is
+    /// no longer needed.
+    fn resolve(&self, value: Result<T, Canceled>) -> Option<Box<dyn Cancellable + Send>> {
+        let (waker, target) = {
+            let mut state = self.state();
+            if state.is_resolved {
+                return None;
+            }
+            state.value = Some(value);
+            state.is_resolved = true;
+            (state.waker.take(), state.target.take())
+        };
+
+        // Waking may run arbitrary code, so it must happen outside of the
+        // lock.
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+        target
+    }
+
+    fn cancel(&self) {
+        if let Some(target) = self.resolve(Err(Canceled)) {
+            target.cancel();
+        }
+    }
+}
+
+impl<T> fmt::Debug for Completion<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Completion")
+            .field("is_complete", &self.is_complete())
+            .finish()
+    }
+}
+
+impl<T> Completion<T> {
+    /// Returns `true` if the completion handler has been called or cancelled
+    /// and the value has not yet been taken by polling.
+    #[inline]
+    pub fn is_complete(&self) -> bool {
+        self.shared.state().value.is_some()
+    }
+
+    /// Cancels `target` if `self` is cancelled or dropped before its
+    /// completion handler is called.
+    ///
+    /// This should be the operation that calls the handler, such as an
+    /// [`NSProgress`](crate::foundation::NSProgress).
+    pub fn cancel_on_drop<C>(self, target: C) -> Self
+    where
+        C: Cancellable + Send + 'static,
+    {
+        let mut state = self.shared.state();
+        if !state.is_resolved {
+            state.target = Some(Box::new(target));
+        }
+        drop(state);
+        self
+    }
+
+    /// Resolves `self` to [`Canceled`] once `token` is cancelled, also
+    /// cancelling the work given to [`cancel_on_drop`](Self::cancel_on_drop).
+    pub fn cancel_with(mut self, token: &CancellationToken) -> Self
+    where
+        T: Send + 'static,
+    {
+        let shared = sync::Arc::clone(&self.shared);
+        self.registration = Some(token.on_cancel(move || shared.cancel()));
+        self
+    }
+}
+
+impl<U, E> Completion<Result<U, E>> {
+    /// Returns a future that resolves to the `Result` of the handler, with
+    /// [`Canceled`] converted into its error type.
+    ///
+    /// This is used by [`completion_to_future!`](crate::completion_to_future).
+    #[inline]
+    pub fn flatten(self) -> FlattenCompletion<Result<U, E>> {
+        FlattenCompletion(self)
+    }
+}
+
+impl<T> Cancellable for Completion<T> {
+    /// Resolves `self` to [`Canceled`] and cancels the work given to
+    /// [`cancel_on_drop`](Self::cancel_on_drop).
+    #[inline]
+    fn cancel(&self) {
+        self.shared.cancel();
+    }
+}
+
+impl<T> Drop for Completion<T> {
+    #[inline]
+    fn drop(&mut self) {
+        self.shared.cancel();
+    }
+}
+
+// The value is only moved out, never pinned.
+impl<T> Unpin for Completion<T> {}
+
+impl<T> Future for Completion<T> {
+    type Output = Result<T, Canceled>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.shared.state();
+        match state.value.take() {
+            Some(value) => Poll::Ready(value),
+            None => {
+                match &state.waker {
+                    Some(waker) if waker.will_wake(cx.waker()) => {}
+                    _ => state.waker = Some(cx.waker().clone()),
+                }
+                Poll::Pending
+            }
+        }
+    }
+}
+
+/// A future that resolves to the `Result` that its completion handler is
+/// called with.
+///
+/// This is created by [`Completion::flatten`].
+#[must_use = "futures do nothing unless polled"]
+#[derive(Debug)]
+pub struct FlattenCompletion<T>(Completion<T>);
+
+impl<U, E> Future for FlattenCompletion<Result<U, E>>
+where
+    E: From<Canceled>,
+{
+    type Output = Result<U, E>;
+
+    #[inline]
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.0)
+            .poll(cx)
+            .map(|result| result.unwrap_or_else(|canceled| Err(canceled.into())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            mpsc,
+        },
+        task::Wake,
+        thread::{self, Thread},
+    };
+
+    struct ThreadWaker(Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: sync::Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    fn block_on<F: Future + Unpin>(mut future: F) -> F::Output {
+        let waker = Waker::from(sync::Arc::new(ThreadWaker(thread::current())));
+        let mut cx = Context::from_waker(&waker);
+        loop {
+            match Pin::new(&mut future).poll(&mut cx) {
+                Poll::Ready(output) => return output,
+                Poll::Pending => thread::park(),
+            }
+        }
+    }
+
+    #[test]
+    fn completes_immediately() {
+        let completion = call_with_completion(|done| done("value"));
+        assert!(completion.is_complete());
+        assert_eq!(block_on(completion), Ok("value"));
+    }
+
+    #[test]
+    fn completes_from_another_thread() {
+        let (sender, receiver) = mpsc::channel::<CompletionHandler<u32>>();
+        let completion = call_with_completion(move |done| sender.send(done).unwrap());
+        assert!(!completion.is_complete());
+
+        let handle = thread::spawn(move || receiver.recv().unwrap()(42));
+        assert_eq!(block_on(completion), Ok(42));
+        handle.join().unwrap();
+    }
+
+    /// A target that counts how many times it is cancelled.
+    #[derive(Clone, Default)]
+    struct Target(sync::Arc<AtomicUsize>);
+
+    impl Cancellable for Target {
+        fn cancel(&self) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    impl Target {
+        fn count(&self) -> usize {
+            self.0.load(Ordering::SeqCst)
+        }
+    }
+
+    #[test]
+    fn dropped_handler_cancels() {
+        let completion = call_with_completion(|done: CompletionHandler<u32>| drop(done));
+        assert_eq!(block_on(completion), Err(Canceled));
+    }
+
+    #[test]
+    fn cancel_with_token() {
+        let (sender, _receiver) = mpsc::channel::<CompletionHandler<u32>>();
+        let target = Target::default();
+        let token = CancellationToken::new();
+
+        let completion = call_with_completion(move |done| sender.send(done).unwrap())
+            .cancel_on_drop(target.clone())
+            .cancel_with(&token);
+
+        token.cancel();
+        assert_eq!(target.count(), 1);
+        assert_eq!(block_on(completion), Err(Canceled));
+        assert_eq!(target.count(), 1);
+    }
+
+    #[test]
+    fn drop_cancels_target() {
+        let (sender, receiver) = mpsc::channel::<CompletionHandler<u32>>();
+        let target = Target::default();
+
+        let completion = call_with_completion(move |done| sender.send(done).unwrap())
+            .cancel_on_drop(target.clone());
+        drop(completion);
+        assert_eq!(target.count(), 1);
+
+        // Completing afterward has no effect.
+        receiver.recv().unwrap()(1);
+        assert_eq!(target.count(), 1);
+    }
+
+    #[test]
+    fn completed_does_not_cancel_target() {
+        let target = Target::default();
+        let completion = call_with_completion(|done| done(1)).cancel_on_drop(target.clone());
+        assert_eq!(block_on(completion), Ok(1));
+        assert_eq!(target.count(), 0);
+    }
+
+    #[test]
+    fn flatten() {
+        #[derive(Debug, PartialEq)]
+        enum Error {
+            Canceled,
+            Failed,
+        }
+
+        impl From<Canceled> for Error {
+            fn from(_: Canceled) -> Self {
+                Self::Canceled
+            }
+        }
+
+        fn fetch(fail: bool, done: impl FnOnce(Result<u32, Error>)) {
+            done(if fail { Err(Error::Failed) } else { Ok(1) });
+        }
+
+        assert_eq!(block_on(completion_to_future!(fetch(false))), Ok(1));
+        assert_eq!(
+            block_on(completion_to_future!(fetch(true))),
+            Err(Error::Failed)
+        );
+        assert_eq!(
+            block_on(completion_to_future!(|done| drop(done))),
+            Err::<u32, _>(Error::Canceled)
+        );
+    }
+}
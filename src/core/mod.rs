@@ -6,15 +6,23 @@
 mod macros;
 
 mod arc;
+mod cancellation;
+mod completion;
 mod four_char_code;
 mod object_type;
 mod os_err;
 mod os_status;
 mod os_type;
+#[cfg(any(feature = "core_foundation", feature = "dispatch"))]
+mod timer;
 
 pub use arc::*;
+pub use cancellation::*;
+pub use completion::*;
 pub use four_char_code::*;
 pub use object_type::*;
 pub use os_err::*;
 pub use os_status::*;
 pub use os_type::*;
+#[cfg(any(feature = "core_foundation", feature = "dispatch"))]
+pub use timer::*;
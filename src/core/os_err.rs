@@ -93,6 +93,13 @@ macro_rules! os_err {
     }};
 }
 
+/// General errors.
+impl OSErr {
+    /// A parameter is invalid.
+    #[doc(alias = "paramErr")]
+    pub const PARAM_ERR: Self = os_err!(-50);
+}
+
 /// Apple event manager errors.
 impl OSErr {
     /// Data could not be coerced to the requested descriptor type.
@@ -0,0 +1,230 @@
+use crate::core::{Arc, Cancellable};
+use std::{fmt, time::Duration};
+
+#[cfg(feature = "core_foundation")]
+use crate::core_foundation::{CFRunLoop, CFRunLoopMode, CFRunLoopTimer};
+#[cfg(feature = "dispatch")]
+use crate::dispatch::{DispatchQosClass, DispatchQueue, DispatchSource, DispatchTime};
+
+/// A timer that calls a closure, backed by either a
+/// [`CFRunLoopTimer`](crate::core_foundation::CFRunLoopTimer) or a dispatch
+/// timer [source](crate::dispatch::DispatchSource).
+///
+/// Timers are created with [`TimerBuilder`]. The timer stops firing when it
+/// is [cancelled](Self::cancel) or dropped.
+///
+/// # Feature Flags
+///
+/// This requires the **`core_foundation`** or **`dispatch`**
+/// [feature flag](crate#feature-flags).
+pub struct Timer {
+    backend: Backend,
+}
+
+enum Backend {
+    #[cfg(feature = "core_foundation")]
+    RunLoop(Arc<CFRunLoopTimer>),
+    #[cfg(feature = "dispatch")]
+    Dispatch(Arc<DispatchSource>),
+}
+
+impl fmt::Debug for Timer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let backend = match &self.backend {
+            #[cfg(feature = "core_foundation")]
+            Backend::RunLoop(_) => "CFRunLoopTimer",
+            #[cfg(feature = "dispatch")]
+            Backend::Dispatch(_) => "DispatchSource",
+        };
+        f.debug_struct("Timer")
+            .field("backend", &backend)
+            .field("is_cancelled", &self.is_cancelled())
+            .finish()
+    }
+}
+
+impl Drop for Timer {
+    #[inline]
+    fn drop(&mut self) {
+        self.cancel();
+    }
+}
+
+impl Timer {
+    /// Returns a builder for a timer that first fires after `delay`.
+    #[inline]
+    pub fn builder(delay: Duration) -> TimerBuilder {
+        TimerBuilder::new(delay)
+    }
+
+    /// Stops the timer from ever firing again.
+    #[inline]
+    pub fn cancel(&self) {
+        match &self.backend {
+            #[cfg(feature = "core_foundation")]
+            Backend::RunLoop(timer) => timer.invalidate(),
+            #[cfg(feature = "dispatch")]
+            Backend::Dispatch(source) => source.cancel(),
+        }
+    }
+
+    /// Returns `true` if the timer has been cancelled.
+    #[inline]
+    pub fn is_cancelled(&self) -> bool {
+        match &self.backend {
+            #[cfg(feature = "core_foundation")]
+            Backend::RunLoop(timer) => !timer.is_valid(),
+            #[cfg(feature = "dispatch")]
+            Backend::Dispatch(source) => source.is_cancelled(),
+        }
+    }
+
+    /// Returns the underlying run loop timer, if this timer uses one.
+    #[cfg(feature = "core_foundation")]
+    #[inline]
+    pub fn as_run_loop_timer(&self) -> Option<&CFRunLoopTimer> {
+        match &self.backend {
+            Backend::RunLoop(timer) => Some(timer),
+            #[cfg(feature = "dispatch")]
+            Backend::Dispatch(_) => None,
+        }
+    }
+
+    /// Returns the underlying dispatch source, if this timer uses one.
+    #[cfg(feature = "dispatch")]
+    #[inline]
+    pub fn as_dispatch_source(&self) -> Option<&DispatchSource> {
+        match &self.backend {
+            #[cfg(feature = "core_foundation")]
+            Backend::RunLoop(_) => None,
+            Backend::Dispatch(source) => Some(source),
+        }
+    }
+}
+
+impl Cancellable for Timer {
+    #[inline]
+    fn cancel(&self) {
+        Timer::cancel(self);
+    }
+}
+
+/// Configures and schedules a [`Timer`].
+///
+/// Unless set with [`tolerance`](Self::tolerance), the timer may fire up to
+/// 10% of its interval late, or 10% of its delay if it does not repeat. This
+/// follows Apple's energy efficiency guidance by letting the system coalesce
+/// timers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TimerBuilder {
+    delay: Duration,
+    interval: Option<Duration>,
+    tolerance: Option<Duration>,
+}
+
+impl TimerBuilder {
+    /// Returns a builder for a timer that first fires after `delay`.
+    #[inline]
+    pub const fn new(delay: Duration) -> Self {
+        Self {
+            delay,
+            interval: None,
+            tolerance: None,
+        }
+    }
+
+    /// Makes the timer fire every `interval` after its first firing.
+    #[inline]
+    pub const fn repeating(mut self, interval: Duration) -> Self {
+        self.interval = Some(interval);
+        self
+    }
+
+    /// Sets how late the timer may fire.
+    #[inline]
+    pub const fn tolerance(mut self, tolerance: Duration) -> Self {
+        self.tolerance = Some(tolerance);
+        self
+    }
+
+    /// Returns the tolerance the timer will be created with.
+    #[inline]
+    pub fn effective_tolerance(&self) -> Duration {
+        self.tolerance
+            .unwrap_or_else(|| self.interval.unwrap_or(self.delay) / 10)
+    }
+
+    /// Schedules the timer using the backend best suited to the enabled
+    /// features.
+    ///
+    /// With the **`dispatch`** feature, `callout` is called on a global
+    /// utility queue, which does not require a running run loop. Otherwise,
+    /// the timer is added to the common modes of the current run loop.
+    ///
+    /// It is safe to panic within `callout`. Panics will abort the process.
+    #[inline]
+    pub fn schedule<F>(self, callout: F) -> Timer
+    where
+        F: FnMut() + Send + 'static,
+    {
+        #[cfg(feature = "dispatch")]
+        {
+            let queue = DispatchQueue::global_with_qos(DispatchQosClass::Utility);
+            self.schedule_on_queue(queue, callout)
+        }
+
+        #[cfg(not(feature = "dispatch"))]
+        {
+            self.schedule_on_run_loop(
+                &CFRunLoop::current(),
+                CFRunLoopMode::common_modes(),
+                callout,
+            )
+        }
+    }
+
+    /// Schedules the timer on `run_loop` in `mode`.
+    ///
+    /// It is safe to panic within `callout`. Panics will abort the process.
+    #[cfg(feature = "core_foundation")]
+    pub fn schedule_on_run_loop<F>(
+        self,
+        run_loop: &CFRunLoop,
+        mode: &CFRunLoopMode,
+        mut callout: F,
+    ) -> Timer
+    where
+        F: FnMut() + Send + 'static,
+    {
+        let timer = CFRunLoopTimer::with_delay(self.delay, self.interval, move |_| callout());
+        timer.set_tolerance(self.effective_tolerance().as_secs_f64());
+        run_loop.add_timer(&timer, mode);
+
+        Timer {
+            backend: Backend::RunLoop(timer),
+        }
+    }
+
+    /// Schedules the timer to call `callout` on `queue`.
+    ///
+    /// It is safe to panic within `callout`. Panics will abort the process.
+    #[cfg(feature = "dispatch")]
+    pub fn schedule_on_queue<F>(self, queue: &DispatchQueue, callout: F) -> Timer
+    where
+        F: FnMut() + Send + 'static,
+    {
+        let delay = self.delay.as_nanos().min(i64::MAX as u128) as i64;
+        let start = DispatchTime::IMMEDIATE.offset_nanos(delay);
+        let source = DispatchSource::timer(
+            queue,
+            start,
+            self.interval,
+            self.effective_tolerance(),
+            callout,
+        );
+
+        Timer {
+            backend: Backend::Dispatch(source),
+        }
+    }
+}
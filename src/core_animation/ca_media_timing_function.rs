@@ -0,0 +1,135 @@
+use crate::core::Arc;
+use crate::foundation::NSString;
+use crate::objc::{ClassType, NSObject, Sel};
+
+objc_subclass! {
+    /// A cubic Bézier curve that maps the elapsed fraction of an animation's
+    /// duration to the fraction of its change, defining its pacing.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/quartzcore/camediatimingfunction).
+    pub class CAMediaTimingFunction: NSObject<'static>;
+}
+
+impl CAMediaTimingFunction {
+    /// Creates a timing function from the control points of a Bézier curve
+    /// that starts at `(0, 0)` and ends at `(1, 1)`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/quartzcore/camediatimingfunction/init(controlpoints:_:_:_:)).
+    #[inline]
+    #[doc(alias = "initWithControlPoints::::")]
+    pub fn new(c1: (f32, f32), c2: (f32, f32)) -> Arc<Self> {
+        unsafe {
+            let obj: Arc<Self> = Self::class().alloc();
+
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(
+                    obj: Arc<CAMediaTimingFunction>,
+                    sel: Sel,
+                    c1x: f32,
+                    c1y: f32,
+                    c2x: f32,
+                    c2y: f32,
+                ) -> Arc<CAMediaTimingFunction>;
+            }
+
+            // `selector!` does not accept unnamed arguments.
+            let sel = Sel::register(b"initWithControlPoints::::\0".as_ptr().cast());
+
+            objc_msgSend(obj, sel, c1.0, c1.1, c2.0, c2.1)
+        }
+    }
+
+    #[inline]
+    fn named(name: &NSString) -> Arc<Self> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![Self::class(), functionWithName: name => *const Self])
+        }
+    }
+
+    /// Returns a timing function with even pacing.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/quartzcore/camediatimingfunctionname/1521854-linear).
+    #[inline]
+    #[doc(alias = "kCAMediaTimingFunctionLinear")]
+    pub fn linear() -> Arc<Self> {
+        extern "C" {
+            static kCAMediaTimingFunctionLinear: &'static NSString<'static>;
+        }
+        Self::named(unsafe { kCAMediaTimingFunctionLinear })
+    }
+
+    /// Returns a timing function that starts slowly and speeds up.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/quartzcore/camediatimingfunctionname/1521971-easein).
+    #[inline]
+    #[doc(alias = "kCAMediaTimingFunctionEaseIn")]
+    pub fn ease_in() -> Arc<Self> {
+        extern "C" {
+            static kCAMediaTimingFunctionEaseIn: &'static NSString<'static>;
+        }
+        Self::named(unsafe { kCAMediaTimingFunctionEaseIn })
+    }
+
+    /// Returns a timing function that starts quickly and slows down.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/quartzcore/camediatimingfunctionname/1522178-easeout).
+    #[inline]
+    #[doc(alias = "kCAMediaTimingFunctionEaseOut")]
+    pub fn ease_out() -> Arc<Self> {
+        extern "C" {
+            static kCAMediaTimingFunctionEaseOut: &'static NSString<'static>;
+        }
+        Self::named(unsafe { kCAMediaTimingFunctionEaseOut })
+    }
+
+    /// Returns a timing function that starts slowly, speeds up, and slows
+    /// down again.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/quartzcore/camediatimingfunctionname/1522173-easeineaseout).
+    #[inline]
+    #[doc(alias = "kCAMediaTimingFunctionEaseInEaseOut")]
+    pub fn ease_in_ease_out() -> Arc<Self> {
+        extern "C" {
+            static kCAMediaTimingFunctionEaseInEaseOut: &'static NSString<'static>;
+        }
+        Self::named(unsafe { kCAMediaTimingFunctionEaseInEaseOut })
+    }
+
+    /// Returns the timing function used by most system animations.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/quartzcore/camediatimingfunctionname/1521847-default).
+    #[inline]
+    #[doc(alias = "kCAMediaTimingFunctionDefault")]
+    pub fn system_default() -> Arc<Self> {
+        extern "C" {
+            static kCAMediaTimingFunctionDefault: &'static NSString<'static>;
+        }
+        Self::named(unsafe { kCAMediaTimingFunctionDefault })
+    }
+
+    /// Returns the control point at `index`, from 0 to 3, where points 0 and
+    /// 3 are the fixed end points of the curve.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than 3.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/quartzcore/camediatimingfunction/getcontrolpoint(at:values:)).
+    #[inline]
+    #[doc(alias = "getControlPointAtIndex:values:")]
+    pub fn control_point(&self, index: usize) -> (f32, f32) {
+        assert!(index <= 3, "control point index {} out of range", index);
+
+        let mut values = [0f32; 2];
+        unsafe {
+            _msg_send_any![
+                self,
+                getControlPointAtIndex: index
+                values: values.as_mut_ptr()
+                => ()
+            ]
+        }
+        (values[0], values[1])
+    }
+}
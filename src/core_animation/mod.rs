@@ -18,3 +18,7 @@
 
 #[link(name = "QuartzCore", kind = "framework")]
 extern "C" {}
+
+mod ca_media_timing_function;
+
+pub use ca_media_timing_function::*;
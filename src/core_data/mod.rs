@@ -0,0 +1,39 @@
+//! [Core Data](https://developer.apple.com/documentation/coredata)
+//! framework.
+//!
+//! # Feature Flag
+//!
+//! This module corresponds to the **`core_data`**
+//! [feature flag](../index.html#feature-flags).
+//!
+//! It also transitively enables [`foundation`](crate::foundation).
+//!
+//! # Scope
+//!
+//! These bindings are aimed at opening existing stores, such as those of
+//! other apps, and reading or making small edits to them. Custom
+//! `NSManagedObject` subclasses are not supported, so attributes are accessed
+//! by key.
+
+#![cfg(feature = "core_data")]
+
+mod ns_entity_description;
+mod ns_fetch_request;
+mod ns_managed_object;
+mod ns_managed_object_context;
+mod ns_managed_object_model;
+mod ns_persistent_container;
+mod ns_persistent_store_coordinator;
+mod ns_persistent_store_description;
+
+pub use ns_entity_description::*;
+pub use ns_fetch_request::*;
+pub use ns_managed_object::*;
+pub use ns_managed_object_context::*;
+pub use ns_managed_object_model::*;
+pub use ns_persistent_container::*;
+pub use ns_persistent_store_coordinator::*;
+pub use ns_persistent_store_description::*;
+
+#[link(name = "CoreData", kind = "framework")]
+extern "C" {}
@@ -0,0 +1,52 @@
+use crate::core::Arc;
+use crate::foundation::{NSArray, NSDictionary, NSString};
+use crate::objc::NSObject;
+use std::fmt;
+
+objc_subclass! {
+    /// The description of an entity in a managed object model, which is
+    /// similar to a table in a database.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coredata/nsentitydescription).
+    pub class NSEntityDescription: NSObject<'static>;
+}
+
+impl fmt::Debug for NSEntityDescription {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("NSEntityDescription")
+            .field(&self.name())
+            .finish()
+    }
+}
+
+impl NSEntityDescription {
+    /// Returns the name of the entity, which is used to create
+    /// [fetch requests](super::NSFetchRequest::new).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coredata/nsentitydescription/name).
+    #[inline]
+    pub fn name(&self) -> Option<Arc<NSString<'static>>> {
+        unsafe {
+            _msg_send_any![self, name => *const NSString<'static>]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Returns the names of the attributes of the entity, which are the keys
+    /// for [`NSManagedObject::value_for_key`](super::NSManagedObject::value_for_key).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coredata/nsentitydescription/attributesbyname).
+    #[inline]
+    #[doc(alias = "attributesByName")]
+    pub fn attribute_names(&self) -> Arc<NSArray<NSString<'static>>> {
+        unsafe {
+            let attributes = _msg_send_any![
+                self,
+                attributesByName => *const NSDictionary<NSString<'static>, NSObject<'static>>
+            ];
+            (*attributes).all_keys()
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use crate::core::Arc;
+use crate::foundation::{NSArray, NSPredicate, NSSortDescriptor, NSString};
+use crate::objc::{ClassType, NSObject, NSUInteger, Sel};
+
+objc_subclass! {
+    /// A query for managed objects of one entity, optionally filtered and
+    /// sorted.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coredata/nsfetchrequest).
+    pub class NSFetchRequest: NSObject<'static>;
+}
+
+impl NSFetchRequest {
+    /// Creates a request for all objects of the entity named `entity_name`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coredata/nsfetchrequest/init(entityname:)).
+    #[inline]
+    #[doc(alias = "initWithEntityName:")]
+    pub fn new(entity_name: &NSString) -> Arc<Self> {
+        unsafe {
+            let request: Arc<Self> = Self::class().alloc();
+
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(
+                    obj: Arc<NSFetchRequest>,
+                    sel: Sel,
+                    entity_name: &NSString,
+                ) -> Arc<NSFetchRequest>;
+            }
+
+            objc_msgSend(request, selector!(initWithEntityName:), entity_name)
+        }
+    }
+
+    /// Returns the condition that fetched objects must satisfy.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coredata/nsfetchrequest/predicate).
+    #[inline]
+    pub fn predicate(&self) -> Option<Arc<NSPredicate>> {
+        unsafe {
+            _msg_send_any![self, predicate => *const NSPredicate]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Sets the condition that fetched objects must satisfy.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coredata/nsfetchrequest/predicate).
+    #[inline]
+    #[doc(alias = "setPredicate")]
+    #[doc(alias = "setPredicate:")]
+    pub fn set_predicate(&self, predicate: Option<&NSPredicate>) {
+        unsafe { _msg_send_any![self, setPredicate: predicate] }
+    }
+
+    /// Sets the order of fetched objects, from the most to the least
+    /// significant sort descriptor.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coredata/nsfetchrequest/sortdescriptors).
+    #[inline]
+    #[doc(alias = "setSortDescriptors")]
+    #[doc(alias = "setSortDescriptors:")]
+    pub fn set_sort_descriptors(&self, sort_descriptors: Option<&NSArray<NSSortDescriptor>>) {
+        unsafe { _msg_send_any![self, setSortDescriptors: sort_descriptors] }
+    }
+
+    /// Returns the maximum number of objects to fetch, or 0 for no limit.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coredata/nsfetchrequest/fetchlimit).
+    #[inline]
+    #[doc(alias = "fetchLimit")]
+    pub fn fetch_limit(&self) -> usize {
+        unsafe { _msg_send_any![self, fetchLimit => NSUInteger] }
+    }
+
+    /// Sets the maximum number of objects to fetch, or 0 for no limit.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coredata/nsfetchrequest/fetchlimit).
+    #[inline]
+    #[doc(alias = "setFetchLimit")]
+    #[doc(alias = "setFetchLimit:")]
+    pub fn set_fetch_limit(&self, limit: usize) {
+        unsafe { _msg_send_any![self, setFetchLimit: limit as NSUInteger] }
+    }
+}
@@ -0,0 +1,73 @@
+use super::NSEntityDescription;
+use crate::core::Arc;
+use crate::foundation::NSString;
+use crate::objc::{NSObject, BOOL};
+
+objc_subclass! {
+    /// A record in a Core Data store, whose attributes are accessed by key.
+    ///
+    /// Managed objects belong to the [context](super::NSManagedObjectContext)
+    /// that fetched or inserted them, and must only be used on that
+    /// context's queue.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coredata/nsmanagedobject).
+    pub class NSManagedObject: NSObject<'static>;
+}
+
+impl NSManagedObject {
+    /// Returns the entity that `self` is an instance of.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coredata/nsmanagedobject/entity).
+    #[inline]
+    pub fn entity(&self) -> Arc<NSEntityDescription> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, entity => *const NSEntityDescription]) }
+    }
+
+    /// Returns the value of the attribute or relationship named `key`.
+    ///
+    /// Attribute values are Foundation objects such as `NSString`, `NSNumber`,
+    /// `NSDate`, and `NSData`.
+    ///
+    /// # Panics
+    ///
+    /// An unknown `key` raises an Objective-C exception, which aborts the
+    /// process. Valid keys are listed by
+    /// [`NSEntityDescription::attribute_names`].
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coredata/nsmanagedobject/value(forkey:)).
+    #[inline]
+    #[doc(alias = "valueForKey:")]
+    pub fn value_for_key(&self, key: &NSString) -> Option<Arc<NSObject<'static>>> {
+        unsafe {
+            _msg_send_any![self, valueForKey: key => *const NSObject<'static>]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Sets the value of the attribute or relationship named `key`.
+    ///
+    /// The change is written to the store when its context is
+    /// [saved](super::NSManagedObjectContext::save).
+    ///
+    /// # Panics
+    ///
+    /// An unknown `key` raises an Objective-C exception, which aborts the
+    /// process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coredata/nsmanagedobject/setvalue(_:forkey:)).
+    #[inline]
+    #[doc(alias = "setValue:forKey:")]
+    pub fn set_value_for_key(&self, value: Option<&NSObject>, key: &NSString) {
+        unsafe { _msg_send_any![self, setValue: value forKey: key => ()] }
+    }
+
+    /// Returns `true` if `self` has unsaved changes.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coredata/nsmanagedobject/haschanges).
+    #[inline]
+    #[doc(alias = "hasChanges")]
+    pub fn has_changes(&self) -> bool {
+        unsafe { _msg_send_any![self, hasChanges => BOOL] }.into()
+    }
+}
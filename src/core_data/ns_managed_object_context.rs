@@ -0,0 +1,235 @@
+use super::{NSFetchRequest, NSManagedObject, NSPersistentStoreCoordinator};
+use crate::core::Arc;
+use crate::foundation::{NSArray, NSError, NSNotFound, NSString};
+use crate::objc::{Block, ClassType, NSObject, NSUInteger, Sel, StackBlock, BOOL};
+use std::{cell::Cell, ptr};
+
+objc_subclass! {
+    /// A workspace for fetching, inserting, and saving managed objects.
+    ///
+    /// A context and its objects must only be used on its queue. For
+    /// [private queue](NSManagedObjectContextConcurrencyType::PRIVATE_QUEUE)
+    /// contexts, this means within
+    /// [`perform_and_wait`](Self::perform_and_wait).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coredata/nsmanagedobjectcontext).
+    pub class NSManagedObjectContext: NSObject<'static>;
+}
+
+/// The queue on which an [`NSManagedObjectContext`] performs work.
+///
+/// See [documentation](https://developer.apple.com/documentation/coredata/nsmanagedobjectcontextconcurrencytype).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NSManagedObjectContextConcurrencyType(pub NSUInteger);
+
+impl NSManagedObjectContextConcurrencyType {
+    /// The context creates and manages a private queue.
+    #[doc(alias = "NSPrivateQueueConcurrencyType")]
+    pub const PRIVATE_QUEUE: Self = Self(1);
+
+    /// The context is associated with the main queue.
+    #[doc(alias = "NSMainQueueConcurrencyType")]
+    pub const MAIN_QUEUE: Self = Self(2);
+}
+
+impl NSManagedObjectContext {
+    /// Creates a context that performs work on the queue of
+    /// `concurrency_type`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coredata/nsmanagedobjectcontext/init(concurrencytype:)).
+    #[inline]
+    #[doc(alias = "initWithConcurrencyType:")]
+    pub fn new(concurrency_type: NSManagedObjectContextConcurrencyType) -> Arc<Self> {
+        unsafe {
+            let context: Arc<Self> = Self::class().alloc();
+
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(
+                    obj: Arc<NSManagedObjectContext>,
+                    sel: Sel,
+                    concurrency_type: NSManagedObjectContextConcurrencyType,
+                ) -> Arc<NSManagedObjectContext>;
+            }
+
+            objc_msgSend(
+                context,
+                selector!(initWithConcurrencyType:),
+                concurrency_type,
+            )
+        }
+    }
+
+    /// Returns the coordinator that `self` fetches from and saves to.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coredata/nsmanagedobjectcontext/persistentstorecoordinator).
+    #[inline]
+    #[doc(alias = "persistentStoreCoordinator")]
+    pub fn persistent_store_coordinator(&self) -> Option<Arc<NSPersistentStoreCoordinator>> {
+        unsafe {
+            _msg_send_any![self, persistentStoreCoordinator => *const NSPersistentStoreCoordinator]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Sets the coordinator that `self` fetches from and saves to.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coredata/nsmanagedobjectcontext/persistentstorecoordinator).
+    #[inline]
+    #[doc(alias = "setPersistentStoreCoordinator")]
+    #[doc(alias = "setPersistentStoreCoordinator:")]
+    pub fn set_persistent_store_coordinator(
+        &self,
+        coordinator: Option<&NSPersistentStoreCoordinator>,
+    ) {
+        unsafe { _msg_send_any![self, setPersistentStoreCoordinator: coordinator] }
+    }
+
+    /// Calls `f` on the queue of `self` and waits for it to return.
+    ///
+    /// It is safe to panic within `f`. Panics will abort the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coredata/nsmanagedobjectcontext/performandwait(_:)-1xzjb).
+    #[doc(alias = "performBlockAndWait:")]
+    pub fn perform_and_wait<F, T>(&self, f: F) -> T
+    where
+        F: Send + FnOnce() -> T,
+        T: Send,
+    {
+        let f = Cell::new(Some(f));
+        let result = Cell::new(None);
+        let (f_ref, result_ref) = (&f, &result);
+
+        let block = StackBlock::new(move || {
+            if let Some(f) = f_ref.take() {
+                result_ref.set(Some(f()));
+            }
+        });
+        let block: &Block<(), ()> = &block;
+
+        unsafe { _msg_send_any![self, performBlockAndWait: block] }
+
+        result
+            .into_inner()
+            .expect("performBlockAndWait: returned without calling the block")
+    }
+
+    /// Returns the objects matching `request`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coredata/nsmanagedobjectcontext/fetch(_:)-5ntbo).
+    #[inline]
+    #[doc(alias = "executeFetchRequest:error:")]
+    pub fn fetch(
+        &self,
+        request: &NSFetchRequest,
+    ) -> Result<Arc<NSArray<NSManagedObject>>, Arc<NSError<'static>>> {
+        unsafe {
+            let mut error: *const NSError<'static> = ptr::null();
+            let objects = _msg_send_any![
+                self,
+                executeFetchRequest: request
+                error: &mut error
+                => *const NSArray<NSManagedObject>
+            ];
+
+            match objects.as_ref() {
+                Some(objects) => Ok(Arc::retain(objects)),
+                None => Err(Arc::retain_raw(error)),
+            }
+        }
+    }
+
+    /// Returns the number of objects matching `request`, without fetching
+    /// them.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coredata/nsmanagedobjectcontext/count(for:)-6tgnb).
+    #[inline]
+    #[doc(alias = "countForFetchRequest:error:")]
+    pub fn count(&self, request: &NSFetchRequest) -> Result<usize, Arc<NSError<'static>>> {
+        unsafe {
+            let mut error: *const NSError<'static> = ptr::null();
+            let count = _msg_send_any![
+                self,
+                countForFetchRequest: request
+                error: &mut error
+                => NSUInteger
+            ];
+
+            if count == NSNotFound as NSUInteger {
+                Err(Arc::retain_raw(error))
+            } else {
+                Ok(count)
+            }
+        }
+    }
+
+    /// Inserts a new object of the entity named `entity_name`, which is
+    /// written to the store when `self` is [saved](Self::save).
+    ///
+    /// # Panics
+    ///
+    /// An unknown `entity_name` raises an Objective-C exception, which aborts
+    /// the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coredata/nsentitydescription/insertnewobject(forentityname:into:)).
+    #[inline]
+    #[doc(alias = "insertNewObjectForEntityForName:inManagedObjectContext:")]
+    pub fn insert_new_object(&self, entity_name: &NSString) -> Arc<NSManagedObject> {
+        unsafe {
+            let object = _msg_send_any![
+                crate::objc_class!(NSEntityDescription),
+                insertNewObjectForEntityForName: entity_name
+                inManagedObjectContext: self
+                => *const NSManagedObject
+            ];
+            Arc::retain_raw(object)
+        }
+    }
+
+    /// Marks `object` for deletion from the store when `self` is
+    /// [saved](Self::save).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coredata/nsmanagedobjectcontext/delete(_:)).
+    #[inline]
+    #[doc(alias = "deleteObject:")]
+    pub fn delete(&self, object: &NSManagedObject) {
+        unsafe { _msg_send_any![self, deleteObject: object] }
+    }
+
+    /// Returns `true` if `self` has unsaved changes.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coredata/nsmanagedobjectcontext/haschanges).
+    #[inline]
+    #[doc(alias = "hasChanges")]
+    pub fn has_changes(&self) -> bool {
+        unsafe { _msg_send_any![self, hasChanges => BOOL] }.into()
+    }
+
+    /// Writes unsaved changes to the store.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coredata/nsmanagedobjectcontext/save()).
+    #[inline]
+    #[doc(alias = "save:")]
+    pub fn save(&self) -> Result<(), Arc<NSError<'static>>> {
+        unsafe {
+            let mut error: *const NSError<'static> = ptr::null();
+            let success = _msg_send_any![self, save: &mut error => BOOL];
+
+            if success.into() {
+                Ok(())
+            } else {
+                Err(Arc::retain_raw(error))
+            }
+        }
+    }
+
+    /// Discards unsaved changes.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coredata/nsmanagedobjectcontext/rollback()).
+    #[inline]
+    pub fn rollback(&self) {
+        unsafe { _msg_send_any![self, rollback] }
+    }
+}
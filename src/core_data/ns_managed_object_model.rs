@@ -0,0 +1,55 @@
+use super::NSEntityDescription;
+use crate::core::Arc;
+use crate::foundation::{NSArray, NSURL};
+use crate::objc::{ClassType, NSObject, Sel};
+
+objc_subclass! {
+    /// The schema of a Core Data store, describing its entities.
+    ///
+    /// Models are compiled from `.xcdatamodeld` files into `.momd`
+    /// directories, which are found in the resources of the app that owns the
+    /// store.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coredata/nsmanagedobjectmodel).
+    pub class NSManagedObjectModel: NSObject<'static>;
+}
+
+impl NSManagedObjectModel {
+    /// Loads the compiled model at `url`, which is either a `.momd` directory
+    /// or a `.mom` file.
+    ///
+    /// Returns [`None`] if the model cannot be loaded.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coredata/nsmanagedobjectmodel/init(contentsof:)).
+    #[inline]
+    #[doc(alias = "initWithContentsOfURL:")]
+    pub fn from_url(url: &NSURL) -> Option<Arc<Self>> {
+        unsafe {
+            let model: Arc<Self> = Self::class().alloc();
+
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(
+                    obj: Arc<NSManagedObjectModel>,
+                    sel: Sel,
+                    url: &NSURL,
+                ) -> Option<Arc<NSManagedObjectModel>>;
+            }
+
+            objc_msgSend(model, selector!(initWithContentsOfURL:), url)
+        }
+    }
+
+    /// Returns the entities of `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coredata/nsmanagedobjectmodel/entities).
+    #[inline]
+    pub fn entities(&self) -> Arc<NSArray<NSEntityDescription>> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![
+                self,
+                entities => *const NSArray<NSEntityDescription>
+            ])
+        }
+    }
+}
@@ -0,0 +1,162 @@
+use super::{
+    NSManagedObjectContext, NSManagedObjectModel, NSPersistentStoreCoordinator,
+    NSPersistentStoreDescription,
+};
+use crate::core::Arc;
+use crate::foundation::{NSArray, NSError, NSString};
+use crate::objc::{Block, ClassType, NSObject, Sel, StackBlock, BOOL};
+use std::cell::Cell;
+
+objc_subclass! {
+    /// Sets up a model, coordinator, and contexts for a set of stores.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coredata/nspersistentcontainer).
+    pub class NSPersistentContainer: NSObject<'static>;
+}
+
+impl NSPersistentContainer {
+    /// Creates a container named `name` for stores that use `model`.
+    ///
+    /// The name determines the default store location, which is usually
+    /// replaced with
+    /// [`set_persistent_store_descriptions`](Self::set_persistent_store_descriptions).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coredata/nspersistentcontainer/init(name:managedobjectmodel:)).
+    #[inline]
+    #[doc(alias = "initWithName:managedObjectModel:")]
+    pub fn new(name: &NSString, model: &NSManagedObjectModel) -> Arc<Self> {
+        unsafe {
+            let container: Arc<Self> = Self::class().alloc();
+
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(
+                    obj: Arc<NSPersistentContainer>,
+                    sel: Sel,
+                    name: &NSString,
+                    model: &NSManagedObjectModel,
+                ) -> Arc<NSPersistentContainer>;
+            }
+
+            objc_msgSend(
+                container,
+                selector!(initWithName:managedObjectModel:),
+                name,
+                model,
+            )
+        }
+    }
+
+    /// Returns the descriptions of the stores that
+    /// [`load_persistent_stores`](Self::load_persistent_stores) opens.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coredata/nspersistentcontainer/persistentstoredescriptions).
+    #[inline]
+    #[doc(alias = "persistentStoreDescriptions")]
+    pub fn persistent_store_descriptions(&self) -> Arc<NSArray<NSPersistentStoreDescription>> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![
+                self,
+                persistentStoreDescriptions => *const NSArray<NSPersistentStoreDescription>
+            ])
+        }
+    }
+
+    /// Sets the descriptions of the stores that
+    /// [`load_persistent_stores`](Self::load_persistent_stores) opens.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coredata/nspersistentcontainer/persistentstoredescriptions).
+    #[inline]
+    #[doc(alias = "setPersistentStoreDescriptions")]
+    #[doc(alias = "setPersistentStoreDescriptions:")]
+    pub fn set_persistent_store_descriptions(
+        &self,
+        descriptions: &NSArray<NSPersistentStoreDescription>,
+    ) {
+        unsafe { _msg_send_any![self, setPersistentStoreDescriptions: descriptions] }
+    }
+
+    /// Opens each store in
+    /// [`persistent_store_descriptions`](Self::persistent_store_descriptions),
+    /// returning the first error.
+    ///
+    /// Stores are opened synchronously, even if their descriptions request
+    /// otherwise.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coredata/nspersistentcontainer/loadpersistentstores(completionhandler:)).
+    #[doc(alias = "loadPersistentStoresWithCompletionHandler:")]
+    pub fn load_persistent_stores(&self) -> Result<(), Arc<NSError<'static>>> {
+        for description in self.persistent_store_descriptions().iter() {
+            unsafe {
+                _msg_send_any![
+                    description,
+                    setShouldAddStoreAsynchronously: BOOL::NO
+                ]
+            }
+        }
+
+        let first_error: Cell<Option<Arc<NSError<'static>>>> = Cell::new(None);
+        let first_error_ref = &first_error;
+
+        let block = StackBlock::new(
+            move |_: *const NSPersistentStoreDescription, error: *const NSError<'static>| {
+                if let Some(error) = unsafe { error.as_ref() } {
+                    let previous = first_error_ref.take();
+                    first_error_ref.set(previous.or_else(|| Some(Arc::retain(error))));
+                }
+            },
+        );
+        let block: &Block<_, ()> = &block;
+
+        unsafe { _msg_send_any![self, loadPersistentStoresWithCompletionHandler: block] }
+
+        match first_error.into_inner() {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns the coordinator of the stores of `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coredata/nspersistentcontainer/persistentstorecoordinator).
+    #[inline]
+    #[doc(alias = "persistentStoreCoordinator")]
+    pub fn persistent_store_coordinator(&self) -> Arc<NSPersistentStoreCoordinator> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![
+                self,
+                persistentStoreCoordinator => *const NSPersistentStoreCoordinator
+            ])
+        }
+    }
+
+    /// Returns the context associated with the main queue.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coredata/nspersistentcontainer/viewcontext).
+    #[inline]
+    #[doc(alias = "viewContext")]
+    pub fn view_context(&self) -> Arc<NSManagedObjectContext> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![
+                self,
+                viewContext => *const NSManagedObjectContext
+            ])
+        }
+    }
+
+    /// Creates a context that performs work on a private queue, for use with
+    /// [`NSManagedObjectContext::perform_and_wait`].
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coredata/nspersistentcontainer/newbackgroundcontext()).
+    #[inline]
+    #[doc(alias = "newBackgroundContext")]
+    pub fn new_background_context(&self) -> Arc<NSManagedObjectContext> {
+        unsafe {
+            // `new` methods return an owned reference.
+            Arc::from_raw(_msg_send_any![
+                self,
+                newBackgroundContext => *const NSManagedObjectContext
+            ])
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use super::NSManagedObjectModel;
+use crate::core::Arc;
+use crate::foundation::{NSDictionary, NSError, NSNumber, NSString, NSURL};
+use crate::objc::{ClassType, NSObject, Sel};
+use std::ptr;
+
+objc_subclass! {
+    /// Connects managed object contexts to the files of one or more
+    /// persistent stores.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coredata/nspersistentstorecoordinator).
+    pub class NSPersistentStoreCoordinator: NSObject<'static>;
+}
+
+objc_subclass! {
+    /// A store added to an [`NSPersistentStoreCoordinator`].
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coredata/nspersistentstore).
+    pub class NSPersistentStore: NSObject<'static>;
+}
+
+/// Returns the `NSSQLiteStoreType` constant.
+#[inline]
+pub(super) fn sqlite_store_type() -> &'static NSString<'static> {
+    extern "C" {
+        static NSSQLiteStoreType: &'static NSString<'static>;
+    }
+    unsafe { NSSQLiteStoreType }
+}
+
+impl NSPersistentStoreCoordinator {
+    /// Creates a coordinator for stores that use `model`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coredata/nspersistentstorecoordinator/init(managedobjectmodel:)).
+    #[inline]
+    #[doc(alias = "initWithManagedObjectModel:")]
+    pub fn new(model: &NSManagedObjectModel) -> Arc<Self> {
+        unsafe {
+            let coordinator: Arc<Self> = Self::class().alloc();
+
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(
+                    obj: Arc<NSPersistentStoreCoordinator>,
+                    sel: Sel,
+                    model: &NSManagedObjectModel,
+                ) -> Arc<NSPersistentStoreCoordinator>;
+            }
+
+            objc_msgSend(coordinator, selector!(initWithManagedObjectModel:), model)
+        }
+    }
+
+    /// Returns the model of the stores of `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coredata/nspersistentstorecoordinator/managedobjectmodel).
+    #[inline]
+    #[doc(alias = "managedObjectModel")]
+    pub fn managed_object_model(&self) -> Arc<NSManagedObjectModel> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![
+                self,
+                managedObjectModel => *const NSManagedObjectModel
+            ])
+        }
+    }
+
+    /// Opens the SQLite store at `url`, creating it if it does not exist and
+    /// `read_only` is `false`.
+    ///
+    /// Opening another app's store read-only avoids modifying it, including
+    /// through migration.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coredata/nspersistentstorecoordinator/addpersistentstore(oftype:configurationname:at:options:)).
+    #[doc(alias = "addPersistentStoreWithType:configuration:URL:options:error:")]
+    #[doc(alias = "NSSQLiteStoreType")]
+    #[doc(alias = "NSReadOnlyPersistentStoreOption")]
+    pub fn add_sqlite_store(
+        &self,
+        url: &NSURL,
+        read_only: bool,
+    ) -> Result<Arc<NSPersistentStore>, Arc<NSError<'static>>> {
+        extern "C" {
+            static NSReadOnlyPersistentStoreOption: &'static NSString<'static>;
+        }
+
+        let options: Arc<NSDictionary<NSString<'static>, NSObject<'static>>> = unsafe {
+            NSDictionary::from_slices(
+                &[NSReadOnlyPersistentStoreOption],
+                &[NSNumber::from_bool(read_only).as_ref()],
+            )
+        };
+
+        unsafe {
+            let mut error: *const NSError<'static> = ptr::null();
+            let store = _msg_send_any![
+                self,
+                addPersistentStoreWithType: sqlite_store_type()
+                configuration: ptr::null::<NSString>()
+                URL: url
+                options: &*options
+                error: &mut error
+                => *const NSPersistentStore
+            ];
+
+            match store.as_ref() {
+                Some(store) => Ok(Arc::retain(store)),
+                None => Err(Arc::retain_raw(error)),
+            }
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use super::ns_persistent_store_coordinator::sqlite_store_type;
+use crate::core::Arc;
+use crate::foundation::{NSString, NSURL};
+use crate::objc::{ClassType, NSObject, BOOL};
+
+objc_subclass! {
+    /// The configuration used by an
+    /// [`NSPersistentContainer`](super::NSPersistentContainer) to open a
+    /// store.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coredata/nspersistentstoredescription).
+    pub class NSPersistentStoreDescription: NSObject<'static>;
+}
+
+impl NSPersistentStoreDescription {
+    /// Creates a description of the SQLite store at `url`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coredata/nspersistentstoredescription/init(url:)).
+    #[inline]
+    #[doc(alias = "persistentStoreDescriptionWithURL:")]
+    pub fn from_url(url: &NSURL) -> Arc<Self> {
+        let description: Arc<Self> = unsafe {
+            Arc::retain_raw(_msg_send_any![
+                Self::class(),
+                persistentStoreDescriptionWithURL: url
+                => *const Self
+            ])
+        };
+        description.set_store_type(sqlite_store_type());
+        description
+    }
+
+    /// Returns the location of the store.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coredata/nspersistentstoredescription/url).
+    #[inline]
+    #[doc(alias = "URL")]
+    pub fn url(&self) -> Option<Arc<NSURL>> {
+        unsafe {
+            _msg_send_any![self, URL => *const NSURL]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Returns the type of the store, such as `SQLite`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coredata/nspersistentstoredescription/type).
+    #[inline]
+    #[doc(alias = "type")]
+    pub fn store_type(&self) -> Arc<NSString<'static>> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, type => *const NSString<'static>]) }
+    }
+
+    /// Sets the type of the store.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coredata/nspersistentstoredescription/type).
+    #[inline]
+    #[doc(alias = "setType")]
+    #[doc(alias = "setType:")]
+    pub fn set_store_type(&self, store_type: &NSString) {
+        unsafe { _msg_send_any![self, setType: store_type] }
+    }
+
+    /// Returns `true` if the store is opened read-only.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coredata/nspersistentstoredescription/isreadonly).
+    #[inline]
+    #[doc(alias = "isReadOnly")]
+    pub fn is_read_only(&self) -> bool {
+        unsafe { _msg_send_any![self, isReadOnly => BOOL] }.into()
+    }
+
+    /// Sets whether the store is opened read-only.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coredata/nspersistentstoredescription/isreadonly).
+    #[inline]
+    #[doc(alias = "setReadOnly")]
+    #[doc(alias = "setReadOnly:")]
+    pub fn set_read_only(&self, read_only: bool) {
+        unsafe { _msg_send_any![self, setReadOnly: BOOL::from(read_only)] }
+    }
+}
@@ -1,28 +1,79 @@
+use crate::core::{Arc, ObjectType};
+
+/// A type that is
+/// [toll-free bridged](https://developer.apple.com/library/archive/documentation/General/Conceptual/CocoaEncyclopedia/Toll-FreeBridgin/Toll-FreeBridgin.html)
+/// to `T`.
+///
+/// Bridged types share the same in-memory representation, so conversions
+/// neither copy nor retain. This is implemented only for pairs that Apple
+/// documents as bridged, such as [`CFString`](super::CFString) and
+/// [`NSString`](crate::foundation::NSString). Conversions are also available
+/// through the [`From`], [`AsRef`], and [`AsMut`] implementations on each
+/// pair.
+///
+/// Some types are only bridged in one direction. For example, every
+/// [`CFBoolean`](super::CFBoolean) is an
+/// [`NSNumber`](crate::foundation::NSNumber), but not every `NSNumber` is a
+/// `CFBoolean`.
+///
+/// # Safety
+///
+/// Every instance of `Self` must be a valid instance of `T`.
+pub unsafe trait TollFreeBridge<T: ObjectType>: ObjectType + Sized {
+    /// Reinterprets `self` as the bridged type.
+    #[inline]
+    fn bridge(&self) -> &T {
+        unsafe { &*(self as *const Self as *const T) }
+    }
+
+    /// Reinterprets `self` as the bridged type.
+    #[inline]
+    fn bridge_mut(&mut self) -> &mut T {
+        unsafe { &mut *(self as *mut Self as *mut T) }
+    }
+
+    /// Converts a shared reference into one of the bridged type without
+    /// retaining.
+    #[inline]
+    fn bridge_arc(this: Arc<Self>) -> Arc<T> {
+        unsafe { Arc::cast_unchecked(this) }
+    }
+}
+
 #[allow(unused)]
 macro_rules! cf_bridge {
+    (impl<$($gen:ident),+> $a:ty, $b:ty) => {
+        cf_bridge!(@ impl<$($gen),+> $a => $b);
+        cf_bridge!(@ impl<$($gen),+> $b => $a);
+    };
     ($a:ty, $b:ty) => {
-        cf_bridge!(@ $a => $b);
-        cf_bridge!(@ $b => $a);
+        cf_bridge!(@ impl<> $a => $b);
+        cf_bridge!(@ impl<> $b => $a);
     };
-    (@ $a:ty => $b:ty) => {
-        impl From<crate::core::Arc<$a>> for crate::core::Arc<$b> {
+    ($a:ty => $b:ty) => {
+        cf_bridge!(@ impl<> $a => $b);
+    };
+    (@ impl<$($gen:ident),*> $a:ty => $b:ty) => {
+        unsafe impl<$($gen),*> crate::core_foundation::TollFreeBridge<$b> for $a {}
+
+        impl<$($gen),*> From<crate::core::Arc<$a>> for crate::core::Arc<$b> {
             #[inline]
             fn from(bridged: crate::core::Arc<$a>) -> Self {
-                unsafe { crate::core::Arc::cast_unchecked(bridged) }
+                crate::core_foundation::TollFreeBridge::bridge_arc(bridged)
             }
         }
 
-        impl AsRef<$b> for $a {
+        impl<$($gen),*> AsRef<$b> for $a {
             #[inline]
             fn as_ref(&self) -> &$b {
-                unsafe { &*(self as *const $a as *const $b) }
+                crate::core_foundation::TollFreeBridge::bridge(self)
             }
         }
 
-        impl AsMut<$b> for $a {
+        impl<$($gen),*> AsMut<$b> for $a {
             #[inline]
             fn as_mut(&mut self) -> &mut $b {
-                unsafe { &mut *(self as *mut $a as *mut $b) }
+                crate::core_foundation::TollFreeBridge::bridge_mut(self)
             }
         }
     };
@@ -0,0 +1,61 @@
+use crate::core_foundation::{Boolean, CFAllocator, CFIndex};
+use std::ffi::c_void;
+
+/// Callback used to retain a value as it is added to an array.
+///
+/// See [documentation](https://developer.apple.com/documentation/corefoundation/cfarrayretaincallback).
+pub type CFArrayRetainCallBack =
+    unsafe extern "C" fn(allocator: *const CFAllocator, value: *const c_void) -> *const c_void;
+
+/// Callback used to release a value before it is removed from an array.
+///
+/// See [documentation](https://developer.apple.com/documentation/corefoundation/cfarrayreleasecallback).
+pub type CFArrayReleaseCallBack =
+    unsafe extern "C" fn(allocator: *const CFAllocator, value: *const c_void);
+
+/// Callback used to create a descriptive string representation of each value
+/// in an array.
+///
+/// See [documentation](https://developer.apple.com/documentation/corefoundation/cfarraycopydescriptioncallback).
+pub type CFArrayCopyDescriptionCallBack =
+    unsafe extern "C" fn(value: *const c_void) -> *const c_void; // TODO: Return type `Arc<CFString>`.
+
+/// Callback used to determine if two values in an array are equal.
+///
+/// See [documentation](https://developer.apple.com/documentation/corefoundation/cfarrayequalcallback).
+pub type CFArrayEqualCallBack =
+    unsafe extern "C" fn(value1: *const c_void, value2: *const c_void) -> Boolean;
+
+/// Callbacks that describe how values are retained, released, described, and
+/// compared by an array.
+///
+/// See [documentation](https://developer.apple.com/documentation/corefoundation/cfarraycallbacks).
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct CFArrayCallBacks {
+    /// The version number of this structure. Must be 0.
+    pub version: CFIndex,
+    /// The callback used to retain each value as it is added.
+    pub retain: Option<CFArrayRetainCallBack>,
+    /// The callback used to release each value as it is removed.
+    pub release: Option<CFArrayReleaseCallBack>,
+    /// The callback used to describe each value.
+    pub copy_description: Option<CFArrayCopyDescriptionCallBack>,
+    /// The callback used to compare values.
+    pub equal: Option<CFArrayEqualCallBack>,
+}
+
+impl CFArrayCallBacks {
+    /// Returns the callbacks for arrays containing Core Foundation objects.
+    ///
+    /// This internally references
+    /// [`kCFTypeArrayCallBacks`](https://developer.apple.com/documentation/corefoundation/kcftypearraycallbacks).
+    #[inline]
+    #[doc(alias = "kCFTypeArrayCallBacks")]
+    pub fn cf_type() -> &'static Self {
+        extern "C" {
+            static kCFTypeArrayCallBacks: CFArrayCallBacks;
+        }
+        unsafe { &kCFTypeArrayCallBacks }
+    }
+}
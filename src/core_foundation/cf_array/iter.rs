@@ -0,0 +1,138 @@
+use super::CFArray;
+use crate::{
+    core::ObjectType,
+    core_foundation::{sys, CFIndex, CFRange},
+};
+use std::{iter::FusedIterator, os::raw::c_void, ptr};
+
+/// The number of values fetched at a time by [`CFArrayIter`].
+const CHUNK_LEN: usize = 16;
+
+/// A window of values fetched from an array with a single `CFArrayGetValues`
+/// call.
+#[derive(Clone, Copy)]
+struct Chunk {
+    values: [*const c_void; CHUNK_LEN],
+    start: CFIndex,
+    len: CFIndex,
+}
+
+impl Chunk {
+    const EMPTY: Self = Self {
+        values: [ptr::null(); CHUNK_LEN],
+        start: 0,
+        len: 0,
+    };
+
+    #[inline]
+    fn get(&self, index: CFIndex) -> Option<*const c_void> {
+        let offset = index - self.start;
+        if offset >= 0 && offset < self.len {
+            Some(self.values[offset as usize])
+        } else {
+            None
+        }
+    }
+
+    /// Fetches `start..end` from `array`, where the range must be within
+    /// bounds and no longer than `CHUNK_LEN`.
+    #[inline]
+    unsafe fn fill(&mut self, array: *const CFArray, start: CFIndex, end: CFIndex) {
+        let len = end - start;
+        sys::CFArrayGetValues(array, CFRange::new(start, len), self.values.as_mut_ptr());
+        self.start = start;
+        self.len = len;
+    }
+}
+
+/// An iterator over the values of a [`CFArray`].
+///
+/// Values are fetched in chunks to avoid making a call into Core Foundation
+/// for every element.
+pub struct CFArrayIter<'a, T> {
+    array: &'a CFArray<T>,
+    front: CFIndex,
+    back: CFIndex,
+    front_chunk: Chunk,
+    back_chunk: Chunk,
+}
+
+// SAFETY: The chunks only hold pointers to values borrowed from `array`.
+unsafe impl<T: Send + Sync> Send for CFArrayIter<'_, T> {}
+unsafe impl<T: Send + Sync> Sync for CFArrayIter<'_, T> {}
+
+impl<T> Clone for CFArrayIter<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self { ..*self }
+    }
+}
+
+impl<'a, T> CFArrayIter<'a, T> {
+    #[inline]
+    pub(super) fn new(array: &'a CFArray<T>) -> Self {
+        Self {
+            array,
+            front: 0,
+            back: array.len(),
+            front_chunk: Chunk::EMPTY,
+            back_chunk: Chunk::EMPTY,
+        }
+    }
+}
+
+impl<'a, T: ObjectType> Iterator for CFArrayIter<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+
+        let index = self.front;
+        let value = match self.front_chunk.get(index) {
+            Some(value) => value,
+            None => unsafe {
+                let end = self.back.min(index + CHUNK_LEN as CFIndex);
+                self.front_chunk.fill(self.array.as_raw(), index, end);
+                self.front_chunk.values[0]
+            },
+        };
+
+        self.front += 1;
+        Some(unsafe { &*value.cast::<T>() })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.back - self.front) as usize;
+        (len, Some(len))
+    }
+}
+
+impl<'a, T: ObjectType> DoubleEndedIterator for CFArrayIter<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+
+        let index = self.back - 1;
+        let value = match self.back_chunk.get(index) {
+            Some(value) => value,
+            None => unsafe {
+                let start = self.front.max(self.back - CHUNK_LEN as CFIndex);
+                self.back_chunk.fill(self.array.as_raw(), start, self.back);
+                self.back_chunk.values[(index - start) as usize]
+            },
+        };
+
+        self.back = index;
+        Some(unsafe { &*value.cast::<T>() })
+    }
+}
+
+impl<T: ObjectType> ExactSizeIterator for CFArrayIter<'_, T> {}
+
+impl<T: ObjectType> FusedIterator for CFArrayIter<'_, T> {}
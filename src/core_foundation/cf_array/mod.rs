@@ -0,0 +1,306 @@
+use super::{sys, CFAllocator, CFIndex, CFRange, CFType, CFTypeID};
+use crate::core::{Arc, ObjectType};
+use std::{fmt, marker::PhantomData, ops, ops::Deref, ptr, ptr::NonNull};
+
+mod callbacks;
+mod iter;
+mod mutable;
+
+pub use callbacks::*;
+pub use iter::*;
+pub use mutable::*;
+
+/// A static ordered collection of objects of type `T`.
+///
+/// Documentation:
+/// [Swift](https://developer.apple.com/documentation/corefoundation/cfarray?language=swift) |
+/// [Objective-C](https://developer.apple.com/documentation/corefoundation/cfarray?language=objc)
+///
+/// # Element Type
+///
+/// Core Foundation arrays are untyped, so `T` is not checked at runtime.
+/// Arrays created by this crate always use
+/// [`CFArrayCallBacks::cf_type`] to retain their elements. Arrays obtained
+/// elsewhere should be reinterpreted with
+/// [`cast_unchecked`](Self::cast_unchecked) only when their contents are
+/// known.
+#[repr(C)]
+pub struct CFArray<T = CFType<'static>> {
+    base: CFType<'static>,
+    _marker: PhantomData<*const T>,
+}
+
+// SAFETY: The array itself is thread-safe because it is immutable, and its
+// elements are only exposed by reference.
+unsafe impl<T: Send + Sync> Sync for CFArray<T> {}
+unsafe impl<T: Send + Sync> Send for CFArray<T> {}
+
+#[cfg(feature = "foundation")]
+cf_bridge!(impl<T> CFArray<T>, crate::foundation::NSArray<T>);
+
+impl<T> ObjectType for CFArray<T> {
+    #[inline]
+    fn retain(obj: &Self) -> Arc<Self> {
+        let obj = Arc::retain(&obj.base);
+        unsafe { Arc::cast_unchecked(obj) }
+    }
+
+    #[inline]
+    unsafe fn release(obj: NonNull<Self>) {
+        CFType::release(obj.cast());
+    }
+}
+
+impl<T> Deref for CFArray<T> {
+    type Target = CFType<'static>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<T> AsRef<CFType<'static>> for CFArray<T> {
+    #[inline]
+    fn as_ref(&self) -> &CFType<'static> {
+        &self.base
+    }
+}
+
+impl<T> PartialEq for CFArray<T> {
+    #[inline]
+    #[doc(alias = "CFEqual")]
+    fn eq(&self, other: &Self) -> bool {
+        self.base == other.base
+    }
+}
+
+impl<T: ObjectType + fmt::Debug> fmt::Debug for CFArray<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, T: ObjectType> IntoIterator for &'a CFArray<T> {
+    type Item = &'a T;
+    type IntoIter = CFArrayIter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: ObjectType> ops::Index<CFIndex> for CFArray<T> {
+    type Output = T;
+
+    /// Returns the value at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    #[track_caller]
+    fn index(&self, index: CFIndex) -> &T {
+        match self.get(index) {
+            Some(value) => value,
+            None => panic!(
+                "index out of bounds: the len is {} but the index is {}",
+                self.len(),
+                index
+            ),
+        }
+    }
+}
+
+impl<T: ObjectType> From<&[&T]> for Arc<CFArray<T>> {
+    #[inline]
+    fn from(values: &[&T]) -> Self {
+        CFArray::from_slice(values)
+    }
+}
+
+impl<T> CFArray<T> {
+    /// Returns the type identifier for `CFArray`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfarraygettypeid()).
+    #[inline]
+    #[doc(alias = "CFArrayGetTypeID")]
+    pub fn type_id() -> CFTypeID {
+        unsafe { sys::CFArrayGetTypeID() }
+    }
+
+    /// Reinterprets the elements of this array as `U`.
+    ///
+    /// # Safety
+    ///
+    /// All elements must be valid instances of `U`.
+    #[inline]
+    pub unsafe fn cast_unchecked<U>(&self) -> &CFArray<U> {
+        &*(self as *const Self as *const CFArray<U>)
+    }
+
+    /// Returns the number of values in `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfarraygetcount(_:)).
+    #[inline]
+    #[doc(alias = "CFArrayGetCount")]
+    pub fn len(&self) -> CFIndex {
+        unsafe { sys::CFArrayGetCount(self.as_raw()) }
+    }
+
+    /// Returns `true` if `self` contains no values.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    #[inline]
+    pub(crate) fn as_raw(&self) -> *const CFArray {
+        (self as *const Self).cast()
+    }
+}
+
+impl<T: ObjectType> CFArray<T> {
+    /// Creates an array containing retained references to `values`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfarraycreate(_:_:_:_:)).
+    #[inline]
+    #[doc(alias = "CFArrayCreate")]
+    pub fn from_slice(values: &[&T]) -> Arc<Self> {
+        Self::create(None, values)
+    }
+
+    /// Creates an array using `allocator` containing retained references to
+    /// `values`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfarraycreate(_:_:_:_:)).
+    #[inline]
+    #[doc(alias = "CFArrayCreate")]
+    pub fn create(allocator: Option<&CFAllocator>, values: &[&T]) -> Arc<Self> {
+        unsafe {
+            let array = sys::CFArrayCreate(
+                match allocator {
+                    Some(allocator) => allocator,
+                    None => ptr::null(),
+                },
+                values.as_ptr().cast(),
+                values.len() as CFIndex,
+                CFArrayCallBacks::cf_type(),
+            );
+            Arc::from_raw(array.cast())
+        }
+    }
+
+    /// Returns the value at `index`, or [`None`] if `index` is out of bounds.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfarraygetvalueatindex(_:_:)).
+    #[inline]
+    #[doc(alias = "CFArrayGetValueAtIndex")]
+    pub fn get(&self, index: CFIndex) -> Option<&T> {
+        if index >= 0 && index < self.len() {
+            Some(unsafe { self.get_unchecked(index) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the value at `index` without bounds checking.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfarraygetvalueatindex(_:_:)).
+    ///
+    /// # Safety
+    ///
+    /// `index` must be within `0..self.len()`.
+    #[inline]
+    #[doc(alias = "CFArrayGetValueAtIndex")]
+    pub unsafe fn get_unchecked(&self, index: CFIndex) -> &T {
+        &*sys::CFArrayGetValueAtIndex(self.as_raw(), index).cast::<T>()
+    }
+
+    /// Fills `buf` with the values starting at `start`, returning the number
+    /// of values written.
+    ///
+    /// This fetches all values in a single call, which is much faster than
+    /// calling [`get`](Self::get) for each index. Fewer than `buf.len()`
+    /// values are written if the end of `self` is reached first.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfarraygetvalues(_:_:_:)).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start` is negative or greater than `self.len()`.
+    #[inline]
+    #[track_caller]
+    #[doc(alias = "CFArrayGetValues")]
+    pub fn get_values<'a>(&'a self, start: CFIndex, buf: &mut [Option<&'a T>]) -> usize {
+        let len = self.len();
+        assert!(
+            start >= 0 && start <= len,
+            "start index {} out of bounds for array of length {}",
+            start,
+            len
+        );
+
+        let count = (len - start).min(buf.len() as CFIndex);
+
+        // SAFETY: `Option<&T>` has the same layout as a nullable pointer.
+        unsafe {
+            sys::CFArrayGetValues(
+                self.as_raw(),
+                CFRange::new(start, count),
+                buf.as_mut_ptr().cast(),
+            );
+        }
+        count as usize
+    }
+
+    /// Returns the first value, or [`None`] if `self` is empty.
+    #[inline]
+    pub fn first(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    /// Returns the last value, or [`None`] if `self` is empty.
+    #[inline]
+    pub fn last(&self) -> Option<&T> {
+        self.get(self.len() - 1)
+    }
+
+    /// Returns `true` if `value` is equal to any value in `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfarraycontainsvalue(_:_:_:)).
+    #[inline]
+    #[doc(alias = "CFArrayContainsValue")]
+    pub fn contains(&self, value: &T) -> bool {
+        let range = CFRange::new(0, self.len());
+        let value: *const T = value;
+        unsafe { sys::CFArrayContainsValue(self.as_raw(), range, value.cast()) != 0 }
+    }
+
+    /// Returns an iterator over the values of `self`.
+    #[inline]
+    pub fn iter(&self) -> CFArrayIter<'_, T> {
+        CFArrayIter::new(self)
+    }
+
+    /// Returns a vector of retained references to the values of `self`.
+    #[inline]
+    pub fn to_vec(&self) -> Vec<Arc<T>> {
+        let len = self.len();
+        let mut values = Vec::<*const T>::with_capacity(len as usize);
+        unsafe {
+            sys::CFArrayGetValues(
+                self.as_raw(),
+                CFRange::new(0, len),
+                values.as_mut_ptr().cast(),
+            );
+            values.set_len(len as usize);
+        }
+        values
+            .into_iter()
+            .map(|value| Arc::retain(unsafe { &*value }))
+            .collect()
+    }
+}
@@ -0,0 +1,216 @@
+use super::{CFArray, CFArrayCallBacks};
+use crate::{
+    core::{Arc, ObjectType},
+    core_foundation::{sys, CFAllocator, CFIndex},
+};
+use std::{fmt, ops::Deref, ptr, ptr::NonNull};
+
+/// A dynamic ordered collection of objects of type `T`.
+///
+/// Documentation:
+/// [Swift](https://developer.apple.com/documentation/corefoundation/cfmutablearray?language=swift) |
+/// [Objective-C](https://developer.apple.com/documentation/corefoundation/cfmutablearray?language=objc)
+#[repr(C)]
+pub struct CFMutableArray<T = crate::core_foundation::CFType<'static>>(CFArray<T>);
+
+#[cfg(feature = "foundation")]
+cf_bridge!(impl<T> CFMutableArray<T>, crate::foundation::NSMutableArray<T>);
+
+impl<T> ObjectType for CFMutableArray<T> {
+    #[inline]
+    fn retain(obj: &Self) -> Arc<Self> {
+        let obj = Arc::retain(&obj.0);
+        unsafe { Arc::cast_unchecked(obj) }
+    }
+
+    #[inline]
+    unsafe fn release(obj: NonNull<Self>) {
+        CFArray::<T>::release(obj.cast());
+    }
+}
+
+impl<T> Deref for CFMutableArray<T> {
+    type Target = CFArray<T>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> AsRef<CFArray<T>> for CFMutableArray<T> {
+    #[inline]
+    fn as_ref(&self) -> &CFArray<T> {
+        &self.0
+    }
+}
+
+impl<T> PartialEq for CFMutableArray<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T: ObjectType + fmt::Debug> fmt::Debug for CFMutableArray<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T> CFMutableArray<T> {
+    #[inline]
+    fn as_mut_raw(&self) -> *mut CFArray {
+        (self as *const Self as *mut Self).cast()
+    }
+}
+
+impl<T: ObjectType> CFMutableArray<T> {
+    /// Creates an empty array.
+    #[inline]
+    pub fn new() -> Arc<Self> {
+        Self::create(None, 0)
+    }
+
+    /// Creates an empty array that can hold at most `capacity` values, or an
+    /// unlimited number if `capacity` is 0.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfarraycreatemutable(_:_:_:)).
+    #[inline]
+    #[doc(alias = "CFArrayCreateMutable")]
+    pub fn create(allocator: Option<&CFAllocator>, capacity: CFIndex) -> Arc<Self> {
+        unsafe {
+            let array = sys::CFArrayCreateMutable(
+                match allocator {
+                    Some(allocator) => allocator,
+                    None => ptr::null(),
+                },
+                capacity,
+                CFArrayCallBacks::cf_type(),
+            );
+            Arc::from_raw(array.cast())
+        }
+    }
+
+    /// Creates a mutable copy of `array`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfarraycreatemutablecopy(_:_:_:)).
+    #[inline]
+    #[doc(alias = "CFArrayCreateMutableCopy")]
+    pub fn copy_from(array: &CFArray<T>) -> Arc<Self> {
+        unsafe {
+            let array = sys::CFArrayCreateMutableCopy(ptr::null(), 0, array.as_raw());
+            Arc::from_raw(array.cast())
+        }
+    }
+
+    /// Appends `value` to the end of `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfarrayappendvalue(_:_:)).
+    ///
+    /// # Safety
+    ///
+    /// `self` must not be accessed on any other thread during this call.
+    #[inline]
+    #[doc(alias = "CFArrayAppendValue")]
+    pub unsafe fn push(&self, value: &T) {
+        let value: *const T = value;
+        sys::CFArrayAppendValue(self.as_mut_raw(), value.cast());
+    }
+
+    /// Inserts `value` at `index`, shifting later values up.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfarrayinsertvalueatindex(_:_:_:)).
+    ///
+    /// # Safety
+    ///
+    /// `self` must not be accessed on any other thread during this call.
+    #[inline]
+    #[doc(alias = "CFArrayInsertValueAtIndex")]
+    pub unsafe fn insert(&self, index: CFIndex, value: &T) {
+        let len = self.len();
+        assert!(
+            index >= 0 && index <= len,
+            "insertion index (is {}) should be <= len (is {})",
+            index,
+            len
+        );
+        let value: *const T = value;
+        sys::CFArrayInsertValueAtIndex(self.as_mut_raw(), index, value.cast());
+    }
+
+    /// Replaces the value at `index` with `value`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfarraysetvalueatindex(_:_:_:)).
+    ///
+    /// # Safety
+    ///
+    /// No references to values of `self`, such as from
+    /// [`get`](CFArray::get) or [`iter`](CFArray::iter), may be alive, and
+    /// `self` must not be accessed on any other thread during this call.
+    #[inline]
+    #[doc(alias = "CFArraySetValueAtIndex")]
+    pub unsafe fn set(&self, index: CFIndex, value: &T) {
+        let len = self.len();
+        assert!(
+            index >= 0 && index < len,
+            "index (is {}) should be < len (is {})",
+            index,
+            len
+        );
+        let value: *const T = value;
+        sys::CFArraySetValueAtIndex(self.as_mut_raw(), index, value.cast());
+    }
+
+    /// Removes and returns the value at `index`, shifting later values down.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfarrayremovevalueatindex(_:_:)).
+    ///
+    /// # Safety
+    ///
+    /// No references to values of `self`, such as from
+    /// [`get`](CFArray::get) or [`iter`](CFArray::iter), may be alive, and
+    /// `self` must not be accessed on any other thread during this call.
+    #[inline]
+    #[doc(alias = "CFArrayRemoveValueAtIndex")]
+    pub unsafe fn remove(&self, index: CFIndex) -> Arc<T> {
+        let len = self.len();
+        assert!(
+            index >= 0 && index < len,
+            "removal index (is {}) should be < len (is {})",
+            index,
+            len
+        );
+        let value = Arc::retain(self.get_unchecked(index));
+        sys::CFArrayRemoveValueAtIndex(self.as_mut_raw(), index);
+        value
+    }
+
+    /// Removes all values from `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfarrayremoveallvalues(_:)).
+    ///
+    /// # Safety
+    ///
+    /// No references to values of `self`, such as from
+    /// [`get`](CFArray::get) or [`iter`](CFArray::iter), may be alive, and
+    /// `self` must not be accessed on any other thread during this call.
+    #[inline]
+    #[doc(alias = "CFArrayRemoveAllValues")]
+    pub unsafe fn clear(&self) {
+        sys::CFArrayRemoveAllValues(self.as_mut_raw());
+    }
+}
@@ -13,24 +13,7 @@ subclass! {
 
 // `CFBoolean` is bridged to `NSNumber` but not the other way around.
 #[cfg(feature = "foundation")]
-mod foundation_casts {
-    use super::*;
-    use crate::{core::Arc, foundation::NSNumber};
-
-    impl From<Arc<CFBoolean>> for Arc<NSNumber> {
-        #[inline]
-        fn from(boolean: Arc<CFBoolean>) -> Self {
-            unsafe { Arc::cast_unchecked(boolean) }
-        }
-    }
-
-    impl AsRef<NSNumber> for CFBoolean {
-        #[inline]
-        fn as_ref(&self) -> &NSNumber {
-            unsafe { &*(self as *const Self as *const NSNumber) }
-        }
-    }
-}
+cf_bridge!(CFBoolean => crate::foundation::NSNumber);
 
 impl fmt::Debug for CFBoolean {
     #[inline]
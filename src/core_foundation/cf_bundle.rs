@@ -0,0 +1,409 @@
+use super::{
+    sys, CFAllocator, CFArray, CFDictionary, CFError, CFNumber, CFPropertyList, CFString, CFType,
+    CFTypeID, CFURL,
+};
+use crate::core::Arc;
+use std::{ffi::c_void, fmt, ptr, ptr::NonNull, str::FromStr};
+
+subclass! {
+    /// A directory of code and resources, such as an app, framework, or
+    /// plug-in.
+    ///
+    /// Unlike most Core Foundation types, this is not toll-free bridged with
+    /// its Foundation counterpart, `NSBundle`.
+    ///
+    /// Documentation:
+    /// [Swift](https://developer.apple.com/documentation/corefoundation/cfbundle?language=swift) |
+    /// [Objective-C](https://developer.apple.com/documentation/corefoundation/cfbundle?language=objc)
+    #[derive(PartialEq, Hash)]
+    pub class CFBundle: CFType<'static>;
+}
+
+impl Eq for CFBundle {}
+
+impl fmt::Debug for CFBundle {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CFBundle")
+            .field("identifier", &self.identifier())
+            .field("url", &self.bundle_url())
+            .finish()
+    }
+}
+
+impl CFBundle {
+    /// Returns the type identifier for `CFBundle`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfbundlegettypeid()).
+    #[inline]
+    #[doc(alias = "CFBundleGetTypeID")]
+    pub fn type_id() -> CFTypeID {
+        unsafe { sys::CFBundleGetTypeID() }
+    }
+
+    /// Returns the bundle containing the current executable.
+    ///
+    /// This is [`None`] only if the executable's location cannot be found.
+    /// Command-line tools have a main bundle for the directory they are in.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfbundlegetmainbundle()).
+    #[inline]
+    #[doc(alias = "CFBundleGetMainBundle")]
+    pub fn main() -> Option<Arc<Self>> {
+        unsafe { sys::CFBundleGetMainBundle().as_ref().map(Arc::retain) }
+    }
+
+    /// Returns the already-loaded bundle whose identifier is `identifier`,
+    /// such as `com.apple.CoreFoundation`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfbundlegetbundlewithidentifier(_:)).
+    #[inline]
+    #[doc(alias = "CFBundleGetBundleWithIdentifier")]
+    pub fn with_identifier(identifier: &CFString) -> Option<Arc<Self>> {
+        unsafe {
+            sys::CFBundleGetBundleWithIdentifier(identifier)
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Returns the bundle at `url`, or [`None`] if no bundle exists there.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfbundlecreate(_:_:)).
+    #[inline]
+    #[doc(alias = "CFBundleCreate")]
+    pub fn from_url(allocator: Option<&CFAllocator>, url: &CFURL) -> Option<Arc<Self>> {
+        let allocator = match allocator {
+            Some(allocator) => allocator,
+            None => ptr::null(),
+        };
+        unsafe {
+            let bundle = sys::CFBundleCreate(allocator, url);
+            if bundle.is_null() {
+                None
+            } else {
+                Some(Arc::from_raw(bundle))
+            }
+        }
+    }
+
+    /// Returns the `CFBundleIdentifier` of `self`, if it has one.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfbundlegetidentifier(_:)).
+    #[inline]
+    #[doc(alias = "CFBundleGetIdentifier")]
+    pub fn identifier(&self) -> Option<Arc<CFString>> {
+        unsafe { sys::CFBundleGetIdentifier(self).as_ref().map(Arc::retain) }
+    }
+
+    /// Returns the location of `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfbundlecopybundleurl(_:)).
+    #[inline]
+    #[doc(alias = "CFBundleCopyBundleURL")]
+    pub fn bundle_url(&self) -> Arc<CFURL> {
+        unsafe { Arc::from_raw(sys::CFBundleCopyBundleURL(self)) }
+    }
+
+    /// Returns the location of the executable of `self`, or [`None`] if it
+    /// does not have one.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfbundlecopyexecutableurl(_:)).
+    #[inline]
+    #[doc(alias = "CFBundleCopyExecutableURL")]
+    pub fn executable_url(&self) -> Option<Arc<CFURL>> {
+        unsafe {
+            let url = sys::CFBundleCopyExecutableURL(self);
+            if url.is_null() {
+                None
+            } else {
+                Some(Arc::from_raw(url))
+            }
+        }
+    }
+
+    /// Returns the processor architectures that the executable of `self`
+    /// contains code for, as `cpu_type_t` values, or [`None`] if it has no
+    /// executable.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfbundlecopyexecutablearchitectures(_:)).
+    #[inline]
+    #[doc(alias = "CFBundleCopyExecutableArchitectures")]
+    pub fn executable_architectures(&self) -> Option<Arc<CFArray<CFNumber>>> {
+        unsafe {
+            let architectures = sys::CFBundleCopyExecutableArchitectures(self);
+            if architectures.is_null() {
+                None
+            } else {
+                Some(Arc::from_raw(architectures.cast()))
+            }
+        }
+    }
+
+    /// Returns the location of the resources directory of `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfbundlecopyresourcesdirectoryurl(_:)).
+    #[inline]
+    #[doc(alias = "CFBundleCopyResourcesDirectoryURL")]
+    pub fn resources_directory_url(&self) -> Option<Arc<CFURL>> {
+        unsafe {
+            let url = sys::CFBundleCopyResourcesDirectoryURL(self);
+            if url.is_null() {
+                None
+            } else {
+                Some(Arc::from_raw(url))
+            }
+        }
+    }
+
+    /// Returns the location of the resource file `name`, with extension
+    /// `extension`, within `subdirectory` of the resources directory.
+    ///
+    /// Localized variants are preferred when they exist.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfbundlecopyresourceurl(_:_:_:_:)).
+    #[inline]
+    #[doc(alias = "CFBundleCopyResourceURL")]
+    pub fn resource_url(
+        &self,
+        name: &CFString,
+        extension: Option<&CFString>,
+        subdirectory: Option<&CFString>,
+    ) -> Option<Arc<CFURL>> {
+        let extension = match extension {
+            Some(extension) => extension,
+            None => ptr::null(),
+        };
+        let subdirectory = match subdirectory {
+            Some(subdirectory) => subdirectory,
+            None => ptr::null(),
+        };
+        unsafe {
+            let url = sys::CFBundleCopyResourceURL(self, name, extension, subdirectory);
+            if url.is_null() {
+                None
+            } else {
+                Some(Arc::from_raw(url))
+            }
+        }
+    }
+
+    /// Returns the contents of the `Info.plist` of `self`.
+    ///
+    /// Values are property list types, which can be read with
+    /// [`CFPropertyList::from_cf_type`].
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfbundlegetinfodictionary(_:)).
+    #[inline]
+    #[doc(alias = "CFBundleGetInfoDictionary")]
+    pub fn info_dictionary(&self) -> Arc<CFDictionary<CFString, CFType<'static>>> {
+        unsafe { Arc::retain_raw(sys::CFBundleGetInfoDictionary(self).cast()) }
+    }
+
+    /// Returns the value for `key` in the `Info.plist` of `self`, preferring
+    /// a localized value if one exists.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfbundlegetvalueforinfodictionarykey(_:_:)).
+    #[inline]
+    #[doc(alias = "CFBundleGetValueForInfoDictionaryKey")]
+    pub fn value_for_info_key(&self, key: &CFString) -> Option<CFPropertyList> {
+        unsafe {
+            let value = sys::CFBundleGetValueForInfoDictionaryKey(self, key).as_ref()?;
+            CFPropertyList::from_cf_type(Arc::retain(value))
+        }
+    }
+
+    /// Returns the string for `key` in the unlocalized `Info.plist` of
+    /// `self`.
+    fn info_string(&self, key: &'static str) -> Option<Arc<CFString>> {
+        let info = self.info_dictionary();
+        let value = info.get(&CFString::from_static_str(key))?;
+        match CFPropertyList::from_cf_type(Arc::retain(value))? {
+            CFPropertyList::String(string) => Some(string),
+            _ => None,
+        }
+    }
+
+    /// Returns the `CFBundleVersion` of `self`, which identifies its build.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/bundleresources/information-property-list/cfbundleversion).
+    #[inline]
+    #[doc(alias = "CFBundleVersion")]
+    #[doc(alias = "kCFBundleVersionKey")]
+    pub fn version(&self) -> Option<BundleVersion> {
+        self.info_string("CFBundleVersion")?
+            .to_string()
+            .parse()
+            .ok()
+    }
+
+    /// Returns the `CFBundleShortVersionString` of `self`, which is its
+    /// user-visible release version.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/bundleresources/information-property-list/cfbundleshortversionstring).
+    #[inline]
+    #[doc(alias = "CFBundleShortVersionString")]
+    pub fn short_version(&self) -> Option<BundleVersion> {
+        self.info_string("CFBundleShortVersionString")?
+            .to_string()
+            .parse()
+            .ok()
+    }
+
+    /// Returns the `LSMinimumSystemVersion` of `self`, the oldest macOS
+    /// version it runs on.
+    ///
+    /// This can be checked against the running system with
+    /// [`NSProcessInfo::is_operating_system_at_least`](crate::foundation::NSProcessInfo::is_operating_system_at_least).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/bundleresources/information-property-list/lsminimumsystemversion).
+    #[inline]
+    #[doc(alias = "LSMinimumSystemVersion")]
+    pub fn minimum_system_version(&self) -> Option<BundleVersion> {
+        self.info_string("LSMinimumSystemVersion")?
+            .to_string()
+            .parse()
+            .ok()
+    }
+}
+
+/// Loading code.
+///
+/// Frameworks that only exist on some OS versions can be loaded this way
+/// instead of being linked, so that the binary still launches without them.
+/// See [`DynamicLibrary`](crate::mach::DynamicLibrary) for loading them by
+/// path.
+impl CFBundle {
+    /// Returns `true` if the executable of `self` is loaded into the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfbundleisexecutableloaded(_:)).
+    #[inline]
+    #[doc(alias = "CFBundleIsExecutableLoaded")]
+    pub fn is_executable_loaded(&self) -> bool {
+        unsafe { sys::CFBundleIsExecutableLoaded(self) != 0 }
+    }
+
+    /// Loads the executable of `self` into the process, if it is not already
+    /// loaded.
+    ///
+    /// This fails if the executable does not contain code for the current
+    /// architecture, such as an `arm64`-only framework in a process running
+    /// under Rosetta.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfbundleloadexecutableandreturnerror(_:_:)).
+    #[inline]
+    #[doc(alias = "CFBundleLoadExecutableAndReturnError")]
+    pub fn load_executable(&self) -> Result<(), Arc<CFError>> {
+        unsafe {
+            let mut error = ptr::null();
+            if sys::CFBundleLoadExecutableAndReturnError(self, &mut error) != 0 {
+                Ok(())
+            } else {
+                // `NSExecutableLoadError`, for failures without an error.
+                Err(CFError::from_raw_or(error, 3587))
+            }
+        }
+    }
+
+    /// Returns the address of the function `name` in the executable of
+    /// `self`, loading it if needed, or [`None`] if it does not exist.
+    ///
+    /// The address can be cast to a function pointer of the right type with
+    /// [`mem::transmute`](std::mem::transmute).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfbundlegetfunctionpointerforname(_:_:)).
+    #[inline]
+    #[doc(alias = "CFBundleGetFunctionPointerForName")]
+    pub fn function_pointer(&self, name: &CFString) -> Option<NonNull<c_void>> {
+        NonNull::new(unsafe { sys::CFBundleGetFunctionPointerForName(self, name) })
+    }
+
+    /// Returns the address of the global variable `name` in the executable of
+    /// `self`, loading it if needed, or [`None`] if it does not exist.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfbundlegetdatapointerforname(_:_:)).
+    #[inline]
+    #[doc(alias = "CFBundleGetDataPointerForName")]
+    pub fn data_pointer(&self, name: &CFString) -> Option<NonNull<c_void>> {
+        NonNull::new(unsafe { sys::CFBundleGetDataPointerForName(self, name) })
+    }
+}
+
+/// A bundle version of one to three period-separated integers, such as
+/// `"1.4"` or `"2.0.17"`, with the ordering used by the system.
+///
+/// Missing components are zero, so `"1.4"` and `"1.4.0"` are equal.
+///
+/// # Examples
+///
+/// ```
+/// use fruity::core_foundation::BundleVersion;
+///
+/// let installed: BundleVersion = "1.9.3".parse().unwrap();
+/// let available: BundleVersion = "1.10".parse().unwrap();
+///
+/// assert!(available > installed);
+/// assert_eq!(available.to_string(), "1.10.0");
+/// ```
+///
+/// See [documentation](https://developer.apple.com/documentation/bundleresources/information-property-list/cfbundleversion).
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct BundleVersion {
+    /// The first component.
+    pub major: u32,
+    /// The second component.
+    pub minor: u32,
+    /// The third component.
+    pub patch: u32,
+}
+
+impl BundleVersion {
+    /// Creates a version from its components.
+    #[inline]
+    pub const fn new(major: u32, minor: u32, patch: u32) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+        }
+    }
+}
+
+impl fmt::Display for BundleVersion {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// The error returned when parsing a [`BundleVersion`] fails.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ParseBundleVersionError(());
+
+impl fmt::Display for ParseBundleVersionError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        "invalid bundle version".fmt(f)
+    }
+}
+
+impl std::error::Error for ParseBundleVersionError {}
+
+impl FromStr for BundleVersion {
+    type Err = ParseBundleVersionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut components = [0; 3];
+
+        for (i, component) in s.trim().split('.').enumerate() {
+            let slot = components.get_mut(i).ok_or(ParseBundleVersionError(()))?;
+            if component.is_empty() || !component.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(ParseBundleVersionError(()));
+            }
+            *slot = component.parse().map_err(|_| ParseBundleVersionError(()))?;
+        }
+
+        let [major, minor, patch] = components;
+        Ok(Self::new(major, minor, patch))
+    }
+}
@@ -0,0 +1,128 @@
+use super::{sys, CFAllocator, CFIndex, CFType, CFTypeID};
+use crate::core::Arc;
+use std::{ptr, slice};
+
+mod mutable;
+
+pub use mutable::*;
+
+subclass! {
+    /// An immutable buffer of bytes.
+    ///
+    /// Documentation:
+    /// [Swift](https://developer.apple.com/documentation/corefoundation/cfdata?language=swift) |
+    /// [Objective-C](https://developer.apple.com/documentation/corefoundation/cfdata?language=objc)
+    #[derive(PartialEq, Hash)]
+    pub class CFData: CFType<'static>;
+}
+
+impl Eq for CFData {}
+
+#[cfg(feature = "foundation")]
+cf_bridge!(CFData, crate::foundation::NSData);
+
+impl From<&[u8]> for Arc<CFData> {
+    #[inline]
+    fn from(bytes: &[u8]) -> Self {
+        CFData::from_bytes(None, bytes)
+    }
+}
+
+impl From<&CFData> for Vec<u8> {
+    #[inline]
+    fn from(data: &CFData) -> Self {
+        data.to_vec()
+    }
+}
+
+impl AsRef<[u8]> for CFData {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl CFData {
+    /// Returns the type identifier for `CFData`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfdatagettypeid()).
+    #[inline]
+    #[doc(alias = "CFDataGetTypeID")]
+    pub fn type_id() -> CFTypeID {
+        unsafe { sys::CFDataGetTypeID() }
+    }
+
+    /// Creates an immutable object by copying `bytes`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfdatacreate(_:_:_:)).
+    #[inline]
+    #[doc(alias = "CFDataCreate")]
+    pub fn from_bytes(allocator: Option<&CFAllocator>, bytes: &[u8]) -> Arc<Self> {
+        unsafe {
+            Arc::from_raw(sys::CFDataCreate(
+                match allocator {
+                    Some(allocator) => allocator,
+                    None => ptr::null(),
+                },
+                bytes.as_ptr(),
+                bytes.len() as CFIndex,
+            ))
+        }
+    }
+
+    /// Creates an immutable object that references `bytes` without copying.
+    ///
+    /// Because `bytes` lives for the duration of the program, the object's
+    /// contents are never deallocated.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfdatacreatewithbytesnocopy(_:_:_:_:)).
+    #[inline]
+    #[doc(alias = "CFDataCreateWithBytesNoCopy")]
+    pub fn from_static_bytes(bytes: &'static [u8]) -> Arc<Self> {
+        unsafe {
+            Arc::from_raw(sys::CFDataCreateWithBytesNoCopy(
+                ptr::null(),
+                bytes.as_ptr(),
+                bytes.len() as CFIndex,
+                CFAllocator::null(),
+            ))
+        }
+    }
+
+    /// Returns the number of bytes contained by `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfdatagetlength(_:)).
+    #[inline]
+    #[doc(alias = "CFDataGetLength")]
+    pub fn len(&self) -> CFIndex {
+        unsafe { sys::CFDataGetLength(self) }
+    }
+
+    /// Returns `true` if `self` contains no bytes.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the bytes contained by `self`.
+    ///
+    /// If `self` is a [`CFMutableData`], the slice must not outlive any
+    /// mutation of it.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfdatagetbyteptr(_:)).
+    #[inline]
+    #[doc(alias = "CFDataGetBytePtr")]
+    pub fn as_bytes(&self) -> &[u8] {
+        let len = self.len() as usize;
+        if len == 0 {
+            return &[];
+        }
+        unsafe { slice::from_raw_parts(sys::CFDataGetBytePtr(self), len) }
+    }
+
+    /// Copies the bytes of `self` into a new `Vec`.
+    #[inline]
+    pub fn to_vec(&self) -> Vec<u8> {
+        self.as_bytes().to_vec()
+    }
+}
@@ -0,0 +1,128 @@
+use super::CFData;
+use crate::{
+    core::Arc,
+    core_foundation::{sys, CFAllocator, CFIndex},
+};
+use std::ptr;
+
+subclass! {
+    /// A mutable buffer of bytes.
+    ///
+    /// Documentation:
+    /// [Swift](https://developer.apple.com/documentation/corefoundation/cfmutabledata?language=swift) |
+    /// [Objective-C](https://developer.apple.com/documentation/corefoundation/cfmutabledata?language=objc)
+    ///
+    /// # Mutation
+    ///
+    /// Methods that change the contents of `self` may reallocate its buffer,
+    /// which invalidates slices returned by [`CFData::as_bytes`]. Because
+    /// those slices are not tied to a unique borrow, these methods are
+    /// `unsafe`.
+    #[derive(PartialEq, Hash)]
+    pub class CFMutableData: CFData;
+}
+
+impl Eq for CFMutableData {}
+
+#[cfg(feature = "foundation")]
+cf_bridge!(CFMutableData, crate::foundation::NSMutableData);
+
+impl Default for Arc<CFMutableData> {
+    #[inline]
+    fn default() -> Self {
+        CFMutableData::new()
+    }
+}
+
+impl From<&[u8]> for Arc<CFMutableData> {
+    #[inline]
+    fn from(bytes: &[u8]) -> Self {
+        let data = CFMutableData::new();
+        unsafe { data.append(bytes) };
+        data
+    }
+}
+
+impl CFMutableData {
+    #[inline]
+    fn as_mut_raw(&self) -> *mut CFData {
+        (self as *const Self as *mut Self).cast()
+    }
+
+    /// Creates an empty object.
+    #[inline]
+    pub fn new() -> Arc<Self> {
+        Self::create(None, 0)
+    }
+
+    /// Creates an empty object that can hold at most `capacity` bytes, or an
+    /// unlimited number if `capacity` is 0.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfdatacreatemutable(_:_:)).
+    #[inline]
+    #[doc(alias = "CFDataCreateMutable")]
+    pub fn create(allocator: Option<&CFAllocator>, capacity: CFIndex) -> Arc<Self> {
+        unsafe {
+            let data = sys::CFDataCreateMutable(
+                match allocator {
+                    Some(allocator) => allocator,
+                    None => ptr::null(),
+                },
+                capacity,
+            );
+            Arc::from_raw(data.cast())
+        }
+    }
+
+    /// Creates a mutable copy of `data`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfdatacreatemutablecopy(_:_:_:)).
+    #[inline]
+    #[doc(alias = "CFDataCreateMutableCopy")]
+    pub fn copy_from(data: &CFData) -> Arc<Self> {
+        unsafe {
+            let data = sys::CFDataCreateMutableCopy(ptr::null(), 0, data);
+            Arc::from_raw(data.cast())
+        }
+    }
+
+    /// Appends `bytes` to the end of `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfdataappendbytes(_:_:_:)).
+    ///
+    /// # Safety
+    ///
+    /// No slices of the bytes of `self` may be alive, on this or any other
+    /// thread.
+    #[inline]
+    #[doc(alias = "CFDataAppendBytes")]
+    pub unsafe fn append(&self, bytes: &[u8]) {
+        sys::CFDataAppendBytes(self.as_mut_raw(), bytes.as_ptr(), bytes.len() as CFIndex);
+    }
+
+    /// Resizes `self` to `len` bytes, either truncating it or filling new
+    /// bytes with zeros.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfdatasetlength(_:_:)).
+    ///
+    /// # Safety
+    ///
+    /// No slices of the bytes of `self` may be alive, on this or any other
+    /// thread.
+    #[inline]
+    #[doc(alias = "CFDataSetLength")]
+    pub unsafe fn set_len(&self, len: CFIndex) {
+        sys::CFDataSetLength(self.as_mut_raw(), len);
+    }
+
+    /// Removes all bytes from `self`.
+    ///
+    /// # Safety
+    ///
+    /// No slices of the bytes of `self` may be alive, on this or any other
+    /// thread.
+    #[inline]
+    pub unsafe fn clear(&self) {
+        self.set_len(0);
+    }
+}
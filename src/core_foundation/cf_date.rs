@@ -0,0 +1,123 @@
+use super::{sys, CFAllocator, CFComparisonResult, CFType, CFTypeID};
+use crate::core::Arc;
+use std::{cmp::Ordering, fmt, ptr};
+
+/// A type used to represent a specific point in time relative to the absolute
+/// reference date of 1 Jan 2001 00:00:00 GMT.
+///
+/// See [documentation](https://developer.apple.com/documentation/corefoundation/cfabsolutetime).
+pub type CFAbsoluteTime = CFTimeInterval;
+
+/// A type used to represent elapsed time in seconds.
+///
+/// See [documentation](https://developer.apple.com/documentation/corefoundation/cftimeinterval).
+pub type CFTimeInterval = f64;
+
+/// Returns the current system absolute time.
+///
+/// See [documentation](https://developer.apple.com/documentation/corefoundation/cfabsolutetimegetcurrent()).
+#[inline]
+#[allow(non_snake_case)]
+pub fn CFAbsoluteTimeGetCurrent() -> CFAbsoluteTime {
+    unsafe { sys::CFAbsoluteTimeGetCurrent() }
+}
+
+subclass! {
+    /// A specific point in time.
+    ///
+    /// Documentation:
+    /// [Swift](https://developer.apple.com/documentation/corefoundation/cfdate?language=swift) |
+    /// [Objective-C](https://developer.apple.com/documentation/corefoundation/cfdate?language=objc)
+    #[derive(PartialEq, Hash)]
+    pub class CFDate: CFType<'static>;
+}
+
+#[cfg(feature = "foundation")]
+cf_bridge!(CFDate, crate::foundation::NSDate);
+
+impl Eq for CFDate {}
+
+impl PartialOrd for CFDate {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for CFDate {
+    #[inline]
+    #[doc(alias = "CFDateCompare")]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.compare(other).into()
+    }
+}
+
+impl fmt::Debug for CFDate {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("CFDate")
+            .field(&self.absolute_time())
+            .finish()
+    }
+}
+
+impl CFDate {
+    /// Returns the type identifier for `CFDate`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfdategettypeid()).
+    #[inline]
+    #[doc(alias = "CFDateGetTypeID")]
+    pub fn type_id() -> CFTypeID {
+        unsafe { sys::CFDateGetTypeID() }
+    }
+
+    /// Creates a date for the current time.
+    #[inline]
+    pub fn now() -> Arc<Self> {
+        Self::new(None, CFAbsoluteTimeGetCurrent())
+    }
+
+    /// Creates a date for `at`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfdatecreate(_:_:)).
+    #[inline]
+    #[doc(alias = "CFDateCreate")]
+    pub fn new(allocator: Option<&CFAllocator>, at: CFAbsoluteTime) -> Arc<Self> {
+        unsafe {
+            Arc::from_raw(sys::CFDateCreate(
+                match allocator {
+                    Some(allocator) => allocator,
+                    None => ptr::null(),
+                },
+                at,
+            ))
+        }
+    }
+
+    /// Returns the absolute time of `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfdategetabsolutetime(_:)).
+    #[inline]
+    #[doc(alias = "CFDateGetAbsoluteTime")]
+    pub fn absolute_time(&self) -> CFAbsoluteTime {
+        unsafe { sys::CFDateGetAbsoluteTime(self) }
+    }
+
+    /// Returns the number of seconds from `other` to `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfdategettimeintervalsincedate(_:_:)).
+    #[inline]
+    #[doc(alias = "CFDateGetTimeIntervalSinceDate")]
+    pub fn time_interval_since(&self, other: &Self) -> CFTimeInterval {
+        unsafe { sys::CFDateGetTimeIntervalSinceDate(self, other) }
+    }
+
+    /// Compares `self` to `other` and returns the result.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfdatecompare(_:_:_:)).
+    #[inline]
+    #[doc(alias = "CFDateCompare")]
+    pub fn compare(&self, other: &Self) -> CFComparisonResult {
+        unsafe { sys::CFDateCompare(self, other, ptr::null_mut()) }
+    }
+}
@@ -0,0 +1,120 @@
+use crate::core_foundation::{Boolean, CFAllocator, CFHashCode, CFIndex};
+use std::ffi::c_void;
+
+/// Callback used to retain a key or value as it is added to a dictionary.
+///
+/// See [documentation](https://developer.apple.com/documentation/corefoundation/cfdictionaryretaincallback).
+pub type CFDictionaryRetainCallBack =
+    unsafe extern "C" fn(allocator: *const CFAllocator, value: *const c_void) -> *const c_void;
+
+/// Callback used to release a key or value before it is removed from a
+/// dictionary.
+///
+/// See [documentation](https://developer.apple.com/documentation/corefoundation/cfdictionaryreleasecallback).
+pub type CFDictionaryReleaseCallBack =
+    unsafe extern "C" fn(allocator: *const CFAllocator, value: *const c_void);
+
+/// Callback used to create a descriptive string representation of each key or
+/// value in a dictionary.
+///
+/// See [documentation](https://developer.apple.com/documentation/corefoundation/cfdictionarycopydescriptioncallback).
+pub type CFDictionaryCopyDescriptionCallBack =
+    unsafe extern "C" fn(value: *const c_void) -> *const c_void; // TODO: Return type `Arc<CFString>`.
+
+/// Callback used to determine if two keys or values in a dictionary are equal.
+///
+/// See [documentation](https://developer.apple.com/documentation/corefoundation/cfdictionaryequalcallback).
+pub type CFDictionaryEqualCallBack =
+    unsafe extern "C" fn(value1: *const c_void, value2: *const c_void) -> Boolean;
+
+/// Callback used to compute a hash code for keys as they are used to access,
+/// add, or remove values in a dictionary.
+///
+/// See [documentation](https://developer.apple.com/documentation/corefoundation/cfdictionaryhashcallback).
+pub type CFDictionaryHashCallBack = unsafe extern "C" fn(value: *const c_void) -> CFHashCode;
+
+/// Callbacks that describe how keys are retained, released, described,
+/// compared, and hashed by a dictionary.
+///
+/// See [documentation](https://developer.apple.com/documentation/corefoundation/cfdictionarykeycallbacks).
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct CFDictionaryKeyCallBacks {
+    /// The version number of this structure. Must be 0.
+    pub version: CFIndex,
+    /// The callback used to retain each key as it is added.
+    pub retain: Option<CFDictionaryRetainCallBack>,
+    /// The callback used to release each key as it is removed.
+    pub release: Option<CFDictionaryReleaseCallBack>,
+    /// The callback used to describe each key.
+    pub copy_description: Option<CFDictionaryCopyDescriptionCallBack>,
+    /// The callback used to compare keys.
+    pub equal: Option<CFDictionaryEqualCallBack>,
+    /// The callback used to hash keys.
+    pub hash: Option<CFDictionaryHashCallBack>,
+}
+
+impl CFDictionaryKeyCallBacks {
+    /// Returns the key callbacks for dictionaries whose keys are Core
+    /// Foundation objects.
+    ///
+    /// This internally references
+    /// [`kCFTypeDictionaryKeyCallBacks`](https://developer.apple.com/documentation/corefoundation/kcftypedictionarykeycallbacks).
+    #[inline]
+    #[doc(alias = "kCFTypeDictionaryKeyCallBacks")]
+    pub fn cf_type() -> &'static Self {
+        extern "C" {
+            static kCFTypeDictionaryKeyCallBacks: CFDictionaryKeyCallBacks;
+        }
+        unsafe { &kCFTypeDictionaryKeyCallBacks }
+    }
+
+    /// Returns the key callbacks for dictionaries whose keys are strings that
+    /// are copied on insertion.
+    ///
+    /// This internally references
+    /// [`kCFCopyStringDictionaryKeyCallBacks`](https://developer.apple.com/documentation/corefoundation/kcfcopystringdictionarykeycallbacks).
+    #[inline]
+    #[doc(alias = "kCFCopyStringDictionaryKeyCallBacks")]
+    pub fn copy_string() -> &'static Self {
+        extern "C" {
+            static kCFCopyStringDictionaryKeyCallBacks: CFDictionaryKeyCallBacks;
+        }
+        unsafe { &kCFCopyStringDictionaryKeyCallBacks }
+    }
+}
+
+/// Callbacks that describe how values are retained, released, described, and
+/// compared by a dictionary.
+///
+/// See [documentation](https://developer.apple.com/documentation/corefoundation/cfdictionaryvaluecallbacks).
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct CFDictionaryValueCallBacks {
+    /// The version number of this structure. Must be 0.
+    pub version: CFIndex,
+    /// The callback used to retain each value as it is added.
+    pub retain: Option<CFDictionaryRetainCallBack>,
+    /// The callback used to release each value as it is removed.
+    pub release: Option<CFDictionaryReleaseCallBack>,
+    /// The callback used to describe each value.
+    pub copy_description: Option<CFDictionaryCopyDescriptionCallBack>,
+    /// The callback used to compare values.
+    pub equal: Option<CFDictionaryEqualCallBack>,
+}
+
+impl CFDictionaryValueCallBacks {
+    /// Returns the value callbacks for dictionaries whose values are Core
+    /// Foundation objects.
+    ///
+    /// This internally references
+    /// [`kCFTypeDictionaryValueCallBacks`](https://developer.apple.com/documentation/corefoundation/kcftypedictionaryvaluecallbacks).
+    #[inline]
+    #[doc(alias = "kCFTypeDictionaryValueCallBacks")]
+    pub fn cf_type() -> &'static Self {
+        extern "C" {
+            static kCFTypeDictionaryValueCallBacks: CFDictionaryValueCallBacks;
+        }
+        unsafe { &kCFTypeDictionaryValueCallBacks }
+    }
+}
@@ -0,0 +1,46 @@
+use super::CFDictionary;
+use crate::core::ObjectType;
+use std::{ffi::c_void, iter::FusedIterator, marker::PhantomData, vec};
+
+/// An iterator over the key-value pairs of a [`CFDictionary`].
+///
+/// The pairs are fetched up front with
+/// [`CFDictionaryGetKeysAndValues`](https://developer.apple.com/documentation/corefoundation/cfdictionarygetkeysandvalues(_:_:_:)),
+/// so their order is unspecified.
+pub struct CFDictionaryIter<'a, K, V> {
+    keys: vec::IntoIter<*const c_void>,
+    values: vec::IntoIter<*const c_void>,
+    _marker: PhantomData<&'a CFDictionary<K, V>>,
+}
+
+impl<'a, K, V> CFDictionaryIter<'a, K, V> {
+    #[inline]
+    pub(super) fn new(dictionary: &'a CFDictionary<K, V>) -> Self {
+        let (keys, values) = dictionary.raw_keys_and_values();
+        Self {
+            keys: keys.into_iter(),
+            values: values.into_iter(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, K: ObjectType, V: ObjectType> Iterator for CFDictionaryIter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let key = self.keys.next()?;
+        let value = self.values.next()?;
+        unsafe { Some((&*key.cast::<K>(), &*value.cast::<V>())) }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.keys.size_hint()
+    }
+}
+
+impl<K: ObjectType, V: ObjectType> ExactSizeIterator for CFDictionaryIter<'_, K, V> {}
+
+impl<K: ObjectType, V: ObjectType> FusedIterator for CFDictionaryIter<'_, K, V> {}
@@ -0,0 +1,247 @@
+use super::{sys, CFAllocator, CFIndex, CFType, CFTypeID};
+use crate::core::{Arc, ObjectType};
+use std::{
+    ffi::c_void, fmt, iter::FromIterator, marker::PhantomData, ops::Deref, ptr, ptr::NonNull,
+};
+
+mod callbacks;
+mod iter;
+mod mutable;
+
+pub use callbacks::*;
+pub use iter::*;
+pub use mutable::*;
+
+/// A static collection of key-value pairs with keys of type `K` and values of
+/// type `V`.
+///
+/// Documentation:
+/// [Swift](https://developer.apple.com/documentation/corefoundation/cfdictionary?language=swift) |
+/// [Objective-C](https://developer.apple.com/documentation/corefoundation/cfdictionary?language=objc)
+///
+/// # Key and Value Types
+///
+/// Like [`CFArray`](super::CFArray), the generic types are not checked at
+/// runtime. Dictionaries created by this crate use the Core Foundation
+/// callbacks to retain their keys and values.
+#[repr(C)]
+pub struct CFDictionary<K = CFType<'static>, V = CFType<'static>> {
+    base: CFType<'static>,
+    _marker: PhantomData<(*const K, *const V)>,
+}
+
+// SAFETY: The dictionary itself is thread-safe because it is immutable, and
+// its keys and values are only exposed by reference.
+unsafe impl<K: Send + Sync, V: Send + Sync> Sync for CFDictionary<K, V> {}
+unsafe impl<K: Send + Sync, V: Send + Sync> Send for CFDictionary<K, V> {}
+
+#[cfg(feature = "foundation")]
+cf_bridge!(impl<K, V> CFDictionary<K, V>, crate::foundation::NSDictionary<K, V>);
+
+impl<K, V> ObjectType for CFDictionary<K, V> {
+    #[inline]
+    fn retain(obj: &Self) -> Arc<Self> {
+        let obj = Arc::retain(&obj.base);
+        unsafe { Arc::cast_unchecked(obj) }
+    }
+
+    #[inline]
+    unsafe fn release(obj: NonNull<Self>) {
+        CFType::release(obj.cast());
+    }
+}
+
+impl<K, V> Deref for CFDictionary<K, V> {
+    type Target = CFType<'static>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<K, V> AsRef<CFType<'static>> for CFDictionary<K, V> {
+    #[inline]
+    fn as_ref(&self) -> &CFType<'static> {
+        &self.base
+    }
+}
+
+impl<K, V> PartialEq for CFDictionary<K, V> {
+    #[inline]
+    #[doc(alias = "CFEqual")]
+    fn eq(&self, other: &Self) -> bool {
+        self.base == other.base
+    }
+}
+
+impl<K, V> fmt::Debug for CFDictionary<K, V>
+where
+    K: ObjectType + fmt::Debug,
+    V: ObjectType + fmt::Debug,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, K: ObjectType, V: ObjectType> IntoIterator for &'a CFDictionary<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = CFDictionaryIter<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, K, V> FromIterator<(&'a K, &'a V)> for Arc<CFDictionary<K, V>>
+where
+    K: ObjectType + 'a,
+    V: ObjectType + 'a,
+{
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = (&'a K, &'a V)>>(iter: I) -> Self {
+        let (keys, values): (Vec<&K>, Vec<&V>) = iter.into_iter().unzip();
+        CFDictionary::create(None, &keys, &values)
+    }
+}
+
+impl<K, V> CFDictionary<K, V> {
+    /// Returns the type identifier for `CFDictionary`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfdictionarygettypeid()).
+    #[inline]
+    #[doc(alias = "CFDictionaryGetTypeID")]
+    pub fn type_id() -> CFTypeID {
+        unsafe { sys::CFDictionaryGetTypeID() }
+    }
+
+    /// Reinterprets the keys and values of this dictionary as `K2` and `V2`.
+    ///
+    /// # Safety
+    ///
+    /// All keys must be valid instances of `K2` and all values must be valid
+    /// instances of `V2`.
+    #[inline]
+    pub unsafe fn cast_unchecked<K2, V2>(&self) -> &CFDictionary<K2, V2> {
+        &*(self as *const Self as *const CFDictionary<K2, V2>)
+    }
+
+    /// Returns the number of key-value pairs in `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfdictionarygetcount(_:)).
+    #[inline]
+    #[doc(alias = "CFDictionaryGetCount")]
+    pub fn len(&self) -> CFIndex {
+        unsafe { sys::CFDictionaryGetCount(self.as_raw()) }
+    }
+
+    /// Returns `true` if `self` contains no key-value pairs.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    #[inline]
+    pub(crate) fn as_raw(&self) -> *const CFDictionary {
+        (self as *const Self).cast()
+    }
+
+    fn raw_keys_and_values(&self) -> (Vec<*const c_void>, Vec<*const c_void>) {
+        let len = self.len() as usize;
+        let mut keys = Vec::with_capacity(len);
+        let mut values = Vec::with_capacity(len);
+        unsafe {
+            sys::CFDictionaryGetKeysAndValues(
+                self.as_raw(),
+                keys.as_mut_ptr(),
+                values.as_mut_ptr(),
+            );
+            keys.set_len(len);
+            values.set_len(len);
+        }
+        (keys, values)
+    }
+}
+
+impl<K: ObjectType, V: ObjectType> CFDictionary<K, V> {
+    /// Creates a dictionary using `allocator` containing retained references to
+    /// `keys` and their corresponding `values`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfdictionarycreate(_:_:_:_:_:_:)).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `keys` and `values` have different lengths.
+    #[inline]
+    #[doc(alias = "CFDictionaryCreate")]
+    pub fn create(allocator: Option<&CFAllocator>, keys: &[&K], values: &[&V]) -> Arc<Self> {
+        assert_eq!(
+            keys.len(),
+            values.len(),
+            "keys and values must have the same length"
+        );
+        unsafe {
+            let dictionary = sys::CFDictionaryCreate(
+                match allocator {
+                    Some(allocator) => allocator,
+                    None => ptr::null(),
+                },
+                keys.as_ptr().cast(),
+                values.as_ptr().cast(),
+                keys.len() as CFIndex,
+                CFDictionaryKeyCallBacks::cf_type(),
+                CFDictionaryValueCallBacks::cf_type(),
+            );
+            Arc::from_raw(dictionary.cast())
+        }
+    }
+
+    /// Returns the value associated with `key`, or [`None`] if there is none.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfdictionarygetvalueifpresent(_:_:_:)).
+    #[inline]
+    #[doc(alias = "CFDictionaryGetValue")]
+    #[doc(alias = "CFDictionaryGetValueIfPresent")]
+    pub fn get(&self, key: &K) -> Option<&V> {
+        let key: *const K = key;
+        let mut value = ptr::null();
+        unsafe {
+            if sys::CFDictionaryGetValueIfPresent(self.as_raw(), key.cast(), &mut value) != 0 {
+                Some(&*value.cast::<V>())
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns `true` if `self` contains a value for `key`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfdictionarycontainskey(_:_:)).
+    #[inline]
+    #[doc(alias = "CFDictionaryContainsKey")]
+    pub fn contains_key(&self, key: &K) -> bool {
+        let key: *const K = key;
+        unsafe { sys::CFDictionaryContainsKey(self.as_raw(), key.cast()) != 0 }
+    }
+
+    /// Returns an iterator over the key-value pairs of `self`.
+    #[inline]
+    pub fn iter(&self) -> CFDictionaryIter<'_, K, V> {
+        CFDictionaryIter::new(self)
+    }
+
+    /// Returns an iterator over the keys of `self`.
+    #[inline]
+    pub fn keys(&self) -> impl Iterator<Item = &K> + '_ {
+        self.iter().map(|(key, _)| key)
+    }
+
+    /// Returns an iterator over the values of `self`.
+    #[inline]
+    pub fn values(&self) -> impl Iterator<Item = &V> + '_ {
+        self.iter().map(|(_, value)| value)
+    }
+}
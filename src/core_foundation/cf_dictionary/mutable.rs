@@ -0,0 +1,189 @@
+use super::{CFDictionary, CFDictionaryKeyCallBacks, CFDictionaryValueCallBacks};
+use crate::{
+    core::{Arc, ObjectType},
+    core_foundation::{sys, CFAllocator, CFIndex, CFType},
+};
+use std::{fmt, iter::FromIterator, ops::Deref, ptr, ptr::NonNull};
+
+/// A dynamic collection of key-value pairs with keys of type `K` and values of
+/// type `V`.
+///
+/// Documentation:
+/// [Swift](https://developer.apple.com/documentation/corefoundation/cfmutabledictionary?language=swift) |
+/// [Objective-C](https://developer.apple.com/documentation/corefoundation/cfmutabledictionary?language=objc)
+#[repr(C)]
+pub struct CFMutableDictionary<K = CFType<'static>, V = CFType<'static>>(CFDictionary<K, V>);
+
+#[cfg(feature = "foundation")]
+cf_bridge!(impl<K, V> CFMutableDictionary<K, V>, crate::foundation::NSMutableDictionary<K, V>);
+
+impl<K, V> ObjectType for CFMutableDictionary<K, V> {
+    #[inline]
+    fn retain(obj: &Self) -> Arc<Self> {
+        let obj = Arc::retain(&obj.0);
+        unsafe { Arc::cast_unchecked(obj) }
+    }
+
+    #[inline]
+    unsafe fn release(obj: NonNull<Self>) {
+        CFDictionary::<K, V>::release(obj.cast());
+    }
+}
+
+impl<K, V> Deref for CFMutableDictionary<K, V> {
+    type Target = CFDictionary<K, V>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<K, V> AsRef<CFDictionary<K, V>> for CFMutableDictionary<K, V> {
+    #[inline]
+    fn as_ref(&self) -> &CFDictionary<K, V> {
+        &self.0
+    }
+}
+
+impl<K, V> PartialEq for CFMutableDictionary<K, V> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<K, V> fmt::Debug for CFMutableDictionary<K, V>
+where
+    K: ObjectType + fmt::Debug,
+    V: ObjectType + fmt::Debug,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<'a, K, V> FromIterator<(&'a K, &'a V)> for Arc<CFMutableDictionary<K, V>>
+where
+    K: ObjectType + 'a,
+    V: ObjectType + 'a,
+{
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = (&'a K, &'a V)>>(iter: I) -> Self {
+        let dictionary = CFMutableDictionary::new();
+        for (key, value) in iter {
+            // SAFETY: `dictionary` has not been shared yet.
+            unsafe { dictionary.insert(key, value) };
+        }
+        dictionary
+    }
+}
+
+impl<K, V> CFMutableDictionary<K, V> {
+    #[inline]
+    fn as_mut_raw(&self) -> *mut CFDictionary {
+        (self as *const Self as *mut Self).cast()
+    }
+}
+
+impl<K: ObjectType, V: ObjectType> CFMutableDictionary<K, V> {
+    /// Creates an empty dictionary.
+    #[inline]
+    pub fn new() -> Arc<Self> {
+        Self::create(None, 0)
+    }
+
+    /// Creates an empty dictionary that can hold at most `capacity` pairs, or
+    /// an unlimited number if `capacity` is 0.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfdictionarycreatemutable(_:_:_:_:)).
+    #[inline]
+    #[doc(alias = "CFDictionaryCreateMutable")]
+    pub fn create(allocator: Option<&CFAllocator>, capacity: CFIndex) -> Arc<Self> {
+        unsafe {
+            let dictionary = sys::CFDictionaryCreateMutable(
+                match allocator {
+                    Some(allocator) => allocator,
+                    None => ptr::null(),
+                },
+                capacity,
+                CFDictionaryKeyCallBacks::cf_type(),
+                CFDictionaryValueCallBacks::cf_type(),
+            );
+            Arc::from_raw(dictionary.cast())
+        }
+    }
+
+    /// Creates a mutable copy of `dictionary`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfdictionarycreatemutablecopy(_:_:_:)).
+    #[inline]
+    #[doc(alias = "CFDictionaryCreateMutableCopy")]
+    pub fn copy_from(dictionary: &CFDictionary<K, V>) -> Arc<Self> {
+        unsafe {
+            let dictionary =
+                sys::CFDictionaryCreateMutableCopy(ptr::null(), 0, dictionary.as_raw());
+            Arc::from_raw(dictionary.cast())
+        }
+    }
+
+    /// Sets the value for `key`, returning the previous value if there was one.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfdictionarysetvalue(_:_:_:)).
+    ///
+    /// # Safety
+    ///
+    /// No references to keys or values of `self`, such as from
+    /// [`get`](CFDictionary::get) or [`iter`](CFDictionary::iter), may be
+    /// alive, and `self` must not be accessed on any other thread during this
+    /// call.
+    #[inline]
+    #[doc(alias = "CFDictionarySetValue")]
+    pub unsafe fn insert(&self, key: &K, value: &V) -> Option<Arc<V>> {
+        let old = self.get(key).map(Arc::retain);
+
+        let key: *const K = key;
+        let value: *const V = value;
+        sys::CFDictionarySetValue(self.as_mut_raw(), key.cast(), value.cast());
+
+        old
+    }
+
+    /// Removes the value for `key`, returning it if there was one.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfdictionaryremovevalue(_:_:)).
+    ///
+    /// # Safety
+    ///
+    /// No references to keys or values of `self`, such as from
+    /// [`get`](CFDictionary::get) or [`iter`](CFDictionary::iter), may be
+    /// alive, and `self` must not be accessed on any other thread during this
+    /// call.
+    #[inline]
+    #[doc(alias = "CFDictionaryRemoveValue")]
+    pub unsafe fn remove(&self, key: &K) -> Option<Arc<V>> {
+        let old = self.get(key).map(Arc::retain)?;
+
+        let key: *const K = key;
+        sys::CFDictionaryRemoveValue(self.as_mut_raw(), key.cast());
+
+        Some(old)
+    }
+
+    /// Removes all key-value pairs from `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfdictionaryremoveallvalues(_:)).
+    ///
+    /// # Safety
+    ///
+    /// No references to keys or values of `self`, such as from
+    /// [`get`](CFDictionary::get) or [`iter`](CFDictionary::iter), may be
+    /// alive, and `self` must not be accessed on any other thread during this
+    /// call.
+    #[inline]
+    #[doc(alias = "CFDictionaryRemoveAllValues")]
+    pub unsafe fn clear(&self) {
+        sys::CFDictionaryRemoveAllValues(self.as_mut_raw());
+    }
+}
@@ -0,0 +1,135 @@
+use super::{sys, CFIndex, CFString, CFType, CFTypeID};
+use crate::core::Arc;
+use std::{fmt, ptr};
+
+subclass! {
+    /// Information about an error condition including a domain, a
+    /// domain-specific error code, and application-specific information.
+    ///
+    /// Documentation:
+    /// [Swift](https://developer.apple.com/documentation/corefoundation/cferror?language=swift) |
+    /// [Objective-C](https://developer.apple.com/documentation/corefoundation/cferror?language=objc)
+    ///
+    /// # Formatting
+    ///
+    /// The [`Display`](std::fmt::Display) implementation writes the result of
+    /// [`description`](Self::description).
+    #[derive(PartialEq, Hash)]
+    pub class CFError: CFType<'static>;
+}
+
+#[cfg(feature = "foundation")]
+cf_bridge!(CFError, crate::foundation::NSError<'static>);
+
+impl fmt::Debug for CFError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CFError")
+            .field("domain", &self.domain())
+            .field("code", &self.code())
+            .finish()
+    }
+}
+
+impl fmt::Display for CFError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.description().fmt(f)
+    }
+}
+
+impl CFError {
+    /// Returns the type identifier for `CFError`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cferrorgettypeid()).
+    #[inline]
+    #[doc(alias = "CFErrorGetTypeID")]
+    pub fn type_id() -> CFTypeID {
+        unsafe { sys::CFErrorGetTypeID() }
+    }
+
+    /// Takes ownership of `error` from a failed call, or creates an error in
+    /// the Cocoa domain with `code` if the call did not provide one.
+    ///
+    /// # Safety
+    ///
+    /// `error` must be null or an owned reference.
+    pub(crate) unsafe fn from_raw_or(error: *const CFError, code: CFIndex) -> Arc<Self> {
+        if !error.is_null() {
+            return Arc::from_raw(error);
+        }
+
+        extern "C" {
+            static kCFErrorDomainCocoa: &'static CFString;
+        }
+        Arc::from_raw(sys::CFErrorCreate(
+            ptr::null(),
+            kCFErrorDomainCocoa,
+            code,
+            ptr::null(),
+        ))
+    }
+
+    /// Returns the error code.
+    ///
+    /// Note that errors are domain-specific.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cferrorgetcode(_:)).
+    #[inline]
+    #[doc(alias = "CFErrorGetCode")]
+    pub fn code(&self) -> CFIndex {
+        unsafe { sys::CFErrorGetCode(self) }
+    }
+
+    /// Returns the error domain.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cferrorgetdomain(_:)).
+    #[inline]
+    #[doc(alias = "CFErrorGetDomain")]
+    pub fn domain(&self) -> Arc<CFString> {
+        unsafe { Arc::retain_raw(sys::CFErrorGetDomain(self)) }
+    }
+
+    /// Returns a human-presentable description of the error.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cferrorcopydescription(_:)).
+    #[inline]
+    #[doc(alias = "CFErrorCopyDescription")]
+    pub fn description(&self) -> Arc<CFString> {
+        unsafe { Arc::from_raw(sys::CFErrorCopyDescription(self)) }
+    }
+
+    /// Returns a human-presentable failure reason for the error, if one is
+    /// available.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cferrorcopyfailurereason(_:)).
+    #[inline]
+    #[doc(alias = "CFErrorCopyFailureReason")]
+    pub fn failure_reason(&self) -> Option<Arc<CFString>> {
+        unsafe {
+            let reason = sys::CFErrorCopyFailureReason(self);
+            if reason.is_null() {
+                None
+            } else {
+                Some(Arc::from_raw(reason))
+            }
+        }
+    }
+
+    /// Returns a human-presentable recovery suggestion for the error, if one
+    /// is available.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cferrorcopyrecoverysuggestion(_:)).
+    #[inline]
+    #[doc(alias = "CFErrorCopyRecoverySuggestion")]
+    pub fn recovery_suggestion(&self) -> Option<Arc<CFString>> {
+        unsafe {
+            let suggestion = sys::CFErrorCopyRecoverySuggestion(self);
+            if suggestion.is_null() {
+                None
+            } else {
+                Some(Arc::from_raw(suggestion))
+            }
+        }
+    }
+}
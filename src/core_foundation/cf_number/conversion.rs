@@ -0,0 +1,93 @@
+use super::{CFNumber, CFNumberType};
+use std::{convert::TryFrom, error::Error, fmt};
+
+/// The error returned when a [`CFNumber`] cannot be converted to `T` without
+/// losing precision or range.
+///
+/// This holds the closest value of `T` that Core Foundation could produce.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CFNumberLossyConversion<T> {
+    approximation: T,
+}
+
+impl<T> fmt::Display for CFNumberLossyConversion<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        "CFNumber value cannot be represented exactly".fmt(f)
+    }
+}
+
+impl<T: fmt::Debug> Error for CFNumberLossyConversion<T> {}
+
+impl<T> CFNumberLossyConversion<T> {
+    #[inline]
+    pub(crate) fn new(approximation: T) -> Self {
+        Self { approximation }
+    }
+
+    /// Returns the closest value to the number that could be produced.
+    #[inline]
+    pub fn approximation(self) -> T {
+        self.approximation
+    }
+}
+
+macro_rules! impl_try_from_signed {
+    ($($t:ty => $number_type:ident,)+) => {
+        $(
+            impl TryFrom<&CFNumber> for $t {
+                type Error = CFNumberLossyConversion<$t>;
+
+                #[inline]
+                fn try_from(number: &CFNumber) -> Result<Self, Self::Error> {
+                    unsafe { number.try_get_value(CFNumberType::$number_type) }
+                }
+            }
+        )+
+    };
+}
+
+impl_try_from_signed! {
+    i8 => I8,
+    i16 => I16,
+    i32 => I32,
+    i64 => I64,
+    isize => CF_INDEX,
+    f32 => F32,
+    f64 => F64,
+}
+
+// Unsigned values are read through `i64` because Core Foundation has no
+// unsigned number types. Out-of-range values saturate in the approximation.
+macro_rules! impl_try_from_unsigned {
+    ($($t:ty,)+) => {
+        $(
+            impl TryFrom<&CFNumber> for $t {
+                type Error = CFNumberLossyConversion<$t>;
+
+                #[inline]
+                fn try_from(number: &CFNumber) -> Result<Self, Self::Error> {
+                    let (value, is_exact) = match number.to_i64() {
+                        Ok(value) => (value, true),
+                        Err(error) => (error.approximation(), false),
+                    };
+
+                    match <$t>::try_from(value) {
+                        Ok(value) if is_exact => Ok(value),
+                        Ok(value) => Err(CFNumberLossyConversion::new(value)),
+                        Err(_) if value < 0 => Err(CFNumberLossyConversion::new(0)),
+                        Err(_) => Err(CFNumberLossyConversion::new(<$t>::MAX)),
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_try_from_unsigned! {
+    u8,
+    u16,
+    u32,
+    u64,
+    usize,
+}
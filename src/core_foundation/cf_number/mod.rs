@@ -4,12 +4,16 @@ use crate::{
 };
 use std::{
     cmp::Ordering,
+    convert::TryFrom,
     mem::{self, MaybeUninit},
+    num::TryFromIntError,
     ptr,
 };
 
+mod conversion;
 mod type_;
 
+pub use conversion::*;
 pub use type_::*;
 
 use super::{CFIndex, CFTypeID};
@@ -83,6 +87,52 @@ impl From<isize> for Arc<CFNumber> {
     }
 }
 
+// Core Foundation has no unsigned number types, so unsigned integers are
+// stored in the next larger signed type.
+
+impl From<u8> for Arc<CFNumber> {
+    #[inline]
+    fn from(value: u8) -> Self {
+        (value as i16).into()
+    }
+}
+
+impl From<u16> for Arc<CFNumber> {
+    #[inline]
+    fn from(value: u16) -> Self {
+        (value as i32).into()
+    }
+}
+
+impl From<u32> for Arc<CFNumber> {
+    #[inline]
+    fn from(value: u32) -> Self {
+        (value as i64).into()
+    }
+}
+
+impl TryFrom<u64> for Arc<CFNumber> {
+    type Error = TryFromIntError;
+
+    /// Fails if `value` is greater than [`i64::MAX`], the largest value that
+    /// Core Foundation can represent exactly.
+    #[inline]
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        Ok(i64::try_from(value)?.into())
+    }
+}
+
+impl TryFrom<usize> for Arc<CFNumber> {
+    type Error = TryFromIntError;
+
+    /// Fails if `value` is greater than [`i64::MAX`], the largest value that
+    /// Core Foundation can represent exactly.
+    #[inline]
+    fn try_from(value: usize) -> Result<Self, Self::Error> {
+        Ok(i64::try_from(value)?.into())
+    }
+}
+
 impl From<f32> for Arc<CFNumber> {
     #[inline]
     fn from(value: f32) -> Self {
@@ -246,6 +296,44 @@ impl CFNumber {
         }
     }
 
+    /// Returns the value of this object converted to `T`, or an approximation
+    /// if the conversion is lossy or out of range.
+    ///
+    /// # Safety
+    ///
+    /// The generic type `T` must have a valid representation for the requested
+    /// [`CFNumberType`].
+    #[inline]
+    pub(crate) unsafe fn try_get_value<T>(
+        &self,
+        number_type: CFNumberType,
+    ) -> Result<T, CFNumberLossyConversion<T>> {
+        let mut value = MaybeUninit::<T>::uninit();
+
+        let is_exact = sys::CFNumberGetValue(self, number_type, value.as_mut_ptr().cast()) != 0;
+        let value = value.assume_init();
+
+        if is_exact {
+            Ok(value)
+        } else {
+            Err(CFNumberLossyConversion::new(value))
+        }
+    }
+
+    /// Returns the value of this object as an `i64`, or an error with the
+    /// closest `i64` if the value is fractional or out of range.
+    #[inline]
+    pub fn to_i64(&self) -> Result<i64, CFNumberLossyConversion<i64>> {
+        unsafe { self.try_get_value(CFNumberType::I64) }
+    }
+
+    /// Returns the value of this object as an `f64`, or an error with the
+    /// closest `f64` if the value cannot be represented exactly.
+    #[inline]
+    pub fn to_f64(&self) -> Result<f64, CFNumberLossyConversion<f64>> {
+        unsafe { self.try_get_value(CFNumberType::F64) }
+    }
+
     /// Returns the value of this object cast to `i8`.
     #[inline]
     pub fn i8_value(&self) -> Option<i8> {
@@ -0,0 +1,206 @@
+use super::{sys, CFArray, CFPropertyList, CFString};
+use crate::core::Arc;
+use std::ptr;
+
+/// Functions for reading and writing preferences, such as those of other
+/// apps, without going through `defaults(1)`.
+///
+/// Preferences are stored per application ID (a bundle identifier), user,
+/// and host. The `app_*` functions use the current user on any host, which is
+/// where apps store their defaults.
+///
+/// Changes are cached in memory until they are written with
+/// [`app_synchronize`](Self::app_synchronize) or
+/// [`synchronize`](Self::synchronize).
+///
+/// Documentation:
+/// [Swift](https://developer.apple.com/documentation/corefoundation/preferences_utilities?language=swift) |
+/// [Objective-C](https://developer.apple.com/documentation/corefoundation/preferences_utilities?language=objc)
+#[derive(Debug)]
+pub enum CFPreferences {}
+
+/// Domain constants.
+impl CFPreferences {
+    /// The application ID of the current process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/kcfpreferencescurrentapplication).
+    #[inline]
+    #[doc(alias = "kCFPreferencesCurrentApplication")]
+    pub fn current_application() -> &'static CFString {
+        extern "C" {
+            static kCFPreferencesCurrentApplication: &'static CFString;
+        }
+        unsafe { kCFPreferencesCurrentApplication }
+    }
+
+    /// The application ID for preferences shared by all applications.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/kcfpreferencesanyapplication).
+    #[inline]
+    #[doc(alias = "kCFPreferencesAnyApplication")]
+    pub fn any_application() -> &'static CFString {
+        extern "C" {
+            static kCFPreferencesAnyApplication: &'static CFString;
+        }
+        unsafe { kCFPreferencesAnyApplication }
+    }
+
+    /// The user name for the current user.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/kcfpreferencescurrentuser).
+    #[inline]
+    #[doc(alias = "kCFPreferencesCurrentUser")]
+    pub fn current_user() -> &'static CFString {
+        extern "C" {
+            static kCFPreferencesCurrentUser: &'static CFString;
+        }
+        unsafe { kCFPreferencesCurrentUser }
+    }
+
+    /// The user name for preferences shared by all users.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/kcfpreferencesanyuser).
+    #[inline]
+    #[doc(alias = "kCFPreferencesAnyUser")]
+    pub fn any_user() -> &'static CFString {
+        extern "C" {
+            static kCFPreferencesAnyUser: &'static CFString;
+        }
+        unsafe { kCFPreferencesAnyUser }
+    }
+
+    /// The host name for the current host.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/kcfpreferencescurrenthost).
+    #[inline]
+    #[doc(alias = "kCFPreferencesCurrentHost")]
+    pub fn current_host() -> &'static CFString {
+        extern "C" {
+            static kCFPreferencesCurrentHost: &'static CFString;
+        }
+        unsafe { kCFPreferencesCurrentHost }
+    }
+
+    /// The host name for preferences shared by all hosts.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/kcfpreferencesanyhost).
+    #[inline]
+    #[doc(alias = "kCFPreferencesAnyHost")]
+    pub fn any_host() -> &'static CFString {
+        extern "C" {
+            static kCFPreferencesAnyHost: &'static CFString;
+        }
+        unsafe { kCFPreferencesAnyHost }
+    }
+}
+
+impl CFPreferences {
+    /// Returns the value for `key` in the preferences of `app_id`, searching
+    /// all domains that apply to the current user.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfpreferencescopyappvalue(_:_:)).
+    #[inline]
+    #[doc(alias = "CFPreferencesCopyAppValue")]
+    pub fn app_value(key: &CFString, app_id: &CFString) -> Option<CFPropertyList> {
+        unsafe { copied_value(sys::CFPreferencesCopyAppValue(key, app_id)) }
+    }
+
+    /// Sets or removes the value for `key` in the preferences of `app_id` for
+    /// the current user on any host.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfpreferencessetappvalue(_:_:_:)).
+    #[inline]
+    #[doc(alias = "CFPreferencesSetAppValue")]
+    pub fn set_app_value(key: &CFString, value: Option<&CFPropertyList>, app_id: &CFString) {
+        let value = match value {
+            Some(value) => value.as_cf_type(),
+            None => ptr::null(),
+        };
+        unsafe { sys::CFPreferencesSetAppValue(key, value, app_id) }
+    }
+
+    /// Writes changes to the preferences of `app_id` to permanent storage and
+    /// reads in changes made by other processes.
+    ///
+    /// Returns `false` if writing failed.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfpreferencesappsynchronize(_:)).
+    #[inline]
+    #[doc(alias = "CFPreferencesAppSynchronize")]
+    pub fn app_synchronize(app_id: &CFString) -> bool {
+        unsafe { sys::CFPreferencesAppSynchronize(app_id) != 0 }
+    }
+
+    /// Returns the value for `key` in exactly the domain given by `app_id`,
+    /// `user`, and `host`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfpreferencescopyvalue(_:_:_:_:)).
+    #[inline]
+    #[doc(alias = "CFPreferencesCopyValue")]
+    pub fn value(
+        key: &CFString,
+        app_id: &CFString,
+        user: &CFString,
+        host: &CFString,
+    ) -> Option<CFPropertyList> {
+        unsafe { copied_value(sys::CFPreferencesCopyValue(key, app_id, user, host)) }
+    }
+
+    /// Sets or removes the value for `key` in exactly the domain given by
+    /// `app_id`, `user`, and `host`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfpreferencessetvalue(_:_:_:_:_:)).
+    #[inline]
+    #[doc(alias = "CFPreferencesSetValue")]
+    pub fn set_value(
+        key: &CFString,
+        value: Option<&CFPropertyList>,
+        app_id: &CFString,
+        user: &CFString,
+        host: &CFString,
+    ) {
+        let value = match value {
+            Some(value) => value.as_cf_type(),
+            None => ptr::null(),
+        };
+        unsafe { sys::CFPreferencesSetValue(key, value, app_id, user, host) }
+    }
+
+    /// Writes changes to the domain given by `app_id`, `user`, and `host` to
+    /// permanent storage and reads in changes made by other processes.
+    ///
+    /// Returns `false` if writing failed.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfpreferencessynchronize(_:_:_:)).
+    #[inline]
+    #[doc(alias = "CFPreferencesSynchronize")]
+    pub fn synchronize(app_id: &CFString, user: &CFString, host: &CFString) -> bool {
+        unsafe { sys::CFPreferencesSynchronize(app_id, user, host) != 0 }
+    }
+
+    /// Returns the keys that have values in exactly the domain given by
+    /// `app_id`, `user`, and `host`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfpreferencescopykeylist(_:_:_:)).
+    #[inline]
+    #[doc(alias = "CFPreferencesCopyKeyList")]
+    pub fn key_list(app_id: &CFString, user: &CFString, host: &CFString) -> Arc<CFArray<CFString>> {
+        unsafe {
+            let keys = sys::CFPreferencesCopyKeyList(app_id, user, host);
+            if keys.is_null() {
+                CFArray::from_slice(&[])
+            } else {
+                Arc::from_raw(keys.cast())
+            }
+        }
+    }
+}
+
+#[inline]
+unsafe fn copied_value(value: *const super::CFType<'static>) -> Option<CFPropertyList> {
+    if value.is_null() {
+        None
+    } else {
+        CFPropertyList::from_cf_type(Arc::from_raw(value))
+    }
+}
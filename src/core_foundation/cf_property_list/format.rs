@@ -0,0 +1,22 @@
+use crate::core_foundation::CFIndex;
+
+/// The format of serialized property list data.
+///
+/// See [documentation](https://developer.apple.com/documentation/corefoundation/cfpropertylistformat).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CFPropertyListFormat(pub CFIndex);
+
+impl CFPropertyListFormat {
+    /// The legacy OpenStep format, which can be read but not written.
+    #[doc(alias = "kCFPropertyListOpenStepFormat")]
+    pub const OPEN_STEP: Self = Self(1);
+
+    /// The XML format, version 1.0.
+    #[doc(alias = "kCFPropertyListXMLFormat_v1_0")]
+    pub const XML_V1_0: Self = Self(100);
+
+    /// The binary format, version 1.0.
+    #[doc(alias = "kCFPropertyListBinaryFormat_v1_0")]
+    pub const BINARY_V1_0: Self = Self(200);
+}
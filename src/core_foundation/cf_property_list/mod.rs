@@ -0,0 +1,220 @@
+use super::{
+    sys, CFArray, CFBoolean, CFData, CFDate, CFDictionary, CFError, CFNumber, CFString, CFType,
+};
+use crate::core::Arc;
+use std::{fmt, ptr};
+
+mod format;
+mod mutability_options;
+
+#[cfg(feature = "serde")]
+mod serde;
+
+pub use format::*;
+pub use mutability_options::*;
+
+#[cfg(feature = "serde")]
+pub use self::serde::{from_cf_propertylist, to_cf_propertylist, CFSerdeError};
+
+/// A value that can be stored in a property list, such as a preferences
+/// file or an `Info.plist`.
+///
+/// Arrays and dictionaries may only contain other property list values, and
+/// dictionary keys must be strings. This is not enforced by the types of
+/// [`Array`](Self::Array) and [`Dictionary`](Self::Dictionary), so nested
+/// values should be read with [`from_cf_type`](Self::from_cf_type).
+///
+/// Documentation:
+/// [Swift](https://developer.apple.com/documentation/corefoundation/cfpropertylist?language=swift) |
+/// [Objective-C](https://developer.apple.com/documentation/corefoundation/cfpropertylistref?language=objc)
+#[derive(Clone)]
+pub enum CFPropertyList {
+    /// A string.
+    String(Arc<CFString>),
+    /// An integer or floating-point number.
+    Number(Arc<CFNumber>),
+    /// A boolean.
+    Boolean(Arc<CFBoolean>),
+    /// A date.
+    Date(Arc<CFDate>),
+    /// A buffer of bytes.
+    Data(Arc<CFData>),
+    /// An array of property list values.
+    Array(Arc<CFArray>),
+    /// A dictionary of property list values keyed by strings.
+    Dictionary(Arc<CFDictionary>),
+}
+
+impl PartialEq for CFPropertyList {
+    #[inline]
+    #[doc(alias = "CFEqual")]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_cf_type() == other.as_cf_type()
+    }
+}
+
+impl fmt::Debug for CFPropertyList {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Nested values are untyped, so they are converted for formatting.
+        fn debug_value(value: &CFType<'static>) -> impl fmt::Debug {
+            CFPropertyList::from_cf_type(Arc::retain(value))
+        }
+
+        match self {
+            Self::String(string) => string.fmt(f),
+            Self::Number(number) => match number.to_i64() {
+                Ok(int) => int.fmt(f),
+                Err(_) => number.f64_value().unwrap_or(f64::NAN).fmt(f),
+            },
+            Self::Boolean(boolean) => boolean.fmt(f),
+            Self::Date(date) => date.fmt(f),
+            Self::Data(data) => data.as_bytes().fmt(f),
+            Self::Array(array) => f
+                .debug_list()
+                .entries(array.iter().map(debug_value))
+                .finish(),
+            Self::Dictionary(dictionary) => f
+                .debug_map()
+                .entries(
+                    dictionary
+                        .iter()
+                        .map(|(key, value)| (debug_value(key), debug_value(value))),
+                )
+                .finish(),
+        }
+    }
+}
+
+macro_rules! impl_from {
+    ($($variant:ident($t:ty),)+) => {
+        $(
+            impl From<Arc<$t>> for CFPropertyList {
+                #[inline]
+                fn from(value: Arc<$t>) -> Self {
+                    Self::$variant(value)
+                }
+            }
+        )+
+    };
+}
+
+impl_from! {
+    String(CFString),
+    Number(CFNumber),
+    Boolean(CFBoolean),
+    Date(CFDate),
+    Data(CFData),
+    Array(CFArray),
+    Dictionary(CFDictionary),
+}
+
+impl CFPropertyList {
+    /// Returns `value` as a property list value, or [`None`] if its type
+    /// cannot be stored in a property list.
+    pub fn from_cf_type(value: Arc<CFType<'static>>) -> Option<Self> {
+        let type_id = value.get_type_id();
+
+        unsafe {
+            Some(if type_id == CFString::type_id() {
+                Self::String(Arc::cast_unchecked(value))
+            } else if type_id == CFNumber::type_id() {
+                Self::Number(Arc::cast_unchecked(value))
+            } else if type_id == CFBoolean::type_id() {
+                Self::Boolean(Arc::cast_unchecked(value))
+            } else if type_id == CFDate::type_id() {
+                Self::Date(Arc::cast_unchecked(value))
+            } else if type_id == CFData::type_id() {
+                Self::Data(Arc::cast_unchecked(value))
+            } else if type_id == <CFArray>::type_id() {
+                Self::Array(Arc::cast_unchecked(value))
+            } else if type_id == <CFDictionary>::type_id() {
+                Self::Dictionary(Arc::cast_unchecked(value))
+            } else {
+                return None;
+            })
+        }
+    }
+
+    /// Returns the underlying object.
+    #[inline]
+    pub fn as_cf_type(&self) -> &CFType<'static> {
+        match self {
+            Self::String(value) => value,
+            Self::Number(value) => value,
+            Self::Boolean(value) => value,
+            Self::Date(value) => value,
+            Self::Data(value) => value,
+            Self::Array(value) => value,
+            Self::Dictionary(value) => value,
+        }
+    }
+
+    /// Deserializes a property list from `data` in any supported format,
+    /// returning the value and the format it was stored in.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfpropertylistcreatewithdata(_:_:_:_:_:)).
+    #[inline]
+    #[doc(alias = "CFPropertyListCreateWithData")]
+    pub fn from_data(
+        data: &CFData,
+        options: CFPropertyListMutabilityOptions,
+    ) -> Result<(Self, CFPropertyListFormat), Arc<CFError>> {
+        unsafe {
+            let mut format = CFPropertyListFormat::BINARY_V1_0;
+            let mut error = ptr::null();
+            let value = sys::CFPropertyListCreateWithData(
+                ptr::null(),
+                data,
+                options,
+                &mut format,
+                &mut error,
+            );
+
+            if value.is_null() {
+                return Err(Arc::from_raw(error));
+            }
+
+            let value = Self::from_cf_type(Arc::from_raw(value))
+                .expect("Core Foundation returned a non-property list type");
+            Ok((value, format))
+        }
+    }
+
+    /// Serializes `self` in `format`.
+    ///
+    /// This fails if `self` contains values that cannot be stored in a
+    /// property list, or if `format` is
+    /// [`OPEN_STEP`](CFPropertyListFormat::OPEN_STEP).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfpropertylistcreatedata(_:_:_:_:_:)).
+    #[inline]
+    #[doc(alias = "CFPropertyListCreateData")]
+    pub fn to_data(&self, format: CFPropertyListFormat) -> Result<Arc<CFData>, Arc<CFError>> {
+        unsafe {
+            let mut error = ptr::null();
+            let data = sys::CFPropertyListCreateData(
+                ptr::null(),
+                self.as_cf_type(),
+                format,
+                0,
+                &mut error,
+            );
+
+            if data.is_null() {
+                Err(Arc::from_raw(error))
+            } else {
+                Ok(Arc::from_raw(data))
+            }
+        }
+    }
+
+    /// Returns `true` if `self`, including all nested values, can be
+    /// serialized in `format`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfpropertylistisvalid(_:_:)).
+    #[inline]
+    #[doc(alias = "CFPropertyListIsValid")]
+    pub fn is_valid(&self, format: CFPropertyListFormat) -> bool {
+        unsafe { sys::CFPropertyListIsValid(self.as_cf_type(), format) != 0 }
+    }
+}
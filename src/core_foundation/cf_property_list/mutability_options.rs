@@ -0,0 +1,22 @@
+use crate::core_foundation::CFOptionFlags;
+
+/// Which objects in a deserialized property list are created mutable.
+///
+/// See [documentation](https://developer.apple.com/documentation/corefoundation/cfpropertylistmutabilityoptions).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CFPropertyListMutabilityOptions(pub CFOptionFlags);
+
+impl CFPropertyListMutabilityOptions {
+    /// All objects are immutable.
+    #[doc(alias = "kCFPropertyListImmutable")]
+    pub const IMMUTABLE: Self = Self(0);
+
+    /// Arrays and dictionaries are mutable.
+    #[doc(alias = "kCFPropertyListMutableContainers")]
+    pub const MUTABLE_CONTAINERS: Self = Self(1);
+
+    /// Arrays, dictionaries, strings, and data are mutable.
+    #[doc(alias = "kCFPropertyListMutableContainersAndLeaves")]
+    pub const MUTABLE_CONTAINERS_AND_LEAVES: Self = Self(2);
+}
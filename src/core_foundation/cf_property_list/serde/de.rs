@@ -0,0 +1,230 @@
+use super::{CFSerdeError, DATE_TOKEN};
+use crate::core::Arc;
+use crate::core_foundation::{CFArray, CFIndex, CFPropertyList, CFType};
+use serde::de::{self, IntoDeserializer, Visitor};
+
+type Result<T> = std::result::Result<T, CFSerdeError>;
+
+/// Returns `value` as a property list value.
+fn property_list(value: &CFType<'static>) -> Result<CFPropertyList> {
+    CFPropertyList::from_cf_type(Arc::retain(value)).ok_or(CFSerdeError::InvalidType)
+}
+
+/// Deserializes values from a property list.
+pub(super) struct Deserializer {
+    value: CFPropertyList,
+}
+
+impl Deserializer {
+    #[inline]
+    pub(super) fn new(value: CFPropertyList) -> Self {
+        Self { value }
+    }
+}
+
+impl<'de> de::Deserializer<'de> for Deserializer {
+    type Error = CFSerdeError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match self.value {
+            CFPropertyList::String(string) => visitor.visit_string(string.to_string()),
+            CFPropertyList::Number(number) => {
+                if number.is_float_type() {
+                    visitor.visit_f64(number.f64_value().unwrap_or(f64::NAN))
+                } else {
+                    match number.to_i64() {
+                        Ok(int) => visitor.visit_i64(int),
+                        Err(error) => visitor.visit_f64(error.approximation() as f64),
+                    }
+                }
+            }
+            CFPropertyList::Boolean(boolean) => visitor.visit_bool(boolean.as_bool()),
+            CFPropertyList::Date(date) => visitor.visit_f64(date.absolute_time()),
+            CFPropertyList::Data(data) => visitor.visit_bytes(data.as_bytes()),
+            CFPropertyList::Array(array) => visitor.visit_seq(ArrayAccess { array, index: 0 }),
+            CFPropertyList::Dictionary(dictionary) => {
+                let entries = dictionary
+                    .iter()
+                    .map(|(key, value)| (Arc::retain(key), Arc::retain(value)))
+                    .collect::<Vec<_>>();
+                visitor.visit_map(DictionaryAccess {
+                    entries: entries.into_iter(),
+                    value: None,
+                })
+            }
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        // Property lists have no null value, so a value is always present.
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        match self.value {
+            CFPropertyList::Date(date) if name == DATE_TOKEN => {
+                visitor.visit_newtype_struct(date.absolute_time().into_deserializer())
+            }
+            _ => visitor.visit_newtype_struct(self),
+        }
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        match self.value {
+            CFPropertyList::String(string) => {
+                visitor.visit_enum(string.to_string().into_deserializer())
+            }
+            CFPropertyList::Dictionary(dictionary) => {
+                let mut entries = dictionary.iter();
+                match (entries.next(), entries.next()) {
+                    (Some((key, value)), None) => {
+                        let name = match property_list(key)? {
+                            CFPropertyList::String(name) => name.to_string(),
+                            _ => return Err(CFSerdeError::InvalidKey),
+                        };
+                        visitor.visit_enum(VariantAccess {
+                            name,
+                            value: property_list(value)?,
+                        })
+                    }
+                    _ => Err(de::Error::custom(
+                        "enum variant dictionaries must have exactly one key",
+                    )),
+                }
+            }
+            _ => Err(de::Error::custom(
+                "enum variants must be strings or dictionaries",
+            )),
+        }
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_unit()
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf seq tuple tuple_struct map struct identifier
+    }
+}
+
+/// Reads the items of an array.
+struct ArrayAccess {
+    array: Arc<CFArray>,
+    index: usize,
+}
+
+impl<'de> de::SeqAccess<'de> for ArrayAccess {
+    type Error = CFSerdeError;
+
+    fn next_element_seed<T: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>> {
+        let item = match self.array.get(self.index as CFIndex) {
+            Some(item) => property_list(item)?,
+            None => return Ok(None),
+        };
+        self.index += 1;
+        seed.deserialize(Deserializer::new(item)).map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.array.len() as usize - self.index)
+    }
+}
+
+/// Reads the keys and values of a dictionary.
+struct DictionaryAccess {
+    entries: std::vec::IntoIter<(Arc<CFType<'static>>, Arc<CFType<'static>>)>,
+    /// The value for the key that was read last.
+    value: Option<Arc<CFType<'static>>>,
+}
+
+impl<'de> de::MapAccess<'de> for DictionaryAccess {
+    type Error = CFSerdeError;
+
+    fn next_key_seed<K: de::DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>> {
+        let (key, value) = match self.entries.next() {
+            Some(entry) => entry,
+            None => return Ok(None),
+        };
+        self.value = Some(value);
+        seed.deserialize(Deserializer::new(property_list(&key)?))
+            .map(Some)
+    }
+
+    fn next_value_seed<V: de::DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value> {
+        match self.value.take() {
+            Some(value) => seed.deserialize(Deserializer::new(property_list(&value)?)),
+            None => Err(de::Error::custom("dictionary value without key")),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.entries.len())
+    }
+}
+
+/// Reads an enum variant from a dictionary with the variant name as its only
+/// key.
+struct VariantAccess {
+    name: String,
+    value: CFPropertyList,
+}
+
+impl<'de> de::EnumAccess<'de> for VariantAccess {
+    type Error = CFSerdeError;
+    type Variant = Self;
+
+    fn variant_seed<V: de::DeserializeSeed<'de>>(self, seed: V) -> Result<(V::Value, Self)> {
+        let name: de::value::StrDeserializer<CFSerdeError> = self.name.as_str().into_deserializer();
+        let variant = seed.deserialize(name)?;
+        Ok((variant, self))
+    }
+}
+
+impl<'de> de::VariantAccess<'de> for VariantAccess {
+    type Error = CFSerdeError;
+
+    fn unit_variant(self) -> Result<()> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T: de::DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value> {
+        seed.deserialize(Deserializer::new(self.value))
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value> {
+        de::Deserializer::deserialize_seq(Deserializer::new(self.value), visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        de::Deserializer::deserialize_map(Deserializer::new(self.value), visitor)
+    }
+}
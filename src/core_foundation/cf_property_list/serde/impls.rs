@@ -0,0 +1,349 @@
+use super::DATE_TOKEN;
+use crate::core::{Arc, ObjectType};
+use crate::core_foundation::{
+    CFArray, CFBoolean, CFData, CFDate, CFDictionary, CFNumber, CFPropertyList, CFString, CFType,
+};
+use serde::{
+    de::{self, Deserialize, Deserializer, Visitor},
+    ser::{self, Serialize, Serializer},
+};
+use std::{convert::TryFrom, fmt, marker::PhantomData};
+
+impl Serialize for CFString {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_cow_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Arc<CFString> {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = String::deserialize(deserializer)?;
+        Ok(CFString::new(&string))
+    }
+}
+
+impl Serialize for CFNumber {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if self.is_float_type() {
+            serializer.serialize_f64(self.f64_value().unwrap_or(f64::NAN))
+        } else {
+            match self.to_i64() {
+                Ok(int) => serializer.serialize_i64(int),
+                Err(error) => serializer.serialize_f64(error.approximation() as f64),
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Arc<CFNumber> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct NumberVisitor;
+
+        impl<'de> Visitor<'de> for NumberVisitor {
+            type Value = Arc<CFNumber>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a number")
+            }
+
+            fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+                Ok(v.into())
+            }
+
+            fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+                Arc::<CFNumber>::try_from(v).map_err(E::custom)
+            }
+
+            fn visit_f64<E: de::Error>(self, v: f64) -> Result<Self::Value, E> {
+                Ok(v.into())
+            }
+        }
+
+        deserializer.deserialize_any(NumberVisitor)
+    }
+}
+
+impl Serialize for CFBoolean {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bool(self.as_bool())
+    }
+}
+
+impl<'de> Deserialize<'de> for Arc<CFBoolean> {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = bool::deserialize(deserializer)?;
+        Ok(Arc::retain(CFBoolean::new(value)))
+    }
+}
+
+impl Serialize for CFData {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(self.as_bytes())
+    }
+}
+
+impl<'de> Deserialize<'de> for Arc<CFData> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct DataVisitor;
+
+        impl<'de> Visitor<'de> for DataVisitor {
+            type Value = Arc<CFData>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("bytes")
+            }
+
+            fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+                Ok(CFData::from_bytes(None, v))
+            }
+
+            // Formats without a bytes type, such as JSON, write arrays.
+            fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+                while let Some(byte) = seq.next_element()? {
+                    bytes.push(byte);
+                }
+                Ok(CFData::from_bytes(None, &bytes))
+            }
+        }
+
+        deserializer.deserialize_byte_buf(DataVisitor)
+    }
+}
+
+/// Dates are serialized as a newtype struct around their
+/// [`CFAbsoluteTime`](crate::core_foundation::CFAbsoluteTime), which
+/// [`to_cf_propertylist`](super::to_cf_propertylist) turns back into a date.
+impl Serialize for CFDate {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_newtype_struct(DATE_TOKEN, &self.absolute_time())
+    }
+}
+
+impl<'de> Deserialize<'de> for Arc<CFDate> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct DateVisitor;
+
+        impl<'de> Visitor<'de> for DateVisitor {
+            type Value = Arc<CFDate>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a date or an absolute time")
+            }
+
+            fn visit_newtype_struct<D: Deserializer<'de>>(
+                self,
+                deserializer: D,
+            ) -> Result<Self::Value, D::Error> {
+                let at = f64::deserialize(deserializer)?;
+                Ok(CFDate::new(None, at))
+            }
+
+            fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+                Ok(CFDate::new(None, v as f64))
+            }
+
+            fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+                Ok(CFDate::new(None, v as f64))
+            }
+
+            fn visit_f64<E: de::Error>(self, v: f64) -> Result<Self::Value, E> {
+                Ok(CFDate::new(None, v))
+            }
+        }
+
+        deserializer.deserialize_newtype_struct(DATE_TOKEN, DateVisitor)
+    }
+}
+
+impl<T: ObjectType + Serialize> Serialize for CFArray<T> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+impl<'de, T: ObjectType> Deserialize<'de> for Arc<CFArray<T>>
+where
+    Arc<T>: Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let items = Vec::<Arc<T>>::deserialize(deserializer)?;
+        let items: Vec<&T> = items.iter().map(|item| &**item).collect();
+        Ok(CFArray::from_slice(&items))
+    }
+}
+
+impl<K, V> Serialize for CFDictionary<K, V>
+where
+    K: ObjectType + Serialize,
+    V: ObjectType + Serialize,
+{
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.iter())
+    }
+}
+
+impl<'de, K, V> Deserialize<'de> for Arc<CFDictionary<K, V>>
+where
+    K: ObjectType,
+    V: ObjectType,
+    Arc<K>: Deserialize<'de>,
+    Arc<V>: Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct DictionaryVisitor<K, V>(PhantomData<(K, V)>);
+
+        impl<'de, K, V> Visitor<'de> for DictionaryVisitor<K, V>
+        where
+            K: ObjectType,
+            V: ObjectType,
+            Arc<K>: Deserialize<'de>,
+            Arc<V>: Deserialize<'de>,
+        {
+            type Value = Arc<CFDictionary<K, V>>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a map")
+            }
+
+            fn visit_map<A: de::MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let mut keys = Vec::<Arc<K>>::new();
+                let mut values = Vec::<Arc<V>>::new();
+                while let Some((key, value)) = map.next_entry()? {
+                    keys.push(key);
+                    values.push(value);
+                }
+
+                let keys: Vec<&K> = keys.iter().map(|key| &**key).collect();
+                let values: Vec<&V> = values.iter().map(|value| &**value).collect();
+                Ok(CFDictionary::create(None, &keys, &values))
+            }
+        }
+
+        deserializer.deserialize_map(DictionaryVisitor::<K, V>(PhantomData))
+    }
+}
+
+/// Objects that are not property list types fail to serialize.
+impl Serialize for CFType<'static> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match CFPropertyList::from_cf_type(Arc::retain(self)) {
+            Some(value) => value.serialize(serializer),
+            None => Err(ser::Error::custom("object is not a property list type")),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Arc<CFType<'static>> {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = CFPropertyList::deserialize(deserializer)?;
+        Ok(Arc::retain(value.as_cf_type()))
+    }
+}
+
+impl Serialize for CFPropertyList {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::String(value) => value.serialize(serializer),
+            Self::Number(value) => value.serialize(serializer),
+            Self::Boolean(value) => value.serialize(serializer),
+            Self::Date(value) => value.serialize(serializer),
+            Self::Data(value) => value.serialize(serializer),
+            Self::Array(value) => value.serialize(serializer),
+            Self::Dictionary(value) => value.serialize(serializer),
+        }
+    }
+}
+
+/// Values without a property list representation, such as `null`, fail to
+/// deserialize.
+impl<'de> Deserialize<'de> for CFPropertyList {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct PropertyListVisitor;
+
+        impl<'de> Visitor<'de> for PropertyListVisitor {
+            type Value = CFPropertyList;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a property list value")
+            }
+
+            fn visit_bool<E: de::Error>(self, v: bool) -> Result<Self::Value, E> {
+                Ok(CFPropertyList::Boolean(Arc::retain(CFBoolean::new(v))))
+            }
+
+            fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+                Ok(CFPropertyList::Number(v.into()))
+            }
+
+            fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+                let number = Arc::<CFNumber>::try_from(v).map_err(E::custom)?;
+                Ok(CFPropertyList::Number(number))
+            }
+
+            fn visit_f64<E: de::Error>(self, v: f64) -> Result<Self::Value, E> {
+                Ok(CFPropertyList::Number(v.into()))
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                Ok(CFPropertyList::String(CFString::new(v)))
+            }
+
+            fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+                Ok(CFPropertyList::Data(CFData::from_bytes(None, v)))
+            }
+
+            fn visit_some<D: Deserializer<'de>>(
+                self,
+                deserializer: D,
+            ) -> Result<Self::Value, D::Error> {
+                CFPropertyList::deserialize(deserializer)
+            }
+
+            fn visit_newtype_struct<D: Deserializer<'de>>(
+                self,
+                deserializer: D,
+            ) -> Result<Self::Value, D::Error> {
+                CFPropertyList::deserialize(deserializer)
+            }
+
+            fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut items = Vec::<CFPropertyList>::new();
+                while let Some(item) = seq.next_element()? {
+                    items.push(item);
+                }
+
+                let items: Vec<&CFType<'static>> =
+                    items.iter().map(|item| item.as_cf_type()).collect();
+                Ok(CFPropertyList::Array(CFArray::from_slice(&items)))
+            }
+
+            fn visit_map<A: de::MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let mut keys = Vec::<Arc<CFString>>::new();
+                let mut values = Vec::<CFPropertyList>::new();
+                while let Some((key, value)) = map.next_entry()? {
+                    keys.push(key);
+                    values.push(value);
+                }
+
+                let keys: Vec<&CFType<'static>> = keys.iter().map(|key| &***key).collect();
+                let values: Vec<&CFType<'static>> =
+                    values.iter().map(|value| value.as_cf_type()).collect();
+                Ok(CFPropertyList::Dictionary(CFDictionary::create(
+                    None, &keys, &values,
+                )))
+            }
+        }
+
+        deserializer.deserialize_any(PropertyListVisitor)
+    }
+}
@@ -0,0 +1,126 @@
+use super::CFPropertyList;
+use serde::{de::DeserializeOwned, Serialize};
+use std::{error::Error, fmt};
+
+mod de;
+mod impls;
+mod ser;
+
+/// The newtype struct name that [`CFDate`](crate::core_foundation::CFDate)
+/// values are serialized with, so that they can be told apart from numbers.
+///
+/// Other serializers see a newtype struct around the
+/// [`CFAbsoluteTime`](crate::core_foundation::CFAbsoluteTime) of the date.
+const DATE_TOKEN: &str = "$fruity::CFDate";
+
+/// An error from converting between Rust values and [`CFPropertyList`] values
+/// with [`serde`](https://docs.rs/serde).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CFSerdeError {
+    /// A Rust value has no property list representation, such as `()` or a
+    /// `None` that is not a struct field.
+    Unsupported(&'static str),
+
+    /// A map key is not a string.
+    InvalidKey,
+
+    /// An unsigned integer is greater than [`i64::MAX`], the largest value
+    /// that [`CFNumber`](crate::core_foundation::CFNumber) can represent
+    /// exactly.
+    NumberOutOfRange(u64),
+
+    /// An object is not one of the property list types.
+    InvalidType,
+
+    /// An error reported by a `Serialize` or `Deserialize` implementation.
+    Custom(String),
+}
+
+impl fmt::Display for CFSerdeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Unsupported(what) => {
+                write!(f, "{} cannot be stored in a property list", what)
+            }
+            Self::InvalidKey => f.write_str("property list dictionary keys must be strings"),
+            Self::NumberOutOfRange(value) => {
+                write!(f, "{} is too large for a property list number", value)
+            }
+            Self::InvalidType => f.write_str("object is not a property list type"),
+            Self::Custom(message) => f.write_str(message),
+        }
+    }
+}
+
+impl Error for CFSerdeError {}
+
+impl serde::ser::Error for CFSerdeError {
+    #[inline]
+    fn custom<T: fmt::Display>(message: T) -> Self {
+        Self::Custom(message.to_string())
+    }
+}
+
+impl serde::de::Error for CFSerdeError {
+    #[inline]
+    fn custom<T: fmt::Display>(message: T) -> Self {
+        Self::Custom(message.to_string())
+    }
+}
+
+/// Converts a value into a property list, such as a dictionary for
+/// [`CFPreferences`](crate::core_foundation::CFPreferences) or a Keychain
+/// query.
+///
+/// Structs and maps become dictionaries, and sequences and tuples become
+/// arrays. Unit enum variants become strings, and other enum variants become
+/// dictionaries with the variant name as their only key. Struct fields that
+/// are `None` are left out, since property lists have no null value.
+///
+/// This is enabled by the **`serde`** feature flag.
+///
+/// # Examples
+///
+/// ```
+/// use fruity::core_foundation::{to_cf_propertylist, CFPropertyList};
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Window {
+///     width: u32,
+///     height: u32,
+///     title: Option<String>,
+/// }
+///
+/// let plist = to_cf_propertylist(&Window {
+///     width: 640,
+///     height: 480,
+///     title: None,
+/// })
+/// .unwrap();
+///
+/// assert!(matches!(plist, CFPropertyList::Dictionary(_)));
+/// ```
+#[inline]
+pub fn to_cf_propertylist<T: Serialize + ?Sized>(
+    value: &T,
+) -> Result<CFPropertyList, CFSerdeError> {
+    value
+        .serialize(ser::Serializer)?
+        .ok_or(CFSerdeError::Unsupported("a null value"))
+}
+
+/// Creates a value from a property list, such as one read with
+/// [`CFPropertyList::from_data`].
+///
+/// This follows the same mapping as [`to_cf_propertylist`]. Dates are read
+/// as their [`CFAbsoluteTime`](crate::core_foundation::CFAbsoluteTime)
+/// unless `T` expects a [`CFDate`](crate::core_foundation::CFDate).
+///
+/// This is enabled by the **`serde`** feature flag.
+#[inline]
+pub fn from_cf_propertylist<T: DeserializeOwned>(
+    plist: &CFPropertyList,
+) -> Result<T, CFSerdeError> {
+    T::deserialize(de::Deserializer::new(plist.clone()))
+}
@@ -0,0 +1,376 @@
+use super::{CFSerdeError, DATE_TOKEN};
+use crate::core::Arc;
+use crate::core_foundation::{
+    CFArray, CFBoolean, CFData, CFDate, CFDictionary, CFNumber, CFPropertyList, CFString, CFType,
+};
+use serde::ser::{self, Serialize};
+use std::convert::TryFrom;
+
+type Result<T> = std::result::Result<T, CFSerdeError>;
+
+/// Serializes values into property lists.
+///
+/// Values with no property list representation, such as `None`, serialize
+/// to `None` so that struct fields holding them can be skipped.
+pub(super) struct Serializer;
+
+/// Returns `value` or an error describing the null value.
+fn required(value: Option<CFPropertyList>) -> Result<CFPropertyList> {
+    value.ok_or(CFSerdeError::Unsupported("a null value"))
+}
+
+/// Creates a dictionary containing only `value` for `key`.
+fn single_key_dictionary(key: &str, value: &CFPropertyList) -> CFPropertyList {
+    let key = CFString::new(key);
+    let dictionary = CFDictionary::create(None, &[&**key], &[value.as_cf_type()]);
+    CFPropertyList::Dictionary(dictionary)
+}
+
+impl ser::Serializer for Serializer {
+    type Ok = Option<CFPropertyList>;
+    type Error = CFSerdeError;
+
+    type SerializeSeq = SerializeArray;
+    type SerializeTuple = SerializeArray;
+    type SerializeTupleStruct = SerializeArray;
+    type SerializeTupleVariant = SerializeArray;
+    type SerializeMap = SerializeDictionary;
+    type SerializeStruct = SerializeDictionary;
+    type SerializeStructVariant = SerializeDictionary;
+
+    fn serialize_bool(self, v: bool) -> Result<Self::Ok> {
+        let boolean = Arc::retain(CFBoolean::new(v));
+        Ok(Some(CFPropertyList::Boolean(boolean)))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Self::Ok> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Self::Ok> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Self::Ok> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Self::Ok> {
+        Ok(Some(CFPropertyList::Number(v.into())))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Self::Ok> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Self::Ok> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Self::Ok> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Self::Ok> {
+        let number = Arc::<CFNumber>::try_from(v).map_err(|_| CFSerdeError::NumberOutOfRange(v))?;
+        Ok(Some(CFPropertyList::Number(number)))
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Self::Ok> {
+        Ok(Some(CFPropertyList::Number(v.into())))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Self::Ok> {
+        Ok(Some(CFPropertyList::Number(v.into())))
+    }
+
+    fn serialize_char(self, v: char) -> Result<Self::Ok> {
+        self.serialize_str(v.encode_utf8(&mut [0; 4]))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Self::Ok> {
+        Ok(Some(CFPropertyList::String(CFString::new(v))))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Self::Ok> {
+        Ok(Some(CFPropertyList::Data(CFData::from_bytes(None, v))))
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok> {
+        Ok(None)
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Self::Ok> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok> {
+        Ok(None)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok> {
+        Ok(None)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok> {
+        self.serialize_str(variant)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok> {
+        let value = value.serialize(self)?;
+        if name != DATE_TOKEN {
+            return Ok(value);
+        }
+
+        match value.as_ref().and_then(|value| match value {
+            CFPropertyList::Number(number) => number.f64_value(),
+            _ => None,
+        }) {
+            Some(at) => Ok(Some(CFPropertyList::Date(CFDate::new(None, at)))),
+            None => Err(ser::Error::custom("dates must be serialized as numbers")),
+        }
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok> {
+        let value = required(value.serialize(self)?)?;
+        Ok(Some(single_key_dictionary(variant, &value)))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<SerializeArray> {
+        Ok(SerializeArray {
+            items: Vec::with_capacity(len.unwrap_or(0)),
+            variant: None,
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SerializeArray> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(self, _name: &'static str, len: usize) -> Result<SerializeArray> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<SerializeArray> {
+        Ok(SerializeArray {
+            items: Vec::with_capacity(len),
+            variant: Some(variant),
+        })
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<SerializeDictionary> {
+        Ok(SerializeDictionary {
+            keys: Vec::with_capacity(len.unwrap_or(0)),
+            values: Vec::with_capacity(len.unwrap_or(0)),
+            key: None,
+            variant: None,
+        })
+    }
+
+    fn serialize_struct(self, _name: &'static str, len: usize) -> Result<SerializeDictionary> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<SerializeDictionary> {
+        let mut dictionary = self.serialize_map(Some(len))?;
+        dictionary.variant = Some(variant);
+        Ok(dictionary)
+    }
+}
+
+/// Serializes sequences, tuples, and tuple variants into arrays.
+pub(super) struct SerializeArray {
+    items: Vec<CFPropertyList>,
+    /// The key of the enclosing dictionary, for tuple variants.
+    variant: Option<&'static str>,
+}
+
+impl SerializeArray {
+    fn push<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        self.items.push(required(value.serialize(Serializer)?)?);
+        Ok(())
+    }
+
+    fn finish(self) -> Result<Option<CFPropertyList>> {
+        let items: Vec<&CFType<'static>> =
+            self.items.iter().map(|item| item.as_cf_type()).collect();
+        let array = CFPropertyList::Array(CFArray::from_slice(&items));
+        Ok(Some(match self.variant {
+            Some(variant) => single_key_dictionary(variant, &array),
+            None => array,
+        }))
+    }
+}
+
+impl ser::SerializeSeq for SerializeArray {
+    type Ok = Option<CFPropertyList>;
+    type Error = CFSerdeError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTuple for SerializeArray {
+    type Ok = Option<CFPropertyList>;
+    type Error = CFSerdeError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleStruct for SerializeArray {
+    type Ok = Option<CFPropertyList>;
+    type Error = CFSerdeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleVariant for SerializeArray {
+    type Ok = Option<CFPropertyList>;
+    type Error = CFSerdeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok> {
+        self.finish()
+    }
+}
+
+/// Serializes maps, structs, and struct variants into dictionaries.
+pub(super) struct SerializeDictionary {
+    keys: Vec<Arc<CFString>>,
+    values: Vec<CFPropertyList>,
+    /// The key of the map entry whose value is next.
+    key: Option<Arc<CFString>>,
+    /// The key of the enclosing dictionary, for struct variants.
+    variant: Option<&'static str>,
+}
+
+impl SerializeDictionary {
+    fn insert<T: Serialize + ?Sized>(&mut self, key: Arc<CFString>, value: &T) -> Result<()> {
+        // Property lists have no null value, so entries without one are
+        // left out.
+        if let Some(value) = value.serialize(Serializer)? {
+            self.keys.push(key);
+            self.values.push(value);
+        }
+        Ok(())
+    }
+
+    fn finish(self) -> Result<Option<CFPropertyList>> {
+        let keys: Vec<&CFType<'static>> = self.keys.iter().map(|key| &***key).collect();
+        let values: Vec<&CFType<'static>> =
+            self.values.iter().map(|value| value.as_cf_type()).collect();
+        let dictionary = CFPropertyList::Dictionary(CFDictionary::create(None, &keys, &values));
+        Ok(Some(match self.variant {
+            Some(variant) => single_key_dictionary(variant, &dictionary),
+            None => dictionary,
+        }))
+    }
+}
+
+impl ser::SerializeMap for SerializeDictionary {
+    type Ok = Option<CFPropertyList>;
+    type Error = CFSerdeError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<()> {
+        // Unit enum variants are also serialized as strings.
+        match key.serialize(Serializer)? {
+            Some(CFPropertyList::String(key)) => {
+                self.key = Some(key);
+                Ok(())
+            }
+            _ => Err(CFSerdeError::InvalidKey),
+        }
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        match self.key.take() {
+            Some(key) => self.insert(key, value),
+            None => Err(ser::Error::custom("dictionary value without key")),
+        }
+    }
+
+    fn end(self) -> Result<Self::Ok> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStruct for SerializeDictionary {
+    type Ok = Option<CFPropertyList>;
+    type Error = CFSerdeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        self.insert(CFString::new(key), value)
+    }
+
+    fn end(self) -> Result<Self::Ok> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStructVariant for SerializeDictionary {
+    type Ok = Option<CFPropertyList>;
+    type Error = CFSerdeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        self.insert(CFString::new(key), value)
+    }
+
+    fn end(self) -> Result<Self::Ok> {
+        self.finish()
+    }
+}
@@ -0,0 +1,52 @@
+use super::CFIndex;
+use std::ops::Range;
+
+/// A structure representing a range of sequential items in a container, such
+/// as characters in a buffer or elements in a collection.
+///
+/// See [documentation](https://developer.apple.com/documentation/corefoundation/cfrange).
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CFRange {
+    /// An integer representing the starting location of the range.
+    ///
+    /// For type compatibility with the rest of the system, `CFIndex` is
+    /// signed, but this value should never be negative.
+    pub location: CFIndex,
+    /// An integer representing the number of items in the range.
+    pub length: CFIndex,
+}
+
+impl From<Range<CFIndex>> for CFRange {
+    #[inline]
+    fn from(range: Range<CFIndex>) -> Self {
+        Self {
+            location: range.start,
+            length: range.end.saturating_sub(range.start),
+        }
+    }
+}
+
+impl From<CFRange> for Range<CFIndex> {
+    #[inline]
+    fn from(range: CFRange) -> Self {
+        range.location..range.end()
+    }
+}
+
+impl CFRange {
+    /// Creates a new range from a location and a length.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/1543270-cfrangemake).
+    #[inline]
+    #[doc(alias = "CFRangeMake")]
+    pub const fn new(location: CFIndex, length: CFIndex) -> Self {
+        Self { location, length }
+    }
+
+    /// Returns the location one past the last item in the range.
+    #[inline]
+    pub const fn end(&self) -> CFIndex {
+        self.location + self.length
+    }
+}
@@ -0,0 +1,378 @@
+use super::{sys, CFArray, CFTimeInterval, CFType, CFTypeID};
+use crate::core::Arc;
+use std::time::{Duration, Instant};
+
+#[cfg(feature = "dispatch")]
+use crate::dispatch::{DispatchQosClass, DispatchQueue, DispatchSource, Signal};
+
+mod mode;
+mod observer;
+mod schedulable;
+mod source;
+mod timer;
+
+pub use mode::*;
+pub use observer::*;
+pub use schedulable::*;
+pub use source::*;
+pub use timer::*;
+
+subclass! {
+    /// A loop that monitors sources of input to a task and dispatches control
+    /// when sources become ready for processing.
+    ///
+    /// Documentation:
+    /// [Swift](https://developer.apple.com/documentation/corefoundation/cfrunloop?language=swift) |
+    /// [Objective-C](https://developer.apple.com/documentation/corefoundation/cfrunloop?language=objc)
+    #[derive(PartialEq, Hash)]
+    pub class CFRunLoop: CFType<'static>;
+}
+
+/// The reason for [`CFRunLoop::run_in_mode`] returning.
+///
+/// See [documentation](https://developer.apple.com/documentation/corefoundation/cfrunlooprunresult).
+#[repr(i32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CFRunLoopRunResult {
+    /// The run loop mode has no sources or timers.
+    #[doc(alias = "kCFRunLoopRunFinished")]
+    Finished = 1,
+    /// The run loop was stopped with [`CFRunLoop::stop`].
+    #[doc(alias = "kCFRunLoopRunStopped")]
+    Stopped = 2,
+    /// The time interval elapsed.
+    #[doc(alias = "kCFRunLoopRunTimedOut")]
+    TimedOut = 3,
+    /// A source was processed. This is only returned when the run loop was
+    /// told to return after handling a source.
+    #[doc(alias = "kCFRunLoopRunHandledSource")]
+    HandledSource = 4,
+}
+
+/// Getting a run loop.
+impl CFRunLoop {
+    /// Returns the type identifier for `CFRunLoop`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfrunloopgettypeid()).
+    #[inline]
+    #[doc(alias = "CFRunLoopGetTypeID")]
+    pub fn type_id() -> CFTypeID {
+        unsafe { sys::CFRunLoopGetTypeID() }
+    }
+
+    /// Returns the run loop for the current thread, creating one if needed.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfrunloopgetcurrent()).
+    #[inline]
+    #[doc(alias = "CFRunLoopGetCurrent")]
+    pub fn current() -> Arc<Self> {
+        unsafe { Arc::retain_raw(sys::CFRunLoopGetCurrent()) }
+    }
+
+    /// Returns the run loop of the main thread.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfrunloopgetmain()).
+    #[inline]
+    #[doc(alias = "CFRunLoopGetMain")]
+    pub fn main() -> Arc<Self> {
+        unsafe { Arc::retain_raw(sys::CFRunLoopGetMain()) }
+    }
+}
+
+/// Starting and stopping a run loop.
+impl CFRunLoop {
+    /// Runs the current thread's run loop in the default mode indefinitely,
+    /// until it is stopped or all sources and timers are removed.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfrunlooprun()).
+    #[inline]
+    #[doc(alias = "CFRunLoopRun")]
+    pub fn run() {
+        unsafe { sys::CFRunLoopRun() }
+    }
+
+    /// Runs the current thread's run loop in `mode` for at most `seconds`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfrunloopruninmode(_:_:_:)).
+    #[inline]
+    #[doc(alias = "CFRunLoopRunInMode")]
+    pub fn run_in_mode(
+        mode: &CFRunLoopMode,
+        seconds: CFTimeInterval,
+        return_after_source_handled: bool,
+    ) -> CFRunLoopRunResult {
+        unsafe { sys::CFRunLoopRunInMode(mode, seconds, return_after_source_handled as _) }
+    }
+
+    /// Runs the current thread's run loop in `mode` until `is_done` returns
+    /// `true`, or until `timeout` elapses. Returns the last result of
+    /// `is_done`.
+    ///
+    /// `is_done` is checked before running and after each source is handled.
+    /// Timers and observers do not cause a check, so they should
+    /// [stop](Self::stop) the run loop after changing what `is_done` reads.
+    ///
+    /// This is typically used with a [private](CFRunLoopMode::private) mode
+    /// to wait for specific work while other sources are paused. Sources that
+    /// should also keep running in other modes can be added to both.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fruity::core_foundation::{CFRunLoop, CFRunLoopMode, CFRunLoopTimer};
+    /// use std::sync::{
+    ///     atomic::{AtomicBool, Ordering},
+    ///     Arc,
+    /// };
+    /// use std::time::Duration;
+    ///
+    /// let mode = CFRunLoopMode::private();
+    /// let run_loop = CFRunLoop::current();
+    ///
+    /// let is_done = Arc::new(AtomicBool::new(false));
+    /// let timer = CFRunLoopTimer::with_delay(Duration::from_millis(100), None, {
+    ///     let is_done = is_done.clone();
+    ///     move |_| {
+    ///         is_done.store(true, Ordering::SeqCst);
+    ///         CFRunLoop::current().stop();
+    ///     }
+    /// });
+    /// run_loop.add_timer(&timer, &mode);
+    ///
+    /// let finished = CFRunLoop::run_in_mode_until(&mode, Some(Duration::from_secs(1)), || {
+    ///     is_done.load(Ordering::SeqCst)
+    /// });
+    /// assert!(finished);
+    /// ```
+    pub fn run_in_mode_until<F>(
+        mode: &CFRunLoopMode,
+        timeout: Option<Duration>,
+        mut is_done: F,
+    ) -> bool
+    where
+        F: FnMut() -> bool,
+    {
+        let deadline = timeout.map(|timeout| Instant::now() + timeout);
+
+        while !is_done() {
+            let seconds = match deadline {
+                Some(deadline) => match deadline.checked_duration_since(Instant::now()) {
+                    Some(remaining) => remaining.as_secs_f64(),
+                    None => return false,
+                },
+                // Effectively forever, like `CFRunLoopRun`.
+                None => 1.0e10,
+            };
+
+            match Self::run_in_mode(mode, seconds, true) {
+                // Nothing can change the result without sources or timers.
+                CFRunLoopRunResult::Finished => return is_done(),
+                CFRunLoopRunResult::Stopped | CFRunLoopRunResult::HandledSource => {}
+                CFRunLoopRunResult::TimedOut => {
+                    if deadline.is_some() {
+                        return is_done();
+                    }
+                }
+            }
+        }
+        true
+    }
+
+    /// Forces `self` to stop running.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfrunloopstop(_:)).
+    #[inline]
+    #[doc(alias = "CFRunLoopStop")]
+    pub fn stop(&self) {
+        unsafe { sys::CFRunLoopStop(self) }
+    }
+
+    /// Wakes `self` if it is waiting for an event.
+    ///
+    /// This is necessary after [signaling](CFRunLoopSource::signal) a source
+    /// from another thread.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfrunloopwakeup(_:)).
+    #[inline]
+    #[doc(alias = "CFRunLoopWakeUp")]
+    pub fn wake_up(&self) {
+        unsafe { sys::CFRunLoopWakeUp(self) }
+    }
+
+    /// Returns `true` if `self` is waiting for an event.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfrunloopiswaiting(_:)).
+    #[inline]
+    #[doc(alias = "CFRunLoopIsWaiting")]
+    pub fn is_waiting(&self) -> bool {
+        unsafe { sys::CFRunLoopIsWaiting(self) != 0 }
+    }
+
+    /// Returns the name of the mode in which `self` is running, or [`None`]
+    /// if it is not running.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfrunloopcopycurrentmode(_:)).
+    #[inline]
+    #[doc(alias = "CFRunLoopCopyCurrentMode")]
+    pub fn current_mode(&self) -> Option<Arc<CFRunLoopMode>> {
+        unsafe {
+            let mode = sys::CFRunLoopCopyCurrentMode(self);
+            if mode.is_null() {
+                None
+            } else {
+                Some(Arc::from_raw(mode))
+            }
+        }
+    }
+}
+
+/// A run loop that is only stopped from other threads.
+#[cfg(feature = "dispatch")]
+struct StoppableRunLoop(Arc<CFRunLoop>);
+
+// SAFETY: `CFRunLoopStop` may be called from any thread, unlike most other
+// `CFRunLoop` functions, which are only used on the run loop's own thread.
+#[cfg(feature = "dispatch")]
+unsafe impl Send for StoppableRunLoop {}
+
+#[cfg(feature = "dispatch")]
+impl StoppableRunLoop {
+    #[inline]
+    fn stop(&self) {
+        self.0.stop();
+    }
+}
+
+/// Stopping on UNIX signals.
+#[cfg(feature = "dispatch")]
+impl CFRunLoop {
+    /// Stops `self` each time the process receives one of `signals`, instead
+    /// of letting them terminate the process.
+    ///
+    /// This lets a daemon return from [`run`](Self::run) and clean up when
+    /// `launchd` sends [`Signal::TERM`] or the user presses Control-C. The
+    /// returned sources keep handling the signals until they are
+    /// [cancelled](DispatchSource::cancel).
+    ///
+    /// Stopping the main run loop does not end `-[NSApplication run]`, so
+    /// AppKit apps should instead use `NSApplication::terminate_on_signals`.
+    pub fn stop_on_signals(&self, signals: &[Signal]) -> Vec<Arc<DispatchSource>> {
+        let queue = DispatchQueue::global_with_qos(DispatchQosClass::Default);
+        signals
+            .iter()
+            .map(|&signal| {
+                let run_loop = StoppableRunLoop(Arc::retain(self));
+                DispatchSource::signal(queue, signal, move |_| run_loop.stop())
+            })
+            .collect()
+    }
+}
+
+/// Managing modes.
+impl CFRunLoop {
+    /// Adds `mode` to the set of common modes of `self`.
+    ///
+    /// Timers, sources, and observers added in
+    /// [`CFRunLoopMode::common_modes`] are then also handled while running
+    /// in `mode`, including those added before this.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfrunloopaddcommonmode(_:_:)).
+    #[inline]
+    #[doc(alias = "CFRunLoopAddCommonMode")]
+    pub fn add_common_mode(&self, mode: &CFRunLoopMode) {
+        unsafe { sys::CFRunLoopAddCommonMode(self, mode) }
+    }
+
+    /// Returns the modes that `self` has been used in.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfrunloopcopyallmodes(_:)).
+    #[inline]
+    #[doc(alias = "CFRunLoopCopyAllModes")]
+    pub fn all_modes(&self) -> Arc<CFArray<CFRunLoopMode>> {
+        unsafe { Arc::from_raw(sys::CFRunLoopCopyAllModes(self)) }
+    }
+}
+
+/// Managing timers, sources, and observers.
+impl CFRunLoop {
+    /// Adds `timer` to `self` in `mode`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfrunloopaddtimer(_:_:_:)).
+    #[inline]
+    #[doc(alias = "CFRunLoopAddTimer")]
+    pub fn add_timer(&self, timer: &CFRunLoopTimer, mode: &CFRunLoopMode) {
+        unsafe { sys::CFRunLoopAddTimer(self, timer, mode) }
+    }
+
+    /// Removes `timer` from `mode` of `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfrunloopremovetimer(_:_:_:)).
+    #[inline]
+    #[doc(alias = "CFRunLoopRemoveTimer")]
+    pub fn remove_timer(&self, timer: &CFRunLoopTimer, mode: &CFRunLoopMode) {
+        unsafe { sys::CFRunLoopRemoveTimer(self, timer, mode) }
+    }
+
+    /// Returns `true` if `mode` of `self` contains `timer`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfrunloopcontainstimer(_:_:_:)).
+    #[inline]
+    #[doc(alias = "CFRunLoopContainsTimer")]
+    pub fn contains_timer(&self, timer: &CFRunLoopTimer, mode: &CFRunLoopMode) -> bool {
+        unsafe { sys::CFRunLoopContainsTimer(self, timer, mode) != 0 }
+    }
+
+    /// Adds `source` to `self` in `mode`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfrunloopaddsource(_:_:_:)).
+    #[inline]
+    #[doc(alias = "CFRunLoopAddSource")]
+    pub fn add_source(&self, source: &CFRunLoopSource, mode: &CFRunLoopMode) {
+        unsafe { sys::CFRunLoopAddSource(self, source, mode) }
+    }
+
+    /// Removes `source` from `mode` of `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfrunloopremovesource(_:_:_:)).
+    #[inline]
+    #[doc(alias = "CFRunLoopRemoveSource")]
+    pub fn remove_source(&self, source: &CFRunLoopSource, mode: &CFRunLoopMode) {
+        unsafe { sys::CFRunLoopRemoveSource(self, source, mode) }
+    }
+
+    /// Returns `true` if `mode` of `self` contains `source`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfrunloopcontainssource(_:_:_:)).
+    #[inline]
+    #[doc(alias = "CFRunLoopContainsSource")]
+    pub fn contains_source(&self, source: &CFRunLoopSource, mode: &CFRunLoopMode) -> bool {
+        unsafe { sys::CFRunLoopContainsSource(self, source, mode) != 0 }
+    }
+
+    /// Adds `observer` to `self` in `mode`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfrunloopaddobserver(_:_:_:)).
+    #[inline]
+    #[doc(alias = "CFRunLoopAddObserver")]
+    pub fn add_observer(&self, observer: &CFRunLoopObserver, mode: &CFRunLoopMode) {
+        unsafe { sys::CFRunLoopAddObserver(self, observer, mode) }
+    }
+
+    /// Removes `observer` from `mode` of `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfrunloopremoveobserver(_:_:_:)).
+    #[inline]
+    #[doc(alias = "CFRunLoopRemoveObserver")]
+    pub fn remove_observer(&self, observer: &CFRunLoopObserver, mode: &CFRunLoopMode) {
+        unsafe { sys::CFRunLoopRemoveObserver(self, observer, mode) }
+    }
+
+    /// Returns `true` if `mode` of `self` contains `observer`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfrunloopcontainsobserver(_:_:_:)).
+    #[inline]
+    #[doc(alias = "CFRunLoopContainsObserver")]
+    pub fn contains_observer(&self, observer: &CFRunLoopObserver, mode: &CFRunLoopMode) -> bool {
+        unsafe { sys::CFRunLoopContainsObserver(self, observer, mode) != 0 }
+    }
+}
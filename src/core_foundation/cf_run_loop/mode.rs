@@ -0,0 +1,91 @@
+use crate::core::Arc;
+use crate::core_foundation::CFString;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+object_wrapper! {
+    /// A mode in which a [`CFRunLoop`](super::CFRunLoop) can run.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfrunloopmode).
+    #[derive(PartialEq, Eq, Hash)]
+    pub wrapper CFRunLoopMode: CFString;
+}
+
+impl CFRunLoopMode {
+    /// The run loop mode in which to handle input sources other than
+    /// connections.
+    ///
+    /// This internally references
+    /// [`kCFRunLoopDefaultMode`](https://developer.apple.com/documentation/corefoundation/kcfrunloopdefaultmode).
+    #[inline]
+    #[doc(alias = "kCFRunLoopDefaultMode")]
+    #[allow(clippy::should_implement_trait)]
+    pub fn default() -> &'static Self {
+        extern "C" {
+            static kCFRunLoopDefaultMode: &'static CFRunLoopMode;
+        }
+        unsafe { kCFRunLoopDefaultMode }
+    }
+
+    /// A pseudo-mode that represents the set of "common" modes.
+    ///
+    /// Objects added to a run loop using this mode are monitored by all run
+    /// loop modes that have been declared as members of the common set.
+    ///
+    /// This internally references
+    /// [`kCFRunLoopCommonModes`](https://developer.apple.com/documentation/corefoundation/kcfrunloopcommonmodes).
+    #[inline]
+    #[doc(alias = "kCFRunLoopCommonModes")]
+    pub fn common_modes() -> &'static Self {
+        extern "C" {
+            static kCFRunLoopCommonModes: &'static CFRunLoopMode;
+        }
+        unsafe { kCFRunLoopCommonModes }
+    }
+}
+
+/// Creating modes.
+impl CFRunLoopMode {
+    /// Returns the mode named `name`.
+    ///
+    /// Modes are created by a run loop as they are first used, so any name
+    /// can be used as a custom mode.
+    #[inline]
+    pub fn with_name(name: &str) -> Arc<Self> {
+        CFString::new(name).into()
+    }
+
+    /// Returns the mode in which the main run loop runs while tracking
+    /// controls, such as while a mouse button is held on a slider or a
+    /// scroll view is being dragged.
+    ///
+    /// Work scheduled only in the [default](Self::default) mode is paused
+    /// while tracking. Scheduling in the [common modes](Self::common_modes)
+    /// keeps it running, since AppKit and UIKit add this mode to them.
+    ///
+    /// This has the value of `NSEventTrackingRunLoopMode` on macOS and
+    /// `UITrackingRunLoopMode` on other platforms.
+    #[inline]
+    #[doc(alias = "NSEventTrackingRunLoopMode")]
+    #[doc(alias = "UITrackingRunLoopMode")]
+    pub fn event_tracking() -> Arc<Self> {
+        #[cfg(target_os = "macos")]
+        let name = "NSEventTrackingRunLoopMode";
+        #[cfg(not(target_os = "macos"))]
+        let name = "UITrackingRunLoopMode";
+
+        CFString::from_static_str(name).into()
+    }
+
+    /// Returns a new mode that is not used by any other code.
+    ///
+    /// Running a run loop in a private mode only handles the sources, timers,
+    /// and observers added to that mode, which is useful for waiting on
+    /// specific work without reentering other code. See
+    /// [`CFRunLoop::run_in_mode_until`](super::CFRunLoop::run_in_mode_until).
+    pub fn private() -> Arc<Self> {
+        static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+        let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+        Self::with_name(&format!("fruity.private.{}", id))
+    }
+}
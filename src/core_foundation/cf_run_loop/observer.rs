@@ -0,0 +1,207 @@
+use super::timer::release_boxed;
+use crate::{
+    core::Arc,
+    core_foundation::{sys, Boolean, CFIndex, CFOptionFlags, CFType, CFTypeID},
+};
+use std::{ffi::c_void, ops, panic, process, ptr};
+
+subclass! {
+    /// A general means to receive callbacks at different points within a
+    /// running run loop.
+    ///
+    /// Documentation:
+    /// [Swift](https://developer.apple.com/documentation/corefoundation/cfrunloopobserver?language=swift) |
+    /// [Objective-C](https://developer.apple.com/documentation/corefoundation/cfrunloopobserver?language=objc)
+    #[derive(PartialEq, Hash)]
+    pub class CFRunLoopObserver: CFType<'static>;
+}
+
+/// Run loop activity stages in which run loop observers can be scheduled.
+///
+/// See [documentation](https://developer.apple.com/documentation/corefoundation/cfrunloopactivity).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CFRunLoopActivity(pub CFOptionFlags);
+
+impl ops::BitOr for CFRunLoopActivity {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl CFRunLoopActivity {
+    /// The entrance of the run loop.
+    #[doc(alias = "kCFRunLoopEntry")]
+    pub const ENTRY: Self = Self(1 << 0);
+
+    /// Inside the event processing loop before any timers are processed.
+    #[doc(alias = "kCFRunLoopBeforeTimers")]
+    pub const BEFORE_TIMERS: Self = Self(1 << 1);
+
+    /// Inside the event processing loop before any sources are processed.
+    #[doc(alias = "kCFRunLoopBeforeSources")]
+    pub const BEFORE_SOURCES: Self = Self(1 << 2);
+
+    /// Inside the event processing loop before the run loop sleeps.
+    #[doc(alias = "kCFRunLoopBeforeWaiting")]
+    pub const BEFORE_WAITING: Self = Self(1 << 5);
+
+    /// Inside the event processing loop after the run loop wakes up.
+    #[doc(alias = "kCFRunLoopAfterWaiting")]
+    pub const AFTER_WAITING: Self = Self(1 << 6);
+
+    /// The exit of the run loop.
+    #[doc(alias = "kCFRunLoopExit")]
+    pub const EXIT: Self = Self(1 << 7);
+
+    /// All of the activity stages.
+    #[doc(alias = "kCFRunLoopAllActivities")]
+    pub const ALL: Self = Self(0x0FFF_FFFF);
+
+    /// Returns `true` if all of the stages in `other` are set in `self`.
+    #[inline]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+/// Callback invoked when a [`CFRunLoopObserver`] is triggered.
+///
+/// See [documentation](https://developer.apple.com/documentation/corefoundation/cfrunloopobservercallback).
+pub type CFRunLoopObserverCallBack = unsafe extern "C" fn(
+    observer: *mut CFRunLoopObserver,
+    activity: CFRunLoopActivity,
+    info: *mut c_void,
+);
+
+/// A structure that contains program-defined data and callbacks for a
+/// [`CFRunLoopObserver`].
+///
+/// This has the same layout as
+/// [`CFRunLoopTimerContext`](super::CFRunLoopTimerContext).
+///
+/// See [documentation](https://developer.apple.com/documentation/corefoundation/cfrunloopobservercontext).
+pub type CFRunLoopObserverContext = super::CFRunLoopTimerContext;
+
+impl CFRunLoopObserver {
+    /// Returns the type identifier for `CFRunLoopObserver`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfrunloopobservergettypeid()).
+    #[inline]
+    #[doc(alias = "CFRunLoopObserverGetTypeID")]
+    pub fn type_id() -> CFTypeID {
+        unsafe { sys::CFRunLoopObserverGetTypeID() }
+    }
+
+    /// Creates an observer that calls `callout` when the run loop it is added
+    /// to reaches any of the stages in `activities`.
+    ///
+    /// If `repeats` is `false`, the observer is invalidated after its first
+    /// call.
+    ///
+    /// It is safe to panic within `callout`. Panics will abort the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfrunloopobservercreate(_:_:_:_:_:_:)).
+    #[doc(alias = "CFRunLoopObserverCreate")]
+    pub fn new<F>(activities: CFRunLoopActivity, repeats: bool, callout: F) -> Arc<Self>
+    where
+        F: FnMut(&CFRunLoopObserver, CFRunLoopActivity) + Send + 'static,
+    {
+        unsafe extern "C" fn wrapped_callout<F>(
+            observer: *mut CFRunLoopObserver,
+            activity: CFRunLoopActivity,
+            info: *mut c_void,
+        ) where
+            F: FnMut(&CFRunLoopObserver, CFRunLoopActivity) + Send + 'static,
+        {
+            let callout = &mut *(info as *mut F);
+            let observer = &*observer;
+
+            // Unwinding into Core Foundation is undefined behavior.
+            let result =
+                panic::catch_unwind(panic::AssertUnwindSafe(|| callout(observer, activity)));
+            if result.is_err() {
+                process::abort();
+            }
+        }
+
+        let mut context = CFRunLoopObserverContext {
+            version: 0,
+            info: Box::into_raw(Box::new(callout)).cast(),
+            retain: None,
+            release: Some(release_boxed::<F>),
+            copy_description: None,
+        };
+
+        // SAFETY: `context` owns the boxed closure and releases it when the
+        // observer is deallocated.
+        unsafe { Self::create_raw(activities, repeats, 0, wrapped_callout::<F>, &mut context) }
+    }
+
+    /// Creates an observer with a C function callback.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfrunloopobservercreate(_:_:_:_:_:_:)).
+    ///
+    /// # Safety
+    ///
+    /// `callout` must be safe to call with the `info` pointer of `context`,
+    /// and the callbacks of `context` must correctly manage `info`.
+    #[inline]
+    #[doc(alias = "CFRunLoopObserverCreate")]
+    pub unsafe fn create_raw(
+        activities: CFRunLoopActivity,
+        repeats: bool,
+        order: CFIndex,
+        callout: CFRunLoopObserverCallBack,
+        context: &mut CFRunLoopObserverContext,
+    ) -> Arc<Self> {
+        Arc::from_raw(sys::CFRunLoopObserverCreate(
+            ptr::null(),
+            activities,
+            repeats as Boolean,
+            order,
+            callout,
+            context,
+        ))
+    }
+
+    /// Returns the run loop stages during which the observer is called.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfrunloopobservergetactivities(_:)).
+    #[inline]
+    #[doc(alias = "CFRunLoopObserverGetActivities")]
+    pub fn activities(&self) -> CFRunLoopActivity {
+        unsafe { sys::CFRunLoopObserverGetActivities(self) }
+    }
+
+    /// Returns `true` if the observer is called repeatedly.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfrunloopobserverdoesrepeat(_:)).
+    #[inline]
+    #[doc(alias = "CFRunLoopObserverDoesRepeat")]
+    pub fn does_repeat(&self) -> bool {
+        unsafe { sys::CFRunLoopObserverDoesRepeat(self) != 0 }
+    }
+
+    /// Invalidates the observer, stopping it from ever being called again and
+    /// removing it from all run loops.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfrunloopobserverinvalidate(_:)).
+    #[inline]
+    #[doc(alias = "CFRunLoopObserverInvalidate")]
+    pub fn invalidate(&self) {
+        unsafe { sys::CFRunLoopObserverInvalidate(self) }
+    }
+
+    /// Returns `true` if the observer is valid and able to be called.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfrunloopobserverisvalid(_:)).
+    #[inline]
+    #[doc(alias = "CFRunLoopObserverIsValid")]
+    pub fn is_valid(&self) -> bool {
+        unsafe { sys::CFRunLoopObserverIsValid(self) != 0 }
+    }
+}
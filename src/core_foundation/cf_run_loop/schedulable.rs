@@ -0,0 +1,87 @@
+use super::{CFRunLoop, CFRunLoopMode, CFRunLoopObserver, CFRunLoopSource, CFRunLoopTimer};
+
+/// An object that delivers its events or callbacks through a [`CFRunLoop`],
+/// in the modes it is scheduled in.
+///
+/// This lets code choose the modes of any of these objects the same way,
+/// such as scheduling in [`CFRunLoopMode::common_modes`] so that work keeps
+/// running while the user [tracks](CFRunLoopMode::event_tracking) a control.
+/// An object can be scheduled in multiple modes, and on multiple run loops.
+///
+/// Sockets are scheduled through the source returned by
+/// [`CFSocket::create_run_loop_source`](super::CFSocket::create_run_loop_source).
+///
+/// # Examples
+///
+/// ```no_run
+/// use fruity::core_foundation::*;
+/// use std::time::Duration;
+///
+/// let interval = Duration::from_secs(1);
+/// let timer = CFRunLoopTimer::with_delay(interval, Some(interval), |_| {
+///     println!("tick");
+/// });
+///
+/// // Keep ticking while a control is being tracked.
+/// let modes = [CFRunLoopMode::default(), &CFRunLoopMode::event_tracking()];
+/// timer.schedule_in_modes(&CFRunLoop::main(), &modes);
+/// ```
+pub trait CFRunLoopSchedulable {
+    /// Starts handling `self` on `run_loop` while it runs in `mode`.
+    fn schedule(&self, run_loop: &CFRunLoop, mode: &CFRunLoopMode);
+
+    /// Stops handling `self` on `run_loop` in `mode`.
+    fn unschedule(&self, run_loop: &CFRunLoop, mode: &CFRunLoopMode);
+
+    /// Starts handling `self` on `run_loop` in each of `modes`.
+    #[inline]
+    fn schedule_in_modes(&self, run_loop: &CFRunLoop, modes: &[&CFRunLoopMode]) {
+        for mode in modes {
+            self.schedule(run_loop, mode);
+        }
+    }
+
+    /// Stops handling `self` on `run_loop` in each of `modes`.
+    #[inline]
+    fn unschedule_from_modes(&self, run_loop: &CFRunLoop, modes: &[&CFRunLoopMode]) {
+        for mode in modes {
+            self.unschedule(run_loop, mode);
+        }
+    }
+}
+
+impl CFRunLoopSchedulable for CFRunLoopTimer {
+    #[inline]
+    fn schedule(&self, run_loop: &CFRunLoop, mode: &CFRunLoopMode) {
+        run_loop.add_timer(self, mode);
+    }
+
+    #[inline]
+    fn unschedule(&self, run_loop: &CFRunLoop, mode: &CFRunLoopMode) {
+        run_loop.remove_timer(self, mode);
+    }
+}
+
+impl CFRunLoopSchedulable for CFRunLoopSource {
+    #[inline]
+    fn schedule(&self, run_loop: &CFRunLoop, mode: &CFRunLoopMode) {
+        run_loop.add_source(self, mode);
+    }
+
+    #[inline]
+    fn unschedule(&self, run_loop: &CFRunLoop, mode: &CFRunLoopMode) {
+        run_loop.remove_source(self, mode);
+    }
+}
+
+impl CFRunLoopSchedulable for CFRunLoopObserver {
+    #[inline]
+    fn schedule(&self, run_loop: &CFRunLoop, mode: &CFRunLoopMode) {
+        run_loop.add_observer(self, mode);
+    }
+
+    #[inline]
+    fn unschedule(&self, run_loop: &CFRunLoop, mode: &CFRunLoopMode) {
+        run_loop.remove_observer(self, mode);
+    }
+}
@@ -0,0 +1,158 @@
+use super::{timer::release_boxed, CFRunLoop, CFRunLoopMode};
+use crate::{
+    core::Arc,
+    core_foundation::{sys, Boolean, CFHashCode, CFIndex, CFType, CFTypeID},
+};
+use std::{ffi::c_void, panic, process, ptr};
+
+subclass! {
+    /// An input source that can be put into a run loop.
+    ///
+    /// Documentation:
+    /// [Swift](https://developer.apple.com/documentation/corefoundation/cfrunloopsource?language=swift) |
+    /// [Objective-C](https://developer.apple.com/documentation/corefoundation/cfrunloopsource?language=objc)
+    #[derive(PartialEq, Hash)]
+    pub class CFRunLoopSource: CFType<'static>;
+}
+
+/// A structure that contains program-defined data and callbacks for a version
+/// 0 [`CFRunLoopSource`].
+///
+/// See [documentation](https://developer.apple.com/documentation/corefoundation/cfrunloopsourcecontext).
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct CFRunLoopSourceContext {
+    /// The version number of this structure. Must be 0.
+    pub version: CFIndex,
+    /// An arbitrary pointer to program-defined data.
+    pub info: *mut c_void,
+    /// The callback used to retain `info`.
+    pub retain: Option<unsafe extern "C" fn(info: *const c_void) -> *const c_void>,
+    /// The callback used to release `info`.
+    pub release: Option<unsafe extern "C" fn(info: *const c_void)>,
+    /// The callback used to describe `info`.
+    pub copy_description: Option<unsafe extern "C" fn(info: *const c_void) -> *const c_void>, // TODO: Return type `Arc<CFString>`.
+    /// The callback used to test two sources for equality.
+    pub equal: Option<unsafe extern "C" fn(info1: *const c_void, info2: *const c_void) -> Boolean>,
+    /// The callback used to compute a hash code for the source.
+    pub hash: Option<unsafe extern "C" fn(info: *const c_void) -> CFHashCode>,
+    /// The callback invoked when the source is added to a run loop mode.
+    pub schedule: Option<
+        unsafe extern "C" fn(info: *mut c_void, rl: *mut CFRunLoop, mode: *const CFRunLoopMode),
+    >,
+    /// The callback invoked when the source is removed from a run loop mode.
+    pub cancel: Option<
+        unsafe extern "C" fn(info: *mut c_void, rl: *mut CFRunLoop, mode: *const CFRunLoopMode),
+    >,
+    /// The callback invoked when the source is signaled and ready to fire.
+    pub perform: Option<unsafe extern "C" fn(info: *mut c_void)>,
+}
+
+impl CFRunLoopSource {
+    /// Returns the type identifier for `CFRunLoopSource`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfrunloopsourcegettypeid()).
+    #[inline]
+    #[doc(alias = "CFRunLoopSourceGetTypeID")]
+    pub fn type_id() -> CFTypeID {
+        unsafe { sys::CFRunLoopSourceGetTypeID() }
+    }
+
+    /// Creates a version 0 source that calls `perform` after being
+    /// [signaled](Self::signal).
+    ///
+    /// Because a source can be added to multiple run loops, `perform` may be
+    /// called from multiple threads at once.
+    ///
+    /// It is safe to panic within `perform`. Panics will abort the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfrunloopsourcecreate(_:_:_:)).
+    #[doc(alias = "CFRunLoopSourceCreate")]
+    pub fn new<F>(order: CFIndex, perform: F) -> Arc<Self>
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        unsafe extern "C" fn wrapped_perform<F>(info: *mut c_void)
+        where
+            F: Fn() + Send + Sync + 'static,
+        {
+            let perform = &*(info as *const F);
+
+            // Unwinding into Core Foundation is undefined behavior.
+            if panic::catch_unwind(panic::AssertUnwindSafe(perform)).is_err() {
+                process::abort();
+            }
+        }
+
+        let mut context = CFRunLoopSourceContext {
+            version: 0,
+            info: Box::into_raw(Box::new(perform)).cast(),
+            retain: None,
+            release: Some(release_boxed::<F>),
+            copy_description: None,
+            equal: None,
+            hash: None,
+            schedule: None,
+            cancel: None,
+            perform: Some(wrapped_perform::<F>),
+        };
+
+        // SAFETY: `context` owns the boxed closure and releases it when the
+        // source is deallocated.
+        unsafe { Self::create_raw(order, &mut context) }
+    }
+
+    /// Creates a source from a context of C function callbacks.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfrunloopsourcecreate(_:_:_:)).
+    ///
+    /// # Safety
+    ///
+    /// The callbacks of `context` must be safe to call with its `info`
+    /// pointer and must correctly manage it.
+    #[inline]
+    #[doc(alias = "CFRunLoopSourceCreate")]
+    pub unsafe fn create_raw(order: CFIndex, context: &mut CFRunLoopSourceContext) -> Arc<Self> {
+        Arc::from_raw(sys::CFRunLoopSourceCreate(ptr::null(), order, context))
+    }
+
+    /// Signals the source, marking it as ready to fire.
+    ///
+    /// If the source's run loop may be sleeping, it should be woken with
+    /// [`CFRunLoop::wake_up`].
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfrunloopsourcesignal(_:)).
+    #[inline]
+    #[doc(alias = "CFRunLoopSourceSignal")]
+    pub fn signal(&self) {
+        unsafe { sys::CFRunLoopSourceSignal(self) }
+    }
+
+    /// Returns the ordering parameter of the source.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfrunloopsourcegetorder(_:)).
+    #[inline]
+    #[doc(alias = "CFRunLoopSourceGetOrder")]
+    pub fn order(&self) -> CFIndex {
+        unsafe { sys::CFRunLoopSourceGetOrder(self) }
+    }
+
+    /// Invalidates the source, stopping it from ever firing again and
+    /// removing it from all run loops.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfrunloopsourceinvalidate(_:)).
+    #[inline]
+    #[doc(alias = "CFRunLoopSourceInvalidate")]
+    pub fn invalidate(&self) {
+        unsafe { sys::CFRunLoopSourceInvalidate(self) }
+    }
+
+    /// Returns `true` if the source is valid and able to fire.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfrunloopsourceisvalid(_:)).
+    #[inline]
+    #[doc(alias = "CFRunLoopSourceIsValid")]
+    pub fn is_valid(&self) -> bool {
+        unsafe { sys::CFRunLoopSourceIsValid(self) != 0 }
+    }
+}
@@ -0,0 +1,227 @@
+use crate::{
+    core::Arc,
+    core_foundation::{
+        sys, CFAbsoluteTime, CFAbsoluteTimeGetCurrent, CFIndex, CFOptionFlags, CFTimeInterval,
+        CFType, CFTypeID,
+    },
+};
+use std::{ffi::c_void, panic, process, ptr, time::Duration};
+
+subclass! {
+    /// A specialized run loop source that fires at a preset time in the
+    /// future.
+    ///
+    /// Documentation:
+    /// [Swift](https://developer.apple.com/documentation/corefoundation/cfrunlooptimer?language=swift) |
+    /// [Objective-C](https://developer.apple.com/documentation/corefoundation/cfrunlooptimer?language=objc)
+    #[derive(PartialEq, Hash)]
+    pub class CFRunLoopTimer: CFType<'static>;
+}
+
+#[cfg(feature = "foundation")]
+cf_bridge!(CFRunLoopTimer, crate::foundation::NSTimer);
+
+/// Callback invoked when a [`CFRunLoopTimer`] fires.
+///
+/// See [documentation](https://developer.apple.com/documentation/corefoundation/cfrunlooptimercallback).
+pub type CFRunLoopTimerCallBack =
+    unsafe extern "C" fn(timer: *mut CFRunLoopTimer, info: *mut c_void);
+
+/// A structure that contains program-defined data and callbacks for a
+/// [`CFRunLoopTimer`] or [`CFRunLoopObserver`](super::CFRunLoopObserver).
+///
+/// See [documentation](https://developer.apple.com/documentation/corefoundation/cfrunlooptimercontext).
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct CFRunLoopTimerContext {
+    /// The version number of this structure. Must be 0.
+    pub version: CFIndex,
+    /// An arbitrary pointer to program-defined data.
+    pub info: *mut c_void,
+    /// The callback used to retain `info`.
+    pub retain: Option<unsafe extern "C" fn(info: *const c_void) -> *const c_void>,
+    /// The callback used to release `info`.
+    pub release: Option<unsafe extern "C" fn(info: *const c_void)>,
+    /// The callback used to describe `info`.
+    pub copy_description: Option<unsafe extern "C" fn(info: *const c_void) -> *const c_void>, // TODO: Return type `Arc<CFString>`.
+}
+
+/// Releases a boxed closure stored in a context `info` pointer.
+pub(crate) unsafe extern "C" fn release_boxed<F>(info: *const c_void) {
+    drop(Box::from_raw(info as *mut F));
+}
+
+impl CFRunLoopTimer {
+    /// Returns the type identifier for `CFRunLoopTimer`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfrunlooptimergettypeid()).
+    #[inline]
+    #[doc(alias = "CFRunLoopTimerGetTypeID")]
+    pub fn type_id() -> CFTypeID {
+        unsafe { sys::CFRunLoopTimerGetTypeID() }
+    }
+
+    /// Creates a timer that calls `callout` at `fire_date`, and then every
+    /// `interval` seconds if `interval` is positive.
+    ///
+    /// The timer does nothing until it is added to a run loop with
+    /// [`CFRunLoop::add_timer`](super::CFRunLoop::add_timer).
+    ///
+    /// It is safe to panic within `callout`. Panics will abort the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfrunlooptimercreate(_:_:_:_:_:_:_:)).
+    #[doc(alias = "CFRunLoopTimerCreate")]
+    pub fn new<F>(fire_date: CFAbsoluteTime, interval: CFTimeInterval, callout: F) -> Arc<Self>
+    where
+        F: FnMut(&CFRunLoopTimer) + Send + 'static,
+    {
+        unsafe extern "C" fn wrapped_callout<F>(timer: *mut CFRunLoopTimer, info: *mut c_void)
+        where
+            F: FnMut(&CFRunLoopTimer) + Send + 'static,
+        {
+            let callout = &mut *(info as *mut F);
+            let timer = &*timer;
+
+            // Unwinding into Core Foundation is undefined behavior.
+            if panic::catch_unwind(panic::AssertUnwindSafe(|| callout(timer))).is_err() {
+                process::abort();
+            }
+        }
+
+        let mut context = CFRunLoopTimerContext {
+            version: 0,
+            info: Box::into_raw(Box::new(callout)).cast(),
+            retain: None,
+            release: Some(release_boxed::<F>),
+            copy_description: None,
+        };
+
+        // SAFETY: `context` owns the boxed closure and releases it when the
+        // timer is deallocated.
+        unsafe { Self::create_raw(fire_date, interval, 0, wrapped_callout::<F>, &mut context) }
+    }
+
+    /// Creates a timer that calls `callout` after `delay`, and then every
+    /// `repeat` if provided.
+    ///
+    /// See [`new`](Self::new) for details.
+    #[inline]
+    pub fn with_delay<F>(delay: Duration, repeat: Option<Duration>, callout: F) -> Arc<Self>
+    where
+        F: FnMut(&CFRunLoopTimer) + Send + 'static,
+    {
+        let fire_date = CFAbsoluteTimeGetCurrent() + delay.as_secs_f64();
+        let interval = repeat.map_or(0.0, |repeat| repeat.as_secs_f64());
+        Self::new(fire_date, interval, callout)
+    }
+
+    /// Creates a timer with a C function callback.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfrunlooptimercreate(_:_:_:_:_:_:_:)).
+    ///
+    /// # Safety
+    ///
+    /// `callout` must be safe to call with the `info` pointer of `context`,
+    /// and the callbacks of `context` must correctly manage `info`.
+    #[inline]
+    #[doc(alias = "CFRunLoopTimerCreate")]
+    pub unsafe fn create_raw(
+        fire_date: CFAbsoluteTime,
+        interval: CFTimeInterval,
+        order: CFIndex,
+        callout: CFRunLoopTimerCallBack,
+        context: &mut CFRunLoopTimerContext,
+    ) -> Arc<Self> {
+        let flags: CFOptionFlags = 0;
+        Arc::from_raw(sys::CFRunLoopTimerCreate(
+            ptr::null(),
+            fire_date,
+            interval,
+            flags,
+            order,
+            callout,
+            context,
+        ))
+    }
+
+    /// Returns the firing interval of a repeating timer, or 0 if the timer
+    /// does not repeat.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfrunlooptimergetinterval(_:)).
+    #[inline]
+    #[doc(alias = "CFRunLoopTimerGetInterval")]
+    pub fn interval(&self) -> CFTimeInterval {
+        unsafe { sys::CFRunLoopTimerGetInterval(self) }
+    }
+
+    /// Returns `true` if the timer repeats.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfrunlooptimerdoesrepeat(_:)).
+    #[inline]
+    #[doc(alias = "CFRunLoopTimerDoesRepeat")]
+    pub fn does_repeat(&self) -> bool {
+        unsafe { sys::CFRunLoopTimerDoesRepeat(self) != 0 }
+    }
+
+    /// Returns the next time the timer will fire.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfrunlooptimergetnextfiredate(_:)).
+    #[inline]
+    #[doc(alias = "CFRunLoopTimerGetNextFireDate")]
+    pub fn next_fire_date(&self) -> CFAbsoluteTime {
+        unsafe { sys::CFRunLoopTimerGetNextFireDate(self) }
+    }
+
+    /// Sets the next time the timer will fire.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfrunlooptimersetnextfiredate(_:_:)).
+    #[inline]
+    #[doc(alias = "CFRunLoopTimerSetNextFireDate")]
+    pub fn set_next_fire_date(&self, fire_date: CFAbsoluteTime) {
+        unsafe { sys::CFRunLoopTimerSetNextFireDate(self, fire_date) }
+    }
+
+    /// Returns the amount of time after the scheduled fire date that the
+    /// timer may fire.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfrunlooptimergettolerance(_:)).
+    #[inline]
+    #[doc(alias = "CFRunLoopTimerGetTolerance")]
+    pub fn tolerance(&self) -> CFTimeInterval {
+        unsafe { sys::CFRunLoopTimerGetTolerance(self) }
+    }
+
+    /// Sets the amount of time after the scheduled fire date that the timer
+    /// may fire.
+    ///
+    /// Allowing the system this flexibility lets it coalesce timers to save
+    /// power. A tolerance of at least 10% of the interval is recommended for
+    /// repeating timers. The default is 0, though the system may still apply
+    /// a small tolerance.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfrunlooptimersettolerance(_:_:)).
+    #[inline]
+    #[doc(alias = "CFRunLoopTimerSetTolerance")]
+    pub fn set_tolerance(&self, tolerance: CFTimeInterval) {
+        unsafe { sys::CFRunLoopTimerSetTolerance(self, tolerance) }
+    }
+
+    /// Invalidates the timer, stopping it from ever firing again and removing
+    /// it from all run loops.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfrunlooptimerinvalidate(_:)).
+    #[inline]
+    #[doc(alias = "CFRunLoopTimerInvalidate")]
+    pub fn invalidate(&self) {
+        unsafe { sys::CFRunLoopTimerInvalidate(self) }
+    }
+
+    /// Returns `true` if the timer is valid and able to fire.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfrunlooptimerisvalid(_:)).
+    #[inline]
+    #[doc(alias = "CFRunLoopTimerIsValid")]
+    pub fn is_valid(&self) -> bool {
+        unsafe { sys::CFRunLoopTimerIsValid(self) != 0 }
+    }
+}
@@ -0,0 +1,540 @@
+use super::{
+    release_boxed, sys, CFData, CFIndex, CFOptionFlags, CFRunLoopSource, CFRunLoopTimerContext,
+    CFTimeInterval, CFType, CFTypeID,
+};
+use crate::core::Arc;
+use std::{ffi::c_void, ops, os::raw::c_int, panic, process, ptr};
+
+subclass! {
+    /// A BSD socket whose events are delivered through a
+    /// [`CFRunLoop`](super::CFRunLoop).
+    ///
+    /// Events are only delivered once the source returned by
+    /// [`create_run_loop_source`](Self::create_run_loop_source) has been added
+    /// to a run loop.
+    ///
+    /// Documentation:
+    /// [Swift](https://developer.apple.com/documentation/corefoundation/cfsocket?language=swift) |
+    /// [Objective-C](https://developer.apple.com/documentation/corefoundation/cfsocket?language=objc)
+    #[derive(PartialEq, Hash)]
+    pub class CFSocket: CFType<'static>;
+}
+
+/// The file descriptor of a BSD socket.
+///
+/// See [documentation](https://developer.apple.com/documentation/corefoundation/cfsocketnativehandle).
+pub type CFSocketNativeHandle = c_int;
+
+/// The kinds of activity that cause a [`CFSocket`] to call back.
+///
+/// [`READ`](Self::READ), [`ACCEPT`](Self::ACCEPT), and [`DATA`](Self::DATA)
+/// are mutually exclusive, but each can be combined with
+/// [`CONNECT`](Self::CONNECT) and [`WRITE`](Self::WRITE).
+///
+/// See [documentation](https://developer.apple.com/documentation/corefoundation/cfsocketcallbacktype).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CFSocketCallBackType(pub CFOptionFlags);
+
+impl ops::BitOr for CFSocketCallBackType {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl CFSocketCallBackType {
+    /// No callbacks.
+    #[doc(alias = "kCFSocketNoCallBack")]
+    pub const NONE: Self = Self(0);
+
+    /// Data is available to read, but has not been read.
+    #[doc(alias = "kCFSocketReadCallBack")]
+    pub const READ: Self = Self(1);
+
+    /// A listening socket accepted a new connection.
+    #[doc(alias = "kCFSocketAcceptCallBack")]
+    pub const ACCEPT: Self = Self(2);
+
+    /// Data was read from the socket in the background.
+    #[doc(alias = "kCFSocketDataCallBack")]
+    pub const DATA: Self = Self(3);
+
+    /// A connection started in the background completed or failed.
+    #[doc(alias = "kCFSocketConnectCallBack")]
+    pub const CONNECT: Self = Self(4);
+
+    /// The socket can accept more data for writing.
+    #[doc(alias = "kCFSocketWriteCallBack")]
+    pub const WRITE: Self = Self(8);
+
+    /// Returns `true` if all of the callbacks in `other` are set in `self`.
+    #[inline]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+/// Options that control how a [`CFSocket`] re-enables callbacks and closes
+/// its native socket.
+///
+/// See [documentation](https://developer.apple.com/documentation/corefoundation/cfsocketsetsocketflags(_:_:)).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CFSocketFlags(pub CFOptionFlags);
+
+impl ops::BitOr for CFSocketFlags {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl CFSocketFlags {
+    /// The read callback is re-enabled after being called.
+    #[doc(alias = "kCFSocketAutomaticallyReenableReadCallBack")]
+    pub const AUTOMATICALLY_REENABLE_READ_CALLBACK: Self = Self(1);
+
+    /// The accept callback is re-enabled after being called.
+    #[doc(alias = "kCFSocketAutomaticallyReenableAcceptCallBack")]
+    pub const AUTOMATICALLY_REENABLE_ACCEPT_CALLBACK: Self = Self(2);
+
+    /// The data callback is re-enabled after being called.
+    #[doc(alias = "kCFSocketAutomaticallyReenableDataCallBack")]
+    pub const AUTOMATICALLY_REENABLE_DATA_CALLBACK: Self = Self(3);
+
+    /// The write callback is re-enabled after being called.
+    #[doc(alias = "kCFSocketAutomaticallyReenableWriteCallBack")]
+    pub const AUTOMATICALLY_REENABLE_WRITE_CALLBACK: Self = Self(8);
+
+    /// Pending socket errors are left for the callback to handle instead of
+    /// being cleared.
+    #[doc(alias = "kCFSocketLeaveErrors")]
+    pub const LEAVE_ERRORS: Self = Self(64);
+
+    /// The native socket is closed when the [`CFSocket`] is invalidated.
+    #[doc(alias = "kCFSocketCloseOnInvalidate")]
+    pub const CLOSE_ON_INVALIDATE: Self = Self(128);
+
+    /// Returns `true` if all of the flags in `other` are set in `self`.
+    #[inline]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+/// An error returned by a [`CFSocket`] operation.
+///
+/// See [documentation](https://developer.apple.com/documentation/corefoundation/cfsocketerror).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CFSocketError {
+    /// The operation failed.
+    #[doc(alias = "kCFSocketError")]
+    Error,
+    /// The operation did not finish before its timeout.
+    #[doc(alias = "kCFSocketTimeout")]
+    Timeout,
+}
+
+impl CFSocketError {
+    #[inline]
+    fn result(code: CFIndex) -> Result<(), Self> {
+        match code {
+            0 => Ok(()),
+            -2 => Err(Self::Timeout),
+            _ => Err(Self::Error),
+        }
+    }
+}
+
+/// An event passed to the closure of a [`CFSocket`].
+#[derive(Copy, Clone)]
+pub enum CFSocketEvent<'a> {
+    /// Data is available to read from the native socket.
+    Read,
+    /// A listening socket accepted a connection on `handle`, which the
+    /// closure is now responsible for closing.
+    Accept {
+        /// The native socket of the new connection.
+        handle: CFSocketNativeHandle,
+        /// The address of the peer, as a `sockaddr` structure.
+        address: Option<&'a CFData>,
+    },
+    /// Data was read from the socket. Empty `data` means the peer closed the
+    /// connection.
+    Data {
+        /// The bytes that were read.
+        data: &'a CFData,
+        /// The address of the sender, as a `sockaddr` structure.
+        address: Option<&'a CFData>,
+    },
+    /// A background connection finished, with an `errno` value on failure.
+    Connect(Result<(), i32>),
+    /// The socket can accept more data for writing.
+    Write,
+}
+
+/// Callback invoked when a [`CFSocket`] has activity.
+///
+/// See [documentation](https://developer.apple.com/documentation/corefoundation/cfsocketcallback).
+pub type CFSocketCallBack = unsafe extern "C" fn(
+    s: *mut CFSocket,
+    kind: CFSocketCallBackType,
+    address: *const CFData,
+    data: *const c_void,
+    info: *mut c_void,
+);
+
+/// A structure that contains program-defined data and callbacks for a
+/// [`CFSocket`].
+///
+/// This has the same layout as
+/// [`CFRunLoopTimerContext`](super::CFRunLoopTimerContext).
+///
+/// See [documentation](https://developer.apple.com/documentation/corefoundation/cfsocketcontext).
+pub type CFSocketContext = CFRunLoopTimerContext;
+
+unsafe extern "C" fn wrapped_callout<F>(
+    s: *mut CFSocket,
+    kind: CFSocketCallBackType,
+    address: *const CFData,
+    data: *const c_void,
+    info: *mut c_void,
+) where
+    F: FnMut(&CFSocket, CFSocketEvent) + Send + 'static,
+{
+    let callout = &mut *(info as *mut F);
+    let socket = &*s;
+    let address = address.as_ref();
+
+    let event = match kind {
+        CFSocketCallBackType::READ => CFSocketEvent::Read,
+        CFSocketCallBackType::ACCEPT => CFSocketEvent::Accept {
+            handle: *data.cast::<CFSocketNativeHandle>(),
+            address,
+        },
+        CFSocketCallBackType::DATA => CFSocketEvent::Data {
+            data: &*data.cast::<CFData>(),
+            address,
+        },
+        CFSocketCallBackType::CONNECT => match data.cast::<i32>().as_ref() {
+            None => CFSocketEvent::Connect(Ok(())),
+            Some(&error) => CFSocketEvent::Connect(Err(error)),
+        },
+        CFSocketCallBackType::WRITE => CFSocketEvent::Write,
+        _ => return,
+    };
+
+    // Unwinding into Core Foundation is undefined behavior.
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(|| callout(socket, event)));
+    if result.is_err() {
+        process::abort();
+    }
+}
+
+/// Creating a socket.
+impl CFSocket {
+    /// Returns the type identifier for `CFSocket`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfsocketgettypeid()).
+    #[inline]
+    #[doc(alias = "CFSocketGetTypeID")]
+    pub fn type_id() -> CFTypeID {
+        unsafe { sys::CFSocketGetTypeID() }
+    }
+
+    /// Creates a new native socket that calls `callout` for the activity in
+    /// `callback_types`, or returns [`None`] if it could not be created.
+    ///
+    /// The arguments are the same as those of
+    /// [`socket(2)`](https://developer.apple.com/library/archive/documentation/System/Conceptual/ManPages_iPhoneOS/man2/socket.2.html),
+    /// such as `AF_INET`, `SOCK_STREAM`, and `IPPROTO_TCP`. If
+    /// `protocol_family` is not positive, `PF_INET` is used.
+    ///
+    /// It is safe to panic within `callout`. Panics will abort the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfsocketcreate(_:_:_:_:_:_:_:)).
+    #[doc(alias = "CFSocketCreate")]
+    pub fn new<F>(
+        protocol_family: c_int,
+        socket_type: c_int,
+        protocol: c_int,
+        callback_types: CFSocketCallBackType,
+        callout: F,
+    ) -> Option<Arc<Self>>
+    where
+        F: FnMut(&CFSocket, CFSocketEvent) + Send + 'static,
+    {
+        let context = Self::boxed_context(callout);
+
+        unsafe {
+            let socket = sys::CFSocketCreate(
+                ptr::null(),
+                protocol_family,
+                socket_type,
+                protocol,
+                callback_types,
+                Some(wrapped_callout::<F>),
+                &context,
+            );
+
+            if socket.is_null() {
+                release_boxed::<F>(context.info);
+                None
+            } else {
+                Some(Self::adopt::<F>(socket, &context))
+            }
+        }
+    }
+
+    /// Wraps the existing native socket `handle`, calling `callout` for the
+    /// activity in `callback_types`.
+    ///
+    /// If `handle` is already wrapped by a `CFSocket`, that socket is returned
+    /// and `callout` is dropped.
+    ///
+    /// It is safe to panic within `callout`. Panics will abort the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfsocketcreatewithnative(_:_:_:_:_:)).
+    #[doc(alias = "CFSocketCreateWithNative")]
+    pub fn with_native<F>(
+        handle: CFSocketNativeHandle,
+        callback_types: CFSocketCallBackType,
+        callout: F,
+    ) -> Arc<Self>
+    where
+        F: FnMut(&CFSocket, CFSocketEvent) + Send + 'static,
+    {
+        let context = Self::boxed_context(callout);
+
+        unsafe {
+            let socket = sys::CFSocketCreateWithNative(
+                ptr::null(),
+                handle,
+                callback_types,
+                Some(wrapped_callout::<F>),
+                &context,
+            );
+            Self::adopt::<F>(socket, &context)
+        }
+    }
+
+    /// Wraps the existing native socket `handle` with a C function callback.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfsocketcreatewithnative(_:_:_:_:_:)).
+    ///
+    /// # Safety
+    ///
+    /// `callout` must be safe to call with the `info` pointer of `context`,
+    /// and the callbacks of `context` must correctly manage `info`.
+    #[inline]
+    #[doc(alias = "CFSocketCreateWithNative")]
+    pub unsafe fn with_native_raw(
+        handle: CFSocketNativeHandle,
+        callback_types: CFSocketCallBackType,
+        callout: Option<CFSocketCallBack>,
+        context: Option<&CFSocketContext>,
+    ) -> Arc<Self> {
+        let context = context.map_or(ptr::null(), |context| context);
+        Arc::from_raw(sys::CFSocketCreateWithNative(
+            ptr::null(),
+            handle,
+            callback_types,
+            callout,
+            context,
+        ))
+    }
+
+    fn boxed_context<F>(callout: F) -> CFSocketContext {
+        CFSocketContext {
+            version: 0,
+            info: Box::into_raw(Box::new(callout)).cast(),
+            retain: None,
+            release: Some(release_boxed::<F>),
+            copy_description: None,
+        }
+    }
+
+    /// Takes ownership of the +1 `socket`, dropping the closure of `context`
+    /// if Core Foundation returned an existing socket that ignored it.
+    unsafe fn adopt<F>(socket: *const Self, context: &CFSocketContext) -> Arc<Self> {
+        let socket = Arc::from_raw(socket);
+        if socket.context().info != context.info {
+            release_boxed::<F>(context.info);
+        }
+        socket
+    }
+
+    /// Returns the context that `self` was created with.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfsocketgetcontext(_:_:)).
+    #[inline]
+    #[doc(alias = "CFSocketGetContext")]
+    pub fn context(&self) -> CFSocketContext {
+        let mut context = CFSocketContext {
+            version: 0,
+            info: ptr::null_mut(),
+            retain: None,
+            release: None,
+            copy_description: None,
+        };
+        unsafe { sys::CFSocketGetContext(self, &mut context) };
+        context
+    }
+}
+
+/// Using a socket.
+impl CFSocket {
+    /// Returns the native socket wrapped by `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfsocketgetnative(_:)).
+    #[inline]
+    #[doc(alias = "CFSocketGetNative")]
+    pub fn native_handle(&self) -> CFSocketNativeHandle {
+        unsafe { sys::CFSocketGetNative(self) }
+    }
+
+    /// Returns the local address of `self` as a `sockaddr` structure.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfsocketcopyaddress(_:)).
+    #[inline]
+    #[doc(alias = "CFSocketCopyAddress")]
+    pub fn address(&self) -> Option<Arc<CFData>> {
+        unsafe {
+            let address = sys::CFSocketCopyAddress(self);
+            if address.is_null() {
+                None
+            } else {
+                Some(Arc::from_raw(address))
+            }
+        }
+    }
+
+    /// Returns the remote address of `self` as a `sockaddr` structure.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfsocketcopypeeraddress(_:)).
+    #[inline]
+    #[doc(alias = "CFSocketCopyPeerAddress")]
+    pub fn peer_address(&self) -> Option<Arc<CFData>> {
+        unsafe {
+            let address = sys::CFSocketCopyPeerAddress(self);
+            if address.is_null() {
+                None
+            } else {
+                Some(Arc::from_raw(address))
+            }
+        }
+    }
+
+    /// Binds `self` to the `sockaddr` in `address` and, for stream sockets,
+    /// starts listening for connections.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfsocketsetaddress(_:_:)).
+    #[inline]
+    #[doc(alias = "CFSocketSetAddress")]
+    pub fn set_address(&self, address: &CFData) -> Result<(), CFSocketError> {
+        CFSocketError::result(unsafe { sys::CFSocketSetAddress(self, address) })
+    }
+
+    /// Connects `self` to the `sockaddr` in `address`.
+    ///
+    /// If `timeout` is negative, the connection happens in the background and
+    /// completes with a [`CONNECT`](CFSocketCallBackType::CONNECT) callback.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfsocketconnecttoaddress(_:_:_:)).
+    #[inline]
+    #[doc(alias = "CFSocketConnectToAddress")]
+    pub fn connect_to_address(
+        &self,
+        address: &CFData,
+        timeout: CFTimeInterval,
+    ) -> Result<(), CFSocketError> {
+        CFSocketError::result(unsafe { sys::CFSocketConnectToAddress(self, address, timeout) })
+    }
+
+    /// Sends `data` to the `sockaddr` in `address`, or to the connected peer
+    /// if `address` is [`None`].
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfsocketsenddata(_:_:_:_:)).
+    #[inline]
+    #[doc(alias = "CFSocketSendData")]
+    pub fn send_data(
+        &self,
+        address: Option<&CFData>,
+        data: &CFData,
+        timeout: CFTimeInterval,
+    ) -> Result<(), CFSocketError> {
+        let address = address.map_or(ptr::null(), |address| address);
+        CFSocketError::result(unsafe { sys::CFSocketSendData(self, address, data, timeout) })
+    }
+
+    /// Creates a source that delivers the events of `self` when added to a
+    /// [`CFRunLoop`](super::CFRunLoop).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfsocketcreaterunloopsource(_:_:_:)).
+    #[inline]
+    #[doc(alias = "CFSocketCreateRunLoopSource")]
+    pub fn create_run_loop_source(&self, order: CFIndex) -> Arc<CFRunLoopSource> {
+        unsafe { Arc::from_raw(sys::CFSocketCreateRunLoopSource(ptr::null(), self, order)) }
+    }
+
+    /// Enables the callbacks in `callback_types`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfsocketenablecallbacks(_:_:)).
+    #[inline]
+    #[doc(alias = "CFSocketEnableCallBacks")]
+    pub fn enable_callbacks(&self, callback_types: CFSocketCallBackType) {
+        unsafe { sys::CFSocketEnableCallBacks(self, callback_types) }
+    }
+
+    /// Disables the callbacks in `callback_types`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfsocketdisablecallbacks(_:_:)).
+    #[inline]
+    #[doc(alias = "CFSocketDisableCallBacks")]
+    pub fn disable_callbacks(&self, callback_types: CFSocketCallBackType) {
+        unsafe { sys::CFSocketDisableCallBacks(self, callback_types) }
+    }
+
+    /// Returns the options of `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfsocketgetsocketflags(_:)).
+    #[inline]
+    #[doc(alias = "CFSocketGetSocketFlags")]
+    pub fn flags(&self) -> CFSocketFlags {
+        unsafe { sys::CFSocketGetSocketFlags(self) }
+    }
+
+    /// Sets the options of `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfsocketsetsocketflags(_:_:)).
+    #[inline]
+    #[doc(alias = "CFSocketSetSocketFlags")]
+    pub fn set_flags(&self, flags: CFSocketFlags) {
+        unsafe { sys::CFSocketSetSocketFlags(self, flags) }
+    }
+
+    /// Invalidates `self`, stopping its callbacks and closing the native
+    /// socket if [`CLOSE_ON_INVALIDATE`](CFSocketFlags::CLOSE_ON_INVALIDATE)
+    /// is set.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfsocketinvalidate(_:)).
+    #[inline]
+    #[doc(alias = "CFSocketInvalidate")]
+    pub fn invalidate(&self) {
+        unsafe { sys::CFSocketInvalidate(self) }
+    }
+
+    /// Returns `true` if `self` is valid and able to call back.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfsocketisvalid(_:)).
+    #[inline]
+    #[doc(alias = "CFSocketIsValid")]
+    pub fn is_valid(&self) -> bool {
+        unsafe { sys::CFSocketIsValid(self) != 0 }
+    }
+}
@@ -0,0 +1,175 @@
+use super::{sys, CFIndex, CFOptionFlags, CFRunLoopTimerContext, CFSocketNativeHandle, CFString};
+use crate::core::Arc;
+use std::{ops, ptr};
+
+mod property;
+mod read;
+mod write;
+
+pub use property::*;
+pub use read::*;
+pub use write::*;
+
+/// The state of a [`CFReadStream`] or [`CFWriteStream`].
+///
+/// See [documentation](https://developer.apple.com/documentation/corefoundation/cfstreamstatus).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CFStreamStatus(pub CFIndex);
+
+impl CFStreamStatus {
+    /// The stream has not been opened.
+    #[doc(alias = "kCFStreamStatusNotOpen")]
+    pub const NOT_OPEN: Self = Self(0);
+
+    /// The stream is being opened.
+    #[doc(alias = "kCFStreamStatusOpening")]
+    pub const OPENING: Self = Self(1);
+
+    /// The stream is open.
+    #[doc(alias = "kCFStreamStatusOpen")]
+    pub const OPEN: Self = Self(2);
+
+    /// The stream is being read from.
+    #[doc(alias = "kCFStreamStatusReading")]
+    pub const READING: Self = Self(3);
+
+    /// The stream is being written to.
+    #[doc(alias = "kCFStreamStatusWriting")]
+    pub const WRITING: Self = Self(4);
+
+    /// There is no more data to read, or no more data can be written.
+    #[doc(alias = "kCFStreamStatusAtEnd")]
+    pub const AT_END: Self = Self(5);
+
+    /// The stream has been closed.
+    #[doc(alias = "kCFStreamStatusClosed")]
+    pub const CLOSED: Self = Self(6);
+
+    /// An error occurred on the stream.
+    #[doc(alias = "kCFStreamStatusError")]
+    pub const ERROR: Self = Self(7);
+}
+
+/// Events that can be delivered to the client of a [`CFReadStream`] or
+/// [`CFWriteStream`].
+///
+/// See [documentation](https://developer.apple.com/documentation/corefoundation/cfstreameventtype).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CFStreamEventType(pub CFOptionFlags);
+
+impl ops::BitOr for CFStreamEventType {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl CFStreamEventType {
+    /// No event.
+    #[doc(alias = "kCFStreamEventNone")]
+    pub const NONE: Self = Self(0);
+
+    /// The stream finished opening.
+    #[doc(alias = "kCFStreamEventOpenCompleted")]
+    pub const OPEN_COMPLETED: Self = Self(1);
+
+    /// The read stream has bytes that can be read without blocking.
+    #[doc(alias = "kCFStreamEventHasBytesAvailable")]
+    pub const HAS_BYTES_AVAILABLE: Self = Self(2);
+
+    /// The write stream can be written to without blocking.
+    #[doc(alias = "kCFStreamEventCanAcceptBytes")]
+    pub const CAN_ACCEPT_BYTES: Self = Self(4);
+
+    /// An error occurred on the stream.
+    #[doc(alias = "kCFStreamEventErrorOccurred")]
+    pub const ERROR_OCCURRED: Self = Self(8);
+
+    /// The end of the stream was reached.
+    #[doc(alias = "kCFStreamEventEndEncountered")]
+    pub const END_ENCOUNTERED: Self = Self(16);
+
+    /// Returns `true` if all of the events in `other` are set in `self`.
+    #[inline]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+/// A structure that contains program-defined data and callbacks for the
+/// client of a [`CFReadStream`] or [`CFWriteStream`].
+///
+/// This has the same layout as
+/// [`CFRunLoopTimerContext`](super::CFRunLoopTimerContext).
+///
+/// See [documentation](https://developer.apple.com/documentation/corefoundation/cfstreamclientcontext).
+pub type CFStreamClientContext = CFRunLoopTimerContext;
+
+/// Creates a pair of streams that read from and write to the connected native
+/// socket `sock`.
+///
+/// The socket is not closed when the streams are, unless the
+/// `should_close_native_socket` property is set. This property requires the
+/// **`cf_network`** feature flag.
+///
+/// See [documentation](https://developer.apple.com/documentation/corefoundation/cfstreamcreatepairwithsocket(_:_:_:_:)).
+#[inline]
+#[allow(non_snake_case)]
+pub fn CFStreamCreatePairWithSocket(
+    sock: CFSocketNativeHandle,
+) -> Option<(Arc<CFReadStream>, Arc<CFWriteStream>)> {
+    unsafe {
+        let mut read_stream = ptr::null();
+        let mut write_stream = ptr::null();
+        sys::CFStreamCreatePairWithSocket(ptr::null(), sock, &mut read_stream, &mut write_stream);
+        adopt_pair(read_stream, write_stream)
+    }
+}
+
+/// Creates a pair of streams that connect over TCP to `port` on `host`, which
+/// is a host name or an IPv4 or IPv6 address.
+///
+/// The connection is made when either stream is opened.
+///
+/// See [documentation](https://developer.apple.com/documentation/corefoundation/cfstreamcreatepairwithsockettohost(_:_:_:_:_:)).
+#[inline]
+#[allow(non_snake_case)]
+pub fn CFStreamCreatePairWithSocketToHost(
+    host: &CFString,
+    port: u32,
+) -> Option<(Arc<CFReadStream>, Arc<CFWriteStream>)> {
+    unsafe {
+        let mut read_stream = ptr::null();
+        let mut write_stream = ptr::null();
+        sys::CFStreamCreatePairWithSocketToHost(
+            ptr::null(),
+            host,
+            port,
+            &mut read_stream,
+            &mut write_stream,
+        );
+        adopt_pair(read_stream, write_stream)
+    }
+}
+
+/// Takes ownership of +1 streams, returning [`None`] if either is null.
+unsafe fn adopt_pair(
+    read_stream: *const CFReadStream,
+    write_stream: *const CFWriteStream,
+) -> Option<(Arc<CFReadStream>, Arc<CFWriteStream>)> {
+    let read_stream = if read_stream.is_null() {
+        None
+    } else {
+        Some(Arc::from_raw(read_stream))
+    };
+    let write_stream = if write_stream.is_null() {
+        None
+    } else {
+        Some(Arc::from_raw(write_stream))
+    };
+    Some((read_stream?, write_stream?))
+}
@@ -0,0 +1,63 @@
+use crate::core_foundation::CFString;
+
+object_wrapper! {
+    /// The name of a property of a
+    /// [`CFReadStream`](super::CFReadStream) or
+    /// [`CFWriteStream`](super::CFWriteStream).
+    ///
+    /// Properties for secure and proxied connections are provided by the
+    /// [`cf_network`](crate::cf_network) module.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfstreampropertykey).
+    #[derive(PartialEq, Eq, Hash)]
+    pub wrapper CFStreamPropertyKey: CFString;
+}
+
+macro_rules! property_key {
+    ($(#[$docs:meta])+ $fn:ident = $value:literal) => {
+        $(#[$docs])+
+        #[inline]
+        #[doc(alias = $value)]
+        pub fn $fn() -> &'static CFStreamPropertyKey {
+            extern "C" {
+                #[link_name = $value]
+                static VALUE: &'static CFStreamPropertyKey;
+            }
+            unsafe { VALUE }
+        }
+    };
+}
+
+impl CFStreamPropertyKey {
+    property_key! {
+        /// The `CFData` holding the bytes written to a stream created with
+        /// [`CFWriteStream::with_allocated_buffers`](super::CFWriteStream::with_allocated_buffers).
+        data_written = "kCFStreamPropertyDataWritten"
+    }
+
+    property_key! {
+        /// The `CFData` holding the native socket of a socket stream.
+        socket_native_handle = "kCFStreamPropertySocketNativeHandle"
+    }
+
+    property_key! {
+        /// The `CFString` of the remote host of a socket stream.
+        socket_remote_host_name = "kCFStreamPropertySocketRemoteHostName"
+    }
+
+    property_key! {
+        /// The `CFNumber` of the remote port of a socket stream.
+        socket_remote_port_number = "kCFStreamPropertySocketRemotePortNumber"
+    }
+
+    property_key! {
+        /// A `CFBoolean` that makes a file write stream append instead of
+        /// truncate. This must be set before the stream is opened.
+        append_to_file = "kCFStreamPropertyAppendToFile"
+    }
+
+    property_key! {
+        /// The `CFNumber` of the current offset in a file stream.
+        file_current_offset = "kCFStreamPropertyFileCurrentOffset"
+    }
+}
@@ -0,0 +1,285 @@
+use super::{CFStreamClientContext, CFStreamEventType, CFStreamPropertyKey, CFStreamStatus};
+use crate::{
+    core::Arc,
+    core_foundation::{
+        release_boxed, sys, CFAllocator, CFError, CFIndex, CFRunLoop, CFRunLoopMode,
+        CFRunLoopSchedulable, CFType, CFTypeID, CFURL,
+    },
+};
+use std::{ffi::c_void, panic, process, ptr};
+
+subclass! {
+    /// A stream of bytes that can be read from, such as a file or socket.
+    ///
+    /// Documentation:
+    /// [Swift](https://developer.apple.com/documentation/corefoundation/cfreadstream?language=swift) |
+    /// [Objective-C](https://developer.apple.com/documentation/corefoundation/cfreadstream?language=objc)
+    #[derive(PartialEq, Hash)]
+    pub class CFReadStream: CFType<'static>;
+}
+
+/// Callback invoked when a [`CFReadStream`] has an event for its client.
+///
+/// See [documentation](https://developer.apple.com/documentation/corefoundation/cfreadstreamclientcallback).
+pub type CFReadStreamClientCallBack =
+    unsafe extern "C" fn(stream: *mut CFReadStream, event: CFStreamEventType, info: *mut c_void);
+
+/// Creating a read stream.
+impl CFReadStream {
+    /// Returns the type identifier for `CFReadStream`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfreadstreamgettypeid()).
+    #[inline]
+    #[doc(alias = "CFReadStreamGetTypeID")]
+    pub fn type_id() -> CFTypeID {
+        unsafe { sys::CFReadStreamGetTypeID() }
+    }
+
+    /// Creates a stream that reads `bytes` without copying them.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfreadstreamcreatewithbytesnocopy(_:_:_:_:)).
+    #[inline]
+    #[doc(alias = "CFReadStreamCreateWithBytesNoCopy")]
+    pub fn from_static_bytes(bytes: &'static [u8]) -> Arc<Self> {
+        unsafe {
+            Arc::from_raw(sys::CFReadStreamCreateWithBytesNoCopy(
+                ptr::null(),
+                bytes.as_ptr(),
+                bytes.len() as CFIndex,
+                CFAllocator::null(),
+            ))
+        }
+    }
+
+    /// Creates a stream that reads the file at `url`.
+    ///
+    /// The file is not accessed until the stream is opened.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfreadstreamcreatewithfile(_:_:)).
+    #[inline]
+    #[doc(alias = "CFReadStreamCreateWithFile")]
+    pub fn with_file(url: &CFURL) -> Arc<Self> {
+        unsafe { Arc::from_raw(sys::CFReadStreamCreateWithFile(ptr::null(), url)) }
+    }
+}
+
+/// Opening, reading, and closing.
+impl CFReadStream {
+    /// Opens the stream, returning `false` if it failed to open immediately.
+    ///
+    /// Streams that open in the background, such as sockets, deliver
+    /// [`OPEN_COMPLETED`](CFStreamEventType::OPEN_COMPLETED) to their client
+    /// when done.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfreadstreamopen(_:)).
+    #[inline]
+    #[doc(alias = "CFReadStreamOpen")]
+    pub fn open(&self) -> bool {
+        unsafe { sys::CFReadStreamOpen(self) != 0 }
+    }
+
+    /// Closes the stream, stopping it from delivering any further events.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfreadstreamclose(_:)).
+    #[inline]
+    #[doc(alias = "CFReadStreamClose")]
+    pub fn close(&self) {
+        unsafe { sys::CFReadStreamClose(self) }
+    }
+
+    /// Returns the current state of the stream.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfreadstreamgetstatus(_:)).
+    #[inline]
+    #[doc(alias = "CFReadStreamGetStatus")]
+    pub fn status(&self) -> CFStreamStatus {
+        unsafe { sys::CFReadStreamGetStatus(self) }
+    }
+
+    /// Returns the error that occurred on the stream, if any.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfreadstreamcopyerror(_:)).
+    #[inline]
+    #[doc(alias = "CFReadStreamCopyError")]
+    pub fn error(&self) -> Option<Arc<CFError>> {
+        unsafe {
+            let error = sys::CFReadStreamCopyError(self);
+            if error.is_null() {
+                None
+            } else {
+                Some(Arc::from_raw(error))
+            }
+        }
+    }
+
+    /// Returns `true` if the stream can be read from without blocking.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfreadstreamhasbytesavailable(_:)).
+    #[inline]
+    #[doc(alias = "CFReadStreamHasBytesAvailable")]
+    pub fn has_bytes_available(&self) -> bool {
+        unsafe { sys::CFReadStreamHasBytesAvailable(self) != 0 }
+    }
+
+    /// Reads into `buffer`, returning the number of bytes read or 0 at the
+    /// end of the stream.
+    ///
+    /// This blocks until at least one byte is available.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfreadstreamread(_:_:_:)).
+    #[inline]
+    #[doc(alias = "CFReadStreamRead")]
+    pub fn read(&self, buffer: &mut [u8]) -> Result<usize, Arc<CFError>> {
+        let len = buffer.len() as CFIndex;
+        let count = unsafe { sys::CFReadStreamRead(self, buffer.as_mut_ptr(), len) };
+        if count < 0 {
+            Err(self.error().expect("stream failed without an error"))
+        } else {
+            Ok(count as usize)
+        }
+    }
+}
+
+/// Properties.
+impl CFReadStream {
+    /// Returns the value of `key`, or [`None`] if the stream does not have
+    /// it.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfreadstreamcopyproperty(_:_:)).
+    #[inline]
+    #[doc(alias = "CFReadStreamCopyProperty")]
+    pub fn property(&self, key: &CFStreamPropertyKey) -> Option<Arc<CFType<'static>>> {
+        unsafe {
+            let value = sys::CFReadStreamCopyProperty(self, key);
+            if value.is_null() {
+                None
+            } else {
+                Some(Arc::from_raw(value))
+            }
+        }
+    }
+
+    /// Sets or removes the value of `key`, returning `false` if the stream
+    /// does not support it.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfreadstreamsetproperty(_:_:_:)).
+    #[inline]
+    #[doc(alias = "CFReadStreamSetProperty")]
+    pub fn set_property(&self, key: &CFStreamPropertyKey, value: Option<&CFType<'static>>) -> bool {
+        let value = value.map_or(ptr::null(), |value| value);
+        unsafe { sys::CFReadStreamSetProperty(self, key, value) != 0 }
+    }
+}
+
+/// Asynchronous events.
+impl CFReadStream {
+    /// Calls `callback` with each of `events` that occurs on the stream,
+    /// replacing any previous client.
+    ///
+    /// Events are only delivered while the stream is
+    /// [scheduled](Self::schedule_with_run_loop) on a run loop. Returns
+    /// `false` if the stream does not support asynchronous events.
+    ///
+    /// It is safe to panic within `callback`. Panics will abort the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfreadstreamsetclient(_:_:_:_:)).
+    #[doc(alias = "CFReadStreamSetClient")]
+    pub fn set_client<F>(&self, events: CFStreamEventType, callback: F) -> bool
+    where
+        F: FnMut(&CFReadStream, CFStreamEventType) + Send + 'static,
+    {
+        unsafe extern "C" fn wrapped_callback<F>(
+            stream: *mut CFReadStream,
+            event: CFStreamEventType,
+            info: *mut c_void,
+        ) where
+            F: FnMut(&CFReadStream, CFStreamEventType) + Send + 'static,
+        {
+            let callback = &mut *(info as *mut F);
+            let stream = &*stream;
+
+            // Unwinding into Core Foundation is undefined behavior.
+            let result = panic::catch_unwind(panic::AssertUnwindSafe(|| callback(stream, event)));
+            if result.is_err() {
+                process::abort();
+            }
+        }
+
+        let mut context = CFStreamClientContext {
+            version: 0,
+            info: Box::into_raw(Box::new(callback)).cast(),
+            retain: None,
+            release: Some(release_boxed::<F>),
+            copy_description: None,
+        };
+
+        unsafe {
+            // The stream releases the boxed closure when the client is
+            // replaced or the stream is deallocated.
+            let set = self.set_client_raw(events, Some(wrapped_callback::<F>), Some(&mut context));
+            if !set {
+                release_boxed::<F>(context.info);
+            }
+            set
+        }
+    }
+
+    /// Removes the client of the stream, stopping all events.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfreadstreamsetclient(_:_:_:_:)).
+    #[inline]
+    #[doc(alias = "CFReadStreamSetClient")]
+    pub fn clear_client(&self) {
+        unsafe { self.set_client_raw(CFStreamEventType::NONE, None, None) };
+    }
+
+    /// Sets the client of the stream with a C function callback.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfreadstreamsetclient(_:_:_:_:)).
+    ///
+    /// # Safety
+    ///
+    /// `callback` must be safe to call with the `info` pointer of `context`,
+    /// and the callbacks of `context` must correctly manage `info`.
+    #[inline]
+    #[doc(alias = "CFReadStreamSetClient")]
+    pub unsafe fn set_client_raw(
+        &self,
+        events: CFStreamEventType,
+        callback: Option<CFReadStreamClientCallBack>,
+        context: Option<&mut CFStreamClientContext>,
+    ) -> bool {
+        let context = context.map_or(ptr::null_mut(), |context| context);
+        sys::CFReadStreamSetClient(self, events, callback, context) != 0
+    }
+
+    /// Delivers the events of the stream on `run_loop` in `mode`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfreadstreamschedulewithrunloop(_:_:_:)).
+    #[inline]
+    #[doc(alias = "CFReadStreamScheduleWithRunLoop")]
+    pub fn schedule_with_run_loop(&self, run_loop: &CFRunLoop, mode: &CFRunLoopMode) {
+        unsafe { sys::CFReadStreamScheduleWithRunLoop(self, run_loop, mode) }
+    }
+
+    /// Stops delivering the events of the stream on `run_loop` in `mode`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfreadstreamunschedulefromrunloop(_:_:_:)).
+    #[inline]
+    #[doc(alias = "CFReadStreamUnscheduleFromRunLoop")]
+    pub fn unschedule_from_run_loop(&self, run_loop: &CFRunLoop, mode: &CFRunLoopMode) {
+        unsafe { sys::CFReadStreamUnscheduleFromRunLoop(self, run_loop, mode) }
+    }
+}
+
+impl CFRunLoopSchedulable for CFReadStream {
+    #[inline]
+    fn schedule(&self, run_loop: &CFRunLoop, mode: &CFRunLoopMode) {
+        self.schedule_with_run_loop(run_loop, mode);
+    }
+
+    #[inline]
+    fn unschedule(&self, run_loop: &CFRunLoop, mode: &CFRunLoopMode) {
+        self.unschedule_from_run_loop(run_loop, mode);
+    }
+}
@@ -0,0 +1,287 @@
+use super::{CFStreamClientContext, CFStreamEventType, CFStreamPropertyKey, CFStreamStatus};
+use crate::{
+    core::Arc,
+    core_foundation::{
+        release_boxed, sys, CFError, CFIndex, CFRunLoop, CFRunLoopMode, CFRunLoopSchedulable,
+        CFType, CFTypeID, CFURL,
+    },
+};
+use std::{ffi::c_void, panic, process, ptr};
+
+subclass! {
+    /// A stream of bytes that can be written to, such as a file or socket.
+    ///
+    /// Documentation:
+    /// [Swift](https://developer.apple.com/documentation/corefoundation/cfwritestream?language=swift) |
+    /// [Objective-C](https://developer.apple.com/documentation/corefoundation/cfwritestream?language=objc)
+    #[derive(PartialEq, Hash)]
+    pub class CFWriteStream: CFType<'static>;
+}
+
+/// Callback invoked when a [`CFWriteStream`] has an event for its client.
+///
+/// See [documentation](https://developer.apple.com/documentation/corefoundation/cfwritestreamclientcallback).
+pub type CFWriteStreamClientCallBack =
+    unsafe extern "C" fn(stream: *mut CFWriteStream, event: CFStreamEventType, info: *mut c_void);
+
+/// Creating a write stream.
+impl CFWriteStream {
+    /// Returns the type identifier for `CFWriteStream`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfwritestreamgettypeid()).
+    #[inline]
+    #[doc(alias = "CFWriteStreamGetTypeID")]
+    pub fn type_id() -> CFTypeID {
+        unsafe { sys::CFWriteStreamGetTypeID() }
+    }
+
+    /// Creates a stream that writes to memory.
+    ///
+    /// The written bytes are available from the
+    /// [`data_written`](CFStreamPropertyKey::data_written) property.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfwritestreamcreatewithallocatedbuffers(_:_:)).
+    #[inline]
+    #[doc(alias = "CFWriteStreamCreateWithAllocatedBuffers")]
+    pub fn with_allocated_buffers() -> Arc<Self> {
+        unsafe {
+            Arc::from_raw(sys::CFWriteStreamCreateWithAllocatedBuffers(
+                ptr::null(),
+                ptr::null(),
+            ))
+        }
+    }
+
+    /// Creates a stream that writes the file at `url`.
+    ///
+    /// The file is not accessed until the stream is opened, and is truncated
+    /// unless [`append_to_file`](CFStreamPropertyKey::append_to_file) is set.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfwritestreamcreatewithfile(_:_:)).
+    #[inline]
+    #[doc(alias = "CFWriteStreamCreateWithFile")]
+    pub fn with_file(url: &CFURL) -> Arc<Self> {
+        unsafe { Arc::from_raw(sys::CFWriteStreamCreateWithFile(ptr::null(), url)) }
+    }
+}
+
+/// Opening, writing, and closing.
+impl CFWriteStream {
+    /// Opens the stream, returning `false` if it failed to open immediately.
+    ///
+    /// Streams that open in the background, such as sockets, deliver
+    /// [`OPEN_COMPLETED`](CFStreamEventType::OPEN_COMPLETED) to their client
+    /// when done.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfwritestreamopen(_:)).
+    #[inline]
+    #[doc(alias = "CFWriteStreamOpen")]
+    pub fn open(&self) -> bool {
+        unsafe { sys::CFWriteStreamOpen(self) != 0 }
+    }
+
+    /// Closes the stream, stopping it from delivering any further events.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfwritestreamclose(_:)).
+    #[inline]
+    #[doc(alias = "CFWriteStreamClose")]
+    pub fn close(&self) {
+        unsafe { sys::CFWriteStreamClose(self) }
+    }
+
+    /// Returns the current state of the stream.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfwritestreamgetstatus(_:)).
+    #[inline]
+    #[doc(alias = "CFWriteStreamGetStatus")]
+    pub fn status(&self) -> CFStreamStatus {
+        unsafe { sys::CFWriteStreamGetStatus(self) }
+    }
+
+    /// Returns the error that occurred on the stream, if any.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfwritestreamcopyerror(_:)).
+    #[inline]
+    #[doc(alias = "CFWriteStreamCopyError")]
+    pub fn error(&self) -> Option<Arc<CFError>> {
+        unsafe {
+            let error = sys::CFWriteStreamCopyError(self);
+            if error.is_null() {
+                None
+            } else {
+                Some(Arc::from_raw(error))
+            }
+        }
+    }
+
+    /// Returns `true` if the stream can be written to without blocking.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfwritestreamcanacceptbytes(_:)).
+    #[inline]
+    #[doc(alias = "CFWriteStreamCanAcceptBytes")]
+    pub fn can_accept_bytes(&self) -> bool {
+        unsafe { sys::CFWriteStreamCanAcceptBytes(self) != 0 }
+    }
+
+    /// Writes from `buffer`, returning the number of bytes written or 0 if
+    /// the stream is full.
+    ///
+    /// This blocks until at least one byte can be written.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfwritestreamwrite(_:_:_:)).
+    #[inline]
+    #[doc(alias = "CFWriteStreamWrite")]
+    pub fn write(&self, buffer: &[u8]) -> Result<usize, Arc<CFError>> {
+        let len = buffer.len() as CFIndex;
+        let count = unsafe { sys::CFWriteStreamWrite(self, buffer.as_ptr(), len) };
+        if count < 0 {
+            Err(self.error().expect("stream failed without an error"))
+        } else {
+            Ok(count as usize)
+        }
+    }
+}
+
+/// Properties.
+impl CFWriteStream {
+    /// Returns the value of `key`, or [`None`] if the stream does not have
+    /// it.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfwritestreamcopyproperty(_:_:)).
+    #[inline]
+    #[doc(alias = "CFWriteStreamCopyProperty")]
+    pub fn property(&self, key: &CFStreamPropertyKey) -> Option<Arc<CFType<'static>>> {
+        unsafe {
+            let value = sys::CFWriteStreamCopyProperty(self, key);
+            if value.is_null() {
+                None
+            } else {
+                Some(Arc::from_raw(value))
+            }
+        }
+    }
+
+    /// Sets or removes the value of `key`, returning `false` if the stream
+    /// does not support it.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfwritestreamsetproperty(_:_:_:)).
+    #[inline]
+    #[doc(alias = "CFWriteStreamSetProperty")]
+    pub fn set_property(&self, key: &CFStreamPropertyKey, value: Option<&CFType<'static>>) -> bool {
+        let value = value.map_or(ptr::null(), |value| value);
+        unsafe { sys::CFWriteStreamSetProperty(self, key, value) != 0 }
+    }
+}
+
+/// Asynchronous events.
+impl CFWriteStream {
+    /// Calls `callback` with each of `events` that occurs on the stream,
+    /// replacing any previous client.
+    ///
+    /// Events are only delivered while the stream is
+    /// [scheduled](Self::schedule_with_run_loop) on a run loop. Returns
+    /// `false` if the stream does not support asynchronous events.
+    ///
+    /// It is safe to panic within `callback`. Panics will abort the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfwritestreamsetclient(_:_:_:_:)).
+    #[doc(alias = "CFWriteStreamSetClient")]
+    pub fn set_client<F>(&self, events: CFStreamEventType, callback: F) -> bool
+    where
+        F: FnMut(&CFWriteStream, CFStreamEventType) + Send + 'static,
+    {
+        unsafe extern "C" fn wrapped_callback<F>(
+            stream: *mut CFWriteStream,
+            event: CFStreamEventType,
+            info: *mut c_void,
+        ) where
+            F: FnMut(&CFWriteStream, CFStreamEventType) + Send + 'static,
+        {
+            let callback = &mut *(info as *mut F);
+            let stream = &*stream;
+
+            // Unwinding into Core Foundation is undefined behavior.
+            let result = panic::catch_unwind(panic::AssertUnwindSafe(|| callback(stream, event)));
+            if result.is_err() {
+                process::abort();
+            }
+        }
+
+        let mut context = CFStreamClientContext {
+            version: 0,
+            info: Box::into_raw(Box::new(callback)).cast(),
+            retain: None,
+            release: Some(release_boxed::<F>),
+            copy_description: None,
+        };
+
+        unsafe {
+            // The stream releases the boxed closure when the client is
+            // replaced or the stream is deallocated.
+            let set = self.set_client_raw(events, Some(wrapped_callback::<F>), Some(&mut context));
+            if !set {
+                release_boxed::<F>(context.info);
+            }
+            set
+        }
+    }
+
+    /// Removes the client of the stream, stopping all events.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfwritestreamsetclient(_:_:_:_:)).
+    #[inline]
+    #[doc(alias = "CFWriteStreamSetClient")]
+    pub fn clear_client(&self) {
+        unsafe { self.set_client_raw(CFStreamEventType::NONE, None, None) };
+    }
+
+    /// Sets the client of the stream with a C function callback.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfwritestreamsetclient(_:_:_:_:)).
+    ///
+    /// # Safety
+    ///
+    /// `callback` must be safe to call with the `info` pointer of `context`,
+    /// and the callbacks of `context` must correctly manage `info`.
+    #[inline]
+    #[doc(alias = "CFWriteStreamSetClient")]
+    pub unsafe fn set_client_raw(
+        &self,
+        events: CFStreamEventType,
+        callback: Option<CFWriteStreamClientCallBack>,
+        context: Option<&mut CFStreamClientContext>,
+    ) -> bool {
+        let context = context.map_or(ptr::null_mut(), |context| context);
+        sys::CFWriteStreamSetClient(self, events, callback, context) != 0
+    }
+
+    /// Delivers the events of the stream on `run_loop` in `mode`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfwritestreamschedulewithrunloop(_:_:_:)).
+    #[inline]
+    #[doc(alias = "CFWriteStreamScheduleWithRunLoop")]
+    pub fn schedule_with_run_loop(&self, run_loop: &CFRunLoop, mode: &CFRunLoopMode) {
+        unsafe { sys::CFWriteStreamScheduleWithRunLoop(self, run_loop, mode) }
+    }
+
+    /// Stops delivering the events of the stream on `run_loop` in `mode`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfwritestreamunschedulefromrunloop(_:_:_:)).
+    #[inline]
+    #[doc(alias = "CFWriteStreamUnscheduleFromRunLoop")]
+    pub fn unschedule_from_run_loop(&self, run_loop: &CFRunLoop, mode: &CFRunLoopMode) {
+        unsafe { sys::CFWriteStreamUnscheduleFromRunLoop(self, run_loop, mode) }
+    }
+}
+
+impl CFRunLoopSchedulable for CFWriteStream {
+    #[inline]
+    fn schedule(&self, run_loop: &CFRunLoop, mode: &CFRunLoopMode) {
+        self.schedule_with_run_loop(run_loop, mode);
+    }
+
+    #[inline]
+    fn unschedule(&self, run_loop: &CFRunLoop, mode: &CFRunLoopMode) {
+        self.unschedule_from_run_loop(run_loop, mode);
+    }
+}
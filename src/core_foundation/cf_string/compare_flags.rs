@@ -0,0 +1,80 @@
+use crate::core_foundation::CFOptionFlags;
+use std::ops;
+
+/// Flags used by `CFString` comparison functions.
+///
+/// See [documentation](https://developer.apple.com/documentation/corefoundation/cfstringcompareflags).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CFStringCompareFlags(pub CFOptionFlags);
+
+impl ops::BitOr for CFStringCompareFlags {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl ops::BitOrAssign for CFStringCompareFlags {
+    #[inline]
+    fn bitor_assign(&mut self, other: Self) {
+        self.0 |= other.0;
+    }
+}
+
+impl CFStringCompareFlags {
+    /// Performs a literal, case-sensitive comparison.
+    pub const NONE: Self = Self(0);
+
+    /// Specifies that the comparison should ignore differences in case between
+    /// alphabetical characters.
+    #[doc(alias = "kCFCompareCaseInsensitive")]
+    pub const CASE_INSENSITIVE: Self = Self(1);
+
+    /// Specifies that the comparison should start at the last elements of the
+    /// entities being compared.
+    #[doc(alias = "kCFCompareBackwards")]
+    pub const BACKWARDS: Self = Self(4);
+
+    /// Specifies that the comparison is to be performed only on the first
+    /// characters of the strings.
+    #[doc(alias = "kCFCompareAnchored")]
+    pub const ANCHORED: Self = Self(8);
+
+    /// Specifies that loose equivalence is acceptable, especially as pertains
+    /// to diacritical marks.
+    #[doc(alias = "kCFCompareNonliteral")]
+    pub const NONLITERAL: Self = Self(16);
+
+    /// Specifies that the comparison should take into account differences
+    /// related to locale, such as the way the decimal separator is represented.
+    #[doc(alias = "kCFCompareLocalized")]
+    pub const LOCALIZED: Self = Self(32);
+
+    /// Specifies that represented numeric values should be used as the basis
+    /// for comparison and not the actual character values.
+    #[doc(alias = "kCFCompareNumerically")]
+    pub const NUMERICALLY: Self = Self(64);
+
+    /// Specifies that the comparison should ignore diacritic markers.
+    #[doc(alias = "kCFCompareDiacriticInsensitive")]
+    pub const DIACRITIC_INSENSITIVE: Self = Self(128);
+
+    /// Specifies that the comparison should ignore width differences.
+    #[doc(alias = "kCFCompareWidthInsensitive")]
+    pub const WIDTH_INSENSITIVE: Self = Self(256);
+
+    /// Specifies that the comparison is forced to return either
+    /// `LessThan` or `GreaterThan` if the strings are equivalent but not
+    /// strictly equal.
+    #[doc(alias = "kCFCompareForcedOrdering")]
+    pub const FORCED_ORDERING: Self = Self(512);
+
+    /// Returns `true` if all of the flags in `other` are set in `self`.
+    #[inline]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
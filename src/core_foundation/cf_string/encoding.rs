@@ -0,0 +1,122 @@
+use crate::core_foundation::sys;
+use std::fmt;
+
+/// An integer type for constants used to specify supported string encodings in
+/// various `CFString` functions.
+///
+/// See [documentation](https://developer.apple.com/documentation/corefoundation/cfstringencoding).
+#[repr(transparent)]
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct CFStringEncoding(pub u32);
+
+impl fmt::Debug for CFStringEncoding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Self::MAC_ROMAN => f.write_str("MacRoman"),
+            Self::WINDOWS_LATIN1 => f.write_str("WindowsLatin1"),
+            Self::ISO_LATIN1 => f.write_str("ISOLatin1"),
+            Self::NEXT_STEP_LATIN => f.write_str("NextStepLatin"),
+            Self::ASCII => f.write_str("ASCII"),
+            Self::UTF16 => f.write_str("UTF16"),
+            Self::UTF8 => f.write_str("UTF8"),
+            Self::NON_LOSSY_ASCII => f.write_str("NonLossyASCII"),
+            Self::UTF16_BE => f.write_str("UTF16BE"),
+            Self::UTF16_LE => f.write_str("UTF16LE"),
+            Self::UTF32 => f.write_str("UTF32"),
+            Self::UTF32_BE => f.write_str("UTF32BE"),
+            Self::UTF32_LE => f.write_str("UTF32LE"),
+            _ => f.debug_tuple("CFStringEncoding").field(&self.0).finish(),
+        }
+    }
+}
+
+/// Built-in encodings.
+///
+/// See [documentation](https://developer.apple.com/documentation/corefoundation/cfstringbuiltinencodings).
+impl CFStringEncoding {
+    /// An encoding constant that identifies the Mac Roman encoding.
+    #[doc(alias = "kCFStringEncodingMacRoman")]
+    pub const MAC_ROMAN: Self = Self(0);
+
+    /// An encoding constant that identifies the Windows Latin 1 encoding
+    /// (ANSI codepage 1252).
+    #[doc(alias = "kCFStringEncodingWindowsLatin1")]
+    pub const WINDOWS_LATIN1: Self = Self(0x0500);
+
+    /// An encoding constant that identifies the ISO Latin 1 encoding
+    /// (ISO 8859-1).
+    #[doc(alias = "kCFStringEncodingISOLatin1")]
+    pub const ISO_LATIN1: Self = Self(0x0201);
+
+    /// An encoding constant that identifies the NextStep encoding.
+    #[doc(alias = "kCFStringEncodingNextStepLatin")]
+    pub const NEXT_STEP_LATIN: Self = Self(0x0B01);
+
+    /// An encoding constant that identifies the ASCII encoding (decimal values
+    /// 0 through 127).
+    #[doc(alias = "kCFStringEncodingASCII")]
+    pub const ASCII: Self = Self(0x0600);
+
+    /// An encoding constant that identifies the Unicode encoding.
+    ///
+    /// This is an alias for [`UTF16`](Self::UTF16).
+    #[doc(alias = "kCFStringEncodingUnicode")]
+    pub const UNICODE: Self = Self::UTF16;
+
+    /// An encoding constant that identifies the UTF 16 encoding.
+    #[doc(alias = "kCFStringEncodingUTF16")]
+    pub const UTF16: Self = Self(0x0100);
+
+    /// An encoding constant that identifies the UTF 8 encoding.
+    #[doc(alias = "kCFStringEncodingUTF8")]
+    pub const UTF8: Self = Self(0x08000100);
+
+    /// An encoding constant that identifies non-lossy ASCII encoding.
+    #[doc(alias = "kCFStringEncodingNonLossyASCII")]
+    pub const NON_LOSSY_ASCII: Self = Self(0x0BFF);
+
+    /// An encoding constant that identifies kTextEncodingUnicodeDefault +
+    /// kUnicodeUTF16BEFormat encoding.
+    #[doc(alias = "kCFStringEncodingUTF16BE")]
+    pub const UTF16_BE: Self = Self(0x10000100);
+
+    /// An encoding constant that identifies kTextEncodingUnicodeDefault +
+    /// kUnicodeUTF16LEFormat encoding.
+    #[doc(alias = "kCFStringEncodingUTF16LE")]
+    pub const UTF16_LE: Self = Self(0x14000100);
+
+    /// An encoding constant that identifies the UTF 32 encoding.
+    #[doc(alias = "kCFStringEncodingUTF32")]
+    pub const UTF32: Self = Self(0x0c000100);
+
+    /// An encoding constant that identifies kTextEncodingUnicodeDefault +
+    /// kUnicodeUTF32BEFormat encoding.
+    #[doc(alias = "kCFStringEncodingUTF32BE")]
+    pub const UTF32_BE: Self = Self(0x18000100);
+
+    /// An encoding constant that identifies kTextEncodingUnicodeDefault +
+    /// kUnicodeUTF32LEFormat encoding.
+    #[doc(alias = "kCFStringEncodingUTF32LE")]
+    pub const UTF32_LE: Self = Self(0x1c000100);
+}
+
+impl CFStringEncoding {
+    /// Returns the fastest encoding for strings, which is the most efficient
+    /// encoding for the current system.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfstringgetsystemencoding()).
+    #[inline]
+    #[doc(alias = "CFStringGetSystemEncoding")]
+    pub fn system() -> Self {
+        unsafe { sys::CFStringGetSystemEncoding() }
+    }
+
+    /// Returns whether this encoding is supported by the system.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfstringisencodingavailable(_:)).
+    #[inline]
+    #[doc(alias = "CFStringIsEncodingAvailable")]
+    pub fn is_available(self) -> bool {
+        unsafe { sys::CFStringIsEncodingAvailable(self) != 0 }
+    }
+}
@@ -0,0 +1,305 @@
+use super::{sys, Boolean, CFAllocator, CFComparisonResult, CFIndex, CFRange, CFType, CFTypeID};
+use crate::core::Arc;
+use std::{borrow::Cow, cmp::Ordering, ffi::CStr, fmt, ptr, str};
+
+mod compare_flags;
+mod encoding;
+
+pub use compare_flags::*;
+pub use encoding::*;
+
+subclass! {
+    /// An immutable Unicode string.
+    ///
+    /// Documentation:
+    /// [Swift](https://developer.apple.com/documentation/corefoundation/cfstring?language=swift) |
+    /// [Objective-C](https://developer.apple.com/documentation/corefoundation/cfstring?language=objc)
+    #[derive(PartialEq, Hash)]
+    pub class CFString: CFType<'static>;
+}
+
+#[cfg(feature = "foundation")]
+cf_bridge!(CFString, crate::foundation::NSString<'static>);
+
+impl Eq for CFString {}
+
+impl PartialOrd for CFString {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for CFString {
+    #[inline]
+    #[doc(alias = "CFStringCompare")]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.compare(other, CFStringCompareFlags::NONE).into()
+    }
+}
+
+impl PartialEq<str> for CFString {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.to_cow_str() == other
+    }
+}
+
+impl PartialEq<&str> for CFString {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        *self == **other
+    }
+}
+
+impl PartialEq<CFString> for str {
+    #[inline]
+    fn eq(&self, other: &CFString) -> bool {
+        other == self
+    }
+}
+
+impl PartialEq<CFString> for &str {
+    #[inline]
+    fn eq(&self, other: &CFString) -> bool {
+        other == *self
+    }
+}
+
+impl From<&str> for Arc<CFString> {
+    #[inline]
+    fn from(s: &str) -> Self {
+        CFString::new(s)
+    }
+}
+
+impl fmt::Debug for CFString {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.to_cow_str().fmt(f)
+    }
+}
+
+impl fmt::Display for CFString {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.to_cow_str().fmt(f)
+    }
+}
+
+impl CFString {
+    /// Returns the type identifier for `CFString`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfstringgettypeid()).
+    #[inline]
+    #[doc(alias = "CFStringGetTypeID")]
+    pub fn type_id() -> CFTypeID {
+        unsafe { sys::CFStringGetTypeID() }
+    }
+
+    /// Creates a string from copying the bytes of `s`.
+    #[inline]
+    #[doc(alias = "from_str")]
+    pub fn new(s: &str) -> Arc<Self> {
+        Self::create_with_bytes(None, s.as_bytes(), CFStringEncoding::UTF8, false)
+            .expect("could not create a CFString from UTF-8")
+    }
+
+    /// Creates a string that references the bytes of `s` without copying.
+    ///
+    /// Because `s` lives for the duration of the program, the string's
+    /// contents are never deallocated.
+    #[inline]
+    #[doc(alias = "CFStringCreateWithBytesNoCopy")]
+    pub fn from_static_str(s: &'static str) -> Arc<Self> {
+        unsafe {
+            Self::create_with_bytes_no_copy(
+                None,
+                s.as_bytes(),
+                CFStringEncoding::UTF8,
+                false,
+                Some(CFAllocator::null()),
+            )
+        }
+    }
+
+    /// Creates a string from a buffer containing characters in a specified
+    /// encoding.
+    ///
+    /// Returns [`None`] if `bytes` is not valid data for `encoding`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfstringcreatewithbytes(_:_:_:_:_:)).
+    #[inline]
+    #[doc(alias = "CFStringCreateWithBytes")]
+    pub fn create_with_bytes(
+        allocator: Option<&CFAllocator>,
+        bytes: &[u8],
+        encoding: CFStringEncoding,
+        is_external_representation: bool,
+    ) -> Option<Arc<Self>> {
+        unsafe {
+            let string = sys::CFStringCreateWithBytes(
+                match allocator {
+                    Some(allocator) => allocator,
+                    None => ptr::null(),
+                },
+                bytes.as_ptr(),
+                bytes.len() as CFIndex,
+                encoding,
+                is_external_representation as Boolean,
+            );
+            if string.is_null() {
+                None
+            } else {
+                Some(Arc::from_raw(string))
+            }
+        }
+    }
+
+    /// Creates a string from a buffer, containing characters in a specified
+    /// encoding, that might serve as the backing store for the object.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfstringcreatewithbytesnocopy(_:_:_:_:_:_:)).
+    ///
+    /// # Safety
+    ///
+    /// `bytes` must be valid data for `encoding`, and must outlive the returned
+    /// object and all of its clones. `contents_deallocator` must be able to
+    /// deallocate `bytes`, or be [`CFAllocator::null`] if `bytes` should not be
+    /// deallocated.
+    #[inline]
+    #[doc(alias = "CFStringCreateWithBytesNoCopy")]
+    pub unsafe fn create_with_bytes_no_copy(
+        allocator: Option<&CFAllocator>,
+        bytes: &[u8],
+        encoding: CFStringEncoding,
+        is_external_representation: bool,
+        contents_deallocator: Option<&CFAllocator>,
+    ) -> Arc<Self> {
+        Arc::from_raw(sys::CFStringCreateWithBytesNoCopy(
+            match allocator {
+                Some(allocator) => allocator,
+                None => ptr::null(),
+            },
+            bytes.as_ptr(),
+            bytes.len() as CFIndex,
+            encoding,
+            is_external_representation as Boolean,
+            match contents_deallocator {
+                Some(allocator) => allocator,
+                None => ptr::null(),
+            },
+        ))
+    }
+
+    /// Returns the number (in terms of UTF-16 code pairs) of Unicode
+    /// characters in `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/1542853-cfstringgetlength).
+    #[inline]
+    #[doc(alias = "CFStringGetLength")]
+    pub fn len(&self) -> CFIndex {
+        unsafe { sys::CFStringGetLength(self) }
+    }
+
+    /// Returns `true` if `self` has a length of 0.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Compares `self` with `other` using `flags`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfstringcompare(_:_:_:)).
+    #[inline]
+    #[doc(alias = "CFStringCompare")]
+    pub fn compare(&self, other: &Self, flags: CFStringCompareFlags) -> CFComparisonResult {
+        unsafe { sys::CFStringCompare(self, other, flags) }
+    }
+}
+
+/// Getting contents as [UTF-8](https://en.wikipedia.org/wiki/UTF-8).
+impl CFString {
+    /// Returns a null-terminated UTF-8 representation of `self`, or null
+    /// if the internal storage of `self` does not allow this to be returned
+    /// efficiently.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/1542133-cfstringgetcstringptr).
+    #[inline]
+    #[doc(alias = "CFStringGetCStringPtr")]
+    pub fn as_utf8_ptr(&self) -> *const std::os::raw::c_char {
+        unsafe { sys::CFStringGetCStringPtr(self, CFStringEncoding::UTF8) }
+    }
+
+    /// Returns the contents of `self` as a UTF-8 string slice if the internal
+    /// storage of `self` allows it to be returned without copying.
+    ///
+    /// This returns [`None`] if `self` contains null characters, since the
+    /// internal storage is null-terminated.
+    #[inline]
+    pub fn as_str(&self) -> Option<&str> {
+        let ptr = self.as_utf8_ptr();
+        if ptr.is_null() {
+            return None;
+        }
+
+        // SAFETY: `CFStringGetCStringPtr` with UTF-8 encoding returns valid
+        // UTF-8 data that lives as long as `self`.
+        let bytes = unsafe { CStr::from_ptr(ptr).to_bytes() };
+
+        // The pointer is only available for ASCII storage, where each byte
+        // is one UTF-16 unit, so a shorter C string stopped at a null.
+        if bytes.len() as CFIndex != self.len() {
+            return None;
+        }
+        Some(unsafe { str::from_utf8_unchecked(bytes) })
+    }
+
+    /// Returns the contents of `self` as UTF-8, borrowing the internal storage
+    /// when possible and copying otherwise.
+    ///
+    /// The borrowing fast path uses
+    /// [`CFStringGetCStringPtr`](https://developer.apple.com/documentation/corefoundation/1542133-cfstringgetcstringptr),
+    /// and the copying fallback uses
+    /// [`CFStringGetCharacters`](https://developer.apple.com/documentation/corefoundation/cfstringgetcharacters(_:_:_:)).
+    /// Unpaired UTF-16 surrogates, which have no UTF-8 representation, are
+    /// replaced with [`U+FFFD`](std::char::REPLACEMENT_CHARACTER).
+    #[inline]
+    pub fn to_cow_str(&self) -> Cow<'_, str> {
+        match self.as_str() {
+            Some(s) => Cow::Borrowed(s),
+            None => Cow::Owned(self.to_string_slow()),
+        }
+    }
+
+    // Shared non-inlined copying path for `to_cow_str`.
+    fn to_string_slow(&self) -> String {
+        let len = self.len();
+
+        // Unpaired surrogates end conversion to UTF-8 early, so they are
+        // instead replaced when converting from UTF-16.
+        let mut buf = vec![0u16; len as usize];
+        unsafe { sys::CFStringGetCharacters(self, CFRange::new(0, len), buf.as_mut_ptr()) };
+
+        String::from_utf16_lossy(&buf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interior_nul() {
+        let string = CFString::new("a\0b");
+        assert_eq!(string.len(), 3);
+        assert_eq!(string.as_str(), None);
+        assert_eq!(string.to_cow_str(), "a\0b");
+
+        let string = CFString::from_static_str("ab");
+        if let Some(s) = string.as_str() {
+            assert_eq!(s, "ab");
+        }
+        assert_eq!(string.to_cow_str(), "ab");
+    }
+}
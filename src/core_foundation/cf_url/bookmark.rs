@@ -0,0 +1,282 @@
+use super::CFURL;
+use crate::{
+    core::Arc,
+    core_foundation::{sys, Boolean, CFAllocator, CFData, CFError, CFIndex, CFOptionFlags},
+};
+use std::ptr;
+
+/// Options for creating bookmark data.
+///
+/// See [documentation](https://developer.apple.com/documentation/corefoundation/cfurlbookmarkcreationoptions).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CFURLBookmarkCreationOptions(pub CFOptionFlags);
+
+impl std::ops::BitOr for CFURLBookmarkCreationOptions {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl CFURLBookmarkCreationOptions {
+    /// No options.
+    pub const NONE: Self = Self(0);
+
+    /// Creates a bookmark with the smallest possible size.
+    #[doc(alias = "kCFURLBookmarkCreationMinimalBookmarkMask")]
+    pub const MINIMAL_BOOKMARK: Self = Self(1 << 9);
+
+    /// Creates bookmark data suitable for writing to an alias file with
+    /// [`CFURL::write_bookmark_data_to_file`].
+    #[doc(alias = "kCFURLBookmarkCreationSuitableForBookmarkFile")]
+    pub const SUITABLE_FOR_BOOKMARK_FILE: Self = Self(1 << 10);
+
+    /// Creates a security-scoped bookmark for use in a sandboxed app.
+    #[doc(alias = "kCFURLBookmarkCreationWithSecurityScope")]
+    pub const WITH_SECURITY_SCOPE: Self = Self(1 << 11);
+
+    /// When combined with [`WITH_SECURITY_SCOPE`](Self::WITH_SECURITY_SCOPE),
+    /// restricts the bookmark to read-only access.
+    #[doc(alias = "kCFURLBookmarkCreationSecurityScopeAllowOnlyReadAccess")]
+    pub const SECURITY_SCOPE_ALLOW_ONLY_READ_ACCESS: Self = Self(1 << 12);
+
+    /// Disables the automatic embedding of an implicit security scope.
+    #[doc(alias = "kCFURLBookmarkCreationWithoutImplicitSecurityScope")]
+    pub const WITHOUT_IMPLICIT_SECURITY_SCOPE: Self = Self(1 << 29);
+}
+
+/// Options for resolving bookmark data.
+///
+/// See [documentation](https://developer.apple.com/documentation/corefoundation/cfurlbookmarkresolutionoptions).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CFURLBookmarkResolutionOptions(pub CFOptionFlags);
+
+impl std::ops::BitOr for CFURLBookmarkResolutionOptions {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl CFURLBookmarkResolutionOptions {
+    /// No options.
+    pub const NONE: Self = Self(0);
+
+    /// Prevents user interaction while resolving the bookmark.
+    #[doc(alias = "kCFBookmarkResolutionWithoutUIMask")]
+    pub const WITHOUT_UI: Self = Self(1 << 8);
+
+    /// Prevents mounting a volume while resolving the bookmark.
+    #[doc(alias = "kCFBookmarkResolutionWithoutMountingMask")]
+    pub const WITHOUT_MOUNTING: Self = Self(1 << 9);
+
+    /// Resolves a security-scoped bookmark created with
+    /// [`CFURLBookmarkCreationOptions::WITH_SECURITY_SCOPE`].
+    #[doc(alias = "kCFURLBookmarkResolutionWithSecurityScope")]
+    pub const WITH_SECURITY_SCOPE: Self = Self(1 << 10);
+
+    /// Disables implicitly starting access of the security-scoped resource.
+    #[doc(alias = "kCFURLBookmarkResolutionWithoutImplicitStartAccessing")]
+    pub const WITHOUT_IMPLICIT_START_ACCESSING: Self = Self(1 << 15);
+}
+
+/// The result of [`CFURL::resolve_bookmark_data`].
+#[derive(Clone, Debug)]
+pub struct CFURLResolvedBookmark {
+    /// The URL of the bookmarked resource.
+    pub url: Arc<CFURL>,
+
+    /// Whether the bookmark data should be recreated, usually because the
+    /// resource was moved or renamed.
+    pub is_stale: bool,
+}
+
+impl CFURLResolvedBookmark {
+    /// Returns fresh bookmark data for [`url`](Self::url) if the resolved
+    /// bookmark is stale, or [`None`] if it is still up-to-date.
+    ///
+    /// `options` should match those used to create the original bookmark.
+    #[inline]
+    pub fn renew(
+        &self,
+        options: CFURLBookmarkCreationOptions,
+    ) -> Result<Option<Arc<CFData>>, Arc<CFError>> {
+        if self.is_stale {
+            self.url.create_bookmark_data(options, None).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+/// `NSFileReadUnknownError`, for failures without an error.
+const FILE_READ_UNKNOWN_ERROR: CFIndex = 256;
+
+/// `NSFileWriteUnknownError`, for failures without an error.
+const FILE_WRITE_UNKNOWN_ERROR: CFIndex = 512;
+
+#[inline]
+unsafe fn into_result<T>(value: *const T, error: *const CFError) -> Result<Arc<T>, Arc<CFError>>
+where
+    T: crate::core::ObjectType,
+{
+    if value.is_null() {
+        Err(CFError::from_raw_or(error, FILE_READ_UNKNOWN_ERROR))
+    } else {
+        Ok(Arc::from_raw(value))
+    }
+}
+
+/// Bookmarks and alias files.
+impl CFURL {
+    /// Returns bookmark data for `self` that can later be resolved even if the
+    /// resource was moved or renamed.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfurlcreatebookmarkdata(_:_:_:_:_:_:)).
+    #[inline]
+    #[doc(alias = "CFURLCreateBookmarkData")]
+    pub fn create_bookmark_data(
+        &self,
+        options: CFURLBookmarkCreationOptions,
+        relative_to: Option<&CFURL>,
+    ) -> Result<Arc<CFData>, Arc<CFError>> {
+        unsafe {
+            let mut error = ptr::null();
+            let data = sys::CFURLCreateBookmarkData(
+                ptr::null(),
+                self,
+                options,
+                ptr::null(),
+                match relative_to {
+                    Some(url) => url,
+                    None => ptr::null(),
+                },
+                &mut error,
+            );
+            into_result(data, error)
+        }
+    }
+
+    /// Resolves bookmark data into a URL.
+    ///
+    /// If the result is [stale](CFURLResolvedBookmark::is_stale), the bookmark
+    /// should be recreated with [`CFURLResolvedBookmark::renew`].
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfurlcreatebyresolvingbookmarkdata(_:_:_:_:_:_:_:)).
+    #[inline]
+    #[doc(alias = "CFURLCreateByResolvingBookmarkData")]
+    pub fn resolve_bookmark_data(
+        bookmark: &CFData,
+        options: CFURLBookmarkResolutionOptions,
+        relative_to: Option<&CFURL>,
+    ) -> Result<CFURLResolvedBookmark, Arc<CFError>> {
+        unsafe {
+            let mut is_stale: Boolean = 0;
+            let mut error = ptr::null();
+            let url = sys::CFURLCreateByResolvingBookmarkData(
+                ptr::null(),
+                bookmark,
+                options,
+                match relative_to {
+                    Some(url) => url,
+                    None => ptr::null(),
+                },
+                ptr::null(),
+                &mut is_stale,
+                &mut error,
+            );
+            Ok(CFURLResolvedBookmark {
+                url: into_result(url, error)?,
+                is_stale: is_stale != 0,
+            })
+        }
+    }
+
+    /// Reads the bookmark data stored in the alias file at `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfurlcreatebookmarkdatafromfile(_:_:_:)).
+    #[inline]
+    #[doc(alias = "CFURLCreateBookmarkDataFromFile")]
+    pub fn bookmark_data_from_file(&self) -> Result<Arc<CFData>, Arc<CFError>> {
+        unsafe {
+            let mut error = ptr::null();
+            let data = sys::CFURLCreateBookmarkDataFromFile(ptr::null(), self, &mut error);
+            into_result(data, error)
+        }
+    }
+
+    /// Creates an alias file at `self` that refers to the resource in
+    /// `bookmark`.
+    ///
+    /// The bookmark must have been created with
+    /// [`CFURLBookmarkCreationOptions::SUITABLE_FOR_BOOKMARK_FILE`].
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfurlwritebookmarkdatatofile(_:_:_:_:)).
+    #[inline]
+    #[doc(alias = "CFURLWriteBookmarkDataToFile")]
+    pub fn write_bookmark_data_to_file(&self, bookmark: &CFData) -> Result<(), Arc<CFError>> {
+        unsafe {
+            let mut error = ptr::null();
+            if sys::CFURLWriteBookmarkDataToFile(bookmark, self, 0, &mut error) != 0 {
+                Ok(())
+            } else {
+                Err(CFError::from_raw_or(error, FILE_WRITE_UNKNOWN_ERROR))
+            }
+        }
+    }
+
+    /// Converts a legacy `AliasRecord` into bookmark data.
+    ///
+    /// Returns [`None`] if `alias_record` could not be converted.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfurlcreatebookmarkdatafromaliasrecord(_:_:)).
+    #[inline]
+    #[doc(alias = "CFURLCreateBookmarkDataFromAliasRecord")]
+    pub fn bookmark_data_from_alias_record(
+        allocator: Option<&CFAllocator>,
+        alias_record: &CFData,
+    ) -> Option<Arc<CFData>> {
+        unsafe {
+            let data = sys::CFURLCreateBookmarkDataFromAliasRecord(
+                match allocator {
+                    Some(allocator) => allocator,
+                    None => ptr::null(),
+                },
+                alias_record,
+            );
+            if data.is_null() {
+                None
+            } else {
+                Some(Arc::from_raw(data))
+            }
+        }
+    }
+
+    /// Makes the resource referenced by a security-scoped URL accessible to
+    /// the process, returning `true` on success.
+    ///
+    /// Every successful call must be balanced by a call to
+    /// [`stop_accessing_security_scoped_resource`](Self::stop_accessing_security_scoped_resource).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfurlstartaccessingsecurityscopedresource(_:)).
+    #[inline]
+    #[doc(alias = "CFURLStartAccessingSecurityScopedResource")]
+    pub fn start_accessing_security_scoped_resource(&self) -> bool {
+        unsafe { sys::CFURLStartAccessingSecurityScopedResource(self) != 0 }
+    }
+
+    /// Revokes access to the resource referenced by a security-scoped URL.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfurlstopaccessingsecurityscopedresource(_:)).
+    #[inline]
+    #[doc(alias = "CFURLStopAccessingSecurityScopedResource")]
+    pub fn stop_accessing_security_scoped_resource(&self) {
+        unsafe { sys::CFURLStopAccessingSecurityScopedResource(self) }
+    }
+}
@@ -0,0 +1,314 @@
+use super::{sys, Boolean, CFAllocator, CFIndex, CFString, CFType, CFTypeID};
+use crate::core::Arc;
+use std::{
+    ffi::OsStr,
+    fmt,
+    os::unix::ffi::OsStrExt,
+    path::{Path, PathBuf},
+    ptr,
+};
+
+mod bookmark;
+mod path_style;
+
+pub use bookmark::*;
+pub use path_style::*;
+
+subclass! {
+    /// A reference to a local file or a network resource.
+    ///
+    /// Documentation:
+    /// [Swift](https://developer.apple.com/documentation/corefoundation/cfurl?language=swift) |
+    /// [Objective-C](https://developer.apple.com/documentation/corefoundation/cfurl?language=objc)
+    #[derive(PartialEq, Hash)]
+    pub class CFURL: CFType<'static>;
+}
+
+#[cfg(feature = "foundation")]
+cf_bridge!(CFURL, crate::foundation::NSURL);
+
+impl Eq for CFURL {}
+
+impl fmt::Debug for CFURL {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.string().fmt(f)
+    }
+}
+
+impl fmt::Display for CFURL {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.string().fmt(f)
+    }
+}
+
+impl CFURL {
+    /// Returns the type identifier for `CFURL`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfurlgettypeid()).
+    #[inline]
+    #[doc(alias = "CFURLGetTypeID")]
+    pub fn type_id() -> CFTypeID {
+        unsafe { sys::CFURLGetTypeID() }
+    }
+
+    /// Creates a file URL from a local file system path.
+    ///
+    /// If `path` is relative, the URL is resolved against the current working
+    /// directory. Returns [`None`] if `path` is empty.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfurlcreatefromfilesystemrepresentation(_:_:_:_:)).
+    #[inline]
+    #[doc(alias = "CFURLCreateFromFileSystemRepresentation")]
+    pub fn from_path<P: AsRef<Path>>(path: P, is_directory: bool) -> Option<Arc<Self>> {
+        Self::_from_path(path.as_ref(), is_directory)
+    }
+
+    // Shared non-generic implementation of `from_path`.
+    fn _from_path(path: &Path, is_directory: bool) -> Option<Arc<Self>> {
+        let bytes = path.as_os_str().as_bytes();
+        unsafe {
+            let url = sys::CFURLCreateFromFileSystemRepresentation(
+                ptr::null(),
+                bytes.as_ptr(),
+                bytes.len() as CFIndex,
+                is_directory as Boolean,
+            );
+            if url.is_null() {
+                None
+            } else {
+                Some(Arc::from_raw(url))
+            }
+        }
+    }
+
+    /// Creates a URL from a string, such as `https://example.com/index.html`.
+    ///
+    /// If `base_url` is provided, `string` is interpreted relative to it.
+    /// Returns [`None`] if `string` is not a valid URL.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfurlcreatewithstring(_:_:_:)).
+    #[inline]
+    #[doc(alias = "CFURLCreateWithString")]
+    pub fn from_string(
+        allocator: Option<&CFAllocator>,
+        string: &CFString,
+        base_url: Option<&CFURL>,
+    ) -> Option<Arc<Self>> {
+        let allocator = match allocator {
+            Some(allocator) => allocator,
+            None => ptr::null(),
+        };
+        let base_url = match base_url {
+            Some(base_url) => base_url,
+            None => ptr::null(),
+        };
+        unsafe {
+            let url = sys::CFURLCreateWithString(allocator, string, base_url);
+            if url.is_null() {
+                None
+            } else {
+                Some(Arc::from_raw(url))
+            }
+        }
+    }
+
+    /// Creates a file URL from a file system path written in `style`.
+    ///
+    /// Unlike [`from_path`](Self::from_path), this accepts paths that are not
+    /// native to the current platform. Returns [`None`] if `path` is empty.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfurlcreatewithfilesystempath(_:_:_:_:)).
+    #[inline]
+    #[doc(alias = "CFURLCreateWithFileSystemPath")]
+    pub fn with_file_system_path(
+        allocator: Option<&CFAllocator>,
+        path: &CFString,
+        style: CFURLPathStyle,
+        is_directory: bool,
+    ) -> Option<Arc<Self>> {
+        let allocator = match allocator {
+            Some(allocator) => allocator,
+            None => ptr::null(),
+        };
+        unsafe {
+            let url =
+                sys::CFURLCreateWithFileSystemPath(allocator, path, style, is_directory as Boolean);
+            if url.is_null() {
+                None
+            } else {
+                Some(Arc::from_raw(url))
+            }
+        }
+    }
+
+    /// Returns the file system path of `self` written in `style`, or
+    /// [`None`] if it does not represent a local file.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfurlcopyfilesystempath(_:_:)).
+    #[inline]
+    #[doc(alias = "CFURLCopyFileSystemPath")]
+    pub fn file_system_path(&self, style: CFURLPathStyle) -> Option<Arc<CFString>> {
+        unsafe {
+            let path = sys::CFURLCopyFileSystemPath(self, style);
+            if path.is_null() {
+                None
+            } else {
+                Some(Arc::from_raw(path))
+            }
+        }
+    }
+
+    /// Returns the file system path of `self`, or [`None`] if it does not
+    /// represent a local file.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfurlgetfilesystemrepresentation(_:_:_:_:)).
+    #[doc(alias = "CFURLGetFileSystemRepresentation")]
+    pub fn to_path_buf(&self) -> Option<PathBuf> {
+        // `PATH_MAX` from `<sys/syslimits.h>`.
+        const PATH_MAX: usize = 1024;
+
+        let mut buf = [0u8; PATH_MAX];
+        let success = unsafe {
+            sys::CFURLGetFileSystemRepresentation(
+                self,
+                true as Boolean,
+                buf.as_mut_ptr(),
+                PATH_MAX as CFIndex,
+            )
+        };
+        if success == 0 {
+            return None;
+        }
+
+        let len = buf.iter().position(|&b| b == 0).unwrap_or(PATH_MAX);
+        Some(OsStr::from_bytes(&buf[..len]).into())
+    }
+
+    /// Returns the URL string of `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfurlgetstring(_:)).
+    #[inline]
+    #[doc(alias = "CFURLGetString")]
+    pub fn string(&self) -> Arc<CFString> {
+        unsafe { Arc::retain_raw(sys::CFURLGetString(self)) }
+    }
+
+    /// Returns a copy of `self` with an absolute path.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfurlcopyabsoluteurl(_:)).
+    #[inline]
+    #[doc(alias = "CFURLCopyAbsoluteURL")]
+    pub fn absolute_url(&self) -> Arc<Self> {
+        unsafe { Arc::from_raw(sys::CFURLCopyAbsoluteURL(self)) }
+    }
+
+    /// Returns the URL that `self` is relative to, if any.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfurlgetbaseurl(_:)).
+    #[inline]
+    #[doc(alias = "CFURLGetBaseURL")]
+    pub fn base_url(&self) -> Option<Arc<Self>> {
+        unsafe { sys::CFURLGetBaseURL(self).as_ref().map(Arc::retain) }
+    }
+
+    /// Returns the scheme of `self`, such as `https` or `file`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfurlcopyscheme(_:)).
+    #[inline]
+    #[doc(alias = "CFURLCopyScheme")]
+    pub fn scheme(&self) -> Option<Arc<CFString>> {
+        unsafe {
+            let scheme = sys::CFURLCopyScheme(self);
+            if scheme.is_null() {
+                None
+            } else {
+                Some(Arc::from_raw(scheme))
+            }
+        }
+    }
+
+    /// Returns the host name of `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfurlcopyhostname(_:)).
+    #[inline]
+    #[doc(alias = "CFURLCopyHostName")]
+    pub fn host_name(&self) -> Option<Arc<CFString>> {
+        unsafe {
+            let host_name = sys::CFURLCopyHostName(self);
+            if host_name.is_null() {
+                None
+            } else {
+                Some(Arc::from_raw(host_name))
+            }
+        }
+    }
+
+    /// Returns `true` if the path of `self` ends with a slash, and thus
+    /// refers to a directory.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfurlhasdirectorypath(_:)).
+    #[inline]
+    #[doc(alias = "CFURLHasDirectoryPath")]
+    pub fn has_directory_path(&self) -> bool {
+        unsafe { sys::CFURLHasDirectoryPath(self) != 0 }
+    }
+
+    /// Returns the last component of the path of `self`, such as the file
+    /// name.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfurlcopylastpathcomponent(_:)).
+    #[inline]
+    #[doc(alias = "CFURLCopyLastPathComponent")]
+    pub fn last_path_component(&self) -> Arc<CFString> {
+        unsafe { Arc::from_raw(sys::CFURLCopyLastPathComponent(self)) }
+    }
+
+    /// Returns the extension of the last path component of `self`, without
+    /// the leading dot.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfurlcopypathextension(_:)).
+    #[inline]
+    #[doc(alias = "CFURLCopyPathExtension")]
+    pub fn path_extension(&self) -> Option<Arc<CFString>> {
+        unsafe {
+            let extension = sys::CFURLCopyPathExtension(self);
+            if extension.is_null() {
+                None
+            } else {
+                Some(Arc::from_raw(extension))
+            }
+        }
+    }
+
+    /// Returns a copy of `self` with `component` appended to its path.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfurlcreatecopyappendingpathcomponent(_:_:_:_:)).
+    #[inline]
+    #[doc(alias = "CFURLCreateCopyAppendingPathComponent")]
+    pub fn appending_path_component(&self, component: &CFString, is_directory: bool) -> Arc<Self> {
+        unsafe {
+            Arc::from_raw(sys::CFURLCreateCopyAppendingPathComponent(
+                ptr::null(),
+                self,
+                component,
+                is_directory as Boolean,
+            ))
+        }
+    }
+
+    /// Returns a copy of `self` with the last component of its path removed.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfurlcreatecopydeletinglastpathcomponent(_:_:)).
+    #[inline]
+    #[doc(alias = "CFURLCreateCopyDeletingLastPathComponent")]
+    pub fn deleting_last_path_component(&self) -> Arc<Self> {
+        unsafe {
+            Arc::from_raw(sys::CFURLCreateCopyDeletingLastPathComponent(
+                ptr::null(),
+                self,
+            ))
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use crate::core_foundation::CFIndex;
+
+/// The syntax of a file system path.
+///
+/// See [documentation](https://developer.apple.com/documentation/corefoundation/cfurlpathstyle).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CFURLPathStyle(pub CFIndex);
+
+impl CFURLPathStyle {
+    /// Slash-separated paths, as used on macOS and other Unix systems.
+    #[doc(alias = "kCFURLPOSIXPathStyle")]
+    pub const POSIX: Self = Self(0);
+
+    /// Backslash-separated paths, as used on Windows.
+    #[doc(alias = "kCFURLWindowsPathStyle")]
+    pub const WINDOWS: Self = Self(2);
+}
@@ -0,0 +1,20 @@
+/// The 16 raw bytes of a [`CFUUID`](super::CFUUID), in network byte order.
+///
+/// See [documentation](https://developer.apple.com/documentation/corefoundation/cfuuidbytes).
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CFUUIDBytes(pub [u8; 16]);
+
+impl From<[u8; 16]> for CFUUIDBytes {
+    #[inline]
+    fn from(bytes: [u8; 16]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl From<CFUUIDBytes> for [u8; 16] {
+    #[inline]
+    fn from(bytes: CFUUIDBytes) -> Self {
+        bytes.0
+    }
+}
@@ -0,0 +1,133 @@
+use super::{sys, CFAllocator, CFString, CFType, CFTypeID};
+use crate::core::Arc;
+use std::{fmt, ptr};
+
+mod bytes;
+#[cfg(feature = "uuid")]
+mod uuid;
+
+pub use bytes::*;
+
+subclass! {
+    /// A universally unique identifier (UUID).
+    ///
+    /// Instances are uniqued, so creating a `CFUUID` from the same bytes
+    /// twice returns the same object.
+    ///
+    /// This is not toll-free bridged with
+    /// [`NSUUID`](crate::foundation::NSUUID).
+    ///
+    /// Documentation:
+    /// [Swift](https://developer.apple.com/documentation/corefoundation/cfuuid?language=swift) |
+    /// [Objective-C](https://developer.apple.com/documentation/corefoundation/cfuuid?language=objc)
+    #[derive(PartialEq, Hash)]
+    pub class CFUUID: CFType<'static>;
+}
+
+impl Eq for CFUUID {}
+
+impl fmt::Debug for CFUUID {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("CFUUID").field(&self.to_cf_string()).finish()
+    }
+}
+
+impl fmt::Display for CFUUID {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.to_cf_string().fmt(f)
+    }
+}
+
+impl From<&CFUUID> for CFUUIDBytes {
+    #[inline]
+    fn from(uuid: &CFUUID) -> Self {
+        uuid.bytes()
+    }
+}
+
+impl From<CFUUIDBytes> for Arc<CFUUID> {
+    #[inline]
+    fn from(bytes: CFUUIDBytes) -> Self {
+        CFUUID::from_bytes(None, bytes)
+    }
+}
+
+impl CFUUID {
+    /// Returns the type identifier for `CFUUID`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfuuidgettypeid()).
+    #[inline]
+    #[doc(alias = "CFUUIDGetTypeID")]
+    pub fn type_id() -> CFTypeID {
+        unsafe { sys::CFUUIDGetTypeID() }
+    }
+
+    /// Creates a new random (version 4) UUID.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfuuidcreate(_:)).
+    #[inline]
+    #[doc(alias = "CFUUIDCreate")]
+    pub fn new(allocator: Option<&CFAllocator>) -> Arc<Self> {
+        let allocator = match allocator {
+            Some(allocator) => allocator,
+            None => ptr::null(),
+        };
+        unsafe { Arc::from_raw(sys::CFUUIDCreate(allocator)) }
+    }
+
+    /// Returns the UUID with the raw `bytes`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfuuidcreatefromuuidbytes(_:_:)).
+    #[inline]
+    #[doc(alias = "CFUUIDCreateFromUUIDBytes")]
+    pub fn from_bytes(allocator: Option<&CFAllocator>, bytes: CFUUIDBytes) -> Arc<Self> {
+        let allocator = match allocator {
+            Some(allocator) => allocator,
+            None => ptr::null(),
+        };
+        unsafe { Arc::from_raw(sys::CFUUIDCreateFromUUIDBytes(allocator, bytes)) }
+    }
+
+    /// Parses a UUID in the canonical hyphenated form, such as
+    /// `68753A44-4D6F-1226-9C60-0050E4C00067`.
+    ///
+    /// Returns [`None`] if `string` is not a valid UUID.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfuuidcreatefromstring(_:_:)).
+    #[inline]
+    #[doc(alias = "CFUUIDCreateFromString")]
+    pub fn from_string(allocator: Option<&CFAllocator>, string: &CFString) -> Option<Arc<Self>> {
+        let allocator = match allocator {
+            Some(allocator) => allocator,
+            None => ptr::null(),
+        };
+        unsafe {
+            let uuid = sys::CFUUIDCreateFromString(allocator, string);
+            if uuid.is_null() {
+                None
+            } else {
+                Some(Arc::from_raw(uuid))
+            }
+        }
+    }
+
+    /// Returns the raw bytes of `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfuuidgetuuidbytes(_:)).
+    #[inline]
+    #[doc(alias = "CFUUIDGetUUIDBytes")]
+    pub fn bytes(&self) -> CFUUIDBytes {
+        unsafe { sys::CFUUIDGetUUIDBytes(self) }
+    }
+
+    /// Returns `self` in the canonical uppercase hyphenated form.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corefoundation/cfuuidcreatestring(_:_:)).
+    #[inline]
+    #[doc(alias = "CFUUIDCreateString")]
+    pub fn to_cf_string(&self) -> Arc<CFString> {
+        unsafe { Arc::from_raw(sys::CFUUIDCreateString(ptr::null(), self)) }
+    }
+}
@@ -0,0 +1,47 @@
+use super::{CFUUIDBytes, CFUUID};
+use crate::core::Arc;
+use uuid::Uuid;
+
+impl From<Uuid> for Arc<CFUUID> {
+    #[inline]
+    fn from(uuid: Uuid) -> Self {
+        CFUUID::from_uuid(uuid)
+    }
+}
+
+impl From<&CFUUID> for Uuid {
+    #[inline]
+    fn from(uuid: &CFUUID) -> Self {
+        uuid.to_uuid()
+    }
+}
+
+impl From<Uuid> for CFUUIDBytes {
+    #[inline]
+    fn from(uuid: Uuid) -> Self {
+        Self(uuid.into_bytes())
+    }
+}
+
+impl From<CFUUIDBytes> for Uuid {
+    #[inline]
+    fn from(bytes: CFUUIDBytes) -> Self {
+        Uuid::from_bytes(bytes.0)
+    }
+}
+
+/// Conversions with [`uuid`](https://docs.rs/uuid), enabled by the
+/// **`uuid`** feature flag.
+impl CFUUID {
+    /// Returns the `CFUUID` with the same bytes as `uuid`.
+    #[inline]
+    pub fn from_uuid(uuid: Uuid) -> Arc<Self> {
+        Self::from_bytes(None, uuid.into())
+    }
+
+    /// Returns `self` as a `uuid` crate UUID.
+    #[inline]
+    pub fn to_uuid(&self) -> Uuid {
+        self.bytes().into()
+    }
+}
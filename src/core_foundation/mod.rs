@@ -15,15 +15,46 @@ pub mod sys;
 mod bridge;
 
 mod cf_allocator;
+mod cf_array;
 mod cf_boolean;
+mod cf_bundle;
+mod cf_data;
+mod cf_date;
+mod cf_dictionary;
+mod cf_error;
 mod cf_number;
+mod cf_preferences;
+mod cf_property_list;
+mod cf_range;
+mod cf_run_loop;
+mod cf_socket;
+mod cf_stream;
+mod cf_string;
 mod cf_type;
+mod cf_url;
+mod cf_uuid;
 mod cmp;
 
+pub use bridge::TollFreeBridge;
 pub use cf_allocator::*;
+pub use cf_array::*;
 pub use cf_boolean::*;
+pub use cf_bundle::*;
+pub use cf_data::*;
+pub use cf_date::*;
+pub use cf_dictionary::*;
+pub use cf_error::*;
 pub use cf_number::*;
+pub use cf_preferences::*;
+pub use cf_property_list::*;
+pub use cf_range::*;
+pub use cf_run_loop::*;
+pub use cf_socket::*;
+pub use cf_stream::*;
+pub use cf_string::*;
 pub use cf_type::*;
+pub use cf_url::*;
+pub use cf_uuid::*;
 pub use cmp::*;
 
 /// A constant that indicates that a search operation did not succeed in
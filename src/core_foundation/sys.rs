@@ -1,10 +1,23 @@
 //! Raw unsafe C functions exposed by `CoreFoundation.framework`.
 
 use super::{
-    Boolean, CFAllocator, CFAllocatorContext, CFBoolean, CFComparisonResult, CFHashCode, CFIndex,
-    CFNumber, CFNumberType, CFOptionFlags, CFType, CFTypeID,
+    Boolean, CFAbsoluteTime, CFAllocator, CFAllocatorContext, CFArray, CFArrayCallBacks, CFBoolean,
+    CFBundle, CFComparisonResult, CFData, CFDate, CFDictionary, CFDictionaryKeyCallBacks,
+    CFDictionaryValueCallBacks, CFError, CFHashCode, CFIndex, CFNumber, CFNumberType,
+    CFOptionFlags, CFPropertyListFormat, CFPropertyListMutabilityOptions, CFRange, CFReadStream,
+    CFReadStreamClientCallBack, CFRunLoop, CFRunLoopActivity, CFRunLoopMode, CFRunLoopObserver,
+    CFRunLoopObserverCallBack, CFRunLoopObserverContext, CFRunLoopRunResult, CFRunLoopSource,
+    CFRunLoopSourceContext, CFRunLoopTimer, CFRunLoopTimerCallBack, CFRunLoopTimerContext,
+    CFSocket, CFSocketCallBack, CFSocketCallBackType, CFSocketContext, CFSocketFlags,
+    CFSocketNativeHandle, CFStreamClientContext, CFStreamEventType, CFStreamPropertyKey,
+    CFStreamStatus, CFString, CFStringCompareFlags, CFStringEncoding, CFTimeInterval, CFType,
+    CFTypeID, CFURLBookmarkCreationOptions, CFURLBookmarkResolutionOptions, CFURLPathStyle,
+    CFUUIDBytes, CFWriteStream, CFWriteStreamClientCallBack, CFURL, CFUUID,
+};
+use std::{
+    ffi::c_void,
+    os::raw::{c_char, c_int},
 };
-use std::ffi::c_void;
 
 #[allow(missing_docs)]
 #[link(name = "CoreFoundation", kind = "framework")]
@@ -80,4 +93,709 @@ extern "C" {
     pub fn CFBooleanGetTypeID() -> CFTypeID;
 
     pub fn CFBooleanGetValue(boolean: *const CFBoolean) -> Boolean;
+
+    pub fn CFStringGetTypeID() -> CFTypeID;
+
+    pub fn CFStringCreateWithBytes(
+        alloc: *const CFAllocator,
+        bytes: *const u8,
+        num_bytes: CFIndex,
+        encoding: CFStringEncoding,
+        is_external_representation: Boolean,
+    ) -> *const CFString;
+
+    pub fn CFStringCreateWithBytesNoCopy(
+        alloc: *const CFAllocator,
+        bytes: *const u8,
+        num_bytes: CFIndex,
+        encoding: CFStringEncoding,
+        is_external_representation: Boolean,
+        contents_deallocator: *const CFAllocator,
+    ) -> *const CFString;
+
+    pub fn CFStringGetLength(string: *const CFString) -> CFIndex;
+
+    pub fn CFStringGetCStringPtr(
+        string: *const CFString,
+        encoding: CFStringEncoding,
+    ) -> *const c_char;
+
+    pub fn CFStringGetCharacters(string: *const CFString, range: CFRange, buffer: *mut u16);
+
+    pub fn CFStringGetBytes(
+        string: *const CFString,
+        range: CFRange,
+        encoding: CFStringEncoding,
+        loss_byte: u8,
+        is_external_representation: Boolean,
+        buffer: *mut u8,
+        max_buf_len: CFIndex,
+        used_buf_len: *mut CFIndex,
+    ) -> CFIndex;
+
+    pub fn CFStringCompare(
+        string1: *const CFString,
+        string2: *const CFString,
+        compare_options: CFStringCompareFlags,
+    ) -> CFComparisonResult;
+
+    pub fn CFStringGetSystemEncoding() -> CFStringEncoding;
+
+    pub fn CFStringIsEncodingAvailable(encoding: CFStringEncoding) -> Boolean;
+
+    pub fn CFDataGetTypeID() -> CFTypeID;
+
+    pub fn CFDataCreate(
+        allocator: *const CFAllocator,
+        bytes: *const u8,
+        length: CFIndex,
+    ) -> *const CFData;
+
+    pub fn CFDataCreateWithBytesNoCopy(
+        allocator: *const CFAllocator,
+        bytes: *const u8,
+        length: CFIndex,
+        bytes_deallocator: *const CFAllocator,
+    ) -> *const CFData;
+
+    pub fn CFDataCreateMutable(allocator: *const CFAllocator, capacity: CFIndex) -> *mut CFData;
+
+    pub fn CFDataCreateMutableCopy(
+        allocator: *const CFAllocator,
+        capacity: CFIndex,
+        the_data: *const CFData,
+    ) -> *mut CFData;
+
+    pub fn CFDataGetLength(data: *const CFData) -> CFIndex;
+
+    pub fn CFDataGetBytePtr(data: *const CFData) -> *const u8;
+
+    pub fn CFDataAppendBytes(the_data: *mut CFData, bytes: *const u8, length: CFIndex);
+
+    pub fn CFDataSetLength(the_data: *mut CFData, length: CFIndex);
+
+    pub fn CFErrorGetTypeID() -> CFTypeID;
+
+    pub fn CFErrorCreate(
+        allocator: *const CFAllocator,
+        domain: *const CFString,
+        code: CFIndex,
+        user_info: *const CFDictionary,
+    ) -> *const CFError;
+
+    pub fn CFErrorGetCode(err: *const CFError) -> CFIndex;
+
+    pub fn CFErrorGetDomain(err: *const CFError) -> *const CFString;
+
+    pub fn CFErrorCopyDescription(err: *const CFError) -> *const CFString;
+
+    pub fn CFErrorCopyFailureReason(err: *const CFError) -> *const CFString;
+
+    pub fn CFErrorCopyRecoverySuggestion(err: *const CFError) -> *const CFString;
+
+    pub fn CFURLGetTypeID() -> CFTypeID;
+
+    pub fn CFURLCreateFromFileSystemRepresentation(
+        allocator: *const CFAllocator,
+        buffer: *const u8,
+        buf_len: CFIndex,
+        is_directory: Boolean,
+    ) -> *const CFURL;
+
+    pub fn CFURLGetFileSystemRepresentation(
+        url: *const CFURL,
+        resolve_against_base: Boolean,
+        buffer: *mut u8,
+        max_buf_len: CFIndex,
+    ) -> Boolean;
+
+    pub fn CFURLGetString(url: *const CFURL) -> *const CFString;
+
+    pub fn CFURLCopyAbsoluteURL(url: *const CFURL) -> *const CFURL;
+
+    pub fn CFURLCreateWithString(
+        allocator: *const CFAllocator,
+        url_string: *const CFString,
+        base_url: *const CFURL,
+    ) -> *const CFURL;
+
+    pub fn CFURLCreateWithFileSystemPath(
+        allocator: *const CFAllocator,
+        file_path: *const CFString,
+        path_style: CFURLPathStyle,
+        is_directory: Boolean,
+    ) -> *const CFURL;
+
+    pub fn CFURLCopyFileSystemPath(
+        an_url: *const CFURL,
+        path_style: CFURLPathStyle,
+    ) -> *const CFString;
+
+    pub fn CFURLGetBaseURL(an_url: *const CFURL) -> *const CFURL;
+
+    pub fn CFURLCopyScheme(an_url: *const CFURL) -> *const CFString;
+
+    pub fn CFURLCopyHostName(an_url: *const CFURL) -> *const CFString;
+
+    pub fn CFURLHasDirectoryPath(an_url: *const CFURL) -> Boolean;
+
+    pub fn CFURLCopyLastPathComponent(url: *const CFURL) -> *const CFString;
+
+    pub fn CFURLCopyPathExtension(url: *const CFURL) -> *const CFString;
+
+    pub fn CFURLCreateCopyAppendingPathComponent(
+        allocator: *const CFAllocator,
+        url: *const CFURL,
+        path_component: *const CFString,
+        is_directory: Boolean,
+    ) -> *const CFURL;
+
+    pub fn CFURLCreateCopyDeletingLastPathComponent(
+        allocator: *const CFAllocator,
+        url: *const CFURL,
+    ) -> *const CFURL;
+
+    pub fn CFUUIDGetTypeID() -> CFTypeID;
+
+    pub fn CFUUIDCreate(alloc: *const CFAllocator) -> *const CFUUID;
+
+    pub fn CFUUIDCreateFromUUIDBytes(
+        alloc: *const CFAllocator,
+        bytes: CFUUIDBytes,
+    ) -> *const CFUUID;
+
+    pub fn CFUUIDCreateFromString(
+        alloc: *const CFAllocator,
+        uuid_str: *const CFString,
+    ) -> *const CFUUID;
+
+    pub fn CFUUIDCreateString(alloc: *const CFAllocator, uuid: *const CFUUID) -> *const CFString;
+
+    pub fn CFUUIDGetUUIDBytes(uuid: *const CFUUID) -> CFUUIDBytes;
+
+    pub fn CFBundleGetTypeID() -> CFTypeID;
+
+    pub fn CFBundleGetMainBundle() -> *const CFBundle;
+
+    pub fn CFBundleGetBundleWithIdentifier(bundle_id: *const CFString) -> *const CFBundle;
+
+    pub fn CFBundleCreate(
+        allocator: *const CFAllocator,
+        bundle_url: *const CFURL,
+    ) -> *const CFBundle;
+
+    pub fn CFBundleGetIdentifier(bundle: *const CFBundle) -> *const CFString;
+
+    pub fn CFBundleCopyBundleURL(bundle: *const CFBundle) -> *const CFURL;
+
+    pub fn CFBundleCopyExecutableURL(bundle: *const CFBundle) -> *const CFURL;
+
+    pub fn CFBundleCopyExecutableArchitectures(bundle: *const CFBundle) -> *const CFArray;
+
+    pub fn CFBundleCopyResourcesDirectoryURL(bundle: *const CFBundle) -> *const CFURL;
+
+    pub fn CFBundleCopyResourceURL(
+        bundle: *const CFBundle,
+        resource_name: *const CFString,
+        resource_type: *const CFString,
+        sub_dir_name: *const CFString,
+    ) -> *const CFURL;
+
+    pub fn CFBundleGetInfoDictionary(bundle: *const CFBundle) -> *const CFDictionary;
+
+    pub fn CFBundleGetValueForInfoDictionaryKey(
+        bundle: *const CFBundle,
+        key: *const CFString,
+    ) -> *const CFType<'static>;
+
+    pub fn CFBundleIsExecutableLoaded(bundle: *const CFBundle) -> Boolean;
+
+    pub fn CFBundleLoadExecutableAndReturnError(
+        bundle: *const CFBundle,
+        error: *mut *const CFError,
+    ) -> Boolean;
+
+    pub fn CFBundleGetFunctionPointerForName(
+        bundle: *const CFBundle,
+        function_name: *const CFString,
+    ) -> *mut c_void;
+
+    pub fn CFBundleGetDataPointerForName(
+        bundle: *const CFBundle,
+        symbol_name: *const CFString,
+    ) -> *mut c_void;
+
+    pub fn CFURLCreateBookmarkData(
+        allocator: *const CFAllocator,
+        url: *const CFURL,
+        options: CFURLBookmarkCreationOptions,
+        resource_properties_to_include: *const CFType,
+        relative_to_url: *const CFURL,
+        error: *mut *const CFError,
+    ) -> *const CFData;
+
+    pub fn CFURLCreateByResolvingBookmarkData(
+        allocator: *const CFAllocator,
+        bookmark: *const CFData,
+        options: CFURLBookmarkResolutionOptions,
+        relative_to_url: *const CFURL,
+        resource_properties_to_include: *const CFType,
+        is_stale: *mut Boolean,
+        error: *mut *const CFError,
+    ) -> *const CFURL;
+
+    pub fn CFURLCreateBookmarkDataFromFile(
+        allocator: *const CFAllocator,
+        file_url: *const CFURL,
+        error: *mut *const CFError,
+    ) -> *const CFData;
+
+    pub fn CFURLWriteBookmarkDataToFile(
+        bookmark: *const CFData,
+        file_url: *const CFURL,
+        options: CFOptionFlags,
+        error: *mut *const CFError,
+    ) -> Boolean;
+
+    pub fn CFURLCreateBookmarkDataFromAliasRecord(
+        allocator: *const CFAllocator,
+        alias_record_data: *const CFData,
+    ) -> *const CFData;
+
+    pub fn CFURLStartAccessingSecurityScopedResource(url: *const CFURL) -> Boolean;
+
+    pub fn CFURLStopAccessingSecurityScopedResource(url: *const CFURL);
+
+    pub fn CFArrayGetTypeID() -> CFTypeID;
+
+    pub fn CFArrayCreate(
+        allocator: *const CFAllocator,
+        values: *const *const c_void,
+        num_values: CFIndex,
+        call_backs: *const CFArrayCallBacks,
+    ) -> *const CFArray;
+
+    pub fn CFArrayCreateMutable(
+        allocator: *const CFAllocator,
+        capacity: CFIndex,
+        call_backs: *const CFArrayCallBacks,
+    ) -> *mut CFArray;
+
+    pub fn CFArrayCreateMutableCopy(
+        allocator: *const CFAllocator,
+        capacity: CFIndex,
+        the_array: *const CFArray,
+    ) -> *mut CFArray;
+
+    pub fn CFArrayGetCount(the_array: *const CFArray) -> CFIndex;
+
+    pub fn CFArrayGetValueAtIndex(the_array: *const CFArray, idx: CFIndex) -> *const c_void;
+
+    pub fn CFArrayGetValues(the_array: *const CFArray, range: CFRange, values: *mut *const c_void);
+
+    pub fn CFArrayContainsValue(
+        the_array: *const CFArray,
+        range: CFRange,
+        value: *const c_void,
+    ) -> Boolean;
+
+    pub fn CFArrayAppendValue(the_array: *mut CFArray, value: *const c_void);
+
+    pub fn CFArrayInsertValueAtIndex(the_array: *mut CFArray, idx: CFIndex, value: *const c_void);
+
+    pub fn CFArraySetValueAtIndex(the_array: *mut CFArray, idx: CFIndex, value: *const c_void);
+
+    pub fn CFArrayRemoveValueAtIndex(the_array: *mut CFArray, idx: CFIndex);
+
+    pub fn CFArrayRemoveAllValues(the_array: *mut CFArray);
+
+    pub fn CFDictionaryGetTypeID() -> CFTypeID;
+
+    pub fn CFDictionaryCreate(
+        allocator: *const CFAllocator,
+        keys: *const *const c_void,
+        values: *const *const c_void,
+        num_values: CFIndex,
+        key_call_backs: *const CFDictionaryKeyCallBacks,
+        value_call_backs: *const CFDictionaryValueCallBacks,
+    ) -> *const CFDictionary;
+
+    pub fn CFDictionaryCreateMutable(
+        allocator: *const CFAllocator,
+        capacity: CFIndex,
+        key_call_backs: *const CFDictionaryKeyCallBacks,
+        value_call_backs: *const CFDictionaryValueCallBacks,
+    ) -> *mut CFDictionary;
+
+    pub fn CFDictionaryCreateMutableCopy(
+        allocator: *const CFAllocator,
+        capacity: CFIndex,
+        the_dict: *const CFDictionary,
+    ) -> *mut CFDictionary;
+
+    pub fn CFDictionaryGetCount(the_dict: *const CFDictionary) -> CFIndex;
+
+    pub fn CFDictionaryGetValueIfPresent(
+        the_dict: *const CFDictionary,
+        key: *const c_void,
+        value: *mut *const c_void,
+    ) -> Boolean;
+
+    pub fn CFDictionaryContainsKey(the_dict: *const CFDictionary, key: *const c_void) -> Boolean;
+
+    pub fn CFDictionaryGetKeysAndValues(
+        the_dict: *const CFDictionary,
+        keys: *mut *const c_void,
+        values: *mut *const c_void,
+    );
+
+    pub fn CFDictionarySetValue(
+        the_dict: *mut CFDictionary,
+        key: *const c_void,
+        value: *const c_void,
+    );
+
+    pub fn CFDictionaryRemoveValue(the_dict: *mut CFDictionary, key: *const c_void);
+
+    pub fn CFDictionaryRemoveAllValues(the_dict: *mut CFDictionary);
+
+    pub fn CFAbsoluteTimeGetCurrent() -> CFAbsoluteTime;
+
+    pub fn CFDateGetTypeID() -> CFTypeID;
+
+    pub fn CFDateCreate(allocator: *const CFAllocator, at: CFAbsoluteTime) -> *const CFDate;
+
+    pub fn CFDateGetAbsoluteTime(the_date: *const CFDate) -> CFAbsoluteTime;
+
+    pub fn CFDateGetTimeIntervalSinceDate(
+        the_date: *const CFDate,
+        other_date: *const CFDate,
+    ) -> CFTimeInterval;
+
+    pub fn CFDateCompare(
+        the_date: *const CFDate,
+        other_date: *const CFDate,
+        context: *mut c_void,
+    ) -> CFComparisonResult;
+
+    pub fn CFPropertyListCreateWithData(
+        allocator: *const CFAllocator,
+        data: *const CFData,
+        options: CFPropertyListMutabilityOptions,
+        format: *mut CFPropertyListFormat,
+        error: *mut *const CFError,
+    ) -> *const CFType<'static>;
+
+    pub fn CFPropertyListCreateData(
+        allocator: *const CFAllocator,
+        property_list: *const CFType<'static>,
+        format: CFPropertyListFormat,
+        options: CFOptionFlags,
+        error: *mut *const CFError,
+    ) -> *const CFData;
+
+    pub fn CFPropertyListIsValid(
+        plist: *const CFType<'static>,
+        format: CFPropertyListFormat,
+    ) -> Boolean;
+
+    pub fn CFPreferencesCopyAppValue(
+        key: *const CFString,
+        application_id: *const CFString,
+    ) -> *const CFType<'static>;
+
+    pub fn CFPreferencesSetAppValue(
+        key: *const CFString,
+        value: *const CFType<'static>,
+        application_id: *const CFString,
+    );
+
+    pub fn CFPreferencesAppSynchronize(application_id: *const CFString) -> Boolean;
+
+    pub fn CFPreferencesCopyValue(
+        key: *const CFString,
+        application_id: *const CFString,
+        user_name: *const CFString,
+        host_name: *const CFString,
+    ) -> *const CFType<'static>;
+
+    pub fn CFPreferencesSetValue(
+        key: *const CFString,
+        value: *const CFType<'static>,
+        application_id: *const CFString,
+        user_name: *const CFString,
+        host_name: *const CFString,
+    );
+
+    pub fn CFPreferencesSynchronize(
+        application_id: *const CFString,
+        user_name: *const CFString,
+        host_name: *const CFString,
+    ) -> Boolean;
+
+    pub fn CFPreferencesCopyKeyList(
+        application_id: *const CFString,
+        user_name: *const CFString,
+        host_name: *const CFString,
+    ) -> *const CFArray;
+
+    pub fn CFRunLoopGetTypeID() -> CFTypeID;
+    pub fn CFRunLoopGetCurrent() -> *const CFRunLoop;
+    pub fn CFRunLoopGetMain() -> *const CFRunLoop;
+
+    pub fn CFRunLoopRun();
+    pub fn CFRunLoopRunInMode(
+        mode: *const CFRunLoopMode,
+        seconds: CFTimeInterval,
+        return_after_source_handled: Boolean,
+    ) -> CFRunLoopRunResult;
+    pub fn CFRunLoopStop(rl: *const CFRunLoop);
+    pub fn CFRunLoopWakeUp(rl: *const CFRunLoop);
+    pub fn CFRunLoopIsWaiting(rl: *const CFRunLoop) -> Boolean;
+    pub fn CFRunLoopCopyCurrentMode(rl: *const CFRunLoop) -> *const CFRunLoopMode;
+    pub fn CFRunLoopCopyAllModes(rl: *const CFRunLoop) -> *const CFArray<CFRunLoopMode>;
+    pub fn CFRunLoopAddCommonMode(rl: *const CFRunLoop, mode: *const CFRunLoopMode);
+
+    pub fn CFRunLoopAddTimer(
+        rl: *const CFRunLoop,
+        timer: *const CFRunLoopTimer,
+        mode: *const CFRunLoopMode,
+    );
+    pub fn CFRunLoopRemoveTimer(
+        rl: *const CFRunLoop,
+        timer: *const CFRunLoopTimer,
+        mode: *const CFRunLoopMode,
+    );
+    pub fn CFRunLoopContainsTimer(
+        rl: *const CFRunLoop,
+        timer: *const CFRunLoopTimer,
+        mode: *const CFRunLoopMode,
+    ) -> Boolean;
+
+    pub fn CFRunLoopAddSource(
+        rl: *const CFRunLoop,
+        source: *const CFRunLoopSource,
+        mode: *const CFRunLoopMode,
+    );
+    pub fn CFRunLoopRemoveSource(
+        rl: *const CFRunLoop,
+        source: *const CFRunLoopSource,
+        mode: *const CFRunLoopMode,
+    );
+    pub fn CFRunLoopContainsSource(
+        rl: *const CFRunLoop,
+        source: *const CFRunLoopSource,
+        mode: *const CFRunLoopMode,
+    ) -> Boolean;
+
+    pub fn CFRunLoopAddObserver(
+        rl: *const CFRunLoop,
+        observer: *const CFRunLoopObserver,
+        mode: *const CFRunLoopMode,
+    );
+    pub fn CFRunLoopRemoveObserver(
+        rl: *const CFRunLoop,
+        observer: *const CFRunLoopObserver,
+        mode: *const CFRunLoopMode,
+    );
+    pub fn CFRunLoopContainsObserver(
+        rl: *const CFRunLoop,
+        observer: *const CFRunLoopObserver,
+        mode: *const CFRunLoopMode,
+    ) -> Boolean;
+
+    pub fn CFRunLoopTimerGetTypeID() -> CFTypeID;
+    pub fn CFRunLoopTimerCreate(
+        allocator: *const CFAllocator,
+        fire_date: CFAbsoluteTime,
+        interval: CFTimeInterval,
+        flags: CFOptionFlags,
+        order: CFIndex,
+        callout: CFRunLoopTimerCallBack,
+        context: *mut CFRunLoopTimerContext,
+    ) -> *const CFRunLoopTimer;
+    pub fn CFRunLoopTimerGetInterval(timer: *const CFRunLoopTimer) -> CFTimeInterval;
+    pub fn CFRunLoopTimerDoesRepeat(timer: *const CFRunLoopTimer) -> Boolean;
+    pub fn CFRunLoopTimerGetNextFireDate(timer: *const CFRunLoopTimer) -> CFAbsoluteTime;
+    pub fn CFRunLoopTimerSetNextFireDate(timer: *const CFRunLoopTimer, fire_date: CFAbsoluteTime);
+    pub fn CFRunLoopTimerInvalidate(timer: *const CFRunLoopTimer);
+    pub fn CFRunLoopTimerIsValid(timer: *const CFRunLoopTimer) -> Boolean;
+    pub fn CFRunLoopTimerGetTolerance(timer: *const CFRunLoopTimer) -> CFTimeInterval;
+    pub fn CFRunLoopTimerSetTolerance(timer: *const CFRunLoopTimer, tolerance: CFTimeInterval);
+
+    pub fn CFRunLoopSourceGetTypeID() -> CFTypeID;
+    pub fn CFRunLoopSourceCreate(
+        allocator: *const CFAllocator,
+        order: CFIndex,
+        context: *mut CFRunLoopSourceContext,
+    ) -> *const CFRunLoopSource;
+    pub fn CFRunLoopSourceSignal(source: *const CFRunLoopSource);
+    pub fn CFRunLoopSourceGetOrder(source: *const CFRunLoopSource) -> CFIndex;
+    pub fn CFRunLoopSourceInvalidate(source: *const CFRunLoopSource);
+    pub fn CFRunLoopSourceIsValid(source: *const CFRunLoopSource) -> Boolean;
+
+    pub fn CFRunLoopObserverGetTypeID() -> CFTypeID;
+    pub fn CFRunLoopObserverCreate(
+        allocator: *const CFAllocator,
+        activities: CFRunLoopActivity,
+        repeats: Boolean,
+        order: CFIndex,
+        callout: CFRunLoopObserverCallBack,
+        context: *mut CFRunLoopObserverContext,
+    ) -> *const CFRunLoopObserver;
+    pub fn CFRunLoopObserverGetActivities(observer: *const CFRunLoopObserver) -> CFRunLoopActivity;
+    pub fn CFRunLoopObserverDoesRepeat(observer: *const CFRunLoopObserver) -> Boolean;
+    pub fn CFRunLoopObserverInvalidate(observer: *const CFRunLoopObserver);
+    pub fn CFRunLoopObserverIsValid(observer: *const CFRunLoopObserver) -> Boolean;
+
+    pub fn CFSocketGetTypeID() -> CFTypeID;
+    pub fn CFSocketCreate(
+        allocator: *const CFAllocator,
+        protocol_family: c_int,
+        socket_type: c_int,
+        protocol: c_int,
+        callback_types: CFSocketCallBackType,
+        callout: Option<CFSocketCallBack>,
+        context: *const CFSocketContext,
+    ) -> *const CFSocket;
+    pub fn CFSocketCreateWithNative(
+        allocator: *const CFAllocator,
+        sock: CFSocketNativeHandle,
+        callback_types: CFSocketCallBackType,
+        callout: Option<CFSocketCallBack>,
+        context: *const CFSocketContext,
+    ) -> *const CFSocket;
+    pub fn CFSocketGetContext(s: *const CFSocket, context: *mut CFSocketContext);
+    pub fn CFSocketGetNative(s: *const CFSocket) -> CFSocketNativeHandle;
+    pub fn CFSocketCopyAddress(s: *const CFSocket) -> *const CFData;
+    pub fn CFSocketCopyPeerAddress(s: *const CFSocket) -> *const CFData;
+    pub fn CFSocketSetAddress(s: *const CFSocket, address: *const CFData) -> CFIndex;
+    pub fn CFSocketConnectToAddress(
+        s: *const CFSocket,
+        address: *const CFData,
+        timeout: CFTimeInterval,
+    ) -> CFIndex;
+    pub fn CFSocketSendData(
+        s: *const CFSocket,
+        address: *const CFData,
+        data: *const CFData,
+        timeout: CFTimeInterval,
+    ) -> CFIndex;
+    pub fn CFSocketCreateRunLoopSource(
+        allocator: *const CFAllocator,
+        s: *const CFSocket,
+        order: CFIndex,
+    ) -> *const CFRunLoopSource;
+    pub fn CFSocketEnableCallBacks(s: *const CFSocket, callback_types: CFSocketCallBackType);
+    pub fn CFSocketDisableCallBacks(s: *const CFSocket, callback_types: CFSocketCallBackType);
+    pub fn CFSocketGetSocketFlags(s: *const CFSocket) -> CFSocketFlags;
+    pub fn CFSocketSetSocketFlags(s: *const CFSocket, flags: CFSocketFlags);
+    pub fn CFSocketInvalidate(s: *const CFSocket);
+    pub fn CFSocketIsValid(s: *const CFSocket) -> Boolean;
+
+    pub fn CFStreamCreatePairWithSocket(
+        allocator: *const CFAllocator,
+        sock: CFSocketNativeHandle,
+        read_stream: *mut *const CFReadStream,
+        write_stream: *mut *const CFWriteStream,
+    );
+    pub fn CFStreamCreatePairWithSocketToHost(
+        allocator: *const CFAllocator,
+        host: *const CFString,
+        port: u32,
+        read_stream: *mut *const CFReadStream,
+        write_stream: *mut *const CFWriteStream,
+    );
+
+    pub fn CFReadStreamGetTypeID() -> CFTypeID;
+    pub fn CFReadStreamCreateWithBytesNoCopy(
+        allocator: *const CFAllocator,
+        bytes: *const u8,
+        length: CFIndex,
+        bytes_deallocator: *const CFAllocator,
+    ) -> *const CFReadStream;
+    pub fn CFReadStreamCreateWithFile(
+        allocator: *const CFAllocator,
+        file_url: *const CFURL,
+    ) -> *const CFReadStream;
+    pub fn CFReadStreamOpen(stream: *const CFReadStream) -> Boolean;
+    pub fn CFReadStreamClose(stream: *const CFReadStream);
+    pub fn CFReadStreamGetStatus(stream: *const CFReadStream) -> CFStreamStatus;
+    pub fn CFReadStreamCopyError(stream: *const CFReadStream) -> *const CFError;
+    pub fn CFReadStreamHasBytesAvailable(stream: *const CFReadStream) -> Boolean;
+    pub fn CFReadStreamRead(
+        stream: *const CFReadStream,
+        buffer: *mut u8,
+        buffer_length: CFIndex,
+    ) -> CFIndex;
+    pub fn CFReadStreamCopyProperty(
+        stream: *const CFReadStream,
+        property_name: *const CFStreamPropertyKey,
+    ) -> *const CFType<'static>;
+    pub fn CFReadStreamSetProperty(
+        stream: *const CFReadStream,
+        property_name: *const CFStreamPropertyKey,
+        property_value: *const CFType<'static>,
+    ) -> Boolean;
+    pub fn CFReadStreamSetClient(
+        stream: *const CFReadStream,
+        stream_events: CFStreamEventType,
+        client_cb: Option<CFReadStreamClientCallBack>,
+        client_context: *mut CFStreamClientContext,
+    ) -> Boolean;
+    pub fn CFReadStreamScheduleWithRunLoop(
+        stream: *const CFReadStream,
+        run_loop: *const CFRunLoop,
+        run_loop_mode: *const CFRunLoopMode,
+    );
+    pub fn CFReadStreamUnscheduleFromRunLoop(
+        stream: *const CFReadStream,
+        run_loop: *const CFRunLoop,
+        run_loop_mode: *const CFRunLoopMode,
+    );
+
+    pub fn CFWriteStreamGetTypeID() -> CFTypeID;
+    pub fn CFWriteStreamCreateWithAllocatedBuffers(
+        allocator: *const CFAllocator,
+        buffer_allocator: *const CFAllocator,
+    ) -> *const CFWriteStream;
+    pub fn CFWriteStreamCreateWithFile(
+        allocator: *const CFAllocator,
+        file_url: *const CFURL,
+    ) -> *const CFWriteStream;
+    pub fn CFWriteStreamOpen(stream: *const CFWriteStream) -> Boolean;
+    pub fn CFWriteStreamClose(stream: *const CFWriteStream);
+    pub fn CFWriteStreamGetStatus(stream: *const CFWriteStream) -> CFStreamStatus;
+    pub fn CFWriteStreamCopyError(stream: *const CFWriteStream) -> *const CFError;
+    pub fn CFWriteStreamCanAcceptBytes(stream: *const CFWriteStream) -> Boolean;
+    pub fn CFWriteStreamWrite(
+        stream: *const CFWriteStream,
+        buffer: *const u8,
+        buffer_length: CFIndex,
+    ) -> CFIndex;
+    pub fn CFWriteStreamCopyProperty(
+        stream: *const CFWriteStream,
+        property_name: *const CFStreamPropertyKey,
+    ) -> *const CFType<'static>;
+    pub fn CFWriteStreamSetProperty(
+        stream: *const CFWriteStream,
+        property_name: *const CFStreamPropertyKey,
+        property_value: *const CFType<'static>,
+    ) -> Boolean;
+    pub fn CFWriteStreamSetClient(
+        stream: *const CFWriteStream,
+        stream_events: CFStreamEventType,
+        client_cb: Option<CFWriteStreamClientCallBack>,
+        client_context: *mut CFStreamClientContext,
+    ) -> Boolean;
+    pub fn CFWriteStreamScheduleWithRunLoop(
+        stream: *const CFWriteStream,
+        run_loop: *const CFRunLoop,
+        run_loop_mode: *const CFRunLoopMode,
+    );
+    pub fn CFWriteStreamUnscheduleFromRunLoop(
+        stream: *const CFWriteStream,
+        run_loop: *const CFRunLoop,
+        run_loop_mode: *const CFRunLoopMode,
+    );
 }
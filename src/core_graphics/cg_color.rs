@@ -0,0 +1,182 @@
+use super::{sys, CGColorRenderingIntent, CGColorSpace, CGFloat};
+use crate::core::Arc;
+use crate::core_foundation::{CFString, CFType, CFTypeID};
+use std::{fmt, ptr, slice};
+
+subclass! {
+    /// A color with components in a [`CGColorSpace`].
+    ///
+    /// Documentation:
+    /// [Swift](https://developer.apple.com/documentation/coregraphics/cgcolor?language=swift) |
+    /// [Objective-C](https://developer.apple.com/documentation/coregraphics/cgcolorref?language=objc)
+    #[derive(PartialEq, Hash)]
+    pub class CGColor: CFType<'static>;
+}
+
+impl fmt::Debug for CGColor {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CGColor")
+            .field("color_space", &self.color_space())
+            .field("components", &self.components())
+            .finish()
+    }
+}
+
+impl CGColor {
+    /// Returns the type identifier for `CGColor`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coregraphics/cgcolor/typeid).
+    #[inline]
+    #[doc(alias = "CGColorGetTypeID")]
+    pub fn type_id() -> CFTypeID {
+        unsafe { sys::CGColorGetTypeID() }
+    }
+
+    /// Creates a color in `space` from its color components followed by
+    /// alpha, or returns [`None`] if the number of components does not match
+    /// `space`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coregraphics/cgcolor/init(colorspace:components:)).
+    #[inline]
+    #[doc(alias = "CGColorCreate")]
+    pub fn new(space: &CGColorSpace, components: &[CGFloat]) -> Option<Arc<Self>> {
+        if components.len() != space.number_of_components() + 1 {
+            return None;
+        }
+        unsafe {
+            let color = sys::CGColorCreate(space, components.as_ptr());
+            if color.is_null() {
+                None
+            } else {
+                Some(Arc::from_raw(color))
+            }
+        }
+    }
+
+    /// Creates a color in the [sRGB](CGColorSpace::srgb) color space.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coregraphics/cgcolor/init(srgbred:green:blue:alpha:)).
+    #[inline]
+    #[doc(alias = "CGColorCreateSRGB")]
+    pub fn srgb(red: CGFloat, green: CGFloat, blue: CGFloat, alpha: CGFloat) -> Arc<Self> {
+        unsafe { Arc::from_raw(sys::CGColorCreateSRGB(red, green, blue, alpha)) }
+    }
+
+    /// Creates a color in the [generic gray](CGColorSpace::generic_gray)
+    /// color space.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coregraphics/cgcolor/init(gray:alpha:)).
+    #[inline]
+    #[doc(alias = "CGColorCreateGenericGray")]
+    pub fn gray(gray: CGFloat, alpha: CGFloat) -> Arc<Self> {
+        unsafe { Arc::from_raw(sys::CGColorCreateGenericGray(gray, alpha)) }
+    }
+
+    /// Returns opaque black.
+    #[inline]
+    #[doc(alias = "kCGColorBlack")]
+    pub fn black() -> Arc<Self> {
+        unsafe { Self::constant(sys::kCGColorBlack) }
+    }
+
+    /// Returns opaque white.
+    #[inline]
+    #[doc(alias = "kCGColorWhite")]
+    pub fn white() -> Arc<Self> {
+        unsafe { Self::constant(sys::kCGColorWhite) }
+    }
+
+    /// Returns black with zero alpha.
+    #[inline]
+    #[doc(alias = "kCGColorClear")]
+    pub fn clear() -> Arc<Self> {
+        unsafe { Self::constant(sys::kCGColorClear) }
+    }
+
+    #[inline]
+    unsafe fn constant(name: &CFString) -> Arc<Self> {
+        Arc::retain_raw(sys::CGColorGetConstantColor(name))
+    }
+
+    /// Returns a copy of `self` with a different alpha.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coregraphics/cgcolor/copy(alpha:)).
+    #[inline]
+    #[doc(alias = "CGColorCreateCopyWithAlpha")]
+    pub fn with_alpha(&self, alpha: CGFloat) -> Arc<Self> {
+        unsafe { Arc::from_raw(sys::CGColorCreateCopyWithAlpha(self, alpha)) }
+    }
+
+    /// Returns `self` converted to `space`, or [`None`] if it cannot be
+    /// converted.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coregraphics/cgcolor/converted(to:intent:options:)).
+    #[inline]
+    #[doc(alias = "CGColorCreateCopyByMatchingToColorSpace")]
+    pub fn converted(&self, space: &CGColorSpace) -> Option<Arc<Self>> {
+        self.converted_with_intent(space, CGColorRenderingIntent::DEFAULT)
+    }
+
+    /// Returns `self` converted to `space`, handling colors outside of the
+    /// gamut of `space` according to `intent`, or [`None`] if it cannot be
+    /// converted.
+    ///
+    /// This is how a color is matched between the
+    /// [color spaces of displays](super::CGDirectDisplayID::color_space).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coregraphics/cgcolor/converted(to:intent:options:)).
+    #[inline]
+    #[doc(alias = "CGColorCreateCopyByMatchingToColorSpace")]
+    pub fn converted_with_intent(
+        &self,
+        space: &CGColorSpace,
+        intent: CGColorRenderingIntent,
+    ) -> Option<Arc<Self>> {
+        unsafe {
+            let color =
+                sys::CGColorCreateCopyByMatchingToColorSpace(space, intent, self, ptr::null());
+            if color.is_null() {
+                None
+            } else {
+                Some(Arc::from_raw(color))
+            }
+        }
+    }
+
+    /// Returns the color space of `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coregraphics/cgcolor/colorspace).
+    #[inline]
+    #[doc(alias = "CGColorGetColorSpace")]
+    pub fn color_space(&self) -> Arc<CGColorSpace> {
+        unsafe { Arc::retain_raw(sys::CGColorGetColorSpace(self)) }
+    }
+
+    /// Returns the color components of `self` followed by alpha.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coregraphics/cgcolor/components).
+    #[inline]
+    #[doc(alias = "CGColorGetComponents")]
+    #[doc(alias = "CGColorGetNumberOfComponents")]
+    pub fn components(&self) -> &[CGFloat] {
+        unsafe {
+            let len = sys::CGColorGetNumberOfComponents(self);
+            let components = sys::CGColorGetComponents(self);
+            if components.is_null() {
+                &[]
+            } else {
+                slice::from_raw_parts(components, len)
+            }
+        }
+    }
+
+    /// Returns the alpha of `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coregraphics/cgcolor/alpha).
+    #[inline]
+    #[doc(alias = "CGColorGetAlpha")]
+    pub fn alpha(&self) -> CGFloat {
+        unsafe { sys::CGColorGetAlpha(self) }
+    }
+}
@@ -0,0 +1,248 @@
+use super::sys;
+use crate::core::Arc;
+use crate::core_foundation::{CFData, CFString, CFType, CFTypeID};
+use std::fmt;
+
+subclass! {
+    /// A specification of how color values are interpreted, such as sRGB or
+    /// Display P3.
+    ///
+    /// Documentation:
+    /// [Swift](https://developer.apple.com/documentation/coregraphics/cgcolorspace?language=swift) |
+    /// [Objective-C](https://developer.apple.com/documentation/coregraphics/cgcolorspaceref?language=objc)
+    #[derive(PartialEq, Hash)]
+    pub class CGColorSpace: CFType<'static>;
+}
+
+impl fmt::Debug for CGColorSpace {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CGColorSpace")
+            .field("name", &self.name())
+            .finish()
+    }
+}
+
+impl CGColorSpace {
+    /// Returns the type identifier for `CGColorSpace`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coregraphics/cgcolorspace/typeid).
+    #[inline]
+    #[doc(alias = "CGColorSpaceGetTypeID")]
+    pub fn type_id() -> CFTypeID {
+        unsafe { sys::CGColorSpaceGetTypeID() }
+    }
+
+    /// Returns the standard RGB color space used by the web and most images.
+    #[inline]
+    #[doc(alias = "kCGColorSpaceSRGB")]
+    pub fn srgb() -> Arc<Self> {
+        Self::system(unsafe { sys::kCGColorSpaceSRGB })
+    }
+
+    /// Returns the sRGB color space with a linear transfer function.
+    #[inline]
+    #[doc(alias = "kCGColorSpaceLinearSRGB")]
+    pub fn linear_srgb() -> Arc<Self> {
+        Self::system(unsafe { sys::kCGColorSpaceLinearSRGB })
+    }
+
+    /// Returns the wide gamut color space of recent Apple displays.
+    #[inline]
+    #[doc(alias = "kCGColorSpaceDisplayP3")]
+    pub fn display_p3() -> Arc<Self> {
+        Self::system(unsafe { sys::kCGColorSpaceDisplayP3 })
+    }
+
+    /// Returns the grayscale color space with a gamma of 2.2.
+    #[inline]
+    #[doc(alias = "kCGColorSpaceGenericGrayGamma2_2")]
+    pub fn generic_gray() -> Arc<Self> {
+        Self::system(unsafe { sys::kCGColorSpaceGenericGrayGamma2_2 })
+    }
+
+    /// Returns the generic RGB color space used by older versions of macOS.
+    #[inline]
+    #[doc(alias = "kCGColorSpaceGenericRGB")]
+    pub fn generic_rgb() -> Arc<Self> {
+        Self::system(unsafe { sys::kCGColorSpaceGenericRGB })
+    }
+
+    /// Returns the RGB color space of the main display.
+    ///
+    /// Colors in this space look different on other displays, so a named
+    /// color space such as [`srgb`](Self::srgb) is usually preferable.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coregraphics/cgcolorspacecreatedevicergb()).
+    #[inline]
+    #[doc(alias = "CGColorSpaceCreateDeviceRGB")]
+    pub fn device_rgb() -> Arc<Self> {
+        unsafe { Arc::from_raw(sys::CGColorSpaceCreateDeviceRGB()) }
+    }
+
+    /// Returns the grayscale color space of the main display.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coregraphics/cgcolorspacecreatedevicegray()).
+    #[inline]
+    #[doc(alias = "CGColorSpaceCreateDeviceGray")]
+    pub fn device_gray() -> Arc<Self> {
+        unsafe { Arc::from_raw(sys::CGColorSpaceCreateDeviceGray()) }
+    }
+
+    /// Returns the system color space called `name`, or [`None`] if there is
+    /// no such color space.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coregraphics/cgcolorspace/init(name:)).
+    #[inline]
+    #[doc(alias = "CGColorSpaceCreateWithName")]
+    pub fn with_name(name: &CFString) -> Option<Arc<Self>> {
+        unsafe {
+            let space = sys::CGColorSpaceCreateWithName(name);
+            if space.is_null() {
+                None
+            } else {
+                Some(Arc::from_raw(space))
+            }
+        }
+    }
+
+    /// Creates a color space from the contents of an ICC profile, or returns
+    /// [`None`] if `data` is not a valid profile.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coregraphics/cgcolorspace/init(iccdata:)).
+    #[inline]
+    #[doc(alias = "CGColorSpaceCreateWithICCData")]
+    pub fn from_icc_data(data: &CFData) -> Option<Arc<Self>> {
+        unsafe {
+            let space = sys::CGColorSpaceCreateWithICCData(data);
+            if space.is_null() {
+                None
+            } else {
+                Some(Arc::from_raw(space))
+            }
+        }
+    }
+
+    #[inline]
+    fn system(name: &CFString) -> Arc<Self> {
+        Self::with_name(name).expect("system color space is missing")
+    }
+
+    /// Returns the name of `self`, or [`None`] if it was not created by
+    /// name.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coregraphics/cgcolorspace/name).
+    #[inline]
+    #[doc(alias = "CGColorSpaceCopyName")]
+    pub fn name(&self) -> Option<Arc<CFString>> {
+        unsafe {
+            let name = sys::CGColorSpaceCopyName(self);
+            if name.is_null() {
+                None
+            } else {
+                Some(Arc::from_raw(name))
+            }
+        }
+    }
+
+    /// Returns the ICC profile that describes `self`, or [`None`] if it
+    /// cannot be represented as one.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coregraphics/cgcolorspace/copyiccdata()).
+    #[inline]
+    #[doc(alias = "CGColorSpaceCopyICCData")]
+    pub fn icc_data(&self) -> Option<Arc<CFData>> {
+        unsafe {
+            let data = sys::CGColorSpaceCopyICCData(self);
+            if data.is_null() {
+                None
+            } else {
+                Some(Arc::from_raw(data))
+            }
+        }
+    }
+
+    /// Returns the number of color components, excluding alpha.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coregraphics/cgcolorspace/numberofcomponents).
+    #[inline]
+    #[doc(alias = "CGColorSpaceGetNumberOfComponents")]
+    pub fn number_of_components(&self) -> usize {
+        unsafe { sys::CGColorSpaceGetNumberOfComponents(self) }
+    }
+
+    /// Returns the kind of color components in `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coregraphics/cgcolorspace/model).
+    #[inline]
+    #[doc(alias = "CGColorSpaceGetModel")]
+    pub fn model(&self) -> CGColorSpaceModel {
+        unsafe { sys::CGColorSpaceGetModel(self) }
+    }
+}
+
+/// The kind of color components in a [`CGColorSpace`].
+///
+/// See [documentation](https://developer.apple.com/documentation/coregraphics/cgcolorspacemodel).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CGColorSpaceModel(pub i32);
+
+impl CGColorSpaceModel {
+    /// The model could not be determined.
+    #[doc(alias = "kCGColorSpaceModelUnknown")]
+    pub const UNKNOWN: Self = Self(-1);
+
+    /// A single gray component.
+    #[doc(alias = "kCGColorSpaceModelMonochrome")]
+    pub const MONOCHROME: Self = Self(0);
+
+    /// Red, green, and blue components.
+    #[doc(alias = "kCGColorSpaceModelRGB")]
+    pub const RGB: Self = Self(1);
+
+    /// Cyan, magenta, yellow, and black components.
+    #[doc(alias = "kCGColorSpaceModelCMYK")]
+    pub const CMYK: Self = Self(2);
+
+    /// Lightness and two color-opponent components.
+    #[doc(alias = "kCGColorSpaceModelLab")]
+    pub const LAB: Self = Self(3);
+
+    /// Components from a fixed list of colors in a base color space.
+    #[doc(alias = "kCGColorSpaceModelIndexed")]
+    pub const INDEXED: Self = Self(5);
+}
+
+/// How colors outside of the gamut of a destination [`CGColorSpace`] are
+/// mapped into it.
+///
+/// See [documentation](https://developer.apple.com/documentation/coregraphics/cgcolorrenderingintent).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CGColorRenderingIntent(pub i32);
+
+impl CGColorRenderingIntent {
+    /// The default intent of the context or color space.
+    #[doc(alias = "kCGRenderingIntentDefault")]
+    pub const DEFAULT: Self = Self(0);
+
+    /// Maps colors exactly, clipping those out of gamut.
+    #[doc(alias = "kCGRenderingIntentAbsoluteColorimetric")]
+    pub const ABSOLUTE_COLORIMETRIC: Self = Self(1);
+
+    /// Maps colors relative to the white point of each color space, without
+    /// changing colors that are in gamut.
+    #[doc(alias = "kCGRenderingIntentRelativeColorimetric")]
+    pub const RELATIVE_COLORIMETRIC: Self = Self(2);
+
+    /// Compresses the whole gamut to preserve the relationships between
+    /// colors, which suits photographs.
+    #[doc(alias = "kCGRenderingIntentPerceptual")]
+    pub const PERCEPTUAL: Self = Self(3);
+
+    /// Preserves saturation at the expense of hue and lightness, which suits
+    /// charts and graphics.
+    #[doc(alias = "kCGRenderingIntentSaturation")]
+    pub const SATURATION: Self = Self(4);
+}
@@ -0,0 +1,82 @@
+use super::{sys, CGColorSpace, CGRect};
+use crate::core::Arc;
+use crate::core_foundation::CFData;
+use std::ptr;
+
+/// The identifier of a display attached to the system.
+///
+/// Identifiers stay the same while a display is attached, and may be reused
+/// after it is removed.
+///
+/// See [documentation](https://developer.apple.com/documentation/coregraphics/cgdirectdisplayid).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CGDirectDisplayID(pub u32);
+
+impl CGDirectDisplayID {
+    /// Returns the display with the menu bar.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coregraphics/cgmaindisplayid()).
+    #[inline]
+    #[doc(alias = "CGMainDisplayID")]
+    pub fn main() -> Self {
+        unsafe { sys::CGMainDisplayID() }
+    }
+
+    /// Returns the displays that can be drawn to, or an empty list if they
+    /// could not be determined.
+    ///
+    /// Mirrored displays are included, and asleep displays are not.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coregraphics/cggetactivedisplaylist(_:_:_:)).
+    #[doc(alias = "CGGetActiveDisplayList")]
+    pub fn active() -> Vec<Self> {
+        unsafe {
+            let mut count = 0;
+            if sys::CGGetActiveDisplayList(0, ptr::null_mut(), &mut count) != sys::kCGErrorSuccess {
+                return Vec::new();
+            }
+
+            let mut displays = Vec::with_capacity(count as usize);
+            if sys::CGGetActiveDisplayList(count, displays.as_mut_ptr(), &mut count)
+                != sys::kCGErrorSuccess
+            {
+                return Vec::new();
+            }
+            displays.set_len(count as usize);
+            displays
+        }
+    }
+
+    /// Returns the location and size of the display in the global display
+    /// coordinate space.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coregraphics/cgdisplaybounds(_:)).
+    #[inline]
+    #[doc(alias = "CGDisplayBounds")]
+    pub fn bounds(self) -> CGRect {
+        unsafe { sys::CGDisplayBounds(self) }
+    }
+
+    /// Returns the color space of the display's current ColorSync profile.
+    ///
+    /// Colors are matched from one display to another by
+    /// [converting](super::CGColor::converted_with_intent) them to the other
+    /// display's color space.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coregraphics/cgdisplaycopycolorspace(_:)).
+    #[inline]
+    #[doc(alias = "CGDisplayCopyColorSpace")]
+    pub fn color_space(self) -> Arc<CGColorSpace> {
+        unsafe { Arc::from_raw(sys::CGDisplayCopyColorSpace(self)) }
+    }
+
+    /// Returns the contents of the display's current ICC profile, or
+    /// [`None`] if it is not available.
+    ///
+    /// See [`CGColorSpace::icc_data`].
+    #[inline]
+    pub fn icc_data(self) -> Option<Arc<CFData>> {
+        self.color_space().icc_data()
+    }
+}
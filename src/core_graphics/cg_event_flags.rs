@@ -0,0 +1,97 @@
+use std::ops;
+
+/// The modifier keys held during a keyboard or mouse event.
+///
+/// These have the same values as
+/// [`NSEventModifierFlags`](crate::app_kit::NSEventModifierFlags), and the
+/// two convert into each other.
+///
+/// See [documentation](https://developer.apple.com/documentation/coregraphics/cgeventflags).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CGEventFlags(pub u64);
+
+impl ops::BitOr for CGEventFlags {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl ops::BitAnd for CGEventFlags {
+    type Output = Self;
+
+    #[inline]
+    fn bitand(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+}
+
+impl CGEventFlags {
+    /// Caps Lock is on.
+    #[doc(alias = "kCGEventFlagMaskAlphaShift")]
+    pub const CAPS_LOCK: Self = Self(1 << 16);
+
+    /// Shift is held.
+    #[doc(alias = "kCGEventFlagMaskShift")]
+    pub const SHIFT: Self = Self(1 << 17);
+
+    /// Control is held.
+    #[doc(alias = "kCGEventFlagMaskControl")]
+    pub const CONTROL: Self = Self(1 << 18);
+
+    /// Option (Alt) is held.
+    #[doc(alias = "kCGEventFlagMaskAlternate")]
+    pub const OPTION: Self = Self(1 << 19);
+
+    /// Command is held.
+    #[doc(alias = "kCGEventFlagMaskCommand")]
+    pub const COMMAND: Self = Self(1 << 20);
+
+    /// The key is on the numeric keypad, or is an arrow key.
+    #[doc(alias = "kCGEventFlagMaskNumericPad")]
+    pub const NUMERIC_PAD: Self = Self(1 << 21);
+
+    /// Help is held.
+    #[doc(alias = "kCGEventFlagMaskHelp")]
+    pub const HELP: Self = Self(1 << 22);
+
+    /// Fn is held, or the key is a function or navigation key.
+    #[doc(alias = "kCGEventFlagMaskSecondaryFn")]
+    pub const FUNCTION: Self = Self(1 << 23);
+
+    /// The modifiers that keyboard shortcuts are usually made of.
+    pub const SHORTCUT_MODIFIERS: Self =
+        Self(Self::SHIFT.0 | Self::CONTROL.0 | Self::OPTION.0 | Self::COMMAND.0);
+
+    /// Returns `true` if all flags in `other` are set in `self`.
+    #[inline]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns the modifier state expected by `UCKeyTranslate`, which is the
+    /// Carbon `EventModifiers` shifted right by 8 bits.
+    #[allow(dead_code)]
+    pub(crate) fn to_carbon_key_state(self) -> u32 {
+        let mut state = 0;
+        if self.contains(Self::COMMAND) {
+            state |= 1 << 0;
+        }
+        if self.contains(Self::SHIFT) {
+            state |= 1 << 1;
+        }
+        if self.contains(Self::CAPS_LOCK) {
+            state |= 1 << 2;
+        }
+        if self.contains(Self::OPTION) {
+            state |= 1 << 3;
+        }
+        if self.contains(Self::CONTROL) {
+            state |= 1 << 4;
+        }
+        state
+    }
+}
@@ -0,0 +1,232 @@
+use super::{sys, CGColorSpace};
+use crate::core::Arc;
+use crate::core_foundation::{CFType, CFTypeID};
+use std::{fmt, ops};
+
+subclass! {
+    /// A bitmap image.
+    ///
+    /// Images can be converted to RGBA8 or BGRA8 pixels with
+    /// [`Pixels`](super::Pixels).
+    ///
+    /// Documentation:
+    /// [Swift](https://developer.apple.com/documentation/coregraphics/cgimage?language=swift) |
+    /// [Objective-C](https://developer.apple.com/documentation/coregraphics/cgimageref?language=objc)
+    #[derive(PartialEq, Hash)]
+    pub class CGImage: CFType<'static>;
+}
+
+impl fmt::Debug for CGImage {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CGImage")
+            .field("width", &self.width())
+            .field("height", &self.height())
+            .field("bitmap_info", &self.bitmap_info())
+            .finish()
+    }
+}
+
+impl CGImage {
+    /// Returns the type identifier for `CGImage`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coregraphics/cgimage/typeid).
+    #[inline]
+    #[doc(alias = "CGImageGetTypeID")]
+    pub fn type_id() -> CFTypeID {
+        unsafe { sys::CGImageGetTypeID() }
+    }
+
+    /// Returns the width in pixels.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coregraphics/cgimage/width).
+    #[inline]
+    #[doc(alias = "CGImageGetWidth")]
+    pub fn width(&self) -> usize {
+        unsafe { sys::CGImageGetWidth(self) }
+    }
+
+    /// Returns the height in pixels.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coregraphics/cgimage/height).
+    #[inline]
+    #[doc(alias = "CGImageGetHeight")]
+    pub fn height(&self) -> usize {
+        unsafe { sys::CGImageGetHeight(self) }
+    }
+
+    /// Returns the number of bits of each color or alpha component.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coregraphics/cgimage/bitspercomponent).
+    #[inline]
+    #[doc(alias = "CGImageGetBitsPerComponent")]
+    pub fn bits_per_component(&self) -> usize {
+        unsafe { sys::CGImageGetBitsPerComponent(self) }
+    }
+
+    /// Returns the number of bits of each pixel.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coregraphics/cgimage/bitsperpixel).
+    #[inline]
+    #[doc(alias = "CGImageGetBitsPerPixel")]
+    pub fn bits_per_pixel(&self) -> usize {
+        unsafe { sys::CGImageGetBitsPerPixel(self) }
+    }
+
+    /// Returns the number of bytes of each row of pixels, including padding.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coregraphics/cgimage/bytesperrow).
+    #[inline]
+    #[doc(alias = "CGImageGetBytesPerRow")]
+    pub fn bytes_per_row(&self) -> usize {
+        unsafe { sys::CGImageGetBytesPerRow(self) }
+    }
+
+    /// Returns the color space of the pixels, or [`None`] for image masks.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coregraphics/cgimage/colorspace).
+    #[inline]
+    #[doc(alias = "CGImageGetColorSpace")]
+    pub fn color_space(&self) -> Option<Arc<CGColorSpace>> {
+        unsafe {
+            let space = sys::CGImageGetColorSpace(self);
+            if space.is_null() {
+                None
+            } else {
+                Some(Arc::retain_raw(space))
+            }
+        }
+    }
+
+    /// Returns whether and how the pixels store alpha.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coregraphics/cgimage/alphainfo).
+    #[inline]
+    #[doc(alias = "CGImageGetAlphaInfo")]
+    pub fn alpha_info(&self) -> CGImageAlphaInfo {
+        unsafe { sys::CGImageGetAlphaInfo(self) }
+    }
+
+    /// Returns the layout of the pixels.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coregraphics/cgimage/bitmapinfo).
+    #[inline]
+    #[doc(alias = "CGImageGetBitmapInfo")]
+    pub fn bitmap_info(&self) -> CGBitmapInfo {
+        unsafe { sys::CGImageGetBitmapInfo(self) }
+    }
+}
+
+/// Whether a bitmap has an alpha channel, where it is, and whether color
+/// components are premultiplied by it.
+///
+/// See [documentation](https://developer.apple.com/documentation/coregraphics/cgimagealphainfo).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CGImageAlphaInfo(pub u32);
+
+impl CGImageAlphaInfo {
+    /// There is no alpha channel.
+    #[doc(alias = "kCGImageAlphaNone")]
+    pub const NONE: Self = Self(0);
+
+    /// Alpha is the last component, and color components are premultiplied
+    /// by it.
+    #[doc(alias = "kCGImageAlphaPremultipliedLast")]
+    pub const PREMULTIPLIED_LAST: Self = Self(1);
+
+    /// Alpha is the first component, and color components are premultiplied
+    /// by it.
+    #[doc(alias = "kCGImageAlphaPremultipliedFirst")]
+    pub const PREMULTIPLIED_FIRST: Self = Self(2);
+
+    /// Alpha is the last component.
+    #[doc(alias = "kCGImageAlphaLast")]
+    pub const LAST: Self = Self(3);
+
+    /// Alpha is the first component.
+    #[doc(alias = "kCGImageAlphaFirst")]
+    pub const FIRST: Self = Self(4);
+
+    /// The last component is unused.
+    #[doc(alias = "kCGImageAlphaNoneSkipLast")]
+    pub const NONE_SKIP_LAST: Self = Self(5);
+
+    /// The first component is unused.
+    #[doc(alias = "kCGImageAlphaNoneSkipFirst")]
+    pub const NONE_SKIP_FIRST: Self = Self(6);
+
+    /// There is only an alpha channel.
+    #[doc(alias = "kCGImageAlphaOnly")]
+    pub const ONLY: Self = Self(7);
+}
+
+/// The layout of a bitmap: its [alpha info](CGImageAlphaInfo), byte order,
+/// and whether components are floating-point.
+///
+/// See [documentation](https://developer.apple.com/documentation/coregraphics/cgbitmapinfo).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CGBitmapInfo(pub u32);
+
+impl ops::BitOr for CGBitmapInfo {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl From<CGImageAlphaInfo> for CGBitmapInfo {
+    #[inline]
+    fn from(alpha_info: CGImageAlphaInfo) -> Self {
+        Self(alpha_info.0)
+    }
+}
+
+impl CGBitmapInfo {
+    /// The bits that store the [`CGImageAlphaInfo`].
+    #[doc(alias = "kCGBitmapAlphaInfoMask")]
+    pub const ALPHA_INFO_MASK: Self = Self(0x1F);
+
+    /// Components are floating-point values.
+    #[doc(alias = "kCGBitmapFloatComponents")]
+    pub const FLOAT_COMPONENTS: Self = Self(1 << 8);
+
+    /// The bits that store the byte order.
+    #[doc(alias = "kCGBitmapByteOrderMask")]
+    pub const BYTE_ORDER_MASK: Self = Self(0x7000);
+
+    /// The default byte order, which is big-endian.
+    #[doc(alias = "kCGBitmapByteOrderDefault")]
+    pub const BYTE_ORDER_DEFAULT: Self = Self(0 << 12);
+
+    /// 16-bit words are little-endian.
+    #[doc(alias = "kCGBitmapByteOrder16Little")]
+    pub const BYTE_ORDER_16_LITTLE: Self = Self(1 << 12);
+
+    /// 32-bit words are little-endian.
+    #[doc(alias = "kCGBitmapByteOrder32Little")]
+    pub const BYTE_ORDER_32_LITTLE: Self = Self(2 << 12);
+
+    /// 16-bit words are big-endian.
+    #[doc(alias = "kCGBitmapByteOrder16Big")]
+    pub const BYTE_ORDER_16_BIG: Self = Self(3 << 12);
+
+    /// 32-bit words are big-endian.
+    #[doc(alias = "kCGBitmapByteOrder32Big")]
+    pub const BYTE_ORDER_32_BIG: Self = Self(4 << 12);
+
+    /// Returns the alpha info stored in `self`.
+    #[inline]
+    pub const fn alpha_info(self) -> CGImageAlphaInfo {
+        CGImageAlphaInfo(self.0 & Self::ALPHA_INFO_MASK.0)
+    }
+
+    /// Returns `true` if `self` has all of the bits of `other`.
+    #[inline]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
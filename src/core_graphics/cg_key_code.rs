@@ -0,0 +1,527 @@
+use std::fmt;
+
+macro_rules! key_codes {
+    ($(
+        $(#[$docs:meta])+
+        $name:ident = $value:literal, $alias:literal;
+    )+) => {
+        impl CGKeyCode {
+            $(
+                $(#[$docs])+
+                #[doc(alias = $alias)]
+                pub const $name: Self = Self($value);
+            )+
+
+            /// Returns the name of the constant for this key code, such as
+            /// `"ANSI_A"`, or [`None`] if it is not known.
+            pub fn name(self) -> Option<&'static str> {
+                match self {
+                    $(Self::$name => Some(stringify!($name)),)+
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+/// A virtual key code, which identifies a physical key on the keyboard
+/// regardless of the character it types.
+///
+/// Letter, number, and punctuation keys are named by the character they type
+/// on a US keyboard, but other layouts may place different characters on
+/// them. Use [`to_char`](Self::to_char) and [`from_char`](Self::from_char)
+/// for the US layout, such as for keyboard shortcuts that should stay on the
+/// same physical keys, or
+/// [`TISInputSource::translate`](crate::core_services::TISInputSource::translate)
+/// for the user's current layout.
+///
+/// Key codes are received from
+/// [`NSEvent::key_code`](crate::app_kit::NSEvent::key_code).
+///
+/// See [documentation](https://developer.apple.com/documentation/coregraphics/cgkeycode).
+#[repr(transparent)]
+#[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CGKeyCode(pub u16);
+
+impl fmt::Debug for CGKeyCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.name() {
+            Some(name) => write!(f, "CGKeyCode::{}", name),
+            None => f.debug_tuple("CGKeyCode").field(&self.0).finish(),
+        }
+    }
+}
+
+key_codes! {
+    /// The `A` key on a US keyboard.
+    ANSI_A = 0x00, "kVK_ANSI_A";
+
+    /// The `S` key on a US keyboard.
+    ANSI_S = 0x01, "kVK_ANSI_S";
+
+    /// The `D` key on a US keyboard.
+    ANSI_D = 0x02, "kVK_ANSI_D";
+
+    /// The `F` key on a US keyboard.
+    ANSI_F = 0x03, "kVK_ANSI_F";
+
+    /// The `H` key on a US keyboard.
+    ANSI_H = 0x04, "kVK_ANSI_H";
+
+    /// The `G` key on a US keyboard.
+    ANSI_G = 0x05, "kVK_ANSI_G";
+
+    /// The `Z` key on a US keyboard.
+    ANSI_Z = 0x06, "kVK_ANSI_Z";
+
+    /// The `X` key on a US keyboard.
+    ANSI_X = 0x07, "kVK_ANSI_X";
+
+    /// The `C` key on a US keyboard.
+    ANSI_C = 0x08, "kVK_ANSI_C";
+
+    /// The `V` key on a US keyboard.
+    ANSI_V = 0x09, "kVK_ANSI_V";
+
+    /// The `§` key left of 1 on ISO keyboards.
+    ISO_SECTION = 0x0A, "kVK_ISO_Section";
+
+    /// The `B` key on a US keyboard.
+    ANSI_B = 0x0B, "kVK_ANSI_B";
+
+    /// The `Q` key on a US keyboard.
+    ANSI_Q = 0x0C, "kVK_ANSI_Q";
+
+    /// The `W` key on a US keyboard.
+    ANSI_W = 0x0D, "kVK_ANSI_W";
+
+    /// The `E` key on a US keyboard.
+    ANSI_E = 0x0E, "kVK_ANSI_E";
+
+    /// The `R` key on a US keyboard.
+    ANSI_R = 0x0F, "kVK_ANSI_R";
+
+    /// The `Y` key on a US keyboard.
+    ANSI_Y = 0x10, "kVK_ANSI_Y";
+
+    /// The `T` key on a US keyboard.
+    ANSI_T = 0x11, "kVK_ANSI_T";
+
+    /// The `1` key on a US keyboard.
+    ANSI_1 = 0x12, "kVK_ANSI_1";
+
+    /// The `2` key on a US keyboard.
+    ANSI_2 = 0x13, "kVK_ANSI_2";
+
+    /// The `3` key on a US keyboard.
+    ANSI_3 = 0x14, "kVK_ANSI_3";
+
+    /// The `4` key on a US keyboard.
+    ANSI_4 = 0x15, "kVK_ANSI_4";
+
+    /// The `6` key on a US keyboard.
+    ANSI_6 = 0x16, "kVK_ANSI_6";
+
+    /// The `5` key on a US keyboard.
+    ANSI_5 = 0x17, "kVK_ANSI_5";
+
+    /// The `=` key on a US keyboard.
+    ANSI_EQUAL = 0x18, "kVK_ANSI_Equal";
+
+    /// The `9` key on a US keyboard.
+    ANSI_9 = 0x19, "kVK_ANSI_9";
+
+    /// The `7` key on a US keyboard.
+    ANSI_7 = 0x1A, "kVK_ANSI_7";
+
+    /// The `-` key on a US keyboard.
+    ANSI_MINUS = 0x1B, "kVK_ANSI_Minus";
+
+    /// The `8` key on a US keyboard.
+    ANSI_8 = 0x1C, "kVK_ANSI_8";
+
+    /// The `0` key on a US keyboard.
+    ANSI_0 = 0x1D, "kVK_ANSI_0";
+
+    /// The `]` key on a US keyboard.
+    ANSI_RIGHT_BRACKET = 0x1E, "kVK_ANSI_RightBracket";
+
+    /// The `O` key on a US keyboard.
+    ANSI_O = 0x1F, "kVK_ANSI_O";
+
+    /// The `U` key on a US keyboard.
+    ANSI_U = 0x20, "kVK_ANSI_U";
+
+    /// The `[` key on a US keyboard.
+    ANSI_LEFT_BRACKET = 0x21, "kVK_ANSI_LeftBracket";
+
+    /// The `I` key on a US keyboard.
+    ANSI_I = 0x22, "kVK_ANSI_I";
+
+    /// The `P` key on a US keyboard.
+    ANSI_P = 0x23, "kVK_ANSI_P";
+
+    /// The Return key.
+    RETURN = 0x24, "kVK_Return";
+
+    /// The `L` key on a US keyboard.
+    ANSI_L = 0x25, "kVK_ANSI_L";
+
+    /// The `J` key on a US keyboard.
+    ANSI_J = 0x26, "kVK_ANSI_J";
+
+    /// The `'` key on a US keyboard.
+    ANSI_QUOTE = 0x27, "kVK_ANSI_Quote";
+
+    /// The `K` key on a US keyboard.
+    ANSI_K = 0x28, "kVK_ANSI_K";
+
+    /// The `;` key on a US keyboard.
+    ANSI_SEMICOLON = 0x29, "kVK_ANSI_Semicolon";
+
+    /// The `\` key on a US keyboard.
+    ANSI_BACKSLASH = 0x2A, "kVK_ANSI_Backslash";
+
+    /// The `,` key on a US keyboard.
+    ANSI_COMMA = 0x2B, "kVK_ANSI_Comma";
+
+    /// The `/` key on a US keyboard.
+    ANSI_SLASH = 0x2C, "kVK_ANSI_Slash";
+
+    /// The `N` key on a US keyboard.
+    ANSI_N = 0x2D, "kVK_ANSI_N";
+
+    /// The `M` key on a US keyboard.
+    ANSI_M = 0x2E, "kVK_ANSI_M";
+
+    /// The `.` key on a US keyboard.
+    ANSI_PERIOD = 0x2F, "kVK_ANSI_Period";
+
+    /// The Tab key.
+    TAB = 0x30, "kVK_Tab";
+
+    /// The space bar.
+    SPACE = 0x31, "kVK_Space";
+
+    /// The `` ` `` key on a US keyboard.
+    ANSI_GRAVE = 0x32, "kVK_ANSI_Grave";
+
+    /// The Delete (backspace) key.
+    DELETE = 0x33, "kVK_Delete";
+
+    /// The Escape key.
+    ESCAPE = 0x35, "kVK_Escape";
+
+    /// The right Command key.
+    RIGHT_COMMAND = 0x36, "kVK_RightCommand";
+
+    /// The left Command key.
+    COMMAND = 0x37, "kVK_Command";
+
+    /// The left Shift key.
+    SHIFT = 0x38, "kVK_Shift";
+
+    /// The Caps Lock key.
+    CAPS_LOCK = 0x39, "kVK_CapsLock";
+
+    /// The left Option key.
+    OPTION = 0x3A, "kVK_Option";
+
+    /// The left Control key.
+    CONTROL = 0x3B, "kVK_Control";
+
+    /// The right Shift key.
+    RIGHT_SHIFT = 0x3C, "kVK_RightShift";
+
+    /// The right Option key.
+    RIGHT_OPTION = 0x3D, "kVK_RightOption";
+
+    /// The right Control key.
+    RIGHT_CONTROL = 0x3E, "kVK_RightControl";
+
+    /// The Fn key.
+    FUNCTION = 0x3F, "kVK_Function";
+
+    /// The F17 function key.
+    F17 = 0x40, "kVK_F17";
+
+    /// The keypad `.` key.
+    ANSI_KEYPAD_DECIMAL = 0x41, "kVK_ANSI_KeypadDecimal";
+
+    /// The keypad `*` key.
+    ANSI_KEYPAD_MULTIPLY = 0x43, "kVK_ANSI_KeypadMultiply";
+
+    /// The keypad `+` key.
+    ANSI_KEYPAD_PLUS = 0x45, "kVK_ANSI_KeypadPlus";
+
+    /// The keypad `Clear` key.
+    ANSI_KEYPAD_CLEAR = 0x47, "kVK_ANSI_KeypadClear";
+
+    /// The volume up key.
+    VOLUME_UP = 0x48, "kVK_VolumeUp";
+
+    /// The volume down key.
+    VOLUME_DOWN = 0x49, "kVK_VolumeDown";
+
+    /// The mute key.
+    MUTE = 0x4A, "kVK_Mute";
+
+    /// The keypad `/` key.
+    ANSI_KEYPAD_DIVIDE = 0x4B, "kVK_ANSI_KeypadDivide";
+
+    /// The keypad `Enter` key.
+    ANSI_KEYPAD_ENTER = 0x4C, "kVK_ANSI_KeypadEnter";
+
+    /// The keypad `-` key.
+    ANSI_KEYPAD_MINUS = 0x4E, "kVK_ANSI_KeypadMinus";
+
+    /// The F18 function key.
+    F18 = 0x4F, "kVK_F18";
+
+    /// The F19 function key.
+    F19 = 0x50, "kVK_F19";
+
+    /// The keypad `=` key.
+    ANSI_KEYPAD_EQUALS = 0x51, "kVK_ANSI_KeypadEquals";
+
+    /// The keypad `0` key.
+    ANSI_KEYPAD_0 = 0x52, "kVK_ANSI_Keypad0";
+
+    /// The keypad `1` key.
+    ANSI_KEYPAD_1 = 0x53, "kVK_ANSI_Keypad1";
+
+    /// The keypad `2` key.
+    ANSI_KEYPAD_2 = 0x54, "kVK_ANSI_Keypad2";
+
+    /// The keypad `3` key.
+    ANSI_KEYPAD_3 = 0x55, "kVK_ANSI_Keypad3";
+
+    /// The keypad `4` key.
+    ANSI_KEYPAD_4 = 0x56, "kVK_ANSI_Keypad4";
+
+    /// The keypad `5` key.
+    ANSI_KEYPAD_5 = 0x57, "kVK_ANSI_Keypad5";
+
+    /// The keypad `6` key.
+    ANSI_KEYPAD_6 = 0x58, "kVK_ANSI_Keypad6";
+
+    /// The keypad `7` key.
+    ANSI_KEYPAD_7 = 0x59, "kVK_ANSI_Keypad7";
+
+    /// The F20 function key.
+    F20 = 0x5A, "kVK_F20";
+
+    /// The keypad `8` key.
+    ANSI_KEYPAD_8 = 0x5B, "kVK_ANSI_Keypad8";
+
+    /// The keypad `9` key.
+    ANSI_KEYPAD_9 = 0x5C, "kVK_ANSI_Keypad9";
+
+    /// The F5 function key.
+    F5 = 0x60, "kVK_F5";
+
+    /// The F6 function key.
+    F6 = 0x61, "kVK_F6";
+
+    /// The F7 function key.
+    F7 = 0x62, "kVK_F7";
+
+    /// The F3 function key.
+    F3 = 0x63, "kVK_F3";
+
+    /// The F8 function key.
+    F8 = 0x64, "kVK_F8";
+
+    /// The F9 function key.
+    F9 = 0x65, "kVK_F9";
+
+    /// The F11 function key.
+    F11 = 0x67, "kVK_F11";
+
+    /// The F13 function key.
+    F13 = 0x69, "kVK_F13";
+
+    /// The F16 function key.
+    F16 = 0x6A, "kVK_F16";
+
+    /// The F14 function key.
+    F14 = 0x6B, "kVK_F14";
+
+    /// The F10 function key.
+    F10 = 0x6D, "kVK_F10";
+
+    /// The F12 function key.
+    F12 = 0x6F, "kVK_F12";
+
+    /// The F15 function key.
+    F15 = 0x71, "kVK_F15";
+
+    /// The Help key, or Insert on PC keyboards.
+    HELP = 0x72, "kVK_Help";
+
+    /// The Home key.
+    HOME = 0x73, "kVK_Home";
+
+    /// The Page Up key.
+    PAGE_UP = 0x74, "kVK_PageUp";
+
+    /// The forward delete key.
+    FORWARD_DELETE = 0x75, "kVK_ForwardDelete";
+
+    /// The F4 function key.
+    F4 = 0x76, "kVK_F4";
+
+    /// The End key.
+    END = 0x77, "kVK_End";
+
+    /// The F2 function key.
+    F2 = 0x78, "kVK_F2";
+
+    /// The Page Down key.
+    PAGE_DOWN = 0x79, "kVK_PageDown";
+
+    /// The F1 function key.
+    F1 = 0x7A, "kVK_F1";
+
+    /// The left arrow key.
+    LEFT_ARROW = 0x7B, "kVK_LeftArrow";
+
+    /// The right arrow key.
+    RIGHT_ARROW = 0x7C, "kVK_RightArrow";
+
+    /// The down arrow key.
+    DOWN_ARROW = 0x7D, "kVK_DownArrow";
+
+    /// The up arrow key.
+    UP_ARROW = 0x7E, "kVK_UpArrow";
+}
+
+impl CGKeyCode {
+    /// Characters typed by keys on a US keyboard without modifiers.
+    const US_CHARS: &'static [(Self, char)] = &[
+        (Self::ANSI_A, 'a'),
+        (Self::ANSI_B, 'b'),
+        (Self::ANSI_C, 'c'),
+        (Self::ANSI_D, 'd'),
+        (Self::ANSI_E, 'e'),
+        (Self::ANSI_F, 'f'),
+        (Self::ANSI_G, 'g'),
+        (Self::ANSI_H, 'h'),
+        (Self::ANSI_I, 'i'),
+        (Self::ANSI_J, 'j'),
+        (Self::ANSI_K, 'k'),
+        (Self::ANSI_L, 'l'),
+        (Self::ANSI_M, 'm'),
+        (Self::ANSI_N, 'n'),
+        (Self::ANSI_O, 'o'),
+        (Self::ANSI_P, 'p'),
+        (Self::ANSI_Q, 'q'),
+        (Self::ANSI_R, 'r'),
+        (Self::ANSI_S, 's'),
+        (Self::ANSI_T, 't'),
+        (Self::ANSI_U, 'u'),
+        (Self::ANSI_V, 'v'),
+        (Self::ANSI_W, 'w'),
+        (Self::ANSI_X, 'x'),
+        (Self::ANSI_Y, 'y'),
+        (Self::ANSI_Z, 'z'),
+        (Self::ANSI_0, '0'),
+        (Self::ANSI_1, '1'),
+        (Self::ANSI_2, '2'),
+        (Self::ANSI_3, '3'),
+        (Self::ANSI_4, '4'),
+        (Self::ANSI_5, '5'),
+        (Self::ANSI_6, '6'),
+        (Self::ANSI_7, '7'),
+        (Self::ANSI_8, '8'),
+        (Self::ANSI_9, '9'),
+        (Self::ANSI_EQUAL, '='),
+        (Self::ANSI_MINUS, '-'),
+        (Self::ANSI_LEFT_BRACKET, '['),
+        (Self::ANSI_RIGHT_BRACKET, ']'),
+        (Self::ANSI_QUOTE, '\''),
+        (Self::ANSI_SEMICOLON, ';'),
+        (Self::ANSI_BACKSLASH, '\\'),
+        (Self::ANSI_COMMA, ','),
+        (Self::ANSI_SLASH, '/'),
+        (Self::ANSI_PERIOD, '.'),
+        (Self::ANSI_GRAVE, '`'),
+        (Self::SPACE, ' '),
+        (Self::TAB, '\t'),
+        (Self::RETURN, '\r'),
+    ];
+
+    /// Returns the character typed by this key on a US keyboard without
+    /// modifiers, or [`None`] if it does not type one.
+    ///
+    /// This does not depend on the user's keyboard layout.
+    #[inline]
+    pub fn to_char(self) -> Option<char> {
+        Self::US_CHARS
+            .iter()
+            .find(|&&(key, _)| key == self)
+            .map(|&(_, ch)| ch)
+    }
+
+    /// Returns the key that types `ch` on a US keyboard, ignoring case, or
+    /// [`None`] if there is none.
+    ///
+    /// This does not depend on the user's keyboard layout.
+    #[inline]
+    pub fn from_char(ch: char) -> Option<Self> {
+        let ch = ch.to_ascii_lowercase();
+        Self::US_CHARS
+            .iter()
+            .find(|&&(_, key_ch)| key_ch == ch)
+            .map(|&(key, _)| key)
+    }
+
+    /// Returns `true` if this is a modifier key, such as Shift or Command.
+    #[inline]
+    pub fn is_modifier(self) -> bool {
+        (Self::RIGHT_COMMAND.0..=Self::FUNCTION.0).contains(&self.0)
+    }
+
+    /// Returns `true` if this is a key on the numeric keypad.
+    #[inline]
+    pub fn is_keypad(self) -> bool {
+        matches!(
+            self,
+            Self::ANSI_KEYPAD_DECIMAL
+                | Self::ANSI_KEYPAD_MULTIPLY
+                | Self::ANSI_KEYPAD_PLUS
+                | Self::ANSI_KEYPAD_CLEAR
+                | Self::ANSI_KEYPAD_DIVIDE
+                | Self::ANSI_KEYPAD_ENTER
+                | Self::ANSI_KEYPAD_MINUS
+                | Self::ANSI_KEYPAD_EQUALS
+                | Self::ANSI_KEYPAD_0
+                | Self::ANSI_KEYPAD_1
+                | Self::ANSI_KEYPAD_2
+                | Self::ANSI_KEYPAD_3
+                | Self::ANSI_KEYPAD_4
+                | Self::ANSI_KEYPAD_5
+                | Self::ANSI_KEYPAD_6
+                | Self::ANSI_KEYPAD_7
+                | Self::ANSI_KEYPAD_8
+                | Self::ANSI_KEYPAD_9
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn us_chars() {
+        for &(key, ch) in CGKeyCode::US_CHARS {
+            assert!(key.name().is_some(), "{:?}", key);
+            assert_eq!(key.to_char(), Some(ch));
+            assert_eq!(CGKeyCode::from_char(ch), Some(key));
+        }
+        assert_eq!(CGKeyCode::from_char('Q'), Some(CGKeyCode::ANSI_Q));
+        assert_eq!(CGKeyCode::ESCAPE.to_char(), None);
+        assert_eq!(format!("{:?}", CGKeyCode(0x34)), "CGKeyCode(52)");
+    }
+}
@@ -498,4 +498,13 @@ mod tests {
         };
         assert_eq!(CGRect::INFINITE, expected);
     }
+
+    #[test]
+    fn dimensions() {
+        let rect = CGRect::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(rect.width(), 3.0);
+        assert_eq!(rect.height(), 4.0);
+        assert_eq!(rect.size, CGSize::new(3.0, 4.0));
+        assert_eq!(rect.size, CGSize::from((3.0, 4.0)));
+    }
 }
@@ -6,16 +6,16 @@ use super::{CGAffineTransform, CGFloat};
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Default, PartialOrd, PartialEq)]
 pub struct CGSize {
-    /// A height value.
-    pub height: CGFloat,
     /// A width value.
     pub width: CGFloat,
+    /// A height value.
+    pub height: CGFloat,
 }
 
 impl From<(CGFloat, CGFloat)> for CGSize {
     #[inline]
-    fn from((height, width): (CGFloat, CGFloat)) -> Self {
-        CGSize::new(height, width)
+    fn from((width, height): (CGFloat, CGFloat)) -> Self {
+        CGSize::new(width, height)
     }
 }
 
@@ -28,15 +28,16 @@ impl CGSize {
     /// This is equivalent to
     /// [`CGSizeMake`](https://developer.apple.com/documentation/coregraphics/1455082-cgsizemake).
     #[inline]
-    pub const fn new(height: CGFloat, width: CGFloat) -> Self {
-        Self { height, width }
+    #[doc(alias = "CGSizeMake")]
+    pub const fn new(width: CGFloat, height: CGFloat) -> Self {
+        Self { width, height }
     }
 
     /// Returns a size with the given components losslessly converted to
     /// [`CGFloat`]s.
     #[inline]
-    pub const fn from_i16s(height: i16, width: i16) -> Self {
-        Self::new(height as _, width as _)
+    pub const fn from_i16s(width: i16, height: i16) -> Self {
+        Self::new(width as _, height as _)
     }
 
     /// Returns the result of applying an affine transformation to `self`.
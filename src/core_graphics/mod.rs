@@ -4,12 +4,44 @@
 //!
 //! This module corresponds to the **`core_graphics`**
 //! [feature flag](../index.html#feature-flags).
+//!
+//! Images, colors, color spaces, displays, and [`Pixels`] also require the
+//! [`core_foundation`](crate::core_foundation) feature.
 
 #![cfg(feature = "core_graphics")]
 
 #[link(name = "CoreGraphics", kind = "framework")]
 extern "C" {}
 
+mod cg_event_flags;
+mod cg_key_code;
 mod geometry;
 
+pub use cg_event_flags::*;
+pub use cg_key_code::*;
 pub use geometry::*;
+
+#[cfg(feature = "core_foundation")]
+mod cg_color;
+#[cfg(feature = "core_foundation")]
+mod cg_color_space;
+#[cfg(feature = "core_foundation")]
+mod cg_display;
+#[cfg(feature = "core_foundation")]
+mod cg_image;
+#[cfg(feature = "core_foundation")]
+mod pixels;
+
+#[cfg(feature = "core_foundation")]
+pub use cg_color::*;
+#[cfg(feature = "core_foundation")]
+pub use cg_color_space::*;
+#[cfg(feature = "core_foundation")]
+pub use cg_display::*;
+#[cfg(feature = "core_foundation")]
+pub use cg_image::*;
+#[cfg(feature = "core_foundation")]
+pub use pixels::*;
+
+#[cfg(feature = "core_foundation")]
+pub mod sys;
@@ -0,0 +1,301 @@
+use super::{sys, CGBitmapInfo, CGColorSpace, CGImage, CGImageAlphaInfo, CGPoint, CGRect, CGSize};
+use crate::core::Arc;
+use std::{error::Error, fmt};
+
+/// The layout of a caller-owned buffer of 8-bit RGBA or BGRA pixels that
+/// images are rendered into.
+///
+/// Rendering converts any [`PixelSource`] to the buffer's
+/// [color space](Self::color_space), [channel order](PixelFormat), and
+/// [alpha mode](AlphaMode), scaling it to fill `width` by `height` pixels.
+/// The first row of the buffer is the top of the image.
+///
+/// # Examples
+///
+/// ```no_run
+/// use fruity::core_graphics::{AlphaMode, CGImage, PixelFormat, Pixels};
+///
+/// fn to_rgba(image: &CGImage) -> Vec<u8> {
+///     let mut pixels = Pixels::for_source(image);
+///     pixels.format = PixelFormat::Rgba8;
+///     pixels.alpha = AlphaMode::Straight;
+///
+///     pixels.render_to_vec(image).expect("could not render image")
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Pixels {
+    /// The number of pixels in each row.
+    pub width: usize,
+
+    /// The number of rows.
+    pub height: usize,
+
+    /// The distance in bytes between the start of each row.
+    ///
+    /// This must be at least `width * 4`.
+    pub bytes_per_row: usize,
+
+    /// The order of each pixel's channels.
+    pub format: PixelFormat,
+
+    /// Whether color channels are premultiplied by alpha.
+    pub alpha: AlphaMode,
+
+    /// The color space that colors are converted to, or [`None`] for sRGB.
+    ///
+    /// This must be an RGB color space.
+    pub color_space: Option<Arc<CGColorSpace>>,
+}
+
+impl Pixels {
+    /// Returns a tightly packed layout of premultiplied sRGB RGBA8 pixels.
+    #[inline]
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            bytes_per_row: width.saturating_mul(4),
+            format: PixelFormat::Rgba8,
+            alpha: AlphaMode::Premultiplied,
+            color_space: None,
+        }
+    }
+
+    /// Returns [`Pixels::new`] with the pixel size of `source`.
+    #[inline]
+    pub fn for_source<S: PixelSource + ?Sized>(source: &S) -> Self {
+        let (width, height) = source.pixel_size();
+        Self::new(width, height)
+    }
+
+    /// Returns the number of bytes needed to store the pixels.
+    #[inline]
+    pub fn buffer_len(&self) -> usize {
+        self.bytes_per_row.saturating_mul(self.height)
+    }
+
+    /// Renders `source` into `buffer`, replacing its contents.
+    #[inline]
+    pub fn render<S: PixelSource + ?Sized>(
+        &self,
+        source: &S,
+        buffer: &mut [u8],
+    ) -> Result<(), PixelsError> {
+        source.render_pixels(self, buffer)
+    }
+
+    /// Renders `source` into a new buffer of [`buffer_len`](Self::buffer_len)
+    /// bytes.
+    pub fn render_to_vec<S: PixelSource + ?Sized>(
+        &self,
+        source: &S,
+    ) -> Result<Vec<u8>, PixelsError> {
+        self.check_layout()?;
+
+        let mut buffer = vec![0; self.buffer_len()];
+        self.render(source, &mut buffer)?;
+        Ok(buffer)
+    }
+
+    fn check_layout(&self) -> Result<(), PixelsError> {
+        let min_bytes_per_row = self.width.checked_mul(4);
+        let len = self.bytes_per_row.checked_mul(self.height);
+
+        match (min_bytes_per_row, len) {
+            (Some(min_bytes_per_row), Some(_))
+                if self.width != 0
+                    && self.height != 0
+                    && self.bytes_per_row >= min_bytes_per_row =>
+            {
+                Ok(())
+            }
+            _ => Err(PixelsError::InvalidLayout),
+        }
+    }
+
+    fn check_buffer(&self, buffer: &[u8]) -> Result<(), PixelsError> {
+        self.check_layout()?;
+
+        let required = self.buffer_len();
+        if buffer.len() < required {
+            return Err(PixelsError::BufferTooSmall {
+                required,
+                actual: buffer.len(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Divides color channels by alpha, which Core Graphics cannot do when
+    /// drawing.
+    fn unpremultiply(&self, buffer: &mut [u8]) {
+        let row_len = self.width * 4;
+
+        for row in buffer.chunks_mut(self.bytes_per_row).take(self.height) {
+            // Both formats store alpha last.
+            for pixel in row[..row_len].chunks_exact_mut(4) {
+                let alpha = pixel[3] as u32;
+                if alpha == 0 || alpha == 255 {
+                    continue;
+                }
+                for channel in &mut pixel[..3] {
+                    let value = (*channel as u32 * 255 + alpha / 2) / alpha;
+                    *channel = value.min(255) as u8;
+                }
+            }
+        }
+    }
+}
+
+/// The order of the four 8-bit channels of each pixel in [`Pixels`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PixelFormat {
+    /// Red, green, blue, then alpha. This is what most image processing
+    /// crates expect.
+    Rgba8,
+
+    /// Blue, green, red, then alpha. This is the native format of Apple
+    /// GPUs and displays.
+    Bgra8,
+}
+
+impl PixelFormat {
+    #[inline]
+    fn premultiplied_bitmap_info(self) -> CGBitmapInfo {
+        match self {
+            Self::Rgba8 => {
+                CGBitmapInfo::from(CGImageAlphaInfo::PREMULTIPLIED_LAST)
+                    | CGBitmapInfo::BYTE_ORDER_32_BIG
+            }
+            Self::Bgra8 => {
+                CGBitmapInfo::from(CGImageAlphaInfo::PREMULTIPLIED_FIRST)
+                    | CGBitmapInfo::BYTE_ORDER_32_LITTLE
+            }
+        }
+    }
+}
+
+/// How the color channels of [`Pixels`] relate to alpha.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AlphaMode {
+    /// Color channels are multiplied by alpha, so a half-transparent white
+    /// pixel is `[128, 128, 128, 128]`.
+    ///
+    /// This is the native format of Core Graphics and is faster to produce.
+    Premultiplied,
+
+    /// Color channels are independent of alpha, so a half-transparent white
+    /// pixel is `[255, 255, 255, 128]`.
+    ///
+    /// This is what PNG files and most image processing crates use. Colors
+    /// of nearly transparent pixels lose precision.
+    Straight,
+}
+
+/// An image that can be rendered into [`Pixels`].
+pub trait PixelSource {
+    /// Returns the width and height of the image in pixels.
+    fn pixel_size(&self) -> (usize, usize);
+
+    /// Renders `self` into `buffer` as described by `pixels`.
+    ///
+    /// This is called by [`Pixels::render`].
+    fn render_pixels(&self, pixels: &Pixels, buffer: &mut [u8]) -> Result<(), PixelsError>;
+}
+
+impl PixelSource for CGImage {
+    #[inline]
+    fn pixel_size(&self) -> (usize, usize) {
+        (self.width(), self.height())
+    }
+
+    fn render_pixels(&self, pixels: &Pixels, buffer: &mut [u8]) -> Result<(), PixelsError> {
+        pixels.check_buffer(buffer)?;
+
+        let srgb;
+        let color_space = match &pixels.color_space {
+            Some(color_space) => &**color_space,
+            None => {
+                srgb = CGColorSpace::srgb();
+                &*srgb
+            }
+        };
+
+        unsafe {
+            let context = sys::CGBitmapContextCreate(
+                buffer.as_mut_ptr().cast(),
+                pixels.width,
+                pixels.height,
+                8,
+                pixels.bytes_per_row,
+                color_space,
+                pixels.format.premultiplied_bitmap_info(),
+            );
+            if context.is_null() {
+                return Err(PixelsError::UnsupportedColorSpace);
+            }
+
+            // Replace existing contents rather than blending over them.
+            sys::CGContextSetBlendMode(context, sys::kCGBlendModeCopy);
+            sys::CGContextSetInterpolationQuality(context, sys::kCGInterpolationHigh);
+
+            let rect = CGRect {
+                origin: CGPoint::ZERO,
+                size: CGSize {
+                    width: pixels.width as _,
+                    height: pixels.height as _,
+                },
+            };
+            sys::CGContextDrawImage(context, rect, self);
+            sys::CGContextRelease(context);
+        }
+
+        if pixels.alpha == AlphaMode::Straight {
+            pixels.unpremultiply(buffer);
+        }
+
+        Ok(())
+    }
+}
+
+/// An error returned when rendering [`Pixels`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PixelsError {
+    /// The width or height is zero, or `bytes_per_row` is less than
+    /// `width * 4`.
+    InvalidLayout,
+
+    /// The buffer is smaller than [`Pixels::buffer_len`].
+    BufferTooSmall {
+        /// The number of bytes needed.
+        required: usize,
+        /// The length of the buffer.
+        actual: usize,
+    },
+
+    /// The color space is not an RGB color space.
+    UnsupportedColorSpace,
+
+    /// The source could not be read, such as a pixel buffer in a format
+    /// other than 32-bit RGB.
+    UnsupportedSource,
+}
+
+impl fmt::Display for PixelsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidLayout => f.write_str("invalid pixel buffer layout"),
+            Self::BufferTooSmall { required, actual } => write!(
+                f,
+                "pixel buffer is {} bytes but {} are required",
+                actual, required
+            ),
+            Self::UnsupportedColorSpace => f.write_str("color space is not RGB"),
+            Self::UnsupportedSource => f.write_str("image source cannot be rendered"),
+        }
+    }
+}
+
+impl Error for PixelsError {}
@@ -0,0 +1,145 @@
+//! Raw unsafe C functions and constants exposed by `CoreGraphics.framework`.
+
+use super::{
+    CGBitmapInfo, CGColor, CGColorRenderingIntent, CGColorSpace, CGColorSpaceModel,
+    CGDirectDisplayID, CGFloat, CGImage, CGImageAlphaInfo, CGRect,
+};
+use crate::core_foundation::{CFData, CFString, CFTypeID};
+use std::ffi::c_void;
+
+/// An opaque `CGContextRef`.
+#[repr(C)]
+pub struct CGContextRef {
+    _private: [u8; 0],
+}
+
+/// An opaque `CGDataProviderRef`.
+#[repr(C)]
+pub struct CGDataProviderRef {
+    _private: [u8; 0],
+}
+
+#[allow(missing_docs)]
+pub type CGDataProviderReleaseDataCallback =
+    unsafe extern "C" fn(info: *mut c_void, data: *const c_void, size: usize);
+
+#[allow(non_camel_case_types, missing_docs)]
+pub type CGBlendMode = i32;
+#[allow(non_upper_case_globals, missing_docs)]
+pub const kCGBlendModeCopy: CGBlendMode = 17;
+
+#[allow(non_upper_case_globals, missing_docs)]
+pub const kCGRenderingIntentDefault: CGColorRenderingIntent = CGColorRenderingIntent::DEFAULT;
+
+#[allow(non_camel_case_types, missing_docs)]
+pub type CGError = i32;
+#[allow(non_upper_case_globals, missing_docs)]
+pub const kCGErrorSuccess: CGError = 0;
+
+#[allow(non_camel_case_types, missing_docs)]
+pub type CGInterpolationQuality = i32;
+#[allow(non_upper_case_globals, missing_docs)]
+pub const kCGInterpolationHigh: CGInterpolationQuality = 3;
+
+#[allow(missing_docs)]
+#[link(name = "CoreGraphics", kind = "framework")]
+extern "C" {
+    pub static kCGColorSpaceSRGB: &'static CFString;
+    pub static kCGColorSpaceLinearSRGB: &'static CFString;
+    pub static kCGColorSpaceDisplayP3: &'static CFString;
+    pub static kCGColorSpaceGenericGrayGamma2_2: &'static CFString;
+    pub static kCGColorSpaceGenericRGB: &'static CFString;
+
+    pub fn CGColorSpaceGetTypeID() -> CFTypeID;
+    pub fn CGColorSpaceCreateWithName(name: *const CFString) -> *const CGColorSpace;
+    pub fn CGColorSpaceCreateDeviceRGB() -> *const CGColorSpace;
+    pub fn CGColorSpaceCreateDeviceGray() -> *const CGColorSpace;
+    pub fn CGColorSpaceCopyName(space: *const CGColorSpace) -> *const CFString;
+    pub fn CGColorSpaceGetNumberOfComponents(space: *const CGColorSpace) -> usize;
+    pub fn CGColorSpaceGetModel(space: *const CGColorSpace) -> CGColorSpaceModel;
+    pub fn CGColorSpaceCopyICCData(space: *const CGColorSpace) -> *const CFData;
+    pub fn CGColorSpaceCreateWithICCData(data: *const CFData) -> *const CGColorSpace;
+
+    pub static kCGColorBlack: &'static CFString;
+    pub static kCGColorWhite: &'static CFString;
+    pub static kCGColorClear: &'static CFString;
+
+    pub fn CGColorGetTypeID() -> CFTypeID;
+    pub fn CGColorCreate(space: *const CGColorSpace, components: *const CGFloat) -> *const CGColor;
+    pub fn CGColorCreateSRGB(
+        red: CGFloat,
+        green: CGFloat,
+        blue: CGFloat,
+        alpha: CGFloat,
+    ) -> *const CGColor;
+    pub fn CGColorCreateGenericGray(gray: CGFloat, alpha: CGFloat) -> *const CGColor;
+    pub fn CGColorCreateCopyWithAlpha(color: *const CGColor, alpha: CGFloat) -> *const CGColor;
+    pub fn CGColorCreateCopyByMatchingToColorSpace(
+        space: *const CGColorSpace,
+        intent: CGColorRenderingIntent,
+        color: *const CGColor,
+        options: *const c_void,
+    ) -> *const CGColor;
+    pub fn CGColorGetConstantColor(name: *const CFString) -> *const CGColor;
+    pub fn CGColorGetNumberOfComponents(color: *const CGColor) -> usize;
+    pub fn CGColorGetComponents(color: *const CGColor) -> *const CGFloat;
+    pub fn CGColorGetAlpha(color: *const CGColor) -> CGFloat;
+    pub fn CGColorGetColorSpace(color: *const CGColor) -> *const CGColorSpace;
+
+    pub fn CGImageGetTypeID() -> CFTypeID;
+    pub fn CGImageCreate(
+        width: usize,
+        height: usize,
+        bits_per_component: usize,
+        bits_per_pixel: usize,
+        bytes_per_row: usize,
+        space: *const CGColorSpace,
+        bitmap_info: CGBitmapInfo,
+        provider: *mut CGDataProviderRef,
+        decode: *const CGFloat,
+        should_interpolate: bool,
+        intent: CGColorRenderingIntent,
+    ) -> *const CGImage;
+    pub fn CGImageGetWidth(image: *const CGImage) -> usize;
+    pub fn CGImageGetHeight(image: *const CGImage) -> usize;
+    pub fn CGImageGetBitsPerComponent(image: *const CGImage) -> usize;
+    pub fn CGImageGetBitsPerPixel(image: *const CGImage) -> usize;
+    pub fn CGImageGetBytesPerRow(image: *const CGImage) -> usize;
+    pub fn CGImageGetColorSpace(image: *const CGImage) -> *const CGColorSpace;
+    pub fn CGImageGetAlphaInfo(image: *const CGImage) -> CGImageAlphaInfo;
+    pub fn CGImageGetBitmapInfo(image: *const CGImage) -> CGBitmapInfo;
+
+    pub fn CGDataProviderCreateWithData(
+        info: *mut c_void,
+        data: *const c_void,
+        size: usize,
+        release_data: Option<CGDataProviderReleaseDataCallback>,
+    ) -> *mut CGDataProviderRef;
+    pub fn CGDataProviderRelease(provider: *mut CGDataProviderRef);
+
+    pub fn CGBitmapContextCreate(
+        data: *mut c_void,
+        width: usize,
+        height: usize,
+        bits_per_component: usize,
+        bytes_per_row: usize,
+        space: *const CGColorSpace,
+        bitmap_info: CGBitmapInfo,
+    ) -> *mut CGContextRef;
+    pub fn CGContextRelease(context: *mut CGContextRef);
+    pub fn CGContextSetBlendMode(context: *mut CGContextRef, mode: CGBlendMode);
+    pub fn CGContextSetInterpolationQuality(
+        context: *mut CGContextRef,
+        quality: CGInterpolationQuality,
+    );
+    pub fn CGContextDrawImage(context: *mut CGContextRef, rect: CGRect, image: *const CGImage);
+
+    pub fn CGMainDisplayID() -> CGDirectDisplayID;
+    pub fn CGGetActiveDisplayList(
+        max_displays: u32,
+        active_displays: *mut CGDirectDisplayID,
+        display_count: *mut u32,
+    ) -> CGError;
+    pub fn CGDisplayCopyColorSpace(display: CGDirectDisplayID) -> *const CGColorSpace;
+    pub fn CGDisplayBounds(display: CGDirectDisplayID) -> CGRect;
+}
@@ -0,0 +1,56 @@
+use super::CIImage;
+use crate::core::Arc;
+use crate::core_graphics::{CGImage, CGRect};
+use crate::objc::{ClassType, NSObject};
+
+#[cfg(feature = "core_video")]
+use crate::core_video::CVPixelBuffer;
+
+objc_subclass! {
+    /// Renders [`CIImage`] recipes into pixels.
+    ///
+    /// Contexts cache resources such as compiled filters, so one should be
+    /// reused for many renders.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreimage/cicontext).
+    pub class CIContext: NSObject<'static>;
+}
+
+impl CIContext {
+    /// Creates a context that renders on the GPU when one is available.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreimage/cicontext/init()).
+    #[inline]
+    #[doc(alias = "context")]
+    pub fn new() -> Arc<Self> {
+        unsafe { Arc::retain_raw(_msg_send_any![Self::class(), context => *const Self]) }
+    }
+
+    /// Renders the `rect` region of `image` into a new bitmap, or returns
+    /// [`None`] if it could not be rendered, such as if `rect` is infinite.
+    ///
+    /// The bitmap can be converted to RGBA8 or BGRA8 pixels with
+    /// [`Pixels`](crate::core_graphics::Pixels).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreimage/cicontext/createcgimage(_:from:)).
+    #[inline]
+    #[doc(alias = "createCGImage:fromRect:")]
+    pub fn create_cg_image(&self, image: &CIImage, rect: CGRect) -> Option<Arc<CGImage>> {
+        unsafe {
+            let image: *const CGImage = _msg_send_any![self, createCGImage: image fromRect: rect];
+            if image.is_null() {
+                None
+            } else {
+                Some(Arc::from_raw(image))
+            }
+        }
+    }
+
+    /// Renders `image` into `buffer`, replacing its contents.
+    #[cfg(feature = "core_video")]
+    #[inline]
+    #[doc(alias = "render:toCVPixelBuffer:")]
+    pub fn render_to_pixel_buffer(&self, image: &CIImage, buffer: &CVPixelBuffer) {
+        unsafe { _msg_send_any![self, render: image toCVPixelBuffer: buffer] }
+    }
+}
@@ -0,0 +1,258 @@
+use super::CIImage;
+use crate::core::Arc;
+use crate::foundation::{NSArray, NSNumber, NSString};
+use crate::objc::{ClassType, NSObject};
+use std::{fmt, ptr};
+
+objc_subclass! {
+    /// An image processing operation, such as a blur or color adjustment,
+    /// configured through key-value parameters.
+    ///
+    /// Filters are chained by setting the [`output_image`](Self::output_image)
+    /// of one filter as the [`input_image`](CIParameterKey::input_image) of
+    /// the next. No work is done until the final image is rendered by a
+    /// [`CIContext`](super::CIContext).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fruity::core_image::{CIContext, CIFilter, CIImage, CIParameterKey};
+    /// # use fruity::core_graphics::CGImage;
+    /// # fn blur(image: &CGImage) {
+    ///
+    /// let filter = CIFilter::with_name(fruity::ns_string!("CIGaussianBlur")).unwrap();
+    /// filter.set_image(CIParameterKey::input_image(), &CIImage::from_cg_image(image));
+    /// filter.set_number(CIParameterKey::input_radius(), 8.0);
+    ///
+    /// let output = filter.output_image().unwrap();
+    /// let blurred = CIContext::new().create_cg_image(&output, output.extent());
+    /// # }
+    /// ```
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreimage/cifilter).
+    pub class CIFilter: NSObject<'static>;
+}
+
+impl CIFilter {
+    /// Returns a new instance of the built-in filter called `name`, such as
+    /// `CIGaussianBlur`, or [`None`] if there is no such filter.
+    ///
+    /// Parameters start with their default values.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreimage/cifilter/init(name:)).
+    #[inline]
+    #[doc(alias = "filterWithName:")]
+    pub fn with_name(name: &NSString) -> Option<Arc<Self>> {
+        unsafe {
+            _msg_send_any![Self::class(), filterWithName: name => *const Self]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Returns the names of all built-in filters.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreimage/cifilter/filternames(incategory:)).
+    #[inline]
+    #[doc(alias = "filterNamesInCategory:")]
+    pub fn names() -> Arc<NSArray<NSString<'static>>> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![
+                Self::class(),
+                filterNamesInCategory: ptr::null::<NSString>()
+                => *const NSArray<NSString<'static>>
+            ])
+        }
+    }
+
+    /// Returns the name of the filter, such as `CIGaussianBlur`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreimage/cifilter/name).
+    #[inline]
+    pub fn name(&self) -> Arc<NSString<'static>> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, name => *const NSString<'static>]) }
+    }
+
+    /// Returns the keys of the filter's parameters.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreimage/cifilter/inputkeys).
+    #[inline]
+    #[doc(alias = "inputKeys")]
+    pub fn input_keys(&self) -> Arc<NSArray<CIParameterKey>> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![self, inputKeys => *const NSArray<CIParameterKey>])
+        }
+    }
+
+    /// Resets all parameters to their default values.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreimage/cifilter/setdefaults()).
+    #[inline]
+    #[doc(alias = "setDefaults")]
+    pub fn set_defaults(&self) {
+        unsafe { _msg_send_any![self, setDefaults] }
+    }
+
+    /// Returns the value of the parameter for `key`, or [`None`] if it is
+    /// not set.
+    #[inline]
+    #[doc(alias = "valueForKey:")]
+    pub fn value(&self, key: &CIParameterKey) -> Option<Arc<NSObject<'static>>> {
+        unsafe {
+            _msg_send_any![self, valueForKey: key => *const NSObject<'static>]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Sets the parameter for `key` to `value`, such as an [`NSNumber`] for
+    /// a radius or a [`CIImage`] for an image.
+    ///
+    /// # Panics
+    ///
+    /// Raises an Objective-C exception if the filter has no parameter for
+    /// `key`.
+    #[inline]
+    #[doc(alias = "setValue:forKey:")]
+    pub fn set_value(&self, key: &CIParameterKey, value: Option<&NSObject>) {
+        unsafe { _msg_send_any![self, setValue: value forKey: key] }
+    }
+
+    /// Sets the image parameter for `key`, such as
+    /// [`input_image`](CIParameterKey::input_image).
+    #[inline]
+    pub fn set_image(&self, key: &CIParameterKey, image: &CIImage) {
+        self.set_value(key, Some(image));
+    }
+
+    /// Sets the numeric parameter for `key`, such as
+    /// [`input_radius`](CIParameterKey::input_radius).
+    #[inline]
+    pub fn set_number(&self, key: &CIParameterKey, value: f64) {
+        self.set_value(key, Some(&NSNumber::from_double(value)));
+    }
+
+    /// Returns the result of applying the filter to its inputs, or [`None`]
+    /// if a required input is missing.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreimage/cifilter/outputimage).
+    #[inline]
+    #[doc(alias = "outputImage")]
+    pub fn output_image(&self) -> Option<Arc<CIImage>> {
+        unsafe {
+            _msg_send_any![self, outputImage => *const CIImage]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+}
+
+// `ns_string_wrapper!` is not in scope because `foundation` is declared after
+// this module.
+objc_object_wrapper! {
+    /// The key of a [`CIFilter`] parameter.
+    ///
+    /// Filters have parameters beyond these common ones, which can be listed
+    /// with [`CIFilter::input_keys`].
+    #[derive(Ord, PartialOrd, Eq, PartialEq)]
+    pub wrapper CIParameterKey: NSString<'static>;
+}
+
+impl fmt::Debug for CIParameterKey {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl fmt::Display for CIParameterKey {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+macro_rules! key {
+    (
+        $(#[$docs:meta])+
+        $fn:ident $value:literal
+    ) => {
+        $(#[$docs])+
+        #[inline]
+        #[doc(alias = $value)]
+        pub fn $fn() -> &'static CIParameterKey {
+            extern "C" {
+                #[link_name = $value]
+                static VALUE: &'static CIParameterKey;
+            }
+            unsafe { VALUE }
+        }
+    };
+}
+
+impl CIParameterKey {
+    key! {
+        /// The image to process.
+        input_image "kCIInputImageKey"
+    }
+
+    key! {
+        /// The image that the input image is composited over.
+        input_background_image "kCIInputBackgroundImageKey"
+    }
+
+    key! {
+        /// The radius of an effect, such as a blur, in pixels.
+        input_radius "kCIInputRadiusKey"
+    }
+
+    key! {
+        /// The strength of an effect, usually from 0 to 1.
+        input_intensity "kCIInputIntensityKey"
+    }
+
+    key! {
+        /// The scale of an effect.
+        input_scale "kCIInputScaleKey"
+    }
+
+    key! {
+        /// An angle in radians.
+        input_angle "kCIInputAngleKey"
+    }
+
+    key! {
+        /// The center of an effect, as a `CIVector`.
+        input_center "kCIInputCenterKey"
+    }
+
+    key! {
+        /// A color, as a `CIColor`.
+        input_color "kCIInputColorKey"
+    }
+
+    key! {
+        /// The saturation adjustment, where 1 leaves colors unchanged.
+        input_saturation "kCIInputSaturationKey"
+    }
+
+    key! {
+        /// The brightness adjustment, where 0 leaves colors unchanged.
+        input_brightness "kCIInputBrightnessKey"
+    }
+
+    key! {
+        /// The contrast adjustment, where 1 leaves colors unchanged.
+        input_contrast "kCIInputContrastKey"
+    }
+
+    key! {
+        /// The exposure adjustment in F-stops.
+        input_ev "kCIInputEVKey"
+    }
+
+    key! {
+        /// The filter's result, as returned by [`CIFilter::output_image`].
+        output_image "kCIOutputImageKey"
+    }
+}
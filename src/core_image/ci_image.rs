@@ -0,0 +1,108 @@
+use super::CIParameterKey;
+use crate::core::Arc;
+use crate::core_graphics::{CGAffineTransform, CGImage, CGRect};
+use crate::foundation::{NSDictionary, NSString};
+use crate::objc::{ClassType, NSObject};
+
+#[cfg(feature = "core_video")]
+use crate::core_video::CVPixelBuffer;
+
+objc_subclass! {
+    /// A recipe for producing an image.
+    ///
+    /// Images are immutable, and processing them only builds up the recipe.
+    /// Pixels are produced when the image is rendered by a
+    /// [`CIContext`](super::CIContext).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreimage/ciimage).
+    pub class CIImage: NSObject<'static>;
+}
+
+impl CIImage {
+    /// Creates an image from the pixels of `image`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreimage/ciimage/init(cgimage:)).
+    #[inline]
+    #[doc(alias = "imageWithCGImage:")]
+    pub fn from_cg_image(image: &CGImage) -> Arc<Self> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![Self::class(), imageWithCGImage: image => *const Self])
+        }
+    }
+
+    /// Creates an image from the pixels of `buffer`, such as a video frame,
+    /// without copying them.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreimage/ciimage/init(cvpixelbuffer:)).
+    #[cfg(feature = "core_video")]
+    #[inline]
+    #[doc(alias = "imageWithCVPixelBuffer:")]
+    pub fn from_pixel_buffer(buffer: &CVPixelBuffer) -> Arc<Self> {
+        unsafe {
+            Arc::retain_raw(
+                _msg_send_any![Self::class(), imageWithCVPixelBuffer: buffer => *const Self],
+            )
+        }
+    }
+
+    /// Returns the bounds of the image in pixels.
+    ///
+    /// This is infinite for generated images, such as solid colors, which
+    /// must be [cropped](Self::cropped) before rendering.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreimage/ciimage/extent).
+    #[inline]
+    pub fn extent(&self) -> CGRect {
+        unsafe { _msg_send_any![self, extent] }
+    }
+
+    /// Returns the image limited to `rect`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreimage/ciimage/cropped(to:)).
+    #[inline]
+    #[doc(alias = "imageByCroppingToRect:")]
+    pub fn cropped(&self, rect: CGRect) -> Arc<Self> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, imageByCroppingToRect: rect => *const Self]) }
+    }
+
+    /// Returns the image with `transform` applied, such as to scale or
+    /// rotate it.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreimage/ciimage/transformed(by:)).
+    #[inline]
+    #[doc(alias = "imageByApplyingTransform:")]
+    pub fn transformed(&self, transform: CGAffineTransform) -> Arc<Self> {
+        unsafe {
+            Arc::retain_raw(
+                _msg_send_any![self, imageByApplyingTransform: transform => *const Self],
+            )
+        }
+    }
+
+    /// Returns the result of the built-in filter called `name` with `self`
+    /// as its [input image](CIParameterKey::input_image), or [`None`] if
+    /// there is no such filter.
+    ///
+    /// This is a shorthand for configuring a [`CIFilter`](super::CIFilter)
+    /// and getting its output.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreimage/ciimage/applyingfilter(_:parameters:)).
+    #[inline]
+    #[doc(alias = "imageByApplyingFilter:withInputParameters:")]
+    pub fn applying_filter(
+        &self,
+        name: &NSString,
+        parameters: &NSDictionary<CIParameterKey, NSObject<'static>>,
+    ) -> Option<Arc<Self>> {
+        unsafe {
+            _msg_send_any![
+                self,
+                imageByApplyingFilter: name
+                withInputParameters: parameters
+                => *const Self
+            ]
+            .as_ref()
+            .map(Arc::retain)
+        }
+    }
+}
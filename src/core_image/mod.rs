@@ -5,8 +5,21 @@
 //!
 //! This module corresponds to the **`core_image`**
 //! [feature flag](../index.html#feature-flags).
+//!
+//! It also transitively enables [`foundation`](crate::foundation) and
+//! [`core_foundation`](crate::core_foundation). Rendering to and from
+//! [`CVPixelBuffer`](crate::core_video::CVPixelBuffer) requires the
+//! [`core_video`](crate::core_video) feature.
 
 #![cfg(feature = "core_image")]
 
 #[link(name = "CoreImage", kind = "framework")]
 extern "C" {}
+
+mod ci_context;
+mod ci_filter;
+mod ci_image;
+
+pub use ci_context::*;
+pub use ci_filter::*;
+pub use ci_image::*;
@@ -0,0 +1,147 @@
+use super::{CLLocation, CLLocationCoordinate2D, CLPlacemark};
+use crate::core::Arc;
+use crate::foundation::{NSArray, NSError, NSString, NSTimeZone};
+use crate::objc::{Block, ClassType, NSObject, RcBlock, BOOL};
+use std::cell::Cell;
+
+/// The result of a geocoding request, with the best match first.
+pub type CLGeocodeResult = Result<Arc<NSArray<CLPlacemark>>, Arc<NSError<'static>>>;
+
+objc_subclass! {
+    /// A service for converting between coordinates and human-readable
+    /// places.
+    ///
+    /// Requests are sent to Apple's servers and are rate limited, so apps
+    /// should send at most one request per user action. Each geocoder
+    /// handles one request at a time.
+    ///
+    /// Completion handlers are called on the main thread, so its run loop
+    /// must be running, such as with
+    /// [`CFRunLoop::run`](crate::core_foundation::CFRunLoop::run).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corelocation/clgeocoder).
+    pub class CLGeocoder: NSObject<'static>;
+}
+
+/// Returns a block that passes its result to `completion` once.
+fn completion_block<F>(
+    completion: F,
+) -> RcBlock<(*const NSArray<CLPlacemark>, *const NSError<'static>), ()>
+where
+    F: FnOnce(CLGeocodeResult) + Send + 'static,
+{
+    let completion = Cell::new(Some(completion));
+    RcBlock::new(
+        move |placemarks: *const NSArray<CLPlacemark>, error: *const NSError<'static>| {
+            if let Some(completion) = completion.take() {
+                completion(unsafe {
+                    match placemarks.as_ref() {
+                        Some(placemarks) => Ok(Arc::retain(placemarks)),
+                        None => Err(Arc::retain(&*error)),
+                    }
+                });
+            }
+        },
+    )
+}
+
+impl CLGeocoder {
+    /// Creates a geocoder.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corelocation/clgeocoder/init()).
+    #[inline]
+    pub fn new() -> Arc<Self> {
+        unsafe { Self::class().alloc_init() }
+    }
+
+    /// Looks up the places at `location`, calling `completion` on the main
+    /// thread with the result.
+    ///
+    /// It is safe to panic within `completion`. Panics will abort the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corelocation/clgeocoder/reversegeocodelocation(_:completionhandler:)).
+    #[inline]
+    #[doc(alias = "reverseGeocodeLocation:completionHandler:")]
+    pub fn reverse_geocode<F>(&self, location: &CLLocation, completion: F)
+    where
+        F: FnOnce(CLGeocodeResult) + Send + 'static,
+    {
+        let block = completion_block(completion);
+        let block: &Block<_, ()> = &block;
+
+        unsafe {
+            _msg_send_any![
+                self,
+                reverseGeocodeLocation: location
+                completionHandler: block
+                => ()
+            ]
+        }
+    }
+
+    /// Looks up the places matching `address`, such as
+    /// `1 Infinite Loop, Cupertino`, calling `completion` on the main thread
+    /// with the result.
+    ///
+    /// It is safe to panic within `completion`. Panics will abort the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corelocation/clgeocoder/geocodeaddressstring(_:completionhandler:)).
+    #[inline]
+    #[doc(alias = "geocodeAddressString:completionHandler:")]
+    pub fn geocode_address<F>(&self, address: &NSString, completion: F)
+    where
+        F: FnOnce(CLGeocodeResult) + Send + 'static,
+    {
+        let block = completion_block(completion);
+        let block: &Block<_, ()> = &block;
+
+        unsafe {
+            _msg_send_any![
+                self,
+                geocodeAddressString: address
+                completionHandler: block
+                => ()
+            ]
+        }
+    }
+
+    /// Looks up the time zone at `coordinate`, calling `completion` on the
+    /// main thread with the result.
+    ///
+    /// The result is [`None`] if no time zone is known for the location, such
+    /// as in international waters.
+    ///
+    /// It is safe to panic within `completion`. Panics will abort the process.
+    pub fn time_zone_at<F>(&self, coordinate: CLLocationCoordinate2D, completion: F)
+    where
+        F: FnOnce(Result<Option<Arc<NSTimeZone>>, Arc<NSError<'static>>>) + Send + 'static,
+    {
+        let location = CLLocation::new(coordinate);
+        self.reverse_geocode(&location, move |result| {
+            completion(result.map(|placemarks| {
+                placemarks
+                    .first()
+                    .and_then(|placemark| placemark.time_zone())
+            }))
+        });
+    }
+
+    /// Cancels the pending request, calling its completion handler with a
+    /// cancellation error.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corelocation/clgeocoder/cancelgeocode()).
+    #[inline]
+    #[doc(alias = "cancelGeocode")]
+    pub fn cancel(&self) {
+        unsafe { _msg_send_any![self, cancelGeocode] }
+    }
+
+    /// Returns `true` if a request is in progress.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corelocation/clgeocoder/isgeocoding).
+    #[inline]
+    #[doc(alias = "isGeocoding")]
+    pub fn is_geocoding(&self) -> bool {
+        unsafe { _msg_send_any![self, isGeocoding => BOOL] }.into()
+    }
+}
@@ -0,0 +1,145 @@
+use crate::core::Arc;
+use crate::foundation::NSDate;
+use crate::objc::{ClassType, NSObject, Sel, BOOL};
+
+/// A distance in meters.
+///
+/// See [documentation](https://developer.apple.com/documentation/corelocation/cllocationdistance).
+pub type CLLocationDistance = f64;
+
+/// A latitude and longitude in degrees, using the WGS 84 reference frame.
+///
+/// See [documentation](https://developer.apple.com/documentation/corelocation/cllocationcoordinate2d).
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct CLLocationCoordinate2D {
+    /// Degrees north (positive) or south (negative) of the equator, from -90
+    /// to 90.
+    pub latitude: f64,
+
+    /// Degrees east (positive) or west (negative) of the prime meridian, from
+    /// -180 to 180.
+    pub longitude: f64,
+}
+
+impl From<(f64, f64)> for CLLocationCoordinate2D {
+    #[inline]
+    fn from((latitude, longitude): (f64, f64)) -> Self {
+        Self::new(latitude, longitude)
+    }
+}
+
+impl CLLocationCoordinate2D {
+    /// Returns a coordinate with `latitude` and `longitude`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corelocation/cllocationcoordinate2dmake(_:_:)).
+    #[inline]
+    #[doc(alias = "CLLocationCoordinate2DMake")]
+    pub const fn new(latitude: f64, longitude: f64) -> Self {
+        Self {
+            latitude,
+            longitude,
+        }
+    }
+
+    /// Returns `true` if the latitude and longitude are within range.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corelocation/cllocationcoordinate2disvalid(_:)).
+    #[inline]
+    #[doc(alias = "CLLocationCoordinate2DIsValid")]
+    pub fn is_valid(self) -> bool {
+        extern "C" {
+            fn CLLocationCoordinate2DIsValid(coord: CLLocationCoordinate2D) -> BOOL;
+        }
+        unsafe { CLLocationCoordinate2DIsValid(self) }.into()
+    }
+}
+
+objc_subclass! {
+    /// A geographic location, along with its accuracy and the time it was
+    /// determined.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corelocation/cllocation).
+    pub class CLLocation: NSObject<'static>;
+}
+
+impl From<CLLocationCoordinate2D> for Arc<CLLocation> {
+    #[inline]
+    fn from(coordinate: CLLocationCoordinate2D) -> Self {
+        CLLocation::new(coordinate)
+    }
+}
+
+impl CLLocation {
+    /// Creates a location at `coordinate`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corelocation/cllocation/init(latitude:longitude:)).
+    #[inline]
+    #[doc(alias = "initWithLatitude:longitude:")]
+    pub fn new(coordinate: CLLocationCoordinate2D) -> Arc<Self> {
+        unsafe {
+            let location: Arc<Self> = Self::class().alloc();
+
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(
+                    obj: Arc<CLLocation>,
+                    sel: Sel,
+                    latitude: f64,
+                    longitude: f64,
+                ) -> Arc<CLLocation>;
+            }
+
+            objc_msgSend(
+                location,
+                selector!(initWithLatitude:longitude:),
+                coordinate.latitude,
+                coordinate.longitude,
+            )
+        }
+    }
+
+    /// Returns the latitude and longitude of the location.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corelocation/cllocation/coordinate).
+    #[inline]
+    pub fn coordinate(&self) -> CLLocationCoordinate2D {
+        unsafe { _msg_send_any![self, coordinate] }
+    }
+
+    /// Returns the altitude of the location in meters above sea level.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corelocation/cllocation/altitude).
+    #[inline]
+    pub fn altitude(&self) -> CLLocationDistance {
+        unsafe { _msg_send_any![self, altitude] }
+    }
+
+    /// Returns the radius of uncertainty of the coordinate in meters, or a
+    /// negative value if the coordinate is invalid.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corelocation/cllocation/horizontalaccuracy).
+    #[inline]
+    #[doc(alias = "horizontalAccuracy")]
+    pub fn horizontal_accuracy(&self) -> CLLocationDistance {
+        unsafe { _msg_send_any![self, horizontalAccuracy] }
+    }
+
+    /// Returns the time at which the location was determined.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corelocation/cllocation/timestamp).
+    #[inline]
+    pub fn timestamp(&self) -> Arc<NSDate> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, timestamp => *const NSDate]) }
+    }
+
+    /// Returns the great-circle distance in meters between `self` and
+    /// `other`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corelocation/cllocation/distance(from:)).
+    #[inline]
+    #[doc(alias = "distanceFromLocation:")]
+    pub fn distance_from(&self, other: &CLLocation) -> CLLocationDistance {
+        unsafe { _msg_send_any![self, distanceFromLocation: other] }
+    }
+}
@@ -0,0 +1,167 @@
+use super::CLLocation;
+use crate::core::Arc;
+use crate::foundation::{NSArray, NSString, NSTimeZone};
+use crate::objc::NSObject;
+
+objc_subclass! {
+    /// A human-readable description of a geographic location, such as its
+    /// street address, city, and country.
+    ///
+    /// Placemarks are returned by [`CLGeocoder`](super::CLGeocoder). Any of
+    /// their properties may be missing, such as the street of a location at
+    /// sea.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corelocation/clplacemark).
+    pub class CLPlacemark: NSObject<'static>;
+}
+
+macro_rules! string_property {
+    (
+        $(#[$docs:meta])+
+        $fn:ident $sel:ident
+    ) => {
+        $(#[$docs])+
+        #[inline]
+        pub fn $fn(&self) -> Option<Arc<NSString<'static>>> {
+            unsafe {
+                _msg_send_any![self, $sel => *const NSString<'static>]
+                    .as_ref()
+                    .map(Arc::retain)
+            }
+        }
+    };
+}
+
+impl CLPlacemark {
+    /// Returns the location of the placemark.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corelocation/clplacemark/location).
+    #[inline]
+    pub fn location(&self) -> Option<Arc<CLLocation>> {
+        unsafe {
+            _msg_send_any![self, location => *const CLLocation]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Returns the time zone of the placemark.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corelocation/clplacemark/timezone).
+    #[inline]
+    #[doc(alias = "timeZone")]
+    pub fn time_zone(&self) -> Option<Arc<NSTimeZone>> {
+        unsafe {
+            _msg_send_any![self, timeZone => *const NSTimeZone]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    string_property! {
+        /// Returns the name of the placemark, such as a business name or
+        /// street address.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/corelocation/clplacemark/name).
+        name name
+    }
+
+    string_property! {
+        /// Returns the street, such as `Infinite Loop`.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/corelocation/clplacemark/thoroughfare).
+        thoroughfare thoroughfare
+    }
+
+    string_property! {
+        /// Returns the street number, such as `1`.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/corelocation/clplacemark/subthoroughfare).
+        #[doc(alias = "subThoroughfare")]
+        sub_thoroughfare subThoroughfare
+    }
+
+    string_property! {
+        /// Returns the city, such as `Cupertino`.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/corelocation/clplacemark/locality).
+        locality locality
+    }
+
+    string_property! {
+        /// Returns the neighborhood or other area within the city.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/corelocation/clplacemark/sublocality).
+        #[doc(alias = "subLocality")]
+        sub_locality subLocality
+    }
+
+    string_property! {
+        /// Returns the state or province, such as `CA`.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/corelocation/clplacemark/administrativearea).
+        #[doc(alias = "administrativeArea")]
+        administrative_area administrativeArea
+    }
+
+    string_property! {
+        /// Returns the county or other area within the state, such as
+        /// `Santa Clara`.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/corelocation/clplacemark/subadministrativearea).
+        #[doc(alias = "subAdministrativeArea")]
+        sub_administrative_area subAdministrativeArea
+    }
+
+    string_property! {
+        /// Returns the postal code, such as `95014`.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/corelocation/clplacemark/postalcode).
+        #[doc(alias = "postalCode")]
+        postal_code postalCode
+    }
+
+    string_property! {
+        /// Returns the ISO 3166-1 alpha-2 country code, such as `US`.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/corelocation/clplacemark/isocountrycode).
+        #[doc(alias = "ISOcountryCode")]
+        iso_country_code ISOcountryCode
+    }
+
+    string_property! {
+        /// Returns the name of the country, such as `United States`.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/corelocation/clplacemark/country).
+        country country
+    }
+
+    string_property! {
+        /// Returns the name of the lake, river, or other inland body of water
+        /// at the placemark.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/corelocation/clplacemark/inlandwater).
+        #[doc(alias = "inlandWater")]
+        inland_water inlandWater
+    }
+
+    string_property! {
+        /// Returns the name of the ocean at the placemark.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/corelocation/clplacemark/ocean).
+        ocean ocean
+    }
+
+    /// Returns landmarks at the placemark, such as a park or airport.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corelocation/clplacemark/areasofinterest).
+    #[inline]
+    #[doc(alias = "areasOfInterest")]
+    pub fn areas_of_interest(&self) -> Option<Arc<NSArray<NSString<'static>>>> {
+        unsafe {
+            _msg_send_any![self, areasOfInterest => *const NSArray<NSString<'static>>]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+}
@@ -0,0 +1,28 @@
+//! [Core Location](https://developer.apple.com/documentation/corelocation)
+//! framework.
+//!
+//! # Feature Flag
+//!
+//! This module corresponds to the **`core_location`**
+//! [feature flag](../index.html#feature-flags).
+//!
+//! It also transitively enables [`foundation`](crate::foundation).
+//!
+//! # Scope
+//!
+//! These bindings cover geocoding with [`CLGeocoder`], which does not need
+//! location permissions. Tracking the device's location with
+//! `CLLocationManager` is not supported.
+
+#![cfg(feature = "core_location")]
+
+mod cl_geocoder;
+mod cl_location;
+mod cl_placemark;
+
+pub use cl_geocoder::*;
+pub use cl_location::*;
+pub use cl_placemark::*;
+
+#[link(name = "CoreLocation", kind = "framework")]
+extern "C" {}
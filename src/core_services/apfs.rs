@@ -0,0 +1,302 @@
+//! Copy-on-write clones, snapshots, and atomic swaps on
+//! [APFS](https://developer.apple.com/documentation/foundation/file_system/about_apple_file_system)
+//! volumes.
+//!
+//! A clone shares the storage of the original file until either is modified,
+//! so cloning is fast and initially uses no additional space. Use
+//! [`NSURL::volume_supports_file_cloning`](crate::foundation::NSURL::volume_supports_file_cloning)
+//! to check whether a volume supports clones.
+//!
+//! Errors are reported as [`io::Error`] using the `errno` set by the
+//! underlying system call. Cloning across volumes fails with `EXDEV`.
+
+use std::{
+    ffi::{CString, OsStr, OsString},
+    fs::File,
+    io, mem,
+    os::{
+        raw::{c_char, c_int, c_long, c_void},
+        unix::{
+            ffi::{OsStrExt, OsStringExt},
+            io::{AsRawFd, RawFd},
+        },
+    },
+    path::Path,
+    ptr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// Options for [`clone_file`] and [`clone_fd`].
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CloneFlags(pub u32);
+
+impl CloneFlags {
+    /// No options.
+    pub const NONE: Self = Self(0);
+
+    /// Clones a symbolic link itself, rather than the file it points to.
+    #[doc(alias = "CLONE_NOFOLLOW")]
+    pub const NO_FOLLOW: Self = Self(0x0001);
+
+    /// Makes the current user the owner of the clone, rather than copying the
+    /// owner of the original.
+    #[doc(alias = "CLONE_NOOWNERCOPY")]
+    pub const NO_OWNER_COPY: Self = Self(0x0002);
+
+    /// Copies the access control list of the original, even when the clone
+    /// would otherwise inherit one from its directory.
+    ///
+    /// Available on macOS 13 and later.
+    #[doc(alias = "CLONE_ACL")]
+    pub const ACL: Self = Self(0x0004);
+}
+
+impl std::ops::BitOr for CloneFlags {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+/// Attributes requested by [`snapshots`], laid out as `struct attrlist`.
+#[repr(C)]
+struct AttrList {
+    bitmapcount: u16,
+    reserved: u16,
+    commonattr: u32,
+    volattr: u32,
+    dirattr: u32,
+    fileattr: u32,
+    forkattr: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+struct AttrReference {
+    attr_dataoffset: i32,
+    attr_length: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+struct Timespec {
+    tv_sec: c_long,
+    tv_nsec: c_long,
+}
+
+const ATTR_BIT_MAP_COUNT: u16 = 5;
+const ATTR_CMN_NAME: u32 = 0x0000_0001;
+const ATTR_CMN_CRTIME: u32 = 0x0000_0200;
+const ATTR_CMN_RETURNED_ATTRS: u32 = 0x8000_0000;
+
+/// `AT_FDCWD` from `<sys/fcntl.h>`.
+const AT_FDCWD: c_int = -2;
+
+/// `RENAME_SWAP` from `<stdio.h>`.
+const RENAME_SWAP: u32 = 0x0000_0002;
+
+extern "C" {
+    fn clonefile(src: *const c_char, dst: *const c_char, flags: u32) -> c_int;
+    fn fclonefileat(srcfd: c_int, dst_dirfd: c_int, dst: *const c_char, flags: u32) -> c_int;
+
+    fn renamex_np(from: *const c_char, to: *const c_char, flags: u32) -> c_int;
+
+    fn fs_snapshot_create(dirfd: c_int, name: *const c_char, flags: u32) -> c_int;
+    fn fs_snapshot_delete(dirfd: c_int, name: *const c_char, flags: u32) -> c_int;
+    fn fs_snapshot_list(
+        dirfd: c_int,
+        attrlist: *mut AttrList,
+        attrbuf: *mut c_void,
+        bufsize: usize,
+        flags: u32,
+    ) -> c_int;
+}
+
+fn c_string(s: &OsStr) -> io::Result<CString> {
+    CString::new(s.as_bytes()).map_err(|error| io::Error::new(io::ErrorKind::InvalidInput, error))
+}
+
+#[inline]
+fn check(result: c_int) -> io::Result<()> {
+    if result == 0 {
+        Ok(())
+    } else {
+        Err(io::Error::last_os_error())
+    }
+}
+
+/// Creates `dst` as a clone of the file or directory at `src`.
+///
+/// Directories are cloned recursively. `dst` must not already exist.
+///
+/// See `man 2 clonefile`.
+#[doc(alias = "clonefile")]
+pub fn clone_file<S, D>(src: S, dst: D, flags: CloneFlags) -> io::Result<()>
+where
+    S: AsRef<Path>,
+    D: AsRef<Path>,
+{
+    let src = c_string(src.as_ref().as_os_str())?;
+    let dst = c_string(dst.as_ref().as_os_str())?;
+    check(unsafe { clonefile(src.as_ptr(), dst.as_ptr(), flags.0) })
+}
+
+/// Creates `dst` as a clone of the open file `src`.
+///
+/// A relative `dst` is resolved against the directory `dst_dir` if
+/// provided, or else against the current directory.
+///
+/// See `man 2 fclonefileat`.
+#[doc(alias = "fclonefileat")]
+pub fn clone_fd<F, D>(src: &F, dst_dir: Option<RawFd>, dst: D, flags: CloneFlags) -> io::Result<()>
+where
+    F: AsRawFd,
+    D: AsRef<Path>,
+{
+    let dst = c_string(dst.as_ref().as_os_str())?;
+    let dst_dir = dst_dir.unwrap_or(AT_FDCWD);
+    check(unsafe { fclonefileat(src.as_raw_fd(), dst_dir, dst.as_ptr(), flags.0) })
+}
+
+/// Atomically exchanges the files or directories at `a` and `b`, which must
+/// both exist on the same volume.
+///
+/// This installs a staged app update without a moment where neither version
+/// exists: swap the staged bundle with the installed one, then delete the
+/// staged path, which now holds the old version. A clone of the installed
+/// bundle made with [`clone_file`] is a cheap place to stage the update.
+///
+/// See `man 2 renamex_np`.
+#[doc(alias = "renamex_np")]
+#[doc(alias = "RENAME_SWAP")]
+pub fn swap<A, B>(a: A, b: B) -> io::Result<()>
+where
+    A: AsRef<Path>,
+    B: AsRef<Path>,
+{
+    let a = c_string(a.as_ref().as_os_str())?;
+    let b = c_string(b.as_ref().as_os_str())?;
+    check(unsafe { renamex_np(a.as_ptr(), b.as_ptr(), RENAME_SWAP) })
+}
+
+/// A snapshot of an APFS volume, as returned by [`snapshots`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Snapshot {
+    /// The name of the snapshot, such as
+    /// `com.apple.TimeMachine.2021-01-01-120000.local`.
+    pub name: OsString,
+
+    /// When the snapshot was created.
+    pub creation_time: SystemTime,
+}
+
+/// Returns the snapshots of the volume mounted at `volume`.
+///
+/// See `man 2 fs_snapshot_list`.
+#[doc(alias = "fs_snapshot_list")]
+pub fn snapshots<P: AsRef<Path>>(volume: P) -> io::Result<Vec<Snapshot>> {
+    let volume = File::open(volume)?;
+
+    let mut attrs = AttrList {
+        bitmapcount: ATTR_BIT_MAP_COUNT,
+        reserved: 0,
+        commonattr: ATTR_CMN_RETURNED_ATTRS | ATTR_CMN_NAME | ATTR_CMN_CRTIME,
+        volattr: 0,
+        dirattr: 0,
+        fileattr: 0,
+        forkattr: 0,
+    };
+    let mut buf = vec![0u8; 64 * 1024];
+    let mut snapshots = Vec::new();
+
+    loop {
+        let count = unsafe {
+            fs_snapshot_list(
+                volume.as_raw_fd(),
+                &mut attrs,
+                buf.as_mut_ptr().cast(),
+                buf.len(),
+                0,
+            )
+        };
+        match count {
+            -1 => return Err(io::Error::last_os_error()),
+            0 => return Ok(snapshots),
+            _ => {}
+        }
+
+        let mut entry = &buf[..];
+        for _ in 0..count {
+            let (snapshot, len) = unsafe { read_snapshot(entry) };
+            snapshots.push(snapshot);
+            entry = &entry[len..];
+        }
+    }
+}
+
+/// Reads a snapshot entry from the start of `entry`, returning it and the
+/// length of the entry.
+///
+/// # Safety
+///
+/// `entry` must start with an entry written by `fs_snapshot_list` for the
+/// attributes requested in [`snapshots`].
+unsafe fn read_snapshot(entry: &[u8]) -> (Snapshot, usize) {
+    // Each entry is its length, the set of returned attributes, and then the
+    // attributes in the order of their bits.
+    let len = ptr::read_unaligned(entry.as_ptr().cast::<u32>()) as usize;
+    let name_offset = mem::size_of::<u32>() + mem::size_of::<[u32; ATTR_BIT_MAP_COUNT as usize]>();
+    let crtime_offset = name_offset + mem::size_of::<AttrReference>();
+
+    // Name offsets are relative to their reference and include the null byte.
+    let name_ref: AttrReference = ptr::read_unaligned(entry[name_offset..].as_ptr().cast());
+    let name_start = name_offset + name_ref.attr_dataoffset as usize;
+    let name_len = (name_ref.attr_length as usize).saturating_sub(1);
+    let name = entry[name_start..name_start + name_len].to_vec();
+
+    let crtime: Timespec = ptr::read_unaligned(entry[crtime_offset..].as_ptr().cast());
+    let creation_time = UNIX_EPOCH + Duration::new(crtime.tv_sec as u64, crtime.tv_nsec as u32);
+
+    let snapshot = Snapshot {
+        name: OsString::from_vec(name),
+        creation_time,
+    };
+    (snapshot, len)
+}
+
+/// Creates a snapshot named `name` of the volume mounted at `volume`.
+///
+/// This requires the `com.apple.developer.vfs.snapshot` entitlement, which
+/// Apple only grants to backup software.
+///
+/// See `man 2 fs_snapshot_create`.
+#[doc(alias = "fs_snapshot_create")]
+pub fn create_snapshot<P, N>(volume: P, name: N) -> io::Result<()>
+where
+    P: AsRef<Path>,
+    N: AsRef<OsStr>,
+{
+    let volume = File::open(volume)?;
+    let name = c_string(name.as_ref())?;
+    check(unsafe { fs_snapshot_create(volume.as_raw_fd(), name.as_ptr(), 0) })
+}
+
+/// Deletes the snapshot named `name` of the volume mounted at `volume`.
+///
+/// Like [`create_snapshot`], this requires the
+/// `com.apple.developer.vfs.snapshot` entitlement.
+///
+/// See `man 2 fs_snapshot_delete`.
+#[doc(alias = "fs_snapshot_delete")]
+pub fn delete_snapshot<P, N>(volume: P, name: N) -> io::Result<()>
+where
+    P: AsRef<Path>,
+    N: AsRef<OsStr>,
+{
+    let volume = File::open(volume)?;
+    let name = c_string(name.as_ref())?;
+    check(unsafe { fs_snapshot_delete(volume.as_raw_fd(), name.as_ptr(), 0) })
+}
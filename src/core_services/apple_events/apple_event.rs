@@ -0,0 +1,242 @@
+use super::{
+    check, AEAddressDesc, AEDesc, AEDescList, AEDescType, AEEventClass, AEEventID, AEKeyword,
+    AERecord, AEReturnID, AESendMode, AETimeout, AETransactionID,
+};
+use crate::core::{OSErr, OSStatus};
+use crate::core_services::sys;
+use std::{fmt, ops::Deref};
+
+/// An Apple event: a record of parameters and attributes that can be sent to
+/// another application.
+///
+/// Parameters and attributes are both keyed by [`AEKeyword`]. Parameters hold
+/// the event's data, such as [`AEKeyword::DIRECT_OBJECT`], while attributes
+/// describe the event itself, such as [`AEKeyword::ADDRESS_ATTR`].
+///
+/// # Examples
+///
+/// Ask Finder to reveal a file:
+///
+/// ```no_run
+/// use fruity::core_services::*;
+///
+/// # fn main() -> Result<(), fruity::core::OSStatus> {
+/// let finder = AEDesc::from_bundle_id("com.apple.finder")?;
+///
+/// let mut event = AppleEvent::new(
+///     AEEventClass::MISC_STANDARDS,
+///     AEEventID::MAKE_OBJECTS_VISIBLE,
+///     &finder,
+///     AEReturnID::AUTO_GENERATE,
+///     AETransactionID::ANY,
+/// )?;
+///
+/// let file = AEDesc::from_file_url("file:///Applications/")?;
+/// event.set_param(AEKeyword::DIRECT_OBJECT, &file)?;
+///
+/// event.send(AESendMode::AE_WAIT_REPLY, AETimeout::DEFAULT)?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// See [documentation](https://developer.apple.com/documentation/coreservices/appleevent?language=objc).
+#[repr(transparent)]
+pub struct AppleEvent(AERecord);
+
+impl Deref for AppleEvent {
+    type Target = AERecord;
+
+    #[inline]
+    fn deref(&self) -> &AERecord {
+        &self.0
+    }
+}
+
+impl fmt::Debug for AppleEvent {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+impl AppleEvent {
+    /// Creates an Apple event addressed to `target`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreservices/aecreateappleevent(_:_:_:_:_:_:)?language=objc).
+    #[inline]
+    #[doc(alias = "AECreateAppleEvent")]
+    pub fn new(
+        event_class: AEEventClass,
+        event_id: AEEventID,
+        target: &AEAddressDesc,
+        return_id: AEReturnID,
+        transaction_id: AETransactionID,
+    ) -> Result<Self, OSErr> {
+        let desc = unsafe {
+            AEDesc::create_with(|result| {
+                sys::AECreateAppleEvent(
+                    event_class,
+                    event_id,
+                    target,
+                    return_id,
+                    transaction_id,
+                    result,
+                )
+            })
+        }?;
+        Ok(Self::from_desc(desc))
+    }
+
+    /// Wraps `desc`, which must be an Apple event or a null descriptor.
+    #[inline]
+    pub(crate) fn from_desc(desc: AEDesc) -> Self {
+        Self(AERecord(AEDescList(desc)))
+    }
+
+    /// Unwraps the underlying descriptor.
+    #[inline]
+    pub(crate) fn into_desc(self) -> AEDesc {
+        ((self.0).0).0
+    }
+
+    /// Returns a copy of the parameter for `keyword`, coerced to
+    /// `desired_type`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreservices/aegetparamdesc(_:_:_:_:)?language=objc).
+    #[inline]
+    #[doc(alias = "AEGetParamDesc")]
+    pub fn param(&self, keyword: AEKeyword, desired_type: AEDescType) -> Result<AEDesc, OSErr> {
+        self.0.get_key(keyword, desired_type)
+    }
+
+    /// Sets the parameter for `keyword` to a copy of `desc`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreservices/aeputparamdesc(_:_:_:)?language=objc).
+    #[inline]
+    #[doc(alias = "AEPutParamDesc")]
+    pub fn set_param(&mut self, keyword: AEKeyword, desc: &AEDesc) -> Result<(), OSErr> {
+        self.0.set_key(keyword, desc)
+    }
+
+    /// Removes the parameter for `keyword`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreservices/aedeleteparam(_:_:)?language=objc).
+    #[inline]
+    #[doc(alias = "AEDeleteParam")]
+    pub fn remove_param(&mut self, keyword: AEKeyword) -> Result<(), OSErr> {
+        self.0.remove_key(keyword)
+    }
+
+    /// Returns a copy of the attribute for `keyword`, coerced to
+    /// `desired_type`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreservices/aegetattributedesc(_:_:_:_:)?language=objc).
+    #[inline]
+    #[doc(alias = "AEGetAttributeDesc")]
+    pub fn attribute(&self, keyword: AEKeyword, desired_type: AEDescType) -> Result<AEDesc, OSErr> {
+        unsafe {
+            AEDesc::create_with(|result| {
+                sys::AEGetAttributeDesc(self.0.as_desc(), keyword, desired_type, result)
+            })
+        }
+    }
+
+    /// Sets the attribute for `keyword` to a copy of `desc`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreservices/aeputattributedesc(_:_:_:)?language=objc).
+    #[inline]
+    #[doc(alias = "AEPutAttributeDesc")]
+    pub fn set_attribute(&mut self, keyword: AEKeyword, desc: &AEDesc) -> Result<(), OSErr> {
+        check(unsafe { sys::AEPutAttributeDesc(self.0.as_mut_desc(), keyword, desc) })
+    }
+
+    /// Returns the four-character code stored in the attribute for
+    /// `keyword`.
+    fn code_attribute(&self, keyword: AEKeyword) -> Result<u32, OSErr> {
+        let data = self.attribute(keyword, AEDescType::TYPE)?.data()?;
+        let bytes = data.get(..4).ok_or(OSErr::AE_CORRUPT_DATA)?;
+        Ok(u32::from_ne_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    /// Returns the event class of this event.
+    #[inline]
+    pub fn event_class(&self) -> Result<AEEventClass, OSErr> {
+        self.code_attribute(AEKeyword::EVENT_CLASS_ATTR)
+            .map(AEEventClass::from_int)
+    }
+
+    /// Returns the event ID of this event.
+    #[inline]
+    pub fn event_id(&self) -> Result<AEEventID, OSErr> {
+        self.code_attribute(AEKeyword::EVENT_ID_ATTR)
+            .map(AEEventID::from_int)
+    }
+
+    /// Returns the transaction that this event is part of, which is
+    /// [`AETransactionID::ANY`] if it is not part of one.
+    #[inline]
+    pub fn transaction_id(&self) -> Result<AETransactionID, OSErr> {
+        let desc = self.attribute(AEKeyword::TRANSACTION_ID_ATTR, AEDescType::I32)?;
+        desc.to_i32().map(AETransactionID)
+    }
+
+    /// Returns how long the sender of this event is willing to wait for a
+    /// reply.
+    ///
+    /// Handlers can use this to decide whether to reply before doing
+    /// long-running work.
+    #[inline]
+    pub fn timeout(&self) -> Result<AETimeout, OSErr> {
+        let desc = self.attribute(AEKeyword::TIMEOUT_ATTR, AEDescType::I32)?;
+        desc.to_i32().map(|ticks| AETimeout(ticks.into()))
+    }
+
+    /// Sends this event and returns the reply.
+    ///
+    /// If `mode` does not wait for a reply, the returned reply is a null
+    /// descriptor. If the target handled the event but reported an error in
+    /// the reply, that error is returned.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreservices/aesendmessage(_:_:_:_:)?language=objc).
+    #[doc(alias = "AESendMessage")]
+    pub fn send(&self, mode: AESendMode, timeout: AETimeout) -> Result<AppleEvent, OSStatus> {
+        let mut reply = AppleEvent::from_desc(AEDesc::null());
+
+        let status =
+            unsafe { sys::AESendMessage(self.0.as_desc(), reply.0.as_mut_desc(), mode, timeout.0) };
+        if let Some(status) = status {
+            return Err(status);
+        }
+
+        match reply.reply_error() {
+            Some(error) => Err(error),
+            None => Ok(reply),
+        }
+    }
+
+    /// Returns the error number in the [`AEKeyword::ERROR_NUMBER`] parameter of
+    /// this reply, if it reports a non-zero error.
+    #[inline]
+    pub fn reply_error(&self) -> Option<OSStatus> {
+        if self.is_null() {
+            return None;
+        }
+        let code = self
+            .param(AEKeyword::ERROR_NUMBER, AEDescType::I32)
+            .and_then(|desc| desc.to_i32())
+            .ok()?;
+        OSStatus::new(code)
+    }
+
+    /// Returns the error message in the [`AEKeyword::ERROR_STRING`] parameter
+    /// of this reply, if any.
+    #[inline]
+    pub fn reply_error_message(&self) -> Option<String> {
+        if self.is_null() {
+            return None;
+        }
+        self.param(AEKeyword::ERROR_STRING, AEDescType::UTF8)
+            .and_then(|desc| desc.to_utf8_string())
+            .ok()
+    }
+}
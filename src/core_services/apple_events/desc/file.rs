@@ -0,0 +1,75 @@
+use super::{AEDesc, AEDescType};
+use crate::core::{Arc, OSErr};
+use crate::core_foundation::{CFData, CFString, CFURLBookmarkResolutionOptions, CFURL};
+use std::path::{Path, PathBuf};
+
+/// Files and URLs.
+///
+/// Files are the most common Apple event parameters, such as the direct
+/// object of an [open documents](crate::core_services::AEEventID::OPEN_DOCUMENTS)
+/// event. Senders may refer to them with [`AEDescType::FILE_URL`],
+/// [`AEDescType::BOOKMARK_DATA`], or the legacy `typeAlias`, which these
+/// methods read the same way.
+impl AEDesc {
+    /// Creates an [`AEDescType::FILE_URL`] descriptor for the file at `path`.
+    ///
+    /// If `path` is relative, it is resolved against the current working
+    /// directory.
+    #[inline]
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, OSErr> {
+        let path = path.as_ref();
+        let url = CFURL::from_path(path, path.is_dir()).ok_or(OSErr::PARAM_ERR)?;
+        Self::from_url(&url)
+    }
+
+    /// Creates an [`AEDescType::FILE_URL`] descriptor for `url`.
+    #[inline]
+    pub fn from_url(url: &CFURL) -> Result<Self, OSErr> {
+        let url = url.absolute_url().string();
+        Self::new(AEDescType::FILE_URL, url.to_cow_str().as_bytes())
+    }
+
+    /// Creates an [`AEDescType::BOOKMARK_DATA`] descriptor from bookmark
+    /// data, such as from
+    /// [`CFURL::create_bookmark_data`].
+    ///
+    /// Unlike a file URL, a bookmark keeps referring to the file if it is
+    /// moved or renamed.
+    #[inline]
+    pub fn from_bookmark_data(bookmark: &[u8]) -> Result<Self, OSErr> {
+        Self::new(AEDescType::BOOKMARK_DATA, bookmark)
+    }
+
+    /// Returns the file URL that this descriptor refers to, resolving
+    /// bookmark data and aliases.
+    ///
+    /// Fails with [`OSErr::AE_COERCION_FAIL`] if the descriptor does not
+    /// refer to a file, or if a bookmark cannot be resolved.
+    pub fn to_url(&self) -> Result<Arc<CFURL>, OSErr> {
+        if self.descriptor_type() == AEDescType::BOOKMARK_DATA {
+            let bookmark = CFData::from_bytes(None, &self.data()?);
+            return CFURL::resolve_bookmark_data(
+                &bookmark,
+                CFURLBookmarkResolutionOptions::WITHOUT_UI,
+                None,
+            )
+            .map(|resolved| resolved.url)
+            .map_err(|_| OSErr::AE_COERCION_FAIL);
+        }
+
+        // Aliases are coerced to file URLs by Core Services.
+        let data = self.coerced_data(AEDescType::FILE_URL)?;
+        let string = CFString::new(&String::from_utf8_lossy(&data));
+        CFURL::from_string(None, &string, None).ok_or(OSErr::AE_CORRUPT_DATA)
+    }
+
+    /// Returns the path of the file that this descriptor refers to, resolving
+    /// bookmark data and aliases.
+    ///
+    /// Fails with [`OSErr::AE_COERCION_FAIL`] if the descriptor does not
+    /// refer to a local file.
+    #[inline]
+    pub fn to_path_buf(&self) -> Result<PathBuf, OSErr> {
+        self.to_url()?.to_path_buf().ok_or(OSErr::AE_COERCION_FAIL)
+    }
+}
@@ -0,0 +1,81 @@
+use super::{AEDesc, AEDescList, AERecord};
+use crate::core::OSErr;
+
+/// A value that can be converted into an [`AEDesc`].
+///
+/// This is used by [`descriptor!`](crate::descriptor) for values within a
+/// descriptor literal.
+pub trait IntoAEDesc {
+    /// Creates a descriptor from `self`.
+    fn into_ae_desc(self) -> Result<AEDesc, OSErr>;
+}
+
+impl IntoAEDesc for AEDesc {
+    #[inline]
+    fn into_ae_desc(self) -> Result<AEDesc, OSErr> {
+        Ok(self)
+    }
+}
+
+impl IntoAEDesc for &AEDesc {
+    #[inline]
+    fn into_ae_desc(self) -> Result<AEDesc, OSErr> {
+        self.duplicate()
+    }
+}
+
+impl IntoAEDesc for AEDescList {
+    #[inline]
+    fn into_ae_desc(self) -> Result<AEDesc, OSErr> {
+        Ok(self.0)
+    }
+}
+
+impl IntoAEDesc for AERecord {
+    #[inline]
+    fn into_ae_desc(self) -> Result<AEDesc, OSErr> {
+        Ok((self.0).0)
+    }
+}
+
+impl IntoAEDesc for &str {
+    #[inline]
+    fn into_ae_desc(self) -> Result<AEDesc, OSErr> {
+        AEDesc::from_utf8(self)
+    }
+}
+
+impl IntoAEDesc for &String {
+    #[inline]
+    fn into_ae_desc(self) -> Result<AEDesc, OSErr> {
+        AEDesc::from_utf8(self)
+    }
+}
+
+impl IntoAEDesc for String {
+    #[inline]
+    fn into_ae_desc(self) -> Result<AEDesc, OSErr> {
+        AEDesc::from_utf8(&self)
+    }
+}
+
+impl IntoAEDesc for bool {
+    #[inline]
+    fn into_ae_desc(self) -> Result<AEDesc, OSErr> {
+        AEDesc::from_bool(self)
+    }
+}
+
+impl IntoAEDesc for i32 {
+    #[inline]
+    fn into_ae_desc(self) -> Result<AEDesc, OSErr> {
+        AEDesc::from_i32(self)
+    }
+}
+
+impl IntoAEDesc for f64 {
+    #[inline]
+    fn into_ae_desc(self) -> Result<AEDesc, OSErr> {
+        AEDesc::from_f64(self)
+    }
+}
@@ -0,0 +1,126 @@
+use super::{check, AEDesc, AEDescType};
+use crate::core::OSErr;
+use crate::core_services::{sys, AEKeyword};
+use std::{fmt, ops::Deref, os::raw::c_long, ptr};
+
+/// An ordered list of Apple event descriptors.
+///
+/// Unlike the C API, indices are zero-based.
+///
+/// See [documentation](https://developer.apple.com/documentation/coreservices/aedesclist?language=objc).
+#[repr(transparent)]
+pub struct AEDescList(pub(crate) AEDesc);
+
+impl Deref for AEDescList {
+    type Target = AEDesc;
+
+    #[inline]
+    fn deref(&self) -> &AEDesc {
+        &self.0
+    }
+}
+
+impl fmt::Debug for AEDescList {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+impl AEDescList {
+    /// Creates an empty list.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreservices/aecreatelist(_:_:_:_:)?language=objc).
+    #[inline]
+    #[doc(alias = "AECreateList")]
+    pub fn new() -> Result<Self, OSErr> {
+        unsafe { AEDesc::create_with(|result| sys::AECreateList(ptr::null(), 0, 0, result)) }
+            .map(Self)
+    }
+
+    /// Returns the number of descriptors in the list.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreservices/aecountitems(_:_:)?language=objc).
+    #[inline]
+    #[doc(alias = "AECountItems")]
+    pub fn len(&self) -> Result<usize, OSErr> {
+        let mut count: c_long = 0;
+        check(unsafe { sys::AECountItems(&self.0, &mut count) })?;
+        Ok(count as usize)
+    }
+
+    /// Returns `true` if the list has no descriptors.
+    #[inline]
+    pub fn is_empty(&self) -> Result<bool, OSErr> {
+        self.len().map(|len| len == 0)
+    }
+
+    /// Appends a copy of `desc` to the end of the list.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreservices/aeputdesc(_:_:_:)?language=objc).
+    #[inline]
+    #[doc(alias = "AEPutDesc")]
+    pub fn push(&mut self, desc: &AEDesc) -> Result<(), OSErr> {
+        // An index of 0 appends.
+        check(unsafe { sys::AEPutDesc(&mut self.0, 0, desc) })
+    }
+
+    /// Replaces the descriptor at `index` with a copy of `desc`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreservices/aeputdesc(_:_:_:)?language=objc).
+    #[inline]
+    #[doc(alias = "AEPutDesc")]
+    pub fn set(&mut self, index: usize, desc: &AEDesc) -> Result<(), OSErr> {
+        check(unsafe { sys::AEPutDesc(&mut self.0, index as c_long + 1, desc) })
+    }
+
+    /// Returns a copy of the descriptor at `index`, coerced to
+    /// `desired_type`.
+    ///
+    /// Pass [`AEDescType::WILDCARD`] to get the descriptor as-is.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreservices/aegetnthdesc(_:_:_:_:_:)?language=objc).
+    #[inline]
+    #[doc(alias = "AEGetNthDesc")]
+    pub fn get(&self, index: usize, desired_type: AEDescType) -> Result<AEDesc, OSErr> {
+        self.get_with_keyword(index, desired_type)
+            .map(|(_, desc)| desc)
+    }
+
+    /// Returns the keyword and a copy of the descriptor at `index`, coerced
+    /// to `desired_type`.
+    ///
+    /// The keyword is only meaningful if this list is a
+    /// [`AERecord`](super::AERecord).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreservices/aegetnthdesc(_:_:_:_:_:)?language=objc).
+    #[doc(alias = "AEGetNthDesc")]
+    pub fn get_with_keyword(
+        &self,
+        index: usize,
+        desired_type: AEDescType,
+    ) -> Result<(AEKeyword, AEDesc), OSErr> {
+        let mut keyword = AEKeyword::from_int(0);
+        let desc = unsafe {
+            AEDesc::create_with(|result| {
+                sys::AEGetNthDesc(
+                    &self.0,
+                    index as c_long + 1,
+                    desired_type,
+                    &mut keyword,
+                    result,
+                )
+            })
+        }?;
+        Ok((keyword, desc))
+    }
+
+    /// Removes the descriptor at `index`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreservices/aedeleteitem(_:_:)?language=objc).
+    #[inline]
+    #[doc(alias = "AEDeleteItem")]
+    pub fn remove(&mut self, index: usize) -> Result<(), OSErr> {
+        check(unsafe { sys::AEDeleteItem(&mut self.0, index as c_long + 1) })
+    }
+}
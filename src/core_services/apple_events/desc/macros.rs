@@ -0,0 +1,124 @@
+/// Creates an [`AEDesc`](crate::core_services::AEDesc) from a nested literal
+/// of records, lists, and values, in the spirit of `AEBuildDesc` format
+/// strings.
+///
+/// The result is a `Result<AEDesc, OSErr>`, failing if any descriptor could
+/// not be created.
+///
+/// # Syntax
+///
+/// - `{ b"kwd1": value, b"kwd2": value }` creates an
+///   [`AERecord`](crate::core_services::AERecord). Keys are 4-byte literals,
+///   or [`AEKeyword`](crate::core_services::AEKeyword) expressions such as
+///   identifiers or parenthesized expressions.
+///
+/// - `[value, value]` creates an
+///   [`AEDescList`](crate::core_services::AEDescList).
+///
+/// - `b"type"(value)` [coerces](crate::core_services::AEDesc::coerce) `value`
+///   to the descriptor type with the 4-byte code.
+///
+/// - Any other value is an expression converted with
+///   [`IntoAEDesc`](crate::core_services::IntoAEDesc), such as strings,
+///   numbers, booleans, and existing descriptors. Expressions containing
+///   top-level commas, such as generic arguments, must be parenthesized.
+///
+/// Keys and types that are not exactly 4 bytes fail to compile.
+///
+/// # Feature Flag
+///
+/// This macro requires the **`core_services`**
+/// [feature flag](crate#feature-flags).
+///
+/// # Examples
+///
+/// ```
+/// use fruity::core_services::AEKeyword;
+///
+/// const KEY_DATA: AEKeyword = AEKeyword::from_chars(*b"data");
+/// let size = 12;
+///
+/// let desc = fruity::descriptor!({
+///     b"pnam": "Report",
+///     b"ptsz": b"long"(size),
+///     KEY_DATA: [1, 2.5, true, { b"kind": "nested" }],
+/// });
+/// ```
+#[macro_export]
+macro_rules! descriptor {
+    ($($value:tt)+) => {
+        $crate::_descriptor_value!($($value)+)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _descriptor_value {
+    ({ $($body:tt)* }) => {
+        $crate::core_services::AERecord::new().and_then(|mut record| {
+            $crate::_descriptor_record!(record; $($body)*);
+            $crate::core_services::IntoAEDesc::into_ae_desc(record)
+        })
+    };
+    ([ $($body:tt)* ]) => {
+        $crate::core_services::AEDescList::new().and_then(|mut list| {
+            $crate::_descriptor_list!(list, []; $($body)*);
+            $crate::core_services::IntoAEDesc::into_ae_desc(list)
+        })
+    };
+    ($type:literal ( $($value:tt)+ )) => {
+        $crate::_descriptor_value!($($value)+).and_then(|desc| {
+            desc.coerce($crate::core_services::AEDescType::from_chars(*$type))
+        })
+    };
+    ($value:expr) => {
+        $crate::core_services::IntoAEDesc::into_ae_desc($value)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _descriptor_key {
+    ($key:literal) => {
+        $crate::core_services::AEKeyword::from_chars(*$key)
+    };
+    ($key:expr) => {
+        $key
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _descriptor_record {
+    ($record:ident;) => {};
+    ($record:ident; $key:tt : $($rest:tt)*) => {
+        $crate::_descriptor_record!(@value $record, $key, []; $($rest)*)
+    };
+
+    // Collect the tokens of the value up to the next comma.
+    (@value $record:ident, $key:tt, [$($value:tt)+]; $(, $($rest:tt)*)?) => {
+        $record.set_key(
+            $crate::_descriptor_key!($key),
+            &$crate::_descriptor_value!($($value)+)?,
+        )?;
+        $crate::_descriptor_record!($record; $($($rest)*)?)
+    };
+    (@value $record:ident, $key:tt, [$($value:tt)*]; $next:tt $($rest:tt)*) => {
+        $crate::_descriptor_record!(@value $record, $key, [$($value)* $next]; $($rest)*)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _descriptor_list {
+    ($list:ident, [];) => {};
+
+    // Collect the tokens of each item up to the next comma.
+    ($list:ident, [$($item:tt)+]; $(, $($rest:tt)*)?) => {
+        $list.push(&$crate::_descriptor_value!($($item)+)?)?;
+        $crate::_descriptor_list!($list, []; $($($rest)*)?)
+    };
+    ($list:ident, [$($item:tt)*]; $next:tt $($rest:tt)*) => {
+        $crate::_descriptor_list!($list, [$($item)* $next]; $($rest)*)
+    };
+}
@@ -1,3 +1,264 @@
+use super::AEDataStorage;
+use crate::core::OSErr;
+use crate::core_services::sys;
+use std::{mem::MaybeUninit, os::raw::c_void, ptr};
+
+mod file;
+mod into_desc;
+mod list;
+mod macros;
+mod pretty;
+mod record;
 mod type_;
 
+#[cfg(feature = "serde")]
+mod serde;
+
+pub use into_desc::*;
+pub use list::*;
+pub use pretty::*;
+pub use record::*;
 pub use type_::*;
+
+#[cfg(feature = "serde")]
+pub use self::serde::AESerdeError;
+
+/// A descriptor for the address of the target of an Apple event.
+///
+/// This is typically created with [`AEDesc::from_bundle_id`] or
+/// [`AEDesc::from_pid`].
+///
+/// See [documentation](https://developer.apple.com/documentation/coreservices/aeaddressdesc?language=objc).
+pub type AEAddressDesc = AEDesc;
+
+/// An owned Apple event descriptor: a data buffer tagged with its
+/// [`AEDescType`].
+///
+/// The descriptor's data is disposed with
+/// [`AEDisposeDesc`](https://developer.apple.com/documentation/coreservices/aedisposedesc(_:)?language=objc)
+/// when dropped.
+///
+/// See [documentation](https://developer.apple.com/documentation/coreservices/aedesc?language=objc).
+// Apple event structures use 2-byte packing.
+#[repr(C, packed(2))]
+pub struct AEDesc {
+    descriptor_type: AEDescType,
+    data_handle: AEDataStorage,
+}
+
+impl Drop for AEDesc {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe { sys::AEDisposeDesc(self) };
+    }
+}
+
+impl Default for AEDesc {
+    #[inline]
+    fn default() -> Self {
+        Self::null()
+    }
+}
+
+#[inline]
+pub(crate) fn check(error: Option<OSErr>) -> Result<(), OSErr> {
+    match error {
+        Some(error) => Err(error),
+        None => Ok(()),
+    }
+}
+
+impl AEDesc {
+    /// Returns a descriptor of type [`AEDescType::NULL`] with no data.
+    #[inline]
+    pub const fn null() -> Self {
+        Self {
+            descriptor_type: AEDescType::NULL,
+            data_handle: ptr::null_mut(),
+        }
+    }
+
+    /// Calls `f` with an uninitialized descriptor, and returns the descriptor
+    /// if `f` succeeds.
+    #[inline]
+    pub(crate) unsafe fn create_with<F>(f: F) -> Result<Self, OSErr>
+    where
+        F: FnOnce(*mut AEDesc) -> Option<OSErr>,
+    {
+        let mut desc = MaybeUninit::<Self>::uninit();
+        check(f(desc.as_mut_ptr()))?;
+        Ok(desc.assume_init())
+    }
+
+    /// Creates a descriptor of type `descriptor_type` containing a copy of
+    /// `data`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreservices/aecreatedesc(_:_:_:_:)?language=objc).
+    #[inline]
+    #[doc(alias = "AECreateDesc")]
+    pub fn new(descriptor_type: AEDescType, data: &[u8]) -> Result<Self, OSErr> {
+        unsafe {
+            Self::create_with(|result| {
+                sys::AECreateDesc(
+                    descriptor_type,
+                    data.as_ptr().cast(),
+                    data.len() as isize,
+                    result,
+                )
+            })
+        }
+    }
+
+    /// Creates a [`AEDescType::UTF8`] descriptor.
+    #[inline]
+    pub fn from_utf8(s: &str) -> Result<Self, OSErr> {
+        Self::new(AEDescType::UTF8, s.as_bytes())
+    }
+
+    /// Creates a [`AEDescType::BOOL`] descriptor.
+    #[inline]
+    pub fn from_bool(value: bool) -> Result<Self, OSErr> {
+        Self::new(AEDescType::BOOL, &[value as u8])
+    }
+
+    /// Creates a [`AEDescType::I32`] descriptor.
+    #[inline]
+    pub fn from_i32(value: i32) -> Result<Self, OSErr> {
+        Self::new(AEDescType::I32, &value.to_ne_bytes())
+    }
+
+    /// Creates a [`AEDescType::F64`] descriptor.
+    #[inline]
+    pub fn from_f64(value: f64) -> Result<Self, OSErr> {
+        Self::new(AEDescType::F64, &value.to_ne_bytes())
+    }
+
+    /// Creates a [`AEDescType::FILE_URL`] descriptor from a `file://` URL
+    /// string.
+    #[inline]
+    pub fn from_file_url(url: &str) -> Result<Self, OSErr> {
+        Self::new(AEDescType::FILE_URL, url.as_bytes())
+    }
+
+    /// Creates an address descriptor targeting the application with the
+    /// given bundle identifier, such as `"com.apple.finder"`.
+    #[inline]
+    pub fn from_bundle_id(bundle_id: &str) -> Result<AEAddressDesc, OSErr> {
+        Self::new(AEDescType::APP_BUNDLE_ID, bundle_id.as_bytes())
+    }
+
+    /// Creates an address descriptor targeting the process with the given
+    /// process ID.
+    #[inline]
+    pub fn from_pid(pid: i32) -> Result<AEAddressDesc, OSErr> {
+        Self::new(AEDescType::KERNEL_PROCESS_ID, &pid.to_ne_bytes())
+    }
+
+    /// Returns a copy of this descriptor.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreservices/aeduplicatedesc(_:_:)?language=objc).
+    #[inline]
+    #[doc(alias = "AEDuplicateDesc")]
+    pub fn duplicate(&self) -> Result<Self, OSErr> {
+        unsafe { Self::create_with(|result| sys::AEDuplicateDesc(self, result)) }
+    }
+
+    /// Returns the type of this descriptor's data.
+    #[inline]
+    pub fn descriptor_type(&self) -> AEDescType {
+        self.descriptor_type
+    }
+
+    /// Returns a pointer to this descriptor's data storage.
+    #[inline]
+    pub fn data_handle(&self) -> AEDataStorage {
+        self.data_handle
+    }
+
+    /// Returns `true` if this is a [`AEDescType::NULL`] descriptor.
+    #[inline]
+    pub fn is_null(&self) -> bool {
+        self.descriptor_type() == AEDescType::NULL
+    }
+
+    /// Returns the size of this descriptor's data in bytes.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreservices/aegetdescdatasize(_:)?language=objc).
+    #[inline]
+    #[doc(alias = "AEGetDescDataSize")]
+    pub fn data_size(&self) -> usize {
+        unsafe { sys::AEGetDescDataSize(self) as usize }
+    }
+
+    /// Returns a copy of this descriptor's data.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreservices/aegetdescdata(_:_:_:)?language=objc).
+    #[doc(alias = "AEGetDescData")]
+    pub fn data(&self) -> Result<Vec<u8>, OSErr> {
+        let size = self.data_size();
+        let mut data = Vec::<u8>::with_capacity(size);
+        unsafe {
+            check(sys::AEGetDescData(
+                self,
+                data.as_mut_ptr().cast::<c_void>(),
+                size as isize,
+            ))?;
+            data.set_len(size);
+        }
+        Ok(data)
+    }
+
+    /// Coerces this descriptor into a new descriptor of type `to_type`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreservices/aecoercedesc(_:_:_:)?language=objc).
+    #[inline]
+    #[doc(alias = "AECoerceDesc")]
+    pub fn coerce(&self, to_type: AEDescType) -> Result<Self, OSErr> {
+        unsafe { Self::create_with(|result| sys::AECoerceDesc(self, to_type, result)) }
+    }
+
+    /// Reads the data of this descriptor after coercing it to `to_type` if
+    /// needed.
+    fn coerced_data(&self, to_type: AEDescType) -> Result<Vec<u8>, OSErr> {
+        if self.descriptor_type() == to_type {
+            self.data()
+        } else {
+            self.coerce(to_type)?.data()
+        }
+    }
+
+    /// Coerces this descriptor to UTF-8 text and returns it.
+    ///
+    /// Invalid UTF-8 is replaced with U+FFFD.
+    #[inline]
+    pub fn to_utf8_string(&self) -> Result<String, OSErr> {
+        let data = self.coerced_data(AEDescType::UTF8)?;
+        Ok(String::from_utf8(data)
+            .unwrap_or_else(|error| String::from_utf8_lossy(error.as_bytes()).into_owned()))
+    }
+
+    /// Coerces this descriptor to a boolean and returns it.
+    #[inline]
+    pub fn to_bool(&self) -> Result<bool, OSErr> {
+        let data = self.coerced_data(AEDescType::BOOL)?;
+        Ok(data.first().copied().unwrap_or(0) != 0)
+    }
+
+    /// Coerces this descriptor to a 32-bit integer and returns it.
+    #[inline]
+    pub fn to_i32(&self) -> Result<i32, OSErr> {
+        let data = self.coerced_data(AEDescType::I32)?;
+        let bytes = data.get(..4).ok_or(OSErr::AE_CORRUPT_DATA)?;
+        Ok(i32::from_ne_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    /// Coerces this descriptor to a 64-bit float and returns it.
+    #[inline]
+    pub fn to_f64(&self) -> Result<f64, OSErr> {
+        let data = self.coerced_data(AEDescType::F64)?;
+        let bytes = data.get(..8).ok_or(OSErr::AE_CORRUPT_DATA)?;
+        let mut buf = [0; 8];
+        buf.copy_from_slice(bytes);
+        Ok(f64::from_ne_bytes(buf))
+    }
+}
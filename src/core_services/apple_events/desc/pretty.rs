@@ -0,0 +1,334 @@
+use super::{AEDesc, AEDescList, AEDescType};
+use crate::core::OSErr;
+use crate::core_services::{sys, AEKeyword};
+use std::{ascii, fmt, fmt::Write};
+
+/// The number of bytes shown for data of unknown types.
+const MAX_SHOWN_BYTES: usize = 16;
+
+/// Formats an [`AEDesc`] and the descriptors nested within it as an
+/// indented tree, as returned by [`AEDesc::pretty`].
+///
+/// Lists are shown as `[...]` and records and Apple events as `{...}`,
+/// keyed by the header names of known keywords, such as `keyDirectObject`,
+/// or by their four-character codes otherwise. Numbers, booleans, text, and
+/// four-character codes are decoded, and data of other types is shown as
+/// bytes.
+///
+/// The same format is used on one line by the [`Debug`](fmt::Debug) impls of
+/// the descriptor types, or indented with `{:#?}`.
+#[derive(Clone, Copy)]
+pub struct AEDescPretty<'a>(&'a AEDesc);
+
+impl fmt::Display for AEDescPretty<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:#?}", Tree(self.0))
+    }
+}
+
+impl fmt::Debug for AEDescPretty<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl fmt::Debug for AEDesc {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&Tree(self), f)
+    }
+}
+
+impl AEDesc {
+    /// Returns a value that formats this descriptor and its contents as an
+    /// indented tree, for seeing what an Apple event handler received.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fruity::core_services::{AEDesc, AEDescList, AEKeyword, AERecord};
+    ///
+    /// # fn main() -> Result<(), fruity::core::OSErr> {
+    /// let mut list = AEDescList::new()?;
+    /// list.push(&AEDesc::from_i32(1)?)?;
+    /// list.push(&AEDesc::from_bool(true)?)?;
+    ///
+    /// let mut record = AERecord::new()?;
+    /// record.set_key(AEKeyword::DIRECT_OBJECT, &AEDesc::from_utf8("hello")?)?;
+    /// record.set_key(AEKeyword::from_chars(*b"abcd"), &list)?;
+    ///
+    /// println!("{}", record.pretty());
+    /// // {
+    /// //     keyDirectObject: "hello",
+    /// //     'abcd': [
+    /// //         1,
+    /// //         true,
+    /// //     ],
+    /// // }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn pretty(&self) -> AEDescPretty<'_> {
+        AEDescPretty(self)
+    }
+}
+
+/// Formats a descriptor, using `{:#?}` for indentation.
+struct Tree<'a>(&'a AEDesc);
+
+impl fmt::Debug for Tree<'_> {
+    // Legacy text types are still received from older applications.
+    #[allow(deprecated)]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let desc = self.0;
+        let kind = desc.descriptor_type();
+
+        match kind {
+            AEDescType::NULL => f.write_str("null"),
+            AEDescType::TRUE => f.write_str("true"),
+            AEDescType::FALSE => f.write_str("false"),
+            AEDescType::AE_LIST => fmt_list(desc, f),
+            AEDescType::AE_RECORD => fmt_record(desc, f),
+            AEDescType::APPLE_EVENT => {
+                f.write_str("event ")?;
+                for (i, keyword) in [AEKeyword::EVENT_CLASS_ATTR, AEKeyword::EVENT_ID_ATTR]
+                    .iter()
+                    .enumerate()
+                {
+                    if i != 0 {
+                        f.write_char('/')?;
+                    }
+                    match event_attribute(desc, *keyword) {
+                        Ok(code) => write_code(f, code)?,
+                        Err(error) => write_error(f, error)?,
+                    }
+                }
+                f.write_char(' ')?;
+                fmt_record(desc, f)
+            }
+
+            // Object specifiers and similar are records with another type.
+            AEDescType::OBJECT_SPECIFIER
+            | AEDescType::INSERTION_LOC
+            | AEDescType::RANGE_DESCRIPTOR
+            | AEDescType::COMP_DESCRIPTOR
+            | AEDescType::LOGICAL_DESCRIPTOR => {
+                write_code(f, kind.into_int())?;
+                f.write_char(' ')?;
+                match desc.coerce(AEDescType::AE_RECORD) {
+                    Ok(record) => fmt_record(&record, f),
+                    Err(error) => write_error(f, error),
+                }
+            }
+
+            AEDescType::UTF8
+            | AEDescType::UTF16
+            | AEDescType::UNICODE_TEXT
+            | AEDescType::STYLED_UNICODE_TEXT
+            | AEDescType::CHAR
+            | AEDescType::C_STRING
+            | AEDescType::P_STRING => match desc.to_utf8_string() {
+                Ok(string) => fmt::Debug::fmt(&string, f),
+                Err(_) => fmt_bytes(desc, f),
+            },
+
+            // Text that is meaningful on its own, so it is labeled.
+            AEDescType::FILE_URL | AEDescType::APP_URL | AEDescType::APP_BUNDLE_ID => {
+                write_code(f, kind.into_int())?;
+                f.write_char(' ')?;
+                match desc.to_utf8_string() {
+                    Ok(string) => fmt::Debug::fmt(&string, f),
+                    Err(error) => write_error(f, error),
+                }
+            }
+
+            AEDescType::TYPE => fmt_code(desc, f, |code| AEDescType::from_int(code).name()),
+            AEDescType::KEYWORD | AEDescType::PROPERTY => {
+                fmt_code(desc, f, |code| AEKeyword::from_int(code).name())
+            }
+            AEDescType::ENUMERATED | AEDescType::APPL_SIGNATURE => fmt_code(desc, f, |_| None),
+
+            AEDescType::KERNEL_PROCESS_ID => {
+                write_code(f, kind.into_int())?;
+                f.write_char(' ')?;
+                fmt_number(desc, f)
+            }
+
+            _ => fmt_number(desc, f),
+        }
+    }
+}
+
+/// Formats the items of the list `desc` as `[...]`.
+fn fmt_list(desc: &AEDesc, f: &mut fmt::Formatter) -> fmt::Result {
+    let list = as_list(desc);
+    let len = match list.len() {
+        Ok(len) => len,
+        Err(error) => return write_error(f, error),
+    };
+
+    let mut items = f.debug_list();
+    for i in 0..len {
+        match list.get(i, AEDescType::WILDCARD) {
+            Ok(item) => items.entry(&Tree(&item)),
+            Err(error) => items.entry(&Error(error)),
+        };
+    }
+    items.finish()
+}
+
+/// Formats the keyed items of the record or Apple event `desc` as `{...}`.
+fn fmt_record(desc: &AEDesc, f: &mut fmt::Formatter) -> fmt::Result {
+    let list = as_list(desc);
+    let len = match list.len() {
+        Ok(len) => len,
+        Err(error) => return write_error(f, error),
+    };
+
+    let mut items = f.debug_map();
+    for i in 0..len {
+        match list.get_with_keyword(i, AEDescType::WILDCARD) {
+            Ok((keyword, item)) => items.entry(&Keyword(keyword), &Tree(&item)),
+            Err(error) => items.entry(&i, &Error(error)),
+        };
+    }
+    items.finish()
+}
+
+/// Formats a four-character code value by its name, if `name` knows it.
+fn fmt_code(
+    desc: &AEDesc,
+    f: &mut fmt::Formatter,
+    name: impl FnOnce(u32) -> Option<&'static str>,
+) -> fmt::Result {
+    let code = match desc.data() {
+        Ok(data) if data.len() == 4 => u32::from_ne_bytes([data[0], data[1], data[2], data[3]]),
+        Ok(_) => return fmt_bytes(desc, f),
+        Err(error) => return write_error(f, error),
+    };
+    match name(code) {
+        Some(name) => f.write_str(name),
+        None => write_code(f, code),
+    }
+}
+
+/// Formats numeric and boolean values, or the bytes of other values.
+fn fmt_number(desc: &AEDesc, f: &mut fmt::Formatter) -> fmt::Result {
+    let data = match desc.data() {
+        Ok(data) => data,
+        Err(error) => return write_error(f, error),
+    };
+
+    macro_rules! decode {
+        ($($kind:ident => $ty:ty,)+) => {
+            match desc.descriptor_type() {
+                AEDescType::BOOL if data.len() == 1 => return fmt::Debug::fmt(&(data[0] != 0), f),
+                $(
+                    AEDescType::$kind if data.len() == std::mem::size_of::<$ty>() => {
+                        let mut bytes = [0; std::mem::size_of::<$ty>()];
+                        bytes.copy_from_slice(&data);
+                        return fmt::Debug::fmt(&<$ty>::from_ne_bytes(bytes), f);
+                    }
+                )+
+                _ => {}
+            }
+        };
+    }
+
+    decode! {
+        I16 => i16,
+        U16 => u16,
+        I32 => i32,
+        U32 => u32,
+        I64 => i64,
+        U64 => u64,
+        F32 => f32,
+        F64 => f64,
+        KERNEL_PROCESS_ID => i32,
+    }
+
+    write_bytes(f, desc.descriptor_type(), &data)
+}
+
+/// Formats the type and leading bytes of `desc`.
+fn fmt_bytes(desc: &AEDesc, f: &mut fmt::Formatter) -> fmt::Result {
+    match desc.data() {
+        Ok(data) => write_bytes(f, desc.descriptor_type(), &data),
+        Err(error) => write_error(f, error),
+    }
+}
+
+fn write_bytes(f: &mut fmt::Formatter, kind: AEDescType, data: &[u8]) -> fmt::Result {
+    write_code(f, kind.into_int())?;
+    write!(f, " <{} bytes", data.len())?;
+    if !data.is_empty() {
+        f.write_char(':')?;
+        for byte in data.iter().take(MAX_SHOWN_BYTES) {
+            write!(f, " {:02x}", byte)?;
+        }
+        if data.len() > MAX_SHOWN_BYTES {
+            f.write_str(" ...")?;
+        }
+    }
+    f.write_char('>')
+}
+
+/// Writes `code` as a quoted four-character code, like `'utf8'`.
+fn write_code(f: &mut fmt::Formatter, code: u32) -> fmt::Result {
+    f.write_char('\'')?;
+    for ch in code
+        .to_be_bytes()
+        .iter()
+        .flat_map(|&b| ascii::escape_default(b))
+    {
+        f.write_char(ch as char)?;
+    }
+    f.write_char('\'')
+}
+
+fn write_error(f: &mut fmt::Formatter, error: OSErr) -> fmt::Result {
+    write!(f, "<error {:?}>", error)
+}
+
+/// Returns the four-character code stored in the attribute for `keyword`
+/// of the Apple event `desc`.
+fn event_attribute(desc: &AEDesc, keyword: AEKeyword) -> Result<u32, OSErr> {
+    let attribute = unsafe {
+        AEDesc::create_with(|result| {
+            sys::AEGetAttributeDesc(desc, keyword, AEDescType::TYPE, result)
+        })
+    }?;
+    let data = attribute.data()?;
+    let bytes = data.get(..4).ok_or(OSErr::AE_CORRUPT_DATA)?;
+    Ok(u32::from_ne_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+fn as_list(desc: &AEDesc) -> &AEDescList {
+    // SAFETY: `AEDescList` is a transparent wrapper. Listing the items of a
+    // descriptor that is not a list fails rather than misbehaving.
+    unsafe { &*(desc as *const AEDesc as *const AEDescList) }
+}
+
+/// Formats a record keyword by its header name, or as a four-character code.
+struct Keyword(AEKeyword);
+
+impl fmt::Debug for Keyword {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.0.name() {
+            Some(name) => f.write_str(name),
+            None => write_code(f, self.0.into_int()),
+        }
+    }
+}
+
+struct Error(OSErr);
+
+impl fmt::Debug for Error {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_error(f, self.0)
+    }
+}
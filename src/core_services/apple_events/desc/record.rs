@@ -0,0 +1,86 @@
+use super::{check, AEDesc, AEDescList, AEDescType};
+use crate::core::OSErr;
+use crate::core_services::{sys, AEKeyword};
+use std::{fmt, ops::Deref, ptr};
+
+/// A list of Apple event descriptors keyed by [`AEKeyword`].
+///
+/// See [documentation](https://developer.apple.com/documentation/coreservices/aerecord?language=objc).
+#[repr(transparent)]
+pub struct AERecord(pub(crate) AEDescList);
+
+impl Deref for AERecord {
+    type Target = AEDescList;
+
+    #[inline]
+    fn deref(&self) -> &AEDescList {
+        &self.0
+    }
+}
+
+impl fmt::Debug for AERecord {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+impl AERecord {
+    /// Creates an empty record.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreservices/aecreatelist(_:_:_:_:)?language=objc).
+    #[inline]
+    #[doc(alias = "AECreateList")]
+    pub fn new() -> Result<Self, OSErr> {
+        unsafe { AEDesc::create_with(|result| sys::AECreateList(ptr::null(), 0, 1, result)) }
+            .map(|desc| Self(AEDescList(desc)))
+    }
+
+    #[inline]
+    pub(crate) fn as_desc(&self) -> &AEDesc {
+        &(self.0).0
+    }
+
+    #[inline]
+    pub(crate) fn as_mut_desc(&mut self) -> &mut AEDesc {
+        &mut (self.0).0
+    }
+
+    /// Returns a copy of the descriptor for `keyword`, coerced to
+    /// `desired_type`.
+    ///
+    /// Pass [`AEDescType::WILDCARD`] to get the descriptor as-is. Fails with
+    /// [`OSErr::AE_DESC_NOT_FOUND`] if there is no descriptor for `keyword`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreservices/aegetparamdesc(_:_:_:_:)?language=objc).
+    #[inline]
+    #[doc(alias = "AEGetKeyDesc")]
+    #[doc(alias = "AEGetParamDesc")]
+    pub fn get_key(&self, keyword: AEKeyword, desired_type: AEDescType) -> Result<AEDesc, OSErr> {
+        unsafe {
+            AEDesc::create_with(|result| {
+                sys::AEGetParamDesc(self.as_desc(), keyword, desired_type, result)
+            })
+        }
+    }
+
+    /// Sets the descriptor for `keyword` to a copy of `desc`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreservices/aeputparamdesc(_:_:_:)?language=objc).
+    #[inline]
+    #[doc(alias = "AEPutKeyDesc")]
+    #[doc(alias = "AEPutParamDesc")]
+    pub fn set_key(&mut self, keyword: AEKeyword, desc: &AEDesc) -> Result<(), OSErr> {
+        check(unsafe { sys::AEPutParamDesc(self.as_mut_desc(), keyword, desc) })
+    }
+
+    /// Removes the descriptor for `keyword`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreservices/aedeleteparam(_:_:)?language=objc).
+    #[inline]
+    #[doc(alias = "AEDeleteKeyDesc")]
+    #[doc(alias = "AEDeleteParam")]
+    pub fn remove_key(&mut self, keyword: AEKeyword) -> Result<(), OSErr> {
+        check(unsafe { sys::AEDeleteParam(self.as_mut_desc(), keyword) })
+    }
+}
@@ -0,0 +1,357 @@
+use super::{keyword, keyword_name, AESerdeError};
+use crate::core_services::{AEDesc, AEDescList, AEDescType};
+use serde::de::{self, IntoDeserializer, Visitor};
+
+type Result<T> = std::result::Result<T, AESerdeError>;
+
+/// Deserializes values from a descriptor.
+pub(super) struct Deserializer<'a> {
+    desc: &'a AEDesc,
+}
+
+impl<'a> Deserializer<'a> {
+    #[inline]
+    pub(super) fn new(desc: &'a AEDesc) -> Self {
+        Self { desc }
+    }
+
+    /// Returns the data of the descriptor coerced to `ty`, which must be at
+    /// least `N` bytes.
+    fn bytes<const N: usize>(&self, ty: AEDescType) -> Result<[u8; N]> {
+        let data = self.desc.coerced_data(ty)?;
+        let mut bytes = [0; N];
+        bytes.copy_from_slice(data.get(..N).ok_or(crate::core::OSErr::AE_CORRUPT_DATA)?);
+        Ok(bytes)
+    }
+
+    /// Returns the name of an enumerated value or of a type or keyword.
+    fn code_name(&self) -> Result<String> {
+        Ok(keyword_name(self.bytes(self.desc.descriptor_type())?))
+    }
+
+    fn visit_list<'de, V: Visitor<'de>>(&self, visitor: V) -> Result<V::Value> {
+        let list = AEDescList(self.desc.coerce(AEDescType::AE_LIST)?);
+        let len = list.len()?;
+        visitor.visit_seq(ListAccess {
+            list,
+            index: 0,
+            len,
+        })
+    }
+
+    fn visit_record<'de, V: Visitor<'de>>(&self, visitor: V) -> Result<V::Value> {
+        let record = AEDescList(self.desc.coerce(AEDescType::AE_RECORD)?);
+        visit_record(record, visitor)
+    }
+}
+
+fn visit_record<'de, V: Visitor<'de>>(record: AEDescList, visitor: V) -> Result<V::Value> {
+    let len = record.len()?;
+    visitor.visit_map(RecordAccess {
+        record,
+        index: 0,
+        len,
+        value: None,
+    })
+}
+
+macro_rules! deserialize_number {
+    ($($method:ident => $visit:ident, $ty:ident, $desc_type:ident;)+) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+                visitor.$visit($ty::from_ne_bytes(self.bytes(AEDescType::$desc_type)?))
+            }
+        )+
+    };
+}
+
+impl<'de> de::Deserializer<'de> for Deserializer<'_> {
+    type Error = AESerdeError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match self.desc.descriptor_type() {
+            AEDescType::NULL => visitor.visit_unit(),
+            AEDescType::BOOL | AEDescType::TRUE | AEDescType::FALSE => {
+                self.deserialize_bool(visitor)
+            }
+            AEDescType::I16 => self.deserialize_i16(visitor),
+            AEDescType::I32 => self.deserialize_i32(visitor),
+            AEDescType::I64 => self.deserialize_i64(visitor),
+            AEDescType::U16 => self.deserialize_u16(visitor),
+            AEDescType::U32 => self.deserialize_u32(visitor),
+            AEDescType::U64 => self.deserialize_u64(visitor),
+            AEDescType::F32 => self.deserialize_f32(visitor),
+            AEDescType::F64 => self.deserialize_f64(visitor),
+            #[allow(deprecated)]
+            AEDescType::UTF8
+            | AEDescType::UTF16
+            | AEDescType::UNICODE_TEXT
+            | AEDescType::CHAR
+            | AEDescType::STYLED_UNICODE_TEXT => self.deserialize_string(visitor),
+            AEDescType::ENUMERATED | AEDescType::TYPE | AEDescType::KEYWORD => {
+                visitor.visit_string(self.code_name()?)
+            }
+            AEDescType::AE_LIST => self.visit_list(visitor),
+            AEDescType::AE_RECORD => self.visit_record(visitor),
+
+            // Records of application-defined classes have their class as
+            // their type.
+            _ => match self.desc.coerce(AEDescType::AE_RECORD) {
+                Ok(record) => visit_record(AEDescList(record), visitor),
+                Err(_) => visitor_bytes(self.desc, visitor),
+            },
+        }
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_bool(self.desc.to_bool()?)
+    }
+
+    deserialize_number! {
+        deserialize_i16 => visit_i16, i16, I16;
+        deserialize_i32 => visit_i32, i32, I32;
+        deserialize_i64 => visit_i64, i64, I64;
+        deserialize_u16 => visit_u16, u16, U16;
+        deserialize_u32 => visit_u32, u32, U32;
+        deserialize_u64 => visit_u64, u64, U64;
+        deserialize_f32 => visit_f32, f32, F32;
+        deserialize_f64 => visit_f64, f64, F64;
+    }
+
+    fn deserialize_i8<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_i16(visitor)
+    }
+
+    fn deserialize_u8<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_u16(visitor)
+    }
+
+    fn deserialize_char<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_string(visitor)
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_string(visitor)
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_string(self.desc.to_utf8_string()?)
+    }
+
+    fn deserialize_bytes<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_byte_buf(visitor)
+    }
+
+    fn deserialize_byte_buf<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor_bytes(self.desc, visitor)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        if self.desc.is_null() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.visit_list(visitor)
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value> {
+        self.visit_list(visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value> {
+        self.visit_list(visitor)
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.visit_record(visitor)
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        self.visit_record(visitor)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        match self.desc.descriptor_type() {
+            AEDescType::ENUMERATED | AEDescType::TYPE | AEDescType::KEYWORD => {
+                visitor.visit_enum(self.code_name()?.into_deserializer())
+            }
+            AEDescType::AE_RECORD => {
+                let record = AEDescList(self.desc.duplicate()?);
+                if record.len()? != 1 {
+                    return Err(de::Error::custom(
+                        "enum variant records must have exactly one keyword",
+                    ));
+                }
+                let (keyword, value) = record.get_with_keyword(0, AEDescType::WILDCARD)?;
+                visitor.visit_enum(VariantAccess {
+                    name: keyword_name(keyword.into_chars()),
+                    value,
+                })
+            }
+            _ => visitor.visit_enum(self.desc.to_utf8_string()?.into_deserializer()),
+        }
+    }
+
+    fn deserialize_identifier<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_string(visitor)
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_unit()
+    }
+}
+
+fn visitor_bytes<'de, V: Visitor<'de>>(desc: &AEDesc, visitor: V) -> Result<V::Value> {
+    visitor.visit_byte_buf(desc.data()?)
+}
+
+/// Reads the items of a list.
+struct ListAccess {
+    list: AEDescList,
+    index: usize,
+    len: usize,
+}
+
+impl<'de> de::SeqAccess<'de> for ListAccess {
+    type Error = AESerdeError;
+
+    fn next_element_seed<T: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>> {
+        if self.index == self.len {
+            return Ok(None);
+        }
+
+        let item = self.list.get(self.index, AEDescType::WILDCARD)?;
+        self.index += 1;
+        seed.deserialize(Deserializer::new(&item)).map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.len - self.index)
+    }
+}
+
+/// Reads the keywords and values of a record.
+struct RecordAccess {
+    record: AEDescList,
+    index: usize,
+    len: usize,
+    /// The value for the keyword that was read last.
+    value: Option<AEDesc>,
+}
+
+impl<'de> de::MapAccess<'de> for RecordAccess {
+    type Error = AESerdeError;
+
+    fn next_key_seed<K: de::DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>> {
+        if self.index == self.len {
+            return Ok(None);
+        }
+
+        let (keyword, value) = self
+            .record
+            .get_with_keyword(self.index, AEDescType::WILDCARD)?;
+        self.index += 1;
+        self.value = Some(value);
+
+        let name = keyword_name(keyword.into_chars());
+        seed.deserialize(name.into_deserializer()).map(Some)
+    }
+
+    fn next_value_seed<V: de::DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value> {
+        match self.value.take() {
+            Some(value) => seed.deserialize(Deserializer::new(&value)),
+            None => Err(de::Error::custom("record value without keyword")),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.len - self.index)
+    }
+}
+
+/// Reads an enum variant from a record with the variant name as its only
+/// keyword.
+struct VariantAccess {
+    name: String,
+    value: AEDesc,
+}
+
+impl<'de> de::EnumAccess<'de> for VariantAccess {
+    type Error = AESerdeError;
+    type Variant = Self;
+
+    fn variant_seed<V: de::DeserializeSeed<'de>>(self, seed: V) -> Result<(V::Value, Self)> {
+        // Check that the name would have been written by the serializer.
+        keyword(&self.name)?;
+
+        let name: de::value::StrDeserializer<AESerdeError> = self.name.as_str().into_deserializer();
+        let variant = seed.deserialize(name)?;
+        Ok((variant, self))
+    }
+}
+
+impl<'de> de::VariantAccess<'de> for VariantAccess {
+    type Error = AESerdeError;
+
+    fn unit_variant(self) -> Result<()> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T: de::DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value> {
+        seed.deserialize(Deserializer::new(&self.value))
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value> {
+        Deserializer::new(&self.value).visit_list(visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        Deserializer::new(&self.value).visit_record(visitor)
+    }
+}
@@ -0,0 +1,119 @@
+use super::AEDesc;
+use crate::core::OSErr;
+use crate::core_services::AEKeyword;
+use serde::{de::DeserializeOwned, Serialize};
+use std::{error::Error, fmt};
+
+mod de;
+mod ser;
+
+/// An error from converting between Rust values and [`AEDesc`] descriptors
+/// with [`serde`](https://docs.rs/serde).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AESerdeError {
+    /// A descriptor could not be created, read, or coerced.
+    OSErr(OSErr),
+
+    /// A field, map key, or variant name is not exactly 4 bytes, and so
+    /// cannot be used as an [`AEKeyword`].
+    InvalidKeyword(String),
+
+    /// An error reported by a `Serialize` or `Deserialize` implementation.
+    Custom(String),
+}
+
+impl From<OSErr> for AESerdeError {
+    #[inline]
+    fn from(error: OSErr) -> Self {
+        Self::OSErr(error)
+    }
+}
+
+impl fmt::Display for AESerdeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::OSErr(error) => write!(f, "Apple event descriptor error {:?}", error),
+            Self::InvalidKeyword(name) => {
+                write!(f, "{:?} is not a 4-byte Apple event keyword", name)
+            }
+            Self::Custom(message) => f.write_str(message),
+        }
+    }
+}
+
+impl Error for AESerdeError {}
+
+impl serde::ser::Error for AESerdeError {
+    #[inline]
+    fn custom<T: fmt::Display>(message: T) -> Self {
+        Self::Custom(message.to_string())
+    }
+}
+
+impl serde::de::Error for AESerdeError {
+    #[inline]
+    fn custom<T: fmt::Display>(message: T) -> Self {
+        Self::Custom(message.to_string())
+    }
+}
+
+/// Returns the keyword for a field, map key, or variant `name`.
+fn keyword(name: &str) -> Result<AEKeyword, AESerdeError> {
+    match name.as_bytes() {
+        &[a, b, c, d] => Ok(AEKeyword::from_chars([a, b, c, d])),
+        _ => Err(AESerdeError::InvalidKeyword(name.to_owned())),
+    }
+}
+
+/// Returns the name that [`keyword`] maps to `chars`.
+fn keyword_name(chars: [u8; 4]) -> String {
+    String::from_utf8_lossy(&chars).into_owned()
+}
+
+/// Conversions with [`serde`](https://docs.rs/serde), enabled by the
+/// **`serde`** feature flag.
+///
+/// Structs become records whose keywords are the field names, which must be
+/// exactly 4 bytes. Use `#[serde(rename = "pnam")]` to map a Rust field name
+/// to a scripting keyword. Maps with string keys are handled the same way.
+///
+/// Sequences and tuples become lists, and unit enum variants become
+/// [enumerated](super::AEDescType::ENUMERATED) values. Other enum variants
+/// become records with the variant name as their only keyword.
+impl AEDesc {
+    /// Creates a descriptor from a value, such as the parameters of an Apple
+    /// event.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fruity::core_services::AEDesc;
+    /// use serde::Serialize;
+    ///
+    /// #[derive(Serialize)]
+    /// struct Properties {
+    ///     #[serde(rename = "pnam")]
+    ///     name: String,
+    ///     #[serde(rename = "pidx")]
+    ///     index: i32,
+    /// }
+    ///
+    /// let desc = AEDesc::serialize(&Properties {
+    ///     name: "Notes".to_owned(),
+    ///     index: 1,
+    /// });
+    /// ```
+    #[inline]
+    pub fn serialize<T: Serialize + ?Sized>(value: &T) -> Result<Self, AESerdeError> {
+        value.serialize(ser::Serializer)
+    }
+
+    /// Creates a value from `self`, such as the reply of an Apple event.
+    ///
+    /// Values are [coerced](Self::coerce) to the types expected by `T` where
+    /// possible. For example, a number may be read from text.
+    #[inline]
+    pub fn deserialize<T: DeserializeOwned>(&self) -> Result<T, AESerdeError> {
+        T::deserialize(de::Deserializer::new(self))
+    }
+}
@@ -0,0 +1,349 @@
+use super::{keyword, AESerdeError};
+use crate::core_services::{AEDesc, AEDescList, AEDescType, AEKeyword, AERecord, IntoAEDesc};
+use serde::ser::{self, Serialize};
+
+type Result<T> = std::result::Result<T, AESerdeError>;
+
+/// Serializes values into descriptors.
+pub(super) struct Serializer;
+
+/// Creates a record containing only `desc` for `keyword`.
+fn single_key_record(keyword: AEKeyword, desc: &AEDesc) -> Result<AEDesc> {
+    let mut record = AERecord::new()?;
+    record.set_key(keyword, desc)?;
+    Ok(record.into_ae_desc()?)
+}
+
+impl ser::Serializer for Serializer {
+    type Ok = AEDesc;
+    type Error = AESerdeError;
+
+    type SerializeSeq = SerializeList;
+    type SerializeTuple = SerializeList;
+    type SerializeTupleStruct = SerializeList;
+    type SerializeTupleVariant = SerializeList;
+    type SerializeMap = SerializeRecord;
+    type SerializeStruct = SerializeRecord;
+    type SerializeStructVariant = SerializeRecord;
+
+    fn serialize_bool(self, v: bool) -> Result<AEDesc> {
+        Ok(AEDesc::from_bool(v)?)
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<AEDesc> {
+        self.serialize_i16(v.into())
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<AEDesc> {
+        Ok(AEDesc::new(AEDescType::I16, &v.to_ne_bytes())?)
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<AEDesc> {
+        Ok(AEDesc::from_i32(v)?)
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<AEDesc> {
+        Ok(AEDesc::new(AEDescType::I64, &v.to_ne_bytes())?)
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<AEDesc> {
+        self.serialize_u16(v.into())
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<AEDesc> {
+        Ok(AEDesc::new(AEDescType::U16, &v.to_ne_bytes())?)
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<AEDesc> {
+        Ok(AEDesc::new(AEDescType::U32, &v.to_ne_bytes())?)
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<AEDesc> {
+        Ok(AEDesc::new(AEDescType::U64, &v.to_ne_bytes())?)
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<AEDesc> {
+        Ok(AEDesc::new(AEDescType::F32, &v.to_ne_bytes())?)
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<AEDesc> {
+        Ok(AEDesc::from_f64(v)?)
+    }
+
+    fn serialize_char(self, v: char) -> Result<AEDesc> {
+        self.serialize_str(v.encode_utf8(&mut [0; 4]))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<AEDesc> {
+        Ok(AEDesc::from_utf8(v)?)
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<AEDesc> {
+        Ok(AEDesc::new(AEDescType::DATA, v)?)
+    }
+
+    fn serialize_none(self) -> Result<AEDesc> {
+        Ok(AEDesc::null())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<AEDesc> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<AEDesc> {
+        Ok(AEDesc::null())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<AEDesc> {
+        Ok(AEDesc::null())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<AEDesc> {
+        let code = keyword(variant)?.into_chars();
+        Ok(AEDesc::new(AEDescType::ENUMERATED, &code)?)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<AEDesc> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<AEDesc> {
+        single_key_record(keyword(variant)?, &value.serialize(self)?)
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<SerializeList> {
+        Ok(SerializeList {
+            list: AEDescList::new()?,
+            variant: None,
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SerializeList> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(self, _name: &'static str, len: usize) -> Result<SerializeList> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<SerializeList> {
+        Ok(SerializeList {
+            list: AEDescList::new()?,
+            variant: Some(keyword(variant)?),
+        })
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<SerializeRecord> {
+        Ok(SerializeRecord {
+            record: AERecord::new()?,
+            key: None,
+            variant: None,
+        })
+    }
+
+    fn serialize_struct(self, _name: &'static str, len: usize) -> Result<SerializeRecord> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<SerializeRecord> {
+        Ok(SerializeRecord {
+            record: AERecord::new()?,
+            key: None,
+            variant: Some(keyword(variant)?),
+        })
+    }
+}
+
+/// Serializes sequences, tuples, and tuple variants into lists.
+pub(super) struct SerializeList {
+    list: AEDescList,
+    /// The keyword of the enclosing record, for tuple variants.
+    variant: Option<AEKeyword>,
+}
+
+impl SerializeList {
+    fn push<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        self.list.push(&value.serialize(Serializer)?)?;
+        Ok(())
+    }
+
+    fn finish(self) -> Result<AEDesc> {
+        let list = self.list.into_ae_desc()?;
+        match self.variant {
+            Some(variant) => single_key_record(variant, &list),
+            None => Ok(list),
+        }
+    }
+}
+
+impl ser::SerializeSeq for SerializeList {
+    type Ok = AEDesc;
+    type Error = AESerdeError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<AEDesc> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTuple for SerializeList {
+    type Ok = AEDesc;
+    type Error = AESerdeError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<AEDesc> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleStruct for SerializeList {
+    type Ok = AEDesc;
+    type Error = AESerdeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<AEDesc> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleVariant for SerializeList {
+    type Ok = AEDesc;
+    type Error = AESerdeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<AEDesc> {
+        self.finish()
+    }
+}
+
+/// Serializes maps, structs, and struct variants into records.
+pub(super) struct SerializeRecord {
+    record: AERecord,
+    /// The keyword of the map entry whose value is next.
+    key: Option<AEKeyword>,
+    /// The keyword of the enclosing record, for struct variants.
+    variant: Option<AEKeyword>,
+}
+
+impl SerializeRecord {
+    fn set<T: Serialize + ?Sized>(&mut self, keyword: AEKeyword, value: &T) -> Result<()> {
+        self.record
+            .set_key(keyword, &value.serialize(Serializer)?)?;
+        Ok(())
+    }
+
+    fn finish(self) -> Result<AEDesc> {
+        let record = self.record.into_ae_desc()?;
+        match self.variant {
+            Some(variant) => single_key_record(variant, &record),
+            None => Ok(record),
+        }
+    }
+}
+
+impl ser::SerializeMap for SerializeRecord {
+    type Ok = AEDesc;
+    type Error = AESerdeError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<()> {
+        let key = key.serialize(Serializer)?;
+
+        // Accept both string keys and unit enum variants.
+        let name = match key.descriptor_type() {
+            AEDescType::ENUMERATED => String::from_utf8_lossy(&key.data()?).into_owned(),
+            AEDescType::UTF8 => key.to_utf8_string()?,
+            _ => {
+                return Err(ser::Error::custom(
+                    "record keys must be strings or unit enum variants",
+                ))
+            }
+        };
+
+        self.key = Some(keyword(&name)?);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        match self.key.take() {
+            Some(key) => self.set(key, value),
+            None => Err(ser::Error::custom("record value without key")),
+        }
+    }
+
+    fn end(self) -> Result<AEDesc> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStruct for SerializeRecord {
+    type Ok = AEDesc;
+    type Error = AESerdeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        self.set(keyword(key)?, value)
+    }
+
+    fn end(self) -> Result<AEDesc> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStructVariant for SerializeRecord {
+    type Ok = AEDesc;
+    type Error = AESerdeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        self.set(keyword(key)?, value)
+    }
+
+    fn end(self) -> Result<AEDesc> {
+        self.finish()
+    }
+}
@@ -147,7 +147,7 @@ impl AEDescType {
 
     /// Value: `true`.
     ///
-    /// Value: `TRUE` Boolean value.
+    /// `TRUE` Boolean value.
     ///
     /// See [documentation](https://developer.apple.com/documentation/coreservices/typetrue?language=objc).
     #[doc(alias = "typeTrue")]
@@ -304,7 +304,8 @@ impl AEDescType {
 
     /// Value: `psn `.
     ///
-    /// A process serial number. See also [`AEAddressDesc`].
+    /// A process serial number. See also
+    /// [`AEAddressDesc`](super::AEAddressDesc).
     ///
     /// See [documentation](https://developer.apple.com/documentation/coreservices/typeprocessserialnumber?language=objc).
     #[doc(alias = "typeProcessSerialNumber")]
@@ -331,6 +332,24 @@ impl AEDescType {
     /// See [documentation](https://developer.apple.com/documentation/coreservices/typeapplicationurl?language=objc).
     #[doc(alias = "typeApplicationURL")]
     pub const APP_URL: Self = Self::from_chars(*b"aprl");
+
+    /// Value: `bund`.
+    ///
+    /// An application by bundle identifier, as UTF-8 text. See also
+    /// [`AEAddressDesc`](super::AEAddressDesc).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreservices/typeapplicationbundleid?language=objc).
+    #[doc(alias = "typeApplicationBundleID")]
+    pub const APP_BUNDLE_ID: Self = Self::from_chars(*b"bund");
+
+    /// Value: `kpid`.
+    ///
+    /// A process by its kernel process ID (`pid_t`). See also
+    /// [`AEAddressDesc`](super::AEAddressDesc).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreservices/typekernelprocessid?language=objc).
+    #[doc(alias = "typeKernelProcessID")]
+    pub const KERNEL_PROCESS_ID: Self = Self::from_chars(*b"kpid");
 }
 
 /// Preferred unicode text types. In both cases, there is no explicit null
@@ -62,6 +62,16 @@ impl AEEventClass {
     /// See [documentation](https://developer.apple.com/documentation/coreservices/kcoreeventclass?language=objc).
     #[doc(alias = "kCoreEventClass")]
     pub const CORE: Self = Self::from_chars(*b"aevt");
+
+    /// Value: `misc`.
+    ///
+    /// Miscellaneous standard events, such as those understood by the Finder.
+    ///
+    /// See [`AEEventID` constants](struct.AEEventID.html#kAEMiscStandards).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreservices/kaemiscstandards?language=objc).
+    #[doc(alias = "kAEMiscStandards")]
+    pub const MISC_STANDARDS: Self = Self::from_chars(*b"misc");
 }
 
 /// Events in HIToolbox framework.
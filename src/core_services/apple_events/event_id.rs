@@ -122,4 +122,76 @@ impl AEEventID {
     /// See [documentation](https://developer.apple.com/documentation/coreservices/kaeshowpreferences?language=objc).
     #[doc(alias = "kAEShowPreferences")]
     pub const SHOW_PREFERENCES: Self = Self::from_chars(*b"pref");
+
+    /// Event sent when the application is reopened, such as when the user
+    /// clicks its Dock icon while it is already running.
+    ///
+    /// Value: `rapp`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreservices/kaereopenapplication?language=objc).
+    #[doc(alias = "kAEReopenApplication")]
+    pub const REOPEN_APPLICATION: Self = Self::from_chars(*b"rapp");
+}
+
+/// <span id="kInternetEventClass"></span>
+/// Event IDs for [`AEEventClass::INTERNET`](super::AEEventClass::INTERNET).
+impl AEEventID {
+    /// Event that asks the application to open a URL, such as one with a
+    /// custom scheme registered by the application.
+    ///
+    /// The URL is the direct object parameter of the event.
+    ///
+    /// Value: `GURL`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreservices/kaegeturl?language=objc).
+    #[doc(alias = "kAEGetURL")]
+    pub const GET_URL: Self = Self::from_chars(*b"GURL");
+}
+
+/// <span id="kAEMiscStandards"></span>
+/// Event IDs for
+/// [`AEEventClass::MISC_STANDARDS`](super::AEEventClass::MISC_STANDARDS).
+impl AEEventID {
+    /// Event that asks the target to make objects visible, such as revealing
+    /// files in the Finder.
+    ///
+    /// Value: `mvis`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreservices/kaemakeobjectsvisible?language=objc).
+    #[doc(alias = "kAEMakeObjectsVisible")]
+    pub const MAKE_OBJECTS_VISIBLE: Self = Self::from_chars(*b"mvis");
+
+    /// Event that asks the target to activate itself.
+    ///
+    /// Value: `actv`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreservices/kaeactivate?language=objc).
+    #[doc(alias = "kAEActivate")]
+    pub const ACTIVATE: Self = Self::from_chars(*b"actv");
+
+    /// Event that asks the target to start a transaction, replying with its
+    /// [`AETransactionID`](super::AETransactionID).
+    ///
+    /// Value: `begi`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreservices/kaebegintransaction?language=objc).
+    #[doc(alias = "kAEBeginTransaction")]
+    pub const BEGIN_TRANSACTION: Self = Self::from_chars(*b"begi");
+
+    /// Event that asks the target to end the transaction that it is part of.
+    ///
+    /// Value: `endt`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreservices/kaeendtransaction?language=objc).
+    #[doc(alias = "kAEEndTransaction")]
+    pub const END_TRANSACTION: Self = Self::from_chars(*b"endt");
+
+    /// Event that a target sends to tell the client that it ended a
+    /// transaction on its own.
+    ///
+    /// Value: `ttrm`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreservices/kaetransactionterminated?language=objc).
+    #[doc(alias = "kAETransactionTerminated")]
+    pub const TRANSACTION_TERMINATED: Self = Self::from_chars(*b"ttrm");
 }
@@ -0,0 +1,248 @@
+use super::{check, AEDesc, AEDescType, AEEventClass, AEEventID, AEKeyword, AppleEvent};
+use crate::core::OSErr;
+use crate::core_services::sys;
+use std::{ffi::c_void, fmt, panic, process, ptr};
+
+/// A callback invoked by the Apple Event Manager to handle an Apple event.
+///
+/// See [documentation](https://developer.apple.com/documentation/coreservices/aeeventhandlerprocptr?language=objc).
+pub type AEEventHandlerProcPtr = unsafe extern "C" fn(
+    event: *const AppleEvent,
+    reply: *mut AppleEvent,
+    refcon: *mut c_void,
+) -> Option<OSErr>;
+
+/// The reply to an Apple event being handled by an [`AEEventHandler`].
+pub struct AEReply<'a> {
+    // Owned by the Apple Event Manager, so it must never be moved out of or
+    // dropped.
+    reply: &'a mut AEDesc,
+}
+
+impl fmt::Debug for AEReply<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("AEReply").field(&*self.reply).finish()
+    }
+}
+
+impl AEReply<'_> {
+    /// Returns `true` if the sender of the event expects a reply.
+    #[inline]
+    pub fn is_expected(&self) -> bool {
+        !self.reply.is_null()
+    }
+
+    /// Sets the reply parameter for `keyword` to a copy of `desc`.
+    ///
+    /// The result of handling an event is typically stored as
+    /// [`AEKeyword::DIRECT_OBJECT`].
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreservices/aeputparamdesc(_:_:_:)?language=objc).
+    #[inline]
+    #[doc(alias = "AEPutParamDesc")]
+    pub fn set_param(&mut self, keyword: AEKeyword, desc: &AEDesc) -> Result<(), OSErr> {
+        if !self.is_expected() {
+            return Ok(());
+        }
+        check(unsafe { sys::AEPutParamDesc(self.reply, keyword, desc) })
+    }
+}
+
+/// A Rust closure installed as the handler for an event class and event ID
+/// pair.
+///
+/// The handler is removed when this is dropped, unless another handler has
+/// since been installed for the same pair. To keep the handler installed for
+/// the rest of the program, pass this to [`std::mem::forget`].
+///
+/// Handlers are only called while the application processes Apple events,
+/// which `NSApplication` does as part of its main event loop.
+///
+/// See [documentation](https://developer.apple.com/documentation/coreservices/aeinstalleventhandler(_:_:_:_:_:)?language=objc).
+pub struct AEEventHandler {
+    event_class: AEEventClass,
+    event_id: AEEventID,
+    proc_ptr: AEEventHandlerProcPtr,
+    refcon: *mut c_void,
+    drop_refcon: unsafe fn(*mut c_void),
+}
+
+impl fmt::Debug for AEEventHandler {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AEEventHandler")
+            .field("event_class", &self.event_class)
+            .field("event_id", &self.event_id)
+            .finish()
+    }
+}
+
+// SAFETY: The closure is required to be `Send`, and the Apple Event Manager
+// tables are process-wide.
+unsafe impl Send for AEEventHandler {}
+
+impl Drop for AEEventHandler {
+    fn drop(&mut self) {
+        unsafe {
+            let mut proc_ptr: Option<AEEventHandlerProcPtr> = None;
+            let mut refcon = ptr::null_mut();
+
+            let installed = sys::AEGetEventHandler(
+                self.event_class,
+                self.event_id,
+                &mut proc_ptr,
+                &mut refcon,
+                0,
+            );
+
+            // Leave newer handlers in place.
+            if installed.is_none() && refcon == self.refcon {
+                sys::AERemoveEventHandler(self.event_class, self.event_id, self.proc_ptr, 0);
+            }
+
+            // Either way, the Apple Event Manager no longer references the
+            // closure.
+            (self.drop_refcon)(self.refcon);
+        }
+    }
+}
+
+unsafe fn drop_boxed<F>(refcon: *mut c_void) {
+    drop(Box::from_raw(refcon as *mut F));
+}
+
+impl AEEventHandler {
+    /// Installs `handler` for events matching `event_class` and `event_id`,
+    /// replacing any existing handler for that pair.
+    ///
+    /// If `handler` returns an error, the Apple Event Manager reports it to
+    /// the sender in the reply.
+    ///
+    /// It is safe to panic within `handler`. Panics will abort the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreservices/aeinstalleventhandler(_:_:_:_:_:)?language=objc).
+    #[doc(alias = "AEInstallEventHandler")]
+    pub fn install<F>(
+        event_class: AEEventClass,
+        event_id: AEEventID,
+        handler: F,
+    ) -> Result<Self, OSErr>
+    where
+        F: FnMut(&AppleEvent, &mut AEReply) -> Result<(), OSErr> + Send + 'static,
+    {
+        unsafe extern "C" fn wrapped_handler<F>(
+            event: *const AppleEvent,
+            reply: *mut AppleEvent,
+            refcon: *mut c_void,
+        ) -> Option<OSErr>
+        where
+            F: FnMut(&AppleEvent, &mut AEReply) -> Result<(), OSErr> + Send + 'static,
+        {
+            let handler = &mut *(refcon as *mut F);
+            let event = &*event;
+            let mut reply = AEReply {
+                // `AppleEvent` is transparent over `AEDesc`.
+                reply: &mut *(reply as *mut AEDesc),
+            };
+
+            // Unwinding into the Apple Event Manager is undefined behavior.
+            match panic::catch_unwind(panic::AssertUnwindSafe(|| handler(event, &mut reply))) {
+                Ok(Ok(())) => None,
+                Ok(Err(error)) => Some(error),
+                Err(_) => process::abort(),
+            }
+        }
+
+        let refcon: *mut c_void = Box::into_raw(Box::new(handler)).cast();
+        let proc_ptr: AEEventHandlerProcPtr = wrapped_handler::<F>;
+
+        let result = check(unsafe {
+            sys::AEInstallEventHandler(event_class, event_id, proc_ptr, refcon, 0)
+        });
+
+        if let Err(error) = result {
+            unsafe { drop_boxed::<F>(refcon) };
+            return Err(error);
+        }
+
+        Ok(Self {
+            event_class,
+            event_id,
+            proc_ptr,
+            refcon,
+            drop_refcon: drop_boxed::<F>,
+        })
+    }
+
+    /// Installs `handler` for the
+    /// [`OPEN_DOCUMENTS`](AEEventID::OPEN_DOCUMENTS) event, which is sent with
+    /// the `file://` URLs of documents to open.
+    #[inline]
+    pub fn open_documents<F>(mut handler: F) -> Result<Self, OSErr>
+    where
+        F: FnMut(Vec<String>) + Send + 'static,
+    {
+        Self::install(
+            AEEventClass::CORE,
+            AEEventID::OPEN_DOCUMENTS,
+            move |event, _reply| {
+                let list = event.param(AEKeyword::DIRECT_OBJECT, AEDescType::AE_LIST)?;
+                let list = super::AEDescList(list);
+
+                let urls = (0..list.len()?)
+                    .map(|i| list.get(i, AEDescType::FILE_URL)?.to_utf8_string())
+                    .collect::<Result<Vec<String>, OSErr>>()?;
+
+                handler(urls);
+                Ok(())
+            },
+        )
+    }
+
+    /// Installs `handler` for the
+    /// [`REOPEN_APPLICATION`](AEEventID::REOPEN_APPLICATION) event.
+    #[inline]
+    pub fn reopen_application<F>(mut handler: F) -> Result<Self, OSErr>
+    where
+        F: FnMut() + Send + 'static,
+    {
+        Self::install(
+            AEEventClass::CORE,
+            AEEventID::REOPEN_APPLICATION,
+            move |_event, _reply| {
+                handler();
+                Ok(())
+            },
+        )
+    }
+
+    /// Installs `handler` for the [`GET_URL`](AEEventID::GET_URL) event, which
+    /// is sent with URLs whose scheme the application is registered to
+    /// handle.
+    #[inline]
+    pub fn get_url<F>(mut handler: F) -> Result<Self, OSErr>
+    where
+        F: FnMut(String) + Send + 'static,
+    {
+        Self::install(
+            AEEventClass::INTERNET,
+            AEEventID::GET_URL,
+            move |event, _reply| {
+                let url = event.param(AEKeyword::DIRECT_OBJECT, AEDescType::UTF8)?;
+                handler(url.to_utf8_string()?);
+                Ok(())
+            },
+        )
+    }
+
+    /// Returns the event class handled by this handler.
+    #[inline]
+    pub fn event_class(&self) -> AEEventClass {
+        self.event_class
+    }
+
+    /// Returns the event ID handled by this handler.
+    #[inline]
+    pub fn event_id(&self) -> AEEventID {
+        self.event_id
+    }
+}
@@ -0,0 +1,130 @@
+use crate::core::FourCharCode;
+use std::fmt;
+
+/// Specifies the keyword of a parameter or attribute of an Apple event, or of
+/// an item in an [`AERecord`](super::AERecord).
+///
+/// See [documentation](https://developer.apple.com/documentation/coreservices/aekeyword?language=objc).
+#[repr(transparent)]
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct AEKeyword(pub FourCharCode);
+
+impl fmt::Debug for AEKeyword {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Format as escaped ASCII string.
+        self.0.fmt(f)
+    }
+}
+
+impl AEKeyword {
+    /// Returns an instance from the integer value.
+    #[inline]
+    pub const fn from_int(int: u32) -> Self {
+        Self(FourCharCode::from_int(int))
+    }
+
+    /// Returns an instance from the 4-character code.
+    #[inline]
+    pub const fn from_chars(chars: [u8; 4]) -> Self {
+        Self(FourCharCode::from_chars(chars))
+    }
+
+    /// Returns this keyword's integer value.
+    #[inline]
+    pub const fn into_int(self) -> u32 {
+        self.0.into_int()
+    }
+
+    /// Returns this keyword's 4-character code.
+    #[inline]
+    pub const fn into_chars(self) -> [u8; 4] {
+        self.0.into_chars()
+    }
+}
+
+/// Parameter keywords.
+impl AEKeyword {
+    /// Value: `----`.
+    ///
+    /// The direct object parameter of an Apple event.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreservices/keydirectobject?language=objc).
+    #[doc(alias = "keyDirectObject")]
+    pub const DIRECT_OBJECT: Self = Self::from_chars(*b"----");
+
+    /// Value: `errn`.
+    ///
+    /// The error number parameter of a reply Apple event.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreservices/keyerrornumber?language=objc).
+    #[doc(alias = "keyErrorNumber")]
+    pub const ERROR_NUMBER: Self = Self::from_chars(*b"errn");
+
+    /// Value: `errs`.
+    ///
+    /// The error message parameter of a reply Apple event.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreservices/keyerrorstring?language=objc).
+    #[doc(alias = "keyErrorString")]
+    pub const ERROR_STRING: Self = Self::from_chars(*b"errs");
+}
+
+/// Attribute keywords.
+impl AEKeyword {
+    /// Value: `evcl`.
+    ///
+    /// The [`AEEventClass`](super::AEEventClass) of an Apple event.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreservices/keyeventclassattr?language=objc).
+    #[doc(alias = "keyEventClassAttr")]
+    pub const EVENT_CLASS_ATTR: Self = Self::from_chars(*b"evcl");
+
+    /// Value: `evid`.
+    ///
+    /// The [`AEEventID`](super::AEEventID) of an Apple event.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreservices/keyeventidattr?language=objc).
+    #[doc(alias = "keyEventIDAttr")]
+    pub const EVENT_ID_ATTR: Self = Self::from_chars(*b"evid");
+
+    /// Value: `addr`.
+    ///
+    /// The target address of an Apple event.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreservices/keyaddressattr?language=objc).
+    #[doc(alias = "keyAddressAttr")]
+    pub const ADDRESS_ATTR: Self = Self::from_chars(*b"addr");
+
+    /// Value: `rtid`.
+    ///
+    /// The [`AEReturnID`](super::AEReturnID) of an Apple event.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreservices/keyreturnidattr?language=objc).
+    #[doc(alias = "keyReturnIDAttr")]
+    pub const RETURN_ID_ATTR: Self = Self::from_chars(*b"rtid");
+
+    /// Value: `tran`.
+    ///
+    /// The [`AETransactionID`](super::AETransactionID) of an Apple event.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreservices/keytransactionidattr?language=objc).
+    #[doc(alias = "keyTransactionIDAttr")]
+    pub const TRANSACTION_ID_ATTR: Self = Self::from_chars(*b"tran");
+
+    /// Value: `timo`.
+    ///
+    /// The number of ticks the sender is willing to wait for a reply.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreservices/keytimeoutattr?language=objc).
+    #[doc(alias = "keyTimeoutAttr")]
+    pub const TIMEOUT_ATTR: Self = Self::from_chars(*b"timo");
+
+    /// Value: `spid`.
+    ///
+    /// The process ID of the application that sent an Apple event.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreservices/keysenderpidattr?language=objc).
+    #[doc(alias = "keySenderPIDAttr")]
+    pub const SENDER_PID_ATTR: Self = Self::from_chars(*b"spid");
+}
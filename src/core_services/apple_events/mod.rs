@@ -1,15 +1,28 @@
+mod apple_event;
 mod data_storage;
 mod desc;
 mod event_class;
 mod event_id;
+mod handler;
+mod keyword;
+mod names;
 mod return_id;
+mod sdef;
 mod send_mode;
+mod timeout;
+mod transaction;
 mod transaction_id;
 
+pub use apple_event::*;
 pub use data_storage::*;
 pub use desc::*;
 pub use event_class::*;
 pub use event_id::*;
+pub use handler::*;
+pub use keyword::*;
 pub use return_id::*;
+pub use sdef::*;
 pub use send_mode::*;
+pub use timeout::*;
+pub use transaction::*;
 pub use transaction_id::*;
@@ -0,0 +1,369 @@
+//! Tables of the names that Apple's headers give the four-character codes
+//! defined in this module, for debugging descriptors received from other apps.
+
+use super::{AEDescType, AEEventClass, AEEventID, AEKeyword};
+
+#[allow(deprecated)]
+const EVENT_CLASSES: &[(AEEventClass, &str)] = &[
+    (AEEventClass::CORE, "kCoreEventClass"),
+    (AEEventClass::MISC_STANDARDS, "kAEMiscStandards"),
+    (AEEventClass::MOUSE, "kEventClassMouse"),
+    (AEEventClass::KEYBOARD, "kEventClassKeyboard"),
+    (AEEventClass::TEXT_INPUT, "kEventClassTextInput"),
+    (AEEventClass::APPLICATION, "kEventClassApplication"),
+    (AEEventClass::APPLE_EVENT, "kEventClassAppleEvent"),
+    (AEEventClass::MENU, "kEventClassMenu"),
+    (AEEventClass::WINDOW, "kEventClassWindow"),
+    (AEEventClass::CONTROL, "kEventClassControl"),
+    (AEEventClass::COMMAND, "kEventClassCommand"),
+    (AEEventClass::TABLET, "kEventClassTablet"),
+    (AEEventClass::VOLUME, "kEventClassVolume"),
+    (AEEventClass::APPEARANCE_MANAGER, "kEventClassAppearance"),
+    (AEEventClass::SERVICE, "kEventClassService"),
+    (AEEventClass::TOOLBAR, "kEventClassToolbar"),
+    (AEEventClass::TOOLBAR_ITEM, "kEventClassToolbarItem"),
+    (
+        AEEventClass::TOOLBAR_ITEM_VIEW,
+        "kEventClassToolbarItemView",
+    ),
+    (AEEventClass::ACCESSIBILITY, "kEventClassAccessibility"),
+    (AEEventClass::SYSTEM, "kEventClassSystem"),
+    (AEEventClass::INK, "kEventClassInk"),
+    (
+        AEEventClass::TSM_DOCUMENT_ACCESS,
+        "kEventClassTSMDocumentAccess",
+    ),
+    (AEEventClass::GESTURE, "kEventClassGesture"),
+    (AEEventClass::CLOCK_VIEW, "kEventClassClockView"),
+    (AEEventClass::TEXT_FIELD, "kEventClassTextField"),
+    (AEEventClass::HI_OBJECT, "kEventClassHIObject"),
+    (AEEventClass::DELEGATE, "kEventClassDelegate"),
+    (AEEventClass::SCROLLABLE, "kEventClassScrollable"),
+    (AEEventClass::HI_COMBO_BOX, "kEventClassHIComboBox"),
+    (AEEventClass::SEARCH_FIELD, "kEventClassSearchField"),
+    (AEEventClass::APPEARANCE, "kAppearanceEventClass"),
+    (AEEventClass::DATA_BROWSER, "kEventClassDataBrowser"),
+    (AEEventClass::INTERNET, "kInternetEventClass"),
+    (
+        AEEventClass::IC_EDIT_PREFERENCE,
+        "kICEditPreferenceEventClass",
+    ),
+    (AEEventClass::DIGI_HUB, "kDigiHubEventClass"),
+    (AEEventClass::FONT, "kEventClassFont"),
+    (AEEventClass::AB_PEOPLE_PICKER, "kEventClassABPeoplePicker"),
+];
+
+#[allow(deprecated)]
+const EVENT_IDS: &[(AEEventID, &str)] = &[
+    (AEEventID::OPEN_APPLICATION, "kAEOpenApplication"),
+    (AEEventID::OPEN_DOCUMENTS, "kAEOpenDocuments"),
+    (AEEventID::PRINT_DOCUMENTS, "kAEPrintDocuments"),
+    (AEEventID::OPEN_CONTENTS, "kAEOpenContents"),
+    (AEEventID::QUIT_APPLICATION, "kAEQuitApplication"),
+    (AEEventID::ANSWER, "kAEAnswer"),
+    (AEEventID::APPLICATION_DIED, "kAEApplicationDied"),
+    (AEEventID::SHOW_PREFERENCES, "kAEShowPreferences"),
+    (AEEventID::REOPEN_APPLICATION, "kAEReopenApplication"),
+    (AEEventID::GET_URL, "kAEGetURL"),
+    (AEEventID::MAKE_OBJECTS_VISIBLE, "kAEMakeObjectsVisible"),
+    (AEEventID::ACTIVATE, "kAEActivate"),
+    (AEEventID::BEGIN_TRANSACTION, "kAEBeginTransaction"),
+    (AEEventID::END_TRANSACTION, "kAEEndTransaction"),
+    (AEEventID::TRANSACTION_TERMINATED, "kAETransactionTerminated"),
+];
+
+#[allow(deprecated)]
+const KEYWORDS: &[(AEKeyword, &str)] = &[
+    (AEKeyword::DIRECT_OBJECT, "keyDirectObject"),
+    (AEKeyword::ERROR_NUMBER, "keyErrorNumber"),
+    (AEKeyword::ERROR_STRING, "keyErrorString"),
+    (AEKeyword::EVENT_CLASS_ATTR, "keyEventClassAttr"),
+    (AEKeyword::EVENT_ID_ATTR, "keyEventIDAttr"),
+    (AEKeyword::ADDRESS_ATTR, "keyAddressAttr"),
+    (AEKeyword::RETURN_ID_ATTR, "keyReturnIDAttr"),
+    (AEKeyword::TRANSACTION_ID_ATTR, "keyTransactionIDAttr"),
+    (AEKeyword::TIMEOUT_ATTR, "keyTimeoutAttr"),
+    (AEKeyword::SENDER_PID_ATTR, "keySenderPIDAttr"),
+];
+
+#[allow(deprecated)]
+const DESC_TYPES: &[(AEDescType, &str)] = &[
+    (AEDescType::I16, "typeSInt16"),
+    (AEDescType::U16, "typeUInt16"),
+    (AEDescType::I32, "typeSInt32"),
+    (AEDescType::U32, "typeUInt32"),
+    (AEDescType::I64, "typeSInt64"),
+    (AEDescType::U64, "typeUInt64"),
+    (AEDescType::F32, "typeIEEE32BitFloatingPoint"),
+    (AEDescType::F64, "typeIEEE64BitFloatingPoint"),
+    (AEDescType::F128, "type128BitFloatingPoint"),
+    (AEDescType::DECIMAL, "typeDecimalStruct"),
+    (AEDescType::WILDCARD, "typeWildCard"),
+    (AEDescType::BOOL, "typeBoolean"),
+    (AEDescType::TRUE, "typeTrue"),
+    (AEDescType::FALSE, "typeFalse"),
+    (AEDescType::NULL, "typeNull"),
+    (AEDescType::AE_LIST, "typeAEList"),
+    (AEDescType::AE_RECORD, "typeAERecord"),
+    (AEDescType::APPLE_EVENT, "typeAppleEvent"),
+    (AEDescType::EVENT_RECORD, "typeEventRecord"),
+    (AEDescType::ALIAS, "typeAlias"),
+    (AEDescType::ENUMERATED, "typeEnumerated"),
+    (AEDescType::TYPE, "typeType"),
+    (AEDescType::APP_PARAMETERS, "typeAppParameters"),
+    (AEDescType::PROPERTY, "typeProperty"),
+    (AEDescType::FS_REF, "typeFSRef"),
+    (AEDescType::BOOKMARK_DATA, "typeBookmarkData"),
+    (AEDescType::KEYWORD, "typeKeyword"),
+    (AEDescType::SECTION_HANDLE, "typeSectionH"),
+    (AEDescType::APPL_SIGNATURE, "typeApplSignature"),
+    (AEDescType::QD_RECTANGLE, "typeQDRectangle"),
+    (AEDescType::FIXED, "typeFixed"),
+    (AEDescType::PROCESS_SERIAL_NUMBER, "typeProcessSerialNumber"),
+    (AEDescType::FILE_URL, "typeFileURL"),
+    (AEDescType::APP_URL, "typeApplicationURL"),
+    (AEDescType::APP_BUNDLE_ID, "typeApplicationBundleID"),
+    (AEDescType::KERNEL_PROCESS_ID, "typeKernelProcessID"),
+    (AEDescType::UTF16, "typeUTF16ExternalRepresentation"),
+    (AEDescType::UTF8, "typeUTF8Text"),
+    (AEDescType::CHAR, "typeChar"),
+    (AEDescType::STYLED_UNICODE_TEXT, "typeStyledUnicodeText"),
+    (AEDescType::ENCODED_STRING, "typeEncodedString"),
+    (AEDescType::UNICODE_TEXT, "typeUnicodeText"),
+    (AEDescType::C_STRING, "typeCString"),
+    (AEDescType::P_STRING, "typePString"),
+    (AEDescType::OBJECT_SPECIFIER, "typeObjectSpecifier"),
+    (AEDescType::OBJECT_BEING_EXAMINED, "typeObjectBeingExamined"),
+    (AEDescType::CURRENT_CONTAINER, "typeCurrentContainer"),
+    (AEDescType::TOKEN, "typeToken"),
+    (AEDescType::RELATIVE_DESCRIPTOR, "typeRelativeDescriptor"),
+    (AEDescType::ABSOLUTE_ORDINAL, "typeAbsoluteOrdinal"),
+    (AEDescType::INDEX_DESCRIPTOR, "typeIndexDescriptor"),
+    (AEDescType::RANGE_DESCRIPTOR, "typeRangeDescriptor"),
+    (AEDescType::LOGICAL_DESCRIPTOR, "typeLogicalDescriptor"),
+    (AEDescType::COMP_DESCRIPTOR, "typeCompDescriptor"),
+    (AEDescType::OSL_TOKEN_LIST, "typeOSLTokenList"),
+    (AEDescType::AE_TEXT, "typeAEText"),
+    (AEDescType::ARC, "typeArc"),
+    (AEDescType::BEST, "typeBest"),
+    (AEDescType::CELL, "typeCell"),
+    (AEDescType::CLASS_INFO, "typeClassInfo"),
+    (AEDescType::COLOR_TABLE, "typeColorTable"),
+    (AEDescType::COLUMN, "typeColumn"),
+    (AEDescType::DASH_STYLE, "typeDashStyle"),
+    (AEDescType::DATA, "typeData"),
+    (AEDescType::DRAWING_AREA, "typeDrawingArea"),
+    (AEDescType::ELEM_INFO, "typeElemInfo"),
+    (AEDescType::ENUMERATION, "typeEnumeration"),
+    (AEDescType::EPS, "typeEPS"),
+    (AEDescType::EVENT_INFO, "typeEventInfo"),
+    (AEDescType::FINDER_WINDOW, "typeFinderWindow"),
+    (AEDescType::FIXED_POINT, "typeFixedPoint"),
+    (AEDescType::FIXED_RECTANGLE, "typeFixedRectangle"),
+    (AEDescType::GRAPHIC_LINE, "typeGraphicLine"),
+    (AEDescType::GRAPHIC_TEXT, "typeGraphicText"),
+    (AEDescType::GROUPED_GRAPHIC, "typeGroupedGraphic"),
+    (AEDescType::INSERTION_LOC, "typeInsertionLoc"),
+    (AEDescType::INTL_TEXT, "typeIntlText"),
+    (AEDescType::INTL_WRITING_CODE, "typeIntlWritingCode"),
+    (AEDescType::LONG_DATE_TIME, "typeLongDateTime"),
+    (AEDescType::CF_ABSOLUTE_TIME, "typeCFAbsoluteTime"),
+    (AEDescType::ISO8601_DATE_TIME, "typeISO8601DateTime"),
+    (AEDescType::LONG_FIXED, "typeLongFixed"),
+    (AEDescType::LONG_FIXED_POINT, "typeLongFixedPoint"),
+    (AEDescType::LONG_FIXED_RECTANGLE, "typeLongFixedRectangle"),
+    (AEDescType::LONG_POINT, "typeLongPoint"),
+    (AEDescType::LONG_RECTANGLE, "typeLongRectangle"),
+    (AEDescType::MACHINE_LOC, "typeMachineLoc"),
+    (AEDescType::OVAL, "typeOval"),
+    (AEDescType::PARAM_INFO, "typeParamInfo"),
+    (AEDescType::PICT, "typePict"),
+    (AEDescType::PIXEL_MAP, "typePixelMap"),
+    (AEDescType::PIX_MAP_MINUS, "typePixMapMinus"),
+    (AEDescType::POLYGON, "typePolygon"),
+    (AEDescType::PROP_INFO, "typePropInfo"),
+    (AEDescType::PTR, "typePtr"),
+    (AEDescType::QD_POINT, "typeQDPoint"),
+    (AEDescType::QD_REGION, "typeQDRegion"),
+    (AEDescType::RECTANGLE, "typeRectangle"),
+    (AEDescType::RGB16, "typeRGB16"),
+    (AEDescType::RGB96, "typeRGB96"),
+    (AEDescType::RGB_COLOR, "typeRGBColor"),
+    (AEDescType::ROTATION, "typeRotation"),
+    (AEDescType::ROUNDED_RECTANGLE, "typeRoundedRectangle"),
+    (AEDescType::ROW, "typeRow"),
+    (AEDescType::SCRAP_STYLES, "typeScrapStyles"),
+    (AEDescType::SCRIPT, "typeScript"),
+    (AEDescType::STYLED_TEXT, "typeStyledText"),
+    (AEDescType::SUITE_INFO, "typeSuiteInfo"),
+    (AEDescType::TABLE, "typeTable"),
+    (AEDescType::TEXT_STYLES, "typeTextStyles"),
+    (AEDescType::TIFF, "typeTIFF"),
+    (AEDescType::JPEG, "typeJPEG"),
+    (AEDescType::GIF, "typeGIF"),
+    (AEDescType::VERSION, "typeVersion"),
+    (AEDescType::HI_MENU, "typeHIMenu"),
+    (AEDescType::HI_WINDOW, "typeHIWindow"),
+    (AEDescType::METERS, "typeMeters"),
+    (AEDescType::INCHES, "typeInches"),
+    (AEDescType::FEET, "typeFeet"),
+    (AEDescType::YARDS, "typeYards"),
+    (AEDescType::MILES, "typeMiles"),
+    (AEDescType::KILOMETERS, "typeKilometers"),
+    (AEDescType::CENTIMETERS, "typeCentimeters"),
+    (AEDescType::SQUARE_METERS, "typeSquareMeters"),
+    (AEDescType::SQUARE_FEET, "typeSquareFeet"),
+    (AEDescType::SQUARE_YARDS, "typeSquareYards"),
+    (AEDescType::SQUARE_MILES, "typeSquareMiles"),
+    (AEDescType::SQUARE_KILOMETERS, "typeSquareKilometers"),
+    (AEDescType::LITERS, "typeLiters"),
+    (AEDescType::QUARTS, "typeQuarts"),
+    (AEDescType::GALLONS, "typeGallons"),
+    (AEDescType::CUBIC_METERS, "typeCubicMeters"),
+    (AEDescType::CUBIC_FEET, "typeCubicFeet"),
+    (AEDescType::CUBIC_INCHES, "typeCubicInches"),
+    (AEDescType::CUBIC_CENTIMETER, "typeCubicCentimeter"),
+    (AEDescType::CUBIC_YARDS, "typeCubicYards"),
+    (AEDescType::KILOGRAMS, "typeKilograms"),
+    (AEDescType::GRAMS, "typeGrams"),
+    (AEDescType::OUNCES, "typeOunces"),
+    (AEDescType::POUNDS, "typePounds"),
+    (AEDescType::DEGREES_C, "typeDegreesC"),
+    (AEDescType::DEGREES_F, "typeDegreesF"),
+    (AEDescType::DEGREES_K, "typeDegreesK"),
+];
+
+impl AEEventClass {
+    /// Returns the name of the header constant with this value, such as
+    /// `"kCoreEventClass"`, or `None` if it is not defined by this crate.
+    pub fn name(self) -> Option<&'static str> {
+        EVENT_CLASSES
+            .iter()
+            .find(|&&(code, _)| code == self)
+            .map(|&(_, name)| name)
+    }
+}
+
+impl AEEventID {
+    /// Returns the name of the header constant with this value, such as
+    /// `"kAEOpenApplication"`, or `None` if it is not defined by this crate.
+    pub fn name(self) -> Option<&'static str> {
+        EVENT_IDS
+            .iter()
+            .find(|&&(code, _)| code == self)
+            .map(|&(_, name)| name)
+    }
+}
+
+impl AEKeyword {
+    /// Returns the name of the header constant with this value, such as
+    /// `"keyDirectObject"`, or `None` if it is not defined by this crate.
+    pub fn name(self) -> Option<&'static str> {
+        KEYWORDS
+            .iter()
+            .find(|&&(code, _)| code == self)
+            .map(|&(_, name)| name)
+    }
+}
+
+impl AEDescType {
+    /// Returns the name of the header constant with this value, such as
+    /// `"typeSInt16"`, or `None` if it is not defined by this crate.
+    ///
+    /// This is useful for debugging descriptors, whose codes are often
+    /// unreadable on their own.
+    pub fn name(self) -> Option<&'static str> {
+        DESC_TYPES
+            .iter()
+            .find(|&&(code, _)| code == self)
+            .map(|&(_, name)| name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the `(code, name)` pairs of the constants declared in `source`,
+    /// checking that each matches the `Value:` line of its documentation.
+    fn declared(source: &str) -> Vec<([u8; 4], &str)> {
+        let mut declared = Vec::new();
+        let mut value = None;
+        let mut name = None;
+
+        for line in source.lines().map(str::trim) {
+            if let Some(rest) = line.strip_prefix("/// Value: `") {
+                value = rest.get(..4);
+            } else if let Some(rest) = line.strip_prefix("#[doc(alias = \"") {
+                name = rest.strip_suffix("\")]");
+            } else if line.starts_with("pub const ") && line.contains(": Self =") {
+                let start = line.find("*b\"").expect("code is a byte string") + 3;
+                let code = &line[start..start + 4];
+                let name = name.take().expect("constant has a `doc(alias)`");
+
+                assert_eq!(value.take(), Some(code), "`Value:` line of {}", name);
+
+                let mut chars = [0; 4];
+                chars.copy_from_slice(code.as_bytes());
+                declared.push((chars, name));
+            }
+        }
+
+        declared
+    }
+
+    fn check(source: &str, table: &[(u32, &str)]) {
+        let declared = declared(source);
+        assert_eq!(declared.len(), table.len());
+
+        for (chars, name) in declared {
+            let code = u32::from_be_bytes(chars);
+            assert!(
+                table.contains(&(code, name)),
+                "{} ({:?}) is missing from the table",
+                name,
+                std::str::from_utf8(&chars),
+            );
+        }
+    }
+
+    #[test]
+    fn tables_match_declarations() {
+        check(
+            include_str!("event_class.rs"),
+            &EVENT_CLASSES
+                .iter()
+                .map(|&(c, n)| (c.into_int(), n))
+                .collect::<Vec<_>>(),
+        );
+        check(
+            include_str!("event_id.rs"),
+            &EVENT_IDS
+                .iter()
+                .map(|&(c, n)| (c.into_int(), n))
+                .collect::<Vec<_>>(),
+        );
+        check(
+            include_str!("keyword.rs"),
+            &KEYWORDS
+                .iter()
+                .map(|&(c, n)| (c.into_int(), n))
+                .collect::<Vec<_>>(),
+        );
+        check(
+            include_str!("desc/type_.rs"),
+            &DESC_TYPES
+                .iter()
+                .map(|&(c, n)| (c.into_int(), n))
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    #[test]
+    fn name() {
+        assert_eq!(AEEventClass::CORE.name(), Some("kCoreEventClass"));
+        assert_eq!(AEKeyword::DIRECT_OBJECT.name(), Some("keyDirectObject"));
+        assert_eq!(AEDescType::UTF8.name(), Some("typeUTF8Text"));
+        assert_eq!(AEDescType::from_chars(*b"????").name(), None);
+    }
+}
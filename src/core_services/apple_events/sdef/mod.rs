@@ -0,0 +1,728 @@
+use super::{
+    AEAddressDesc, AEDescType, AEEventClass, AEEventID, AEKeyword, AEReturnID, AETransactionID,
+    AppleEvent,
+};
+use crate::core::{Arc, OSErr, OSStatus};
+use crate::core_foundation::{CFData, CFURL};
+use crate::core_services::sys;
+use std::{
+    error::Error,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+    ptr,
+};
+
+mod xml;
+
+/// The terminology of a scriptable application, parsed from its scripting
+/// definition (`.sdef`) file.
+///
+/// This maps the human-readable names used in AppleScript to the codes used
+/// when building an [`AppleEvent`] directly.
+///
+/// # Examples
+///
+/// Ask Music to play by looking up its "play" command:
+///
+/// ```no_run
+/// use fruity::core_services::*;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let sdef = ScriptingDefinition::from_path("/System/Applications/Music.app/Contents/Resources/Music.sdef")?;
+/// let play = sdef.command("play").ok_or("missing play command")?;
+///
+/// let music = AEDesc::from_bundle_id("com.apple.Music")?;
+/// let event = play.apple_event(&music)?;
+/// event.send(AESendMode::AE_NO_REPLY, AETimeout::DEFAULT)?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// See [documentation](https://developer.apple.com/library/archive/documentation/Cocoa/Conceptual/ScriptingGuide/ScriptableCocoaApplications/SApps_creating_sdef.html).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ScriptingDefinition {
+    /// The suites of the definition, including those of included files.
+    pub suites: Vec<ScriptingSuite>,
+}
+
+/// A group of related terms within a [`ScriptingDefinition`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScriptingSuite {
+    /// The name of the suite, such as "Standard Suite".
+    pub name: String,
+
+    /// The code of the suite.
+    pub code: AEEventClass,
+
+    /// The commands and events of the suite.
+    pub commands: Vec<ScriptingCommand>,
+
+    /// The classes and record types of the suite.
+    pub classes: Vec<ScriptingClass>,
+
+    /// Properties added by the suite to classes that may be defined elsewhere.
+    pub class_extensions: Vec<ScriptingClassExtension>,
+
+    /// The enumerations of the suite.
+    pub enumerations: Vec<ScriptingEnumeration>,
+}
+
+/// A command or event, which is sent as an [`AppleEvent`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScriptingCommand {
+    /// The name of the command, such as "open".
+    pub name: String,
+
+    /// The first half of the command's 8-character code.
+    pub event_class: AEEventClass,
+
+    /// The second half of the command's 8-character code.
+    pub event_id: AEEventID,
+
+    /// The named parameters of the command.
+    ///
+    /// The direct parameter is always [`AEKeyword::DIRECT_OBJECT`] and is not
+    /// listed here.
+    pub parameters: Vec<ScriptingParameter>,
+}
+
+/// A named parameter of a [`ScriptingCommand`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScriptingParameter {
+    /// The name of the parameter, such as "with properties".
+    pub name: String,
+
+    /// The keyword of the parameter within the Apple event.
+    pub keyword: AEKeyword,
+
+    /// Whether the parameter may be omitted.
+    pub optional: bool,
+}
+
+/// A class or record type.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScriptingClass {
+    /// The name of the class, such as "document".
+    pub name: String,
+
+    /// The type code of the class.
+    pub code: AEDescType,
+
+    /// The properties declared by the class itself.
+    pub properties: Vec<ScriptingProperty>,
+}
+
+/// Properties added to an existing class.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScriptingClassExtension {
+    /// The name of the class being extended.
+    pub extends: String,
+
+    /// The properties added to the class.
+    pub properties: Vec<ScriptingProperty>,
+}
+
+/// A property of a [`ScriptingClass`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScriptingProperty {
+    /// The name of the property, such as "modified".
+    pub name: String,
+
+    /// The code of the property, used as the key of an object specifier.
+    pub code: AEKeyword,
+}
+
+/// A set of named constants.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScriptingEnumeration {
+    /// The name of the enumeration, such as "save options".
+    pub name: String,
+
+    /// The type code of the enumeration.
+    pub code: AEDescType,
+
+    /// The constants of the enumeration.
+    pub enumerators: Vec<ScriptingEnumerator>,
+}
+
+/// A constant of a [`ScriptingEnumeration`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScriptingEnumerator {
+    /// The name of the constant, such as "ask".
+    pub name: String,
+
+    /// The code sent as an [`AEDescType::ENUMERATED`] descriptor.
+    pub code: AEDescType,
+}
+
+/// An error from reading a [`ScriptingDefinition`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ScriptingDefinitionError {
+    /// A definition file could not be read.
+    Io {
+        /// The file that could not be read.
+        path: PathBuf,
+        /// The kind of error that occurred.
+        kind: io::ErrorKind,
+    },
+
+    /// The definition is not well-formed or is missing a required attribute.
+    Xml {
+        /// The 1-based line number at which the error occurred.
+        line: usize,
+        /// A description of the error.
+        message: &'static str,
+    },
+
+    /// A code is neither the required number of Mac OS Roman characters nor
+    /// a `0x`-prefixed hexadecimal number.
+    InvalidCode {
+        /// The 1-based line number of the element with the code.
+        line: usize,
+        /// The code as written in the definition.
+        code: String,
+    },
+
+    /// The definition of an application could not be copied.
+    OSStatus(OSStatus),
+}
+
+impl fmt::Display for ScriptingDefinitionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Io { path, kind } => {
+                write!(f, "could not read {}: {:?}", path.display(), kind)
+            }
+            Self::Xml { line, message } => write!(f, "line {}: {}", line, message),
+            Self::InvalidCode { line, code } => {
+                write!(f, "line {}: {:?} is not a valid code", line, code)
+            }
+            Self::OSStatus(status) => {
+                write!(f, "could not copy scripting definition: {:?}", status)
+            }
+        }
+    }
+}
+
+impl Error for ScriptingDefinitionError {}
+
+/// Reading a definition.
+impl ScriptingDefinition {
+    /// Parses the definition in `xml`.
+    ///
+    /// Any `xi:include` elements are ignored, since there is no file to
+    /// resolve them against. Use [`from_path`](Self::from_path) to include
+    /// their suites.
+    pub fn from_xml(xml: &str) -> Result<Self, ScriptingDefinitionError> {
+        let mut definition = Self::default();
+        definition.parse(xml, None, &mut Vec::new())?;
+        Ok(definition)
+    }
+
+    /// Reads and parses the definition file at `path`, along with any files
+    /// it includes.
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, ScriptingDefinitionError> {
+        let mut definition = Self::default();
+        definition.parse_file(path.as_ref(), &mut Vec::new())?;
+        Ok(definition)
+    }
+
+    /// Copies and parses the definition of the application or scripting
+    /// addition at `url`.
+    ///
+    /// This also works for applications that only have older `aete`
+    /// terminology resources, which are converted to the `.sdef` format.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreservices/1444097-osacopyscriptingdefinitionfromur?language=objc).
+    #[cfg(target_os = "macos")]
+    #[cfg_attr(docsrs, doc(cfg(target_os = "macos")))]
+    #[doc(alias = "OSACopyScriptingDefinitionFromURL")]
+    pub fn from_app(url: &CFURL) -> Result<Self, ScriptingDefinitionError> {
+        let data = unsafe {
+            let mut data = ptr::null();
+            let status = sys::OSACopyScriptingDefinitionFromURL(url, 0, &mut data);
+            if let Some(status) = OSStatus::new(status) {
+                return Err(ScriptingDefinitionError::OSStatus(status));
+            }
+            if data.is_null() {
+                return Err(ScriptingDefinitionError::OSStatus(OSStatus::from_os_err(
+                    OSErr::AE_CORRUPT_DATA,
+                )));
+            }
+            Arc::<CFData>::from_raw(data)
+        };
+
+        // The copied definition has no location, so only includes with
+        // absolute paths can be resolved.
+        let xml = String::from_utf8_lossy(data.as_bytes());
+        let mut definition = Self::default();
+        definition.parse(&xml, Some(Path::new("/")), &mut Vec::new())?;
+        Ok(definition)
+    }
+
+    fn parse_file(
+        &mut self,
+        path: &Path,
+        visited: &mut Vec<PathBuf>,
+    ) -> Result<(), ScriptingDefinitionError> {
+        // Definitions can include each other, so only read each file once.
+        if visited.iter().any(|visited| visited == path) {
+            return Ok(());
+        }
+        visited.push(path.to_path_buf());
+
+        let xml = fs::read_to_string(path).map_err(|error| ScriptingDefinitionError::Io {
+            path: path.to_path_buf(),
+            kind: error.kind(),
+        })?;
+        self.parse(&xml, path.parent(), visited)
+    }
+
+    /// Appends the suites in `xml` to `self`, resolving includes relative to
+    /// `base` if there is one.
+    fn parse(
+        &mut self,
+        xml: &str,
+        base: Option<&Path>,
+        visited: &mut Vec<PathBuf>,
+    ) -> Result<(), ScriptingDefinitionError> {
+        let mut reader = xml::Reader::new(xml);
+        let mut open = Vec::new();
+        let mut suite: Option<ScriptingSuite> = None;
+        let mut item: Option<(&str, Item)> = None;
+
+        while let Some(tag) = reader.next_tag()? {
+            let (name, attributes, is_empty) = match tag {
+                xml::Tag::Start {
+                    name,
+                    attributes,
+                    is_empty,
+                } => (name, attributes, is_empty),
+                xml::Tag::End { name } => {
+                    if open.pop() != Some(name) {
+                        return Err(reader.error("mismatched end tag"));
+                    }
+                    if item.as_ref().is_some_and(|(element, _)| *element == name) {
+                        if let (Some(suite), Some((_, item))) = (&mut suite, item.take()) {
+                            item.add_to(suite);
+                        }
+                    } else if name == "suite" {
+                        self.suites.extend(suite.take());
+                    }
+                    continue;
+                }
+            };
+
+            let attribute = |key: &str| {
+                attributes
+                    .iter()
+                    .find(|(k, _)| *k == key)
+                    .map(|(_, value)| value.as_str())
+            };
+            let required = |key: &str, message: &'static str| {
+                attribute(key).ok_or_else(|| reader.error(message))
+            };
+            let code = |message: &'static str| {
+                let code = required("code", message)?;
+                parse_code::<4>(code).ok_or_else(|| ScriptingDefinitionError::InvalidCode {
+                    line: reader.line(),
+                    code: code.to_string(),
+                })
+            };
+
+            let new_item = match (name, &mut item, suite.is_some()) {
+                ("suite", None, false) => {
+                    let suite_name = required("name", "suite is missing a name")?;
+                    let suite_code = code("suite is missing a code")?;
+                    suite = Some(ScriptingSuite {
+                        name: suite_name.to_string(),
+                        code: AEEventClass::from_chars(suite_code),
+                        commands: Vec::new(),
+                        classes: Vec::new(),
+                        class_extensions: Vec::new(),
+                        enumerations: Vec::new(),
+                    });
+                    None
+                }
+                ("command", None, true) | ("event", None, true) => {
+                    let command_name = required("name", "command is missing a name")?;
+                    let command_code = required("code", "command is missing a code")?;
+                    let command_code = parse_code::<8>(command_code).ok_or_else(|| {
+                        ScriptingDefinitionError::InvalidCode {
+                            line: reader.line(),
+                            code: command_code.to_string(),
+                        }
+                    })?;
+                    let [a, b, c, d, e, f, g, h] = command_code;
+                    Some(Item::Command(ScriptingCommand {
+                        name: command_name.to_string(),
+                        event_class: AEEventClass::from_chars([a, b, c, d]),
+                        event_id: AEEventID::from_chars([e, f, g, h]),
+                        parameters: Vec::new(),
+                    }))
+                }
+                ("parameter", Some((_, Item::Command(command))), _) => {
+                    let parameter_name = required("name", "parameter is missing a name")?;
+                    let parameter_code = code("parameter is missing a code")?;
+                    command.parameters.push(ScriptingParameter {
+                        name: parameter_name.to_string(),
+                        keyword: AEKeyword::from_chars(parameter_code),
+                        optional: attribute("optional") == Some("yes"),
+                    });
+                    None
+                }
+                ("class", None, true) | ("record-type", None, true) => {
+                    let class_name = required("name", "class is missing a name")?;
+                    let class_code = code("class is missing a code")?;
+                    Some(Item::Class(ScriptingClass {
+                        name: class_name.to_string(),
+                        code: AEDescType::from_chars(class_code),
+                        properties: Vec::new(),
+                    }))
+                }
+                ("class-extension", None, true) => {
+                    let extends = required("extends", "class extension is missing a class")?;
+                    Some(Item::ClassExtension(ScriptingClassExtension {
+                        extends: extends.to_string(),
+                        properties: Vec::new(),
+                    }))
+                }
+                ("property", Some((_, Item::Class(ScriptingClass { properties, .. }))), _)
+                | (
+                    "property",
+                    Some((_, Item::ClassExtension(ScriptingClassExtension { properties, .. }))),
+                    _,
+                ) => {
+                    let property_name = required("name", "property is missing a name")?;
+                    let property_code = code("property is missing a code")?;
+                    properties.push(ScriptingProperty {
+                        name: property_name.to_string(),
+                        code: AEKeyword::from_chars(property_code),
+                    });
+                    None
+                }
+                ("enumeration", None, true) => {
+                    let enumeration_name = required("name", "enumeration is missing a name")?;
+                    let enumeration_code = code("enumeration is missing a code")?;
+                    Some(Item::Enumeration(ScriptingEnumeration {
+                        name: enumeration_name.to_string(),
+                        code: AEDescType::from_chars(enumeration_code),
+                        enumerators: Vec::new(),
+                    }))
+                }
+                ("enumerator", Some((_, Item::Enumeration(enumeration))), _) => {
+                    let enumerator_name = required("name", "enumerator is missing a name")?;
+                    let enumerator_code = code("enumerator is missing a code")?;
+                    enumeration.enumerators.push(ScriptingEnumerator {
+                        name: enumerator_name.to_string(),
+                        code: AEDescType::from_chars(enumerator_code),
+                    });
+                    None
+                }
+                ("xi:include", _, _) => {
+                    let href = required("href", "include is missing an href")?;
+                    if let Some(path) = base.and_then(|base| include_path(base, href)) {
+                        self.parse_file(&path, visited)?;
+                    }
+                    None
+                }
+                _ => None,
+            };
+
+            match (new_item, &mut suite) {
+                // Empty items have no children, so they are complete.
+                (Some(new_item), Some(suite)) if is_empty => new_item.add_to(suite),
+                (Some(new_item), _) => item = Some((name, new_item)),
+                (None, _) => {}
+            }
+            if !is_empty {
+                open.push(name);
+            } else if name == "suite" {
+                self.suites.extend(suite.take());
+            }
+        }
+
+        if !open.is_empty() {
+            return Err(reader.error("unexpected end of document"));
+        }
+        Ok(())
+    }
+}
+
+/// A term of a [`ScriptingSuite`] whose children are being parsed.
+enum Item {
+    Command(ScriptingCommand),
+    Class(ScriptingClass),
+    ClassExtension(ScriptingClassExtension),
+    Enumeration(ScriptingEnumeration),
+}
+
+impl Item {
+    fn add_to(self, suite: &mut ScriptingSuite) {
+        match self {
+            Self::Command(command) => suite.commands.push(command),
+            Self::Class(class) => suite.classes.push(class),
+            Self::ClassExtension(extension) => suite.class_extensions.push(extension),
+            Self::Enumeration(enumeration) => suite.enumerations.push(enumeration),
+        }
+    }
+}
+
+/// Looking up terms by name.
+impl ScriptingDefinition {
+    /// Returns the first command or event named `name`.
+    pub fn command(&self, name: &str) -> Option<&ScriptingCommand> {
+        self.suites
+            .iter()
+            .flat_map(|suite| &suite.commands)
+            .find(|command| command.name == name)
+    }
+
+    /// Returns the first class or record type named `name`.
+    pub fn class(&self, name: &str) -> Option<&ScriptingClass> {
+        self.suites
+            .iter()
+            .flat_map(|suite| &suite.classes)
+            .find(|class| class.name == name)
+    }
+
+    /// Returns the first property named `name` of any class or class
+    /// extension.
+    ///
+    /// Properties with the same name share the same code across classes.
+    pub fn property(&self, name: &str) -> Option<&ScriptingProperty> {
+        self.suites
+            .iter()
+            .flat_map(|suite| {
+                let classes = suite.classes.iter().map(|class| &class.properties);
+                let extensions = suite.class_extensions.iter().map(|ext| &ext.properties);
+                classes.chain(extensions).flatten()
+            })
+            .find(|property| property.name == name)
+    }
+
+    /// Returns the first enumeration named `name`.
+    pub fn enumeration(&self, name: &str) -> Option<&ScriptingEnumeration> {
+        self.suites
+            .iter()
+            .flat_map(|suite| &suite.enumerations)
+            .find(|enumeration| enumeration.name == name)
+    }
+
+    /// Returns the first constant named `name` of any enumeration.
+    pub fn enumerator(&self, name: &str) -> Option<&ScriptingEnumerator> {
+        self.suites
+            .iter()
+            .flat_map(|suite| &suite.enumerations)
+            .flat_map(|enumeration| &enumeration.enumerators)
+            .find(|enumerator| enumerator.name == name)
+    }
+}
+
+impl ScriptingCommand {
+    /// Returns the parameter named `name`.
+    pub fn parameter(&self, name: &str) -> Option<&ScriptingParameter> {
+        self.parameters
+            .iter()
+            .find(|parameter| parameter.name == name)
+    }
+
+    /// Creates an Apple event for this command addressed to `target`.
+    ///
+    /// Parameters can then be set with [`AppleEvent::set_param`].
+    #[inline]
+    pub fn apple_event(&self, target: &AEAddressDesc) -> Result<AppleEvent, OSErr> {
+        AppleEvent::new(
+            self.event_class,
+            self.event_id,
+            target,
+            AEReturnID::AUTO_GENERATE,
+            AETransactionID::ANY,
+        )
+    }
+}
+
+/// The characters 0x80 through 0xFF of the Mac OS Roman encoding, which codes
+/// are written in.
+const MAC_ROMAN_HIGH: &str = "ÄÅÇÉÑÖÜáàâäãåçéèêëíìîïñóòôöõúùûü\
+                              †°¢£§•¶ß®©™´¨≠ÆØ∞±≤≥¥µ∂∑∏π∫ªºΩæø\
+                              ¿¡¬√ƒ≈∆«»…\u{A0}ÀÃÕŒœ–—“”‘’÷◊ÿŸ⁄€‹›ﬁﬂ\
+                              ‡·‚„‰ÂÊÁËÈÍÎÏÌÓÔ\u{F8FF}ÒÚÛÙıˆ˜¯˘˙˚¸˝˛ˇ";
+
+/// Parses a code of `N` Mac OS Roman characters or a `0x`-prefixed
+/// hexadecimal number of `N` bytes.
+fn parse_code<const N: usize>(code: &str) -> Option<[u8; N]> {
+    let mut bytes = [0; N];
+
+    if let Some(hex) = code.strip_prefix("0x").or_else(|| code.strip_prefix("0X")) {
+        if hex.len() != N * 2 || !hex.is_ascii() {
+            return None;
+        }
+        for (byte, digits) in bytes.iter_mut().zip(hex.as_bytes().chunks(2)) {
+            let digits = std::str::from_utf8(digits).ok()?;
+            *byte = u8::from_str_radix(digits, 16).ok()?;
+        }
+        return Some(bytes);
+    }
+
+    let mut chars = code.chars();
+    for byte in bytes.iter_mut() {
+        let c = chars.next()?;
+        *byte = if c.is_ascii() {
+            c as u8
+        } else {
+            let index = MAC_ROMAN_HIGH.chars().position(|high| high == c)?;
+            0x80 + index as u8
+        };
+    }
+
+    if chars.next().is_some() {
+        None
+    } else {
+        Some(bytes)
+    }
+}
+
+/// Returns the file referenced by the `href` of an `xi:include` element.
+fn include_path(base: &Path, href: &str) -> Option<PathBuf> {
+    let path = match href.strip_prefix("file://") {
+        // Only local files can be included.
+        Some(url) => url.strip_prefix("localhost").unwrap_or(url),
+        None if href.contains("://") => return None,
+        None => href,
+    };
+
+    // Strip any fragment identifier, then decode percent escapes.
+    let path = path.split('#').next()?;
+    let mut decoded = Vec::with_capacity(path.len());
+    let mut bytes = path.bytes();
+    while let Some(byte) = bytes.next() {
+        if byte == b'%' {
+            let digits = [bytes.next()?, bytes.next()?];
+            let digits = std::str::from_utf8(&digits).ok()?;
+            decoded.push(u8::from_str_radix(digits, 16).ok()?);
+        } else {
+            decoded.push(byte);
+        }
+    }
+
+    Some(base.join(String::from_utf8(decoded).ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SDEF: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE dictionary SYSTEM "file://localhost/System/Library/DTDs/sdef.dtd">
+<dictionary title="Test Terminology">
+    <!-- The <suite> below is all that matters. -->
+    <suite name="Test Suite" code="tEst">
+        <command name="save" code="coresave">
+            <direct-parameter type="specifier"/>
+            <parameter name="in" code="kfil" type="file" optional="yes"/>
+            <parameter name="as" code="fltp" type="text"/>
+        </command>
+        <event name="idle" code="0x6D69736369646C65"/>
+        <class name="document" code="docu" plural="documents">
+            <property name="modified" code="imod" type="boolean"/>
+        </class>
+        <class-extension extends="application">
+            <property name="front&#x20;most" code="pisf" type="boolean"/>
+        </class-extension>
+        <enumeration name="save options" code="savo">
+            <enumerator name="yes" code="yes "/>
+            <enumerator name="ask" code="ask&amp;"/>
+        </enumeration>
+    </suite>
+</dictionary>
+"#;
+
+    #[test]
+    fn parse() {
+        let sdef = ScriptingDefinition::from_xml(SDEF).unwrap();
+        assert_eq!(sdef.suites.len(), 1);
+        assert_eq!(sdef.suites[0].code, AEEventClass::from_chars(*b"tEst"));
+
+        let save = sdef.command("save").unwrap();
+        assert_eq!(save.event_class, AEEventClass::from_chars(*b"core"));
+        assert_eq!(save.event_id, AEEventID::from_chars(*b"save"));
+        assert_eq!(
+            save.parameters,
+            [
+                ScriptingParameter {
+                    name: "in".into(),
+                    keyword: AEKeyword::from_chars(*b"kfil"),
+                    optional: true,
+                },
+                ScriptingParameter {
+                    name: "as".into(),
+                    keyword: AEKeyword::from_chars(*b"fltp"),
+                    optional: false,
+                },
+            ]
+        );
+
+        assert_eq!(
+            sdef.class("document").unwrap().code,
+            AEDescType::from_chars(*b"docu")
+        );
+        assert_eq!(
+            sdef.property("front most").unwrap().code,
+            AEKeyword::from_chars(*b"pisf")
+        );
+        assert_eq!(
+            sdef.enumerator("ask").unwrap().code,
+            AEDescType::from_chars(*b"ask&")
+        );
+    }
+
+    #[test]
+    fn parse_empty_event() {
+        let sdef = ScriptingDefinition::from_xml(SDEF).unwrap();
+        let idle = sdef.command("idle").unwrap();
+        assert_eq!(idle.event_class, AEEventClass::from_chars(*b"misc"));
+        assert_eq!(idle.event_id, AEEventID::from_chars(*b"idle"));
+    }
+
+    #[test]
+    fn parse_code_mac_roman() {
+        assert_eq!(MAC_ROMAN_HIGH.chars().count(), 0x80);
+        assert_eq!(parse_code::<4>("ƒ©∂a"), Some([0xC4, 0xA9, 0xB6, b'a']));
+        assert_eq!(parse_code::<4>("0xFFFFFFFF"), Some([0xFF; 4]));
+        assert_eq!(parse_code::<4>("abc"), None);
+        assert_eq!(parse_code::<4>("abcde"), None);
+        assert_eq!(parse_code::<4>("\u{3042}abc"), None);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let cases: &[(&str, ScriptingDefinitionError)] = &[
+            (
+                "<suite name=\"a\" code=\"abcd\">\n</dictionary>",
+                ScriptingDefinitionError::Xml {
+                    line: 2,
+                    message: "mismatched end tag",
+                },
+            ),
+            (
+                "<suite name=\"a\" code=\"abc\"/>",
+                ScriptingDefinitionError::InvalidCode {
+                    line: 1,
+                    code: "abc".into(),
+                },
+            ),
+            (
+                "<suite code=\"abcd\"/>",
+                ScriptingDefinitionError::Xml {
+                    line: 1,
+                    message: "suite is missing a name",
+                },
+            ),
+        ];
+
+        for (xml, expected) in cases {
+            assert_eq!(ScriptingDefinition::from_xml(xml).unwrap_err(), *expected);
+        }
+    }
+}
@@ -0,0 +1,217 @@
+//! A minimal XML reader for the subset of XML used by scripting definitions.
+//!
+//! Only element tags and their attributes are reported. Text, comments,
+//! processing instructions, CDATA sections, and the document type declaration
+//! are skipped, since scripting definitions store all codes and names in
+//! attributes.
+
+use super::ScriptingDefinitionError;
+
+/// A start or end tag.
+#[derive(Debug, PartialEq, Eq)]
+pub(super) enum Tag<'a> {
+    Start {
+        name: &'a str,
+        attributes: Vec<(&'a str, String)>,
+        is_empty: bool,
+    },
+    End {
+        name: &'a str,
+    },
+}
+
+/// Reads [`Tag`]s from an XML document.
+pub(super) struct Reader<'a> {
+    xml: &'a str,
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    pub(super) fn new(xml: &'a str) -> Self {
+        Self { xml, pos: 0 }
+    }
+
+    /// Returns the 1-based line number of the current position.
+    pub(super) fn line(&self) -> usize {
+        self.xml[..self.pos].matches('\n').count() + 1
+    }
+
+    /// Returns an error describing a problem at the current position.
+    pub(super) fn error(&self, message: &'static str) -> ScriptingDefinitionError {
+        ScriptingDefinitionError::Xml {
+            line: self.line(),
+            message,
+        }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.xml[self.pos..]
+    }
+
+    /// Advances past `terminator`, failing with `message` if it is missing.
+    fn skip_past(
+        &mut self,
+        terminator: &str,
+        message: &'static str,
+    ) -> Result<(), ScriptingDefinitionError> {
+        match self.rest().find(terminator) {
+            Some(index) => {
+                self.pos += index + terminator.len();
+                Ok(())
+            }
+            None => Err(self.error(message)),
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn read_name(&mut self) -> Result<&'a str, ScriptingDefinitionError> {
+        let rest = self.rest();
+        let len = rest
+            .find(|c: char| c.is_whitespace() || matches!(c, '/' | '>' | '='))
+            .unwrap_or(rest.len());
+        if len == 0 {
+            return Err(self.error("expected a name"));
+        }
+        self.pos += len;
+        Ok(&rest[..len])
+    }
+
+    /// Skips a `<!DOCTYPE ...>` declaration, including any internal subset.
+    fn skip_doctype(&mut self) -> Result<(), ScriptingDefinitionError> {
+        let mut depth = 0usize;
+        for (index, c) in self.rest().char_indices() {
+            match c {
+                '[' => depth += 1,
+                ']' => depth = depth.saturating_sub(1),
+                '>' if depth == 0 => {
+                    self.pos += index + 1;
+                    return Ok(());
+                }
+                _ => {}
+            }
+        }
+        Err(self.error("unterminated document type declaration"))
+    }
+
+    /// Returns the next tag, or [`None`] at the end of the document.
+    pub(super) fn next_tag(&mut self) -> Result<Option<Tag<'a>>, ScriptingDefinitionError> {
+        loop {
+            match self.rest().find('<') {
+                Some(index) => self.pos += index,
+                None => return Ok(None),
+            }
+
+            let rest = self.rest();
+            if rest.starts_with("<!--") {
+                self.skip_past("-->", "unterminated comment")?;
+            } else if rest.starts_with("<![CDATA[") {
+                self.skip_past("]]>", "unterminated CDATA section")?;
+            } else if rest.starts_with("<!") {
+                self.skip_doctype()?;
+            } else if rest.starts_with("<?") {
+                self.skip_past("?>", "unterminated processing instruction")?;
+            } else if rest.starts_with("</") {
+                self.pos += 2;
+                let name = self.read_name()?;
+                self.skip_past(">", "unterminated end tag")?;
+                return Ok(Some(Tag::End { name }));
+            } else {
+                self.pos += 1;
+                return self.read_start_tag().map(Some);
+            }
+        }
+    }
+
+    fn read_start_tag(&mut self) -> Result<Tag<'a>, ScriptingDefinitionError> {
+        let name = self.read_name()?;
+        let mut attributes = Vec::new();
+
+        loop {
+            self.skip_whitespace();
+
+            let rest = self.rest();
+            if rest.starts_with("/>") {
+                self.pos += 2;
+                return Ok(Tag::Start {
+                    name,
+                    attributes,
+                    is_empty: true,
+                });
+            } else if rest.starts_with('>') {
+                self.pos += 1;
+                return Ok(Tag::Start {
+                    name,
+                    attributes,
+                    is_empty: false,
+                });
+            } else if rest.is_empty() {
+                return Err(self.error("unterminated start tag"));
+            }
+
+            let key = self.read_name()?;
+            self.skip_whitespace();
+            if !self.rest().starts_with('=') {
+                return Err(self.error("expected '=' after attribute name"));
+            }
+            self.pos += 1;
+            self.skip_whitespace();
+
+            let quote = match self.rest().chars().next() {
+                Some(quote @ '"') | Some(quote @ '\'') => quote,
+                _ => return Err(self.error("expected a quoted attribute value")),
+            };
+            self.pos += 1;
+
+            let rest = self.rest();
+            let len = match rest.find(quote) {
+                Some(len) => len,
+                None => return Err(self.error("unterminated attribute value")),
+            };
+            let value = unescape(&rest[..len]).ok_or_else(|| self.error("invalid entity"))?;
+            self.pos += len + 1;
+
+            attributes.push((key, value));
+        }
+    }
+}
+
+/// Replaces the predefined and numeric character entities in `value`.
+fn unescape(value: &str) -> Option<String> {
+    let mut result = String::with_capacity(value.len());
+    let mut rest = value;
+
+    while let Some(start) = rest.find('&') {
+        result.push_str(&rest[..start]);
+        rest = &rest[start + 1..];
+
+        let end = rest.find(';')?;
+        let entity = &rest[..end];
+        rest = &rest[end + 1..];
+
+        let c = match entity {
+            "amp" => '&',
+            "lt" => '<',
+            "gt" => '>',
+            "quot" => '"',
+            "apos" => '\'',
+            _ => {
+                let code = if let Some(hex) = entity.strip_prefix("#x") {
+                    u32::from_str_radix(hex, 16).ok()?
+                } else if let Some(decimal) = entity.strip_prefix('#') {
+                    decimal.parse().ok()?
+                } else {
+                    return None;
+                };
+                std::char::from_u32(code)?
+            }
+        };
+        result.push(c);
+    }
+
+    result.push_str(rest);
+    Some(result)
+}
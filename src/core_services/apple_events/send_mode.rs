@@ -1,10 +1,114 @@
+use std::ops;
+
 /// Specify send preferences to the `AESend` function.
 ///
+/// A mode combines one reply preference with one user interaction
+/// preference, which can be done with [`new`](Self::new):
+///
+/// ```
+/// use fruity::core_services::{AEInteractionLevel, AEReplyMode, AESendMode};
+///
+/// let mode = AESendMode::new(AEReplyMode::Wait, AEInteractionLevel::Never);
+/// assert_eq!(mode, AESendMode::AE_WAIT_REPLY | AESendMode::AE_NEVER_INTERACT);
+///
+/// assert_eq!(mode.reply_mode(), Some(AEReplyMode::Wait));
+/// assert_eq!(mode.interaction_level(), Some(AEInteractionLevel::Never));
+/// ```
+///
 /// See [documentation](https://developer.apple.com/documentation/coreservices/aesendmode?language=objc).
 #[repr(transparent)]
 #[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
 pub struct AESendMode(pub i32);
 
+impl ops::BitOr for AESendMode {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl From<AEReplyMode> for AESendMode {
+    #[inline]
+    fn from(mode: AEReplyMode) -> Self {
+        match mode {
+            AEReplyMode::None => Self::AE_NO_REPLY,
+            AEReplyMode::Queue => Self::AE_QUEUE_REPLY,
+            AEReplyMode::Wait => Self::AE_WAIT_REPLY,
+        }
+    }
+}
+
+impl From<AEInteractionLevel> for AESendMode {
+    #[inline]
+    fn from(level: AEInteractionLevel) -> Self {
+        match level {
+            AEInteractionLevel::Never => Self::AE_NEVER_INTERACT,
+            AEInteractionLevel::Can => Self::AE_CAN_INTERACT,
+            AEInteractionLevel::Always => Self::AE_ALWAYS_INTERACT,
+        }
+    }
+}
+
+impl AESendMode {
+    /// The bits of the reply preference.
+    const REPLY_MASK: i32 = 0x00000003;
+
+    /// The bits of the user interaction preference.
+    const INTERACT_MASK: i32 = 0x00000030;
+
+    /// Returns the mode with the reply preference `reply` and the user
+    /// interaction preference `interaction`.
+    #[inline]
+    pub fn new(reply: AEReplyMode, interaction: AEInteractionLevel) -> Self {
+        Self::from(reply) | Self::from(interaction)
+    }
+
+    /// Returns `true` if all flags in `other` are set in `self`.
+    #[inline]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns the reply preference, or [`None`] if none is set.
+    #[inline]
+    pub fn reply_mode(self) -> Option<AEReplyMode> {
+        match self.0 & Self::REPLY_MASK {
+            0x1 => Some(AEReplyMode::None),
+            0x2 => Some(AEReplyMode::Queue),
+            0x3 => Some(AEReplyMode::Wait),
+            _ => None,
+        }
+    }
+
+    /// Returns the user interaction preference, or [`None`] if none is set,
+    /// in which case the Apple Event Manager picks one based on whether the
+    /// target is local.
+    #[inline]
+    pub fn interaction_level(self) -> Option<AEInteractionLevel> {
+        match self.0 & Self::INTERACT_MASK {
+            0x10 => Some(AEInteractionLevel::Never),
+            0x20 => Some(AEInteractionLevel::Can),
+            0x30 => Some(AEInteractionLevel::Always),
+            _ => None,
+        }
+    }
+
+    /// Returns `self` with its reply preference replaced by `reply`.
+    #[inline]
+    pub fn with_reply_mode(self, reply: AEReplyMode) -> Self {
+        Self(self.0 & !Self::REPLY_MASK) | Self::from(reply)
+    }
+
+    /// Returns `self` with its user interaction preference replaced by
+    /// `interaction`.
+    #[inline]
+    pub fn with_interaction_level(self, interaction: AEInteractionLevel) -> Self {
+        Self(self.0 & !Self::INTERACT_MASK) | Self::from(interaction)
+    }
+}
+
 impl AESendMode {
     /// The reply preference—your application does not want a reply Apple event.
     /// If you set the bit specified by this constant, the server processes the
@@ -148,3 +252,72 @@ impl AESendMode {
     #[doc(alias = "kAEDoNotAutomaticallyAddAnnotationsToEvent")]
     pub const AE_DO_NOT_AUTOMATICALLY_ADD_ANNOTATIONS_TO_EVENT: Self = Self(0x00010000);
 }
+
+/// Whether and how the sender of an Apple event receives its reply.
+///
+/// This is the reply preference of an [`AESendMode`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AEReplyMode {
+    /// No reply is sent.
+    #[doc(alias = "kAENoReply")]
+    None,
+
+    /// The reply is delivered to the sender's event queue later.
+    #[doc(alias = "kAEQueueReply")]
+    Queue,
+
+    /// [`AppleEvent::send`](super::AppleEvent::send) waits for the reply and
+    /// returns it.
+    #[doc(alias = "kAEWaitReply")]
+    Wait,
+}
+
+/// Whether the target of an Apple event may interact with the user, such as
+/// by showing a dialog, to handle it.
+///
+/// This is the user interaction preference of an [`AESendMode`]. The target
+/// may still refuse to interact.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AEInteractionLevel {
+    /// The target must not interact with the user.
+    #[doc(alias = "kAENeverInteract")]
+    Never,
+
+    /// The target may interact with the user if it needs information.
+    #[doc(alias = "kAECanInteract")]
+    Can,
+
+    /// The target should interact with the user, such as to confirm the
+    /// action.
+    #[doc(alias = "kAEAlwaysInteract")]
+    Always,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn preferences() {
+        let mode = AESendMode::AE_QUEUE_REPLY
+            | AESendMode::AE_CAN_INTERACT
+            | AESendMode::AE_CAN_SWITCH_LAYER;
+        assert_eq!(mode.reply_mode(), Some(AEReplyMode::Queue));
+        assert_eq!(mode.interaction_level(), Some(AEInteractionLevel::Can));
+
+        let mode = mode
+            .with_reply_mode(AEReplyMode::None)
+            .with_interaction_level(AEInteractionLevel::Always);
+        assert_eq!(
+            mode,
+            AESendMode::AE_NO_REPLY
+                | AESendMode::AE_ALWAYS_INTERACT
+                | AESendMode::AE_CAN_SWITCH_LAYER
+        );
+        assert!(mode.contains(AESendMode::AE_CAN_SWITCH_LAYER));
+
+        let mode = AESendMode(0);
+        assert_eq!(mode.reply_mode(), None);
+        assert_eq!(mode.interaction_level(), None);
+    }
+}
@@ -0,0 +1,56 @@
+use std::{os::raw::c_long, time::Duration};
+
+/// The number of ticks (1/60th of a second) to wait for a reply to an Apple
+/// event.
+///
+/// This is passed to [`AppleEvent::send`](super::AppleEvent::send).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct AETimeout(pub c_long);
+
+impl Default for AETimeout {
+    #[inline]
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl From<Duration> for AETimeout {
+    #[inline]
+    fn from(duration: Duration) -> Self {
+        Self::from_duration(duration)
+    }
+}
+
+impl AETimeout {
+    /// The Apple Event Manager's default timeout, which is about one minute.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreservices/kaedefaulttimeout?language=objc).
+    #[doc(alias = "kAEDefaultTimeout")]
+    pub const DEFAULT: Self = Self(-1);
+
+    /// Wait indefinitely for a reply.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreservices/knotimeout?language=objc).
+    #[doc(alias = "kNoTimeOut")]
+    pub const NONE: Self = Self(-2);
+
+    /// Returns the number of ticks in `duration`, rounded down.
+    #[inline]
+    pub fn from_duration(duration: Duration) -> Self {
+        let ticks = duration.as_millis() * 60 / 1000;
+        Self(ticks.min(c_long::MAX as u128) as c_long)
+    }
+
+    /// Returns the duration of `self`, or [`None`] if it is
+    /// [`DEFAULT`](Self::DEFAULT), [`NONE`](Self::NONE), or otherwise
+    /// negative.
+    #[inline]
+    pub fn to_duration(self) -> Option<Duration> {
+        if self.0 < 0 {
+            None
+        } else {
+            Some(Duration::from_millis(self.0 as u64 * 1000 / 60))
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use super::{
+    AEAddressDesc, AEDescType, AEEventClass, AEEventID, AEKeyword, AEReturnID, AESendMode,
+    AETimeout, AETransactionID, AppleEvent,
+};
+use crate::core::{OSErr, OSStatus};
+
+/// A sequence of Apple events that the target handles as one unit, such as
+/// a series of edits that should not be interleaved with other clients'.
+///
+/// The transaction is ended with [`end`](Self::end), or when dropped.
+/// Targets only support transactions if they implement the
+/// [`BEGIN_TRANSACTION`](AEEventID::BEGIN_TRANSACTION) event.
+///
+/// # Examples
+///
+/// ```no_run
+/// use fruity::core_services::*;
+///
+/// # fn main() -> Result<(), fruity::core::OSStatus> {
+/// let target = AEDesc::from_bundle_id("com.example.database")?;
+/// let transaction = AETransaction::begin(&target, AETimeout::DEFAULT)?;
+///
+/// for _ in 0..3 {
+///     let event = transaction.new_event(
+///         AEEventClass::from_chars(*b"core"),
+///         AEEventID::from_chars(*b"setd"),
+///     )?;
+///     event.send(AESendMode::AE_WAIT_REPLY, transaction.timeout())?;
+/// }
+///
+/// transaction.end()?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// See [documentation](https://developer.apple.com/documentation/coreservices/kaebegintransaction?language=objc).
+#[derive(Debug)]
+pub struct AETransaction {
+    target: AEAddressDesc,
+    id: AETransactionID,
+    timeout: AETimeout,
+    is_ended: bool,
+}
+
+impl Drop for AETransaction {
+    #[inline]
+    fn drop(&mut self) {
+        if !self.is_ended {
+            // Errors can't be reported here, and the target ends abandoned
+            // transactions eventually, so there is no need to wait.
+            let _ = self.send_end(AESendMode::AE_NO_REPLY);
+        }
+    }
+}
+
+impl AETransaction {
+    /// Asks `target` to start a transaction, waiting up to `timeout` for it
+    /// to reply.
+    ///
+    /// `timeout` is also returned by [`timeout`](Self::timeout) for sending
+    /// the events of the transaction.
+    #[doc(alias = "kAEBeginTransaction")]
+    pub fn begin(target: &AEAddressDesc, timeout: AETimeout) -> Result<Self, OSStatus> {
+        let event = AppleEvent::new(
+            AEEventClass::MISC_STANDARDS,
+            AEEventID::BEGIN_TRANSACTION,
+            target,
+            AEReturnID::AUTO_GENERATE,
+            AETransactionID::ANY,
+        )?;
+        let reply = event.send(AESendMode::AE_WAIT_REPLY, timeout)?;
+
+        let id = reply
+            .param(AEKeyword::DIRECT_OBJECT, AEDescType::I32)
+            .and_then(|desc| desc.to_i32())?;
+
+        Ok(Self {
+            target: target.duplicate()?,
+            id: AETransactionID(id),
+            timeout,
+            is_ended: false,
+        })
+    }
+
+    /// Returns the ID that the target assigned to the transaction.
+    #[inline]
+    pub fn id(&self) -> AETransactionID {
+        self.id
+    }
+
+    /// Returns the timeout that the transaction was started with.
+    #[inline]
+    pub fn timeout(&self) -> AETimeout {
+        self.timeout
+    }
+
+    /// Returns the target of the transaction.
+    #[inline]
+    pub fn target(&self) -> &AEAddressDesc {
+        &self.target
+    }
+
+    /// Creates an Apple event that is part of the transaction, addressed to
+    /// its target.
+    #[inline]
+    pub fn new_event(
+        &self,
+        event_class: AEEventClass,
+        event_id: AEEventID,
+    ) -> Result<AppleEvent, OSErr> {
+        AppleEvent::new(
+            event_class,
+            event_id,
+            &self.target,
+            AEReturnID::AUTO_GENERATE,
+            self.id,
+        )
+    }
+
+    /// Asks the target to end the transaction, waiting for it to reply.
+    #[doc(alias = "kAEEndTransaction")]
+    pub fn end(mut self) -> Result<(), OSStatus> {
+        self.is_ended = true;
+        self.send_end(AESendMode::AE_WAIT_REPLY)
+    }
+
+    fn send_end(&self, mode: AESendMode) -> Result<(), OSStatus> {
+        let event = self.new_event(AEEventClass::MISC_STANDARDS, AEEventID::END_TRANSACTION)?;
+        event.send(mode, self.timeout)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,205 @@
+//! Common Finder operations, sent as [Apple events](super::AppleEvent).
+//!
+//! Each function waits for Finder to reply. Apps that send these must include
+//! the `NSAppleEventsUsageDescription` key in their `Info.plist`, and the user
+//! is asked once to allow them to control Finder.
+//!
+//! Items are given as file URLs, such as from
+//! [`CFURL::from_path`](crate::core_foundation::CFURL::from_path).
+
+use super::{
+    AEDesc, AEDescList, AEDescType, AEEventClass, AEEventID, AEKeyword, AERecord, AEReturnID,
+    AESendMode, AETimeout, AETransactionID, AppleEvent, FinderLabel,
+};
+use crate::core::OSStatus;
+use crate::core_foundation::{CFString, CFURL};
+use std::path::PathBuf;
+
+/// The bundle identifier of Finder.
+const FINDER_BUNDLE_ID: &str = "com.apple.finder";
+
+/// The `fndr` event class of Finder's own commands.
+const FINDER_SUITE: AEEventClass = AEEventClass::from_chars(*b"fndr");
+
+const GET_DATA: AEEventID = AEEventID::from_chars(*b"getd");
+const SET_DATA: AEEventID = AEEventID::from_chars(*b"setd");
+const EMPTY: AEEventID = AEEventID::from_chars(*b"empt");
+const EJECT: AEEventID = AEEventID::from_chars(*b"ejct");
+
+/// The keywords of an object specifier record.
+const KEY_WANT: AEKeyword = AEKeyword::from_chars(*b"want");
+const KEY_FROM: AEKeyword = AEKeyword::from_chars(*b"from");
+const KEY_FORM: AEKeyword = AEKeyword::from_chars(*b"form");
+const KEY_SELD: AEKeyword = AEKeyword::from_chars(*b"seld");
+
+/// The parameter holding the new value of a "set" event.
+const KEY_DATA: AEKeyword = AEKeyword::from_chars(*b"data");
+
+/// The parameter holding the type that the reply should be coerced to.
+const KEY_REQUESTED_TYPE: AEKeyword = AEKeyword::from_chars(*b"rtyp");
+
+/// A list of aliases, as in AppleScript's `as alias list`.
+const ALIAS_LIST: AEDescType = AEDescType::from_chars(*b"alst");
+
+const PROP_SELECTION: AEDescType = AEDescType::from_chars(*b"sele");
+const PROP_TRASH: AEDescType = AEDescType::from_chars(*b"trsh");
+const PROP_COMMENT: AEDescType = AEDescType::from_chars(*b"comt");
+const PROP_LABEL_INDEX: AEDescType = AEDescType::from_chars(*b"labi");
+
+/// Creates a descriptor for a four-character code of type `descriptor_type`.
+#[inline]
+fn code_desc(descriptor_type: AEDescType, code: AEDescType) -> Result<AEDesc, OSStatus> {
+    Ok(AEDesc::new(
+        descriptor_type,
+        &code.into_int().to_ne_bytes(),
+    )?)
+}
+
+/// Creates a descriptor for the file at `url`.
+#[inline]
+fn file_desc(url: &CFURL) -> Result<AEDesc, OSStatus> {
+    Ok(AEDesc::from_file_url(
+        &url.absolute_url().string().to_string(),
+    )?)
+}
+
+/// Creates an object specifier for the `property` of `container`, where a
+/// null container refers to Finder itself.
+fn property_specifier(property: AEDescType, container: &AEDesc) -> Result<AEDesc, OSStatus> {
+    let mut record = AERecord::new()?;
+    record.set_key(
+        KEY_WANT,
+        &code_desc(AEDescType::TYPE, AEDescType::PROPERTY)?,
+    )?;
+    record.set_key(KEY_FROM, container)?;
+    record.set_key(
+        KEY_FORM,
+        &code_desc(AEDescType::ENUMERATED, AEDescType::PROPERTY)?,
+    )?;
+    record.set_key(KEY_SELD, &code_desc(AEDescType::TYPE, property)?)?;
+    Ok(record.coerce(AEDescType::OBJECT_SPECIFIER)?)
+}
+
+/// Sends an event with `direct_object` to Finder and returns its reply.
+fn send(
+    event_class: AEEventClass,
+    event_id: AEEventID,
+    direct_object: Option<&AEDesc>,
+    params: &[(AEKeyword, &AEDesc)],
+) -> Result<AppleEvent, OSStatus> {
+    let finder = AEDesc::from_bundle_id(FINDER_BUNDLE_ID)?;
+    let mut event = AppleEvent::new(
+        event_class,
+        event_id,
+        &finder,
+        AEReturnID::AUTO_GENERATE,
+        AETransactionID::ANY,
+    )?;
+    if let Some(direct_object) = direct_object {
+        event.set_param(AEKeyword::DIRECT_OBJECT, direct_object)?;
+    }
+    for &(keyword, desc) in params {
+        event.set_param(keyword, desc)?;
+    }
+    event.send(AESendMode::AE_WAIT_REPLY, AETimeout::DEFAULT)
+}
+
+/// Sets the `property` of the item at `url` to `value`.
+fn set_property(url: &CFURL, property: AEDescType, value: &AEDesc) -> Result<(), OSStatus> {
+    let item = property_specifier(property, &file_desc(url)?)?;
+    send(
+        AEEventClass::CORE,
+        SET_DATA,
+        Some(&item),
+        &[(KEY_DATA, value)],
+    )?;
+    Ok(())
+}
+
+/// Selects the items at `urls` in Finder windows and brings Finder to the
+/// front.
+///
+/// This is the same as "Show in Finder".
+pub fn reveal(urls: &[&CFURL]) -> Result<(), OSStatus> {
+    let mut items = AEDescList::new()?;
+    for url in urls {
+        items.push(&file_desc(url)?)?;
+    }
+
+    send(
+        AEEventClass::MISC_STANDARDS,
+        AEEventID::MAKE_OBJECTS_VISIBLE,
+        Some(&items),
+        &[],
+    )?;
+    send(AEEventClass::MISC_STANDARDS, AEEventID::ACTIVATE, None, &[])?;
+    Ok(())
+}
+
+/// Returns the paths of the items selected in the frontmost Finder window.
+pub fn selection() -> Result<Vec<PathBuf>, OSStatus> {
+    let selection = property_specifier(PROP_SELECTION, &AEDesc::null())?;
+    let requested_type = code_desc(AEDescType::TYPE, ALIAS_LIST)?;
+    let reply = send(
+        AEEventClass::CORE,
+        GET_DATA,
+        Some(&selection),
+        &[(KEY_REQUESTED_TYPE, &requested_type)],
+    )?;
+
+    let items = AEDescList(reply.param(AEKeyword::DIRECT_OBJECT, AEDescType::AE_LIST)?);
+    let mut paths = Vec::with_capacity(items.len()?);
+    for index in 0..items.len()? {
+        let url = items.get(index, AEDescType::FILE_URL)?.to_utf8_string()?;
+        let path = CFURL::from_string(None, &CFString::new(&url), None)
+            .as_deref()
+            .and_then(CFURL::to_path_buf);
+        paths.extend(path);
+    }
+    Ok(paths)
+}
+
+/// Empties the Trash without asking for confirmation.
+pub fn empty_trash() -> Result<(), OSStatus> {
+    let trash = property_specifier(PROP_TRASH, &AEDesc::null())?;
+    send(FINDER_SUITE, EMPTY, Some(&trash), &[])?;
+    Ok(())
+}
+
+/// Sets the Spotlight comment that Finder shows in the "Get Info" window of
+/// the item at `url`.
+pub fn set_comment(url: &CFURL, comment: &str) -> Result<(), OSStatus> {
+    set_property(url, PROP_COMMENT, &AEDesc::from_utf8(comment)?)
+}
+
+/// Sets the label color of the item at `url`.
+///
+/// Unlike [`FinderLabel::set`], this goes through Finder, so that open
+/// windows update immediately.
+pub fn set_label(url: &CFURL, label: FinderLabel) -> Result<(), OSStatus> {
+    set_property(url, PROP_LABEL_INDEX, &AEDesc::from_i32(label.index())?)
+}
+
+/// Ejects the volume mounted at `url`, such as `/Volumes/Backup`.
+pub fn eject(url: &CFURL) -> Result<(), OSStatus> {
+    send(FINDER_SUITE, EJECT, Some(&file_desc(url)?), &[])?;
+    Ok(())
+}
+
+impl FinderLabel {
+    /// Returns the `label index` that Finder's scripting interface uses for
+    /// this label, which orders the colors differently.
+    #[inline]
+    const fn index(self) -> i32 {
+        match self {
+            Self::None => 0,
+            Self::Orange => 1,
+            Self::Red => 2,
+            Self::Yellow => 3,
+            Self::Blue => 4,
+            Self::Purple => 5,
+            Self::Green => 6,
+            Self::Gray => 7,
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use super::xattr::{self, XattrOptions};
+use std::{io, path::Path};
+
+/// The name of the extended attribute that stores the 32-byte Finder info of
+/// a file or folder.
+const FINDER_INFO: &str = "com.apple.FinderInfo";
+
+/// The byte within the Finder info that stores the `fdFlags` low bits.
+const FLAGS_INDEX: usize = 9;
+
+/// Bits 1 through 3 of the Finder flags hold the label color.
+const LABEL_MASK: u8 = 0b0000_1110;
+
+/// The legacy Finder label color of a file or folder.
+///
+/// These are stored within the `com.apple.FinderInfo` extended attribute and
+/// correspond to the first seven Finder tags.
+#[repr(u8)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FinderLabel {
+    /// No label.
+    None = 0,
+    /// Gray label.
+    Gray = 1,
+    /// Green label.
+    Green = 2,
+    /// Purple label.
+    Purple = 3,
+    /// Blue label.
+    Blue = 4,
+    /// Yellow label.
+    Yellow = 5,
+    /// Red label.
+    Red = 6,
+    /// Orange label.
+    Orange = 7,
+}
+
+impl Default for FinderLabel {
+    #[inline]
+    fn default() -> Self {
+        Self::None
+    }
+}
+
+impl FinderLabel {
+    #[inline]
+    const fn from_bits(bits: u8) -> Self {
+        match bits & 0b111 {
+            1 => Self::Gray,
+            2 => Self::Green,
+            3 => Self::Purple,
+            4 => Self::Blue,
+            5 => Self::Yellow,
+            6 => Self::Red,
+            7 => Self::Orange,
+            _ => Self::None,
+        }
+    }
+
+    /// Returns the label of the file at `path`.
+    ///
+    /// Files without Finder info have no label.
+    pub fn get<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        match xattr::get(path, FINDER_INFO, XattrOptions::NONE)? {
+            Some(info) if info.len() > FLAGS_INDEX => {
+                Ok(Self::from_bits((info[FLAGS_INDEX] & LABEL_MASK) >> 1))
+            }
+            _ => Ok(Self::None),
+        }
+    }
+
+    /// Sets this label on the file at `path`, leaving the rest of its Finder
+    /// info unchanged.
+    pub fn set<P: AsRef<Path>>(self, path: P) -> io::Result<()> {
+        let path = path.as_ref();
+
+        let mut info = xattr::get(path, FINDER_INFO, XattrOptions::NONE)?.unwrap_or_default();
+        info.resize(info.len().max(32), 0);
+
+        info[FLAGS_INDEX] = (info[FLAGS_INDEX] & !LABEL_MASK) | ((self as u8) << 1);
+
+        if info.iter().all(|&b| b == 0) {
+            // Finder info that is all zeros is equivalent to none at all.
+            match xattr::remove(path, FINDER_INFO, XattrOptions::NONE) {
+                Err(error) if error.raw_os_error() == Some(xattr::ENOATTR) => Ok(()),
+                result => result,
+            }
+        } else {
+            xattr::set(path, FINDER_INFO, &info, XattrOptions::NONE)
+        }
+    }
+}
@@ -0,0 +1,545 @@
+use super::sys;
+use crate::core::Arc;
+use crate::core_foundation::{CFArray, CFRunLoop, CFRunLoopMode, CFRunLoopSchedulable, CFString};
+use std::{
+    ffi::{c_void, CStr, OsStr},
+    fmt, ops,
+    os::{raw::c_char, unix::ffi::OsStrExt},
+    panic,
+    path::{Path, PathBuf},
+    process, ptr, slice,
+    time::Duration,
+};
+
+#[cfg(feature = "dispatch")]
+use crate::dispatch::DispatchQueue;
+
+/// A stream of file system events for a set of directory trees, reported by
+/// the [File System Events](https://developer.apple.com/documentation/coreservices/file_system_events)
+/// API.
+///
+/// Streams are created with [`FSEventStreamBuilder`]. Events stop being
+/// reported when the stream is dropped.
+///
+/// # Examples
+///
+/// ```no_run
+/// use fruity::core_foundation::{CFRunLoop, CFRunLoopMode};
+/// use fruity::core_services::{FSEventStreamBuilder, FSEventStreamCreateFlags};
+/// use std::time::Duration;
+///
+/// let _stream = FSEventStreamBuilder::new(&["/tmp"])
+///     .latency(Duration::from_millis(100))
+///     .flags(FSEventStreamCreateFlags::FILE_EVENTS)
+///     .schedule_on_run_loop(&CFRunLoop::current(), CFRunLoopMode::default(), |event| {
+///         println!("{:?} {:?}", event.path, event.flags);
+///     })
+///     .expect("could not start stream");
+///
+/// CFRunLoop::run();
+/// ```
+///
+/// Documentation:
+/// [Objective-C](https://developer.apple.com/documentation/coreservices/fseventstreamref?language=objc)
+pub struct FSEventStream {
+    raw: *mut sys::FSEventStreamRef,
+}
+
+// SAFETY: The stream is only accessed through `&self` methods that FSEvents
+// permits from any thread, and the callback is `Send`.
+unsafe impl Send for FSEventStream {}
+
+impl fmt::Debug for FSEventStream {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("FSEventStream")
+            .field("paths", &self.paths())
+            .field("latest_event_id", &self.latest_event_id())
+            .finish()
+    }
+}
+
+impl Drop for FSEventStream {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe {
+            sys::FSEventStreamStop(self.raw);
+            sys::FSEventStreamInvalidate(self.raw);
+            sys::FSEventStreamRelease(self.raw);
+        }
+    }
+}
+
+/// Scheduling a stream that was started with
+/// [`FSEventStreamBuilder::schedule_on_run_loop`] in more modes or run loops.
+///
+/// Streams started on a dispatch queue must not be scheduled on a run loop.
+impl CFRunLoopSchedulable for FSEventStream {
+    #[inline]
+    #[doc(alias = "FSEventStreamScheduleWithRunLoop")]
+    fn schedule(&self, run_loop: &CFRunLoop, mode: &CFRunLoopMode) {
+        unsafe { sys::FSEventStreamScheduleWithRunLoop(self.raw, run_loop, mode) };
+    }
+
+    #[inline]
+    #[doc(alias = "FSEventStreamUnscheduleFromRunLoop")]
+    fn unschedule(&self, run_loop: &CFRunLoop, mode: &CFRunLoopMode) {
+        unsafe { sys::FSEventStreamUnscheduleFromRunLoop(self.raw, run_loop, mode) };
+    }
+}
+
+impl FSEventStream {
+    /// Returns a builder for a stream that reports events for `paths` and
+    /// their subdirectories.
+    #[inline]
+    pub fn builder<P: AsRef<Path>>(paths: &[P]) -> FSEventStreamBuilder {
+        FSEventStreamBuilder::new(paths)
+    }
+
+    /// Synchronously reports all events that are buffered because of the
+    /// latency of the stream.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreservices/fseventstreamflushsync(_:)).
+    #[inline]
+    #[doc(alias = "FSEventStreamFlushSync")]
+    pub fn flush(&self) {
+        unsafe { sys::FSEventStreamFlushSync(self.raw) };
+    }
+
+    /// Asynchronously reports all events that are buffered because of the
+    /// latency of the stream, and returns the ID of the last one.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreservices/fseventstreamflushasync(_:)).
+    #[inline]
+    #[doc(alias = "FSEventStreamFlushAsync")]
+    pub fn flush_async(&self) -> FSEventStreamEventId {
+        unsafe { sys::FSEventStreamFlushAsync(self.raw) }
+    }
+
+    /// Returns the ID of the last event reported by the stream.
+    ///
+    /// This can be persisted and passed to
+    /// [`FSEventStreamBuilder::since`] to resume from this point later.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreservices/fseventstreamgetlatesteventid(_:)).
+    #[inline]
+    #[doc(alias = "FSEventStreamGetLatestEventId")]
+    pub fn latest_event_id(&self) -> FSEventStreamEventId {
+        unsafe { sys::FSEventStreamGetLatestEventId(self.raw) }
+    }
+
+    /// Returns the paths that the stream reports events for.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreservices/fseventstreamcopypathsbeingwatched(_:)).
+    #[inline]
+    #[doc(alias = "FSEventStreamCopyPathsBeingWatched")]
+    pub fn paths(&self) -> Arc<CFArray<CFString>> {
+        unsafe { Arc::from_raw(sys::FSEventStreamCopyPathsBeingWatched(self.raw)) }
+    }
+}
+
+/// An event reported by an [`FSEventStream`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FSEvent<'a> {
+    /// The path of the item or directory that changed.
+    ///
+    /// Without [`FILE_EVENTS`](FSEventStreamCreateFlags::FILE_EVENTS), this
+    /// is the directory that contains the changes.
+    pub path: &'a Path,
+
+    /// What happened to the path.
+    pub flags: FSEventStreamEventFlags,
+
+    /// The ID of the event, which increases over time.
+    pub id: FSEventStreamEventId,
+}
+
+/// Configures and starts an [`FSEventStream`].
+///
+/// By default, only events after the stream starts are reported, and events
+/// are coalesced for up to a second.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FSEventStreamBuilder {
+    paths: Vec<PathBuf>,
+    since: FSEventStreamEventId,
+    latency: Duration,
+    flags: FSEventStreamCreateFlags,
+}
+
+impl FSEventStreamBuilder {
+    /// Returns a builder for a stream that reports events for `paths` and
+    /// their subdirectories.
+    #[inline]
+    pub fn new<P: AsRef<Path>>(paths: &[P]) -> Self {
+        Self {
+            paths: paths
+                .iter()
+                .map(|path| path.as_ref().to_path_buf())
+                .collect(),
+            since: FSEventStreamEventId::SINCE_NOW,
+            latency: Duration::from_secs(1),
+            flags: FSEventStreamCreateFlags::NONE,
+        }
+    }
+
+    /// Reports past events that occurred after the event with ID `since`,
+    /// before any new events.
+    #[inline]
+    pub fn since(mut self, since: FSEventStreamEventId) -> Self {
+        self.since = since;
+        self
+    }
+
+    /// Sets how long to wait after an event before reporting it, so that
+    /// multiple events are reported together.
+    #[inline]
+    pub fn latency(mut self, latency: Duration) -> Self {
+        self.latency = latency;
+        self
+    }
+
+    /// Sets options for the stream.
+    #[inline]
+    pub fn flags(mut self, flags: FSEventStreamCreateFlags) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    /// Starts the stream on `run_loop` in `mode`, or returns [`None`] if it
+    /// could not be started.
+    ///
+    /// It is safe to panic within `callback`. Panics will abort the process.
+    #[doc(alias = "FSEventStreamScheduleWithRunLoop")]
+    pub fn schedule_on_run_loop<F>(
+        self,
+        run_loop: &CFRunLoop,
+        mode: &CFRunLoopMode,
+        callback: F,
+    ) -> Option<FSEventStream>
+    where
+        F: FnMut(FSEvent) + Send + 'static,
+    {
+        let stream = self.create(callback)?;
+        unsafe { sys::FSEventStreamScheduleWithRunLoop(stream.raw, run_loop, mode) };
+        stream.start()
+    }
+
+    /// Starts the stream to call `callback` on `queue`, or returns [`None`]
+    /// if it could not be started.
+    ///
+    /// It is safe to panic within `callback`. Panics will abort the process.
+    #[cfg(feature = "dispatch")]
+    #[doc(alias = "FSEventStreamSetDispatchQueue")]
+    pub fn schedule_on_queue<F>(self, queue: &DispatchQueue, callback: F) -> Option<FSEventStream>
+    where
+        F: FnMut(FSEvent) + Send + 'static,
+    {
+        let stream = self.create(callback)?;
+        unsafe { sys::FSEventStreamSetDispatchQueue(stream.raw, queue) };
+        stream.start()
+    }
+
+    #[doc(alias = "FSEventStreamCreate")]
+    fn create<F>(self, callback: F) -> Option<FSEventStream>
+    where
+        F: FnMut(FSEvent) + Send + 'static,
+    {
+        let paths: Vec<Arc<CFString>> = self
+            .paths
+            .iter()
+            .map(|path| CFString::new(&path.to_string_lossy()))
+            .collect();
+        let paths: Vec<&CFString> = paths.iter().map(|path| &**path).collect();
+        let paths = CFArray::from_slice(&paths);
+
+        let context = sys::FSEventStreamContext {
+            version: 0,
+            info: Box::into_raw(Box::new(callback)).cast(),
+            retain: None,
+            release: Some(release_callback::<F>),
+            copy_description: None,
+        };
+
+        // Paths are read as C strings in `call_callback`.
+        let flags = FSEventStreamCreateFlags(
+            self.flags.0
+                & !(sys::kFSEventStreamCreateFlagUseCFTypes
+                    | sys::kFSEventStreamCreateFlagUseExtendedData),
+        );
+
+        let raw = unsafe {
+            sys::FSEventStreamCreate(
+                ptr::null(),
+                call_callback::<F>,
+                &context,
+                paths.as_raw().cast(),
+                self.since,
+                self.latency.as_secs_f64(),
+                flags,
+            )
+        };
+
+        if raw.is_null() {
+            unsafe { release_callback::<F>(context.info) };
+            None
+        } else {
+            Some(FSEventStream { raw })
+        }
+    }
+}
+
+impl FSEventStream {
+    #[inline]
+    #[doc(alias = "FSEventStreamStart")]
+    fn start(self) -> Option<Self> {
+        if unsafe { sys::FSEventStreamStart(self.raw) } != 0 {
+            Some(self)
+        } else {
+            None
+        }
+    }
+}
+
+unsafe extern "C" fn call_callback<F>(
+    _stream: *const sys::FSEventStreamRef,
+    info: *mut c_void,
+    num_events: usize,
+    event_paths: *const *const c_char,
+    event_flags: *const FSEventStreamEventFlags,
+    event_ids: *const FSEventStreamEventId,
+) where
+    F: FnMut(FSEvent) + Send + 'static,
+{
+    let callback = &mut *(info as *mut F);
+    let paths = slice::from_raw_parts(event_paths, num_events);
+    let flags = slice::from_raw_parts(event_flags, num_events);
+    let ids = slice::from_raw_parts(event_ids, num_events);
+
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+        for ((&path, &flags), &id) in paths.iter().zip(flags).zip(ids) {
+            let path = Path::new(OsStr::from_bytes(CStr::from_ptr(path).to_bytes()));
+            callback(FSEvent { path, flags, id });
+        }
+    }));
+
+    // Unwinding into FSEvents is undefined behavior.
+    if result.is_err() {
+        process::abort();
+    }
+}
+
+unsafe extern "C" fn release_callback<F>(info: *const c_void) {
+    drop(Box::from_raw(info as *mut F));
+}
+
+/// The ID of an event reported by an [`FSEventStream`].
+///
+/// IDs increase over time and are shared by all volumes, so they can be used
+/// to resume a stream across launches.
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FSEventStreamEventId(pub u64);
+
+impl FSEventStreamEventId {
+    /// Reports only events that occur after the stream starts.
+    #[doc(alias = "kFSEventStreamEventIdSinceNow")]
+    pub const SINCE_NOW: Self = Self(u64::MAX);
+
+    /// Returns the ID of the most recent event of any stream.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreservices/fseventsgetcurrenteventid()).
+    #[inline]
+    #[doc(alias = "FSEventsGetCurrentEventId")]
+    pub fn current() -> Self {
+        unsafe { sys::FSEventsGetCurrentEventId() }
+    }
+}
+
+/// Options for creating an [`FSEventStream`].
+///
+/// See [documentation](https://developer.apple.com/documentation/coreservices/fseventstreamcreateflags).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct FSEventStreamCreateFlags(pub u32);
+
+impl ops::BitOr for FSEventStreamCreateFlags {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl FSEventStreamCreateFlags {
+    /// The default behavior.
+    #[doc(alias = "kFSEventStreamCreateFlagNone")]
+    pub const NONE: Self = Self(0);
+
+    /// Reports an event as soon as it occurs after a period without events,
+    /// rather than only after the latency.
+    #[doc(alias = "kFSEventStreamCreateFlagNoDefer")]
+    pub const NO_DEFER: Self = Self(0x02);
+
+    /// Reports changes to the watched paths themselves, such as when they
+    /// are moved, with [`ROOT_CHANGED`](FSEventStreamEventFlags::ROOT_CHANGED).
+    #[doc(alias = "kFSEventStreamCreateFlagWatchRoot")]
+    pub const WATCH_ROOT: Self = Self(0x04);
+
+    /// Ignores events caused by the current process.
+    #[doc(alias = "kFSEventStreamCreateFlagIgnoreSelf")]
+    pub const IGNORE_SELF: Self = Self(0x08);
+
+    /// Reports events for individual files, rather than for the directories
+    /// that contain them.
+    #[doc(alias = "kFSEventStreamCreateFlagFileEvents")]
+    pub const FILE_EVENTS: Self = Self(0x10);
+
+    /// Marks events caused by the current process with
+    /// [`OWN_EVENT`](FSEventStreamEventFlags::OWN_EVENT).
+    #[doc(alias = "kFSEventStreamCreateFlagMarkSelf")]
+    pub const MARK_SELF: Self = Self(0x20);
+
+    /// Reports every past event since the start ID, rather than only the
+    /// final state of each path.
+    #[doc(alias = "kFSEventStreamCreateFlagFullHistory")]
+    pub const FULL_HISTORY: Self = Self(0x80);
+
+    /// Returns `true` if all flags in `other` are set in `self`.
+    #[inline]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+/// What happened in an [`FSEvent`].
+///
+/// The `ITEM_` flags are only set with
+/// [`FILE_EVENTS`](FSEventStreamCreateFlags::FILE_EVENTS). Multiple changes
+/// to a path within the latency are combined into one event.
+///
+/// See [documentation](https://developer.apple.com/documentation/coreservices/fseventstreameventflags).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct FSEventStreamEventFlags(pub u32);
+
+impl ops::BitOr for FSEventStreamEventFlags {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl FSEventStreamEventFlags {
+    /// Something changed in the directory.
+    #[doc(alias = "kFSEventStreamEventFlagNone")]
+    pub const NONE: Self = Self(0);
+
+    /// Events were coalesced, so the directory and its subdirectories must
+    /// be rescanned.
+    #[doc(alias = "kFSEventStreamEventFlagMustScanSubDirs")]
+    pub const MUST_SCAN_SUB_DIRS: Self = Self(0x0000_0001);
+
+    /// Events were dropped because the current process fell behind.
+    #[doc(alias = "kFSEventStreamEventFlagUserDropped")]
+    pub const USER_DROPPED: Self = Self(0x0000_0002);
+
+    /// Events were dropped because the kernel fell behind.
+    #[doc(alias = "kFSEventStreamEventFlagKernelDropped")]
+    pub const KERNEL_DROPPED: Self = Self(0x0000_0004);
+
+    /// Event IDs wrapped around, so persisted IDs are no longer valid.
+    #[doc(alias = "kFSEventStreamEventFlagEventIdsWrapped")]
+    pub const EVENT_IDS_WRAPPED: Self = Self(0x0000_0008);
+
+    /// All past events requested with
+    /// [`since`](FSEventStreamBuilder::since) have been reported.
+    #[doc(alias = "kFSEventStreamEventFlagHistoryDone")]
+    pub const HISTORY_DONE: Self = Self(0x0000_0010);
+
+    /// A watched path or one of its parents changed.
+    #[doc(alias = "kFSEventStreamEventFlagRootChanged")]
+    pub const ROOT_CHANGED: Self = Self(0x0000_0020);
+
+    /// A volume was mounted under a watched path.
+    #[doc(alias = "kFSEventStreamEventFlagMount")]
+    pub const MOUNT: Self = Self(0x0000_0040);
+
+    /// A volume was unmounted under a watched path.
+    #[doc(alias = "kFSEventStreamEventFlagUnmount")]
+    pub const UNMOUNT: Self = Self(0x0000_0080);
+
+    /// The item was created.
+    #[doc(alias = "kFSEventStreamEventFlagItemCreated")]
+    pub const ITEM_CREATED: Self = Self(0x0000_0100);
+
+    /// The item was removed.
+    #[doc(alias = "kFSEventStreamEventFlagItemRemoved")]
+    pub const ITEM_REMOVED: Self = Self(0x0000_0200);
+
+    /// The metadata of the item changed, such as its modification time.
+    #[doc(alias = "kFSEventStreamEventFlagItemInodeMetaMod")]
+    pub const ITEM_INODE_META_MOD: Self = Self(0x0000_0400);
+
+    /// The item was renamed, either from or to this path.
+    #[doc(alias = "kFSEventStreamEventFlagItemRenamed")]
+    pub const ITEM_RENAMED: Self = Self(0x0000_0800);
+
+    /// The contents of the item changed.
+    #[doc(alias = "kFSEventStreamEventFlagItemModified")]
+    pub const ITEM_MODIFIED: Self = Self(0x0000_1000);
+
+    /// The Finder info of the item changed.
+    #[doc(alias = "kFSEventStreamEventFlagItemFinderInfoMod")]
+    pub const ITEM_FINDER_INFO_MOD: Self = Self(0x0000_2000);
+
+    /// The owner of the item changed.
+    #[doc(alias = "kFSEventStreamEventFlagItemChangeOwner")]
+    pub const ITEM_CHANGE_OWNER: Self = Self(0x0000_4000);
+
+    /// The extended attributes of the item changed.
+    #[doc(alias = "kFSEventStreamEventFlagItemXattrMod")]
+    pub const ITEM_XATTR_MOD: Self = Self(0x0000_8000);
+
+    /// The item is a file.
+    #[doc(alias = "kFSEventStreamEventFlagItemIsFile")]
+    pub const ITEM_IS_FILE: Self = Self(0x0001_0000);
+
+    /// The item is a directory.
+    #[doc(alias = "kFSEventStreamEventFlagItemIsDir")]
+    pub const ITEM_IS_DIR: Self = Self(0x0002_0000);
+
+    /// The item is a symbolic link.
+    #[doc(alias = "kFSEventStreamEventFlagItemIsSymlink")]
+    pub const ITEM_IS_SYMLINK: Self = Self(0x0004_0000);
+
+    /// The event was caused by the current process, with
+    /// [`MARK_SELF`](FSEventStreamCreateFlags::MARK_SELF).
+    #[doc(alias = "kFSEventStreamEventFlagOwnEvent")]
+    pub const OWN_EVENT: Self = Self(0x0008_0000);
+
+    /// The item is a hard link.
+    #[doc(alias = "kFSEventStreamEventFlagItemIsHardlink")]
+    pub const ITEM_IS_HARDLINK: Self = Self(0x0010_0000);
+
+    /// The item was the last hard link to a file.
+    #[doc(alias = "kFSEventStreamEventFlagItemIsLastHardlink")]
+    pub const ITEM_IS_LAST_HARDLINK: Self = Self(0x0020_0000);
+
+    /// The item is a clone or was cloned.
+    #[doc(alias = "kFSEventStreamEventFlagItemCloned")]
+    pub const ITEM_CLONED: Self = Self(0x0040_0000);
+
+    /// Returns `true` if all flags in `other` are set in `self`.
+    #[inline]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns `true` if any flag in `other` is set in `self`.
+    #[inline]
+    pub const fn intersects(self, other: Self) -> bool {
+        self.0 & other.0 != 0
+    }
+}
@@ -0,0 +1,286 @@
+//! [Launch Services](https://developer.apple.com/documentation/coreservices/launch_services)
+//! for opening URLs and files, and for finding the apps that handle them.
+//!
+//! To handle a URL scheme, declare it in the app's `Info.plist`, make the app
+//! its default handler with [`url_scheme::set_default_handler`], and receive
+//! URLs with [`AEEventHandler::get_url`]. Other apps, or the app itself, can
+//! then invoke the scheme with [`open_url`].
+//!
+//! [`url_scheme::set_default_handler`]: super::url_scheme::set_default_handler
+//! [`AEEventHandler::get_url`]: super::AEEventHandler::get_url
+
+use super::sys;
+use crate::core::{Arc, OSStatus};
+use crate::core_foundation::{CFArray, CFError, CFString, CFURL};
+use std::{ops, ptr};
+
+/// The roles of an app for handling content, such as viewing or editing.
+///
+/// See [documentation](https://developer.apple.com/documentation/coreservices/lsrolesmask).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct LSRolesMask(pub u32);
+
+impl ops::BitOr for LSRolesMask {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl Default for LSRolesMask {
+    #[inline]
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+
+impl LSRolesMask {
+    /// No role.
+    #[doc(alias = "kLSRolesNone")]
+    pub const NONE: Self = Self(0x0000_0001);
+
+    /// Can view and print content.
+    #[doc(alias = "kLSRolesViewer")]
+    pub const VIEWER: Self = Self(0x0000_0002);
+
+    /// Can view, print, and edit content.
+    #[doc(alias = "kLSRolesEditor")]
+    pub const EDITOR: Self = Self(0x0000_0004);
+
+    /// Can run content, such as scripts.
+    #[doc(alias = "kLSRolesShell")]
+    pub const SHELL: Self = Self(0x0000_0008);
+
+    /// Any role.
+    #[doc(alias = "kLSRolesAll")]
+    pub const ALL: Self = Self(0xFFFF_FFFF);
+
+    /// Returns `true` if all roles in `other` are set in `self`.
+    #[inline]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+/// Options for [`open_urls_with`].
+///
+/// See [documentation](https://developer.apple.com/documentation/coreservices/lslaunchflags).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct LSLaunchFlags(pub u32);
+
+impl ops::BitOr for LSLaunchFlags {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl Default for LSLaunchFlags {
+    #[inline]
+    fn default() -> Self {
+        Self::DEFAULTS
+    }
+}
+
+impl LSLaunchFlags {
+    /// Opens the items in the foreground.
+    #[doc(alias = "kLSLaunchDefaults")]
+    pub const DEFAULTS: Self = Self(0x0000_0001);
+
+    /// Prints the items instead of opening them.
+    #[doc(alias = "kLSLaunchAndPrint")]
+    pub const AND_PRINT: Self = Self(0x0000_0002);
+
+    /// Shows an alert if the items cannot be opened.
+    #[doc(alias = "kLSLaunchAndDisplayErrors")]
+    pub const AND_DISPLAY_ERRORS: Self = Self(0x0000_0040);
+
+    /// Does not add the app or items to the Recent Items menu.
+    #[doc(alias = "kLSLaunchDontAddToRecents")]
+    pub const DONT_ADD_TO_RECENTS: Self = Self(0x0000_0100);
+
+    /// Opens the app in the background.
+    #[doc(alias = "kLSLaunchDontSwitch")]
+    pub const DONT_SWITCH: Self = Self(0x0000_0200);
+
+    /// Returns without waiting for the app to finish launching.
+    #[doc(alias = "kLSLaunchAsync")]
+    pub const ASYNC: Self = Self(0x0001_0000);
+
+    /// Launches a new instance even if the app is already running.
+    #[doc(alias = "kLSLaunchNewInstance")]
+    pub const NEW_INSTANCE: Self = Self(0x0008_0000);
+
+    /// Hides the app after it launches.
+    #[doc(alias = "kLSLaunchAndHide")]
+    pub const AND_HIDE: Self = Self(0x0010_0000);
+
+    /// Hides other apps after the app launches.
+    #[doc(alias = "kLSLaunchAndHideOthers")]
+    pub const AND_HIDE_OTHERS: Self = Self(0x0020_0000);
+
+    /// Returns `true` if all flags in `other` are set in `self`.
+    #[inline]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+/// Opens `url` in its default app, returning the URL of that app.
+///
+/// File URLs open the file, and other URLs are handled by the default
+/// handler for their scheme.
+///
+/// See [documentation](https://developer.apple.com/documentation/coreservices/lsopencfurlref(_:_:)).
+#[inline]
+#[doc(alias = "LSOpenCFURLRef")]
+pub fn open_url(url: &CFURL) -> Result<Arc<CFURL>, OSStatus> {
+    unsafe {
+        let mut launched: *const CFURL = ptr::null();
+        match sys::LSOpenCFURLRef(url, &mut launched) {
+            Some(error) => Err(error),
+            None => Ok(Arc::from_raw(launched)),
+        }
+    }
+}
+
+/// Opens `urls` in the app at `application`, or in their default apps if
+/// [`None`], returning the URL of the app that was launched.
+///
+/// If `urls` is empty, `application` is launched without opening anything.
+/// With [`LSLaunchFlags::ASYNC`], the app URL may not be known yet and
+/// [`None`] is returned instead.
+///
+/// See [documentation](https://developer.apple.com/documentation/coreservices/lsopenfromurlspec(_:_:)).
+#[doc(alias = "LSOpenFromURLSpec")]
+pub fn open_urls_with(
+    urls: &CFArray<CFURL>,
+    application: Option<&CFURL>,
+    flags: LSLaunchFlags,
+) -> Result<Option<Arc<CFURL>>, OSStatus> {
+    let spec = sys::LSLaunchURLSpec {
+        app_url: match application {
+            Some(application) => application,
+            None => ptr::null(),
+        },
+        item_urls: if urls.is_empty() { ptr::null() } else { urls },
+        pass_thru_params: ptr::null(),
+        launch_flags: flags,
+        async_ref_con: ptr::null_mut(),
+    };
+
+    unsafe {
+        let mut launched: *const CFURL = ptr::null();
+        match sys::LSOpenFromURLSpec(&spec, &mut launched) {
+            Some(error) => Err(error),
+            None if launched.is_null() => Ok(None),
+            None => Ok(Some(Arc::from_raw(launched))),
+        }
+    }
+}
+
+/// Returns the URL of the default app for opening `url` in `roles`.
+///
+/// See [documentation](https://developer.apple.com/documentation/coreservices/lscopydefaultapplicationurlforurl(_:_:_:)).
+#[inline]
+#[doc(alias = "LSCopyDefaultApplicationURLForURL")]
+pub fn default_application_url(
+    url: &CFURL,
+    roles: LSRolesMask,
+) -> Result<Arc<CFURL>, Arc<CFError>> {
+    unsafe {
+        let mut error: *const CFError = ptr::null();
+        let application = sys::LSCopyDefaultApplicationURLForURL(url, roles, &mut error);
+        if application.is_null() {
+            Err(Arc::from_raw(error))
+        } else {
+            Ok(Arc::from_raw(application))
+        }
+    }
+}
+
+/// Returns the URL of the default app for opening files of the uniform type
+/// identifier `content_type`, such as `public.plain-text`, in `roles`.
+///
+/// See [documentation](https://developer.apple.com/documentation/coreservices/lscopydefaultapplicationurlforcontenttype(_:_:_:)).
+#[inline]
+#[doc(alias = "LSCopyDefaultApplicationURLForContentType")]
+pub fn default_application_url_for_content_type(
+    content_type: &CFString,
+    roles: LSRolesMask,
+) -> Result<Arc<CFURL>, Arc<CFError>> {
+    unsafe {
+        let mut error: *const CFError = ptr::null();
+        let application =
+            sys::LSCopyDefaultApplicationURLForContentType(content_type, roles, &mut error);
+        if application.is_null() {
+            Err(Arc::from_raw(error))
+        } else {
+            Ok(Arc::from_raw(application))
+        }
+    }
+}
+
+/// Returns the URLs of all installed apps with `bundle_id`, starting with
+/// the one that Launch Services prefers.
+///
+/// See [documentation](https://developer.apple.com/documentation/coreservices/lscopyapplicationurlsforbundleidentifier(_:_:)).
+#[inline]
+#[doc(alias = "LSCopyApplicationURLsForBundleIdentifier")]
+pub fn application_urls(bundle_id: &CFString) -> Result<Arc<CFArray<CFURL>>, Arc<CFError>> {
+    unsafe {
+        let mut error: *const CFError = ptr::null();
+        let applications = sys::LSCopyApplicationURLsForBundleIdentifier(bundle_id, &mut error);
+        if applications.is_null() {
+            Err(Arc::from_raw(error))
+        } else {
+            Ok(Arc::from_raw(applications))
+        }
+    }
+}
+
+/// Returns the bundle identifier of the default app for the uniform type
+/// identifier `content_type` in `roles`, or [`None`] if there is none.
+///
+/// See [documentation](https://developer.apple.com/documentation/coreservices/lscopydefaultrolehandlerforcontenttype(_:_:)).
+#[inline]
+#[doc(alias = "LSCopyDefaultRoleHandlerForContentType")]
+pub fn default_handler_for_content_type(
+    content_type: &CFString,
+    roles: LSRolesMask,
+) -> Option<Arc<CFString>> {
+    unsafe {
+        let handler = sys::LSCopyDefaultRoleHandlerForContentType(content_type, roles);
+        if handler.is_null() {
+            None
+        } else {
+            Some(Arc::from_raw(handler))
+        }
+    }
+}
+
+/// Makes the app with `bundle_id` the default app for the uniform type
+/// identifier `content_type` in `roles`.
+///
+/// To set the default handler for a URL scheme instead, use
+/// [`url_scheme::set_default_handler`](super::url_scheme::set_default_handler).
+///
+/// See [documentation](https://developer.apple.com/documentation/coreservices/lssetdefaultrolehandlerforcontenttype(_:_:_:)).
+#[inline]
+#[doc(alias = "LSSetDefaultRoleHandlerForContentType")]
+pub fn set_default_handler_for_content_type(
+    content_type: &CFString,
+    roles: LSRolesMask,
+    bundle_id: &CFString,
+) -> Result<(), OSStatus> {
+    match unsafe { sys::LSSetDefaultRoleHandlerForContentType(content_type, roles, bundle_id) } {
+        Some(error) => Err(error),
+        None => Ok(()),
+    }
+}
@@ -0,0 +1,111 @@
+use super::sys;
+use crate::{
+    core::Arc,
+    core_foundation::{CFAllocator, CFArray, CFString, CFType, CFTypeID},
+};
+use std::ptr;
+
+subclass! {
+    /// Metadata associated with a file.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreservices/mditemref).
+    pub class MDItem: CFType<'static>;
+}
+
+impl MDItem {
+    /// Returns the type identifier for `MDItem`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreservices/mditemgettypeid()).
+    #[inline]
+    #[doc(alias = "MDItemGetTypeID")]
+    pub fn type_id() -> CFTypeID {
+        unsafe { sys::MDItemGetTypeID() }
+    }
+
+    /// Creates an item for the file at `path`.
+    ///
+    /// Returns [`None`] if the file does not exist.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreservices/mditemcreate(_:_:)).
+    #[inline]
+    #[doc(alias = "MDItemCreate")]
+    pub fn create(allocator: Option<&CFAllocator>, path: &CFString) -> Option<Arc<Self>> {
+        unsafe {
+            let item = sys::MDItemCreate(
+                match allocator {
+                    Some(allocator) => allocator,
+                    None => ptr::null(),
+                },
+                path,
+            );
+            if item.is_null() {
+                None
+            } else {
+                Some(Arc::from_raw(item))
+            }
+        }
+    }
+
+    /// Returns the value of the attribute `name`, or [`None`] if it is not
+    /// set.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreservices/mditemcopyattribute(_:_:)).
+    #[inline]
+    #[doc(alias = "MDItemCopyAttribute")]
+    pub fn copy_attribute(&self, name: &CFString) -> Option<Arc<CFType<'static>>> {
+        unsafe {
+            let value = sys::MDItemCopyAttribute(self, name);
+            if value.is_null() {
+                None
+            } else {
+                Some(Arc::from_raw(value))
+            }
+        }
+    }
+
+    /// Returns the Finder comment of the item, as shown in its "Get Info"
+    /// window.
+    ///
+    /// This reads the Spotlight
+    /// [`kMDItemFinderComment`](https://developer.apple.com/documentation/coreservices/kmditemfindercomment)
+    /// attribute, which may lag behind the Finder until the item is
+    /// re-indexed. Use [`finder::set_comment`](super::finder::set_comment) to
+    /// change it.
+    #[inline]
+    #[doc(alias = "kMDItemFinderComment")]
+    pub fn finder_comment(&self) -> Option<Arc<CFString>> {
+        extern "C" {
+            static kMDItemFinderComment: &'static CFString;
+        }
+
+        let value = self.copy_attribute(unsafe { kMDItemFinderComment })?;
+        if value.get_type_id() == CFString::type_id() {
+            Some(unsafe { Arc::cast_unchecked(value) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the names of the Finder tags assigned to the item.
+    ///
+    /// This reads the Spotlight
+    /// [`kMDItemUserTags`](https://developer.apple.com/documentation/coreservices/kmditemusertags)
+    /// attribute, which may lag behind the file system until the item is
+    /// re-indexed. Use `NSURL::tag_names` and `NSURL::set_tag_names` in
+    /// [`foundation`](crate::foundation) to access the tags directly.
+    #[inline]
+    #[doc(alias = "kMDItemUserTags")]
+    pub fn user_tags(&self) -> Option<Arc<CFArray<CFString>>> {
+        extern "C" {
+            static kMDItemUserTags: &'static CFString;
+        }
+
+        let value = self.copy_attribute(unsafe { kMDItemUserTags })?;
+        if value.get_type_id() == CFArray::<CFString>::type_id() {
+            // SAFETY: Spotlight stores tags as an array of strings.
+            Some(unsafe { Arc::cast_unchecked(value) })
+        } else {
+            None
+        }
+    }
+}
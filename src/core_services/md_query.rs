@@ -0,0 +1,454 @@
+use std::{
+    fmt::{self, Write},
+    ops,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+#[cfg(all(feature = "foundation", target_os = "macos"))]
+use crate::{
+    core::Arc,
+    foundation::{NSPredicate, NSString},
+};
+
+/// A Spotlight metadata query, built from typed comparisons.
+///
+/// Values are quoted and escaped when the query is built, so text from users
+/// cannot change the structure of the query as it can when a query string is
+/// formatted by hand.
+///
+/// The query string, which is what `mdfind` takes and what
+/// `to_predicate` passes to `NSMetadataQuery`, is
+/// returned by [`as_str`](Self::as_str) and [`Display`](fmt::Display).
+///
+/// See [documentation](https://developer.apple.com/library/archive/documentation/Carbon/Conceptual/SpotlightQuery/Concepts/QueryFormat.html).
+///
+/// # Examples
+///
+/// ```
+/// use fruity::core_services::{MDQuery, MDQueryAttribute, MDQueryTextOptions};
+///
+/// let query = MDQueryAttribute::DISPLAY_NAME
+///     .contains("report \"final\"", MDQueryTextOptions::CASE_INSENSITIVE)
+///     .and(MDQueryAttribute::FS_SIZE.greater_than(1_000_000.0));
+///
+/// assert_eq!(
+///     query.as_str(),
+///     r#"(kMDItemDisplayName == "*report \"final\"*"c) && (kMDItemFSSize > 1000000)"#,
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MDQuery(String);
+
+impl fmt::Display for MDQuery {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl MDQuery {
+    /// Returns the query string.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns a query that matches items matched by both `self` and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self(format!("({}) && ({})", self.0, other.0))
+    }
+
+    /// Returns a query that matches items matched by either `self` or
+    /// `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self(format!("({}) || ({})", self.0, other.0))
+    }
+
+    /// Returns a query that matches items not matched by `self`.
+    #[allow(clippy::should_implement_trait)]
+    pub fn not(self) -> Self {
+        Self(format!("!({})", self.0))
+    }
+
+    /// Returns the predicate for `NSMetadataQuery` that is equivalent to
+    /// `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nspredicate/init(frommetadataquerystring:)).
+    #[cfg(all(feature = "foundation", target_os = "macos"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "foundation", target_os = "macos"))))]
+    #[doc(alias = "predicateFromMetadataQueryString:")]
+    pub fn to_predicate(&self) -> Option<Arc<NSPredicate>> {
+        NSPredicate::from_metadata_query_string(&NSString::from_str(&self.0))
+    }
+}
+
+/// The name of a Spotlight metadata attribute, such as
+/// [`DISPLAY_NAME`](Self::DISPLAY_NAME), for building an [`MDQuery`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MDQueryAttribute<'a>(&'a str);
+
+impl<'a> MDQueryAttribute<'a> {
+    /// Returns the attribute named `name`, such as `"kMDItemFSName"`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `name` is empty or contains characters other than ASCII
+    /// letters, digits, and underscores, since it is not quoted in the query.
+    pub fn new(name: &'a str) -> Self {
+        assert!(
+            !name.is_empty() && name.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'_'),
+            "invalid Spotlight attribute name {:?}",
+            name,
+        );
+        Self(name)
+    }
+
+    /// Returns the name of the attribute.
+    #[inline]
+    pub fn name(self) -> &'a str {
+        self.0
+    }
+}
+
+impl MDQueryAttribute<'static> {
+    /// Matches any attribute, which searches all metadata of an item.
+    pub const ANY: Self = Self("*");
+
+    /// The name of the item as shown to the user.
+    #[doc(alias = "kMDItemDisplayName")]
+    pub const DISPLAY_NAME: Self = Self("kMDItemDisplayName");
+
+    /// The file name of the item.
+    #[doc(alias = "kMDItemFSName")]
+    pub const FS_NAME: Self = Self("kMDItemFSName");
+
+    /// The size of the file in bytes.
+    #[doc(alias = "kMDItemFSSize")]
+    pub const FS_SIZE: Self = Self("kMDItemFSSize");
+
+    /// The uniform type identifier of the item, such as `"public.jpeg"`.
+    #[doc(alias = "kMDItemContentType")]
+    pub const CONTENT_TYPE: Self = Self("kMDItemContentType");
+
+    /// The uniform type identifier of the item and all of the types it
+    /// conforms to, for matching items such as all images.
+    #[doc(alias = "kMDItemContentTypeTree")]
+    pub const CONTENT_TYPE_TREE: Self = Self("kMDItemContentTypeTree");
+
+    /// The date the contents of the item were created.
+    #[doc(alias = "kMDItemContentCreationDate")]
+    pub const CONTENT_CREATION_DATE: Self = Self("kMDItemContentCreationDate");
+
+    /// The date the contents of the item were last modified.
+    #[doc(alias = "kMDItemContentModificationDate")]
+    pub const CONTENT_MODIFICATION_DATE: Self = Self("kMDItemContentModificationDate");
+
+    /// The date the item was last opened.
+    #[doc(alias = "kMDItemLastUsedDate")]
+    pub const LAST_USED_DATE: Self = Self("kMDItemLastUsedDate");
+
+    /// The text content of a document.
+    #[doc(alias = "kMDItemTextContent")]
+    pub const TEXT_CONTENT: Self = Self("kMDItemTextContent");
+
+    /// The authors of a document.
+    #[doc(alias = "kMDItemAuthors")]
+    pub const AUTHORS: Self = Self("kMDItemAuthors");
+
+    /// The tags that the user assigned to the item in Finder.
+    #[doc(alias = "kMDItemUserTags")]
+    pub const USER_TAGS: Self = Self("kMDItemUserTags");
+
+    /// The comment shown in the "Get Info" window of the item in Finder.
+    #[doc(alias = "kMDItemFinderComment")]
+    pub const FINDER_COMMENT: Self = Self("kMDItemFinderComment");
+}
+
+/// Text comparisons.
+impl MDQueryAttribute<'_> {
+    /// Matches items whose value of `self` is exactly `text`.
+    pub fn equals(self, text: &str, options: MDQueryTextOptions) -> MDQuery {
+        self.compare_text("==", "", text, "", options)
+    }
+
+    /// Matches items whose value of `self` is not `text`.
+    pub fn not_equals(self, text: &str, options: MDQueryTextOptions) -> MDQuery {
+        self.compare_text("!=", "", text, "", options)
+    }
+
+    /// Matches items whose value of `self` contains `text`.
+    pub fn contains(self, text: &str, options: MDQueryTextOptions) -> MDQuery {
+        self.compare_text("==", "*", text, "*", options)
+    }
+
+    /// Matches items whose value of `self` starts with `text`.
+    pub fn starts_with(self, text: &str, options: MDQueryTextOptions) -> MDQuery {
+        self.compare_text("==", "", text, "*", options)
+    }
+
+    /// Matches items whose value of `self` ends with `text`.
+    pub fn ends_with(self, text: &str, options: MDQueryTextOptions) -> MDQuery {
+        self.compare_text("==", "*", text, "", options)
+    }
+
+    /// Matches items whose value of `self` matches `pattern`, in which `*`
+    /// matches any number of characters and `?` matches one character.
+    ///
+    /// Quotes and backslashes in `pattern` are escaped, but wildcards are
+    /// not. Use [`contains`](Self::contains) and similar methods for text
+    /// that may contain literal `*` or `?` characters.
+    pub fn matches(self, pattern: &str, options: MDQueryTextOptions) -> MDQuery {
+        let mut query = format!("{} == \"", self.0);
+        escape(&mut query, pattern, false);
+        query.push('"');
+        options.write(&mut query);
+        MDQuery(query)
+    }
+
+    fn compare_text(
+        self,
+        operator: &str,
+        prefix: &str,
+        text: &str,
+        suffix: &str,
+        options: MDQueryTextOptions,
+    ) -> MDQuery {
+        let mut query = format!("{} {} \"{}", self.0, operator, prefix);
+        escape(&mut query, text, true);
+        query.push_str(suffix);
+        query.push('"');
+        options.write(&mut query);
+        MDQuery(query)
+    }
+}
+
+/// Numeric comparisons.
+///
+/// # Panics
+///
+/// These panic if a value is not finite.
+impl MDQueryAttribute<'_> {
+    /// Matches items whose value of `self` is `value`.
+    pub fn equals_number(self, value: f64) -> MDQuery {
+        self.compare_number("==", value)
+    }
+
+    /// Matches items whose value of `self` is less than `value`.
+    pub fn less_than(self, value: f64) -> MDQuery {
+        self.compare_number("<", value)
+    }
+
+    /// Matches items whose value of `self` is greater than `value`.
+    pub fn greater_than(self, value: f64) -> MDQuery {
+        self.compare_number(">", value)
+    }
+
+    /// Matches items whose value of `self` is within `range`, including both
+    /// ends.
+    #[doc(alias = "InRange")]
+    pub fn in_range(self, range: ops::RangeInclusive<f64>) -> MDQuery {
+        MDQuery(format!(
+            "InRange({}, {}, {})",
+            self.0,
+            number(*range.start()),
+            number(*range.end()),
+        ))
+    }
+
+    fn compare_number(self, operator: &str, value: f64) -> MDQuery {
+        MDQuery(format!("{} {} {}", self.0, operator, number(value)))
+    }
+}
+
+/// Date comparisons.
+impl MDQueryAttribute<'_> {
+    /// Matches items whose date of `self` is before `time`.
+    pub fn before(self, time: SystemTime) -> MDQuery {
+        MDQuery(format!("{} < {}", self.0, date(time)))
+    }
+
+    /// Matches items whose date of `self` is at or after `time`.
+    pub fn after(self, time: SystemTime) -> MDQuery {
+        MDQuery(format!("{} >= {}", self.0, date(time)))
+    }
+
+    /// Matches items whose date of `self` is at or after the start of `range`
+    /// and before its end.
+    pub fn between(self, range: ops::Range<SystemTime>) -> MDQuery {
+        self.after(range.start).and(self.before(range.end))
+    }
+}
+
+/// Options for comparing text in an [`MDQuery`].
+///
+/// See [documentation](https://developer.apple.com/library/archive/documentation/Carbon/Conceptual/SpotlightQuery/Concepts/QueryFormat.html#//apple_ref/doc/uid/TP40001849-CJBEJBHH).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct MDQueryTextOptions(u8);
+
+impl ops::BitOr for MDQueryTextOptions {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl MDQueryTextOptions {
+    /// Compares text exactly.
+    pub const NONE: Self = Self(0);
+
+    /// Ignores the case of letters. This is the `c` modifier.
+    pub const CASE_INSENSITIVE: Self = Self(1 << 0);
+
+    /// Ignores diacritics, such as accents. This is the `d` modifier.
+    pub const DIACRITIC_INSENSITIVE: Self = Self(1 << 1);
+
+    /// Matches the text at the start of words, such as "Report" in
+    /// "AnnualReport". This is the `w` modifier.
+    pub const WORD: Self = Self(1 << 2);
+
+    /// Returns `true` if all options in `other` are set in `self`.
+    #[inline]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    fn write(self, query: &mut String) {
+        let modifiers = [
+            (Self::CASE_INSENSITIVE, 'c'),
+            (Self::DIACRITIC_INSENSITIVE, 'd'),
+            (Self::WORD, 'w'),
+        ];
+        for &(option, modifier) in &modifiers {
+            if self.contains(option) {
+                query.push(modifier);
+            }
+        }
+    }
+}
+
+/// Appends `text` to `query` with quotes and backslashes escaped, as well as
+/// wildcards if `literal`.
+fn escape(query: &mut String, text: &str, literal: bool) {
+    for ch in text.chars() {
+        let escaped = match ch {
+            '"' | '\\' => true,
+            '*' | '?' => literal,
+            _ => false,
+        };
+        if escaped {
+            query.push('\\');
+        }
+        query.push(ch);
+    }
+}
+
+fn number(value: f64) -> String {
+    assert!(
+        value.is_finite(),
+        "Spotlight query value {} is not finite",
+        value
+    );
+    value.to_string()
+}
+
+/// Formats `time` as a `$time.iso` date in UTC, truncated to seconds.
+fn date(time: SystemTime) -> String {
+    let seconds = match time.duration_since(UNIX_EPOCH) {
+        Ok(duration) => duration.as_secs() as i64,
+        Err(error) => -(error.duration().as_secs_f64().ceil() as i64),
+    };
+
+    let days = seconds.div_euclid(86_400);
+    let time_of_day = seconds.rem_euclid(86_400);
+    let (year, month, day) = civil_from_days(days);
+
+    let mut date = String::new();
+    let _ = write!(
+        date,
+        "$time.iso({:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z)",
+        year,
+        month,
+        day,
+        time_of_day / 3600,
+        time_of_day / 60 % 60,
+        time_of_day % 60,
+    );
+    date
+}
+
+/// Returns the Gregorian date of `days` since 1970-01-01.
+///
+/// This is Howard Hinnant's `civil_from_days` algorithm.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = year_of_era + era * 400 + (month <= 2) as i64;
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn escapes_text() {
+        let query = MDQueryAttribute::FS_NAME.contains(r#"a"b\c*d?"#, MDQueryTextOptions::NONE);
+        assert_eq!(query.as_str(), r#"kMDItemFSName == "*a\"b\\c\*d\?*""#);
+
+        let query = MDQueryAttribute::FS_NAME.matches("*.rs\"", MDQueryTextOptions::NONE);
+        assert_eq!(query.as_str(), r#"kMDItemFSName == "*.rs\"""#);
+    }
+
+    #[test]
+    fn options() {
+        let options = MDQueryTextOptions::CASE_INSENSITIVE
+            | MDQueryTextOptions::DIACRITIC_INSENSITIVE
+            | MDQueryTextOptions::WORD;
+        let query = MDQueryAttribute::ANY.equals("cafe", options);
+        assert_eq!(query.as_str(), r#"* == "cafe"cdw"#);
+    }
+
+    #[test]
+    fn composition() {
+        let query = MDQueryAttribute::CONTENT_TYPE_TREE
+            .equals("public.image", MDQueryTextOptions::NONE)
+            .and(MDQueryAttribute::FS_SIZE.in_range(1.0..=2.5).not())
+            .or(MDQueryAttribute::USER_TAGS.starts_with("Red", MDQueryTextOptions::NONE));
+        assert_eq!(
+            query.as_str(),
+            r#"((kMDItemContentTypeTree == "public.image") && (!(InRange(kMDItemFSSize, 1, 2.5)))) || (kMDItemUserTags == "Red*")"#,
+        );
+    }
+
+    #[test]
+    fn dates() {
+        let start = UNIX_EPOCH + Duration::from_secs(951_782_400); // 2000-02-29
+        let end = start + Duration::from_secs(86_400 + 3_661);
+        let query = MDQueryAttribute::CONTENT_MODIFICATION_DATE.between(start..end);
+        assert_eq!(
+            query.as_str(),
+            "(kMDItemContentModificationDate >= $time.iso(2000-02-29T00:00:00Z)) && \
+             (kMDItemContentModificationDate < $time.iso(2000-03-01T01:01:01Z))",
+        );
+
+        let before_epoch = UNIX_EPOCH - Duration::from_secs(1);
+        assert_eq!(date(before_epoch), "$time.iso(1969-12-31T23:59:59Z)");
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_attribute() {
+        MDQueryAttribute::new("kMDItemFSName == \"\" || kMDItemFSName");
+    }
+}
@@ -6,13 +6,50 @@
 //! This module corresponds to the **`core_services`**
 //! [feature flag](../index.html#feature-flags).
 //!
+//! Apple Events, FSEvents, Launch Services, Spotlight metadata, Time Machine,
+//! and text input sources are only available on macOS. On iOS, this module
+//! provides the uniform type identifier functions in [`sys`], [`apfs`], and
+//! [`xattr`].
+//!
 //! # Documentation
 //!
 
 #![cfg(feature = "core_services")]
 
+pub mod apfs;
+#[cfg(target_os = "macos")]
+pub mod finder;
+#[cfg(target_os = "macos")]
+pub mod launch_services;
 pub mod sys;
+#[cfg(target_os = "macos")]
+pub mod system_events;
+#[cfg(target_os = "macos")]
+pub mod time_machine;
+#[cfg(target_os = "macos")]
+pub mod url_scheme;
+pub mod xattr;
 
+#[cfg(target_os = "macos")]
 mod apple_events;
+mod finder_label;
+#[cfg(target_os = "macos")]
+mod fs_events;
+#[cfg(target_os = "macos")]
+mod md_item;
+#[cfg(target_os = "macos")]
+mod md_query;
+#[cfg(target_os = "macos")]
+mod tis_input_source;
 
+#[cfg(target_os = "macos")]
 pub use apple_events::*;
+pub use finder_label::*;
+#[cfg(target_os = "macos")]
+pub use fs_events::*;
+#[cfg(target_os = "macos")]
+pub use md_item::*;
+#[cfg(target_os = "macos")]
+pub use md_query::*;
+#[cfg(target_os = "macos")]
+pub use tis_input_source::*;
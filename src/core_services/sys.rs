@@ -1,4 +1,349 @@
 //! Raw unsafe C functions exposed by `CoreServices.framework`.
 
+use crate::core_foundation::CFString;
+use std::os::raw::c_uchar;
+
+#[cfg(target_os = "macos")]
+use super::launch_services::{LSLaunchFlags, LSRolesMask};
+#[cfg(target_os = "macos")]
+use super::{
+    AEDesc, AEDescType, AEEventClass, AEEventHandlerProcPtr, AEEventID, AEKeyword, AEReturnID,
+    AESendMode, AETransactionID, FSEventStreamCreateFlags, FSEventStreamEventFlags,
+    FSEventStreamEventId, MDItem, TISInputSource,
+};
+#[cfg(target_os = "macos")]
+use crate::core::{OSErr, OSStatus};
+#[cfg(target_os = "macos")]
+use crate::core_foundation::{
+    CFAllocator, CFArray, CFData, CFError, CFIndex, CFRunLoop, CFRunLoopMode, CFTimeInterval,
+    CFType, CFTypeID, CFURL,
+};
+#[cfg(target_os = "macos")]
+use std::{
+    ffi::c_void,
+    os::raw::{c_char, c_long, c_ulong},
+};
+
+#[cfg(all(feature = "dispatch", target_os = "macos"))]
+use crate::dispatch::DispatchQueue;
+
+#[allow(non_upper_case_globals, missing_docs)]
+#[cfg(target_os = "macos")]
+pub const kFSEventStreamCreateFlagUseCFTypes: u32 = 0x01;
+#[allow(non_upper_case_globals, missing_docs)]
+#[cfg(target_os = "macos")]
+pub const kFSEventStreamCreateFlagUseExtendedData: u32 = 0x40;
+
+/// An opaque `FSEventStreamRef`.
+#[cfg(target_os = "macos")]
+#[repr(C)]
+pub struct FSEventStreamRef {
+    _private: [u8; 0],
+}
+
+#[allow(missing_docs)]
+#[cfg(target_os = "macos")]
+pub type FSEventStreamCallback = unsafe extern "C" fn(
+    stream: *const FSEventStreamRef,
+    info: *mut c_void,
+    num_events: usize,
+    event_paths: *const *const c_char,
+    event_flags: *const FSEventStreamEventFlags,
+    event_ids: *const FSEventStreamEventId,
+);
+
+#[allow(missing_docs)]
+#[cfg(target_os = "macos")]
+#[repr(C)]
+pub struct FSEventStreamContext {
+    pub version: CFIndex,
+    pub info: *mut c_void,
+    pub retain: Option<unsafe extern "C" fn(info: *const c_void) -> *const c_void>,
+    pub release: Option<unsafe extern "C" fn(info: *const c_void)>,
+    pub copy_description: Option<unsafe extern "C" fn(info: *const c_void) -> *const CFString>,
+}
+
+#[allow(missing_docs)]
+#[cfg(target_os = "macos")]
+#[repr(C, packed(2))]
+pub struct LSLaunchURLSpec {
+    pub app_url: *const CFURL,
+    pub item_urls: *const CFArray<CFURL>,
+    pub pass_thru_params: *const AEDesc,
+    pub launch_flags: LSLaunchFlags,
+    pub async_ref_con: *mut c_void,
+}
+
+#[allow(missing_docs)]
+#[link(name = "CoreServices", kind = "framework")]
+extern "C" {
+    pub static kUTTagClassFilenameExtension: &'static CFString;
+    pub static kUTTagClassMIMEType: &'static CFString;
+
+    pub fn UTTypeCreatePreferredIdentifierForTag(
+        in_tag_class: *const CFString,
+        in_tag: *const CFString,
+        in_conforming_to_uti: *const CFString,
+    ) -> *const CFString;
+    pub fn UTTypeCopyPreferredTagWithClass(
+        in_uti: *const CFString,
+        in_tag_class: *const CFString,
+    ) -> *const CFString;
+    pub fn UTTypeConformsTo(
+        in_uti: *const CFString,
+        in_conforms_to_uti: *const CFString,
+    ) -> c_uchar;
+    pub fn UTTypeCopyDescription(in_uti: *const CFString) -> *const CFString;
+    pub fn UTTypeIsDeclared(in_uti: *const CFString) -> c_uchar;
+    pub fn UTTypeIsDynamic(in_uti: *const CFString) -> c_uchar;
+}
+
+#[allow(missing_docs)]
+#[cfg(target_os = "macos")]
+#[link(name = "CoreServices", kind = "framework")]
+extern "C" {
+    pub fn MDItemGetTypeID() -> CFTypeID;
+
+    pub fn MDItemCreate(allocator: *const CFAllocator, path: *const CFString) -> *const MDItem;
+
+    pub fn MDItemCopyAttribute(
+        item: *const MDItem,
+        name: *const CFString,
+    ) -> *const CFType<'static>;
+
+    pub fn LSCopyDefaultHandlerForURLScheme(in_url_scheme: *const CFString) -> *const CFString;
+    pub fn LSCopyAllHandlersForURLScheme(
+        in_url_scheme: *const CFString,
+    ) -> *const CFArray<CFString>;
+    pub fn LSSetDefaultHandlerForURLScheme(
+        in_url_scheme: *const CFString,
+        in_handler_bundle_id: *const CFString,
+    ) -> Option<OSStatus>;
+
+    pub fn LSOpenCFURLRef(
+        in_url: *const CFURL,
+        out_launched_url: *mut *const CFURL,
+    ) -> Option<OSStatus>;
+    pub fn LSOpenFromURLSpec(
+        in_launch_spec: *const LSLaunchURLSpec,
+        out_launched_url: *mut *const CFURL,
+    ) -> Option<OSStatus>;
+    pub fn LSCopyDefaultApplicationURLForURL(
+        in_url: *const CFURL,
+        in_role_mask: LSRolesMask,
+        out_error: *mut *const CFError,
+    ) -> *const CFURL;
+    pub fn LSCopyDefaultApplicationURLForContentType(
+        in_content_type: *const CFString,
+        in_role_mask: LSRolesMask,
+        out_error: *mut *const CFError,
+    ) -> *const CFURL;
+    pub fn LSCopyApplicationURLsForBundleIdentifier(
+        in_bundle_identifier: *const CFString,
+        out_error: *mut *const CFError,
+    ) -> *const CFArray<CFURL>;
+    pub fn LSCopyDefaultRoleHandlerForContentType(
+        in_content_type: *const CFString,
+        in_role: LSRolesMask,
+    ) -> *const CFString;
+    pub fn LSSetDefaultRoleHandlerForContentType(
+        in_content_type: *const CFString,
+        in_role: LSRolesMask,
+        in_handler_bundle_id: *const CFString,
+    ) -> Option<OSStatus>;
+
+    pub fn CSBackupIsItemExcluded(item: *const CFURL, exclude_by_path: *mut c_uchar) -> c_uchar;
+    pub fn CSBackupSetItemExcluded(
+        item: *const CFURL,
+        exclude: c_uchar,
+        exclude_by_path: c_uchar,
+    ) -> Option<OSStatus>;
+
+    pub fn AECreateDesc(
+        type_code: AEDescType,
+        data_ptr: *const c_void,
+        data_size: isize,
+        result: *mut AEDesc,
+    ) -> Option<OSErr>;
+    pub fn AEDisposeDesc(the_ae_desc: *mut AEDesc) -> Option<OSErr>;
+    pub fn AEDuplicateDesc(the_ae_desc: *const AEDesc, result: *mut AEDesc) -> Option<OSErr>;
+    pub fn AECoerceDesc(
+        the_ae_desc: *const AEDesc,
+        to_type: AEDescType,
+        result: *mut AEDesc,
+    ) -> Option<OSErr>;
+    pub fn AEGetDescDataSize(the_ae_desc: *const AEDesc) -> isize;
+    pub fn AEGetDescData(
+        the_ae_desc: *const AEDesc,
+        data_ptr: *mut c_void,
+        maximum_size: isize,
+    ) -> Option<OSErr>;
+
+    pub fn AECreateList(
+        factoring_ptr: *const c_void,
+        factored_size: isize,
+        is_record: c_uchar,
+        result_list: *mut AEDesc,
+    ) -> Option<OSErr>;
+    pub fn AECountItems(the_ae_desc_list: *const AEDesc, the_count: *mut c_long) -> Option<OSErr>;
+    pub fn AEPutDesc(
+        the_ae_desc_list: *mut AEDesc,
+        index: c_long,
+        the_ae_desc: *const AEDesc,
+    ) -> Option<OSErr>;
+    pub fn AEGetNthDesc(
+        the_ae_desc_list: *const AEDesc,
+        index: c_long,
+        desired_type: AEDescType,
+        the_ae_keyword: *mut AEKeyword,
+        result: *mut AEDesc,
+    ) -> Option<OSErr>;
+    pub fn AEDeleteItem(the_ae_desc_list: *mut AEDesc, index: c_long) -> Option<OSErr>;
+
+    pub fn AEPutParamDesc(
+        the_apple_event: *mut AEDesc,
+        the_ae_keyword: AEKeyword,
+        the_ae_desc: *const AEDesc,
+    ) -> Option<OSErr>;
+    pub fn AEGetParamDesc(
+        the_apple_event: *const AEDesc,
+        the_ae_keyword: AEKeyword,
+        desired_type: AEDescType,
+        result: *mut AEDesc,
+    ) -> Option<OSErr>;
+    pub fn AEDeleteParam(the_apple_event: *mut AEDesc, the_ae_keyword: AEKeyword) -> Option<OSErr>;
+
+    pub fn AEPutAttributeDesc(
+        the_apple_event: *mut AEDesc,
+        the_ae_keyword: AEKeyword,
+        the_ae_desc: *const AEDesc,
+    ) -> Option<OSErr>;
+    pub fn AEGetAttributeDesc(
+        the_apple_event: *const AEDesc,
+        the_ae_keyword: AEKeyword,
+        desired_type: AEDescType,
+        result: *mut AEDesc,
+    ) -> Option<OSErr>;
+
+    pub fn AECreateAppleEvent(
+        the_ae_event_class: AEEventClass,
+        the_ae_event_id: AEEventID,
+        target: *const AEDesc,
+        return_id: AEReturnID,
+        transaction_id: AETransactionID,
+        result: *mut AEDesc,
+    ) -> Option<OSErr>;
+    pub fn AESendMessage(
+        event: *const AEDesc,
+        reply: *mut AEDesc,
+        send_mode: AESendMode,
+        time_out_in_ticks: c_long,
+    ) -> Option<OSStatus>;
+
+    pub fn AEInstallEventHandler(
+        the_ae_event_class: AEEventClass,
+        the_ae_event_id: AEEventID,
+        handler: AEEventHandlerProcPtr,
+        handler_refcon: *mut c_void,
+        is_sys_handler: c_uchar,
+    ) -> Option<OSErr>;
+    pub fn AERemoveEventHandler(
+        the_ae_event_class: AEEventClass,
+        the_ae_event_id: AEEventID,
+        handler: AEEventHandlerProcPtr,
+        is_sys_handler: c_uchar,
+    ) -> Option<OSErr>;
+    pub fn AEGetEventHandler(
+        the_ae_event_class: AEEventClass,
+        the_ae_event_id: AEEventID,
+        handler: *mut Option<AEEventHandlerProcPtr>,
+        handler_refcon: *mut *mut c_void,
+        is_sys_handler: c_uchar,
+    ) -> Option<OSErr>;
+
+    pub fn FSEventStreamCreate(
+        allocator: *const CFAllocator,
+        callback: FSEventStreamCallback,
+        context: *const FSEventStreamContext,
+        paths_to_watch: *const CFArray<CFString>,
+        since_when: FSEventStreamEventId,
+        latency: CFTimeInterval,
+        flags: FSEventStreamCreateFlags,
+    ) -> *mut FSEventStreamRef;
+    pub fn FSEventStreamScheduleWithRunLoop(
+        stream: *mut FSEventStreamRef,
+        run_loop: *const CFRunLoop,
+        run_loop_mode: *const CFRunLoopMode,
+    );
+    pub fn FSEventStreamUnscheduleFromRunLoop(
+        stream: *mut FSEventStreamRef,
+        run_loop: *const CFRunLoop,
+        run_loop_mode: *const CFRunLoopMode,
+    );
+    #[cfg(feature = "dispatch")]
+    pub fn FSEventStreamSetDispatchQueue(
+        stream: *mut FSEventStreamRef,
+        queue: *const DispatchQueue,
+    );
+    pub fn FSEventStreamStart(stream: *mut FSEventStreamRef) -> c_uchar;
+    pub fn FSEventStreamStop(stream: *mut FSEventStreamRef);
+    pub fn FSEventStreamInvalidate(stream: *mut FSEventStreamRef);
+    pub fn FSEventStreamRelease(stream: *mut FSEventStreamRef);
+    pub fn FSEventStreamFlushSync(stream: *mut FSEventStreamRef);
+    pub fn FSEventStreamFlushAsync(stream: *mut FSEventStreamRef) -> FSEventStreamEventId;
+    pub fn FSEventStreamGetLatestEventId(stream: *const FSEventStreamRef) -> FSEventStreamEventId;
+    pub fn FSEventStreamCopyPathsBeingWatched(
+        stream: *const FSEventStreamRef,
+    ) -> *const CFArray<CFString>;
+    pub fn FSEventsGetCurrentEventId() -> FSEventStreamEventId;
+}
+
+#[allow(non_upper_case_globals, missing_docs)]
+#[cfg(target_os = "macos")]
+pub const kUCKeyActionDown: u16 = 0;
+#[allow(non_upper_case_globals, missing_docs)]
+#[cfg(target_os = "macos")]
+pub const kUCKeyTranslateNoDeadKeysMask: u32 = 1;
+
+#[allow(missing_docs)]
+#[cfg(target_os = "macos")]
 #[link(name = "CoreServices", kind = "framework")]
-extern "C" {}
+extern "C" {
+    pub fn UCKeyTranslate(
+        key_layout: *const c_void,
+        virtual_key_code: u16,
+        key_action: u16,
+        modifier_key_state: u32,
+        keyboard_type: u32,
+        key_translate_options: u32,
+        dead_key_state: *mut u32,
+        max_string_length: c_ulong,
+        actual_string_length: *mut c_ulong,
+        unicode_string: *mut u16,
+    ) -> Option<OSStatus>;
+}
+
+#[allow(missing_docs)]
+#[cfg(target_os = "macos")]
+#[link(name = "Carbon", kind = "framework")]
+extern "C" {
+    pub fn OSACopyScriptingDefinitionFromURL(
+        file_url: *const CFURL,
+        mode_flags: i32,
+        sdef: *mut *const CFData,
+    ) -> i32;
+
+    pub fn TISInputSourceGetTypeID() -> CFTypeID;
+    pub fn TISCopyCurrentKeyboardLayoutInputSource() -> *const TISInputSource;
+    pub fn TISCopyCurrentASCIICapableKeyboardLayoutInputSource() -> *const TISInputSource;
+    pub fn TISGetInputSourceProperty(
+        source: &TISInputSource,
+        property_key: &CFString,
+    ) -> *const CFType<'static>;
+
+    pub static kTISPropertyInputSourceID: &'static CFString;
+    pub static kTISPropertyLocalizedName: &'static CFString;
+    pub static kTISPropertyUnicodeKeyLayoutData: &'static CFString;
+
+    pub fn LMGetKbdType() -> u8;
+}
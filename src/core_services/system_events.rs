@@ -0,0 +1,156 @@
+//! File and folder choose dialogs presented by System Events, sent as
+//! [Apple events](super::AppleEvent).
+//!
+//! These are for agents and daemons that have no user interface of their own
+//! and so cannot show an `NSOpenPanel`. System Events is brought to the front
+//! and shows the same dialogs as AppleScript's `choose file` and
+//! `choose folder`.
+//!
+//! Each function waits for the user to close the dialog. Apps that send these
+//! must include the `NSAppleEventsUsageDescription` key in their
+//! `Info.plist`, and the user is asked once to allow them to control System
+//! Events.
+
+use super::{
+    AEDesc, AEDescList, AEDescType, AEEventClass, AEEventID, AEKeyword, AEReturnID, AESendMode,
+    AETimeout, AETransactionID, AppleEvent,
+};
+use crate::core::{Arc, OSStatus};
+use crate::core_foundation::{CFString, CFURL};
+
+/// The bundle identifier of System Events.
+const SYSTEM_EVENTS_BUNDLE_ID: &str = "com.apple.systemevents";
+
+/// The `syso` event class of the Standard Additions scripting addition.
+const STANDARD_ADDITIONS: AEEventClass = AEEventClass::from_chars(*b"syso");
+
+const CHOOSE_FILE: AEEventID = AEEventID::from_chars(*b"stdf");
+const CHOOSE_FOLDER: AEEventID = AEEventID::from_chars(*b"stfl");
+
+const KEY_PROMPT: AEKeyword = AEKeyword::from_chars(*b"prmp");
+const KEY_TYPES: AEKeyword = AEKeyword::from_chars(*b"ftyp");
+const KEY_DEFAULT_LOCATION: AEKeyword = AEKeyword::from_chars(*b"dflc");
+const KEY_INVISIBLES: AEKeyword = AEKeyword::from_chars(*b"lfiv");
+const KEY_MULTIPLE: AEKeyword = AEKeyword::from_chars(*b"mlsl");
+const KEY_PACKAGE_CONTENTS: AEKeyword = AEKeyword::from_chars(*b"shpc");
+
+/// `userCanceledErr`, which the reply reports when the user clicks "Cancel".
+const USER_CANCELED: i32 = -128;
+
+/// Options for [`choose_file`] and [`choose_folder`].
+///
+/// The default options show the dialog without a prompt, allow choosing a
+/// single item of any type, and start in the last location the user chose.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ChooseOptions<'a> {
+    /// The text shown above the file browser.
+    pub prompt: Option<&'a str>,
+
+    /// The uniform type identifiers, such as `"public.image"`, of the files
+    /// that can be chosen. All files can be chosen if this is empty.
+    ///
+    /// This is ignored by [`choose_folder`].
+    pub types: &'a [&'a str],
+
+    /// The folder that the dialog starts in.
+    pub default_location: Option<&'a CFURL>,
+
+    /// Whether files and folders that are normally hidden are shown.
+    pub invisibles: bool,
+
+    /// Whether more than one item can be chosen.
+    pub multiple_selections: bool,
+
+    /// Whether the contents of packages, such as apps, can be browsed.
+    pub package_contents: bool,
+}
+
+/// Shows a dialog for choosing files and returns the URLs of the chosen
+/// files.
+///
+/// This returns an empty list if the user cancels the dialog.
+pub fn choose_file(options: &ChooseOptions) -> Result<Vec<Arc<CFURL>>, OSStatus> {
+    let mut types = AEDescList::new()?;
+    for ty in options.types {
+        types.push(&AEDesc::from_utf8(ty)?)?;
+    }
+
+    let types = if options.types.is_empty() {
+        None
+    } else {
+        Some((KEY_TYPES, &*types))
+    };
+    choose(CHOOSE_FILE, options, types)
+}
+
+/// Shows a dialog for choosing folders and returns the URLs of the chosen
+/// folders.
+///
+/// This returns an empty list if the user cancels the dialog.
+pub fn choose_folder(options: &ChooseOptions) -> Result<Vec<Arc<CFURL>>, OSStatus> {
+    choose(CHOOSE_FOLDER, options, None)
+}
+
+/// Sends a choose event with the parameters of `options` to System Events
+/// and returns the chosen items.
+fn choose(
+    event_id: AEEventID,
+    options: &ChooseOptions,
+    extra_param: Option<(AEKeyword, &AEDesc)>,
+) -> Result<Vec<Arc<CFURL>>, OSStatus> {
+    let system_events = AEDesc::from_bundle_id(SYSTEM_EVENTS_BUNDLE_ID)?;
+
+    // The dialog belongs to System Events, so it would otherwise appear
+    // behind the frontmost app.
+    AppleEvent::new(
+        AEEventClass::MISC_STANDARDS,
+        AEEventID::ACTIVATE,
+        &system_events,
+        AEReturnID::AUTO_GENERATE,
+        AETransactionID::ANY,
+    )?
+    .send(AESendMode::AE_WAIT_REPLY, AETimeout::DEFAULT)?;
+
+    let mut event = AppleEvent::new(
+        STANDARD_ADDITIONS,
+        event_id,
+        &system_events,
+        AEReturnID::AUTO_GENERATE,
+        AETransactionID::ANY,
+    )?;
+    if let Some(prompt) = options.prompt {
+        event.set_param(KEY_PROMPT, &AEDesc::from_utf8(prompt)?)?;
+    }
+    if let Some(url) = options.default_location {
+        let url = url.absolute_url().string().to_string();
+        event.set_param(KEY_DEFAULT_LOCATION, &AEDesc::from_file_url(&url)?)?;
+    }
+    if let Some((keyword, desc)) = extra_param {
+        event.set_param(keyword, desc)?;
+    }
+    event.set_param(KEY_INVISIBLES, &AEDesc::from_bool(options.invisibles)?)?;
+    event.set_param(
+        KEY_MULTIPLE,
+        &AEDesc::from_bool(options.multiple_selections)?,
+    )?;
+    event.set_param(
+        KEY_PACKAGE_CONTENTS,
+        &AEDesc::from_bool(options.package_contents)?,
+    )?;
+
+    // The user may take any amount of time to choose.
+    let reply = match event.send(AESendMode::AE_WAIT_REPLY, AETimeout::NONE) {
+        Ok(reply) => reply,
+        Err(error) if error.value() == USER_CANCELED => return Ok(Vec::new()),
+        Err(error) => return Err(error),
+    };
+
+    // A single alias is coerced to a list of one item.
+    let items = AEDescList(reply.param(AEKeyword::DIRECT_OBJECT, AEDescType::AE_LIST)?);
+    let mut urls = Vec::with_capacity(items.len()?);
+    for index in 0..items.len()? {
+        let url = items.get(index, AEDescType::FILE_URL)?.to_utf8_string()?;
+        urls.extend(CFURL::from_string(None, &CFString::new(&url), None));
+    }
+    Ok(urls)
+}
@@ -0,0 +1,168 @@
+//! [Time Machine](https://support.apple.com/guide/mac-help/back-up-your-mac-with-time-machine-mh35860/mac)
+//! backup exclusions and status.
+//!
+//! Items are excluded from backups in one of two ways:
+//!
+//! - [`BackupExclusion::Item`] marks the item itself, so the exclusion
+//!   follows it when it is moved or copied. This is appropriate for caches
+//!   and other data that can be recreated.
+//!
+//! - [`BackupExclusion::Path`] excludes whatever is at a path, like adding it
+//!   in the Time Machine settings. This requires root privileges.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use fruity::core_services::time_machine::{self, BackupExclusion};
+//!
+//! let cache = "/Users/me/Library/Caches/com.example.app";
+//! time_machine::exclude(cache, BackupExclusion::Item)?;
+//!
+//! assert_eq!(time_machine::exclusion(cache), Some(BackupExclusion::Item));
+//! # Ok::<(), fruity::core::OSStatus>(())
+//! ```
+
+use super::sys;
+use crate::core::{Arc, OSErr, OSStatus};
+use crate::core_foundation::{CFArray, CFData, CFDictionary, CFString, CFType, CFURL};
+use crate::core_foundation::{CFDate, CFPropertyList, CFPropertyListMutabilityOptions};
+use std::{fs, path::Path};
+
+/// The preferences of the Time Machine daemon, which record its backups.
+const PREFERENCES_PATH: &str = "/Library/Preferences/com.apple.TimeMachine.plist";
+
+/// How an item is excluded from Time Machine backups.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BackupExclusion {
+    /// The item itself is excluded, wherever it is moved.
+    ///
+    /// This is stored in the item's extended attributes.
+    Item,
+
+    /// Whatever is at the item's path is excluded.
+    ///
+    /// Setting this requires root privileges.
+    Path,
+}
+
+/// Returns how the item at `path` is excluded from backups, or [`None`] if
+/// it is backed up.
+///
+/// See [documentation](https://developer.apple.com/documentation/coreservices/1445043-csbackupisitemexcluded).
+#[inline]
+#[doc(alias = "CSBackupIsItemExcluded")]
+pub fn exclusion<P: AsRef<Path>>(path: P) -> Option<BackupExclusion> {
+    let url = file_url(path.as_ref())?;
+    let mut by_path = 0;
+    let is_excluded = unsafe { sys::CSBackupIsItemExcluded(&*url, &mut by_path) };
+
+    if is_excluded == 0 {
+        None
+    } else if by_path != 0 {
+        Some(BackupExclusion::Path)
+    } else {
+        Some(BackupExclusion::Item)
+    }
+}
+
+/// Returns `true` if the item at `path` is excluded from backups in any way.
+#[inline]
+pub fn is_excluded<P: AsRef<Path>>(path: P) -> bool {
+    exclusion(path).is_some()
+}
+
+/// Excludes the item at `path` from backups.
+///
+/// See [documentation](https://developer.apple.com/documentation/coreservices/1443602-csbackupsetitemexcluded).
+#[inline]
+#[doc(alias = "CSBackupSetItemExcluded")]
+pub fn exclude<P: AsRef<Path>>(path: P, kind: BackupExclusion) -> Result<(), OSStatus> {
+    set_excluded(path.as_ref(), true, kind)
+}
+
+/// Removes an exclusion of `kind` from the item at `path`, so that it is
+/// backed up unless it is also excluded in the other way.
+///
+/// See [documentation](https://developer.apple.com/documentation/coreservices/1443602-csbackupsetitemexcluded).
+#[inline]
+#[doc(alias = "CSBackupSetItemExcluded")]
+pub fn remove_exclusion<P: AsRef<Path>>(path: P, kind: BackupExclusion) -> Result<(), OSStatus> {
+    set_excluded(path.as_ref(), false, kind)
+}
+
+fn set_excluded(path: &Path, exclude: bool, kind: BackupExclusion) -> Result<(), OSStatus> {
+    let url = file_url(path).ok_or(OSErr::PARAM_ERR)?;
+    let by_path = kind == BackupExclusion::Path;
+    match unsafe { sys::CSBackupSetItemExcluded(&*url, exclude as u8, by_path as u8) } {
+        Some(error) => Err(error),
+        None => Ok(()),
+    }
+}
+
+fn file_url(path: &Path) -> Option<Arc<CFURL>> {
+    CFURL::from_path(path, path.is_dir())
+}
+
+/// Returns the date of the most recent completed backup to any destination,
+/// or [`None`] if there are none or the Time Machine preferences could not be
+/// read.
+///
+/// There is no public API for this, so it is read from the snapshot dates
+/// recorded in the Time Machine preferences. Reading them may require Full
+/// Disk Access.
+pub fn latest_backup_date() -> Option<Arc<CFDate>> {
+    let data = fs::read(PREFERENCES_PATH).ok()?;
+    let data = CFData::from_bytes(None, &data);
+    let preferences =
+        match CFPropertyList::from_data(&data, CFPropertyListMutabilityOptions::IMMUTABLE) {
+            Ok((CFPropertyList::Dictionary(preferences), _)) => preferences,
+            _ => return None,
+        };
+
+    let destinations = match property(&preferences, "Destinations")? {
+        CFPropertyList::Array(destinations) => destinations,
+        _ => return None,
+    };
+
+    let mut latest: Option<Arc<CFDate>> = None;
+    for destination in destinations.iter() {
+        let destination = match CFPropertyList::from_cf_type(Arc::retain(destination)) {
+            Some(CFPropertyList::Dictionary(destination)) => destination,
+            _ => continue,
+        };
+
+        // Each destination lists the dates of its snapshots in order.
+        let last_snapshot = match property(&destination, "SnapshotDates") {
+            Some(CFPropertyList::Array(dates)) => last_date(&dates),
+            _ => None,
+        };
+        let reference_snapshot = match property(&destination, "ReferenceLocalSnapshotDate") {
+            Some(CFPropertyList::Date(date)) => Some(date),
+            _ => None,
+        };
+
+        for date in last_snapshot.into_iter().chain(reference_snapshot) {
+            let is_later = match &latest {
+                Some(latest) => date.absolute_time() > latest.absolute_time(),
+                None => true,
+            };
+            if is_later {
+                latest = Some(date);
+            }
+        }
+    }
+    latest
+}
+
+fn property(dictionary: &CFDictionary, key: &'static str) -> Option<CFPropertyList> {
+    let key = CFString::from_static_str(key);
+    let value: &CFType = dictionary.get(&key)?;
+    CFPropertyList::from_cf_type(Arc::retain(value))
+}
+
+fn last_date(dates: &CFArray) -> Option<Arc<CFDate>> {
+    match CFPropertyList::from_cf_type(Arc::retain(dates.last()?))? {
+        CFPropertyList::Date(date) => Some(date),
+        _ => None,
+    }
+}
@@ -0,0 +1,171 @@
+use super::sys;
+use crate::core::Arc;
+use crate::core_foundation::{CFData, CFString, CFType, CFTypeID};
+
+#[cfg(feature = "core_graphics")]
+use crate::core_graphics::{CGEventFlags, CGKeyCode};
+#[cfg(feature = "core_graphics")]
+use std::os::raw::c_ulong;
+
+subclass! {
+    /// A text input source, such as a keyboard layout or an input method.
+    ///
+    /// Keyboard layouts map the virtual [key codes](crate::core_graphics::CGKeyCode)
+    /// of physical keys to the characters that they type, which differ
+    /// between layouts such as US, French, and Dvorak.
+    ///
+    /// # Examples
+    ///
+    /// Showing a keyboard shortcut with the character of its key on the
+    /// user's keyboard:
+    ///
+    /// ```no_run
+    /// use fruity::core_graphics::{CGEventFlags, CGKeyCode};
+    /// use fruity::core_services::TISInputSource;
+    ///
+    /// let layout = TISInputSource::current_ascii_capable_keyboard_layout().unwrap();
+    ///
+    /// // "Z" on US keyboards and "W" on French keyboards.
+    /// let key = layout.translate(CGKeyCode::ANSI_Z, CGEventFlags::SHIFT);
+    /// println!("⌘{}", key.unwrap_or_default());
+    /// ```
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/carbon/tisinputsourceref).
+    #[derive(PartialEq, Hash)]
+    pub class TISInputSource: CFType<'static>;
+}
+
+impl TISInputSource {
+    /// Returns the type identifier for `TISInputSource`.
+    #[inline]
+    #[doc(alias = "TISInputSourceGetTypeID")]
+    pub fn type_id() -> CFTypeID {
+        unsafe { sys::TISInputSourceGetTypeID() }
+    }
+
+    /// Returns the keyboard layout currently in use.
+    ///
+    /// If the user is typing with an input method, such as for Japanese,
+    /// this is the layout that it uses for keys it does not handle.
+    #[inline]
+    #[doc(alias = "TISCopyCurrentKeyboardLayoutInputSource")]
+    pub fn current_keyboard_layout() -> Option<Arc<Self>> {
+        unsafe {
+            let source = sys::TISCopyCurrentKeyboardLayoutInputSource();
+            if source.is_null() {
+                None
+            } else {
+                Some(Arc::from_raw(source))
+            }
+        }
+    }
+
+    /// Returns the most recently used keyboard layout that can type ASCII,
+    /// which is the layout that keyboard shortcuts are matched against.
+    #[inline]
+    #[doc(alias = "TISCopyCurrentASCIICapableKeyboardLayoutInputSource")]
+    pub fn current_ascii_capable_keyboard_layout() -> Option<Arc<Self>> {
+        unsafe {
+            let source = sys::TISCopyCurrentASCIICapableKeyboardLayoutInputSource();
+            if source.is_null() {
+                None
+            } else {
+                Some(Arc::from_raw(source))
+            }
+        }
+    }
+
+    /// Returns the reverse-DNS identifier of the input source, such as
+    /// `"com.apple.keylayout.US"`.
+    #[inline]
+    #[doc(alias = "kTISPropertyInputSourceID")]
+    pub fn id(&self) -> Option<&CFString> {
+        unsafe { self.property(sys::kTISPropertyInputSourceID) }
+    }
+
+    /// Returns the name of the input source for showing to the user, such as
+    /// `"U.S."`.
+    #[inline]
+    #[doc(alias = "kTISPropertyLocalizedName")]
+    pub fn localized_name(&self) -> Option<&CFString> {
+        unsafe { self.property(sys::kTISPropertyLocalizedName) }
+    }
+
+    /// Returns the `UCKeyboardLayout` data of a keyboard layout, or [`None`]
+    /// for input methods.
+    #[inline]
+    #[doc(alias = "kTISPropertyUnicodeKeyLayoutData")]
+    pub fn unicode_key_layout_data(&self) -> Option<&CFData> {
+        unsafe { self.property(sys::kTISPropertyUnicodeKeyLayoutData) }
+    }
+
+    /// Returns the value of `key`, which must be of type `T`.
+    #[inline]
+    unsafe fn property<T>(&self, key: &CFString) -> Option<&T> {
+        (sys::TISGetInputSourceProperty(self, key) as *const T).as_ref()
+    }
+}
+
+/// Translating keys.
+#[cfg(feature = "core_graphics")]
+#[cfg_attr(docsrs, doc(cfg(feature = "core_graphics")))]
+impl TISInputSource {
+    /// Returns the characters typed by pressing `key` with the modifiers in
+    /// `flags` on this keyboard layout, or [`None`] if it does not type any
+    /// or this is not a keyboard layout.
+    ///
+    /// Dead keys, such as Option-E for an accent, return the accent alone.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/coreservices/1390584-uckeytranslate).
+    #[doc(alias = "UCKeyTranslate")]
+    pub fn translate(&self, key: CGKeyCode, flags: CGEventFlags) -> Option<String> {
+        let layout = self.unicode_key_layout_data()?;
+
+        let mut dead_key_state = 0;
+        let mut chars = [0u16; 4];
+        let mut len: c_ulong = 0;
+        let error = unsafe {
+            sys::UCKeyTranslate(
+                layout.as_bytes().as_ptr().cast(),
+                key.0,
+                sys::kUCKeyActionDown,
+                flags.to_carbon_key_state(),
+                sys::LMGetKbdType() as u32,
+                sys::kUCKeyTranslateNoDeadKeysMask,
+                &mut dead_key_state,
+                chars.len() as c_ulong,
+                &mut len,
+                chars.as_mut_ptr(),
+            )
+        };
+
+        if error.is_some() || len == 0 {
+            return None;
+        }
+        String::from_utf16(&chars[..len as usize]).ok()
+    }
+
+    /// Returns the key that types `ch` on this keyboard layout, along with
+    /// [`CGEventFlags::SHIFT`] if Shift must also be held, or [`None`] if no
+    /// key types it with or without Shift.
+    ///
+    /// This is the reverse of [`translate`](Self::translate), for finding
+    /// which physical key a keyboard shortcut such as Command-Z is on.
+    pub fn key_for_char(&self, ch: char) -> Option<(CGKeyCode, CGEventFlags)> {
+        let mut buf = [0; 4];
+        let ch: &str = ch.encode_utf8(&mut buf);
+
+        for &flags in &[CGEventFlags::default(), CGEventFlags::SHIFT] {
+            for code in 0..=CGKeyCode::UP_ARROW.0 {
+                let key = CGKeyCode(code);
+                if key.is_modifier() || key.is_keypad() {
+                    continue;
+                }
+                if self.translate(key, flags).as_deref() == Some(ch) {
+                    return Some((key, flags));
+                }
+            }
+        }
+        None
+    }
+}
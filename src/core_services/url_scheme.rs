@@ -0,0 +1,136 @@
+//! [Launch Services](https://developer.apple.com/documentation/coreservices/launch_services)
+//! handlers for URL schemes, such as `mailto` or an app's custom scheme.
+//!
+//! Handlers are identified by their bundle identifier. Apps declare the
+//! schemes they can handle in the `CFBundleURLTypes` array of their
+//! `Info.plist`, and receive URLs as
+//! [get URL](super::AEEventHandler::get_url) Apple events.
+//!
+//! On macOS 12 and later, the
+//! [`NSWorkspace`](crate::app_kit::NSWorkspace) equivalents operate on
+//! application URLs instead.
+
+use super::sys;
+use crate::core::{Arc, OSStatus};
+use crate::core_foundation::{
+    CFArray, CFBundle, CFComparisonResult, CFPropertyList, CFString, CFStringCompareFlags,
+};
+
+/// Returns the bundle identifier of the default handler for `scheme`, or
+/// [`None`] if no app handles it.
+///
+/// See [documentation](https://developer.apple.com/documentation/coreservices/lscopydefaulthandlerforurlscheme(_:)).
+#[inline]
+#[doc(alias = "LSCopyDefaultHandlerForURLScheme")]
+pub fn default_handler(scheme: &CFString) -> Option<Arc<CFString>> {
+    unsafe {
+        let handler = sys::LSCopyDefaultHandlerForURLScheme(scheme);
+        if handler.is_null() {
+            None
+        } else {
+            Some(Arc::from_raw(handler))
+        }
+    }
+}
+
+/// Returns the bundle identifiers of all apps that declare `scheme`, or
+/// [`None`] if there are none.
+///
+/// See [documentation](https://developer.apple.com/documentation/coreservices/lscopyallhandlersforurlscheme(_:)).
+#[inline]
+#[doc(alias = "LSCopyAllHandlersForURLScheme")]
+pub fn all_handlers(scheme: &CFString) -> Option<Arc<CFArray<CFString>>> {
+    unsafe {
+        let handlers = sys::LSCopyAllHandlersForURLScheme(scheme);
+        if handlers.is_null() {
+            None
+        } else {
+            Some(Arc::from_raw(handlers))
+        }
+    }
+}
+
+/// Makes the app with `bundle_id` the default handler for `scheme`.
+///
+/// The system may ask the user to confirm the change.
+///
+/// See [documentation](https://developer.apple.com/documentation/coreservices/lssetdefaulthandlerforurlscheme(_:_:)).
+#[inline]
+#[doc(alias = "LSSetDefaultHandlerForURLScheme")]
+pub fn set_default_handler(scheme: &CFString, bundle_id: &CFString) -> Result<(), OSStatus> {
+    match unsafe { sys::LSSetDefaultHandlerForURLScheme(scheme, bundle_id) } {
+        Some(error) => Err(error),
+        None => Ok(()),
+    }
+}
+
+/// Returns the URL schemes declared in the `CFBundleURLTypes` of the
+/// `Info.plist` of `bundle`.
+pub fn declared_schemes(bundle: &CFBundle) -> Vec<Arc<CFString>> {
+    let url_types_key = CFString::from_static_str("CFBundleURLTypes");
+    let schemes_key = CFString::from_static_str("CFBundleURLSchemes");
+
+    let url_types = match bundle.value_for_info_key(&url_types_key) {
+        Some(CFPropertyList::Array(url_types)) => url_types,
+        _ => return Vec::new(),
+    };
+
+    let mut result = Vec::new();
+    for url_type in url_types.iter() {
+        let url_type = match CFPropertyList::from_cf_type(Arc::retain(url_type)) {
+            Some(CFPropertyList::Dictionary(url_type)) => url_type,
+            _ => continue,
+        };
+
+        let schemes = match url_type
+            .get(&schemes_key)
+            .and_then(|schemes| CFPropertyList::from_cf_type(Arc::retain(schemes)))
+        {
+            Some(CFPropertyList::Array(schemes)) => schemes,
+            _ => continue,
+        };
+
+        for scheme in schemes.iter() {
+            if let Some(CFPropertyList::String(scheme)) =
+                CFPropertyList::from_cf_type(Arc::retain(scheme))
+            {
+                result.push(scheme);
+            }
+        }
+    }
+    result
+}
+
+/// Returns `true` if the main bundle declares `scheme` in its `Info.plist`.
+///
+/// URL schemes are compared case-insensitively.
+pub fn is_declared(scheme: &CFString) -> bool {
+    match CFBundle::main() {
+        Some(bundle) => declared_schemes(&bundle)
+            .iter()
+            .any(|declared| eq_ignore_case(declared, scheme)),
+        None => false,
+    }
+}
+
+/// Returns `true` if the main bundle is the default handler for `scheme`.
+///
+/// This is `false` for executables without a bundle identifier, which
+/// cannot be registered as handlers.
+pub fn is_default_handler(scheme: &CFString) -> bool {
+    let identifier = match CFBundle::main().and_then(|bundle| bundle.identifier()) {
+        Some(identifier) => identifier,
+        None => return false,
+    };
+
+    // Launch Services may return the bundle identifier in lowercase.
+    match default_handler(scheme) {
+        Some(handler) => eq_ignore_case(&handler, &identifier),
+        None => false,
+    }
+}
+
+#[inline]
+fn eq_ignore_case(a: &CFString, b: &CFString) -> bool {
+    a.compare(b, CFStringCompareFlags::CASE_INSENSITIVE) == CFComparisonResult::EqualTo
+}
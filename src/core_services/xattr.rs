@@ -0,0 +1,239 @@
+//! [Extended attributes](https://developer.apple.com/library/archive/documentation/System/Conceptual/ManPages_iPhoneOS/man2/getxattr.2.html)
+//! of files in the file system.
+//!
+//! Errors are reported as [`io::Error`] using the `errno` set by the
+//! underlying system call. A missing attribute (`ENOATTR`) is not considered
+//! an error when reading, and instead results in [`None`].
+
+use std::{
+    ffi::{CStr, CString, OsStr},
+    io,
+    os::{
+        raw::{c_char, c_int, c_void},
+        unix::ffi::OsStrExt,
+    },
+    path::Path,
+    ptr,
+};
+
+/// Options for reading and writing extended attributes.
+///
+/// See [documentation](https://developer.apple.com/library/archive/documentation/System/Conceptual/ManPages_iPhoneOS/man2/setxattr.2.html).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct XattrOptions(pub c_int);
+
+impl XattrOptions {
+    /// No options.
+    pub const NONE: Self = Self(0);
+
+    /// Do not follow symbolic links.
+    #[doc(alias = "XATTR_NOFOLLOW")]
+    pub const NO_FOLLOW: Self = Self(0x0001);
+
+    /// Fail if the named attribute already exists.
+    #[doc(alias = "XATTR_CREATE")]
+    pub const CREATE: Self = Self(0x0002);
+
+    /// Fail if the named attribute does not exist.
+    #[doc(alias = "XATTR_REPLACE")]
+    pub const REPLACE: Self = Self(0x0004);
+
+    /// Show or remove HFS+ compression extended attributes.
+    #[doc(alias = "XATTR_SHOWCOMPRESSION")]
+    pub const SHOW_COMPRESSION: Self = Self(0x0020);
+}
+
+impl std::ops::BitOr for XattrOptions {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+extern "C" {
+    fn getxattr(
+        path: *const c_char,
+        name: *const c_char,
+        value: *mut c_void,
+        size: usize,
+        position: u32,
+        options: c_int,
+    ) -> isize;
+
+    fn setxattr(
+        path: *const c_char,
+        name: *const c_char,
+        value: *const c_void,
+        size: usize,
+        position: u32,
+        options: c_int,
+    ) -> c_int;
+
+    fn listxattr(path: *const c_char, namebuf: *mut c_char, size: usize, options: c_int) -> isize;
+
+    fn removexattr(path: *const c_char, name: *const c_char, options: c_int) -> c_int;
+}
+
+/// `ERANGE` from `<sys/errno.h>`.
+const ERANGE: i32 = 34;
+
+/// `ENOATTR` from `<sys/errno.h>`.
+pub(crate) const ENOATTR: i32 = 93;
+
+fn c_string(s: &OsStr) -> io::Result<CString> {
+    CString::new(s.as_bytes()).map_err(|error| io::Error::new(io::ErrorKind::InvalidInput, error))
+}
+
+/// Returns the value of the extended attribute `name` of the file at `path`,
+/// or [`None`] if the attribute does not exist.
+///
+/// See [documentation](https://developer.apple.com/library/archive/documentation/System/Conceptual/ManPages_iPhoneOS/man2/getxattr.2.html).
+#[doc(alias = "getxattr")]
+pub fn get<P, N>(path: P, name: N, options: XattrOptions) -> io::Result<Option<Vec<u8>>>
+where
+    P: AsRef<Path>,
+    N: AsRef<OsStr>,
+{
+    let path = c_string(path.as_ref().as_os_str())?;
+    let name = c_string(name.as_ref())?;
+
+    loop {
+        let len = unsafe {
+            getxattr(
+                path.as_ptr(),
+                name.as_ptr(),
+                ptr::null_mut(),
+                0,
+                0,
+                options.0,
+            )
+        };
+        if len < 0 {
+            return match io::Error::last_os_error() {
+                error if error.raw_os_error() == Some(ENOATTR) => Ok(None),
+                error => Err(error),
+            };
+        }
+
+        let mut value = Vec::<u8>::with_capacity(len as usize);
+        let read = unsafe {
+            getxattr(
+                path.as_ptr(),
+                name.as_ptr(),
+                value.as_mut_ptr().cast(),
+                value.capacity(),
+                0,
+                options.0,
+            )
+        };
+
+        if read >= 0 {
+            unsafe { value.set_len(read as usize) };
+            return Ok(Some(value));
+        }
+
+        match io::Error::last_os_error() {
+            // The attribute grew between the two calls, so try again.
+            error if error.raw_os_error() == Some(ERANGE) => continue,
+            error if error.raw_os_error() == Some(ENOATTR) => return Ok(None),
+            error => return Err(error),
+        }
+    }
+}
+
+/// Sets the value of the extended attribute `name` of the file at `path`.
+///
+/// See [documentation](https://developer.apple.com/library/archive/documentation/System/Conceptual/ManPages_iPhoneOS/man2/setxattr.2.html).
+#[doc(alias = "setxattr")]
+pub fn set<P, N>(path: P, name: N, value: &[u8], options: XattrOptions) -> io::Result<()>
+where
+    P: AsRef<Path>,
+    N: AsRef<OsStr>,
+{
+    let path = c_string(path.as_ref().as_os_str())?;
+    let name = c_string(name.as_ref())?;
+
+    let result = unsafe {
+        setxattr(
+            path.as_ptr(),
+            name.as_ptr(),
+            value.as_ptr().cast(),
+            value.len(),
+            0,
+            options.0,
+        )
+    };
+
+    if result == 0 {
+        Ok(())
+    } else {
+        Err(io::Error::last_os_error())
+    }
+}
+
+/// Removes the extended attribute `name` from the file at `path`.
+///
+/// See [documentation](https://developer.apple.com/library/archive/documentation/System/Conceptual/ManPages_iPhoneOS/man2/removexattr.2.html).
+#[doc(alias = "removexattr")]
+pub fn remove<P, N>(path: P, name: N, options: XattrOptions) -> io::Result<()>
+where
+    P: AsRef<Path>,
+    N: AsRef<OsStr>,
+{
+    let path = c_string(path.as_ref().as_os_str())?;
+    let name = c_string(name.as_ref())?;
+
+    if unsafe { removexattr(path.as_ptr(), name.as_ptr(), options.0) } == 0 {
+        Ok(())
+    } else {
+        Err(io::Error::last_os_error())
+    }
+}
+
+/// Returns the names of all extended attributes of the file at `path`.
+///
+/// See [documentation](https://developer.apple.com/library/archive/documentation/System/Conceptual/ManPages_iPhoneOS/man2/listxattr.2.html).
+#[doc(alias = "listxattr")]
+pub fn list<P>(path: P, options: XattrOptions) -> io::Result<Vec<CString>>
+where
+    P: AsRef<Path>,
+{
+    let path = c_string(path.as_ref().as_os_str())?;
+
+    let buf = loop {
+        let len = unsafe { listxattr(path.as_ptr(), ptr::null_mut(), 0, options.0) };
+        if len < 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        let mut buf = Vec::<u8>::with_capacity(len as usize);
+        let read = unsafe {
+            listxattr(
+                path.as_ptr(),
+                buf.as_mut_ptr().cast(),
+                buf.capacity(),
+                options.0,
+            )
+        };
+
+        if read >= 0 {
+            unsafe { buf.set_len(read as usize) };
+            break buf;
+        }
+
+        match io::Error::last_os_error() {
+            error if error.raw_os_error() == Some(ERANGE) => continue,
+            error => return Err(error),
+        }
+    };
+
+    // Names are stored back-to-back, each terminated by a null byte.
+    Ok(buf
+        .split_inclusive(|&b| b == 0)
+        .filter_map(|name| CStr::from_bytes_with_nul(name).ok())
+        .map(CStr::to_owned)
+        .collect())
+}
@@ -0,0 +1,253 @@
+use super::sys;
+use crate::core::Arc;
+use crate::core_foundation::{CFType, CFTypeID};
+use crate::core_graphics::{
+    self, CGBitmapInfo, CGColorSpace, CGImage, CGImageAlphaInfo, PixelSource, Pixels, PixelsError,
+};
+use std::{fmt, ptr};
+
+subclass! {
+    /// An image stored in main memory, such as a video frame.
+    ///
+    /// Buffers in a 32-bit RGB format can be converted to RGBA8 or BGRA8
+    /// pixels with [`Pixels`](crate::core_graphics::Pixels).
+    ///
+    /// Documentation:
+    /// [Swift](https://developer.apple.com/documentation/corevideo/cvpixelbuffer?language=swift) |
+    /// [Objective-C](https://developer.apple.com/documentation/corevideo/cvpixelbufferref?language=objc)
+    #[derive(PartialEq, Hash)]
+    pub class CVPixelBuffer: CFType<'static>;
+}
+
+impl fmt::Debug for CVPixelBuffer {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CVPixelBuffer")
+            .field("width", &self.width())
+            .field("height", &self.height())
+            .field("pixel_format_type", &self.pixel_format_type())
+            .finish()
+    }
+}
+
+impl CVPixelBuffer {
+    /// Returns the type identifier for `CVPixelBuffer`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corevideo/cvpixelbuffergettypeid()).
+    #[inline]
+    #[doc(alias = "CVPixelBufferGetTypeID")]
+    pub fn type_id() -> CFTypeID {
+        unsafe { sys::CVPixelBufferGetTypeID() }
+    }
+
+    /// Creates a buffer of `width` by `height` pixels in `format`, or returns
+    /// [`None`] if it could not be allocated.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corevideo/cvpixelbuffercreate(_:_:_:_:_:_:)).
+    #[inline]
+    #[doc(alias = "CVPixelBufferCreate")]
+    pub fn new(width: usize, height: usize, format: CVPixelFormatType) -> Option<Arc<Self>> {
+        unsafe {
+            let mut buffer: *const Self = ptr::null();
+            let status = sys::CVPixelBufferCreate(
+                ptr::null(),
+                width,
+                height,
+                format,
+                ptr::null(),
+                &mut buffer,
+            );
+            if status != 0 || buffer.is_null() {
+                None
+            } else {
+                Some(Arc::from_raw(buffer))
+            }
+        }
+    }
+
+    /// Returns the width in pixels.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corevideo/cvpixelbuffergetwidth(_:)).
+    #[inline]
+    #[doc(alias = "CVPixelBufferGetWidth")]
+    pub fn width(&self) -> usize {
+        unsafe { sys::CVPixelBufferGetWidth(self) }
+    }
+
+    /// Returns the height in pixels.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corevideo/cvpixelbuffergetheight(_:)).
+    #[inline]
+    #[doc(alias = "CVPixelBufferGetHeight")]
+    pub fn height(&self) -> usize {
+        unsafe { sys::CVPixelBufferGetHeight(self) }
+    }
+
+    /// Returns the number of bytes of each row of pixels, including padding.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corevideo/cvpixelbuffergetbytesperrow(_:)).
+    #[inline]
+    #[doc(alias = "CVPixelBufferGetBytesPerRow")]
+    pub fn bytes_per_row(&self) -> usize {
+        unsafe { sys::CVPixelBufferGetBytesPerRow(self) }
+    }
+
+    /// Returns the layout of the pixels.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corevideo/cvpixelbuffergetpixelformattype(_:)).
+    #[inline]
+    #[doc(alias = "CVPixelBufferGetPixelFormatType")]
+    pub fn pixel_format_type(&self) -> CVPixelFormatType {
+        unsafe { sys::CVPixelBufferGetPixelFormatType(self) }
+    }
+
+    /// Returns `true` if the pixels are stored in multiple planes, such as
+    /// separate luma and chroma planes.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corevideo/cvpixelbufferisplanar(_:)).
+    #[inline]
+    #[doc(alias = "CVPixelBufferIsPlanar")]
+    pub fn is_planar(&self) -> bool {
+        unsafe { sys::CVPixelBufferIsPlanar(self) != 0 }
+    }
+
+    /// Returns the color space attached to the buffer, if any.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/corevideo/cvimagebuffergetcolorspace(_:)).
+    #[cfg(target_os = "macos")]
+    #[inline]
+    #[doc(alias = "CVImageBufferGetColorSpace")]
+    pub fn color_space(&self) -> Option<Arc<CGColorSpace>> {
+        unsafe {
+            let space = sys::CVImageBufferGetColorSpace(self);
+            if space.is_null() {
+                None
+            } else {
+                Some(Arc::retain_raw(space))
+            }
+        }
+    }
+
+    /// Returns the Core Graphics layout of 32-bit RGB formats.
+    fn bitmap_info(&self) -> Option<CGBitmapInfo> {
+        let (alpha_info, byte_order) = match self.pixel_format_type() {
+            CVPixelFormatType::ARGB32 => (
+                CGImageAlphaInfo::PREMULTIPLIED_FIRST,
+                CGBitmapInfo::BYTE_ORDER_32_BIG,
+            ),
+            CVPixelFormatType::BGRA32 => (
+                CGImageAlphaInfo::PREMULTIPLIED_FIRST,
+                CGBitmapInfo::BYTE_ORDER_32_LITTLE,
+            ),
+            CVPixelFormatType::ABGR32 => (
+                CGImageAlphaInfo::PREMULTIPLIED_LAST,
+                CGBitmapInfo::BYTE_ORDER_32_LITTLE,
+            ),
+            CVPixelFormatType::RGBA32 => (
+                CGImageAlphaInfo::PREMULTIPLIED_LAST,
+                CGBitmapInfo::BYTE_ORDER_32_BIG,
+            ),
+            _ => return None,
+        };
+        Some(CGBitmapInfo::from(alpha_info) | byte_order)
+    }
+}
+
+impl PixelSource for CVPixelBuffer {
+    #[inline]
+    fn pixel_size(&self) -> (usize, usize) {
+        (self.width(), self.height())
+    }
+
+    fn render_pixels(&self, pixels: &Pixels, buffer: &mut [u8]) -> Result<(), PixelsError> {
+        let bitmap_info = self.bitmap_info().ok_or(PixelsError::UnsupportedSource)?;
+
+        #[cfg(target_os = "macos")]
+        let color_space = self.color_space();
+        #[cfg(not(target_os = "macos"))]
+        let color_space = None;
+        let color_space = color_space.unwrap_or_else(CGColorSpace::srgb);
+
+        let flags = sys::kCVPixelBufferLock_ReadOnly;
+        if unsafe { sys::CVPixelBufferLockBaseAddress(self, flags) } != 0 {
+            return Err(PixelsError::UnsupportedSource);
+        }
+
+        // Wrap the locked memory in an image without copying it. The image
+        // is released before the buffer is unlocked.
+        let result = unsafe {
+            let (width, height) = self.pixel_size();
+            let bytes_per_row = self.bytes_per_row();
+
+            let provider = core_graphics::sys::CGDataProviderCreateWithData(
+                ptr::null_mut(),
+                sys::CVPixelBufferGetBaseAddress(self),
+                bytes_per_row * height,
+                None,
+            );
+
+            let image = core_graphics::sys::CGImageCreate(
+                width,
+                height,
+                8,
+                32,
+                bytes_per_row,
+                &*color_space,
+                bitmap_info,
+                provider,
+                ptr::null(),
+                true,
+                core_graphics::sys::kCGRenderingIntentDefault,
+            );
+            core_graphics::sys::CGDataProviderRelease(provider);
+
+            if image.is_null() {
+                Err(PixelsError::UnsupportedSource)
+            } else {
+                let image: Arc<CGImage> = Arc::from_raw(image);
+                image.render_pixels(pixels, buffer)
+            }
+        };
+
+        unsafe { sys::CVPixelBufferUnlockBaseAddress(self, flags) };
+        result
+    }
+}
+
+/// A four-character code for the layout of pixels in a [`CVPixelBuffer`].
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CVPixelFormatType(pub u32);
+
+impl CVPixelFormatType {
+    /// 8-bit alpha, red, green, and blue.
+    #[doc(alias = "kCVPixelFormatType_32ARGB")]
+    pub const ARGB32: Self = Self(0x0000_0020);
+
+    /// 8-bit blue, green, red, and alpha.
+    #[doc(alias = "kCVPixelFormatType_32BGRA")]
+    pub const BGRA32: Self = Self::from_code(*b"BGRA");
+
+    /// 8-bit alpha, blue, green, and red.
+    #[doc(alias = "kCVPixelFormatType_32ABGR")]
+    pub const ABGR32: Self = Self::from_code(*b"ABGR");
+
+    /// 8-bit red, green, blue, and alpha.
+    #[doc(alias = "kCVPixelFormatType_32RGBA")]
+    pub const RGBA32: Self = Self::from_code(*b"RGBA");
+
+    /// Bi-planar 8-bit video range Y'CbCr 4:2:0, which is what most cameras
+    /// produce.
+    #[doc(alias = "kCVPixelFormatType_420YpCbCr8BiPlanarVideoRange")]
+    pub const YCBCR_420_BIPLANAR_VIDEO_RANGE: Self = Self::from_code(*b"420v");
+
+    /// Bi-planar 8-bit full range Y'CbCr 4:2:0.
+    #[doc(alias = "kCVPixelFormatType_420YpCbCr8BiPlanarFullRange")]
+    pub const YCBCR_420_BIPLANAR_FULL_RANGE: Self = Self::from_code(*b"420f");
+
+    /// Creates a format from its four-character code, such as `*b"BGRA"`.
+    #[inline]
+    pub const fn from_code(code: [u8; 4]) -> Self {
+        Self(u32::from_be_bytes(code))
+    }
+}
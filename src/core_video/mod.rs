@@ -5,8 +5,17 @@
 //!
 //! This module corresponds to the **`core_video`**
 //! [feature flag](../index.html#feature-flags).
+//!
+//! It also transitively enables [`foundation`](crate::foundation) and
+//! [`core_foundation`](crate::core_foundation).
 
 #![cfg(feature = "core_video")]
 
-#[link(name = "CoreImage", kind = "framework")]
+#[link(name = "CoreVideo", kind = "framework")]
 extern "C" {}
+
+mod cv_pixel_buffer;
+
+pub use cv_pixel_buffer::*;
+
+pub mod sys;
@@ -0,0 +1,48 @@
+//! Raw unsafe C functions exposed by `CoreVideo.framework`.
+
+use super::{CVPixelBuffer, CVPixelFormatType};
+use crate::core_foundation::{Boolean, CFAllocator, CFDictionary, CFTypeID};
+use std::ffi::c_void;
+
+#[cfg(target_os = "macos")]
+use crate::core_graphics::CGColorSpace;
+
+#[allow(missing_docs)]
+pub type CVReturn = i32;
+
+#[allow(missing_docs)]
+pub type CVPixelBufferLockFlags = u64;
+#[allow(non_upper_case_globals, missing_docs)]
+pub const kCVPixelBufferLock_ReadOnly: CVPixelBufferLockFlags = 0x0000_0001;
+
+#[allow(missing_docs)]
+#[link(name = "CoreVideo", kind = "framework")]
+extern "C" {
+    pub fn CVPixelBufferGetTypeID() -> CFTypeID;
+    pub fn CVPixelBufferCreate(
+        allocator: *const CFAllocator,
+        width: usize,
+        height: usize,
+        pixel_format_type: CVPixelFormatType,
+        pixel_buffer_attributes: *const CFDictionary,
+        pixel_buffer_out: *mut *const CVPixelBuffer,
+    ) -> CVReturn;
+    pub fn CVPixelBufferGetWidth(pixel_buffer: *const CVPixelBuffer) -> usize;
+    pub fn CVPixelBufferGetHeight(pixel_buffer: *const CVPixelBuffer) -> usize;
+    pub fn CVPixelBufferGetBytesPerRow(pixel_buffer: *const CVPixelBuffer) -> usize;
+    pub fn CVPixelBufferGetPixelFormatType(pixel_buffer: *const CVPixelBuffer)
+        -> CVPixelFormatType;
+    pub fn CVPixelBufferIsPlanar(pixel_buffer: *const CVPixelBuffer) -> Boolean;
+    pub fn CVPixelBufferLockBaseAddress(
+        pixel_buffer: *const CVPixelBuffer,
+        lock_flags: CVPixelBufferLockFlags,
+    ) -> CVReturn;
+    pub fn CVPixelBufferUnlockBaseAddress(
+        pixel_buffer: *const CVPixelBuffer,
+        unlock_flags: CVPixelBufferLockFlags,
+    ) -> CVReturn;
+    pub fn CVPixelBufferGetBaseAddress(pixel_buffer: *const CVPixelBuffer) -> *mut c_void;
+
+    #[cfg(target_os = "macos")]
+    pub fn CVImageBufferGetColorSpace(image_buffer: *const CVPixelBuffer) -> *const CGColorSpace;
+}
@@ -0,0 +1,188 @@
+use super::{
+    sys,
+    work::{abort_on_panic, call_boxed, DispatchFn},
+    DispatchObject, DispatchQueue, DispatchTime,
+};
+use crate::core::Arc;
+use std::mem;
+
+subclass! {
+    /// A group of tasks that can be monitored as a single unit.
+    ///
+    /// Documentation:
+    /// [Swift](https://developer.apple.com/documentation/dispatch/dispatchgroup) |
+    /// [Objective-C](https://developer.apple.com/documentation/dispatch/dispatch_group)
+    pub class DispatchGroup: DispatchObject;
+}
+
+impl DispatchGroup {
+    /// Creates a new group with which tasks can be associated.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/dispatch/dispatchgroup/init()).
+    #[inline]
+    #[doc(alias = "dispatch_group_create")]
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new() -> Arc<Self> {
+        unsafe { Arc::from_raw(sys::dispatch_group_create()) }
+    }
+
+    /// Explicitly indicates that a task has entered the group.
+    ///
+    /// Each call must be balanced with a call to [`leave`](Self::leave).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/dispatch/dispatchgroup/enter()).
+    #[inline]
+    #[doc(alias = "dispatch_group_enter")]
+    pub fn enter(&self) {
+        unsafe { sys::dispatch_group_enter(self) };
+    }
+
+    /// Explicitly indicates that a task in the group finished executing.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/dispatch/dispatchgroup/leave()).
+    ///
+    /// # Safety
+    ///
+    /// This must balance a previous call to [`enter`](Self::enter). Calling
+    /// this more times than `enter` crashes the process.
+    #[inline]
+    #[doc(alias = "dispatch_group_leave")]
+    pub unsafe fn leave(&self) {
+        sys::dispatch_group_leave(self);
+    }
+
+    /// Waits synchronously for all tasks in the group to finish, returning
+    /// `false` if `timeout` elapsed first.
+    ///
+    /// Pass [`DispatchTime::DISTANT_FUTURE`] to wait indefinitely.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/dispatch/dispatchgroup/wait(timeout:)).
+    #[inline]
+    #[doc(alias = "dispatch_group_wait")]
+    pub fn wait(&self, timeout: DispatchTime) -> bool {
+        unsafe { sys::dispatch_group_wait(self, timeout) == 0 }
+    }
+
+    /// Submits a function for asynchronous execution on `queue` and
+    /// associates it with the group.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/dispatch/dispatchqueue/async(group:qos:flags:execute:)).
+    ///
+    /// # Safety
+    ///
+    /// It is safe to panic within the `work` function. Panics will abort the
+    /// process.
+    ///
+    /// If the overhead of the extra setup is undesirable or you would like to
+    /// handle panics yourself, use
+    /// [`spawn_async_no_panic`](Self::spawn_async_no_panic) or
+    /// [`spawn_async_raw`](Self::spawn_async_raw) instead.
+    #[inline]
+    #[doc(alias = "dispatch_group_async")]
+    #[doc(alias = "dispatch_group_async_f")]
+    pub fn spawn_async<F>(&self, queue: &DispatchQueue, work: F)
+    where
+        F: Send + FnOnce() + 'static,
+    {
+        unsafe { self.spawn_async_no_panic(queue, abort_on_panic(work)) };
+    }
+
+    /// Submits a function for asynchronous execution on `queue` and
+    /// associates it with the group, without catching panics.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/dispatch/dispatchqueue/async(group:qos:flags:execute:)).
+    ///
+    /// # Safety
+    ///
+    /// It is undefined behavior to panic within the `work` function because it
+    /// is called from an `extern "C" fn`. Catch the panic yourself or call
+    /// [`spawn_async`](Self::spawn_async) instead.
+    #[inline]
+    #[doc(alias = "dispatch_group_async")]
+    #[doc(alias = "dispatch_group_async_f")]
+    pub unsafe fn spawn_async_no_panic<F>(&self, queue: &DispatchQueue, work: F)
+    where
+        F: Send + FnOnce() + 'static,
+    {
+        self.spawn_async_raw(queue, Box::into_raw(Box::new(work)), call_boxed::<F>);
+    }
+
+    /// Submits a C function with a context pointer for asynchronous execution
+    /// on `queue` and associates it with the group.
+    #[inline]
+    #[doc(alias = "dispatch_group_async")]
+    #[doc(alias = "dispatch_group_async_f")]
+    pub fn spawn_async_raw<Ctx>(
+        &self,
+        queue: &DispatchQueue,
+        ctx: *mut Ctx,
+        work: extern "C" fn(*mut Ctx),
+    ) {
+        unsafe {
+            // SAFETY: Both functions have the same ABI.
+            let work: DispatchFn = mem::transmute(work);
+
+            sys::dispatch_group_async_f(self, queue, ctx.cast(), work);
+        }
+    }
+
+    /// Schedules a function to be submitted to `queue` once all tasks
+    /// currently in the group have finished.
+    ///
+    /// If the group is empty, `work` is submitted immediately.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/dispatch/dispatchgroup/notify(qos:flags:queue:execute:)).
+    ///
+    /// # Safety
+    ///
+    /// It is safe to panic within the `work` function. Panics will abort the
+    /// process.
+    #[inline]
+    #[doc(alias = "dispatch_group_notify")]
+    #[doc(alias = "dispatch_group_notify_f")]
+    pub fn notify<F>(&self, queue: &DispatchQueue, work: F)
+    where
+        F: Send + FnOnce() + 'static,
+    {
+        unsafe { self.notify_no_panic(queue, abort_on_panic(work)) };
+    }
+
+    /// Schedules a function to be submitted to `queue` once all tasks
+    /// currently in the group have finished, without catching panics.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/dispatch/dispatchgroup/notify(qos:flags:queue:execute:)).
+    ///
+    /// # Safety
+    ///
+    /// It is undefined behavior to panic within the `work` function because it
+    /// is called from an `extern "C" fn`. Catch the panic yourself or call
+    /// [`notify`](Self::notify) instead.
+    #[inline]
+    #[doc(alias = "dispatch_group_notify")]
+    #[doc(alias = "dispatch_group_notify_f")]
+    pub unsafe fn notify_no_panic<F>(&self, queue: &DispatchQueue, work: F)
+    where
+        F: Send + FnOnce() + 'static,
+    {
+        self.notify_raw(queue, Box::into_raw(Box::new(work)), call_boxed::<F>);
+    }
+
+    /// Schedules a C function with a context pointer to be submitted to
+    /// `queue` once all tasks currently in the group have finished.
+    #[inline]
+    #[doc(alias = "dispatch_group_notify")]
+    #[doc(alias = "dispatch_group_notify_f")]
+    pub fn notify_raw<Ctx>(
+        &self,
+        queue: &DispatchQueue,
+        ctx: *mut Ctx,
+        work: extern "C" fn(*mut Ctx),
+    ) {
+        unsafe {
+            // SAFETY: Both functions have the same ABI.
+            let work: DispatchFn = mem::transmute(work);
+
+            sys::dispatch_group_notify_f(self, queue, ctx.cast(), work);
+        }
+    }
+}
@@ -10,15 +10,20 @@
 pub mod sys;
 
 mod autorelease_frequency;
+mod group;
 mod object;
 mod qos;
 mod queue;
+mod semaphore;
 mod source;
 mod time;
+mod work;
 
 pub use autorelease_frequency::*;
+pub use group::*;
 pub use object::*;
 pub use qos::*;
 pub use queue::*;
+pub use semaphore::*;
 pub use source::*;
 pub use time::*;
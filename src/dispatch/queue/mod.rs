@@ -1,4 +1,9 @@
-use super::{sys, DispatchObject, DispatchQos, DispatchQosClass};
+use super::{
+    sys,
+    work::{abort_on_panic, call_boxed},
+    DispatchObject, DispatchQos, DispatchQosClass, DispatchTime,
+};
+use crate::core::CancellationToken;
 use std::{
     ffi::{c_void, CStr, CString},
     fmt,
@@ -400,6 +405,102 @@ impl DispatchQueue {
         }
     }
 
+    /// Submits a function for asynchronous execution, unless `token` is
+    /// cancelled before it starts.
+    ///
+    /// Once started, `work` can check
+    /// [`is_cancelled`](CancellationToken::is_cancelled) to stop early.
+    ///
+    /// # Safety
+    ///
+    /// It is safe to panic within the `work` function. Panics will abort the
+    /// process.
+    #[inline]
+    pub fn spawn_async_cancellable<F>(&self, token: &CancellationToken, work: F)
+    where
+        F: Send + FnOnce() + 'static,
+    {
+        let token = token.clone();
+        self.spawn_async(move || {
+            if !token.is_cancelled() {
+                work();
+            }
+        });
+    }
+
+    /// Submits a function for asynchronous execution at `when`.
+    ///
+    /// The function is submitted to `self` no earlier than `when`, rather than
+    /// executed at exactly that time.
+    ///
+    /// Documentation:
+    /// [Swift](https://developer.apple.com/documentation/dispatch/dispatchqueue/asyncafter(deadline:qos:flags:execute:)) |
+    /// [Objective-C](https://developer.apple.com/documentation/dispatch/dispatch_after_f?language=objc)
+    ///
+    /// # Safety
+    ///
+    /// It is safe to panic within the `work` function. Panics will abort the
+    /// process.
+    ///
+    /// If the overhead of the extra setup is undesirable or you would like to
+    /// handle panics yourself, use
+    /// [`spawn_after_no_panic`](Self::spawn_after_no_panic) or
+    /// [`spawn_after_raw`](Self::spawn_after_raw) instead.
+    #[inline]
+    #[doc(alias = "dispatch_after")]
+    #[doc(alias = "dispatch_after_f")]
+    pub fn spawn_after<F>(&self, when: DispatchTime, work: F)
+    where
+        F: Send + FnOnce() + 'static,
+    {
+        // SAFETY: Any panics within `work` are caught.
+        unsafe { self.spawn_after_no_panic(when, abort_on_panic(work)) };
+    }
+
+    /// Submits a function for asynchronous execution at `when`, without
+    /// catching panics.
+    ///
+    /// Documentation:
+    /// [Swift](https://developer.apple.com/documentation/dispatch/dispatchqueue/asyncafter(deadline:qos:flags:execute:)) |
+    /// [Objective-C](https://developer.apple.com/documentation/dispatch/dispatch_after_f?language=objc)
+    ///
+    /// # Safety
+    ///
+    /// It is undefined behavior to panic within the `work` function because it
+    /// is called from an `extern "C" fn`. Catch the panic yourself or call
+    /// [`spawn_after`](Self::spawn_after) instead.
+    #[inline]
+    #[doc(alias = "dispatch_after")]
+    #[doc(alias = "dispatch_after_f")]
+    pub unsafe fn spawn_after_no_panic<F>(&self, when: DispatchTime, work: F)
+    where
+        F: Send + FnOnce() + 'static,
+    {
+        self.spawn_after_raw(when, Box::into_raw(Box::new(work)), call_boxed::<F>);
+    }
+
+    /// Submits a C function with a context pointer for asynchronous execution
+    /// at `when`.
+    ///
+    /// Documentation:
+    /// [Objective-C](https://developer.apple.com/documentation/dispatch/dispatch_after_f?language=objc)
+    #[inline]
+    #[doc(alias = "dispatch_after")]
+    #[doc(alias = "dispatch_after_f")]
+    pub fn spawn_after_raw<Ctx>(
+        &self,
+        when: DispatchTime,
+        ctx: *mut Ctx,
+        work: extern "C" fn(*mut Ctx),
+    ) {
+        unsafe {
+            // SAFETY: Both functions have the same ABI.
+            let work: DispatchFn = mem::transmute(work);
+
+            sys::dispatch_after_f(when, self, ctx.cast(), work);
+        }
+    }
+
     /// Submits a function for synchronous execution and returns the function's
     /// result after it finishes executing.
     ///
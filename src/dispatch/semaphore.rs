@@ -0,0 +1,62 @@
+use super::{sys, DispatchObject, DispatchTime};
+use crate::core::Arc;
+
+subclass! {
+    /// An object that controls access to a resource across multiple execution
+    /// contexts through use of a traditional counting semaphore.
+    ///
+    /// Documentation:
+    /// [Swift](https://developer.apple.com/documentation/dispatch/dispatchsemaphore) |
+    /// [Objective-C](https://developer.apple.com/documentation/dispatch/dispatch_semaphore)
+    pub class DispatchSemaphore: DispatchObject;
+}
+
+impl DispatchSemaphore {
+    /// Creates a new counting semaphore with an initial value.
+    ///
+    /// Passing 0 is useful for when two threads need to reconcile the
+    /// completion of a particular event. Passing a value greater than 0 is
+    /// useful for managing a finite pool of resources, where the pool size is
+    /// equal to the value.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/dispatch/dispatchsemaphore/init(value:)).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` is greater than `isize::MAX`.
+    #[inline]
+    #[doc(alias = "dispatch_semaphore_create")]
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(value: usize) -> Arc<Self> {
+        assert!(
+            value <= isize::MAX as usize,
+            "semaphore value {} is too large",
+            value
+        );
+
+        // This only returns null for negative values.
+        unsafe { Arc::from_raw(sys::dispatch_semaphore_create(value as _)) }
+    }
+
+    /// Increments the semaphore, returning `true` if a waiting thread was
+    /// woken.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/dispatch/dispatchsemaphore/signal()).
+    #[inline]
+    #[doc(alias = "dispatch_semaphore_signal")]
+    pub fn signal(&self) -> bool {
+        unsafe { sys::dispatch_semaphore_signal(self) != 0 }
+    }
+
+    /// Decrements the semaphore, waiting until `timeout` for it to become
+    /// available. Returns `false` if `timeout` elapsed first.
+    ///
+    /// Pass [`DispatchTime::DISTANT_FUTURE`] to wait indefinitely.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/dispatch/dispatchsemaphore/wait(timeout:)).
+    #[inline]
+    #[doc(alias = "dispatch_semaphore_wait")]
+    pub fn wait(&self, timeout: DispatchTime) -> bool {
+        unsafe { sys::dispatch_semaphore_wait(self, timeout) == 0 }
+    }
+}
@@ -1,9 +1,12 @@
 use super::{sys, DispatchObject, DispatchQueue, DispatchTime};
-use crate::core::Arc;
-use std::ptr;
+use crate::core::{Arc, Cancellable};
+use std::{ffi::c_void, panic, process, ptr};
 
+mod signal;
+mod timer;
 mod type_;
 
+pub use signal::*;
 pub use type_::*;
 
 // TODO: Create wrapper types for specific dispatch source types.
@@ -148,3 +151,41 @@ impl DispatchSource {
         unsafe { sys::dispatch_source_testcancel(self) != 0 }
     }
 }
+
+impl DispatchSource {
+    /// Sets `handler` as the event handler, dropping it when `self` is
+    /// deallocated.
+    ///
+    /// This replaces the context of `self`.
+    pub(crate) unsafe fn set_owned_event_handler<F>(&self, handler: F)
+    where
+        F: FnMut() + Send + 'static,
+    {
+        unsafe extern "C" fn call_handler<F>(ctx: *mut c_void)
+        where
+            F: FnMut() + Send + 'static,
+        {
+            let handler = &mut *(ctx as *mut F);
+
+            // Unwinding into libdispatch is undefined behavior.
+            if panic::catch_unwind(panic::AssertUnwindSafe(handler)).is_err() {
+                process::abort();
+            }
+        }
+
+        unsafe extern "C" fn release_handler<F>(ctx: *mut c_void) {
+            drop(Box::from_raw(ctx as *mut F));
+        }
+
+        self.set_context(Box::into_raw(Box::new(handler)).cast());
+        sys::dispatch_set_finalizer_f(&**self, Some(release_handler::<F>));
+        sys::dispatch_source_set_event_handler_f(self, call_handler::<F>);
+    }
+}
+
+impl Cancellable for DispatchSource {
+    #[inline]
+    fn cancel(&self) {
+        DispatchSource::cancel(self);
+    }
+}
@@ -0,0 +1,260 @@
+use super::{DispatchSource, DispatchSourceType};
+use crate::core::Arc;
+use crate::dispatch::{DispatchQosClass, DispatchQueue};
+use std::{
+    collections::VecDeque,
+    fmt,
+    future::Future,
+    os::raw::c_int,
+    pin::Pin,
+    sync::{self, Mutex},
+    task::{Context, Poll, Waker},
+};
+
+/// A UNIX signal number, such as [`TERM`](Self::TERM).
+///
+/// See [documentation](https://developer.apple.com/library/archive/documentation/System/Conceptual/ManPages_iPhoneOS/man3/signal.3.html).
+#[repr(transparent)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Signal(pub c_int);
+
+impl fmt::Debug for Signal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.name() {
+            Some(name) => f.write_str(name),
+            None => f.debug_tuple("Signal").field(&self.0).finish(),
+        }
+    }
+}
+
+impl Signal {
+    /// The controlling terminal was closed, or a daemon was asked to reload
+    /// its configuration.
+    #[doc(alias = "SIGHUP")]
+    pub const HUP: Self = Self(1);
+
+    /// The user pressed Control-C.
+    #[doc(alias = "SIGINT")]
+    pub const INT: Self = Self(2);
+
+    /// The user pressed Control-\.
+    #[doc(alias = "SIGQUIT")]
+    pub const QUIT: Self = Self(3);
+
+    /// The process was asked to terminate, such as by `launchd` or `kill`.
+    #[doc(alias = "SIGTERM")]
+    pub const TERM: Self = Self(15);
+
+    /// The size of the controlling terminal changed.
+    #[doc(alias = "SIGWINCH")]
+    pub const WINCH: Self = Self(28);
+
+    /// User-defined signal 1.
+    #[doc(alias = "SIGUSR1")]
+    pub const USR1: Self = Self(30);
+
+    /// User-defined signal 2.
+    #[doc(alias = "SIGUSR2")]
+    pub const USR2: Self = Self(31);
+
+    /// Returns the name of the signal, such as `"SIGTERM"`.
+    pub fn name(self) -> Option<&'static str> {
+        let name = match self {
+            Self::HUP => "SIGHUP",
+            Self::INT => "SIGINT",
+            Self::QUIT => "SIGQUIT",
+            Self::TERM => "SIGTERM",
+            Self::WINCH => "SIGWINCH",
+            Self::USR1 => "SIGUSR1",
+            Self::USR2 => "SIGUSR2",
+            _ => return None,
+        };
+        Some(name)
+    }
+}
+
+/// `SIG_IGN`.
+const IGNORE: usize = 1;
+
+extern "C" {
+    fn signal(signal: c_int, handler: usize) -> usize;
+}
+
+/// UNIX signals.
+impl DispatchSource {
+    /// Creates a source that calls `handler` on `queue` with the number of
+    /// times `sig` was delivered since the previous call.
+    ///
+    /// The default action of `sig`, which usually terminates the process, is
+    /// ignored so that only `handler` runs. It stays ignored after the source
+    /// is [cancelled](Self::cancel).
+    ///
+    /// Unlike a handler installed with `sigaction`, `handler` runs like any
+    /// other work on `queue`, so it may allocate, lock, and send messages.
+    ///
+    /// It is safe to panic within `handler`. Panics will abort the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/dispatch/dispatch_source_type_signal).
+    pub fn signal<F>(queue: &DispatchQueue, sig: Signal, mut handler: F) -> Arc<Self>
+    where
+        F: FnMut(usize) + Send + 'static,
+    {
+        unsafe {
+            signal(sig.0, IGNORE);
+
+            let source = Self::create(DispatchSourceType::signal(), sig.0 as usize, 0, Some(queue));
+
+            // The handler is dropped when the source is deallocated, so the
+            // source outlives every call. Capturing an `Arc` would leak it.
+            let this = &*source as *const Self as usize;
+            source.set_owned_event_handler(move || handler((*(this as *const Self)).data()));
+            source.resume();
+
+            source
+        }
+    }
+}
+
+/// An asynchronous stream of the UNIX signals that the process receives.
+///
+/// Each signal is handled by a [`DispatchSource::signal`], so its default
+/// action is ignored. The sources are cancelled when the stream is dropped.
+///
+/// # Examples
+///
+/// ```no_run
+/// # async fn example() {
+/// use fruity::dispatch::{Signal, SignalStream};
+///
+/// let mut signals = SignalStream::new(&[Signal::TERM, Signal::INT]);
+///
+/// let signal = signals.recv().await;
+/// println!("received {:?}, shutting down", signal);
+/// # }
+/// ```
+pub struct SignalStream {
+    shared: sync::Arc<Mutex<StreamState>>,
+    sources: Vec<Arc<DispatchSource>>,
+}
+
+#[derive(Default)]
+struct StreamState {
+    pending: VecDeque<Signal>,
+    waker: Option<Waker>,
+}
+
+impl fmt::Debug for SignalStream {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SignalStream")
+            .field("sources", &self.sources.len())
+            .finish()
+    }
+}
+
+impl Drop for SignalStream {
+    fn drop(&mut self) {
+        for source in &self.sources {
+            source.cancel();
+        }
+    }
+}
+
+impl SignalStream {
+    /// Creates a stream of each of `signals` that the process receives.
+    ///
+    /// Deliveries of the same signal before the stream is polled may be
+    /// coalesced into one.
+    pub fn new(signals: &[Signal]) -> Self {
+        let shared = sync::Arc::new(Mutex::new(StreamState::default()));
+        let queue = DispatchQueue::global_with_qos(DispatchQosClass::Default);
+
+        let sources = signals
+            .iter()
+            .map(|&signal| {
+                let shared = sync::Arc::clone(&shared);
+                DispatchSource::signal(queue, signal, move |_| {
+                    let mut state = shared.lock().unwrap_or_else(|e| e.into_inner());
+                    if !state.pending.contains(&signal) {
+                        state.pending.push_back(signal);
+                    }
+                    if let Some(waker) = state.waker.take() {
+                        waker.wake();
+                    }
+                })
+            })
+            .collect();
+
+        Self { shared, sources }
+    }
+
+    /// Returns the next signal that was received, if any, or registers the
+    /// waker of `cx` to be woken when one is.
+    pub fn poll_recv(&mut self, cx: &mut Context<'_>) -> Poll<Signal> {
+        let mut state = self.shared.lock().unwrap_or_else(|e| e.into_inner());
+        match state.pending.pop_front() {
+            Some(signal) => Poll::Ready(signal),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+
+    /// Returns a future that resolves to the next signal that is received.
+    #[inline]
+    pub fn recv(&mut self) -> NextSignal<'_> {
+        NextSignal(self)
+    }
+}
+
+/// A future that resolves to the next signal of a [`SignalStream`].
+///
+/// This is created by [`SignalStream::recv`].
+#[must_use = "futures do nothing unless polled"]
+#[derive(Debug)]
+pub struct NextSignal<'a>(&'a mut SignalStream);
+
+impl Future for NextSignal<'_> {
+    type Output = Signal;
+
+    #[inline]
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Signal> {
+        self.0.poll_recv(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{sync::mpsc, task::Wake, time::Duration};
+
+    extern "C" {
+        fn raise(signal: c_int) -> c_int;
+    }
+
+    struct ChannelWaker(Mutex<mpsc::Sender<()>>);
+
+    impl Wake for ChannelWaker {
+        fn wake(self: sync::Arc<Self>) {
+            let _ = self.0.lock().unwrap().send(());
+        }
+    }
+
+    #[test]
+    fn stream_receives_raised_signal() {
+        let mut signals = SignalStream::new(&[Signal::USR2]);
+
+        let (sender, receiver) = mpsc::channel();
+        let waker = Waker::from(sync::Arc::new(ChannelWaker(Mutex::new(sender))));
+        let mut cx = Context::from_waker(&waker);
+
+        assert_eq!(signals.poll_recv(&mut cx), Poll::Pending);
+
+        assert_eq!(unsafe { raise(Signal::USR2.0) }, 0);
+        receiver
+            .recv_timeout(Duration::from_secs(5))
+            .expect("the stream was not woken");
+
+        assert_eq!(signals.poll_recv(&mut cx), Poll::Ready(Signal::USR2));
+    }
+}
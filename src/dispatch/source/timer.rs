@@ -0,0 +1,49 @@
+use super::{DispatchSource, DispatchSourceType};
+use crate::core::Arc;
+use crate::dispatch::{DispatchQueue, DispatchTime};
+use std::time::Duration;
+
+/// Timers.
+impl DispatchSource {
+    /// Creates a timer source that calls `handler` on `queue` at `start`, and
+    /// then every `interval` if provided.
+    ///
+    /// The system may defer each firing by up to `leeway` to coalesce it with
+    /// other work and save power. Apple recommends a leeway of at least 10% of
+    /// the interval.
+    ///
+    /// The timer starts immediately and fires until it is
+    /// [cancelled](Self::cancel). `handler` is dropped when the source is
+    /// deallocated.
+    ///
+    /// It is safe to panic within `handler`. Panics will abort the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/dispatch/dispatch_source_type_timer).
+    pub fn timer<F>(
+        queue: &DispatchQueue,
+        start: DispatchTime,
+        interval: Option<Duration>,
+        leeway: Duration,
+        handler: F,
+    ) -> Arc<Self>
+    where
+        F: FnMut() + Send + 'static,
+    {
+        let interval = interval.map_or(DispatchTime::DISTANT_FUTURE.0, duration_nanos);
+
+        unsafe {
+            let source = Self::create(DispatchSourceType::timer(), 0, 0, Some(queue));
+
+            source.set_owned_event_handler(handler);
+            source.set_timer(start, interval, duration_nanos(leeway));
+            source.resume();
+
+            source
+        }
+    }
+}
+
+#[inline]
+fn duration_nanos(duration: Duration) -> u64 {
+    duration.as_nanos().min(u64::MAX as u128) as u64
+}
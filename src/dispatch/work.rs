@@ -0,0 +1,28 @@
+//! Helpers for passing Rust closures as dispatch work functions.
+
+use std::{ffi::c_void, panic, process};
+
+pub(crate) type DispatchFn = unsafe extern "C" fn(ctx: *mut c_void);
+
+/// Wraps `work` to abort on panic.
+#[inline]
+pub(crate) fn abort_on_panic<F>(work: F) -> impl Send + FnOnce() + 'static
+where
+    F: Send + FnOnce() + 'static,
+{
+    move || match panic::catch_unwind(panic::AssertUnwindSafe(work)) {
+        Ok(()) => {}
+        Err(_error) => process::abort(),
+    }
+}
+
+/// Calls and frees a boxed function passed as a dispatch context.
+pub(crate) extern "C" fn call_boxed<F>(ctx: *mut F)
+where
+    F: Send + FnOnce() + 'static,
+{
+    // SAFETY: `work` is only used from within this function.
+    let work = unsafe { Box::from_raw(ctx) };
+
+    work();
+}
@@ -0,0 +1,31 @@
+//! [File Provider](https://developer.apple.com/documentation/fileprovider)
+//! framework.
+//!
+//! # Feature Flag
+//!
+//! This module corresponds to the **`file_provider`**
+//! [feature flag](../index.html#feature-flags).
+//!
+//! It also transitively enables [`foundation`](crate::foundation).
+//!
+//! # Scope
+//!
+//! These are the APIs used by the app that hosts a File Provider extension,
+//! or by the extension itself, to manage its domains with
+//! [`NSFileProviderManager`]. The extension's enumerators and item types are
+//! implemented in Objective-C or Swift, which can call into a Rust core.
+//!
+//! Domains on macOS require macOS 11 or later.
+
+#![cfg(feature = "file_provider")]
+
+mod ns_file_provider_domain;
+mod ns_file_provider_item_identifier;
+mod ns_file_provider_manager;
+
+pub use ns_file_provider_domain::*;
+pub use ns_file_provider_item_identifier::*;
+pub use ns_file_provider_manager::*;
+
+#[link(name = "FileProvider", kind = "framework")]
+extern "C" {}
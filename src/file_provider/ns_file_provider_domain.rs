@@ -0,0 +1,95 @@
+use crate::core::Arc;
+use crate::foundation::NSString;
+use crate::objc::{ClassType, NSObject, Sel};
+use std::fmt;
+
+// `ns_string_wrapper!` is only in scope within `foundation`.
+objc_object_wrapper! {
+    /// The identifier of an [`NSFileProviderDomain`], unique within its
+    /// File Provider extension.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/fileprovider/nsfileproviderdomainidentifier).
+    #[derive(Ord, PartialOrd, Eq, PartialEq)]
+    pub wrapper NSFileProviderDomainIdentifier: NSString<'static>;
+}
+
+impl fmt::Debug for NSFileProviderDomainIdentifier {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl fmt::Display for NSFileProviderDomainIdentifier {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+objc_subclass! {
+    /// A tree of items provided by a File Provider extension, such as one
+    /// per signed-in account, shown as a location in Finder.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/fileprovider/nsfileproviderdomain).
+    pub class NSFileProviderDomain: NSObject<'static>;
+}
+
+impl fmt::Debug for NSFileProviderDomain {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("NSFileProviderDomain")
+            .field("identifier", &self.identifier())
+            .field("display_name", &self.display_name())
+            .finish()
+    }
+}
+
+impl NSFileProviderDomain {
+    /// Creates a domain with `identifier`, shown to the user as
+    /// `display_name`.
+    ///
+    /// Requires macOS 11 or later.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/fileprovider/nsfileproviderdomain/init(identifier:displayname:)).
+    #[inline]
+    #[doc(alias = "initWithIdentifier:displayName:")]
+    pub fn new(identifier: &NSFileProviderDomainIdentifier, display_name: &NSString) -> Arc<Self> {
+        unsafe {
+            let obj: Arc<Self> = Self::class().alloc();
+
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(
+                    obj: Arc<NSFileProviderDomain>,
+                    sel: Sel,
+                    identifier: &NSFileProviderDomainIdentifier,
+                    display_name: &NSString,
+                ) -> Arc<NSFileProviderDomain>;
+            }
+
+            objc_msgSend(
+                obj,
+                selector!(initWithIdentifier:displayName:),
+                identifier,
+                display_name,
+            )
+        }
+    }
+
+    /// Returns the identifier of the domain.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/fileprovider/nsfileproviderdomain/identifier).
+    #[inline]
+    pub fn identifier(&self) -> &NSFileProviderDomainIdentifier {
+        unsafe { _msg_send_any![self, identifier] }
+    }
+
+    /// Returns the name of the domain shown to the user.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/fileprovider/nsfileproviderdomain/displayname).
+    #[inline]
+    #[doc(alias = "displayName")]
+    pub fn display_name(&self) -> &NSString<'static> {
+        unsafe { _msg_send_any![self, displayName] }
+    }
+}
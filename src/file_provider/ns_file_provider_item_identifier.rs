@@ -0,0 +1,73 @@
+use crate::foundation::NSString;
+use std::fmt;
+
+// `ns_string_wrapper!` is only in scope within `foundation`.
+objc_object_wrapper! {
+    /// The identifier of an item provided by a File Provider extension.
+    ///
+    /// Identifiers are chosen by the extension, except for the
+    /// [container identifiers](Self::root_container) defined by the system.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/fileprovider/nsfileprovideritemidentifier).
+    #[derive(Ord, PartialOrd, Eq, PartialEq)]
+    pub wrapper NSFileProviderItemIdentifier: NSString<'static>;
+}
+
+impl fmt::Debug for NSFileProviderItemIdentifier {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl fmt::Display for NSFileProviderItemIdentifier {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+macro_rules! identifier {
+    (
+        $(#[$docs:meta])+
+        $fn:ident $value:literal
+    ) => {
+        $(#[$docs])+
+        #[inline]
+        #[doc(alias = $value)]
+        pub fn $fn() -> &'static NSFileProviderItemIdentifier {
+            extern "C" {
+                #[link_name = $value]
+                static VALUE: &'static NSFileProviderItemIdentifier;
+            }
+            unsafe { VALUE }
+        }
+    };
+}
+
+/// Container identifiers defined by the system.
+impl NSFileProviderItemIdentifier {
+    identifier! {
+        /// The root of the domain.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/fileprovider/nsfileprovideritemidentifier/rootcontainer).
+        root_container "NSFileProviderRootContainerItemIdentifier"
+    }
+
+    identifier! {
+        /// All items the system may need to know about without enumerating
+        /// their parents, such as recent or tagged items.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/fileprovider/nsfileprovideritemidentifier/workingset).
+        working_set "NSFileProviderWorkingSetContainerItemIdentifier"
+    }
+
+    identifier! {
+        /// Items in the trash.
+        ///
+        /// Requires macOS 11 or later.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/fileprovider/nsfileprovideritemidentifier/trashcontainer).
+        trash_container "NSFileProviderTrashContainerItemIdentifier"
+    }
+}
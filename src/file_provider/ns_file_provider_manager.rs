@@ -0,0 +1,253 @@
+use super::{NSFileProviderDomain, NSFileProviderItemIdentifier};
+use crate::core::Arc;
+use crate::foundation::{NSArray, NSError, NSURL};
+use crate::objc::{Block, ClassType, NSObject, RcBlock};
+use std::cell::Cell;
+
+objc_subclass! {
+    /// Communicates with the system about the items of a File Provider
+    /// domain, and registers the domains of the extension.
+    ///
+    /// Completion handlers are called on an unspecified queue.
+    ///
+    /// # Examples
+    ///
+    /// After the server reports changes to a folder, the extension's
+    /// enumerator for it is signaled so that the system asks for them:
+    ///
+    /// ```no_run
+    /// use fruity::file_provider::{NSFileProviderItemIdentifier, NSFileProviderManager};
+    ///
+    /// let manager = NSFileProviderManager::default_manager();
+    /// manager.signal_enumerator(NSFileProviderItemIdentifier::working_set(), |result| {
+    ///     if let Err(error) = result {
+    ///         eprintln!("failed to signal enumerator: {}", error);
+    ///     }
+    /// });
+    /// ```
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/fileprovider/nsfileprovidermanager).
+    pub class NSFileProviderManager: NSObject<'static>;
+}
+
+/// The result of a request without a value.
+pub type NSFileProviderResult = Result<(), Arc<NSError<'static>>>;
+
+/// Returns a block that passes its error, if any, to `completion` once.
+fn completion_block<F>(completion: F) -> RcBlock<(*const NSError<'static>,), ()>
+where
+    F: FnOnce(NSFileProviderResult) + Send + 'static,
+{
+    let completion = Cell::new(Some(completion));
+    RcBlock::new(move |error: *const NSError<'static>| {
+        if let Some(completion) = completion.take() {
+            completion(match unsafe { error.as_ref() } {
+                Some(error) => Err(Arc::retain(error)),
+                None => Ok(()),
+            });
+        }
+    })
+}
+
+/// Domain management.
+impl NSFileProviderManager {
+    /// Returns the manager of the extension's default domain, for extensions
+    /// that do not use domains.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/fileprovider/nsfileprovidermanager/default).
+    #[inline]
+    #[doc(alias = "defaultManager")]
+    pub fn default_manager() -> &'static Self {
+        unsafe { _msg_send_any![Self::class(), defaultManager] }
+    }
+
+    /// Returns the manager of `domain`, or [`None`] if the domain is not
+    /// registered.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/fileprovider/nsfileprovidermanager/init(for:)).
+    #[inline]
+    #[doc(alias = "managerForDomain:")]
+    pub fn for_domain(domain: &NSFileProviderDomain) -> Option<Arc<Self>> {
+        unsafe {
+            _msg_send_any![Self::class(), managerForDomain: domain => *const Self]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Registers `domain` so that it appears in Finder, calling `completion`
+    /// with the result.
+    ///
+    /// It is safe to panic within `completion`. Panics will abort the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/fileprovider/nsfileprovidermanager/add(_:completionhandler:)).
+    #[inline]
+    #[doc(alias = "addDomain:completionHandler:")]
+    pub fn add_domain<F>(domain: &NSFileProviderDomain, completion: F)
+    where
+        F: FnOnce(NSFileProviderResult) + Send + 'static,
+    {
+        let block = completion_block(completion);
+        let block: &Block<_, ()> = &block;
+
+        unsafe {
+            _msg_send_any![
+                Self::class(),
+                addDomain: domain
+                completionHandler: block
+                => ()
+            ]
+        }
+    }
+
+    /// Unregisters `domain` and deletes its local files, calling
+    /// `completion` with the result.
+    ///
+    /// It is safe to panic within `completion`. Panics will abort the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/fileprovider/nsfileprovidermanager/remove(_:completionhandler:)).
+    #[inline]
+    #[doc(alias = "removeDomain:completionHandler:")]
+    pub fn remove_domain<F>(domain: &NSFileProviderDomain, completion: F)
+    where
+        F: FnOnce(NSFileProviderResult) + Send + 'static,
+    {
+        let block = completion_block(completion);
+        let block: &Block<_, ()> = &block;
+
+        unsafe {
+            _msg_send_any![
+                Self::class(),
+                removeDomain: domain
+                completionHandler: block
+                => ()
+            ]
+        }
+    }
+
+    /// Unregisters all domains of the extension, such as when the user signs
+    /// out, calling `completion` with the result.
+    ///
+    /// It is safe to panic within `completion`. Panics will abort the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/fileprovider/nsfileprovidermanager/removealldomains(completionhandler:)).
+    #[inline]
+    #[doc(alias = "removeAllDomainsWithCompletionHandler:")]
+    pub fn remove_all_domains<F>(completion: F)
+    where
+        F: FnOnce(NSFileProviderResult) + Send + 'static,
+    {
+        let block = completion_block(completion);
+        let block: &Block<_, ()> = &block;
+
+        unsafe {
+            _msg_send_any![
+                Self::class(),
+                removeAllDomainsWithCompletionHandler: block
+                => ()
+            ]
+        }
+    }
+
+    /// Calls `completion` with the registered domains of the extension.
+    ///
+    /// It is safe to panic within `completion`. Panics will abort the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/fileprovider/nsfileprovidermanager/getdomainswithcompletionhandler(_:)).
+    #[doc(alias = "getDomainsWithCompletionHandler:")]
+    pub fn get_domains<F>(completion: F)
+    where
+        F: FnOnce(Result<Arc<NSArray<NSFileProviderDomain>>, Arc<NSError<'static>>>)
+            + Send
+            + 'static,
+    {
+        let completion = Cell::new(Some(completion));
+        let block = RcBlock::new(
+            move |domains: *const NSArray<NSFileProviderDomain>, error: *const NSError<'static>| {
+                if let Some(completion) = completion.take() {
+                    completion(unsafe {
+                        match domains.as_ref() {
+                            Some(domains) => Ok(Arc::retain(domains)),
+                            None => Err(Arc::retain(&*error)),
+                        }
+                    });
+                }
+            },
+        );
+        let block: &Block<_, ()> = &block;
+
+        unsafe {
+            _msg_send_any![
+                Self::class(),
+                getDomainsWithCompletionHandler: block
+                => ()
+            ]
+        }
+    }
+}
+
+/// Item updates.
+impl NSFileProviderManager {
+    /// Tells the system that the contents of the container `item` changed,
+    /// so that it asks the extension's enumerator for the changes, calling
+    /// `completion` with the result.
+    ///
+    /// Changes to items outside of the visible containers are signaled with
+    /// [`NSFileProviderItemIdentifier::working_set`].
+    ///
+    /// It is safe to panic within `completion`. Panics will abort the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/fileprovider/nsfileprovidermanager/signalenumerator(for:completionhandler:)).
+    #[inline]
+    #[doc(alias = "signalEnumeratorForContainerItemIdentifier:completionHandler:")]
+    pub fn signal_enumerator<F>(&self, item: &NSFileProviderItemIdentifier, completion: F)
+    where
+        F: FnOnce(NSFileProviderResult) + Send + 'static,
+    {
+        let block = completion_block(completion);
+        let block: &Block<_, ()> = &block;
+
+        unsafe {
+            _msg_send_any![
+                self,
+                signalEnumeratorForContainerItemIdentifier: item
+                completionHandler: block
+                => ()
+            ]
+        }
+    }
+
+    /// Calls `completion` with the URL at which the user sees `item`, such as
+    /// for revealing it in Finder.
+    ///
+    /// It is safe to panic within `completion`. Panics will abort the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/fileprovider/nsfileprovidermanager/getuservisibleurl(for:completionhandler:)).
+    #[doc(alias = "getUserVisibleURLForItemIdentifier:completionHandler:")]
+    pub fn get_user_visible_url<F>(&self, item: &NSFileProviderItemIdentifier, completion: F)
+    where
+        F: FnOnce(Result<Arc<NSURL>, Arc<NSError<'static>>>) + Send + 'static,
+    {
+        let completion = Cell::new(Some(completion));
+        let block = RcBlock::new(move |url: *const NSURL, error: *const NSError<'static>| {
+            if let Some(completion) = completion.take() {
+                completion(unsafe {
+                    match url.as_ref() {
+                        Some(url) => Ok(Arc::retain(url)),
+                        None => Err(Arc::retain(&*error)),
+                    }
+                });
+            }
+        });
+        let block: &Block<_, ()> = &block;
+
+        unsafe {
+            _msg_send_any![
+                self,
+                getUserVisibleURLForItemIdentifier: item
+                completionHandler: block
+                => ()
+            ]
+        }
+    }
+}
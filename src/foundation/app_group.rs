@@ -0,0 +1,255 @@
+use super::{NSFileManager, NSString, NSUserDefaults, NSURL};
+use crate::core::Arc;
+use std::{ffi::CString, fmt, str::FromStr};
+
+/// The longest Mach service name that `launchd` accepts, in bytes.
+///
+/// This is the size of `name_t` from `<bootstrap.h>`, minus the trailing
+/// nul.
+const MAX_MACH_SERVICE_NAME_LEN: usize = 127;
+
+/// An app group, whose members share defaults, a container directory, and
+/// Mach services.
+///
+/// Apps and their helpers or extensions join a group with the
+/// `com.apple.security.application-groups` entitlement. Group identifiers are
+/// either prefixed with the team identifier, such as
+/// `"ABCDE12345.com.example.shared"`, or with `"group."`, such as
+/// `"group.com.example.shared"`.
+///
+/// Sandboxed members may only register and look up Mach services whose name
+/// starts with the group identifier followed by a period, which
+/// [`mach_service_name`](Self::mach_service_name) produces.
+///
+/// # Examples
+///
+/// ```
+/// use fruity::foundation::AppGroup;
+///
+/// let group: AppGroup = "ABCDE12345.com.example.shared".parse().unwrap();
+/// assert_eq!(group.team_identifier(), Some("ABCDE12345"));
+///
+/// let service = group.mach_service_name("helper").unwrap();
+/// assert_eq!(service.to_str(), Ok("ABCDE12345.com.example.shared.helper"));
+/// assert!(group.owns_mach_service("ABCDE12345.com.example.shared.helper"));
+/// ```
+///
+/// See [documentation](https://developer.apple.com/documentation/bundleresources/entitlements/com.apple.security.application-groups).
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AppGroup {
+    identifier: String,
+}
+
+impl fmt::Display for AppGroup {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.identifier.fmt(f)
+    }
+}
+
+impl FromStr for AppGroup {
+    type Err = AppGroupError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl AppGroup {
+    /// Creates an app group from its identifier.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AppGroupError::InvalidIdentifier`] if `identifier` is not
+    /// prefixed with a team identifier or `"group."`, or is not a
+    /// reverse-DNS name of ASCII letters, digits, hyphens, and underscores.
+    pub fn new(identifier: &str) -> Result<Self, AppGroupError> {
+        let name = if let Some(name) = identifier.strip_prefix("group.") {
+            name
+        } else {
+            match identifier.split_once('.') {
+                Some((team, name)) if is_team_identifier(team) => name,
+                _ => return Err(AppGroupError::InvalidIdentifier),
+            }
+        };
+
+        if !is_dotted_name(name) {
+            return Err(AppGroupError::InvalidIdentifier);
+        }
+
+        Ok(Self {
+            identifier: identifier.to_owned(),
+        })
+    }
+
+    /// Returns the group identifier.
+    #[inline]
+    pub fn identifier(&self) -> &str {
+        &self.identifier
+    }
+
+    /// Returns the team identifier that prefixes the group identifier, or
+    /// [`None`] if it is prefixed with `"group."`.
+    #[inline]
+    pub fn team_identifier(&self) -> Option<&str> {
+        let (prefix, _) = self.identifier.split_once('.')?;
+        Some(prefix).filter(|&prefix| prefix != "group")
+    }
+
+    /// Returns the Mach service name for the service called `name` within
+    /// the group, which can be passed to
+    /// [`XPCConnection::mach_service`](crate::xpc::XPCConnection::mach_service).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AppGroupError::InvalidServiceName`] if `name` is empty or
+    /// contains characters other than ASCII letters, digits, hyphens,
+    /// underscores, and periods between them, or
+    /// [`AppGroupError::ServiceNameTooLong`] if the full name is longer than
+    /// `launchd` accepts.
+    pub fn mach_service_name(&self, name: &str) -> Result<CString, AppGroupError> {
+        if !is_dotted_name(name) {
+            return Err(AppGroupError::InvalidServiceName);
+        }
+
+        let service = format!("{}.{}", self.identifier, name);
+        if service.len() > MAX_MACH_SERVICE_NAME_LEN {
+            return Err(AppGroupError::ServiceNameTooLong);
+        }
+
+        // Validated names contain no nul bytes.
+        Ok(CString::new(service).unwrap())
+    }
+
+    /// Returns `true` if `service` is a valid Mach service name within the
+    /// group, which sandboxed group members are allowed to use.
+    pub fn owns_mach_service(&self, service: &str) -> bool {
+        let name = match service
+            .strip_prefix(self.identifier.as_str())
+            .and_then(|rest| rest.strip_prefix('.'))
+        {
+            Some(name) => name,
+            None => return false,
+        };
+        is_dotted_name(name) && service.len() <= MAX_MACH_SERVICE_NAME_LEN
+    }
+
+    /// Returns the defaults shared by the group, or [`None`] if they could
+    /// not be created.
+    ///
+    /// Values are only shared between apps that are entitled to the group.
+    #[inline]
+    pub fn user_defaults(&self) -> Option<Arc<NSUserDefaults>> {
+        NSUserDefaults::with_suite_name(&NSString::from_str(&self.identifier))
+    }
+
+    /// Returns the directory shared by the group, or [`None`] if the app is
+    /// not entitled to the group.
+    #[inline]
+    pub fn container_url(&self) -> Option<Arc<NSURL>> {
+        NSFileManager::default()
+            .container_url_for_security_application_group(&NSString::from_str(&self.identifier))
+    }
+}
+
+/// The error returned when an [`AppGroup`] identifier or Mach service name
+/// is invalid.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AppGroupError {
+    /// The group identifier is not prefixed with a team identifier or
+    /// `"group."`, or contains invalid characters.
+    InvalidIdentifier,
+
+    /// The Mach service name is empty or contains invalid characters.
+    InvalidServiceName,
+
+    /// The full Mach service name is longer than 127 bytes.
+    ServiceNameTooLong,
+}
+
+impl fmt::Display for AppGroupError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidIdentifier => "invalid app group identifier",
+            Self::InvalidServiceName => "invalid Mach service name",
+            Self::ServiceNameTooLong => "Mach service name is too long",
+        }
+        .fmt(f)
+    }
+}
+
+impl std::error::Error for AppGroupError {}
+
+/// Returns `true` if `s` is a 10-character team identifier, such as
+/// `"ABCDE12345"`.
+fn is_team_identifier(s: &str) -> bool {
+    s.len() == 10
+        && s.bytes()
+            .all(|b| b.is_ascii_uppercase() || b.is_ascii_digit())
+}
+
+/// Returns `true` if `s` is made of one or more period-separated components
+/// of ASCII letters, digits, hyphens, and underscores.
+fn is_dotted_name(s: &str) -> bool {
+    s.split('.').all(|component| {
+        !component.is_empty()
+            && component
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_')
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identifiers() {
+        for valid in &["ABCDE12345.com.example", "group.com.example.shared"] {
+            assert!(AppGroup::new(valid).is_ok(), "{}", valid);
+        }
+        for invalid in &[
+            "",
+            "com.example.shared",
+            "abcde12345.com.example",
+            "ABCDE12345.",
+            "group.",
+            "group.com..example",
+            "group.com.example/shared",
+        ] {
+            assert_eq!(
+                AppGroup::new(invalid),
+                Err(AppGroupError::InvalidIdentifier),
+                "{}",
+                invalid
+            );
+        }
+
+        let group = AppGroup::new("group.com.example").unwrap();
+        assert_eq!(group.team_identifier(), None);
+    }
+
+    #[test]
+    fn mach_services() {
+        let group = AppGroup::new("ABCDE12345.com.example").unwrap();
+
+        assert_eq!(
+            group.mach_service_name("xpc.helper").unwrap().to_str(),
+            Ok("ABCDE12345.com.example.xpc.helper")
+        );
+        assert_eq!(
+            group.mach_service_name(".helper"),
+            Err(AppGroupError::InvalidServiceName)
+        );
+        assert_eq!(
+            group.mach_service_name(&"a".repeat(MAX_MACH_SERVICE_NAME_LEN)),
+            Err(AppGroupError::ServiceNameTooLong)
+        );
+
+        assert!(group.owns_mach_service("ABCDE12345.com.example.helper"));
+        assert!(!group.owns_mach_service("ABCDE12345.com.example"));
+        assert!(!group.owns_mach_service("ABCDE12345.com.examplehelper"));
+        assert!(!group.owns_mach_service("ABCDE12345.com.other.helper"));
+    }
+}
@@ -0,0 +1,116 @@
+use super::{NSFileCoordinator, NSFileCoordinatorWritingOptions, NSString, NSURL};
+use std::{
+    fs::{File, OpenOptions},
+    io,
+    os::unix::fs::OpenOptionsExt,
+    path::Path,
+};
+
+/// `O_NONBLOCK` from `<sys/fcntl.h>`.
+const O_NONBLOCK: i32 = 0x0004;
+
+/// `O_EXLOCK` from `<sys/fcntl.h>`.
+const O_EXLOCK: i32 = 0x0020;
+
+/// `EWOULDBLOCK` from `<sys/errno.h>`.
+const EWOULDBLOCK: i32 = 35;
+
+/// An exclusive advisory lock over a file, held for as long as this value
+/// lives.
+///
+/// The file is opened with `O_EXLOCK`, which atomically acquires a `flock(2)`
+/// lock at open time. Other processes that open the same file with `O_EXLOCK`
+/// or `O_SHLOCK` will wait or fail until this lock is dropped. This is useful
+/// for having multiple instances of a program coordinate access to shared
+/// state files.
+///
+/// Unlike [`NSDistributedLock`](super::NSDistributedLock), the lock is released
+/// by the kernel if the process terminates.
+#[derive(Debug)]
+pub struct FileLock {
+    file: File,
+}
+
+impl FileLock {
+    /// Opens or creates the file at `path` and blocks until an exclusive lock
+    /// on it is acquired.
+    #[inline]
+    pub fn lock<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Self::open(path.as_ref(), O_EXLOCK)
+    }
+
+    /// Opens or creates the file at `path` and attempts to acquire an
+    /// exclusive lock on it without blocking.
+    ///
+    /// Returns [`None`] if the lock is held elsewhere.
+    #[inline]
+    pub fn try_lock<P: AsRef<Path>>(path: P) -> io::Result<Option<Self>> {
+        match Self::open(path.as_ref(), O_EXLOCK | O_NONBLOCK) {
+            Ok(lock) => Ok(Some(lock)),
+            Err(error) if error.raw_os_error() == Some(EWOULDBLOCK) => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Opens or creates the file at `path` and locks it within coordinated
+    /// writing, then calls `f` with the lock.
+    ///
+    /// Other processes and file presenters that use
+    /// [`NSFileCoordinator`] for the same file wait until `f` returns. File
+    /// presenters are also asked to save their changes before `f` is called
+    /// and to reload the file afterward.
+    ///
+    /// Returns an [`InvalidInput`](io::ErrorKind::InvalidInput) error if
+    /// `path` is not valid UTF-8.
+    pub fn coordinate<P, F, T>(path: P, f: F) -> io::Result<T>
+    where
+        P: AsRef<Path>,
+        F: FnOnce(&Self) -> T,
+    {
+        let path = path.as_ref();
+        let url = match path.to_str() {
+            Some(path) => NSURL::from_file_path(&NSString::from_str(path)),
+            None => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "path is not valid UTF-8",
+                ))
+            }
+        };
+
+        let coordinator = NSFileCoordinator::new();
+        let options = NSFileCoordinatorWritingOptions::default();
+
+        coordinator
+            .coordinate_writing(&url, options, |url| {
+                // The coordinator may provide a different URL for the item,
+                // such as after it has been moved by a file presenter.
+                let lock = match url.path() {
+                    Some(url_path) => Self::lock(url_path.to_string()),
+                    None => Self::lock(path),
+                }?;
+                Ok(f(&lock))
+            })
+            .unwrap_or_else(|error| Err(io::Error::other(error.to_string())))
+    }
+
+    fn open(path: &Path, flags: i32) -> io::Result<Self> {
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .custom_flags(flags)
+            .open(path)?;
+        Ok(Self { file })
+    }
+
+    /// Returns the locked file.
+    #[inline]
+    pub fn file(&self) -> &File {
+        &self.file
+    }
+
+    /// Releases the lock by closing the file.
+    #[inline]
+    pub fn unlock(self) {}
+}
@@ -15,24 +15,130 @@ mod ns_string;
 
 pub mod error_codes;
 
+mod app_group;
 mod cmp;
+mod file_lock;
 mod geometry;
+#[cfg(all(feature = "core_services", target_os = "macos"))]
+mod ns_apple_event_descriptor;
+mod ns_array;
+mod ns_attributed_string;
+#[cfg(target_os = "macos")]
+mod ns_background_activity_scheduler;
+mod ns_character_set;
+mod ns_data;
+mod ns_date;
+mod ns_date_formatter_style;
+mod ns_date_interval;
+mod ns_date_interval_formatter;
+mod ns_dictionary;
+#[cfg(target_os = "macos")]
+mod ns_distributed_lock;
+mod ns_enumeration_options;
+mod ns_enumerator;
 mod ns_error;
 mod ns_exception;
+mod ns_file_coordinator;
+mod ns_file_manager;
+mod ns_file_handle;
+mod ns_hash_table;
+mod ns_index_path;
+mod ns_iso8601_date_format_options;
+mod ns_iso8601_date_formatter;
+mod ns_key_value_observing;
+mod ns_map_table;
+mod ns_notification;
 mod ns_null;
 mod ns_number;
+mod ns_ordered_collection_difference;
+mod ns_pointer_array;
+mod ns_pointer_functions;
+mod ns_predicate;
+mod ns_process_info;
+mod ns_progress;
+mod ns_quality_of_service;
 mod ns_range;
+mod ns_relative_date_time_formatter;
+mod ns_sort_descriptor;
+mod ns_time_zone;
+mod ns_timer;
+mod ns_url;
+mod ns_url_authentication_challenge;
+mod ns_url_components;
+mod ns_url_credential;
+mod ns_url_protection_space;
+mod ns_url_query_item;
+mod ns_user_defaults;
+#[cfg(target_os = "macos")]
+mod ns_user_script_task;
+mod ns_uuid;
 mod ns_value;
+#[cfg(target_os = "macos")]
+mod ns_xml_node;
+mod ns_xml_parser;
 
+pub use app_group::*;
 pub use cmp::*;
+pub use file_lock::*;
 pub use geometry::*;
+#[cfg(all(feature = "core_services", target_os = "macos"))]
+pub use ns_apple_event_descriptor::*;
+pub use ns_array::*;
+pub use ns_attributed_string::*;
+#[cfg(target_os = "macos")]
+pub use ns_background_activity_scheduler::*;
+pub use ns_character_set::*;
+pub use ns_data::*;
+pub use ns_date::*;
+pub use ns_date_formatter_style::*;
+pub use ns_date_interval::*;
+pub use ns_date_interval_formatter::*;
+pub use ns_dictionary::*;
+#[cfg(target_os = "macos")]
+pub use ns_distributed_lock::*;
+pub use ns_enumeration_options::*;
+pub use ns_enumerator::*;
 pub use ns_error::*;
 pub use ns_exception::*;
+pub use ns_file_coordinator::*;
+pub use ns_file_manager::*;
+pub use ns_file_handle::*;
+pub use ns_hash_table::*;
+pub use ns_index_path::*;
+pub use ns_iso8601_date_format_options::*;
+pub use ns_iso8601_date_formatter::*;
+pub use ns_key_value_observing::*;
+pub use ns_map_table::*;
+pub use ns_notification::*;
 pub use ns_null::*;
 pub use ns_number::*;
+pub use ns_ordered_collection_difference::*;
+pub use ns_pointer_array::*;
+pub use ns_pointer_functions::*;
+pub use ns_predicate::*;
+pub use ns_process_info::*;
+pub use ns_progress::*;
+pub use ns_quality_of_service::*;
 pub use ns_range::*;
+pub use ns_relative_date_time_formatter::*;
+pub use ns_sort_descriptor::*;
 pub use ns_string::*;
+pub use ns_time_zone::*;
+pub use ns_timer::*;
+pub use ns_url::*;
+pub use ns_url_authentication_challenge::*;
+pub use ns_url_components::*;
+pub use ns_url_credential::*;
+pub use ns_url_protection_space::*;
+pub use ns_url_query_item::*;
+pub use ns_user_defaults::*;
+#[cfg(target_os = "macos")]
+pub use ns_user_script_task::*;
+pub use ns_uuid::*;
 pub use ns_value::*;
+#[cfg(target_os = "macos")]
+pub use ns_xml_node::*;
+pub use ns_xml_parser::*;
 
 /// A number of seconds.
 ///
@@ -0,0 +1,492 @@
+use super::{NSString, NSURL};
+use crate::core::{Arc, OSErr};
+use crate::core_services::{AEDesc, AEDescType, AEEventClass, AEEventID, AEKeyword, AppleEvent};
+use crate::objc::{ClassType, NSInteger, NSObject, Sel};
+use std::{
+    mem,
+    path::{Path, PathBuf},
+};
+
+objc_subclass! {
+    /// A wrapper for the Apple event descriptor data type.
+    ///
+    /// This is the Foundation counterpart to [`AEDesc`]. Descriptors can be
+    /// converted between the two with [`from_ae_desc`](Self::from_ae_desc),
+    /// [`as_ae_desc`](Self::as_ae_desc), and [`to_ae_desc`](Self::to_ae_desc).
+    ///
+    /// # Feature Flag
+    ///
+    /// This type also requires the **`core_services`**
+    /// [feature flag](../index.html#feature-flags).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsappleeventdescriptor).
+    pub class NSAppleEventDescriptor: NSObject<'static>;
+}
+
+impl From<AEDesc> for Arc<NSAppleEventDescriptor> {
+    #[inline]
+    fn from(desc: AEDesc) -> Self {
+        NSAppleEventDescriptor::from_ae_desc(desc)
+    }
+}
+
+impl From<AppleEvent> for Arc<NSAppleEventDescriptor> {
+    #[inline]
+    fn from(event: AppleEvent) -> Self {
+        NSAppleEventDescriptor::from_apple_event(event)
+    }
+}
+
+/// Returns a retained reference to an unowned, nullable descriptor.
+#[inline]
+unsafe fn retain_nullable(
+    desc: *const NSAppleEventDescriptor,
+) -> Option<Arc<NSAppleEventDescriptor>> {
+    if desc.is_null() {
+        None
+    } else {
+        Some(Arc::retain_raw(desc))
+    }
+}
+
+/// Converting to and from Apple event descriptors.
+impl NSAppleEventDescriptor {
+    /// Creates a descriptor that takes ownership of `desc`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsappleeventdescriptor/init(aedescnocopy:)).
+    #[inline]
+    #[doc(alias = "initWithAEDescNoCopy")]
+    #[doc(alias = "initWithAEDescNoCopy:")]
+    pub fn from_ae_desc(desc: AEDesc) -> Arc<Self> {
+        unsafe {
+            let value: Arc<Self> = Self::class().alloc();
+
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(
+                    obj: Arc<NSAppleEventDescriptor>,
+                    sel: Sel,
+                    desc: *const AEDesc,
+                ) -> Arc<NSAppleEventDescriptor>;
+            }
+
+            let result = objc_msgSend(value, selector!(initWithAEDescNoCopy:), &desc);
+
+            // The descriptor now owns the data of `desc`.
+            mem::forget(desc);
+
+            result
+        }
+    }
+
+    /// Creates a descriptor that takes ownership of `event`.
+    #[inline]
+    pub fn from_apple_event(event: AppleEvent) -> Arc<Self> {
+        Self::from_ae_desc(event.into_desc())
+    }
+
+    /// Returns the descriptor owned by `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsappleeventdescriptor/aedesc).
+    #[inline]
+    #[doc(alias = "aeDesc")]
+    pub fn as_ae_desc(&self) -> &AEDesc {
+        unsafe { &*_msg_send_any![self, aeDesc => *const AEDesc] }
+    }
+
+    /// Returns a copy of the descriptor owned by `self`.
+    #[inline]
+    pub fn to_ae_desc(&self) -> Result<AEDesc, OSErr> {
+        self.as_ae_desc().duplicate()
+    }
+
+    /// Returns a copy of `self` as an Apple event.
+    ///
+    /// This fails with [`OSErr::AE_WRONG_DATA_TYPE`] if `self` is not an
+    /// Apple event.
+    #[inline]
+    pub fn to_apple_event(&self) -> Result<AppleEvent, OSErr> {
+        if self.descriptor_type() == AEDescType::APPLE_EVENT {
+            self.to_ae_desc().map(AppleEvent::from_desc)
+        } else {
+            Err(OSErr::AE_WRONG_DATA_TYPE)
+        }
+    }
+}
+
+/// Creating descriptors.
+impl NSAppleEventDescriptor {
+    /// Returns a descriptor of type [`AEDescType::NULL`] with no data.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsappleeventdescriptor/null()).
+    #[inline]
+    #[doc(alias = "nullDescriptor")]
+    pub fn null() -> Arc<Self> {
+        unsafe {
+            let desc = _msg_send_any![Self::class(), nullDescriptor => *const Self];
+            Arc::retain_raw(desc)
+        }
+    }
+
+    /// Returns an empty list descriptor.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsappleeventdescriptor/list()).
+    #[inline]
+    #[doc(alias = "listDescriptor")]
+    pub fn list() -> Arc<Self> {
+        unsafe {
+            let desc = _msg_send_any![Self::class(), listDescriptor => *const Self];
+            Arc::retain_raw(desc)
+        }
+    }
+
+    /// Returns an empty record descriptor.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsappleeventdescriptor/record()).
+    #[inline]
+    #[doc(alias = "recordDescriptor")]
+    pub fn record() -> Arc<Self> {
+        unsafe {
+            let desc = _msg_send_any![Self::class(), recordDescriptor => *const Self];
+            Arc::retain_raw(desc)
+        }
+    }
+
+    /// Returns a descriptor containing `value` as Unicode text.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsappleeventdescriptor/init(string:)).
+    #[inline]
+    #[doc(alias = "descriptorWithString")]
+    #[doc(alias = "descriptorWithString:")]
+    pub fn from_string(value: &NSString) -> Arc<Self> {
+        unsafe {
+            let desc = _msg_send_any![Self::class(), descriptorWithString: value => *const Self];
+            Arc::retain_raw(desc)
+        }
+    }
+
+    /// Returns a descriptor of type [`AEDescType::BOOL`].
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsappleeventdescriptor/init(boolean:)).
+    #[inline]
+    #[doc(alias = "descriptorWithBoolean")]
+    #[doc(alias = "descriptorWithBoolean:")]
+    pub fn from_bool(value: bool) -> Arc<Self> {
+        unsafe {
+            let desc =
+                _msg_send_any![Self::class(), descriptorWithBoolean: value as u8 => *const Self];
+            Arc::retain_raw(desc)
+        }
+    }
+
+    /// Returns a descriptor of type [`AEDescType::I32`].
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsappleeventdescriptor/init(int32:)).
+    #[inline]
+    #[doc(alias = "descriptorWithInt32")]
+    #[doc(alias = "descriptorWithInt32:")]
+    pub fn from_i32(value: i32) -> Arc<Self> {
+        unsafe {
+            let desc = _msg_send_any![Self::class(), descriptorWithInt32: value => *const Self];
+            Arc::retain_raw(desc)
+        }
+    }
+
+    /// Returns a descriptor of type [`AEDescType::F64`].
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsappleeventdescriptor/init(double:)).
+    #[inline]
+    #[doc(alias = "descriptorWithDouble")]
+    #[doc(alias = "descriptorWithDouble:")]
+    pub fn from_f64(value: f64) -> Arc<Self> {
+        unsafe {
+            let desc = _msg_send_any![Self::class(), descriptorWithDouble: value => *const Self];
+            Arc::retain_raw(desc)
+        }
+    }
+
+    /// Returns a descriptor of type [`AEDescType::FILE_URL`].
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsappleeventdescriptor/init(fileurl:)).
+    #[inline]
+    #[doc(alias = "descriptorWithFileURL")]
+    #[doc(alias = "descriptorWithFileURL:")]
+    pub fn from_file_url(url: &NSURL) -> Arc<Self> {
+        unsafe {
+            let desc = _msg_send_any![Self::class(), descriptorWithFileURL: url => *const Self];
+            Arc::retain_raw(desc)
+        }
+    }
+
+    /// Returns a descriptor of type [`AEDescType::FILE_URL`] for the file at
+    /// `path`.
+    ///
+    /// See [`AEDesc::from_path`].
+    #[inline]
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Arc<Self>, OSErr> {
+        AEDesc::from_path(path).map(Self::from_ae_desc)
+    }
+
+    /// Returns a descriptor of type [`AEDescType::BOOKMARK_DATA`].
+    ///
+    /// See [`AEDesc::from_bookmark_data`].
+    #[inline]
+    pub fn from_bookmark_data(bookmark: &[u8]) -> Result<Arc<Self>, OSErr> {
+        AEDesc::from_bookmark_data(bookmark).map(Self::from_ae_desc)
+    }
+}
+
+/// Reading values.
+impl NSAppleEventDescriptor {
+    /// Returns the type of this descriptor's data.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsappleeventdescriptor/descriptortype).
+    #[inline]
+    #[doc(alias = "descriptorType")]
+    pub fn descriptor_type(&self) -> AEDescType {
+        unsafe { _msg_send_any![self, descriptorType] }
+    }
+
+    /// Returns a copy of `self` coerced to `desired_type`, or `None` if
+    /// coercion is not possible.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsappleeventdescriptor/coerce(todescriptortype:)).
+    #[inline]
+    #[doc(alias = "coerceToDescriptorType")]
+    #[doc(alias = "coerceToDescriptorType:")]
+    pub fn coerce(&self, desired_type: AEDescType) -> Option<Arc<Self>> {
+        unsafe {
+            retain_nullable(_msg_send_any![
+                self,
+                coerceToDescriptorType: desired_type => *const Self
+            ])
+        }
+    }
+
+    /// Returns the contents of `self` coerced to Unicode text, or `None` if
+    /// coercion is not possible.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsappleeventdescriptor/stringvalue).
+    #[inline]
+    #[doc(alias = "stringValue")]
+    pub fn string_value(&self) -> Option<Arc<NSString<'static>>> {
+        unsafe {
+            let string = _msg_send_any![self, stringValue => *const NSString<'static>];
+            if string.is_null() {
+                None
+            } else {
+                Some(Arc::retain_raw(string))
+            }
+        }
+    }
+
+    /// Returns the contents of `self` coerced to a boolean, or `false` if
+    /// coercion is not possible.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsappleeventdescriptor/booleanvalue).
+    #[inline]
+    #[doc(alias = "booleanValue")]
+    pub fn bool_value(&self) -> bool {
+        unsafe { _msg_send_any![self, booleanValue => u8] != 0 }
+    }
+
+    /// Returns the contents of `self` coerced to an integer, or 0 if coercion
+    /// is not possible.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsappleeventdescriptor/int32value).
+    #[inline]
+    #[doc(alias = "int32Value")]
+    pub fn i32_value(&self) -> i32 {
+        unsafe { _msg_send_any![self, int32Value] }
+    }
+
+    /// Returns the contents of `self` coerced to a double, or NaN if coercion
+    /// is not possible.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsappleeventdescriptor/doublevalue).
+    #[inline]
+    #[doc(alias = "doubleValue")]
+    pub fn f64_value(&self) -> f64 {
+        unsafe { _msg_send_any![self, doubleValue] }
+    }
+
+    /// Returns the contents of `self` coerced to a file URL, or `None` if
+    /// coercion is not possible.
+    ///
+    /// Aliases are coerced, but bookmark data is not; use
+    /// [`to_path_buf`](Self::to_path_buf) to also resolve bookmarks.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsappleeventdescriptor/fileurlvalue).
+    #[inline]
+    #[doc(alias = "fileURLValue")]
+    pub fn file_url_value(&self) -> Option<Arc<NSURL>> {
+        unsafe {
+            let url = _msg_send_any![self, fileURLValue => *const NSURL];
+            if url.is_null() {
+                None
+            } else {
+                Some(Arc::retain_raw(url))
+            }
+        }
+    }
+
+    /// Returns the path of the file that `self` refers to, resolving bookmark
+    /// data and aliases, or `None` if it does not refer to a local file.
+    ///
+    /// See [`AEDesc::to_path_buf`].
+    #[inline]
+    pub fn to_path_buf(&self) -> Option<PathBuf> {
+        self.as_ae_desc().to_path_buf().ok()
+    }
+}
+
+/// List and record descriptors.
+///
+/// Unlike the Objective-C API, indices here start at 0.
+impl NSAppleEventDescriptor {
+    /// Returns the number of items in a list or record descriptor.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsappleeventdescriptor/numberofitems).
+    #[inline]
+    #[doc(alias = "numberOfItems")]
+    pub fn len(&self) -> usize {
+        unsafe { _msg_send_any![self, numberOfItems => NSInteger] as usize }
+    }
+
+    /// Returns `true` if `self` has no items.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the item at `index`, or `None` if out of bounds.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsappleeventdescriptor/atindex(_:)).
+    #[inline]
+    #[doc(alias = "descriptorAtIndex")]
+    #[doc(alias = "descriptorAtIndex:")]
+    pub fn get(&self, index: usize) -> Option<Arc<Self>> {
+        if index >= self.len() {
+            return None;
+        }
+        let index = (index + 1) as NSInteger;
+        unsafe { retain_nullable(_msg_send_any![self, descriptorAtIndex: index => *const Self]) }
+    }
+
+    /// Appends `desc` to the end of a list descriptor.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsappleeventdescriptor/insert(_:at:)).
+    #[inline]
+    #[doc(alias = "insertDescriptor:atIndex:")]
+    pub fn push(&self, desc: &Self) {
+        // An index of 0 appends.
+        unsafe { _msg_send_any![self, insertDescriptor: desc atIndex: 0 as NSInteger] }
+    }
+
+    /// Inserts `desc` at `index` in a list descriptor, replacing the item at
+    /// `index` if there is one.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsappleeventdescriptor/insert(_:at:)).
+    #[inline]
+    #[doc(alias = "insertDescriptor:atIndex:")]
+    pub fn set(&self, index: usize, desc: &Self) {
+        let index = (index + 1) as NSInteger;
+        unsafe { _msg_send_any![self, insertDescriptor: desc atIndex: index] }
+    }
+
+    /// Returns the item for `keyword` in a record descriptor.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsappleeventdescriptor/forkeyword(_:)).
+    #[inline]
+    #[doc(alias = "descriptorForKeyword")]
+    #[doc(alias = "descriptorForKeyword:")]
+    pub fn get_key(&self, keyword: AEKeyword) -> Option<Arc<Self>> {
+        unsafe {
+            retain_nullable(_msg_send_any![self, descriptorForKeyword: keyword => *const Self])
+        }
+    }
+
+    /// Sets the item for `keyword` in a record descriptor.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsappleeventdescriptor/setdescriptor(_:forkeyword:)).
+    #[inline]
+    #[doc(alias = "setDescriptor:forKeyword:")]
+    pub fn set_key(&self, keyword: AEKeyword, desc: &Self) {
+        unsafe { _msg_send_any![self, setDescriptor: desc forKeyword: keyword] }
+    }
+}
+
+/// Apple event descriptors.
+impl NSAppleEventDescriptor {
+    /// Returns the class of an Apple event descriptor.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsappleeventdescriptor/eventclass).
+    #[inline]
+    #[doc(alias = "eventClass")]
+    pub fn event_class(&self) -> AEEventClass {
+        unsafe { _msg_send_any![self, eventClass] }
+    }
+
+    /// Returns the ID of an Apple event descriptor.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsappleeventdescriptor/eventid).
+    #[inline]
+    #[doc(alias = "eventID")]
+    pub fn event_id(&self) -> AEEventID {
+        unsafe { _msg_send_any![self, eventID] }
+    }
+
+    /// Returns the parameter for `keyword` of an Apple event descriptor.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsappleeventdescriptor/paramdescriptor(forkeyword:)).
+    #[inline]
+    #[doc(alias = "paramDescriptorForKeyword")]
+    #[doc(alias = "paramDescriptorForKeyword:")]
+    pub fn param(&self, keyword: AEKeyword) -> Option<Arc<Self>> {
+        unsafe {
+            retain_nullable(_msg_send_any![
+                self,
+                paramDescriptorForKeyword: keyword => *const Self
+            ])
+        }
+    }
+
+    /// Returns the attribute for `keyword` of an Apple event descriptor.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsappleeventdescriptor/attributedescriptor(forkeyword:)).
+    #[inline]
+    #[doc(alias = "attributeDescriptorForKeyword")]
+    #[doc(alias = "attributeDescriptorForKeyword:")]
+    pub fn attribute(&self, keyword: AEKeyword) -> Option<Arc<Self>> {
+        unsafe {
+            retain_nullable(_msg_send_any![
+                self,
+                attributeDescriptorForKeyword: keyword => *const Self
+            ])
+        }
+    }
+}
+
+/// Conversions with [`serde`](https://docs.rs/serde), enabled by the
+/// **`serde`** feature flag.
+///
+/// These follow the same mapping as [`AEDesc::serialize`] and
+/// [`AEDesc::deserialize`].
+#[cfg(feature = "serde")]
+impl NSAppleEventDescriptor {
+    /// Creates a descriptor from a value, such as a record of properties.
+    #[inline]
+    pub fn serialize<T: serde::Serialize + ?Sized>(
+        value: &T,
+    ) -> Result<Arc<Self>, crate::core_services::AESerdeError> {
+        AEDesc::serialize(value).map(Self::from_ae_desc)
+    }
+
+    /// Creates a value from `self`, such as the reply of an Apple event.
+    #[inline]
+    pub fn deserialize<T: serde::de::DeserializeOwned>(
+        &self,
+    ) -> Result<T, crate::core_services::AESerdeError> {
+        self.as_ae_desc().deserialize()
+    }
+}
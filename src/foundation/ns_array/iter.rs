@@ -0,0 +1,198 @@
+use super::{NSArray, NSNull};
+use crate::objc::NSUInteger;
+use std::{fmt, os::raw::c_ulong, ptr};
+
+/// The number of objects requested per fast enumeration batch.
+const BUF_LEN: usize = 16;
+
+/// The state of a fast enumeration, as used by
+/// `countByEnumeratingWithState:objects:count:`.
+///
+/// See [documentation](https://developer.apple.com/documentation/foundation/nsfastenumerationstate).
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct NSFastEnumerationState {
+    /// Arbitrary state information used by the enumerated collection.
+    pub state: c_ulong,
+    /// A pointer to the current batch of objects.
+    pub items_ptr: *mut *const crate::objc::NSObject<'static>,
+    /// A pointer to a value that changes if the collection is mutated.
+    pub mutations_ptr: *mut c_ulong,
+    /// Extra state information used by the enumerated collection.
+    pub extra: [c_ulong; 5],
+}
+
+impl Default for NSFastEnumerationState {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            state: 0,
+            items_ptr: ptr::null_mut(),
+            mutations_ptr: ptr::null_mut(),
+            extra: [0; 5],
+        }
+    }
+}
+
+/// An iterator over the objects of an [`NSArray`], including [`NSNull`].
+///
+/// Objects are yielded as is, so they may be `NSNull` rather than a `T`.
+///
+/// This uses the
+/// [`NSFastEnumeration`](https://developer.apple.com/documentation/foundation/nsfastenumeration)
+/// protocol to fetch objects in batches.
+///
+/// # Panics
+///
+/// Iteration panics if the array is mutated while being iterated, which
+/// matches the exception raised by `for`-`in` loops in Objective-C.
+pub struct NSArrayRawIter<'a, T> {
+    array: &'a NSArray<T>,
+    state: NSFastEnumerationState,
+    buf: [*const T; BUF_LEN],
+    /// Whether the current batch is stored in `buf`, rather than in memory
+    /// owned by the array. This is tracked instead of comparing against
+    /// `items_ptr` because the iterator may be moved between batches.
+    items_in_buf: bool,
+    index: usize,
+    count: usize,
+    mutations: Option<c_ulong>,
+    remaining: usize,
+}
+
+impl<T> fmt::Debug for NSArrayRawIter<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("NSArrayRawIter")
+            .field("remaining", &self.remaining)
+            .finish()
+    }
+}
+
+impl<'a, T> NSArrayRawIter<'a, T> {
+    #[inline]
+    pub(super) fn new(array: &'a NSArray<T>) -> Self {
+        Self {
+            array,
+            state: NSFastEnumerationState::default(),
+            buf: [ptr::null(); BUF_LEN],
+            items_in_buf: false,
+            index: 0,
+            count: 0,
+            mutations: None,
+            remaining: array.len(),
+        }
+    }
+
+    /// Fetches the next batch of objects, returning `false` if there are none.
+    #[cold]
+    fn next_batch(&mut self) -> bool {
+        let buf = self.buf.as_mut_ptr();
+        let count: NSUInteger = unsafe {
+            _msg_send_any_cached![
+                self.array,
+                countByEnumeratingWithState: &mut self.state as *mut NSFastEnumerationState
+                objects: buf
+                count: BUF_LEN as NSUInteger
+            ]
+        };
+
+        self.index = 0;
+        self.count = count;
+        if count == 0 {
+            return false;
+        }
+
+        self.items_in_buf = ptr::eq(self.state.items_ptr.cast::<*const T>(), buf);
+
+        let mutations = unsafe { *self.state.mutations_ptr };
+        match self.mutations {
+            None => self.mutations = Some(mutations),
+            Some(expected) => self.check_mutations(expected, mutations),
+        }
+
+        true
+    }
+
+    #[inline]
+    #[track_caller]
+    fn check_mutations(&self, expected: c_ulong, mutations: c_ulong) {
+        if expected != mutations {
+            panic!("{:p} was mutated while being enumerated", self.array);
+        }
+    }
+}
+
+impl<'a, T> Iterator for NSArrayRawIter<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a T> {
+        if self.index == self.count {
+            if !self.next_batch() {
+                return None;
+            }
+        } else if let Some(expected) = self.mutations {
+            self.check_mutations(expected, unsafe { *self.state.mutations_ptr });
+        }
+
+        let object = if self.items_in_buf {
+            self.buf[self.index]
+        } else {
+            unsafe { *(self.state.items_ptr as *const *const T).add(self.index) }
+        };
+
+        self.index += 1;
+        self.remaining = self.remaining.saturating_sub(1);
+
+        Some(unsafe { &*object })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for NSArrayRawIter<'_, T> {}
+
+/// An iterator over the objects of an [`NSArray`] that skips [`NSNull`].
+///
+/// See [`NSArrayRawIter`] for how objects are fetched.
+///
+/// # Panics
+///
+/// Iteration panics if the array is mutated while being iterated.
+pub struct NSArrayIter<'a, T> {
+    raw: NSArrayRawIter<'a, T>,
+}
+
+impl<T> fmt::Debug for NSArrayIter<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("NSArrayIter")
+            .field("remaining", &self.raw.remaining)
+            .finish()
+    }
+}
+
+impl<'a, T> NSArrayIter<'a, T> {
+    #[inline]
+    pub(super) fn new(array: &'a NSArray<T>) -> Self {
+        Self {
+            raw: NSArrayRawIter::new(array),
+        }
+    }
+}
+
+impl<'a, T> Iterator for NSArrayIter<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a T> {
+        self.raw.by_ref().find(|object| !NSNull::is_null(*object))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.raw.remaining))
+    }
+}
@@ -0,0 +1,463 @@
+use super::{
+    NSEnumerationOptions, NSEnumerator, NSNotFound, NSNull, NSOrderedCollectionDifference, NSRange,
+};
+use crate::core::{Arc, ObjectType};
+use crate::objc::{
+    Block, Class, ClassType, NSObject, NSUInteger, ObjCObject, Sel, StackBlock, BOOL,
+};
+use std::{fmt, marker::PhantomData, ops::Deref, ptr::NonNull};
+
+mod iter;
+mod mutable;
+
+pub use iter::*;
+pub use mutable::*;
+
+/// A static ordered collection of objects of type `T`.
+///
+/// See [documentation](https://developer.apple.com/documentation/foundation/nsarray).
+///
+/// # Element Type
+///
+/// Objective-C arrays are only lightly typed, so `T` is not checked at
+/// runtime. Arrays returned by this crate are typed according to their
+/// documented contents. Arrays obtained elsewhere should be reinterpreted with
+/// [`cast_unchecked`](Self::cast_unchecked) only when their contents are
+/// known.
+///
+/// Arrays may hold [`NSNull`] in place of `nil`, such as those parsed from
+/// JSON. Getters like [`get`](Self::get) return [`None`] for it rather than
+/// an object that is not a `T`, and [`get_nullable`](Self::get_nullable)
+/// tells it apart from a missing object. [`iter`](Self::iter) skips it, and
+/// [`iter_raw`](Self::iter_raw) yields it as is.
+#[repr(C)]
+pub struct NSArray<T = NSObject<'static>> {
+    base: NSObject<'static>,
+    _marker: PhantomData<*const T>,
+}
+
+// SAFETY: Immutable arrays are thread-safe, and the mutators of
+// `NSMutableArray` are `unsafe` for this reason.
+unsafe impl<T: Send + Sync> Send for NSArray<T> {}
+unsafe impl<T: Send + Sync> Sync for NSArray<T> {}
+
+impl<T> ObjectType for NSArray<T> {
+    #[inline]
+    fn retain(obj: &Self) -> Arc<Self> {
+        let obj = Arc::retain(&obj.base);
+        unsafe { Arc::cast_unchecked(obj) }
+    }
+
+    #[inline]
+    unsafe fn release(obj: NonNull<Self>) {
+        NSObject::release(obj.cast());
+    }
+}
+
+impl<T: 'static> crate::objc::ObjectType<'static> for NSArray<T> {}
+
+impl<T: 'static> ClassType<'static> for NSArray<T> {
+    #[inline]
+    fn class() -> &'static Class {
+        crate::_objc_class!(@ "OBJC_CLASS_$_NSArray")
+    }
+}
+
+impl<T> Deref for NSArray<T> {
+    type Target = NSObject<'static>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<T> AsRef<ObjCObject<'static>> for NSArray<T> {
+    #[inline]
+    fn as_ref(&self) -> &ObjCObject<'static> {
+        self.base.as_ref()
+    }
+}
+
+impl<T> PartialEq for NSArray<T> {
+    #[inline]
+    #[doc(alias = "isEqualToArray")]
+    #[doc(alias = "isEqualToArray:")]
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { _msg_send_any![self, isEqualToArray: other => BOOL] }.into()
+    }
+}
+
+impl<T: ObjectType + fmt::Debug + 'static> fmt::Debug for NSArray<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, T: ObjectType + 'static> IntoIterator for &'a NSArray<T> {
+    type Item = &'a T;
+    type IntoIter = NSArrayIter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: ObjectType + 'static> Default for Arc<NSArray<T>> {
+    #[inline]
+    fn default() -> Self {
+        NSArray::new()
+    }
+}
+
+impl<T: ObjectType + 'static> From<&[&T]> for Arc<NSArray<T>> {
+    #[inline]
+    fn from(objects: &[&T]) -> Self {
+        NSArray::from_slice(objects)
+    }
+}
+
+impl<T> NSArray<T> {
+    /// Reinterprets the elements of this array as `U`.
+    ///
+    /// # Safety
+    ///
+    /// All elements must be valid instances of `U`.
+    #[inline]
+    pub unsafe fn cast_unchecked<U>(&self) -> &NSArray<U> {
+        &*(self as *const Self as *const NSArray<U>)
+    }
+
+    /// Reinterprets the elements of `this` as `U`.
+    ///
+    /// # Safety
+    ///
+    /// All elements must be valid instances of `U`.
+    #[inline]
+    pub unsafe fn cast_arc_unchecked<U>(this: Arc<Self>) -> Arc<NSArray<U>> {
+        Arc::cast_unchecked(this)
+    }
+
+    /// Returns the number of objects in `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsarray/count).
+    #[inline]
+    #[doc(alias = "count")]
+    pub fn len(&self) -> usize {
+        unsafe { _msg_send_any_cached![self, count => NSUInteger] }
+    }
+
+    /// Returns `true` if `self` contains no objects.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<T: ObjectType + 'static> NSArray<T> {
+    /// Creates an empty array.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsarray/init()).
+    #[inline]
+    pub fn new() -> Arc<Self> {
+        unsafe { Self::class().alloc_init() }
+    }
+
+    /// Creates an array containing retained references to `objects`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsarray/init(objects:count:)).
+    #[inline]
+    #[doc(alias = "initWithObjects:count:")]
+    pub fn from_slice(objects: &[&T]) -> Arc<Self> {
+        unsafe {
+            let value: Arc<Self> = Self::class().alloc();
+
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(
+                    obj: Arc<NSArray>,
+                    sel: Sel,
+                    objects: *const *const NSObject<'static>,
+                    count: NSUInteger,
+                ) -> Arc<NSArray>;
+            }
+
+            let array = objc_msgSend(
+                Arc::cast_unchecked(value),
+                selector!(initWithObjects:count:),
+                objects.as_ptr().cast(),
+                objects.len(),
+            );
+            Arc::cast_unchecked(array)
+        }
+    }
+
+    /// Returns the object at `index`, or [`None`] if `index` is out of
+    /// bounds or the object is [`NSNull`].
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsarray/object(at:)).
+    #[inline]
+    #[doc(alias = "objectAtIndex")]
+    #[doc(alias = "objectAtIndex:")]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.get_nullable(index).flatten()
+    }
+
+    /// Returns the object at `index`, `Some(None)` if the object is
+    /// [`NSNull`], or [`None`] if `index` is out of bounds.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsarray/object(at:)).
+    #[inline]
+    #[doc(alias = "objectAtIndex")]
+    #[doc(alias = "objectAtIndex:")]
+    pub fn get_nullable(&self, index: usize) -> Option<Option<&T>> {
+        if index < self.len() {
+            let object = unsafe { self.get_unchecked(index) };
+            Some(Some(object).filter(|object| !NSNull::is_null(*object)))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the object at `index` without bounds checking.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsarray/object(at:)).
+    ///
+    /// # Safety
+    ///
+    /// `index` must be within `0..self.len()`. Otherwise, an Objective-C
+    /// exception is raised.
+    ///
+    /// The object is returned as is, so it may be [`NSNull`] rather than a
+    /// `T`.
+    #[inline]
+    #[doc(alias = "objectAtIndex")]
+    #[doc(alias = "objectAtIndex:")]
+    pub unsafe fn get_unchecked(&self, index: usize) -> &T {
+        &*_msg_send_any_cached![self, objectAtIndex: index as NSUInteger => *const T]
+    }
+
+    /// Returns the first object, or [`None`] if `self` is empty or the
+    /// object is [`NSNull`].
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsarray/firstobject).
+    #[inline]
+    #[doc(alias = "firstObject")]
+    pub fn first(&self) -> Option<&T> {
+        unsafe { _msg_send_any![self, firstObject => *const T].as_ref() }
+            .filter(|object| !NSNull::is_null(*object))
+    }
+
+    /// Returns the last object, or [`None`] if `self` is empty or the object
+    /// is [`NSNull`].
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsarray/lastobject).
+    #[inline]
+    #[doc(alias = "lastObject")]
+    pub fn last(&self) -> Option<&T> {
+        unsafe { _msg_send_any![self, lastObject => *const T].as_ref() }
+            .filter(|object| !NSNull::is_null(*object))
+    }
+
+    /// Fills `buf` with the objects starting at `start`, returning the number
+    /// of objects written.
+    ///
+    /// This fetches all objects in a single message, which is much faster
+    /// than calling [`get`](Self::get) for each index. Fewer than `buf.len()`
+    /// objects are written if the end of `self` is reached first.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsarray/getobjects(_:range:)).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start` is greater than `self.len()`.
+    #[inline]
+    #[track_caller]
+    #[doc(alias = "getObjects:range:")]
+    pub fn get_objects<'a>(&'a self, start: usize, buf: &mut [Option<&'a T>]) -> usize {
+        let len = self.len();
+        assert!(
+            start <= len,
+            "start index {} out of bounds for array of length {}",
+            start,
+            len
+        );
+
+        let count = (len - start).min(buf.len());
+        let range = NSRange::new(start as NSUInteger, count as NSUInteger);
+
+        // SAFETY: `Option<&T>` has the same layout as a nullable pointer.
+        let objects = buf.as_mut_ptr().cast::<*const T>();
+        unsafe { _msg_send_any![self, getObjects: objects range: range] }
+
+        count
+    }
+
+    /// Returns `true` if an object equal to `object` is in `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsarray/contains(_:)).
+    #[inline]
+    #[doc(alias = "containsObject")]
+    #[doc(alias = "containsObject:")]
+    pub fn contains(&self, object: &T) -> bool {
+        unsafe { _msg_send_any![self, containsObject: object => BOOL] }.into()
+    }
+
+    /// Returns the lowest index of an object equal to `object`, or [`None`]
+    /// if there is none.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsarray/index(of:)).
+    #[inline]
+    #[doc(alias = "indexOfObject")]
+    #[doc(alias = "indexOfObject:")]
+    pub fn index_of(&self, object: &T) -> Option<usize> {
+        let index = unsafe { _msg_send_any![self, indexOfObject: object => NSUInteger] };
+        if index == NSNotFound as NSUInteger {
+            None
+        } else {
+            Some(index)
+        }
+    }
+
+    /// Returns an iterator over the objects of `self`, skipping
+    /// [`NSNull`].
+    ///
+    /// This uses fast enumeration, which fetches objects in batches.
+    #[inline]
+    pub fn iter(&self) -> NSArrayIter<'_, T> {
+        NSArrayIter::new(self)
+    }
+
+    /// Returns an iterator over the objects of `self`, including
+    /// [`NSNull`].
+    ///
+    /// Unlike [`iter`](Self::iter), objects are yielded as is, so they may
+    /// be `NSNull` rather than a `T`. Use
+    /// [`NSNull::is_null`](super::NSNull::is_null) to check before treating
+    /// them as `T`.
+    #[inline]
+    pub fn iter_raw(&self) -> NSArrayRawIter<'_, T> {
+        NSArrayRawIter::new(self)
+    }
+
+    /// Returns an enumerator over the objects of `self`.
+    ///
+    /// Prefer [`iter`](Self::iter), which is faster.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsarray/objectenumerator()).
+    #[inline]
+    #[doc(alias = "objectEnumerator")]
+    pub fn object_enumerator(&self) -> Arc<NSEnumerator<T>> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, objectEnumerator => *const NSEnumerator<T>]) }
+    }
+
+    /// Returns a vector of retained references to the objects of `self`,
+    /// skipping [`NSNull`].
+    #[inline]
+    pub fn to_vec(&self) -> Vec<Arc<T>> {
+        self.iter().map(Arc::retain).collect()
+    }
+
+    /// Returns the changes that turn `other` into `self`, comparing objects
+    /// with `isEqual:`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsarray/difference(from:)).
+    #[inline]
+    #[doc(alias = "differenceFromArray:")]
+    pub fn difference_from(&self, other: &NSArray<T>) -> Arc<NSOrderedCollectionDifference<T>> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![
+                self,
+                differenceFromArray: other
+                => *const NSOrderedCollectionDifference<T>
+            ])
+        }
+    }
+
+    /// Returns a new array with `difference` applied to `self`, or [`None`]
+    /// if its removals do not match the objects of `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsarray/applying(_:)).
+    #[inline]
+    #[doc(alias = "arrayByApplyingDifference:")]
+    pub fn applying_difference(
+        &self,
+        difference: &NSOrderedCollectionDifference<T>,
+    ) -> Option<Arc<Self>> {
+        unsafe {
+            _msg_send_any![self, arrayByApplyingDifference: difference => *const Self]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Returns the indexes of objects for which `predicate` returns `true`.
+    ///
+    /// With [`NSEnumerationOptions::CONCURRENT`], `predicate` may be called
+    /// from multiple threads at once, which can be faster for large arrays.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsarray/indexesofobjects(options:passingtest:)).
+    #[doc(alias = "indexesOfObjectsWithOptions:passingTest:")]
+    pub fn indexes_passing_test<F>(&self, options: NSEnumerationOptions, predicate: F) -> Vec<usize>
+    where
+        F: Fn(&T, usize) -> bool + Sync,
+    {
+        unsafe {
+            let indexes = self.index_set_passing_test(options, &predicate);
+
+            let count: NSUInteger = _msg_send_any![indexes, count];
+            let mut result = Vec::<NSUInteger>::with_capacity(count);
+
+            let written: NSUInteger = _msg_send_any![
+                indexes,
+                getIndexes: result.as_mut_ptr()
+                maxCount: count
+                inIndexRange: std::ptr::null_mut::<NSRange>()
+            ];
+            result.set_len(written);
+            result
+        }
+    }
+
+    /// Returns a new array containing the objects for which `predicate`
+    /// returns `true`, in their original order.
+    ///
+    /// With [`NSEnumerationOptions::CONCURRENT`], `predicate` may be called
+    /// from multiple threads at once, which can be faster for large arrays.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsarray/objects(at:)).
+    #[doc(alias = "objectsAtIndexes:")]
+    pub fn filtered<F>(&self, options: NSEnumerationOptions, predicate: F) -> Arc<Self>
+    where
+        F: Fn(&T) -> bool + Sync,
+    {
+        unsafe {
+            let indexes = self.index_set_passing_test(options, &|object, _| predicate(object));
+            let array = _msg_send_any![self, objectsAtIndexes: indexes => *const Self];
+            Arc::retain_raw(array)
+        }
+    }
+
+    /// Returns an autoreleased `NSIndexSet`.
+    unsafe fn index_set_passing_test(
+        &self,
+        options: NSEnumerationOptions,
+        predicate: &(dyn Fn(&T, usize) -> bool + Sync),
+    ) -> &NSObject<'static> {
+        let block = StackBlock::new(
+            |object: *const T, index: NSUInteger, _stop: *mut BOOL| -> BOOL {
+                predicate(&*object, index).into()
+            },
+        );
+        let block: &Block<_, BOOL> = &block;
+
+        &*_msg_send_any![
+            self,
+            indexesOfObjectsWithOptions: options
+            passingTest: block
+            => *const NSObject<'static>
+        ]
+    }
+}
@@ -0,0 +1,293 @@
+use super::NSArray;
+use crate::core::{Arc, ObjectType};
+use crate::objc::{Class, ClassType, NSObject, NSUInteger, ObjCObject};
+use std::{fmt, ops::Deref, ptr::NonNull};
+
+/// A dynamic ordered collection of objects of type `T`.
+///
+/// Mutation methods take `&self` and are `unsafe`, because they may release
+/// objects that are still borrowed and the array is not thread-safe while
+/// being mutated. [`NSArray::iter`] panics if the array is mutated during
+/// iteration.
+///
+/// See [documentation](https://developer.apple.com/documentation/foundation/nsmutablearray).
+#[repr(C)]
+pub struct NSMutableArray<T = NSObject<'static>> {
+    base: NSArray<T>,
+}
+
+impl<T> ObjectType for NSMutableArray<T> {
+    #[inline]
+    fn retain(obj: &Self) -> Arc<Self> {
+        let obj = NSArray::retain(&obj.base);
+        unsafe { Arc::cast_unchecked(obj) }
+    }
+
+    #[inline]
+    unsafe fn release(obj: NonNull<Self>) {
+        NSArray::<T>::release(obj.cast());
+    }
+}
+
+impl<T: 'static> crate::objc::ObjectType<'static> for NSMutableArray<T> {}
+
+impl<T: 'static> ClassType<'static> for NSMutableArray<T> {
+    #[inline]
+    fn class() -> &'static Class {
+        crate::_objc_class!(@ "OBJC_CLASS_$_NSMutableArray")
+    }
+}
+
+impl<T> Deref for NSMutableArray<T> {
+    type Target = NSArray<T>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<T> AsRef<ObjCObject<'static>> for NSMutableArray<T> {
+    #[inline]
+    fn as_ref(&self) -> &ObjCObject<'static> {
+        self.base.as_ref()
+    }
+}
+
+impl<T> AsRef<NSArray<T>> for NSMutableArray<T> {
+    #[inline]
+    fn as_ref(&self) -> &NSArray<T> {
+        &self.base
+    }
+}
+
+impl<T> PartialEq for NSMutableArray<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.base == other.base
+    }
+}
+
+impl<T: ObjectType + fmt::Debug + 'static> fmt::Debug for NSMutableArray<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.base.fmt(f)
+    }
+}
+
+impl<T: ObjectType + 'static> Default for Arc<NSMutableArray<T>> {
+    #[inline]
+    fn default() -> Self {
+        NSMutableArray::new()
+    }
+}
+
+impl<T: ObjectType + 'static> NSMutableArray<T> {
+    /// Creates an empty array.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsmutablearray/init()).
+    #[inline]
+    pub fn new() -> Arc<Self> {
+        unsafe { Self::class().alloc_init() }
+    }
+
+    /// Creates an empty array with enough memory to hold `capacity` objects.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsmutablearray/init(capacity:)).
+    #[inline]
+    #[doc(alias = "initWithCapacity")]
+    #[doc(alias = "initWithCapacity:")]
+    pub fn with_capacity(capacity: usize) -> Arc<Self> {
+        unsafe {
+            let value: Arc<Self> = Self::class().alloc();
+
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(
+                    obj: Arc<NSMutableArray>,
+                    sel: crate::objc::Sel,
+                    capacity: NSUInteger,
+                ) -> Arc<NSMutableArray>;
+            }
+
+            let array = objc_msgSend(
+                Arc::cast_unchecked(value),
+                selector!(initWithCapacity:),
+                capacity,
+            );
+            Arc::cast_unchecked(array)
+        }
+    }
+
+    /// Creates a mutable array containing retained references to `objects`.
+    #[inline]
+    pub fn from_slice(objects: &[&T]) -> Arc<Self> {
+        let array = Self::with_capacity(objects.len());
+        for object in objects {
+            // SAFETY: `array` has not been shared yet.
+            unsafe { array.push(object) };
+        }
+        array
+    }
+
+    /// Appends a retained reference to `object`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsmutablearray/add(_:)).
+    ///
+    /// # Safety
+    ///
+    /// `self` must not be accessed on any other thread during this call.
+    #[inline]
+    #[doc(alias = "addObject")]
+    #[doc(alias = "addObject:")]
+    pub unsafe fn push(&self, object: &T) {
+        _msg_send_any_cached![self, addObject: object]
+    }
+
+    /// Inserts a retained reference to `object` at `index`, shifting later
+    /// objects up.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than `self.len()`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsmutablearray/insert(_:at:)).
+    ///
+    /// # Safety
+    ///
+    /// `self` must not be accessed on any other thread during this call.
+    #[inline]
+    #[track_caller]
+    #[doc(alias = "insertObject:atIndex:")]
+    pub unsafe fn insert(&self, index: usize, object: &T) {
+        check_index(index, self.len() + 1);
+        _msg_send_any![
+            self,
+            insertObject: object
+            atIndex: index as NSUInteger
+        ]
+    }
+
+    /// Replaces the object at `index` with `object`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsmutablearray/replaceobject(at:with:)).
+    ///
+    /// # Safety
+    ///
+    /// No references to objects of `self`, such as from
+    /// [`get`](NSArray::get) or [`iter`](NSArray::iter), may be alive, and
+    /// `self` must not be accessed on any other thread during this call.
+    #[inline]
+    #[track_caller]
+    #[doc(alias = "replaceObjectAtIndex:withObject:")]
+    pub unsafe fn set(&self, index: usize, object: &T) {
+        check_index(index, self.len());
+        _msg_send_any![
+            self,
+            replaceObjectAtIndex: index as NSUInteger
+            withObject: object
+        ]
+    }
+
+    /// Removes and returns the object at `index`, shifting later objects
+    /// down.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsmutablearray/removeobject(at:)).
+    ///
+    /// # Safety
+    ///
+    /// No references to objects of `self`, such as from
+    /// [`get`](NSArray::get) or [`iter`](NSArray::iter), may be alive, and
+    /// `self` must not be accessed on any other thread during this call.
+    #[inline]
+    #[track_caller]
+    #[doc(alias = "removeObjectAtIndex")]
+    #[doc(alias = "removeObjectAtIndex:")]
+    pub unsafe fn remove(&self, index: usize) -> Arc<T> {
+        check_index(index, self.len());
+
+        // Retain before removing because the array may hold the only
+        // reference.
+        let object = Arc::retain(self.get_unchecked(index));
+        _msg_send_any![self, removeObjectAtIndex: index as NSUInteger => ()];
+        object
+    }
+
+    /// Removes and returns the last object, or [`None`] if `self` is empty.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsmutablearray/removelastobject()).
+    ///
+    /// # Safety
+    ///
+    /// No references to objects of `self`, such as from
+    /// [`get`](NSArray::get) or [`iter`](NSArray::iter), may be alive, and
+    /// `self` must not be accessed on any other thread during this call.
+    #[inline]
+    #[doc(alias = "removeLastObject")]
+    pub unsafe fn pop(&self) -> Option<Arc<T>> {
+        let object = Arc::retain(self.last()?);
+        _msg_send_any![self, removeLastObject => ()];
+        Some(object)
+    }
+
+    /// Removes all objects.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsmutablearray/removeallobjects()).
+    ///
+    /// # Safety
+    ///
+    /// No references to objects of `self`, such as from
+    /// [`get`](NSArray::get) or [`iter`](NSArray::iter), may be alive, and
+    /// `self` must not be accessed on any other thread during this call.
+    #[inline]
+    #[doc(alias = "removeAllObjects")]
+    pub unsafe fn clear(&self) {
+        _msg_send_any![self, removeAllObjects]
+    }
+
+    /// Swaps the objects at indexes `a` and `b`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` are out of bounds.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsmutablearray/exchangeobject(at:withobjectat:)).
+    ///
+    /// # Safety
+    ///
+    /// `self` must not be accessed on any other thread during this call.
+    #[inline]
+    #[track_caller]
+    #[doc(alias = "exchangeObjectAtIndex:withObjectAtIndex:")]
+    pub unsafe fn swap(&self, a: usize, b: usize) {
+        let len = self.len();
+        check_index(a, len);
+        check_index(b, len);
+        _msg_send_any![
+            self,
+            exchangeObjectAtIndex: a as NSUInteger
+            withObjectAtIndex: b as NSUInteger
+        ]
+    }
+}
+
+#[inline]
+#[track_caller]
+fn check_index(index: usize, len: usize) {
+    // Out-of-bounds access raises an Objective-C exception, which must not
+    // unwind into Rust.
+    assert!(
+        index < len,
+        "index {} out of bounds for NSMutableArray of length {}",
+        index,
+        len,
+    );
+}
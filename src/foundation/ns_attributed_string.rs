@@ -0,0 +1,154 @@
+use super::{NSDictionary, NSString};
+use crate::core::Arc;
+use crate::objc::{ClassType, NSObject, NSUInteger, Sel};
+
+objc_subclass! {
+    /// A string with attributes, such as font and color, applied to it.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsattributedstring).
+    pub class NSAttributedString: NSObject<'static>;
+}
+
+impl NSAttributedString {
+    /// Creates an attributed string with `string` and no attributes.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsattributedstring/init(string:)).
+    #[inline]
+    #[doc(alias = "initWithString:")]
+    pub fn new(string: &NSString) -> Arc<Self> {
+        unsafe {
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(
+                    obj: Arc<NSAttributedString>,
+                    sel: Sel,
+                    string: &NSString,
+                ) -> Arc<NSAttributedString>;
+            }
+
+            let obj = Self::class().alloc();
+            let sel = selector!(initWithString:);
+
+            objc_msgSend(obj, sel, string)
+        }
+    }
+
+    /// Creates an attributed string with `attributes` applied to all of
+    /// `string`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsattributedstring/init(string:attributes:)).
+    #[inline]
+    #[doc(alias = "initWithString:attributes:")]
+    pub fn with_attributes(
+        string: &NSString,
+        attributes: &NSDictionary<NSAttributedStringKey, NSObject<'static>>,
+    ) -> Arc<Self> {
+        unsafe {
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(
+                    obj: Arc<NSAttributedString>,
+                    sel: Sel,
+                    string: &NSString,
+                    attributes: &NSDictionary<NSAttributedStringKey, NSObject<'static>>,
+                ) -> Arc<NSAttributedString>;
+            }
+
+            let obj = Self::class().alloc();
+            let sel = selector!(initWithString:attributes:);
+
+            objc_msgSend(obj, sel, string, attributes)
+        }
+    }
+
+    /// Returns the characters of the attributed string without attributes.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsattributedstring/string).
+    #[inline]
+    pub fn string(&self) -> Arc<NSString<'static>> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, string => *const NSString<'static>]) }
+    }
+
+    /// Returns the number of UTF-16 code units in the string.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsattributedstring/length).
+    #[inline]
+    #[doc(alias = "length")]
+    pub fn len(&self) -> usize {
+        unsafe { _msg_send_any![self, length => NSUInteger] }
+    }
+
+    /// Returns `true` if the string has no characters.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+ns_string_wrapper! {
+    /// The name of an attribute in an [`NSAttributedString`].
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsattributedstring/key).
+    #[derive(Ord, PartialOrd, Eq, PartialEq)]
+    pub wrapper NSAttributedStringKey;
+}
+
+#[cfg(feature = "app_kit")]
+macro_rules! key {
+    (
+        $(#[$docs:meta])+
+        $fn:ident $value:literal
+    ) => {
+        $(#[$docs])+
+        #[inline]
+        #[doc(alias = $value)]
+        pub fn $fn() -> &'static NSAttributedStringKey {
+            extern "C" {
+                #[link_name = $value]
+                static VALUE: &'static NSAttributedStringKey;
+            }
+            unsafe { VALUE }
+        }
+    };
+}
+
+/// AppKit attributes.
+#[cfg(feature = "app_kit")]
+impl NSAttributedStringKey {
+    key! {
+        /// The [`NSFont`](crate::app_kit::NSFont) of the text.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/foundation/nsattributedstring/key/font).
+        font "NSFontAttributeName"
+    }
+
+    key! {
+        /// The [`NSColor`](crate::app_kit::NSColor) of the text.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/foundation/nsattributedstring/key/foregroundcolor).
+        foreground_color "NSForegroundColorAttributeName"
+    }
+
+    key! {
+        /// The [`NSColor`](crate::app_kit::NSColor) behind the text.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/foundation/nsattributedstring/key/backgroundcolor).
+        background_color "NSBackgroundColorAttributeName"
+    }
+
+    key! {
+        /// An [`NSNumber`](super::NSNumber) of points to raise the text by,
+        /// or lower it by if negative.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/foundation/nsattributedstring/key/baselineoffset).
+        baseline_offset "NSBaselineOffsetAttributeName"
+    }
+
+    key! {
+        /// An [`NSNumber`](super::NSNumber) of points to add between
+        /// characters.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/foundation/nsattributedstring/key/kern).
+        kern "NSKernAttributeName"
+    }
+}
@@ -0,0 +1,260 @@
+use super::{NSQualityOfService, NSString, NSTimeInterval};
+use crate::core::Arc;
+use crate::objc::{Block, ClassType, NSInteger, NSObject, RcBlock, Sel, BOOL};
+use std::{cell::RefCell, fmt};
+
+objc_subclass! {
+    /// A task scheduler for running maintenance or other deferrable work at a
+    /// time chosen by the system to save energy.
+    ///
+    /// Unlike a timer, the system may run the work at any point within the
+    /// [tolerance](Self::tolerance), and may ask it to
+    /// [defer](Self::should_defer) while it runs.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsbackgroundactivityscheduler).
+    pub class NSBackgroundActivityScheduler: NSObject<'static>;
+}
+
+/// The outcome of a run of an [`NSBackgroundActivityScheduler`]'s work.
+///
+/// See [documentation](https://developer.apple.com/documentation/foundation/nsbackgroundactivityscheduler/result).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NSBackgroundActivityResult(pub NSInteger);
+
+impl NSBackgroundActivityResult {
+    /// The work completed.
+    #[doc(alias = "NSBackgroundActivityResultFinished")]
+    pub const FINISHED: Self = Self(1);
+
+    /// The work was deferred and should be run again later.
+    #[doc(alias = "NSBackgroundActivityResultDeferred")]
+    pub const DEFERRED: Self = Self(2);
+}
+
+/// Reports the result of an [`NSBackgroundActivityScheduler`]'s work to the
+/// system.
+///
+/// This may be moved to another thread to complete asynchronous work. If it
+/// is dropped without being called, the work is reported as
+/// [finished](NSBackgroundActivityResult::FINISHED).
+pub struct NSBackgroundActivityCompletionHandler {
+    block: Option<RcBlock<(NSBackgroundActivityResult,), ()>>,
+}
+
+// SAFETY: The completion handler is safe to call from any thread.
+unsafe impl Send for NSBackgroundActivityCompletionHandler {}
+
+impl fmt::Debug for NSBackgroundActivityCompletionHandler {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("NSBackgroundActivityCompletionHandler")
+            .finish()
+    }
+}
+
+impl Drop for NSBackgroundActivityCompletionHandler {
+    #[inline]
+    fn drop(&mut self) {
+        self.complete_ref(NSBackgroundActivityResult::FINISHED);
+    }
+}
+
+impl NSBackgroundActivityCompletionHandler {
+    #[inline]
+    fn complete_ref(&mut self, result: NSBackgroundActivityResult) {
+        if let Some(block) = self.block.take() {
+            block.call((result,));
+        }
+    }
+
+    /// Reports `result` to the system.
+    #[inline]
+    pub fn complete(mut self, result: NSBackgroundActivityResult) {
+        self.complete_ref(result);
+    }
+
+    /// Reports that the work completed.
+    #[inline]
+    pub fn finish(self) {
+        self.complete(NSBackgroundActivityResult::FINISHED);
+    }
+
+    /// Reports that the work was deferred, so that the system runs it again
+    /// later.
+    #[inline]
+    pub fn defer(self) {
+        self.complete(NSBackgroundActivityResult::DEFERRED);
+    }
+}
+
+impl NSBackgroundActivityScheduler {
+    /// Creates a scheduler for the activity named `identifier`.
+    ///
+    /// The identifier should be unique and in reverse DNS notation, such as
+    /// `com.example.app.cleanup`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsbackgroundactivityscheduler/init(identifier:)).
+    #[inline]
+    #[doc(alias = "initWithIdentifier:")]
+    pub fn new(identifier: &NSString) -> Arc<Self> {
+        unsafe {
+            let scheduler: Arc<Self> = Self::class().alloc();
+
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(
+                    obj: Arc<NSBackgroundActivityScheduler>,
+                    sel: Sel,
+                    identifier: &NSString,
+                ) -> Arc<NSBackgroundActivityScheduler>;
+            }
+
+            objc_msgSend(scheduler, selector!(initWithIdentifier:), identifier)
+        }
+    }
+
+    /// Returns the identifier of the activity.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsbackgroundactivityscheduler/identifier).
+    #[inline]
+    pub fn identifier(&self) -> Arc<NSString<'static>> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, identifier => *const NSString<'static>]) }
+    }
+
+    /// Returns the number of seconds between runs of a repeating activity.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsbackgroundactivityscheduler/interval).
+    #[inline]
+    pub fn interval(&self) -> NSTimeInterval {
+        unsafe { _msg_send_any![self, interval] }
+    }
+
+    /// Sets the number of seconds between runs of a repeating activity.
+    ///
+    /// This should be set before calling [`schedule`](Self::schedule).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsbackgroundactivityscheduler/interval).
+    #[inline]
+    #[doc(alias = "setInterval")]
+    #[doc(alias = "setInterval:")]
+    pub fn set_interval(&self, interval: NSTimeInterval) {
+        unsafe { _msg_send_any![self, setInterval: interval] }
+    }
+
+    /// Returns the number of seconds around the scheduled time within which
+    /// the activity may run.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsbackgroundactivityscheduler/tolerance).
+    #[inline]
+    pub fn tolerance(&self) -> NSTimeInterval {
+        unsafe { _msg_send_any![self, tolerance] }
+    }
+
+    /// Sets the number of seconds around the scheduled time within which the
+    /// activity may run.
+    ///
+    /// By default, this is half of the [interval](Self::interval).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsbackgroundactivityscheduler/tolerance).
+    #[inline]
+    #[doc(alias = "setTolerance")]
+    #[doc(alias = "setTolerance:")]
+    pub fn set_tolerance(&self, tolerance: NSTimeInterval) {
+        unsafe { _msg_send_any![self, setTolerance: tolerance] }
+    }
+
+    /// Returns `true` if the activity runs once per
+    /// [interval](Self::interval) rather than only once.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsbackgroundactivityscheduler/repeats).
+    #[inline]
+    pub fn repeats(&self) -> bool {
+        unsafe { _msg_send_any![self, repeats => BOOL] }.into()
+    }
+
+    /// Sets whether the activity runs once per [interval](Self::interval)
+    /// rather than only once.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsbackgroundactivityscheduler/repeats).
+    #[inline]
+    #[doc(alias = "setRepeats")]
+    #[doc(alias = "setRepeats:")]
+    pub fn set_repeats(&self, repeats: bool) {
+        unsafe { _msg_send_any![self, setRepeats: BOOL::from(repeats)] }
+    }
+
+    /// Returns the priority of the activity.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsbackgroundactivityscheduler/qualityofservice).
+    #[inline]
+    #[doc(alias = "qualityOfService")]
+    pub fn quality_of_service(&self) -> NSQualityOfService {
+        unsafe { _msg_send_any![self, qualityOfService] }
+    }
+
+    /// Sets the priority of the activity.
+    ///
+    /// The default is [`BACKGROUND`](NSQualityOfService::BACKGROUND).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsbackgroundactivityscheduler/qualityofservice).
+    #[inline]
+    #[doc(alias = "setQualityOfService")]
+    #[doc(alias = "setQualityOfService:")]
+    pub fn set_quality_of_service(&self, quality_of_service: NSQualityOfService) {
+        unsafe { _msg_send_any![self, setQualityOfService: quality_of_service] }
+    }
+
+    /// Returns `true` if the system asks that the running activity stop and
+    /// [defer](NSBackgroundActivityCompletionHandler::defer) its remaining
+    /// work.
+    ///
+    /// Long-running work should check this periodically.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsbackgroundactivityscheduler/shoulddefer).
+    #[inline]
+    #[doc(alias = "shouldDefer")]
+    pub fn should_defer(&self) -> bool {
+        unsafe { _msg_send_any![self, shouldDefer => BOOL] }.into()
+    }
+
+    /// Begins scheduling the activity, calling `block` on a background
+    /// thread each time it runs.
+    ///
+    /// `block` must report its result through the provided completion
+    /// handler, either before returning or after finishing asynchronous
+    /// work.
+    ///
+    /// It is safe to panic within `block`. Panics will abort the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsbackgroundactivityscheduler/schedule(_:)).
+    #[inline]
+    #[doc(alias = "scheduleWithBlock:")]
+    pub fn schedule<F>(&self, block: F)
+    where
+        F: FnMut(NSBackgroundActivityCompletionHandler) + Send + 'static,
+    {
+        type CompletionBlock = Block<(NSBackgroundActivityResult,), ()>;
+
+        let block = RefCell::new(block);
+        let block = RcBlock::new(move |completion: *const CompletionBlock| {
+            let completion = NSBackgroundActivityCompletionHandler {
+                block: Some(unsafe { RcBlock::copy(&*completion) }),
+            };
+            (*block.borrow_mut())(completion);
+        });
+        let block: &Block<_, ()> = &block;
+
+        unsafe { _msg_send_any![self, scheduleWithBlock: block] }
+    }
+
+    /// Stops scheduling the activity.
+    ///
+    /// A run that is already in progress is not stopped.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsbackgroundactivityscheduler/invalidate()).
+    #[inline]
+    pub fn invalidate(&self) {
+        unsafe { _msg_send_any![self, invalidate] }
+    }
+}
@@ -0,0 +1,120 @@
+use super::NSString;
+use crate::core::Arc;
+use crate::objc::{ClassType, NSObject, BOOL};
+
+objc_subclass! {
+    /// An immutable set of Unicode characters, used for searching strings and
+    /// for percent-encoding.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nscharacterset).
+    pub class NSCharacterSet: NSObject<'static>;
+}
+
+impl NSCharacterSet {
+    /// Creates a set containing the characters of `chars`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nscharacterset/init(charactersin:)).
+    #[inline]
+    #[doc(alias = "characterSetWithCharactersInString")]
+    #[doc(alias = "characterSetWithCharactersInString:")]
+    pub fn from_chars(chars: &NSString) -> Arc<Self> {
+        unsafe {
+            let set = _msg_send_any![
+                Self::class(),
+                characterSetWithCharactersInString: chars
+                => *const Self
+            ];
+            Arc::retain_raw(set)
+        }
+    }
+
+    /// Returns a set containing the characters not in `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nscharacterset/inverted).
+    #[inline]
+    #[doc(alias = "invertedSet")]
+    pub fn inverted(&self) -> Arc<Self> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, invertedSet => *const Self]) }
+    }
+
+    /// Returns `true` if `c` is in `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nscharacterset/contains(_:)).
+    #[inline]
+    #[doc(alias = "longCharacterIsMember")]
+    #[doc(alias = "longCharacterIsMember:")]
+    pub fn contains(&self, c: char) -> bool {
+        unsafe { _msg_send_any![self, longCharacterIsMember: c as u32 => BOOL] }.into()
+    }
+}
+
+/// URL component sets.
+///
+/// Each set contains the characters that do not need to be percent-encoded
+/// in its component, for use with
+/// [`NSString::adding_percent_encoding`].
+impl NSCharacterSet {
+    #[inline]
+    fn shared(sel: crate::objc::Sel) -> Arc<Self> {
+        unsafe { Arc::retain_raw(Self::class()._msg_send_any::<*const Self>(sel)) }
+    }
+
+    /// Returns the characters allowed in a URL fragment.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nscharacterset/urlfragmentallowed).
+    #[inline]
+    #[doc(alias = "URLFragmentAllowedCharacterSet")]
+    pub fn url_fragment_allowed() -> Arc<Self> {
+        Self::shared(selector!(URLFragmentAllowedCharacterSet))
+    }
+
+    /// Returns the characters allowed in a URL host.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nscharacterset/urlhostallowed).
+    #[inline]
+    #[doc(alias = "URLHostAllowedCharacterSet")]
+    pub fn url_host_allowed() -> Arc<Self> {
+        Self::shared(selector!(URLHostAllowedCharacterSet))
+    }
+
+    /// Returns the characters allowed in a URL password.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nscharacterset/urlpasswordallowed).
+    #[inline]
+    #[doc(alias = "URLPasswordAllowedCharacterSet")]
+    pub fn url_password_allowed() -> Arc<Self> {
+        Self::shared(selector!(URLPasswordAllowedCharacterSet))
+    }
+
+    /// Returns the characters allowed in a URL path.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nscharacterset/urlpathallowed).
+    #[inline]
+    #[doc(alias = "URLPathAllowedCharacterSet")]
+    pub fn url_path_allowed() -> Arc<Self> {
+        Self::shared(selector!(URLPathAllowedCharacterSet))
+    }
+
+    /// Returns the characters allowed in a URL query.
+    ///
+    /// This includes `&` and `=`, so it is not suitable for encoding the
+    /// names and values of individual query items. Use
+    /// [`NSURLComponents::set_query_pairs`](super::NSURLComponents::set_query_pairs)
+    /// for those instead.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nscharacterset/urlqueryallowed).
+    #[inline]
+    #[doc(alias = "URLQueryAllowedCharacterSet")]
+    pub fn url_query_allowed() -> Arc<Self> {
+        Self::shared(selector!(URLQueryAllowedCharacterSet))
+    }
+
+    /// Returns the characters allowed in a URL user.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nscharacterset/urluserallowed).
+    #[inline]
+    #[doc(alias = "URLUserAllowedCharacterSet")]
+    pub fn url_user_allowed() -> Arc<Self> {
+        Self::shared(selector!(URLUserAllowedCharacterSet))
+    }
+}
@@ -0,0 +1,269 @@
+use super::{NSError, NSString, NSURL};
+use crate::core::Arc;
+use crate::objc::{ClassType, NSObject, NSUInteger, Sel, BOOL};
+use std::{ptr, slice};
+
+mod mutable;
+mod options;
+
+pub use mutable::*;
+pub use options::*;
+
+objc_subclass! {
+    /// An immutable buffer of bytes.
+    ///
+    /// This is toll-free bridged with
+    /// [`CFData`](crate::core_foundation::CFData) when the `core_foundation`
+    /// feature is enabled.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdata).
+    pub class NSData: NSObject<'static>;
+}
+
+impl From<&[u8]> for Arc<NSData> {
+    #[inline]
+    fn from(bytes: &[u8]) -> Self {
+        NSData::from_bytes(bytes)
+    }
+}
+
+impl From<&NSData> for Vec<u8> {
+    #[inline]
+    fn from(data: &NSData) -> Self {
+        data.to_vec()
+    }
+}
+
+impl AsRef<[u8]> for NSData {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+/// Creating data.
+impl NSData {
+    /// Creates an object containing a copy of `bytes`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdata/init(bytes:length:)).
+    #[inline]
+    #[doc(alias = "initWithBytes:length:")]
+    pub fn from_bytes(bytes: &[u8]) -> Arc<Self> {
+        unsafe {
+            let data: Arc<Self> = Self::class().alloc();
+
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(
+                    obj: Arc<NSData>,
+                    sel: Sel,
+                    bytes: *const u8,
+                    length: NSUInteger,
+                ) -> Arc<NSData>;
+            }
+
+            objc_msgSend(
+                data,
+                selector!(initWithBytes:length:),
+                bytes.as_ptr(),
+                bytes.len() as NSUInteger,
+            )
+        }
+    }
+
+    /// Decodes `string` as Base64, returning [`None`] if it is malformed.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdata/init(base64encoded:options:)).
+    #[inline]
+    #[doc(alias = "initWithBase64EncodedString:options:")]
+    pub fn from_base64(
+        string: &NSString,
+        options: NSDataBase64DecodingOptions,
+    ) -> Option<Arc<Self>> {
+        unsafe {
+            let data: Arc<Self> = Self::class().alloc();
+
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(
+                    obj: Arc<NSData>,
+                    sel: Sel,
+                    string: &NSString,
+                    options: NSDataBase64DecodingOptions,
+                ) -> Option<Arc<NSData>>;
+            }
+
+            objc_msgSend(
+                data,
+                selector!(initWithBase64EncodedString:options:),
+                string,
+                options,
+            )
+        }
+    }
+
+    /// Reads the contents of the file at `url`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdata/init(contentsof:options:)).
+    #[inline]
+    #[doc(alias = "dataWithContentsOfURL:options:error:")]
+    pub fn from_url(
+        url: &NSURL,
+        options: NSDataReadingOptions,
+    ) -> Result<Arc<Self>, Arc<NSError<'static>>> {
+        unsafe {
+            let mut error: *const NSError<'static> = ptr::null();
+            let data = _msg_send_any![
+                Self::class(),
+                dataWithContentsOfURL: url
+                options: options
+                error: &mut error
+                => *const Self
+            ];
+
+            match data.as_ref() {
+                Some(data) => Ok(Arc::retain(data)),
+                None => Err(Arc::retain_raw(error)),
+            }
+        }
+    }
+
+    /// Reads the contents of the file at `path`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdata/init(contentsoffile:options:)).
+    #[inline]
+    #[doc(alias = "dataWithContentsOfFile:options:error:")]
+    pub fn from_file(
+        path: &NSString,
+        options: NSDataReadingOptions,
+    ) -> Result<Arc<Self>, Arc<NSError<'static>>> {
+        unsafe {
+            let mut error: *const NSError<'static> = ptr::null();
+            let data = _msg_send_any![
+                Self::class(),
+                dataWithContentsOfFile: path
+                options: options
+                error: &mut error
+                => *const Self
+            ];
+
+            match data.as_ref() {
+                Some(data) => Ok(Arc::retain(data)),
+                None => Err(Arc::retain_raw(error)),
+            }
+        }
+    }
+}
+
+/// Accessing bytes.
+impl NSData {
+    /// Returns the number of bytes contained by `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdata/length).
+    #[inline]
+    pub fn len(&self) -> usize {
+        unsafe { _msg_send_any![self, length => NSUInteger] }
+    }
+
+    /// Returns `true` if `self` contains no bytes.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the bytes contained by `self`.
+    ///
+    /// If `self` is an [`NSMutableData`], the slice must not outlive any
+    /// mutation of it.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdata/bytes).
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        let len = self.len();
+        if len == 0 {
+            // `bytes` may be null for empty data.
+            return &[];
+        }
+        unsafe { slice::from_raw_parts(_msg_send_any![self, bytes => *const u8], len) }
+    }
+
+    /// Copies the bytes of `self` into a new `Vec`.
+    #[inline]
+    pub fn to_vec(&self) -> Vec<u8> {
+        self.as_bytes().to_vec()
+    }
+
+    /// Returns the contents of `self` encoded as Base64.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdata/base64encodedstring(options:)).
+    #[inline]
+    #[doc(alias = "base64EncodedStringWithOptions:")]
+    pub fn base64_encoded(&self, options: NSDataBase64EncodingOptions) -> Arc<NSString<'static>> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![
+                self,
+                base64EncodedStringWithOptions: options
+                => *const NSString<'static>
+            ])
+        }
+    }
+}
+
+/// Writing to files.
+impl NSData {
+    /// Writes the contents of `self` to the file at `url`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdata/write(to:options:)).
+    #[inline]
+    #[doc(alias = "writeToURL:options:error:")]
+    pub fn write_to_url(
+        &self,
+        url: &NSURL,
+        options: NSDataWritingOptions,
+    ) -> Result<(), Arc<NSError<'static>>> {
+        unsafe {
+            let mut error: *const NSError<'static> = ptr::null();
+            let success = _msg_send_any![
+                self,
+                writeToURL: url
+                options: options
+                error: &mut error
+                => BOOL
+            ];
+
+            if success.into() {
+                Ok(())
+            } else {
+                Err(Arc::retain_raw(error))
+            }
+        }
+    }
+
+    /// Writes the contents of `self` to the file at `path`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdata/write(tofile:options:)).
+    #[inline]
+    #[doc(alias = "writeToFile:options:error:")]
+    pub fn write_to_file(
+        &self,
+        path: &NSString,
+        options: NSDataWritingOptions,
+    ) -> Result<(), Arc<NSError<'static>>> {
+        unsafe {
+            let mut error: *const NSError<'static> = ptr::null();
+            let success = _msg_send_any![
+                self,
+                writeToFile: path
+                options: options
+                error: &mut error
+                => BOOL
+            ];
+
+            if success.into() {
+                Ok(())
+            } else {
+                Err(Arc::retain_raw(error))
+            }
+        }
+    }
+}
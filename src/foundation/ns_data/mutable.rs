@@ -0,0 +1,102 @@
+use super::NSData;
+use crate::core::Arc;
+use crate::objc::{ClassType, NSUInteger, Sel};
+
+objc_subclass! {
+    /// A mutable buffer of bytes.
+    ///
+    /// This is toll-free bridged with
+    /// [`CFMutableData`](crate::core_foundation::CFMutableData) when the
+    /// `core_foundation` feature is enabled.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsmutabledata).
+    ///
+    /// # Mutation
+    ///
+    /// Methods that change the contents of `self` may reallocate its buffer,
+    /// which invalidates slices returned by [`NSData::as_bytes`]. Because
+    /// those slices are not tied to a unique borrow, these methods are
+    /// `unsafe`.
+    pub class NSMutableData: NSData;
+}
+
+impl Default for Arc<NSMutableData> {
+    #[inline]
+    fn default() -> Self {
+        NSMutableData::new()
+    }
+}
+
+impl From<&[u8]> for Arc<NSMutableData> {
+    #[inline]
+    fn from(bytes: &[u8]) -> Self {
+        let data = NSMutableData::with_capacity(bytes.len());
+        unsafe { data.append(bytes) };
+        data
+    }
+}
+
+impl NSMutableData {
+    /// Creates an empty object.
+    #[inline]
+    pub fn new() -> Arc<Self> {
+        unsafe { Self::class().alloc_init() }
+    }
+
+    /// Creates an empty object with space for `capacity` bytes.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsmutabledata/init(capacity:)).
+    #[inline]
+    #[doc(alias = "initWithCapacity:")]
+    pub fn with_capacity(capacity: usize) -> Arc<Self> {
+        unsafe {
+            let data: Arc<Self> = Self::class().alloc();
+
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(
+                    obj: Arc<NSMutableData>,
+                    sel: Sel,
+                    capacity: NSUInteger,
+                ) -> Arc<NSMutableData>;
+            }
+
+            objc_msgSend(data, selector!(initWithCapacity:), capacity)
+        }
+    }
+
+    /// Appends `bytes` to the end of `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsmutabledata/append(_:length:)).
+    ///
+    /// # Safety
+    ///
+    /// No slices of the bytes of `self` may be alive, on this or any other
+    /// thread.
+    #[inline]
+    #[doc(alias = "appendBytes:length:")]
+    pub unsafe fn append(&self, bytes: &[u8]) {
+        _msg_send_any![
+            self,
+            appendBytes: bytes.as_ptr()
+            length: bytes.len() as NSUInteger
+            => ()
+        ]
+    }
+
+    /// Resizes `self` to `len` bytes, either truncating it or filling new
+    /// bytes with zeros.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsmutabledata/length).
+    ///
+    /// # Safety
+    ///
+    /// No slices of the bytes of `self` may be alive, on this or any other
+    /// thread.
+    #[inline]
+    #[doc(alias = "setLength")]
+    #[doc(alias = "setLength:")]
+    pub unsafe fn set_len(&self, len: usize) {
+        _msg_send_any![self, setLength: len as NSUInteger => ()]
+    }
+}
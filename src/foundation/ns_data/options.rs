@@ -0,0 +1,115 @@
+use crate::objc::NSUInteger;
+use std::ops;
+
+macro_rules! options {
+    ($name:ident) => {
+        impl ops::BitOr for $name {
+            type Output = Self;
+
+            #[inline]
+            fn bitor(self, other: Self) -> Self {
+                Self(self.0 | other.0)
+            }
+        }
+
+        impl $name {
+            /// Returns `true` if all options in `other` are set in `self`.
+            #[inline]
+            pub fn contains(self, other: Self) -> bool {
+                self.0 & other.0 == other.0
+            }
+        }
+    };
+}
+
+/// Options for reading [`NSData`](super::NSData) from a file.
+///
+/// See [documentation](https://developer.apple.com/documentation/foundation/nsdata/readingoptions).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NSDataReadingOptions(pub NSUInteger);
+
+options!(NSDataReadingOptions);
+
+impl NSDataReadingOptions {
+    /// Maps the file into virtual memory instead of reading it, if it is
+    /// safe to do so.
+    #[doc(alias = "NSDataReadingMappedIfSafe")]
+    pub const MAPPED_IF_SAFE: Self = Self(1 << 0);
+
+    /// Reads the file without adding it to the file system cache, for data
+    /// that is only read once.
+    #[doc(alias = "NSDataReadingUncached")]
+    pub const UNCACHED: Self = Self(1 << 1);
+
+    /// Maps the file into virtual memory whenever possible.
+    #[doc(alias = "NSDataReadingMappedAlways")]
+    pub const MAPPED_ALWAYS: Self = Self(1 << 3);
+}
+
+/// Options for writing [`NSData`](super::NSData) to a file.
+///
+/// See [documentation](https://developer.apple.com/documentation/foundation/nsdata/writingoptions).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NSDataWritingOptions(pub NSUInteger);
+
+options!(NSDataWritingOptions);
+
+impl NSDataWritingOptions {
+    /// Writes to a temporary file that then replaces the destination.
+    #[doc(alias = "NSDataWritingAtomic")]
+    pub const ATOMIC: Self = Self(1 << 0);
+
+    /// Fails if the destination already exists.
+    ///
+    /// This cannot be combined with [`ATOMIC`](Self::ATOMIC).
+    #[doc(alias = "NSDataWritingWithoutOverwriting")]
+    pub const WITHOUT_OVERWRITING: Self = Self(1 << 1);
+}
+
+/// Options for encoding [`NSData`](super::NSData) as Base64.
+///
+/// Lines are not split unless a line length is set. Split lines end with
+/// both a carriage return and line feed, unless only one is set.
+///
+/// See [documentation](https://developer.apple.com/documentation/foundation/nsdata/base64encodingoptions).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NSDataBase64EncodingOptions(pub NSUInteger);
+
+options!(NSDataBase64EncodingOptions);
+
+impl NSDataBase64EncodingOptions {
+    /// Splits lines after 64 characters.
+    #[doc(alias = "NSDataBase64Encoding64CharacterLineLength")]
+    pub const LINE_LENGTH_64: Self = Self(1 << 0);
+
+    /// Splits lines after 76 characters, as used by MIME.
+    #[doc(alias = "NSDataBase64Encoding76CharacterLineLength")]
+    pub const LINE_LENGTH_76: Self = Self(1 << 1);
+
+    /// Ends split lines with a carriage return.
+    #[doc(alias = "NSDataBase64EncodingEndLineWithCarriageReturn")]
+    pub const END_LINE_WITH_CARRIAGE_RETURN: Self = Self(1 << 4);
+
+    /// Ends split lines with a line feed.
+    #[doc(alias = "NSDataBase64EncodingEndLineWithLineFeed")]
+    pub const END_LINE_WITH_LINE_FEED: Self = Self(1 << 5);
+}
+
+/// Options for decoding Base64 into [`NSData`](super::NSData).
+///
+/// See [documentation](https://developer.apple.com/documentation/foundation/nsdata/base64decodingoptions).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NSDataBase64DecodingOptions(pub NSUInteger);
+
+options!(NSDataBase64DecodingOptions);
+
+impl NSDataBase64DecodingOptions {
+    /// Skips characters outside of the Base64 alphabet, such as line breaks,
+    /// instead of failing.
+    #[doc(alias = "NSDataBase64DecodingIgnoreUnknownCharacters")]
+    pub const IGNORE_UNKNOWN_CHARACTERS: Self = Self(1 << 0);
+}
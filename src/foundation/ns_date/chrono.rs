@@ -0,0 +1,38 @@
+use super::NSDate;
+use crate::core::Arc;
+use chrono::{DateTime, TimeZone, Utc};
+
+impl<Tz: TimeZone> From<DateTime<Tz>> for Arc<NSDate> {
+    #[inline]
+    fn from(date: DateTime<Tz>) -> Self {
+        NSDate::from_chrono(&date)
+    }
+}
+
+impl<Tz: TimeZone> From<&DateTime<Tz>> for Arc<NSDate> {
+    #[inline]
+    fn from(date: &DateTime<Tz>) -> Self {
+        NSDate::from_chrono(date)
+    }
+}
+
+/// Conversions with [`chrono`](https://docs.rs/chrono), enabled by the
+/// **`chrono`** feature flag.
+impl NSDate {
+    /// Creates a date from a `chrono` date and time.
+    ///
+    /// Precision is limited to that of an `f64` number of seconds, which is
+    /// about a microsecond for present-day dates.
+    #[inline]
+    pub fn from_chrono<Tz: TimeZone>(date: &DateTime<Tz>) -> Arc<Self> {
+        Self::from_unix_parts(date.timestamp(), date.timestamp_subsec_nanos())
+    }
+
+    /// Returns `self` as a `chrono` date and time in UTC, or [`None`] if it is
+    /// out of the range supported by `chrono`.
+    #[inline]
+    pub fn to_chrono(&self) -> Option<DateTime<Utc>> {
+        let (secs, nanos) = self.to_unix_parts()?;
+        Utc.timestamp_opt(secs, nanos).single()
+    }
+}
@@ -0,0 +1,231 @@
+use super::{
+    NSComparisonResult, NSISO8601DateFormatOptions, NSISO8601DateFormatter, NSString,
+    NSTimeInterval,
+};
+use crate::core::Arc;
+use crate::objc::{ClassType, NSObject, Sel, BOOL};
+use std::{cmp::Ordering, fmt};
+
+#[cfg(feature = "chrono")]
+mod chrono;
+#[cfg(feature = "time")]
+mod time;
+
+objc_subclass! {
+    /// A specific point in time, independent of any calendar or time zone.
+    ///
+    /// Dates are stored as a number of seconds relative to the reference date
+    /// of 00:00:00 UTC on 1 January 2001.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdate).
+    pub class NSDate: NSObject<'static>;
+}
+
+impl PartialEq for NSDate {
+    #[inline]
+    #[doc(alias = "isEqualToDate")]
+    #[doc(alias = "isEqualToDate:")]
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { _msg_send_any![self, isEqualToDate: other => BOOL] }.into()
+    }
+}
+
+impl Eq for NSDate {}
+
+impl PartialOrd for NSDate {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for NSDate {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.compare(other).into()
+    }
+}
+
+impl fmt::Debug for NSDate {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.to_rfc3339().fmt(f)
+    }
+}
+
+impl fmt::Display for NSDate {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.to_rfc3339().fmt(f)
+    }
+}
+
+impl NSDate {
+    #[inline]
+    unsafe fn init_with_interval(sel: Sel, interval: NSTimeInterval) -> Arc<Self> {
+        let value: Arc<Self> = Self::class().alloc();
+
+        #[allow(clashing_extern_declarations)]
+        extern "C" {
+            fn objc_msgSend(obj: Arc<NSDate>, sel: Sel, interval: NSTimeInterval) -> Arc<NSDate>;
+        }
+
+        objc_msgSend(value, sel, interval)
+    }
+
+    /// Returns the current date and time.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdate/init()).
+    #[inline]
+    pub fn now() -> Arc<Self> {
+        unsafe { Self::class().alloc_init() }
+    }
+
+    /// Creates a date that is `interval` seconds from 00:00:00 UTC on
+    /// 1 January 1970.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdate/init(timeintervalsince1970:)).
+    #[inline]
+    #[doc(alias = "initWithTimeIntervalSince1970")]
+    #[doc(alias = "initWithTimeIntervalSince1970:")]
+    pub fn from_time_interval_since_1970(interval: NSTimeInterval) -> Arc<Self> {
+        unsafe { Self::init_with_interval(selector!(initWithTimeIntervalSince1970:), interval) }
+    }
+
+    /// Creates a date that is `interval` seconds from the reference date of
+    /// 00:00:00 UTC on 1 January 2001.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdate/init(timeintervalsincereferencedate:)).
+    #[inline]
+    #[doc(alias = "initWithTimeIntervalSinceReferenceDate")]
+    #[doc(alias = "initWithTimeIntervalSinceReferenceDate:")]
+    pub fn from_time_interval_since_reference_date(interval: NSTimeInterval) -> Arc<Self> {
+        unsafe {
+            Self::init_with_interval(selector!(initWithTimeIntervalSinceReferenceDate:), interval)
+        }
+    }
+
+    /// Returns a date in the distant past, useful as a lower bound.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdate/distantpast).
+    #[inline]
+    #[doc(alias = "distantPast")]
+    pub fn distant_past() -> Arc<Self> {
+        unsafe { Arc::retain_raw(_msg_send_any![Self::class(), distantPast => *const Self]) }
+    }
+
+    /// Returns a date in the distant future, useful as an upper bound.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdate/distantfuture).
+    #[inline]
+    #[doc(alias = "distantFuture")]
+    pub fn distant_future() -> Arc<Self> {
+        unsafe { Arc::retain_raw(_msg_send_any![Self::class(), distantFuture => *const Self]) }
+    }
+
+    /// Parses an [RFC 3339](https://tools.ietf.org/html/rfc3339) timestamp,
+    /// such as `1985-04-12T23:20:50.52Z`.
+    ///
+    /// Fractional seconds are optional. Returns [`None`] if `s` is not a valid
+    /// timestamp.
+    pub fn from_rfc3339(s: &str) -> Option<Arc<Self>> {
+        let s = NSString::from_str(s);
+        let formatter = NSISO8601DateFormatter::with_options(
+            NSISO8601DateFormatOptions::INTERNET_DATE_TIME
+                | NSISO8601DateFormatOptions::FRACTIONAL_SECONDS,
+        );
+
+        // The formatter requires fractional seconds exactly when the option is
+        // set, so retry without them.
+        formatter.date_from_string(&s).or_else(|| {
+            formatter.set_format_options(NSISO8601DateFormatOptions::INTERNET_DATE_TIME);
+            formatter.date_from_string(&s)
+        })
+    }
+
+    /// Formats `self` as an [RFC 3339](https://tools.ietf.org/html/rfc3339)
+    /// timestamp in UTC, such as `1985-04-12T23:20:50.520Z`.
+    ///
+    /// The result has millisecond precision, which is the most that
+    /// [`NSISO8601DateFormatter`] supports.
+    pub fn to_rfc3339(&self) -> Arc<NSString<'static>> {
+        NSISO8601DateFormatter::with_options(
+            NSISO8601DateFormatOptions::INTERNET_DATE_TIME
+                | NSISO8601DateFormatOptions::FRACTIONAL_SECONDS,
+        )
+        .string_from_date(self)
+    }
+
+    /// Returns the number of seconds between `self` and 00:00:00 UTC on
+    /// 1 January 1970.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdate/timeintervalsince1970).
+    #[inline]
+    #[doc(alias = "timeIntervalSince1970")]
+    pub fn time_interval_since_1970(&self) -> NSTimeInterval {
+        unsafe { _msg_send_any![self, timeIntervalSince1970] }
+    }
+
+    /// Returns the number of seconds between `self` and the reference date of
+    /// 00:00:00 UTC on 1 January 2001.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdate/timeintervalsincereferencedate).
+    #[inline]
+    #[doc(alias = "timeIntervalSinceReferenceDate")]
+    pub fn time_interval_since_reference_date(&self) -> NSTimeInterval {
+        unsafe { _msg_send_any![self, timeIntervalSinceReferenceDate] }
+    }
+
+    /// Returns the number of seconds between `self` and `other`, which is
+    /// negative if `self` is earlier.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdate/timeintervalsince(_:)).
+    #[inline]
+    #[doc(alias = "timeIntervalSinceDate")]
+    #[doc(alias = "timeIntervalSinceDate:")]
+    pub fn time_interval_since(&self, other: &NSDate) -> NSTimeInterval {
+        unsafe { _msg_send_any![self, timeIntervalSinceDate: other] }
+    }
+
+    /// Returns a new date that is `interval` seconds after `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdate/addingtimeinterval(_:)).
+    #[inline]
+    #[doc(alias = "dateByAddingTimeInterval")]
+    #[doc(alias = "dateByAddingTimeInterval:")]
+    pub fn adding_time_interval(&self, interval: NSTimeInterval) -> Arc<Self> {
+        unsafe {
+            let date = _msg_send_any![self, dateByAddingTimeInterval: interval => *const Self];
+            Arc::retain_raw(date)
+        }
+    }
+
+    /// Compares the chronological order of `self` and `other`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdate/compare(_:)).
+    #[inline]
+    pub fn compare(&self, other: &NSDate) -> NSComparisonResult {
+        unsafe { _msg_send_any![self, compare: other] }
+    }
+
+    /// Splits the Unix timestamp of `self` into whole seconds, rounded towards
+    /// negative infinity, and nanoseconds.
+    ///
+    /// Returns [`None`] if the seconds do not fit in an `i64`.
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    fn to_unix_parts(&self) -> Option<(i64, u32)> {
+        let interval = self.time_interval_since_1970();
+        let secs = interval.floor();
+        if !(secs >= i64::MIN as f64 && secs < i64::MAX as f64) {
+            return None;
+        }
+
+        let nanos = ((interval - secs) * 1e9).round().min(999_999_999.0) as u32;
+        Some((secs as i64, nanos))
+    }
+
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    fn from_unix_parts(secs: i64, nanos: u32) -> Arc<Self> {
+        Self::from_time_interval_since_1970(secs as f64 + nanos as f64 / 1e9)
+    }
+}
@@ -0,0 +1,32 @@
+use super::NSDate;
+use crate::core::Arc;
+use time::OffsetDateTime;
+
+impl From<OffsetDateTime> for Arc<NSDate> {
+    #[inline]
+    fn from(date: OffsetDateTime) -> Self {
+        NSDate::from_offset_date_time(date)
+    }
+}
+
+/// Conversions with [`time`](https://docs.rs/time), enabled by the **`time`**
+/// feature flag.
+impl NSDate {
+    /// Creates a date from a `time` date and time.
+    ///
+    /// Precision is limited to that of an `f64` number of seconds, which is
+    /// about a microsecond for present-day dates.
+    #[inline]
+    pub fn from_offset_date_time(date: OffsetDateTime) -> Arc<Self> {
+        Self::from_unix_parts(date.unix_timestamp(), date.nanosecond())
+    }
+
+    /// Returns `self` as a `time` date and time in UTC, or [`None`] if it is
+    /// out of the range supported by `time`.
+    #[inline]
+    pub fn to_offset_date_time(&self) -> Option<OffsetDateTime> {
+        let (secs, nanos) = self.to_unix_parts()?;
+        let nanos = i128::from(secs) * 1_000_000_000 + i128::from(nanos);
+        OffsetDateTime::from_unix_timestamp_nanos(nanos).ok()
+    }
+}
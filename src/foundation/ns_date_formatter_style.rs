@@ -0,0 +1,34 @@
+use crate::objc::NSUInteger;
+
+/// How much detail a formatter includes when showing a date or time.
+///
+/// The exact format depends on the user's locale. Examples are for the
+/// `en_US` locale.
+///
+/// See [documentation](https://developer.apple.com/documentation/foundation/dateformatter/style).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NSDateFormatterStyle(pub NSUInteger);
+
+impl NSDateFormatterStyle {
+    /// Leaves out the date or time.
+    #[doc(alias = "NSDateFormatterNoStyle")]
+    pub const NONE: Self = Self(0);
+
+    /// Numeric only, such as "11/23/37" or "3:30 PM".
+    #[doc(alias = "NSDateFormatterShortStyle")]
+    pub const SHORT: Self = Self(1);
+
+    /// Abbreviated, such as "Nov 23, 1937" or "3:30:32 PM".
+    #[doc(alias = "NSDateFormatterMediumStyle")]
+    pub const MEDIUM: Self = Self(2);
+
+    /// Spelled out, such as "November 23, 1937" or "3:30:32 PM PST".
+    #[doc(alias = "NSDateFormatterLongStyle")]
+    pub const LONG: Self = Self(3);
+
+    /// Complete, such as "Tuesday, April 12, 1952 AD" or
+    /// "3:30:42 PM Pacific Standard Time".
+    #[doc(alias = "NSDateFormatterFullStyle")]
+    pub const FULL: Self = Self(4);
+}
@@ -0,0 +1,175 @@
+use super::{NSComparisonResult, NSDate, NSTimeInterval};
+use crate::core::Arc;
+use crate::objc::{ClassType, NSObject, Sel, BOOL};
+use std::{cmp::Ordering, fmt};
+
+objc_subclass! {
+    /// The span of time between a start date and an end date.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdateinterval).
+    pub class NSDateInterval: NSObject<'static>;
+}
+
+impl PartialEq for NSDateInterval {
+    #[inline]
+    #[doc(alias = "isEqualToDateInterval:")]
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { _msg_send_any![self, isEqualToDateInterval: other => BOOL] }.into()
+    }
+}
+
+impl Eq for NSDateInterval {}
+
+impl PartialOrd for NSDateInterval {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for NSDateInterval {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.compare(other).into()
+    }
+}
+
+impl fmt::Debug for NSDateInterval {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("NSDateInterval")
+            .field("start_date", &self.start_date())
+            .field("end_date", &self.end_date())
+            .finish()
+    }
+}
+
+impl NSDateInterval {
+    /// Creates an interval from `start` to `end`, or returns [`None`] if
+    /// `end` is earlier than `start`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdateinterval/init(start:end:)).
+    #[inline]
+    #[doc(alias = "initWithStartDate:endDate:")]
+    pub fn new(start: &NSDate, end: &NSDate) -> Option<Arc<Self>> {
+        // Foundation raises an exception for negative intervals.
+        if end < start {
+            return None;
+        }
+
+        unsafe {
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(
+                    obj: Arc<NSDateInterval>,
+                    sel: Sel,
+                    start: &NSDate,
+                    end: &NSDate,
+                ) -> Arc<NSDateInterval>;
+            }
+
+            let obj = Self::class().alloc();
+            let sel = selector!(initWithStartDate:endDate:);
+
+            Some(objc_msgSend(obj, sel, start, end))
+        }
+    }
+
+    /// Creates an interval that lasts `duration` seconds from `start`, or
+    /// returns [`None`] if `duration` is negative or NaN.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdateinterval/init(start:duration:)).
+    #[inline]
+    #[doc(alias = "initWithStartDate:duration:")]
+    pub fn with_duration(start: &NSDate, duration: NSTimeInterval) -> Option<Arc<Self>> {
+        if duration.is_nan() || duration < 0.0 {
+            return None;
+        }
+
+        unsafe {
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(
+                    obj: Arc<NSDateInterval>,
+                    sel: Sel,
+                    start: &NSDate,
+                    duration: NSTimeInterval,
+                ) -> Arc<NSDateInterval>;
+            }
+
+            let obj = Self::class().alloc();
+            let sel = selector!(initWithStartDate:duration:);
+
+            Some(objc_msgSend(obj, sel, start, duration))
+        }
+    }
+
+    /// Returns the date at which the interval starts.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdateinterval/startdate).
+    #[inline]
+    #[doc(alias = "startDate")]
+    pub fn start_date(&self) -> Arc<NSDate> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, startDate => *const NSDate]) }
+    }
+
+    /// Returns the date at which the interval ends.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdateinterval/enddate).
+    #[inline]
+    #[doc(alias = "endDate")]
+    pub fn end_date(&self) -> Arc<NSDate> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, endDate => *const NSDate]) }
+    }
+
+    /// Returns the number of seconds between the start and end dates.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdateinterval/duration).
+    #[inline]
+    pub fn duration(&self) -> NSTimeInterval {
+        unsafe { _msg_send_any![self, duration] }
+    }
+
+    /// Returns `true` if `date` is within the interval, including its start
+    /// and end dates.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdateinterval/contains(_:)).
+    #[inline]
+    #[doc(alias = "containsDate:")]
+    pub fn contains_date(&self, date: &NSDate) -> bool {
+        unsafe { _msg_send_any![self, containsDate: date => BOOL] }.into()
+    }
+
+    /// Returns `true` if `self` and `other` share any point in time,
+    /// including when one ends as the other starts.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdateinterval/intersects(_:)).
+    #[inline]
+    #[doc(alias = "intersectsDateInterval:")]
+    pub fn intersects(&self, other: &NSDateInterval) -> bool {
+        unsafe { _msg_send_any![self, intersectsDateInterval: other => BOOL] }.into()
+    }
+
+    /// Returns the span of time shared by `self` and `other`, or [`None`] if
+    /// they do not [intersect](Self::intersects).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdateinterval/intersection(with:)).
+    #[inline]
+    #[doc(alias = "intersectionWithDateInterval:")]
+    pub fn intersection(&self, other: &NSDateInterval) -> Option<Arc<Self>> {
+        unsafe {
+            _msg_send_any![self, intersectionWithDateInterval: other => *const Self]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Compares the start dates of `self` and `other`, then their durations
+    /// if the start dates are the same.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdateinterval/compare(_:)).
+    #[inline]
+    pub fn compare(&self, other: &NSDateInterval) -> NSComparisonResult {
+        unsafe { _msg_send_any![self, compare: other] }
+    }
+}
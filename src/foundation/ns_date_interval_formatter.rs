@@ -0,0 +1,128 @@
+use super::{NSDate, NSDateFormatterStyle, NSDateInterval, NSString, NSTimeZone};
+use crate::core::Arc;
+use crate::objc::{ClassType, NSObject};
+
+objc_subclass! {
+    /// A formatter that shows the span between two dates in the user's
+    /// locale, such as "1/15/21, 9:00 – 10:30 AM".
+    ///
+    /// Parts shared by both dates, such as the day above, are only shown
+    /// once.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/dateintervalformatter).
+    pub class NSDateIntervalFormatter: NSObject<'static>;
+}
+
+impl NSDateIntervalFormatter {
+    /// Creates a formatter with
+    /// [`NSDateFormatterStyle::NONE`] for both the date and time.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/dateintervalformatter/init()).
+    #[inline]
+    pub fn new() -> Arc<Self> {
+        unsafe { Self::class().alloc_init() }
+    }
+
+    /// Creates a formatter that shows dates with `date_style` and times with
+    /// `time_style`.
+    #[inline]
+    pub fn with_styles(
+        date_style: NSDateFormatterStyle,
+        time_style: NSDateFormatterStyle,
+    ) -> Arc<Self> {
+        let formatter = Self::new();
+        formatter.set_date_style(date_style);
+        formatter.set_time_style(time_style);
+        formatter
+    }
+
+    /// Returns how the dates are shown.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/dateintervalformatter/datestyle).
+    #[inline]
+    #[doc(alias = "dateStyle")]
+    pub fn date_style(&self) -> NSDateFormatterStyle {
+        unsafe { _msg_send_any![self, dateStyle] }
+    }
+
+    /// Sets how the dates are shown.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/dateintervalformatter/datestyle).
+    #[inline]
+    #[doc(alias = "setDateStyle:")]
+    pub fn set_date_style(&self, style: NSDateFormatterStyle) {
+        unsafe { _msg_send_any![self, setDateStyle: style] }
+    }
+
+    /// Returns how the times of day are shown.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/dateintervalformatter/timestyle).
+    #[inline]
+    #[doc(alias = "timeStyle")]
+    pub fn time_style(&self) -> NSDateFormatterStyle {
+        unsafe { _msg_send_any![self, timeStyle] }
+    }
+
+    /// Sets how the times of day are shown.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/dateintervalformatter/timestyle).
+    #[inline]
+    #[doc(alias = "setTimeStyle:")]
+    pub fn set_time_style(&self, style: NSDateFormatterStyle) {
+        unsafe { _msg_send_any![self, setTimeStyle: style] }
+    }
+
+    /// Returns the time zone that dates are shown in, which is the system
+    /// time zone by default.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/dateintervalformatter/timezone).
+    #[inline]
+    #[doc(alias = "timeZone")]
+    pub fn time_zone(&self) -> Arc<NSTimeZone> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, timeZone => *const NSTimeZone]) }
+    }
+
+    /// Sets the time zone that dates are shown in, or resets it to the
+    /// system time zone if [`None`].
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/dateintervalformatter/timezone).
+    #[inline]
+    #[doc(alias = "setTimeZone:")]
+    pub fn set_time_zone(&self, time_zone: Option<&NSTimeZone>) {
+        unsafe { _msg_send_any![self, setTimeZone: time_zone] }
+    }
+
+    /// Returns the localized span from `start` to `end`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/dateintervalformatter/string(from:to:)).
+    #[inline]
+    #[doc(alias = "stringFromDate:toDate:")]
+    pub fn string_from_dates(&self, start: &NSDate, end: &NSDate) -> Arc<NSString<'static>> {
+        unsafe {
+            let string = _msg_send_any![
+                self,
+                stringFromDate: start
+                toDate: end
+                => *const NSString<'static>
+            ];
+            Arc::retain_raw(string)
+        }
+    }
+
+    /// Returns the localized span of `interval`, or [`None`] if it could not
+    /// be formatted.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/dateintervalformatter/string(from:)).
+    #[inline]
+    #[doc(alias = "stringFromDateInterval:")]
+    pub fn string_from_date_interval(
+        &self,
+        interval: &NSDateInterval,
+    ) -> Option<Arc<NSString<'static>>> {
+        unsafe {
+            _msg_send_any![self, stringFromDateInterval: interval => *const NSString<'static>]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+}
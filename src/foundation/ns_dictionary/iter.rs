@@ -0,0 +1,84 @@
+use super::{NSDictionary, NSNull};
+use crate::core::ObjectType;
+use std::{iter::FusedIterator, marker::PhantomData, vec};
+
+/// An iterator over the key-value pairs of an [`NSDictionary`], including
+/// [`NSNull`] values.
+///
+/// Values are yielded as is, so they may be `NSNull` rather than a `V`.
+///
+/// The pairs are fetched up front with
+/// [`getObjects:andKeys:count:`](https://developer.apple.com/documentation/foundation/nsdictionary/getobjects(_:andkeys:count:)),
+/// so their order is unspecified.
+pub struct NSDictionaryRawIter<'a, K, V> {
+    keys: vec::IntoIter<*const K>,
+    values: vec::IntoIter<*const V>,
+    _marker: PhantomData<&'a NSDictionary<K, V>>,
+}
+
+impl<'a, K: ObjectType + 'static, V: ObjectType + 'static> NSDictionaryRawIter<'a, K, V> {
+    #[inline]
+    pub(super) fn new(dictionary: &'a NSDictionary<K, V>) -> Self {
+        let (keys, values) = dictionary.raw_keys_and_values();
+        Self {
+            keys: keys.into_iter(),
+            values: values.into_iter(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, K: ObjectType, V: ObjectType> Iterator for NSDictionaryRawIter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let key = self.keys.next()?;
+        let value = self.values.next()?;
+        unsafe { Some((&*key, &*value)) }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.keys.size_hint()
+    }
+}
+
+impl<K: ObjectType, V: ObjectType> ExactSizeIterator for NSDictionaryRawIter<'_, K, V> {}
+
+impl<K: ObjectType, V: ObjectType> FusedIterator for NSDictionaryRawIter<'_, K, V> {}
+
+/// An iterator over the key-value pairs of an [`NSDictionary`] that skips
+/// pairs whose value is [`NSNull`].
+///
+/// See [`NSDictionaryRawIter`] for how pairs are fetched.
+pub struct NSDictionaryIter<'a, K, V> {
+    raw: NSDictionaryRawIter<'a, K, V>,
+}
+
+impl<'a, K: ObjectType + 'static, V: ObjectType + 'static> NSDictionaryIter<'a, K, V> {
+    #[inline]
+    pub(super) fn new(dictionary: &'a NSDictionary<K, V>) -> Self {
+        Self {
+            raw: NSDictionaryRawIter::new(dictionary),
+        }
+    }
+}
+
+impl<'a, K: ObjectType, V: ObjectType> Iterator for NSDictionaryIter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.raw
+            .by_ref()
+            .find(|(_, value)| !NSNull::is_null(*value))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.raw.size_hint().1)
+    }
+}
+
+impl<K: ObjectType, V: ObjectType> FusedIterator for NSDictionaryIter<'_, K, V> {}
@@ -0,0 +1,319 @@
+use super::{NSArray, NSNull};
+use crate::core::{Arc, ObjectType};
+use crate::objc::{Class, ClassType, NSObject, NSUInteger, ObjCObject, Sel, BOOL};
+use std::{fmt, iter::FromIterator, marker::PhantomData, ops, ops::Deref, ptr::NonNull};
+
+mod iter;
+mod mutable;
+
+pub use iter::*;
+pub use mutable::*;
+
+/// A static collection of key-value pairs with keys of type `K` and values of
+/// type `V`.
+///
+/// This is toll-free bridged with
+/// [`CFDictionary`](crate::core_foundation::CFDictionary) when the
+/// **`core_foundation`** feature is enabled.
+///
+/// See [documentation](https://developer.apple.com/documentation/foundation/nsdictionary).
+///
+/// # Key and Value Types
+///
+/// Like [`NSArray`], the generic types are not checked at runtime. Keys are
+/// copied when inserted, so `K` should be an immutable type that conforms to
+/// `NSCopying`, such as [`NSString`](super::NSString).
+///
+/// Values may be [`NSNull`] in place of `nil`, such as in dictionaries parsed
+/// from JSON. [`get`](Self::get) returns [`None`] for it rather than an object
+/// that is not a `V`, and [`get_nullable`](Self::get_nullable) tells it apart
+/// from a missing key. [`iter`](Self::iter) and [`values`](Self::values)
+/// skip it, and [`iter_raw`](Self::iter_raw) yields it as is.
+#[repr(C)]
+pub struct NSDictionary<K = NSObject<'static>, V = NSObject<'static>> {
+    base: NSObject<'static>,
+    _marker: PhantomData<(*const K, *const V)>,
+}
+
+impl<K, V> ObjectType for NSDictionary<K, V> {
+    #[inline]
+    fn retain(obj: &Self) -> Arc<Self> {
+        let obj = Arc::retain(&obj.base);
+        unsafe { Arc::cast_unchecked(obj) }
+    }
+
+    #[inline]
+    unsafe fn release(obj: NonNull<Self>) {
+        NSObject::release(obj.cast());
+    }
+}
+
+impl<K: 'static, V: 'static> crate::objc::ObjectType<'static> for NSDictionary<K, V> {}
+
+impl<K: 'static, V: 'static> ClassType<'static> for NSDictionary<K, V> {
+    #[inline]
+    fn class() -> &'static Class {
+        crate::_objc_class!(@ "OBJC_CLASS_$_NSDictionary")
+    }
+}
+
+impl<K, V> Deref for NSDictionary<K, V> {
+    type Target = NSObject<'static>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<K, V> AsRef<ObjCObject<'static>> for NSDictionary<K, V> {
+    #[inline]
+    fn as_ref(&self) -> &ObjCObject<'static> {
+        self.base.as_ref()
+    }
+}
+
+impl<K, V> PartialEq for NSDictionary<K, V> {
+    #[inline]
+    #[doc(alias = "isEqualToDictionary")]
+    #[doc(alias = "isEqualToDictionary:")]
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { _msg_send_any![self, isEqualToDictionary: other => BOOL] }.into()
+    }
+}
+
+impl<K, V> fmt::Debug for NSDictionary<K, V>
+where
+    K: ObjectType + fmt::Debug + 'static,
+    V: ObjectType + fmt::Debug + 'static,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K: ObjectType + 'static, V: ObjectType + 'static> ops::Index<&K> for NSDictionary<K, V> {
+    type Output = V;
+
+    /// Returns the value for `key`, like a subscript in Objective-C.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `key` is not in the dictionary or its value is [`NSNull`].
+    #[inline]
+    #[track_caller]
+    fn index(&self, key: &K) -> &V {
+        self.get_nullable(key)
+            .expect("no entry found for key")
+            .expect("value for key is NSNull")
+    }
+}
+
+impl<'a, K: ObjectType + 'static, V: ObjectType + 'static> IntoIterator for &'a NSDictionary<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = NSDictionaryIter<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, K, V> FromIterator<(&'a K, &'a V)> for Arc<NSDictionary<K, V>>
+where
+    K: ObjectType + 'static,
+    V: ObjectType + 'static,
+{
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = (&'a K, &'a V)>>(iter: I) -> Self {
+        let (keys, values): (Vec<&K>, Vec<&V>) = iter.into_iter().unzip();
+        NSDictionary::from_slices(&keys, &values)
+    }
+}
+
+impl<K: ObjectType + 'static, V: ObjectType + 'static> Default for Arc<NSDictionary<K, V>> {
+    #[inline]
+    fn default() -> Self {
+        NSDictionary::new()
+    }
+}
+
+impl<K, V> NSDictionary<K, V> {
+    /// Reinterprets the keys and values of this dictionary as `K2` and `V2`.
+    ///
+    /// # Safety
+    ///
+    /// All keys must be valid instances of `K2` and all values must be valid
+    /// instances of `V2`.
+    #[inline]
+    pub unsafe fn cast_unchecked<K2, V2>(&self) -> &NSDictionary<K2, V2> {
+        &*(self as *const Self as *const NSDictionary<K2, V2>)
+    }
+
+    /// Returns the number of key-value pairs in `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdictionary/count).
+    #[inline]
+    #[doc(alias = "count")]
+    pub fn len(&self) -> usize {
+        unsafe { _msg_send_any_cached![self, count => NSUInteger] }
+    }
+
+    /// Returns `true` if `self` contains no key-value pairs.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<K: ObjectType + 'static, V: ObjectType + 'static> NSDictionary<K, V> {
+    /// Creates an empty dictionary.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdictionary/init()).
+    #[inline]
+    pub fn new() -> Arc<Self> {
+        unsafe { Self::class().alloc_init() }
+    }
+
+    /// Creates a dictionary containing copies of `keys` and retained
+    /// references to their corresponding `values`.
+    ///
+    /// If a key appears more than once, the last corresponding value is used.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdictionary/init(objects:forkeys:count:)).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `keys` and `values` have different lengths.
+    #[inline]
+    #[doc(alias = "initWithObjects:forKeys:count:")]
+    pub fn from_slices(keys: &[&K], values: &[&V]) -> Arc<Self> {
+        assert_eq!(
+            keys.len(),
+            values.len(),
+            "keys and values must have the same length"
+        );
+        unsafe {
+            let value: Arc<Self> = Self::class().alloc();
+
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(
+                    obj: Arc<NSDictionary>,
+                    sel: Sel,
+                    objects: *const *const NSObject<'static>,
+                    keys: *const *const NSObject<'static>,
+                    count: NSUInteger,
+                ) -> Arc<NSDictionary>;
+            }
+
+            let dictionary = objc_msgSend(
+                Arc::cast_unchecked(value),
+                selector!(initWithObjects:forKeys:count:),
+                values.as_ptr().cast(),
+                keys.as_ptr().cast(),
+                keys.len(),
+            );
+            Arc::cast_unchecked(dictionary)
+        }
+    }
+
+    /// Returns the value associated with `key`, or [`None`] if there is none
+    /// or it is [`NSNull`].
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdictionary/object(forkey:)).
+    #[inline]
+    #[doc(alias = "objectForKey")]
+    #[doc(alias = "objectForKey:")]
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.get_nullable(key).flatten()
+    }
+
+    /// Returns the value associated with `key`, `Some(None)` if it is
+    /// [`NSNull`], or [`None`] if there is none.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdictionary/object(forkey:)).
+    #[inline]
+    #[doc(alias = "objectForKey")]
+    #[doc(alias = "objectForKey:")]
+    pub fn get_nullable(&self, key: &K) -> Option<Option<&V>> {
+        let value = unsafe { _msg_send_any_cached![self, objectForKey: key => *const V].as_ref() }?;
+        Some(Some(value).filter(|value| !NSNull::is_null(*value)))
+    }
+
+    /// Returns `true` if `self` contains a value for `key`, including
+    /// [`NSNull`].
+    #[inline]
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.get_nullable(key).is_some()
+    }
+
+    /// Returns an iterator over the key-value pairs of `self`, skipping
+    /// pairs whose value is [`NSNull`].
+    #[inline]
+    pub fn iter(&self) -> NSDictionaryIter<'_, K, V> {
+        NSDictionaryIter::new(self)
+    }
+
+    /// Returns an iterator over the key-value pairs of `self`, including
+    /// [`NSNull`] values.
+    ///
+    /// Unlike [`iter`](Self::iter), values are yielded as is, so they may be
+    /// `NSNull` rather than a `V`. Use [`NSNull::is_null`] to check before
+    /// treating them as `V`.
+    #[inline]
+    pub fn iter_raw(&self) -> NSDictionaryRawIter<'_, K, V> {
+        NSDictionaryRawIter::new(self)
+    }
+
+    /// Returns an iterator over the keys of `self`, including those whose
+    /// value is [`NSNull`].
+    #[inline]
+    pub fn keys(&self) -> impl Iterator<Item = &K> + '_ {
+        self.iter_raw().map(|(key, _)| key)
+    }
+
+    /// Returns an iterator over the values of `self`, skipping [`NSNull`].
+    #[inline]
+    pub fn values(&self) -> impl Iterator<Item = &V> + '_ {
+        self.iter().map(|(_, value)| value)
+    }
+
+    /// Returns an array of the keys of `self`, in an unspecified order.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdictionary/allkeys).
+    #[inline]
+    #[doc(alias = "allKeys")]
+    pub fn all_keys(&self) -> Arc<NSArray<K>> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, allKeys => *const NSArray<K>]) }
+    }
+
+    /// Returns an array of the values of `self`, in the same order as
+    /// [`all_keys`](Self::all_keys).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdictionary/allvalues).
+    #[inline]
+    #[doc(alias = "allValues")]
+    pub fn all_values(&self) -> Arc<NSArray<V>> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, allValues => *const NSArray<V>]) }
+    }
+
+    fn raw_keys_and_values(&self) -> (Vec<*const K>, Vec<*const V>) {
+        let len = self.len();
+        let mut keys = Vec::with_capacity(len);
+        let mut values = Vec::with_capacity(len);
+        unsafe {
+            _msg_send_any![
+                self,
+                getObjects: values.as_mut_ptr()
+                andKeys: keys.as_mut_ptr()
+                count: len as NSUInteger
+                => ()
+            ];
+            keys.set_len(len);
+            values.set_len(len);
+        }
+        (keys, values)
+    }
+}
@@ -0,0 +1,200 @@
+use super::NSDictionary;
+use crate::core::{Arc, ObjectType};
+use crate::objc::{Class, ClassType, NSObject, NSUInteger, ObjCObject, Sel};
+use std::{fmt, iter::FromIterator, ops::Deref, ptr::NonNull};
+
+/// A dynamic collection of key-value pairs with keys of type `K` and values
+/// of type `V`.
+///
+/// This is toll-free bridged with
+/// [`CFMutableDictionary`](crate::core_foundation::CFMutableDictionary) when
+/// the **`core_foundation`** feature is enabled.
+///
+/// See [documentation](https://developer.apple.com/documentation/foundation/nsmutabledictionary).
+#[repr(C)]
+pub struct NSMutableDictionary<K = NSObject<'static>, V = NSObject<'static>> {
+    base: NSDictionary<K, V>,
+}
+
+impl<K, V> ObjectType for NSMutableDictionary<K, V> {
+    #[inline]
+    fn retain(obj: &Self) -> Arc<Self> {
+        let obj = NSDictionary::retain(&obj.base);
+        unsafe { Arc::cast_unchecked(obj) }
+    }
+
+    #[inline]
+    unsafe fn release(obj: NonNull<Self>) {
+        NSDictionary::<K, V>::release(obj.cast());
+    }
+}
+
+impl<K: 'static, V: 'static> crate::objc::ObjectType<'static> for NSMutableDictionary<K, V> {}
+
+impl<K: 'static, V: 'static> ClassType<'static> for NSMutableDictionary<K, V> {
+    #[inline]
+    fn class() -> &'static Class {
+        crate::_objc_class!(@ "OBJC_CLASS_$_NSMutableDictionary")
+    }
+}
+
+impl<K, V> Deref for NSMutableDictionary<K, V> {
+    type Target = NSDictionary<K, V>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<K, V> AsRef<ObjCObject<'static>> for NSMutableDictionary<K, V> {
+    #[inline]
+    fn as_ref(&self) -> &ObjCObject<'static> {
+        self.base.as_ref()
+    }
+}
+
+impl<K, V> AsRef<NSDictionary<K, V>> for NSMutableDictionary<K, V> {
+    #[inline]
+    fn as_ref(&self) -> &NSDictionary<K, V> {
+        &self.base
+    }
+}
+
+impl<K, V> PartialEq for NSMutableDictionary<K, V> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.base == other.base
+    }
+}
+
+impl<K, V> fmt::Debug for NSMutableDictionary<K, V>
+where
+    K: ObjectType + fmt::Debug + 'static,
+    V: ObjectType + fmt::Debug + 'static,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.base.fmt(f)
+    }
+}
+
+impl<'a, K, V> FromIterator<(&'a K, &'a V)> for Arc<NSMutableDictionary<K, V>>
+where
+    K: ObjectType + 'static,
+    V: ObjectType + 'static,
+{
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = (&'a K, &'a V)>>(iter: I) -> Self {
+        let iter = iter.into_iter();
+        let dictionary = NSMutableDictionary::with_capacity(iter.size_hint().0);
+        for (key, value) in iter {
+            // SAFETY: `dictionary` has not been shared yet.
+            unsafe { dictionary.insert(key, value) };
+        }
+        dictionary
+    }
+}
+
+impl<K: ObjectType + 'static, V: ObjectType + 'static> Default for Arc<NSMutableDictionary<K, V>> {
+    #[inline]
+    fn default() -> Self {
+        NSMutableDictionary::new()
+    }
+}
+
+impl<K: ObjectType + 'static, V: ObjectType + 'static> NSMutableDictionary<K, V> {
+    /// Creates an empty dictionary.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsmutabledictionary/init()).
+    #[inline]
+    pub fn new() -> Arc<Self> {
+        unsafe { Self::class().alloc_init() }
+    }
+
+    /// Creates an empty dictionary with enough memory to hold `capacity`
+    /// key-value pairs.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsmutabledictionary/init(capacity:)).
+    #[inline]
+    #[doc(alias = "initWithCapacity")]
+    #[doc(alias = "initWithCapacity:")]
+    pub fn with_capacity(capacity: usize) -> Arc<Self> {
+        unsafe {
+            let value: Arc<Self> = Self::class().alloc();
+
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(
+                    obj: Arc<NSMutableDictionary>,
+                    sel: Sel,
+                    capacity: NSUInteger,
+                ) -> Arc<NSMutableDictionary>;
+            }
+
+            let dictionary = objc_msgSend(
+                Arc::cast_unchecked(value),
+                selector!(initWithCapacity:),
+                capacity,
+            );
+            Arc::cast_unchecked(dictionary)
+        }
+    }
+
+    /// Sets the value for a copy of `key`, returning the previous value if
+    /// there was one.
+    ///
+    /// This is equivalent to `dictionary[key] = value` in Objective-C.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsmutabledictionary/setobject(_:forkey:)).
+    ///
+    /// # Safety
+    ///
+    /// No references to keys or values of `self`, such as from
+    /// [`get`](NSDictionary::get), indexing, or [`iter`](NSDictionary::iter),
+    /// may be alive, and `self` must not be accessed on any other thread
+    /// during this call.
+    #[inline]
+    #[doc(alias = "setObject:forKey:")]
+    #[doc(alias = "setObject:forKeyedSubscript:")]
+    pub unsafe fn insert(&self, key: &K, value: &V) -> Option<Arc<V>> {
+        let old = self.get(key).map(Arc::retain);
+        _msg_send_any_cached![self, setObject: value forKey: key => ()];
+        old
+    }
+
+    /// Removes the value for `key`, returning it if there was one.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsmutabledictionary/removeobject(forkey:)).
+    ///
+    /// # Safety
+    ///
+    /// No references to keys or values of `self`, such as from
+    /// [`get`](NSDictionary::get), indexing, or [`iter`](NSDictionary::iter),
+    /// may be alive, and `self` must not be accessed on any other thread
+    /// during this call.
+    #[inline]
+    #[doc(alias = "removeObjectForKey")]
+    #[doc(alias = "removeObjectForKey:")]
+    pub unsafe fn remove(&self, key: &K) -> Option<Arc<V>> {
+        let old = self.get(key).map(Arc::retain)?;
+        _msg_send_any![self, removeObjectForKey: key => ()];
+        Some(old)
+    }
+
+    /// Removes all key-value pairs from `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsmutabledictionary/removeallobjects()).
+    ///
+    /// # Safety
+    ///
+    /// No references to keys or values of `self`, such as from
+    /// [`get`](NSDictionary::get), indexing, or [`iter`](NSDictionary::iter),
+    /// may be alive, and `self` must not be accessed on any other thread
+    /// during this call.
+    #[inline]
+    #[doc(alias = "removeAllObjects")]
+    pub unsafe fn clear(&self) {
+        _msg_send_any![self, removeAllObjects]
+    }
+}
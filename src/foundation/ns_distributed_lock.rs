@@ -0,0 +1,110 @@
+use super::{NSDate, NSException, NSString};
+use crate::core::Arc;
+use crate::objc::{ClassType, NSObject, Sel, BOOL};
+
+objc_subclass! {
+    /// A lock that multiple applications on multiple hosts can use to restrict
+    /// access to some shared resource, such as a file.
+    ///
+    /// The lock is implemented by an entry (such as a file or directory) in the
+    /// file system. It does not block; [`try_lock`](Self::try_lock) must be
+    /// polled until it succeeds.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdistributedlock).
+    pub class NSDistributedLock: NSObject<'static>;
+}
+
+impl NSDistributedLock {
+    /// Creates a lock backed by the file system entry at `path`.
+    ///
+    /// All of the directories in `path` must already exist. Returns [`None`]
+    /// if `path` is not valid.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdistributedlock/init(path:)).
+    #[inline]
+    #[doc(alias = "initWithPath")]
+    #[doc(alias = "initWithPath:")]
+    pub fn with_path(path: &NSString) -> Option<Arc<Self>> {
+        unsafe {
+            let value: Arc<Self> = Self::class().alloc();
+
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(
+                    obj: Arc<NSDistributedLock>,
+                    sel: Sel,
+                    path: &NSString,
+                ) -> Option<Arc<NSDistributedLock>>;
+            }
+
+            objc_msgSend(value, selector!(initWithPath:), path)
+        }
+    }
+
+    /// Attempts to acquire the lock, returning immediately with `true` if the
+    /// attempt was successful.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdistributedlock/try()).
+    #[inline]
+    #[doc(alias = "tryLock")]
+    pub fn try_lock(&self) -> bool {
+        unsafe { _msg_send_any![self, tryLock => BOOL] }.into()
+    }
+
+    /// Relinquishes the lock.
+    ///
+    /// This returns the raised exception if the lock is not held by the
+    /// current process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdistributedlock/unlock()).
+    #[inline]
+    pub fn unlock(&self) -> Result<(), Arc<NSException>> {
+        NSException::catch(|| unsafe { _msg_send_any![self, unlock => ()] })
+    }
+
+    /// Forces the lock to be relinquished.
+    ///
+    /// This should only be used if the process holding the lock is known to
+    /// have terminated without unlocking it.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdistributedlock/break()).
+    #[inline]
+    #[doc(alias = "breakLock")]
+    pub fn break_lock(&self) {
+        unsafe { _msg_send_any![self, breakLock] }
+    }
+
+    /// Returns the time when the lock was acquired by any process, or [`None`]
+    /// if it is not held.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsdistributedlock/lockdate).
+    #[inline]
+    #[doc(alias = "lockDate")]
+    pub fn lock_date(&self) -> Option<Arc<NSDate>> {
+        unsafe {
+            let date = _msg_send_any![self, lockDate => *const NSDate];
+            if date.is_null() {
+                None
+            } else {
+                Some(Arc::retain_raw(date))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unlock_without_lock() {
+        let path = std::env::temp_dir().join(format!("fruity-{}.lock", std::process::id()));
+        let path = NSString::from_str(path.to_str().unwrap());
+        let lock = NSDistributedLock::with_path(&path).unwrap();
+
+        assert!(lock.unlock().is_err());
+
+        assert!(lock.try_lock());
+        assert!(lock.unlock().is_ok());
+    }
+}
@@ -0,0 +1,52 @@
+use crate::objc::NSUInteger;
+use std::ops;
+
+/// Options for block-based enumeration of collections.
+///
+/// These are accepted by collection methods that call a closure for each
+/// element, such as filtering by a predicate.
+///
+/// See [documentation](https://developer.apple.com/documentation/foundation/nsenumerationoptions).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NSEnumerationOptions(pub NSUInteger);
+
+impl ops::BitOr for NSEnumerationOptions {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl NSEnumerationOptions {
+    /// Enumerates serially from first to last element.
+    pub const NONE: Self = Self(0);
+
+    /// Enumerates concurrently, which is useful for large collections whose
+    /// per-element work is independent.
+    ///
+    /// The order of invocation is nondeterministic, so the closure must be
+    /// safe to call from multiple threads at once.
+    #[doc(alias = "NSEnumerationConcurrent")]
+    pub const CONCURRENT: Self = Self(1 << 0);
+
+    /// Enumerates from last to first element.
+    ///
+    /// This is ignored when combined with [`CONCURRENT`](Self::CONCURRENT).
+    #[doc(alias = "NSEnumerationReverse")]
+    pub const REVERSE: Self = Self(1 << 1);
+
+    /// Returns `true` if all options in `other` are set in `self`.
+    #[inline]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns `true` if [`CONCURRENT`](Self::CONCURRENT) is set.
+    #[inline]
+    pub const fn is_concurrent(self) -> bool {
+        self.contains(Self::CONCURRENT)
+    }
+}
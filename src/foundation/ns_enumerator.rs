@@ -0,0 +1,138 @@
+use super::NSArray;
+use crate::core::{Arc, ObjectType};
+use crate::objc::{Class, ClassType, NSObject, ObjCObject};
+use std::{marker::PhantomData, ops::Deref, ptr::NonNull};
+
+/// An abstract class whose subclasses enumerate collections of objects of type
+/// `T`.
+///
+/// This can be used as an [`Iterator`] through `&NSEnumerator<T>` or
+/// `Arc<NSEnumerator<T>>`.
+///
+/// See [documentation](https://developer.apple.com/documentation/foundation/nsenumerator).
+///
+/// # Element Type
+///
+/// Objective-C enumerators are only lightly typed, so `T` is not checked at
+/// runtime. Enumerators returned by this crate are typed according to their
+/// source collection. Enumerators obtained elsewhere should be reinterpreted
+/// with [`cast_unchecked`](Self::cast_unchecked) only when their contents are
+/// known.
+///
+/// # Mutation
+///
+/// It is not safe to modify a mutable collection while enumerating it. Doing
+/// so raises an Objective-C exception for most collections.
+#[repr(C)]
+pub struct NSEnumerator<T = NSObject<'static>> {
+    base: NSObject<'static>,
+    // Enumerators are stateful and not thread-safe, so this type is neither
+    // `Send` nor `Sync`.
+    _marker: PhantomData<*const T>,
+}
+
+impl<T> ObjectType for NSEnumerator<T> {
+    #[inline]
+    fn retain(obj: &Self) -> Arc<Self> {
+        let obj = Arc::retain(&obj.base);
+        unsafe { Arc::cast_unchecked(obj) }
+    }
+
+    #[inline]
+    unsafe fn release(obj: NonNull<Self>) {
+        NSObject::release(obj.cast());
+    }
+}
+
+impl<T: 'static> crate::objc::ObjectType<'static> for NSEnumerator<T> {}
+
+impl<T: 'static> ClassType<'static> for NSEnumerator<T> {
+    #[inline]
+    fn class() -> &'static Class {
+        crate::_objc_class!(@ "OBJC_CLASS_$_NSEnumerator")
+    }
+}
+
+impl<T> Deref for NSEnumerator<T> {
+    type Target = NSObject<'static>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<T> AsRef<ObjCObject<'static>> for NSEnumerator<T> {
+    #[inline]
+    fn as_ref(&self) -> &ObjCObject<'static> {
+        self.base.as_ref()
+    }
+}
+
+impl<T: ObjectType + 'static> Iterator for &NSEnumerator<T> {
+    type Item = Arc<T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_object()
+    }
+}
+
+impl<T: ObjectType + 'static> Iterator for Arc<NSEnumerator<T>> {
+    type Item = Arc<T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_object()
+    }
+}
+
+impl<T> NSEnumerator<T> {
+    /// Reinterprets the objects enumerated by `self` as `U`.
+    ///
+    /// # Safety
+    ///
+    /// All enumerated objects must be valid instances of `U`.
+    #[inline]
+    pub unsafe fn cast_unchecked<U>(&self) -> &NSEnumerator<U> {
+        &*(self as *const Self as *const NSEnumerator<U>)
+    }
+
+    /// Reinterprets the objects enumerated by `this` as `U`.
+    ///
+    /// # Safety
+    ///
+    /// All enumerated objects must be valid instances of `U`.
+    #[inline]
+    pub unsafe fn cast_arc_unchecked<U>(this: Arc<Self>) -> Arc<NSEnumerator<U>> {
+        Arc::cast_unchecked(this)
+    }
+}
+
+impl<T: ObjectType + 'static> NSEnumerator<T> {
+    /// Returns the next object, or `None` if all objects have been enumerated.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsenumerator/nextobject()).
+    #[inline]
+    #[doc(alias = "nextObject")]
+    pub fn next_object(&self) -> Option<Arc<T>> {
+        unsafe {
+            let object = _msg_send_any_cached![self, nextObject => *const T];
+            if object.is_null() {
+                None
+            } else {
+                Some(Arc::retain_raw(object))
+            }
+        }
+    }
+
+    /// Returns an array of the objects that have not yet been enumerated,
+    /// exhausting `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsenumerator/allobjects).
+    #[inline]
+    #[doc(alias = "allObjects")]
+    pub fn all_objects(&self) -> Arc<NSArray<T>> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, allObjects => *const NSArray<T>]) }
+    }
+}
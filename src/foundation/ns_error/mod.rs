@@ -1,6 +1,6 @@
 use super::NSString;
 use crate::core::Arc;
-use crate::objc::{NSInteger, NSObject};
+use crate::objc::{ClassType, NSInteger, NSObject};
 use std::fmt;
 
 mod domain;
@@ -35,8 +35,25 @@ impl fmt::Display for NSError<'_> {
     }
 }
 
-impl NSError<'_> {
-    // TODO: `new(domain: &NSErrorDomain, code: NSInteger, user_info: &NSDictionary<NSErrorUserInfoKey, id>) -> Arc<Self>`
+impl NSError<'static> {
+    /// Creates an error with `domain` and `code`, and no user info.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nserror/1522782-errorwithdomain).
+    #[inline]
+    #[doc(alias = "errorWithDomain:code:userInfo:")]
+    pub fn new(domain: &NSErrorDomain, code: NSInteger) -> Arc<Self> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![
+                Self::class(),
+                errorWithDomain: domain
+                code: code
+                userInfo: std::ptr::null::<NSObject>()
+                => *const Self
+            ])
+        }
+    }
+
+    // TODO: `with_user_info(domain: &NSErrorDomain, code: NSInteger, user_info: &NSDictionary<NSErrorUserInfoKey, id>) -> Arc<Self>`
 }
 
 /// Getting error properties.
@@ -1,11 +1,16 @@
-use super::NSString;
+use super::{NSArray, NSNumber, NSString};
 use crate::core::Arc;
-use crate::objc::NSObject;
+use crate::objc::{catch_exception, ClassType, NSObject, ObjCObject, ObjectType};
+use std::ptr;
 
 mod name;
 
 pub use name::NSExceptionName;
 
+extern "C-unwind" {
+    fn objc_exception_throw(exception: *const ObjCObject) -> !;
+}
+
 /// A function pointer that can be used to perform last-minute logging before
 /// the program terminates.
 ///
@@ -14,7 +19,7 @@ pub use name::NSExceptionName;
 /// [`NSSetUncaughtExceptionHandler`](fn.NSSetUncaughtExceptionHandler.html).
 ///
 /// See [documentation](https://developer.apple.com/documentation/foundation/nsuncaughtexceptionhandler).
-pub type NSUncaughtExceptionHandler = unsafe extern "C" fn(NSException);
+pub type NSUncaughtExceptionHandler = unsafe extern "C" fn(&NSException);
 
 /// Returns the top-level error handler.
 ///
@@ -63,11 +68,59 @@ impl NSException {
     #[inline]
     #[doc(alias = "objc_exception_throw")]
     pub fn raise(&self) -> ! {
-        // TODO(#7): Use "C-unwind" ABI when stable.
-        extern "C" {
-            fn objc_exception_throw(exception: &NSException) -> !;
+        unsafe { objc_exception_throw(self.as_ref()) }
+    }
+}
+
+/// Handling exceptions.
+impl NSException {
+    /// Calls `f` and returns its result, or the `NSException` raised within
+    /// it.
+    ///
+    /// This is the equivalent of `@try`/`@catch (NSException *exception)`.
+    /// Objects thrown that are not instances of `NSException` continue to
+    /// propagate. See [`catch_exception`] for which exceptions can be caught.
+    ///
+    /// [`catch_exception`]: crate::objc::catch_exception
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fruity::foundation::{NSArray, NSException, NSExceptionName};
+    /// use fruity::objc::NSObject;
+    ///
+    /// let array = NSArray::<NSObject>::new();
+    ///
+    /// let exception = NSException::catch(|| unsafe {
+    ///     fruity::msg_send![array, objectAtIndex: 0usize => *const NSObject]
+    /// })
+    /// .unwrap_err();
+    ///
+    /// assert_eq!(&*exception.name(), NSExceptionName::range());
+    /// ```
+    #[doc(alias = "@try")]
+    #[doc(alias = "@catch")]
+    pub fn catch<F, T>(f: F) -> Result<T, Arc<Self>>
+    where
+        F: FnOnce() -> T,
+    {
+        let exception = match catch_exception(f) {
+            Ok(value) => return Ok(value),
+            Err(exception) => exception,
+        };
+
+        match exception {
+            Some(exception)
+                if exception
+                    .class()
+                    .is_subclass_of(<Self as ClassType>::class()) =>
+            {
+                Err(unsafe { Arc::cast_unchecked(exception) })
+            }
+            // Rethrow objects that `@catch (NSException *)` would not catch.
+            Some(exception) => unsafe { objc_exception_throw(Arc::into_raw(exception)) },
+            None => unsafe { objc_exception_throw(ptr::null()) },
         }
-        unsafe { objc_exception_throw(self) }
     }
 }
 
@@ -94,7 +147,59 @@ impl NSException {
 
 /// Getting exception stack frames.
 impl NSException {
-    // TODO: `callStackReturnAddresses`
+    /// Returns the return addresses of the call stack where `self` was
+    /// raised, or [`None`] if it has not been raised.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsexception/1410155-callstackreturnaddresses).
+    #[inline]
+    #[doc(alias = "callStackReturnAddresses")]
+    pub fn call_stack_return_addresses(&self) -> Option<Arc<NSArray<NSNumber>>> {
+        unsafe {
+            _msg_send_any![self, callStackReturnAddresses => *const NSArray<NSNumber>]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
 
-    // TODO: `callStackSymbols`
+    /// Returns a description of each frame of the call stack where `self`
+    /// was raised, or [`None`] if it has not been raised.
+    ///
+    /// These are formatted like a crash log, with the image, address, and
+    /// symbol of each frame.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsexception/1414145-callstacksymbols).
+    #[inline]
+    #[doc(alias = "callStackSymbols")]
+    pub fn call_stack_symbols(&self) -> Option<Arc<NSArray<NSString<'static>>>> {
+        unsafe {
+            _msg_send_any![self, callStackSymbols => *const NSArray<NSString<'static>>]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::objc::{catch_exception, NSObject};
+
+    #[test]
+    fn catch_out_of_bounds() {
+        let array = NSArray::<NSObject>::new();
+        let object_at_index =
+            || unsafe { _msg_send_any![&*array, objectAtIndex: 0usize => *const NSObject] };
+
+        let exception = NSException::catch(object_at_index).unwrap_err();
+        assert_eq!(&*exception.name(), NSExceptionName::range());
+
+        assert!(matches!(catch_exception(object_at_index), Err(Some(_))));
+        assert!(matches!(NSException::catch(|| 1), Ok(1)));
+    }
+
+    #[test]
+    #[should_panic(expected = "within catch")]
+    fn catch_propagates_panics() {
+        let _ = NSException::catch(|| panic!("within catch"));
+    }
 }
@@ -0,0 +1,199 @@
+use super::{NSError, NSURL};
+use crate::core::Arc;
+use crate::objc::{Block, ClassType, NSObject, NSUInteger, StackBlock};
+use std::{cell::Cell, ops, ptr};
+
+/// Options for [`NSFileCoordinator::coordinate_reading`].
+///
+/// See [documentation](https://developer.apple.com/documentation/foundation/nsfilecoordinator/readingoptions).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NSFileCoordinatorReadingOptions(pub NSUInteger);
+
+impl ops::BitOr for NSFileCoordinatorReadingOptions {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl NSFileCoordinatorReadingOptions {
+    /// Reads without asking file presenters to save their changes first.
+    #[doc(alias = "NSFileCoordinatorReadingWithoutChanges")]
+    pub const WITHOUT_CHANGES: Self = Self(1 << 0);
+
+    /// Reads the destination of a symbolic link instead of the link itself.
+    #[doc(alias = "NSFileCoordinatorReadingResolvesSymbolicLink")]
+    pub const RESOLVES_SYMBOLIC_LINK: Self = Self(1 << 1);
+
+    /// Reads only metadata that is available immediately, without
+    /// downloading the item.
+    #[doc(alias = "NSFileCoordinatorReadingImmediatelyAvailableMetadataOnly")]
+    pub const IMMEDIATELY_AVAILABLE_METADATA_ONLY: Self = Self(1 << 2);
+
+    /// Reads a snapshot of the item for uploading, such as a zip archive of
+    /// a directory.
+    #[doc(alias = "NSFileCoordinatorReadingForUploading")]
+    pub const FOR_UPLOADING: Self = Self(1 << 3);
+}
+
+/// Options for [`NSFileCoordinator::coordinate_writing`].
+///
+/// See [documentation](https://developer.apple.com/documentation/foundation/nsfilecoordinator/writingoptions).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NSFileCoordinatorWritingOptions(pub NSUInteger);
+
+impl ops::BitOr for NSFileCoordinatorWritingOptions {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl NSFileCoordinatorWritingOptions {
+    /// The item is about to be deleted.
+    #[doc(alias = "NSFileCoordinatorWritingForDeleting")]
+    pub const FOR_DELETING: Self = Self(1 << 0);
+
+    /// The item is about to be moved.
+    #[doc(alias = "NSFileCoordinatorWritingForMoving")]
+    pub const FOR_MOVING: Self = Self(1 << 1);
+
+    /// The item is about to be updated with changes merged from elsewhere.
+    #[doc(alias = "NSFileCoordinatorWritingForMerging")]
+    pub const FOR_MERGING: Self = Self(1 << 2);
+
+    /// The item is about to be replaced with a different item.
+    #[doc(alias = "NSFileCoordinatorWritingForReplacing")]
+    pub const FOR_REPLACING: Self = Self(1 << 3);
+
+    /// Only metadata that does not depend on the item's contents is about to
+    /// change.
+    #[doc(alias = "NSFileCoordinatorWritingContentIndependentMetadataOnly")]
+    pub const CONTENT_INDEPENDENT_METADATA_ONLY: Self = Self(1 << 4);
+}
+
+objc_subclass! {
+    /// Coordinates reading and writing of files and directories among file
+    /// presenters, such as open documents, and other processes.
+    ///
+    /// Coordinated access waits for other coordinated access to the same
+    /// item to finish, and lets file presenters save their changes before a
+    /// read or reload the item after a write.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsfilecoordinator).
+    pub class NSFileCoordinator: NSObject<'static>;
+}
+
+impl NSFileCoordinator {
+    /// Creates a coordinator that is not associated with a file presenter.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsfilecoordinator/init(filepresenter:)).
+    #[inline]
+    pub fn new() -> Arc<Self> {
+        unsafe { Self::class().alloc_init() }
+    }
+
+    /// Calls `f` with the URL to read the item at `url` from, once it can be
+    /// read, and waits for it to return.
+    ///
+    /// It is safe to panic within `f`. Panics will abort the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsfilecoordinator/coordinate(readingitemat:options:error:byaccessor:)).
+    #[doc(alias = "coordinateReadingItemAtURL:options:error:byAccessor:")]
+    pub fn coordinate_reading<F, T>(
+        &self,
+        url: &NSURL,
+        options: NSFileCoordinatorReadingOptions,
+        f: F,
+    ) -> Result<T, Arc<NSError<'static>>>
+    where
+        F: FnOnce(&NSURL) -> T,
+    {
+        coordinate(f, |accessor, error| unsafe {
+            _msg_send_any![
+                self,
+                coordinateReadingItemAtURL: url
+                options: options
+                error: error
+                byAccessor: accessor
+                => ()
+            ]
+        })
+    }
+
+    /// Calls `f` with the URL to write the item at `url` to, once it can be
+    /// written, and waits for it to return.
+    ///
+    /// It is safe to panic within `f`. Panics will abort the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsfilecoordinator/coordinate(writingitemat:options:error:byaccessor:)).
+    #[doc(alias = "coordinateWritingItemAtURL:options:error:byAccessor:")]
+    pub fn coordinate_writing<F, T>(
+        &self,
+        url: &NSURL,
+        options: NSFileCoordinatorWritingOptions,
+        f: F,
+    ) -> Result<T, Arc<NSError<'static>>>
+    where
+        F: FnOnce(&NSURL) -> T,
+    {
+        coordinate(f, |accessor, error| unsafe {
+            _msg_send_any![
+                self,
+                coordinateWritingItemAtURL: url
+                options: options
+                error: error
+                byAccessor: accessor
+                => ()
+            ]
+        })
+    }
+
+    /// Cancels coordinated access that is waiting or in progress, which makes
+    /// waiting calls return an error.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsfilecoordinator/cancel()).
+    #[inline]
+    pub fn cancel(&self) {
+        unsafe { _msg_send_any![self, cancel] }
+    }
+}
+
+/// Calls `send` with an accessor block that calls `f`, returning its result
+/// or the error reported instead of calling it.
+fn coordinate<F, T, S>(f: F, send: S) -> Result<T, Arc<NSError<'static>>>
+where
+    F: FnOnce(&NSURL) -> T,
+    S: FnOnce(&Block<(*const NSURL,), ()>, *mut *const NSError<'static>),
+{
+    let f = Cell::new(Some(f));
+    let result = Cell::new(None);
+    let (f_ref, result_ref) = (&f, &result);
+
+    let accessor = StackBlock::new(move |url: *const NSURL| {
+        if let (Some(f), Some(url)) = (f_ref.take(), unsafe { url.as_ref() }) {
+            result_ref.set(Some(f(url)));
+        }
+    });
+    let accessor: &Block<_, ()> = &accessor;
+
+    let mut error: *const NSError<'static> = ptr::null();
+    send(accessor, &mut error);
+
+    match result.into_inner() {
+        Some(result) => Ok(result),
+        None => {
+            assert!(
+                !error.is_null(),
+                "file coordination failed without an error"
+            );
+            Err(unsafe { Arc::retain_raw(error) })
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use crate::core::Arc;
+use crate::objc::{ClassType, NSObject, Sel, BOOL};
+use std::os::{raw::c_int, unix::io::AsRawFd, unix::io::RawFd};
+
+objc_subclass! {
+    /// An object-oriented wrapper for a file descriptor.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/filehandle).
+    pub class NSFileHandle: NSObject<'static>;
+}
+
+impl AsRawFd for NSFileHandle {
+    #[inline]
+    fn as_raw_fd(&self) -> RawFd {
+        self.file_descriptor()
+    }
+}
+
+impl NSFileHandle {
+    /// Creates a file handle for `fd`.
+    ///
+    /// If `close_on_dealloc` is `true`, the handle takes ownership of `fd` and
+    /// closes it when deallocated.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/filehandle/init(filedescriptor:closeondealloc:)).
+    ///
+    /// # Safety
+    ///
+    /// `fd` must remain open for as long as the handle is used, and must not
+    /// be closed elsewhere if `close_on_dealloc` is `true`.
+    #[inline]
+    #[doc(alias = "initWithFileDescriptor:closeOnDealloc:")]
+    pub unsafe fn from_raw_fd(fd: RawFd, close_on_dealloc: bool) -> Arc<Self> {
+        let handle: Arc<Self> = Self::class().alloc();
+
+        #[allow(clashing_extern_declarations)]
+        extern "C" {
+            fn objc_msgSend(
+                obj: Arc<NSFileHandle>,
+                sel: Sel,
+                fd: c_int,
+                close_on_dealloc: BOOL,
+            ) -> Arc<NSFileHandle>;
+        }
+
+        objc_msgSend(
+            handle,
+            selector!(initWithFileDescriptor:closeOnDealloc:),
+            fd,
+            BOOL::from(close_on_dealloc),
+        )
+    }
+
+    /// Returns a handle that reads nothing and discards writes.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/filehandle/nulldevice).
+    #[inline]
+    #[doc(alias = "fileHandleWithNullDevice")]
+    pub fn null_device() -> Arc<Self> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![Self::class(), fileHandleWithNullDevice => *const Self])
+        }
+    }
+
+    /// Returns the file descriptor of `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/filehandle/filedescriptor).
+    #[inline]
+    #[doc(alias = "fileDescriptor")]
+    pub fn file_descriptor(&self) -> RawFd {
+        unsafe { _msg_send_any![self, fileDescriptor => c_int] }
+    }
+}
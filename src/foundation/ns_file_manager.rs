@@ -0,0 +1,99 @@
+use super::{NSError, NSString, NSURL};
+use crate::core::Arc;
+use crate::objc::{ClassType, NSObject, BOOL};
+use std::ptr;
+
+objc_subclass! {
+    /// Access to the file system.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/filemanager).
+    pub class NSFileManager: NSObject<'static>;
+}
+
+impl NSFileManager {
+    /// Returns the shared file manager.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/filemanager/default).
+    #[inline]
+    #[doc(alias = "defaultManager")]
+    #[allow(clippy::should_implement_trait)]
+    pub fn default() -> &'static Self {
+        unsafe { _msg_send_any![Self::class(), defaultManager] }
+    }
+
+    /// Returns the directory shared by apps in the app group
+    /// `group_identifier`, or [`None`] if the app is not entitled to the
+    /// group.
+    ///
+    /// On macOS, the directory is created if it does not exist.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/filemanager/containerurl(forsecurityapplicationgroupidentifier:)).
+    #[inline]
+    #[doc(alias = "containerURLForSecurityApplicationGroupIdentifier:")]
+    pub fn container_url_for_security_application_group(
+        &self,
+        group_identifier: &NSString,
+    ) -> Option<Arc<NSURL>> {
+        unsafe {
+            _msg_send_any![
+                self,
+                containerURLForSecurityApplicationGroupIdentifier: group_identifier
+                => *const NSURL
+            ]
+            .as_ref()
+            .map(Arc::retain)
+        }
+    }
+
+    /// Copies the file or directory at `src` to `dst`, which must not exist.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/filemanager/copyitem(at:to:)).
+    #[inline]
+    #[doc(alias = "copyItemAtURL:toURL:error:")]
+    pub fn copy_item(&self, src: &NSURL, dst: &NSURL) -> Result<(), Arc<NSError<'static>>> {
+        let mut error: *const NSError<'static> = ptr::null();
+        let copied = unsafe {
+            _msg_send_any![
+                self,
+                copyItemAtURL: src
+                toURL: dst
+                error: &mut error
+                => BOOL
+            ]
+        };
+        if copied.into() {
+            Ok(())
+        } else {
+            Err(unsafe { Arc::retain_raw(error) })
+        }
+    }
+
+    /// Moves the file or directory at `url` to the Trash, returning its new
+    /// location.
+    ///
+    /// Unlike [`NSWorkspace::recycle_urls`](crate::app_kit::NSWorkspace::recycle_urls),
+    /// this blocks until done and does not show progress.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/filemanager/trashitem(at:resultingitemurl:)).
+    #[cfg(target_os = "macos")]
+    #[inline]
+    #[doc(alias = "trashItemAtURL:resultingItemURL:error:")]
+    pub fn trash_item(&self, url: &NSURL) -> Result<Arc<NSURL>, Arc<NSError<'static>>> {
+        let mut new_url: *const NSURL = ptr::null();
+        let mut error: *const NSError<'static> = ptr::null();
+        let trashed = unsafe {
+            _msg_send_any![
+                self,
+                trashItemAtURL: url
+                resultingItemURL: &mut new_url
+                error: &mut error
+                => BOOL
+            ]
+        };
+        if trashed.into() {
+            Ok(unsafe { Arc::retain_raw(new_url) })
+        } else {
+            Err(unsafe { Arc::retain_raw(error) })
+        }
+    }
+}
@@ -0,0 +1,180 @@
+use super::{NSArray, NSEnumerator, NSHashTableOptions, NSPointerFunctionsOptions};
+use crate::core::Arc;
+use crate::objc::{ClassType, NSObject, NSUInteger, Sel, BOOL};
+
+objc_subclass! {
+    /// A mutable set modeled after `NSSet` that can hold weak references to
+    /// its members.
+    ///
+    /// Weakly-held members are removed from the table when they are
+    /// deallocated.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nshashtable).
+    pub class NSHashTable: NSObject<'static>;
+}
+
+impl NSHashTable {
+    /// Creates an empty table that holds strong references to its members.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nshashtable/init(options:capacity:)).
+    #[inline]
+    pub fn strong() -> Arc<Self> {
+        Self::with_options(NSPointerFunctionsOptions::STRONG_MEMORY, 0)
+    }
+
+    /// Creates an empty table that holds zeroing weak references to its
+    /// members.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nshashtable/weakobjects()).
+    #[inline]
+    #[doc(alias = "weakObjectsHashTable")]
+    pub fn weak() -> Arc<Self> {
+        Self::with_options(NSPointerFunctionsOptions::WEAK_MEMORY, 0)
+    }
+
+    /// Creates an empty table with the given options and initial capacity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `options` describe anything other than strongly or weakly
+    /// held objects.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nshashtable/init(options:capacity:)).
+    #[inline]
+    #[doc(alias = "initWithOptions:capacity:")]
+    pub fn with_options(options: NSHashTableOptions, capacity: usize) -> Arc<Self> {
+        assert!(
+            options.is_object_storage(),
+            "NSHashTable can only hold strong or weak objects, not {:?}",
+            options,
+        );
+
+        unsafe {
+            let value: Arc<Self> = Self::class().alloc();
+
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(
+                    obj: Arc<NSHashTable>,
+                    sel: Sel,
+                    options: NSHashTableOptions,
+                    capacity: NSUInteger,
+                ) -> Arc<NSHashTable>;
+            }
+
+            objc_msgSend(
+                value,
+                selector!(initWithOptions:capacity:),
+                options,
+                capacity,
+            )
+        }
+    }
+
+    /// Returns the number of members in the table.
+    ///
+    /// Weakly-held members that were deallocated may still be counted until
+    /// the table is next mutated.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nshashtable/count).
+    #[inline]
+    #[doc(alias = "count")]
+    pub fn len(&self) -> usize {
+        unsafe { _msg_send_any_cached![self, count => NSUInteger] }
+    }
+
+    /// Returns `true` if the table has no members.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Adds `obj` to the table.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nshashtable/add(_:)).
+    #[inline]
+    #[doc(alias = "addObject")]
+    #[doc(alias = "addObject:")]
+    pub fn insert(&self, obj: &NSObject) {
+        unsafe { _msg_send_any![self, addObject: obj] }
+    }
+
+    /// Removes `obj` from the table.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nshashtable/remove(_:)).
+    #[inline]
+    #[doc(alias = "removeObject")]
+    #[doc(alias = "removeObject:")]
+    pub fn remove(&self, obj: &NSObject) {
+        unsafe { _msg_send_any![self, removeObject: obj] }
+    }
+
+    /// Removes all members from the table.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nshashtable/removeallobjects()).
+    #[inline]
+    #[doc(alias = "removeAllObjects")]
+    pub fn clear(&self) {
+        unsafe { _msg_send_any![self, removeAllObjects] }
+    }
+
+    /// Returns `true` if the table contains `obj`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nshashtable/contains(_:)).
+    #[inline]
+    #[doc(alias = "containsObject")]
+    #[doc(alias = "containsObject:")]
+    pub fn contains(&self, obj: &NSObject) -> bool {
+        unsafe { _msg_send_any![self, containsObject: obj => BOOL] }.into()
+    }
+
+    /// Returns the member of the table equal to `obj`, if any.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nshashtable/member(_:)).
+    #[inline]
+    #[doc(alias = "member:")]
+    pub fn get(&self, obj: &NSObject) -> Option<Arc<NSObject<'static>>> {
+        unsafe {
+            let member = _msg_send_any![self, member: obj => *const NSObject<'static>];
+            if member.is_null() {
+                None
+            } else {
+                Some(Arc::retain_raw(member))
+            }
+        }
+    }
+
+    /// Returns one of the members of the table, or [`None`] if it is empty.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nshashtable/anyobject).
+    #[inline]
+    #[doc(alias = "anyObject")]
+    pub fn any(&self) -> Option<Arc<NSObject<'static>>> {
+        unsafe {
+            let member = _msg_send_any![self, anyObject => *const NSObject<'static>];
+            if member.is_null() {
+                None
+            } else {
+                Some(Arc::retain_raw(member))
+            }
+        }
+    }
+
+    /// Returns an enumerator over the members of the table.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nshashtable/objectenumerator()).
+    #[inline]
+    #[doc(alias = "objectEnumerator")]
+    pub fn object_enumerator(&self) -> Arc<NSEnumerator> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, objectEnumerator => *const NSEnumerator]) }
+    }
+
+    /// Returns an array of the members of the table.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nshashtable/allobjects).
+    #[inline]
+    #[doc(alias = "allObjects")]
+    pub fn all_objects(&self) -> Arc<NSArray> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, allObjects => *const NSArray]) }
+    }
+}
@@ -0,0 +1,85 @@
+use crate::core::Arc;
+use crate::objc::{ClassType, NSObject, NSUInteger, Sel};
+use std::fmt;
+
+objc_subclass! {
+    /// A path of indexes into nested collections, such as the section and
+    /// item of an element in a collection view.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsindexpath).
+    pub class NSIndexPath: NSObject<'static>;
+}
+
+impl fmt::Debug for NSIndexPath {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.to_vec()).finish()
+    }
+}
+
+impl NSIndexPath {
+    /// Creates an index path from `indexes`, outermost first.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsindexpath/init(indexes:length:)).
+    #[inline]
+    #[doc(alias = "initWithIndexes:length:")]
+    pub fn from_indexes(indexes: &[NSUInteger]) -> Arc<Self> {
+        unsafe {
+            let obj: Arc<Self> = Self::class().alloc();
+
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(
+                    obj: Arc<NSIndexPath>,
+                    sel: Sel,
+                    indexes: *const NSUInteger,
+                    length: NSUInteger,
+                ) -> Arc<NSIndexPath>;
+            }
+
+            objc_msgSend(
+                obj,
+                selector!(initWithIndexes:length:),
+                indexes.as_ptr(),
+                indexes.len(),
+            )
+        }
+    }
+
+    /// Returns the number of indexes in the path.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsindexpath/length).
+    #[inline]
+    #[doc(alias = "length")]
+    pub fn len(&self) -> usize {
+        unsafe { _msg_send_any![self, length] }
+    }
+
+    /// Returns `true` if the path has no indexes.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the index at `position`, or [`None`] if `position` is out of
+    /// bounds.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsindexpath/index(atposition:)).
+    #[inline]
+    #[doc(alias = "indexAtPosition:")]
+    pub fn get(&self, position: usize) -> Option<NSUInteger> {
+        if position < self.len() {
+            Some(unsafe { _msg_send_any![self, indexAtPosition: position] })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the indexes of the path, outermost first.
+    #[inline]
+    pub fn to_vec(&self) -> Vec<NSUInteger> {
+        (0..self.len())
+            .map(|position| unsafe { _msg_send_any![self, indexAtPosition: position] })
+            .collect()
+    }
+}
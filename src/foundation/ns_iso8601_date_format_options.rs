@@ -0,0 +1,102 @@
+use crate::objc::NSUInteger;
+use std::ops;
+
+/// Options for the date and time components used by an
+/// [`NSISO8601DateFormatter`](super::NSISO8601DateFormatter).
+///
+/// See [documentation](https://developer.apple.com/documentation/foundation/iso8601dateformatter/options).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NSISO8601DateFormatOptions(pub NSUInteger);
+
+impl ops::BitOr for NSISO8601DateFormatOptions {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+/// Components.
+impl NSISO8601DateFormatOptions {
+    /// The year.
+    #[doc(alias = "NSISO8601DateFormatWithYear")]
+    pub const YEAR: Self = Self(1 << 0);
+
+    /// The month.
+    #[doc(alias = "NSISO8601DateFormatWithMonth")]
+    pub const MONTH: Self = Self(1 << 1);
+
+    /// The week of the year.
+    #[doc(alias = "NSISO8601DateFormatWithWeekOfYear")]
+    pub const WEEK_OF_YEAR: Self = Self(1 << 2);
+
+    /// The day of the month, or of the week if combined with
+    /// [`WEEK_OF_YEAR`](Self::WEEK_OF_YEAR).
+    #[doc(alias = "NSISO8601DateFormatWithDay")]
+    pub const DAY: Self = Self(1 << 4);
+
+    /// The time of day.
+    #[doc(alias = "NSISO8601DateFormatWithTime")]
+    pub const TIME: Self = Self(1 << 5);
+
+    /// The time zone, as `Z` for UTC or an offset such as `-08:00`.
+    #[doc(alias = "NSISO8601DateFormatWithTimeZone")]
+    pub const TIME_ZONE: Self = Self(1 << 6);
+
+    /// Milliseconds after the seconds of the time.
+    #[doc(alias = "NSISO8601DateFormatWithFractionalSeconds")]
+    pub const FRACTIONAL_SECONDS: Self = Self(1 << 11);
+}
+
+/// Separators.
+impl NSISO8601DateFormatOptions {
+    /// A space instead of `T` between the date and time.
+    #[doc(alias = "NSISO8601DateFormatWithSpaceBetweenDateAndTime")]
+    pub const SPACE_BETWEEN_DATE_AND_TIME: Self = Self(1 << 7);
+
+    /// `-` between date components.
+    #[doc(alias = "NSISO8601DateFormatWithDashSeparatorInDate")]
+    pub const DASH_SEPARATOR_IN_DATE: Self = Self(1 << 8);
+
+    /// `:` between time components.
+    #[doc(alias = "NSISO8601DateFormatWithColonSeparatorInTime")]
+    pub const COLON_SEPARATOR_IN_TIME: Self = Self(1 << 9);
+
+    /// `:` between the hours and minutes of the time zone.
+    #[doc(alias = "NSISO8601DateFormatWithColonSeparatorInTimeZone")]
+    pub const COLON_SEPARATOR_IN_TIME_ZONE: Self = Self(1 << 10);
+}
+
+/// Combinations.
+impl NSISO8601DateFormatOptions {
+    /// A full date, such as `2001-01-01`.
+    #[doc(alias = "NSISO8601DateFormatWithFullDate")]
+    pub const FULL_DATE: Self =
+        Self(Self::YEAR.0 | Self::MONTH.0 | Self::DAY.0 | Self::DASH_SEPARATOR_IN_DATE.0);
+
+    /// A full time with a time zone, such as `12:00:00Z`.
+    #[doc(alias = "NSISO8601DateFormatWithFullTime")]
+    pub const FULL_TIME: Self = Self(
+        Self::TIME.0
+            | Self::COLON_SEPARATOR_IN_TIME.0
+            | Self::TIME_ZONE.0
+            | Self::COLON_SEPARATOR_IN_TIME_ZONE.0,
+    );
+
+    /// A full date and time as used on the internet, such as
+    /// `2001-01-01T12:00:00Z`.
+    ///
+    /// This is the default for a new formatter.
+    #[doc(alias = "NSISO8601DateFormatWithInternetDateTime")]
+    pub const INTERNET_DATE_TIME: Self = Self(Self::FULL_DATE.0 | Self::FULL_TIME.0);
+}
+
+impl NSISO8601DateFormatOptions {
+    /// Returns `true` if all options in `other` are set in `self`.
+    #[inline]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
@@ -0,0 +1,103 @@
+use super::{NSDate, NSISO8601DateFormatOptions, NSString, NSTimeZone};
+use crate::core::Arc;
+use crate::objc::{ClassType, NSObject};
+
+objc_subclass! {
+    /// A formatter that converts between dates and their
+    /// [ISO 8601](https://www.iso.org/iso-8601-date-and-time-format.html)
+    /// string representations.
+    ///
+    /// Unlike most formatters, this is thread-safe.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/iso8601dateformatter).
+    pub class NSISO8601DateFormatter: NSObject<'static>;
+}
+
+impl NSISO8601DateFormatter {
+    /// Creates a formatter that uses
+    /// [`INTERNET_DATE_TIME`](NSISO8601DateFormatOptions::INTERNET_DATE_TIME)
+    /// in UTC.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/iso8601dateformatter/init()).
+    #[inline]
+    pub fn new() -> Arc<Self> {
+        unsafe { Self::class().alloc_init() }
+    }
+
+    /// Creates a formatter that uses `options` in UTC.
+    #[inline]
+    pub fn with_options(options: NSISO8601DateFormatOptions) -> Arc<Self> {
+        let formatter = Self::new();
+        formatter.set_format_options(options);
+        formatter
+    }
+
+    /// Returns the components used when formatting and parsing.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/iso8601dateformatter/formatoptions).
+    #[inline]
+    #[doc(alias = "formatOptions")]
+    pub fn format_options(&self) -> NSISO8601DateFormatOptions {
+        unsafe { _msg_send_any![self, formatOptions] }
+    }
+
+    /// Sets the components used when formatting and parsing.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/iso8601dateformatter/formatoptions).
+    #[inline]
+    #[doc(alias = "setFormatOptions")]
+    #[doc(alias = "setFormatOptions:")]
+    pub fn set_format_options(&self, options: NSISO8601DateFormatOptions) {
+        unsafe { _msg_send_any![self, setFormatOptions: options] }
+    }
+
+    /// Returns the time zone used when formatting, which is GMT by default.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/iso8601dateformatter/timezone).
+    #[inline]
+    #[doc(alias = "timeZone")]
+    pub fn time_zone(&self) -> Arc<NSTimeZone> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, timeZone => *const NSTimeZone]) }
+    }
+
+    /// Sets the time zone used when formatting, or resets it to GMT if
+    /// [`None`].
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/iso8601dateformatter/timezone).
+    #[inline]
+    #[doc(alias = "setTimeZone:")]
+    pub fn set_time_zone(&self, time_zone: Option<&NSTimeZone>) {
+        unsafe { _msg_send_any![self, setTimeZone: time_zone] }
+    }
+
+    /// Returns the string representation of `date`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/iso8601dateformatter/string(from:)).
+    #[inline]
+    #[doc(alias = "stringFromDate")]
+    #[doc(alias = "stringFromDate:")]
+    pub fn string_from_date(&self, date: &NSDate) -> Arc<NSString<'static>> {
+        unsafe {
+            let string = _msg_send_any![self, stringFromDate: date => *const NSString<'static>];
+            Arc::retain_raw(string)
+        }
+    }
+
+    /// Parses `string` as a date, returning [`None`] if it does not match the
+    /// [format options](Self::format_options).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/iso8601dateformatter/date(from:)).
+    #[inline]
+    #[doc(alias = "dateFromString")]
+    #[doc(alias = "dateFromString:")]
+    pub fn date_from_string(&self, string: &NSString) -> Option<Arc<NSDate>> {
+        unsafe {
+            let date = _msg_send_any![self, dateFromString: string => *const NSDate];
+            if date.is_null() {
+                None
+            } else {
+                Some(Arc::retain_raw(date))
+            }
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use crate::core::Arc;
+use crate::foundation::{NSDictionary, NSNumber, NSRange, NSString};
+use crate::objc::{NSObject, NSUInteger, ObjCObject};
+use std::{fmt, ptr};
+
+/// The type of change reported to a key-value observer.
+///
+/// See [documentation](https://developer.apple.com/documentation/foundation/nskeyvaluechange).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NSKeyValueChange(pub NSUInteger);
+
+impl NSKeyValueChange {
+    /// The value was replaced.
+    #[doc(alias = "NSKeyValueChangeSetting")]
+    pub const SETTING: Self = Self(1);
+
+    /// Objects were inserted into a to-many relationship.
+    #[doc(alias = "NSKeyValueChangeInsertion")]
+    pub const INSERTION: Self = Self(2);
+
+    /// Objects were removed from a to-many relationship.
+    #[doc(alias = "NSKeyValueChangeRemoval")]
+    pub const REMOVAL: Self = Self(3);
+
+    /// Objects in a to-many relationship were replaced.
+    #[doc(alias = "NSKeyValueChangeReplacement")]
+    pub const REPLACEMENT: Self = Self(4);
+}
+
+/// A change to an observed property, decoded from the change dictionary
+/// passed to `observeValueForKeyPath:ofObject:change:context:`.
+///
+/// Which values are present depends on the
+/// [`NSKeyValueObservingOptions`](super::NSKeyValueObservingOptions) used.
+/// `nil` values are reported as [`None`].
+///
+/// See [documentation](https://developer.apple.com/documentation/foundation/nskeyvaluechangekey).
+#[derive(Clone)]
+pub struct NSKeyValueObservedChange {
+    /// The type of change.
+    pub kind: NSKeyValueChange,
+
+    /// The value before the change, if
+    /// [`OLD`](super::NSKeyValueObservingOptions::OLD) was requested.
+    ///
+    /// For to-many relationships, this is an array of the removed or replaced
+    /// objects.
+    pub old_value: Option<Arc<NSObject<'static>>>,
+
+    /// The value after the change, if
+    /// [`NEW`](super::NSKeyValueObservingOptions::NEW) was requested.
+    ///
+    /// For to-many relationships, this is an array of the inserted or
+    /// replacing objects.
+    pub new_value: Option<Arc<NSObject<'static>>>,
+
+    /// The indexes of the inserted, removed, or replaced objects in a to-many
+    /// relationship.
+    pub indexes: Option<Vec<NSUInteger>>,
+
+    /// Whether this is reported before the change is made, if
+    /// [`PRIOR`](super::NSKeyValueObservingOptions::PRIOR) was requested.
+    pub is_prior: bool,
+}
+
+impl fmt::Debug for NSKeyValueObservedChange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Objects are formatted as pointers.
+        fn object<'a>(
+            value: &'a Option<Arc<NSObject<'static>>>,
+        ) -> Option<&'a ObjCObject<'static>> {
+            value.as_deref().map(|value| -> &ObjCObject { value })
+        }
+
+        f.debug_struct("NSKeyValueObservedChange")
+            .field("kind", &self.kind)
+            .field("old_value", &object(&self.old_value))
+            .field("new_value", &object(&self.new_value))
+            .field("indexes", &self.indexes)
+            .field("is_prior", &self.is_prior)
+            .finish()
+    }
+}
+
+type ChangeDictionary = NSDictionary<NSString<'static>, NSObject<'static>>;
+
+macro_rules! key {
+    ($name:ident) => {{
+        extern "C" {
+            static $name: &'static NSString<'static>;
+        }
+        unsafe { $name }
+    }};
+}
+
+impl NSKeyValueObservedChange {
+    pub(super) fn from_dictionary(change: &ChangeDictionary) -> Self {
+        let number = |key: &NSString<'static>| {
+            change
+                .get(key)
+                .map(|value| unsafe { &*(value as *const NSObject as *const NSNumber) })
+        };
+
+        let value = |key: &NSString<'static>| {
+            // Values that were `nil` are `NSNull`, which `get` skips.
+            change.get(key).map(Arc::retain)
+        };
+
+        let indexes = change
+            .get(key!(NSKeyValueChangeIndexesKey))
+            .map(index_set_to_vec);
+
+        Self {
+            kind: number(key!(NSKeyValueChangeKindKey)).map_or(NSKeyValueChange::SETTING, |kind| {
+                NSKeyValueChange(kind.unsigned_integer_value())
+            }),
+            old_value: value(key!(NSKeyValueChangeOldKey)),
+            new_value: value(key!(NSKeyValueChangeNewKey)),
+            indexes,
+            is_prior: number(key!(NSKeyValueChangeNotificationIsPriorKey))
+                .is_some_and(|prior| prior.bool_value()),
+        }
+    }
+}
+
+/// Returns the indexes in an `NSIndexSet`.
+fn index_set_to_vec(indexes: &NSObject) -> Vec<NSUInteger> {
+    unsafe {
+        let count = _msg_send_any![indexes, count => NSUInteger];
+        let mut buf: Vec<NSUInteger> = Vec::with_capacity(count);
+
+        let written = _msg_send_any![
+            indexes,
+            getIndexes: buf.as_mut_ptr()
+            maxCount: count
+            inIndexRange: ptr::null_mut::<NSRange>()
+            => NSUInteger
+        ];
+        buf.set_len(written.min(count));
+        buf
+    }
+}
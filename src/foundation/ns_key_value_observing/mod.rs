@@ -0,0 +1,136 @@
+use crate::core::Arc;
+use crate::foundation::{NSDictionary, NSString};
+use crate::objc::{Class, ClassType, LazyClass, NSObject, Sel};
+use std::{ffi::c_void, fmt};
+
+mod change;
+mod options;
+
+pub use change::*;
+pub use options::*;
+
+type Callback = dyn Fn(&NSObject<'static>, &NSKeyValueObservedChange) + Send + Sync;
+
+/// Key-value observing.
+impl NSObject<'static> {
+    /// Calls `f` with `self` and the change whenever the property at
+    /// `key_path` of `self` changes, until the returned observation is
+    /// dropped.
+    ///
+    /// `f` is called synchronously on the thread that changes the property,
+    /// which may be any thread. Only properties that are key-value observing
+    /// compliant report changes. The observation keeps `self` alive.
+    ///
+    /// It is safe to panic within `f`. Panics will abort the process.
+    ///
+    /// See [documentation](https://developer.apple.com/library/archive/documentation/Cocoa/Conceptual/KeyValueObserving/Articles/KVOBasics.html).
+    #[doc(alias = "addObserver:forKeyPath:options:context:")]
+    pub fn observe<F>(
+        &self,
+        key_path: &NSString<'static>,
+        options: NSKeyValueObservingOptions,
+        f: F,
+    ) -> NSKeyValueObservation
+    where
+        F: Fn(&NSObject<'static>, &NSKeyValueObservedChange) + Send + Sync + 'static,
+    {
+        let callback: Box<Box<Callback>> = Box::new(Box::new(f));
+        let observer: Arc<NSObject<'static>> = unsafe { observer_class().alloc_init() };
+        let key_path = Arc::retain(key_path);
+
+        // The callback is passed as the context, so that the observer class
+        // needs no instance variables.
+        let context = &*callback as *const Box<Callback> as *mut c_void;
+        unsafe {
+            _msg_send_any![
+                self,
+                addObserver: &*observer
+                forKeyPath: &*key_path
+                options: options
+                context: context
+                => ()
+            ]
+        };
+
+        NSKeyValueObservation {
+            object: Arc::retain(self),
+            observer,
+            key_path,
+            callback,
+        }
+    }
+}
+
+/// An observation created by [`NSObject::observe`], which is removed when
+/// dropped.
+#[must_use = "The observation is immediately removed if unused"]
+pub struct NSKeyValueObservation {
+    object: Arc<NSObject<'static>>,
+    observer: Arc<NSObject<'static>>,
+    key_path: Arc<NSString<'static>>,
+    callback: Box<Box<Callback>>,
+}
+
+impl fmt::Debug for NSKeyValueObservation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("NSKeyValueObservation")
+            .field("key_path", &self.key_path)
+            .finish()
+    }
+}
+
+impl Drop for NSKeyValueObservation {
+    #[inline]
+    #[doc(alias = "removeObserver:forKeyPath:context:")]
+    fn drop(&mut self) {
+        let context = &*self.callback as *const Box<Callback> as *mut c_void;
+        unsafe {
+            _msg_send_any![
+                &*self.object,
+                removeObserver: &*self.observer
+                forKeyPath: &*self.key_path
+                context: context
+                => ()
+            ]
+        }
+    }
+}
+
+/// Returns the class used for observer objects, registering it with the
+/// Objective-C runtime on first use.
+fn observer_class() -> &'static Class {
+    static CLASS: LazyClass = LazyClass::new("FruityKeyValueObserver");
+
+    CLASS.get(NSObject::class(), |builder| unsafe {
+        builder.add_method(
+            selector!(observeValueForKeyPath:ofObject:change:context:),
+            observe_value as ObserveValue,
+        );
+    })
+}
+
+type ChangeDictionary = NSDictionary<NSString<'static>, NSObject<'static>>;
+
+type ObserveValue = extern "C" fn(
+    &NSObject<'static>,
+    Sel,
+    *const NSString<'static>,
+    *const NSObject<'static>,
+    *const ChangeDictionary,
+    *mut c_void,
+);
+
+extern "C" fn observe_value(
+    _this: &NSObject<'static>,
+    _: Sel,
+    _key_path: *const NSString<'static>,
+    object: *const NSObject<'static>,
+    change: *const ChangeDictionary,
+    context: *mut c_void,
+) {
+    unsafe {
+        let callback = &*(context as *const Box<Callback>);
+        let change = NSKeyValueObservedChange::from_dictionary(&*change);
+        callback(&*object, &change);
+    }
+}
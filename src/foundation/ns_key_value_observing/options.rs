@@ -0,0 +1,48 @@
+use crate::objc::NSUInteger;
+use std::ops;
+
+/// Options for which values are included in an
+/// [`NSKeyValueObservedChange`](super::NSKeyValueObservedChange), and when
+/// changes are reported.
+///
+/// See [documentation](https://developer.apple.com/documentation/foundation/nskeyvalueobservingoptions).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NSKeyValueObservingOptions(pub NSUInteger);
+
+impl ops::BitOr for NSKeyValueObservingOptions {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl NSKeyValueObservingOptions {
+    /// Includes the value after the change.
+    #[doc(alias = "NSKeyValueObservingOptionNew")]
+    pub const NEW: Self = Self(0x01);
+
+    /// Includes the value before the change.
+    #[doc(alias = "NSKeyValueObservingOptionOld")]
+    pub const OLD: Self = Self(0x02);
+
+    /// Reports the current value immediately, before
+    /// [`observe`](crate::objc::NSObject::observe) returns.
+    #[doc(alias = "NSKeyValueObservingOptionInitial")]
+    pub const INITIAL: Self = Self(0x04);
+
+    /// Reports each change twice, once before and once after it is made.
+    ///
+    /// The report before the change has
+    /// [`is_prior`](super::NSKeyValueObservedChange::is_prior) set.
+    #[doc(alias = "NSKeyValueObservingOptionPrior")]
+    pub const PRIOR: Self = Self(0x08);
+
+    /// Returns `true` if all options in `other` are set in `self`.
+    #[inline]
+    pub fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
@@ -0,0 +1,191 @@
+use super::{NSEnumerator, NSMapTableOptions, NSPointerFunctionsOptions};
+use crate::core::Arc;
+use crate::objc::{ClassType, NSObject, NSUInteger, Sel};
+
+objc_subclass! {
+    /// A mutable collection modeled after `NSDictionary` that can hold weak
+    /// references to its keys and values.
+    ///
+    /// Entries whose weakly-held key or value is deallocated are removed from
+    /// the table. This makes it suitable for caches and registries keyed by
+    /// objects whose lifetimes are managed elsewhere.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsmaptable).
+    pub class NSMapTable: NSObject<'static>;
+}
+
+impl NSMapTable {
+    /// Creates an empty table with strong keys and strong values.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsmaptable/strongtostrongobjects()).
+    #[inline]
+    #[doc(alias = "strongToStrongObjectsMapTable")]
+    pub fn strong_to_strong() -> Arc<Self> {
+        use NSPointerFunctionsOptions as Options;
+        Self::with_options(Options::STRONG_MEMORY, Options::STRONG_MEMORY, 0)
+    }
+
+    /// Creates an empty table with strong keys and weak values.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsmaptable/strongtoweakobjects()).
+    #[inline]
+    #[doc(alias = "strongToWeakObjectsMapTable")]
+    pub fn strong_to_weak() -> Arc<Self> {
+        use NSPointerFunctionsOptions as Options;
+        Self::with_options(Options::STRONG_MEMORY, Options::WEAK_MEMORY, 0)
+    }
+
+    /// Creates an empty table with weak keys and strong values.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsmaptable/weaktostrongobjects()).
+    #[inline]
+    #[doc(alias = "weakToStrongObjectsMapTable")]
+    pub fn weak_to_strong() -> Arc<Self> {
+        use NSPointerFunctionsOptions as Options;
+        Self::with_options(Options::WEAK_MEMORY, Options::STRONG_MEMORY, 0)
+    }
+
+    /// Creates an empty table with weak keys and weak values.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsmaptable/weaktoweakobjects()).
+    #[inline]
+    #[doc(alias = "weakToWeakObjectsMapTable")]
+    pub fn weak_to_weak() -> Arc<Self> {
+        use NSPointerFunctionsOptions as Options;
+        Self::with_options(Options::WEAK_MEMORY, Options::WEAK_MEMORY, 0)
+    }
+
+    /// Creates an empty table with the given key and value options and
+    /// initial capacity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either `key_options` or `value_options` describe anything
+    /// other than strongly or weakly held objects.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsmaptable/init(keyoptions:valueoptions:capacity:)).
+    #[inline]
+    #[doc(alias = "initWithKeyOptions:valueOptions:capacity:")]
+    pub fn with_options(
+        key_options: NSMapTableOptions,
+        value_options: NSMapTableOptions,
+        capacity: usize,
+    ) -> Arc<Self> {
+        assert!(
+            key_options.is_object_storage() && value_options.is_object_storage(),
+            "NSMapTable can only hold strong or weak objects, not {:?} and {:?}",
+            key_options,
+            value_options,
+        );
+
+        unsafe {
+            let value: Arc<Self> = Self::class().alloc();
+
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(
+                    obj: Arc<NSMapTable>,
+                    sel: Sel,
+                    key_options: NSMapTableOptions,
+                    value_options: NSMapTableOptions,
+                    capacity: NSUInteger,
+                ) -> Arc<NSMapTable>;
+            }
+
+            objc_msgSend(
+                value,
+                selector!(initWithKeyOptions:valueOptions:capacity:),
+                key_options,
+                value_options,
+                capacity,
+            )
+        }
+    }
+
+    /// Returns the number of entries in the table.
+    ///
+    /// Entries with a weakly-held key or value that was deallocated may still
+    /// be counted until the table is next mutated.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsmaptable/count).
+    #[inline]
+    #[doc(alias = "count")]
+    pub fn len(&self) -> usize {
+        unsafe { _msg_send_any_cached![self, count => NSUInteger] }
+    }
+
+    /// Returns `true` if the table has no entries.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the value for `key`, or [`None`] if there is none.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsmaptable/object(forkey:)).
+    #[inline]
+    #[doc(alias = "objectForKey")]
+    #[doc(alias = "objectForKey:")]
+    pub fn get(&self, key: &NSObject) -> Option<Arc<NSObject<'static>>> {
+        unsafe {
+            let value = _msg_send_any![self, objectForKey: key => *const NSObject<'static>];
+            if value.is_null() {
+                None
+            } else {
+                Some(Arc::retain_raw(value))
+            }
+        }
+    }
+
+    /// Returns `true` if the table has a value for `key`.
+    #[inline]
+    pub fn contains_key(&self, key: &NSObject) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Sets the value for `key`, replacing any existing value.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsmaptable/setobject(_:forkey:)).
+    #[inline]
+    #[doc(alias = "setObject:forKey:")]
+    pub fn insert(&self, key: &NSObject, value: &NSObject) {
+        unsafe { _msg_send_any![self, setObject: value forKey: key] }
+    }
+
+    /// Removes the value for `key`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsmaptable/removeobject(forkey:)).
+    #[inline]
+    #[doc(alias = "removeObjectForKey")]
+    #[doc(alias = "removeObjectForKey:")]
+    pub fn remove(&self, key: &NSObject) {
+        unsafe { _msg_send_any![self, removeObjectForKey: key] }
+    }
+
+    /// Removes all entries from the table.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsmaptable/removeallobjects()).
+    #[inline]
+    #[doc(alias = "removeAllObjects")]
+    pub fn clear(&self) {
+        unsafe { _msg_send_any![self, removeAllObjects] }
+    }
+
+    /// Returns an enumerator over the keys in the table.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsmaptable/keyenumerator()).
+    #[inline]
+    #[doc(alias = "keyEnumerator")]
+    pub fn key_enumerator(&self) -> Arc<NSEnumerator> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, keyEnumerator => *const NSEnumerator]) }
+    }
+
+    /// Returns an enumerator over the values in the table.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsmaptable/objectenumerator()).
+    #[inline]
+    #[doc(alias = "objectEnumerator")]
+    pub fn object_enumerator(&self) -> Arc<NSEnumerator> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, objectEnumerator => *const NSEnumerator]) }
+    }
+}
@@ -0,0 +1,101 @@
+use super::{NSNotification, NSNotificationName};
+use crate::core::Arc;
+use crate::objc::{Block, ClassType, NSObject, RcBlock};
+use std::{fmt, ptr};
+
+objc_subclass! {
+    /// Broadcasts [`NSNotification`]s to registered observers.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/notificationcenter).
+    pub class NSNotificationCenter: NSObject<'static>;
+}
+
+impl NSNotificationCenter {
+    /// Returns the app's default notification center.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/notificationcenter/default).
+    #[inline]
+    #[doc(alias = "defaultCenter")]
+    #[doc(alias = "default_center")]
+    #[allow(clippy::should_implement_trait)]
+    pub fn default() -> &'static NSNotificationCenter {
+        unsafe { _msg_send_any![Self::class(), defaultCenter] }
+    }
+
+    /// Calls `block` for each notification named `name` posted by `object`,
+    /// until the returned observer is dropped.
+    ///
+    /// Passing [`None`] for `name` or `object` matches any name or sender.
+    /// `block` is called synchronously on the thread that posts the
+    /// notification, so it may be called on several threads at once, or
+    /// within itself if it posts a matching notification.
+    ///
+    /// It is safe to panic within `block`. Panics will abort the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/notificationcenter/addobserver(forname:object:queue:using:)).
+    #[doc(alias = "addObserverForName:object:queue:usingBlock:")]
+    #[doc(alias = "add_observer_for_name")]
+    pub fn add_observer<F>(
+        &self,
+        name: Option<&NSNotificationName>,
+        object: Option<&NSObject>,
+        block: F,
+    ) -> NSNotificationObserver
+    where
+        F: Fn(&NSNotification) + Send + Sync + 'static,
+    {
+        let block = RcBlock::new(move |notification: *const NSNotification| {
+            block(unsafe { &*notification });
+        });
+        let block: &Block<_, ()> = &block;
+
+        let token = unsafe {
+            Arc::retain_raw(_msg_send_any![
+                self,
+                addObserverForName: name
+                object: object
+                queue: ptr::null::<NSObject>()
+                usingBlock: block
+                => *const NSObject<'static>
+            ])
+        };
+
+        NSNotificationObserver {
+            center: Arc::retain(self),
+            token,
+        }
+    }
+
+    /// Posts a notification named `name` from `object` to observers of
+    /// `self`, returning once they have all been called.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/notificationcenter/post(name:object:)).
+    #[inline]
+    #[doc(alias = "postNotificationName:object:")]
+    pub fn post(&self, name: &NSNotificationName, object: Option<&NSObject>) {
+        unsafe { _msg_send_any![self, postNotificationName: name object: object => ()] }
+    }
+}
+
+/// A registration created by [`NSNotificationCenter::add_observer`], which
+/// is removed when dropped.
+#[must_use = "The observer is immediately removed if unused"]
+pub struct NSNotificationObserver {
+    center: Arc<NSNotificationCenter>,
+    token: Arc<NSObject<'static>>,
+}
+
+impl fmt::Debug for NSNotificationObserver {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("NSNotificationObserver").finish()
+    }
+}
+
+impl Drop for NSNotificationObserver {
+    #[inline]
+    #[doc(alias = "removeObserver:")]
+    fn drop(&mut self) {
+        let token: &NSObject = &self.token;
+        unsafe { _msg_send_any![&*self.center, removeObserver: token] }
+    }
+}
@@ -0,0 +1,65 @@
+use super::{NSDictionary, NSString};
+use crate::core::Arc;
+use crate::objc::NSObject;
+use std::fmt;
+
+mod center;
+mod name;
+
+pub use center::*;
+pub use name::*;
+
+objc_subclass! {
+    /// Information broadcast to observers through an
+    /// [`NSNotificationCenter`].
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsnotification).
+    pub class NSNotification: NSObject<'static>;
+}
+
+impl fmt::Debug for NSNotification {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("NSNotification")
+            .field("name", &self.name())
+            .finish()
+    }
+}
+
+impl NSNotification {
+    /// Returns the name of `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsnotification/name-swift.property).
+    #[inline]
+    pub fn name(&self) -> &NSNotificationName {
+        unsafe { _msg_send_any![self, name] }
+    }
+
+    /// Returns the object that posted `self`, if any.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsnotification/object).
+    #[inline]
+    pub fn object(&self) -> Option<Arc<NSObject<'static>>> {
+        unsafe {
+            _msg_send_any![self, object => *const NSObject<'static>]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Returns information about `self`, whose keys depend on its
+    /// [`name`](Self::name).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsnotification/userinfo).
+    #[inline]
+    #[doc(alias = "userInfo")]
+    pub fn user_info(&self) -> Option<Arc<NSDictionary<NSString<'static>, NSObject<'static>>>> {
+        unsafe {
+            _msg_send_any![
+                self,
+                userInfo => *const NSDictionary<NSString<'static>, NSObject<'static>>
+            ]
+            .as_ref()
+            .map(Arc::retain)
+        }
+    }
+}
@@ -0,0 +1,364 @@
+ns_string_wrapper! {
+    /// The name of an [`NSNotification`](super::NSNotification).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsnotification/name).
+    #[derive(Ord, PartialOrd, Eq, PartialEq)]
+    pub wrapper NSNotificationName;
+}
+
+macro_rules! name {
+    (
+        $(#[$docs:meta])+
+        $fn:ident $value:literal
+    ) => {
+        $(#[$docs])+
+        #[inline]
+        #[doc(alias = $value)]
+        pub fn $fn() -> &'static NSNotificationName {
+            extern "C" {
+                #[link_name = $value]
+                static VALUE: &'static NSNotificationName;
+            }
+            unsafe { VALUE }
+        }
+    };
+}
+
+/// System notifications.
+///
+/// These are posted to the
+/// [default center](super::NSNotificationCenter::default).
+impl NSNotificationName {
+    name! {
+        /// Posted when the system clock is changed, such as by the user or a
+        /// network time update.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/foundation/nsnotification/name-swift.struct/nssystemclockdidchange).
+        system_clock_did_change "NSSystemClockDidChangeNotification"
+    }
+
+    name! {
+        /// Posted when the system time zone changes.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/foundation/nsnotification/name-swift.struct/nssystemtimezonedidchange).
+        system_time_zone_did_change "NSSystemTimeZoneDidChangeNotification"
+    }
+
+    name! {
+        /// Posted when the user's locale changes.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/foundation/nslocale/currentlocaledidchangenotification).
+        current_locale_did_change "NSCurrentLocaleDidChangeNotification"
+    }
+
+    name! {
+        /// Posted when the thermal state of the system changes.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/foundation/processinfo/thermalstatedidchangenotification).
+        process_info_thermal_state_did_change "NSProcessInfoThermalStateDidChangeNotification"
+    }
+
+    name! {
+        /// Posted when Low Power Mode is turned on or off.
+        ///
+        /// This is only posted since macOS 12 and iOS 9.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/foundation/nsnotification/name-swift.struct/nsprocessinfopowerstatedidchange).
+        process_info_power_state_did_change "NSProcessInfoPowerStateDidChangeNotification"
+    }
+}
+
+/// [`NSApplication`](https://developer.apple.com/documentation/appkit/nsapplication)
+/// notifications.
+///
+/// Requires the **`app_kit`** feature flag.
+#[cfg(all(feature = "app_kit", target_os = "macos"))]
+impl NSNotificationName {
+    name! {
+        /// Posted when the app has launched and initialized.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/appkit/nsapplication/didfinishlaunchingnotification).
+        application_did_finish_launching "NSApplicationDidFinishLaunchingNotification"
+    }
+
+    name! {
+        /// Posted when the app is about to terminate.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/appkit/nsapplication/willterminatenotification).
+        application_will_terminate "NSApplicationWillTerminateNotification"
+    }
+
+    name! {
+        /// Posted when the app becomes the active app.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/appkit/nsapplication/didbecomeactivenotification).
+        application_did_become_active "NSApplicationDidBecomeActiveNotification"
+    }
+
+    name! {
+        /// Posted when the app stops being the active app.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/appkit/nsapplication/didresignactivenotification).
+        application_did_resign_active "NSApplicationDidResignActiveNotification"
+    }
+
+    name! {
+        /// Posted when the app is hidden.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/appkit/nsapplication/didhidenotification).
+        application_did_hide "NSApplicationDidHideNotification"
+    }
+
+    name! {
+        /// Posted when the app is made visible after being hidden.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/appkit/nsapplication/didunhidenotification).
+        application_did_unhide "NSApplicationDidUnhideNotification"
+    }
+
+    name! {
+        /// Posted when displays are added, removed, or change resolution.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/appkit/nsapplication/didchangescreenparametersnotification).
+        application_did_change_screen_parameters "NSApplicationDidChangeScreenParametersNotification"
+    }
+}
+
+/// [`NSScreen`](crate::app_kit::NSScreen) notifications.
+///
+/// Requires the **`app_kit`** feature flag.
+#[cfg(all(feature = "app_kit", any(target_os = "macos", mac_catalyst)))]
+impl NSNotificationName {
+    name! {
+        /// Posted with the screen whose color space changed, such as when the
+        /// user picks a different display profile.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/appkit/nsscreen/colorspacedidchangenotification).
+        screen_color_space_did_change "NSScreenColorSpaceDidChangeNotification"
+    }
+}
+
+/// [`NSWindow`](crate::app_kit::NSWindow) notifications.
+///
+/// These are posted with the window as the object.
+///
+/// Requires the **`app_kit`** feature flag.
+#[cfg(all(feature = "app_kit", any(target_os = "macos", mac_catalyst)))]
+impl NSNotificationName {
+    name! {
+        /// Posted when the window becomes more or less visible, such as when
+        /// it is covered by another window or moved to another space.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/appkit/nswindow/didchangeocclusionstatenotification).
+        window_did_change_occlusion_state "NSWindowDidChangeOcclusionStateNotification"
+    }
+
+    name! {
+        /// Posted when the window starts receiving keyboard input.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/appkit/nswindow/didbecomekeynotification).
+        window_did_become_key "NSWindowDidBecomeKeyNotification"
+    }
+
+    name! {
+        /// Posted when the window stops receiving keyboard input.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/appkit/nswindow/didresignkeynotification).
+        window_did_resign_key "NSWindowDidResignKeyNotification"
+    }
+
+    name! {
+        /// Posted when the window becomes the main window of the app.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/appkit/nswindow/didbecomemainnotification).
+        window_did_become_main "NSWindowDidBecomeMainNotification"
+    }
+
+    name! {
+        /// Posted when the window stops being the main window of the app.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/appkit/nswindow/didresignmainnotification).
+        window_did_resign_main "NSWindowDidResignMainNotification"
+    }
+
+    name! {
+        /// Posted when the window is minimized to the Dock.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/appkit/nswindow/didminiaturizenotification).
+        window_did_miniaturize "NSWindowDidMiniaturizeNotification"
+    }
+
+    name! {
+        /// Posted when the window is restored from the Dock.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/appkit/nswindow/diddeminiaturizenotification).
+        window_did_deminiaturize "NSWindowDidDeminiaturizeNotification"
+    }
+}
+
+/// [`UIApplication`](https://developer.apple.com/documentation/uikit/uiapplication)
+/// notifications.
+///
+/// Requires the **`ui_kit`** feature flag.
+#[cfg(all(feature = "ui_kit", not(target_os = "macos")))]
+impl NSNotificationName {
+    name! {
+        /// Posted when the app has launched.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/uikit/uiapplication/didfinishlaunchingnotification).
+        application_did_finish_launching "UIApplicationDidFinishLaunchingNotification"
+    }
+
+    name! {
+        /// Posted when the app becomes active.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/uikit/uiapplication/didbecomeactivenotification).
+        application_did_become_active "UIApplicationDidBecomeActiveNotification"
+    }
+
+    name! {
+        /// Posted when the app is about to become inactive, such as when a call
+        /// comes in.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/uikit/uiapplication/willresignactivenotification).
+        application_will_resign_active "UIApplicationWillResignActiveNotification"
+    }
+
+    name! {
+        /// Posted when the app enters the background.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/uikit/uiapplication/didenterbackgroundnotification).
+        application_did_enter_background "UIApplicationDidEnterBackgroundNotification"
+    }
+
+    name! {
+        /// Posted when the app is about to leave the background.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/uikit/uiapplication/willenterforegroundnotification).
+        application_will_enter_foreground "UIApplicationWillEnterForegroundNotification"
+    }
+
+    name! {
+        /// Posted when the app is about to terminate.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/uikit/uiapplication/willterminatenotification).
+        application_will_terminate "UIApplicationWillTerminateNotification"
+    }
+
+    name! {
+        /// Posted when the app receives a low memory warning.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/uikit/uiapplication/didreceivememorywarningnotification).
+        application_did_receive_memory_warning "UIApplicationDidReceiveMemoryWarningNotification"
+    }
+}
+
+/// [`NSWorkspace`](crate::app_kit::NSWorkspace) notifications.
+///
+/// These are posted to the workspace's
+/// [notification center](crate::app_kit::NSWorkspace::notification_center),
+/// not the [default center](super::NSNotificationCenter::default).
+///
+/// Requires the **`app_kit`** feature flag.
+#[cfg(all(feature = "app_kit", any(target_os = "macos", mac_catalyst)))]
+impl NSNotificationName {
+    name! {
+        /// Posted before the system goes to sleep.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/appkit/nsworkspace/willsleepnotification).
+        workspace_will_sleep "NSWorkspaceWillSleepNotification"
+    }
+
+    name! {
+        /// Posted after the system wakes from sleep.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/appkit/nsworkspace/didwakenotification).
+        workspace_did_wake "NSWorkspaceDidWakeNotification"
+    }
+
+    name! {
+        /// Posted when the displays go to sleep.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/appkit/nsworkspace/screensdidsleepnotification).
+        workspace_screens_did_sleep "NSWorkspaceScreensDidSleepNotification"
+    }
+
+    name! {
+        /// Posted when the displays wake from sleep.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/appkit/nsworkspace/screensdidwakenotification).
+        workspace_screens_did_wake "NSWorkspaceScreensDidWakeNotification"
+    }
+
+    name! {
+        /// Posted when the user's session becomes active, such as after fast
+        /// user switching back to it.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/appkit/nsworkspace/sessiondidbecomeactivenotification).
+        workspace_session_did_become_active "NSWorkspaceSessionDidBecomeActiveNotification"
+    }
+
+    name! {
+        /// Posted when the user's session becomes inactive, such as when
+        /// switching to another user.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/appkit/nsworkspace/sessiondidresignactivenotification).
+        workspace_session_did_resign_active "NSWorkspaceSessionDidResignActiveNotification"
+    }
+
+    name! {
+        /// Posted when the user requests a logout or power off.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/appkit/nsworkspace/willpoweroffnotification).
+        workspace_will_power_off "NSWorkspaceWillPowerOffNotification"
+    }
+
+    name! {
+        /// Posted when an app finishes launching.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/appkit/nsworkspace/didlaunchapplicationnotification).
+        workspace_did_launch_application "NSWorkspaceDidLaunchApplicationNotification"
+    }
+
+    name! {
+        /// Posted when an app terminates.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/appkit/nsworkspace/didterminateapplicationnotification).
+        workspace_did_terminate_application "NSWorkspaceDidTerminateApplicationNotification"
+    }
+
+    name! {
+        /// Posted when an app becomes the active app.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/appkit/nsworkspace/didactivateapplicationnotification).
+        workspace_did_activate_application "NSWorkspaceDidActivateApplicationNotification"
+    }
+
+    name! {
+        /// Posted when an app stops being the active app.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/appkit/nsworkspace/diddeactivateapplicationnotification).
+        workspace_did_deactivate_application "NSWorkspaceDidDeactivateApplicationNotification"
+    }
+
+    name! {
+        /// Posted when a volume is mounted.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/appkit/nsworkspace/didmountnotification).
+        workspace_did_mount "NSWorkspaceDidMountNotification"
+    }
+
+    name! {
+        /// Posted when a volume is unmounted.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/appkit/nsworkspace/didunmountnotification).
+        workspace_did_unmount "NSWorkspaceDidUnmountNotification"
+    }
+
+    name! {
+        /// Posted when the active Space changes.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/appkit/nsworkspace/activespacedidchangenotification).
+        workspace_active_space_did_change "NSWorkspaceActiveSpaceDidChangeNotification"
+    }
+}
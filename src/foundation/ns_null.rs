@@ -29,4 +29,14 @@ impl NSNull {
         }
         unsafe { kCFNull }
     }
+
+    /// Returns `true` if `object` is the [`NSNull`] singleton, regardless of
+    /// the type it is known as.
+    ///
+    /// Containers deserialized from JSON or property lists use it in place of
+    /// `nil`, so elements typed as `T` may be it instead.
+    #[inline]
+    pub fn is_null<T>(object: &T) -> bool {
+        object as *const T as *const Self == Self::null()
+    }
 }
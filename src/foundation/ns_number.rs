@@ -169,6 +169,55 @@ impl fmt::Display for NSNumber {
     }
 }
 
+/// Numbers are serialized with the same type they are formatted with.
+#[cfg(feature = "serde")]
+impl serde::Serialize for NSNumber {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self._cfboolean_value() {
+            Some(value) => serializer.serialize_bool(value),
+            None => match self.objc_type_single() as u8 {
+                b'f' => serializer.serialize_f32(self.float_value()),
+                b'd' => serializer.serialize_f64(self.double_value()),
+                b'c' | b'i' | b's' | b'l' | b'q' => serializer.serialize_i64(self.longlong_value()),
+                _ => serializer.serialize_u64(self.unsigned_longlong_value()),
+            },
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Arc<NSNumber> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct NumberVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for NumberVisitor {
+            type Value = Arc<NSNumber>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a number or a boolean")
+            }
+
+            fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E> {
+                Ok(NSNumber::from_bool(v))
+            }
+
+            fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E> {
+                Ok(NSNumber::from_longlong(v))
+            }
+
+            fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E> {
+                Ok(NSNumber::from_unsigned_longlong(v))
+            }
+
+            fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E> {
+                Ok(NSNumber::from_double(v))
+            }
+        }
+
+        deserializer.deserialize_any(NumberVisitor)
+    }
+}
+
 /// Scalar constructors.
 impl NSNumber {
     // TODO: Add constructors:
@@ -0,0 +1,229 @@
+use super::{NSArray, NSNotFound};
+use crate::core::{Arc, ObjectType};
+use crate::objc::{Class, ClassType, NSInteger, NSObject, NSUInteger, ObjCObject, BOOL};
+use std::{fmt, marker::PhantomData, ops::Deref, ptr::NonNull};
+
+/// The insertions and removals that turn one ordered collection of objects
+/// of type `T` into another, such as when animating updates to a table view.
+///
+/// Differences are created with [`NSArray::difference_from`] and applied with
+/// [`NSArray::applying_difference`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use fruity::foundation::{NSArray, NSCollectionChangeType, NSString};
+///
+/// let a = NSString::from_str("a");
+/// let b = NSString::from_str("b");
+/// let c = NSString::from_str("c");
+///
+/// let old = NSArray::from_slice(&[&*a, &*b, &*c]);
+/// let new = NSArray::from_slice(&[&*c, &*a, &*b]);
+///
+/// let difference = new.difference_from(&old).inferring_moves();
+/// for change in difference.removals() {
+///     assert_eq!(change.kind, NSCollectionChangeType::REMOVE);
+///     // Remove the row at `change.index`.
+/// }
+/// for change in difference.insertions() {
+///     // Insert a row at `change.index`, or move it from
+///     // `change.associated_index`.
+/// }
+///
+/// assert_eq!(old.applying_difference(&difference).as_deref(), Some(&*new));
+/// ```
+///
+/// See [documentation](https://developer.apple.com/documentation/foundation/nsorderedcollectiondifference).
+#[repr(C)]
+pub struct NSOrderedCollectionDifference<T = NSObject<'static>> {
+    base: NSObject<'static>,
+    _marker: PhantomData<*const T>,
+}
+
+impl<T> ObjectType for NSOrderedCollectionDifference<T> {
+    #[inline]
+    fn retain(obj: &Self) -> Arc<Self> {
+        let obj = Arc::retain(&obj.base);
+        unsafe { Arc::cast_unchecked(obj) }
+    }
+
+    #[inline]
+    unsafe fn release(obj: NonNull<Self>) {
+        NSObject::release(obj.cast());
+    }
+}
+
+impl<T: 'static> crate::objc::ObjectType<'static> for NSOrderedCollectionDifference<T> {}
+
+impl<T: 'static> ClassType<'static> for NSOrderedCollectionDifference<T> {
+    #[inline]
+    fn class() -> &'static Class {
+        crate::_objc_class!(@ "OBJC_CLASS_$_NSOrderedCollectionDifference")
+    }
+}
+
+impl<T> Deref for NSOrderedCollectionDifference<T> {
+    type Target = NSObject<'static>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<T> AsRef<ObjCObject<'static>> for NSOrderedCollectionDifference<T> {
+    #[inline]
+    fn as_ref(&self) -> &ObjCObject<'static> {
+        self.base.as_ref()
+    }
+}
+
+impl<T: ObjectType + fmt::Debug + 'static> fmt::Debug for NSOrderedCollectionDifference<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("NSOrderedCollectionDifference")
+            .field("removals", &self.removals())
+            .field("insertions", &self.insertions())
+            .finish()
+    }
+}
+
+impl<T> NSOrderedCollectionDifference<T> {
+    /// Returns `true` if there are any insertions or removals.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsorderedcollectiondifference/haschanges).
+    #[inline]
+    #[doc(alias = "hasChanges")]
+    pub fn has_changes(&self) -> bool {
+        unsafe { _msg_send_any![self, hasChanges => BOOL] }.into()
+    }
+}
+
+impl<T: ObjectType + 'static> NSOrderedCollectionDifference<T> {
+    /// Returns the insertions, in ascending order of the index in the new
+    /// collection.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsorderedcollectiondifference/insertions).
+    #[inline]
+    pub fn insertions(&self) -> Vec<NSOrderedCollectionChange<T>> {
+        unsafe { Self::read_changes(&*_msg_send_any![self, insertions => *const NSArray]) }
+    }
+
+    /// Returns the removals, in ascending order of the index in the old
+    /// collection.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsorderedcollectiondifference/removals).
+    #[inline]
+    pub fn removals(&self) -> Vec<NSOrderedCollectionChange<T>> {
+        unsafe { Self::read_changes(&*_msg_send_any![self, removals => *const NSArray]) }
+    }
+
+    /// Returns a difference that pairs removals and insertions of equal
+    /// objects as moves, by setting their
+    /// [`associated_index`](NSOrderedCollectionChange::associated_index).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsorderedcollectiondifference/inferringmoves()).
+    #[inline]
+    #[doc(alias = "differenceByInferringMoves")]
+    pub fn inferring_moves(&self) -> Arc<Self> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, differenceByInferringMoves => *const Self]) }
+    }
+
+    /// Returns the difference that undoes `self`, with insertions and
+    /// removals swapped.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsorderedcollectiondifference/inverse()).
+    #[inline]
+    #[doc(alias = "inverseDifference")]
+    pub fn inverse(&self) -> Arc<Self> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, inverseDifference => *const Self]) }
+    }
+
+    unsafe fn read_changes(changes: &NSArray) -> Vec<NSOrderedCollectionChange<T>> {
+        changes
+            .iter()
+            .map(|change| NSOrderedCollectionChange::read(change))
+            .collect()
+    }
+}
+
+/// An insertion or removal in an [`NSOrderedCollectionDifference`].
+///
+/// See [documentation](https://developer.apple.com/documentation/foundation/nsorderedcollectionchange).
+pub struct NSOrderedCollectionChange<T: ObjectType> {
+    /// The object that is inserted or removed, or [`None`] if the difference
+    /// was created without objects.
+    pub object: Option<Arc<T>>,
+
+    /// Whether the object is inserted or removed.
+    pub kind: NSCollectionChangeType,
+
+    /// The index in the new collection of an insertion, or in the old
+    /// collection of a removal.
+    pub index: usize,
+
+    /// The index of the matching removal of an insertion or insertion of a
+    /// removal, if this change is part of a move.
+    pub associated_index: Option<usize>,
+}
+
+impl<T: ObjectType + fmt::Debug> fmt::Debug for NSOrderedCollectionChange<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("NSOrderedCollectionChange")
+            .field("object", &self.object.as_deref())
+            .field("kind", &self.kind)
+            .field("index", &self.index)
+            .field("associated_index", &self.associated_index)
+            .finish()
+    }
+}
+
+impl<T: ObjectType> Clone for NSOrderedCollectionChange<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            object: self.object.clone(),
+            kind: self.kind,
+            index: self.index,
+            associated_index: self.associated_index,
+        }
+    }
+}
+
+impl<T: ObjectType + 'static> NSOrderedCollectionChange<T> {
+    /// Reads the properties of an `NSOrderedCollectionChange` object.
+    unsafe fn read(change: &NSObject) -> Self {
+        let object = _msg_send_any![change, object => *const T];
+        let kind = _msg_send_any![change, changeType => NSCollectionChangeType];
+        let index = _msg_send_any![change, index => NSUInteger];
+        let associated_index = _msg_send_any![change, associatedIndex => NSUInteger];
+
+        Self {
+            object: object.as_ref().map(Arc::retain),
+            kind,
+            index,
+            associated_index: if associated_index == NSNotFound as NSUInteger {
+                None
+            } else {
+                Some(associated_index)
+            },
+        }
+    }
+}
+
+/// Whether an [`NSOrderedCollectionChange`] inserts or removes an object.
+///
+/// See [documentation](https://developer.apple.com/documentation/foundation/nscollectionchangetype).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NSCollectionChangeType(pub NSInteger);
+
+impl NSCollectionChangeType {
+    /// The object is inserted.
+    #[doc(alias = "NSCollectionChangeInsert")]
+    pub const INSERT: Self = Self(0);
+
+    /// The object is removed.
+    #[doc(alias = "NSCollectionChangeRemove")]
+    pub const REMOVE: Self = Self(1);
+}
@@ -0,0 +1,229 @@
+use super::NSPointerFunctionsOptions;
+use crate::core::Arc;
+use crate::objc::{ClassType, NSObject, NSUInteger, Sel};
+use std::ptr;
+
+objc_subclass! {
+    /// A mutable collection modeled after `NSArray` that can hold `nil` and
+    /// weak references.
+    ///
+    /// Unlike the Objective-C class, this only stores objects. Entries may be
+    /// empty, either because `nil` was stored or because a weakly-held object
+    /// was deallocated.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nspointerarray).
+    pub class NSPointerArray: NSObject<'static>;
+}
+
+impl NSPointerArray {
+    /// Creates an empty array that holds strong references to its objects.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nspointerarray/strongobjects()).
+    #[inline]
+    #[doc(alias = "strongObjectsPointerArray")]
+    pub fn strong() -> Arc<Self> {
+        Self::with_options(NSPointerFunctionsOptions::STRONG_MEMORY)
+    }
+
+    /// Creates an empty array that holds zeroing weak references to its
+    /// objects.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nspointerarray/weakobjects()).
+    #[inline]
+    #[doc(alias = "weakObjectsPointerArray")]
+    pub fn weak() -> Arc<Self> {
+        Self::with_options(NSPointerFunctionsOptions::WEAK_MEMORY)
+    }
+
+    /// Creates an empty array with the given memory and personality options.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `options` describe anything other than strongly or weakly
+    /// held objects.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nspointerarray/init(options:)).
+    #[inline]
+    #[doc(alias = "initWithOptions")]
+    #[doc(alias = "initWithOptions:")]
+    pub fn with_options(options: NSPointerFunctionsOptions) -> Arc<Self> {
+        assert!(
+            options.is_object_storage(),
+            "NSPointerArray can only hold strong or weak objects, not {:?}",
+            options,
+        );
+
+        unsafe {
+            let value: Arc<Self> = Self::class().alloc();
+
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(
+                    obj: Arc<NSPointerArray>,
+                    sel: Sel,
+                    options: NSPointerFunctionsOptions,
+                ) -> Arc<NSPointerArray>;
+            }
+
+            objc_msgSend(value, selector!(initWithOptions:), options)
+        }
+    }
+
+    /// Returns the number of entries in the array, including empty ones.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nspointerarray/count).
+    #[inline]
+    #[doc(alias = "count")]
+    pub fn len(&self) -> usize {
+        unsafe { _msg_send_any_cached![self, count => NSUInteger] }
+    }
+
+    /// Returns `true` if the array has no entries.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Sets the number of entries in the array, adding empty entries or
+    /// removing entries from the end as needed.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nspointerarray/count).
+    #[inline]
+    #[doc(alias = "setCount")]
+    #[doc(alias = "setCount:")]
+    pub fn set_len(&self, len: usize) {
+        unsafe { _msg_send_any![self, setCount: len as NSUInteger] }
+    }
+
+    /// Returns the object at `index`, or [`None`] if the entry is empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nspointerarray/pointer(at:)).
+    #[inline]
+    #[track_caller]
+    #[doc(alias = "pointerAtIndex")]
+    #[doc(alias = "pointerAtIndex:")]
+    pub fn get(&self, index: usize) -> Option<Arc<NSObject<'static>>> {
+        check_index(index, self.len());
+        unsafe {
+            let obj = _msg_send_any_cached![
+                self,
+                pointerAtIndex: index as NSUInteger => *const NSObject<'static>
+            ];
+            if obj.is_null() {
+                None
+            } else {
+                Some(Arc::retain_raw(obj))
+            }
+        }
+    }
+
+    /// Appends an entry to the end of the array.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nspointerarray/addpointer(_:)).
+    #[inline]
+    #[doc(alias = "addPointer")]
+    #[doc(alias = "addPointer:")]
+    pub fn push(&self, obj: Option<&NSObject>) {
+        unsafe { _msg_send_any![self, addPointer: as_ptr(obj)] }
+    }
+
+    /// Inserts an entry at `index`, shifting later entries up.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than the length of the array.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nspointerarray/insertpointer(_:at:)).
+    #[inline]
+    #[track_caller]
+    #[doc(alias = "insertPointer:atIndex:")]
+    pub fn insert(&self, index: usize, obj: Option<&NSObject>) {
+        check_index(index, self.len() + 1);
+        unsafe {
+            _msg_send_any![
+                self,
+                insertPointer: as_ptr(obj)
+                atIndex: index as NSUInteger
+            ]
+        }
+    }
+
+    /// Replaces the entry at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nspointerarray/replacepointer(at:withpointer:)).
+    #[inline]
+    #[track_caller]
+    #[doc(alias = "replacePointerAtIndex:withPointer:")]
+    pub fn set(&self, index: usize, obj: Option<&NSObject>) {
+        check_index(index, self.len());
+        unsafe {
+            _msg_send_any![
+                self,
+                replacePointerAtIndex: index as NSUInteger
+                withPointer: as_ptr(obj)
+            ]
+        }
+    }
+
+    /// Removes the entry at `index`, shifting later entries down.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nspointerarray/removepointer(at:)).
+    #[inline]
+    #[track_caller]
+    #[doc(alias = "removePointerAtIndex")]
+    #[doc(alias = "removePointerAtIndex:")]
+    pub fn remove(&self, index: usize) {
+        check_index(index, self.len());
+        unsafe { _msg_send_any![self, removePointerAtIndex: index as NSUInteger] }
+    }
+
+    /// Removes empty entries from the array.
+    ///
+    /// Note that Foundation may skip entries whose weakly-held object was
+    /// deallocated unless a `nil` entry was explicitly stored beforehand.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nspointerarray/compact()).
+    #[inline]
+    pub fn compact(&self) {
+        unsafe { _msg_send_any![self, compact] }
+    }
+
+    /// Returns the objects in the array, skipping empty entries.
+    #[inline]
+    pub fn to_vec(&self) -> Vec<Arc<NSObject<'static>>> {
+        (0..self.len()).filter_map(|i| self.get(i)).collect()
+    }
+}
+
+#[inline]
+#[track_caller]
+fn check_index(index: usize, len: usize) {
+    // Out-of-bounds access raises an Objective-C exception, which must not
+    // unwind into Rust.
+    assert!(
+        index < len,
+        "index {} out of bounds for NSPointerArray of length {}",
+        index,
+        len,
+    );
+}
+
+#[inline]
+fn as_ptr(obj: Option<&NSObject>) -> *const NSObject<'static> {
+    match obj {
+        Some(obj) => (obj as *const NSObject).cast(),
+        None => ptr::null(),
+    }
+}
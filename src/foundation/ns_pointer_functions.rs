@@ -0,0 +1,103 @@
+use crate::objc::NSUInteger;
+use std::ops;
+
+/// Options that define the memory and personality behavior of the pointers
+/// stored in a [`NSPointerArray`](super::NSPointerArray),
+/// [`NSHashTable`](super::NSHashTable), or [`NSMapTable`](super::NSMapTable).
+///
+/// An option value combines at most one memory option, at most one
+/// personality option, and optionally [`COPY_IN`](Self::COPY_IN).
+///
+/// See [documentation](https://developer.apple.com/documentation/foundation/nspointerfunctions/options).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NSPointerFunctionsOptions(pub NSUInteger);
+
+impl ops::BitOr for NSPointerFunctionsOptions {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+/// Memory options.
+impl NSPointerFunctionsOptions {
+    /// Strong references to objects, using `retain` and `release`.
+    #[doc(alias = "NSPointerFunctionsStrongMemory")]
+    pub const STRONG_MEMORY: Self = Self(0);
+
+    /// No memory management of stored pointers.
+    #[doc(alias = "NSPointerFunctionsOpaqueMemory")]
+    pub const OPAQUE_MEMORY: Self = Self(2);
+
+    /// Stored pointers are freed with `free` and copied with `calloc`.
+    #[doc(alias = "NSPointerFunctionsMallocMemory")]
+    pub const MALLOC_MEMORY: Self = Self(3);
+
+    /// Stored pointers are managed with Mach virtual memory.
+    #[doc(alias = "NSPointerFunctionsMachVirtualMemory")]
+    pub const MACH_VIRTUAL_MEMORY: Self = Self(4);
+
+    /// Zeroing weak references to objects, which become `nil` when their
+    /// object is deallocated.
+    #[doc(alias = "NSPointerFunctionsWeakMemory")]
+    pub const WEAK_MEMORY: Self = Self(5);
+}
+
+/// Personality options.
+impl NSPointerFunctionsOptions {
+    /// Objects are hashed and compared with `hash` and `isEqual:`.
+    #[doc(alias = "NSPointerFunctionsObjectPersonality")]
+    pub const OBJECT_PERSONALITY: Self = Self(0);
+
+    /// Pointers are hashed and compared by address.
+    #[doc(alias = "NSPointerFunctionsOpaquePersonality")]
+    pub const OPAQUE_PERSONALITY: Self = Self(1 << 8);
+
+    /// Objects are hashed and compared by address.
+    #[doc(alias = "NSPointerFunctionsObjectPointerPersonality")]
+    pub const OBJECT_POINTER_PERSONALITY: Self = Self(2 << 8);
+
+    /// Pointers are C strings, hashed and compared as such.
+    #[doc(alias = "NSPointerFunctionsCStringPersonality")]
+    pub const C_STRING_PERSONALITY: Self = Self(3 << 8);
+
+    /// Pointers are structs, hashed and compared by their bytes.
+    #[doc(alias = "NSPointerFunctionsStructPersonality")]
+    pub const STRUCT_PERSONALITY: Self = Self(4 << 8);
+
+    /// Pointers are integers, hashed and compared by value.
+    #[doc(alias = "NSPointerFunctionsIntegerPersonality")]
+    pub const INTEGER_PERSONALITY: Self = Self(5 << 8);
+}
+
+impl NSPointerFunctionsOptions {
+    /// Copies values when they are added to the collection.
+    #[doc(alias = "NSPointerFunctionsCopyIn")]
+    pub const COPY_IN: Self = Self(1 << 16);
+
+    /// Returns `true` if these options only describe objects that are held
+    /// strongly or weakly, which is required by the safe constructors of
+    /// collections that store objects.
+    #[inline]
+    pub(crate) const fn is_object_storage(self) -> bool {
+        let memory = self.0 & 0xFF;
+        let personality = self.0 & 0xFF00;
+
+        (memory == Self::STRONG_MEMORY.0 || memory == Self::WEAK_MEMORY.0)
+            && (personality == Self::OBJECT_PERSONALITY.0
+                || personality == Self::OBJECT_POINTER_PERSONALITY.0)
+    }
+}
+
+/// Options for a [`NSHashTable`](super::NSHashTable).
+///
+/// See [documentation](https://developer.apple.com/documentation/foundation/nshashtableoptions).
+pub type NSHashTableOptions = NSPointerFunctionsOptions;
+
+/// Options for the keys or values of a [`NSMapTable`](super::NSMapTable).
+///
+/// See [documentation](https://developer.apple.com/documentation/foundation/nsmaptableoptions).
+pub type NSMapTableOptions = NSPointerFunctionsOptions;
@@ -0,0 +1,110 @@
+use super::{NSArray, NSString};
+use crate::core::Arc;
+use crate::objc::{ClassType, NSObject, BOOL};
+use std::fmt;
+
+objc_subclass! {
+    /// A logical condition for filtering objects, such as
+    /// `name BEGINSWITH 'A' AND age > 21`.
+    ///
+    /// Predicates are evaluated against key-value coding properties, and are
+    /// how fetch requests are filtered in Core Data.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nspredicate).
+    pub class NSPredicate: NSObject<'static>;
+}
+
+impl fmt::Debug for NSPredicate {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("NSPredicate")
+            .field(&self.predicate_format())
+            .finish()
+    }
+}
+
+impl NSPredicate {
+    /// Parses a predicate from `format`, substituting each `%@` placeholder
+    /// with the next object in `arguments`.
+    ///
+    /// Values should be passed as arguments rather than written into
+    /// `format`, so that they are quoted correctly.
+    ///
+    /// # Panics
+    ///
+    /// An invalid `format` raises an Objective-C exception, which aborts the
+    /// process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nspredicate/init(format:argumentarray:)).
+    #[inline]
+    #[doc(alias = "predicateWithFormat:argumentArray:")]
+    pub fn with_format(format: &NSString, arguments: Option<&NSArray>) -> Arc<Self> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![
+                Self::class(),
+                predicateWithFormat: format
+                argumentArray: arguments
+                => *const Self
+            ])
+        }
+    }
+
+    /// Returns a predicate that always evaluates to `value`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nspredicate/init(value:)).
+    #[inline]
+    #[doc(alias = "predicateWithValue:")]
+    pub fn from_value(value: bool) -> Arc<Self> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![
+                Self::class(),
+                predicateWithValue: BOOL::from(value)
+                => *const Self
+            ])
+        }
+    }
+
+    /// Parses a predicate from a Spotlight metadata query string, such as one
+    /// built by `fruity::core_services::MDQuery`.
+    ///
+    /// This returns `None` if `query` is not valid.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nspredicate/init(frommetadataquerystring:)).
+    #[cfg(target_os = "macos")]
+    #[cfg_attr(docsrs, doc(cfg(target_os = "macos")))]
+    #[inline]
+    #[doc(alias = "predicateFromMetadataQueryString:")]
+    pub fn from_metadata_query_string(query: &NSString) -> Option<Arc<Self>> {
+        unsafe {
+            _msg_send_any![
+                Self::class(),
+                predicateFromMetadataQueryString: query
+                => Option<&Self>
+            ]
+        }
+        .map(Arc::retain)
+    }
+
+    /// Returns `true` if `object` satisfies the condition of `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nspredicate/evaluate(with:)).
+    #[inline]
+    #[doc(alias = "evaluateWithObject:")]
+    pub fn evaluate(&self, object: Option<&NSObject>) -> bool {
+        unsafe { _msg_send_any![self, evaluateWithObject: object => BOOL] }.into()
+    }
+
+    /// Returns the format string of `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nspredicate/predicateformat).
+    #[inline]
+    #[doc(alias = "predicateFormat")]
+    pub fn predicate_format(&self) -> Arc<NSString<'static>> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![
+                self,
+                predicateFormat => *const NSString<'static>
+            ])
+        }
+    }
+}
@@ -0,0 +1,303 @@
+use super::{NSArray, NSDictionary, NSString, NSTimeInterval};
+use crate::core::Arc;
+use crate::objc::{ClassType, NSInteger, NSObject, BOOL};
+use std::fmt;
+
+objc_subclass! {
+    /// Information about the current process, such as its arguments and
+    /// environment, and about the system it is running on.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/processinfo).
+    pub class NSProcessInfo: NSObject<'static>;
+}
+
+impl NSProcessInfo {
+    /// Returns the information for the current process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/processinfo/processinfo).
+    #[inline]
+    #[doc(alias = "processInfo")]
+    pub fn current() -> &'static NSProcessInfo {
+        unsafe { _msg_send_any![Self::class(), processInfo] }
+    }
+
+    /// Returns the command-line arguments of the process, starting with the
+    /// path of the executable.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/processinfo/arguments).
+    #[inline]
+    pub fn arguments(&self) -> Arc<NSArray<NSString<'static>>> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![
+                self,
+                arguments => *const NSArray<NSString<'static>>
+            ])
+        }
+    }
+
+    /// Returns the environment variables of the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/processinfo/environment).
+    #[inline]
+    pub fn environment(&self) -> Arc<NSDictionary<NSString<'static>, NSString<'static>>> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![
+                self,
+                environment => *const NSDictionary<NSString<'static>, NSString<'static>>
+            ])
+        }
+    }
+
+    /// Returns the name of the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/processinfo/processname).
+    #[inline]
+    #[doc(alias = "processName")]
+    pub fn process_name(&self) -> Arc<NSString<'static>> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, processName => *const NSString<'static>]) }
+    }
+
+    /// Returns the identifier of the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/processinfo/processidentifier).
+    #[inline]
+    #[doc(alias = "processIdentifier")]
+    pub fn process_identifier(&self) -> i32 {
+        unsafe { _msg_send_any![self, processIdentifier] }
+    }
+
+    /// Returns a string that is unique across the network, such as for
+    /// naming temporary files.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/processinfo/globallyuniquestring).
+    #[inline]
+    #[doc(alias = "globallyUniqueString")]
+    pub fn globally_unique_string(&self) -> Arc<NSString<'static>> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![
+                self,
+                globallyUniqueString => *const NSString<'static>
+            ])
+        }
+    }
+
+    /// Returns the name of the host computer on the network.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/processinfo/hostname).
+    #[inline]
+    #[doc(alias = "hostName")]
+    pub fn host_name(&self) -> Arc<NSString<'static>> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, hostName => *const NSString<'static>]) }
+    }
+
+    /// Returns the version of the operating system.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/processinfo/operatingsystemversion).
+    #[inline]
+    #[doc(alias = "operatingSystemVersion")]
+    pub fn operating_system_version(&self) -> NSOperatingSystemVersion {
+        unsafe { _msg_send_any![self, operatingSystemVersion] }
+    }
+
+    /// Returns a human-readable description of the operating system version,
+    /// such as `Version 11.2 (Build 20D64)`.
+    ///
+    /// This is not suitable for parsing.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/processinfo/operatingsystemversionstring).
+    #[inline]
+    #[doc(alias = "operatingSystemVersionString")]
+    pub fn operating_system_version_string(&self) -> Arc<NSString<'static>> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![
+                self,
+                operatingSystemVersionString => *const NSString<'static>
+            ])
+        }
+    }
+
+    /// Returns `true` if the operating system version is `version` or
+    /// later.
+    ///
+    /// The [`os_version_at_least!`](crate::os_version_at_least) macro is a
+    /// shorthand for calling this on the [current](Self::current) process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/processinfo/isoperatingsystematleast(_:)).
+    #[inline]
+    #[doc(alias = "isOperatingSystemAtLeastVersion:")]
+    pub fn is_operating_system_at_least(&self, version: NSOperatingSystemVersion) -> bool {
+        unsafe { _msg_send_any![self, isOperatingSystemAtLeastVersion: version => BOOL] }.into()
+    }
+
+    /// Returns the number of processing cores.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/processinfo/processorcount).
+    #[inline]
+    #[doc(alias = "processorCount")]
+    pub fn processor_count(&self) -> usize {
+        unsafe { _msg_send_any![self, processorCount] }
+    }
+
+    /// Returns the number of processing cores that are enabled.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/processinfo/activeprocessorcount).
+    #[inline]
+    #[doc(alias = "activeProcessorCount")]
+    pub fn active_processor_count(&self) -> usize {
+        unsafe { _msg_send_any![self, activeProcessorCount] }
+    }
+
+    /// Returns the amount of physical memory in bytes.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/processinfo/physicalmemory).
+    #[inline]
+    #[doc(alias = "physicalMemory")]
+    pub fn physical_memory(&self) -> u64 {
+        unsafe { _msg_send_any![self, physicalMemory] }
+    }
+
+    /// Returns how long the system has been running since it last restarted.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/processinfo/systemuptime).
+    #[inline]
+    #[doc(alias = "systemUptime")]
+    pub fn system_uptime(&self) -> NSTimeInterval {
+        unsafe { _msg_send_any![self, systemUptime] }
+    }
+
+    /// Returns how hot the system is, which apps should respond to by doing
+    /// less work.
+    ///
+    /// Changes are posted as
+    /// [`process_info_thermal_state_did_change`](super::NSNotificationName::process_info_thermal_state_did_change)
+    /// notifications.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/processinfo/thermalstate-swift.property).
+    #[inline]
+    #[doc(alias = "thermalState")]
+    pub fn thermal_state(&self) -> NSProcessInfoThermalState {
+        unsafe { _msg_send_any![self, thermalState] }
+    }
+
+    /// Returns `true` if the user enabled Low Power Mode to extend battery
+    /// life, which apps should respond to by doing less work.
+    ///
+    /// This is always `false` before macOS 12 and iOS 9.
+    ///
+    /// Changes are posted as
+    /// [`process_info_power_state_did_change`](super::NSNotificationName::process_info_power_state_did_change)
+    /// notifications.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/processinfo/islowpowermodeenabled).
+    #[inline]
+    #[doc(alias = "isLowPowerModeEnabled")]
+    pub fn is_low_power_mode_enabled(&self) -> bool {
+        let available = if cfg!(target_os = "macos") {
+            self.is_operating_system_at_least(NSOperatingSystemVersion::new(12, 0, 0))
+        } else {
+            self.is_operating_system_at_least(NSOperatingSystemVersion::new(9, 0, 0))
+        };
+        available && unsafe { _msg_send_any![self, isLowPowerModeEnabled => BOOL] }.into()
+    }
+}
+
+/// A version of the operating system, such as 11.2.3.
+///
+/// Versions are ordered by major, then minor, then patch version.
+///
+/// See [documentation](https://developer.apple.com/documentation/foundation/operatingsystemversion).
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NSOperatingSystemVersion {
+    /// The major version, such as 11 in 11.2.3.
+    pub major_version: NSInteger,
+    /// The minor version, such as 2 in 11.2.3.
+    pub minor_version: NSInteger,
+    /// The patch version, such as 3 in 11.2.3.
+    pub patch_version: NSInteger,
+}
+
+impl fmt::Display for NSOperatingSystemVersion {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}.{}.{}",
+            self.major_version, self.minor_version, self.patch_version
+        )
+    }
+}
+
+impl NSOperatingSystemVersion {
+    /// Returns the version with the specified components.
+    #[inline]
+    pub const fn new(major: NSInteger, minor: NSInteger, patch: NSInteger) -> Self {
+        Self {
+            major_version: major,
+            minor_version: minor,
+            patch_version: patch,
+        }
+    }
+
+    /// Returns the version of the running operating system.
+    #[inline]
+    pub fn current() -> Self {
+        NSProcessInfo::current().operating_system_version()
+    }
+}
+
+/// Returns `true` if the running operating system version is at least
+/// `major.minor.patch`, where `minor` and `patch` default to 0.
+///
+/// This allows for calling APIs that are not available on all versions that
+/// a binary supports, which would otherwise fail or crash.
+///
+/// Requires the **`foundation`** feature flag.
+///
+/// # Examples
+///
+/// ```
+/// if fruity::os_version_at_least!(11, 0) {
+///     // Use APIs introduced in macOS 11.
+/// }
+/// ```
+#[macro_export]
+macro_rules! os_version_at_least {
+    ($major:expr) => {
+        $crate::os_version_at_least!($major, 0, 0)
+    };
+    ($major:expr, $minor:expr) => {
+        $crate::os_version_at_least!($major, $minor, 0)
+    };
+    ($major:expr, $minor:expr, $patch:expr) => {
+        $crate::foundation::NSProcessInfo::current().is_operating_system_at_least(
+            $crate::foundation::NSOperatingSystemVersion::new($major, $minor, $patch),
+        )
+    };
+}
+
+/// How hot the system is.
+///
+/// See [documentation](https://developer.apple.com/documentation/foundation/processinfo/thermalstate-swift.enum).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NSProcessInfoThermalState(pub NSInteger);
+
+impl NSProcessInfoThermalState {
+    /// The temperature is normal.
+    #[doc(alias = "NSProcessInfoThermalStateNominal")]
+    pub const NOMINAL: Self = Self(0);
+
+    /// The temperature is slightly elevated.
+    #[doc(alias = "NSProcessInfoThermalStateFair")]
+    pub const FAIR: Self = Self(1);
+
+    /// The temperature is high, and performance is reduced to cool down.
+    #[doc(alias = "NSProcessInfoThermalStateSerious")]
+    pub const SERIOUS: Self = Self(2);
+
+    /// The temperature is so high that the system must cool down, and apps
+    /// should do as little work as possible.
+    #[doc(alias = "NSProcessInfoThermalStateCritical")]
+    pub const CRITICAL: Self = Self(3);
+}
@@ -0,0 +1,86 @@
+use super::NSProgress;
+use crate::core::Arc;
+use std::iter::FusedIterator;
+
+/// An iterator adaptor that reports each yielded item as a completed unit of
+/// work to an [`NSProgress`].
+///
+/// Iteration stops early once the progress is
+/// [cancelled](NSProgress::is_cancelled), so that users can cancel long
+/// computations from UI observing the progress tree.
+///
+/// # Examples
+///
+/// ```no_run
+/// use fruity::foundation::{NSProgress, ProgressIterator};
+///
+/// let progress = NSProgress::discrete(0);
+/// progress.set_cancellable(true);
+///
+/// let sum: u64 = ProgressIterator::new(0..1_000_000u64, progress)
+///     .map(|n| n * n)
+///     .sum();
+/// ```
+#[derive(Clone)]
+pub struct ProgressIterator<I> {
+    iter: I,
+    progress: Arc<NSProgress>,
+}
+
+impl<I: Iterator> ProgressIterator<I> {
+    /// Wraps `iter` to report to `progress`.
+    ///
+    /// If the number of items in `iter` is known exactly, it becomes the
+    /// total unit count of `progress`.
+    #[inline]
+    pub fn new(iter: I, progress: Arc<NSProgress>) -> Self {
+        if let (lower, Some(upper)) = iter.size_hint() {
+            if lower == upper {
+                let completed = progress.completed_unit_count();
+                progress.set_total_unit_count(completed.saturating_add(upper as i64));
+            }
+        }
+
+        Self { iter, progress }
+    }
+}
+
+impl<I> ProgressIterator<I> {
+    /// Returns the progress that `self` reports to.
+    #[inline]
+    pub fn progress(&self) -> &Arc<NSProgress> {
+        &self.progress
+    }
+
+    /// Returns the wrapped iterator.
+    #[inline]
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
+impl<I: Iterator> Iterator for ProgressIterator<I> {
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<I::Item> {
+        if self.progress.is_cancelled() {
+            return None;
+        }
+
+        let item = self.iter.next()?;
+
+        let completed = self.progress.completed_unit_count();
+        self.progress.set_completed_unit_count(completed + 1);
+
+        Some(item)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Cancellation may end iteration at any point.
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<I: FusedIterator> FusedIterator for ProgressIterator<I> {}
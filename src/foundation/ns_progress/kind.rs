@@ -0,0 +1,124 @@
+ns_string_wrapper! {
+    /// The kind of work that an [`NSProgress`](super::NSProgress) reports,
+    /// which determines how it is described to users.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/progresskind).
+    #[derive(Ord, PartialOrd, Eq, PartialEq)]
+    pub wrapper NSProgressKind;
+}
+
+ns_string_wrapper! {
+    /// The file operation reported by an [`NSProgress`](super::NSProgress)
+    /// of kind [`file`](NSProgressKind::file).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/progress/fileoperationkind-swift.struct).
+    #[derive(Ord, PartialOrd, Eq, PartialEq)]
+    pub wrapper NSProgressFileOperationKind;
+}
+
+ns_string_wrapper! {
+    /// A key in the user info dictionary of an
+    /// [`NSProgress`](super::NSProgress).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/progressuserinfokey).
+    #[derive(Ord, PartialOrd, Eq, PartialEq)]
+    pub wrapper NSProgressUserInfoKey;
+}
+
+macro_rules! value {
+    (
+        $(#[$docs:meta])+
+        $fn:ident -> $ty:ident = $value:literal
+    ) => {
+        $(#[$docs])+
+        #[inline]
+        #[doc(alias = $value)]
+        pub fn $fn() -> &'static $ty {
+            extern "C" {
+                #[link_name = $value]
+                static VALUE: &'static $ty;
+            }
+            unsafe { VALUE }
+        }
+    };
+}
+
+impl NSProgressKind {
+    value! {
+        /// Progress of work on files, described with the
+        /// [file operation](super::NSProgress::set_file_operation_kind) and
+        /// [file count](NSProgressUserInfoKey::file_total_count).
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/foundation/progresskind/file).
+        file -> NSProgressKind = "NSProgressKindFile"
+    }
+}
+
+impl NSProgressFileOperationKind {
+    value! {
+        /// Files are being copied.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/foundation/progress/fileoperationkind-swift.struct/copying).
+        copying -> NSProgressFileOperationKind = "NSProgressFileOperationKindCopying"
+    }
+
+    value! {
+        /// Files are being decompressed.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/foundation/progress/fileoperationkind-swift.struct/decompressingafterdownloading).
+        decompressing_after_downloading -> NSProgressFileOperationKind = "NSProgressFileOperationKindDecompressingAfterDownloading"
+    }
+
+    value! {
+        /// Files are being downloaded.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/foundation/progress/fileoperationkind-swift.struct/downloading).
+        downloading -> NSProgressFileOperationKind = "NSProgressFileOperationKindDownloading"
+    }
+
+    value! {
+        /// Files are being received by another source.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/foundation/progress/fileoperationkind-swift.struct/receiving).
+        receiving -> NSProgressFileOperationKind = "NSProgressFileOperationKindReceiving"
+    }
+
+    value! {
+        /// Files are being uploaded.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/foundation/progress/fileoperationkind-swift.struct/uploading).
+        uploading -> NSProgressFileOperationKind = "NSProgressFileOperationKindUploading"
+    }
+}
+
+impl NSProgressUserInfoKey {
+    value! {
+        /// An `NSNumber` of the estimated seconds remaining.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/foundation/progressuserinfokey/estimatedtimeremainingkey).
+        estimated_time_remaining -> NSProgressUserInfoKey = "NSProgressEstimatedTimeRemainingKey"
+    }
+
+    value! {
+        /// An `NSNumber` of the processing speed in bytes per second.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/foundation/progressuserinfokey/throughputkey).
+        throughput -> NSProgressUserInfoKey = "NSProgressThroughputKey"
+    }
+
+    value! {
+        /// An `NSNumber` of the total number of files, for progress of kind
+        /// [`file`](NSProgressKind::file).
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/foundation/progressuserinfokey/filetotalcountkey).
+        file_total_count -> NSProgressUserInfoKey = "NSProgressFileTotalCountKey"
+    }
+
+    value! {
+        /// An `NSNumber` of the number of completed files, for progress of
+        /// kind [`file`](NSProgressKind::file).
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/foundation/progressuserinfokey/filecompletedcountkey).
+        file_completed_count -> NSProgressUserInfoKey = "NSProgressFileCompletedCountKey"
+    }
+}
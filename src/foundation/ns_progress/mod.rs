@@ -0,0 +1,477 @@
+use super::{NSString, NSURL};
+use crate::core::{Arc, Cancellable};
+use crate::objc::{Block, ClassType, NSObject, RcBlock, BOOL};
+
+mod iter;
+mod kind;
+
+pub use iter::*;
+pub use kind::*;
+
+objc_subclass! {
+    /// Reports the progress of a task, which may be made up of child tasks
+    /// whose progress is combined into that of their parent.
+    ///
+    /// Progress is reported in units of work, such as bytes or files. Other
+    /// parts of an app, or other apps if [published](Self::publish), can
+    /// observe it and request that the task is cancelled or paused.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/progress).
+    pub class NSProgress: NSObject<'static>;
+}
+
+/// Creating progress.
+impl NSProgress {
+    /// Creates progress for `total_unit_count` units of work that is not
+    /// part of an existing progress tree.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/progress/discreteprogress(totalunitcount:)).
+    #[inline]
+    #[doc(alias = "discreteProgressWithTotalUnitCount:")]
+    pub fn discrete(total_unit_count: i64) -> Arc<Self> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![
+                Self::class(),
+                discreteProgressWithTotalUnitCount: total_unit_count
+                => *const Self
+            ])
+        }
+    }
+
+    /// Creates progress for `total_unit_count` units of work as a child of
+    /// the [current](Self::current) progress, if any.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/progress/init(totalunitcount:)).
+    #[inline]
+    #[doc(alias = "progressWithTotalUnitCount:")]
+    pub fn new(total_unit_count: i64) -> Arc<Self> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![
+                Self::class(),
+                progressWithTotalUnitCount: total_unit_count
+                => *const Self
+            ])
+        }
+    }
+
+    /// Creates progress for `total_unit_count` units of work as a child of
+    /// `parent`, accounting for `pending_unit_count` units of the parent's
+    /// work.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/progress/init(totalunitcount:parent:pendingunitcount:)).
+    #[inline]
+    #[doc(alias = "progressWithTotalUnitCount:parent:pendingUnitCount:")]
+    pub fn with_parent(
+        total_unit_count: i64,
+        parent: &NSProgress,
+        pending_unit_count: i64,
+    ) -> Arc<Self> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![
+                Self::class(),
+                progressWithTotalUnitCount: total_unit_count
+                parent: parent
+                pendingUnitCount: pending_unit_count
+                => *const Self
+            ])
+        }
+    }
+
+    /// Returns the progress that is current on this thread, to which new
+    /// progress created with [`new`](Self::new) is added.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/progress/current()).
+    #[inline]
+    #[doc(alias = "currentProgress")]
+    pub fn current() -> Option<Arc<Self>> {
+        unsafe {
+            _msg_send_any![Self::class(), currentProgress => *const Self]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+}
+
+/// Building a progress tree.
+impl NSProgress {
+    /// Makes `self` the [current](Self::current) progress on this thread, so
+    /// that progress created by called code becomes its child, accounting
+    /// for `pending_unit_count` units of work.
+    ///
+    /// This must be balanced by a call to
+    /// [`resign_current`](Self::resign_current) on the same thread.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/progress/becomecurrent(withpendingunitcount:)).
+    #[inline]
+    #[doc(alias = "becomeCurrentWithPendingUnitCount:")]
+    pub fn become_current(&self, pending_unit_count: i64) {
+        unsafe { _msg_send_any![self, becomeCurrentWithPendingUnitCount: pending_unit_count => ()] }
+    }
+
+    /// Balances a call to [`become_current`](Self::become_current).
+    ///
+    /// If no child progress was created in between, the pending units are
+    /// marked completed.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/progress/resigncurrent()).
+    #[inline]
+    #[doc(alias = "resignCurrent")]
+    pub fn resign_current(&self) {
+        unsafe { _msg_send_any![self, resignCurrent] }
+    }
+
+    /// Adds `child` to `self`, accounting for `pending_unit_count` units of
+    /// the work of `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/progress/addchild(_:withpendingunitcount:)).
+    #[inline]
+    #[doc(alias = "addChild:withPendingUnitCount:")]
+    pub fn add_child(&self, child: &NSProgress, pending_unit_count: i64) {
+        unsafe {
+            _msg_send_any![
+                self,
+                addChild: child
+                withPendingUnitCount: pending_unit_count
+                => ()
+            ]
+        }
+    }
+}
+
+/// Reporting progress.
+impl NSProgress {
+    /// Returns the total number of units of work.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/progress/totalunitcount).
+    #[inline]
+    #[doc(alias = "totalUnitCount")]
+    pub fn total_unit_count(&self) -> i64 {
+        unsafe { _msg_send_any![self, totalUnitCount] }
+    }
+
+    /// Sets the total number of units of work.
+    ///
+    /// A negative count makes the progress
+    /// [indeterminate](Self::is_indeterminate).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/progress/totalunitcount).
+    #[inline]
+    #[doc(alias = "setTotalUnitCount")]
+    #[doc(alias = "setTotalUnitCount:")]
+    pub fn set_total_unit_count(&self, count: i64) {
+        unsafe { _msg_send_any![self, setTotalUnitCount: count => ()] }
+    }
+
+    /// Returns the number of completed units of work.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/progress/completedunitcount).
+    #[inline]
+    #[doc(alias = "completedUnitCount")]
+    pub fn completed_unit_count(&self) -> i64 {
+        unsafe { _msg_send_any![self, completedUnitCount] }
+    }
+
+    /// Sets the number of completed units of work.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/progress/completedunitcount).
+    #[inline]
+    #[doc(alias = "setCompletedUnitCount")]
+    #[doc(alias = "setCompletedUnitCount:")]
+    pub fn set_completed_unit_count(&self, count: i64) {
+        unsafe { _msg_send_any![self, setCompletedUnitCount: count => ()] }
+    }
+
+    /// Returns the fraction of work completed between 0 and 1, including the
+    /// work of children.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/progress/fractioncompleted).
+    #[inline]
+    #[doc(alias = "fractionCompleted")]
+    pub fn fraction_completed(&self) -> f64 {
+        unsafe { _msg_send_any![self, fractionCompleted] }
+    }
+
+    /// Returns `true` if the completed unit count has reached the total.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/progress/isfinished).
+    #[inline]
+    #[doc(alias = "isFinished")]
+    pub fn is_finished(&self) -> bool {
+        unsafe { _msg_send_any![self, isFinished => BOOL] }.into()
+    }
+
+    /// Returns `true` if the amount of work is unknown.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/progress/isindeterminate).
+    #[inline]
+    #[doc(alias = "isIndeterminate")]
+    pub fn is_indeterminate(&self) -> bool {
+        unsafe { _msg_send_any![self, isIndeterminate => BOOL] }.into()
+    }
+
+    /// Returns the description of the progress shown to users.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/progress/localizeddescription).
+    #[inline]
+    #[doc(alias = "localizedDescription")]
+    pub fn localized_description(&self) -> Arc<NSString<'static>> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![self, localizedDescription => *const NSString<'static>])
+        }
+    }
+
+    /// Sets the description of the progress shown to users, such as
+    /// "Exporting photos".
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/progress/localizeddescription).
+    #[inline]
+    #[doc(alias = "setLocalizedDescription")]
+    #[doc(alias = "setLocalizedDescription:")]
+    pub fn set_localized_description(&self, description: Option<&NSString>) {
+        unsafe { _msg_send_any![self, setLocalizedDescription: description => ()] }
+    }
+
+    /// Returns the secondary description of the progress shown to users.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/progress/localizedadditionaldescription).
+    #[inline]
+    #[doc(alias = "localizedAdditionalDescription")]
+    pub fn localized_additional_description(&self) -> Arc<NSString<'static>> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![
+                self,
+                localizedAdditionalDescription => *const NSString<'static>
+            ])
+        }
+    }
+
+    /// Sets the secondary description of the progress shown to users, such
+    /// as "3 of 10 photos".
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/progress/localizedadditionaldescription).
+    #[inline]
+    #[doc(alias = "setLocalizedAdditionalDescription")]
+    #[doc(alias = "setLocalizedAdditionalDescription:")]
+    pub fn set_localized_additional_description(&self, description: Option<&NSString>) {
+        unsafe { _msg_send_any![self, setLocalizedAdditionalDescription: description => ()] }
+    }
+}
+
+/// Cancelling and pausing.
+impl NSProgress {
+    /// Returns `true` if the task can be cancelled.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/progress/iscancellable).
+    #[inline]
+    #[doc(alias = "isCancellable")]
+    pub fn is_cancellable(&self) -> bool {
+        unsafe { _msg_send_any![self, isCancellable => BOOL] }.into()
+    }
+
+    /// Sets whether the task can be cancelled, which determines whether
+    /// users are offered to cancel it.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/progress/iscancellable).
+    #[inline]
+    #[doc(alias = "setCancellable")]
+    #[doc(alias = "setCancellable:")]
+    pub fn set_cancellable(&self, cancellable: bool) {
+        unsafe { _msg_send_any![self, setCancellable: BOOL::from(cancellable) => ()] }
+    }
+
+    /// Returns `true` if `self` or an ancestor was cancelled.
+    ///
+    /// Long-running tasks should check this periodically and stop early.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/progress/iscancelled).
+    #[inline]
+    #[doc(alias = "isCancelled")]
+    pub fn is_cancelled(&self) -> bool {
+        unsafe { _msg_send_any![self, isCancelled => BOOL] }.into()
+    }
+
+    /// Cancels `self` and its children.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/progress/cancel()).
+    #[inline]
+    pub fn cancel(&self) {
+        unsafe { _msg_send_any![self, cancel] }
+    }
+
+    /// Sets a closure that is called when `self` is cancelled.
+    ///
+    /// `handler` is called on an arbitrary queue. If `self` was already
+    /// cancelled, it is called immediately.
+    ///
+    /// Panicking within `handler` aborts the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/progress/cancellationhandler).
+    #[doc(alias = "setCancellationHandler")]
+    #[doc(alias = "setCancellationHandler:")]
+    pub fn set_cancellation_handler<F>(&self, handler: F)
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        let block = RcBlock::new(handler);
+        let block: &Block<(), ()> = &block;
+        unsafe { _msg_send_any![self, setCancellationHandler: block => ()] }
+    }
+
+    /// Returns `true` if the task can be paused.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/progress/ispausable).
+    #[inline]
+    #[doc(alias = "isPausable")]
+    pub fn is_pausable(&self) -> bool {
+        unsafe { _msg_send_any![self, isPausable => BOOL] }.into()
+    }
+
+    /// Sets whether the task can be paused.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/progress/ispausable).
+    #[inline]
+    #[doc(alias = "setPausable")]
+    #[doc(alias = "setPausable:")]
+    pub fn set_pausable(&self, pausable: bool) {
+        unsafe { _msg_send_any![self, setPausable: BOOL::from(pausable) => ()] }
+    }
+
+    /// Returns `true` if `self` or an ancestor was paused.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/progress/ispaused).
+    #[inline]
+    #[doc(alias = "isPaused")]
+    pub fn is_paused(&self) -> bool {
+        unsafe { _msg_send_any![self, isPaused => BOOL] }.into()
+    }
+
+    /// Pauses `self` and its children.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/progress/pause()).
+    #[inline]
+    pub fn pause(&self) {
+        unsafe { _msg_send_any![self, pause] }
+    }
+
+    /// Resumes `self` and its children after a [`pause`](Self::pause).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/progress/resume()).
+    #[inline]
+    pub fn resume(&self) {
+        unsafe { _msg_send_any![self, resume] }
+    }
+}
+
+impl Cancellable for NSProgress {
+    #[inline]
+    fn cancel(&self) {
+        NSProgress::cancel(self);
+    }
+}
+
+/// Describing and publishing file progress.
+impl NSProgress {
+    /// Returns the kind of work that `self` reports, if set.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/progress/kind).
+    #[inline]
+    pub fn kind(&self) -> Option<Arc<NSProgressKind>> {
+        unsafe {
+            _msg_send_any![self, kind => *const NSProgressKind]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Sets the kind of work that `self` reports.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/progress/kind).
+    #[inline]
+    #[doc(alias = "setKind")]
+    #[doc(alias = "setKind:")]
+    pub fn set_kind(&self, kind: Option<&NSProgressKind>) {
+        unsafe { _msg_send_any![self, setKind: kind => ()] }
+    }
+
+    /// Sets the file operation that `self` reports, for progress of kind
+    /// [`file`](NSProgressKind::file).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/progress/fileoperationkind-swift.property).
+    #[inline]
+    #[doc(alias = "setFileOperationKind")]
+    #[doc(alias = "setFileOperationKind:")]
+    pub fn set_file_operation_kind(&self, kind: Option<&NSProgressFileOperationKind>) {
+        unsafe { _msg_send_any![self, setFileOperationKind: kind => ()] }
+    }
+
+    /// Sets the file that `self` reports on.
+    ///
+    /// Finder shows progress published for a file in place of its icon.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/progress/fileurl).
+    #[inline]
+    #[doc(alias = "setFileURL")]
+    #[doc(alias = "setFileURL:")]
+    pub fn set_file_url(&self, url: Option<&NSURL>) {
+        unsafe { _msg_send_any![self, setFileURL: url => ()] }
+    }
+
+    /// Sets the value for `key` in the user info of `self`, or removes it if
+    /// `value` is [`None`].
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/progress/setuserinfoobject(_:forkey:)).
+    #[inline]
+    #[doc(alias = "setUserInfoObject:forKey:")]
+    pub fn set_user_info_object(&self, value: Option<&NSObject>, key: &NSProgressUserInfoKey) {
+        unsafe { _msg_send_any![self, setUserInfoObject: value forKey: key => ()] }
+    }
+
+    /// Makes `self` visible to other processes, such as Finder, until
+    /// [`unpublish`](Self::unpublish) is called.
+    ///
+    /// Progress of kind [`file`](NSProgressKind::file) with a
+    /// [file URL](Self::set_file_url) is shown on the file in Finder, which
+    /// may cancel or pause it.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/progress/publish()).
+    #[cfg(target_os = "macos")]
+    #[inline]
+    pub fn publish(&self) {
+        unsafe { _msg_send_any![self, publish] }
+    }
+
+    /// Removes `self` from other processes after a
+    /// [`publish`](Self::publish).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/progress/unpublish()).
+    #[cfg(target_os = "macos")]
+    #[inline]
+    pub fn unpublish(&self) {
+        unsafe { _msg_send_any![self, unpublish] }
+    }
+}
+
+impl NSProgress {
+    /// Calls `f` with `self` as the [current](Self::current) progress,
+    /// accounting for `pending_unit_count` units of work.
+    ///
+    /// Progress created with [`new`](Self::new) within `f` becomes a child of
+    /// `self`.
+    #[inline]
+    pub fn perform_as_current<F, T>(&self, pending_unit_count: i64, f: F) -> T
+    where
+        F: FnOnce() -> T,
+    {
+        struct Resign<'a>(&'a NSProgress);
+
+        impl Drop for Resign<'_> {
+            fn drop(&mut self) {
+                self.0.resign_current();
+            }
+        }
+
+        self.become_current(pending_unit_count);
+        let _resign = Resign(self);
+        f()
+    }
+}
@@ -0,0 +1,40 @@
+use crate::objc::NSInteger;
+
+/// The nature and importance of work, used by the system to prioritize
+/// resources such as CPU time and I/O.
+///
+/// See [documentation](https://developer.apple.com/documentation/foundation/qualityofservice).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NSQualityOfService(pub NSInteger);
+
+impl Default for NSQualityOfService {
+    #[inline]
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl NSQualityOfService {
+    /// Work that directly involves the user interface, such as animations.
+    #[doc(alias = "NSQualityOfServiceUserInteractive")]
+    pub const USER_INTERACTIVE: Self = Self(0x21);
+
+    /// Work requested by the user that prevents further interaction until it
+    /// completes.
+    #[doc(alias = "NSQualityOfServiceUserInitiated")]
+    pub const USER_INITIATED: Self = Self(0x19);
+
+    /// Work that the user is not immediately waiting on, such as periodic
+    /// content updates.
+    #[doc(alias = "NSQualityOfServiceUtility")]
+    pub const UTILITY: Self = Self(0x11);
+
+    /// Work that is not visible to the user, such as maintenance or backups.
+    #[doc(alias = "NSQualityOfServiceBackground")]
+    pub const BACKGROUND: Self = Self(0x09);
+
+    /// Lets the system infer the quality of service from other sources.
+    #[doc(alias = "NSQualityOfServiceDefault")]
+    pub const DEFAULT: Self = Self(-1);
+}
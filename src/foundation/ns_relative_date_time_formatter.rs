@@ -0,0 +1,158 @@
+use super::{NSDate, NSString, NSTimeInterval};
+use crate::core::Arc;
+use crate::objc::{ClassType, NSInteger, NSObject};
+
+objc_subclass! {
+    /// A formatter that shows how far a date is from another in the user's
+    /// language, such as "5 minutes ago" or "in 2 days".
+    ///
+    /// The largest fitting unit is used, so 90 minutes is shown as
+    /// "1 hour ago".
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fruity::foundation::{NSDate, NSRelativeDateTimeFormatter};
+    ///
+    /// let formatter = NSRelativeDateTimeFormatter::new();
+    /// let saved = NSDate::now().adding_time_interval(-300.0);
+    ///
+    /// // "5 minutes ago"
+    /// let string = formatter.localized_string_for_date(&saved, &NSDate::now());
+    /// ```
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/relativedatetimeformatter).
+    pub class NSRelativeDateTimeFormatter: NSObject<'static>;
+}
+
+impl NSRelativeDateTimeFormatter {
+    /// Creates a formatter with
+    /// [`NSRelativeDateTimeFormatterStyle::NUMERIC`] and
+    /// [`NSRelativeDateTimeFormatterUnitsStyle::FULL`].
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/relativedatetimeformatter/init()).
+    #[inline]
+    pub fn new() -> Arc<Self> {
+        unsafe { Self::class().alloc_init() }
+    }
+
+    /// Returns whether named phrases such as "yesterday" are used.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/relativedatetimeformatter/datetimestyle).
+    #[inline]
+    #[doc(alias = "dateTimeStyle")]
+    pub fn date_time_style(&self) -> NSRelativeDateTimeFormatterStyle {
+        unsafe { _msg_send_any![self, dateTimeStyle] }
+    }
+
+    /// Sets whether named phrases such as "yesterday" are used.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/relativedatetimeformatter/datetimestyle).
+    #[inline]
+    #[doc(alias = "setDateTimeStyle:")]
+    pub fn set_date_time_style(&self, style: NSRelativeDateTimeFormatterStyle) {
+        unsafe { _msg_send_any![self, setDateTimeStyle: style] }
+    }
+
+    /// Returns how units such as "minutes" are written.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/relativedatetimeformatter/unitsstyle).
+    #[inline]
+    #[doc(alias = "unitsStyle")]
+    pub fn units_style(&self) -> NSRelativeDateTimeFormatterUnitsStyle {
+        unsafe { _msg_send_any![self, unitsStyle] }
+    }
+
+    /// Sets how units such as "minutes" are written.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/relativedatetimeformatter/unitsstyle).
+    #[inline]
+    #[doc(alias = "setUnitsStyle:")]
+    pub fn set_units_style(&self, style: NSRelativeDateTimeFormatterUnitsStyle) {
+        unsafe { _msg_send_any![self, setUnitsStyle: style] }
+    }
+
+    /// Returns how far `date` is from `reference_date`, such as
+    /// "5 minutes ago" if `date` is earlier.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/relativedatetimeformatter/localizedstring(for:relativeto:)).
+    #[inline]
+    #[doc(alias = "localizedStringForDate:relativeToDate:")]
+    pub fn localized_string_for_date(
+        &self,
+        date: &NSDate,
+        reference_date: &NSDate,
+    ) -> Arc<NSString<'static>> {
+        unsafe {
+            let string = _msg_send_any![
+                self,
+                localizedStringForDate: date
+                relativeToDate: reference_date
+                => *const NSString<'static>
+            ];
+            Arc::retain_raw(string)
+        }
+    }
+
+    /// Returns a phrase for `interval` seconds from now, such as
+    /// "5 minutes ago" if `interval` is -300.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/relativedatetimeformatter/localizedstring(fromtimeinterval:)).
+    #[inline]
+    #[doc(alias = "localizedStringFromTimeInterval:")]
+    pub fn localized_string_from_time_interval(
+        &self,
+        interval: NSTimeInterval,
+    ) -> Arc<NSString<'static>> {
+        unsafe {
+            let string = _msg_send_any![
+                self,
+                localizedStringFromTimeInterval: interval
+                => *const NSString<'static>
+            ];
+            Arc::retain_raw(string)
+        }
+    }
+}
+
+/// Whether an [`NSRelativeDateTimeFormatter`] uses named phrases.
+///
+/// See [documentation](https://developer.apple.com/documentation/foundation/relativedatetimeformatter/datetimestyle-swift.enum).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NSRelativeDateTimeFormatterStyle(pub NSInteger);
+
+impl NSRelativeDateTimeFormatterStyle {
+    /// Always uses numbers, such as "1 day ago".
+    #[doc(alias = "NSRelativeDateTimeFormatterStyleNumeric")]
+    pub const NUMERIC: Self = Self(0);
+
+    /// Uses names where the language has them, such as "yesterday".
+    #[doc(alias = "NSRelativeDateTimeFormatterStyleNamed")]
+    pub const NAMED: Self = Self(1);
+}
+
+/// How an [`NSRelativeDateTimeFormatter`] writes units.
+///
+/// See [documentation](https://developer.apple.com/documentation/foundation/relativedatetimeformatter/unitsstyle-swift.enum).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NSRelativeDateTimeFormatterUnitsStyle(pub NSInteger);
+
+impl NSRelativeDateTimeFormatterUnitsStyle {
+    /// Such as "in 2 months".
+    #[doc(alias = "NSRelativeDateTimeFormatterUnitsStyleFull")]
+    pub const FULL: Self = Self(0);
+
+    /// Such as "in two months".
+    #[doc(alias = "NSRelativeDateTimeFormatterUnitsStyleSpellOut")]
+    pub const SPELL_OUT: Self = Self(1);
+
+    /// Such as "in 2 mo.".
+    #[doc(alias = "NSRelativeDateTimeFormatterUnitsStyleShort")]
+    pub const SHORT: Self = Self(2);
+
+    /// Such as "in 2mo".
+    #[doc(alias = "NSRelativeDateTimeFormatterUnitsStyleAbbreviated")]
+    pub const ABBREVIATED: Self = Self(3);
+}
@@ -0,0 +1,72 @@
+use super::NSString;
+use crate::core::Arc;
+use crate::objc::{ClassType, NSObject, Sel, BOOL};
+use std::fmt;
+
+objc_subclass! {
+    /// Describes how to order objects by the value of a key-value coding
+    /// property.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nssortdescriptor).
+    pub class NSSortDescriptor: NSObject<'static>;
+}
+
+impl fmt::Debug for NSSortDescriptor {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("NSSortDescriptor")
+            .field("key", &self.key())
+            .field("ascending", &self.ascending())
+            .finish()
+    }
+}
+
+impl NSSortDescriptor {
+    /// Creates a sort descriptor that orders objects by `key`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nssortdescriptor/init(key:ascending:)).
+    #[inline]
+    #[doc(alias = "initWithKey:ascending:")]
+    pub fn new(key: &NSString, ascending: bool) -> Arc<Self> {
+        unsafe {
+            let descriptor: Arc<Self> = Self::class().alloc();
+
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(
+                    obj: Arc<NSSortDescriptor>,
+                    sel: Sel,
+                    key: &NSString,
+                    ascending: BOOL,
+                ) -> Arc<NSSortDescriptor>;
+            }
+
+            objc_msgSend(
+                descriptor,
+                selector!(initWithKey:ascending:),
+                key,
+                BOOL::from(ascending),
+            )
+        }
+    }
+
+    /// Returns the key path of the property that objects are ordered by.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nssortdescriptor/key).
+    #[inline]
+    pub fn key(&self) -> Option<Arc<NSString<'static>>> {
+        unsafe {
+            _msg_send_any![self, key => *const NSString<'static>]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Returns `true` if objects are ordered from smallest to largest.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nssortdescriptor/ascending).
+    #[inline]
+    pub fn ascending(&self) -> bool {
+        unsafe { _msg_send_any![self, ascending => BOOL] }.into()
+    }
+}
@@ -1,7 +1,7 @@
-use super::{NSComparisonResult, NSRange};
+use super::{NSArray, NSCharacterSet, NSComparisonResult, NSData, NSRange};
 use crate::core::Arc;
-use crate::objc::{Class, ClassType, NSObject, NSUInteger, Sel, BOOL};
-use std::{cmp::Ordering, ffi::CStr, fmt, os::raw::c_char, ptr, slice, str};
+use crate::objc::{Block, Class, ClassType, NSObject, NSUInteger, Sel, StackBlock, BOOL};
+use std::{cell::RefCell, cmp::Ordering, ffi::CStr, fmt, os::raw::c_char, ptr, slice, str};
 
 #[macro_use]
 mod macros;
@@ -203,6 +203,26 @@ impl fmt::Display for NSString<'_> {
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for NSString<'_> {
+    #[inline]
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // SAFETY: The lifetime of `str` is very short.
+        let str = unsafe { self.to_str() };
+
+        serializer.serialize_str(str)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Arc<NSString<'static>> {
+    #[inline]
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Ok(NSString::from_str(&string))
+    }
+}
+
 /// Getting available encodings.
 impl NSString<'_> {
     /// Returns a slice containing all supported encodings.
@@ -434,6 +454,7 @@ impl NSString<'_> {
         #[allow(non_upper_case_globals)]
         const kCFStringEncodingUTF8: CFStringEncoding = 0x08000100;
 
+        #[allow(clashing_extern_declarations)]
         extern "C" {
             fn CFStringGetCStringPtr(s: &NSString, encoding: CFStringEncoding) -> *const c_char;
         }
@@ -453,7 +474,7 @@ impl NSString<'_> {
     /// [`-[NSString UTF8String]`](https://developer.apple.com/documentation/foundation/nsstring/1411189-utf8string).
     #[inline]
     pub fn to_utf8_ptr(&self) -> *const c_char {
-        unsafe { _msg_send_any![self, UTF8String] }
+        unsafe { _msg_send_any_cached![self, UTF8String] }
     }
 
     /// Returns the contents of `self` as a native UTF-8 string slice, or `None`
@@ -629,7 +650,7 @@ impl NSString<'_> {
     /// See [documentation](https://developer.apple.com/documentation/foundation/nsstring/1414212-length).
     #[inline]
     pub fn length(&self) -> NSUInteger {
-        unsafe { _msg_send_any![self, length] }
+        unsafe { _msg_send_any_cached![self, length] }
     }
 
     /// Returns a selector with `self` as its name.
@@ -643,6 +664,32 @@ impl NSString<'_> {
         NSSelectorFromString(self)
     }
 
+    /// Calls `f` with each line of `self`, stopping early if `f` returns
+    /// `false`.
+    ///
+    /// Lines may be separated by any Unicode line terminator. The line
+    /// terminators are not included in the lines passed to `f`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsstring/enumeratelines(_:)).
+    #[inline]
+    #[doc(alias = "enumerateLinesUsingBlock")]
+    #[doc(alias = "enumerateLinesUsingBlock:")]
+    pub fn enumerate_lines<F>(&self, f: F)
+    where
+        F: FnMut(&NSString) -> bool,
+    {
+        let f = RefCell::new(f);
+        let block = StackBlock::new(|line: *const NSString<'static>, stop: *mut BOOL| {
+            let mut f = f.borrow_mut();
+            if !(*f)(unsafe { &*line }) {
+                unsafe { *stop = BOOL::YES };
+            }
+        });
+
+        let block: &Block<_, ()> = &block;
+        unsafe { _msg_send_any![self, enumerateLinesUsingBlock: block] }
+    }
+
     // TODO: Other comparison methods:
     // - compare:options:
     // - compare:options:range:
@@ -718,6 +765,170 @@ impl NSString<'_> {
     pub fn has_suffix(&self, suffix: &NSString) -> bool {
         unsafe { _msg_send_any![self, hasSuffix: suffix => BOOL] }.into()
     }
+
+    /// Returns a copy of `self` with all characters not in `allowed` replaced
+    /// by their percent-encoded UTF-8 bytes, or [`None`] if `self` is not
+    /// valid UTF-16.
+    ///
+    /// `allowed` is typically one of the URL component sets, such as
+    /// [`NSCharacterSet::url_path_allowed`].
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsstring/addingpercentencoding(withallowedcharacters:)).
+    #[inline]
+    #[doc(alias = "stringByAddingPercentEncodingWithAllowedCharacters")]
+    #[doc(alias = "stringByAddingPercentEncodingWithAllowedCharacters:")]
+    pub fn adding_percent_encoding(
+        &self,
+        allowed: &NSCharacterSet,
+    ) -> Option<Arc<NSString<'static>>> {
+        unsafe {
+            _msg_send_any![
+                self,
+                stringByAddingPercentEncodingWithAllowedCharacters: allowed
+                => *const NSString<'static>
+            ]
+            .as_ref()
+            .map(Arc::retain)
+        }
+    }
+
+    /// Returns a copy of `self` with percent-encoded sequences replaced by the
+    /// characters they represent, or [`None`] if a sequence does not encode
+    /// valid UTF-8.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsstring/removingpercentencoding).
+    #[inline]
+    #[doc(alias = "stringByRemovingPercentEncoding")]
+    pub fn removing_percent_encoding(&self) -> Option<Arc<NSString<'static>>> {
+        unsafe {
+            _msg_send_any![self, stringByRemovingPercentEncoding => *const NSString<'static>]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Returns the substrings of `self` that are divided by `separator`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsstring/components(separatedby:)).
+    #[inline]
+    #[doc(alias = "componentsSeparatedByString")]
+    #[doc(alias = "componentsSeparatedByString:")]
+    pub fn components_separated_by(&self, separator: &NSString) -> Arc<NSArray<NSString<'static>>> {
+        unsafe {
+            let array = _msg_send_any![
+                self,
+                componentsSeparatedByString: separator
+                => *const NSArray<NSString<'static>>
+            ];
+            Arc::retain_raw(array)
+        }
+    }
+}
+
+/// Converting between encodings.
+impl<'data> NSString<'data> {
+    /// Creates a string by decoding `data` in `encoding`, returning [`None`]
+    /// if it is not valid in that encoding.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsstring/init(data:encoding:)).
+    #[inline]
+    #[doc(alias = "initWithData:encoding:")]
+    pub fn from_data(data: &NSData, encoding: NSStringEncoding) -> Option<Arc<Self>> {
+        unsafe {
+            let value: Arc<Self> = Self::class().alloc();
+
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend<'data>(
+                    obj: Arc<NSString<'data>>,
+                    sel: Sel,
+                    data: &NSData,
+                    encoding: NSStringEncoding,
+                ) -> Option<Arc<NSString<'data>>>;
+            }
+
+            objc_msgSend(value, selector!(initWithData:encoding:), data, encoding)
+        }
+    }
+
+    /// Creates a string by decoding `bytes` in `encoding`, returning [`None`]
+    /// if they are not valid in that encoding.
+    ///
+    /// This is useful for text that is not UTF-8, such as
+    /// [`MAC_ROMAN`](NSStringEncoding::MAC_ROMAN) text in older file formats.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsstring/init(bytes:length:encoding:)).
+    #[inline]
+    #[doc(alias = "initWithBytes:length:encoding:")]
+    pub fn from_bytes_with_encoding(bytes: &[u8], encoding: NSStringEncoding) -> Option<Arc<Self>> {
+        unsafe {
+            let value: Arc<Self> = Self::class().alloc();
+
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend<'data>(
+                    obj: Arc<NSString<'data>>,
+                    sel: Sel,
+                    bytes: *const u8,
+                    length: NSUInteger,
+                    encoding: NSStringEncoding,
+                ) -> Option<Arc<NSString<'data>>>;
+            }
+
+            objc_msgSend(
+                value,
+                selector!(initWithBytes:length:encoding:),
+                bytes.as_ptr(),
+                bytes.len() as NSUInteger,
+                encoding,
+            )
+        }
+    }
+
+    /// Returns `self` encoded in `encoding`, or [`None`] if any character
+    /// cannot be represented in it.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsstring/data(using:allowlossyconversion:)).
+    #[inline]
+    #[doc(alias = "dataUsingEncoding:")]
+    pub fn to_data(&self, encoding: NSStringEncoding) -> Option<Arc<NSData>> {
+        self.to_data_impl(encoding, false)
+    }
+
+    /// Returns `self` encoded in `encoding`, replacing or removing
+    /// characters that cannot be represented in it.
+    ///
+    /// This returns [`None`] only if the conversion fails entirely.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsstring/data(using:allowlossyconversion:)).
+    #[inline]
+    #[doc(alias = "dataUsingEncoding:allowLossyConversion:")]
+    pub fn to_data_lossy(&self, encoding: NSStringEncoding) -> Option<Arc<NSData>> {
+        self.to_data_impl(encoding, true)
+    }
+
+    #[inline]
+    fn to_data_impl(&self, encoding: NSStringEncoding, lossy: bool) -> Option<Arc<NSData>> {
+        unsafe {
+            _msg_send_any![
+                self,
+                dataUsingEncoding: encoding
+                allowLossyConversion: BOOL::from(lossy)
+                => *const NSData
+            ]
+            .as_ref()
+            .map(Arc::retain)
+        }
+    }
+
+    /// Returns `true` if `self` can be encoded in `encoding` without loss.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsstring/canbeconverted(to:)).
+    #[inline]
+    #[doc(alias = "canBeConvertedToEncoding:")]
+    pub fn can_be_converted_to(&self, encoding: NSStringEncoding) -> bool {
+        unsafe { _msg_send_any![self, canBeConvertedToEncoding: encoding => BOOL] }.into()
+    }
 }
 
 objc_subclass! {
@@ -0,0 +1,94 @@
+use super::{NSDate, NSString};
+use crate::core::Arc;
+use crate::objc::{ClassType, NSInteger, NSObject, Sel, BOOL};
+
+objc_subclass! {
+    /// A geopolitical region's rules for standard time and daylight saving
+    /// time.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nstimezone).
+    pub class NSTimeZone: NSObject<'static>;
+}
+
+impl NSTimeZone {
+    /// Returns the time zone currently used by the system.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/timezone/current).
+    #[inline]
+    #[doc(alias = "systemTimeZone")]
+    pub fn system() -> Arc<Self> {
+        unsafe { Arc::retain_raw(_msg_send_any![Self::class(), systemTimeZone => *const Self]) }
+    }
+
+    /// Returns the time zone with the IANA identifier `name`, such as
+    /// `America/New_York`, or [`None`] if it is unknown.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/timezone/init(identifier:)).
+    #[inline]
+    #[doc(alias = "initWithName:")]
+    pub fn with_name(name: &NSString) -> Option<Arc<Self>> {
+        unsafe {
+            let zone: Arc<Self> = Self::class().alloc();
+
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(
+                    obj: Arc<NSTimeZone>,
+                    sel: Sel,
+                    name: &NSString,
+                ) -> Option<Arc<NSTimeZone>>;
+            }
+
+            objc_msgSend(zone, selector!(initWithName:), name)
+        }
+    }
+
+    /// Returns the IANA identifier of the time zone, such as
+    /// `America/New_York`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/timezone/identifier).
+    #[inline]
+    pub fn name(&self) -> Arc<NSString<'static>> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, name => *const NSString<'static>]) }
+    }
+
+    /// Returns the abbreviation of the time zone at the current time, such
+    /// as `EST` or `EDT`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/timezone/abbreviation(for:)).
+    #[inline]
+    pub fn abbreviation(&self) -> Option<Arc<NSString<'static>>> {
+        unsafe {
+            _msg_send_any![self, abbreviation => *const NSString<'static>]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Returns the difference in seconds from GMT at the current time.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/timezone/secondsfromgmt(for:)).
+    #[inline]
+    #[doc(alias = "secondsFromGMT")]
+    pub fn seconds_from_gmt(&self) -> NSInteger {
+        unsafe { _msg_send_any![self, secondsFromGMT] }
+    }
+
+    /// Returns the difference in seconds from GMT at `date`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/timezone/secondsfromgmt(for:)).
+    #[inline]
+    #[doc(alias = "secondsFromGMTForDate:")]
+    pub fn seconds_from_gmt_at(&self, date: &NSDate) -> NSInteger {
+        unsafe { _msg_send_any![self, secondsFromGMTForDate: date] }
+    }
+
+    /// Returns `true` if daylight saving time is in effect at `date`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/timezone/isdaylightsavingtime(for:)).
+    #[inline]
+    #[doc(alias = "isDaylightSavingTimeForDate:")]
+    pub fn is_daylight_saving_time_at(&self, date: &NSDate) -> bool {
+        unsafe { _msg_send_any![self, isDaylightSavingTimeForDate: date => BOOL] }.into()
+    }
+}
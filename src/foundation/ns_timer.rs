@@ -0,0 +1,174 @@
+use super::{NSDate, NSTimeInterval};
+use crate::core::Arc;
+use crate::objc::{Block, ClassType, NSObject, RcBlock, BOOL};
+use std::cell::RefCell;
+
+#[cfg(feature = "core_foundation")]
+use crate::core_foundation::{CFRunLoop, CFRunLoopMode, CFRunLoopSchedulable};
+
+objc_subclass! {
+    /// A timer that fires after a certain time interval has elapsed, calling
+    /// a closure.
+    ///
+    /// This is toll-free bridged with
+    /// [`CFRunLoopTimer`](crate::core_foundation::CFRunLoopTimer) when the
+    /// **`core_foundation`** feature is enabled.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/timer).
+    pub class NSTimer: NSObject<'static>;
+}
+
+/// Scheduling on a [`CFRunLoop`] through
+/// toll-free bridging, such as in modes other than the default.
+#[cfg(feature = "core_foundation")]
+impl CFRunLoopSchedulable for NSTimer {
+    #[inline]
+    fn schedule(&self, run_loop: &CFRunLoop, mode: &CFRunLoopMode) {
+        run_loop.add_timer(self.as_ref(), mode);
+    }
+
+    #[inline]
+    fn unschedule(&self, run_loop: &CFRunLoop, mode: &CFRunLoopMode) {
+        run_loop.remove_timer(self.as_ref(), mode);
+    }
+}
+
+impl NSTimer {
+    fn block<F>(block: F) -> RcBlock<(*const NSTimer,), ()>
+    where
+        F: FnMut(&NSTimer) + Send + 'static,
+    {
+        let block = RefCell::new(block);
+        RcBlock::new(move |timer: *const NSTimer| {
+            (*block.borrow_mut())(unsafe { &*timer });
+        })
+    }
+
+    /// Creates a timer that calls `block` after `interval` seconds, and then
+    /// every `interval` seconds if `repeats` is `true`.
+    ///
+    /// The timer does nothing until it is added to a run loop.
+    ///
+    /// It is safe to panic within `block`. Panics will abort the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/timer/init(timeinterval:repeats:block:)).
+    #[inline]
+    #[doc(alias = "timerWithTimeInterval:repeats:block:")]
+    pub fn with_block<F>(interval: NSTimeInterval, repeats: bool, block: F) -> Arc<Self>
+    where
+        F: FnMut(&NSTimer) + Send + 'static,
+    {
+        let block = Self::block(block);
+        let block: &Block<_, ()> = &block;
+        unsafe {
+            let timer = _msg_send_any![
+                Self::class(),
+                timerWithTimeInterval: interval
+                repeats: BOOL::from(repeats)
+                block: block
+                => *const Self
+            ];
+            Arc::retain_raw(timer)
+        }
+    }
+
+    /// Creates a timer like [`with_block`](Self::with_block) and schedules it
+    /// on the current run loop in the default mode.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/timer/scheduledtimer(withtimeinterval:repeats:block:)).
+    #[inline]
+    #[doc(alias = "scheduledTimerWithTimeInterval:repeats:block:")]
+    pub fn scheduled_with_block<F>(interval: NSTimeInterval, repeats: bool, block: F) -> Arc<Self>
+    where
+        F: FnMut(&NSTimer) + Send + 'static,
+    {
+        let block = Self::block(block);
+        let block: &Block<_, ()> = &block;
+        unsafe {
+            let timer = _msg_send_any![
+                Self::class(),
+                scheduledTimerWithTimeInterval: interval
+                repeats: BOOL::from(repeats)
+                block: block
+                => *const Self
+            ];
+            Arc::retain_raw(timer)
+        }
+    }
+
+    /// Calls the timer's closure immediately, invalidating the timer if it
+    /// does not repeat.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/timer/fire()).
+    #[inline]
+    pub fn fire(&self) {
+        unsafe { _msg_send_any![self, fire] }
+    }
+
+    /// Returns the time at which the timer will next fire.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/timer/firedate).
+    #[inline]
+    #[doc(alias = "fireDate")]
+    pub fn fire_date(&self) -> Arc<NSDate> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, fireDate => *const NSDate]) }
+    }
+
+    /// Sets the time at which the timer will next fire.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/timer/firedate).
+    #[inline]
+    #[doc(alias = "setFireDate")]
+    #[doc(alias = "setFireDate:")]
+    pub fn set_fire_date(&self, date: &NSDate) {
+        unsafe { _msg_send_any![self, setFireDate: date] }
+    }
+
+    /// Returns the interval of a repeating timer, or 0 if it does not
+    /// repeat.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/timer/timeinterval).
+    #[inline]
+    #[doc(alias = "timeInterval")]
+    pub fn time_interval(&self) -> NSTimeInterval {
+        unsafe { _msg_send_any![self, timeInterval] }
+    }
+
+    /// Returns the amount of time after the fire date that the timer may
+    /// fire.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/timer/tolerance).
+    #[inline]
+    pub fn tolerance(&self) -> NSTimeInterval {
+        unsafe { _msg_send_any![self, tolerance] }
+    }
+
+    /// Sets the amount of time after the fire date that the timer may fire,
+    /// allowing the system to coalesce timers to save power.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/timer/tolerance).
+    #[inline]
+    #[doc(alias = "setTolerance")]
+    #[doc(alias = "setTolerance:")]
+    pub fn set_tolerance(&self, tolerance: NSTimeInterval) {
+        unsafe { _msg_send_any![self, setTolerance: tolerance] }
+    }
+
+    /// Stops the timer from ever firing again and removes it from its run
+    /// loop.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/timer/invalidate()).
+    #[inline]
+    pub fn invalidate(&self) {
+        unsafe { _msg_send_any![self, invalidate] }
+    }
+
+    /// Returns `true` if the timer is able to fire.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/timer/isvalid).
+    #[inline]
+    #[doc(alias = "isValid")]
+    pub fn is_valid(&self) -> bool {
+        unsafe { _msg_send_any![self, isValid => BOOL] }.into()
+    }
+}
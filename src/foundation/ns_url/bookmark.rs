@@ -0,0 +1,249 @@
+use super::{NSURLResourceKey, NSURL};
+use crate::core::Arc;
+use crate::foundation::{NSArray, NSData, NSDictionary, NSError};
+use crate::objc::{ClassType, NSUInteger, BOOL};
+use std::{ops, ptr};
+
+/// Options for creating bookmark data with
+/// [`NSURL::bookmark_data`](super::NSURL::bookmark_data).
+///
+/// See [documentation](https://developer.apple.com/documentation/foundation/nsurl/bookmarkcreationoptions).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NSURLBookmarkCreationOptions(pub NSUInteger);
+
+impl ops::BitOr for NSURLBookmarkCreationOptions {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl NSURLBookmarkCreationOptions {
+    /// Creates a smaller bookmark that may fail to resolve in more cases.
+    #[doc(alias = "NSURLBookmarkCreationMinimalBookmark")]
+    pub const MINIMAL_BOOKMARK: Self = Self(1 << 9);
+
+    /// Creates data suitable for writing to a Finder alias file.
+    #[doc(alias = "NSURLBookmarkCreationSuitableForBookmarkFile")]
+    pub const SUITABLE_FOR_BOOKMARK_FILE: Self = Self(1 << 10);
+
+    /// Creates a security-scoped bookmark that a sandboxed app can resolve
+    /// in later launches to regain access to the file.
+    #[cfg(target_os = "macos")]
+    #[doc(alias = "NSURLBookmarkCreationWithSecurityScope")]
+    pub const WITH_SECURITY_SCOPE: Self = Self(1 << 11);
+
+    /// When combined with
+    /// [`WITH_SECURITY_SCOPE`](Self::WITH_SECURITY_SCOPE), only grants
+    /// read access when the bookmark is resolved.
+    #[cfg(target_os = "macos")]
+    #[doc(alias = "NSURLBookmarkCreationSecurityScopeAllowOnlyReadAccess")]
+    pub const SECURITY_SCOPE_ALLOW_ONLY_READ_ACCESS: Self = Self(1 << 12);
+
+    /// Excludes the implicit security scope of a URL the user selected, so
+    /// that the bookmark cannot be used to access the file.
+    #[doc(alias = "NSURLBookmarkCreationWithoutImplicitSecurityScope")]
+    pub const WITHOUT_IMPLICIT_SECURITY_SCOPE: Self = Self(1 << 29);
+
+    /// Returns `true` if all options in `other` are set in `self`.
+    #[inline]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+/// Options for resolving bookmark data with
+/// [`NSURL::from_bookmark_data`](super::NSURL::from_bookmark_data).
+///
+/// See [documentation](https://developer.apple.com/documentation/foundation/nsurl/bookmarkresolutionoptions).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NSURLBookmarkResolutionOptions(pub NSUInteger);
+
+impl ops::BitOr for NSURLBookmarkResolutionOptions {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl NSURLBookmarkResolutionOptions {
+    /// Does not show any UI while resolving the bookmark.
+    #[doc(alias = "NSURLBookmarkResolutionWithoutUI")]
+    pub const WITHOUT_UI: Self = Self(1 << 8);
+
+    /// Does not mount a volume while resolving the bookmark.
+    #[doc(alias = "NSURLBookmarkResolutionWithoutMounting")]
+    pub const WITHOUT_MOUNTING: Self = Self(1 << 9);
+
+    /// Resolves a security-scoped bookmark to a URL that can be accessed
+    /// with
+    /// [`start_accessing_security_scoped_resource`](super::NSURL::start_accessing_security_scoped_resource).
+    #[cfg(target_os = "macos")]
+    #[doc(alias = "NSURLBookmarkResolutionWithSecurityScope")]
+    pub const WITH_SECURITY_SCOPE: Self = Self(1 << 10);
+
+    /// Does not implicitly start accessing the security-scoped resource of
+    /// the resolved URL.
+    #[doc(alias = "NSURLBookmarkResolutionWithoutImplicitStartAccessing")]
+    pub const WITHOUT_IMPLICIT_START_ACCESSING: Self = Self(1 << 15);
+
+    /// Returns `true` if all options in `other` are set in `self`.
+    #[inline]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+/// Bookmarks and security-scoped resources.
+///
+/// A bookmark is an opaque reference to a file that keeps working if the
+/// file is moved or renamed. Sandboxed apps store security-scoped bookmarks
+/// to keep access to files the user chose across launches.
+impl NSURL {
+    /// Creates bookmark data that refers to the file at `self`.
+    ///
+    /// The values of `resource_keys` are stored in the bookmark and can be
+    /// read without resolving it. If `relative_to` is given, the bookmark
+    /// can only be resolved relative to that URL, such as the URL of the
+    /// document it is stored in.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsurl/bookmarkdata(options:includingresourcevaluesforkeys:relativeto:)).
+    #[inline]
+    #[doc(alias = "bookmarkDataWithOptions:includingResourceValuesForKeys:relativeToURL:error:")]
+    pub fn bookmark_data(
+        &self,
+        options: NSURLBookmarkCreationOptions,
+        resource_keys: Option<&NSArray<NSURLResourceKey>>,
+        relative_to: Option<&NSURL>,
+    ) -> Result<Arc<NSData>, Arc<NSError<'static>>> {
+        unsafe {
+            let mut error: *const NSError<'static> = ptr::null();
+            let data = _msg_send_any![
+                self,
+                bookmarkDataWithOptions: options
+                includingResourceValuesForKeys: resource_keys
+                relativeToURL: relative_to
+                error: &mut error
+                => *const NSData
+            ];
+
+            match data.as_ref() {
+                Some(data) => Ok(Arc::retain(data)),
+                None => Err(Arc::retain_raw(error)),
+            }
+        }
+    }
+
+    /// Resolves bookmark data created by
+    /// [`bookmark_data`](Self::bookmark_data).
+    ///
+    /// The returned `bool` is `true` if the bookmark is stale, such as when
+    /// the file was moved. Stale bookmarks still resolve, but should be
+    /// replaced with new bookmark data for the returned URL.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsurl/init(resolvingbookmarkdata:options:relativeto:bookmarkdataisstale:)).
+    #[inline]
+    #[doc(alias = "URLByResolvingBookmarkData:options:relativeToURL:bookmarkDataIsStale:error:")]
+    pub fn from_bookmark_data(
+        data: &NSData,
+        options: NSURLBookmarkResolutionOptions,
+        relative_to: Option<&NSURL>,
+    ) -> Result<(Arc<Self>, bool), Arc<NSError<'static>>> {
+        unsafe {
+            let mut is_stale = BOOL::NO;
+            let mut error: *const NSError<'static> = ptr::null();
+            let url = _msg_send_any![
+                Self::class(),
+                URLByResolvingBookmarkData: data
+                options: options
+                relativeToURL: relative_to
+                bookmarkDataIsStale: &mut is_stale
+                error: &mut error
+                => *const Self
+            ];
+
+            match url.as_ref() {
+                Some(url) => Ok((Arc::retain(url), is_stale.into())),
+                None => Err(Arc::retain_raw(error)),
+            }
+        }
+    }
+
+    /// Makes the resource at a security-scoped URL accessible to the app.
+    ///
+    /// Returns `true` if access was granted, in which case a matching call
+    /// to
+    /// [`stop_accessing_security_scoped_resource`](Self::stop_accessing_security_scoped_resource)
+    /// is required once the resource is no longer needed.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsurl/startaccessingsecurityscopedresource()).
+    #[inline]
+    #[doc(alias = "startAccessingSecurityScopedResource")]
+    pub fn start_accessing_security_scoped_resource(&self) -> bool {
+        unsafe { _msg_send_any![self, startAccessingSecurityScopedResource => BOOL] }.into()
+    }
+
+    /// Revokes access granted by
+    /// [`start_accessing_security_scoped_resource`](Self::start_accessing_security_scoped_resource).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsurl/stopaccessingsecurityscopedresource()).
+    #[inline]
+    #[doc(alias = "stopAccessingSecurityScopedResource")]
+    pub fn stop_accessing_security_scoped_resource(&self) {
+        unsafe { _msg_send_any![self, stopAccessingSecurityScopedResource] }
+    }
+
+    /// Calls `f` while the resource at a security-scoped URL is accessible.
+    ///
+    /// The argument to `f` is `true` if access was granted. Access is
+    /// revoked after `f` returns or panics.
+    #[inline]
+    pub fn with_security_scoped_access<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce(bool) -> R,
+    {
+        struct Stop<'a>(&'a NSURL);
+
+        impl Drop for Stop<'_> {
+            #[inline]
+            fn drop(&mut self) {
+                self.0.stop_accessing_security_scoped_resource();
+            }
+        }
+
+        if self.start_accessing_security_scoped_resource() {
+            let _stop = Stop(self);
+            f(true)
+        } else {
+            f(false)
+        }
+    }
+
+    /// Returns the resource values stored in bookmark data for `keys`,
+    /// without resolving it.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsurl/resourcevalues(forkeys:frombookmarkdata:)).
+    #[inline]
+    #[doc(alias = "resourceValuesForKeys:fromBookmarkData:")]
+    pub fn bookmark_resource_values(
+        keys: &NSArray<NSURLResourceKey>,
+        data: &NSData,
+    ) -> Option<Arc<NSDictionary<NSURLResourceKey>>> {
+        unsafe {
+            _msg_send_any![
+                Self::class(),
+                resourceValuesForKeys: keys
+                fromBookmarkData: data
+                => *const NSDictionary<NSURLResourceKey>
+            ]
+            .as_ref()
+            .map(Arc::retain)
+        }
+    }
+}
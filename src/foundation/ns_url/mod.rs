@@ -0,0 +1,405 @@
+use super::{NSArray, NSString};
+use crate::core::Arc;
+use crate::objc::{ClassType, NSObject, Sel, BOOL};
+use std::fmt;
+
+mod bookmark;
+mod resource;
+
+pub use bookmark::*;
+pub use resource::*;
+
+objc_subclass! {
+    /// A reference to a local file or a network resource.
+    ///
+    /// This is toll-free bridged with
+    /// [`CFURL`](crate::core_foundation::CFURL) when the **`core_foundation`**
+    /// feature is enabled.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsurl).
+    pub class NSURL: NSObject<'static>;
+}
+
+impl fmt::Debug for NSURL {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.absolute_string().fmt(f)
+    }
+}
+
+impl fmt::Display for NSURL {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.absolute_string().fmt(f)
+    }
+}
+
+impl NSURL {
+    /// Parses `string` as a URL, returning [`None`] if it is malformed.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsurl/init(string:)).
+    #[inline]
+    #[doc(alias = "initWithString")]
+    #[doc(alias = "initWithString:")]
+    pub fn from_string(string: &NSString) -> Option<Arc<Self>> {
+        unsafe {
+            let value: Arc<Self> = Self::class().alloc();
+
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(obj: Arc<NSURL>, sel: Sel, string: &NSString)
+                    -> Option<Arc<NSURL>>;
+            }
+
+            objc_msgSend(value, selector!(initWithString:), string)
+        }
+    }
+
+    /// Parses `string` as a URL relative to `base`, returning [`None`] if it
+    /// is malformed.
+    ///
+    /// The result keeps a reference to `base`. Use
+    /// [`absolute_url`](Self::absolute_url) or [`join`](Self::join) to get
+    /// the resolved URL.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsurl/init(string:relativeto:)).
+    #[inline]
+    #[doc(alias = "initWithString:relativeToURL:")]
+    pub fn from_string_relative_to(string: &NSString, base: &NSURL) -> Option<Arc<Self>> {
+        unsafe {
+            let value: Arc<Self> = Self::class().alloc();
+
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(
+                    obj: Arc<NSURL>,
+                    sel: Sel,
+                    string: &NSString,
+                    base: &NSURL,
+                ) -> Option<Arc<NSURL>>;
+            }
+
+            objc_msgSend(
+                value,
+                selector!(initWithString:relativeToURL:),
+                string,
+                base,
+            )
+        }
+    }
+
+    /// Creates a file URL for `path`.
+    ///
+    /// Relative paths are resolved against the current directory. The file
+    /// system is checked to determine whether `path` is a directory; use
+    /// [`from_directory_path`](Self::from_directory_path) to avoid this.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsurl/init(fileurlwithpath:)).
+    #[inline]
+    #[doc(alias = "fileURLWithPath")]
+    #[doc(alias = "fileURLWithPath:")]
+    pub fn from_file_path(path: &NSString) -> Arc<Self> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![
+                Self::class(),
+                fileURLWithPath: path
+                => *const Self
+            ])
+        }
+    }
+
+    /// Creates a file URL for `path` that is treated as a directory, without
+    /// checking the file system.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsurl/init(fileurlwithpath:isdirectory:)).
+    #[inline]
+    #[doc(alias = "fileURLWithPath:isDirectory:")]
+    pub fn from_directory_path(path: &NSString) -> Arc<Self> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![
+                Self::class(),
+                fileURLWithPath: path
+                isDirectory: BOOL::YES
+                => *const Self
+            ])
+        }
+    }
+
+    /// Resolves `reference` against `self` as a base URL, as described in
+    /// [RFC 3986](https://tools.ietf.org/html/rfc3986#section-5).
+    ///
+    /// Returns [`None`] if `reference` is malformed.
+    #[inline]
+    pub fn join(&self, reference: &NSString) -> Option<Arc<Self>> {
+        Some(Self::from_string_relative_to(reference, self)?.absolute_url())
+    }
+
+    /// Returns the absolute form of `self`, resolved against its base URL.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsurl/absoluteurl).
+    #[inline]
+    #[doc(alias = "absoluteURL")]
+    pub fn absolute_url(&self) -> Arc<Self> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, absoluteURL => *const Self]) }
+    }
+
+    /// Returns the absolute form of `self` as a string.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsurl/absolutestring).
+    #[inline]
+    #[doc(alias = "absoluteString")]
+    pub fn absolute_string(&self) -> Arc<NSString<'static>> {
+        unsafe {
+            let string = _msg_send_any![self, absoluteString => *const NSString<'static>];
+            Arc::retain_raw(string)
+        }
+    }
+
+    /// Returns the string used to create `self`, without its base URL.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsurl/relativestring).
+    #[inline]
+    #[doc(alias = "relativeString")]
+    pub fn relative_string(&self) -> Arc<NSString<'static>> {
+        unsafe {
+            let string = _msg_send_any![self, relativeString => *const NSString<'static>];
+            Arc::retain_raw(string)
+        }
+    }
+
+    /// Returns the base URL that `self` is relative to, if any.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsurl/baseurl).
+    #[inline]
+    #[doc(alias = "baseURL")]
+    pub fn base_url(&self) -> Option<Arc<Self>> {
+        unsafe {
+            _msg_send_any![self, baseURL => *const Self]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Returns `true` if the scheme of `self` is `file`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsurl/isfileurl).
+    #[inline]
+    #[doc(alias = "isFileURL")]
+    pub fn is_file_url(&self) -> bool {
+        unsafe { _msg_send_any![self, isFileURL => BOOL] }.into()
+    }
+
+    /// Returns `true` if `self` refers to a file by its identifier rather
+    /// than by its path, such as `file:///.file/id=6571367.2773272/`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsurl/isfilereferenceurl()).
+    #[inline]
+    #[doc(alias = "isFileReferenceURL")]
+    pub fn is_file_reference_url(&self) -> bool {
+        unsafe { _msg_send_any![self, isFileReferenceURL => BOOL] }.into()
+    }
+
+    /// Returns a file reference URL for the file at `self`, which keeps
+    /// referring to the file if it is moved or renamed.
+    ///
+    /// Returns [`None`] if `self` is not a file URL or the file does not
+    /// exist.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsurl/filereferenceurl()).
+    #[inline]
+    #[doc(alias = "fileReferenceURL")]
+    pub fn file_reference_url(&self) -> Option<Arc<Self>> {
+        unsafe {
+            _msg_send_any![self, fileReferenceURL => *const Self]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Returns the path-based form of a file URL, resolving file references.
+    ///
+    /// Returns [`None`] if `self` is not a file URL or a file reference
+    /// could not be resolved.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsurl/filepathurl).
+    #[inline]
+    #[doc(alias = "filePathURL")]
+    pub fn file_path_url(&self) -> Option<Arc<Self>> {
+        unsafe {
+            _msg_send_any![self, filePathURL => *const Self]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Returns `self` with `.` and `..` path components resolved.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsurl/standardized).
+    #[inline]
+    #[doc(alias = "standardizedURL")]
+    pub fn standardized(&self) -> Option<Arc<Self>> {
+        unsafe {
+            _msg_send_any![self, standardizedURL => *const Self]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Returns a file URL with its path standardized, such as by expanding
+    /// `~` and removing `/private` from paths that exist without it.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsurl/standardizingpath).
+    #[inline]
+    #[doc(alias = "URLByStandardizingPath")]
+    pub fn standardizing_path(&self) -> Arc<Self> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, URLByStandardizingPath => *const Self]) }
+    }
+
+    /// Returns a file URL with symbolic links in its path resolved.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsurl/resolvingsymlinksinpath).
+    #[inline]
+    #[doc(alias = "URLByResolvingSymlinksInPath")]
+    pub fn resolving_symlinks_in_path(&self) -> Arc<Self> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![
+                self,
+                URLByResolvingSymlinksInPath => *const Self
+            ])
+        }
+    }
+}
+
+/// Path components.
+impl NSURL {
+    /// Returns the components of the path of `self`, with `/` as the first
+    /// component of absolute paths.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsurl/pathcomponents).
+    #[inline]
+    #[doc(alias = "pathComponents")]
+    pub fn path_components(&self) -> Option<Arc<NSArray<NSString<'static>>>> {
+        unsafe {
+            _msg_send_any![self, pathComponents => *const NSArray<NSString<'static>>]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Returns the last component of the path of `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsurl/lastpathcomponent).
+    #[inline]
+    #[doc(alias = "lastPathComponent")]
+    pub fn last_path_component(&self) -> Option<Arc<NSString<'static>>> {
+        unsafe {
+            _msg_send_any![self, lastPathComponent => *const NSString<'static>]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Returns the extension of the last component of the path of `self`,
+    /// or an empty string if there is none.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsurl/pathextension).
+    #[inline]
+    #[doc(alias = "pathExtension")]
+    pub fn path_extension(&self) -> Option<Arc<NSString<'static>>> {
+        unsafe {
+            _msg_send_any![self, pathExtension => *const NSString<'static>]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Returns a URL made by appending `component` to the path of `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsurl/appendingpathcomponent(_:)).
+    #[inline]
+    #[doc(alias = "URLByAppendingPathComponent")]
+    #[doc(alias = "URLByAppendingPathComponent:")]
+    pub fn appending_path_component(&self, component: &NSString) -> Arc<Self> {
+        unsafe {
+            let url = _msg_send_any![self, URLByAppendingPathComponent: component => *const Self];
+            Arc::retain_raw(url)
+        }
+    }
+
+    /// Returns a URL made by removing the last path component of `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsurl/deletinglastpathcomponent()).
+    #[inline]
+    #[doc(alias = "URLByDeletingLastPathComponent")]
+    pub fn deleting_last_path_component(&self) -> Arc<Self> {
+        unsafe {
+            let url = _msg_send_any![self, URLByDeletingLastPathComponent => *const Self];
+            Arc::retain_raw(url)
+        }
+    }
+    /// Returns a URL made by appending `extension` to the last path
+    /// component of `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsurl/appendingpathextension(_:)).
+    #[inline]
+    #[doc(alias = "URLByAppendingPathExtension")]
+    #[doc(alias = "URLByAppendingPathExtension:")]
+    pub fn appending_path_extension(&self, extension: &NSString) -> Arc<Self> {
+        unsafe {
+            let url = _msg_send_any![self, URLByAppendingPathExtension: extension => *const Self];
+            Arc::retain_raw(url)
+        }
+    }
+
+    /// Returns a URL made by removing the extension of the last path
+    /// component of `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsurl/deletingpathextension()).
+    #[inline]
+    #[doc(alias = "URLByDeletingPathExtension")]
+    pub fn deleting_path_extension(&self) -> Arc<Self> {
+        unsafe {
+            let url = _msg_send_any![self, URLByDeletingPathExtension => *const Self];
+            Arc::retain_raw(url)
+        }
+    }
+}
+
+macro_rules! url_component {
+    ($(#[doc = $doc:literal] $sel:ident;)+) => {
+        /// Components.
+        ///
+        /// These return [`None`] if `self` does not have the component. Use
+        /// [`NSURLComponents`](super::NSURLComponents) to modify them.
+        impl NSURL {
+            $(
+                #[doc = $doc]
+                #[inline]
+                pub fn $sel(&self) -> Option<Arc<NSString<'static>>> {
+                    unsafe {
+                        _msg_send_any![self, $sel => *const NSString<'static>]
+                            .as_ref()
+                            .map(Arc::retain)
+                    }
+                }
+            )+
+        }
+    };
+}
+
+url_component! {
+    /// Returns the scheme, such as `https`.
+    scheme;
+    /// Returns the user name.
+    user;
+    /// Returns the password.
+    password;
+    /// Returns the host.
+    host;
+    /// Returns the path, with percent-encoding removed.
+    path;
+    /// Returns the query, without the leading `?`.
+    query;
+    /// Returns the fragment, without the leading `#`.
+    fragment;
+}
@@ -0,0 +1,358 @@
+use super::NSURL;
+use crate::core::{Arc, ObjectType};
+use crate::foundation::{NSDate, NSError, NSNumber};
+use crate::objc::{NSObject, BOOL};
+use std::ptr;
+
+#[cfg(target_os = "macos")]
+use crate::foundation::{NSArray, NSString};
+
+ns_string_wrapper! {
+    /// A key for a property of the file system resource at an
+    /// [`NSURL`](super::NSURL).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/urlresourcekey).
+    #[derive(Ord, PartialOrd, Eq, PartialEq)]
+    pub wrapper NSURLResourceKey;
+}
+
+macro_rules! key {
+    (
+        $(#[$docs:meta])+
+        $fn:ident $value:literal
+    ) => {
+        $(#[$docs])+
+        #[inline]
+        #[doc(alias = $value)]
+        pub fn $fn() -> &'static NSURLResourceKey {
+            extern "C" {
+                #[link_name = $value]
+                static VALUE: &'static NSURLResourceKey;
+            }
+            unsafe { VALUE }
+        }
+    };
+}
+
+impl NSURLResourceKey {
+    key! {
+        /// The name of the resource in the file system, as an
+        /// [`NSString`](crate::foundation::NSString).
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/foundation/urlresourcekey/namekey).
+        name "NSURLNameKey"
+    }
+
+    key! {
+        /// Whether the resource is a regular file, as an [`NSNumber`].
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/foundation/urlresourcekey/isregularfilekey).
+        is_regular_file "NSURLIsRegularFileKey"
+    }
+
+    key! {
+        /// Whether the resource is a directory, as an [`NSNumber`].
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/foundation/urlresourcekey/isdirectorykey).
+        is_directory "NSURLIsDirectoryKey"
+    }
+
+    key! {
+        /// Whether the resource is a symbolic link, as an [`NSNumber`].
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/foundation/urlresourcekey/issymboliclinkkey).
+        is_symbolic_link "NSURLIsSymbolicLinkKey"
+    }
+
+    key! {
+        /// Whether the resource is hidden from the user, as an
+        /// [`NSNumber`].
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/foundation/urlresourcekey/ishiddenkey).
+        is_hidden "NSURLIsHiddenKey"
+    }
+
+    key! {
+        /// The time the resource was created, as an [`NSDate`].
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/foundation/urlresourcekey/creationdatekey).
+        creation_date "NSURLCreationDateKey"
+    }
+
+    key! {
+        /// The time the contents of the resource were last modified, as an
+        /// [`NSDate`].
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/foundation/urlresourcekey/contentmodificationdatekey).
+        content_modification_date "NSURLContentModificationDateKey"
+    }
+
+    key! {
+        /// The size of the file in bytes, as an [`NSNumber`].
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/foundation/urlresourcekey/filesizekey).
+        file_size "NSURLFileSizeKey"
+    }
+
+    key! {
+        /// The root directory of the volume that contains the resource, as
+        /// an [`NSURL`].
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/foundation/urlresourcekey/volumeurlkey).
+        volume_url "NSURLVolumeURLKey"
+    }
+
+    key! {
+        /// Whether the volume is stored on a local device, as an
+        /// [`NSNumber`].
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/foundation/urlresourcekey/volumeislocalkey).
+        volume_is_local "NSURLVolumeIsLocalKey"
+    }
+
+    key! {
+        /// The size of the volume in bytes, as an [`NSNumber`].
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/foundation/urlresourcekey/volumetotalcapacitykey).
+        volume_total_capacity "NSURLVolumeTotalCapacityKey"
+    }
+
+    key! {
+        /// The free space on the volume in bytes, as an [`NSNumber`].
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/foundation/urlresourcekey/volumeavailablecapacitykey).
+        volume_available_capacity "NSURLVolumeAvailableCapacityKey"
+    }
+
+    key! {
+        /// Whether the volume supports copy-on-write clones of files, as an
+        /// [`NSNumber`].
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/foundation/urlresourcekey/volumesupportsfilecloningkey).
+        volume_supports_file_cloning "NSURLVolumeSupportsFileCloningKey"
+    }
+
+    key! {
+        /// Whether the volume supports sparse files, as an [`NSNumber`].
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/foundation/urlresourcekey/volumesupportssparsefileskey).
+        volume_supports_sparse_files "NSURLVolumeSupportsSparseFilesKey"
+    }
+
+    key! {
+        /// Whether file names on the volume are case-sensitive, as an
+        /// [`NSNumber`].
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/foundation/urlresourcekey/volumesupportscasesensitivenameskey).
+        volume_supports_case_sensitive_names "NSURLVolumeSupportsCaseSensitiveNamesKey"
+    }
+
+    key! {
+        /// Whether the volume supports hard links, as an [`NSNumber`].
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/foundation/urlresourcekey/volumesupportshardlinkskey).
+        volume_supports_hard_links "NSURLVolumeSupportsHardLinksKey"
+    }
+
+    key! {
+        /// The names of the Finder tags assigned to the resource, as an
+        /// [`NSArray`](crate::foundation::NSArray) of
+        /// [`NSString`](crate::foundation::NSString).
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/foundation/urlresourcekey/tagnameskey).
+        #[cfg(target_os = "macos")]
+        tag_names "NSURLTagNamesKey"
+    }
+}
+
+/// Resource values.
+///
+/// These read properties of the file system resource at a file URL. Values
+/// are cached by `self`, so use
+/// [`remove_cached_resource_values`](Self::remove_cached_resource_values) to
+/// read the resource again.
+impl NSURL {
+    /// Returns the value of the resource property for `key`, or [`None`] if
+    /// the resource does not have the property.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsurl/getresourcevalue(_:forkey:)).
+    #[inline]
+    #[doc(alias = "getResourceValue:forKey:error:")]
+    pub fn resource_value(
+        &self,
+        key: &NSURLResourceKey,
+    ) -> Result<Option<Arc<NSObject<'static>>>, Arc<NSError<'static>>> {
+        unsafe { self.resource_value_as(key) }
+    }
+
+    /// Returns the resource property for `key` as `T`.
+    ///
+    /// # Safety
+    ///
+    /// The value for `key` must be an instance of `T`.
+    unsafe fn resource_value_as<T: ObjectType>(
+        &self,
+        key: &NSURLResourceKey,
+    ) -> Result<Option<Arc<T>>, Arc<NSError<'static>>> {
+        let mut value: *const T = ptr::null();
+        let mut error: *const NSError<'static> = ptr::null();
+        let success = _msg_send_any![
+            self,
+            getResourceValue: &mut value
+            forKey: key
+            error: &mut error
+            => BOOL
+        ];
+
+        if success.into() {
+            Ok(value.as_ref().map(|value| Arc::retain(value)))
+        } else {
+            Err(Arc::retain_raw(error))
+        }
+    }
+
+    /// Sets the value of the resource property for `key`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsurl/setresourcevalue(_:forkey:)).
+    #[inline]
+    #[doc(alias = "setResourceValue:forKey:error:")]
+    pub fn set_resource_value(
+        &self,
+        value: Option<&NSObject>,
+        key: &NSURLResourceKey,
+    ) -> Result<(), Arc<NSError<'static>>> {
+        unsafe {
+            let mut error: *const NSError<'static> = ptr::null();
+            let success = _msg_send_any![
+                self,
+                setResourceValue: value
+                forKey: key
+                error: &mut error
+                => BOOL
+            ];
+
+            if success.into() {
+                Ok(())
+            } else {
+                Err(Arc::retain_raw(error))
+            }
+        }
+    }
+
+    /// Discards all cached resource values of `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsurl/removeallcachedresourcevalues()).
+    #[inline]
+    #[doc(alias = "removeAllCachedResourceValues")]
+    pub fn remove_cached_resource_values(&self) {
+        unsafe { _msg_send_any![self, removeAllCachedResourceValues] }
+    }
+
+    /// Returns `Ok` if the resource at a file URL can be reached.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsurl/checkresourceisreachableandreturnerror(_:)).
+    #[inline]
+    #[doc(alias = "checkResourceIsReachableAndReturnError:")]
+    pub fn check_resource_is_reachable(&self) -> Result<(), Arc<NSError<'static>>> {
+        unsafe {
+            let mut error: *const NSError<'static> = ptr::null();
+            let success = _msg_send_any![
+                self,
+                checkResourceIsReachableAndReturnError: &mut error
+                => BOOL
+            ];
+
+            if success.into() {
+                Ok(())
+            } else {
+                Err(Arc::retain_raw(error))
+            }
+        }
+    }
+
+    /// Returns `true` if the resource is a directory.
+    ///
+    /// See [`NSURLResourceKey::is_directory`].
+    #[inline]
+    pub fn is_directory(&self) -> Result<bool, Arc<NSError<'static>>> {
+        let value =
+            unsafe { self.resource_value_as::<NSNumber>(NSURLResourceKey::is_directory())? };
+        Ok(matches!(value, Some(value) if value.bool_value()))
+    }
+
+    /// Returns the size of the file in bytes, or [`None`] if the resource is
+    /// not a regular file.
+    ///
+    /// See [`NSURLResourceKey::file_size`].
+    #[inline]
+    pub fn file_size(&self) -> Result<Option<u64>, Arc<NSError<'static>>> {
+        let value = unsafe { self.resource_value_as::<NSNumber>(NSURLResourceKey::file_size())? };
+        Ok(value.map(|value| value.unsigned_longlong_value()))
+    }
+
+    /// Returns `true` if the volume that contains the resource supports
+    /// copy-on-write clones, such as with
+    /// [`apfs::clone_file`](crate::core_services::apfs::clone_file).
+    ///
+    /// See [`NSURLResourceKey::volume_supports_file_cloning`].
+    #[inline]
+    pub fn volume_supports_file_cloning(&self) -> Result<bool, Arc<NSError<'static>>> {
+        let value = unsafe {
+            self.resource_value_as::<NSNumber>(NSURLResourceKey::volume_supports_file_cloning())?
+        };
+        Ok(matches!(value, Some(value) if value.bool_value()))
+    }
+
+    /// Returns the free space in bytes on the volume that contains the
+    /// resource.
+    ///
+    /// See [`NSURLResourceKey::volume_available_capacity`].
+    #[inline]
+    pub fn volume_available_capacity(&self) -> Result<Option<u64>, Arc<NSError<'static>>> {
+        let value = unsafe {
+            self.resource_value_as::<NSNumber>(NSURLResourceKey::volume_available_capacity())?
+        };
+        Ok(value.map(|value| value.unsigned_longlong_value()))
+    }
+
+    /// Returns the time the resource was created.
+    ///
+    /// See [`NSURLResourceKey::creation_date`].
+    #[inline]
+    pub fn creation_date(&self) -> Result<Option<Arc<NSDate>>, Arc<NSError<'static>>> {
+        unsafe { self.resource_value_as(NSURLResourceKey::creation_date()) }
+    }
+
+    /// Returns the time the contents of the resource were last modified.
+    ///
+    /// See [`NSURLResourceKey::content_modification_date`].
+    #[inline]
+    pub fn content_modification_date(&self) -> Result<Option<Arc<NSDate>>, Arc<NSError<'static>>> {
+        unsafe { self.resource_value_as(NSURLResourceKey::content_modification_date()) }
+    }
+
+    /// Returns the names of the Finder tags assigned to the resource.
+    ///
+    /// Unlike the Spotlight `kMDItemUserTags` attribute, this reads the tags
+    /// from the file system and so is never stale.
+    ///
+    /// See [`NSURLResourceKey::tag_names`].
+    #[cfg(target_os = "macos")]
+    #[inline]
+    pub fn tag_names(&self) -> Result<Arc<NSArray<NSString<'static>>>, Arc<NSError<'static>>> {
+        let value = unsafe { self.resource_value_as(NSURLResourceKey::tag_names())? };
+        Ok(value.unwrap_or_default())
+    }
+
+    /// Replaces the Finder tags assigned to the resource with `names`.
+    ///
+    /// Tags that have not been used before are added to the Finder sidebar
+    /// without a color.
+    ///
+    /// See [`NSURLResourceKey::tag_names`].
+    #[cfg(target_os = "macos")]
+    #[inline]
+    pub fn set_tag_names(&self, names: &NSArray<NSString>) -> Result<(), Arc<NSError<'static>>> {
+        self.set_resource_value(Some(names), NSURLResourceKey::tag_names())
+    }
+}
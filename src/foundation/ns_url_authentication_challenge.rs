@@ -0,0 +1,296 @@
+use super::{NSError, NSURLAuthenticationMethod, NSURLCredential, NSURLProtectionSpace};
+use crate::core::Arc;
+use crate::objc::{Block, NSInteger, NSObject, RcBlock};
+use std::{fmt, ptr};
+
+#[cfg(feature = "security")]
+use crate::security::SecTrust;
+
+objc_subclass! {
+    /// A request for authentication from a server.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/urlauthenticationchallenge).
+    pub class NSURLAuthenticationChallenge: NSObject<'static>;
+}
+
+/// The kind of an [`NSURLAuthenticationChallenge`], based on its
+/// [authentication method](NSURLProtectionSpace::authentication_method).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum NSURLAuthenticationChallengeKind {
+    /// The server's certificate chain should be evaluated, and accepted with
+    /// [`NSURLCredential::for_trust`] or rejected.
+    ServerTrust,
+
+    /// The server requests a client certificate, which is provided with
+    /// [`NSURLCredential::with_identity`].
+    ClientCertificate,
+
+    /// HTTP basic authentication with a user name and password.
+    HTTPBasic,
+
+    /// HTTP digest authentication with a user name and password.
+    HTTPDigest,
+
+    /// NTLM authentication.
+    NTLM,
+
+    /// Kerberos or NTLM authentication negotiated with the server.
+    Negotiate,
+
+    /// Any other method, such as the protocol's
+    /// [default](NSURLAuthenticationMethod::protocol_default).
+    Other,
+}
+
+impl NSURLAuthenticationChallenge {
+    /// Returns the server or area that requires authentication.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/urlauthenticationchallenge/protectionspace).
+    #[inline]
+    #[doc(alias = "protectionSpace")]
+    pub fn protection_space(&self) -> Arc<NSURLProtectionSpace> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![
+                self,
+                protectionSpace => *const NSURLProtectionSpace
+            ])
+        }
+    }
+
+    /// Returns the kind of challenge, which determines how it should be
+    /// answered.
+    pub fn kind(&self) -> NSURLAuthenticationChallengeKind {
+        use NSURLAuthenticationChallengeKind as Kind;
+
+        let method = self.protection_space().authentication_method();
+        let method = &*method;
+
+        if method == NSURLAuthenticationMethod::server_trust() {
+            Kind::ServerTrust
+        } else if method == NSURLAuthenticationMethod::client_certificate() {
+            Kind::ClientCertificate
+        } else if method == NSURLAuthenticationMethod::http_basic() {
+            Kind::HTTPBasic
+        } else if method == NSURLAuthenticationMethod::http_digest() {
+            Kind::HTTPDigest
+        } else if method == NSURLAuthenticationMethod::ntlm() {
+            Kind::NTLM
+        } else if method == NSURLAuthenticationMethod::negotiate() {
+            Kind::Negotiate
+        } else {
+            Kind::Other
+        }
+    }
+
+    /// Returns the server's certificate chain if this is a
+    /// [server trust](NSURLAuthenticationChallengeKind::ServerTrust)
+    /// challenge.
+    ///
+    /// The trust can be customized, such as with
+    /// [pinned anchors](SecTrust::set_anchor_certificates), before being
+    /// [evaluated](SecTrust::evaluate).
+    #[cfg(feature = "security")]
+    #[inline]
+    pub fn server_trust(&self) -> Option<Arc<SecTrust>> {
+        self.protection_space().server_trust()
+    }
+
+    /// Returns a credential suggested by the system, such as one stored in
+    /// the keychain for the protection space.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/urlauthenticationchallenge/proposedcredential).
+    #[inline]
+    #[doc(alias = "proposedCredential")]
+    pub fn proposed_credential(&self) -> Option<Arc<NSURLCredential>> {
+        unsafe {
+            _msg_send_any![self, proposedCredential => *const NSURLCredential]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Returns the number of times authentication has already failed for
+    /// this protection space.
+    ///
+    /// A non-zero count usually means a previously provided password was
+    /// wrong, so it should not be provided again.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/urlauthenticationchallenge/previousfailurecount).
+    #[inline]
+    #[doc(alias = "previousFailureCount")]
+    pub fn previous_failure_count(&self) -> NSInteger {
+        unsafe { _msg_send_any![self, previousFailureCount] }
+    }
+
+    /// Returns the error from the last failed authentication attempt, if
+    /// any.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/urlauthenticationchallenge/error).
+    #[inline]
+    pub fn error(&self) -> Option<Arc<NSError<'static>>> {
+        unsafe {
+            _msg_send_any![self, error => *const NSError<'static>]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+}
+
+/// How to answer an [`NSURLAuthenticationChallenge`].
+///
+/// See [documentation](https://developer.apple.com/documentation/foundation/urlsession/authchallengedisposition).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NSURLSessionAuthChallengeDisposition(pub NSInteger);
+
+impl Default for NSURLSessionAuthChallengeDisposition {
+    #[inline]
+    fn default() -> Self {
+        Self::PERFORM_DEFAULT_HANDLING
+    }
+}
+
+impl NSURLSessionAuthChallengeDisposition {
+    /// Answers with the provided credential.
+    #[doc(alias = "NSURLSessionAuthChallengeUseCredential")]
+    pub const USE_CREDENTIAL: Self = Self(0);
+
+    /// Handles the challenge as if there were no delegate, ignoring the
+    /// provided credential.
+    #[doc(alias = "NSURLSessionAuthChallengePerformDefaultHandling")]
+    pub const PERFORM_DEFAULT_HANDLING: Self = Self(1);
+
+    /// Cancels the request.
+    #[doc(alias = "NSURLSessionAuthChallengeCancelAuthenticationChallenge")]
+    pub const CANCEL_AUTHENTICATION_CHALLENGE: Self = Self(2);
+
+    /// Rejects this challenge so that the next protection space offered by
+    /// the server is tried instead.
+    #[doc(alias = "NSURLSessionAuthChallengeRejectProtectionSpace")]
+    pub const REJECT_PROTECTION_SPACE: Self = Self(3);
+}
+
+/// The block type passed to URL session delegates for answering an
+/// [`NSURLAuthenticationChallenge`].
+pub type NSURLSessionAuthChallengeBlock =
+    Block<(NSURLSessionAuthChallengeDisposition, *const NSURLCredential), ()>;
+
+/// Answers an [`NSURLAuthenticationChallenge`] received by a URL session
+/// delegate.
+///
+/// This may be moved to another thread to answer after asynchronous work,
+/// such as prompting the user. If it is dropped without being called, the
+/// challenge is answered with
+/// [default handling](NSURLSessionAuthChallengeDisposition::PERFORM_DEFAULT_HANDLING).
+pub struct NSURLSessionAuthChallengeCompletionHandler {
+    block: Option<RcBlock<(NSURLSessionAuthChallengeDisposition, *const NSURLCredential), ()>>,
+}
+
+// SAFETY: The completion handler is safe to call from any thread.
+unsafe impl Send for NSURLSessionAuthChallengeCompletionHandler {}
+
+impl fmt::Debug for NSURLSessionAuthChallengeCompletionHandler {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("NSURLSessionAuthChallengeCompletionHandler")
+            .finish()
+    }
+}
+
+impl Drop for NSURLSessionAuthChallengeCompletionHandler {
+    #[inline]
+    fn drop(&mut self) {
+        self.complete_ref(
+            NSURLSessionAuthChallengeDisposition::PERFORM_DEFAULT_HANDLING,
+            None,
+        );
+    }
+}
+
+impl NSURLSessionAuthChallengeCompletionHandler {
+    /// Wraps the completion handler block passed to a URL session delegate.
+    ///
+    /// # Safety
+    ///
+    /// `block` must be the completion handler of
+    /// `URLSession:didReceiveChallenge:completionHandler:` or
+    /// `URLSession:task:didReceiveChallenge:completionHandler:`.
+    #[inline]
+    pub unsafe fn from_block(block: &NSURLSessionAuthChallengeBlock) -> Self {
+        Self {
+            block: Some(RcBlock::copy(block)),
+        }
+    }
+
+    #[inline]
+    fn complete_ref(
+        &mut self,
+        disposition: NSURLSessionAuthChallengeDisposition,
+        credential: Option<&NSURLCredential>,
+    ) {
+        if let Some(block) = self.block.take() {
+            let credential = match credential {
+                Some(credential) => credential,
+                None => ptr::null(),
+            };
+            block.call((disposition, credential));
+        }
+    }
+
+    /// Answers the challenge with `disposition` and an optional
+    /// `credential`.
+    #[inline]
+    pub fn complete(
+        mut self,
+        disposition: NSURLSessionAuthChallengeDisposition,
+        credential: Option<&NSURLCredential>,
+    ) {
+        self.complete_ref(disposition, credential);
+    }
+
+    /// Answers the challenge with `credential`.
+    #[inline]
+    pub fn use_credential(self, credential: &NSURLCredential) {
+        self.complete(
+            NSURLSessionAuthChallengeDisposition::USE_CREDENTIAL,
+            Some(credential),
+        );
+    }
+
+    /// Accepts the server's certificate chain `trust` for a
+    /// [server trust](NSURLAuthenticationChallengeKind::ServerTrust)
+    /// challenge.
+    #[cfg(feature = "security")]
+    #[inline]
+    pub fn use_server_trust(self, trust: &SecTrust) {
+        self.use_credential(&NSURLCredential::for_trust(trust));
+    }
+
+    /// Lets the system handle the challenge, as if the delegate did not
+    /// handle it.
+    #[inline]
+    pub fn perform_default_handling(self) {
+        self.complete(
+            NSURLSessionAuthChallengeDisposition::PERFORM_DEFAULT_HANDLING,
+            None,
+        );
+    }
+
+    /// Cancels the request.
+    #[inline]
+    pub fn cancel(self) {
+        self.complete(
+            NSURLSessionAuthChallengeDisposition::CANCEL_AUTHENTICATION_CHALLENGE,
+            None,
+        );
+    }
+
+    /// Rejects the challenge so that the next protection space is tried.
+    #[inline]
+    pub fn reject_protection_space(self) {
+        self.complete(
+            NSURLSessionAuthChallengeDisposition::REJECT_PROTECTION_SPACE,
+            None,
+        );
+    }
+}
@@ -0,0 +1,333 @@
+use super::{NSArray, NSCharacterSet, NSNumber, NSString, NSURLQueryItem, NSURL};
+use crate::core::Arc;
+use crate::objc::{ClassType, NSObject, Sel, BOOL};
+
+objc_subclass! {
+    /// A structure that parses URLs into and constructs URLs from their
+    /// components.
+    ///
+    /// Components are accessed either as decoded strings, which are
+    /// percent-encoded as needed when the URL is built, or in their
+    /// percent-encoded form.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsurlcomponents).
+    pub class NSURLComponents: NSObject<'static>;
+}
+
+impl NSURLComponents {
+    /// Creates an empty set of components.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsurlcomponents/init()).
+    #[inline]
+    pub fn new() -> Arc<Self> {
+        unsafe { Self::class().alloc_init() }
+    }
+
+    /// Parses the components of `string`, returning [`None`] if it is a
+    /// malformed URL.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsurlcomponents/init(string:)).
+    #[inline]
+    #[doc(alias = "initWithString")]
+    #[doc(alias = "initWithString:")]
+    pub fn from_string(string: &NSString) -> Option<Arc<Self>> {
+        unsafe {
+            let value: Arc<Self> = Self::class().alloc();
+
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(
+                    obj: Arc<NSURLComponents>,
+                    sel: Sel,
+                    string: &NSString,
+                ) -> Option<Arc<NSURLComponents>>;
+            }
+
+            objc_msgSend(value, selector!(initWithString:), string)
+        }
+    }
+
+    /// Parses the components of `url`, returning [`None`] if it is malformed.
+    ///
+    /// If `resolve` is `true` and `url` is relative, its absolute form is
+    /// parsed instead.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsurlcomponents/init(url:resolvingagainstbaseurl:)).
+    #[inline]
+    #[doc(alias = "initWithURL:resolvingAgainstBaseURL:")]
+    pub fn from_url(url: &NSURL, resolve: bool) -> Option<Arc<Self>> {
+        unsafe {
+            let value: Arc<Self> = Self::class().alloc();
+
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(
+                    obj: Arc<NSURLComponents>,
+                    sel: Sel,
+                    url: &NSURL,
+                    resolve: BOOL,
+                ) -> Option<Arc<NSURLComponents>>;
+            }
+
+            objc_msgSend(
+                value,
+                selector!(initWithURL:resolvingAgainstBaseURL:),
+                url,
+                BOOL::from(resolve),
+            )
+        }
+    }
+
+    /// Returns a URL built from the components, or [`None`] if they do not
+    /// form a valid URL.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsurlcomponents/url).
+    #[inline]
+    #[doc(alias = "URL")]
+    pub fn url(&self) -> Option<Arc<NSURL>> {
+        unsafe {
+            _msg_send_any![self, URL => *const NSURL]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Returns a URL built from the components relative to `base`, or [`None`]
+    /// if they do not form a valid URL.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsurlcomponents/url(relativeto:)).
+    #[inline]
+    #[doc(alias = "URLRelativeToURL")]
+    #[doc(alias = "URLRelativeToURL:")]
+    pub fn url_relative_to(&self, base: &NSURL) -> Option<Arc<NSURL>> {
+        unsafe {
+            _msg_send_any![self, URLRelativeToURL: base => *const NSURL]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Returns a URL string built from the components, or [`None`] if they do
+    /// not form a valid URL.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsurlcomponents/string).
+    #[inline]
+    pub fn string(&self) -> Option<Arc<NSString<'static>>> {
+        unsafe {
+            _msg_send_any![self, string => *const NSString<'static>]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Returns the port, if any.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsurlcomponents/port).
+    #[inline]
+    pub fn port(&self) -> Option<Arc<NSNumber>> {
+        unsafe {
+            _msg_send_any![self, port => *const NSNumber]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Sets or removes the port.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsurlcomponents/port).
+    #[inline]
+    #[doc(alias = "setPort")]
+    #[doc(alias = "setPort:")]
+    pub fn set_port(&self, port: Option<&NSNumber>) {
+        unsafe { _msg_send_any![self, setPort: port] }
+    }
+}
+
+/// Query items.
+impl NSURLComponents {
+    /// Returns the items of the query, with percent-encoding removed, or
+    /// [`None`] if there is no query.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsurlcomponents/queryitems).
+    #[inline]
+    #[doc(alias = "queryItems")]
+    pub fn query_items(&self) -> Option<Arc<NSArray<NSURLQueryItem>>> {
+        unsafe {
+            _msg_send_any![self, queryItems => *const NSArray<NSURLQueryItem>]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Sets the query from `items`, which are percent-encoded as needed, or
+    /// removes it.
+    ///
+    /// `&` and `=` in names and values are not encoded, so they would be
+    /// parsed as separators. Use [`set_query_pairs`](Self::set_query_pairs)
+    /// to encode them.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsurlcomponents/queryitems).
+    #[inline]
+    #[doc(alias = "setQueryItems")]
+    #[doc(alias = "setQueryItems:")]
+    pub fn set_query_items(&self, items: Option<&NSArray<NSURLQueryItem>>) {
+        unsafe { _msg_send_any![self, setQueryItems: items] }
+    }
+
+    /// Returns the items of the query in their percent-encoded form, or
+    /// [`None`] if there is no query.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsurlcomponents/percentencodedqueryitems).
+    #[inline]
+    #[doc(alias = "percentEncodedQueryItems")]
+    pub fn percent_encoded_query_items(&self) -> Option<Arc<NSArray<NSURLQueryItem>>> {
+        unsafe {
+            _msg_send_any![self, percentEncodedQueryItems => *const NSArray<NSURLQueryItem>]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Sets the query from percent-encoded `items`, or removes it.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsurlcomponents/percentencodedqueryitems).
+    ///
+    /// # Safety
+    ///
+    /// The names and values of `items` must be validly percent-encoded for a
+    /// query. Otherwise, an Objective-C exception is raised.
+    #[inline]
+    #[doc(alias = "setPercentEncodedQueryItems")]
+    #[doc(alias = "setPercentEncodedQueryItems:")]
+    pub unsafe fn set_percent_encoded_query_items(&self, items: Option<&NSArray<NSURLQueryItem>>) {
+        _msg_send_any![self, setPercentEncodedQueryItems: items]
+    }
+
+    /// Sets the query from `pairs` of names and values.
+    ///
+    /// Unlike [`set_query_items`](Self::set_query_items), this also
+    /// percent-encodes `&`, `=`, and `+` so that names and values round-trip
+    /// exactly through servers that treat them as separators or spaces.
+    pub fn set_query_pairs<I, K, V>(&self, pairs: I)
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        let allowed = query_item_allowed();
+        let encode = |s: &str| {
+            NSString::from_str(s)
+                .adding_percent_encoding(&allowed)
+                .expect("query component is valid UTF-16")
+        };
+
+        let items: Vec<Arc<NSURLQueryItem>> = pairs
+            .into_iter()
+            .map(|(name, value)| {
+                let name = encode(name.as_ref());
+                let value = encode(value.as_ref());
+                NSURLQueryItem::new(&name, Some(&value))
+            })
+            .collect();
+
+        let items: Vec<&NSURLQueryItem> = items.iter().map(|item| &**item).collect();
+
+        // SAFETY: Every name and value was encoded above.
+        unsafe { self.set_percent_encoded_query_items(Some(&NSArray::from_slice(&items))) }
+    }
+}
+
+/// Returns the characters that may appear unencoded in a query item name or
+/// value.
+fn query_item_allowed() -> Arc<NSCharacterSet> {
+    unsafe {
+        let allowed = NSCharacterSet::url_query_allowed();
+        let allowed: Arc<NSCharacterSet> =
+            Arc::from_raw(_msg_send_any![allowed, mutableCopy => *const NSCharacterSet]);
+
+        let separators = NSString::from_str("&=+");
+        let _: () = _msg_send_any![allowed, removeCharactersInString: &*separators];
+
+        allowed
+    }
+}
+
+macro_rules! component {
+    ($($get:ident, $set:ident => $objc_get:ident, $objc_set:ident, $doc:literal;)+) => {
+        /// Components.
+        ///
+        /// Getters return [`None`] if the component is not present, and
+        /// passing [`None`] to a setter removes the component.
+        impl NSURLComponents {
+            $(
+                #[doc = concat!("Returns ", $doc, ".")]
+                #[inline]
+                pub fn $get(&self) -> Option<Arc<NSString<'static>>> {
+                    unsafe {
+                        _msg_send_any![self, $objc_get => *const NSString<'static>]
+                            .as_ref()
+                            .map(Arc::retain)
+                    }
+                }
+
+                #[doc = concat!("Sets ", $doc, ".")]
+                #[inline]
+                pub fn $set(&self, value: Option<&NSString>) {
+                    unsafe { _msg_send_any![self, $objc_set: value] }
+                }
+            )+
+        }
+    };
+}
+
+macro_rules! percent_encoded_component {
+    ($($get:ident, $set:ident => $objc_get:ident, $objc_set:ident, $doc:literal;)+) => {
+        /// Percent-encoded components.
+        ///
+        /// Getters return [`None`] if the component is not present, and
+        /// passing [`None`] to a setter removes the component.
+        impl NSURLComponents {
+            $(
+                #[doc = concat!("Returns ", $doc, " in its percent-encoded form.")]
+                #[inline]
+                pub fn $get(&self) -> Option<Arc<NSString<'static>>> {
+                    unsafe {
+                        _msg_send_any![self, $objc_get => *const NSString<'static>]
+                            .as_ref()
+                            .map(Arc::retain)
+                    }
+                }
+
+                #[doc = concat!("Sets ", $doc, " from its percent-encoded form.")]
+                ///
+                /// # Safety
+                ///
+                /// `value` must be validly percent-encoded for the component.
+                /// Otherwise, an Objective-C exception is raised.
+                #[inline]
+                pub unsafe fn $set(&self, value: Option<&NSString>) {
+                    _msg_send_any![self, $objc_set: value]
+                }
+            )+
+        }
+    };
+}
+
+component! {
+    scheme, set_scheme => scheme, setScheme, "the scheme, such as `https`";
+    user, set_user => user, setUser, "the user name";
+    password, set_password => password, setPassword, "the password";
+    host, set_host => host, setHost, "the host";
+    path, set_path => path, setPath, "the path";
+    query, set_query => query, setQuery, "the query, without the leading `?`";
+    fragment, set_fragment => fragment, setFragment, "the fragment, without the leading `#`";
+}
+
+percent_encoded_component! {
+    percent_encoded_user, set_percent_encoded_user => percentEncodedUser, setPercentEncodedUser, "the user name";
+    percent_encoded_password, set_percent_encoded_password => percentEncodedPassword, setPercentEncodedPassword, "the password";
+    percent_encoded_host, set_percent_encoded_host => percentEncodedHost, setPercentEncodedHost, "the host";
+    percent_encoded_path, set_percent_encoded_path => percentEncodedPath, setPercentEncodedPath, "the path";
+    percent_encoded_query, set_percent_encoded_query => percentEncodedQuery, setPercentEncodedQuery, "the query";
+    percent_encoded_fragment, set_percent_encoded_fragment => percentEncodedFragment, setPercentEncodedFragment, "the fragment";
+}
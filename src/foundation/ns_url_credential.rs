@@ -0,0 +1,199 @@
+use super::NSString;
+use crate::core::Arc;
+use crate::objc::{ClassType, NSObject, NSUInteger, BOOL};
+
+#[cfg(feature = "security")]
+use super::NSArray;
+#[cfg(feature = "security")]
+use crate::security::{SecCertificate, SecIdentity, SecTrust};
+
+/// How long an [`NSURLCredential`] is stored.
+///
+/// See [documentation](https://developer.apple.com/documentation/foundation/urlcredential/persistence).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NSURLCredentialPersistence(pub NSUInteger);
+
+impl Default for NSURLCredentialPersistence {
+    #[inline]
+    fn default() -> Self {
+        Self::FOR_SESSION
+    }
+}
+
+impl NSURLCredentialPersistence {
+    /// The credential is used once and not stored.
+    #[doc(alias = "NSURLCredentialPersistenceNone")]
+    pub const NONE: Self = Self(0);
+
+    /// The credential is stored until the session ends.
+    #[doc(alias = "NSURLCredentialPersistenceForSession")]
+    pub const FOR_SESSION: Self = Self(1);
+
+    /// The credential is stored in the keychain.
+    #[doc(alias = "NSURLCredentialPersistencePermanent")]
+    pub const PERMANENT: Self = Self(2);
+
+    /// The credential is stored in the keychain and synced to the user's
+    /// other devices through iCloud.
+    #[doc(alias = "NSURLCredentialPersistenceSynchronizable")]
+    pub const SYNCHRONIZABLE: Self = Self(3);
+}
+
+objc_subclass! {
+    /// A credential for responding to an
+    /// [`NSURLAuthenticationChallenge`](super::NSURLAuthenticationChallenge).
+    ///
+    /// A credential is either a user name and password, a client identity
+    /// for TLS client certificate authentication, or a server trust that has
+    /// been accepted.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsurlcredential).
+    pub class NSURLCredential: NSObject<'static>;
+}
+
+impl NSURLCredential {
+    /// Returns a credential for HTTP basic, digest, or other password-based
+    /// authentication.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/urlcredential/init(user:password:persistence:)).
+    #[inline]
+    #[doc(alias = "credentialWithUser:password:persistence:")]
+    pub fn with_user(
+        user: &NSString,
+        password: &NSString,
+        persistence: NSURLCredentialPersistence,
+    ) -> Arc<Self> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![
+                Self::class(),
+                credentialWithUser: user
+                password: password
+                persistence: persistence
+                => *const Self
+            ])
+        }
+    }
+
+    /// Returns a credential for TLS client certificate authentication with
+    /// `identity`.
+    ///
+    /// `certificates` are the intermediate certificates to send along with
+    /// the identity's certificate, if the server does not already have them.
+    ///
+    /// Identities can be found in the keychain with
+    /// [`SecItemQuery`](crate::security::SecItemQuery).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/urlcredential/init(identity:certificates:persistence:)).
+    #[cfg(feature = "security")]
+    #[inline]
+    #[doc(alias = "credentialWithIdentity:certificates:persistence:")]
+    pub fn with_identity(
+        identity: &SecIdentity,
+        certificates: Option<&NSArray<SecCertificate>>,
+        persistence: NSURLCredentialPersistence,
+    ) -> Arc<Self> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![
+                Self::class(),
+                credentialWithIdentity: identity
+                certificates: certificates
+                persistence: persistence
+                => *const Self
+            ])
+        }
+    }
+
+    /// Returns a credential that accepts the server's certificate chain
+    /// `trust`, for responding to server trust challenges.
+    ///
+    /// This should only be used after `trust` has been
+    /// [evaluated](SecTrust::evaluate) according to the app's policy.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/urlcredential/init(trust:)).
+    #[cfg(feature = "security")]
+    #[inline]
+    #[doc(alias = "credentialForTrust:")]
+    pub fn for_trust(trust: &SecTrust) -> Arc<Self> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![
+                Self::class(),
+                credentialForTrust: trust
+                => *const Self
+            ])
+        }
+    }
+
+    /// Returns the user name of the credential, if it has one.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/urlcredential/user).
+    #[inline]
+    pub fn user(&self) -> Option<Arc<NSString<'static>>> {
+        unsafe {
+            _msg_send_any![self, user => *const NSString<'static>]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Returns the password of the credential, if it has one.
+    ///
+    /// This may read the password from the keychain, which can prompt the
+    /// user. Use [`has_password`](Self::has_password) to check for a password
+    /// without reading it.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/urlcredential/password).
+    #[inline]
+    pub fn password(&self) -> Option<Arc<NSString<'static>>> {
+        unsafe {
+            _msg_send_any![self, password => *const NSString<'static>]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Returns `true` if the credential has a password.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/urlcredential/haspassword).
+    #[inline]
+    #[doc(alias = "hasPassword")]
+    pub fn has_password(&self) -> bool {
+        unsafe { _msg_send_any![self, hasPassword => BOOL] }.into()
+    }
+
+    /// Returns the client identity of the credential, if it has one.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/urlcredential/identity).
+    #[cfg(feature = "security")]
+    #[inline]
+    pub fn identity(&self) -> Option<Arc<SecIdentity>> {
+        unsafe {
+            _msg_send_any![self, identity => *const SecIdentity]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Returns the intermediate certificates sent along with the client
+    /// identity.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/urlcredential/certificates).
+    #[cfg(feature = "security")]
+    #[inline]
+    pub fn certificates(&self) -> Arc<NSArray<SecCertificate>> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![
+                self,
+                certificates => *const NSArray<SecCertificate>
+            ])
+        }
+    }
+
+    /// Returns how long the credential is stored.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/urlcredential).
+    #[inline]
+    pub fn persistence(&self) -> NSURLCredentialPersistence {
+        unsafe { _msg_send_any![self, persistence] }
+    }
+}
@@ -0,0 +1,257 @@
+use super::{NSArray, NSData, NSString};
+use crate::core::Arc;
+use crate::objc::{ClassType, NSInteger, NSObject, Sel, BOOL};
+
+#[cfg(feature = "security")]
+use crate::security::SecTrust;
+
+ns_string_wrapper! {
+    /// A scheme for authenticating with a server, as used by
+    /// [`NSURLProtectionSpace`].
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/urlprotectionspace).
+    #[derive(Ord, PartialOrd, Eq, PartialEq)]
+    pub wrapper NSURLAuthenticationMethod;
+}
+
+macro_rules! method {
+    (
+        $(#[$docs:meta])+
+        $fn:ident $value:literal
+    ) => {
+        $(#[$docs])+
+        #[inline]
+        #[doc(alias = $value)]
+        pub fn $fn() -> &'static NSURLAuthenticationMethod {
+            extern "C" {
+                #[link_name = $value]
+                static VALUE: &'static NSURLAuthenticationMethod;
+            }
+            unsafe { VALUE }
+        }
+    };
+}
+
+impl NSURLAuthenticationMethod {
+    method! {
+        /// The protocol's default scheme, such as HTTP basic authentication.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/foundation/nsurlauthenticationmethoddefault).
+        protocol_default "NSURLAuthenticationMethodDefault"
+    }
+
+    method! {
+        /// HTTP basic authentication, where a user name and password are sent
+        /// to the server.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/foundation/nsurlauthenticationmethodhttpbasic).
+        http_basic "NSURLAuthenticationMethodHTTPBasic"
+    }
+
+    method! {
+        /// HTTP digest authentication, where only a hash of the password is
+        /// sent to the server.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/foundation/nsurlauthenticationmethodhttpdigest).
+        http_digest "NSURLAuthenticationMethodHTTPDigest"
+    }
+
+    method! {
+        /// Authentication through a web form.
+        ///
+        /// This is never issued by the URL loading system itself.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/foundation/nsurlauthenticationmethodhtmlform).
+        html_form "NSURLAuthenticationMethodHTMLForm"
+    }
+
+    method! {
+        /// NTLM authentication.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/foundation/nsurlauthenticationmethodntlm).
+        ntlm "NSURLAuthenticationMethodNTLM"
+    }
+
+    method! {
+        /// Kerberos or NTLM authentication negotiated with the server.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/foundation/nsurlauthenticationmethodnegotiate).
+        negotiate "NSURLAuthenticationMethodNegotiate"
+    }
+
+    method! {
+        /// TLS client certificate authentication, where the client proves its
+        /// identity with a certificate and private key.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/foundation/nsurlauthenticationmethodclientcertificate).
+        client_certificate "NSURLAuthenticationMethodClientCertificate"
+    }
+
+    method! {
+        /// TLS server trust evaluation, where the client decides whether to
+        /// trust the server's certificate chain.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/foundation/nsurlauthenticationmethodservertrust).
+        server_trust "NSURLAuthenticationMethodServerTrust"
+    }
+}
+
+objc_subclass! {
+    /// A server or area on a server that requires authentication.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsurlprotectionspace).
+    pub class NSURLProtectionSpace: NSObject<'static>;
+}
+
+impl NSURLProtectionSpace {
+    /// Creates a protection space for `host` and `port`.
+    ///
+    /// `protocol` is a URL scheme such as `https`, and `realm` is the realm
+    /// of HTTP basic and digest authentication. If `method` is [`None`], the
+    /// [default method](NSURLAuthenticationMethod::protocol_default) is used.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/urlprotectionspace/init(host:port:protocol:realm:authenticationmethod:)).
+    #[inline]
+    #[doc(alias = "initWithHost:port:protocol:realm:authenticationMethod:")]
+    pub fn new(
+        host: &NSString,
+        port: NSInteger,
+        protocol: Option<&NSString>,
+        realm: Option<&NSString>,
+        method: Option<&NSURLAuthenticationMethod>,
+    ) -> Arc<Self> {
+        unsafe {
+            let space: Arc<Self> = Self::class().alloc();
+
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(
+                    obj: Arc<NSURLProtectionSpace>,
+                    sel: Sel,
+                    host: &NSString,
+                    port: NSInteger,
+                    protocol: Option<&NSString>,
+                    realm: Option<&NSString>,
+                    method: Option<&NSURLAuthenticationMethod>,
+                ) -> Arc<NSURLProtectionSpace>;
+            }
+
+            objc_msgSend(
+                space,
+                selector!(initWithHost:port:protocol:realm:authenticationMethod:),
+                host,
+                port,
+                protocol,
+                realm,
+                method,
+            )
+        }
+    }
+
+    /// Returns the host of the server.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/urlprotectionspace/host).
+    #[inline]
+    pub fn host(&self) -> Arc<NSString<'static>> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, host => *const NSString<'static>]) }
+    }
+
+    /// Returns the port of the server.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/urlprotectionspace/port).
+    #[inline]
+    pub fn port(&self) -> NSInteger {
+        unsafe { _msg_send_any![self, port] }
+    }
+
+    /// Returns the protocol of the server, such as `https`, or [`None`] for a
+    /// proxy.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/urlprotectionspace/protocol).
+    #[inline]
+    pub fn protocol(&self) -> Option<Arc<NSString<'static>>> {
+        unsafe {
+            _msg_send_any![self, protocol => *const NSString<'static>]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Returns the authentication realm, which is only set for HTTP basic
+    /// and digest authentication.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/urlprotectionspace/realm).
+    #[inline]
+    pub fn realm(&self) -> Option<Arc<NSString<'static>>> {
+        unsafe {
+            _msg_send_any![self, realm => *const NSString<'static>]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Returns the scheme for authenticating with the server.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/urlprotectionspace/authenticationmethod).
+    #[inline]
+    #[doc(alias = "authenticationMethod")]
+    pub fn authentication_method(&self) -> Arc<NSURLAuthenticationMethod> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![
+                self,
+                authenticationMethod => *const NSURLAuthenticationMethod
+            ])
+        }
+    }
+
+    /// Returns `true` if the protection space is a proxy server.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/urlprotectionspace/isproxy()).
+    #[inline]
+    #[doc(alias = "isProxy")]
+    pub fn is_proxy(&self) -> bool {
+        unsafe { _msg_send_any![self, isProxy => BOOL] }.into()
+    }
+
+    /// Returns `true` if credentials for the protection space are sent
+    /// securely, such as over TLS or with digest authentication.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/urlprotectionspace/receivescredentialsecurely).
+    #[inline]
+    #[doc(alias = "receivesCredentialSecurely")]
+    pub fn receives_credential_securely(&self) -> bool {
+        unsafe { _msg_send_any![self, receivesCredentialSecurely => BOOL] }.into()
+    }
+
+    /// Returns the trust object for the server's certificate chain, which is
+    /// only set for [server trust](NSURLAuthenticationMethod::server_trust)
+    /// challenges.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/urlprotectionspace/servertrust).
+    #[cfg(feature = "security")]
+    #[inline]
+    #[doc(alias = "serverTrust")]
+    pub fn server_trust(&self) -> Option<Arc<SecTrust>> {
+        unsafe {
+            _msg_send_any![self, serverTrust => *const SecTrust]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Returns the DER-encoded distinguished names of the certificate
+    /// authorities that the server accepts, which are only set for
+    /// [client certificate](NSURLAuthenticationMethod::client_certificate)
+    /// challenges.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/urlprotectionspace/distinguishednames).
+    #[inline]
+    #[doc(alias = "distinguishedNames")]
+    pub fn distinguished_names(&self) -> Option<Arc<NSArray<NSData>>> {
+        unsafe {
+            _msg_send_any![self, distinguishedNames => *const NSArray<NSData>]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use super::NSString;
+use crate::core::Arc;
+use crate::objc::{ClassType, NSObject, Sel};
+
+objc_subclass! {
+    /// A name-value pair in the query of a URL.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsurlqueryitem).
+    pub class NSURLQueryItem: NSObject<'static>;
+}
+
+impl NSURLQueryItem {
+    /// Creates a query item with `name` and an optional `value`.
+    ///
+    /// An item without a value is formatted as just its name, such as
+    /// `?flag`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsurlqueryitem/init(name:value:)).
+    #[inline]
+    #[doc(alias = "initWithName:value:")]
+    pub fn new(name: &NSString, value: Option<&NSString>) -> Arc<Self> {
+        unsafe {
+            let item: Arc<Self> = Self::class().alloc();
+
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(
+                    obj: Arc<NSURLQueryItem>,
+                    sel: Sel,
+                    name: &NSString,
+                    value: Option<&NSString>,
+                ) -> Arc<NSURLQueryItem>;
+            }
+
+            objc_msgSend(item, selector!(initWithName:value:), name, value)
+        }
+    }
+
+    /// Returns the name of the item.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsurlqueryitem/name).
+    #[inline]
+    pub fn name(&self) -> Arc<NSString<'static>> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, name => *const NSString<'static>]) }
+    }
+
+    /// Returns the value of the item, if it has one.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsurlqueryitem/value).
+    #[inline]
+    pub fn value(&self) -> Option<Arc<NSString<'static>>> {
+        unsafe {
+            _msg_send_any![self, value => *const NSString<'static>]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+}
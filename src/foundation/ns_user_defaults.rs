@@ -0,0 +1,177 @@
+use super::{NSData, NSDictionary, NSString};
+use crate::core::Arc;
+use crate::objc::{ClassType, NSInteger, NSObject, Sel, BOOL};
+use std::ptr;
+
+objc_subclass! {
+    /// A persistent store of user preferences, either for the app or for a
+    /// suite shared with other apps, such as an app group.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/userdefaults).
+    pub class NSUserDefaults: NSObject<'static>;
+}
+
+impl NSUserDefaults {
+    /// Returns the defaults of the app.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/userdefaults/standard).
+    #[inline]
+    #[doc(alias = "standardUserDefaults")]
+    pub fn standard() -> &'static Self {
+        unsafe { _msg_send_any![Self::class(), standardUserDefaults] }
+    }
+
+    /// Returns the defaults of the suite named `suite_name`, such as an
+    /// [app group](super::AppGroup::user_defaults) identifier, or [`None`]
+    /// if the name is the app's bundle identifier or `NSGlobalDomain`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/userdefaults/init(suitename:)).
+    #[inline]
+    #[doc(alias = "initWithSuiteName:")]
+    pub fn with_suite_name(suite_name: &NSString) -> Option<Arc<Self>> {
+        unsafe {
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(
+                    obj: Arc<NSUserDefaults>,
+                    sel: Sel,
+                    suite_name: &NSString,
+                ) -> Option<Arc<NSUserDefaults>>;
+            }
+
+            let obj = Self::class().alloc();
+            let sel = selector!(initWithSuiteName:);
+
+            objc_msgSend(obj, sel, suite_name)
+        }
+    }
+
+    /// Returns the value for `key`, or [`None`] if there is none.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/userdefaults/object(forkey:)).
+    #[inline]
+    #[doc(alias = "objectForKey:")]
+    pub fn object_for_key(&self, key: &NSString) -> Option<Arc<NSObject<'static>>> {
+        unsafe {
+            _msg_send_any![self, objectForKey: key => *const NSObject<'static>]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Returns the string for `key`, or [`None`] if there is none or the
+    /// value is not a string or number.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/userdefaults/string(forkey:)).
+    #[inline]
+    #[doc(alias = "stringForKey:")]
+    pub fn string_for_key(&self, key: &NSString) -> Option<Arc<NSString<'static>>> {
+        unsafe {
+            _msg_send_any![self, stringForKey: key => *const NSString<'static>]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Returns the data for `key`, or [`None`] if there is none or the value
+    /// is not data.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/userdefaults/data(forkey:)).
+    #[inline]
+    #[doc(alias = "dataForKey:")]
+    pub fn data_for_key(&self, key: &NSString) -> Option<Arc<NSData>> {
+        unsafe {
+            _msg_send_any![self, dataForKey: key => *const NSData]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Returns the Boolean for `key`, or `false` if there is none.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/userdefaults/bool(forkey:)).
+    #[inline]
+    #[doc(alias = "boolForKey:")]
+    pub fn bool_for_key(&self, key: &NSString) -> bool {
+        unsafe { _msg_send_any![self, boolForKey: key => BOOL] }.into()
+    }
+
+    /// Returns the integer for `key`, or 0 if there is none.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/userdefaults/integer(forkey:)).
+    #[inline]
+    #[doc(alias = "integerForKey:")]
+    pub fn integer_for_key(&self, key: &NSString) -> NSInteger {
+        unsafe { _msg_send_any![self, integerForKey: key] }
+    }
+
+    /// Returns the floating-point number for `key`, or 0.0 if there is none.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/userdefaults/double(forkey:)).
+    #[inline]
+    #[doc(alias = "doubleForKey:")]
+    pub fn double_for_key(&self, key: &NSString) -> f64 {
+        unsafe { _msg_send_any![self, doubleForKey: key] }
+    }
+
+    /// Sets the value for `key`, which must be a property list object such as
+    /// a string, number, date, data, array, or dictionary, or removes it if
+    /// [`None`].
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/userdefaults/set(_:forkey:)-2m1bf).
+    #[inline]
+    #[doc(alias = "setObject:forKey:")]
+    pub fn set_object(&self, value: Option<&NSObject>, key: &NSString) {
+        let value = match value {
+            Some(value) => value,
+            None => ptr::null(),
+        };
+        unsafe { _msg_send_any![self, setObject: value forKey: key => ()] }
+    }
+
+    /// Sets the Boolean for `key`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/userdefaults/set(_:forkey:)-2b8ts).
+    #[inline]
+    #[doc(alias = "setBool:forKey:")]
+    pub fn set_bool(&self, value: bool, key: &NSString) {
+        unsafe { _msg_send_any![self, setBool: BOOL::from(value) forKey: key => ()] }
+    }
+
+    /// Sets the integer for `key`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/userdefaults/set(_:forkey:)-3ojk7).
+    #[inline]
+    #[doc(alias = "setInteger:forKey:")]
+    pub fn set_integer(&self, value: NSInteger, key: &NSString) {
+        unsafe { _msg_send_any![self, setInteger: value forKey: key => ()] }
+    }
+
+    /// Sets the floating-point number for `key`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/userdefaults/set(_:forkey:)-1mwb2).
+    #[inline]
+    #[doc(alias = "setDouble:forKey:")]
+    pub fn set_double(&self, value: f64, key: &NSString) {
+        unsafe { _msg_send_any![self, setDouble: value forKey: key => ()] }
+    }
+
+    /// Removes the value for `key`, so that the
+    /// [registered default](Self::register_defaults) is used, if any.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/userdefaults/removeobject(forkey:)).
+    #[inline]
+    #[doc(alias = "removeObjectForKey:")]
+    pub fn remove_object(&self, key: &NSString) {
+        unsafe { _msg_send_any![self, removeObjectForKey: key => ()] }
+    }
+
+    /// Sets values to use for keys that have no value, which are not saved.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/userdefaults/register(defaults:)).
+    #[inline]
+    #[doc(alias = "registerDefaults:")]
+    pub fn register_defaults(&self, defaults: &NSDictionary<NSString<'static>, NSObject<'static>>) {
+        unsafe { _msg_send_any![self, registerDefaults: defaults => ()] }
+    }
+}
@@ -0,0 +1,86 @@
+use super::{init_with_url, NSUserScriptTask};
+use crate::core::Arc;
+use crate::foundation::{NSError, NSString, NSURL};
+
+#[cfg(feature = "core_services")]
+use super::retain_result;
+#[cfg(feature = "core_services")]
+use crate::{
+    foundation::NSAppleEventDescriptor,
+    objc::{Block, RcBlock},
+};
+#[cfg(feature = "core_services")]
+use std::cell::Cell;
+
+objc_subclass! {
+    /// An AppleScript that the user installed in the app's Application Scripts
+    /// directory.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsuserapplescripttask).
+    pub class NSUserAppleScriptTask: NSUserScriptTask;
+}
+
+impl NSUserAppleScriptTask {
+    /// Creates a task for the AppleScript at `url`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsuserscripttask/init(url:)).
+    #[inline]
+    #[doc(alias = "initWithURL:error:")]
+    pub fn from_url(url: &NSURL) -> Result<Arc<Self>, Arc<NSError<'static>>> {
+        unsafe { init_with_url(url) }
+    }
+
+    /// Creates a task for the AppleScript named `file_name` in the
+    /// [Application Scripts directory](NSUserScriptTask::application_scripts_directory).
+    ///
+    /// `file_name` includes the extension, such as `Backup.scpt`.
+    #[inline]
+    pub fn with_file_name(file_name: &NSString) -> Result<Arc<Self>, Arc<NSError<'static>>> {
+        let url =
+            NSUserScriptTask::application_scripts_directory()?.appending_path_component(file_name);
+        Self::from_url(&url)
+    }
+
+    /// Runs the script by sending it `event`, or by running its top-level
+    /// code if `event` is [`None`].
+    ///
+    /// `completion` is called on an arbitrary thread with the script's result,
+    /// if any.
+    ///
+    /// It is safe to panic within `completion`. Panics will abort the process.
+    ///
+    /// # Feature Flag
+    ///
+    /// This method also requires the **`core_services`**
+    /// [feature flag](../index.html#feature-flags).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsuserapplescripttask/execute(withappleevent:completionhandler:)).
+    #[cfg(feature = "core_services")]
+    #[inline]
+    #[doc(alias = "executeWithAppleEvent:completionHandler:")]
+    pub fn execute_with_event<F>(&self, event: Option<&NSAppleEventDescriptor>, completion: F)
+    where
+        F: FnOnce(Result<Option<Arc<NSAppleEventDescriptor>>, Arc<NSError<'static>>>)
+            + Send
+            + 'static,
+    {
+        let completion = Cell::new(Some(completion));
+        let block = RcBlock::new(
+            move |result: *const NSAppleEventDescriptor, error: *const NSError<'static>| {
+                if let Some(completion) = completion.take() {
+                    completion(unsafe { retain_result(result, error) });
+                }
+            },
+        );
+        let block: &Block<_, ()> = &block;
+
+        unsafe {
+            _msg_send_any![
+                self,
+                executeWithAppleEvent: event
+                completionHandler: block
+                => ()
+            ]
+        }
+    }
+}
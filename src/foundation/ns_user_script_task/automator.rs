@@ -0,0 +1,98 @@
+use super::{init_with_url, retain_result, NSUserScriptTask};
+use crate::core::Arc;
+use crate::foundation::{NSDictionary, NSError, NSString, NSURL};
+use crate::objc::{Block, NSObject, RcBlock};
+use std::cell::Cell;
+
+objc_subclass! {
+    /// An Automator workflow that the user installed in the app's Application
+    /// Scripts directory.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsuserautomatortask).
+    pub class NSUserAutomatorTask: NSUserScriptTask;
+}
+
+impl NSUserAutomatorTask {
+    /// Creates a task for the Automator workflow at `url`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsuserscripttask/init(url:)).
+    #[inline]
+    #[doc(alias = "initWithURL:error:")]
+    pub fn from_url(url: &NSURL) -> Result<Arc<Self>, Arc<NSError<'static>>> {
+        unsafe { init_with_url(url) }
+    }
+
+    /// Creates a task for the Automator workflow named `file_name` in the
+    /// [Application Scripts directory](NSUserScriptTask::application_scripts_directory).
+    ///
+    /// `file_name` includes the extension, such as `Resize.workflow`.
+    #[inline]
+    pub fn with_file_name(file_name: &NSString) -> Result<Arc<Self>, Arc<NSError<'static>>> {
+        let url =
+            NSUserScriptTask::application_scripts_directory()?.appending_path_component(file_name);
+        Self::from_url(&url)
+    }
+
+    /// Returns the values of the workflow's variables, keyed by name.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsuserautomatortask/variables).
+    #[inline]
+    pub fn variables(&self) -> Option<Arc<NSDictionary<NSString<'static>, NSObject<'static>>>> {
+        unsafe {
+            _msg_send_any![self, variables => *const NSDictionary<NSString<'static>, NSObject<'static>>]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Sets the values of the workflow's variables, keyed by name.
+    ///
+    /// Values must be property list objects, such as strings, numbers, and
+    /// arrays of them.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsuserautomatortask/variables).
+    #[inline]
+    #[doc(alias = "setVariables")]
+    #[doc(alias = "setVariables:")]
+    pub fn set_variables(
+        &self,
+        variables: Option<&NSDictionary<NSString<'static>, NSObject<'static>>>,
+    ) {
+        unsafe { _msg_send_any![self, setVariables: variables] }
+    }
+
+    /// Runs the workflow with `input`, calling `completion` on an arbitrary
+    /// thread with the workflow's output, if any.
+    ///
+    /// `input` must support secure coding, such as strings, URLs, and arrays
+    /// of them.
+    ///
+    /// It is safe to panic within `completion`. Panics will abort the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsuserautomatortask/execute(withinput:completionhandler:)).
+    #[inline]
+    #[doc(alias = "executeWithInput:completionHandler:")]
+    pub fn execute_with_input<F>(&self, input: Option<&NSObject<'static>>, completion: F)
+    where
+        F: FnOnce(Result<Option<Arc<NSObject<'static>>>, Arc<NSError<'static>>>) + Send + 'static,
+    {
+        let completion = Cell::new(Some(completion));
+        let block = RcBlock::new(
+            move |output: *const NSObject<'static>, error: *const NSError<'static>| {
+                if let Some(completion) = completion.take() {
+                    completion(unsafe { retain_result(output, error) });
+                }
+            },
+        );
+        let block: &Block<_, ()> = &block;
+
+        unsafe {
+            _msg_send_any![
+                self,
+                executeWithInput: input
+                completionHandler: block
+                => ()
+            ]
+        }
+    }
+}
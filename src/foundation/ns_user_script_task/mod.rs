@@ -0,0 +1,165 @@
+use super::{NSError, NSString, NSURL};
+use crate::core::{Arc, ObjectType};
+use crate::objc::{Block, ClassType, NSObject, NSUInteger, RcBlock, Sel, BOOL};
+use std::{cell::Cell, ptr};
+
+mod apple_script;
+mod automator;
+mod unix;
+
+pub use apple_script::*;
+pub use automator::*;
+pub use unix::*;
+
+objc_subclass! {
+    /// A script that the user installed in the app's Application Scripts
+    /// directory.
+    ///
+    /// Sandboxed apps may only run scripts from this directory, which the user
+    /// controls. This makes it the supported way to integrate with user
+    /// automation, such as AppleScripts or Automator workflows that invoke
+    /// Shortcuts.
+    ///
+    /// Scripts run outside of the app's sandbox, so their output should not be
+    /// trusted.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsuserscripttask).
+    pub class NSUserScriptTask: NSObject<'static>;
+}
+
+/// Returns the result of an operation that returns `value` or sets `error`.
+#[inline]
+pub(crate) unsafe fn retain_result<T: ObjectType>(
+    value: *const T,
+    error: *const NSError<'static>,
+) -> Result<Option<Arc<T>>, Arc<NSError<'static>>> {
+    match error.as_ref() {
+        Some(error) => Err(Arc::retain(error)),
+        None => Ok(value.as_ref().map(Arc::retain)),
+    }
+}
+
+/// Creates an instance of `T` with `initWithURL:error:`.
+#[inline]
+pub(crate) unsafe fn init_with_url<T>(url: &NSURL) -> Result<Arc<T>, Arc<NSError<'static>>>
+where
+    T: ClassType<'static>,
+{
+    let task: Arc<T> = <T as ClassType>::class().alloc();
+
+    #[allow(clashing_extern_declarations)]
+    extern "C" {
+        fn objc_msgSend(
+            obj: Arc<NSObject<'static>>,
+            sel: Sel,
+            url: &NSURL,
+            error: &mut *const NSError<'static>,
+        ) -> Option<Arc<NSObject<'static>>>;
+    }
+
+    let mut error = ptr::null();
+    match objc_msgSend(
+        Arc::cast_unchecked(task),
+        selector!(initWithURL:error:),
+        url,
+        &mut error,
+    ) {
+        Some(task) => Ok(Arc::cast_unchecked(task)),
+        None => Err(Arc::retain_raw(error)),
+    }
+}
+
+impl NSUserScriptTask {
+    /// Returns the current user's Application Scripts directory for this
+    /// app, from which scripts may be run.
+    ///
+    /// This is `~/Library/Application Scripts/<bundle identifier>`. The
+    /// directory is not created by this call.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/filemanager/searchpathdirectory/applicationscriptsdirectory).
+    #[doc(alias = "NSApplicationScriptsDirectory")]
+    pub fn application_scripts_directory() -> Result<Arc<NSURL>, Arc<NSError<'static>>> {
+        const NS_APPLICATION_SCRIPTS_DIRECTORY: NSUInteger = 23;
+        const NS_USER_DOMAIN_MASK: NSUInteger = 1;
+
+        unsafe {
+            let manager = crate::objc_class!(NSFileManager);
+            let manager = &*_msg_send_any![manager, defaultManager => *const NSObject<'static>];
+
+            let mut error: *const NSError<'static> = ptr::null();
+            let url = _msg_send_any![
+                manager,
+                URLForDirectory: NS_APPLICATION_SCRIPTS_DIRECTORY
+                inDomain: NS_USER_DOMAIN_MASK
+                appropriateForURL: ptr::null::<NSURL>()
+                create: BOOL::NO
+                error: &mut error
+                => *const NSURL
+            ];
+
+            match url.as_ref() {
+                Some(url) => Ok(Arc::retain(url)),
+                None => Err(Arc::retain_raw(error)),
+            }
+        }
+    }
+
+    /// Creates a task for the script at `url`.
+    ///
+    /// The concrete class of the result depends on the type of the script.
+    /// This fails if `url` is not within the
+    /// [Application Scripts directory](Self::application_scripts_directory)
+    /// of a sandboxed app.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsuserscripttask/init(url:)).
+    #[inline]
+    #[doc(alias = "initWithURL:error:")]
+    pub fn from_url(url: &NSURL) -> Result<Arc<Self>, Arc<NSError<'static>>> {
+        unsafe { init_with_url(url) }
+    }
+
+    /// Creates a task for the script named `file_name` in the
+    /// [Application Scripts directory](Self::application_scripts_directory).
+    ///
+    /// `file_name` includes the extension, such as `Backup.scpt`.
+    #[inline]
+    pub fn with_file_name(file_name: &NSString) -> Result<Arc<Self>, Arc<NSError<'static>>> {
+        let url = Self::application_scripts_directory()?.appending_path_component(file_name);
+        Self::from_url(&url)
+    }
+
+    /// Returns the URL of the script.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsuserscripttask/scripturl).
+    #[inline]
+    #[doc(alias = "scriptURL")]
+    pub fn script_url(&self) -> Arc<NSURL> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, scriptURL => *const NSURL]) }
+    }
+
+    /// Runs the script without input, calling `completion` on an arbitrary
+    /// thread when it finishes.
+    ///
+    /// It is safe to panic within `completion`. Panics will abort the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsuserscripttask/execute(completionhandler:)).
+    #[inline]
+    #[doc(alias = "executeWithCompletionHandler:")]
+    pub fn execute<F>(&self, completion: F)
+    where
+        F: FnOnce(Result<(), Arc<NSError<'static>>>) + Send + 'static,
+    {
+        let completion = Cell::new(Some(completion));
+        let block = RcBlock::new(move |error: *const NSError<'static>| {
+            if let Some(completion) = completion.take() {
+                completion(match unsafe { error.as_ref() } {
+                    Some(error) => Err(Arc::retain(error)),
+                    None => Ok(()),
+                });
+            }
+        });
+        let block: &Block<_, ()> = &block;
+
+        unsafe { _msg_send_any![self, executeWithCompletionHandler: block] }
+    }
+}
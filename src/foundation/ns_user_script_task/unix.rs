@@ -0,0 +1,244 @@
+use super::{init_with_url, NSUserScriptTask};
+use crate::core::Arc;
+use crate::foundation::{NSArray, NSError, NSFileHandle, NSString, NSURL};
+use crate::objc::{Block, RcBlock};
+use std::{
+    cell::Cell,
+    fs::File,
+    io::{self, Read},
+    os::{
+        raw::c_int,
+        unix::io::{AsRawFd, FromRawFd},
+    },
+    thread,
+};
+
+objc_subclass! {
+    /// A Unix shell script or executable that the user installed in the
+    /// app's Application Scripts directory.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsuserunixtask).
+    pub class NSUserUnixTask: NSUserScriptTask;
+}
+
+/// The output captured by
+/// [`NSUserUnixTask::execute_capturing_output`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NSUserUnixTaskOutput {
+    /// The bytes written to standard output.
+    pub stdout: Vec<u8>,
+    /// The bytes written to standard error.
+    pub stderr: Vec<u8>,
+}
+
+/// Returns the read and write ends of a new pipe.
+fn pipe() -> io::Result<(File, File)> {
+    extern "C" {
+        fn pipe(fds: *mut c_int) -> c_int;
+    }
+
+    let mut fds: [c_int; 2] = [0; 2];
+    unsafe {
+        if pipe(fds.as_mut_ptr()) != 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok((File::from_raw_fd(fds[0]), File::from_raw_fd(fds[1])))
+    }
+}
+
+/// Reads `file` to its end on a new thread.
+///
+/// Read errors end the output early rather than being reported, since the
+/// script's exit status is what matters to callers.
+fn read_in_background(mut file: File) -> thread::JoinHandle<Vec<u8>> {
+    thread::spawn(move || {
+        let mut bytes = Vec::new();
+        let _ = file.read_to_end(&mut bytes);
+        bytes
+    })
+}
+
+macro_rules! file_handle_property {
+    ($(#[$get_meta:meta])+ get $get:ident = $get_sel:ident; $(#[$set_meta:meta])+ set $set:ident = $set_sel:ident;) => {
+        $(#[$get_meta])+
+        #[inline]
+        pub fn $get(&self) -> Option<Arc<NSFileHandle>> {
+            unsafe {
+                _msg_send_any![self, $get_sel => *const NSFileHandle]
+                    .as_ref()
+                    .map(Arc::retain)
+            }
+        }
+
+        $(#[$set_meta])+
+        #[inline]
+        pub fn $set(&self, handle: Option<&NSFileHandle>) {
+            unsafe { _msg_send_any![self, $set_sel: handle] }
+        }
+    };
+}
+
+impl NSUserUnixTask {
+    /// Creates a task for the Unix script or executable at `url`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsuserscripttask/init(url:)).
+    #[inline]
+    #[doc(alias = "initWithURL:error:")]
+    pub fn from_url(url: &NSURL) -> Result<Arc<Self>, Arc<NSError<'static>>> {
+        unsafe { init_with_url(url) }
+    }
+
+    /// Creates a task for the Unix script or executable named `file_name` in
+    /// the
+    /// [Application Scripts directory](NSUserScriptTask::application_scripts_directory).
+    #[inline]
+    pub fn with_file_name(file_name: &NSString) -> Result<Arc<Self>, Arc<NSError<'static>>> {
+        let url =
+            NSUserScriptTask::application_scripts_directory()?.appending_path_component(file_name);
+        Self::from_url(&url)
+    }
+
+    file_handle_property! {
+        /// Returns the handle that the script reads standard input from.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/foundation/nsuserunixtask/standardinput).
+        get standard_input = standardInput;
+
+        /// Sets the handle that the script reads standard input from.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/foundation/nsuserunixtask/standardinput).
+        set set_standard_input = setStandardInput;
+    }
+
+    file_handle_property! {
+        /// Returns the handle that the script writes standard output to.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/foundation/nsuserunixtask/standardoutput).
+        get standard_output = standardOutput;
+
+        /// Sets the handle that the script writes standard output to.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/foundation/nsuserunixtask/standardoutput).
+        set set_standard_output = setStandardOutput;
+    }
+
+    file_handle_property! {
+        /// Returns the handle that the script writes standard error to.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/foundation/nsuserunixtask/standarderror).
+        get standard_error = standardError;
+
+        /// Sets the handle that the script writes standard error to.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/foundation/nsuserunixtask/standarderror).
+        set set_standard_error = setStandardError;
+    }
+
+    /// Runs the script with `arguments`, calling `completion` on an arbitrary
+    /// thread when it exits.
+    ///
+    /// A script that exits with a non-zero status results in an error.
+    ///
+    /// It is safe to panic within `completion`. Panics will abort the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsuserunixtask/execute(witharguments:completionhandler:)).
+    #[inline]
+    #[doc(alias = "executeWithArguments:completionHandler:")]
+    pub fn execute_with_arguments<F>(
+        &self,
+        arguments: Option<&NSArray<NSString<'static>>>,
+        completion: F,
+    ) where
+        F: FnOnce(Result<(), Arc<NSError<'static>>>) + Send + 'static,
+    {
+        let completion = Cell::new(Some(completion));
+        let block = RcBlock::new(move |error: *const NSError<'static>| {
+            if let Some(completion) = completion.take() {
+                completion(match unsafe { error.as_ref() } {
+                    Some(error) => Err(Arc::retain(error)),
+                    None => Ok(()),
+                });
+            }
+        });
+        let block: &Block<_, ()> = &block;
+
+        unsafe {
+            _msg_send_any![
+                self,
+                executeWithArguments: arguments
+                completionHandler: block
+                => ()
+            ]
+        }
+    }
+
+    /// Runs the script with `arguments`, calling `completion` with everything
+    /// it wrote to standard output and standard error once it exits.
+    ///
+    /// This replaces the [standard output](Self::standard_output) and
+    /// [standard error](Self::standard_error) handles of `self`. Output is
+    /// read on background threads, so scripts that write a lot of output do
+    /// not block.
+    ///
+    /// Returns an error if the pipes for capturing output could not be
+    /// created.
+    ///
+    /// It is safe to panic within `completion`. Panics will abort the process.
+    pub fn execute_capturing_output<A, F>(&self, arguments: &[A], completion: F) -> io::Result<()>
+    where
+        A: AsRef<str>,
+        F: FnOnce(Result<NSUserUnixTaskOutput, Arc<NSError<'static>>>) + Send + 'static,
+    {
+        let (stdout_read, stdout_write) = pipe()?;
+        let (stderr_read, stderr_write) = pipe()?;
+
+        // The handles do not own the write ends, so that they can be closed
+        // once the script exits regardless of when the handles are released.
+        unsafe {
+            self.set_standard_output(Some(&NSFileHandle::from_raw_fd(
+                stdout_write.as_raw_fd(),
+                false,
+            )));
+            self.set_standard_error(Some(&NSFileHandle::from_raw_fd(
+                stderr_write.as_raw_fd(),
+                false,
+            )));
+        }
+
+        let stdout = read_in_background(stdout_read);
+        let stderr = read_in_background(stderr_read);
+
+        let arguments: Vec<Arc<NSString<'static>>> = arguments
+            .iter()
+            .map(|argument| NSString::from_str(argument.as_ref()))
+            .collect();
+        let arguments: Vec<&NSString<'static>> = arguments.iter().map(|s| &**s).collect();
+        let arguments = NSArray::from_slice(&arguments);
+
+        // Dropping the write ends lets the readers reach the end of output.
+        let state = Cell::new(Some((completion, stdout_write, stderr_write)));
+        let stdout = Cell::new(Some(stdout));
+        let stderr = Cell::new(Some(stderr));
+
+        self.execute_with_arguments(Some(&arguments), move |result| {
+            if let Some((completion, stdout_write, stderr_write)) = state.take() {
+                drop((stdout_write, stderr_write));
+
+                let join = |reader: &Cell<Option<thread::JoinHandle<Vec<u8>>>>| {
+                    reader
+                        .take()
+                        .and_then(|reader| reader.join().ok())
+                        .unwrap_or_default()
+                };
+                let output = NSUserUnixTaskOutput {
+                    stdout: join(&stdout),
+                    stderr: join(&stderr),
+                };
+
+                completion(result.map(|()| output));
+            }
+        });
+
+        Ok(())
+    }
+}
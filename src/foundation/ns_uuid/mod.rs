@@ -0,0 +1,131 @@
+use super::NSString;
+use crate::core::Arc;
+use crate::objc::{ClassType, NSObject, Sel, BOOL};
+use std::fmt;
+
+#[cfg(feature = "uuid")]
+mod uuid;
+
+objc_subclass! {
+    /// A universally unique identifier (UUID), such as those used for
+    /// Bluetooth services and IOKit devices.
+    ///
+    /// This is not toll-free bridged with
+    /// [`CFUUID`](crate::core_foundation::CFUUID).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsuuid).
+    pub class NSUUID: NSObject<'static>;
+}
+
+impl PartialEq for NSUUID {
+    #[inline]
+    #[doc(alias = "isEqual")]
+    #[doc(alias = "isEqual:")]
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { _msg_send_any![self, isEqual: other => BOOL] }.into()
+    }
+}
+
+impl Eq for NSUUID {}
+
+impl fmt::Debug for NSUUID {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("NSUUID").field(&self.uuid_string()).finish()
+    }
+}
+
+impl fmt::Display for NSUUID {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.uuid_string().fmt(f)
+    }
+}
+
+impl From<[u8; 16]> for Arc<NSUUID> {
+    #[inline]
+    fn from(bytes: [u8; 16]) -> Self {
+        NSUUID::from_bytes(&bytes)
+    }
+}
+
+impl From<&NSUUID> for [u8; 16] {
+    #[inline]
+    fn from(uuid: &NSUUID) -> Self {
+        uuid.bytes()
+    }
+}
+
+impl NSUUID {
+    /// Creates a new random (version 4) UUID.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsuuid/init()).
+    #[inline]
+    #[doc(alias = "init")]
+    pub fn new() -> Arc<Self> {
+        unsafe { Self::class().alloc_init() }
+    }
+
+    /// Returns the UUID with the raw `bytes`, in network byte order.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsuuid/init(uuid:)).
+    #[inline]
+    #[doc(alias = "initWithUUIDBytes:")]
+    pub fn from_bytes(bytes: &[u8; 16]) -> Arc<Self> {
+        unsafe {
+            let uuid: Arc<Self> = Self::class().alloc();
+
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(obj: Arc<NSUUID>, sel: Sel, bytes: *const u8) -> Arc<NSUUID>;
+            }
+
+            objc_msgSend(uuid, selector!(initWithUUIDBytes:), bytes.as_ptr())
+        }
+    }
+
+    /// Parses a UUID in the canonical hyphenated form, such as
+    /// `E621E1F8-C36C-495A-93FC-0C247A3E6E5F`.
+    ///
+    /// Returns [`None`] if `string` is not a valid UUID.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsuuid/init(uuidstring:)).
+    #[inline]
+    #[doc(alias = "initWithUUIDString:")]
+    pub fn from_string(string: &NSString) -> Option<Arc<Self>> {
+        unsafe {
+            let uuid: Arc<Self> = Self::class().alloc();
+
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(
+                    obj: Arc<NSUUID>,
+                    sel: Sel,
+                    string: &NSString,
+                ) -> Option<Arc<NSUUID>>;
+            }
+
+            objc_msgSend(uuid, selector!(initWithUUIDString:), string)
+        }
+    }
+
+    /// Returns the raw bytes of `self`, in network byte order.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsuuid/getbytes(_:)).
+    #[inline]
+    #[doc(alias = "getUUIDBytes:")]
+    pub fn bytes(&self) -> [u8; 16] {
+        let mut bytes = [0u8; 16];
+        unsafe { _msg_send_any![self, getUUIDBytes: bytes.as_mut_ptr() => ()] };
+        bytes
+    }
+
+    /// Returns `self` in the canonical uppercase hyphenated form.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/nsuuid/uuidstring).
+    #[inline]
+    #[doc(alias = "UUIDString")]
+    pub fn uuid_string(&self) -> Arc<NSString<'static>> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, UUIDString => *const NSString<'static>]) }
+    }
+}
@@ -0,0 +1,33 @@
+use super::NSUUID;
+use crate::core::Arc;
+use uuid::Uuid;
+
+impl From<Uuid> for Arc<NSUUID> {
+    #[inline]
+    fn from(uuid: Uuid) -> Self {
+        NSUUID::from_uuid(uuid)
+    }
+}
+
+impl From<&NSUUID> for Uuid {
+    #[inline]
+    fn from(uuid: &NSUUID) -> Self {
+        uuid.to_uuid()
+    }
+}
+
+/// Conversions with [`uuid`](https://docs.rs/uuid), enabled by the
+/// **`uuid`** feature flag.
+impl NSUUID {
+    /// Creates an `NSUUID` with the same bytes as `uuid`.
+    #[inline]
+    pub fn from_uuid(uuid: Uuid) -> Arc<Self> {
+        Self::from_bytes(uuid.as_bytes())
+    }
+
+    /// Returns `self` as a `uuid` crate UUID.
+    #[inline]
+    pub fn to_uuid(&self) -> Uuid {
+        Uuid::from_bytes(self.bytes())
+    }
+}
@@ -0,0 +1,161 @@
+use super::{NSXMLElement, NSXMLNode, NSXMLNodeOptions};
+use crate::core::Arc;
+use crate::foundation::{NSData, NSError, NSString, NSURL};
+use crate::objc::{ClassType, NSObject, Sel};
+use std::ptr;
+
+objc_subclass! {
+    /// An XML document parsed into a tree of [`NSXMLNode`]s in memory.
+    ///
+    /// Nodes can be found with XPath queries via
+    /// [`nodes_for_xpath`](NSXMLNode::nodes_for_xpath), edited, and written
+    /// back out with [`xml_data`](Self::xml_data).
+    ///
+    /// For large documents that only need to be read once, [`NSXMLParser`]
+    /// avoids building the tree.
+    ///
+    /// [`NSXMLParser`]: crate::foundation::NSXMLParser
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/xmldocument).
+    pub class NSXMLDocument: NSXMLNode;
+}
+
+/// Calls an `init...:options:error:` method of `NSXMLDocument`.
+#[inline]
+unsafe fn init_with_source(
+    source: &NSObject,
+    selector: Sel,
+    options: NSXMLNodeOptions,
+) -> Result<Arc<NSXMLDocument>, Arc<NSError<'static>>> {
+    let document: Arc<NSXMLDocument> = NSXMLDocument::class().alloc();
+
+    #[allow(clashing_extern_declarations)]
+    extern "C" {
+        fn objc_msgSend(
+            obj: Arc<NSXMLDocument>,
+            sel: Sel,
+            source: &NSObject,
+            options: NSXMLNodeOptions,
+            error: &mut *const NSError<'static>,
+        ) -> Option<Arc<NSXMLDocument>>;
+    }
+
+    let mut error = ptr::null();
+    match objc_msgSend(document, selector, source, options, &mut error) {
+        Some(document) => Ok(document),
+        None => Err(Arc::retain_raw(error)),
+    }
+}
+
+impl NSXMLDocument {
+    /// Parses the XML document in `bytes`.
+    ///
+    /// The encoding is detected from the XML declaration, defaulting to
+    /// UTF-8.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/xmldocument/init(data:options:)).
+    #[inline]
+    #[doc(alias = "initWithData:options:error:")]
+    pub fn from_bytes(
+        bytes: &[u8],
+        options: NSXMLNodeOptions,
+    ) -> Result<Arc<Self>, Arc<NSError<'static>>> {
+        let data = NSData::from_bytes(bytes);
+        unsafe { init_with_source(&data, selector!(initWithData:options:error:), options) }
+    }
+
+    /// Parses the XML document in `string`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/xmldocument/init(xmlstring:options:)).
+    #[inline]
+    #[doc(alias = "initWithXMLString:options:error:")]
+    pub fn from_xml_string(
+        string: &NSString,
+        options: NSXMLNodeOptions,
+    ) -> Result<Arc<Self>, Arc<NSError<'static>>> {
+        unsafe { init_with_source(string, selector!(initWithXMLString:options:error:), options) }
+    }
+
+    /// Reads and parses the XML document at `url`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/xmldocument/init(contentsof:options:)).
+    #[inline]
+    #[doc(alias = "initWithContentsOfURL:options:error:")]
+    pub fn from_url(
+        url: &NSURL,
+        options: NSXMLNodeOptions,
+    ) -> Result<Arc<Self>, Arc<NSError<'static>>> {
+        unsafe {
+            init_with_source(
+                url,
+                selector!(initWithContentsOfURL:options:error:),
+                options,
+            )
+        }
+    }
+
+    /// Creates a document whose root is `root`.
+    ///
+    /// `root` must not already have a parent.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/xmldocument/init(rootelement:)).
+    #[inline]
+    #[doc(alias = "initWithRootElement:")]
+    pub fn with_root_element(root: &NSXMLElement) -> Arc<Self> {
+        unsafe {
+            let document: Arc<Self> = Self::class().alloc();
+
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(
+                    obj: Arc<NSXMLDocument>,
+                    sel: Sel,
+                    root: &NSXMLElement,
+                ) -> Arc<NSXMLDocument>;
+            }
+
+            objc_msgSend(document, selector!(initWithRootElement:), root)
+        }
+    }
+
+    /// Returns the root element of `self`, if it has one.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/xmldocument/rootelement()).
+    #[inline]
+    #[doc(alias = "rootElement")]
+    pub fn root_element(&self) -> Option<Arc<NSXMLElement>> {
+        unsafe {
+            _msg_send_any![self, rootElement => *const NSXMLElement]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Replaces the root element of `self` and all of its contents with
+    /// `root`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/xmldocument/setrootelement(_:)).
+    #[inline]
+    #[doc(alias = "setRootElement")]
+    #[doc(alias = "setRootElement:")]
+    pub fn set_root_element(&self, root: &NSXMLElement) {
+        unsafe { _msg_send_any![self, setRootElement: root] }
+    }
+
+    /// Returns `self` written as XML in its character encoding, which is
+    /// UTF-8 unless the document declares another.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/xmldocument/xmldata(options:)).
+    #[inline]
+    #[doc(alias = "XMLDataWithOptions:")]
+    pub fn xml_data(&self, options: NSXMLNodeOptions) -> Vec<u8> {
+        unsafe {
+            let xml = _msg_send_any![
+                self,
+                XMLDataWithOptions: options
+                => *const NSData
+            ];
+            (*xml).to_vec()
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use super::NSXMLNode;
+use crate::core::Arc;
+use crate::foundation::{NSArray, NSString};
+use crate::objc::{ClassType, Sel};
+
+objc_subclass! {
+    /// An element node in an XML document tree, which may have attributes
+    /// and child nodes.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/xmlelement).
+    pub class NSXMLElement: NSXMLNode;
+}
+
+impl NSXMLElement {
+    /// Creates an empty element named `name`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/xmlelement/init(name:)).
+    #[inline]
+    #[doc(alias = "initWithName:")]
+    pub fn new(name: &NSString) -> Arc<Self> {
+        unsafe {
+            let element: Arc<Self> = Self::class().alloc();
+
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(
+                    obj: Arc<NSXMLElement>,
+                    sel: Sel,
+                    name: &NSString,
+                ) -> Arc<NSXMLElement>;
+            }
+
+            objc_msgSend(element, selector!(initWithName:), name)
+        }
+    }
+
+    /// Creates an element named `name` containing the text `string`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/xmlelement/init(name:stringvalue:)).
+    #[inline]
+    #[doc(alias = "initWithName:stringValue:")]
+    pub fn with_string_value(name: &NSString, string: &NSString) -> Arc<Self> {
+        unsafe {
+            let element: Arc<Self> = Self::class().alloc();
+
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(
+                    obj: Arc<NSXMLElement>,
+                    sel: Sel,
+                    name: &NSString,
+                    string: &NSString,
+                ) -> Arc<NSXMLElement>;
+            }
+
+            objc_msgSend(element, selector!(initWithName:stringValue:), name, string)
+        }
+    }
+
+    /// Returns the attribute nodes of `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/xmlelement/attributes).
+    #[inline]
+    pub fn attributes(&self) -> Option<Arc<NSArray<NSXMLNode>>> {
+        unsafe {
+            _msg_send_any![self, attributes => *const NSArray<NSXMLNode>]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Returns the attribute of `self` named `name`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/xmlelement/attribute(forname:)).
+    #[inline]
+    #[doc(alias = "attributeForName:")]
+    pub fn attribute_for_name(&self, name: &NSString) -> Option<Arc<NSXMLNode>> {
+        unsafe {
+            _msg_send_any![self, attributeForName: name => *const NSXMLNode]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Adds `attribute`, created with [`NSXMLNode::attribute`], to `self`.
+    ///
+    /// Nothing is added if `self` already has an attribute of the same name.
+    /// Use [`set_attribute`](Self::set_attribute) to replace it.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/xmlelement/addattribute(_:)).
+    #[inline]
+    #[doc(alias = "addAttribute:")]
+    pub fn add_attribute(&self, attribute: &NSXMLNode) {
+        unsafe { _msg_send_any![self, addAttribute: attribute] }
+    }
+
+    /// Sets the attribute of `self` named `name` to `value`, replacing any
+    /// existing value.
+    #[inline]
+    pub fn set_attribute(&self, name: &NSString, value: &NSString) {
+        self.remove_attribute(name);
+        self.add_attribute(&NSXMLNode::attribute(name, value));
+    }
+
+    /// Removes the attribute of `self` named `name`, if any.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/xmlelement/removeattribute(forname:)).
+    #[inline]
+    #[doc(alias = "removeAttributeForName:")]
+    pub fn remove_attribute(&self, name: &NSString) {
+        unsafe { _msg_send_any![self, removeAttributeForName: name] }
+    }
+
+    /// Adds `child` after the existing children of `self`.
+    ///
+    /// `child` must not already have a parent.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/xmlelement/addchild(_:)).
+    #[inline]
+    #[doc(alias = "addChild:")]
+    pub fn add_child(&self, child: &NSXMLNode) {
+        unsafe { _msg_send_any![self, addChild: child] }
+    }
+
+    /// Returns the child elements of `self` named `name`.
+    ///
+    /// Only direct children are searched. Use
+    /// [`nodes_for_xpath`](NSXMLNode::nodes_for_xpath) to search all
+    /// descendants.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/xmlelement/elements(forname:)).
+    #[inline]
+    #[doc(alias = "elementsForName:")]
+    pub fn elements_for_name(&self, name: &NSString) -> Arc<NSArray<NSXMLElement>> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![
+                self,
+                elementsForName: name
+                => *const NSArray<NSXMLElement>
+            ])
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use crate::objc::NSUInteger;
+
+/// The type of an [`NSXMLNode`](super::NSXMLNode).
+///
+/// See [documentation](https://developer.apple.com/documentation/foundation/xmlnode/kind).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NSXMLNodeKind(pub NSUInteger);
+
+impl NSXMLNodeKind {
+    /// A node that has not been initialized.
+    #[doc(alias = "NSXMLInvalidKind")]
+    pub const INVALID: Self = Self(0);
+
+    /// An [`NSXMLDocument`](super::NSXMLDocument).
+    #[doc(alias = "NSXMLDocumentKind")]
+    pub const DOCUMENT: Self = Self(1);
+
+    /// An [`NSXMLElement`](super::NSXMLElement).
+    #[doc(alias = "NSXMLElementKind")]
+    pub const ELEMENT: Self = Self(2);
+
+    /// An attribute of an element.
+    #[doc(alias = "NSXMLAttributeKind")]
+    pub const ATTRIBUTE: Self = Self(3);
+
+    /// A namespace declaration of an element.
+    #[doc(alias = "NSXMLNamespaceKind")]
+    pub const NAMESPACE: Self = Self(4);
+
+    /// A processing instruction, such as `<?xml-stylesheet ...?>`.
+    #[doc(alias = "NSXMLProcessingInstructionKind")]
+    pub const PROCESSING_INSTRUCTION: Self = Self(5);
+
+    /// A comment.
+    #[doc(alias = "NSXMLCommentKind")]
+    pub const COMMENT: Self = Self(6);
+
+    /// Text content, including CDATA sections.
+    #[doc(alias = "NSXMLTextKind")]
+    pub const TEXT: Self = Self(7);
+
+    /// A document type declaration.
+    #[doc(alias = "NSXMLDTDKind")]
+    pub const DTD: Self = Self(8);
+
+    /// An entity declaration within a DTD.
+    #[doc(alias = "NSXMLEntityDeclarationKind")]
+    pub const ENTITY_DECLARATION: Self = Self(9);
+
+    /// An attribute list declaration within a DTD.
+    #[doc(alias = "NSXMLAttributeDeclarationKind")]
+    pub const ATTRIBUTE_DECLARATION: Self = Self(10);
+
+    /// An element declaration within a DTD.
+    #[doc(alias = "NSXMLElementDeclarationKind")]
+    pub const ELEMENT_DECLARATION: Self = Self(11);
+
+    /// A notation declaration within a DTD.
+    #[doc(alias = "NSXMLNotationDeclarationKind")]
+    pub const NOTATION_DECLARATION: Self = Self(12);
+}
@@ -0,0 +1,293 @@
+use super::{NSArray, NSError, NSString};
+use crate::core::Arc;
+use crate::objc::{ClassType, NSObject, NSUInteger};
+use std::{fmt, ptr};
+
+mod document;
+mod element;
+mod kind;
+mod options;
+
+pub use document::*;
+pub use element::*;
+pub use kind::*;
+pub use options::*;
+
+objc_subclass! {
+    /// A node in an XML document tree, such as an element, attribute, or
+    /// text.
+    ///
+    /// Trees are built in memory by parsing an [`NSXMLDocument`] or by
+    /// constructing [`NSXMLElement`]s and adding children to them.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/xmlnode).
+    pub class NSXMLNode: NSObject<'static>;
+}
+
+impl fmt::Debug for NSXMLNode {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.xml_string().fmt(f)
+    }
+}
+
+impl fmt::Display for NSXMLNode {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.xml_string().fmt(f)
+    }
+}
+
+/// Creating nodes.
+impl NSXMLNode {
+    /// Creates an attribute node named `name` with `value`, to be added to an
+    /// element with [`NSXMLElement::add_attribute`].
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/xmlnode/attribute(withname:stringvalue:)).
+    #[inline]
+    #[doc(alias = "attributeWithName:stringValue:")]
+    pub fn attribute(name: &NSString, value: &NSString) -> Arc<Self> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![
+                Self::class(),
+                attributeWithName: name
+                stringValue: value
+                => *const Self
+            ])
+        }
+    }
+
+    /// Creates a text node containing `string`.
+    ///
+    /// Special characters are escaped when the node is written as XML.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/xmlnode/text(withstringvalue:)).
+    #[inline]
+    #[doc(alias = "textWithStringValue:")]
+    pub fn text(string: &NSString) -> Arc<Self> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![
+                Self::class(),
+                textWithStringValue: string
+                => *const Self
+            ])
+        }
+    }
+
+    /// Creates a comment node containing `string`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/xmlnode/comment(withstringvalue:)).
+    #[inline]
+    #[doc(alias = "commentWithStringValue:")]
+    pub fn comment(string: &NSString) -> Arc<Self> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![
+                Self::class(),
+                commentWithStringValue: string
+                => *const Self
+            ])
+        }
+    }
+}
+
+/// Node properties.
+impl NSXMLNode {
+    /// Returns the type of `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/xmlnode/kind-swift.property).
+    #[inline]
+    pub fn kind(&self) -> NSXMLNodeKind {
+        unsafe { _msg_send_any![self, kind] }
+    }
+
+    /// Returns the name of `self`, such as the tag of an element, if it has
+    /// one.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/xmlnode/name).
+    #[inline]
+    pub fn name(&self) -> Option<Arc<NSString<'static>>> {
+        unsafe {
+            _msg_send_any![self, name => *const NSString<'static>]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Sets the name of `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/xmlnode/name).
+    #[inline]
+    #[doc(alias = "setName")]
+    #[doc(alias = "setName:")]
+    pub fn set_name(&self, name: &NSString) {
+        unsafe { _msg_send_any![self, setName: name] }
+    }
+
+    /// Returns the content of `self`.
+    ///
+    /// For elements and documents, this is the text of all descendants
+    /// concatenated together.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/xmlnode/stringvalue).
+    #[inline]
+    #[doc(alias = "stringValue")]
+    pub fn string_value(&self) -> Option<Arc<NSString<'static>>> {
+        unsafe {
+            _msg_send_any![self, stringValue => *const NSString<'static>]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Sets the content of `self`.
+    ///
+    /// For elements, this replaces all children with a single text node.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/xmlnode/stringvalue).
+    #[inline]
+    #[doc(alias = "setStringValue")]
+    #[doc(alias = "setStringValue:")]
+    pub fn set_string_value(&self, string: &NSString) {
+        unsafe { _msg_send_any![self, setStringValue: string] }
+    }
+}
+
+/// Navigating the tree.
+impl NSXMLNode {
+    /// Returns the number of children of `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/xmlnode/childcount).
+    #[inline]
+    #[doc(alias = "childCount")]
+    pub fn child_count(&self) -> usize {
+        unsafe { _msg_send_any![self, childCount => NSUInteger] }
+    }
+
+    /// Returns the children of `self`, or [`None`] if it cannot have any.
+    ///
+    /// Attributes and namespaces are not included.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/xmlnode/children).
+    #[inline]
+    pub fn children(&self) -> Option<Arc<NSArray<NSXMLNode>>> {
+        unsafe {
+            _msg_send_any![self, children => *const NSArray<NSXMLNode>]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Returns the child of `self` at `index`, or [`None`] if out of bounds.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/xmlnode/child(at:)).
+    #[inline]
+    #[doc(alias = "childAtIndex:")]
+    pub fn child(&self, index: usize) -> Option<Arc<NSXMLNode>> {
+        if index >= self.child_count() {
+            return None;
+        }
+        unsafe {
+            _msg_send_any![self, childAtIndex: index as NSUInteger => *const NSXMLNode]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Returns the node that contains `self`, if any.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/xmlnode/parent).
+    #[inline]
+    pub fn parent(&self) -> Option<Arc<NSXMLNode>> {
+        unsafe {
+            _msg_send_any![self, parent => *const NSXMLNode]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Returns the document that contains `self`, if any.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/xmlnode/rootdocument).
+    #[inline]
+    #[doc(alias = "rootDocument")]
+    pub fn root_document(&self) -> Option<Arc<NSXMLDocument>> {
+        unsafe {
+            _msg_send_any![self, rootDocument => *const NSXMLDocument]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Returns the XPath expression that identifies `self` within its
+    /// document.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/xmlnode/xpath).
+    #[inline]
+    #[doc(alias = "XPath")]
+    pub fn xpath(&self) -> Option<Arc<NSString<'static>>> {
+        unsafe {
+            _msg_send_any![self, XPath => *const NSString<'static>]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Returns the nodes matching the XPath expression `xpath`, evaluated
+    /// with `self` as the context node.
+    ///
+    /// For example, `.//command[@name="open"]` finds matching `command`
+    /// elements anywhere below `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/xmlnode/nodes(forxpath:)).
+    #[inline]
+    #[doc(alias = "nodesForXPath:error:")]
+    pub fn nodes_for_xpath(
+        &self,
+        xpath: &NSString,
+    ) -> Result<Arc<NSArray<NSXMLNode>>, Arc<NSError<'static>>> {
+        unsafe {
+            let mut error: *const NSError<'static> = ptr::null();
+            let nodes = _msg_send_any![
+                self,
+                nodesForXPath: xpath
+                error: &mut error
+                => *const NSArray<NSXMLNode>
+            ];
+
+            match nodes.as_ref() {
+                Some(nodes) => Ok(Arc::retain(nodes)),
+                None => Err(Arc::retain_raw(error)),
+            }
+        }
+    }
+}
+
+/// Writing XML.
+impl NSXMLNode {
+    /// Returns `self` written as XML.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/xmlnode/xmlstring).
+    #[inline]
+    #[doc(alias = "XMLString")]
+    pub fn xml_string(&self) -> Arc<NSString<'static>> {
+        unsafe { Arc::retain_raw(_msg_send_any![self, XMLString => *const NSString<'static>]) }
+    }
+
+    /// Returns `self` written as XML with `options`.
+    ///
+    /// Use [`PRETTY_PRINT`](NSXMLNodeOptions::PRETTY_PRINT) for indented
+    /// output.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/xmlnode/xmlstring(options:)).
+    #[inline]
+    #[doc(alias = "XMLStringWithOptions:")]
+    pub fn xml_string_with_options(&self, options: NSXMLNodeOptions) -> Arc<NSString<'static>> {
+        unsafe {
+            Arc::retain_raw(_msg_send_any![
+                self,
+                XMLStringWithOptions: options
+                => *const NSString<'static>
+            ])
+        }
+    }
+}
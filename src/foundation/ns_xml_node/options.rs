@@ -0,0 +1,84 @@
+use crate::objc::NSUInteger;
+use std::ops;
+
+/// Options for parsing XML documents and for writing nodes as XML.
+///
+/// See [documentation](https://developer.apple.com/documentation/foundation/xmlnode/options).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NSXMLNodeOptions(pub NSUInteger);
+
+impl ops::BitOr for NSXMLNodeOptions {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+/// Output options.
+impl NSXMLNodeOptions {
+    /// No options.
+    #[doc(alias = "NSXMLNodeOptionsNone")]
+    pub const NONE: Self = Self(0);
+
+    /// Writes empty elements with start and end tags, such as `<a></a>`.
+    #[doc(alias = "NSXMLNodeExpandEmptyElement")]
+    pub const EXPAND_EMPTY_ELEMENT: Self = Self(1 << 1);
+
+    /// Writes empty elements as a single tag, such as `<a/>`.
+    #[doc(alias = "NSXMLNodeCompactEmptyElement")]
+    pub const COMPACT_EMPTY_ELEMENT: Self = Self(1 << 2);
+
+    /// Quotes attribute values with single quotes.
+    #[doc(alias = "NSXMLNodeUseSingleQuotes")]
+    pub const USE_SINGLE_QUOTES: Self = Self(1 << 3);
+
+    /// Quotes attribute values with double quotes.
+    #[doc(alias = "NSXMLNodeUseDoubleQuotes")]
+    pub const USE_DOUBLE_QUOTES: Self = Self(1 << 4);
+
+    /// Indents nested elements on separate lines.
+    #[doc(alias = "NSXMLNodePrettyPrint")]
+    pub const PRETTY_PRINT: Self = Self(1 << 17);
+}
+
+/// Parsing options.
+impl NSXMLNodeOptions {
+    /// Corrects malformed HTML to be well-formed XHTML.
+    #[doc(alias = "NSXMLDocumentTidyHTML")]
+    pub const TIDY_HTML: Self = Self(1 << 9);
+
+    /// Corrects malformed XML where possible instead of failing.
+    #[doc(alias = "NSXMLDocumentTidyXML")]
+    pub const TIDY_XML: Self = Self(1 << 10);
+
+    /// Validates the document against its DTD or schema.
+    #[doc(alias = "NSXMLDocumentValidate")]
+    pub const VALIDATE: Self = Self(1 << 13);
+
+    /// Never loads external entities.
+    #[doc(alias = "NSXMLNodeLoadExternalEntitiesNever")]
+    pub const LOAD_EXTERNAL_ENTITIES_NEVER: Self = Self(1 << 19);
+
+    /// Keeps CDATA sections instead of converting them to text.
+    #[doc(alias = "NSXMLNodePreserveCDATA")]
+    pub const PRESERVE_CDATA: Self = Self(1 << 24);
+
+    /// Keeps whitespace between elements.
+    #[doc(alias = "NSXMLNodePreserveWhitespace")]
+    pub const PRESERVE_WHITESPACE: Self = Self(1 << 25);
+
+    /// Keeps the document type declaration.
+    #[doc(alias = "NSXMLNodePreserveDTD")]
+    pub const PRESERVE_DTD: Self = Self(1 << 26);
+}
+
+impl NSXMLNodeOptions {
+    /// Returns `true` if all options in `other` are set in `self`.
+    #[inline]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
@@ -0,0 +1,278 @@
+use super::NSXMLParser;
+use crate::core::Arc;
+use crate::foundation::{NSData, NSDictionary, NSError, NSString};
+use crate::objc::{Class, ClassType, LazyClass, NSObject, Sel};
+use std::ffi::{c_void, CStr};
+
+/// Handles events reported by an [`NSXMLParser`] as it reads a document.
+///
+/// Every method does nothing by default, so implementations only need to
+/// handle the events they are interested in. Events are reported in document
+/// order on the thread that called [`NSXMLParser::parse`].
+///
+/// Parsing can be stopped early from any method with
+/// [`NSXMLParser::abort_parsing`].
+///
+/// See [documentation](https://developer.apple.com/documentation/foundation/xmlparserdelegate).
+#[allow(unused_variables)]
+pub trait NSXMLParserDelegate {
+    /// Called when the parser begins reading the document.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/xmlparserdelegate/parserdidstartdocument(_:)).
+    #[doc(alias = "parserDidStartDocument:")]
+    fn did_start_document(&mut self, parser: &NSXMLParser) {}
+
+    /// Called when the parser reaches the end of the document without errors.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/xmlparserdelegate/parserdidenddocument(_:)).
+    #[doc(alias = "parserDidEndDocument:")]
+    fn did_end_document(&mut self, parser: &NSXMLParser) {}
+
+    /// Called for the start tag of an element.
+    ///
+    /// `namespace_uri` and `qualified_name` are only provided when
+    /// [namespace processing](NSXMLParser::set_should_process_namespaces) is
+    /// enabled.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/xmlparserdelegate/parser(_:didstartelement:namespaceuri:qualifiedname:attributes:)).
+    #[doc(alias = "parser:didStartElement:namespaceURI:qualifiedName:attributes:")]
+    fn did_start_element(
+        &mut self,
+        parser: &NSXMLParser,
+        element_name: &NSString,
+        namespace_uri: Option<&NSString>,
+        qualified_name: Option<&NSString>,
+        attributes: &NSDictionary<NSString<'static>, NSString<'static>>,
+    ) {
+    }
+
+    /// Called for the end tag of an element, including elements written as a
+    /// single empty tag.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/xmlparserdelegate/parser(_:didendelement:namespaceuri:qualifiedname:)).
+    #[doc(alias = "parser:didEndElement:namespaceURI:qualifiedName:")]
+    fn did_end_element(
+        &mut self,
+        parser: &NSXMLParser,
+        element_name: &NSString,
+        namespace_uri: Option<&NSString>,
+        qualified_name: Option<&NSString>,
+    ) {
+    }
+
+    /// Called with text content of the current element.
+    ///
+    /// The text of a single element may be split across multiple calls, so
+    /// it should be accumulated until
+    /// [`did_end_element`](Self::did_end_element).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/xmlparserdelegate/parser(_:foundcharacters:)).
+    #[doc(alias = "parser:foundCharacters:")]
+    fn found_characters(&mut self, parser: &NSXMLParser, string: &NSString) {}
+
+    /// Called with the contents of a CDATA section.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/xmlparserdelegate/parser(_:foundcdata:)).
+    #[doc(alias = "parser:foundCDATA:")]
+    fn found_cdata(&mut self, parser: &NSXMLParser, cdata: &[u8]) {}
+
+    /// Called with the text of a comment.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/xmlparserdelegate/parser(_:foundcomment:)).
+    #[doc(alias = "parser:foundComment:")]
+    fn found_comment(&mut self, parser: &NSXMLParser, comment: &NSString) {}
+
+    /// Called for a processing instruction, such as
+    /// `<?xml-stylesheet href="style.css"?>`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/xmlparserdelegate/parser(_:foundprocessinginstructionwithtarget:data:)).
+    #[doc(alias = "parser:foundProcessingInstructionWithTarget:data:")]
+    fn found_processing_instruction(
+        &mut self,
+        parser: &NSXMLParser,
+        target: &NSString,
+        data: Option<&NSString>,
+    ) {
+    }
+
+    /// Called when the document is malformed, after which parsing stops.
+    ///
+    /// The same error is returned by [`NSXMLParser::parse`].
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/xmlparserdelegate/parser(_:parseerroroccurred:)).
+    #[doc(alias = "parser:parseErrorOccurred:")]
+    fn parse_error_occurred(&mut self, parser: &NSXMLParser, error: &NSError) {}
+}
+
+/// The delegate reference stored in instances of the delegate class.
+type DelegateRef<'a> = &'a mut dyn NSXMLParserDelegate;
+
+/// The name of the instance variable that points to a `DelegateRef`.
+const DELEGATE_IVAR: &[u8] = b"rustDelegate\0";
+
+/// Creates an Objective-C object that forwards parser events to `delegate`.
+///
+/// The object must not be used after `delegate` is no longer valid.
+pub(super) unsafe fn new_delegate_object(delegate: *mut DelegateRef) -> Arc<NSObject<'static>> {
+    let object: Arc<NSObject<'static>> = delegate_class().alloc_init();
+    *delegate_slot(&object) = delegate.cast();
+    object
+}
+
+/// Returns the instance variable of `object` that points to its delegate.
+#[inline]
+fn delegate_slot(object: &NSObject) -> *mut *mut c_void {
+    let name = unsafe { CStr::from_bytes_with_nul_unchecked(DELEGATE_IVAR) };
+    object.ivar_ptr::<*mut c_void>(name)
+}
+
+#[inline]
+unsafe fn delegate<'a>(object: &NSObject) -> &'a mut DelegateRef<'a> {
+    &mut *(*delegate_slot(object)).cast::<DelegateRef>()
+}
+
+/// Returns the class used for delegate objects, registering it with the
+/// Objective-C runtime on first use.
+fn delegate_class() -> &'static Class {
+    static CLASS: LazyClass = LazyClass::new("FruityXMLParserDelegate");
+
+    CLASS.get(NSObject::class(), |builder| unsafe {
+        builder.add_ivar::<*mut c_void>(CStr::from_bytes_with_nul_unchecked(DELEGATE_IVAR));
+
+        builder.add_method(
+            selector!(parserDidStartDocument:),
+            did_start_document as ParserEvent,
+        );
+        builder.add_method(
+            selector!(parserDidEndDocument:),
+            did_end_document as ParserEvent,
+        );
+        builder.add_method(
+            selector!(parser:didStartElement:namespaceURI:qualifiedName:attributes:),
+            did_start_element as DidStartElement,
+        );
+        builder.add_method(
+            selector!(parser:didEndElement:namespaceURI:qualifiedName:),
+            did_end_element as DidEndElement,
+        );
+        builder.add_method(
+            selector!(parser:foundCharacters:),
+            found_characters as FoundString,
+        );
+        builder.add_method(selector!(parser:foundCDATA:), found_cdata as FoundData);
+        builder.add_method(
+            selector!(parser:foundComment:),
+            found_comment as FoundString,
+        );
+        builder.add_method(
+            selector!(parser:foundProcessingInstructionWithTarget:data:),
+            found_processing_instruction as FoundProcessingInstruction,
+        );
+        builder.add_method(
+            selector!(parser:parseErrorOccurred:),
+            parse_error_occurred as ParseErrorOccurred,
+        );
+    })
+}
+
+type ParserEvent = extern "C" fn(&NSObject<'static>, Sel, &'static NSXMLParser);
+
+type DidStartElement = extern "C" fn(
+    &NSObject<'static>,
+    Sel,
+    &'static NSXMLParser,
+    &'static NSString<'static>,
+    Option<&'static NSString<'static>>,
+    Option<&'static NSString<'static>>,
+    &'static NSDictionary<NSString<'static>, NSString<'static>>,
+);
+
+type DidEndElement = extern "C" fn(
+    &NSObject<'static>,
+    Sel,
+    &'static NSXMLParser,
+    &'static NSString<'static>,
+    Option<&'static NSString<'static>>,
+    Option<&'static NSString<'static>>,
+);
+
+type FoundString =
+    extern "C" fn(&NSObject<'static>, Sel, &'static NSXMLParser, &'static NSString<'static>);
+
+type FoundData = extern "C" fn(&NSObject<'static>, Sel, &'static NSXMLParser, &'static NSData);
+
+type FoundProcessingInstruction = extern "C" fn(
+    &NSObject<'static>,
+    Sel,
+    &'static NSXMLParser,
+    &'static NSString<'static>,
+    Option<&'static NSString<'static>>,
+);
+
+type ParseErrorOccurred =
+    extern "C" fn(&NSObject<'static>, Sel, &'static NSXMLParser, &'static NSError<'static>);
+
+extern "C" fn did_start_document(this: &NSObject, _: Sel, parser: &NSXMLParser) {
+    unsafe { delegate(this).did_start_document(parser) }
+}
+
+extern "C" fn did_end_document(this: &NSObject, _: Sel, parser: &NSXMLParser) {
+    unsafe { delegate(this).did_end_document(parser) }
+}
+
+extern "C" fn did_start_element(
+    this: &NSObject,
+    _: Sel,
+    parser: &NSXMLParser,
+    element_name: &NSString,
+    namespace_uri: Option<&NSString>,
+    qualified_name: Option<&NSString>,
+    attributes: &NSDictionary<NSString<'static>, NSString<'static>>,
+) {
+    unsafe {
+        delegate(this).did_start_element(
+            parser,
+            element_name,
+            namespace_uri,
+            qualified_name,
+            attributes,
+        )
+    }
+}
+
+extern "C" fn did_end_element(
+    this: &NSObject,
+    _: Sel,
+    parser: &NSXMLParser,
+    element_name: &NSString,
+    namespace_uri: Option<&NSString>,
+    qualified_name: Option<&NSString>,
+) {
+    unsafe { delegate(this).did_end_element(parser, element_name, namespace_uri, qualified_name) }
+}
+
+extern "C" fn found_characters(this: &NSObject, _: Sel, parser: &NSXMLParser, string: &NSString) {
+    unsafe { delegate(this).found_characters(parser, string) }
+}
+
+extern "C" fn found_cdata(this: &NSObject, _: Sel, parser: &NSXMLParser, cdata: &NSData) {
+    unsafe { delegate(this).found_cdata(parser, cdata.as_bytes()) }
+}
+
+extern "C" fn found_comment(this: &NSObject, _: Sel, parser: &NSXMLParser, comment: &NSString) {
+    unsafe { delegate(this).found_comment(parser, comment) }
+}
+
+extern "C" fn found_processing_instruction(
+    this: &NSObject,
+    _: Sel,
+    parser: &NSXMLParser,
+    target: &NSString,
+    data: Option<&NSString>,
+) {
+    unsafe { delegate(this).found_processing_instruction(parser, target, data) }
+}
+
+extern "C" fn parse_error_occurred(this: &NSObject, _: Sel, parser: &NSXMLParser, error: &NSError) {
+    unsafe { delegate(this).parse_error_occurred(parser, error) }
+}
@@ -0,0 +1,225 @@
+use super::{NSData, NSError, NSURL};
+use crate::core::Arc;
+use crate::objc::{sys, ClassType, NSInteger, NSObject, Sel, BOOL};
+use std::ptr;
+
+mod delegate;
+
+pub use delegate::*;
+
+objc_subclass! {
+    /// A streaming XML parser that reports the contents of a document to an
+    /// [`NSXMLParserDelegate`] as it reads them.
+    ///
+    /// Unlike [`NSXMLDocument`], this does not keep the document in memory,
+    /// which makes it suitable for large files.
+    ///
+    /// [`NSXMLDocument`]: https://developer.apple.com/documentation/foundation/xmldocument
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/xmlparser).
+    pub class NSXMLParser: NSObject<'static>;
+}
+
+impl NSXMLParser {
+    /// Creates a parser for the XML document in `bytes`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/xmlparser/init(data:)).
+    #[inline]
+    #[doc(alias = "initWithData:")]
+    pub fn from_bytes(bytes: &[u8]) -> Arc<Self> {
+        let data = NSData::from_bytes(bytes);
+        unsafe {
+            let parser: Arc<Self> = Self::class().alloc();
+
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(obj: Arc<NSXMLParser>, sel: Sel, data: &NSData)
+                    -> Arc<NSXMLParser>;
+            }
+
+            objc_msgSend(parser, selector!(initWithData:), &data)
+        }
+    }
+
+    /// Creates a parser for the XML document at `url`, or [`None`] if it
+    /// cannot be opened.
+    ///
+    /// The document is read incrementally while parsing.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/xmlparser/init(contentsof:)).
+    #[inline]
+    #[doc(alias = "initWithContentsOfURL:")]
+    pub fn from_url(url: &NSURL) -> Option<Arc<Self>> {
+        unsafe {
+            let parser: Arc<Self> = Self::class().alloc();
+
+            #[allow(clashing_extern_declarations)]
+            extern "C" {
+                fn objc_msgSend(
+                    obj: Arc<NSXMLParser>,
+                    sel: Sel,
+                    url: &NSURL,
+                ) -> Option<Arc<NSXMLParser>>;
+            }
+
+            objc_msgSend(parser, selector!(initWithContentsOfURL:), url)
+        }
+    }
+
+    /// Parses the document, reporting its contents to `delegate`.
+    ///
+    /// This blocks until the end of the document, a parse error, or a call to
+    /// [`abort_parsing`](Self::abort_parsing). A parser can only be used
+    /// once.
+    ///
+    /// It is safe to panic within `delegate`. Panics will abort the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/xmlparser/parse()).
+    #[doc(alias = "setDelegate:")]
+    pub fn parse<D: NSXMLParserDelegate>(
+        &self,
+        delegate: &mut D,
+    ) -> Result<(), Arc<NSError<'static>>> {
+        let mut delegate: &mut dyn NSXMLParserDelegate = delegate;
+
+        unsafe {
+            let object = new_delegate_object(&mut delegate);
+
+            // Parsing from multiple threads at once would deliver events to
+            // the wrong delegate.
+            let lock: *const Self = self;
+            sys::objc_sync_enter(lock.cast());
+
+            _msg_send_any![self, setDelegate: &*object => ()];
+            let success: BOOL = _msg_send_any![self, parse];
+            _msg_send_any![self, setDelegate: ptr::null::<NSObject>() => ()];
+
+            sys::objc_sync_exit(lock.cast());
+
+            if success.into() {
+                return Ok(());
+            }
+        }
+
+        // `parserError` is set both for malformed documents and for
+        // `abortParsing`.
+        match self.parser_error() {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+
+    /// Stops parsing, causing [`parse`](Self::parse) to return an error.
+    ///
+    /// This should be called from within an [`NSXMLParserDelegate`] method.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/xmlparser/abortparsing()).
+    #[inline]
+    #[doc(alias = "abortParsing")]
+    pub fn abort_parsing(&self) {
+        unsafe { _msg_send_any![self, abortParsing] }
+    }
+
+    /// Returns the error that stopped parsing, if any.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/xmlparser/parsererror).
+    #[inline]
+    #[doc(alias = "parserError")]
+    pub fn parser_error(&self) -> Option<Arc<NSError<'static>>> {
+        unsafe {
+            _msg_send_any![self, parserError => *const NSError<'static>]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Returns the line number of the current position in the document,
+    /// starting at 1.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/xmlparser/linenumber).
+    #[inline]
+    #[doc(alias = "lineNumber")]
+    pub fn line_number(&self) -> NSInteger {
+        unsafe { _msg_send_any![self, lineNumber] }
+    }
+
+    /// Returns the column number of the current position in the document,
+    /// starting at 1.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/xmlparser/columnnumber).
+    #[inline]
+    #[doc(alias = "columnNumber")]
+    pub fn column_number(&self) -> NSInteger {
+        unsafe { _msg_send_any![self, columnNumber] }
+    }
+}
+
+/// Parsing options.
+///
+/// These must be set before calling [`parse`](Self::parse).
+impl NSXMLParser {
+    /// Returns `true` if the parser reports namespace URIs and qualified
+    /// names of elements.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/xmlparser/shouldprocessnamespaces).
+    #[inline]
+    #[doc(alias = "shouldProcessNamespaces")]
+    pub fn should_process_namespaces(&self) -> bool {
+        unsafe { _msg_send_any![self, shouldProcessNamespaces => BOOL] }.into()
+    }
+
+    /// Sets whether the parser reports namespace URIs and qualified names of
+    /// elements.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/xmlparser/shouldprocessnamespaces).
+    #[inline]
+    #[doc(alias = "setShouldProcessNamespaces")]
+    #[doc(alias = "setShouldProcessNamespaces:")]
+    pub fn set_should_process_namespaces(&self, value: bool) {
+        unsafe { _msg_send_any![self, setShouldProcessNamespaces: BOOL::from(value)] }
+    }
+
+    /// Returns `true` if the parser reports the scope of namespace
+    /// declarations.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/xmlparser/shouldreportnamespaceprefixes).
+    #[inline]
+    #[doc(alias = "shouldReportNamespacePrefixes")]
+    pub fn should_report_namespace_prefixes(&self) -> bool {
+        unsafe { _msg_send_any![self, shouldReportNamespacePrefixes => BOOL] }.into()
+    }
+
+    /// Sets whether the parser reports the scope of namespace declarations.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/xmlparser/shouldreportnamespaceprefixes).
+    #[inline]
+    #[doc(alias = "setShouldReportNamespacePrefixes")]
+    #[doc(alias = "setShouldReportNamespacePrefixes:")]
+    pub fn set_should_report_namespace_prefixes(&self, value: bool) {
+        unsafe { _msg_send_any![self, setShouldReportNamespacePrefixes: BOOL::from(value)] }
+    }
+
+    /// Returns `true` if the parser loads external entities declared in the
+    /// document's DTD.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/xmlparser/shouldresolveexternalentities).
+    #[inline]
+    #[doc(alias = "shouldResolveExternalEntities")]
+    pub fn should_resolve_external_entities(&self) -> bool {
+        unsafe { _msg_send_any![self, shouldResolveExternalEntities => BOOL] }.into()
+    }
+
+    /// Sets whether the parser loads external entities declared in the
+    /// document's DTD.
+    ///
+    /// This is disabled by default and should stay disabled for untrusted
+    /// documents.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/foundation/xmlparser/shouldresolveexternalentities).
+    #[inline]
+    #[doc(alias = "setShouldResolveExternalEntities")]
+    #[doc(alias = "setShouldResolveExternalEntities:")]
+    pub fn set_should_resolve_external_entities(&self, value: bool) {
+        unsafe { _msg_send_any![self, setShouldResolveExternalEntities: BOOL::from(value)] }
+    }
+}
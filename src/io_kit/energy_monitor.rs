@@ -0,0 +1,235 @@
+use super::{PowerSourceNotifier, PowerSourceType};
+use crate::core_foundation::{CFRunLoop, CFRunLoopMode};
+use crate::foundation::{
+    NSNotificationCenter, NSNotificationName, NSNotificationObserver, NSProcessInfo,
+    NSProcessInfoThermalState,
+};
+use std::{fmt, sync};
+
+/// The energy conditions that compute-heavy work should adapt to.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct EnergyState {
+    /// How hot the system is.
+    pub thermal_state: NSProcessInfoThermalState,
+
+    /// Whether the user enabled Low Power Mode.
+    pub is_low_power_mode_enabled: bool,
+
+    /// Where the system is drawing power from, if known.
+    pub power_source: Option<PowerSourceType>,
+}
+
+impl EnergyState {
+    /// Returns the current energy conditions.
+    pub fn current() -> Self {
+        let process_info = NSProcessInfo::current();
+        Self {
+            thermal_state: process_info.thermal_state(),
+            is_low_power_mode_enabled: process_info.is_low_power_mode_enabled(),
+            power_source: PowerSourceType::current(),
+        }
+    }
+
+    /// Returns `true` if optional or deferrable work should be reduced,
+    /// because the system is [seriously](NSProcessInfoThermalState::SERIOUS)
+    /// hot or the user enabled Low Power Mode.
+    ///
+    /// Running on battery alone does not count, since users expect apps to
+    /// keep working normally when unplugged.
+    #[inline]
+    pub fn should_reduce_work(&self) -> bool {
+        self.thermal_state >= NSProcessInfoThermalState::SERIOUS || self.is_low_power_mode_enabled
+    }
+}
+
+/// A change reported by an [`EnergyMonitor`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EnergyEvent {
+    /// The thermal state changed to the given state.
+    #[doc(alias = "NSProcessInfoThermalStateDidChangeNotification")]
+    ThermalStateChanged(NSProcessInfoThermalState),
+
+    /// Low Power Mode was turned on (`true`) or off (`false`).
+    #[doc(alias = "NSProcessInfoPowerStateDidChangeNotification")]
+    LowPowerModeChanged(bool),
+
+    /// The system switched to the given power source, such as when the power
+    /// adapter is unplugged.
+    #[doc(alias = "IOPSNotificationCreateRunLoopSource")]
+    PowerSourceChanged(Option<PowerSourceType>),
+}
+
+impl EnergyEvent {
+    /// Returns the events that describe the change from `old` to `new`.
+    fn diff(old: &EnergyState, new: &EnergyState) -> impl Iterator<Item = Self> {
+        let thermal_state = Some(Self::ThermalStateChanged(new.thermal_state))
+            .filter(|_| old.thermal_state != new.thermal_state);
+        let low_power_mode = Some(Self::LowPowerModeChanged(new.is_low_power_mode_enabled))
+            .filter(|_| old.is_low_power_mode_enabled != new.is_low_power_mode_enabled);
+        let power_source = Some(Self::PowerSourceChanged(new.power_source))
+            .filter(|_| old.power_source != new.power_source);
+
+        thermal_state
+            .into_iter()
+            .chain(low_power_mode)
+            .chain(power_source)
+    }
+}
+
+/// The last reported state and the callback that changes are reported to.
+struct Shared<F> {
+    state: EnergyState,
+    callback: F,
+}
+
+/// Reports thermal state, Low Power Mode, and power source changes to a
+/// single callback as [`EnergyEvent`]s until dropped.
+///
+/// This lets compute-heavy work, such as indexing or encoding, throttle
+/// itself when the system is hot or saving energy, and resume when
+/// conditions improve.
+///
+/// Each change is reported once, even if the system posts several
+/// notifications for it. Power source changes are reported on the run loop
+/// that the monitor is scheduled on, while thermal state and Low Power Mode
+/// changes are reported on whichever thread the system posts them from.
+///
+/// # Examples
+///
+/// ```no_run
+/// use fruity::core_foundation::{CFRunLoop, CFRunLoopMode};
+/// use fruity::io_kit::{EnergyEvent, EnergyMonitor};
+///
+/// let _monitor = EnergyMonitor::schedule_on_run_loop(
+///     &CFRunLoop::current(),
+///     CFRunLoopMode::default(),
+///     |event, state| {
+///         println!("{:?}", event);
+///         if state.should_reduce_work() {
+///             // Lower the number of worker threads.
+///         }
+///     },
+/// )
+/// .expect("could not monitor power sources");
+///
+/// CFRunLoop::run();
+/// ```
+pub struct EnergyMonitor {
+    // Held until dropped to keep reporting.
+    #[allow(dead_code)]
+    power_source: PowerSourceNotifier,
+    #[allow(dead_code)]
+    observers: [NSNotificationObserver; 2],
+}
+
+impl fmt::Debug for EnergyMonitor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("EnergyMonitor").finish()
+    }
+}
+
+impl EnergyMonitor {
+    /// Starts reporting changes to `callback` along with the new state, or
+    /// returns [`None`] if power sources could not be monitored.
+    ///
+    /// The current state can be read with [`EnergyState::current`], and is
+    /// not reported.
+    ///
+    /// It is safe to panic within `callback`. Panics will abort the process.
+    pub fn schedule_on_run_loop<F>(
+        run_loop: &CFRunLoop,
+        mode: &CFRunLoopMode,
+        callback: F,
+    ) -> Option<Self>
+    where
+        F: FnMut(EnergyEvent, &EnergyState) + Send + 'static,
+    {
+        let shared = sync::Arc::new(sync::Mutex::new(Shared {
+            state: EnergyState::current(),
+            callback,
+        }));
+
+        let update = move |shared: &sync::Mutex<Shared<F>>| {
+            let mut shared = match shared.lock() {
+                Ok(shared) => shared,
+                Err(poisoned) => poisoned.into_inner(),
+            };
+            let Shared { state, callback } = &mut *shared;
+
+            let new = EnergyState::current();
+            for event in EnergyEvent::diff(state, &new) {
+                callback(event, &new);
+            }
+            *state = new;
+        };
+
+        let power_source = {
+            let shared = shared.clone();
+            PowerSourceNotifier::schedule_on_run_loop(run_loop, mode, move || update(&shared))?
+        };
+
+        let center = NSNotificationCenter::default();
+        let observe = |name| {
+            let shared = shared.clone();
+            center.add_observer(Some(name), None, move |_| update(&shared))
+        };
+        let observers = [
+            observe(NSNotificationName::process_info_thermal_state_did_change()),
+            observe(NSNotificationName::process_info_power_state_did_change()),
+        ];
+
+        Some(Self {
+            power_source,
+            observers,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state(
+        thermal_state: NSProcessInfoThermalState,
+        is_low_power_mode_enabled: bool,
+        power_source: Option<PowerSourceType>,
+    ) -> EnergyState {
+        EnergyState {
+            thermal_state,
+            is_low_power_mode_enabled,
+            power_source,
+        }
+    }
+
+    #[test]
+    fn diff() {
+        let old = state(
+            NSProcessInfoThermalState::NOMINAL,
+            false,
+            Some(PowerSourceType::AC),
+        );
+        assert_eq!(EnergyEvent::diff(&old, &old).count(), 0);
+
+        let new = state(
+            NSProcessInfoThermalState::SERIOUS,
+            false,
+            Some(PowerSourceType::Battery),
+        );
+        let events: Vec<_> = EnergyEvent::diff(&old, &new).collect();
+        assert_eq!(
+            events,
+            [
+                EnergyEvent::ThermalStateChanged(NSProcessInfoThermalState::SERIOUS),
+                EnergyEvent::PowerSourceChanged(Some(PowerSourceType::Battery)),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_reduce_work() {
+        let battery = Some(PowerSourceType::Battery);
+        assert!(!state(NSProcessInfoThermalState::FAIR, false, battery).should_reduce_work());
+        assert!(state(NSProcessInfoThermalState::SERIOUS, false, None).should_reduce_work());
+        assert!(state(NSProcessInfoThermalState::NOMINAL, true, None).should_reduce_work());
+    }
+}
@@ -0,0 +1,85 @@
+use super::sys;
+use std::{error::Error, ffi::CStr, fmt, num::NonZeroI32};
+
+/// A non-zero error code returned by an IOKit function.
+///
+/// Rust bindings of functions that return `IOReturn` return
+/// [`Result<T, IOReturn>`](Result), where `kIOReturnSuccess` becomes [`Ok`].
+///
+/// See [documentation](https://developer.apple.com/documentation/kernel/ioreturn).
+#[repr(transparent)]
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct IOReturn(NonZeroI32);
+
+impl fmt::Debug for IOReturn {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("IOReturn")
+            .field(&format_args!("{:#010x}", self.value()))
+            .field(&self.message())
+            .finish()
+    }
+}
+
+impl fmt::Display for IOReturn {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} (IOReturn {:#010x})", self.message(), self.value())
+    }
+}
+
+impl Error for IOReturn {}
+
+impl IOReturn {
+    /// The caller does not have the required privileges.
+    #[doc(alias = "kIOReturnNotPrivileged")]
+    pub const NOT_PRIVILEGED: Self = unsafe { Self::new_unchecked(0xE000_02C1_u32 as i32) };
+
+    /// An argument was invalid.
+    #[doc(alias = "kIOReturnBadArgument")]
+    pub const BAD_ARGUMENT: Self = unsafe { Self::new_unchecked(0xE000_02C2_u32 as i32) };
+
+    /// Creates an instance from `value`, returning `None` if it is
+    /// `kIOReturnSuccess`.
+    #[inline]
+    pub const fn new(value: i32) -> Option<Self> {
+        match NonZeroI32::new(value) {
+            Some(value) => Some(Self(value)),
+            None => None,
+        }
+    }
+
+    /// Creates an instance from `value`, without checking if it is zero.
+    ///
+    /// # Safety
+    ///
+    /// The value must not be zero.
+    #[inline]
+    pub const unsafe fn new_unchecked(value: i32) -> Self {
+        Self(NonZeroI32::new_unchecked(value))
+    }
+
+    /// Returns `Ok(())` for `kIOReturnSuccess`, or the error otherwise.
+    #[inline]
+    pub(crate) fn result(value: sys::IOReturn) -> Result<(), Self> {
+        match Self::new(value) {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns this error's integer value.
+    #[inline]
+    pub const fn value(self) -> i32 {
+        self.0.get()
+    }
+
+    /// Returns a description of this error, such as "(iokit/common) not
+    /// privileged".
+    #[inline]
+    #[doc(alias = "mach_error_string")]
+    pub fn message(self) -> &'static str {
+        unsafe { CStr::from_ptr(sys::mach_error_string(self.value())) }
+            .to_str()
+            .unwrap_or("unknown error")
+    }
+}
@@ -5,6 +5,9 @@
 //! This module corresponds to the **`io_kit`**
 //! [feature flag](../index.html#feature-flags).
 //!
+//! It is only available on macOS, since IOKit is not public API on other
+//! platforms.
+//!
 //! # Documentation
 //!
 //! - [Introduction](https://developer.apple.com/library/archive/documentation/DeviceDrivers/Conceptual/IOKitFundamentals/Introduction/Introduction.html)
@@ -20,6 +23,20 @@
 //! - [Managing Device Removal](https://developer.apple.com/library/archive/documentation/DeviceDrivers/Conceptual/IOKitFundamentals/DeviceRemoval/DeviceRemoval.html)
 //! - [Base and Helper Class Hierarchy](https://developer.apple.com/library/archive/documentation/DeviceDrivers/Conceptual/IOKitFundamentals/HelperClassesChart/HelperClassesChart.html)
 
-#![cfg(feature = "io_kit")]
+#![cfg(all(feature = "io_kit", target_os = "macos"))]
 
 pub mod sys;
+
+#[cfg(feature = "foundation")]
+mod energy_monitor;
+mod io_return;
+mod power_assertion;
+mod power_source;
+mod system_power;
+
+#[cfg(feature = "foundation")]
+pub use energy_monitor::*;
+pub use io_return::*;
+pub use power_assertion::*;
+pub use power_source::*;
+pub use system_power::*;
@@ -0,0 +1,96 @@
+use super::{sys, IOReturn};
+use crate::core_foundation::CFString;
+
+/// What a [`PowerAssertion`] prevents.
+///
+/// See [documentation](https://developer.apple.com/documentation/iokit/iopmlib_h/assertion_types).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PowerAssertionType {
+    /// Prevents the system from sleeping when the user is idle, while still
+    /// letting the display sleep.
+    ///
+    /// This is what `caffeinate -i` does, and is the right choice for long
+    /// running work such as downloads or builds.
+    #[doc(alias = "kIOPMAssertionTypePreventUserIdleSystemSleep")]
+    PreventUserIdleSystemSleep,
+
+    /// Prevents the display from dimming or sleeping when the user is idle,
+    /// which also keeps the system awake.
+    ///
+    /// This is what `caffeinate -d` does, and is meant for presenting
+    /// content such as video.
+    #[doc(alias = "kIOPMAssertionTypePreventUserIdleDisplaySleep")]
+    PreventUserIdleDisplaySleep,
+
+    /// Prevents the system from sleeping for any reason, including closing
+    /// the lid, while on AC power.
+    ///
+    /// This is what `caffeinate -s` does.
+    #[doc(alias = "kIOPMAssertionTypePreventSystemSleep")]
+    PreventSystemSleep,
+}
+
+impl PowerAssertionType {
+    /// Returns the name of the assertion type, as passed to IOKit.
+    #[inline]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::PreventUserIdleSystemSleep => "PreventUserIdleSystemSleep",
+            Self::PreventUserIdleDisplaySleep => "PreventUserIdleDisplaySleep",
+            Self::PreventSystemSleep => "PreventSystemSleep",
+        }
+    }
+}
+
+/// Keeps the system or display awake until dropped.
+///
+/// Active assertions are listed by `pmset -g assertions` along with their
+/// name, so the name should explain why the assertion is held, such as
+/// "Downloading updates".
+///
+/// # Examples
+///
+/// ```no_run
+/// use fruity::core_foundation::CFString;
+/// use fruity::io_kit::{PowerAssertion, PowerAssertionType};
+///
+/// let name = CFString::from_static_str("Running backup");
+/// let _assertion = PowerAssertion::new(PowerAssertionType::PreventUserIdleSystemSleep, &name)
+///     .expect("failed to prevent sleep");
+///
+/// // The system stays awake until `_assertion` is dropped.
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct PowerAssertion {
+    id: sys::IOPMAssertionID,
+}
+
+impl Drop for PowerAssertion {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe { sys::IOPMAssertionRelease(self.id) };
+    }
+}
+
+impl PowerAssertion {
+    /// Creates an assertion of `kind` described by `name`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/iokit/iopmlib_h).
+    #[doc(alias = "IOPMAssertionCreateWithName")]
+    pub fn new(kind: PowerAssertionType, name: &CFString) -> Result<Self, IOReturn> {
+        let kind = CFString::from_static_str(kind.as_str());
+        let mut id: sys::IOPMAssertionID = 0;
+
+        IOReturn::result(unsafe {
+            sys::IOPMAssertionCreateWithName(&*kind, sys::kIOPMAssertionLevelOn, name, &mut id)
+        })?;
+
+        Ok(Self { id })
+    }
+
+    /// Returns the system-wide identifier of the assertion.
+    #[inline]
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+}
@@ -0,0 +1,148 @@
+use super::sys;
+use crate::core::Arc;
+use crate::core_foundation::{
+    CFRunLoop, CFRunLoopMode, CFRunLoopSchedulable, CFRunLoopSource, CFType,
+};
+use std::{ffi::c_void, fmt, panic, process};
+
+/// Where the system is drawing power from.
+///
+/// See [documentation](https://developer.apple.com/documentation/iokit/iopowersources_h).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PowerSourceType {
+    /// An external power adapter.
+    #[doc(alias = "kIOPMACPowerKey")]
+    AC,
+
+    /// An internal battery.
+    #[doc(alias = "kIOPMBatteryPowerKey")]
+    Battery,
+
+    /// An uninterruptible power supply.
+    #[doc(alias = "kIOPMUPSPowerKey")]
+    UPS,
+}
+
+impl PowerSourceType {
+    /// Returns the power source that the system is currently drawing from,
+    /// or [`None`] if it could not be determined.
+    #[doc(alias = "IOPSCopyPowerSourcesInfo")]
+    #[doc(alias = "IOPSGetProvidingPowerSourceType")]
+    pub fn current() -> Option<Self> {
+        unsafe {
+            let snapshot = sys::IOPSCopyPowerSourcesInfo();
+            if snapshot.is_null() {
+                return None;
+            }
+            let snapshot: Arc<CFType> = Arc::from_raw(snapshot);
+
+            let kind = sys::IOPSGetProvidingPowerSourceType(&*snapshot).as_ref()?;
+            if *kind == "AC Power" {
+                Some(Self::AC)
+            } else if *kind == "Battery Power" {
+                Some(Self::Battery)
+            } else if *kind == "UPS Power" {
+                Some(Self::UPS)
+            } else {
+                None
+            }
+        }
+    }
+}
+
+/// Calls a callback whenever a power source changes, such as when the power
+/// adapter is connected or the battery level changes, until dropped.
+///
+/// The callback is not told what changed, so it should read the new state,
+/// such as with [`PowerSourceType::current`].
+///
+/// See [documentation](https://developer.apple.com/documentation/iokit/1523868-iopsnotificationcreaterunloopsou).
+pub struct PowerSourceNotifier {
+    source: Arc<CFRunLoopSource>,
+    context: *mut c_void,
+    release_context: unsafe fn(*mut c_void),
+}
+
+// SAFETY: The callback is `Send`, and the context is only released on drop.
+unsafe impl Send for PowerSourceNotifier {}
+
+impl fmt::Debug for PowerSourceNotifier {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("PowerSourceNotifier")
+            .field("is_valid", &self.source.is_valid())
+            .finish()
+    }
+}
+
+impl Drop for PowerSourceNotifier {
+    fn drop(&mut self) {
+        // Removes the source from all run loops so that the callback is never
+        // called again.
+        self.source.invalidate();
+        unsafe { (self.release_context)(self.context) };
+    }
+}
+
+/// Scheduling in more modes or run loops.
+impl CFRunLoopSchedulable for PowerSourceNotifier {
+    #[inline]
+    fn schedule(&self, run_loop: &CFRunLoop, mode: &CFRunLoopMode) {
+        run_loop.add_source(&self.source, mode);
+    }
+
+    #[inline]
+    fn unschedule(&self, run_loop: &CFRunLoop, mode: &CFRunLoopMode) {
+        run_loop.remove_source(&self.source, mode);
+    }
+}
+
+impl PowerSourceNotifier {
+    /// Starts calling `callback` on `run_loop` in `mode`, or returns [`None`]
+    /// if registration failed.
+    ///
+    /// It is safe to panic within `callback`. Panics will abort the process.
+    #[doc(alias = "IOPSNotificationCreateRunLoopSource")]
+    pub fn schedule_on_run_loop<F>(
+        run_loop: &CFRunLoop,
+        mode: &CFRunLoopMode,
+        callback: F,
+    ) -> Option<Self>
+    where
+        F: FnMut() + Send + 'static,
+    {
+        let context = Box::into_raw(Box::new(callback));
+
+        let source =
+            unsafe { sys::IOPSNotificationCreateRunLoopSource(call_callback::<F>, context.cast()) };
+        if source.is_null() {
+            unsafe { release_context::<F>(context.cast()) };
+            return None;
+        }
+
+        let source = unsafe { Arc::from_raw(source) };
+        run_loop.add_source(&source, mode);
+
+        Some(Self {
+            source,
+            context: context.cast(),
+            release_context: release_context::<F>,
+        })
+    }
+}
+
+unsafe extern "C" fn call_callback<F>(context: *mut c_void)
+where
+    F: FnMut() + Send + 'static,
+{
+    let callback = &mut *(context as *mut F);
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(callback));
+
+    // Unwinding into IOKit is undefined behavior.
+    if result.is_err() {
+        process::abort();
+    }
+}
+
+unsafe fn release_context<F>(context: *mut c_void) {
+    drop(Box::from_raw(context as *mut F));
+}
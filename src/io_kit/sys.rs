@@ -1,4 +1,97 @@
 //! Raw unsafe C functions exposed by `IOKit.framework`.
 
+use crate::core_foundation::{CFRunLoopSource, CFString, CFType};
+use std::os::raw::{c_char, c_int, c_long, c_void};
+
+#[cfg(feature = "dispatch")]
+use crate::dispatch::DispatchQueue;
+
+#[allow(non_camel_case_types, missing_docs)]
+pub type IOReturn = c_int;
+#[allow(non_camel_case_types, missing_docs)]
+pub type io_object_t = u32;
+#[allow(non_camel_case_types, missing_docs)]
+pub type io_connect_t = io_object_t;
+#[allow(non_camel_case_types, missing_docs)]
+pub type io_service_t = io_object_t;
+#[allow(missing_docs)]
+pub type IOPMAssertionID = u32;
+#[allow(missing_docs)]
+pub type IOPMAssertionLevel = u32;
+
+#[allow(non_upper_case_globals, missing_docs)]
+pub const kIOPMAssertionLevelOn: IOPMAssertionLevel = 255;
+
+#[allow(non_upper_case_globals, missing_docs)]
+pub const kIOMessageCanSystemSleep: u32 = 0xE000_0270;
+#[allow(non_upper_case_globals, missing_docs)]
+pub const kIOMessageSystemWillSleep: u32 = 0xE000_0280;
+#[allow(non_upper_case_globals, missing_docs)]
+pub const kIOMessageSystemWillNotSleep: u32 = 0xE000_0290;
+#[allow(non_upper_case_globals, missing_docs)]
+pub const kIOMessageSystemHasPoweredOn: u32 = 0xE000_0300;
+#[allow(non_upper_case_globals, missing_docs)]
+pub const kIOMessageSystemWillPowerOn: u32 = 0xE000_0320;
+
+/// An opaque `IONotificationPortRef`.
+#[repr(C)]
+pub struct IONotificationPort {
+    _private: [u8; 0],
+}
+
+#[allow(missing_docs)]
+pub type IOServiceInterestCallback = unsafe extern "C" fn(
+    refcon: *mut c_void,
+    service: io_service_t,
+    message_type: u32,
+    message_argument: *mut c_void,
+);
+
+#[allow(missing_docs)]
+pub type IOPowerSourceCallbackType = unsafe extern "C" fn(context: *mut c_void);
+
+#[allow(missing_docs)]
 #[link(name = "IOKit", kind = "framework")]
-extern "C" {}
+extern "C" {
+    pub fn IOPMAssertionCreateWithName(
+        assertion_type: *const CFString,
+        assertion_level: IOPMAssertionLevel,
+        assertion_name: *const CFString,
+        assertion_id: *mut IOPMAssertionID,
+    ) -> IOReturn;
+    pub fn IOPMAssertionRelease(assertion_id: IOPMAssertionID) -> IOReturn;
+
+    pub fn IORegisterForSystemPower(
+        refcon: *mut c_void,
+        the_port_ref: *mut *mut IONotificationPort,
+        callback: IOServiceInterestCallback,
+        notifier: *mut io_object_t,
+    ) -> io_connect_t;
+    pub fn IODeregisterForSystemPower(notifier: *mut io_object_t) -> IOReturn;
+    pub fn IOAllowPowerChange(kernel_port: io_connect_t, notification_id: c_long) -> IOReturn;
+    pub fn IOCancelPowerChange(kernel_port: io_connect_t, notification_id: c_long) -> IOReturn;
+
+    pub fn IOServiceClose(connect: io_connect_t) -> IOReturn;
+
+    pub fn IONotificationPortDestroy(notify: *mut IONotificationPort);
+    pub fn IONotificationPortGetRunLoopSource(
+        notify: *mut IONotificationPort,
+    ) -> *const CFRunLoopSource;
+    #[cfg(feature = "dispatch")]
+    pub fn IONotificationPortSetDispatchQueue(
+        notify: *mut IONotificationPort,
+        queue: *const DispatchQueue,
+    );
+
+    pub fn IOPSCopyPowerSourcesInfo() -> *const CFType<'static>;
+    pub fn IOPSGetProvidingPowerSourceType(snapshot: *const CFType<'static>) -> *const CFString;
+    pub fn IOPSNotificationCreateRunLoopSource(
+        callback: IOPowerSourceCallbackType,
+        context: *mut c_void,
+    ) -> *const CFRunLoopSource;
+}
+
+#[allow(missing_docs)]
+extern "C" {
+    pub fn mach_error_string(error_value: IOReturn) -> *const c_char;
+}
@@ -0,0 +1,282 @@
+use super::{sys, IOReturn};
+use crate::core_foundation::{CFRunLoop, CFRunLoopMode, CFRunLoopSchedulable};
+use std::{ffi::c_void, fmt, mem, os::raw::c_long, panic, process, ptr};
+
+#[cfg(feature = "dispatch")]
+use crate::dispatch::DispatchQueue;
+
+/// Reports system sleep and wake events to a callback until dropped.
+///
+/// # Examples
+///
+/// ```no_run
+/// use fruity::core_foundation::{CFRunLoop, CFRunLoopMode};
+/// use fruity::io_kit::{SystemPowerEvent, SystemPowerNotifier};
+///
+/// let _notifier = SystemPowerNotifier::schedule_on_run_loop(
+///     &CFRunLoop::current(),
+///     CFRunLoopMode::default(),
+///     |event| match event {
+///         SystemPowerEvent::CanSleep(response) => response.allow(),
+///         SystemPowerEvent::WillSleep(response) => {
+///             println!("going to sleep");
+///             response.allow();
+///         }
+///         SystemPowerEvent::HasPoweredOn => println!("woke up"),
+///         _ => {}
+///     },
+/// )
+/// .expect("could not register for system power notifications");
+///
+/// CFRunLoop::run();
+/// ```
+///
+/// See [documentation](https://developer.apple.com/library/archive/qa/qa1340/_index.html).
+pub struct SystemPowerNotifier {
+    root_port: sys::io_connect_t,
+    notify_port: *mut sys::IONotificationPort,
+    notifier: sys::io_object_t,
+    context: *mut c_void,
+    release_context: unsafe fn(*mut c_void),
+}
+
+// SAFETY: The callback is `Send`, and the ports are only released on drop.
+unsafe impl Send for SystemPowerNotifier {}
+
+impl fmt::Debug for SystemPowerNotifier {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SystemPowerNotifier")
+            .field("root_port", &self.root_port)
+            .field("notifier", &self.notifier)
+            .finish()
+    }
+}
+
+impl Drop for SystemPowerNotifier {
+    fn drop(&mut self) {
+        unsafe {
+            sys::IODeregisterForSystemPower(&mut self.notifier);
+            sys::IOServiceClose(self.root_port);
+            // Also removes the run loop source from any run loops.
+            sys::IONotificationPortDestroy(self.notify_port);
+            (self.release_context)(self.context);
+        }
+    }
+}
+
+/// Scheduling a notifier that was started with
+/// [`schedule_on_run_loop`](SystemPowerNotifier::schedule_on_run_loop) in more
+/// modes or run loops.
+///
+/// Notifiers started on a dispatch queue must not be scheduled on a run loop.
+impl CFRunLoopSchedulable for SystemPowerNotifier {
+    #[inline]
+    fn schedule(&self, run_loop: &CFRunLoop, mode: &CFRunLoopMode) {
+        unsafe {
+            let source = sys::IONotificationPortGetRunLoopSource(self.notify_port);
+            run_loop.add_source(&*source, mode);
+        }
+    }
+
+    #[inline]
+    fn unschedule(&self, run_loop: &CFRunLoop, mode: &CFRunLoopMode) {
+        unsafe {
+            let source = sys::IONotificationPortGetRunLoopSource(self.notify_port);
+            run_loop.remove_source(&*source, mode);
+        }
+    }
+}
+
+impl SystemPowerNotifier {
+    /// Starts reporting events on `run_loop` in `mode`, or returns [`None`]
+    /// if registration failed.
+    ///
+    /// It is safe to panic within `callback`. Panics will abort the process.
+    #[doc(alias = "IORegisterForSystemPower")]
+    #[doc(alias = "IONotificationPortGetRunLoopSource")]
+    pub fn schedule_on_run_loop<F>(
+        run_loop: &CFRunLoop,
+        mode: &CFRunLoopMode,
+        callback: F,
+    ) -> Option<Self>
+    where
+        F: FnMut(SystemPowerEvent) + Send + 'static,
+    {
+        let notifier = Self::register(callback)?;
+        unsafe {
+            let source = sys::IONotificationPortGetRunLoopSource(notifier.notify_port);
+            run_loop.add_source(&*source, mode);
+        }
+        Some(notifier)
+    }
+
+    /// Starts reporting events to `callback` on `queue`, or returns [`None`]
+    /// if registration failed.
+    ///
+    /// It is safe to panic within `callback`. Panics will abort the process.
+    #[cfg(feature = "dispatch")]
+    #[doc(alias = "IORegisterForSystemPower")]
+    #[doc(alias = "IONotificationPortSetDispatchQueue")]
+    pub fn schedule_on_queue<F>(queue: &DispatchQueue, callback: F) -> Option<Self>
+    where
+        F: FnMut(SystemPowerEvent) + Send + 'static,
+    {
+        let notifier = Self::register(callback)?;
+        unsafe { sys::IONotificationPortSetDispatchQueue(notifier.notify_port, queue) };
+        Some(notifier)
+    }
+
+    fn register<F>(callback: F) -> Option<Self>
+    where
+        F: FnMut(SystemPowerEvent) + Send + 'static,
+    {
+        let context = Box::into_raw(Box::new(Context {
+            root_port: 0,
+            callback,
+        }));
+
+        let mut notify_port: *mut sys::IONotificationPort = ptr::null_mut();
+        let mut notifier: sys::io_object_t = 0;
+
+        let root_port = unsafe {
+            sys::IORegisterForSystemPower(
+                context.cast(),
+                &mut notify_port,
+                call_callback::<F>,
+                &mut notifier,
+            )
+        };
+
+        if root_port == 0 {
+            unsafe { release_context::<F>(context.cast()) };
+            return None;
+        }
+
+        // No messages are delivered before the port is scheduled.
+        unsafe { (*context).root_port = root_port };
+
+        Some(Self {
+            root_port,
+            notify_port,
+            notifier,
+            context: context.cast(),
+            release_context: release_context::<F>,
+        })
+    }
+}
+
+struct Context<F> {
+    root_port: sys::io_connect_t,
+    callback: F,
+}
+
+unsafe extern "C" fn call_callback<F>(
+    refcon: *mut c_void,
+    _service: sys::io_service_t,
+    message_type: u32,
+    message_argument: *mut c_void,
+) where
+    F: FnMut(SystemPowerEvent) + Send + 'static,
+{
+    let context = &mut *(refcon as *mut Context<F>);
+
+    let response = || SleepResponse {
+        root_port: context.root_port,
+        notification_id: message_argument as c_long,
+    };
+
+    let event = match message_type {
+        sys::kIOMessageCanSystemSleep => SystemPowerEvent::CanSleep(response()),
+        sys::kIOMessageSystemWillSleep => SystemPowerEvent::WillSleep(response()),
+        sys::kIOMessageSystemWillNotSleep => SystemPowerEvent::WillNotSleep,
+        sys::kIOMessageSystemWillPowerOn => SystemPowerEvent::WillPowerOn,
+        sys::kIOMessageSystemHasPoweredOn => SystemPowerEvent::HasPoweredOn,
+        _ => return,
+    };
+
+    let callback = &mut context.callback;
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(|| callback(event)));
+
+    // Unwinding into IOKit is undefined behavior.
+    if result.is_err() {
+        process::abort();
+    }
+}
+
+unsafe fn release_context<F>(context: *mut c_void) {
+    drop(Box::from_raw(context as *mut Context<F>));
+}
+
+/// A system sleep or wake event reported by a [`SystemPowerNotifier`].
+#[derive(Debug)]
+pub enum SystemPowerEvent {
+    /// The system is idle and wants to sleep.
+    ///
+    /// The sleep can be delayed until the response is sent, or prevented
+    /// with [`SleepResponse::cancel`].
+    #[doc(alias = "kIOMessageCanSystemSleep")]
+    CanSleep(SleepResponse),
+
+    /// The system is going to sleep and cannot be stopped.
+    ///
+    /// The sleep is delayed until the response is sent, or for up to 30
+    /// seconds.
+    #[doc(alias = "kIOMessageSystemWillSleep")]
+    WillSleep(SleepResponse),
+
+    /// A sleep announced by [`CanSleep`](Self::CanSleep) was cancelled.
+    #[doc(alias = "kIOMessageSystemWillNotSleep")]
+    WillNotSleep,
+
+    /// The system is starting to wake up.
+    #[doc(alias = "kIOMessageSystemWillPowerOn")]
+    WillPowerOn,
+
+    /// The system has finished waking up.
+    #[doc(alias = "kIOMessageSystemHasPoweredOn")]
+    HasPoweredOn,
+}
+
+/// A response to a pending system sleep.
+///
+/// If the response is dropped without being sent, the sleep is allowed.
+pub struct SleepResponse {
+    root_port: sys::io_connect_t,
+    notification_id: c_long,
+}
+
+impl fmt::Debug for SleepResponse {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SleepResponse")
+            .field("notification_id", &self.notification_id)
+            .finish()
+    }
+}
+
+impl Drop for SleepResponse {
+    #[inline]
+    fn drop(&mut self) {
+        if self.root_port != 0 {
+            unsafe { sys::IOAllowPowerChange(self.root_port, self.notification_id) };
+        }
+    }
+}
+
+impl SleepResponse {
+    /// Lets the system sleep.
+    #[inline]
+    #[doc(alias = "IOAllowPowerChange")]
+    pub fn allow(self) {
+        // Sent by `drop`.
+    }
+
+    /// Prevents the system from sleeping.
+    ///
+    /// This only has an effect for [`SystemPowerEvent::CanSleep`]. Otherwise
+    /// the system still sleeps.
+    #[doc(alias = "IOCancelPowerChange")]
+    pub fn cancel(mut self) -> Result<(), IOReturn> {
+        let root_port = mem::replace(&mut self.root_port, 0);
+        IOReturn::result(unsafe { sys::IOCancelPowerChange(root_port, self.notification_id) })
+    }
+}
@@ -49,6 +49,34 @@
 //! This feature transitively enables the [`objc`] and [`core_graphics`]
 //! features/modules.
 //!
+//! A framework is only linked when its feature is enabled. Modules for
+//! frameworks that only exist on some platforms, such as [`app_kit`] on macOS
+//! and [`ui_kit`] on iOS, are also disabled on other targets. This makes it
+//! safe to enable the same features for a macOS app and its iOS counterpart.
+//!
+//! Conversions to and from types in other crates are enabled by feature flags
+//! named after those crates:
+//!
+//! - **`chrono`**: [`NSDate`](foundation::NSDate) and
+//!   [`chrono::DateTime`](https://docs.rs/chrono/0.4/chrono/struct.DateTime.html).
+//!
+//! - **`log`**: [`OSLogger`](os::OSLogger) and
+//!   [`log::Log`](https://docs.rs/log/0.4/log/trait.Log.html).
+//!
+//! - **`serde`**: [`AEDesc`](core_services::AEDesc),
+//!   [`NSAppleEventDescriptor`](foundation::NSAppleEventDescriptor), property
+//!   list types such as [`CFPropertyList`](core_foundation::CFPropertyList),
+//!   and
+//!   [`serde::Serialize`](https://docs.rs/serde/1/serde/trait.Serialize.html)/[`serde::Deserialize`](https://docs.rs/serde/1/serde/trait.Deserialize.html)
+//!   types.
+//!
+//! - **`time`**: [`NSDate`](foundation::NSDate) and
+//!   [`time::OffsetDateTime`](https://docs.rs/time/0.3/time/struct.OffsetDateTime.html).
+//!
+//! - **`uuid`**: [`CFUUID`](core_foundation::CFUUID),
+//!   [`NSUUID`](foundation::NSUUID), and
+//!   [`uuid::Uuid`](https://docs.rs/uuid/1/uuid/struct.Uuid.html).
+//!
 //! # Goals
 //!
 //! ## Idiomatic Rust
@@ -168,6 +196,7 @@
     target_arch = "arm",
     target_arch = "aarch64",
 ))]
+#![cfg_attr(docsrs, feature(doc_cfg))]
 #![deny(improper_ctypes)]
 #![warn(missing_docs)]
 
@@ -182,20 +211,71 @@ pub mod core;
 pub mod _priv;
 
 #[macro_use]
+#[cfg_attr(docsrs, doc(cfg(feature = "objc")))]
 pub mod objc;
 
+#[cfg_attr(
+    docsrs,
+    doc(cfg(all(feature = "app_kit", any(target_os = "macos", mac_catalyst))))
+)]
 pub mod app_kit;
+#[cfg_attr(docsrs, doc(cfg(feature = "authentication_services")))]
+pub mod authentication_services;
+#[cfg_attr(docsrs, doc(cfg(feature = "av_foundation")))]
+pub mod av_foundation;
+#[cfg_attr(docsrs, doc(cfg(feature = "cf_network")))]
 pub mod cf_network;
+#[cfg_attr(docsrs, doc(cfg(feature = "cloud_kit")))]
+pub mod cloud_kit;
+#[cfg_attr(docsrs, doc(cfg(feature = "core_animation")))]
 pub mod core_animation;
+#[cfg_attr(docsrs, doc(cfg(feature = "core_audio")))]
 pub mod core_audio;
+#[cfg_attr(docsrs, doc(cfg(feature = "core_data")))]
+pub mod core_data;
+#[cfg_attr(docsrs, doc(cfg(feature = "core_foundation")))]
 pub mod core_foundation;
+#[cfg_attr(docsrs, doc(cfg(feature = "core_graphics")))]
 pub mod core_graphics;
+#[cfg_attr(docsrs, doc(cfg(feature = "core_image")))]
 pub mod core_image;
+#[cfg_attr(docsrs, doc(cfg(feature = "core_location")))]
+pub mod core_location;
+#[cfg_attr(docsrs, doc(cfg(feature = "core_services")))]
 pub mod core_services;
+#[cfg_attr(docsrs, doc(cfg(feature = "core_text")))]
 pub mod core_text;
+#[cfg_attr(docsrs, doc(cfg(feature = "core_video")))]
 pub mod core_video;
+#[cfg_attr(docsrs, doc(cfg(feature = "dispatch")))]
 pub mod dispatch;
+#[cfg_attr(docsrs, doc(cfg(feature = "file_provider")))]
+pub mod file_provider;
+#[cfg_attr(docsrs, doc(cfg(feature = "foundation")))]
 pub mod foundation;
+#[cfg_attr(docsrs, doc(cfg(all(feature = "io_kit", target_os = "macos"))))]
 pub mod io_kit;
+#[cfg_attr(docsrs, doc(cfg(feature = "mach")))]
+pub mod mach;
+#[cfg_attr(docsrs, doc(cfg(feature = "media_player")))]
+pub mod media_player;
+#[cfg_attr(docsrs, doc(cfg(feature = "os")))]
+pub mod os;
+#[cfg_attr(docsrs, doc(cfg(feature = "security")))]
+pub mod security;
+#[cfg_attr(docsrs, doc(cfg(feature = "system_configuration")))]
 pub mod system_configuration;
+#[cfg_attr(docsrs, doc(cfg(all(feature = "ui_kit", not(target_os = "macos")))))]
 pub mod ui_kit;
+#[cfg_attr(docsrs, doc(cfg(feature = "uniform_type_identifiers")))]
+pub mod uniform_type_identifiers;
+#[cfg_attr(
+    docsrs,
+    doc(cfg(all(
+        feature = "user_notifications",
+        any(target_os = "macos", target_os = "ios")
+    )))
+)]
+pub mod user_notifications;
+#[cfg_attr(docsrs, doc(cfg(all(feature = "xpc", target_os = "macos"))))]
+pub mod xpc;
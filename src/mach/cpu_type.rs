@@ -0,0 +1,138 @@
+use super::sys;
+use std::{ffi::CStr, fmt, io, mem, ptr};
+
+/// A processor architecture, as used in Mach-O headers and to choose which
+/// slice of a universal binary to run.
+///
+/// See [documentation](https://developer.apple.com/documentation/kernel/cpu_type_t).
+#[repr(transparent)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[doc(alias = "cpu_type_t")]
+pub struct CPUType(pub i32);
+
+impl fmt::Debug for CPUType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.name() {
+            Some(name) => f.write_str(name),
+            None => f.debug_tuple("CPUType").field(&self.0).finish(),
+        }
+    }
+}
+
+impl CPUType {
+    /// 32-bit Intel.
+    #[doc(alias = "CPU_TYPE_X86")]
+    #[doc(alias = "CPU_TYPE_I386")]
+    pub const X86: Self = Self(7);
+
+    /// 64-bit Intel.
+    #[doc(alias = "CPU_TYPE_X86_64")]
+    pub const X86_64: Self = Self(7 | sys::CPU_ARCH_ABI64);
+
+    /// 32-bit ARM.
+    #[doc(alias = "CPU_TYPE_ARM")]
+    pub const ARM: Self = Self(12);
+
+    /// 64-bit ARM, as used by Apple silicon.
+    #[doc(alias = "CPU_TYPE_ARM64")]
+    pub const ARM64: Self = Self(12 | sys::CPU_ARCH_ABI64);
+
+    /// 64-bit ARM with 32-bit pointers, as used by watchOS.
+    #[doc(alias = "CPU_TYPE_ARM64_32")]
+    pub const ARM64_32: Self = Self(12 | sys::CPU_ARCH_ABI64_32);
+
+    /// 32-bit PowerPC.
+    #[doc(alias = "CPU_TYPE_POWERPC")]
+    pub const POWERPC: Self = Self(18);
+
+    /// 64-bit PowerPC.
+    #[doc(alias = "CPU_TYPE_POWERPC64")]
+    pub const POWERPC64: Self = Self(18 | sys::CPU_ARCH_ABI64);
+
+    /// Returns the architecture that the current process was compiled for.
+    ///
+    /// A process running under Rosetta reports [`X86_64`](Self::X86_64),
+    /// even though the machine is [`ARM64`](Self::ARM64). Use
+    /// [`is_translated`] to detect this.
+    #[inline]
+    pub const fn current() -> Self {
+        #[cfg(target_arch = "x86")]
+        {
+            Self::X86
+        }
+        #[cfg(target_arch = "x86_64")]
+        {
+            Self::X86_64
+        }
+        #[cfg(target_arch = "arm")]
+        {
+            Self::ARM
+        }
+        #[cfg(all(target_arch = "aarch64", target_pointer_width = "64"))]
+        {
+            Self::ARM64
+        }
+        #[cfg(all(target_arch = "aarch64", target_pointer_width = "32"))]
+        {
+            Self::ARM64_32
+        }
+    }
+
+    /// Returns the name used for `self` by tools like `lipo` and `arch`, or
+    /// [`None`] if it is not one of the constants of this type.
+    #[inline]
+    pub fn name(self) -> Option<&'static str> {
+        let name = match self {
+            Self::X86 => "i386",
+            Self::X86_64 => "x86_64",
+            Self::ARM => "arm",
+            Self::ARM64 => "arm64",
+            Self::ARM64_32 => "arm64_32",
+            Self::POWERPC => "ppc",
+            Self::POWERPC64 => "ppc64",
+            _ => return None,
+        };
+        Some(name)
+    }
+
+    /// Returns `true` if `self` is a 64-bit architecture.
+    #[inline]
+    pub fn is_64_bit(self) -> bool {
+        self.0 & sys::CPU_ARCH_ABI64 != 0
+    }
+}
+
+/// Returns `true` if the current process is an Intel binary running on Apple
+/// silicon through Rosetta translation.
+///
+/// This is always `false` on Intel machines, where the underlying
+/// `sysctl.proc_translated` value does not exist.
+///
+/// See [documentation](https://developer.apple.com/documentation/apple-silicon/about-the-rosetta-translation-environment).
+#[doc(alias = "sysctl.proc_translated")]
+pub fn is_translated() -> io::Result<bool> {
+    let name = CStr::from_bytes_with_nul(b"sysctl.proc_translated\0").unwrap();
+    let mut translated: i32 = 0;
+    let mut len = mem::size_of::<i32>();
+
+    let result = unsafe {
+        sys::sysctlbyname(
+            name.as_ptr(),
+            (&mut translated as *mut i32).cast(),
+            &mut len,
+            ptr::null_mut(),
+            0,
+        )
+    };
+
+    if result == 0 {
+        return Ok(translated == 1);
+    }
+
+    let error = io::Error::last_os_error();
+    if error.kind() == io::ErrorKind::NotFound {
+        Ok(false)
+    } else {
+        Err(error)
+    }
+}
@@ -0,0 +1,366 @@
+use super::{executable_architectures, sys, CPUType};
+use std::{
+    error::Error,
+    ffi::{c_void, CStr, CString},
+    fmt, mem,
+    os::unix::ffi::OsStrExt,
+    path::Path,
+    ptr::NonNull,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// A dynamic library or framework loaded into the process at runtime with
+/// `dlopen`.
+///
+/// This is for using frameworks that do not exist on every OS version that a
+/// binary supports, such as ScreenCaptureKit or Virtualization, without
+/// linking them. A binary that links a missing framework fails to launch,
+/// whereas loading one fails with an error that can be handled.
+///
+/// Typed functions are looked up with [`dynamic_fn!`](crate::dynamic_fn).
+/// Functions in libraries that are already linked, but which may be missing
+/// on older versions, are looked up with [`weak_fn!`](crate::weak_fn).
+///
+/// The library is closed when this is dropped, after which symbols from it
+/// must not be used. Use [`leak`](Self::leak) to keep it loaded for the rest
+/// of the process.
+///
+/// # Examples
+///
+/// ```no_run
+/// use fruity::mach::DynamicLibrary;
+///
+/// let library = match DynamicLibrary::framework("Virtualization") {
+///     Ok(library) => library.leak(),
+///     Err(error) => {
+///         eprintln!("virtualization is unavailable: {}", error);
+///         return;
+///     }
+/// };
+///
+/// if library.has_symbol("VZErrorDomain") {
+///     // Use the framework.
+/// }
+/// ```
+///
+/// See [documentation](https://developer.apple.com/library/archive/documentation/DeveloperTools/Conceptual/DynamicLibraries/100-Articles/UsingDynamicLibraries.html).
+#[derive(Debug)]
+pub struct DynamicLibrary {
+    handle: NonNull<c_void>,
+}
+
+// SAFETY: `dlopen` handles are process-wide and `dlsym` is thread-safe.
+unsafe impl Send for DynamicLibrary {}
+unsafe impl Sync for DynamicLibrary {}
+
+impl Drop for DynamicLibrary {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe { sys::dlclose(self.handle.as_ptr()) };
+    }
+}
+
+impl DynamicLibrary {
+    /// Loads the library or framework executable at `path`.
+    ///
+    /// If loading fails because the library has no code for the current
+    /// architecture, such as an `arm64`-only library in a process running
+    /// under Rosetta, the error is
+    /// [`IncompatibleArchitecture`](DynamicLibraryError::IncompatibleArchitecture).
+    ///
+    /// See [documentation](https://developer.apple.com/library/archive/documentation/System/Conceptual/ManPages_iPhoneOS/man3/dlopen.3.html).
+    #[doc(alias = "dlopen")]
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, DynamicLibraryError> {
+        let path = path.as_ref();
+        let c_path = CString::new(path.as_os_str().as_bytes())
+            .map_err(|_| DynamicLibraryError::Load("path contains a nul byte".into()))?;
+
+        let handle = unsafe { sys::dlopen(c_path.as_ptr(), sys::RTLD_LAZY | sys::RTLD_LOCAL) };
+        if let Some(handle) = NonNull::new(handle) {
+            return Ok(Self { handle });
+        }
+
+        let message = last_error();
+
+        // System libraries are in the dyld shared cache rather than on disk,
+        // so their architectures are not checked.
+        if let Ok(available) = executable_architectures(path) {
+            if !available.contains(&CPUType::current()) {
+                return Err(DynamicLibraryError::IncompatibleArchitecture { available });
+            }
+        }
+        Err(DynamicLibraryError::Load(message))
+    }
+
+    /// Loads the system framework `name`, such as `"ScreenCaptureKit"`, from
+    /// `/System/Library/Frameworks`.
+    #[inline]
+    pub fn framework(name: &str) -> Result<Self, DynamicLibraryError> {
+        Self::open(format!(
+            "/System/Library/Frameworks/{0}.framework/{0}",
+            name
+        ))
+    }
+
+    /// Returns the library at `path` if it is already loaded, without loading
+    /// it.
+    #[doc(alias = "RTLD_NOLOAD")]
+    pub fn loaded<P: AsRef<Path>>(path: P) -> Option<Self> {
+        let path = CString::new(path.as_ref().as_os_str().as_bytes()).ok()?;
+        let handle = unsafe {
+            sys::dlopen(
+                path.as_ptr(),
+                sys::RTLD_LAZY | sys::RTLD_LOCAL | sys::RTLD_NOLOAD,
+            )
+        };
+        NonNull::new(handle).map(|handle| Self { handle })
+    }
+
+    /// Keeps the library loaded for the rest of the process, so that its
+    /// symbols can be used from anywhere.
+    #[inline]
+    pub fn leak(self) -> &'static Self {
+        Box::leak(Box::new(self))
+    }
+
+    /// Returns the address of the exported symbol `name`, or [`None`] if the
+    /// library does not export it.
+    ///
+    /// Names are as written in C, without the leading underscore of the
+    /// linker symbol.
+    ///
+    /// See [documentation](https://developer.apple.com/library/archive/documentation/System/Conceptual/ManPages_iPhoneOS/man3/dlsym.3.html).
+    #[inline]
+    #[doc(alias = "dlsym")]
+    pub fn symbol_address(&self, name: &str) -> Option<NonNull<c_void>> {
+        unsafe { lookup(self.handle.as_ptr(), name) }
+    }
+
+    /// Returns `true` if the library exports the symbol `name`.
+    #[inline]
+    pub fn has_symbol(&self, name: &str) -> bool {
+        self.symbol_address(name).is_some()
+    }
+
+    /// Returns the exported symbol `name` as a `T`, such as an
+    /// `unsafe extern "C" fn` pointer, or [`None`] if the library does not
+    /// export it.
+    ///
+    /// # Safety
+    ///
+    /// `T` must be a pointer type that matches the symbol, and must not be
+    /// used after `self` is dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T` is not the size of a pointer.
+    #[inline]
+    pub unsafe fn symbol<T: Copy>(&self, name: &str) -> Option<T> {
+        assert_eq!(
+            mem::size_of::<T>(),
+            mem::size_of::<*mut c_void>(),
+            "symbols can only be read as pointers",
+        );
+        let address = self.symbol_address(name)?.as_ptr();
+        Some(mem::transmute_copy(&address))
+    }
+}
+
+/// Returns `true` if any image loaded in the process exports the symbol
+/// `name`.
+///
+/// This detects weakly-linked functions and variables that are missing on
+/// older OS versions, such as those of a framework introduced after the
+/// deployment target.
+#[inline]
+#[doc(alias = "RTLD_DEFAULT")]
+pub fn is_symbol_available(name: &str) -> bool {
+    unsafe { lookup(sys::RTLD_DEFAULT, name) }.is_some()
+}
+
+/// A symbol in any image loaded in the process, which is looked up once and
+/// then cached.
+///
+/// This is what [`weak_fn!`](crate::weak_fn) declares for each function.
+#[derive(Debug)]
+pub struct WeakSymbol {
+    name: &'static str,
+    address: AtomicUsize,
+}
+
+impl WeakSymbol {
+    /// The cached address when the symbol has not been looked up yet.
+    const UNRESOLVED: usize = 0;
+
+    /// The cached address when the symbol was not found, which is never a
+    /// valid address.
+    const MISSING: usize = 1;
+
+    /// Creates a symbol for `name` that is looked up when first used.
+    #[inline]
+    pub const fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            address: AtomicUsize::new(Self::UNRESOLVED),
+        }
+    }
+
+    /// Returns the name of the symbol.
+    #[inline]
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the address of the symbol, or [`None`] if no loaded image
+    /// exports it.
+    pub fn address(&self) -> Option<NonNull<c_void>> {
+        let address = match self.address.load(Ordering::Acquire) {
+            Self::UNRESOLVED => {
+                // Racing lookups find the same address, so neither wins.
+                let address = unsafe { lookup(sys::RTLD_DEFAULT, self.name) }
+                    .map_or(Self::MISSING, |address| address.as_ptr() as usize);
+                self.address.store(address, Ordering::Release);
+                address
+            }
+            address => address,
+        };
+        match address {
+            Self::MISSING => None,
+            address => NonNull::new(address as *mut c_void),
+        }
+    }
+
+    /// Returns `true` if any loaded image exports the symbol.
+    #[inline]
+    pub fn is_available(&self) -> bool {
+        self.address().is_some()
+    }
+}
+
+/// The error returned when [`DynamicLibrary::open`] fails.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DynamicLibraryError {
+    /// The library has no code for [`CPUType::current`], only for
+    /// `available`.
+    ///
+    /// Under Rosetta, the current architecture is
+    /// [`X86_64`](CPUType::X86_64), so libraries must have an Intel slice.
+    IncompatibleArchitecture {
+        /// The architectures that the library has code for.
+        available: Vec<CPUType>,
+    },
+
+    /// The library does not exist, or could not be loaded for another
+    /// reason, as described by `dlerror`.
+    Load(String),
+}
+
+impl fmt::Display for DynamicLibraryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::IncompatibleArchitecture { available } => write!(
+                f,
+                "library has no code for {:?}, only for {:?}",
+                CPUType::current(),
+                available,
+            ),
+            Self::Load(message) => f.write_str(message),
+        }
+    }
+}
+
+impl Error for DynamicLibraryError {}
+
+/// Looks up `name` in `handle`, returning [`None`] if it is missing or is not
+/// a valid C string.
+unsafe fn lookup(handle: *mut c_void, name: &str) -> Option<NonNull<c_void>> {
+    let name = CString::new(name).ok()?;
+    NonNull::new(sys::dlsym(handle, name.as_ptr()))
+}
+
+fn last_error() -> String {
+    unsafe {
+        let message = sys::dlerror();
+        if message.is_null() {
+            "unknown error".into()
+        } else {
+            CStr::from_ptr(message).to_string_lossy().into_owned()
+        }
+    }
+}
+
+/// Looks up a C function in a [`DynamicLibrary`](crate::mach::DynamicLibrary)
+/// as a typed function pointer, returning [`None`] if it is not exported.
+///
+/// This must be called in an `unsafe` block, because the signature is not
+/// checked against the library.
+///
+/// Requires the **`mach`** feature flag.
+///
+/// # Examples
+///
+/// ```no_run
+/// use fruity::mach::DynamicLibrary;
+///
+/// let library = DynamicLibrary::open("/usr/lib/libSystem.B.dylib").unwrap();
+/// let getpid = unsafe { fruity::dynamic_fn!(library, fn getpid() -> i32) };
+///
+/// if let Some(getpid) = getpid {
+///     println!("{}", unsafe { getpid() });
+/// }
+/// ```
+#[macro_export]
+macro_rules! dynamic_fn {
+    ($library:expr, fn $name:ident($($arg:ident: $ty:ty),* $(,)?) $(-> $ret:ty)?) => {
+        $crate::mach::DynamicLibrary::symbol::<
+            unsafe extern "C" fn($($ty),*) $(-> $ret)?
+        >(&$library, stringify!($name))
+    };
+}
+
+/// Declares functions that return C functions as typed function pointers,
+/// or [`None`] if no image loaded in the process exports them.
+///
+/// This is for calling functions of linked frameworks that were introduced
+/// after the deployment target, which would otherwise prevent the binary
+/// from launching on older versions. Each function is looked up once, with
+/// a [`WeakSymbol`](crate::mach::WeakSymbol).
+///
+/// Requires the **`mach`** feature flag.
+///
+/// # Examples
+///
+/// ```no_run
+/// fruity::weak_fn! {
+///     /// Available in macOS 10.12 and later.
+///     fn os_unfair_lock_lock(lock: *mut u32);
+/// }
+///
+/// match os_unfair_lock_lock() {
+///     Some(lock) => { /* ... */ }
+///     None => { /* Fall back to another lock. */ }
+/// }
+/// ```
+#[macro_export]
+macro_rules! weak_fn {
+    ($(
+        $(#[$meta:meta])*
+        $vis:vis fn $name:ident($($arg:ident: $ty:ty),* $(,)?) $(-> $ret:ty)?;
+    )+) => {
+        $(
+            $(#[$meta])*
+            #[allow(non_snake_case)]
+            $vis fn $name() -> Option<unsafe extern "C" fn($($ty),*) $(-> $ret)?> {
+                static SYMBOL: $crate::mach::WeakSymbol =
+                    $crate::mach::WeakSymbol::new(stringify!($name));
+
+                SYMBOL.address().map(|address| unsafe {
+                    std::mem::transmute::<
+                        *mut std::ffi::c_void,
+                        unsafe extern "C" fn($($ty),*) $(-> $ret)?,
+                    >(address.as_ptr())
+                })
+            }
+        )+
+    };
+}
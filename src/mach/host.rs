@@ -0,0 +1,243 @@
+use super::{sys, KernReturn};
+use std::{ffi::CStr, fmt, io, mem, ptr};
+
+/// A send right to the host, which is deallocated on [`Drop`].
+///
+/// The host reports statistics about the whole system.
+///
+/// See [documentation](https://developer.apple.com/documentation/kernel/host_t).
+#[doc(alias = "host_t")]
+pub struct Host(sys::host_t);
+
+impl Drop for Host {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe { sys::mach_port_deallocate(sys::mach_task_self_, self.0) };
+    }
+}
+
+impl fmt::Debug for Host {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Host").field(&self.0).finish()
+    }
+}
+
+impl Host {
+    /// Returns the host that the current task runs on.
+    #[inline]
+    #[doc(alias = "mach_host_self")]
+    pub fn current() -> Self {
+        Self(unsafe { sys::mach_host_self() })
+    }
+
+    /// Returns the size of a virtual memory page in bytes, which
+    /// [`VMStatistics`] counts are in.
+    #[inline]
+    #[doc(alias = "host_page_size")]
+    pub fn page_size(&self) -> Result<usize, KernReturn> {
+        let mut page_size = 0;
+        KernReturn::result(unsafe { sys::host_page_size(self.0, &mut page_size) })?;
+        Ok(page_size)
+    }
+
+    /// Returns the number of clock ticks that all CPUs have spent in each
+    /// state since boot.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/kernel/host_statistics).
+    #[doc(alias = "host_statistics")]
+    #[doc(alias = "HOST_CPU_LOAD_INFO")]
+    pub fn cpu_load(&self) -> Result<HostCPULoad, KernReturn> {
+        let mut info = sys::host_cpu_load_info::default();
+        let mut count = sys::count_of::<sys::host_cpu_load_info>();
+        KernReturn::result(unsafe {
+            sys::host_statistics(
+                self.0,
+                sys::HOST_CPU_LOAD_INFO,
+                (&mut info as *mut sys::host_cpu_load_info).cast(),
+                &mut count,
+            )
+        })?;
+
+        let ticks = info.cpu_ticks;
+        Ok(HostCPULoad {
+            user: ticks[sys::CPU_STATE_USER] as u64,
+            system: ticks[sys::CPU_STATE_SYSTEM] as u64,
+            idle: ticks[sys::CPU_STATE_IDLE] as u64,
+            nice: ticks[sys::CPU_STATE_NICE] as u64,
+        })
+    }
+
+    /// Returns system-wide virtual memory statistics.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/kernel/host_statistics64).
+    #[doc(alias = "host_statistics64")]
+    #[doc(alias = "HOST_VM_INFO64")]
+    pub fn vm_statistics(&self) -> Result<VMStatistics, KernReturn> {
+        let mut info = sys::vm_statistics64::default();
+        let mut count = sys::count_of::<sys::vm_statistics64>();
+        KernReturn::result(unsafe {
+            sys::host_statistics64(
+                self.0,
+                sys::HOST_VM_INFO64,
+                (&mut info as *mut sys::vm_statistics64).cast(),
+                &mut count,
+            )
+        })?;
+
+        Ok(VMStatistics {
+            free_count: info.free_count,
+            active_count: info.active_count,
+            inactive_count: info.inactive_count,
+            wire_count: info.wire_count,
+            speculative_count: info.speculative_count,
+            purgeable_count: info.purgeable_count,
+            external_page_count: info.external_page_count,
+            internal_page_count: info.internal_page_count,
+            compressor_page_count: info.compressor_page_count,
+            pageins: info.pageins,
+            pageouts: info.pageouts,
+            swapins: info.swapins,
+            swapouts: info.swapouts,
+        })
+    }
+}
+
+/// Clock ticks that all CPUs of a [`Host`] have spent in each state since
+/// boot.
+///
+/// CPU usage over an interval is found by comparing two samples with
+/// [`usage_since`](Self::usage_since).
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[doc(alias = "host_cpu_load_info")]
+pub struct HostCPULoad {
+    /// Ticks spent running in user mode.
+    pub user: u64,
+
+    /// Ticks spent running in the kernel.
+    pub system: u64,
+
+    /// Ticks spent idle.
+    pub idle: u64,
+
+    /// Ticks spent running low-priority processes in user mode.
+    pub nice: u64,
+}
+
+impl HostCPULoad {
+    /// Returns the total number of ticks.
+    #[inline]
+    pub const fn total(&self) -> u64 {
+        self.user + self.system + self.idle + self.nice
+    }
+
+    /// Returns the fraction of time that CPUs were busy between `earlier` and
+    /// `self`, from 0.0 to 1.0.
+    ///
+    /// This is the sum of "% User" and "% System" in Activity Monitor.
+    pub fn usage_since(&self, earlier: &Self) -> f64 {
+        // Tick counters are 32-bit in the kernel and may wrap.
+        let delta = |now: u64, then: u64| (now as u32).wrapping_sub(then as u32) as u64;
+
+        let busy = delta(self.user, earlier.user)
+            + delta(self.system, earlier.system)
+            + delta(self.nice, earlier.nice);
+        let total = busy + delta(self.idle, earlier.idle);
+
+        if total == 0 {
+            0.0
+        } else {
+            busy as f64 / total as f64
+        }
+    }
+}
+
+/// System-wide virtual memory statistics of a [`Host`].
+///
+/// Counts are in pages of [`Host::page_size`] bytes. Activity Monitor's
+/// "App Memory" is `internal_page_count - purgeable_count`, and "Memory Used"
+/// additionally includes `wire_count` and `compressor_page_count`.
+///
+/// See [documentation](https://developer.apple.com/documentation/kernel/vm_statistics64).
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[doc(alias = "vm_statistics64")]
+pub struct VMStatistics {
+    /// Pages that are not in use.
+    pub free_count: u32,
+
+    /// Pages that are in use and were recently referenced.
+    pub active_count: u32,
+
+    /// Pages that are in use but were not recently referenced.
+    pub inactive_count: u32,
+
+    /// Pages that cannot be paged out.
+    pub wire_count: u32,
+
+    /// Pages that are free but were read ahead speculatively.
+    pub speculative_count: u32,
+
+    /// Pages that can be discarded by their owner's request.
+    pub purgeable_count: u32,
+
+    /// Pages backed by files.
+    pub external_page_count: u32,
+
+    /// Pages not backed by files, such as heap memory.
+    pub internal_page_count: u32,
+
+    /// Pages used to store compressed memory.
+    pub compressor_page_count: u32,
+
+    /// Pages read from disk since boot.
+    pub pageins: u64,
+
+    /// Pages written to disk since boot.
+    pub pageouts: u64,
+
+    /// Pages read from swap since boot.
+    pub swapins: u64,
+
+    /// Pages written to swap since boot.
+    pub swapouts: u64,
+}
+
+/// How close the system is to running out of memory, as shown by the
+/// "Memory Pressure" graph of Activity Monitor.
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MemoryPressureLevel(pub i32);
+
+impl MemoryPressureLevel {
+    /// Memory is readily available.
+    pub const NORMAL: Self = Self(1);
+
+    /// The system is compressing memory and may start swapping.
+    pub const WARNING: Self = Self(2);
+
+    /// The system is swapping and apps should free memory.
+    pub const CRITICAL: Self = Self(4);
+
+    /// Returns the current memory pressure level of the system.
+    #[doc(alias = "kern.memorystatus_vm_pressure_level")]
+    pub fn current() -> io::Result<Self> {
+        let name = CStr::from_bytes_with_nul(b"kern.memorystatus_vm_pressure_level\0").unwrap();
+        let mut level: i32 = 0;
+        let mut len = mem::size_of::<i32>();
+
+        let result = unsafe {
+            sys::sysctlbyname(
+                name.as_ptr(),
+                (&mut level as *mut i32).cast(),
+                &mut len,
+                ptr::null_mut(),
+                0,
+            )
+        };
+
+        if result == 0 {
+            Ok(Self(level))
+        } else {
+            Err(io::Error::last_os_error())
+        }
+    }
+}
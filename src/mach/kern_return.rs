@@ -0,0 +1,68 @@
+use super::sys;
+use std::{error::Error, ffi::CStr, fmt, num::NonZeroI32};
+
+/// A non-zero error code returned by a Mach kernel function.
+///
+/// Rust bindings of functions that return `kern_return_t` return
+/// [`Result<T, KernReturn>`](Result), where `KERN_SUCCESS` becomes [`Ok`].
+///
+/// See [documentation](https://developer.apple.com/documentation/kernel/kern_return_t).
+#[repr(transparent)]
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[doc(alias = "kern_return_t")]
+pub struct KernReturn(NonZeroI32);
+
+impl fmt::Debug for KernReturn {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("KernReturn")
+            .field(&self.value())
+            .field(&self.message())
+            .finish()
+    }
+}
+
+impl fmt::Display for KernReturn {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} (kern_return_t {})", self.message(), self.value())
+    }
+}
+
+impl Error for KernReturn {}
+
+impl KernReturn {
+    /// Creates an instance from `value`, returning `None` if it is
+    /// `KERN_SUCCESS`.
+    #[inline]
+    pub const fn new(value: i32) -> Option<Self> {
+        match NonZeroI32::new(value) {
+            Some(value) => Some(Self(value)),
+            None => None,
+        }
+    }
+
+    /// Returns `Ok(())` for `KERN_SUCCESS`, or the error otherwise.
+    #[inline]
+    pub(crate) fn result(value: sys::kern_return_t) -> Result<(), Self> {
+        match Self::new(value) {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns this error's integer value.
+    #[inline]
+    pub const fn value(self) -> i32 {
+        self.0.get()
+    }
+
+    /// Returns a description of this error, such as "(os/kern) invalid
+    /// argument".
+    #[inline]
+    #[doc(alias = "mach_error_string")]
+    pub fn message(self) -> &'static str {
+        unsafe { CStr::from_ptr(sys::mach_error_string(self.value())) }
+            .to_str()
+            .unwrap_or("unknown error")
+    }
+}
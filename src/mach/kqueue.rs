@@ -0,0 +1,634 @@
+use super::sys;
+use std::{
+    io, ops,
+    os::unix::io::{AsRawFd, FromRawFd, IntoRawFd, RawFd},
+    ptr, slice,
+    time::Duration,
+};
+
+#[cfg(feature = "dispatch")]
+use crate::{
+    core::Arc,
+    dispatch::{DispatchQueue, DispatchSource, DispatchSourceType},
+};
+
+/// A kernel event queue, as created by `kqueue`.
+///
+/// Events are registered with [`register`](Self::register) and received
+/// with [`poll`](Self::poll) or by iterating over [`events`](Self::events).
+/// To receive events on a dispatch queue instead, use
+/// [`dispatch_source`](Self::dispatch_source).
+///
+/// The file descriptor is closed when the queue is dropped.
+///
+/// See [documentation](https://developer.apple.com/library/archive/documentation/System/Conceptual/ManPages_iPhoneOS/man2/kqueue.2.html).
+#[derive(Debug)]
+#[doc(alias = "kqueue")]
+pub struct KQueue {
+    fd: RawFd,
+}
+
+impl Drop for KQueue {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe { sys::close(self.fd) };
+    }
+}
+
+impl AsRawFd for KQueue {
+    #[inline]
+    fn as_raw_fd(&self) -> RawFd {
+        self.fd
+    }
+}
+
+impl IntoRawFd for KQueue {
+    #[inline]
+    fn into_raw_fd(self) -> RawFd {
+        let fd = self.fd;
+        std::mem::forget(self);
+        fd
+    }
+}
+
+impl FromRawFd for KQueue {
+    #[inline]
+    unsafe fn from_raw_fd(fd: RawFd) -> Self {
+        Self { fd }
+    }
+}
+
+impl KQueue {
+    /// Creates a new, empty kernel event queue.
+    #[inline]
+    #[doc(alias = "kqueue")]
+    pub fn new() -> io::Result<Self> {
+        match unsafe { sys::kqueue() } {
+            -1 => Err(io::Error::last_os_error()),
+            fd => Ok(Self { fd }),
+        }
+    }
+
+    /// Adds or modifies the event filter described by `event`.
+    ///
+    /// Registering the same identifier and filter again replaces the
+    /// previous registration.
+    #[inline]
+    pub fn register(&self, event: &KEvent) -> io::Result<()> {
+        self.register_all(slice::from_ref(event))
+    }
+
+    /// Applies all changes in `events`, stopping at the first that fails.
+    #[doc(alias = "kevent")]
+    pub fn register_all(&self, events: &[KEvent]) -> io::Result<()> {
+        let result = unsafe {
+            sys::kevent(
+                self.fd,
+                events.as_ptr().cast(),
+                events.len() as _,
+                ptr::null_mut(),
+                0,
+                ptr::null(),
+            )
+        };
+        if result == -1 {
+            Err(io::Error::last_os_error())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Removes the event filter registered for the identifier and filter of
+    /// `event`.
+    #[inline]
+    pub fn unregister(&self, event: &KEvent) -> io::Result<()> {
+        self.register(&KEvent {
+            flags: KEventFlags::DELETE,
+            ..*event
+        })
+    }
+
+    /// Triggers the [user event](KEvent::user) registered for `ident`.
+    #[inline]
+    #[doc(alias = "NOTE_TRIGGER")]
+    pub fn trigger(&self, ident: usize) -> io::Result<()> {
+        self.register(&KEvent {
+            fflags: sys::NOTE_TRIGGER,
+            ..KEvent::new(ident, KEventFilter::USER, KEventFlags::default())
+        })
+    }
+
+    /// Waits for pending events and writes them to `events`, returning how
+    /// many were written.
+    ///
+    /// If `timeout` is [`None`], this blocks until at least one event is
+    /// pending. A zero timeout returns immediately.
+    #[doc(alias = "kevent")]
+    pub fn poll(&self, events: &mut [KEvent], timeout: Option<Duration>) -> io::Result<usize> {
+        let timeout = timeout.map(|timeout| sys::timespec {
+            tv_sec: timeout.as_secs().min(i64::MAX as u64) as _,
+            tv_nsec: timeout.subsec_nanos() as _,
+        });
+        let timeout = match &timeout {
+            Some(timeout) => timeout,
+            None => ptr::null(),
+        };
+
+        let result = unsafe {
+            sys::kevent(
+                self.fd,
+                ptr::null(),
+                0,
+                events.as_mut_ptr().cast(),
+                events.len().min(i32::MAX as usize) as _,
+                timeout,
+            )
+        };
+        if result == -1 {
+            Err(io::Error::last_os_error())
+        } else {
+            Ok(result as usize)
+        }
+    }
+
+    /// Returns an iterator that blocks until each event is pending.
+    ///
+    /// Interrupted waits are retried.
+    #[inline]
+    pub fn events(&self) -> KEvents<'_> {
+        KEvents {
+            queue: self,
+            buffer: Vec::with_capacity(KEvents::CAPACITY),
+            index: 0,
+        }
+    }
+
+    /// Creates a dispatch source that calls `handler` on `queue` with each
+    /// event that becomes pending.
+    ///
+    /// The source starts immediately and keeps `self` open until it is
+    /// [cancelled](DispatchSource::cancel) and deallocated. Events can still
+    /// be registered through other references to `self`.
+    ///
+    /// It is safe to panic within `handler`. Panics will abort the process.
+    #[cfg(feature = "dispatch")]
+    pub fn dispatch_source<F>(
+        self: std::sync::Arc<Self>,
+        queue: &DispatchQueue,
+        mut handler: F,
+    ) -> Arc<DispatchSource>
+    where
+        F: FnMut(&KEvent) + Send + 'static,
+    {
+        let fd = self.fd as usize;
+        let mut buffer = [KEvent::default(); KEvents::CAPACITY];
+
+        unsafe {
+            let source = DispatchSource::create(DispatchSourceType::read(), fd, 0, Some(queue));
+
+            // The queue is readable while events are pending, so drain them
+            // without blocking.
+            source.set_owned_event_handler(move || {
+                while let Ok(count) = self.poll(&mut buffer, Some(Duration::from_secs(0))) {
+                    buffer[..count].iter().for_each(&mut handler);
+                    if count < buffer.len() {
+                        break;
+                    }
+                }
+            });
+            source.resume();
+
+            source
+        }
+    }
+}
+
+/// A blocking iterator over the events of a [`KQueue`].
+///
+/// This is created by [`KQueue::events`].
+#[derive(Debug)]
+pub struct KEvents<'a> {
+    queue: &'a KQueue,
+    buffer: Vec<KEvent>,
+    index: usize,
+}
+
+impl KEvents<'_> {
+    const CAPACITY: usize = 16;
+}
+
+impl Iterator for KEvents<'_> {
+    type Item = io::Result<KEvent>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.index == self.buffer.len() {
+            self.buffer.resize(Self::CAPACITY, KEvent::default());
+            self.index = 0;
+
+            match self.queue.poll(&mut self.buffer, None) {
+                Ok(count) => self.buffer.truncate(count),
+                Err(error) => {
+                    self.buffer.clear();
+                    if error.kind() != io::ErrorKind::Interrupted {
+                        return Some(Err(error));
+                    }
+                }
+            }
+        }
+
+        let event = self.buffer[self.index];
+        self.index += 1;
+        Some(Ok(event))
+    }
+}
+
+/// A kernel event, as registered with and received from a [`KQueue`].
+///
+/// The typed constructors create registrations for common filters. When
+/// received, [`fflags`](Self::fflags) holds the filter-specific events that
+/// occurred and [`data`](Self::data) holds filter-specific data.
+///
+/// See [documentation](https://developer.apple.com/library/archive/documentation/System/Conceptual/ManPages_iPhoneOS/man2/kevent.2.html).
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[doc(alias = "kevent")]
+pub struct KEvent {
+    ident: usize,
+    filter: KEventFilter,
+    flags: KEventFlags,
+    fflags: u32,
+    data: isize,
+    udata: usize,
+}
+
+impl KEvent {
+    /// Creates an event for `ident` and `filter` with `flags`.
+    #[inline]
+    pub const fn new(ident: usize, filter: KEventFilter, flags: KEventFlags) -> Self {
+        Self {
+            ident,
+            filter,
+            flags,
+            fflags: 0,
+            data: 0,
+            udata: 0,
+        }
+    }
+
+    /// Registers for when the file descriptor `fd` has data to read.
+    ///
+    /// When received, [`data`](Self::data) is the number of bytes
+    /// available.
+    #[inline]
+    #[doc(alias = "EVFILT_READ")]
+    pub const fn read(fd: RawFd) -> Self {
+        Self::new(fd as usize, KEventFilter::READ, KEventFlags::ADD)
+    }
+
+    /// Registers for when the file descriptor `fd` can be written to.
+    ///
+    /// When received, [`data`](Self::data) is the space remaining in the
+    /// write buffer.
+    #[inline]
+    #[doc(alias = "EVFILT_WRITE")]
+    pub const fn write(fd: RawFd) -> Self {
+        Self::new(fd as usize, KEventFilter::WRITE, KEventFlags::ADD)
+    }
+
+    /// Registers for `events` on the file opened as `fd`.
+    ///
+    /// The registration is cleared after each event is received.
+    #[inline]
+    #[doc(alias = "EVFILT_VNODE")]
+    pub const fn vnode(fd: RawFd, events: VnodeEvents) -> Self {
+        Self {
+            fflags: events.0,
+            ..Self::new(
+                fd as usize,
+                KEventFilter::VNODE,
+                KEventFlags(KEventFlags::ADD.0 | KEventFlags::CLEAR.0),
+            )
+        }
+    }
+
+    /// Registers for `events` of the process with ID `pid`.
+    ///
+    /// The registration is removed once the process exits.
+    #[inline]
+    #[doc(alias = "EVFILT_PROC")]
+    pub const fn proc(pid: i32, events: ProcEvents) -> Self {
+        Self {
+            fflags: events.0,
+            ..Self::new(pid as usize, KEventFilter::PROC, KEventFlags::ADD)
+        }
+    }
+
+    /// Registers for deliveries of `signal` to the current process.
+    ///
+    /// Signals are still delivered as usual, so `signal` should be ignored
+    /// with `SIG_IGN` to prevent its default action. When received,
+    /// [`data`](Self::data) is the number of times the signal was delivered.
+    #[inline]
+    #[doc(alias = "EVFILT_SIGNAL")]
+    pub const fn signal(signal: i32) -> Self {
+        Self::new(signal as usize, KEventFilter::SIGNAL, KEventFlags::ADD)
+    }
+
+    /// Registers a timer identified by `ident` that fires every `interval`.
+    ///
+    /// Add [`ONESHOT`](KEventFlags::ONESHOT) to fire only once. When
+    /// received, [`data`](Self::data) is the number of times the timer fired.
+    #[inline]
+    #[doc(alias = "EVFILT_TIMER")]
+    pub fn timer(ident: usize, interval: Duration) -> Self {
+        Self {
+            fflags: sys::NOTE_NSECONDS,
+            data: interval.as_nanos().min(isize::MAX as u128) as isize,
+            ..Self::new(ident, KEventFilter::TIMER, KEventFlags::ADD)
+        }
+    }
+
+    /// Registers a user event identified by `ident`, which is received after
+    /// each [`KQueue::trigger`].
+    #[inline]
+    #[doc(alias = "EVFILT_USER")]
+    pub const fn user(ident: usize) -> Self {
+        Self::new(
+            ident,
+            KEventFilter::USER,
+            KEventFlags(KEventFlags::ADD.0 | KEventFlags::CLEAR.0),
+        )
+    }
+
+    /// Returns `self` with `flags` added.
+    #[inline]
+    pub const fn with_flags(self, flags: KEventFlags) -> Self {
+        Self {
+            flags: KEventFlags(self.flags.0 | flags.0),
+            ..self
+        }
+    }
+
+    /// Returns `self` with user data that is passed through unchanged to
+    /// received events.
+    #[inline]
+    pub const fn with_udata(self, udata: usize) -> Self {
+        Self { udata, ..self }
+    }
+
+    /// Returns the identifier of the event source, such as a file
+    /// descriptor, process ID, or signal number.
+    #[inline]
+    pub const fn ident(&self) -> usize {
+        self.ident
+    }
+
+    /// Returns the filter that produced this event.
+    #[inline]
+    pub const fn filter(&self) -> KEventFilter {
+        self.filter
+    }
+
+    /// Returns the action and status flags.
+    #[inline]
+    pub const fn flags(&self) -> KEventFlags {
+        self.flags
+    }
+
+    /// Returns the filter-specific flags.
+    #[inline]
+    pub const fn fflags(&self) -> u32 {
+        self.fflags
+    }
+
+    /// Returns the events that occurred to a [vnode](Self::vnode).
+    #[inline]
+    pub const fn vnode_events(&self) -> VnodeEvents {
+        VnodeEvents(self.fflags)
+    }
+
+    /// Returns the events that occurred to a [process](Self::proc).
+    #[inline]
+    pub const fn proc_events(&self) -> ProcEvents {
+        ProcEvents(self.fflags)
+    }
+
+    /// Returns the filter-specific data.
+    #[inline]
+    pub const fn data(&self) -> isize {
+        self.data
+    }
+
+    /// Returns the user data set by [`with_udata`](Self::with_udata).
+    #[inline]
+    pub const fn udata(&self) -> usize {
+        self.udata
+    }
+
+    /// Returns `true` if the end of the file or connection was reached.
+    #[inline]
+    pub const fn is_eof(&self) -> bool {
+        self.flags.contains(KEventFlags::EOF)
+    }
+
+    /// Returns the error that occurred while registering this event, if any.
+    #[inline]
+    pub fn error(&self) -> Option<io::Error> {
+        if self.flags.contains(KEventFlags::ERROR) && self.data != 0 {
+            Some(io::Error::from_raw_os_error(self.data as i32))
+        } else {
+            None
+        }
+    }
+}
+
+/// The filter used to process a [`KEvent`].
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct KEventFilter(pub i16);
+
+impl KEventFilter {
+    /// A file descriptor has data to read.
+    #[doc(alias = "EVFILT_READ")]
+    pub const READ: Self = Self(-1);
+
+    /// A file descriptor can be written to.
+    #[doc(alias = "EVFILT_WRITE")]
+    pub const WRITE: Self = Self(-2);
+
+    /// A file changed.
+    #[doc(alias = "EVFILT_VNODE")]
+    pub const VNODE: Self = Self(-4);
+
+    /// A process changed.
+    #[doc(alias = "EVFILT_PROC")]
+    pub const PROC: Self = Self(-5);
+
+    /// A signal was delivered.
+    #[doc(alias = "EVFILT_SIGNAL")]
+    pub const SIGNAL: Self = Self(-6);
+
+    /// A timer fired.
+    #[doc(alias = "EVFILT_TIMER")]
+    pub const TIMER: Self = Self(-7);
+
+    /// A user event was triggered.
+    #[doc(alias = "EVFILT_USER")]
+    pub const USER: Self = Self(-10);
+}
+
+/// Action and status flags of a [`KEvent`].
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct KEventFlags(pub u16);
+
+impl ops::BitOr for KEventFlags {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl KEventFlags {
+    /// Adds the event, or modifies it if it is already registered.
+    #[doc(alias = "EV_ADD")]
+    pub const ADD: Self = Self(0x0001);
+
+    /// Removes the event.
+    #[doc(alias = "EV_DELETE")]
+    pub const DELETE: Self = Self(0x0002);
+
+    /// Enables delivery of the event.
+    #[doc(alias = "EV_ENABLE")]
+    pub const ENABLE: Self = Self(0x0004);
+
+    /// Disables delivery of the event without removing it.
+    #[doc(alias = "EV_DISABLE")]
+    pub const DISABLE: Self = Self(0x0008);
+
+    /// Removes the event after it is first received.
+    #[doc(alias = "EV_ONESHOT")]
+    pub const ONESHOT: Self = Self(0x0010);
+
+    /// Resets the state of the event after it is received.
+    #[doc(alias = "EV_CLEAR")]
+    pub const CLEAR: Self = Self(0x0020);
+
+    /// Disables the event after it is received, until it is enabled again.
+    #[doc(alias = "EV_DISPATCH")]
+    pub const DISPATCH: Self = Self(0x0080);
+
+    /// The end of the file or connection was reached.
+    #[doc(alias = "EV_EOF")]
+    pub const EOF: Self = Self(0x8000);
+
+    /// An error occurred, and the error number is in
+    /// [`data`](KEvent::data).
+    #[doc(alias = "EV_ERROR")]
+    pub const ERROR: Self = Self(0x4000);
+
+    /// Returns `true` if all flags in `other` are set in `self`.
+    #[inline]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+/// Events of a file registered with [`KEvent::vnode`].
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct VnodeEvents(pub u32);
+
+impl ops::BitOr for VnodeEvents {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl VnodeEvents {
+    /// The file was unlinked.
+    #[doc(alias = "NOTE_DELETE")]
+    pub const DELETE: Self = Self(0x0001);
+
+    /// The file was written to.
+    #[doc(alias = "NOTE_WRITE")]
+    pub const WRITE: Self = Self(0x0002);
+
+    /// The file was extended.
+    #[doc(alias = "NOTE_EXTEND")]
+    pub const EXTEND: Self = Self(0x0004);
+
+    /// The attributes of the file changed.
+    #[doc(alias = "NOTE_ATTRIB")]
+    pub const ATTRIB: Self = Self(0x0008);
+
+    /// The link count of the file changed.
+    #[doc(alias = "NOTE_LINK")]
+    pub const LINK: Self = Self(0x0010);
+
+    /// The file was renamed.
+    #[doc(alias = "NOTE_RENAME")]
+    pub const RENAME: Self = Self(0x0020);
+
+    /// Access to the file was revoked, or its file system was unmounted.
+    #[doc(alias = "NOTE_REVOKE")]
+    pub const REVOKE: Self = Self(0x0040);
+
+    /// A lock on the file was released.
+    #[doc(alias = "NOTE_FUNLOCK")]
+    pub const FUNLOCK: Self = Self(0x0100);
+
+    /// Returns `true` if all events in `other` are set in `self`.
+    #[inline]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+/// Events of a process registered with [`KEvent::proc`].
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ProcEvents(pub u32);
+
+impl ops::BitOr for ProcEvents {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl ProcEvents {
+    /// The process exited.
+    #[doc(alias = "NOTE_EXIT")]
+    pub const EXIT: Self = Self(0x8000_0000);
+
+    /// The process forked.
+    #[doc(alias = "NOTE_FORK")]
+    pub const FORK: Self = Self(0x4000_0000);
+
+    /// The process executed a new program.
+    #[doc(alias = "NOTE_EXEC")]
+    pub const EXEC: Self = Self(0x2000_0000);
+
+    /// The process received a signal.
+    #[doc(alias = "NOTE_SIGNAL")]
+    pub const SIGNAL: Self = Self(0x0800_0000);
+
+    /// When combined with [`EXIT`](Self::EXIT), the exit status is in
+    /// [`data`](KEvent::data).
+    #[doc(alias = "NOTE_EXITSTATUS")]
+    pub const EXIT_STATUS: Self = Self(0x0400_0000);
+
+    /// Returns `true` if all events in `other` are set in `self`.
+    #[inline]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
@@ -0,0 +1,77 @@
+use super::CPUType;
+use std::{
+    fs::File,
+    io::{self, Read, Seek, SeekFrom},
+    path::Path,
+};
+
+const FAT_MAGIC: u32 = 0xcafe_babe;
+const FAT_MAGIC_64: u32 = 0xcafe_babf;
+const MH_MAGIC: u32 = 0xfeed_face;
+const MH_MAGIC_64: u32 = 0xfeed_facf;
+
+/// Returns the architectures that the Mach-O executable or library at `path`
+/// contains code for.
+///
+/// Universal binaries return one entry per slice, in the order they appear
+/// in the file. Other binaries return a single entry. Files that are not
+/// Mach-O return an error of kind [`InvalidData`](io::ErrorKind::InvalidData).
+///
+/// See [documentation](https://developer.apple.com/documentation/apple-silicon/building-a-universal-macos-binary).
+#[doc(alias = "fat_header")]
+#[doc(alias = "lipo")]
+pub fn executable_architectures<P: AsRef<Path>>(path: P) -> io::Result<Vec<CPUType>> {
+    let mut file = File::open(path)?;
+
+    let mut header = [0u8; 8];
+    file.read_exact(&mut header)?;
+
+    let magic_be = u32::from_be_bytes([header[0], header[1], header[2], header[3]]);
+    let magic_le = u32::from_le_bytes([header[0], header[1], header[2], header[3]]);
+
+    // Universal headers are always big-endian.
+    let fat_arch_size: u64 = match magic_be {
+        FAT_MAGIC => 20,
+        FAT_MAGIC_64 => 32,
+        _ => {
+            // Thin headers use the byte order of their architecture.
+            let cpu_type = match (magic_le, magic_be) {
+                (MH_MAGIC, _) | (MH_MAGIC_64, _) => {
+                    i32::from_le_bytes([header[4], header[5], header[6], header[7]])
+                }
+                (_, MH_MAGIC) | (_, MH_MAGIC_64) => {
+                    i32::from_be_bytes([header[4], header[5], header[6], header[7]])
+                }
+                _ => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        "not a Mach-O file",
+                    ))
+                }
+            };
+            return Ok(vec![CPUType(cpu_type)]);
+        }
+    };
+
+    let count = u32::from_be_bytes([header[4], header[5], header[6], header[7]]);
+
+    // Java class files share the universal magic number, but their version
+    // number takes the place of the architecture count and is far larger.
+    if count > 64 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "not a Mach-O file",
+        ));
+    }
+
+    let mut architectures = Vec::with_capacity(count as usize);
+    for index in 0..u64::from(count) {
+        file.seek(SeekFrom::Start(8 + index * fat_arch_size))?;
+
+        let mut cpu_type = [0u8; 4];
+        file.read_exact(&mut cpu_type)?;
+        architectures.push(CPUType(i32::from_be_bytes(cpu_type)));
+    }
+
+    Ok(architectures)
+}
@@ -0,0 +1,49 @@
+//! [Mach](https://developer.apple.com/documentation/kernel/mach) kernel
+//! interfaces for querying tasks, threads, and the host.
+//!
+//! These report the same figures that Activity Monitor and `top` show, such
+//! as CPU usage, memory footprint, and system-wide memory and CPU load.
+//! Every process on the system can be listed with [`all_pids`], along with
+//! its name, owner, executable, and arguments.
+//!
+//! Processor architectures are also handled here, for inspecting universal
+//! binaries and launching them as a specific architecture on Apple silicon.
+//! Libraries that may be missing, or lack code for the current architecture,
+//! can be loaded at runtime with [`DynamicLibrary`].
+//!
+//! [`KQueue`] monitors files, processes, signals, and timers through the
+//! kernel event queue, and can deliver its events on a dispatch queue.
+//!
+//! # Feature Flag
+//!
+//! This module corresponds to the **`mach`**
+//! [feature flag](../index.html#feature-flags).
+
+#![cfg(feature = "mach")]
+
+pub mod sys;
+
+mod cpu_type;
+mod dynamic_library;
+mod host;
+mod kern_return;
+mod kqueue;
+mod mach_o;
+mod proc_info;
+mod process;
+mod spawn;
+mod task;
+mod thread;
+mod time;
+
+pub use cpu_type::*;
+pub use dynamic_library::*;
+pub use host::*;
+pub use kern_return::*;
+pub use kqueue::*;
+pub use mach_o::*;
+pub use proc_info::*;
+pub use process::*;
+pub use spawn::*;
+pub use task::*;
+pub use thread::*;
@@ -0,0 +1,89 @@
+use super::{sys, time};
+use std::{io, mem, time::Duration};
+
+/// Memory and CPU usage of any process, as reported by `proc_pidinfo`.
+///
+/// Unlike [`Task`](super::Task), this works for other processes owned by the
+/// same user without special privileges.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[doc(alias = "proc_taskinfo")]
+pub struct ProcTaskInfo {
+    /// The size of the virtual address space in bytes.
+    pub virtual_size: u64,
+
+    /// The physical memory in use in bytes.
+    pub resident_size: u64,
+
+    /// Time spent running in user mode by all threads, including those that
+    /// have exited.
+    pub total_user_time: Duration,
+
+    /// Time spent running in the kernel by all threads, including those that
+    /// have exited.
+    pub total_system_time: Duration,
+
+    /// The number of threads.
+    pub thread_count: i32,
+
+    /// The number of threads that are running.
+    pub running_thread_count: i32,
+
+    /// The number of page faults.
+    pub faults: i32,
+
+    /// The number of pages read from disk.
+    pub pageins: i32,
+
+    /// The number of context switches.
+    pub context_switches: i32,
+
+    /// The number of system calls.
+    pub syscalls: i64,
+
+    /// The scheduling priority.
+    pub priority: i32,
+}
+
+impl ProcTaskInfo {
+    /// Returns information about the process with ID `pid`.
+    #[doc(alias = "proc_pidinfo")]
+    #[doc(alias = "PROC_PIDTASKINFO")]
+    pub fn of_pid(pid: i32) -> io::Result<Self> {
+        let mut info = sys::proc_taskinfo::default();
+        let size = mem::size_of::<sys::proc_taskinfo>() as i32;
+
+        let written = unsafe {
+            sys::proc_pidinfo(
+                pid,
+                sys::PROC_PIDTASKINFO,
+                0,
+                (&mut info as *mut sys::proc_taskinfo).cast(),
+                size,
+            )
+        };
+
+        if written <= 0 {
+            return Err(io::Error::last_os_error());
+        }
+        if written < size {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "proc_pidinfo returned a truncated proc_taskinfo",
+            ));
+        }
+
+        Ok(Self {
+            virtual_size: info.pti_virtual_size,
+            resident_size: info.pti_resident_size,
+            total_user_time: time::absolute_time(info.pti_total_user),
+            total_system_time: time::absolute_time(info.pti_total_system),
+            thread_count: info.pti_threadnum,
+            running_thread_count: info.pti_numrunning,
+            faults: info.pti_faults,
+            pageins: info.pti_pageins,
+            context_switches: info.pti_csw,
+            syscalls: info.pti_syscalls_mach as i64 + info.pti_syscalls_unix as i64,
+            priority: info.pti_priority,
+        })
+    }
+}
@@ -0,0 +1,303 @@
+use super::sys;
+use std::{
+    convert::TryInto,
+    ffi::{OsStr, OsString},
+    io, mem,
+    os::{
+        raw::{c_char, c_int},
+        unix::ffi::{OsStrExt, OsStringExt},
+    },
+    path::PathBuf,
+    ptr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// Returns the IDs of all processes on the system, including background
+/// processes and daemons that `NSRunningApplication` does not list.
+///
+/// Processes may exit or start at any time, so the list is only a snapshot.
+#[doc(alias = "proc_listpids")]
+#[doc(alias = "PROC_ALL_PIDS")]
+pub fn all_pids() -> io::Result<Vec<i32>> {
+    let pid_size = mem::size_of::<c_int>();
+
+    loop {
+        // Called with no buffer, this returns the size needed.
+        let size = unsafe { sys::proc_listpids(sys::PROC_ALL_PIDS, 0, ptr::null_mut(), 0) };
+        if size <= 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        // Leave room for processes started between the two calls.
+        let capacity = size as usize / pid_size + 32;
+        let mut pids: Vec<c_int> = vec![0; capacity];
+
+        let written = unsafe {
+            sys::proc_listpids(
+                sys::PROC_ALL_PIDS,
+                0,
+                pids.as_mut_ptr().cast(),
+                (capacity * pid_size) as c_int,
+            )
+        };
+        if written <= 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        let count = written as usize / pid_size;
+        if count < capacity {
+            pids.truncate(count);
+            pids.retain(|&pid| pid != 0);
+            return Ok(pids);
+        }
+
+        // The buffer filled up, so there may be more processes.
+    }
+}
+
+/// Returns the path of the executable of the process with ID `pid`.
+#[doc(alias = "proc_pidpath")]
+pub fn pid_path(pid: i32) -> io::Result<PathBuf> {
+    let mut buffer = vec![0u8; sys::PROC_PIDPATHINFO_MAXSIZE];
+
+    let len = unsafe {
+        sys::proc_pidpath(
+            pid,
+            buffer.as_mut_ptr().cast(),
+            sys::PROC_PIDPATHINFO_MAXSIZE as u32,
+        )
+    };
+    if len <= 0 {
+        return Err(io::Error::last_os_error());
+    }
+
+    buffer.truncate(len as usize);
+    Ok(PathBuf::from(OsString::from_vec(buffer)))
+}
+
+/// Identity and scheduling information of any process, as reported by
+/// `proc_pidinfo`.
+///
+/// Unlike [`ProcTaskInfo`](super::ProcTaskInfo), this is available for all
+/// processes, including those owned by other users.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[doc(alias = "proc_bsdinfo")]
+pub struct ProcBSDInfo {
+    /// The process ID.
+    pub pid: i32,
+
+    /// The ID of the parent process.
+    pub parent_pid: i32,
+
+    /// The ID of the process group.
+    pub group_pid: i32,
+
+    /// The effective user ID, which determines the process's permissions.
+    pub uid: u32,
+
+    /// The effective group ID.
+    pub gid: u32,
+
+    /// The real user ID, of the user who started the process.
+    pub real_uid: u32,
+
+    /// The real group ID.
+    pub real_gid: u32,
+
+    /// The name of the process, which may be truncated to 32 bytes.
+    pub name: String,
+
+    /// The scheduling priority adjustment, from -20 (highest) to 20.
+    pub nice: i32,
+
+    /// When the process started.
+    pub start_time: SystemTime,
+}
+
+impl ProcBSDInfo {
+    /// Returns information about the process with ID `pid`.
+    #[doc(alias = "proc_pidinfo")]
+    #[doc(alias = "PROC_PIDTBSDINFO")]
+    pub fn of_pid(pid: i32) -> io::Result<Self> {
+        let mut info = sys::proc_bsdinfo::default();
+        let size = mem::size_of::<sys::proc_bsdinfo>() as i32;
+
+        let written = unsafe {
+            sys::proc_pidinfo(
+                pid,
+                sys::PROC_PIDTBSDINFO,
+                0,
+                (&mut info as *mut sys::proc_bsdinfo).cast(),
+                size,
+            )
+        };
+
+        if written <= 0 {
+            return Err(io::Error::last_os_error());
+        }
+        if written < size {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "proc_pidinfo returned a truncated proc_bsdinfo",
+            ));
+        }
+
+        // `pbi_name` is empty for some processes, which `ps` shows by the
+        // shorter `pbi_comm`.
+        let name = if info.pbi_name[0] != 0 {
+            c_chars_to_string(&info.pbi_name)
+        } else {
+            c_chars_to_string(&info.pbi_comm)
+        };
+
+        let start_time = UNIX_EPOCH
+            + Duration::from_secs(info.pbi_start_tvsec)
+            + Duration::from_micros(info.pbi_start_tvusec);
+
+        Ok(Self {
+            pid: info.pbi_pid as i32,
+            parent_pid: info.pbi_ppid as i32,
+            group_pid: info.pbi_pgid as i32,
+            uid: info.pbi_uid,
+            gid: info.pbi_gid,
+            real_uid: info.pbi_ruid,
+            real_gid: info.pbi_rgid,
+            name,
+            nice: info.pbi_nice,
+            start_time,
+        })
+    }
+}
+
+/// The command line and environment that a process was started with, as
+/// reported by the `KERN_PROCARGS2` sysctl.
+///
+/// Only processes owned by the same user can be read without special
+/// privileges.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[doc(alias = "KERN_PROCARGS2")]
+pub struct ProcArgs {
+    /// The path that the executable was started from, which may be relative.
+    pub executable_path: PathBuf,
+
+    /// The arguments, starting with the name that the process was started as.
+    pub arguments: Vec<OsString>,
+
+    /// The environment variables, as `NAME=value` entries.
+    pub environment: Vec<OsString>,
+}
+
+impl ProcArgs {
+    /// Returns the command line and environment of the process with ID
+    /// `pid`.
+    #[doc(alias = "sysctl")]
+    pub fn of_pid(pid: i32) -> io::Result<Self> {
+        let mut arg_max: c_int = 0;
+        let mut size = mem::size_of::<c_int>();
+        sysctl(
+            &mut [sys::CTL_KERN, sys::KERN_ARGMAX],
+            (&mut arg_max as *mut c_int).cast(),
+            &mut size,
+        )?;
+
+        let mut buffer = vec![0u8; arg_max as usize];
+        let mut size = buffer.len();
+        sysctl(
+            &mut [sys::CTL_KERN, sys::KERN_PROCARGS2, pid],
+            buffer.as_mut_ptr().cast(),
+            &mut size,
+        )?;
+        buffer.truncate(size);
+
+        Self::parse(&buffer).ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidData, "malformed KERN_PROCARGS2 data")
+        })
+    }
+
+    /// Parses the `KERN_PROCARGS2` layout: the argument count, the executable
+    /// path, padding, then nul-terminated arguments and environment entries.
+    fn parse(buffer: &[u8]) -> Option<Self> {
+        let argc_size = mem::size_of::<c_int>();
+        if buffer.len() < argc_size {
+            return None;
+        }
+        let (argc, rest) = buffer.split_at(argc_size);
+        let argc = c_int::from_ne_bytes(argc.try_into().ok()?);
+
+        let mut strings = rest.split(|&b| b == 0);
+        let executable_path = PathBuf::from(OsStr::from_bytes(strings.next()?));
+
+        // The path is followed by nul padding, which appears as empty
+        // strings.
+        let mut strings = strings.skip_while(|s| s.is_empty());
+
+        let arguments = (&mut strings)
+            .take(argc.max(0) as usize)
+            .map(|s| OsStr::from_bytes(s).to_owned())
+            .collect::<Vec<_>>();
+        if arguments.len() != argc.max(0) as usize {
+            return None;
+        }
+
+        // The environment ends at the first empty string, after which the
+        // buffer holds unrelated data such as the executable path again.
+        let environment = strings
+            .take_while(|s| !s.is_empty())
+            .map(|s| OsStr::from_bytes(s).to_owned())
+            .collect();
+
+        Some(Self {
+            executable_path,
+            arguments,
+            environment,
+        })
+    }
+}
+
+fn sysctl(name: &mut [c_int], old: *mut u8, old_len: &mut usize) -> io::Result<()> {
+    let result = unsafe {
+        sys::sysctl(
+            name.as_mut_ptr(),
+            name.len() as u32,
+            old.cast(),
+            old_len,
+            ptr::null_mut(),
+            0,
+        )
+    };
+    if result == 0 {
+        Ok(())
+    } else {
+        Err(io::Error::last_os_error())
+    }
+}
+
+/// Converts a fixed-size, nul-padded C string to a `String`.
+fn c_chars_to_string(chars: &[c_char]) -> String {
+    let bytes: Vec<u8> = chars
+        .iter()
+        .map(|&c| c as u8)
+        .take_while(|&b| b != 0)
+        .collect();
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_proc_args() {
+        let mut buffer = 2i32.to_ne_bytes().to_vec();
+        buffer
+            .extend_from_slice(b"/bin/echo\0\0\0\0echo\0hello\0HOME=/Users/me\0PATH=/bin\0\0/bin");
+
+        let args = ProcArgs::parse(&buffer).unwrap();
+        assert_eq!(args.executable_path, PathBuf::from("/bin/echo"));
+        assert_eq!(args.arguments, ["echo", "hello"]);
+        assert_eq!(args.environment, ["HOME=/Users/me", "PATH=/bin"]);
+
+        assert_eq!(ProcArgs::parse(&buffer[..17]), None);
+        assert_eq!(ProcArgs::parse(&[0, 0]), None);
+    }
+}
@@ -0,0 +1,189 @@
+use super::{sys, CPUType};
+use std::{
+    ffi::{CString, OsStr, OsString},
+    io,
+    os::{
+        raw::c_char,
+        unix::{ffi::OsStrExt, process::ExitStatusExt},
+    },
+    process::ExitStatus,
+    ptr,
+};
+
+/// A builder for launching a program with `posix_spawn`, choosing which
+/// architecture of a universal binary to run.
+///
+/// This is useful on Apple silicon for running the Intel slice of a program
+/// under Rosetta, or for ensuring that the native slice is chosen by a
+/// process that is itself translated. [`std::process::Command`] cannot do
+/// this, since it does not expose the spawn attributes.
+///
+/// The child inherits the environment, working directory, and standard I/O
+/// of the current process. `program` is searched for in `PATH` if it does
+/// not contain a slash.
+///
+/// See [documentation](https://developer.apple.com/documentation/apple-silicon/about-the-rosetta-translation-environment).
+///
+/// # Examples
+///
+/// ```no_run
+/// use fruity::mach::{CPUType, SpawnCommand};
+///
+/// let status = SpawnCommand::new("/usr/bin/arch")
+///     .preferred_architectures(&[CPUType::X86_64])
+///     .spawn()?
+///     .wait()?;
+///
+/// assert!(status.success());
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Clone, Debug)]
+#[doc(alias = "posix_spawn")]
+#[doc(alias = "posix_spawnp")]
+pub struct SpawnCommand {
+    program: OsString,
+    args: Vec<OsString>,
+    architectures: Vec<CPUType>,
+}
+
+impl SpawnCommand {
+    /// Creates a command for running `program` with no arguments.
+    #[inline]
+    pub fn new<S: AsRef<OsStr>>(program: S) -> Self {
+        Self {
+            program: program.as_ref().to_owned(),
+            args: Vec::new(),
+            architectures: Vec::new(),
+        }
+    }
+
+    /// Adds `arg` to the arguments passed to the program.
+    #[inline]
+    pub fn arg<S: AsRef<OsStr>>(&mut self, arg: S) -> &mut Self {
+        self.args.push(arg.as_ref().to_owned());
+        self
+    }
+
+    /// Adds `args` to the arguments passed to the program.
+    #[inline]
+    pub fn args<I, S>(&mut self, args: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<OsStr>,
+    {
+        self.args
+            .extend(args.into_iter().map(|arg| arg.as_ref().to_owned()));
+        self
+    }
+
+    /// Sets the architectures to run the program as, in order of
+    /// preference.
+    ///
+    /// The first architecture in the list that the binary contains is used.
+    /// If none are present, spawning fails with `EBADARCH`. By default, the
+    /// system chooses the native architecture if possible.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/apple-silicon/about-the-rosetta-translation-environment).
+    #[inline]
+    #[doc(alias = "posix_spawnattr_setbinpref_np")]
+    pub fn preferred_architectures(&mut self, architectures: &[CPUType]) -> &mut Self {
+        self.architectures = architectures.to_vec();
+        self
+    }
+
+    /// Launches the program, returning a handle to the new process.
+    pub fn spawn(&self) -> io::Result<SpawnedProcess> {
+        let program = c_string(&self.program)?;
+
+        let args = std::iter::once(Ok(program.clone()))
+            .chain(self.args.iter().map(|arg| c_string(arg)))
+            .collect::<io::Result<Vec<CString>>>()?;
+
+        let mut argv: Vec<*mut c_char> = args.iter().map(|arg| arg.as_ptr() as *mut _).collect();
+        argv.push(ptr::null_mut());
+
+        unsafe {
+            let mut attr: sys::posix_spawnattr_t = ptr::null_mut();
+            check(sys::posix_spawnattr_init(&mut attr))?;
+
+            let result = (|| {
+                if !self.architectures.is_empty() {
+                    let mut count = 0;
+                    check(sys::posix_spawnattr_setbinpref_np(
+                        &mut attr,
+                        self.architectures.len(),
+                        self.architectures.as_ptr() as *mut sys::cpu_type_t,
+                        &mut count,
+                    ))?;
+                }
+
+                let mut pid = 0;
+                check(sys::posix_spawnp(
+                    &mut pid,
+                    program.as_ptr(),
+                    ptr::null(),
+                    &attr,
+                    argv.as_ptr(),
+                    *sys::_NSGetEnviron(),
+                ))?;
+
+                Ok(SpawnedProcess { pid })
+            })();
+
+            sys::posix_spawnattr_destroy(&mut attr);
+            result
+        }
+    }
+}
+
+/// A process launched by [`SpawnCommand`].
+///
+/// Like [`std::process::Child`], the process is not killed or waited on when
+/// this is dropped.
+#[derive(Debug)]
+pub struct SpawnedProcess {
+    pid: sys::pid_t,
+}
+
+impl SpawnedProcess {
+    /// Returns the process ID.
+    #[inline]
+    pub fn id(&self) -> i32 {
+        self.pid
+    }
+
+    /// Waits for the process to exit and returns its exit status.
+    #[doc(alias = "waitpid")]
+    pub fn wait(&mut self) -> io::Result<ExitStatus> {
+        let mut status = 0;
+        loop {
+            if unsafe { sys::waitpid(self.pid, &mut status, 0) } != -1 {
+                return Ok(ExitStatus::from_raw(status));
+            }
+
+            let error = io::Error::last_os_error();
+            if error.kind() != io::ErrorKind::Interrupted {
+                return Err(error);
+            }
+        }
+    }
+}
+
+fn c_string(s: &OsStr) -> io::Result<CString> {
+    CString::new(s.as_bytes()).map_err(|_| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "spawn argument contains a nul byte",
+        )
+    })
+}
+
+/// Converts the error number returned by a `posix_spawn` function.
+#[inline]
+fn check(result: i32) -> io::Result<()> {
+    if result == 0 {
+        Ok(())
+    } else {
+        Err(io::Error::from_raw_os_error(result))
+    }
+}
@@ -0,0 +1,340 @@
+//! Raw unsafe C functions and types exposed by the Mach kernel, libproc,
+//! `posix_spawn`, and `kqueue`.
+
+#![allow(non_camel_case_types, missing_docs)]
+
+use std::os::raw::{c_char, c_int, c_long, c_void};
+
+pub type kern_return_t = c_int;
+pub type natural_t = u32;
+pub type integer_t = i32;
+pub type mach_port_t = natural_t;
+pub type mach_msg_type_number_t = natural_t;
+pub type vm_address_t = usize;
+pub type vm_size_t = usize;
+
+pub type task_t = mach_port_t;
+pub type thread_act_t = mach_port_t;
+pub type host_t = mach_port_t;
+
+pub type cpu_type_t = integer_t;
+pub type pid_t = c_int;
+pub type posix_spawnattr_t = *mut c_void;
+
+pub const CPU_ARCH_ABI64: cpu_type_t = 0x0100_0000;
+pub const CPU_ARCH_ABI64_32: cpu_type_t = 0x0200_0000;
+
+pub const KERN_SUCCESS: kern_return_t = 0;
+
+pub const RTLD_LAZY: c_int = 0x1;
+pub const RTLD_NOW: c_int = 0x2;
+pub const RTLD_LOCAL: c_int = 0x4;
+pub const RTLD_GLOBAL: c_int = 0x8;
+pub const RTLD_NOLOAD: c_int = 0x10;
+pub const RTLD_DEFAULT: *mut c_void = -2isize as *mut c_void;
+
+pub const MACH_TASK_BASIC_INFO: u32 = 20;
+pub const TASK_VM_INFO: u32 = 22;
+pub const THREAD_BASIC_INFO: u32 = 3;
+pub const HOST_CPU_LOAD_INFO: c_int = 3;
+pub const HOST_VM_INFO64: c_int = 4;
+pub const PROC_PIDTASKINFO: c_int = 4;
+
+pub const NOTE_NSECONDS: u32 = 0x0000_0004;
+pub const NOTE_TRIGGER: u32 = 0x0100_0000;
+
+pub const TH_USAGE_SCALE: integer_t = 1000;
+pub const TH_FLAGS_IDLE: integer_t = 0x2;
+
+pub const CPU_STATE_USER: usize = 0;
+pub const CPU_STATE_SYSTEM: usize = 1;
+pub const CPU_STATE_IDLE: usize = 2;
+pub const CPU_STATE_NICE: usize = 3;
+pub const CPU_STATE_MAX: usize = 4;
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct time_value_t {
+    pub seconds: integer_t,
+    pub microseconds: integer_t,
+}
+
+#[repr(C, packed(4))]
+#[derive(Copy, Clone, Default)]
+pub struct mach_task_basic_info {
+    pub virtual_size: u64,
+    pub resident_size: u64,
+    pub resident_size_max: u64,
+    pub user_time: time_value_t,
+    pub system_time: time_value_t,
+    pub policy: integer_t,
+    pub suspend_count: integer_t,
+}
+
+/// The fields of `task_vm_info` up to `phys_footprint`, which is
+/// `TASK_VM_INFO_REV1_COUNT`.
+#[repr(C, packed(4))]
+#[derive(Copy, Clone, Default)]
+pub struct task_vm_info {
+    pub virtual_size: u64,
+    pub region_count: integer_t,
+    pub page_size: integer_t,
+    pub resident_size: u64,
+    pub resident_size_peak: u64,
+    pub device: u64,
+    pub device_peak: u64,
+    pub internal: u64,
+    pub internal_peak: u64,
+    pub external: u64,
+    pub external_peak: u64,
+    pub reusable: u64,
+    pub reusable_peak: u64,
+    pub purgeable_volatile_pmap: u64,
+    pub purgeable_volatile_resident: u64,
+    pub purgeable_volatile_virtual: u64,
+    pub compressed: u64,
+    pub compressed_peak: u64,
+    pub compressed_lifetime: u64,
+    pub phys_footprint: u64,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct thread_basic_info {
+    pub user_time: time_value_t,
+    pub system_time: time_value_t,
+    pub cpu_usage: integer_t,
+    pub policy: integer_t,
+    pub run_state: integer_t,
+    pub flags: integer_t,
+    pub suspend_count: integer_t,
+    pub sleep_time: integer_t,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct host_cpu_load_info {
+    pub cpu_ticks: [natural_t; CPU_STATE_MAX],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct vm_statistics64 {
+    pub free_count: natural_t,
+    pub active_count: natural_t,
+    pub inactive_count: natural_t,
+    pub wire_count: natural_t,
+    pub zero_fill_count: u64,
+    pub reactivations: u64,
+    pub pageins: u64,
+    pub pageouts: u64,
+    pub faults: u64,
+    pub cow_faults: u64,
+    pub lookups: u64,
+    pub hits: u64,
+    pub purges: u64,
+    pub purgeable_count: natural_t,
+    pub speculative_count: natural_t,
+    pub decompressions: u64,
+    pub compressions: u64,
+    pub swapins: u64,
+    pub swapouts: u64,
+    pub compressor_page_count: natural_t,
+    pub throttled_count: natural_t,
+    pub external_page_count: natural_t,
+    pub internal_page_count: natural_t,
+    pub total_uncompressed_pages_in_compressor: u64,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct proc_taskinfo {
+    pub pti_virtual_size: u64,
+    pub pti_resident_size: u64,
+    pub pti_total_user: u64,
+    pub pti_total_system: u64,
+    pub pti_threads_user: u64,
+    pub pti_threads_system: u64,
+    pub pti_policy: i32,
+    pub pti_faults: i32,
+    pub pti_pageins: i32,
+    pub pti_cow_faults: i32,
+    pub pti_messages_sent: i32,
+    pub pti_messages_received: i32,
+    pub pti_syscalls_mach: i32,
+    pub pti_syscalls_unix: i32,
+    pub pti_csw: i32,
+    pub pti_threadnum: i32,
+    pub pti_numrunning: i32,
+    pub pti_priority: i32,
+}
+
+pub const PROC_ALL_PIDS: u32 = 1;
+pub const PROC_PIDTBSDINFO: c_int = 3;
+pub const PROC_PIDPATHINFO_MAXSIZE: usize = 4 * 1024;
+pub const MAXCOMLEN: usize = 16;
+
+pub const CTL_KERN: c_int = 1;
+pub const KERN_ARGMAX: c_int = 8;
+pub const KERN_PROCARGS2: c_int = 49;
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct proc_bsdinfo {
+    pub pbi_flags: u32,
+    pub pbi_status: u32,
+    pub pbi_xstatus: u32,
+    pub pbi_pid: u32,
+    pub pbi_ppid: u32,
+    pub pbi_uid: u32,
+    pub pbi_gid: u32,
+    pub pbi_ruid: u32,
+    pub pbi_rgid: u32,
+    pub pbi_svuid: u32,
+    pub pbi_svgid: u32,
+    pub rfu_1: u32,
+    pub pbi_comm: [c_char; MAXCOMLEN],
+    pub pbi_name: [c_char; 2 * MAXCOMLEN],
+    pub pbi_nfiles: u32,
+    pub pbi_pgid: u32,
+    pub pbi_pjobc: u32,
+    pub e_tdev: u32,
+    pub e_tpgid: u32,
+    pub pbi_nice: i32,
+    pub pbi_start_tvsec: u64,
+    pub pbi_start_tvusec: u64,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct timespec {
+    pub tv_sec: c_long,
+    pub tv_nsec: c_long,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct mach_timebase_info_data_t {
+    pub numer: u32,
+    pub denom: u32,
+}
+
+/// Returns the number of `natural_t` values in `T`, for use as a
+/// `mach_msg_type_number_t` count.
+#[inline]
+pub const fn count_of<T>() -> mach_msg_type_number_t {
+    (std::mem::size_of::<T>() / std::mem::size_of::<natural_t>()) as _
+}
+
+// These are reexported by libSystem.
+#[link(name = "System", kind = "dylib")]
+extern "C" {
+    pub static mach_task_self_: mach_port_t;
+
+    pub fn mach_thread_self() -> thread_act_t;
+    pub fn mach_host_self() -> host_t;
+    pub fn mach_port_deallocate(task: task_t, name: mach_port_t) -> kern_return_t;
+    pub fn mach_error_string(error: kern_return_t) -> *const c_char;
+    pub fn mach_timebase_info(info: *mut mach_timebase_info_data_t) -> kern_return_t;
+
+    pub fn vm_deallocate(task: task_t, address: vm_address_t, size: vm_size_t) -> kern_return_t;
+
+    pub fn task_info(
+        task: task_t,
+        flavor: u32,
+        info: *mut integer_t,
+        count: *mut mach_msg_type_number_t,
+    ) -> kern_return_t;
+    pub fn task_threads(
+        task: task_t,
+        threads: *mut *mut thread_act_t,
+        count: *mut mach_msg_type_number_t,
+    ) -> kern_return_t;
+
+    pub fn thread_info(
+        thread: thread_act_t,
+        flavor: u32,
+        info: *mut integer_t,
+        count: *mut mach_msg_type_number_t,
+    ) -> kern_return_t;
+
+    pub fn host_statistics(
+        host: host_t,
+        flavor: c_int,
+        info: *mut integer_t,
+        count: *mut mach_msg_type_number_t,
+    ) -> kern_return_t;
+    pub fn host_statistics64(
+        host: host_t,
+        flavor: c_int,
+        info: *mut integer_t,
+        count: *mut mach_msg_type_number_t,
+    ) -> kern_return_t;
+    pub fn host_page_size(host: host_t, page_size: *mut vm_size_t) -> kern_return_t;
+
+    pub fn proc_pidinfo(
+        pid: c_int,
+        flavor: c_int,
+        arg: u64,
+        buffer: *mut c_void,
+        buffer_size: c_int,
+    ) -> c_int;
+    pub fn proc_listpids(
+        kind: u32,
+        typeinfo: u32,
+        buffer: *mut c_void,
+        buffer_size: c_int,
+    ) -> c_int;
+    pub fn proc_pidpath(pid: c_int, buffer: *mut c_void, buffer_size: u32) -> c_int;
+
+    pub fn posix_spawnattr_init(attr: *mut posix_spawnattr_t) -> c_int;
+    pub fn posix_spawnattr_destroy(attr: *mut posix_spawnattr_t) -> c_int;
+    pub fn posix_spawnattr_setbinpref_np(
+        attr: *mut posix_spawnattr_t,
+        count: usize,
+        pref: *mut cpu_type_t,
+        ocount: *mut usize,
+    ) -> c_int;
+    pub fn posix_spawnp(
+        pid: *mut pid_t,
+        file: *const c_char,
+        file_actions: *const c_void,
+        attr: *const posix_spawnattr_t,
+        argv: *const *mut c_char,
+        envp: *const *mut c_char,
+    ) -> c_int;
+    pub fn waitpid(pid: pid_t, status: *mut c_int, options: c_int) -> pid_t;
+    pub fn _NSGetEnviron() -> *mut *const *mut c_char;
+
+    pub fn kqueue() -> c_int;
+    pub fn kevent(
+        kq: c_int,
+        changelist: *const c_void,
+        nchanges: c_int,
+        eventlist: *mut c_void,
+        nevents: c_int,
+        timeout: *const timespec,
+    ) -> c_int;
+    pub fn close(fd: c_int) -> c_int;
+
+    pub fn sysctl(
+        name: *mut c_int,
+        name_len: u32,
+        old: *mut c_void,
+        old_len: *mut usize,
+        new: *mut c_void,
+        new_len: usize,
+    ) -> c_int;
+    pub fn sysctlbyname(
+        name: *const c_char,
+        old: *mut c_void,
+        old_len: *mut usize,
+        new: *mut c_void,
+        new_len: usize,
+    ) -> c_int;
+
+    pub fn dlopen(path: *const c_char, mode: c_int) -> *mut c_void;
+    pub fn dlsym(handle: *mut c_void, symbol: *const c_char) -> *mut c_void;
+    pub fn dlclose(handle: *mut c_void) -> c_int;
+    pub fn dlerror() -> *const c_char;
+}
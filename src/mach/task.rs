@@ -0,0 +1,143 @@
+use super::{sys, time, KernReturn, Thread};
+use std::{mem, slice, time::Duration};
+
+/// A Mach task, the kernel's view of a process.
+///
+/// Only the current task is available. Querying other processes requires
+/// privileges for `task_for_pid`, so [`ProcTaskInfo`](super::ProcTaskInfo)
+/// should be used for them instead.
+///
+/// See [documentation](https://developer.apple.com/documentation/kernel/task_t).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[doc(alias = "task_t")]
+pub struct Task(sys::task_t);
+
+impl Task {
+    /// Returns the task of the current process.
+    #[inline]
+    #[doc(alias = "mach_task_self")]
+    pub fn current() -> Self {
+        Self(unsafe { sys::mach_task_self_ })
+    }
+
+    /// Returns the port name of `self`.
+    #[inline]
+    pub const fn as_raw(self) -> sys::task_t {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn info<T: Default>(self, flavor: u32) -> Result<T, KernReturn> {
+        let mut info = T::default();
+        let mut count = sys::count_of::<T>();
+        KernReturn::result(sys::task_info(
+            self.0,
+            flavor,
+            (&mut info as *mut T).cast(),
+            &mut count,
+        ))?;
+        Ok(info)
+    }
+
+    /// Returns the memory usage and CPU time of `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/kernel/task_info).
+    #[doc(alias = "task_info")]
+    #[doc(alias = "MACH_TASK_BASIC_INFO")]
+    pub fn basic_info(self) -> Result<TaskBasicInfo, KernReturn> {
+        let info: sys::mach_task_basic_info = unsafe { self.info(sys::MACH_TASK_BASIC_INFO)? };
+        Ok(TaskBasicInfo {
+            virtual_size: info.virtual_size,
+            resident_size: info.resident_size,
+            resident_size_max: info.resident_size_max,
+            user_time: time::time_value(info.user_time),
+            system_time: time::time_value(info.system_time),
+            suspend_count: info.suspend_count,
+        })
+    }
+
+    /// Returns the physical memory footprint of `self` in bytes.
+    ///
+    /// This is the "Memory" column of Activity Monitor, which includes
+    /// compressed memory and excludes memory that can be reclaimed, unlike
+    /// [`resident_size`](TaskBasicInfo::resident_size).
+    #[doc(alias = "TASK_VM_INFO")]
+    #[doc(alias = "phys_footprint")]
+    pub fn physical_footprint(self) -> Result<u64, KernReturn> {
+        let info: sys::task_vm_info = unsafe { self.info(sys::TASK_VM_INFO)? };
+        Ok(info.phys_footprint)
+    }
+
+    /// Returns the threads of `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/kernel/task_threads).
+    #[doc(alias = "task_threads")]
+    pub fn threads(self) -> Result<Vec<Thread>, KernReturn> {
+        let mut list: *mut sys::thread_act_t = std::ptr::null_mut();
+        let mut count: sys::mach_msg_type_number_t = 0;
+
+        unsafe {
+            KernReturn::result(sys::task_threads(self.0, &mut list, &mut count))?;
+
+            let threads = slice::from_raw_parts(list, count as usize)
+                .iter()
+                .map(|&port| Thread::from_raw(port))
+                .collect();
+
+            sys::vm_deallocate(
+                sys::mach_task_self_,
+                list as sys::vm_address_t,
+                count as usize * mem::size_of::<sys::thread_act_t>(),
+            );
+
+            Ok(threads)
+        }
+    }
+
+    /// Returns the number of threads of `self`.
+    #[inline]
+    pub fn thread_count(self) -> Result<usize, KernReturn> {
+        Ok(self.threads()?.len())
+    }
+
+    /// Returns the recent CPU usage of `self`, where 1.0 is one core fully
+    /// used.
+    ///
+    /// This is the sum of the usage of its threads, which is the "% CPU"
+    /// column of Activity Monitor divided by 100.
+    pub fn cpu_usage(self) -> Result<f64, KernReturn> {
+        let mut usage = 0.0;
+        for thread in self.threads()? {
+            let info = thread.basic_info()?;
+            if !info.is_idle {
+                usage += info.cpu_usage;
+            }
+        }
+        Ok(usage)
+    }
+}
+
+/// Memory usage and CPU time of a [`Task`].
+///
+/// See [documentation](https://developer.apple.com/documentation/kernel/mach_task_basic_info).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[doc(alias = "mach_task_basic_info")]
+pub struct TaskBasicInfo {
+    /// The size of the virtual address space in bytes.
+    pub virtual_size: u64,
+
+    /// The physical memory in use in bytes.
+    pub resident_size: u64,
+
+    /// The peak of [`resident_size`](Self::resident_size).
+    pub resident_size_max: u64,
+
+    /// Time spent running in user mode by threads that have exited.
+    pub user_time: Duration,
+
+    /// Time spent running in the kernel by threads that have exited.
+    pub system_time: Duration,
+
+    /// The number of times the task has been suspended.
+    pub suspend_count: i32,
+}
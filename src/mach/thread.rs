@@ -0,0 +1,129 @@
+use super::{sys, time, KernReturn};
+use std::{fmt, time::Duration};
+
+/// A send right to a Mach thread, which is deallocated on [`Drop`].
+///
+/// See [documentation](https://developer.apple.com/documentation/kernel/thread_act_t).
+#[doc(alias = "thread_act_t")]
+pub struct Thread(sys::thread_act_t);
+
+impl Drop for Thread {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe { sys::mach_port_deallocate(sys::mach_task_self_, self.0) };
+    }
+}
+
+impl fmt::Debug for Thread {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Thread").field(&self.0).finish()
+    }
+}
+
+impl Thread {
+    /// Returns the calling thread.
+    #[inline]
+    #[doc(alias = "mach_thread_self")]
+    pub fn current() -> Self {
+        Self(unsafe { sys::mach_thread_self() })
+    }
+
+    /// Takes ownership of a send right to a thread.
+    ///
+    /// # Safety
+    ///
+    /// `port` must be a send right owned by the caller.
+    #[inline]
+    pub const unsafe fn from_raw(port: sys::thread_act_t) -> Self {
+        Self(port)
+    }
+
+    /// Returns the port name of `self`.
+    #[inline]
+    pub const fn as_raw(&self) -> sys::thread_act_t {
+        self.0
+    }
+
+    /// Returns scheduling and CPU usage information about `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/kernel/thread_info).
+    #[doc(alias = "thread_info")]
+    #[doc(alias = "THREAD_BASIC_INFO")]
+    pub fn basic_info(&self) -> Result<ThreadBasicInfo, KernReturn> {
+        let mut info = sys::thread_basic_info::default();
+        let mut count = sys::count_of::<sys::thread_basic_info>();
+        KernReturn::result(unsafe {
+            sys::thread_info(
+                self.0,
+                sys::THREAD_BASIC_INFO,
+                (&mut info as *mut sys::thread_basic_info).cast(),
+                &mut count,
+            )
+        })?;
+
+        Ok(ThreadBasicInfo {
+            user_time: time::time_value(info.user_time),
+            system_time: time::time_value(info.system_time),
+            cpu_usage: info.cpu_usage as f64 / sys::TH_USAGE_SCALE as f64,
+            run_state: ThreadRunState(info.run_state),
+            is_idle: info.flags & sys::TH_FLAGS_IDLE != 0,
+            suspend_count: info.suspend_count,
+            sleep_time: Duration::from_secs(info.sleep_time.max(0) as u64),
+        })
+    }
+}
+
+/// Scheduling and CPU usage information about a [`Thread`].
+///
+/// See [documentation](https://developer.apple.com/documentation/kernel/thread_basic_info).
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[doc(alias = "thread_basic_info")]
+pub struct ThreadBasicInfo {
+    /// Time spent running in user mode.
+    pub user_time: Duration,
+
+    /// Time spent running in the kernel.
+    pub system_time: Duration,
+
+    /// Recent CPU usage, where 1.0 is one core fully used.
+    pub cpu_usage: f64,
+
+    /// Whether the thread is running, waiting, or stopped.
+    pub run_state: ThreadRunState,
+
+    /// Whether this is an idle thread of the kernel.
+    pub is_idle: bool,
+
+    /// The number of times the thread has been suspended.
+    pub suspend_count: i32,
+
+    /// How long the thread has been sleeping.
+    pub sleep_time: Duration,
+}
+
+/// The scheduling state of a [`Thread`].
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ThreadRunState(pub i32);
+
+impl ThreadRunState {
+    /// The thread is running or runnable.
+    #[doc(alias = "TH_STATE_RUNNING")]
+    pub const RUNNING: Self = Self(1);
+
+    /// The thread is stopped.
+    #[doc(alias = "TH_STATE_STOPPED")]
+    pub const STOPPED: Self = Self(2);
+
+    /// The thread is waiting normally.
+    #[doc(alias = "TH_STATE_WAITING")]
+    pub const WAITING: Self = Self(3);
+
+    /// The thread is in an uninterruptible wait.
+    #[doc(alias = "TH_STATE_UNINTERRUPTIBLE")]
+    pub const UNINTERRUPTIBLE: Self = Self(4);
+
+    /// The thread is halting.
+    #[doc(alias = "TH_STATE_HALTED")]
+    pub const HALTED: Self = Self(5);
+}
@@ -0,0 +1,19 @@
+use super::sys;
+use std::time::Duration;
+
+/// Converts a `time_value_t` to a [`Duration`].
+#[inline]
+pub(super) fn time_value(time: sys::time_value_t) -> Duration {
+    Duration::new(time.seconds as u64, time.microseconds as u32 * 1_000)
+}
+
+/// Converts a duration in Mach absolute time units to a [`Duration`].
+///
+/// Units are nanoseconds on Intel, but not on Apple silicon.
+pub(super) fn absolute_time(units: u64) -> Duration {
+    let mut timebase = sys::mach_timebase_info_data_t { numer: 1, denom: 1 };
+    unsafe { sys::mach_timebase_info(&mut timebase) };
+
+    let nanos = units as u128 * timebase.numer as u128 / timebase.denom.max(1) as u128;
+    Duration::from_nanos(nanos as u64)
+}
@@ -0,0 +1,103 @@
+use crate::core_graphics::CGPoint;
+use crate::objc::{ClassType, NSInteger, NSObject, NSUInteger};
+use std::{ffi::c_void, ptr};
+
+objc_subclass! {
+    /// The AppKit event class, which is the only public way to create the
+    /// "system defined" events sent by media keys.
+    class NSEvent: NSObject<'static>;
+}
+
+#[link(name = "AppKit", kind = "framework")]
+extern "C" {}
+
+extern "C" {
+    fn CGEventPost(tap: u32, event: *const c_void);
+}
+
+/// `NSEventTypeSystemDefined`.
+const SYSTEM_DEFINED: NSUInteger = 14;
+
+/// The `NSEvent` subtype of auxiliary key events, such as media keys.
+const AUX_CONTROL_BUTTONS: i16 = 8;
+
+/// `kCGHIDEventTap`, which posts events as if they came from the keyboard.
+const HID_EVENT_TAP: u32 = 0;
+
+/// A key on the keyboard for controlling media playback and volume.
+///
+/// By default, media keys control the app that most recently played audio,
+/// such as Music.
+#[repr(i32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MediaKey {
+    /// Toggles between playing and pausing.
+    #[doc(alias = "NX_KEYTYPE_PLAY")]
+    PlayPause = 16,
+    /// Skips to the next track.
+    #[doc(alias = "NX_KEYTYPE_NEXT")]
+    Next = 17,
+    /// Returns to the previous track, or the start of the current one.
+    #[doc(alias = "NX_KEYTYPE_PREVIOUS")]
+    Previous = 18,
+    /// Fast-forwards through the current track.
+    #[doc(alias = "NX_KEYTYPE_FAST")]
+    FastForward = 19,
+    /// Rewinds through the current track.
+    #[doc(alias = "NX_KEYTYPE_REWIND")]
+    Rewind = 20,
+    /// Turns the system volume up.
+    #[doc(alias = "NX_KEYTYPE_SOUND_UP")]
+    VolumeUp = 0,
+    /// Turns the system volume down.
+    #[doc(alias = "NX_KEYTYPE_SOUND_DOWN")]
+    VolumeDown = 1,
+    /// Toggles muting the system volume.
+    #[doc(alias = "NX_KEYTYPE_MUTE")]
+    Mute = 7,
+}
+
+impl MediaKey {
+    /// Simulates pressing and releasing this key.
+    ///
+    /// The process must be allowed to control the computer in the
+    /// Accessibility section of System Settings, or the events are silently
+    /// dropped.
+    #[doc(alias = "CGEventPost")]
+    pub fn post(self) {
+        self.post_state(true);
+        self.post_state(false);
+    }
+
+    fn post_state(self, is_down: bool) {
+        // The key code is in the high 16 bits, and the key state is in the
+        // next 8 bits as `NX_KEYDOWN` (0xa) or `NX_KEYUP` (0xb).
+        let state: NSInteger = if is_down { 0xa } else { 0xb };
+        let data1 = ((self as NSInteger) << 16) | (state << 8);
+        let flags = (state << 8) as NSUInteger;
+
+        unsafe {
+            let event: *const NSEvent = _msg_send_any![
+                NSEvent::class(),
+                otherEventWithType: SYSTEM_DEFINED
+                location: CGPoint::default()
+                modifierFlags: flags
+                timestamp: 0.0f64
+                windowNumber: 0 as NSInteger
+                context: ptr::null::<NSObject>()
+                subtype: AUX_CONTROL_BUTTONS
+                data1: data1
+                data2: -1 as NSInteger
+            ];
+            let event = match event.as_ref() {
+                Some(event) => event,
+                None => return,
+            };
+
+            let cg_event: *const c_void = _msg_send_any![event, CGEvent];
+            if !cg_event.is_null() {
+                CGEventPost(HID_EVENT_TAP, cg_event);
+            }
+        }
+    }
+}
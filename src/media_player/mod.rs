@@ -0,0 +1,37 @@
+//! [Media Player](https://developer.apple.com/documentation/mediaplayer)
+//! framework.
+//!
+//! # Feature Flag
+//!
+//! This module corresponds to the **`media_player`**
+//! [feature flag](../index.html#feature-flags).
+//!
+//! It also transitively enables [`foundation`](crate::foundation).
+//!
+//! # Scope
+//!
+//! An app that plays media can publish what it is playing with
+//! [`MPNowPlayingInfoCenter`] and respond to play/pause, track skipping, and
+//! seeking from the keyboard, headphones, and Control Center with
+//! [`MPRemoteCommandCenter`]. These APIs are available on macOS 10.12.2 and
+//! later.
+//!
+//! On macOS, controlling other apps, such as Music, is done with
+//! [`MediaKey::post`], which simulates pressing a media key on the keyboard.
+//! The system "Now Playing" state of other apps is not public API, so it
+//! cannot be queried.
+
+#![cfg(feature = "media_player")]
+
+#[cfg(target_os = "macos")]
+mod media_key;
+mod mp_now_playing_info_center;
+mod mp_remote_command;
+
+#[cfg(target_os = "macos")]
+pub use media_key::*;
+pub use mp_now_playing_info_center::*;
+pub use mp_remote_command::*;
+
+#[link(name = "MediaPlayer", kind = "framework")]
+extern "C" {}
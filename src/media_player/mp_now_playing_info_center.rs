@@ -0,0 +1,204 @@
+use crate::core::Arc;
+use crate::foundation::{NSDictionary, NSString};
+use crate::objc::{ClassType, NSObject, NSUInteger};
+use std::{fmt, ptr};
+
+// `ns_string_wrapper!` is only in scope within `foundation`.
+objc_object_wrapper! {
+    /// A key in the dictionary of [`MPNowPlayingInfoCenter::now_playing_info`].
+    ///
+    /// This covers both the `MPMediaItemProperty` keys that describe the
+    /// media and the `MPNowPlayingInfoProperty` keys that describe playback.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/mediaplayer/mpnowplayinginfocenter/nowplayinginfo).
+    #[derive(Ord, PartialOrd, Eq, PartialEq)]
+    pub wrapper MPNowPlayingInfoKey: NSString<'static>;
+}
+
+impl fmt::Debug for MPNowPlayingInfoKey {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl fmt::Display for MPNowPlayingInfoKey {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+macro_rules! key {
+    (
+        $(#[$docs:meta])+
+        $fn:ident $value:literal
+    ) => {
+        $(#[$docs])+
+        #[inline]
+        #[doc(alias = $value)]
+        pub fn $fn() -> &'static MPNowPlayingInfoKey {
+            extern "C" {
+                #[link_name = $value]
+                static VALUE: &'static MPNowPlayingInfoKey;
+            }
+            unsafe { VALUE }
+        }
+    };
+}
+
+impl MPNowPlayingInfoKey {
+    key! {
+        /// The title of the media, as an
+        /// [`NSString`](crate::foundation::NSString).
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/mediaplayer/mpmediaitempropertytitle).
+        title "MPMediaItemPropertyTitle"
+    }
+
+    key! {
+        /// The performing artist, as an
+        /// [`NSString`](crate::foundation::NSString).
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/mediaplayer/mpmediaitempropertyartist).
+        artist "MPMediaItemPropertyArtist"
+    }
+
+    key! {
+        /// The title of the album, as an
+        /// [`NSString`](crate::foundation::NSString).
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/mediaplayer/mpmediaitempropertyalbumtitle).
+        album_title "MPMediaItemPropertyAlbumTitle"
+    }
+
+    key! {
+        /// The duration of the media in seconds, as an
+        /// [`NSNumber`](crate::foundation::NSNumber).
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/mediaplayer/mpmediaitempropertyplaybackduration).
+        playback_duration "MPMediaItemPropertyPlaybackDuration"
+    }
+
+    key! {
+        /// The position in seconds when the info was set, as an
+        /// [`NSNumber`](crate::foundation::NSNumber).
+        ///
+        /// The system advances the displayed position from this value
+        /// according to [`playback_rate`](Self::playback_rate), so it only
+        /// needs to be updated when seeking or changing rate.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/mediaplayer/mpnowplayinginfopropertyelapsedplaybacktime).
+        elapsed_playback_time "MPNowPlayingInfoPropertyElapsedPlaybackTime"
+    }
+
+    key! {
+        /// The playback speed, as an
+        /// [`NSNumber`](crate::foundation::NSNumber) such as 1.0 for normal
+        /// speed or 0.0 for paused.
+        ///
+        /// See [documentation](https://developer.apple.com/documentation/mediaplayer/mpnowplayinginfopropertyplaybackrate).
+        playback_rate "MPNowPlayingInfoPropertyPlaybackRate"
+    }
+}
+
+/// The playback state that the app reports to the system.
+///
+/// See [documentation](https://developer.apple.com/documentation/mediaplayer/mpnowplayingplaybackstate).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct MPNowPlayingPlaybackState(pub NSUInteger);
+
+impl MPNowPlayingPlaybackState {
+    /// The state is not known.
+    #[doc(alias = "MPNowPlayingPlaybackStateUnknown")]
+    pub const UNKNOWN: Self = Self(0);
+
+    /// Media is playing.
+    #[doc(alias = "MPNowPlayingPlaybackStatePlaying")]
+    pub const PLAYING: Self = Self(1);
+
+    /// Media is paused and can be resumed.
+    #[doc(alias = "MPNowPlayingPlaybackStatePaused")]
+    pub const PAUSED: Self = Self(2);
+
+    /// Playback has stopped.
+    #[doc(alias = "MPNowPlayingPlaybackStateStopped")]
+    pub const STOPPED: Self = Self(3);
+
+    /// Playback was interrupted, such as by a call.
+    #[doc(alias = "MPNowPlayingPlaybackStateInterrupted")]
+    pub const INTERRUPTED: Self = Self(4);
+}
+
+objc_subclass! {
+    /// Publishes what the app is playing to the system, which shows it in
+    /// Control Center and sends it [remote commands](super::MPRemoteCommandCenter).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/mediaplayer/mpnowplayinginfocenter).
+    pub class MPNowPlayingInfoCenter: NSObject<'static>;
+}
+
+impl MPNowPlayingInfoCenter {
+    /// Returns the now playing info center of the app.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/mediaplayer/mpnowplayinginfocenter/default()).
+    #[inline]
+    #[doc(alias = "defaultCenter")]
+    #[allow(clippy::should_implement_trait)]
+    pub fn default() -> &'static Self {
+        unsafe { _msg_send_any![Self::class(), defaultCenter] }
+    }
+
+    /// Returns the info about the playing media, if any has been set.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/mediaplayer/mpnowplayinginfocenter/nowplayinginfo).
+    #[inline]
+    #[doc(alias = "nowPlayingInfo")]
+    pub fn now_playing_info(
+        &self,
+    ) -> Option<Arc<NSDictionary<MPNowPlayingInfoKey, NSObject<'static>>>> {
+        unsafe {
+            _msg_send_any![self, nowPlayingInfo => *const NSDictionary<MPNowPlayingInfoKey, NSObject<'static>>]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Sets the info about the playing media, or clears it with [`None`].
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/mediaplayer/mpnowplayinginfocenter/nowplayinginfo).
+    #[inline]
+    #[doc(alias = "setNowPlayingInfo:")]
+    pub fn set_now_playing_info(
+        &self,
+        info: Option<&NSDictionary<MPNowPlayingInfoKey, NSObject<'static>>>,
+    ) {
+        let info = match info {
+            Some(info) => info,
+            None => ptr::null(),
+        };
+        unsafe { _msg_send_any![self, setNowPlayingInfo: info => ()] }
+    }
+
+    /// Returns the playback state that the app reports.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/mediaplayer/mpnowplayinginfocenter/playbackstate).
+    #[inline]
+    #[doc(alias = "playbackState")]
+    pub fn playback_state(&self) -> MPNowPlayingPlaybackState {
+        unsafe { _msg_send_any![self, playbackState] }
+    }
+
+    /// Sets the playback state that the app reports.
+    ///
+    /// The system uses this to decide which app receives media keys, so it
+    /// should be updated whenever playback starts or stops.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/mediaplayer/mpnowplayinginfocenter/playbackstate).
+    #[inline]
+    #[doc(alias = "setPlaybackState:")]
+    pub fn set_playback_state(&self, state: MPNowPlayingPlaybackState) {
+        unsafe { _msg_send_any![self, setPlaybackState: state => ()] }
+    }
+}
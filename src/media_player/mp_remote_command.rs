@@ -0,0 +1,273 @@
+use crate::core::Arc;
+use crate::foundation::NSTimeInterval;
+use crate::objc::{Block, ClassType, NSInteger, NSObject, RcBlock, BOOL};
+use std::{cell::RefCell, fmt};
+
+objc_subclass! {
+    /// The commands that the system sends to the app that is playing media,
+    /// such as from media keys, headphones, and Control Center.
+    ///
+    /// Handlers are called on the main thread, so its run loop must be
+    /// running.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/mediaplayer/mpremotecommandcenter).
+    pub class MPRemoteCommandCenter: NSObject<'static>;
+}
+
+impl MPRemoteCommandCenter {
+    /// Returns the command center of the app.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/mediaplayer/mpremotecommandcenter/shared()).
+    #[inline]
+    #[doc(alias = "sharedCommandCenter")]
+    pub fn shared() -> &'static Self {
+        unsafe { _msg_send_any![Self::class(), sharedCommandCenter] }
+    }
+
+    /// Returns the command to start playback.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/mediaplayer/mpremotecommandcenter/playcommand).
+    #[inline]
+    #[doc(alias = "playCommand")]
+    pub fn play_command(&self) -> &MPRemoteCommand {
+        unsafe { _msg_send_any![self, playCommand] }
+    }
+
+    /// Returns the command to pause playback.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/mediaplayer/mpremotecommandcenter/pausecommand).
+    #[inline]
+    #[doc(alias = "pauseCommand")]
+    pub fn pause_command(&self) -> &MPRemoteCommand {
+        unsafe { _msg_send_any![self, pauseCommand] }
+    }
+
+    /// Returns the command to toggle between playing and pausing, which is
+    /// sent by the play/pause media key.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/mediaplayer/mpremotecommandcenter/toggleplaypausecommand).
+    #[inline]
+    #[doc(alias = "togglePlayPauseCommand")]
+    pub fn toggle_play_pause_command(&self) -> &MPRemoteCommand {
+        unsafe { _msg_send_any![self, togglePlayPauseCommand] }
+    }
+
+    /// Returns the command to stop playback.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/mediaplayer/mpremotecommandcenter/stopcommand).
+    #[inline]
+    #[doc(alias = "stopCommand")]
+    pub fn stop_command(&self) -> &MPRemoteCommand {
+        unsafe { _msg_send_any![self, stopCommand] }
+    }
+
+    /// Returns the command to skip to the next track.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/mediaplayer/mpremotecommandcenter/nexttrackcommand).
+    #[inline]
+    #[doc(alias = "nextTrackCommand")]
+    pub fn next_track_command(&self) -> &MPRemoteCommand {
+        unsafe { _msg_send_any![self, nextTrackCommand] }
+    }
+
+    /// Returns the command to return to the previous track.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/mediaplayer/mpremotecommandcenter/previoustrackcommand).
+    #[inline]
+    #[doc(alias = "previousTrackCommand")]
+    pub fn previous_track_command(&self) -> &MPRemoteCommand {
+        unsafe { _msg_send_any![self, previousTrackCommand] }
+    }
+
+    /// Returns the command to seek to a position, such as by dragging the
+    /// progress bar in Control Center.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/mediaplayer/mpremotecommandcenter/changeplaybackpositioncommand).
+    #[inline]
+    #[doc(alias = "changePlaybackPositionCommand")]
+    pub fn change_playback_position_command(&self) -> &MPChangePlaybackPositionCommand {
+        unsafe { _msg_send_any![self, changePlaybackPositionCommand] }
+    }
+}
+
+objc_subclass! {
+    /// A command sent by [`MPRemoteCommandCenter`].
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/mediaplayer/mpremotecommand).
+    pub class MPRemoteCommand: NSObject<'static>;
+}
+
+impl MPRemoteCommand {
+    /// Returns `true` if the system shows controls for this command.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/mediaplayer/mpremotecommand/isenabled).
+    #[inline]
+    #[doc(alias = "isEnabled")]
+    pub fn is_enabled(&self) -> bool {
+        unsafe { _msg_send_any![self, isEnabled => BOOL] }.into()
+    }
+
+    /// Sets whether the system shows controls for this command.
+    ///
+    /// Commands are enabled by default once they have a handler.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/mediaplayer/mpremotecommand/isenabled).
+    #[inline]
+    #[doc(alias = "setEnabled:")]
+    pub fn set_enabled(&self, value: bool) {
+        unsafe { _msg_send_any![self, setEnabled: BOOL::from(value) => ()] }
+    }
+
+    /// Calls `handler` each time this command is sent, until the returned
+    /// target is dropped.
+    ///
+    /// It is safe to panic within `handler`. Panics will abort the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/mediaplayer/mpremotecommand/addtarget(handler:)).
+    #[doc(alias = "addTargetWithHandler:")]
+    pub fn add_handler<F>(&self, handler: F) -> MPRemoteCommandTarget
+    where
+        F: FnMut(&MPRemoteCommandEvent) -> MPRemoteCommandHandlerStatus + Send + 'static,
+    {
+        let handler = RefCell::new(handler);
+        let block = RcBlock::new(move |event: *const MPRemoteCommandEvent| {
+            (*handler.borrow_mut())(unsafe { &*event })
+        });
+        let block: &Block<_, MPRemoteCommandHandlerStatus> = &block;
+
+        let token = unsafe {
+            Arc::retain_raw(_msg_send_any![
+                self,
+                addTargetWithHandler: block
+                => *const NSObject<'static>
+            ])
+        };
+
+        MPRemoteCommandTarget {
+            command: Arc::retain(self),
+            token,
+        }
+    }
+}
+
+objc_subclass! {
+    /// The command to seek to a position in the playing media.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/mediaplayer/mpchangeplaybackpositioncommand).
+    pub class MPChangePlaybackPositionCommand: MPRemoteCommand;
+}
+
+impl MPChangePlaybackPositionCommand {
+    /// Calls `handler` with the requested position, in seconds from the
+    /// start of the media, until the returned target is dropped.
+    ///
+    /// It is safe to panic within `handler`. Panics will abort the process.
+    pub fn add_position_handler<F>(&self, mut handler: F) -> MPRemoteCommandTarget
+    where
+        F: FnMut(NSTimeInterval) -> MPRemoteCommandHandlerStatus + Send + 'static,
+    {
+        self.add_handler(move |event| {
+            let event: &MPChangePlaybackPositionCommandEvent =
+                unsafe { &*(event as *const MPRemoteCommandEvent).cast() };
+            handler(event.position_time())
+        })
+    }
+}
+
+/// A handler registered by [`MPRemoteCommand::add_handler`], which is
+/// removed when dropped.
+#[must_use = "The handler is immediately removed if unused"]
+pub struct MPRemoteCommandTarget {
+    command: Arc<MPRemoteCommand>,
+    token: Arc<NSObject<'static>>,
+}
+
+impl fmt::Debug for MPRemoteCommandTarget {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("MPRemoteCommandTarget").finish()
+    }
+}
+
+impl Drop for MPRemoteCommandTarget {
+    #[inline]
+    #[doc(alias = "removeTarget:")]
+    fn drop(&mut self) {
+        let token: &NSObject = &self.token;
+        unsafe { _msg_send_any![&*self.command, removeTarget: token => ()] }
+    }
+}
+
+objc_subclass! {
+    /// A command being handled by a [`MPRemoteCommand`] handler.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/mediaplayer/mpremotecommandevent).
+    pub class MPRemoteCommandEvent: NSObject<'static>;
+}
+
+impl MPRemoteCommandEvent {
+    /// Returns the command that was sent.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/mediaplayer/mpremotecommandevent/command).
+    #[inline]
+    pub fn command(&self) -> &MPRemoteCommand {
+        unsafe { _msg_send_any![self, command] }
+    }
+
+    /// Returns the time at which the command was sent, in seconds since
+    /// system startup.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/mediaplayer/mpremotecommandevent/timestamp).
+    #[inline]
+    pub fn timestamp(&self) -> NSTimeInterval {
+        unsafe { _msg_send_any![self, timestamp] }
+    }
+}
+
+objc_subclass! {
+    /// A request to seek to a position, sent by
+    /// [`MPChangePlaybackPositionCommand`].
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/mediaplayer/mpchangeplaybackpositioncommandevent).
+    pub class MPChangePlaybackPositionCommandEvent: MPRemoteCommandEvent;
+}
+
+impl MPChangePlaybackPositionCommandEvent {
+    /// Returns the requested position, in seconds from the start of the
+    /// media.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/mediaplayer/mpchangeplaybackpositioncommandevent/positiontime).
+    #[inline]
+    #[doc(alias = "positionTime")]
+    pub fn position_time(&self) -> NSTimeInterval {
+        unsafe { _msg_send_any![self, positionTime] }
+    }
+}
+
+/// The result of handling a [`MPRemoteCommandEvent`].
+///
+/// See [documentation](https://developer.apple.com/documentation/mediaplayer/mpremotecommandhandlerstatus).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct MPRemoteCommandHandlerStatus(pub NSInteger);
+
+impl MPRemoteCommandHandlerStatus {
+    /// The command was handled.
+    #[doc(alias = "MPRemoteCommandHandlerStatusSuccess")]
+    pub const SUCCESS: Self = Self(0);
+
+    /// The requested content, such as a track, does not exist.
+    #[doc(alias = "MPRemoteCommandHandlerStatusNoSuchContent")]
+    pub const NO_SUCH_CONTENT: Self = Self(100);
+
+    /// Nothing is playing that the command could act on.
+    #[doc(alias = "MPRemoteCommandHandlerStatusNoActionableNowPlayingItem")]
+    pub const NO_ACTIONABLE_NOW_PLAYING_ITEM: Self = Self(110);
+
+    /// The device needed for the command is not available.
+    #[doc(alias = "MPRemoteCommandHandlerStatusDeviceNotFound")]
+    pub const DEVICE_NOT_FOUND: Self = Self(120);
+
+    /// The command could not be handled.
+    #[doc(alias = "MPRemoteCommandHandlerStatusCommandFailed")]
+    pub const COMMAND_FAILED: Self = Self(200);
+}
@@ -1,10 +1,48 @@
-use std::ffi::c_void;
+use super::ObjectType;
+use crate::core::Arc;
+use std::{
+    cell::{Cell, UnsafeCell},
+    ffi::c_void,
+    fmt,
+    marker::PhantomData,
+    process,
+    ptr::{self, NonNull},
+};
 
 /// Calls a function in the context of a new autorelease pool, like
 /// `@autoreleasepool`.
 ///
+/// The pool is passed to `f`, and references obtained through it with
+/// [`AutoreleasePool::autorelease`] cannot be returned from `f`:
+///
+/// ```compile_fail
+/// use fruity::{foundation::NSString, objc::autoreleasepool};
+///
+/// let string: &NSString = autoreleasepool(|pool| {
+///     pool.autorelease(NSString::from_str("hello"))
+/// });
+/// ```
+///
+/// Long-running loops that call into Objective-C should wrap each iteration
+/// in a pool, or else autoreleased objects accumulate until the thread's
+/// outermost pool is drained.
+///
 /// See [documentation](https://developer.apple.com/library/archive/documentation/Cocoa/Conceptual/MemoryMgmt/Articles/mmAutoreleasePools.html).
 ///
+/// # Examples
+///
+/// ```no_run
+/// use fruity::{foundation::NSString, objc::autoreleasepool};
+///
+/// for i in 0..1000 {
+///     autoreleasepool(|pool| {
+///         let string = pool.autorelease(NSString::from_str(&i.to_string()));
+///         // ...
+///         # let _ = string;
+///     });
+/// }
+/// ```
+///
 /// # Panic Handling
 ///
 /// If a panic occurs within the function, the autorelease pool is drained as
@@ -14,10 +52,10 @@ use std::ffi::c_void;
 #[inline]
 pub fn autoreleasepool<F, T>(f: F) -> T
 where
-    F: FnOnce() -> T,
+    F: for<'pool> FnOnce(&'pool AutoreleasePool) -> T,
 {
-    let _pool = AutoreleasePool(unsafe { objc_autoreleasePoolPush() });
-    f()
+    let pool = AutoreleasePool::new();
+    f(&pool)
 }
 
 // For macOS 10.6 and lower, Clang emits `[[NSAutoreleasePool alloc] init]` and
@@ -26,13 +64,270 @@ where
 extern "C" {
     fn objc_autoreleasePoolPush() -> *mut c_void;
     fn objc_autoreleasePoolPop(pool: *mut c_void);
+    fn objc_autorelease(obj: *mut c_void) -> *mut c_void;
+}
+
+thread_local! {
+    /// The innermost pool created by [`AutoreleasePool::new`] on this thread.
+    static CURRENT_POOL: Cell<*mut c_void> = const { Cell::new(ptr::null_mut()) };
+}
+
+/// An autorelease pool that is drained when dropped.
+///
+/// This is the guard behind [`autoreleasepool`], which should be preferred
+/// when a closure is convenient. Pools are thread-local and must be dropped
+/// in the reverse order of their creation.
+///
+/// See [documentation](https://developer.apple.com/documentation/foundation/nsautoreleasepool).
+#[doc(alias = "NSAutoreleasePool")]
+pub struct AutoreleasePool {
+    token: *mut c_void,
+
+    /// The pool that was innermost before this one.
+    parent: *mut c_void,
+
+    // Autorelease pools are thread-local.
+    _not_send_sync: PhantomData<*mut ()>,
 }
 
-struct AutoreleasePool(*mut c_void);
+impl Default for AutoreleasePool {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for AutoreleasePool {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("AutoreleasePool").field(&self.token).finish()
+    }
+}
 
 impl Drop for AutoreleasePool {
     #[inline]
     fn drop(&mut self) {
-        unsafe { objc_autoreleasePoolPop(self.0) };
+        // Popping an outer pool also pops every pool within it, which would
+        // release objects that inner pools still lend out.
+        if CURRENT_POOL.with(Cell::get) != self.token {
+            eprintln!("fruity: autorelease pool dropped out of order");
+            process::abort();
+        }
+        CURRENT_POOL.with(|current| current.set(self.parent));
+
+        unsafe { objc_autoreleasePoolPop(self.token) };
+    }
+}
+
+impl AutoreleasePool {
+    /// Pushes a new pool onto the current thread's stack of pools.
+    ///
+    /// # Aborts
+    ///
+    /// The process is aborted if the returned pool is dropped while a pool
+    /// created after it is still alive.
+    #[inline]
+    #[doc(alias = "objc_autoreleasePoolPush")]
+    pub fn new() -> Self {
+        let token = unsafe { objc_autoreleasePoolPush() };
+        let parent = CURRENT_POOL.with(|current| current.replace(token));
+        Self {
+            token,
+            parent,
+            _not_send_sync: PhantomData,
+        }
+    }
+
+    /// Returns `true` if `self` is the innermost pool on the current thread.
+    #[inline]
+    pub fn is_innermost(&self) -> bool {
+        CURRENT_POOL.with(Cell::get) == self.token
+    }
+
+    /// Transfers ownership of `obj` to `self`, returning a reference that is
+    /// valid until `self` is drained.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is not the [innermost](Self::is_innermost) pool, since
+    /// the object would be released when the innermost pool is drained.
+    #[inline]
+    #[doc(alias = "objc_autorelease")]
+    pub fn autorelease<'pool, 'data, T: ObjectType<'data>>(&'pool self, obj: Arc<T>) -> &'pool T {
+        assert!(
+            self.is_innermost(),
+            "objects can only be autoreleased into the innermost pool"
+        );
+
+        let obj = Arc::into_raw(obj) as *mut T;
+
+        // SAFETY: The object is released once `self` is drained, which
+        // cannot happen while it is borrowed.
+        unsafe {
+            objc_autorelease(obj.cast());
+            &*obj
+        }
+    }
+}
+
+/// A reusable arena that releases the objects it tracks in bulk at the end of
+/// each [`scope`](Self::scope).
+///
+/// This is meant for tight loops that create many temporary objects. Rather
+/// than releasing each object as soon as its [`Arc`] is dropped, objects can
+/// be handed to the arena with [`track`](Self::track) and then released all at
+/// once. Each scope also runs within its own autorelease pool, so objects
+/// autoreleased by Objective-C code are drained at the same time.
+///
+/// The arena keeps its allocation between scopes, so creating it once outside
+/// of a loop avoids reallocating on every iteration.
+///
+/// # Examples
+///
+/// ```no_run
+/// use fruity::{foundation::NSString, objc::AutoreleaseArena};
+///
+/// let mut arena = AutoreleaseArena::with_capacity(16);
+///
+/// for i in 0..1000 {
+///     arena.scope(|arena| {
+///         let a = arena.track(NSString::from_str("hello"));
+///         let b = arena.track(NSString::from_str(&i.to_string()));
+///         // ...
+///         # let _ = (a, b);
+///     });
+/// }
+/// ```
+pub struct AutoreleaseArena {
+    tracked: UnsafeCell<Vec<Tracked>>,
+
+    // Autorelease pools are thread-local.
+    _not_send_sync: PhantomData<*mut ()>,
+}
+
+/// A type-erased owned object.
+struct Tracked {
+    obj: NonNull<c_void>,
+    release: unsafe fn(NonNull<c_void>),
+}
+
+unsafe fn release_erased<T: crate::core::ObjectType>(obj: NonNull<c_void>) {
+    T::release(obj.cast());
+}
+
+impl Default for AutoreleaseArena {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for AutoreleaseArena {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AutoreleaseArena")
+            .field("tracked_len", &self.tracked_len())
+            .finish()
+    }
+}
+
+impl AutoreleaseArena {
+    /// Creates an arena without allocating.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            tracked: UnsafeCell::new(Vec::new()),
+            _not_send_sync: PhantomData,
+        }
+    }
+
+    /// Creates an arena with space to track `capacity` objects per scope
+    /// without reallocating.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            tracked: UnsafeCell::new(Vec::with_capacity(capacity)),
+            _not_send_sync: PhantomData,
+        }
+    }
+
+    /// Calls `f` in the context of a new autorelease pool, then releases all
+    /// objects tracked during the call before draining the pool.
+    ///
+    /// # Panic Handling
+    ///
+    /// If a panic occurs within `f`, tracked objects are released and the
+    /// pool is drained as the stack unwinds.
+    #[inline]
+    pub fn scope<F, T>(&mut self, f: F) -> T
+    where
+        F: FnOnce(&AutoreleaseArena) -> T,
+    {
+        struct Scope<'a> {
+            arena: &'a mut AutoreleaseArena,
+
+            // Dropped after tracked objects are released, so that anything
+            // they autorelease while being deallocated is also drained.
+            _pool: AutoreleasePool,
+        }
+
+        impl Drop for Scope<'_> {
+            #[inline]
+            fn drop(&mut self) {
+                self.arena.release_all();
+            }
+        }
+
+        let scope = Scope {
+            _pool: AutoreleasePool::new(),
+            arena: self,
+        };
+        f(scope.arena)
+    }
+
+    /// Takes ownership of `obj` until the end of the current scope, returning
+    /// a reference that is valid for that long.
+    #[inline]
+    pub fn track<T: crate::core::ObjectType>(&self, obj: Arc<T>) -> &T {
+        let obj = Arc::into_raw(obj);
+
+        // SAFETY: The arena is neither `Send` nor `Sync` and no other
+        // references to `tracked` exist while this method runs.
+        let tracked = unsafe { &mut *self.tracked.get() };
+        tracked.push(Tracked {
+            obj: unsafe { NonNull::new_unchecked(obj as *mut c_void) },
+            release: release_erased::<T>,
+        });
+
+        // SAFETY: The object is owned by the arena until `release_all`, which
+        // can only be called once the borrow of `self` has ended.
+        unsafe { &*obj }
+    }
+
+    /// Returns the number of objects currently tracked.
+    #[inline]
+    pub fn tracked_len(&self) -> usize {
+        unsafe { (*self.tracked.get()).len() }
+    }
+
+    /// Returns the number of objects the arena can track without
+    /// reallocating.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        unsafe { (*self.tracked.get()).capacity() }
+    }
+
+    #[inline]
+    fn release_all(&mut self) {
+        for tracked in self.tracked.get_mut().drain(..) {
+            unsafe { (tracked.release)(tracked.obj) };
+        }
+    }
+}
+
+impl Drop for AutoreleaseArena {
+    #[inline]
+    fn drop(&mut self) {
+        // Objects are only tracked within `scope`, which releases them on
+        // exit. This is kept as a fallback.
+        self.release_all();
     }
 }
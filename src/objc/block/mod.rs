@@ -0,0 +1,242 @@
+use std::{
+    cell::UnsafeCell,
+    fmt,
+    marker::PhantomData,
+    mem,
+    os::raw::{c_int, c_ulong, c_void},
+    panic::{self, AssertUnwindSafe},
+    process,
+};
+
+mod rc;
+mod stack;
+
+pub use rc::*;
+pub use stack::*;
+
+// Apple's block ABI is documented at
+// https://clang.llvm.org/docs/Block-ABI-Apple.html.
+
+/// The block has copy and dispose helpers in its descriptor.
+const BLOCK_HAS_COPY_DISPOSE: c_int = 1 << 25;
+
+#[cfg_attr(target_vendor = "apple", link(name = "System", kind = "dylib"))]
+extern "C" {
+    static _NSConcreteStackBlock: [*const c_void; 32];
+
+    fn _Block_copy(block: *const c_void) -> *mut c_void;
+    fn _Block_release(block: *const c_void);
+}
+
+/// The fields common to all blocks, followed by captured state.
+#[repr(C)]
+struct BlockHeader {
+    isa: *const c_void,
+    flags: c_int,
+    reserved: c_int,
+    invoke: unsafe extern "C" fn(),
+    descriptor: *const BlockDescriptor,
+}
+
+/// A block descriptor with copy and dispose helpers.
+#[repr(C)]
+struct BlockDescriptor {
+    reserved: c_ulong,
+    size: c_ulong,
+    copy: unsafe extern "C" fn(dst: *mut c_void, src: *const c_void),
+    dispose: unsafe extern "C" fn(block: *mut c_void),
+}
+
+/// The full layout of a block created from a Rust closure.
+#[repr(C)]
+struct BlockLayout<F> {
+    header: BlockHeader,
+    closure: F,
+}
+
+impl<F> BlockLayout<F> {
+    /// Copies the closure into a block being moved to the heap.
+    unsafe extern "C" fn copy_clone(dst: *mut c_void, src: *const c_void)
+    where
+        F: Clone,
+    {
+        let dst = dst.cast::<Self>();
+        let src = &*src.cast::<Self>();
+
+        // The runtime has already copied the bytes of `src` into `dst`, so
+        // the closure is overwritten without dropping it.
+        let closure = abort_on_panic(|| src.closure.clone());
+        (&mut (*dst).closure as *mut F).write(closure);
+    }
+
+    /// Leaves the bitwise copy of the closure made by the runtime as-is, for
+    /// blocks whose stack original is forgotten after copying.
+    unsafe extern "C" fn copy_move(_dst: *mut c_void, _src: *const c_void) {}
+
+    /// Drops the closure of a heap block when its last reference is released.
+    unsafe extern "C" fn dispose(block: *mut c_void) {
+        let block = block.cast::<Self>();
+        abort_on_panic(|| (&mut (*block).closure as *mut F).drop_in_place());
+    }
+}
+
+/// Calls `f`, aborting the process if it panics.
+///
+/// Unwinding out of a block into Objective-C is undefined behavior.
+#[inline]
+fn abort_on_panic<T>(f: impl FnOnce() -> T) -> T {
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(value) => value,
+        Err(_) => process::abort(),
+    }
+}
+
+/// An Objective-C block that takes arguments `A` (as a tuple) and returns
+/// `R`.
+///
+/// Blocks are Objective-C's closures. Rust closures can be turned into blocks
+/// with [`StackBlock`] or [`RcBlock`], which dereference to this type.
+///
+/// See [documentation](https://developer.apple.com/library/archive/documentation/Cocoa/Conceptual/Blocks/Articles/00_Introduction.html).
+///
+/// # Usage
+///
+/// This is an opaque type meant to be used behind a shared reference
+/// `&Block<A, R>`, which can be passed to Objective-C methods that expect a
+/// block of the corresponding signature.
+///
+/// # Examples
+///
+/// ```
+/// use fruity::objc::StackBlock;
+///
+/// let offset = 10;
+/// let block = StackBlock::new(move |x: i32, y: i32| x + y + offset);
+///
+/// assert_eq!(block.call((1, 2)), 13);
+/// ```
+#[repr(C)]
+pub struct Block<A, R> {
+    header: UnsafeCell<BlockHeader>,
+    _marker: PhantomData<fn(A) -> R>,
+}
+
+impl<A, R> fmt::Debug for Block<A, R> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Block")
+            .field("ptr", &(self as *const Self))
+            .finish()
+    }
+}
+
+impl<A: BlockArguments, R> Block<A, R> {
+    /// Calls the block with `args`.
+    #[inline]
+    pub fn call(&self, args: A) -> R {
+        unsafe { A::call_block(self.as_ptr(), args) }
+    }
+}
+
+impl<A, R> Block<A, R> {
+    /// Returns a raw pointer to the block, suitable for passing to C.
+    #[inline]
+    pub fn as_ptr(&self) -> *const c_void {
+        (self as *const Self).cast()
+    }
+
+    /// Returns a block from a raw pointer.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must point to a valid block that takes arguments `A` and returns
+    /// `R`, and that lives for at least `'a`.
+    #[inline]
+    pub unsafe fn from_ptr<'a>(ptr: *const c_void) -> &'a Self {
+        &*ptr.cast()
+    }
+}
+
+/// Tuples of argument types that can be passed to a [`Block`].
+///
+/// This is implemented for tuples of up to 12 elements.
+pub trait BlockArguments: Sized {
+    #[doc(hidden)]
+    unsafe fn call_block<R>(block: *const c_void, args: Self) -> R;
+}
+
+/// Closures that can be the body of a block taking arguments `A` and
+/// returning `R`.
+///
+/// This is implemented for all `Fn` closures of up to 12 arguments.
+pub trait BlockFn<A, R>: Sized {
+    #[doc(hidden)]
+    fn invoke_fn() -> unsafe extern "C" fn();
+}
+
+macro_rules! impl_block_fn {
+    ($($arg:ident : $t:ident),*) => {
+        impl<$($t,)*> BlockArguments for ($($t,)*) {
+            #[inline]
+            #[allow(clippy::unused_unit)]
+            unsafe fn call_block<R>(block: *const c_void, ($($arg,)*): Self) -> R {
+                let header = &*block.cast::<BlockHeader>();
+                let invoke: unsafe extern "C" fn(*const c_void $(, $t)*) -> R =
+                    mem::transmute(header.invoke);
+
+                invoke(block $(, $arg)*)
+            }
+        }
+
+        impl<Func, R, $($t,)*> BlockFn<($($t,)*), R> for Func
+        where
+            Func: Fn($($t),*) -> R,
+        {
+            #[inline]
+            fn invoke_fn() -> unsafe extern "C" fn() {
+                unsafe extern "C" fn invoke<Func, R, $($t,)*>(
+                    block: *const BlockLayout<Func>
+                    $(, $arg: $t)*
+                ) -> R
+                where
+                    Func: Fn($($t),*) -> R,
+                {
+                    let closure = &(*block).closure;
+                    abort_on_panic(|| closure($($arg),*))
+                }
+
+                let invoke: unsafe extern "C" fn(*const BlockLayout<Func> $(, $t)*) -> R =
+                    invoke::<Func, R, $($t,)*>;
+
+                // SAFETY: Function pointers have the same size and are
+                // transmuted back to the correct type when called.
+                unsafe { mem::transmute(invoke) }
+            }
+        }
+    };
+}
+
+/// Calls `impl_block_fn` on N..0 args.
+macro_rules! impl_block_fns {
+    () => {
+        impl_block_fn!();
+    };
+    ($arg_1:ident : $t_1:ident $(, $arg_n:ident : $t_n:ident)*) => {
+        impl_block_fn!($arg_1: $t_1 $(, $arg_n: $t_n)*);
+        impl_block_fns!($($arg_n: $t_n),*);
+    };
+}
+
+impl_block_fns!(
+    a: A,
+    b: B,
+    c: C,
+    d: D,
+    e: E,
+    f: F,
+    g: G,
+    h: H,
+    i: I,
+    j: J,
+    k: K,
+    l: L
+);
@@ -0,0 +1,134 @@
+use super::{
+    _Block_copy, _Block_release, _NSConcreteStackBlock, Block, BlockDescriptor, BlockFn,
+    BlockHeader, BlockLayout, BLOCK_HAS_COPY_DISPOSE,
+};
+use std::{
+    fmt,
+    marker::PhantomData,
+    mem::{self, ManuallyDrop},
+    ops::Deref,
+    os::raw::{c_int, c_ulong},
+    ptr::NonNull,
+};
+
+/// A reference-counted block on the heap.
+///
+/// Use this for blocks that Objective-C may keep around after the method they
+/// were passed to returns, such as completion handlers.
+///
+/// Cloning this is cheap: it increments the reference count of the same
+/// block.
+///
+/// See [documentation](https://clang.llvm.org/docs/Block-ABI-Apple.html).
+pub struct RcBlock<A, R> {
+    ptr: NonNull<Block<A, R>>,
+}
+
+impl<A, R> Drop for RcBlock<A, R> {
+    #[inline]
+    #[doc(alias = "_Block_release")]
+    fn drop(&mut self) {
+        unsafe { _Block_release(self.ptr.as_ptr().cast()) };
+    }
+}
+
+impl<A, R> Clone for RcBlock<A, R> {
+    #[inline]
+    #[doc(alias = "_Block_copy")]
+    fn clone(&self) -> Self {
+        // SAFETY: Copying a heap block only increments its reference count.
+        unsafe { Self::copy(self) }
+    }
+}
+
+impl<A, R> Deref for RcBlock<A, R> {
+    type Target = Block<A, R>;
+
+    #[inline]
+    fn deref(&self) -> &Block<A, R> {
+        unsafe { self.ptr.as_ref() }
+    }
+}
+
+impl<A, R> fmt::Debug for RcBlock<A, R> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RcBlock").field("ptr", &self.ptr).finish()
+    }
+}
+
+/// The descriptor for blocks whose closure is moved rather than cloned onto
+/// the heap.
+struct MoveDescriptor<F>(PhantomData<F>);
+
+impl<F> MoveDescriptor<F> {
+    const DESCRIPTOR: BlockDescriptor = BlockDescriptor {
+        reserved: 0,
+        size: mem::size_of::<BlockLayout<F>>() as c_ulong,
+        copy: BlockLayout::<F>::copy_move,
+        dispose: BlockLayout::<F>::dispose,
+    };
+}
+
+impl<A, R> RcBlock<A, R> {
+    /// Creates a heap block that calls `closure`.
+    #[inline]
+    pub fn new<F>(closure: F) -> Self
+    where
+        F: BlockFn<A, R> + 'static,
+    {
+        let stack = ManuallyDrop::new(BlockLayout {
+            header: BlockHeader {
+                isa: unsafe { _NSConcreteStackBlock.as_ptr().cast() },
+                flags: BLOCK_HAS_COPY_DISPOSE as c_int,
+                reserved: 0,
+                invoke: F::invoke_fn(),
+                descriptor: &MoveDescriptor::<F>::DESCRIPTOR,
+            },
+            closure,
+        });
+
+        // The runtime moves the closure to the heap by copying its bytes, so
+        // the stack original is never dropped.
+        unsafe { Self::copy_raw(&*stack as *const BlockLayout<F> as *const Block<A, R>) }
+    }
+
+    /// Copies `block` to the heap, or increments its reference count if it is
+    /// already on the heap.
+    ///
+    /// # Safety
+    ///
+    /// The copy may outlive any state borrowed by `block`'s closure.
+    #[inline]
+    #[doc(alias = "_Block_copy")]
+    pub unsafe fn copy(block: &Block<A, R>) -> Self {
+        Self::copy_raw(block)
+    }
+
+    #[inline]
+    unsafe fn copy_raw(block: *const Block<A, R>) -> Self {
+        let ptr = _Block_copy(block.cast());
+        Self {
+            ptr: NonNull::new_unchecked(ptr.cast()),
+        }
+    }
+
+    /// Consumes the block, returning a raw pointer that must be released with
+    /// [`from_raw`](Self::from_raw).
+    #[inline]
+    pub fn into_raw(this: Self) -> *const Block<A, R> {
+        ManuallyDrop::new(this).ptr.as_ptr()
+    }
+
+    /// Takes ownership of a heap block pointer.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be a non-null heap block that takes arguments `A` and
+    /// returns `R`, with a reference count owned by the caller.
+    #[inline]
+    pub unsafe fn from_raw(ptr: *const Block<A, R>) -> Self {
+        Self {
+            ptr: NonNull::new_unchecked(ptr as *mut _),
+        }
+    }
+}
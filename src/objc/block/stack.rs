@@ -0,0 +1,93 @@
+use super::{
+    _NSConcreteStackBlock, Block, BlockDescriptor, BlockFn, BlockHeader, BlockLayout, RcBlock,
+    BLOCK_HAS_COPY_DISPOSE,
+};
+use std::{
+    cell::UnsafeCell,
+    fmt,
+    marker::PhantomData,
+    mem,
+    ops::Deref,
+    os::raw::{c_int, c_ulong},
+};
+
+/// A block that wraps a closure `F` and lives on the stack.
+///
+/// This is the cheapest way to pass a closure to an Objective-C method that
+/// calls the block before returning, such as `-enumerateObjectsUsingBlock:`.
+///
+/// If the method copies the block to keep it around, the copy receives a
+/// clone of the closure. Because the copy may outlive `'f`, only pass a
+/// `StackBlock` that borrows local state to methods that do not escape their
+/// block. Use [`RcBlock`] for methods like completion handlers.
+///
+/// See [documentation](https://clang.llvm.org/docs/Block-ABI-Apple.html).
+#[repr(C)]
+pub struct StackBlock<'f, A, R, F> {
+    layout: UnsafeCell<BlockLayout<F>>,
+    _lifetime: PhantomData<&'f ()>,
+    _signature: PhantomData<fn(A) -> R>,
+}
+
+impl<A, R, F> fmt::Debug for StackBlock<'_, A, R, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("StackBlock")
+            .field("ptr", &(self as *const Self))
+            .finish()
+    }
+}
+
+impl<A, R, F> Deref for StackBlock<'_, A, R, F> {
+    type Target = Block<A, R>;
+
+    #[inline]
+    fn deref(&self) -> &Block<A, R> {
+        // SAFETY: `Block` is a prefix of the block layout.
+        unsafe { &*(self.layout.get() as *const Block<A, R>) }
+    }
+}
+
+impl<A, R, F: Clone> StackBlock<'_, A, R, F> {
+    const DESCRIPTOR: BlockDescriptor = BlockDescriptor {
+        reserved: 0,
+        size: mem::size_of::<BlockLayout<F>>() as c_ulong,
+        copy: BlockLayout::<F>::copy_clone,
+        dispose: BlockLayout::<F>::dispose,
+    };
+}
+
+impl<'f, A, R, F> StackBlock<'f, A, R, F>
+where
+    F: BlockFn<A, R> + Clone + 'f,
+{
+    /// Creates a block that calls `closure`.
+    #[inline]
+    pub fn new(closure: F) -> Self {
+        let header = BlockHeader {
+            isa: unsafe { _NSConcreteStackBlock.as_ptr().cast() },
+            flags: BLOCK_HAS_COPY_DISPOSE as c_int,
+            reserved: 0,
+            invoke: F::invoke_fn(),
+            descriptor: &Self::DESCRIPTOR,
+        };
+        Self {
+            layout: UnsafeCell::new(BlockLayout { header, closure }),
+            _lifetime: PhantomData,
+            _signature: PhantomData,
+        }
+    }
+}
+
+impl<A, R, F> StackBlock<'_, A, R, F>
+where
+    F: Clone + 'static,
+{
+    /// Copies this block to the heap, cloning its closure.
+    #[inline]
+    #[doc(alias = "_Block_copy")]
+    pub fn copy(&self) -> RcBlock<A, R> {
+        // SAFETY: The closure is `'static`, so the copy cannot outlive any
+        // state it references.
+        unsafe { RcBlock::copy(self) }
+    }
+}
@@ -1,4 +1,4 @@
-use super::{sys, Method, Property, Sel, BOOL};
+use super::{sys, Imp, Method, Property, Protocol, Sel, BOOL};
 use crate::core::{Arc, ObjectType};
 use std::{
     cell::UnsafeCell,
@@ -218,6 +218,15 @@ impl Class {
         }
     }
 
+    /// Returns `true` if this class adopts `protocol`.
+    ///
+    /// Protocols adopted by superclasses are not included.
+    #[inline]
+    #[doc(alias = "class_conformsToProtocol")]
+    pub fn conforms_to_protocol(&self, protocol: &Protocol) -> bool {
+        unsafe { class_conformsToProtocol(self, protocol) }.into()
+    }
+
     /// Returns the size of instances of this class.
     #[inline]
     pub fn instance_size(&self) -> usize {
@@ -254,12 +263,7 @@ impl Class {
     pub fn copy_class_method_list(&self) -> Option<Malloced<[&Method]>> {
         use std::{mem::MaybeUninit, os::raw::c_uint};
 
-        // TODO: Move this function into `objc::sys` module.
-        extern "C" {
-            fn object_getClass(obj: *const Class) -> *const Class;
-        }
-
-        let superclass = unsafe { object_getClass(self) };
+        let superclass = unsafe { sys::object_getClass(self as *const Self as _) };
 
         let mut len = MaybeUninit::<c_uint>::uninit();
         unsafe {
@@ -290,6 +294,22 @@ impl Class {
         unsafe { sys::class_getInstanceMethod(self, name).as_ref() }
     }
 
+    /// Returns the function that would be called if `name` were sent to an
+    /// instance of this class.
+    ///
+    /// If instances of this class do not respond to `name`, the returned
+    /// function is the runtime's message forwarding machinery.
+    ///
+    /// To avoid repeating this lookup in hot paths, use
+    /// [`CachedImp`](super::CachedImp).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/objectivec/class_getmethodimplementation(_:_:)?language=objc).
+    #[inline]
+    #[doc(alias = "class_getMethodImplementation")]
+    pub fn method_implementation(&self, name: Sel) -> Imp {
+        unsafe { sys::class_getMethodImplementation(self, name) }
+    }
+
     /// Returns the instance methods implemented by this class, or `None` if
     /// this class implements no instance methods.
     ///
@@ -369,4 +389,5 @@ extern "C" {
     fn class_getName(class: &Class) -> *const c_char;
     fn class_getSuperclass(class: &Class) -> Option<&Class>;
     fn class_getInstanceSize(class: &Class) -> usize;
+    fn class_conformsToProtocol(class: &Class, protocol: &Protocol) -> BOOL;
 }
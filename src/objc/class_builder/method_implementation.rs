@@ -0,0 +1,62 @@
+use crate::objc::{Encode, Imp, Sel};
+use std::mem;
+
+/// A Rust function that can be registered as the implementation of an
+/// Objective-C method with [`ClassBuilder`](super::ClassBuilder).
+///
+/// This is implemented for `extern "C"` function pointers whose first two
+/// arguments are the receiver and the selector, and whose remaining arguments
+/// and return type implement [`Encode`]. Up to 8 method arguments are
+/// supported.
+///
+/// Lifetime parameters of object types must be written out, as in
+/// `extern "C" fn(&NSObject<'static>, Sel)`, since eliding them makes the
+/// function generic over those lifetimes.
+///
+/// # Safety
+///
+/// [`type_encoding`](Self::type_encoding) and
+/// [`ARG_COUNT`](Self::ARG_COUNT) must describe the signature of the function
+/// returned by [`imp`](Self::imp).
+pub unsafe trait MethodImplementation: Copy {
+    /// The number of arguments after the receiver and selector.
+    const ARG_COUNT: usize;
+
+    /// Returns the type encoding of the method, such as `"v@:q"`.
+    fn type_encoding() -> String;
+
+    /// Returns `self` as an untyped function pointer.
+    fn imp(self) -> Imp;
+}
+
+macro_rules! impl_method_implementation {
+    ($($arg:ident),*) => {
+        unsafe impl<T, Ret: Encode, $($arg: Encode),*> MethodImplementation
+            for extern "C" fn(&T, Sel $(, $arg)*) -> Ret
+        {
+            const ARG_COUNT: usize = <[&str]>::len(&[$(stringify!($arg)),*]);
+
+            fn type_encoding() -> String {
+                let mut encoding = String::from(Ret::ENCODING);
+                encoding.push_str("@:");
+                $(encoding.push_str($arg::ENCODING);)*
+                encoding
+            }
+
+            #[inline]
+            fn imp(self) -> Imp {
+                unsafe { mem::transmute::<Self, Imp>(self) }
+            }
+        }
+    };
+}
+
+impl_method_implementation!();
+impl_method_implementation!(A);
+impl_method_implementation!(A, B);
+impl_method_implementation!(A, B, C);
+impl_method_implementation!(A, B, C, D);
+impl_method_implementation!(A, B, C, D, E);
+impl_method_implementation!(A, B, C, D, E, F);
+impl_method_implementation!(A, B, C, D, E, F, G);
+impl_method_implementation!(A, B, C, D, E, F, G, H);
@@ -0,0 +1,271 @@
+use super::{sys, Class, Encode, Protocol, Sel};
+use std::{
+    ffi::{CStr, CString},
+    fmt, mem,
+    ptr::NonNull,
+};
+
+#[cfg(feature = "foundation")]
+use std::{
+    ptr,
+    sync::{
+        atomic::{AtomicPtr, Ordering},
+        Once,
+    },
+};
+
+mod method_implementation;
+
+pub use method_implementation::*;
+
+/// Declares a new Objective-C class at runtime.
+///
+/// This allows implementing delegates and other classes expected by
+/// Objective-C APIs in Rust. The class is usable once
+/// [`register`](Self::register) is called, after which instance variables
+/// can no longer be added.
+///
+/// # Examples
+///
+/// ```no_run
+/// use fruity::{
+///     objc::{ClassBuilder, ClassType, NSInteger, NSObject, Sel},
+///     selector,
+/// };
+/// use std::ffi::CStr;
+///
+/// let count_ivar = CStr::from_bytes_with_nul(b"count\0").unwrap();
+///
+/// extern "C" fn increment(this: &NSObject<'static>, _: Sel) -> NSInteger {
+///     let count_ivar = CStr::from_bytes_with_nul(b"count\0").unwrap();
+///     let count = this.ivar_ptr::<NSInteger>(count_ivar);
+///     unsafe {
+///         *count += 1;
+///         *count
+///     }
+/// }
+///
+/// let name = CStr::from_bytes_with_nul(b"FruityCounter\0").unwrap();
+/// let mut builder = ClassBuilder::new(name, NSObject::class()).expect("class already exists");
+///
+/// builder.add_ivar::<NSInteger>(count_ivar);
+/// unsafe {
+///     builder.add_method(
+///         selector!(increment),
+///         increment as extern "C" fn(&NSObject<'static>, Sel) -> NSInteger,
+///     );
+/// }
+///
+/// let class = builder.register();
+/// ```
+pub struct ClassBuilder {
+    class: NonNull<Class>,
+}
+
+// The class is not visible to other threads until it is registered.
+unsafe impl Send for ClassBuilder {}
+
+impl fmt::Debug for ClassBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("ClassBuilder").field(&self.class()).finish()
+    }
+}
+
+impl Drop for ClassBuilder {
+    #[inline]
+    #[doc(alias = "objc_disposeClassPair")]
+    fn drop(&mut self) {
+        unsafe { sys::objc_disposeClassPair(self.class.as_ptr()) };
+    }
+}
+
+impl ClassBuilder {
+    /// Starts declaring a subclass of `superclass` named `name`, or returns
+    /// `None` if a class with that name already exists.
+    ///
+    /// Class names are global to the process, so they should be prefixed to
+    /// avoid conflicts with other libraries.
+    #[inline]
+    #[doc(alias = "objc_allocateClassPair")]
+    pub fn new(name: &CStr, superclass: &Class) -> Option<Self> {
+        let class = unsafe { sys::objc_allocateClassPair(superclass, name.as_ptr(), 0) };
+        NonNull::new(class).map(|class| Self { class })
+    }
+
+    /// Returns the class named `name`, first declaring it as a subclass of
+    /// `superclass` with `declare` and registering it if it does not exist.
+    ///
+    /// This allows code that may be loaded more than once, such as a library
+    /// linked into several plugins, to share one class.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a class named `name` is being declared but has not been
+    /// registered.
+    pub fn get_or_register<F>(name: &CStr, superclass: &Class, declare: F) -> &'static Class
+    where
+        F: FnOnce(&mut Self),
+    {
+        match Self::new(name, superclass) {
+            Some(mut builder) => {
+                declare(&mut builder);
+                builder.register()
+            }
+            None => match Class::get(name) {
+                Some(class) => class,
+                None => panic!("class {:?} is declared but not registered", name),
+            },
+        }
+    }
+
+    /// Returns the class being declared.
+    #[inline]
+    fn class(&self) -> &Class {
+        unsafe { self.class.as_ref() }
+    }
+
+    /// Returns the metaclass, which holds class methods.
+    #[inline]
+    fn metaclass(&self) -> *mut Class {
+        unsafe { sys::object_getClass(self.class.as_ptr().cast()) as *mut Class }
+    }
+
+    /// Adds an instance variable of type `T` named `name`.
+    ///
+    /// Instance variables are zero-initialized and can be accessed with
+    /// [`NSObject::ivar_ptr`](super::NSObject::ivar_ptr). They are not
+    /// dropped when instances are deallocated.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the class already has an instance variable named `name`.
+    #[doc(alias = "class_addIvar")]
+    pub fn add_ivar<T: Encode>(&mut self, name: &CStr) {
+        let encoding = CString::new(T::ENCODING).expect("encoding contains a nul byte");
+        let added = unsafe {
+            sys::class_addIvar(
+                self.class.as_ptr(),
+                name.as_ptr(),
+                mem::size_of::<T>(),
+                mem::align_of::<T>().trailing_zeros() as u8,
+                encoding.as_ptr(),
+            )
+        };
+        assert!(added.is_yes(), "failed to add instance variable {:?}", name);
+    }
+
+    /// Adds an instance method that calls `imp` when `sel` is sent.
+    ///
+    /// This overrides any implementation inherited from a superclass.
+    ///
+    /// # Safety
+    ///
+    /// The receiver of `imp` must be a type that instances of the class can
+    /// be referenced as, such as [`NSObject`](super::NSObject). If `sel` is
+    /// inherited or declared by a protocol, the argument and return types of
+    /// `imp` must match that declaration.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of arguments of `imp` does not match the number
+    /// of colons in `sel`, or if the class already implements `sel`.
+    #[doc(alias = "class_addMethod")]
+    pub unsafe fn add_method<F: MethodImplementation>(&mut self, sel: Sel, imp: F) {
+        add_method(self.class.as_ptr(), sel, imp);
+    }
+
+    /// Adds a class method that calls `imp` when `sel` is sent to the class.
+    ///
+    /// # Safety
+    ///
+    /// The receiver of `imp` must be [`Class`]. If `sel` is inherited, the
+    /// argument and return types of `imp` must match that declaration.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of arguments of `imp` does not match the number
+    /// of colons in `sel`, or if the class already implements `sel`.
+    pub unsafe fn add_class_method<F: MethodImplementation>(&mut self, sel: Sel, imp: F) {
+        add_method(self.metaclass(), sel, imp);
+    }
+
+    /// Declares that the class conforms to `protocol`.
+    ///
+    /// This does not check that the class implements the methods required by
+    /// `protocol`.
+    #[inline]
+    #[doc(alias = "class_addProtocol")]
+    pub fn add_protocol(&mut self, protocol: &Protocol) {
+        unsafe { sys::class_addProtocol(self.class.as_ptr(), protocol) };
+    }
+
+    /// Registers the class with the Objective-C runtime, after which it can
+    /// be instantiated and looked up by name.
+    #[inline]
+    #[doc(alias = "objc_registerClassPair")]
+    pub fn register(self) -> &'static Class {
+        let class = self.class;
+        mem::forget(self);
+        unsafe {
+            sys::objc_registerClassPair(class.as_ptr());
+            &*class.as_ptr()
+        }
+    }
+}
+
+/// A class declared by this crate, which is registered on first use.
+#[cfg(feature = "foundation")]
+pub(crate) struct LazyClass {
+    name: &'static str,
+    class: AtomicPtr<Class>,
+    register: Once,
+}
+
+#[cfg(feature = "foundation")]
+impl LazyClass {
+    /// Creates a class that will be named `name` followed by the version of
+    /// this crate.
+    #[inline]
+    pub const fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            class: AtomicPtr::new(ptr::null_mut()),
+            register: Once::new(),
+        }
+    }
+
+    /// Returns the class, first declaring it as a subclass of `superclass`
+    /// with `declare` if it has not been registered.
+    pub fn get<F>(&self, superclass: &Class, declare: F) -> &'static Class
+    where
+        F: FnOnce(&mut ClassBuilder),
+    {
+        self.register.call_once(|| {
+            // The version is included so that multiple versions of this crate
+            // in one process do not share a class whose methods may differ.
+            let name = format!("{}_{}", self.name, env!("CARGO_PKG_VERSION"));
+            let name = CString::new(name).expect("class name contains a nul byte");
+
+            let class = ClassBuilder::get_or_register(&name, superclass, declare);
+            self.class
+                .store(class as *const Class as *mut Class, Ordering::Release);
+        });
+        unsafe { &*self.class.load(Ordering::Acquire) }
+    }
+}
+
+unsafe fn add_method<F: MethodImplementation>(class: *mut Class, sel: Sel, imp: F) {
+    let sel_args = sel.name().to_bytes().iter().filter(|&&b| b == b':').count();
+    assert_eq!(
+        sel_args,
+        F::ARG_COUNT,
+        "selector {:?} takes {} arguments, but the implementation takes {}",
+        sel.name(),
+        sel_args,
+        F::ARG_COUNT,
+    );
+
+    let encoding = CString::new(F::type_encoding()).expect("encoding contains a nul byte");
+    let added = sys::class_addMethod(class, sel, imp.imp(), encoding.as_ptr());
+    assert!(added.is_yes(), "failed to add method {:?}", sel.name());
+}
@@ -0,0 +1,115 @@
+use super::{Class, ObjectType, Sel, BOOL};
+use std::ffi::c_void;
+
+/// A type with an Objective-C
+/// [type encoding](https://developer.apple.com/library/archive/documentation/Cocoa/Conceptual/ObjCRuntimeGuide/Articles/ocrtTypeEncodings.html).
+///
+/// Encodings describe the types of method arguments, return values, and
+/// instance variables to the Objective-C runtime. They are used by
+/// [`ClassBuilder`](super::ClassBuilder) to declare methods and instance
+/// variables implemented in Rust.
+///
+/// Objects are encoded as pointers in method implementation signatures,
+/// since references with arbitrary lifetimes cannot appear there. References
+/// can be used as arguments to [`msg_send!`](crate::msg_send).
+///
+/// # Safety
+///
+/// [`ENCODING`](Self::ENCODING) must match the size, alignment, and ABI of
+/// `Self`.
+pub unsafe trait Encode {
+    /// The type encoding string.
+    const ENCODING: &'static str;
+}
+
+macro_rules! impl_encode {
+    ($($ty:ty => $encoding:expr,)+) => {
+        $(
+            unsafe impl Encode for $ty {
+                const ENCODING: &'static str = $encoding;
+            }
+        )+
+    };
+}
+
+impl_encode! {
+    () => "v",
+    bool => "B",
+    i8 => "c",
+    i16 => "s",
+    i32 => "i",
+    i64 => "q",
+    u8 => "C",
+    u16 => "S",
+    u32 => "I",
+    u64 => "Q",
+    f32 => "f",
+    f64 => "d",
+    Sel => ":",
+    &'static Class => "#",
+    Option<&'static Class> => "#",
+    *const c_void => "^v",
+    *mut c_void => "^v",
+}
+
+#[cfg(target_pointer_width = "64")]
+impl_encode! {
+    isize => "q",
+    usize => "Q",
+}
+
+#[cfg(target_pointer_width = "32")]
+impl_encode! {
+    isize => "l",
+    usize => "L",
+}
+
+unsafe impl Encode for BOOL {
+    #[cfg(any(
+        all(any(target_os = "macos", mac_catalyst), target_arch = "x86_64"),
+        all(target_os = "ios", target_pointer_width = "32"),
+    ))]
+    const ENCODING: &'static str = "c";
+
+    #[cfg(not(any(
+        all(any(target_os = "macos", mac_catalyst), target_arch = "x86_64"),
+        all(target_os = "ios", target_pointer_width = "32"),
+    )))]
+    const ENCODING: &'static str = "B";
+}
+
+unsafe impl<'data, T: ObjectType<'data>> Encode for *const T {
+    const ENCODING: &'static str = "@";
+}
+
+unsafe impl<'data, T: ObjectType<'data>> Encode for *mut T {
+    const ENCODING: &'static str = "@";
+}
+
+unsafe impl<'data, T: ObjectType<'data>> Encode for &T {
+    const ENCODING: &'static str = "@";
+}
+
+unsafe impl<'data, T: ObjectType<'data>> Encode for Option<&T> {
+    const ENCODING: &'static str = "@";
+}
+
+#[cfg(feature = "core_graphics")]
+mod core_graphics {
+    use super::Encode;
+    use crate::core_graphics::{CGPoint, CGRect, CGSize};
+
+    #[cfg(target_pointer_width = "64")]
+    impl_encode! {
+        CGPoint => "{CGPoint=dd}",
+        CGSize => "{CGSize=dd}",
+        CGRect => "{CGRect={CGPoint=dd}{CGSize=dd}}",
+    }
+
+    #[cfg(target_pointer_width = "32")]
+    impl_encode! {
+        CGPoint => "{CGPoint=ff}",
+        CGSize => "{CGSize=ff}",
+        CGRect => "{CGRect={CGPoint=ff}{CGSize=ff}}",
+    }
+}
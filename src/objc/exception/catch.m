@@ -0,0 +1,21 @@
+// Compiled by `build.rs`, since Rust cannot catch Objective-C exceptions on
+// its own.
+
+#include <objc/objc.h>
+
+id objc_retain(id value);
+
+// Calls `f(context)`. If it throws, stores the thrown object, retained, in
+// `exception` and returns 1.
+//
+// `@catch (id)` only matches Objective-C exceptions, so Rust panics unwind
+// through this frame to the caller.
+int fruity_objc_try(void (*f)(void *), void *context, id *exception) {
+    @try {
+        f(context);
+        return 0;
+    } @catch (id thrown) {
+        *exception = objc_retain(thrown);
+        return 1;
+    }
+}
@@ -0,0 +1,76 @@
+use super::{id, ObjCObject};
+use crate::core::Arc;
+use std::{ffi::c_void, os::raw::c_int, ptr};
+
+extern "C-unwind" {
+    // Defined in `catch.m`.
+    fn fruity_objc_try(
+        f: unsafe extern "C-unwind" fn(*mut c_void),
+        context: *mut c_void,
+        exception: *mut *const ObjCObject<'static>,
+    ) -> c_int;
+}
+
+/// Calls `f` and returns its result, or the object thrown by an Objective-C
+/// exception raised within it.
+///
+/// This is the equivalent of `@try`/`@catch (id exception)`. Many framework
+/// methods report programmer errors like out-of-bounds indexes by raising
+/// exceptions, which otherwise abort the process. The caught object is
+/// usually an `NSException`, but may be [`None`] if `nil` was thrown.
+///
+/// Exceptions can only be caught when raised by a call that may unwind, such
+/// as a message sent through this crate or with
+/// [`msg_send!`](crate::msg_send). Rust panics within `f` are not caught by
+/// `@catch (id exception)`, so they unwind through this call as usual.
+///
+/// Frameworks generally do not clean up after exceptions, so objects
+/// involved may be left in an inconsistent state.
+///
+/// See [documentation](https://developer.apple.com/library/archive/documentation/Cocoa/Conceptual/Exceptions/Tasks/HandlingExceptions.html).
+#[doc(alias = "@try")]
+#[doc(alias = "@catch")]
+pub fn catch_exception<F, T>(f: F) -> Result<T, Option<id<'static>>>
+where
+    F: FnOnce() -> T,
+{
+    struct Context<F, T> {
+        f: Option<F>,
+        result: Option<T>,
+    }
+
+    unsafe extern "C-unwind" fn call<F: FnOnce() -> T, T>(context: *mut c_void) {
+        let context = &mut *context.cast::<Context<F, T>>();
+        let f = context.f.take().unwrap();
+
+        // Exceptions must unwind straight into `@try`. Wrapping this in
+        // `catch_unwind` would intercept them as foreign exceptions first.
+        context.result = Some(f());
+    }
+
+    let mut context = Context {
+        f: Some(f),
+        result: None,
+    };
+    let mut exception: *const ObjCObject<'static> = ptr::null();
+
+    let thrown = unsafe {
+        fruity_objc_try(
+            call::<F, T>,
+            (&mut context as *mut Context<F, T>).cast(),
+            &mut exception,
+        )
+    };
+
+    if thrown != 0 {
+        // The shim retains the exception.
+        let exception = if exception.is_null() {
+            None
+        } else {
+            Some(unsafe { Arc::from_raw(exception) })
+        };
+        return Err(exception);
+    }
+
+    Ok(context.result.unwrap())
+}
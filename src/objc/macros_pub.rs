@@ -56,3 +56,59 @@ macro_rules! _objc_class {
         class
     }};
 }
+
+/// Sends a message to an Objective-C object or class.
+///
+/// This calls [`ObjCObject::send_message`] or [`Class::send_message`] with a
+/// selector built from the message's keywords. The return type can be
+/// specified after `=>` if it cannot be inferred. Arguments and return values
+/// must implement [`Encode`].
+///
+/// In debug builds, the types are checked against the type encoding of the
+/// method that handles the message, and a mismatch panics. This catches
+/// mistakes like returning [`isize`] from a method that returns
+/// `NSUInteger`, but cannot catch everything, since objects of any class are
+/// encoded the same way.
+///
+/// [`ObjCObject::send_message`]: crate::objc::ObjCObject::send_message
+/// [`Class::send_message`]: crate::objc::Class::send_message
+/// [`Encode`]: crate::objc::Encode
+///
+/// # Safety
+///
+/// This must be used within an `unsafe` block. The receiver must respond to
+/// the message, and the argument and return types must match the method that
+/// handles it.
+///
+/// # Feature Flag
+///
+/// This macro is defined in [`objc`](objc/index.html), which requires the
+/// **`objc`** [feature flag](index.html#feature-flags).
+///
+/// # Examples
+///
+/// ```rust
+/// use fruity::objc::{Class, NSUInteger, BOOL};
+///
+/// let class: &Class = fruity::objc_class!(NSObject);
+///
+/// let hash = unsafe { fruity::msg_send![class, hash => NSUInteger] };
+///
+/// let responds: BOOL = unsafe {
+///     fruity::msg_send![class, instancesRespondToSelector: fruity::selector!(init)]
+/// };
+/// assert!(bool::from(responds));
+/// # let _ = hash;
+/// ```
+#[macro_export]
+macro_rules! msg_send {
+    ($obj:expr, $sel:ident $(=> $ret:ty)?) => {
+        $obj.send_message $(::<(), $ret>)? ($crate::selector!($sel), ())
+    };
+    ($obj:expr, $($arg_name:ident : $arg:expr)+ $(=> $ret:ty)?) => {
+        $obj.send_message $(::<_, $ret>)? (
+            $crate::selector!($($arg_name :)+),
+            ($($arg,)+)
+        )
+    };
+}
@@ -0,0 +1,139 @@
+use crate::objc::{Class, Imp, Sel};
+use std::{
+    fmt, ptr,
+    sync::atomic::{AtomicPtr, Ordering},
+};
+
+/// A single-entry cache of the [`Imp`] that a class uses for a selector.
+///
+/// This is the same idea as an inline cache in a JIT: the first
+/// `(class, selector)` pair looked up is remembered, and subsequent lookups
+/// for that exact pair skip the runtime's method lookup. Lookups for any other
+/// pair fall through to [`Class::method_implementation`] without replacing the
+/// cached entry, so a call site that sees many classes is no slower than an
+/// uncached lookup.
+///
+/// Calling the returned [`Imp`] directly avoids `objc_msgSend` entirely, which
+/// matters in tight loops such as iterating over a collection.
+///
+/// # Caveats
+///
+/// The cache is not invalidated if the method is later replaced (e.g. via
+/// [`Method::set_implementation`](super::Method::set_implementation) or by
+/// adding a category). Only use this for methods that are not swizzled after
+/// the cache is first used.
+///
+/// # Examples
+///
+/// ```
+/// use fruity::{cached_selector, foundation::NSString, ns_string};
+/// use fruity::objc::{CachedImp, NSUInteger, Sel};
+/// use std::mem;
+///
+/// static LENGTH: CachedImp = CachedImp::new();
+///
+/// let string = ns_string!("hello");
+/// let sel = cached_selector!(length);
+/// let imp = LENGTH.get(string.object_class(), sel);
+///
+/// let length: unsafe extern "C" fn(&NSString, Sel) -> NSUInteger =
+///     unsafe { mem::transmute(imp) };
+///
+/// assert_eq!(unsafe { length(string, sel) }, 5);
+/// ```
+pub struct CachedImp(AtomicPtr<Entry>);
+
+// Entries are immutable once published, and are only freed with exclusive
+// access to the owning `CachedImp`.
+struct Entry {
+    class: *const Class,
+    sel: Sel,
+    imp: Imp,
+}
+
+impl Default for CachedImp {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for CachedImp {
+    #[inline]
+    fn drop(&mut self) {
+        let entry = *self.0.get_mut();
+        if !entry.is_null() {
+            drop(unsafe { Box::from_raw(entry) });
+        }
+    }
+}
+
+impl fmt::Debug for CachedImp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut debug = f.debug_struct("CachedImp");
+        if let Some(entry) = self.entry() {
+            debug
+                .field("class", unsafe { &*entry.class })
+                .field("sel", &entry.sel);
+        }
+        debug.finish()
+    }
+}
+
+impl CachedImp {
+    /// Creates an empty cache.
+    #[inline]
+    pub const fn new() -> Self {
+        Self(AtomicPtr::new(ptr::null_mut()))
+    }
+
+    #[inline]
+    fn entry(&self) -> Option<&Entry> {
+        unsafe { self.0.load(Ordering::Acquire).as_ref() }
+    }
+
+    /// Returns the implementation that `class` uses for `sel`, from the cache
+    /// if possible.
+    #[inline]
+    pub fn get(&self, class: &Class, sel: Sel) -> Imp {
+        match self.entry() {
+            // Selectors are uniqued by the runtime, so pointer equality avoids
+            // calling `sel_isEqual`.
+            Some(entry) if ptr::eq(entry.class, class) && entry.sel.as_ptr() == sel.as_ptr() => {
+                entry.imp
+            }
+            Some(_) => class.method_implementation(sel),
+            None => self.fill(class, sel),
+        }
+    }
+
+    #[cold]
+    fn fill(&self, class: &Class, sel: Sel) -> Imp {
+        let imp = class.method_implementation(sel);
+        let entry = Box::into_raw(Box::new(Entry { class, sel, imp }));
+
+        let result =
+            self.0
+                .compare_exchange(ptr::null_mut(), entry, Ordering::AcqRel, Ordering::Acquire);
+
+        // Another thread won the race to fill the cache.
+        if result.is_err() {
+            drop(unsafe { Box::from_raw(entry) });
+        }
+
+        imp
+    }
+
+    /// Removes the cached entry, so that the next call to
+    /// [`get`](Self::get) performs a fresh lookup.
+    ///
+    /// This requires exclusive access because other threads may be reading
+    /// the current entry.
+    #[inline]
+    pub fn clear(&mut self) {
+        let entry = std::mem::replace(self.0.get_mut(), ptr::null_mut());
+        if !entry.is_null() {
+            drop(unsafe { Box::from_raw(entry) });
+        }
+    }
+}
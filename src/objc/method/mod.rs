@@ -1,8 +1,10 @@
 use crate::objc::{sys, Sel};
 use std::{ffi::CStr, fmt};
 
+mod cached_imp;
 mod description;
 
+pub use cached_imp::*;
 pub use description::*;
 
 /// A pointer to the function of a method implementation.
@@ -22,29 +22,42 @@ mod macros_pub;
 mod msg;
 
 mod autoreleasepool;
+mod block;
 mod bool;
 mod class;
+mod class_builder;
 mod class_type;
+mod encode;
+mod exception;
 mod image_info;
 mod int;
 mod method;
 mod ns_object;
+mod ns_object_protocol;
 mod objc_object;
 mod object_type;
 mod property;
+mod protocol;
 
 pub use self::bool::*;
 pub use autoreleasepool::*;
+pub use block::*;
 pub use class::*;
+pub use class_builder::*;
 pub use class_type::*;
+pub use encode::*;
+pub use exception::*;
 pub use image_info::*;
 pub use int::*;
 pub use method::*;
+pub use msg::MessageArguments;
 pub use ns_object::*;
+pub use ns_object_protocol::*;
 pub use objc_object::*;
 pub use object_type::*;
 pub use property::*;
-pub use sel::Sel;
+pub use protocol::*;
+pub use sel::{CachedSel, Sel};
 
 #[link(name = "objc", kind = "dylib")]
 extern "C" {}
@@ -1,16 +1,16 @@
 #[allow(unused)]
 use std::{any::TypeId, mem};
 
-// TODO(#7): Use "C-unwind" ABI when stable.
+// Objective-C exceptions may unwind through these.
 #[allow(unused)]
-extern "C" {
+extern "C-unwind" {
     pub fn objc_msgSend();
     fn objc_msgSend_fpret();
     fn objc_msgSend_stret();
 }
 
 #[inline]
-pub fn msg_send_fn<Ret: 'static>() -> unsafe extern "C" fn() {
+pub fn msg_send_fn<Ret: 'static>() -> unsafe extern "C-unwind" fn() {
     #[cfg(target_arch = "x86")]
     {
         let ret_type = TypeId::of::<Ret>();
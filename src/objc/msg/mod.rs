@@ -1,7 +1,8 @@
-use super::{Class, ObjCObject, Sel};
+use super::{Class, Encode, ObjCObject, Sel};
 use std::{ffi::c_void, mem};
 
 mod get_fn;
+mod verify;
 
 macro_rules! _msg_send_any {
     ($obj:expr, $sel:ident $(=> $ret:ty)?) => {
@@ -118,6 +119,76 @@ where {
     }
 }
 
+impl ObjCObject<'_> {
+    /// Sends the message `sel` with `args` to `self` and returns the result.
+    ///
+    /// This is the method behind [`msg_send!`](crate::msg_send), which should
+    /// usually be used instead. The variant of `objc_msgSend` that returns
+    /// `Ret` on the current architecture is selected automatically.
+    ///
+    /// In debug builds, this panics if the method that handles `sel` declares
+    /// argument or return types whose encodings do not match `A` and `Ret`.
+    ///
+    /// # Safety
+    ///
+    /// `self` must respond to `sel`, and `A` and `Ret` must have the same ABI
+    /// as the arguments and return type of the method that handles it.
+    #[inline]
+    #[doc(alias = "objc_msgSend")]
+    pub unsafe fn send_message<A, Ret>(&self, sel: Sel, args: A) -> Ret
+    where
+        A: MessageArguments,
+        Ret: Encode + 'static,
+    {
+        let obj = self as *const Self as *const c_void;
+
+        #[cfg(debug_assertions)]
+        verify::verify_message::<A, Ret>(obj, sel);
+
+        A::msg_send_any(obj, sel, args)
+    }
+}
+
+impl Class {
+    /// Sends the message `sel` with `args` to `self` and returns the result.
+    ///
+    /// This is the method behind [`msg_send!`](crate::msg_send) for class
+    /// methods. See [`ObjCObject::send_message`] for details.
+    ///
+    /// # Safety
+    ///
+    /// `self` must respond to `sel`, and `A` and `Ret` must have the same ABI
+    /// as the arguments and return type of the class method that handles it.
+    #[inline]
+    #[doc(alias = "objc_msgSend")]
+    pub unsafe fn send_message<A, Ret>(&self, sel: Sel, args: A) -> Ret
+    where
+        A: MessageArguments,
+        Ret: Encode + 'static,
+    {
+        let obj = self as *const Self as *const c_void;
+
+        #[cfg(debug_assertions)]
+        verify::verify_message::<A, Ret>(obj, sel);
+
+        A::msg_send_any(obj, sel, args)
+    }
+}
+
+/// The arguments of a message sent with
+/// [`ObjCObject::send_message`] or [`msg_send!`](crate::msg_send).
+///
+/// This is implemented for tuples of up to 12 [`Encode`] types.
+///
+/// # Safety
+///
+/// [`ENCODINGS`](Self::ENCODINGS) must contain the encoding of each argument
+/// in order.
+pub unsafe trait MessageArguments: MsgArgs {
+    /// The type encodings of the arguments.
+    const ENCODINGS: &'static [&'static str];
+}
+
 // This trait is intentionally undocumented to ensure it is not publicly
 // exported.
 #[deny(missing_docs)]
@@ -141,8 +212,7 @@ macro_rules! impl_msg_args_base {
                 sel: Sel,
                 ($($arg,)*): Self,
             ) -> Ret {
-                // TODO(#7): Use "C-unwind" ABI when stable.
-                let msg_send: unsafe extern "C" fn(*const c_void, Sel $(, $arg)*) -> Ret
+                let msg_send: unsafe extern "C-unwind" fn(*const c_void, Sel $(, $arg)*) -> Ret
                     = mem::transmute(get_fn::msg_send_fn::<Ret>());
 
                 msg_send(obj, sel $(, $arg)*)
@@ -155,14 +225,17 @@ macro_rules! impl_msg_args_base {
                 sel: Sel,
                 ($($arg,)*): Self,
             ) -> Ret {
-                // TODO(#7): Use "C-unwind" ABI when stable.
-                let msg_send: unsafe extern "C" fn() = get_fn::objc_msgSend;
-                let msg_send: unsafe extern "C" fn(*const c_void, Sel $(, $arg)*) -> Ret
+                let msg_send: unsafe extern "C-unwind" fn() = get_fn::objc_msgSend;
+                let msg_send: unsafe extern "C-unwind" fn(*const c_void, Sel $(, $arg)*) -> Ret
                     = mem::transmute(msg_send);
 
                 msg_send(obj, sel $(, $arg)*)
             }
         }
+
+        unsafe impl<$($arg: Encode,)*> MessageArguments for ($($arg,)*) {
+            const ENCODINGS: &'static [&'static str] = &[$($arg::ENCODING),*];
+        }
     };
 }
 
@@ -0,0 +1,262 @@
+//! Checks that message sends match the type encoding of the receiving method.
+
+use super::MessageArguments;
+use crate::objc::{sys, Encode, Sel};
+use std::ffi::{c_void, CStr};
+
+/// Panics if the method that `obj` uses to respond to `sel` has a different
+/// signature than `(A) -> Ret`.
+///
+/// Nothing is checked if `obj` has no such method, since the message may be
+/// handled through forwarding.
+pub(super) unsafe fn verify_message<A, Ret>(obj: *const c_void, sel: Sel)
+where
+    A: MessageArguments,
+    Ret: Encode,
+{
+    // For classes, this is the metaclass, whose instance methods are the
+    // class methods.
+    let class = sys::object_getClass(obj);
+    let method = sys::class_getInstanceMethod(class, sel);
+    if method.is_null() {
+        return;
+    }
+
+    let encoding = sys::method_getTypeEncoding(method);
+    if encoding.is_null() {
+        return;
+    }
+
+    let encoding = match CStr::from_ptr(encoding).to_str() {
+        Ok(encoding) => encoding,
+        Err(_) => return,
+    };
+
+    let mut types = MethodTypes(encoding);
+    let fail = |message: String| -> ! {
+        panic!(
+            "invalid message send to {:?} of {:?} (encoding {:?}): {}",
+            sel,
+            (*class).name(),
+            encoding,
+            message,
+        )
+    };
+
+    let ret = types.next().unwrap_or("");
+    if !is_compatible(Ret::ENCODING, ret) {
+        fail(format!(
+            "expected return type {:?}, found {:?}",
+            Ret::ENCODING,
+            ret
+        ));
+    }
+
+    // Skip `self` and `_cmd`.
+    let args: Vec<&str> = types.skip(2).collect();
+    if args.len() != A::ENCODINGS.len() {
+        fail(format!(
+            "expected {} arguments, found {}",
+            A::ENCODINGS.len(),
+            args.len()
+        ));
+    }
+
+    for (index, (expected, found)) in A::ENCODINGS.iter().zip(args).enumerate() {
+        if !is_compatible(expected, found) {
+            fail(format!(
+                "expected argument {} to be {:?}, found {:?}",
+                index, expected, found
+            ));
+        }
+    }
+}
+
+/// Returns `true` if a value encoded as `expected` can be passed where
+/// `found` is declared.
+fn is_compatible(expected: &str, found: &str) -> bool {
+    let found = found.trim_start_matches(|c| "rnNoORV".contains(c));
+
+    if expected == found {
+        return true;
+    }
+
+    match expected {
+        // Objects may be declared with a class name (`@"NSString"`), as a
+        // block (`@?`), or as a class (`#`).
+        "@" => found.starts_with('@') || found == "#",
+        "#" => found.starts_with('@'),
+        // `void` pointers stand in for any pointer type.
+        "^v" => found.starts_with('^') || found == "*",
+        _ => false,
+    }
+}
+
+/// An iterator over the types in a method type encoding, such as
+/// `"v24@0:8@16"`.
+struct MethodTypes<'a>(&'a str);
+
+impl<'a> Iterator for MethodTypes<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        if self.0.is_empty() {
+            return None;
+        }
+
+        let len = type_len(self.0.as_bytes());
+        let (ty, rest) = self.0.split_at(len.min(self.0.len()));
+
+        // Each type is followed by its stack offset.
+        self.0 = rest.trim_start_matches(|c: char| c.is_ascii_digit() || c == '-');
+
+        Some(ty)
+    }
+}
+
+/// Returns the length of the single type at the start of `encoding`.
+fn type_len(encoding: &[u8]) -> usize {
+    let mut len = 0;
+
+    // Qualifiers such as `const` precede the type they apply to.
+    while let Some(b'r' | b'n' | b'N' | b'o' | b'O' | b'R' | b'V') = encoding.get(len) {
+        len += 1;
+    }
+
+    match encoding.get(len) {
+        None => len,
+        Some(b'^') => len + 1 + type_len(&encoding[len + 1..]),
+        Some(b'@') => match encoding.get(len + 1) {
+            Some(b'?') => len + 2,
+            Some(b'"') => match encoding[len + 2..].iter().position(|&b| b == b'"') {
+                Some(end) => len + 3 + end,
+                None => encoding.len(),
+            },
+            _ => len + 1,
+        },
+        Some(b'b') => {
+            len += 1;
+            while let Some(b'0'..=b'9') = encoding.get(len) {
+                len += 1;
+            }
+            len
+        }
+        Some(&open @ (b'{' | b'(' | b'[')) => {
+            let close = match open {
+                b'{' => b'}',
+                b'(' => b')',
+                _ => b']',
+            };
+
+            let mut depth = 0usize;
+            for (i, &b) in encoding.iter().enumerate().skip(len) {
+                if b == open {
+                    depth += 1;
+                } else if b == close {
+                    depth -= 1;
+                    if depth == 0 {
+                        return i + 1;
+                    }
+                }
+            }
+            encoding.len()
+        }
+        Some(_) => len + 1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CG_RECT: &str = "{CGRect={CGPoint=dd}{CGSize=dd}}";
+
+    #[test]
+    fn type_len_of_single_types() {
+        let cases: &[(&str, usize)] = &[
+            ("{CGRect={CGPoint=dd}{CGSize=dd}}16@0:8", CG_RECT.len()),
+            ("@\"NSString\"16", 11),
+            ("@?24", 2),
+            ("@16", 1),
+            ("r*", 2),
+            ("^{__CFString=}8", 14),
+            ("^^v", 3),
+            ("b4", 2),
+            ("b12i", 3),
+            ("[10i]", 5),
+            ("(?=iq)8", 6),
+            ("rn^v", 4),
+            ("Vv", 2),
+            ("", 0),
+        ];
+
+        for &(encoding, len) in cases {
+            assert_eq!(type_len(encoding.as_bytes()), len, "{:?}", encoding);
+        }
+    }
+
+    #[test]
+    fn method_types() {
+        let types: Vec<&str> = MethodTypes("{CGRect={CGPoint=dd}{CGSize=dd}}16@0:8").collect();
+        assert_eq!(types, [CG_RECT, "@", ":"]);
+
+        let types: Vec<&str> = MethodTypes("v40@0:8@\"NSString\"16@?24r^{__CFString=}32").collect();
+        assert_eq!(
+            types,
+            ["v", "@", ":", "@\"NSString\"", "@?", "r^{__CFString=}"]
+        );
+
+        let types: Vec<&str> = MethodTypes("Vv28@0:8[10i]16b4-4").collect();
+        assert_eq!(types, ["Vv", "@", ":", "[10i]", "b4"]);
+    }
+
+    #[test]
+    fn compatible_types() {
+        let cases = [
+            (CG_RECT, CG_RECT),
+            ("@", "@"),
+            ("@", "@\"NSString\""),
+            ("@", "@?"),
+            ("@", "#"),
+            ("#", "@\"NSObject\""),
+            ("*", "r*"),
+            ("^v", "^{__CFString=}"),
+            ("^v", "r^{__CFString=}"),
+            ("^v", "r*"),
+            ("^{__CFString=}", "r^{__CFString=}"),
+            ("^v", "n^v"),
+            ("b4", "b4"),
+            ("[10i]", "[10i]"),
+            ("v", "Vv"),
+        ];
+
+        for &(expected, found) in &cases {
+            assert!(is_compatible(expected, found), "{:?} {:?}", expected, found);
+        }
+    }
+
+    #[test]
+    fn incompatible_types() {
+        let cases = [
+            (CG_RECT, "{CGRect={CGPoint=ff}{CGSize=ff}}"),
+            ("@", "^v"),
+            ("@", "*"),
+            ("^v", "@"),
+            ("^{__CFString=}", "^v"),
+            ("*", "^v"),
+            ("q", "Q"),
+            ("i", "[10i]"),
+            ("b4", "b8"),
+            ("r*", "*"),
+        ];
+
+        for &(expected, found) in &cases {
+            assert!(
+                !is_compatible(expected, found),
+                "{:?} {:?}",
+                expected,
+                found
+            );
+        }
+    }
+}
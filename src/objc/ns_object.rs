@@ -1,12 +1,16 @@
-use super::{Class, ClassType, NSUInteger, ObjCObject, Sel, BOOL};
+use super::{Class, ClassType, Encode, NSObjectProtocol, BOOL};
 use crate::core::Arc;
+use std::ffi::CStr;
 
-// TODO: Create `NSObjectProtocol` for `@protocol NSObject` and `Deref` to that.
 objc_subclass! {
     /// An instance of the root class for most Objective-C objects.
     ///
+    /// Methods of the `NSObject` protocol, such as
+    /// [`responds_to_selector`](NSObjectProtocol::responds_to_selector), are
+    /// reached through [`NSObjectProtocol`].
+    ///
     /// See [documentation](https://developer.apple.com/documentation/objectivec/nsobject).
-    pub class NSObject<'data>: ObjCObject<'data>;
+    pub class NSObject<'data>: NSObjectProtocol<'data>;
 }
 
 impl Default for Arc<NSObject<'_>> {
@@ -24,48 +28,43 @@ impl PartialEq for NSObject<'_> {
 }
 
 impl<'data> NSObject<'data> {
-    /// Returns this object's reference count.
-    ///
-    /// This method is only useful for debugging certain objects.
+    /// Returns the class of this object without sending it a message.
     ///
-    /// See [documentation](https://developer.apple.com/documentation/objectivec/1418956-nsobject/1571952-retaincount).
+    /// Unlike `[self class]`, this returns the actual runtime class, which may
+    /// be a private subclass (e.g. `__NSCFString` for most strings).
     #[inline]
-    pub fn retain_count(&self) -> usize {
-        unsafe { _msg_send_any_cached![self, retainCount] }
+    #[doc(alias = "object_getClass")]
+    pub fn object_class(&self) -> &'static Class {
+        unsafe { &*super::sys::object_getClass(self as *const Self as _) }
     }
 
-    /// Returns `true` if this object implements or inherits a method that can
-    /// respond to a specified message.
+    /// Returns a pointer to the instance variable of `self` named `name`.
     ///
-    /// See [documentation](https://developer.apple.com/documentation/objectivec/1418956-nsobject/1418583-respondstoselector).
-    #[inline]
-    pub fn responds_to_selector(&self, selector: Sel) -> bool {
-        unsafe { _msg_send_any_cached![self, respondsToSelector: selector => BOOL] }.into()
-    }
-
-    /// Returns `true` if this object is an instance or subclass of `class`.
+    /// Reading or writing through the pointer is only safe while no other
+    /// thread accesses the same variable.
     ///
-    /// See [documentation](https://developer.apple.com/documentation/objectivec/1418956-nsobject/1418511-iskindofclass)
-    #[inline]
-    pub fn is_kind_of_class(&self, class: &Class) -> bool {
-        unsafe { _msg_send_any_cached![self, isKindOfClass: class => BOOL] }.into()
-    }
-
-    /// Returns `true` if this object is an instance of `class`.
+    /// # Panics
     ///
-    /// See [documentation](https://developer.apple.com/documentation/objectivec/1418956-nsobject/1418766-ismemberofclass)
-    #[inline]
-    pub fn is_member_of_class(&self, class: &Class) -> bool {
-        unsafe { _msg_send_any_cached![self, isMemberOfClass: class => BOOL] }.into()
-    }
+    /// Panics if the class of `self` has no instance variable named `name`,
+    /// or if its type encoding does not match that of `T`.
+    #[doc(alias = "class_getInstanceVariable")]
+    #[doc(alias = "ivar_getOffset")]
+    pub fn ivar_ptr<T: Encode>(&self, name: &CStr) -> *mut T {
+        unsafe {
+            let ivar = super::sys::class_getInstanceVariable(self.object_class(), name.as_ptr());
+            assert!(!ivar.is_null(), "no instance variable named {:?}", name);
 
-    /// Returns an integer that can be used as a table address in a hash table
-    /// structure.
-    ///
-    /// See [documentation](https://developer.apple.com/documentation/objectivec/1418956-nsobject/1418859-hash).
-    #[inline]
-    pub fn hash(&self) -> NSUInteger {
-        unsafe { _msg_send_any_cached![self, hash] }
+            let encoding = CStr::from_ptr(super::sys::ivar_getTypeEncoding(ivar));
+            assert_eq!(
+                encoding.to_bytes(),
+                T::ENCODING.as_bytes(),
+                "instance variable {:?} has a different type",
+                name,
+            );
+
+            let offset = super::sys::ivar_getOffset(ivar);
+            (self as *const Self as *mut u8).offset(offset).cast()
+        }
     }
 
     /// Returns a copy of this object using
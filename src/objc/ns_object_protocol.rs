@@ -0,0 +1,81 @@
+use super::{Class, NSUInteger, ObjCObject, Protocol, Sel, BOOL};
+
+subclass! {
+    /// An object that conforms to the `NSObject` protocol, which includes
+    /// instances of [`NSObject`](super::NSObject) and of other root classes
+    /// such as `NSProxy`.
+    ///
+    /// Methods that every Objective-C object responds to are defined here, and
+    /// [`NSObject`](super::NSObject) and its subclasses reach them through
+    /// [`Deref`](std::ops::Deref).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/objectivec/1418956-nsobject).
+    pub class NSObjectProtocol<'data>: ObjCObject<'data>;
+}
+
+impl<'data> super::ObjectType<'data> for NSObjectProtocol<'data> {}
+
+impl<'data> NSObjectProtocol<'data> {
+    /// Returns this object's reference count.
+    ///
+    /// This method is only useful for debugging certain objects.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/objectivec/1418956-nsobject/1571952-retaincount).
+    #[inline]
+    pub fn retain_count(&self) -> usize {
+        unsafe { _msg_send_any_cached![self, retainCount] }
+    }
+
+    /// Returns `true` if this object implements or inherits a method that can
+    /// respond to a specified message.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/objectivec/1418956-nsobject/1418583-respondstoselector).
+    #[inline]
+    pub fn responds_to_selector(&self, selector: Sel) -> bool {
+        unsafe { _msg_send_any_cached![self, respondsToSelector: selector => BOOL] }.into()
+    }
+
+    /// Returns `true` if this object conforms to `protocol`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/objectivec/1418956-nsobject/1418515-conformstoprotocol).
+    #[inline]
+    #[doc(alias = "conformsToProtocol:")]
+    pub fn conforms_to_protocol(&self, protocol: &Protocol) -> bool {
+        unsafe { _msg_send_any_cached![self, conformsToProtocol: protocol => BOOL] }.into()
+    }
+
+    /// Returns `true` if this object is an instance or subclass of `class`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/objectivec/1418956-nsobject/1418511-iskindofclass)
+    #[inline]
+    pub fn is_kind_of_class(&self, class: &Class) -> bool {
+        unsafe { _msg_send_any_cached![self, isKindOfClass: class => BOOL] }.into()
+    }
+
+    /// Returns `true` if this object is an instance of `class`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/objectivec/1418956-nsobject/1418766-ismemberofclass)
+    #[inline]
+    pub fn is_member_of_class(&self, class: &Class) -> bool {
+        unsafe { _msg_send_any_cached![self, isMemberOfClass: class => BOOL] }.into()
+    }
+
+    /// Returns `true` if this object forwards messages to another object
+    /// rather than inheriting from [`NSObject`](super::NSObject).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/objectivec/1418956-nsobject/1418528-isproxy).
+    #[inline]
+    #[doc(alias = "isProxy")]
+    pub fn is_proxy(&self) -> bool {
+        unsafe { _msg_send_any_cached![self, isProxy => BOOL] }.into()
+    }
+
+    /// Returns an integer that can be used as a table address in a hash table
+    /// structure.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/objectivec/1418956-nsobject/1418859-hash).
+    #[inline]
+    pub fn hash(&self) -> NSUInteger {
+        unsafe { _msg_send_any_cached![self, hash] }
+    }
+}
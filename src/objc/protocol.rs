@@ -0,0 +1,73 @@
+use std::{cell::UnsafeCell, ffi::CStr, fmt, os::raw::c_char, panic::RefUnwindSafe, ptr};
+
+/// An Objective-C protocol.
+///
+/// See [documentation](https://developer.apple.com/documentation/objectivec/protocol).
+///
+/// # Usage
+///
+/// This is an opaque type meant to be used behind a shared reference
+/// `&Protocol`. Protocols are never deallocated, so references to them are
+/// `'static`.
+#[repr(C)]
+pub struct Protocol {
+    // Stores data that may be mutated behind a shared reference. Internal
+    // mutability triggers undefined behavior without `UnsafeCell`.
+    _data: UnsafeCell<[u8; 0]>,
+}
+
+unsafe impl Sync for Protocol {}
+unsafe impl Send for Protocol {}
+
+// Although this uses `UnsafeCell`, it does not point to any Rust types.
+impl RefUnwindSafe for Protocol {}
+
+impl fmt::Debug for Protocol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Protocol").field(&self.name()).finish()
+    }
+}
+
+impl PartialEq for Protocol {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        ptr::eq(self, other)
+    }
+}
+
+impl Eq for Protocol {}
+
+impl Protocol {
+    /// Returns the protocol named `name`, or `None` if it is not known to the
+    /// Objective-C runtime.
+    ///
+    /// A protocol is only known if code loaded into the process references
+    /// it. For example, `NSApplicationDelegate` is available once AppKit is
+    /// loaded.
+    #[inline]
+    #[doc(alias = "objc_getProtocol")]
+    pub fn get(name: &CStr) -> Option<&'static Protocol> {
+        unsafe { objc_getProtocol(name.as_ptr()) }
+    }
+
+    /// Returns the name of this protocol.
+    #[inline]
+    #[doc(alias = "protocol_getName")]
+    pub fn name(&self) -> &CStr {
+        unsafe { CStr::from_ptr(protocol_getName(self)) }
+    }
+
+    /// Returns `true` if this protocol adopts `other`, directly or through
+    /// another protocol.
+    #[inline]
+    #[doc(alias = "protocol_conformsToProtocol")]
+    pub fn conforms_to(&self, other: &Protocol) -> bool {
+        unsafe { protocol_conformsToProtocol(self, other) }.into()
+    }
+}
+
+extern "C" {
+    fn objc_getProtocol(name: *const c_char) -> Option<&'static Protocol>;
+    fn protocol_getName(protocol: &Protocol) -> *const c_char;
+    fn protocol_conformsToProtocol(protocol: &Protocol, other: &Protocol) -> super::BOOL;
+}
@@ -1,50 +1,72 @@
-#![allow(non_upper_case_globals, non_snake_case)]
+use super::Sel;
+use std::{
+    os::raw::c_void,
+    ptr::{self, NonNull},
+    sync::atomic::{AtomicPtr, Ordering},
+};
 
-// This module maps selector literals used in `_cached_selector` to globally
-// cached selectors.
-//
-// Ideally in the future we'd be able to have static selectors like those in
-// Objective-C binaries. See https://github.com/nvzqz/fruity/issues/2.
+/// A lazily-registered selector that can be stored in a `static`.
+///
+/// Registering a selector requires a lookup in the runtime's global selector
+/// table. Hot paths can instead pay that cost once and reuse the result by
+/// storing it in a `CachedSel`. The
+/// [`cached_selector!`](../macro.cached_selector.html) macro creates one of
+/// these per call site.
+///
+/// Selectors known at compile time can instead be resolved when the binary is
+/// loaded, like those in Objective-C binaries, with
+/// [`static_selector!`](../macro.static_selector.html).
+///
+/// # Examples
+///
+/// ```
+/// use fruity::{objc::CachedSel, selector};
+///
+/// static INIT: CachedSel = CachedSel::new();
+///
+/// let sel = INIT.get_or_register_with(|| selector!(init));
+/// assert_eq!(sel, selector!(init));
+/// ```
+#[repr(transparent)]
+pub struct CachedSel(AtomicPtr<c_void>);
 
-use super::atomic::AtomicSel;
-
-pub(crate) static class: AtomicSel = AtomicSel::null();
-pub(crate) static copy: AtomicSel = AtomicSel::null();
-pub(crate) static mutableCopy: AtomicSel = AtomicSel::null();
-pub(crate) static hash: AtomicSel = AtomicSel::null();
-pub(crate) static retainCount: AtomicSel = AtomicSel::null();
-
-pub mod isEqual {
-    use super::*;
-    pub(crate) static SELECTOR: AtomicSel = AtomicSel::null();
-}
-
-pub mod isEqualToNumber {
-    use super::*;
-    pub(crate) static SELECTOR: AtomicSel = AtomicSel::null();
-}
-
-pub mod isEqualToString {
-    use super::*;
-    pub(crate) static SELECTOR: AtomicSel = AtomicSel::null();
+impl Default for CachedSel {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
-pub mod isKindOfClass {
-    use super::*;
-    pub(crate) static SELECTOR: AtomicSel = AtomicSel::null();
-}
+impl CachedSel {
+    /// Creates an empty cache.
+    #[inline]
+    pub const fn new() -> Self {
+        Self(AtomicPtr::new(ptr::null_mut()))
+    }
 
-pub mod isMemberOfClass {
-    use super::*;
-    pub(crate) static SELECTOR: AtomicSel = AtomicSel::null();
-}
-
-pub mod respondsToSelector {
-    use super::*;
-    pub(crate) static SELECTOR: AtomicSel = AtomicSel::null();
-}
+    /// Returns the cached selector, if one has been stored.
+    #[inline]
+    pub fn get(&self) -> Option<Sel> {
+        NonNull::new(self.0.load(Ordering::Relaxed))
+            .map(|ptr| unsafe { Sel::from_non_null_ptr(ptr) })
+    }
 
-pub mod instancesRespondToSelector {
-    use super::*;
-    pub(crate) static SELECTOR: AtomicSel = AtomicSel::null();
+    /// Returns the cached selector, or calls `register` to create a new one
+    /// that is stored in `self` and returned.
+    ///
+    /// Racing threads may each call `register`. This is fine because the
+    /// runtime returns the same selector for the same name.
+    #[inline]
+    pub fn get_or_register_with<F>(&self, register: F) -> Sel
+    where
+        F: FnOnce() -> Sel,
+    {
+        if let Some(selector) = self.get() {
+            selector
+        } else {
+            let selector = register();
+            self.0.store(selector.as_ptr() as _, Ordering::Relaxed);
+            selector
+        }
+    }
 }
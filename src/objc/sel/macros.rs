@@ -97,13 +97,101 @@ macro_rules! selector {
     };
 }
 
-macro_rules! _cached_selector {
-    ($sel:ident) => {
-        $crate::objc::sel::cached::$sel
-            .load_or_store_with(|| $crate::selector!($sel))
+/// Creates a [`Sel`](objc/struct.Sel.html) from a selector literal, and
+/// caches it in a per-call-site `static` for subsequent uses.
+///
+/// This is preferable to [`selector!`](macro.selector.html) in code that runs
+/// repeatedly, such as loops or frequently-called functions, since the runtime
+/// selector table is only consulted the first time the call site is reached.
+///
+/// # Feature Flag
+///
+/// This macro is defined in [`objc`](objc/index.html),
+/// which requires the **`objc`**
+/// [feature flag](index.html#feature-flags).
+///
+/// # Examples
+///
+/// ```
+/// use fruity::{cached_selector, selector};
+///
+/// for _ in 0..3 {
+///     let sel = cached_selector!(initWithArg:arg2:);
+///     assert_eq!(sel, selector!(initWithArg:arg2:));
+/// }
+/// ```
+#[macro_export]
+macro_rules! cached_selector {
+    ($($sel:tt)*) => {
+        {
+            static CACHED: $crate::objc::CachedSel = $crate::objc::CachedSel::new();
+            CACHED.get_or_register_with(|| $crate::selector!($($sel)*))
+        }
+    };
+}
+
+/// Creates a [`Sel`](objc/struct.Sel.html) from a selector literal that is
+/// resolved when the binary is loaded, like `@selector` in Objective-C.
+///
+/// The method name and a reference to it are emitted into the
+/// `__objc_methname` and `__objc_selrefs` sections, which the Objective-C
+/// runtime fixes up before `main`. Using the selector is then a single load,
+/// with no lookup in the runtime's selector table or check of a
+/// [`CachedSel`](objc/struct.CachedSel.html).
+///
+/// Classes are already referenced this way by
+/// [`objc_class!`](macro.objc_class.html), whose symbols are bound by the
+/// dynamic loader.
+///
+/// # Feature Flag
+///
+/// This macro is defined in [`objc`](objc/index.html),
+/// which requires the **`objc`**
+/// [feature flag](index.html#feature-flags).
+///
+/// # Examples
+///
+/// ```
+/// use fruity::{selector, static_selector};
+///
+/// let sel = static_selector!(initWithArg:arg2:);
+/// assert_eq!(sel, selector!(initWithArg:arg2:));
+/// ```
+#[macro_export]
+macro_rules! static_selector {
+    ($($sel:tt)*) => {
+        {
+            const NAME: &str = $crate::selector_str!($($sel)*);
+
+            #[link_section = "__TEXT,__objc_methname,cstring_literals"]
+            static METHNAME: [u8; NAME.len()] = {
+                let bytes = NAME.as_bytes();
+                let mut out = [0u8; NAME.len()];
+                let mut i = 0;
+                while i < bytes.len() {
+                    out[i] = bytes[i];
+                    i += 1;
+                }
+                out
+            };
+
+            #[link_section = "__DATA,__objc_selrefs,literal_pointers,no_dead_strip"]
+            static SELREF: $crate::_priv::objc::SelRef =
+                $crate::_priv::objc::SelRef::new(METHNAME.as_ptr());
+
+            // Without image info in the binary, the runtime does not fix up
+            // its selector references. The linker merges duplicates.
+            #[link_section = "__DATA,__objc_imageinfo,regular,no_dead_strip"]
+            #[used]
+            static IMAGE_INFO: $crate::objc::ImageInfo = $crate::_priv::objc::IMAGE_INFO;
+
+            SELREF.get()
+        }
     };
-    ($($sel:ident :)+) => {
-        $crate::objc::sel::cached $(::$sel)+ ::SELECTOR
-            .load_or_store_with(|| $crate::selector!($($sel :)+))
+}
+
+macro_rules! _cached_selector {
+    ($($sel:tt)*) => {
+        $crate::static_selector!($($sel)*)
     };
 }
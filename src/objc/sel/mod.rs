@@ -9,8 +9,9 @@ use std::{
 #[macro_use]
 mod macros;
 
-pub(crate) mod atomic;
-pub(crate) mod cached;
+mod cached;
+
+pub use cached::CachedSel;
 
 /// A method selector.
 ///
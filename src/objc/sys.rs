@@ -1,13 +1,42 @@
 //! Raw unsafe C functions exposed by libobjc.
 
 use super::*;
-use std::os::raw::{c_char, c_uint};
+use std::os::raw::{c_char, c_int, c_uint, c_void};
 
 #[allow(missing_docs)]
 extern "C" {
     pub fn class_getClassMethod(cls: *const Class, name: Sel) -> *const Method;
     pub fn class_getInstanceMethod(cls: *const Class, name: Sel) -> *const Method;
     pub fn class_copyMethodList(cls: *const Class, out_count: *mut c_uint) -> *mut *const Method;
+    pub fn class_getMethodImplementation(cls: *const Class, name: Sel) -> Imp;
+
+    pub fn object_getClass(obj: *const c_void) -> *const Class;
+
+    pub fn objc_allocateClassPair(
+        superclass: *const Class,
+        name: *const c_char,
+        extra_bytes: usize,
+    ) -> *mut Class;
+    pub fn objc_registerClassPair(cls: *mut Class);
+    pub fn objc_disposeClassPair(cls: *mut Class);
+
+    pub fn class_addMethod(cls: *mut Class, name: Sel, imp: Imp, types: *const c_char) -> BOOL;
+    pub fn class_addIvar(
+        cls: *mut Class,
+        name: *const c_char,
+        size: usize,
+        alignment: u8,
+        types: *const c_char,
+    ) -> BOOL;
+    pub fn class_getInstanceVariable(cls: *const Class, name: *const c_char) -> *const c_void;
+
+    pub fn class_addProtocol(cls: *mut Class, protocol: *const Protocol) -> BOOL;
+
+    pub fn ivar_getOffset(ivar: *const c_void) -> isize;
+    pub fn ivar_getTypeEncoding(ivar: *const c_void) -> *const c_char;
+
+    pub fn objc_sync_enter(obj: *const c_void) -> c_int;
+    pub fn objc_sync_exit(obj: *const c_void) -> c_int;
 
     pub fn method_getNumberOfArguments(m: *const Method) -> u32;
 
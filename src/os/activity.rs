@@ -0,0 +1,225 @@
+use super::sys;
+use crate::core::{Arc, ObjectType};
+use std::{cell::UnsafeCell, ffi::CStr, fmt, marker::PhantomData, ops::BitOr, ptr::NonNull};
+
+/// A named unit of work that groups the log messages and activities created
+/// while it is active, for tracing in Console and Instruments.
+///
+/// Activities are applied to the current thread with [`scope`](Self::scope)
+/// or [`enter`](Self::enter). Work started within an activity, such as blocks
+/// submitted to dispatch queues, is automatically associated with it.
+///
+/// See [documentation](https://developer.apple.com/documentation/os/logging/recording_log_messages_with_activities).
+#[repr(C)]
+#[doc(alias = "os_activity_t")]
+pub struct OSActivity {
+    // Stores data that may be mutated behind a shared reference. Internal
+    // mutability triggers undefined behavior without `UnsafeCell`.
+    _data: UnsafeCell<[u8; 0]>,
+}
+
+impl ObjectType for OSActivity {
+    #[inline]
+    #[doc(alias = "os_retain")]
+    fn retain(obj: &Self) -> Arc<Self> {
+        unsafe {
+            sys::os_retain((obj as *const Self).cast());
+            Arc::from_raw(obj)
+        }
+    }
+
+    #[inline]
+    #[doc(alias = "os_release")]
+    unsafe fn release(obj: NonNull<Self>) {
+        sys::os_release(obj.as_ptr().cast());
+    }
+}
+
+unsafe impl Send for OSActivity {}
+unsafe impl Sync for OSActivity {}
+
+impl fmt::Debug for OSActivity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("OSActivity")
+            .field("identifier", &self.identifier())
+            .finish()
+    }
+}
+
+/// The `__dso_handle` of the image that contains this crate, which is used
+/// to locate activity descriptions within it.
+#[inline]
+pub(super) fn dso_handle() -> *const std::ffi::c_void {
+    extern "C" {
+        static __dso_handle: u8;
+    }
+    unsafe { &__dso_handle as *const u8 }.cast()
+}
+
+impl OSActivity {
+    /// Creates an activity described by `description`, nested within the
+    /// activity of the current thread unless `flags` says otherwise.
+    ///
+    /// `description` should be a string literal. It is stored as an offset
+    /// into the binary rather than copied, so it must be part of the same
+    /// binary as this crate.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/os/os_activity_create).
+    #[inline]
+    #[doc(alias = "os_activity_create")]
+    #[doc(alias = "_os_activity_create")]
+    #[doc(alias = "OS_ACTIVITY_CURRENT")]
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(description: &'static CStr, flags: OSActivityFlags) -> Arc<Self> {
+        unsafe { Self::with_raw_parent(description, &sys::_os_activity_current, flags) }
+    }
+
+    /// Creates an activity described by `description` that is nested within
+    /// `parent`.
+    ///
+    /// See [`new`](Self::new) for the requirements of `description`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/os/os_activity_create).
+    #[inline]
+    pub fn with_parent(
+        description: &'static CStr,
+        parent: &OSActivity,
+        flags: OSActivityFlags,
+    ) -> Arc<Self> {
+        unsafe { Self::with_raw_parent(description, parent, flags) }
+    }
+
+    #[inline]
+    unsafe fn with_raw_parent(
+        description: &'static CStr,
+        parent: *const OSActivity,
+        flags: OSActivityFlags,
+    ) -> Arc<Self> {
+        Arc::from_raw(sys::_os_activity_create(
+            dso_handle(),
+            description.as_ptr(),
+            parent,
+            flags,
+        ))
+    }
+
+    /// Returns the identifier of `self`, which is unique within the current
+    /// boot of the system.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/os/os_activity_get_identifier).
+    #[inline]
+    #[doc(alias = "os_activity_get_identifier")]
+    pub fn identifier(&self) -> u64 {
+        unsafe { sys::os_activity_get_identifier(self, &mut 0) }
+    }
+
+    /// Returns the identifier of the activity that `self` is nested within,
+    /// or 0 if it has no parent.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/os/os_activity_get_identifier).
+    #[inline]
+    pub fn parent_identifier(&self) -> u64 {
+        let mut parent_id = 0;
+        unsafe { sys::os_activity_get_identifier(self, &mut parent_id) };
+        parent_id
+    }
+
+    /// Calls `f` with `self` as the activity of the current thread.
+    ///
+    /// # Panic Handling
+    ///
+    /// If a panic occurs within `f`, the previous activity is restored as the
+    /// stack unwinds.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/os/os_activity_apply).
+    #[inline]
+    #[doc(alias = "os_activity_apply")]
+    pub fn scope<F, T>(&self, f: F) -> T
+    where
+        F: FnOnce() -> T,
+    {
+        let _scope = self.enter();
+        f()
+    }
+
+    /// Makes `self` the activity of the current thread until the returned
+    /// scope is dropped.
+    ///
+    /// Scopes must be dropped in the reverse order that they were entered.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/os/os_activity_scope_enter).
+    #[inline]
+    #[doc(alias = "os_activity_scope_enter")]
+    pub fn enter(&self) -> OSActivityScope<'_> {
+        let mut state = sys::os_activity_scope_state_s::default();
+        unsafe { sys::os_activity_scope_enter(self, &mut state) };
+        OSActivityScope {
+            state,
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// The activity of the current thread while an [`OSActivity`] is
+/// [entered](OSActivity::enter).
+///
+/// The previous activity is restored when this is dropped.
+#[must_use = "The activity is immediately left if the scope is unused"]
+pub struct OSActivityScope<'a> {
+    state: sys::os_activity_scope_state_s,
+
+    // Scopes are thread-local.
+    _marker: PhantomData<(&'a OSActivity, *mut ())>,
+}
+
+impl fmt::Debug for OSActivityScope<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("OSActivityScope").finish()
+    }
+}
+
+impl Drop for OSActivityScope<'_> {
+    #[inline]
+    #[doc(alias = "os_activity_scope_leave")]
+    fn drop(&mut self) {
+        unsafe { sys::os_activity_scope_leave(&mut self.state) }
+    }
+}
+
+/// Options for creating an [`OSActivity`].
+///
+/// See [documentation](https://developer.apple.com/documentation/os/os_activity_flag_t).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[doc(alias = "os_activity_flag_t")]
+pub struct OSActivityFlags(pub u32);
+
+impl BitOr for OSActivityFlags {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl OSActivityFlags {
+    /// Nests the activity within the current activity.
+    #[doc(alias = "OS_ACTIVITY_FLAG_DEFAULT")]
+    pub const DEFAULT: Self = Self(0);
+
+    /// Creates the activity without a parent.
+    #[doc(alias = "OS_ACTIVITY_FLAG_DETACHED")]
+    pub const DETACHED: Self = Self(1 << 0);
+
+    /// Only nests the activity if there is no current activity, and otherwise
+    /// reuses the current activity.
+    #[doc(alias = "OS_ACTIVITY_FLAG_IF_NONE_PRESENT")]
+    pub const IF_NONE_PRESENT: Self = Self(1 << 1);
+
+    /// Returns `true` if all flags in `other` are set in `self`.
+    #[inline]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
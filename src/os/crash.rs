@@ -0,0 +1,284 @@
+//! Diagnostics recorded in the unified log when the process crashes.
+//!
+//! [`install`] reports Rust panics and uncaught Objective-C exceptions as
+//! faults, with their message, backtrace, and the most recent
+//! [breadcrumbs](breadcrumb). Faults are always persisted, and can be found
+//! in Console or with `log show --predicate 'messageType == fault'`.
+//!
+//! Breadcrumbs are short notes about what the process was doing, kept in a
+//! fixed-size ring buffer. Recording and reading them does not allocate or
+//! lock, so they can be used from signal handlers.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use fruity::os::{crash, OSLog};
+//! use std::ffi::CStr;
+//!
+//! let subsystem = CStr::from_bytes_with_nul(b"com.example.app\0").unwrap();
+//! let category = CStr::from_bytes_with_nul(b"crash\0").unwrap();
+//! crash::install(OSLog::new(subsystem, category));
+//!
+//! crash::breadcrumb("opened document");
+//! crash::breadcrumb("started export");
+//! ```
+
+use super::{sys, OSLog};
+use crate::core::Arc;
+use std::{
+    ffi::CStr,
+    fmt::Write,
+    os::raw::{c_int, c_void},
+    panic, ptr, str,
+    sync::{
+        atomic::{self, AtomicPtr, AtomicU8, AtomicUsize, Ordering},
+        Once,
+    },
+};
+
+#[cfg(feature = "foundation")]
+use crate::foundation::{
+    NSException, NSGetUncaughtExceptionHandler, NSSetUncaughtExceptionHandler,
+};
+
+/// The number of breadcrumbs kept before the oldest are overwritten.
+pub const BREADCRUMB_CAPACITY: usize = 32;
+
+/// The number of bytes kept of each breadcrumb, after which it is truncated.
+pub const BREADCRUMB_MAX_LEN: usize = 120;
+
+/// The most frames included in the backtrace of a panic.
+const MAX_FRAMES: usize = 128;
+
+/// A breadcrumb, guarded by a sequence number that is odd while it is being
+/// written and `2 * (n + 1)` once breadcrumb `n` is complete.
+///
+/// The bytes are atomic so that a read racing with a write is not undefined
+/// behavior. Such a read is discarded by checking the sequence number.
+struct Slot {
+    seq: AtomicUsize,
+    len: AtomicUsize,
+    bytes: [AtomicU8; BREADCRUMB_MAX_LEN],
+}
+
+struct Ring {
+    next: AtomicUsize,
+    slots: [Slot; BREADCRUMB_CAPACITY],
+}
+
+#[allow(clippy::declare_interior_mutable_const)]
+const EMPTY_BYTE: AtomicU8 = AtomicU8::new(0);
+
+#[allow(clippy::declare_interior_mutable_const)]
+const EMPTY_SLOT: Slot = Slot {
+    seq: AtomicUsize::new(0),
+    len: AtomicUsize::new(0),
+    bytes: [EMPTY_BYTE; BREADCRUMB_MAX_LEN],
+};
+
+static RING: Ring = Ring {
+    next: AtomicUsize::new(0),
+    slots: [EMPTY_SLOT; BREADCRUMB_CAPACITY],
+};
+
+/// Records `message` as the most recent breadcrumb, truncated to
+/// [`BREADCRUMB_MAX_LEN`] bytes.
+///
+/// This is safe to call from signal handlers. If more than
+/// [`BREADCRUMB_CAPACITY`] breadcrumbs are recorded at the same time, some
+/// of them may be lost.
+pub fn breadcrumb(message: &str) {
+    let mut len = message.len().min(BREADCRUMB_MAX_LEN);
+    while !message.is_char_boundary(len) {
+        len -= 1;
+    }
+
+    let n = RING.next.fetch_add(1, Ordering::Relaxed);
+    let slot = &RING.slots[n % BREADCRUMB_CAPACITY];
+
+    slot.seq.store(2 * n + 1, Ordering::Relaxed);
+    atomic::fence(Ordering::Release);
+
+    for (byte, &value) in slot.bytes.iter().zip(&message.as_bytes()[..len]) {
+        byte.store(value, Ordering::Relaxed);
+    }
+    slot.len.store(len, Ordering::Relaxed);
+
+    // If another write to this slot started meanwhile, it owns the slot.
+    let _ = slot
+        .seq
+        .compare_exchange(2 * n + 1, 2 * n + 2, Ordering::Release, Ordering::Relaxed);
+}
+
+/// Calls `f` with each recorded breadcrumb, from oldest to newest.
+///
+/// This is safe to call from signal handlers if `f` is.
+pub fn breadcrumbs<F: FnMut(&str)>(mut f: F) {
+    let end = RING.next.load(Ordering::Acquire);
+    let start = end.saturating_sub(BREADCRUMB_CAPACITY);
+
+    for n in start..end {
+        let slot = &RING.slots[n % BREADCRUMB_CAPACITY];
+        let seq = slot.seq.load(Ordering::Acquire);
+        if seq != 2 * n + 2 {
+            continue;
+        }
+
+        let len = slot.len.load(Ordering::Relaxed).min(BREADCRUMB_MAX_LEN);
+        let mut bytes = [0; BREADCRUMB_MAX_LEN];
+        for (value, byte) in bytes.iter_mut().zip(&slot.bytes) {
+            *value = byte.load(Ordering::Relaxed);
+        }
+
+        atomic::fence(Ordering::Acquire);
+        if slot.seq.load(Ordering::Relaxed) != seq {
+            continue;
+        }
+        if let Ok(message) = str::from_utf8(&bytes[..len]) {
+            f(message);
+        }
+    }
+}
+
+/// Removes all recorded breadcrumbs.
+pub fn clear_breadcrumbs() {
+    for slot in &RING.slots {
+        slot.seq.store(0, Ordering::Release);
+    }
+}
+
+/// Writes each recorded breadcrumb to the file descriptor `fd` on its own
+/// line, such as to standard error from a signal handler.
+///
+/// This is safe to call from signal handlers.
+pub fn write_breadcrumbs(fd: c_int) {
+    breadcrumbs(|message| unsafe {
+        sys::write(fd, message.as_ptr().cast(), message.len());
+        sys::write(fd, b"\n".as_ptr().cast(), 1);
+    });
+}
+
+/// The log that crashes are reported to, set by [`install`].
+static LOG: AtomicPtr<OSLog> = AtomicPtr::new(ptr::null_mut());
+
+/// Reports panics and uncaught Objective-C exceptions to `log` as faults.
+///
+/// Panics are reported before the previous panic hook runs, and exceptions
+/// before the previous uncaught exception handler, so existing handling is
+/// kept. Calling this again only changes the log, and the previous log is
+/// never released, since a crash may be reporting to it.
+///
+/// Uncaught exceptions are only reported with the **`foundation`** feature
+/// flag.
+pub fn install(log: Arc<OSLog>) {
+    static INSTALL: Once = Once::new();
+
+    LOG.store(Arc::into_raw(log) as *mut OSLog, Ordering::Release);
+
+    INSTALL.call_once(|| {
+        let previous_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            let payload = info.payload();
+            let message = match payload.downcast_ref::<&str>() {
+                Some(message) => message,
+                None => match payload.downcast_ref::<String>() {
+                    Some(message) => message.as_str(),
+                    None => "Box<dyn Any>",
+                },
+            };
+
+            let mut report = String::from("panicked");
+            if let Some(location) = info.location() {
+                let _ = write!(report, " at {}", location);
+            }
+            let _ = write!(report, ": {}", message);
+            write_backtrace(&mut report);
+
+            report_fault(&report);
+            previous_hook(info);
+        }));
+
+        #[cfg(feature = "foundation")]
+        install_exception_handler();
+    });
+}
+
+/// Records `message` as a fault in the log given to [`install`], or the
+/// [global log](OSLog::global) otherwise, followed by the recorded
+/// breadcrumbs.
+///
+/// This allocates, so it must not be called from signal handlers.
+pub fn report_fault(message: &str) {
+    let mut report = String::from(message);
+
+    let mut has_breadcrumbs = false;
+    breadcrumbs(|breadcrumb| {
+        if !has_breadcrumbs {
+            report.push_str("\n\nBreadcrumbs:");
+            has_breadcrumbs = true;
+        }
+        let _ = write!(report, "\n  {}", breadcrumb);
+    });
+
+    let log = match unsafe { LOG.load(Ordering::Acquire).as_ref() } {
+        Some(log) => log,
+        None => OSLog::global(),
+    };
+    log.fault(&report);
+}
+
+/// Appends the symbolicated frames of the current thread to `report`.
+fn write_backtrace(report: &mut String) {
+    let mut frames = [ptr::null_mut::<c_void>(); MAX_FRAMES];
+    unsafe {
+        let count = sys::backtrace(frames.as_mut_ptr(), MAX_FRAMES as c_int);
+        if count <= 0 {
+            return;
+        }
+
+        let symbols = sys::backtrace_symbols(frames.as_ptr(), count);
+        if symbols.is_null() {
+            return;
+        }
+
+        report.push_str("\n\nBacktrace:");
+        for i in 0..count as usize {
+            let symbol = CStr::from_ptr(*symbols.add(i));
+            let _ = write!(report, "\n  {}", symbol.to_string_lossy());
+        }
+        sys::free(symbols.cast());
+    }
+}
+
+/// The uncaught exception handler that was installed before ours.
+#[cfg(feature = "foundation")]
+static PREVIOUS_EXCEPTION_HANDLER: AtomicUsize = AtomicUsize::new(0);
+
+#[cfg(feature = "foundation")]
+fn install_exception_handler() {
+    if let Some(previous) = NSGetUncaughtExceptionHandler() {
+        PREVIOUS_EXCEPTION_HANDLER.store(previous as usize, Ordering::Release);
+    }
+    NSSetUncaughtExceptionHandler(Some(handle_exception));
+}
+
+#[cfg(feature = "foundation")]
+unsafe extern "C" fn handle_exception(exception: &NSException) {
+    let mut report = format!("uncaught exception {}", exception.name());
+    if let Some(reason) = exception.reason() {
+        let _ = write!(report, ": {}", reason);
+    }
+    if let Some(symbols) = exception.call_stack_symbols() {
+        report.push_str("\n\nBacktrace:");
+        for symbol in symbols.iter() {
+            let _ = write!(report, "\n  {}", symbol);
+        }
+    }
+    report_fault(&report);
+
+    let previous = PREVIOUS_EXCEPTION_HANDLER.load(Ordering::Acquire);
+    if previous != 0 {
+        let previous: crate::foundation::NSUncaughtExceptionHandler = std::mem::transmute(previous);
+        previous(exception);
+    }
+}
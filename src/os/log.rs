@@ -0,0 +1,240 @@
+use super::{activity::dso_handle, sys};
+use crate::core::{Arc, ObjectType};
+use std::{
+    cell::UnsafeCell,
+    ffi::{CStr, CString},
+    fmt, mem,
+    os::raw::c_char,
+    ptr::NonNull,
+};
+
+/// A destination for messages in the unified logging system, identified by a
+/// subsystem and category.
+///
+/// Messages can be viewed in Console or with `log stream`, and filtered by
+/// subsystem and category, such as with
+/// `log stream --predicate 'subsystem == "com.example.app"'`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use fruity::os::OSLog;
+/// use std::ffi::CStr;
+///
+/// let subsystem = CStr::from_bytes_with_nul(b"com.example.app\0").unwrap();
+/// let category = CStr::from_bytes_with_nul(b"network\0").unwrap();
+///
+/// let log = OSLog::new(subsystem, category);
+/// log.info("connected");
+/// log.error(&format!("request failed with status {}", 500));
+/// ```
+///
+/// See [documentation](https://developer.apple.com/documentation/os/logging).
+#[repr(C)]
+#[doc(alias = "os_log_t")]
+pub struct OSLog {
+    // Stores data that may be mutated behind a shared reference. Internal
+    // mutability triggers undefined behavior without `UnsafeCell`.
+    _data: UnsafeCell<[u8; 0]>,
+}
+
+impl ObjectType for OSLog {
+    #[inline]
+    #[doc(alias = "os_retain")]
+    fn retain(obj: &Self) -> Arc<Self> {
+        unsafe {
+            sys::os_retain((obj as *const Self).cast());
+            Arc::from_raw(obj)
+        }
+    }
+
+    #[inline]
+    #[doc(alias = "os_release")]
+    unsafe fn release(obj: NonNull<Self>) {
+        sys::os_release(obj.as_ptr().cast());
+    }
+}
+
+unsafe impl Send for OSLog {}
+unsafe impl Sync for OSLog {}
+
+impl fmt::Debug for OSLog {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("OSLog").finish()
+    }
+}
+
+impl OSLog {
+    /// Creates a log for `category` within `subsystem`, which is usually a
+    /// reverse DNS identifier such as `com.example.app`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/os/os_log_create).
+    #[inline]
+    #[doc(alias = "os_log_create")]
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(subsystem: &CStr, category: &CStr) -> Arc<Self> {
+        unsafe { Arc::from_raw(sys::os_log_create(subsystem.as_ptr(), category.as_ptr())) }
+    }
+
+    /// Returns the log without a subsystem or category.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/os/os_log_default).
+    #[inline]
+    #[doc(alias = "OS_LOG_DEFAULT")]
+    pub fn global() -> &'static Self {
+        unsafe { &sys::_os_log_default }
+    }
+
+    /// Returns a log that discards all messages.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/os/os_log_disabled).
+    #[inline]
+    #[doc(alias = "OS_LOG_DISABLED")]
+    pub fn disabled() -> &'static Self {
+        unsafe { &sys::_os_log_disabled }
+    }
+
+    /// Returns `true` if messages of `kind` are recorded, based on the
+    /// system's logging configuration.
+    ///
+    /// This can be used to skip building expensive messages.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/os/os_log_type_enabled).
+    #[inline]
+    #[doc(alias = "os_log_type_enabled")]
+    pub fn is_enabled(&self, kind: OSLogType) -> bool {
+        unsafe { sys::os_log_type_enabled(self, kind) }
+    }
+
+    /// Records `message` as `kind`.
+    ///
+    /// The message is public, so it is visible in logs collected from other
+    /// machines. Interior null bytes are replaced with U+FFFD.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/os/os_log_with_type).
+    #[inline]
+    #[doc(alias = "os_log_with_type")]
+    #[doc(alias = "_os_log_impl")]
+    pub fn log(&self, kind: OSLogType, message: &str) {
+        if !self.is_enabled(kind) {
+            return;
+        }
+        with_public_message(message, |format, buf| unsafe {
+            sys::_os_log_impl(
+                dso_handle(),
+                self,
+                kind,
+                format,
+                buf.as_ptr(),
+                buf.len() as u32,
+            )
+        });
+    }
+
+    /// Records `message` for debugging during development.
+    ///
+    /// These messages are only kept in memory, and only when enabled.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/os/os_log_debug).
+    #[inline]
+    #[doc(alias = "os_log_debug")]
+    pub fn debug(&self, message: &str) {
+        self.log(OSLogType::DEBUG, message);
+    }
+
+    /// Records `message` as information that may help with troubleshooting.
+    ///
+    /// These messages are kept in memory, and only persisted when a fault
+    /// or error occurs.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/os/os_log_info).
+    #[inline]
+    #[doc(alias = "os_log_info")]
+    pub fn info(&self, message: &str) {
+        self.log(OSLogType::INFO, message);
+    }
+
+    /// Records `message` as an error that occurred within the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/os/os_log_error).
+    #[inline]
+    #[doc(alias = "os_log_error")]
+    pub fn error(&self, message: &str) {
+        self.log(OSLogType::ERROR, message);
+    }
+
+    /// Records `message` as a fault, which is a bug in the system or in
+    /// multiple processes.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/os/os_log_fault).
+    #[inline]
+    #[doc(alias = "os_log_fault")]
+    pub fn fault(&self, message: &str) {
+        self.log(OSLogType::FAULT, message);
+    }
+}
+
+/// The level of a message recorded by an [`OSLog`], which determines whether
+/// it is stored and for how long.
+///
+/// See [documentation](https://developer.apple.com/documentation/os/os_log_type_t).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[doc(alias = "os_log_type_t")]
+pub struct OSLogType(pub u8);
+
+impl OSLogType {
+    /// Messages that are persisted to disk by default.
+    #[doc(alias = "OS_LOG_TYPE_DEFAULT")]
+    pub const DEFAULT: Self = Self(0x00);
+
+    /// Messages that may help with troubleshooting.
+    #[doc(alias = "OS_LOG_TYPE_INFO")]
+    pub const INFO: Self = Self(0x01);
+
+    /// Messages for debugging during development.
+    #[doc(alias = "OS_LOG_TYPE_DEBUG")]
+    pub const DEBUG: Self = Self(0x02);
+
+    /// Errors that occurred within the process.
+    #[doc(alias = "OS_LOG_TYPE_ERROR")]
+    pub const ERROR: Self = Self(0x10);
+
+    /// Bugs in the system or in multiple processes.
+    #[doc(alias = "OS_LOG_TYPE_FAULT")]
+    pub const FAULT: Self = Self(0x11);
+}
+
+/// The format of every message, which takes a single public C string.
+///
+/// Formats are stored as an offset into the binary rather than copied, so
+/// this must be a static in this crate.
+static PUBLIC_STRING_FORMAT: [u8; 11] = *b"%{public}s\0";
+
+/// An empty format for signposts without a message.
+pub(super) static EMPTY_FORMAT: [u8; 1] = *b"\0";
+
+/// Calls `f` with a format and argument buffer for `message`, in the
+/// encoding that `os_log` and `os_signpost` macros generate at compile time.
+pub(super) fn with_public_message<T>(
+    message: &str,
+    f: impl FnOnce(*const c_char, &[u8]) -> T,
+) -> T {
+    let message = match CString::new(message) {
+        Ok(message) => message,
+        Err(_) => CString::new(message.replace('\0', "\u{FFFD}")).unwrap(),
+    };
+
+    const PTR_SIZE: usize = mem::size_of::<*const c_char>();
+
+    // Summary, argument count, then the argument's descriptor, size, and
+    // value. The summary and descriptor mark the argument as a public string.
+    let mut buf = [0u8; 4 + PTR_SIZE];
+    buf[0] = 0x02;
+    buf[1] = 1;
+    buf[2] = 0x22;
+    buf[3] = PTR_SIZE as u8;
+    buf[4..].copy_from_slice(&(message.as_ptr() as usize).to_ne_bytes());
+
+    f(PUBLIC_STRING_FORMAT.as_ptr().cast(), &buf)
+}
@@ -0,0 +1,133 @@
+use super::{OSLog, OSLogType};
+use crate::core::Arc;
+use ::log::{Level, LevelFilter, Log, Metadata, Record, SetLoggerError};
+use std::{
+    collections::HashMap,
+    ffi::{CStr, CString},
+    sync::Mutex,
+};
+
+/// A [`log::Log`](https://docs.rs/log/0.4/log/trait.Log.html) implementation
+/// that records messages in the unified logging system.
+///
+/// Each message is recorded in an [`OSLog`] for `subsystem` whose category is
+/// the record's target, which defaults to the module path.
+///
+/// Levels map to [`OSLogType`]s as follows:
+///
+/// | `log::Level` | `OSLogType` |
+/// | ------------ | ----------- |
+/// | `Error`      | `ERROR`     |
+/// | `Warn`       | `DEFAULT`   |
+/// | `Info`       | `INFO`      |
+/// | `Debug`      | `DEBUG`     |
+/// | `Trace`      | `DEBUG`     |
+///
+/// # Examples
+///
+/// ```no_run
+/// use fruity::os::OSLogger;
+/// use std::ffi::CStr;
+///
+/// let subsystem = CStr::from_bytes_with_nul(b"com.example.app\0").unwrap();
+/// OSLogger::new(subsystem)
+///     .level_filter(log::LevelFilter::Info)
+///     .init()
+///     .unwrap();
+///
+/// log::info!("started");
+/// ```
+#[derive(Debug)]
+pub struct OSLogger {
+    subsystem: CString,
+    level_filter: LevelFilter,
+    logs: Mutex<HashMap<String, Arc<OSLog>>>,
+}
+
+impl OSLogger {
+    /// Creates a logger for `subsystem`, which is usually a reverse DNS
+    /// identifier such as `com.example.app`.
+    ///
+    /// All levels are enabled. The system's logging configuration still
+    /// decides which messages are stored.
+    #[inline]
+    pub fn new(subsystem: &CStr) -> Self {
+        Self {
+            subsystem: subsystem.to_owned(),
+            level_filter: LevelFilter::Trace,
+            logs: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Discards messages less severe than `level_filter`.
+    #[inline]
+    pub fn level_filter(mut self, level_filter: LevelFilter) -> Self {
+        self.level_filter = level_filter;
+        self
+    }
+
+    /// Sets `self` as the global logger and sets the maximum level of the
+    /// `log` crate to match.
+    pub fn init(self) -> Result<(), SetLoggerError> {
+        let level_filter = self.level_filter;
+        ::log::set_boxed_logger(Box::new(self))?;
+        ::log::set_max_level(level_filter);
+        Ok(())
+    }
+
+    /// Returns the log for `target`, creating it on first use.
+    fn log_for(&self, target: &str) -> Arc<OSLog> {
+        let mut logs = match self.logs.lock() {
+            Ok(logs) => logs,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        if let Some(log) = logs.get(target) {
+            return log.clone();
+        }
+
+        let category = CString::new(target.replace('\0', "")).unwrap();
+        let log = OSLog::new(&self.subsystem, &category);
+        logs.insert(target.to_owned(), log.clone());
+        log
+    }
+}
+
+#[inline]
+fn log_type(level: Level) -> OSLogType {
+    match level {
+        Level::Error => OSLogType::ERROR,
+        Level::Warn => OSLogType::DEFAULT,
+        Level::Info => OSLogType::INFO,
+        Level::Debug | Level::Trace => OSLogType::DEBUG,
+    }
+}
+
+impl Log for OSLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= self.level_filter
+            && self
+                .log_for(metadata.target())
+                .is_enabled(log_type(metadata.level()))
+    }
+
+    fn log(&self, record: &Record) {
+        if record.level() > self.level_filter {
+            return;
+        }
+
+        let kind = log_type(record.level());
+        let log = self.log_for(record.target());
+
+        if !log.is_enabled(kind) {
+            return;
+        }
+
+        match record.args().as_str() {
+            Some(message) => log.log(kind, message),
+            None => log.log(kind, &record.args().to_string()),
+        }
+    }
+
+    #[inline]
+    fn flush(&self) {}
+}
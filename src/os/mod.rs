@@ -0,0 +1,31 @@
+//! [OS](https://developer.apple.com/documentation/os) library for
+//! low-level synchronization and diagnostics.
+//!
+//! # Feature Flag
+//!
+//! This module corresponds to the **`os`**
+//! [feature flag](../index.html#feature-flags).
+//!
+//! Enabling the **`log`** feature flag provides [`OSLogger`], which routes
+//! the [`log`](https://docs.rs/log/0.4) crate's macros into unified logging.
+
+#![cfg(feature = "os")]
+
+pub mod crash;
+pub mod sys;
+
+mod activity;
+mod log;
+mod signpost;
+mod unfair_lock;
+
+pub use self::log::*;
+pub use activity::*;
+pub use signpost::*;
+pub use unfair_lock::*;
+
+#[cfg(feature = "log")]
+mod logger;
+
+#[cfg(feature = "log")]
+pub use logger::*;
@@ -0,0 +1,166 @@
+use super::{
+    activity::dso_handle,
+    log::{with_public_message, EMPTY_FORMAT},
+    sys, OSLog,
+};
+use std::{ffi::CStr, os::raw::c_void};
+
+/// Identifies a signpost interval, so that its beginning and end can be
+/// matched in Instruments.
+///
+/// See [documentation](https://developer.apple.com/documentation/os/os_signpost_id_t).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[doc(alias = "os_signpost_id_t")]
+pub struct OSSignpostID(pub u64);
+
+impl OSSignpostID {
+    /// An ID that is not valid for any signpost.
+    #[doc(alias = "OS_SIGNPOST_ID_INVALID")]
+    pub const INVALID: Self = Self(!0);
+
+    /// An ID for signposts that are not associated with an interval.
+    #[doc(alias = "OS_SIGNPOST_ID_NULL")]
+    pub const NULL: Self = Self(0);
+
+    /// An ID for intervals that never overlap with other intervals of the
+    /// same name, so that a new ID is not needed for each one.
+    #[doc(alias = "OS_SIGNPOST_ID_EXCLUSIVE")]
+    pub const EXCLUSIVE: Self = Self(0xEEEE_B0B5_B2B2_EEEE);
+
+    /// Returns a new ID that is unique within `log`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/os/os_signpost_id_generate).
+    #[inline]
+    #[doc(alias = "os_signpost_id_generate")]
+    pub fn generate(log: &OSLog) -> Self {
+        unsafe { sys::os_signpost_id_generate(log) }
+    }
+
+    /// Returns the ID for `ptr` within `log`, so that intervals about the
+    /// same object can be matched without storing an ID.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/os/os_signpost_id_make_with_pointer).
+    #[inline]
+    #[doc(alias = "os_signpost_id_make_with_pointer")]
+    pub fn from_pointer<T: ?Sized>(log: &OSLog, ptr: *const T) -> Self {
+        unsafe { sys::os_signpost_id_make_with_pointer(log, ptr as *const c_void) }
+    }
+}
+
+/// Signposts, which mark points and intervals of time for profiling with the
+/// Points of Interest and os_signpost instruments.
+///
+/// Signpost names should be string literals. Like
+/// [`OSActivity`](super::OSActivity) descriptions, they are stored as an
+/// offset into the binary rather than copied, so they must be part of the
+/// same binary as this crate.
+impl OSLog {
+    /// Returns `true` if signposts emitted to `self` are recorded, such as
+    /// when Instruments is attached.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/os/os_signpost_enabled).
+    #[inline]
+    #[doc(alias = "os_signpost_enabled")]
+    pub fn signposts_enabled(&self) -> bool {
+        unsafe { sys::os_signpost_enabled(self) }
+    }
+
+    /// Marks a single point in time called `name`, described by `message`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/os/os_signpost_event_emit).
+    #[inline]
+    #[doc(alias = "os_signpost_event_emit")]
+    pub fn signpost_event(&self, id: OSSignpostID, name: &'static CStr, message: &str) {
+        self.emit_signpost(sys::OS_SIGNPOST_EVENT, id, name, Some(message));
+    }
+
+    /// Marks the beginning of an interval called `name`, which ends when the
+    /// returned value is dropped.
+    ///
+    /// Overlapping intervals with the same name need different IDs.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/os/os_signpost_interval_begin).
+    #[inline]
+    #[doc(alias = "os_signpost_interval_begin")]
+    pub fn signpost_interval(
+        &self,
+        id: OSSignpostID,
+        name: &'static CStr,
+        message: &str,
+    ) -> OSSignpostInterval<'_> {
+        self.emit_signpost(sys::OS_SIGNPOST_INTERVAL_BEGIN, id, name, Some(message));
+        OSSignpostInterval {
+            log: self,
+            id,
+            name,
+        }
+    }
+
+    fn emit_signpost(
+        &self,
+        kind: sys::os_signpost_type_t,
+        id: OSSignpostID,
+        name: &'static CStr,
+        message: Option<&str>,
+    ) {
+        if !self.signposts_enabled() {
+            return;
+        }
+
+        let emit = |format, buf: &[u8]| unsafe {
+            sys::_os_signpost_emit_with_name_impl(
+                dso_handle(),
+                self,
+                kind,
+                id,
+                name.as_ptr(),
+                format,
+                buf.as_ptr(),
+                buf.len() as u32,
+            )
+        };
+
+        match message {
+            Some(message) if !message.is_empty() => with_public_message(message, emit),
+            // No summary flags and no arguments.
+            _ => emit(EMPTY_FORMAT.as_ptr().cast(), &[0, 0]),
+        }
+    }
+}
+
+/// A signpost interval that ends when dropped.
+///
+/// Created by [`OSLog::signpost_interval`].
+#[must_use = "The interval immediately ends if unused"]
+#[derive(Debug)]
+pub struct OSSignpostInterval<'a> {
+    log: &'a OSLog,
+    id: OSSignpostID,
+    name: &'static CStr,
+}
+
+impl Drop for OSSignpostInterval<'_> {
+    #[inline]
+    #[doc(alias = "os_signpost_interval_end")]
+    fn drop(&mut self) {
+        self.log
+            .emit_signpost(sys::OS_SIGNPOST_INTERVAL_END, self.id, self.name, None);
+    }
+}
+
+impl OSSignpostInterval<'_> {
+    /// Ends the interval with `message`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/os/os_signpost_interval_end).
+    #[inline]
+    pub fn end_with(self, message: &str) {
+        let this = std::mem::ManuallyDrop::new(self);
+        this.log.emit_signpost(
+            sys::OS_SIGNPOST_INTERVAL_END,
+            this.id,
+            this.name,
+            Some(message),
+        );
+    }
+}
@@ -0,0 +1,92 @@
+//! Raw unsafe C functions exposed by libsystem_platform, libsystem_trace, and
+//! libsystem_c.
+
+use super::{OSActivity, OSActivityFlags, OSLog, OSLogType, OSSignpostID};
+use std::os::raw::{c_char, c_int, c_void};
+
+/// The storage of an `os_unfair_lock`.
+#[allow(non_camel_case_types)]
+pub type os_unfair_lock = u32;
+
+/// The state saved by `os_activity_scope_enter` and restored by
+/// `os_activity_scope_leave`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(non_camel_case_types)]
+pub struct os_activity_scope_state_s {
+    #[allow(missing_docs)]
+    pub opaque: [u64; 2],
+}
+
+/// The kind of signpost emitted by `_os_signpost_emit_with_name_impl`.
+#[allow(non_camel_case_types)]
+pub type os_signpost_type_t = u8;
+
+#[allow(non_upper_case_globals, missing_docs)]
+pub const OS_SIGNPOST_EVENT: os_signpost_type_t = 0x00;
+#[allow(non_upper_case_globals, missing_docs)]
+pub const OS_SIGNPOST_INTERVAL_BEGIN: os_signpost_type_t = 0x01;
+#[allow(non_upper_case_globals, missing_docs)]
+pub const OS_SIGNPOST_INTERVAL_END: os_signpost_type_t = 0x02;
+
+// These are reexported by libSystem.
+#[link(name = "System", kind = "dylib")]
+#[allow(missing_docs)]
+extern "C" {
+    pub fn os_unfair_lock_lock(lock: *mut os_unfair_lock);
+    pub fn os_unfair_lock_trylock(lock: *mut os_unfair_lock) -> bool;
+    pub fn os_unfair_lock_unlock(lock: *mut os_unfair_lock);
+    pub fn os_unfair_lock_assert_owner(lock: *const os_unfair_lock);
+    pub fn os_unfair_lock_assert_not_owner(lock: *const os_unfair_lock);
+
+    pub static _os_activity_current: OSActivity;
+
+    pub fn _os_activity_create(
+        dso: *const c_void,
+        description: *const c_char,
+        parent: *const OSActivity,
+        flags: OSActivityFlags,
+    ) -> *const OSActivity;
+    pub fn os_activity_get_identifier(activity: *const OSActivity, parent_id: *mut u64) -> u64;
+    pub fn os_activity_scope_enter(
+        activity: *const OSActivity,
+        state: *mut os_activity_scope_state_s,
+    );
+    pub fn os_activity_scope_leave(state: *mut os_activity_scope_state_s);
+
+    pub static _os_log_default: OSLog;
+    pub static _os_log_disabled: OSLog;
+
+    pub fn os_log_create(subsystem: *const c_char, category: *const c_char) -> *const OSLog;
+    pub fn os_log_type_enabled(log: *const OSLog, kind: OSLogType) -> bool;
+    pub fn _os_log_impl(
+        dso: *const c_void,
+        log: *const OSLog,
+        kind: OSLogType,
+        format: *const c_char,
+        buf: *const u8,
+        size: u32,
+    );
+
+    pub fn os_signpost_enabled(log: *const OSLog) -> bool;
+    pub fn os_signpost_id_generate(log: *const OSLog) -> OSSignpostID;
+    pub fn os_signpost_id_make_with_pointer(log: *const OSLog, ptr: *const c_void) -> OSSignpostID;
+    pub fn _os_signpost_emit_with_name_impl(
+        dso: *const c_void,
+        log: *const OSLog,
+        kind: os_signpost_type_t,
+        spid: OSSignpostID,
+        name: *const c_char,
+        format: *const c_char,
+        buf: *const u8,
+        size: u32,
+    );
+
+    pub fn os_retain(obj: *const c_void) -> *const c_void;
+    pub fn os_release(obj: *const c_void);
+
+    pub fn backtrace(array: *mut *mut c_void, size: c_int) -> c_int;
+    pub fn backtrace_symbols(array: *const *mut c_void, size: c_int) -> *mut *mut c_char;
+    pub fn free(ptr: *mut c_void);
+    pub fn write(fd: c_int, buf: *const c_void, nbyte: usize) -> isize;
+}
@@ -0,0 +1,192 @@
+use super::sys;
+use std::{
+    cell::UnsafeCell,
+    fmt,
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+};
+
+/// A mutual exclusion lock backed by `os_unfair_lock`, the lowest-level lock
+/// on Apple platforms.
+///
+/// Unlike spinlocks and semaphores, the lock records which thread owns it.
+/// When a higher-priority thread waits, the kernel donates its priority to
+/// the owner so that the owner is not starved by medium-priority work. This
+/// avoids priority inversion, which is why the lock must be unlocked by the
+/// thread that locked it and why [`OSUnfairLockGuard`] is not [`Send`].
+///
+/// The lock is "unfair" in that a thread that unlocks and immediately locks
+/// again may reacquire it before a waiting thread is woken.
+///
+/// Unlike [`std::sync::Mutex`], the lock is not poisoned if a thread panics
+/// while holding it.
+///
+/// See [documentation](https://developer.apple.com/documentation/os/os_unfair_lock).
+#[doc(alias = "os_unfair_lock")]
+#[doc(alias = "os_unfair_lock_t")]
+pub struct OSUnfairLock<T: ?Sized> {
+    lock: UnsafeCell<sys::os_unfair_lock>,
+    data: UnsafeCell<T>,
+}
+
+unsafe impl<T: ?Sized + Send> Send for OSUnfairLock<T> {}
+unsafe impl<T: ?Sized + Send> Sync for OSUnfairLock<T> {}
+
+impl<T: Default> Default for OSUnfairLock<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T> From<T> for OSUnfairLock<T> {
+    #[inline]
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T: ?Sized + fmt::Debug> fmt::Debug for OSUnfairLock<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut debug = f.debug_struct("OSUnfairLock");
+        match self.try_lock() {
+            Some(guard) => debug.field("data", &&*guard),
+            None => debug.field("data", &format_args!("<locked>")),
+        };
+        debug.finish()
+    }
+}
+
+impl<T> OSUnfairLock<T> {
+    /// Creates an unlocked lock around `value`.
+    #[inline]
+    #[doc(alias = "OS_UNFAIR_LOCK_INIT")]
+    pub const fn new(value: T) -> Self {
+        Self {
+            lock: UnsafeCell::new(0),
+            data: UnsafeCell::new(value),
+        }
+    }
+
+    /// Consumes the lock, returning the protected value.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.data.into_inner()
+    }
+}
+
+impl<T: ?Sized> OSUnfairLock<T> {
+    /// Waits until the lock is available and then locks it.
+    ///
+    /// # Aborts
+    ///
+    /// Locking a lock that is already owned by the current thread crashes the
+    /// process rather than deadlocking.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/os/os_unfair_lock_lock).
+    #[inline]
+    #[doc(alias = "os_unfair_lock_lock")]
+    pub fn lock(&self) -> OSUnfairLockGuard<'_, T> {
+        unsafe { sys::os_unfair_lock_lock(self.lock.get()) };
+        OSUnfairLockGuard::new(self)
+    }
+
+    /// Locks the lock if it is available, or returns [`None`] without
+    /// waiting.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/os/os_unfair_lock_trylock).
+    #[inline]
+    #[doc(alias = "os_unfair_lock_trylock")]
+    pub fn try_lock(&self) -> Option<OSUnfairLockGuard<'_, T>> {
+        if unsafe { sys::os_unfair_lock_trylock(self.lock.get()) } {
+            Some(OSUnfairLockGuard::new(self))
+        } else {
+            None
+        }
+    }
+
+    /// Returns a mutable reference to the protected value without locking,
+    /// since `self` is borrowed exclusively.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut T {
+        self.data.get_mut()
+    }
+
+    /// Crashes the process if the current thread does not own the lock.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/os/os_unfair_lock_assert_owner).
+    #[inline]
+    #[doc(alias = "os_unfair_lock_assert_owner")]
+    pub fn assert_owner(&self) {
+        unsafe { sys::os_unfair_lock_assert_owner(self.lock.get()) }
+    }
+
+    /// Crashes the process if the current thread owns the lock.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/os/os_unfair_lock_assert_not_owner).
+    #[inline]
+    #[doc(alias = "os_unfair_lock_assert_not_owner")]
+    pub fn assert_not_owner(&self) {
+        unsafe { sys::os_unfair_lock_assert_not_owner(self.lock.get()) }
+    }
+}
+
+/// Exclusive access to the value of an [`OSUnfairLock`], which is unlocked
+/// when dropped.
+#[must_use = "The lock is immediately unlocked if the guard is unused"]
+pub struct OSUnfairLockGuard<'a, T: ?Sized> {
+    lock: &'a OSUnfairLock<T>,
+
+    // The lock must be unlocked by the thread that locked it.
+    _not_send: PhantomData<*mut ()>,
+}
+
+unsafe impl<T: ?Sized + Sync> Sync for OSUnfairLockGuard<'_, T> {}
+
+impl<'a, T: ?Sized> OSUnfairLockGuard<'a, T> {
+    #[inline]
+    fn new(lock: &'a OSUnfairLock<T>) -> Self {
+        Self {
+            lock,
+            _not_send: PhantomData,
+        }
+    }
+}
+
+impl<T: ?Sized> Drop for OSUnfairLockGuard<'_, T> {
+    #[inline]
+    #[doc(alias = "os_unfair_lock_unlock")]
+    fn drop(&mut self) {
+        unsafe { sys::os_unfair_lock_unlock(self.lock.lock.get()) }
+    }
+}
+
+impl<T: ?Sized> Deref for OSUnfairLockGuard<'_, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        unsafe { &*self.lock.data.get() }
+    }
+}
+
+impl<T: ?Sized> DerefMut for OSUnfairLockGuard<'_, T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.lock.data.get() }
+    }
+}
+
+impl<T: ?Sized + fmt::Debug> fmt::Debug for OSUnfairLockGuard<'_, T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+impl<T: ?Sized + fmt::Display> fmt::Display for OSUnfairLockGuard<'_, T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
@@ -0,0 +1,60 @@
+//! [Security](https://developer.apple.com/documentation/security) framework.
+//!
+//! # Feature Flag
+//!
+//! This module corresponds to the **`security`**
+//! [feature flag](../index.html#feature-flags).
+//!
+//! It also transitively enables [`core_foundation`](crate::core_foundation).
+//!
+//! # Documentation
+//!
+//! - [Keychain Services](https://developer.apple.com/documentation/security/keychain_services)
+//! - [Certificate, Key, and Trust Services](https://developer.apple.com/documentation/security/certificate_key_and_trust_services)
+//! - [Code Signing Services](https://developer.apple.com/documentation/security/code_signing_services)
+//! - [Using the Keychain to Manage User Secrets](https://developer.apple.com/documentation/security/keychain_services/keychain_items/using_the_keychain_to_manage_user_secrets)
+
+#![cfg(feature = "security")]
+
+use crate::core::{Arc, OSStatus};
+use crate::core_foundation::CFString;
+use std::ptr;
+
+pub mod sys;
+#[cfg(target_os = "macos")]
+pub mod translocation;
+
+mod sec_access_control;
+mod sec_certificate;
+#[cfg(target_os = "macos")]
+mod sec_code;
+mod sec_identity;
+mod sec_item;
+mod sec_policy;
+mod sec_trust;
+
+pub use sec_access_control::*;
+pub use sec_certificate::*;
+#[cfg(target_os = "macos")]
+pub use sec_code::*;
+pub use sec_identity::*;
+pub use sec_item::*;
+pub use sec_policy::*;
+pub use sec_trust::*;
+
+/// Returns a description of a Security `status`, or [`None`] if it is not
+/// known.
+///
+/// See [documentation](https://developer.apple.com/documentation/security/seccopyerrormessagestring(_:_:)).
+#[inline]
+#[doc(alias = "SecCopyErrorMessageString")]
+pub fn error_message(status: OSStatus) -> Option<Arc<CFString>> {
+    unsafe {
+        let message = sys::SecCopyErrorMessageString(status, ptr::null_mut());
+        if message.is_null() {
+            None
+        } else {
+            Some(Arc::from_raw(message))
+        }
+    }
+}
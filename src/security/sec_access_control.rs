@@ -0,0 +1,182 @@
+use super::sys;
+use crate::core::Arc;
+use crate::core_foundation::{CFAllocator, CFError, CFOptionFlags, CFString, CFType, CFTypeID};
+use std::{ops, ptr};
+
+subclass! {
+    /// Conditions for accessing a keychain item, such as requiring the user
+    /// to authenticate.
+    ///
+    /// This is set on items with
+    /// [`SecItemQuery::access_control`](super::SecItemQuery::access_control).
+    ///
+    /// Documentation:
+    /// [Swift](https://developer.apple.com/documentation/security/secaccesscontrol?language=swift) |
+    /// [Objective-C](https://developer.apple.com/documentation/security/secaccesscontrolref?language=objc)
+    #[derive(PartialEq, Hash)]
+    pub class SecAccessControl: CFType<'static>;
+}
+
+impl SecAccessControl {
+    /// Returns the type identifier for `SecAccessControl`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/security/secaccesscontrolgettypeid()).
+    #[inline]
+    #[doc(alias = "SecAccessControlGetTypeID")]
+    pub fn type_id() -> CFTypeID {
+        unsafe { sys::SecAccessControlGetTypeID() }
+    }
+
+    /// Creates access control that makes an item available according to
+    /// `accessible`, with the additional constraints in `flags`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/security/secaccesscontrolcreatewithflags(_:_:_:_:)).
+    #[inline]
+    #[doc(alias = "SecAccessControlCreateWithFlags")]
+    pub fn create(
+        allocator: Option<&CFAllocator>,
+        accessible: SecAccessible,
+        flags: SecAccessControlCreateFlags,
+    ) -> Result<Arc<Self>, Arc<CFError>> {
+        unsafe {
+            let mut error: *const CFError = ptr::null();
+            let access_control = sys::SecAccessControlCreateWithFlags(
+                match allocator {
+                    Some(allocator) => allocator,
+                    None => ptr::null(),
+                },
+                &**accessible.as_cf_string(),
+                flags,
+                &mut error,
+            );
+
+            if access_control.is_null() {
+                Err(Arc::from_raw(error))
+            } else {
+                Ok(Arc::from_raw(access_control))
+            }
+        }
+    }
+}
+
+/// When a keychain item can be read.
+///
+/// Variants ending in `ThisDeviceOnly` are never synchronized to other
+/// devices or restored from backups onto other devices.
+///
+/// See [documentation](https://developer.apple.com/documentation/security/ksecattraccessible).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SecAccessible {
+    /// While the device is unlocked.
+    #[doc(alias = "kSecAttrAccessibleWhenUnlocked")]
+    WhenUnlocked,
+
+    /// After the device is first unlocked after a restart, such as for
+    /// background apps.
+    #[doc(alias = "kSecAttrAccessibleAfterFirstUnlock")]
+    AfterFirstUnlock,
+
+    /// While the device is unlocked, only if it has a passcode set.
+    ///
+    /// Items are deleted if the passcode is removed.
+    #[doc(alias = "kSecAttrAccessibleWhenPasscodeSetThisDeviceOnly")]
+    WhenPasscodeSetThisDeviceOnly,
+
+    /// While the device is unlocked.
+    #[doc(alias = "kSecAttrAccessibleWhenUnlockedThisDeviceOnly")]
+    WhenUnlockedThisDeviceOnly,
+
+    /// After the device is first unlocked after a restart.
+    #[doc(alias = "kSecAttrAccessibleAfterFirstUnlockThisDeviceOnly")]
+    AfterFirstUnlockThisDeviceOnly,
+}
+
+impl SecAccessible {
+    /// Returns the value of the `kSecAttrAccessible` attribute for `self`.
+    #[inline]
+    pub fn as_cf_string(self) -> &'static CFString {
+        unsafe {
+            match self {
+                Self::WhenUnlocked => sys::kSecAttrAccessibleWhenUnlocked,
+                Self::AfterFirstUnlock => sys::kSecAttrAccessibleAfterFirstUnlock,
+                Self::WhenPasscodeSetThisDeviceOnly => {
+                    sys::kSecAttrAccessibleWhenPasscodeSetThisDeviceOnly
+                }
+                Self::WhenUnlockedThisDeviceOnly => {
+                    sys::kSecAttrAccessibleWhenUnlockedThisDeviceOnly
+                }
+                Self::AfterFirstUnlockThisDeviceOnly => {
+                    sys::kSecAttrAccessibleAfterFirstUnlockThisDeviceOnly
+                }
+            }
+        }
+    }
+}
+
+/// Constraints for [`SecAccessControl::create`].
+///
+/// Authentication constraints are combined with [`OR`](Self::OR) or
+/// [`AND`](Self::AND), and default to requiring all of them.
+///
+/// See [documentation](https://developer.apple.com/documentation/security/secaccesscontrolcreateflags).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SecAccessControlCreateFlags(pub CFOptionFlags);
+
+impl ops::BitOr for SecAccessControlCreateFlags {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl SecAccessControlCreateFlags {
+    /// No constraints.
+    pub const NONE: Self = Self(0);
+
+    /// Requires biometry or the device passcode.
+    #[doc(alias = "kSecAccessControlUserPresence")]
+    pub const USER_PRESENCE: Self = Self(1 << 0);
+
+    /// Requires biometry with any enrolled finger or face.
+    #[doc(alias = "kSecAccessControlBiometryAny")]
+    pub const BIOMETRY_ANY: Self = Self(1 << 1);
+
+    /// Requires biometry with the fingers or face enrolled when the item was
+    /// added.
+    #[doc(alias = "kSecAccessControlBiometryCurrentSet")]
+    pub const BIOMETRY_CURRENT_SET: Self = Self(1 << 3);
+
+    /// Requires the device passcode.
+    #[doc(alias = "kSecAccessControlDevicePasscode")]
+    pub const DEVICE_PASSCODE: Self = Self(1 << 4);
+
+    /// Requires a paired watch.
+    #[cfg(target_os = "macos")]
+    #[doc(alias = "kSecAccessControlWatch")]
+    pub const WATCH: Self = Self(1 << 5);
+
+    /// Requires any one of the other constraints.
+    #[doc(alias = "kSecAccessControlOr")]
+    pub const OR: Self = Self(1 << 14);
+
+    /// Requires all of the other constraints.
+    #[doc(alias = "kSecAccessControlAnd")]
+    pub const AND: Self = Self(1 << 15);
+
+    /// Allows using the private key of a key pair for signing.
+    #[doc(alias = "kSecAccessControlPrivateKeyUsage")]
+    pub const PRIVATE_KEY_USAGE: Self = Self(1 << 30);
+
+    /// Requires an app-provided password for encrypting the item.
+    #[doc(alias = "kSecAccessControlApplicationPassword")]
+    pub const APPLICATION_PASSWORD: Self = Self(1 << 31);
+
+    /// Returns `true` if all flags in `other` are set in `self`.
+    #[inline]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
@@ -0,0 +1,125 @@
+use super::sys;
+use crate::core::{Arc, OSStatus};
+use crate::core_foundation::{CFAllocator, CFArray, CFData, CFError, CFString, CFType, CFTypeID};
+use std::ptr;
+
+subclass! {
+    /// An X.509 certificate.
+    ///
+    /// Documentation:
+    /// [Swift](https://developer.apple.com/documentation/security/seccertificate?language=swift) |
+    /// [Objective-C](https://developer.apple.com/documentation/security/seccertificateref?language=objc)
+    #[derive(PartialEq, Hash)]
+    pub class SecCertificate: CFType<'static>;
+}
+
+impl SecCertificate {
+    /// Returns the type identifier for `SecCertificate`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/security/seccertificategettypeid()).
+    #[inline]
+    #[doc(alias = "SecCertificateGetTypeID")]
+    pub fn type_id() -> CFTypeID {
+        unsafe { sys::SecCertificateGetTypeID() }
+    }
+
+    /// Creates a certificate from its DER encoding, or returns [`None`] if
+    /// `data` is not a valid certificate.
+    ///
+    /// PEM-encoded certificates must be decoded from base64 first.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/security/seccertificatecreatewithdata(_:_:)).
+    #[inline]
+    #[doc(alias = "SecCertificateCreateWithData")]
+    pub fn from_der(allocator: Option<&CFAllocator>, data: &CFData) -> Option<Arc<Self>> {
+        unsafe {
+            let certificate = sys::SecCertificateCreateWithData(
+                match allocator {
+                    Some(allocator) => allocator,
+                    None => ptr::null(),
+                },
+                data,
+            );
+            if certificate.is_null() {
+                None
+            } else {
+                Some(Arc::from_raw(certificate))
+            }
+        }
+    }
+
+    /// Returns the DER encoding of `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/security/seccertificatecopydata(_:)).
+    #[inline]
+    #[doc(alias = "SecCertificateCopyData")]
+    pub fn der_data(&self) -> Arc<CFData> {
+        unsafe { Arc::from_raw(sys::SecCertificateCopyData(self)) }
+    }
+
+    /// Returns a human-readable summary of the subject, such as its common
+    /// name.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/security/seccertificatecopysubjectsummary(_:)).
+    #[inline]
+    #[doc(alias = "SecCertificateCopySubjectSummary")]
+    pub fn subject_summary(&self) -> Option<Arc<CFString>> {
+        unsafe {
+            let summary = sys::SecCertificateCopySubjectSummary(self);
+            if summary.is_null() {
+                None
+            } else {
+                Some(Arc::from_raw(summary))
+            }
+        }
+    }
+
+    /// Returns the common name of the subject, if it has one.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/security/seccertificatecopycommonname(_:_:)).
+    #[inline]
+    #[doc(alias = "SecCertificateCopyCommonName")]
+    pub fn common_name(&self) -> Result<Option<Arc<CFString>>, OSStatus> {
+        unsafe {
+            let mut common_name: *const CFString = ptr::null();
+            match sys::SecCertificateCopyCommonName(self, &mut common_name) {
+                Some(error) => Err(error),
+                None if common_name.is_null() => Ok(None),
+                None => Ok(Some(Arc::from_raw(common_name))),
+            }
+        }
+    }
+
+    /// Returns the email addresses of the subject.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/security/seccertificatecopyemailaddresses(_:_:)).
+    #[inline]
+    #[doc(alias = "SecCertificateCopyEmailAddresses")]
+    pub fn email_addresses(&self) -> Result<Arc<CFArray<CFString>>, OSStatus> {
+        unsafe {
+            let mut addresses: *const CFArray = ptr::null();
+            match sys::SecCertificateCopyEmailAddresses(self, &mut addresses) {
+                Some(error) => Err(error),
+                None if addresses.is_null() => Ok(CFArray::from_slice(&[])),
+                None => Ok(Arc::from_raw(addresses.cast())),
+            }
+        }
+    }
+
+    /// Returns the DER-encoded serial number.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/security/seccertificatecopyserialnumberdata(_:_:)).
+    #[inline]
+    #[doc(alias = "SecCertificateCopySerialNumberData")]
+    pub fn serial_number(&self) -> Result<Arc<CFData>, Arc<CFError>> {
+        unsafe {
+            let mut error: *const CFError = ptr::null();
+            let serial_number = sys::SecCertificateCopySerialNumberData(self, &mut error);
+            if serial_number.is_null() {
+                Err(Arc::from_raw(error))
+            } else {
+                Ok(Arc::from_raw(serial_number))
+            }
+        }
+    }
+}
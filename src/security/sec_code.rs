@@ -0,0 +1,556 @@
+use super::{sys, SecCertificate};
+use crate::core::{Arc, OSStatus, ObjectType};
+use crate::core_foundation::{
+    CFArray, CFDate, CFDictionary, CFNumber, CFString, CFType, CFTypeID, CFURL,
+};
+use std::{fmt, ops, ptr};
+
+subclass! {
+    /// Running code, such as the current process.
+    ///
+    /// Documentation:
+    /// [Swift](https://developer.apple.com/documentation/security/seccode?language=swift) |
+    /// [Objective-C](https://developer.apple.com/documentation/security/seccoderef?language=objc)
+    #[derive(PartialEq, Hash)]
+    pub class SecCode: CFType<'static>;
+}
+
+subclass! {
+    /// Code on disk, such as an app bundle or executable.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fruity::core_foundation::CFURL;
+    /// use fruity::security::{SecCSFlags, SecCodeInfoFlags, SecStaticCode};
+    ///
+    /// let url = CFURL::from_path("/Applications/Safari.app", true).unwrap();
+    /// let code = SecStaticCode::from_path(&url, SecCSFlags::DEFAULT).unwrap();
+    ///
+    /// code.check_validity(SecCSFlags::DEFAULT, None).unwrap();
+    ///
+    /// let info = code
+    ///     .signing_information(SecCodeInfoFlags::SIGNING_INFORMATION)
+    ///     .unwrap();
+    /// println!("{:?} by {:?}", info.identifier(), info.team_identifier());
+    /// ```
+    ///
+    /// Documentation:
+    /// [Swift](https://developer.apple.com/documentation/security/secstaticcode?language=swift) |
+    /// [Objective-C](https://developer.apple.com/documentation/security/secstaticcoderef?language=objc)
+    #[derive(PartialEq, Hash)]
+    pub class SecStaticCode: CFType<'static>;
+}
+
+subclass! {
+    /// A condition that signed code must satisfy, written in the
+    /// [code signing requirement language](https://developer.apple.com/library/archive/documentation/Security/Conceptual/CodeSigningGuide/RequirementLang/RequirementLang.html).
+    ///
+    /// Documentation:
+    /// [Swift](https://developer.apple.com/documentation/security/secrequirement?language=swift) |
+    /// [Objective-C](https://developer.apple.com/documentation/security/secrequirementref?language=objc)
+    #[derive(PartialEq, Hash)]
+    pub class SecRequirement: CFType<'static>;
+}
+
+impl SecCode {
+    /// Returns the type identifier for `SecCode`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/security/seccodegettypeid()).
+    #[inline]
+    #[doc(alias = "SecCodeGetTypeID")]
+    pub fn type_id() -> CFTypeID {
+        unsafe { sys::SecCodeGetTypeID() }
+    }
+
+    /// Returns the code of the current process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/security/seccodecopyself(_:_:)).
+    #[inline]
+    #[doc(alias = "SecCodeCopySelf")]
+    pub fn current() -> Result<Arc<Self>, OSStatus> {
+        unsafe {
+            let mut code: *const Self = ptr::null();
+            match sys::SecCodeCopySelf(SecCSFlags::DEFAULT, &mut code) {
+                Some(error) => Err(error),
+                None => Ok(Arc::from_raw(code)),
+            }
+        }
+    }
+
+    /// Returns the code of the process with ID `pid`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/security/seccodecopyguestwithattributes(_:_:_:_:)).
+    #[doc(alias = "SecCodeCopyGuestWithAttributes")]
+    #[doc(alias = "kSecGuestAttributePid")]
+    pub fn with_pid(pid: i32) -> Result<Arc<Self>, OSStatus> {
+        let key: &CFString = unsafe { sys::kSecGuestAttributePid };
+        let pid = CFNumber::new(pid);
+        let pid: &CFType<'static> = &pid;
+        let attributes = CFDictionary::create(None, &[key], &[pid]);
+
+        unsafe {
+            let mut code: *const Self = ptr::null();
+            match sys::SecCodeCopyGuestWithAttributes(
+                ptr::null(),
+                attributes.as_raw(),
+                SecCSFlags::DEFAULT,
+                &mut code,
+            ) {
+                Some(error) => Err(error),
+                None => Ok(Arc::from_raw(code)),
+            }
+        }
+    }
+
+    /// Returns the code on disk that `self` was loaded from.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/security/seccodecopystaticcode(_:_:_:)).
+    #[inline]
+    #[doc(alias = "SecCodeCopyStaticCode")]
+    pub fn static_code(&self) -> Result<Arc<SecStaticCode>, OSStatus> {
+        unsafe {
+            let mut static_code: *const SecStaticCode = ptr::null();
+            match sys::SecCodeCopyStaticCode(self, SecCSFlags::DEFAULT, &mut static_code) {
+                Some(error) => Err(error),
+                None => Ok(Arc::from_raw(static_code)),
+            }
+        }
+    }
+
+    /// Checks that the signature of `self` is valid, and that `self`
+    /// satisfies `requirement` if provided.
+    ///
+    /// This also checks that the running code has not been modified since it
+    /// was loaded.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/security/seccodecheckvalidity(_:_:_:)).
+    #[inline]
+    #[doc(alias = "SecCodeCheckValidity")]
+    pub fn check_validity(
+        &self,
+        flags: SecCSFlags,
+        requirement: Option<&SecRequirement>,
+    ) -> Result<(), OSStatus> {
+        let requirement = match requirement {
+            Some(requirement) => requirement,
+            None => ptr::null(),
+        };
+        match unsafe { sys::SecCodeCheckValidity(self, flags, requirement) } {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns information about the signature of `self`, including its
+    /// dynamic status if `flags` contains
+    /// [`DYNAMIC_INFORMATION`](SecCodeInfoFlags::DYNAMIC_INFORMATION).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/security/seccodecopysigninginformation(_:_:_:)).
+    #[inline]
+    #[doc(alias = "SecCodeCopySigningInformation")]
+    pub fn signing_information(
+        &self,
+        flags: SecCodeInfoFlags,
+    ) -> Result<SecCodeSigningInfo, OSStatus> {
+        let this: *const Self = self;
+        unsafe { SecCodeSigningInfo::copy(this.cast(), flags) }
+    }
+
+    /// Returns the requirement that identifies `self` across versions, such
+    /// as its bundle identifier and signing team.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/security/seccodecopydesignatedrequirement(_:_:_:)).
+    #[inline]
+    #[doc(alias = "SecCodeCopyDesignatedRequirement")]
+    pub fn designated_requirement(&self) -> Result<Arc<SecRequirement>, OSStatus> {
+        let this: *const Self = self;
+        unsafe { copy_designated_requirement(this.cast()) }
+    }
+}
+
+impl SecStaticCode {
+    /// The status when code is not signed.
+    #[doc(alias = "errSecCSUnsigned")]
+    pub const UNSIGNED: OSStatus = unsafe { OSStatus::new_unchecked(-67062) };
+
+    /// The status when code does not satisfy a requirement.
+    #[doc(alias = "errSecCSReqFailed")]
+    pub const REQUIREMENT_FAILED: OSStatus = unsafe { OSStatus::new_unchecked(-67050) };
+
+    /// Returns the type identifier for `SecStaticCode`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/security/secstaticcodegettypeid()).
+    #[inline]
+    #[doc(alias = "SecStaticCodeGetTypeID")]
+    pub fn type_id() -> CFTypeID {
+        unsafe { sys::SecStaticCodeGetTypeID() }
+    }
+
+    /// Returns the code of the bundle or executable at `path`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/security/secstaticcodecreatewithpath(_:_:_:)).
+    #[inline]
+    #[doc(alias = "SecStaticCodeCreateWithPath")]
+    pub fn from_path(path: &CFURL, flags: SecCSFlags) -> Result<Arc<Self>, OSStatus> {
+        unsafe {
+            let mut static_code: *const Self = ptr::null();
+            match sys::SecStaticCodeCreateWithPath(path, flags, &mut static_code) {
+                Some(error) => Err(error),
+                None => Ok(Arc::from_raw(static_code)),
+            }
+        }
+    }
+
+    /// Checks that the signature of `self` is valid, and that `self`
+    /// satisfies `requirement` if provided.
+    ///
+    /// Fails with [`UNSIGNED`](Self::UNSIGNED) if `self` is not signed, or
+    /// with [`REQUIREMENT_FAILED`](Self::REQUIREMENT_FAILED) if it does not
+    /// satisfy `requirement`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/security/secstaticcodecheckvalidity(_:_:_:)).
+    #[inline]
+    #[doc(alias = "SecStaticCodeCheckValidity")]
+    pub fn check_validity(
+        &self,
+        flags: SecCSFlags,
+        requirement: Option<&SecRequirement>,
+    ) -> Result<(), OSStatus> {
+        let requirement = match requirement {
+            Some(requirement) => requirement,
+            None => ptr::null(),
+        };
+        match unsafe { sys::SecStaticCodeCheckValidity(self, flags, requirement) } {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns `true` if `self` is validly signed and was notarized by
+    /// Apple.
+    ///
+    /// This uses the notarization ticket stapled to `self` or cached by the
+    /// system, and does not access the network.
+    pub fn is_notarized(&self) -> Result<bool, OSStatus> {
+        let requirement = SecRequirement::from_string(&CFString::from_static_str("notarized"))?;
+        match self.check_validity(SecCSFlags::NO_NETWORK_ACCESS, Some(&requirement)) {
+            Ok(()) => Ok(true),
+            Err(Self::REQUIREMENT_FAILED) | Err(Self::UNSIGNED) => Ok(false),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Returns information about the signature of `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/security/seccodecopysigninginformation(_:_:_:)).
+    #[inline]
+    #[doc(alias = "SecCodeCopySigningInformation")]
+    pub fn signing_information(
+        &self,
+        flags: SecCodeInfoFlags,
+    ) -> Result<SecCodeSigningInfo, OSStatus> {
+        unsafe { SecCodeSigningInfo::copy(self, flags) }
+    }
+
+    /// Returns the requirement that identifies `self` across versions, such
+    /// as its bundle identifier and signing team.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/security/seccodecopydesignatedrequirement(_:_:_:)).
+    #[inline]
+    #[doc(alias = "SecCodeCopyDesignatedRequirement")]
+    pub fn designated_requirement(&self) -> Result<Arc<SecRequirement>, OSStatus> {
+        unsafe { copy_designated_requirement(self) }
+    }
+
+    /// Checks that `self`, such as a downloaded update, is validly signed
+    /// and satisfies the designated requirement of the current process, so
+    /// that it may replace the running app.
+    ///
+    /// Every architecture and all nested code are validated with the checks
+    /// that Gatekeeper uses. This fails with
+    /// [`REQUIREMENT_FAILED`](Self::REQUIREMENT_FAILED) if `self` was signed
+    /// by someone else or has a different identifier.
+    pub fn check_update_for_current_process(&self) -> Result<(), OSStatus> {
+        let requirement = SecCode::current()?.designated_requirement()?;
+        let flags = SecCSFlags::CHECK_ALL_ARCHITECTURES
+            | SecCSFlags::CHECK_NESTED_CODE
+            | SecCSFlags::STRICT_VALIDATE;
+        self.check_validity(flags, Some(&requirement))
+    }
+}
+
+unsafe fn copy_designated_requirement(
+    code: *const SecStaticCode,
+) -> Result<Arc<SecRequirement>, OSStatus> {
+    let mut requirement: *const SecRequirement = ptr::null();
+    match sys::SecCodeCopyDesignatedRequirement(code, SecCSFlags::DEFAULT, &mut requirement) {
+        Some(error) => Err(error),
+        None => Ok(Arc::from_raw(requirement)),
+    }
+}
+
+impl SecRequirement {
+    /// Returns the type identifier for `SecRequirement`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/security/secrequirementgettypeid()).
+    #[inline]
+    #[doc(alias = "SecRequirementGetTypeID")]
+    pub fn type_id() -> CFTypeID {
+        unsafe { sys::SecRequirementGetTypeID() }
+    }
+
+    /// Compiles a requirement from its source `text`, such as
+    /// `anchor apple generic and certificate leaf[subject.OU] = "TEAMID"`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/security/secrequirementcreatewithstring(_:_:_:)).
+    #[inline]
+    #[doc(alias = "SecRequirementCreateWithString")]
+    pub fn from_string(text: &CFString) -> Result<Arc<Self>, OSStatus> {
+        unsafe {
+            let mut requirement: *const Self = ptr::null();
+            match sys::SecRequirementCreateWithString(text, SecCSFlags::DEFAULT, &mut requirement) {
+                Some(error) => Err(error),
+                None => Ok(Arc::from_raw(requirement)),
+            }
+        }
+    }
+
+    /// Returns the source text of `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/security/secrequirementcopystring(_:_:_:)).
+    #[inline]
+    #[doc(alias = "SecRequirementCopyString")]
+    pub fn text(&self) -> Result<Arc<CFString>, OSStatus> {
+        unsafe {
+            let mut text: *const CFString = ptr::null();
+            match sys::SecRequirementCopyString(self, SecCSFlags::DEFAULT, &mut text) {
+                Some(error) => Err(error),
+                None => Ok(Arc::from_raw(text)),
+            }
+        }
+    }
+}
+
+/// Flags for creating and validating [`SecCode`] and [`SecStaticCode`].
+///
+/// See [documentation](https://developer.apple.com/documentation/security/seccsflags).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SecCSFlags(pub u32);
+
+impl ops::BitOr for SecCSFlags {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl SecCSFlags {
+    /// The default behavior.
+    #[doc(alias = "kSecCSDefaultFlags")]
+    pub const DEFAULT: Self = Self(0);
+
+    /// Validates every architecture of a universal binary, rather than only
+    /// the one for the current system.
+    #[doc(alias = "kSecCSCheckAllArchitectures")]
+    pub const CHECK_ALL_ARCHITECTURES: Self = Self(1 << 0);
+
+    /// Skips validating the main executable.
+    #[doc(alias = "kSecCSDoNotValidateExecutable")]
+    pub const DO_NOT_VALIDATE_EXECUTABLE: Self = Self(1 << 1);
+
+    /// Skips validating the resources of a bundle.
+    #[doc(alias = "kSecCSDoNotValidateResources")]
+    pub const DO_NOT_VALIDATE_RESOURCES: Self = Self(1 << 2);
+
+    /// Also validates nested code, such as frameworks and helpers.
+    #[doc(alias = "kSecCSCheckNestedCode")]
+    pub const CHECK_NESTED_CODE: Self = Self(1 << 3);
+
+    /// Applies the stricter checks that Gatekeeper uses.
+    #[doc(alias = "kSecCSStrictValidate")]
+    pub const STRICT_VALIDATE: Self = Self(1 << 4);
+
+    /// Does not access the network, such as for revocation checks.
+    #[doc(alias = "kSecCSNoNetworkAccess")]
+    pub const NO_NETWORK_ACCESS: Self = Self(1 << 29);
+
+    /// Fails if any certificate has been revoked.
+    #[doc(alias = "kSecCSEnforceRevocationChecks")]
+    pub const ENFORCE_REVOCATION_CHECKS: Self = Self(1 << 30);
+
+    /// Fails if any certificate has expired, even if the signature has a
+    /// secure timestamp.
+    #[doc(alias = "kSecCSConsiderExpiration")]
+    pub const CONSIDER_EXPIRATION: Self = Self(1 << 31);
+
+    /// Returns `true` if all flags in `other` are set in `self`.
+    #[inline]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+/// The information to include in a [`SecCodeSigningInfo`].
+///
+/// The identifier, format, and main executable are always included.
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SecCodeInfoFlags(pub u32);
+
+impl ops::BitOr for SecCodeInfoFlags {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl SecCodeInfoFlags {
+    /// Includes the certificates, team identifier, and timestamp.
+    #[doc(alias = "kSecCSSigningInformation")]
+    pub const SIGNING_INFORMATION: Self = Self(1 << 1);
+
+    /// Includes the designated requirement and entitlements.
+    #[doc(alias = "kSecCSRequirementInformation")]
+    pub const REQUIREMENT_INFORMATION: Self = Self(1 << 2);
+
+    /// Includes the dynamic status of a running [`SecCode`].
+    #[doc(alias = "kSecCSDynamicInformation")]
+    pub const DYNAMIC_INFORMATION: Self = Self(1 << 3);
+
+    /// Includes information about the files that are signed.
+    #[doc(alias = "kSecCSContentInformation")]
+    pub const CONTENT_INFORMATION: Self = Self(1 << 4);
+
+    /// Returns `true` if all flags in `other` are set in `self`.
+    #[inline]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+/// Information about the signature of a [`SecStaticCode`] or [`SecCode`].
+///
+/// Values that were not requested with [`SecCodeInfoFlags`], or that the
+/// code does not have, are [`None`]. Unsigned code has no
+/// [`identifier`](Self::identifier).
+///
+/// See [documentation](https://developer.apple.com/documentation/security/signing_information_dictionary_keys).
+#[derive(Clone)]
+pub struct SecCodeSigningInfo {
+    dictionary: Arc<CFDictionary<CFString, CFType<'static>>>,
+}
+
+impl fmt::Debug for SecCodeSigningInfo {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.dictionary.fmt(f)
+    }
+}
+
+impl SecCodeSigningInfo {
+    /// # Safety
+    ///
+    /// `code` must be a `SecStaticCode` or `SecCode`.
+    unsafe fn copy(code: *const SecStaticCode, flags: SecCodeInfoFlags) -> Result<Self, OSStatus> {
+        let mut dictionary: *const CFDictionary = ptr::null();
+        match sys::SecCodeCopySigningInformation(code, flags, &mut dictionary) {
+            Some(error) => Err(error),
+            None => Ok(Self {
+                dictionary: Arc::from_raw(dictionary.cast()),
+            }),
+        }
+    }
+
+    /// Returns the value for `key` if it is an instance of `T`, whose type
+    /// identifier is `type_id`.
+    fn get<T: ObjectType>(&self, key: &CFString, type_id: CFTypeID) -> Option<Arc<T>> {
+        let value = self.dictionary.get(key)?;
+        if value.get_type_id() == type_id {
+            Some(unsafe { Arc::cast_unchecked(Arc::retain(value)) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the signing identifier, which is usually the bundle
+    /// identifier.
+    #[inline]
+    #[doc(alias = "kSecCodeInfoIdentifier")]
+    pub fn identifier(&self) -> Option<Arc<CFString>> {
+        self.get(unsafe { sys::kSecCodeInfoIdentifier }, CFString::type_id())
+    }
+
+    /// Returns the identifier of the team that signed the code.
+    #[inline]
+    #[doc(alias = "kSecCodeInfoTeamIdentifier")]
+    pub fn team_identifier(&self) -> Option<Arc<CFString>> {
+        self.get(
+            unsafe { sys::kSecCodeInfoTeamIdentifier },
+            CFString::type_id(),
+        )
+    }
+
+    /// Returns the signing certificate chain, starting with the leaf.
+    #[inline]
+    #[doc(alias = "kSecCodeInfoCertificates")]
+    pub fn certificates(&self) -> Option<Arc<CFArray<SecCertificate>>> {
+        self.get(
+            unsafe { sys::kSecCodeInfoCertificates },
+            CFArray::<SecCertificate>::type_id(),
+        )
+    }
+
+    /// Returns the time of the secure timestamp of the signature.
+    #[inline]
+    #[doc(alias = "kSecCodeInfoTimestamp")]
+    pub fn timestamp(&self) -> Option<Arc<CFDate>> {
+        self.get(unsafe { sys::kSecCodeInfoTimestamp }, CFDate::type_id())
+    }
+
+    /// Returns the code signing flags, such as whether the hardened runtime
+    /// is enabled.
+    #[inline]
+    #[doc(alias = "kSecCodeInfoFlags")]
+    pub fn flags(&self) -> Option<u32> {
+        self.get::<CFNumber>(unsafe { sys::kSecCodeInfoFlags }, CFNumber::type_id())
+            .and_then(|flags| flags.i64_value())
+            .map(|flags| flags as u32)
+    }
+
+    /// Returns a description of the format of the code, such as
+    /// `"bundle with Mach-O universal (x86_64 arm64)"`.
+    #[inline]
+    #[doc(alias = "kSecCodeInfoFormat")]
+    pub fn format(&self) -> Option<Arc<CFString>> {
+        self.get(unsafe { sys::kSecCodeInfoFormat }, CFString::type_id())
+    }
+
+    /// Returns the URL of the main executable.
+    #[inline]
+    #[doc(alias = "kSecCodeInfoMainExecutable")]
+    pub fn main_executable(&self) -> Option<Arc<CFURL>> {
+        self.get(unsafe { sys::kSecCodeInfoMainExecutable }, CFURL::type_id())
+    }
+
+    /// Returns the entitlements the code was signed with.
+    #[inline]
+    #[doc(alias = "kSecCodeInfoEntitlementsDict")]
+    pub fn entitlements(&self) -> Option<Arc<CFDictionary<CFString, CFType<'static>>>> {
+        self.get(
+            unsafe { sys::kSecCodeInfoEntitlementsDict },
+            CFDictionary::<CFString, CFType<'static>>::type_id(),
+        )
+    }
+
+    /// Returns the underlying dictionary.
+    #[inline]
+    pub fn as_dictionary(&self) -> &CFDictionary<CFString, CFType<'static>> {
+        &self.dictionary
+    }
+}
@@ -0,0 +1,44 @@
+use super::{sys, SecCertificate};
+use crate::core::{Arc, OSStatus};
+use crate::core_foundation::{CFType, CFTypeID};
+use std::ptr;
+
+subclass! {
+    /// A certificate paired with its private key, such as for signing or
+    /// client authentication.
+    ///
+    /// Identities are found in the keychain with
+    /// [`SecItemClass::Identity`](super::SecItemClass::Identity).
+    ///
+    /// Documentation:
+    /// [Swift](https://developer.apple.com/documentation/security/secidentity?language=swift) |
+    /// [Objective-C](https://developer.apple.com/documentation/security/secidentityref?language=objc)
+    #[derive(PartialEq, Hash)]
+    pub class SecIdentity: CFType<'static>;
+}
+
+impl SecIdentity {
+    /// Returns the type identifier for `SecIdentity`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/security/secidentitygettypeid()).
+    #[inline]
+    #[doc(alias = "SecIdentityGetTypeID")]
+    pub fn type_id() -> CFTypeID {
+        unsafe { sys::SecIdentityGetTypeID() }
+    }
+
+    /// Returns the certificate of `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/security/secidentitycopycertificate(_:_:)).
+    #[inline]
+    #[doc(alias = "SecIdentityCopyCertificate")]
+    pub fn certificate(&self) -> Result<Arc<SecCertificate>, OSStatus> {
+        unsafe {
+            let mut certificate: *const SecCertificate = ptr::null();
+            match sys::SecIdentityCopyCertificate(self, &mut certificate) {
+                Some(error) => Err(error),
+                None => Ok(Arc::from_raw(certificate)),
+            }
+        }
+    }
+}
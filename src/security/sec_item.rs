@@ -0,0 +1,397 @@
+use super::{sys, SecAccessControl, SecAccessible};
+use crate::core::{Arc, OSStatus};
+use crate::core_foundation::{
+    CFArray, CFBoolean, CFData, CFDictionary, CFMutableDictionary, CFNumber, CFString, CFType,
+};
+use std::{fmt, ptr};
+
+/// The kind of a keychain item.
+///
+/// See [documentation](https://developer.apple.com/documentation/security/ksecclass).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SecItemClass {
+    /// A password for an app or service, identified by its
+    /// [service](SecItemQuery::service) and
+    /// [account](SecItemQuery::account).
+    #[doc(alias = "kSecClassGenericPassword")]
+    GenericPassword,
+
+    /// A password for a network server, identified by its
+    /// [server](SecItemQuery::server), [port](SecItemQuery::port), and
+    /// [account](SecItemQuery::account).
+    #[doc(alias = "kSecClassInternetPassword")]
+    InternetPassword,
+
+    /// A [certificate](super::SecCertificate).
+    #[doc(alias = "kSecClassCertificate")]
+    Certificate,
+
+    /// An [identity](super::SecIdentity), which is a certificate and its
+    /// private key.
+    #[doc(alias = "kSecClassIdentity")]
+    Identity,
+}
+
+impl SecItemClass {
+    /// Returns the value of the `kSecClass` key for `self`.
+    #[inline]
+    pub fn as_cf_string(self) -> &'static CFString {
+        unsafe {
+            match self {
+                Self::GenericPassword => sys::kSecClassGenericPassword,
+                Self::InternetPassword => sys::kSecClassInternetPassword,
+                Self::Certificate => sys::kSecClassCertificate,
+                Self::Identity => sys::kSecClassIdentity,
+            }
+        }
+    }
+}
+
+/// Attributes of keychain items, as returned by
+/// [`SecItemQuery::find_attributes`].
+pub type SecItemAttributes = CFDictionary<CFString, CFType<'static>>;
+
+/// Attributes that identify keychain items, used to add, find, update, or
+/// delete them.
+///
+/// # Examples
+///
+/// ```no_run
+/// use fruity::core_foundation::{CFData, CFString};
+/// use fruity::security::SecItemQuery;
+///
+/// let service = CFString::from_static_str("com.example.app");
+/// let account = CFString::from_static_str("alice");
+///
+/// let query = SecItemQuery::generic_password()
+///     .service(&service)
+///     .account(&account);
+///
+/// query.add_data(&CFData::from_bytes(None, b"hunter2")).unwrap();
+///
+/// let password = query.find_data().unwrap();
+/// assert_eq!(password.as_bytes(), b"hunter2");
+///
+/// query.delete().unwrap();
+/// ```
+pub struct SecItemQuery {
+    // Never shared with other queries, so that it can be mutated.
+    dictionary: Arc<CFMutableDictionary<CFString, CFType<'static>>>,
+}
+
+impl Clone for SecItemQuery {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            dictionary: CFMutableDictionary::copy_from(&self.dictionary),
+        }
+    }
+}
+
+impl fmt::Debug for SecItemQuery {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.dictionary.fmt(f)
+    }
+}
+
+/// Building a query.
+impl SecItemQuery {
+    /// Creates a query for items of `class`.
+    #[inline]
+    pub fn new(class: SecItemClass) -> Self {
+        let query = Self {
+            dictionary: CFMutableDictionary::new(),
+        };
+        query.insert(unsafe { sys::kSecClass }, class.as_cf_string())
+    }
+
+    /// Creates a query for generic passwords.
+    #[inline]
+    pub fn generic_password() -> Self {
+        Self::new(SecItemClass::GenericPassword)
+    }
+
+    /// Creates a query for internet passwords.
+    #[inline]
+    pub fn internet_password() -> Self {
+        Self::new(SecItemClass::InternetPassword)
+    }
+
+    #[inline]
+    fn insert(self, key: &CFString, value: &CFType<'static>) -> Self {
+        // SAFETY: `self` is owned, so no references into its unshared
+        // dictionary are alive.
+        unsafe { self.dictionary.insert(key, value) };
+        self
+    }
+
+    /// Returns a copy of the query with `key` set to `value`, so that `self`
+    /// is unchanged.
+    #[inline]
+    fn with(&self, key: &CFString, value: &CFType<'static>) -> Self {
+        self.clone().insert(key, value)
+    }
+
+    /// Sets `key` to `value`, for attributes without a dedicated method.
+    #[inline]
+    pub fn attribute(self, key: &CFString, value: &CFType<'static>) -> Self {
+        self.insert(key, value)
+    }
+
+    /// Sets the service of a generic password.
+    #[inline]
+    #[doc(alias = "kSecAttrService")]
+    pub fn service(self, service: &CFString) -> Self {
+        self.insert(unsafe { sys::kSecAttrService }, service)
+    }
+
+    /// Sets the account name.
+    #[inline]
+    #[doc(alias = "kSecAttrAccount")]
+    pub fn account(self, account: &CFString) -> Self {
+        self.insert(unsafe { sys::kSecAttrAccount }, account)
+    }
+
+    /// Sets the label shown to the user, such as in Keychain Access.
+    #[inline]
+    #[doc(alias = "kSecAttrLabel")]
+    pub fn label(self, label: &CFString) -> Self {
+        self.insert(unsafe { sys::kSecAttrLabel }, label)
+    }
+
+    /// Sets the kind of item shown to the user, such as "application
+    /// password".
+    #[inline]
+    #[doc(alias = "kSecAttrDescription")]
+    pub fn description(self, description: &CFString) -> Self {
+        self.insert(unsafe { sys::kSecAttrDescription }, description)
+    }
+
+    /// Sets a comment for the item.
+    #[inline]
+    #[doc(alias = "kSecAttrComment")]
+    pub fn comment(self, comment: &CFString) -> Self {
+        self.insert(unsafe { sys::kSecAttrComment }, comment)
+    }
+
+    /// Sets the access group, which is shared by apps with the same keychain
+    /// access group entitlement.
+    #[inline]
+    #[doc(alias = "kSecAttrAccessGroup")]
+    pub fn access_group(self, access_group: &CFString) -> Self {
+        self.insert(unsafe { sys::kSecAttrAccessGroup }, access_group)
+    }
+
+    /// Sets the server of an internet password, such as `example.com`.
+    #[inline]
+    #[doc(alias = "kSecAttrServer")]
+    pub fn server(self, server: &CFString) -> Self {
+        self.insert(unsafe { sys::kSecAttrServer }, server)
+    }
+
+    /// Sets the port of an internet password.
+    #[inline]
+    #[doc(alias = "kSecAttrPort")]
+    pub fn port(self, port: u16) -> Self {
+        let port = CFNumber::new(i32::from(port));
+        self.insert(unsafe { sys::kSecAttrPort }, &port)
+    }
+
+    /// Sets the path of an internet password, such as `/login`.
+    #[inline]
+    #[doc(alias = "kSecAttrPath")]
+    pub fn path(self, path: &CFString) -> Self {
+        self.insert(unsafe { sys::kSecAttrPath }, path)
+    }
+
+    /// Sets whether the item is synchronized with iCloud Keychain.
+    ///
+    /// Queries only match items that are not synchronized unless this is
+    /// set. Use [`any_synchronizable`](Self::any_synchronizable) to match
+    /// both.
+    #[inline]
+    #[doc(alias = "kSecAttrSynchronizable")]
+    pub fn synchronizable(self, synchronizable: bool) -> Self {
+        self.insert(
+            unsafe { sys::kSecAttrSynchronizable },
+            CFBoolean::new(synchronizable),
+        )
+    }
+
+    /// Matches items whether or not they are synchronized.
+    #[inline]
+    #[doc(alias = "kSecAttrSynchronizableAny")]
+    pub fn any_synchronizable(self) -> Self {
+        self.insert(unsafe { sys::kSecAttrSynchronizable }, unsafe {
+            sys::kSecAttrSynchronizableAny
+        })
+    }
+
+    /// Sets when an added item can be read.
+    ///
+    /// This cannot be combined with
+    /// [`access_control`](Self::access_control).
+    #[inline]
+    #[doc(alias = "kSecAttrAccessible")]
+    pub fn accessible(self, accessible: SecAccessible) -> Self {
+        self.insert(
+            unsafe { sys::kSecAttrAccessible },
+            accessible.as_cf_string(),
+        )
+    }
+
+    /// Sets the conditions for reading an added item.
+    #[inline]
+    #[doc(alias = "kSecAttrAccessControl")]
+    pub fn access_control(self, access_control: &SecAccessControl) -> Self {
+        self.insert(unsafe { sys::kSecAttrAccessControl }, access_control)
+    }
+
+    /// Uses the data protection keychain on macOS, which behaves like the
+    /// keychain on iOS, instead of the file-based keychain.
+    #[cfg(target_os = "macos")]
+    #[inline]
+    #[doc(alias = "kSecUseDataProtectionKeychain")]
+    pub fn data_protection_keychain(self) -> Self {
+        self.insert(
+            unsafe { sys::kSecUseDataProtectionKeychain },
+            CFBoolean::true_value(),
+        )
+    }
+
+    /// Returns the underlying dictionary.
+    #[inline]
+    pub fn as_dictionary(&self) -> &CFDictionary<CFString, CFType<'static>> {
+        &self.dictionary
+    }
+}
+
+/// Keychain operations.
+///
+/// Failures are reported as the [`OSStatus`] returned by Security, such as
+/// [`SecItemQuery::ITEM_NOT_FOUND`] or [`SecItemQuery::DUPLICATE_ITEM`].
+/// Use [`error_message`](super::error_message) to describe them.
+impl SecItemQuery {
+    /// The status when no item matches a query.
+    #[doc(alias = "errSecItemNotFound")]
+    pub const ITEM_NOT_FOUND: OSStatus = unsafe { OSStatus::new_unchecked(-25300) };
+
+    /// The status when adding an item that already exists.
+    #[doc(alias = "errSecDuplicateItem")]
+    pub const DUPLICATE_ITEM: OSStatus = unsafe { OSStatus::new_unchecked(-25299) };
+
+    /// Adds an item with the attributes of `self`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/security/secitemadd(_:_:)).
+    #[inline]
+    #[doc(alias = "SecItemAdd")]
+    pub fn add(&self) -> Result<(), OSStatus> {
+        match unsafe { sys::SecItemAdd(self.dictionary.as_raw(), ptr::null_mut()) } {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+
+    /// Adds an item with the attributes of `self` and the secret `data`,
+    /// such as a password.
+    #[inline]
+    #[doc(alias = "kSecValueData")]
+    pub fn add_data(&self, data: &CFData) -> Result<(), OSStatus> {
+        self.with(unsafe { sys::kSecValueData }, data).add()
+    }
+
+    /// Returns the secret data of the first matching item.
+    #[inline]
+    #[doc(alias = "kSecReturnData")]
+    pub fn find_data(&self) -> Result<Arc<CFData>, OSStatus> {
+        let query = self.with(unsafe { sys::kSecReturnData }, CFBoolean::true_value());
+        unsafe { Ok(Arc::cast_unchecked(query.copy_matching()?)) }
+    }
+
+    /// Returns the first matching item itself, such as a
+    /// [`SecCertificate`](super::SecCertificate) or
+    /// [`SecIdentity`](super::SecIdentity) depending on the
+    /// [class](SecItemClass) of the query.
+    #[inline]
+    #[doc(alias = "kSecReturnRef")]
+    pub fn find_reference(&self) -> Result<Arc<CFType<'static>>, OSStatus> {
+        self.with(unsafe { sys::kSecReturnRef }, CFBoolean::true_value())
+            .copy_matching()
+    }
+
+    /// Returns the attributes of the first matching item.
+    #[inline]
+    #[doc(alias = "kSecReturnAttributes")]
+    pub fn find_attributes(&self) -> Result<Arc<SecItemAttributes>, OSStatus> {
+        let query = self.with(
+            unsafe { sys::kSecReturnAttributes },
+            CFBoolean::true_value(),
+        );
+        unsafe { Ok(Arc::cast_unchecked(query.copy_matching()?)) }
+    }
+
+    /// Returns the attributes of all matching items.
+    #[inline]
+    #[doc(alias = "kSecMatchLimitAll")]
+    pub fn find_all_attributes(&self) -> Result<Arc<CFArray<SecItemAttributes>>, OSStatus> {
+        let query = self
+            .with(
+                unsafe { sys::kSecReturnAttributes },
+                CFBoolean::true_value(),
+            )
+            .insert(unsafe { sys::kSecMatchLimit }, unsafe {
+                sys::kSecMatchLimitAll
+            });
+        unsafe { Ok(Arc::cast_unchecked(query.copy_matching()?)) }
+    }
+
+    /// Returns the result of searching with `self`, whose type depends on
+    /// the `kSecReturn` and `kSecMatchLimit` keys of the query.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/security/secitemcopymatching(_:_:)).
+    #[inline]
+    #[doc(alias = "SecItemCopyMatching")]
+    pub fn copy_matching(&self) -> Result<Arc<CFType<'static>>, OSStatus> {
+        unsafe {
+            let mut result: *const CFType<'static> = ptr::null();
+            match sys::SecItemCopyMatching(self.dictionary.as_raw(), &mut result) {
+                Some(error) => Err(error),
+                None if result.is_null() => Err(Self::ITEM_NOT_FOUND),
+                None => Ok(Arc::from_raw(result)),
+            }
+        }
+    }
+
+    /// Sets `attributes` on all matching items.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/security/secitemupdate(_:_:)).
+    #[inline]
+    #[doc(alias = "SecItemUpdate")]
+    pub fn update(&self, attributes: &SecItemAttributes) -> Result<(), OSStatus> {
+        match unsafe { sys::SecItemUpdate(self.dictionary.as_raw(), attributes.as_raw()) } {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+
+    /// Replaces the secret data of all matching items.
+    #[inline]
+    pub fn update_data(&self, data: &CFData) -> Result<(), OSStatus> {
+        let value_data: &CFString = unsafe { sys::kSecValueData };
+        let data: &CFType<'static> = data;
+        self.update(&CFDictionary::create(None, &[value_data], &[data]))
+    }
+
+    /// Deletes all matching items.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/security/secitemdelete(_:)).
+    #[inline]
+    #[doc(alias = "SecItemDelete")]
+    pub fn delete(&self) -> Result<(), OSStatus> {
+        match unsafe { sys::SecItemDelete(self.dictionary.as_raw()) } {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use super::sys;
+use crate::core::Arc;
+use crate::core_foundation::{CFString, CFType, CFTypeID};
+use std::ptr;
+
+subclass! {
+    /// Rules for evaluating a certificate chain with a
+    /// [`SecTrust`](super::SecTrust).
+    ///
+    /// Documentation:
+    /// [Swift](https://developer.apple.com/documentation/security/secpolicy?language=swift) |
+    /// [Objective-C](https://developer.apple.com/documentation/security/secpolicyref?language=objc)
+    #[derive(PartialEq, Hash)]
+    pub class SecPolicy: CFType<'static>;
+}
+
+impl SecPolicy {
+    /// Returns the type identifier for `SecPolicy`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/security/secpolicygettypeid()).
+    #[inline]
+    #[doc(alias = "SecPolicyGetTypeID")]
+    pub fn type_id() -> CFTypeID {
+        unsafe { sys::SecPolicyGetTypeID() }
+    }
+
+    /// Creates a policy that only checks that the chain is valid X.509 and
+    /// ends in a trusted anchor.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/security/secpolicycreatebasicx509()).
+    #[inline]
+    #[doc(alias = "SecPolicyCreateBasicX509")]
+    pub fn basic_x509() -> Arc<Self> {
+        unsafe { Arc::from_raw(sys::SecPolicyCreateBasicX509()) }
+    }
+
+    /// Creates a policy for evaluating TLS certificates.
+    ///
+    /// If `server` is `true`, the leaf certificate must be valid for a
+    /// server, and for `hostname` if provided. Otherwise it must be valid for
+    /// a client.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/security/secpolicycreatessl(_:_:)).
+    #[inline]
+    #[doc(alias = "SecPolicyCreateSSL")]
+    pub fn ssl(server: bool, hostname: Option<&CFString>) -> Arc<Self> {
+        unsafe {
+            Arc::from_raw(sys::SecPolicyCreateSSL(
+                server as _,
+                match hostname {
+                    Some(hostname) => hostname,
+                    None => ptr::null(),
+                },
+            ))
+        }
+    }
+}
@@ -0,0 +1,172 @@
+use super::{sys, SecCertificate, SecPolicy};
+use crate::core::{Arc, OSStatus};
+use crate::core_foundation::{CFArray, CFDate, CFError, CFIndex, CFType, CFTypeID};
+use std::ptr;
+
+subclass! {
+    /// The evaluation of a certificate chain against
+    /// [policies](super::SecPolicy).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fruity::core_foundation::{CFArray, CFData, CFString};
+    /// use fruity::security::{SecCertificate, SecPolicy, SecTrust};
+    ///
+    /// # let der: &[u8] = &[];
+    /// let certificate = SecCertificate::from_der(None, &CFData::from_bytes(None, der)).unwrap();
+    ///
+    /// let hostname = CFString::from_static_str("example.com");
+    /// let trust = SecTrust::create(
+    ///     &CFArray::from_slice(&[&*certificate]),
+    ///     &SecPolicy::ssl(true, Some(&hostname)),
+    /// )
+    /// .unwrap();
+    ///
+    /// match trust.evaluate() {
+    ///     Ok(()) => println!("trusted"),
+    ///     Err(error) => println!("not trusted: {}", error),
+    /// }
+    /// ```
+    ///
+    /// Documentation:
+    /// [Swift](https://developer.apple.com/documentation/security/sectrust?language=swift) |
+    /// [Objective-C](https://developer.apple.com/documentation/security/sectrustref?language=objc)
+    #[derive(PartialEq, Hash)]
+    pub class SecTrust: CFType<'static>;
+}
+
+impl SecTrust {
+    /// Returns the type identifier for `SecTrust`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/security/sectrustgettypeid()).
+    #[inline]
+    #[doc(alias = "SecTrustGetTypeID")]
+    pub fn type_id() -> CFTypeID {
+        unsafe { sys::SecTrustGetTypeID() }
+    }
+
+    /// Creates a trust object for evaluating `certificates` against
+    /// `policy`.
+    ///
+    /// The first certificate is the one to evaluate. The others may be used
+    /// to build the chain to a trusted anchor.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/security/sectrustcreatewithcertificates(_:_:_:)).
+    #[inline]
+    #[doc(alias = "SecTrustCreateWithCertificates")]
+    pub fn create(
+        certificates: &CFArray<SecCertificate>,
+        policy: &SecPolicy,
+    ) -> Result<Arc<Self>, OSStatus> {
+        unsafe {
+            let mut trust: *const Self = ptr::null();
+            match sys::SecTrustCreateWithCertificates(&**certificates, &**policy, &mut trust) {
+                Some(error) => Err(error),
+                None => Ok(Arc::from_raw(trust)),
+            }
+        }
+    }
+
+    /// Evaluates the certificate chain, returning the reason if it is not
+    /// trusted.
+    ///
+    /// This may fetch intermediate certificates and revocation information
+    /// over the network, so it should not be called on the main thread.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/security/sectrustevaluatewitherror(_:_:)).
+    #[inline]
+    #[doc(alias = "SecTrustEvaluateWithError")]
+    pub fn evaluate(&self) -> Result<(), Arc<CFError>> {
+        unsafe {
+            let mut error: *const CFError = ptr::null();
+            if sys::SecTrustEvaluateWithError(self, &mut error) {
+                Ok(())
+            } else {
+                Err(Arc::from_raw(error))
+            }
+        }
+    }
+
+    /// Sets the certificates that are trusted as anchors, such as a private
+    /// certificate authority.
+    ///
+    /// This disables the system anchors unless
+    /// [`set_anchor_certificates_only(false)`](Self::set_anchor_certificates_only)
+    /// is called afterwards.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/security/sectrustsetanchorcertificates(_:_:)).
+    #[inline]
+    #[doc(alias = "SecTrustSetAnchorCertificates")]
+    pub fn set_anchor_certificates(
+        &self,
+        certificates: &CFArray<SecCertificate>,
+    ) -> Result<(), OSStatus> {
+        match unsafe { sys::SecTrustSetAnchorCertificates(self, certificates.as_raw()) } {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+
+    /// Sets whether only the [anchor certificates](Self::set_anchor_certificates)
+    /// are trusted, rather than also the system anchors.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/security/sectrustsetanchorcertificatesonly(_:_:)).
+    #[inline]
+    #[doc(alias = "SecTrustSetAnchorCertificatesOnly")]
+    pub fn set_anchor_certificates_only(&self, only: bool) -> Result<(), OSStatus> {
+        match unsafe { sys::SecTrustSetAnchorCertificatesOnly(self, only as _) } {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+
+    /// Sets whether evaluation may fetch missing intermediate certificates
+    /// and revocation information over the network.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/security/sectrustsetnetworkfetchallowed(_:_:)).
+    #[inline]
+    #[doc(alias = "SecTrustSetNetworkFetchAllowed")]
+    pub fn set_network_fetch_allowed(&self, allowed: bool) -> Result<(), OSStatus> {
+        match unsafe { sys::SecTrustSetNetworkFetchAllowed(self, allowed as _) } {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+
+    /// Sets the time at which the certificates must be valid, instead of
+    /// now.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/security/sectrustsetverifydate(_:_:)).
+    #[inline]
+    #[doc(alias = "SecTrustSetVerifyDate")]
+    pub fn set_verify_date(&self, date: &CFDate) -> Result<(), OSStatus> {
+        match unsafe { sys::SecTrustSetVerifyDate(self, date) } {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns the number of certificates in the evaluated chain.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/security/sectrustgetcertificatecount(_:)).
+    #[inline]
+    #[doc(alias = "SecTrustGetCertificateCount")]
+    pub fn certificate_count(&self) -> CFIndex {
+        unsafe { sys::SecTrustGetCertificateCount(self) }
+    }
+
+    /// Returns the certificates of the evaluated chain, starting with the
+    /// leaf and ending with the anchor.
+    #[doc(alias = "SecTrustGetCertificateAtIndex")]
+    #[doc(alias = "SecTrustCopyCertificateChain")]
+    pub fn certificate_chain(&self) -> Vec<Arc<SecCertificate>> {
+        (0..self.certificate_count())
+            .filter_map(|index| unsafe {
+                sys::SecTrustGetCertificateAtIndex(self, index)
+                    .as_ref()
+                    .map(Arc::retain)
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,216 @@
+//! Raw unsafe C functions and constants exposed by `Security.framework`.
+
+use super::{
+    SecAccessControl, SecAccessControlCreateFlags, SecCertificate, SecIdentity, SecPolicy, SecTrust,
+};
+use crate::core::OSStatus;
+use crate::core_foundation::{
+    Boolean, CFAllocator, CFArray, CFData, CFDate, CFDictionary, CFError, CFIndex, CFString,
+    CFType, CFTypeID,
+};
+use std::ffi::c_void;
+
+#[cfg(target_os = "macos")]
+use super::{SecCSFlags, SecCode, SecCodeInfoFlags, SecRequirement, SecStaticCode};
+#[cfg(target_os = "macos")]
+use crate::core_foundation::CFURL;
+
+#[allow(missing_docs)]
+#[link(name = "Security", kind = "framework")]
+extern "C" {
+    pub fn SecItemAdd(
+        attributes: *const CFDictionary,
+        result: *mut *const CFType<'static>,
+    ) -> Option<OSStatus>;
+    pub fn SecItemCopyMatching(
+        query: *const CFDictionary,
+        result: *mut *const CFType<'static>,
+    ) -> Option<OSStatus>;
+    pub fn SecItemUpdate(
+        query: *const CFDictionary,
+        attributes_to_update: *const CFDictionary,
+    ) -> Option<OSStatus>;
+    pub fn SecItemDelete(query: *const CFDictionary) -> Option<OSStatus>;
+
+    pub fn SecAccessControlGetTypeID() -> CFTypeID;
+    pub fn SecAccessControlCreateWithFlags(
+        allocator: *const CFAllocator,
+        protection: *const CFType<'static>,
+        flags: SecAccessControlCreateFlags,
+        error: *mut *const CFError,
+    ) -> *const SecAccessControl;
+
+    pub fn SecCertificateGetTypeID() -> CFTypeID;
+    pub fn SecCertificateCreateWithData(
+        allocator: *const CFAllocator,
+        data: *const CFData,
+    ) -> *const SecCertificate;
+    pub fn SecCertificateCopyData(certificate: *const SecCertificate) -> *const CFData;
+    pub fn SecCertificateCopySubjectSummary(certificate: *const SecCertificate) -> *const CFString;
+    pub fn SecCertificateCopyCommonName(
+        certificate: *const SecCertificate,
+        common_name: *mut *const CFString,
+    ) -> Option<OSStatus>;
+    pub fn SecCertificateCopyEmailAddresses(
+        certificate: *const SecCertificate,
+        email_addresses: *mut *const CFArray,
+    ) -> Option<OSStatus>;
+    pub fn SecCertificateCopySerialNumberData(
+        certificate: *const SecCertificate,
+        error: *mut *const CFError,
+    ) -> *const CFData;
+
+    pub fn SecIdentityGetTypeID() -> CFTypeID;
+    pub fn SecIdentityCopyCertificate(
+        identity: *const SecIdentity,
+        certificate: *mut *const SecCertificate,
+    ) -> Option<OSStatus>;
+
+    pub fn SecPolicyGetTypeID() -> CFTypeID;
+    pub fn SecPolicyCreateBasicX509() -> *const SecPolicy;
+    pub fn SecPolicyCreateSSL(server: Boolean, hostname: *const CFString) -> *const SecPolicy;
+
+    pub fn SecTrustGetTypeID() -> CFTypeID;
+    pub fn SecTrustCreateWithCertificates(
+        certificates: *const CFType<'static>,
+        policies: *const CFType<'static>,
+        trust: *mut *const SecTrust,
+    ) -> Option<OSStatus>;
+    pub fn SecTrustEvaluateWithError(trust: *const SecTrust, error: *mut *const CFError) -> bool;
+    pub fn SecTrustSetAnchorCertificates(
+        trust: *const SecTrust,
+        anchor_certificates: *const CFArray,
+    ) -> Option<OSStatus>;
+    pub fn SecTrustSetAnchorCertificatesOnly(
+        trust: *const SecTrust,
+        anchor_certificates_only: Boolean,
+    ) -> Option<OSStatus>;
+    pub fn SecTrustSetNetworkFetchAllowed(
+        trust: *const SecTrust,
+        allow_fetch: Boolean,
+    ) -> Option<OSStatus>;
+    pub fn SecTrustSetVerifyDate(
+        trust: *const SecTrust,
+        verify_date: *const CFDate,
+    ) -> Option<OSStatus>;
+    pub fn SecTrustGetCertificateCount(trust: *const SecTrust) -> CFIndex;
+    pub fn SecTrustGetCertificateAtIndex(
+        trust: *const SecTrust,
+        index: CFIndex,
+    ) -> *const SecCertificate;
+
+    pub fn SecCopyErrorMessageString(status: OSStatus, reserved: *mut c_void) -> *const CFString;
+
+    // Item classes.
+    pub static kSecClass: &'static CFString;
+    pub static kSecClassGenericPassword: &'static CFString;
+    pub static kSecClassInternetPassword: &'static CFString;
+    pub static kSecClassCertificate: &'static CFString;
+    pub static kSecClassIdentity: &'static CFString;
+
+    // Item attributes.
+    pub static kSecAttrAccessControl: &'static CFString;
+    pub static kSecAttrAccessible: &'static CFString;
+    pub static kSecAttrAccessGroup: &'static CFString;
+    pub static kSecAttrAccount: &'static CFString;
+    pub static kSecAttrComment: &'static CFString;
+    pub static kSecAttrDescription: &'static CFString;
+    pub static kSecAttrLabel: &'static CFString;
+    pub static kSecAttrPath: &'static CFString;
+    pub static kSecAttrPort: &'static CFString;
+    pub static kSecAttrServer: &'static CFString;
+    pub static kSecAttrService: &'static CFString;
+    pub static kSecAttrSynchronizable: &'static CFString;
+    pub static kSecAttrSynchronizableAny: &'static CFString;
+
+    // Accessibility values.
+    pub static kSecAttrAccessibleWhenUnlocked: &'static CFString;
+    pub static kSecAttrAccessibleAfterFirstUnlock: &'static CFString;
+    pub static kSecAttrAccessibleWhenPasscodeSetThisDeviceOnly: &'static CFString;
+    pub static kSecAttrAccessibleWhenUnlockedThisDeviceOnly: &'static CFString;
+    pub static kSecAttrAccessibleAfterFirstUnlockThisDeviceOnly: &'static CFString;
+
+    // Search and return keys.
+    pub static kSecMatchLimit: &'static CFString;
+    pub static kSecMatchLimitOne: &'static CFString;
+    pub static kSecMatchLimitAll: &'static CFString;
+    pub static kSecReturnAttributes: &'static CFString;
+    pub static kSecReturnData: &'static CFString;
+    pub static kSecReturnRef: &'static CFString;
+    pub static kSecValueData: &'static CFString;
+    pub static kSecUseDataProtectionKeychain: &'static CFString;
+}
+
+#[cfg(target_os = "macos")]
+#[allow(missing_docs)]
+#[link(name = "Security", kind = "framework")]
+extern "C" {
+    pub fn SecCodeGetTypeID() -> CFTypeID;
+    pub fn SecCodeCopySelf(flags: SecCSFlags, code: *mut *const SecCode) -> Option<OSStatus>;
+    pub fn SecCodeCopyGuestWithAttributes(
+        host: *const SecCode,
+        attributes: *const CFDictionary,
+        flags: SecCSFlags,
+        guest: *mut *const SecCode,
+    ) -> Option<OSStatus>;
+    pub fn SecCodeCopyStaticCode(
+        code: *const SecCode,
+        flags: SecCSFlags,
+        static_code: *mut *const SecStaticCode,
+    ) -> Option<OSStatus>;
+    pub fn SecCodeCheckValidity(
+        code: *const SecCode,
+        flags: SecCSFlags,
+        requirement: *const SecRequirement,
+    ) -> Option<OSStatus>;
+
+    pub fn SecStaticCodeGetTypeID() -> CFTypeID;
+    pub fn SecStaticCodeCreateWithPath(
+        path: *const CFURL,
+        flags: SecCSFlags,
+        static_code: *mut *const SecStaticCode,
+    ) -> Option<OSStatus>;
+    pub fn SecStaticCodeCheckValidity(
+        static_code: *const SecStaticCode,
+        flags: SecCSFlags,
+        requirement: *const SecRequirement,
+    ) -> Option<OSStatus>;
+
+    /// `code` may also be a `SecCode`.
+    pub fn SecCodeCopySigningInformation(
+        code: *const SecStaticCode,
+        flags: SecCodeInfoFlags,
+        information: *mut *const CFDictionary,
+    ) -> Option<OSStatus>;
+
+    /// `code` may also be a `SecCode`.
+    pub fn SecCodeCopyDesignatedRequirement(
+        code: *const SecStaticCode,
+        flags: SecCSFlags,
+        requirement: *mut *const SecRequirement,
+    ) -> Option<OSStatus>;
+
+    pub fn SecRequirementGetTypeID() -> CFTypeID;
+    pub fn SecRequirementCopyString(
+        requirement: *const SecRequirement,
+        flags: SecCSFlags,
+        text: *mut *const CFString,
+    ) -> Option<OSStatus>;
+    pub fn SecRequirementCreateWithString(
+        text: *const CFString,
+        flags: SecCSFlags,
+        requirement: *mut *const SecRequirement,
+    ) -> Option<OSStatus>;
+
+    pub static kSecGuestAttributePid: &'static CFString;
+
+    // Signing information keys.
+    pub static kSecCodeInfoCertificates: &'static CFString;
+    pub static kSecCodeInfoEntitlementsDict: &'static CFString;
+    pub static kSecCodeInfoFlags: &'static CFString;
+    pub static kSecCodeInfoFormat: &'static CFString;
+    pub static kSecCodeInfoIdentifier: &'static CFString;
+    pub static kSecCodeInfoMainExecutable: &'static CFString;
+    pub static kSecCodeInfoTeamIdentifier: &'static CFString;
+    pub static kSecCodeInfoTimestamp: &'static CFString;
+}
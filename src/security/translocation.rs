@@ -0,0 +1,156 @@
+//! Detection of Gatekeeper
+//! [app translocation](https://developer.apple.com/documentation/security/notarizing_macos_software_before_distribution),
+//! where a quarantined app is run from a randomized, read-only mount rather
+//! than from where the user put it.
+//!
+//! A translocated app cannot update itself in place, since its bundle is
+//! read-only and disappears after it quits. Translocation ends once the user
+//! moves the app with Finder, such as into `/Applications`.
+//!
+//! The `SecTranslocate` functions used here are not public API, so they are
+//! looked up at runtime and never linked. If they are missing, translocation
+//! is detected from the shape of the path instead, and the original location
+//! is unknown.
+
+use crate::core::Arc;
+use crate::core_foundation::{CFBundle, CFURL};
+use std::{
+    ffi::{c_void, CStr},
+    mem,
+    os::raw::c_char,
+    ptr,
+};
+
+/// Whether an app bundle is translocated, and where it came from.
+#[derive(Clone, Debug)]
+pub enum Translocation {
+    /// The app runs from where it is on disk.
+    NotTranslocated,
+
+    /// The app runs from a translocation mount.
+    Translocated {
+        /// The location of the app before translocation, if it could be
+        /// determined.
+        original: Option<Arc<CFURL>>,
+    },
+}
+
+impl Translocation {
+    /// Returns the translocation of the bundle at `url`.
+    pub fn of(url: &CFURL) -> Self {
+        if is_translocated(url) {
+            Self::Translocated {
+                original: original_url(url),
+            }
+        } else {
+            Self::NotTranslocated
+        }
+    }
+
+    /// Returns the translocation of the main bundle, or
+    /// [`NotTranslocated`](Self::NotTranslocated) if the process has no
+    /// bundle.
+    pub fn current() -> Self {
+        match CFBundle::main() {
+            Some(bundle) => Self::of(&bundle.bundle_url()),
+            None => Self::NotTranslocated,
+        }
+    }
+
+    /// Returns `true` if the app is translocated.
+    #[inline]
+    pub fn is_translocated(&self) -> bool {
+        matches!(self, Self::Translocated { .. })
+    }
+
+    /// Returns what an updater should do given this translocation.
+    #[inline]
+    pub fn guidance(&self) -> Guidance {
+        match self {
+            Self::NotTranslocated => Guidance::UpdateInPlace,
+            Self::Translocated { .. } => Guidance::AskUserToMove,
+        }
+    }
+}
+
+/// What an auto-updater should do, given an app's [`Translocation`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Guidance {
+    /// The bundle can be replaced where it is.
+    UpdateInPlace,
+
+    /// The bundle cannot be replaced. Ask the user to move the app with
+    /// Finder, such as into `/Applications`, and relaunch it.
+    ///
+    /// Replacing the [original](Translocation::Translocated::original) bundle
+    /// does not help, since the update would be translocated again on next
+    /// launch.
+    AskUserToMove,
+}
+
+/// Returns `true` if `url` is within a translocation mount.
+#[doc(alias = "SecTranslocateIsTranslocatedURL")]
+pub fn is_translocated(url: &CFURL) -> bool {
+    type IsTranslocatedURL =
+        unsafe extern "C" fn(url: &CFURL, is_translocated: *mut bool, error: *mut c_void) -> bool;
+
+    let is_translocated_url = unsafe { symbol(b"SecTranslocateIsTranslocatedURL\0") };
+    if let Some(is_translocated_url) = is_translocated_url {
+        let is_translocated_url: IsTranslocatedURL = unsafe { mem::transmute(is_translocated_url) };
+
+        let mut is_translocated = false;
+        if unsafe { is_translocated_url(url, &mut is_translocated, ptr::null_mut()) } {
+            return is_translocated;
+        }
+    }
+
+    // Translocated bundles live at
+    // `/private/var/folders/…/AppTranslocation/<UUID>/d/<name>.app`.
+    match url.to_path_buf() {
+        Some(path) => path
+            .components()
+            .any(|c| c.as_os_str() == "AppTranslocation"),
+        None => false,
+    }
+}
+
+/// Returns the location that the translocated bundle at `url` was mounted
+/// from, or [`None`] if `url` is not translocated or the location could not
+/// be determined.
+#[doc(alias = "SecTranslocateCreateOriginalPathForURL")]
+pub fn original_url(url: &CFURL) -> Option<Arc<CFURL>> {
+    type CreateOriginalPathForURL =
+        unsafe extern "C" fn(url: &CFURL, error: *mut c_void) -> *const CFURL;
+
+    let create_original_path = unsafe { symbol(b"SecTranslocateCreateOriginalPathForURL\0")? };
+    let create_original_path: CreateOriginalPathForURL =
+        unsafe { mem::transmute(create_original_path) };
+
+    unsafe {
+        let original = create_original_path(url, ptr::null_mut());
+        if original.is_null() {
+            None
+        } else {
+            Some(Arc::from_raw(original))
+        }
+    }
+}
+
+/// Returns the address of the exported function `name`, if loaded.
+unsafe fn symbol(name: &[u8]) -> Option<*mut c_void> {
+    // `RTLD_DEFAULT` searches all images loaded in the process, which
+    // includes Security.framework since this module links it.
+    const RTLD_DEFAULT: *mut c_void = -2isize as *mut c_void;
+
+    extern "C" {
+        fn dlsym(handle: *mut c_void, symbol: *const c_char) -> *mut c_void;
+    }
+
+    let name = CStr::from_bytes_with_nul_unchecked(name);
+    let symbol = dlsym(RTLD_DEFAULT, name.as_ptr());
+    if symbol.is_null() {
+        None
+    } else {
+        Some(symbol)
+    }
+}
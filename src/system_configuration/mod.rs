@@ -16,5 +16,17 @@
 
 #![cfg(feature = "system_configuration")]
 
+pub mod sys;
+
+#[cfg(target_os = "macos")]
+mod network_interface;
+#[cfg(target_os = "macos")]
+mod sc_network_interface;
+
+#[cfg(target_os = "macos")]
+pub use network_interface::*;
+#[cfg(target_os = "macos")]
+pub use sc_network_interface::*;
+
 #[link(name = "SystemConfiguration", kind = "framework")]
 extern "C" {}
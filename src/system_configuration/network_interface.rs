@@ -0,0 +1,337 @@
+use super::{sys, SCNetworkInterface, SCNetworkInterfaceType};
+use crate::core::Arc;
+use crate::core_foundation::{CFString, CFType};
+use std::{
+    collections::HashMap,
+    ffi::CStr,
+    io,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+    os::raw::{c_char, c_int, c_uint, c_void},
+    ptr,
+};
+
+/// A network interface with its addresses and configuration, as returned by
+/// [`NetworkInterface::all`].
+///
+/// This combines what the kernel reports for each interface through
+/// [`getifaddrs`](https://developer.apple.com/library/archive/documentation/System/Conceptual/ManPages_iPhoneOS/man3/getifaddrs.3.html)
+/// with what System Configuration knows about it through
+/// [`SCNetworkInterface`], such as its user-visible name.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NetworkInterface {
+    /// The BSD name of the interface, such as `en0`.
+    pub bsd_name: String,
+
+    /// The name of the interface shown to users, such as "Wi-Fi", or
+    /// [`None`] if System Configuration does not manage it.
+    pub name: Option<String>,
+
+    /// The kind of hardware or protocol of the interface.
+    pub kind: Option<SCNetworkInterfaceType>,
+
+    /// The MAC address of the interface, if it has one.
+    pub mac_address: Option<[u8; 6]>,
+
+    /// The IPv4 and IPv6 addresses assigned to the interface.
+    pub addresses: Vec<IpAddr>,
+
+    /// The position of the interface's network service in the order set in
+    /// Network preferences, where `0` is the most preferred, or [`None`] if
+    /// it has no enabled service.
+    pub service_order: Option<usize>,
+
+    /// Whether the interface is administratively up.
+    pub is_up: bool,
+
+    /// Whether the interface is a loopback interface, such as `lo0`.
+    pub is_loopback: bool,
+}
+
+impl NetworkInterface {
+    /// Returns all interfaces known to the kernel or to System Configuration,
+    /// in the order that the kernel lists them.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fruity::system_configuration::NetworkInterface;
+    ///
+    /// let mut interfaces = NetworkInterface::all()?;
+    /// interfaces.retain(|interface| interface.service_order.is_some());
+    /// interfaces.sort_by_key(|interface| interface.service_order);
+    ///
+    /// for interface in interfaces {
+    ///     println!(
+    ///         "{} ({}): {:?}",
+    ///         interface.name.as_deref().unwrap_or("Unknown"),
+    ///         interface.bsd_name,
+    ///         interface.addresses,
+    ///     );
+    /// }
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    #[doc(alias = "getifaddrs")]
+    #[doc(alias = "SCNetworkInterfaceCopyAll")]
+    pub fn all() -> io::Result<Vec<Self>> {
+        let mut interfaces = unsafe { read_if_addrs()? };
+
+        let mut index_of: HashMap<String, usize> = interfaces
+            .iter()
+            .enumerate()
+            .map(|(i, interface)| (interface.bsd_name.clone(), i))
+            .collect();
+
+        if let Some(sc_interfaces) = SCNetworkInterface::all() {
+            for sc_interface in sc_interfaces.iter() {
+                let bsd_name = match sc_interface.bsd_name() {
+                    Some(bsd_name) => bsd_name.to_string(),
+                    None => continue,
+                };
+
+                // Include configured interfaces that are not attached, such
+                // as a Thunderbolt bridge without a cable.
+                let index = *index_of.entry(bsd_name.clone()).or_insert_with(|| {
+                    interfaces.push(Self::new(bsd_name));
+                    interfaces.len() - 1
+                });
+                let interface = &mut interfaces[index];
+
+                interface.name = sc_interface
+                    .localized_display_name()
+                    .map(ToString::to_string);
+                interface.kind = sc_interface.interface_type();
+                if interface.mac_address.is_none() {
+                    interface.mac_address = sc_interface
+                        .hardware_address_string()
+                        .and_then(|address| parse_mac_address(&address.to_cow_str()));
+                }
+            }
+        }
+
+        for (bsd_name, order) in service_order() {
+            if let Some(&index) = index_of.get(&bsd_name) {
+                interfaces[index].service_order = Some(order);
+            }
+        }
+
+        Ok(interfaces)
+    }
+
+    fn new(bsd_name: String) -> Self {
+        Self {
+            bsd_name,
+            name: None,
+            kind: None,
+            mac_address: None,
+            addresses: Vec::new(),
+            service_order: None,
+            is_up: false,
+            is_loopback: false,
+        }
+    }
+}
+
+/// Returns the BSD names of the interfaces of enabled services in the current
+/// network location, with their positions in the service order.
+fn service_order() -> Vec<(String, usize)> {
+    unsafe fn copy(object: *const CFType<'static>) -> Option<Arc<CFType<'static>>> {
+        if object.is_null() {
+            None
+        } else {
+            Some(Arc::from_raw(object))
+        }
+    }
+
+    unsafe {
+        let name = CFString::from_static_str("fruity");
+        let prefs = match copy(sys::SCPreferencesCreate(ptr::null(), &name, ptr::null())) {
+            Some(prefs) => prefs,
+            None => return Vec::new(),
+        };
+        let set = match copy(sys::SCNetworkSetCopyCurrent(&prefs)) {
+            Some(set) => set,
+            None => return Vec::new(),
+        };
+
+        let order = match sys::SCNetworkSetGetServiceOrder(&set).as_ref() {
+            Some(order) => order,
+            None => return Vec::new(),
+        };
+        let services = match sys::SCNetworkSetCopyServices(&set) {
+            services if services.is_null() => return Vec::new(),
+            services => Arc::from_raw(services),
+        };
+
+        services
+            .iter()
+            .filter(|&service| sys::SCNetworkServiceGetEnabled(service) != 0)
+            .filter_map(|service| {
+                let id = sys::SCNetworkServiceGetServiceID(service).as_ref()?;
+                let position = order.iter().position(|other| other == id)?;
+                let interface = sys::SCNetworkServiceGetInterface(service).as_ref()?;
+                let bsd_name = interface.bsd_name()?.to_string();
+                Some((bsd_name, position))
+            })
+            .collect()
+    }
+}
+
+/// Reads the interfaces and their addresses from the kernel.
+unsafe fn read_if_addrs() -> io::Result<Vec<NetworkInterface>> {
+    let mut list = ptr::null_mut();
+    if getifaddrs(&mut list) != 0 {
+        return Err(io::Error::last_os_error());
+    }
+
+    let mut interfaces = Vec::<NetworkInterface>::new();
+    let mut next = list;
+
+    while let Some(entry) = next.as_ref() {
+        next = entry.ifa_next;
+
+        let bsd_name = CStr::from_ptr(entry.ifa_name).to_string_lossy();
+        let interface = match interfaces.iter().position(|i| i.bsd_name == bsd_name) {
+            Some(index) => &mut interfaces[index],
+            None => {
+                interfaces.push(NetworkInterface::new(bsd_name.into_owned()));
+                interfaces.last_mut().unwrap()
+            }
+        };
+
+        interface.is_up = entry.ifa_flags & IFF_UP != 0;
+        interface.is_loopback = entry.ifa_flags & IFF_LOOPBACK != 0;
+
+        let address = match entry.ifa_addr.as_ref() {
+            Some(address) => address,
+            None => continue,
+        };
+
+        match c_int::from(address.sa_family) {
+            AF_INET => {
+                let address = &*(address as *const sockaddr as *const sockaddr_in);
+                let octets = address.sin_addr.to_ne_bytes();
+                interface.addresses.push(Ipv4Addr::from(octets).into());
+            }
+            AF_INET6 => {
+                let address = &*(address as *const sockaddr as *const sockaddr_in6);
+                interface
+                    .addresses
+                    .push(Ipv6Addr::from(address.sin6_addr).into());
+            }
+            AF_LINK => {
+                let address = &*(address as *const sockaddr as *const sockaddr_dl);
+                if address.sdl_alen == 6 {
+                    // The address follows the name, and may extend past the
+                    // declared length of `sdl_data`.
+                    let data = address.sdl_data.as_ptr().cast::<u8>();
+                    let mut mac = [0; 6];
+                    ptr::copy_nonoverlapping(
+                        data.add(address.sdl_nlen as usize),
+                        mac.as_mut_ptr(),
+                        mac.len(),
+                    );
+                    interface.mac_address = Some(mac);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    freeifaddrs(list);
+    Ok(interfaces)
+}
+
+/// Parses a MAC address like `"a4:83:e7:01:02:03"`.
+fn parse_mac_address(s: &str) -> Option<[u8; 6]> {
+    let mut mac = [0; 6];
+    let mut parts = s.split(':');
+    for byte in &mut mac {
+        *byte = u8::from_str_radix(parts.next()?, 16).ok()?;
+    }
+    match parts.next() {
+        Some(_) => None,
+        None => Some(mac),
+    }
+}
+
+const AF_INET: c_int = 2;
+const AF_LINK: c_int = 18;
+const AF_INET6: c_int = 30;
+
+const IFF_UP: c_uint = 0x1;
+const IFF_LOOPBACK: c_uint = 0x8;
+
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct ifaddrs {
+    ifa_next: *mut ifaddrs,
+    ifa_name: *mut c_char,
+    ifa_flags: c_uint,
+    ifa_addr: *mut sockaddr,
+    ifa_netmask: *mut sockaddr,
+    ifa_dstaddr: *mut sockaddr,
+    ifa_data: *mut c_void,
+}
+
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct sockaddr {
+    sa_len: u8,
+    sa_family: u8,
+    sa_data: [c_char; 14],
+}
+
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct sockaddr_in {
+    sin_len: u8,
+    sin_family: u8,
+    sin_port: u16,
+    sin_addr: u32,
+    sin_zero: [c_char; 8],
+}
+
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct sockaddr_in6 {
+    sin6_len: u8,
+    sin6_family: u8,
+    sin6_port: u16,
+    sin6_flowinfo: u32,
+    sin6_addr: [u8; 16],
+    sin6_scope_id: u32,
+}
+
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct sockaddr_dl {
+    sdl_len: u8,
+    sdl_family: u8,
+    sdl_index: u16,
+    sdl_type: u8,
+    sdl_nlen: u8,
+    sdl_alen: u8,
+    sdl_slen: u8,
+    sdl_data: [c_char; 12],
+}
+
+extern "C" {
+    fn getifaddrs(ifap: *mut *mut ifaddrs) -> c_int;
+    fn freeifaddrs(ifp: *mut ifaddrs);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_mac_address_strings() {
+        assert_eq!(
+            parse_mac_address("a4:83:e7:01:02:0f"),
+            Some([0xa4, 0x83, 0xe7, 0x01, 0x02, 0x0f])
+        );
+        assert_eq!(parse_mac_address("a4:83:e7:01:02"), None);
+        assert_eq!(parse_mac_address("a4:83:e7:01:02:03:04"), None);
+        assert_eq!(parse_mac_address("a4:83:e7:01:02:zz"), None);
+    }
+}
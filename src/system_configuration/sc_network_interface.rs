@@ -0,0 +1,196 @@
+use super::sys;
+use crate::core::Arc;
+use crate::core_foundation::{CFArray, CFString, CFType, CFTypeID};
+
+subclass! {
+    /// A network interface known to System Configuration, such as Wi-Fi or
+    /// a VPN.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/systemconfiguration/scnetworkinterface).
+    #[derive(PartialEq, Hash)]
+    pub class SCNetworkInterface: CFType<'static>;
+}
+
+impl SCNetworkInterface {
+    /// Returns the type identifier for `SCNetworkInterface`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/systemconfiguration/1516957-scnetworkinterfacegettypeid).
+    #[inline]
+    #[doc(alias = "SCNetworkInterfaceGetTypeID")]
+    pub fn type_id() -> CFTypeID {
+        unsafe { sys::SCNetworkInterfaceGetTypeID() }
+    }
+
+    /// Returns all interfaces that can be configured, or [`None`] if they
+    /// could not be read.
+    ///
+    /// Interfaces without a configuration, such as loopback, are not included.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/systemconfiguration/1517090-scnetworkinterfacecopyall).
+    #[inline]
+    #[doc(alias = "SCNetworkInterfaceCopyAll")]
+    pub fn all() -> Option<Arc<CFArray<Self>>> {
+        unsafe {
+            let interfaces = sys::SCNetworkInterfaceCopyAll();
+            if interfaces.is_null() {
+                None
+            } else {
+                Some(Arc::from_raw(interfaces))
+            }
+        }
+    }
+
+    /// Returns the BSD name of the interface, such as `en0`, or [`None`] for
+    /// interfaces that are layered on others, such as PPP.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/systemconfiguration/1516962-scnetworkinterfacegetbsdname).
+    #[inline]
+    #[doc(alias = "SCNetworkInterfaceGetBSDName")]
+    pub fn bsd_name(&self) -> Option<&CFString> {
+        unsafe { sys::SCNetworkInterfaceGetBSDName(self).as_ref() }
+    }
+
+    /// Returns the kind of hardware or protocol of the interface, or
+    /// [`None`] if it is not known to this crate.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/systemconfiguration/1517371-scnetworkinterfacegetinterfacety).
+    #[inline]
+    #[doc(alias = "SCNetworkInterfaceGetInterfaceType")]
+    pub fn interface_type(&self) -> Option<SCNetworkInterfaceType> {
+        let kind = unsafe { sys::SCNetworkInterfaceGetInterfaceType(self).as_ref()? };
+        SCNetworkInterfaceType::from_cf_string(kind)
+    }
+
+    /// Returns the name of the interface shown to users, such as "Wi-Fi".
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/systemconfiguration/1517060-scnetworkinterfacegetlocalizeddi).
+    #[inline]
+    #[doc(alias = "SCNetworkInterfaceGetLocalizedDisplayName")]
+    pub fn localized_display_name(&self) -> Option<&CFString> {
+        unsafe { sys::SCNetworkInterfaceGetLocalizedDisplayName(self).as_ref() }
+    }
+
+    /// Returns the hardware address of the interface as a string of
+    /// colon-separated hex bytes, such as `"a4:83:e7:01:02:03"`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/systemconfiguration/1516935-scnetworkinterfacegethardwareadd).
+    #[inline]
+    #[doc(alias = "SCNetworkInterfaceGetHardwareAddressString")]
+    pub fn hardware_address_string(&self) -> Option<&CFString> {
+        unsafe { sys::SCNetworkInterfaceGetHardwareAddressString(self).as_ref() }
+    }
+
+    /// Returns the interface that this interface is layered on, such as the
+    /// Ethernet interface beneath PPPoE.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/systemconfiguration/1517385-scnetworkinterfacegetinterface).
+    #[inline]
+    #[doc(alias = "SCNetworkInterfaceGetInterface")]
+    pub fn underlying_interface(&self) -> Option<&SCNetworkInterface> {
+        unsafe { sys::SCNetworkInterfaceGetInterface(self).as_ref() }
+    }
+}
+
+/// The kind of hardware or protocol of an [`SCNetworkInterface`].
+///
+/// See [documentation](https://developer.apple.com/documentation/systemconfiguration/scnetworkinterface/network_interface_types).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SCNetworkInterfaceType {
+    /// Wired Ethernet, including Thunderbolt and USB adapters.
+    #[doc(alias = "kSCNetworkInterfaceTypeEthernet")]
+    Ethernet,
+
+    /// Wi-Fi.
+    #[doc(alias = "kSCNetworkInterfaceTypeIEEE80211")]
+    IEEE80211,
+
+    /// Bluetooth personal area networking.
+    #[doc(alias = "kSCNetworkInterfaceTypeBluetooth")]
+    Bluetooth,
+
+    /// Ethernet interfaces aggregated into one link.
+    #[doc(alias = "kSCNetworkInterfaceTypeBond")]
+    Bond,
+
+    /// Interfaces bridged into one network.
+    #[doc(alias = "kSCNetworkInterfaceTypeBridge")]
+    Bridge,
+
+    /// A virtual LAN on an Ethernet interface.
+    #[doc(alias = "kSCNetworkInterfaceTypeVLAN")]
+    VLAN,
+
+    /// FireWire.
+    #[doc(alias = "kSCNetworkInterfaceTypeFireWire")]
+    FireWire,
+
+    /// A cellular modem.
+    #[doc(alias = "kSCNetworkInterfaceTypeWWAN")]
+    WWAN,
+
+    /// A dial-up modem.
+    #[doc(alias = "kSCNetworkInterfaceTypeModem")]
+    Modem,
+
+    /// A serial port.
+    #[doc(alias = "kSCNetworkInterfaceTypeSerial")]
+    Serial,
+
+    /// Point-to-Point Protocol, such as PPPoE.
+    #[doc(alias = "kSCNetworkInterfaceTypePPP")]
+    PPP,
+
+    /// An IPsec VPN.
+    #[doc(alias = "kSCNetworkInterfaceTypeIPSec")]
+    IPSec,
+
+    /// An L2TP VPN.
+    #[doc(alias = "kSCNetworkInterfaceTypeL2TP")]
+    L2TP,
+
+    /// A PPTP VPN.
+    #[doc(alias = "kSCNetworkInterfaceTypePPTP")]
+    PPTP,
+
+    /// An IPv6 over IPv4 tunnel.
+    #[doc(alias = "kSCNetworkInterfaceType6to4")]
+    SixToFour,
+}
+
+impl SCNetworkInterfaceType {
+    const NAMES: &'static [(Self, &'static str)] = &[
+        (Self::Ethernet, "Ethernet"),
+        (Self::IEEE80211, "IEEE80211"),
+        (Self::Bluetooth, "Bluetooth"),
+        (Self::Bond, "Bond"),
+        (Self::Bridge, "Bridge"),
+        (Self::VLAN, "VLAN"),
+        (Self::FireWire, "FireWire"),
+        (Self::WWAN, "WWAN"),
+        (Self::Modem, "Modem"),
+        (Self::Serial, "Serial"),
+        (Self::PPP, "PPP"),
+        (Self::IPSec, "IPSec"),
+        (Self::L2TP, "L2TP"),
+        (Self::PPTP, "PPTP"),
+        (Self::SixToFour, "6to4"),
+    ];
+
+    fn from_cf_string(kind: &CFString) -> Option<Self> {
+        Self::NAMES
+            .iter()
+            .find(|(_, name)| kind == *name)
+            .map(|&(kind, _)| kind)
+    }
+
+    /// Returns the string value of the `kSCNetworkInterfaceType` constant for
+    /// this type, such as `"IEEE80211"`.
+    #[inline]
+    pub fn as_str(self) -> &'static str {
+        Self::NAMES
+            .iter()
+            .find(|&&(kind, _)| kind == self)
+            .map(|&(_, name)| name)
+            .unwrap_or_default()
+    }
+}
@@ -0,0 +1,39 @@
+//! Raw unsafe C functions exposed by `SystemConfiguration.framework`.
+
+#[cfg(target_os = "macos")]
+use super::SCNetworkInterface;
+#[cfg(target_os = "macos")]
+use crate::core_foundation::{Boolean, CFAllocator, CFArray, CFString, CFType, CFTypeID};
+
+#[allow(missing_docs)]
+#[cfg(target_os = "macos")]
+#[link(name = "SystemConfiguration", kind = "framework")]
+extern "C" {
+    pub fn SCNetworkInterfaceGetTypeID() -> CFTypeID;
+    pub fn SCNetworkInterfaceCopyAll() -> *const CFArray<SCNetworkInterface>;
+    pub fn SCNetworkInterfaceGetBSDName(interface: &SCNetworkInterface) -> *const CFString;
+    pub fn SCNetworkInterfaceGetInterfaceType(interface: &SCNetworkInterface) -> *const CFString;
+    pub fn SCNetworkInterfaceGetLocalizedDisplayName(
+        interface: &SCNetworkInterface,
+    ) -> *const CFString;
+    pub fn SCNetworkInterfaceGetHardwareAddressString(
+        interface: &SCNetworkInterface,
+    ) -> *const CFString;
+    pub fn SCNetworkInterfaceGetInterface(
+        interface: &SCNetworkInterface,
+    ) -> *const SCNetworkInterface;
+
+    pub fn SCPreferencesCreate(
+        allocator: *const CFAllocator,
+        name: &CFString,
+        prefs_id: *const CFString,
+    ) -> *const CFType<'static>;
+
+    pub fn SCNetworkSetCopyCurrent(prefs: &CFType<'static>) -> *const CFType<'static>;
+    pub fn SCNetworkSetGetServiceOrder(set: &CFType<'static>) -> *const CFArray<CFString>;
+    pub fn SCNetworkSetCopyServices(set: &CFType<'static>) -> *const CFArray;
+
+    pub fn SCNetworkServiceGetServiceID(service: &CFType<'static>) -> *const CFString;
+    pub fn SCNetworkServiceGetInterface(service: &CFType<'static>) -> *const SCNetworkInterface;
+    pub fn SCNetworkServiceGetEnabled(service: &CFType<'static>) -> Boolean;
+}
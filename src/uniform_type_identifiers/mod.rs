@@ -0,0 +1,22 @@
+//! [Uniform Type Identifiers](https://developer.apple.com/documentation/uniformtypeidentifiers)
+//! framework.
+//!
+//! # Feature Flag
+//!
+//! This module corresponds to the **`uniform_type_identifiers`**
+//! [feature flag](../index.html#feature-flags).
+//!
+//! It also transitively enables [`foundation`](crate::foundation) and
+//! [`core_services`](crate::core_services).
+//!
+//! # Compatibility
+//!
+//! The framework is only available on macOS 11 and later. On older versions,
+//! [`UTType`] falls back to the deprecated `UTType*` functions of Core
+//! Services, which behave the same for the operations provided here.
+
+#![cfg(feature = "uniform_type_identifiers")]
+
+mod ut_type;
+
+pub use ut_type::*;
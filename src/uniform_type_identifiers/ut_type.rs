@@ -0,0 +1,429 @@
+use crate::core::Arc;
+use crate::core_foundation::CFString;
+use crate::core_services::sys;
+use crate::foundation::{NSComparisonResult, NSString};
+use crate::objc::{Class, NSObject, BOOL};
+use std::{
+    ffi::CStr,
+    fmt,
+    os::raw::{c_char, c_int, c_void},
+    ptr,
+    sync::{
+        atomic::{AtomicPtr, Ordering},
+        Once,
+    },
+};
+
+/// A type of data, such as a file format, identified by a reverse-DNS
+/// string such as `public.png`.
+///
+/// Types form a hierarchy through [conformance](Self::conforms_to): for
+/// example, `public.png` conforms to `public.image`, which conforms to
+/// `public.data`.
+///
+/// See [documentation](https://developer.apple.com/documentation/uniformtypeidentifiers/uttype-swift.struct).
+#[derive(Clone)]
+pub struct UTType {
+    identifier: Arc<NSString<'static>>,
+}
+
+impl fmt::Debug for UTType {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("UTType").field(&self.identifier).finish()
+    }
+}
+
+impl fmt::Display for UTType {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.identifier.fmt(f)
+    }
+}
+
+impl PartialEq for UTType {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        // Identifiers are case-insensitive.
+        self.identifier.case_insensitive_compare(&other.identifier)
+            == NSComparisonResult::OrderedSame
+    }
+}
+
+impl Eq for UTType {}
+
+/// Returns the `UTType` class if the framework is available.
+fn modern_class() -> Option<&'static Class> {
+    const PATH: &[u8] =
+        b"/System/Library/Frameworks/UniformTypeIdentifiers.framework/UniformTypeIdentifiers\0";
+    const RTLD_LAZY: c_int = 0x1;
+
+    extern "C" {
+        fn dlopen(path: *const c_char, mode: c_int) -> *mut c_void;
+    }
+
+    static CLASS: AtomicPtr<Class> = AtomicPtr::new(ptr::null_mut());
+    static INIT: Once = Once::new();
+
+    INIT.call_once(|| {
+        // The framework is loaded at runtime rather than linked, so that
+        // binaries still launch on versions without it.
+        unsafe { dlopen(PATH.as_ptr().cast(), RTLD_LAZY) };
+
+        let name = CStr::from_bytes_with_nul(b"UTType\0").unwrap();
+        if let Some(class) = Class::get(name) {
+            CLASS.store(class as *const Class as *mut Class, Ordering::Release);
+        }
+    });
+
+    unsafe { CLASS.load(Ordering::Acquire).as_ref() }
+}
+
+macro_rules! types {
+    ($(
+        $(#[$docs:meta])+
+        $fn:ident $identifier:literal
+    )+) => {
+        $(
+            $(#[$docs])+
+            #[inline]
+            #[doc(alias = $identifier)]
+            pub fn $fn() -> Self {
+                Self::from_identifier($crate::ns_string!($identifier))
+            }
+        )+
+    };
+}
+
+/// Common types.
+impl UTType {
+    types! {
+        /// The base type of all physical items, such as files and folders.
+        item "public.item"
+
+        /// The base type of all content that can be opened, such as
+        /// documents and media.
+        content "public.content"
+
+        /// The base type of all byte streams, such as regular files.
+        data "public.data"
+
+        /// A directory, including packages and bundles.
+        directory "public.directory"
+
+        /// A directory that is not a package, as shown in Finder.
+        folder "public.folder"
+
+        /// A directory that is shown as a single file, such as an app.
+        package "com.apple.package"
+
+        /// An app bundle.
+        application_bundle "com.apple.application-bundle"
+
+        /// An executable file.
+        executable "public.executable"
+
+        /// A symbolic link.
+        symbolic_link "public.symlink"
+
+        /// The base type of all text.
+        text "public.text"
+
+        /// Text with no markup, in an unspecified encoding.
+        plain_text "public.plain-text"
+
+        /// Plain text in UTF-8.
+        utf8_plain_text "public.utf8-plain-text"
+
+        /// Source code of any programming language.
+        source_code "public.source-code"
+
+        /// A shell script.
+        shell_script "public.shell-script"
+
+        /// JSON.
+        json "public.json"
+
+        /// XML.
+        xml "public.xml"
+
+        /// HTML.
+        html "public.html"
+
+        /// A property list, in any format.
+        property_list "com.apple.property-list"
+
+        /// A URL.
+        url "public.url"
+
+        /// A URL to a file.
+        file_url "public.file-url"
+
+        /// The base type of all images.
+        image "public.image"
+
+        /// A PNG image.
+        png "public.png"
+
+        /// A JPEG image.
+        jpeg "public.jpeg"
+
+        /// A PDF document.
+        pdf "com.adobe.pdf"
+
+        /// The base type of all audio.
+        audio "public.audio"
+
+        /// The base type of all video, with or without audio.
+        movie "public.movie"
+
+        /// The base type of all archives, such as zip files.
+        archive "public.archive"
+
+        /// A zip archive.
+        zip "public.zip-archive"
+    }
+}
+
+impl UTType {
+    /// Returns the type with `identifier`, such as `public.png`.
+    ///
+    /// This does not check that the type is [declared](Self::is_declared).
+    #[inline]
+    pub fn from_identifier(identifier: &NSString<'static>) -> Self {
+        Self {
+            identifier: Arc::retain(identifier),
+        }
+    }
+
+    /// Returns the type for files with `extension`, such as `png`.
+    ///
+    /// Only types conforming to `conforming_to` are considered, which
+    /// defaults to [`data`](Self::data). If no declared type matches, a
+    /// [dynamic](Self::is_dynamic) type is returned. [`None`] is returned if
+    /// the extension cannot be represented, such as if it is empty.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/uniformtypeidentifiers/uttype-swift.struct/init(filenameextension:conformingto:)).
+    #[doc(alias = "typeWithFilenameExtension:conformingToType:")]
+    #[doc(alias = "UTTypeCreatePreferredIdentifierForTag")]
+    pub fn from_filename_extension(
+        extension: &NSString,
+        conforming_to: Option<&UTType>,
+    ) -> Option<Self> {
+        let data;
+        let conforming_to = match conforming_to {
+            Some(ty) => ty,
+            None => {
+                data = Self::data();
+                &data
+            }
+        };
+
+        match (modern_class(), conforming_to.modern()) {
+            (Some(class), Some(conforming_to)) => unsafe {
+                Self::from_modern(_msg_send_any![
+                    class,
+                    typeWithFilenameExtension: extension
+                    conformingToType: &*conforming_to
+                    => *const NSObject<'static>
+                ])
+            },
+            _ => unsafe {
+                Self::from_tag(sys::kUTTagClassFilenameExtension, extension, conforming_to)
+            },
+        }
+    }
+
+    /// Returns the type for data with `mime_type`, such as `image/png`.
+    ///
+    /// Only types conforming to `conforming_to` are considered, which
+    /// defaults to [`data`](Self::data). If no declared type matches, a
+    /// [dynamic](Self::is_dynamic) type is returned. [`None`] is returned if
+    /// the MIME type cannot be represented, such as if it is empty.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/uniformtypeidentifiers/uttype-swift.struct/init(mimetype:conformingto:)).
+    #[doc(alias = "typeWithMIMEType:conformingToType:")]
+    #[doc(alias = "UTTypeCreatePreferredIdentifierForTag")]
+    pub fn from_mime_type(mime_type: &NSString, conforming_to: Option<&UTType>) -> Option<Self> {
+        let data;
+        let conforming_to = match conforming_to {
+            Some(ty) => ty,
+            None => {
+                data = Self::data();
+                &data
+            }
+        };
+
+        match (modern_class(), conforming_to.modern()) {
+            (Some(class), Some(conforming_to)) => unsafe {
+                Self::from_modern(_msg_send_any![
+                    class,
+                    typeWithMIMEType: mime_type
+                    conformingToType: &*conforming_to
+                    => *const NSObject<'static>
+                ])
+            },
+            _ => unsafe { Self::from_tag(sys::kUTTagClassMIMEType, mime_type, conforming_to) },
+        }
+    }
+
+    /// Returns the identifier of the type, such as `public.png`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/uniformtypeidentifiers/uttype-swift.struct/identifier).
+    #[inline]
+    pub fn identifier(&self) -> &NSString<'static> {
+        &self.identifier
+    }
+
+    /// Returns the preferred filename extension of the type, such as `png`,
+    /// or [`None`] if it has none.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/uniformtypeidentifiers/uttype-swift.struct/preferredfilenameextension).
+    #[doc(alias = "preferredFilenameExtension")]
+    #[doc(alias = "UTTypeCopyPreferredTagWithClass")]
+    pub fn preferred_filename_extension(&self) -> Option<Arc<NSString<'static>>> {
+        match self.modern() {
+            Some(modern) => unsafe {
+                _msg_send_any![modern, preferredFilenameExtension => *const NSString<'static>]
+                    .as_ref()
+                    .map(Arc::retain)
+            },
+            None => unsafe { self.preferred_tag(sys::kUTTagClassFilenameExtension) },
+        }
+    }
+
+    /// Returns the preferred MIME type of the type, such as `image/png`, or
+    /// [`None`] if it has none.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/uniformtypeidentifiers/uttype-swift.struct/preferredmimetype).
+    #[doc(alias = "preferredMIMEType")]
+    #[doc(alias = "UTTypeCopyPreferredTagWithClass")]
+    pub fn preferred_mime_type(&self) -> Option<Arc<NSString<'static>>> {
+        match self.modern() {
+            Some(modern) => unsafe {
+                _msg_send_any![modern, preferredMIMEType => *const NSString<'static>]
+                    .as_ref()
+                    .map(Arc::retain)
+            },
+            None => unsafe { self.preferred_tag(sys::kUTTagClassMIMEType) },
+        }
+    }
+
+    /// Returns `true` if `self` is `other` or a subtype of it.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/uniformtypeidentifiers/uttype-swift.struct/conforms(to:)).
+    #[doc(alias = "conformsToType:")]
+    #[doc(alias = "UTTypeConformsTo")]
+    pub fn conforms_to(&self, other: &UTType) -> bool {
+        match (self.modern(), other.modern()) {
+            (Some(modern), Some(other)) => {
+                unsafe { _msg_send_any![modern, conformsToType: other => BOOL] }.into()
+            }
+            _ => unsafe { sys::UTTypeConformsTo(self.cf_identifier(), other.cf_identifier()) != 0 },
+        }
+    }
+
+    /// Returns a localized description of the type, such as `PNG image`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/uniformtypeidentifiers/uttype-swift.struct/localizeddescription).
+    #[doc(alias = "localizedDescription")]
+    #[doc(alias = "UTTypeCopyDescription")]
+    pub fn localized_description(&self) -> Option<Arc<NSString<'static>>> {
+        match self.modern() {
+            Some(modern) => unsafe {
+                _msg_send_any![modern, localizedDescription => *const NSString<'static>]
+                    .as_ref()
+                    .map(Arc::retain)
+            },
+            None => unsafe {
+                let description = sys::UTTypeCopyDescription(self.cf_identifier());
+                Self::cf_to_ns(description)
+            },
+        }
+    }
+
+    /// Returns `true` if the type is declared by the system or an installed
+    /// app.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/uniformtypeidentifiers/uttype-swift.struct/isdeclared).
+    #[doc(alias = "isDeclared")]
+    #[doc(alias = "UTTypeIsDeclared")]
+    pub fn is_declared(&self) -> bool {
+        match self.modern() {
+            Some(modern) => unsafe { _msg_send_any![modern, isDeclared => BOOL] }.into(),
+            None => unsafe { sys::UTTypeIsDeclared(self.cf_identifier()) != 0 },
+        }
+    }
+
+    /// Returns `true` if the type was created for an unknown filename
+    /// extension or MIME type, with an identifier starting with `dyn.`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/uniformtypeidentifiers/uttype-swift.struct/isdynamic).
+    #[doc(alias = "isDynamic")]
+    #[doc(alias = "UTTypeIsDynamic")]
+    pub fn is_dynamic(&self) -> bool {
+        match self.modern() {
+            Some(modern) => unsafe { _msg_send_any![modern, isDynamic => BOOL] }.into(),
+            None => unsafe { sys::UTTypeIsDynamic(self.cf_identifier()) != 0 },
+        }
+    }
+}
+
+// Implementation details for each path.
+impl UTType {
+    #[inline]
+    fn cf_identifier(&self) -> &CFString {
+        let identifier: &NSString<'static> = &self.identifier;
+        identifier.as_ref()
+    }
+
+    /// Returns the `UTType` instance for `self`, or [`None`] if the framework
+    /// is unavailable.
+    fn modern(&self) -> Option<Arc<NSObject<'static>>> {
+        let class = modern_class()?;
+        unsafe {
+            _msg_send_any![class, typeWithIdentifier: &*self.identifier => *const NSObject<'static>]
+                .as_ref()
+                .map(Arc::retain)
+        }
+    }
+
+    /// Creates an instance from a `UTType` instance.
+    unsafe fn from_modern(modern: *const NSObject<'static>) -> Option<Self> {
+        let modern = modern.as_ref()?;
+        let identifier = _msg_send_any![modern, identifier => *const NSString<'static>];
+        Some(Self::from_identifier(&*identifier))
+    }
+
+    unsafe fn from_tag(
+        tag_class: &CFString,
+        tag: &NSString,
+        conforming_to: &UTType,
+    ) -> Option<Self> {
+        let identifier = sys::UTTypeCreatePreferredIdentifierForTag(
+            tag_class,
+            (tag as *const NSString).cast(),
+            conforming_to.cf_identifier(),
+        );
+        Some(Self {
+            identifier: Self::cf_to_ns(identifier)?,
+        })
+    }
+
+    unsafe fn preferred_tag(&self, tag_class: &CFString) -> Option<Arc<NSString<'static>>> {
+        Self::cf_to_ns(sys::UTTypeCopyPreferredTagWithClass(
+            self.cf_identifier(),
+            tag_class,
+        ))
+    }
+
+    /// Takes ownership of a `CFString` returned by a "Copy" or "Create"
+    /// function.
+    unsafe fn cf_to_ns(string: *const CFString) -> Option<Arc<NSString<'static>>> {
+        if string.is_null() {
+            None
+        } else {
+            Some(Arc::from_raw(string.cast()))
+        }
+    }
+}
@@ -0,0 +1,47 @@
+//! [User Notifications](https://developer.apple.com/documentation/usernotifications)
+//! framework.
+//!
+//! # Feature Flag
+//!
+//! This module corresponds to the **`user_notifications`**
+//! [feature flag](../index.html#feature-flags).
+//!
+//! It also transitively enables [`foundation`](crate::foundation).
+//!
+//! # Scope
+//!
+//! These bindings cover asking the user for permission to show
+//! notifications, and registering with the Apple Push Notification service
+//! (APNs) with [`RemoteNotifications`] to get the device token that a server
+//! sends pushes to.
+//!
+//! # Async
+//!
+//! Methods that take a completion closure have `_async` forms that return a
+//! future, built on [`call_with_completion`](crate::core::call_with_completion)
+//! and [`completion_to_future!`](crate::completion_to_future):
+//!
+//! ```no_run
+//! # async fn example() {
+//! use fruity::user_notifications::{UNAuthorizationOptions, UNUserNotificationCenter};
+//!
+//! let options = UNAuthorizationOptions::ALERT | UNAuthorizationOptions::SOUND;
+//! let granted = UNUserNotificationCenter::current()
+//!     .request_authorization_async(options)
+//!     .await;
+//! # }
+//! ```
+
+#![cfg(all(
+    feature = "user_notifications",
+    any(target_os = "macos", target_os = "ios")
+))]
+
+mod remote_notifications;
+mod un_user_notification_center;
+
+pub use remote_notifications::*;
+pub use un_user_notification_center::*;
+
+#[link(name = "UserNotifications", kind = "framework")]
+extern "C" {}
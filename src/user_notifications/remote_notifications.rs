@@ -0,0 +1,267 @@
+use crate::core::Arc;
+use crate::foundation::{NSData, NSError};
+use crate::objc::{sys, Class, ClassType, LazyClass, MethodImplementation, NSObject, Sel, BOOL};
+use std::{
+    ffi::{CStr, CString},
+    fmt, mem,
+    panic::{self, AssertUnwindSafe},
+    process,
+    sync::Mutex,
+};
+
+/// The name of the application class, which is looked up at runtime so that
+/// this module does not depend on AppKit or UIKit.
+#[cfg(target_os = "macos")]
+const APPLICATION_CLASS: &[u8] = b"NSApplication\0";
+#[cfg(not(target_os = "macos"))]
+const APPLICATION_CLASS: &[u8] = b"UIApplication\0";
+
+type Completion = dyn FnOnce(Result<DeviceToken, Arc<NSError<'static>>>) + Send;
+
+/// The completion of the pending registration.
+static COMPLETION: Mutex<Option<Box<Completion>>> = Mutex::new(None);
+
+/// Registration with the Apple Push Notification service (APNs), which
+/// provides the [`DeviceToken`] that a server sends pushes to.
+///
+/// The app must be signed with the `aps-environment` entitlement.
+///
+/// The token is delivered to the application delegate. If the delegate does
+/// not implement
+/// `application:didRegisterForRemoteNotificationsWithDeviceToken:` and
+/// `application:didFailToRegisterForRemoteNotificationsWithError:`,
+/// implementations that call the completion of [`register`](Self::register)
+/// are added to its class. If it does, it must call
+/// [`did_register`](Self::did_register) and
+/// [`did_fail_to_register`](Self::did_fail_to_register).
+///
+/// # Examples
+///
+/// ```no_run
+/// use fruity::user_notifications::RemoteNotifications;
+///
+/// RemoteNotifications::register(|result| match result {
+///     Ok(token) => println!("send pushes to {}", token),
+///     Err(error) => eprintln!("failed to register: {}", error),
+/// });
+/// ```
+///
+/// Documentation:
+/// [macOS](https://developer.apple.com/documentation/appkit/nsapplication/registerforremotenotifications()) |
+/// [iOS](https://developer.apple.com/documentation/uikit/uiapplication/registerforremotenotifications())
+#[derive(Debug)]
+pub enum RemoteNotifications {}
+
+impl RemoteNotifications {
+    /// Registers the app with APNs, calling `completion` with the device
+    /// token or the reason registration failed.
+    ///
+    /// This must be called on the main thread after the app finished
+    /// launching. The token may change, so this should be called on every
+    /// launch. Registering again before `completion` is called replaces it.
+    ///
+    /// It is safe to panic within `completion`. Panics will abort the process.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the process is not an app, where the application class is
+    /// not loaded.
+    #[doc(alias = "registerForRemoteNotifications")]
+    pub fn register<F>(completion: F)
+    where
+        F: FnOnce(Result<DeviceToken, Arc<NSError<'static>>>) + Send + 'static,
+    {
+        *COMPLETION.lock().unwrap_or_else(|error| error.into_inner()) = Some(Box::new(completion));
+
+        let app = shared_application();
+        unsafe {
+            install_delegate_methods(app);
+            _msg_send_any![app, registerForRemoteNotifications => ()];
+        }
+    }
+
+    /// Unregisters the app from APNs, so that pushes are no longer delivered.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the process is not an app.
+    #[inline]
+    #[doc(alias = "unregisterForRemoteNotifications")]
+    pub fn unregister() {
+        unsafe { _msg_send_any![shared_application(), unregisterForRemoteNotifications => ()] }
+    }
+
+    /// Returns `true` if the app successfully registered with APNs.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the process is not an app.
+    #[inline]
+    #[doc(alias = "isRegisteredForRemoteNotifications")]
+    pub fn is_registered() -> bool {
+        unsafe {
+            _msg_send_any![
+                shared_application(),
+                isRegisteredForRemoteNotifications => BOOL
+            ]
+        }
+        .into()
+    }
+
+    /// Calls the completion of [`register`](Self::register) with `token`,
+    /// for application delegates that implement
+    /// `application:didRegisterForRemoteNotificationsWithDeviceToken:`.
+    #[doc(alias = "application:didRegisterForRemoteNotificationsWithDeviceToken:")]
+    pub fn did_register(token: &NSData) {
+        complete(Ok(DeviceToken(token.to_vec())));
+    }
+
+    /// Calls the completion of [`register`](Self::register) with `error`,
+    /// for application delegates that implement
+    /// `application:didFailToRegisterForRemoteNotificationsWithError:`.
+    #[doc(alias = "application:didFailToRegisterForRemoteNotificationsWithError:")]
+    pub fn did_fail_to_register(error: &NSError<'static>) {
+        complete(Err(Arc::retain(error)));
+    }
+}
+
+/// The address of a device for push notifications from a server.
+///
+/// Servers typically expect the token as a hexadecimal string, which is how
+/// it is formatted with [`Display`](fmt::Display).
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct DeviceToken(Vec<u8>);
+
+impl fmt::Debug for DeviceToken {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("DeviceToken")
+            .field(&format_args!("{}", self))
+            .finish()
+    }
+}
+
+impl fmt::Display for DeviceToken {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.iter().try_for_each(|byte| write!(f, "{:02x}", byte))
+    }
+}
+
+impl DeviceToken {
+    /// Returns the bytes of the token.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Returns the token as a lowercase hexadecimal string.
+    #[inline]
+    pub fn to_hex(&self) -> String {
+        self.to_string()
+    }
+
+    /// Returns the bytes of the token.
+    #[inline]
+    pub fn into_vec(self) -> Vec<u8> {
+        self.0
+    }
+}
+
+/// Calls the pending completion, if any, with `result`.
+fn complete(result: Result<DeviceToken, Arc<NSError<'static>>>) {
+    let completion = COMPLETION
+        .lock()
+        .unwrap_or_else(|error| error.into_inner())
+        .take();
+
+    if let Some(completion) = completion {
+        // Unwinding into Objective-C is undefined behavior.
+        if panic::catch_unwind(AssertUnwindSafe(|| completion(result))).is_err() {
+            process::abort();
+        }
+    }
+}
+
+fn shared_application() -> &'static NSObject<'static> {
+    let name = unsafe { CStr::from_bytes_with_nul_unchecked(APPLICATION_CLASS) };
+    let class = Class::get(name).expect("application class is not loaded");
+    unsafe { _msg_send_any![class, sharedApplication] }
+}
+
+type DidRegisterImp =
+    extern "C" fn(&NSObject<'static>, Sel, *const NSObject<'static>, *const NSData);
+
+extern "C" fn did_register(
+    _: &NSObject<'static>,
+    _: Sel,
+    _app: *const NSObject<'static>,
+    token: *const NSData,
+) {
+    RemoteNotifications::did_register(unsafe { &*token });
+}
+
+type DidFailToRegisterImp =
+    extern "C" fn(&NSObject<'static>, Sel, *const NSObject<'static>, *const NSError<'static>);
+
+extern "C" fn did_fail_to_register(
+    _: &NSObject<'static>,
+    _: Sel,
+    _app: *const NSObject<'static>,
+    error: *const NSError<'static>,
+) {
+    RemoteNotifications::did_fail_to_register(unsafe { &*error });
+}
+
+/// Adds the registration callbacks to the delegate of `app`, or sets a
+/// delegate that implements them if there is none.
+unsafe fn install_delegate_methods(app: &NSObject<'static>) {
+    let delegate = _msg_send_any![app, delegate => *const NSObject<'static>];
+
+    if delegate.is_null() {
+        let delegate: Arc<NSObject<'static>> = delegate_class().alloc_init();
+        _msg_send_any![app, setDelegate: &*delegate => ()];
+
+        // The application does not retain its delegate.
+        mem::forget(delegate);
+        return;
+    }
+
+    let class = sys::object_getClass(delegate.cast()) as *mut Class;
+
+    add_method_if_missing(
+        class,
+        selector!(application:didRegisterForRemoteNotificationsWithDeviceToken:),
+        did_register as DidRegisterImp,
+    );
+    add_method_if_missing(
+        class,
+        selector!(application:didFailToRegisterForRemoteNotificationsWithError:),
+        did_fail_to_register as DidFailToRegisterImp,
+    );
+}
+
+unsafe fn add_method_if_missing<F: MethodImplementation>(class: *mut Class, sel: Sel, imp: F) {
+    if (*class).instances_respond_to_selector(sel) {
+        return;
+    }
+
+    let encoding = CString::new(F::type_encoding()).expect("encoding contains a nul byte");
+    sys::class_addMethod(class, sel, imp.imp(), encoding.as_ptr());
+}
+
+/// Returns the class of the delegate set when the application has none,
+/// registering it with the Objective-C runtime on first use.
+fn delegate_class() -> &'static Class {
+    static CLASS: LazyClass = LazyClass::new("FruityRemoteNotificationsDelegate");
+
+    CLASS.get(NSObject::class(), |builder| unsafe {
+        builder.add_method(
+            selector!(application:didRegisterForRemoteNotificationsWithDeviceToken:),
+            did_register as DidRegisterImp,
+        );
+        builder.add_method(
+            selector!(application:didFailToRegisterForRemoteNotificationsWithError:),
+            did_fail_to_register as DidFailToRegisterImp,
+        );
+    })
+}
@@ -0,0 +1,185 @@
+use crate::core::{call_with_completion, Arc, Completion, FlattenCompletion};
+use crate::foundation::NSError;
+use crate::objc::{Block, ClassType, NSInteger, NSObject, NSUInteger, RcBlock, BOOL};
+use std::{cell::Cell, ops};
+
+objc_subclass! {
+    /// Manages the notifications of the app, and whether the user allows
+    /// them.
+    ///
+    /// Completion handlers are called on a background queue.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/usernotifications/unusernotificationcenter).
+    pub class UNUserNotificationCenter: NSObject<'static>;
+}
+
+impl UNUserNotificationCenter {
+    /// Returns the notification center of the app.
+    ///
+    /// # Panics
+    ///
+    /// The app must have a bundle identifier, so this raises an Objective-C
+    /// exception in command-line tools, which aborts the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/usernotifications/unusernotificationcenter/current()).
+    #[inline]
+    #[doc(alias = "currentNotificationCenter")]
+    pub fn current() -> &'static Self {
+        unsafe { _msg_send_any![Self::class(), currentNotificationCenter] }
+    }
+
+    /// Asks the user to allow the notification features in `options`,
+    /// calling `completion` with whether they were granted.
+    ///
+    /// The user is only asked the first time. Later requests complete with
+    /// the user's earlier choice, which they may change in System Settings.
+    ///
+    /// It is safe to panic within `completion`. Panics will abort the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/usernotifications/unusernotificationcenter/requestauthorization(options:completionhandler:)).
+    #[doc(alias = "requestAuthorizationWithOptions:completionHandler:")]
+    pub fn request_authorization<F>(&self, options: UNAuthorizationOptions, completion: F)
+    where
+        F: FnOnce(Result<bool, Arc<NSError<'static>>>) + Send + 'static,
+    {
+        let completion = Cell::new(Some(completion));
+        let block = RcBlock::new(move |granted: BOOL, error: *const NSError<'static>| {
+            if let Some(completion) = completion.take() {
+                completion(match unsafe { error.as_ref() } {
+                    Some(error) => Err(Arc::retain(error)),
+                    None => Ok(granted.into()),
+                });
+            }
+        });
+        let block: &Block<_, ()> = &block;
+
+        unsafe {
+            _msg_send_any![
+                self,
+                requestAuthorizationWithOptions: options
+                completionHandler: block
+                => ()
+            ]
+        }
+    }
+
+    /// Calls `completion` with whether the user allows notifications from
+    /// the app.
+    ///
+    /// It is safe to panic within `completion`. Panics will abort the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/usernotifications/unusernotificationcenter/getnotificationsettings(completionhandler:)).
+    #[doc(alias = "getNotificationSettingsWithCompletionHandler:")]
+    pub fn get_authorization_status<F>(&self, completion: F)
+    where
+        F: FnOnce(UNAuthorizationStatus) + Send + 'static,
+    {
+        let completion = Cell::new(Some(completion));
+        let block = RcBlock::new(move |settings: *const NSObject<'static>| {
+            if let Some(completion) = completion.take() {
+                let status = unsafe { _msg_send_any![&*settings, authorizationStatus] };
+                completion(status);
+            }
+        });
+        let block: &Block<_, ()> = &block;
+
+        unsafe {
+            _msg_send_any![
+                self,
+                getNotificationSettingsWithCompletionHandler: block
+                => ()
+            ]
+        }
+    }
+}
+
+/// Futures.
+impl UNUserNotificationCenter {
+    /// Asks the user to allow the notification features in `options`,
+    /// resolving to whether they were granted.
+    ///
+    /// This is the future form of
+    /// [`request_authorization`](Self::request_authorization).
+    #[inline]
+    pub fn request_authorization_async(
+        &self,
+        options: UNAuthorizationOptions,
+    ) -> FlattenCompletion<Result<bool, Arc<NSError<'static>>>> {
+        crate::completion_to_future!(self.request_authorization(options))
+    }
+
+    /// Resolves to whether the user allows notifications from the app.
+    ///
+    /// This is the future form of
+    /// [`get_authorization_status`](Self::get_authorization_status).
+    #[inline]
+    pub fn authorization_status_async(&self) -> Completion<UNAuthorizationStatus> {
+        call_with_completion(|done| self.get_authorization_status(done))
+    }
+}
+
+/// The notification features that an app asks the user to allow.
+///
+/// See [documentation](https://developer.apple.com/documentation/usernotifications/unauthorizationoptions).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct UNAuthorizationOptions(pub NSUInteger);
+
+impl ops::BitOr for UNAuthorizationOptions {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl UNAuthorizationOptions {
+    /// Updating the badge on the app's icon.
+    #[doc(alias = "UNAuthorizationOptionBadge")]
+    pub const BADGE: Self = Self(1 << 0);
+
+    /// Playing sounds.
+    #[doc(alias = "UNAuthorizationOptionSound")]
+    pub const SOUND: Self = Self(1 << 1);
+
+    /// Showing alerts.
+    #[doc(alias = "UNAuthorizationOptionAlert")]
+    pub const ALERT: Self = Self(1 << 2);
+
+    /// Delivering notifications quietly to Notification Center without
+    /// asking the user first.
+    #[doc(alias = "UNAuthorizationOptionProvisional")]
+    pub const PROVISIONAL: Self = Self(1 << 6);
+
+    /// Returns `true` if all options in `other` are set in `self`.
+    #[inline]
+    pub fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+/// Whether the user allows notifications from an app.
+///
+/// See [documentation](https://developer.apple.com/documentation/usernotifications/unauthorizationstatus).
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct UNAuthorizationStatus(pub NSInteger);
+
+impl UNAuthorizationStatus {
+    /// The user has not been asked yet.
+    #[doc(alias = "UNAuthorizationStatusNotDetermined")]
+    pub const NOT_DETERMINED: Self = Self(0);
+
+    /// The user denied notifications.
+    #[doc(alias = "UNAuthorizationStatusDenied")]
+    pub const DENIED: Self = Self(1);
+
+    /// The user allowed notifications.
+    #[doc(alias = "UNAuthorizationStatusAuthorized")]
+    pub const AUTHORIZED: Self = Self(2);
+
+    /// Notifications are delivered quietly until the user decides.
+    #[doc(alias = "UNAuthorizationStatusProvisional")]
+    pub const PROVISIONAL: Self = Self(3);
+}
@@ -0,0 +1,30 @@
+//! [XPC](https://developer.apple.com/documentation/xpc) library for
+//! interprocess communication.
+//!
+//! XPC is how apps talk to their helper tools, XPC services, and privileged
+//! daemons registered with `launchd`. Messages are [`XPCDictionary`] values
+//! sent over an [`XPCConnection`].
+//!
+//! # Feature Flag
+//!
+//! This module corresponds to the **`xpc`**
+//! [feature flag](../index.html#feature-flags).
+//!
+//! It also transitively enables [`objc`](crate::objc), for blocks, and
+//! [`dispatch`](crate::dispatch).
+//!
+//! XPC is only available on macOS.
+
+#![cfg(all(feature = "xpc", target_os = "macos"))]
+
+pub mod sys;
+
+mod xpc_array;
+mod xpc_connection;
+mod xpc_dictionary;
+mod xpc_object;
+
+pub use xpc_array::*;
+pub use xpc_connection::*;
+pub use xpc_dictionary::*;
+pub use xpc_object::*;
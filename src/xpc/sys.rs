@@ -0,0 +1,150 @@
+//! Raw unsafe C functions exposed by libxpc.
+
+use super::{XPCArray, XPCConnection, XPCDictionary, XPCEndpoint, XPCObject};
+use crate::dispatch::DispatchQueue;
+use crate::objc::Block;
+use std::os::raw::{c_char, c_int, c_void};
+
+/// The opaque type behind `xpc_type_t`.
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub struct _xpc_type_s {
+    _private: [u8; 0],
+}
+
+/// Identifies the type of an XPC object.
+#[allow(non_camel_case_types)]
+pub type xpc_type_t = *const _xpc_type_s;
+
+/// The handler of events on a connection.
+#[allow(non_camel_case_types)]
+pub type xpc_handler_t = Block<(*const XPCObject,), ()>;
+
+/// The function applied to each entry of a dictionary.
+#[allow(non_camel_case_types)]
+pub type xpc_dictionary_applier_t = Block<(*const c_char, *const XPCObject), bool>;
+
+#[allow(non_upper_case_globals, missing_docs)]
+pub const XPC_CONNECTION_MACH_SERVICE_LISTENER: u64 = 1 << 0;
+#[allow(non_upper_case_globals, missing_docs)]
+pub const XPC_CONNECTION_MACH_SERVICE_PRIVILEGED: u64 = 1 << 1;
+
+/// The key of the description in error dictionaries.
+pub const XPC_ERROR_KEY_DESCRIPTION: &[u8] = b"XPCErrorDescription\0";
+
+// These are reexported by libSystem.
+#[link(name = "System", kind = "dylib")]
+#[allow(missing_docs)]
+extern "C" {
+    pub static _xpc_type_array: _xpc_type_s;
+    pub static _xpc_type_bool: _xpc_type_s;
+    pub static _xpc_type_connection: _xpc_type_s;
+    pub static _xpc_type_data: _xpc_type_s;
+    pub static _xpc_type_dictionary: _xpc_type_s;
+    pub static _xpc_type_double: _xpc_type_s;
+    pub static _xpc_type_endpoint: _xpc_type_s;
+    pub static _xpc_type_error: _xpc_type_s;
+    pub static _xpc_type_int64: _xpc_type_s;
+    pub static _xpc_type_null: _xpc_type_s;
+    pub static _xpc_type_string: _xpc_type_s;
+    pub static _xpc_type_uint64: _xpc_type_s;
+
+    pub static _xpc_error_connection_interrupted: XPCDictionary;
+    pub static _xpc_error_connection_invalid: XPCDictionary;
+    pub static _xpc_error_termination_imminent: XPCDictionary;
+
+    pub fn xpc_retain(object: *const XPCObject) -> *const XPCObject;
+    pub fn xpc_release(object: *const XPCObject);
+    pub fn xpc_get_type(object: *const XPCObject) -> xpc_type_t;
+    pub fn xpc_type_get_name(kind: xpc_type_t) -> *const c_char;
+    pub fn xpc_copy_description(object: *const XPCObject) -> *mut c_char;
+    pub fn xpc_equal(object1: *const XPCObject, object2: *const XPCObject) -> bool;
+    pub fn xpc_hash(object: *const XPCObject) -> usize;
+
+    pub fn xpc_null_create() -> *const XPCObject;
+    pub fn xpc_bool_create(value: bool) -> *const XPCObject;
+    pub fn xpc_bool_get_value(xbool: *const XPCObject) -> bool;
+    pub fn xpc_int64_create(value: i64) -> *const XPCObject;
+    pub fn xpc_int64_get_value(xint: *const XPCObject) -> i64;
+    pub fn xpc_uint64_create(value: u64) -> *const XPCObject;
+    pub fn xpc_uint64_get_value(xuint: *const XPCObject) -> u64;
+    pub fn xpc_double_create(value: f64) -> *const XPCObject;
+    pub fn xpc_double_get_value(xdouble: *const XPCObject) -> f64;
+    pub fn xpc_string_create(string: *const c_char) -> *const XPCObject;
+    pub fn xpc_string_get_string_ptr(xstring: *const XPCObject) -> *const c_char;
+    pub fn xpc_data_create(bytes: *const c_void, length: usize) -> *const XPCObject;
+    pub fn xpc_data_get_bytes_ptr(xdata: *const XPCObject) -> *const c_void;
+    pub fn xpc_data_get_length(xdata: *const XPCObject) -> usize;
+
+    pub fn xpc_array_create(objects: *const *const XPCObject, count: usize) -> *const XPCArray;
+    pub fn xpc_array_get_count(xarray: *const XPCArray) -> usize;
+    pub fn xpc_array_get_value(xarray: *const XPCArray, index: usize) -> *const XPCObject;
+    pub fn xpc_array_append_value(xarray: *const XPCArray, value: *const XPCObject);
+
+    pub fn xpc_dictionary_create(
+        keys: *const *const c_char,
+        values: *const *const XPCObject,
+        count: usize,
+    ) -> *const XPCDictionary;
+    pub fn xpc_dictionary_create_reply(original: *const XPCDictionary) -> *const XPCDictionary;
+    pub fn xpc_dictionary_get_count(xdict: *const XPCDictionary) -> usize;
+    pub fn xpc_dictionary_get_value(
+        xdict: *const XPCDictionary,
+        key: *const c_char,
+    ) -> *const XPCObject;
+    pub fn xpc_dictionary_set_value(
+        xdict: *const XPCDictionary,
+        key: *const c_char,
+        value: *const XPCObject,
+    );
+    pub fn xpc_dictionary_apply(
+        xdict: *const XPCDictionary,
+        applier: &xpc_dictionary_applier_t,
+    ) -> bool;
+    pub fn xpc_dictionary_get_remote_connection(
+        xdict: *const XPCDictionary,
+    ) -> *const XPCConnection;
+
+    pub fn xpc_connection_create(
+        name: *const c_char,
+        targetq: *const DispatchQueue,
+    ) -> *const XPCConnection;
+    pub fn xpc_connection_create_mach_service(
+        name: *const c_char,
+        targetq: *const DispatchQueue,
+        flags: u64,
+    ) -> *const XPCConnection;
+    pub fn xpc_connection_create_from_endpoint(
+        endpoint: *const XPCEndpoint,
+    ) -> *const XPCConnection;
+    pub fn xpc_connection_set_target_queue(
+        connection: *const XPCConnection,
+        targetq: *const DispatchQueue,
+    );
+    pub fn xpc_connection_set_event_handler(
+        connection: *const XPCConnection,
+        handler: &xpc_handler_t,
+    );
+    pub fn xpc_connection_resume(connection: *const XPCConnection);
+    pub fn xpc_connection_suspend(connection: *const XPCConnection);
+    pub fn xpc_connection_cancel(connection: *const XPCConnection);
+    pub fn xpc_connection_send_message(
+        connection: *const XPCConnection,
+        message: *const XPCDictionary,
+    );
+    pub fn xpc_connection_send_message_with_reply(
+        connection: *const XPCConnection,
+        message: *const XPCDictionary,
+        replyq: *const DispatchQueue,
+        handler: &xpc_handler_t,
+    );
+    pub fn xpc_connection_send_message_with_reply_sync(
+        connection: *const XPCConnection,
+        message: *const XPCDictionary,
+    ) -> *const XPCObject;
+    pub fn xpc_connection_get_name(connection: *const XPCConnection) -> *const c_char;
+    pub fn xpc_connection_get_pid(connection: *const XPCConnection) -> c_int;
+    pub fn xpc_connection_get_euid(connection: *const XPCConnection) -> u32;
+
+    pub fn xpc_endpoint_create(connection: *const XPCConnection) -> *const XPCEndpoint;
+}
@@ -0,0 +1,83 @@
+use super::{sys, XPCObject};
+use crate::core::Arc;
+use std::fmt;
+
+subclass! {
+    /// A mutable array of XPC objects.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/xpc/xpc_array_create(_:_:)).
+    #[derive(PartialEq, Hash)]
+    pub class XPCArray: XPCObject;
+}
+
+impl fmt::Debug for XPCArray {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl XPCArray {
+    /// Creates an empty array.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/xpc/xpc_array_create(_:_:)).
+    #[inline]
+    #[doc(alias = "xpc_array_create")]
+    pub fn new() -> Arc<Self> {
+        Self::from_slice(&[])
+    }
+
+    /// Creates an array containing `objects`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/xpc/xpc_array_create(_:_:)).
+    #[inline]
+    #[doc(alias = "xpc_array_create")]
+    pub fn from_slice(objects: &[&XPCObject]) -> Arc<Self> {
+        // `&XPCObject` has the same representation as `xpc_object_t`.
+        let len = objects.len();
+        unsafe { Arc::from_raw(sys::xpc_array_create(objects.as_ptr().cast(), len)) }
+    }
+
+    /// Returns the number of objects.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/xpc/xpc_array_get_count(_:)).
+    #[inline]
+    #[doc(alias = "xpc_array_get_count")]
+    pub fn len(&self) -> usize {
+        unsafe { sys::xpc_array_get_count(self) }
+    }
+
+    /// Returns `true` if there are no objects.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the object at `index`, or [`None`] if it is out of bounds.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/xpc/xpc_array_get_value(_:_:)).
+    #[inline]
+    #[doc(alias = "xpc_array_get_value")]
+    pub fn get(&self, index: usize) -> Option<&XPCObject> {
+        if index < self.len() {
+            unsafe { sys::xpc_array_get_value(self, index).as_ref() }
+        } else {
+            None
+        }
+    }
+
+    /// Appends `value` to the end of the array.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/xpc/xpc_array_append_value(_:_:)).
+    #[inline]
+    #[doc(alias = "xpc_array_append_value")]
+    pub fn push(&self, value: &XPCObject) {
+        unsafe { sys::xpc_array_append_value(self, value) }
+    }
+
+    /// Returns an iterator over the objects.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &XPCObject> + '_ {
+        (0..self.len()).filter_map(move |index| self.get(index))
+    }
+}
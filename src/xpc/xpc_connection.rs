@@ -0,0 +1,446 @@
+use super::{sys, XPCDictionary, XPCObject};
+use crate::core::Arc;
+use crate::dispatch::DispatchQueue;
+use crate::objc::RcBlock;
+use std::{cell::Cell, error::Error, ffi::CStr, fmt, ops, os::raw::c_int, ptr};
+
+subclass! {
+    /// A bidirectional channel for sending messages to and receiving messages
+    /// from another process.
+    ///
+    /// Connections are created suspended. An
+    /// [event handler](Self::set_event_handler) must be set before calling
+    /// [`resume`](Self::resume), after which the connection stays active
+    /// until [`cancel`](Self::cancel) is called, even if it is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fruity::xpc::{XPCConnection, XPCConnectionFlags, XPCDictionary, XPCObject};
+    /// use std::ffi::CStr;
+    ///
+    /// let name = CStr::from_bytes_with_nul(b"com.example.helper\0").unwrap();
+    /// let connection = XPCConnection::mach_service(name, None, XPCConnectionFlags::NONE);
+    /// connection.set_event_handler(|event| eprintln!("{:?}", event));
+    /// connection.resume();
+    ///
+    /// let key = CStr::from_bytes_with_nul(b"command\0").unwrap();
+    /// let command = CStr::from_bytes_with_nul(b"ping\0").unwrap();
+    ///
+    /// let message = XPCDictionary::new();
+    /// message.insert(key, &XPCObject::from_c_str(command));
+    ///
+    /// match connection.send_message_with_reply_sync(&message) {
+    ///     Ok(reply) => println!("{:?}", reply),
+    ///     Err(error) => eprintln!("{}", error),
+    /// }
+    /// ```
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/xpc/xpc_connection_t).
+    #[derive(PartialEq, Hash)]
+    #[doc(alias = "xpc_connection_t")]
+    pub class XPCConnection: XPCObject;
+}
+
+impl fmt::Debug for XPCConnection {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("XPCConnection")
+            .field("name", &self.name())
+            .field("pid", &self.pid())
+            .finish()
+    }
+}
+
+#[inline]
+fn queue_ptr(queue: Option<&DispatchQueue>) -> *const DispatchQueue {
+    queue.map_or(ptr::null(), |queue| queue)
+}
+
+/// Creating connections.
+impl XPCConnection {
+    /// Creates a connection to the `launchd` service called `name`, or a
+    /// listener for it if `flags` contains
+    /// [`LISTENER`](XPCConnectionFlags::LISTENER).
+    ///
+    /// Events are delivered on `queue`, or on a new serial queue if [`None`].
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/xpc/xpc_connection_create_mach_service(_:_:_:)).
+    #[inline]
+    #[doc(alias = "xpc_connection_create_mach_service")]
+    pub fn mach_service(
+        name: &CStr,
+        queue: Option<&DispatchQueue>,
+        flags: XPCConnectionFlags,
+    ) -> Arc<Self> {
+        unsafe {
+            Arc::from_raw(sys::xpc_connection_create_mach_service(
+                name.as_ptr(),
+                queue_ptr(queue),
+                flags.0,
+            ))
+        }
+    }
+
+    /// Creates a listener that is not registered with `launchd`.
+    ///
+    /// Other processes connect to it through its
+    /// [`endpoint`](Self::endpoint), which must be sent to them in a message
+    /// over another connection.
+    ///
+    /// Events are delivered on `queue`, or on a new serial queue if [`None`].
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/xpc/xpc_connection_create(_:_:)).
+    #[inline]
+    #[doc(alias = "xpc_connection_create")]
+    pub fn anonymous(queue: Option<&DispatchQueue>) -> Arc<Self> {
+        unsafe { Arc::from_raw(sys::xpc_connection_create(ptr::null(), queue_ptr(queue))) }
+    }
+
+    /// Creates a connection to the listener of `endpoint`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/xpc/xpc_connection_create_from_endpoint(_:)).
+    #[inline]
+    #[doc(alias = "xpc_connection_create_from_endpoint")]
+    pub fn from_endpoint(endpoint: &XPCEndpoint) -> Arc<Self> {
+        unsafe { Arc::from_raw(sys::xpc_connection_create_from_endpoint(endpoint)) }
+    }
+
+    /// Returns an endpoint that other processes can connect to `self`
+    /// through, if `self` is an [anonymous](Self::anonymous) listener.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/xpc/xpc_endpoint_create(_:)).
+    #[inline]
+    #[doc(alias = "xpc_endpoint_create")]
+    pub fn endpoint(&self) -> Arc<XPCEndpoint> {
+        unsafe { Arc::from_raw(sys::xpc_endpoint_create(self)) }
+    }
+}
+
+/// Lifecycle.
+impl XPCConnection {
+    /// Calls `handler` with each event on the connection.
+    ///
+    /// Listeners receive an [`XPCEvent::Connection`] for each new peer,
+    /// which needs its own event handler and must be resumed. Other
+    /// connections receive messages and errors.
+    ///
+    /// This must be called before the connection is first
+    /// [resumed](Self::resume).
+    ///
+    /// It is safe to panic within `handler`. Panics will abort the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/xpc/xpc_connection_set_event_handler(_:_:)).
+    #[inline]
+    #[doc(alias = "xpc_connection_set_event_handler")]
+    pub fn set_event_handler<F>(&self, handler: F)
+    where
+        F: Fn(XPCEvent) + Send + Sync + 'static,
+    {
+        let block = RcBlock::new(move |object: *const XPCObject| {
+            handler(XPCEvent::from_object(unsafe { &*object }));
+        });
+        unsafe { sys::xpc_connection_set_event_handler(self, &block) };
+    }
+
+    /// Sets the queue that events are delivered on.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/xpc/xpc_connection_set_target_queue(_:_:)).
+    #[inline]
+    #[doc(alias = "xpc_connection_set_target_queue")]
+    pub fn set_target_queue(&self, queue: &DispatchQueue) {
+        unsafe { sys::xpc_connection_set_target_queue(self, queue) };
+    }
+
+    /// Starts or resumes the delivery of events and the sending of messages.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/xpc/xpc_connection_resume(_:)).
+    #[inline]
+    #[doc(alias = "xpc_connection_resume")]
+    pub fn resume(&self) {
+        unsafe { sys::xpc_connection_resume(self) };
+    }
+
+    /// Pauses the delivery of events and the sending of messages, until a
+    /// matching call to [`resume`](Self::resume).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/xpc/xpc_connection_suspend(_:)).
+    #[inline]
+    #[doc(alias = "xpc_connection_suspend")]
+    pub fn suspend(&self) {
+        unsafe { sys::xpc_connection_suspend(self) };
+    }
+
+    /// Closes the connection, after which the event handler receives
+    /// [`XPCConnectionError::is_invalid`] and no other events.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/xpc/xpc_connection_cancel(_:)).
+    #[inline]
+    #[doc(alias = "xpc_connection_cancel")]
+    pub fn cancel(&self) {
+        unsafe { sys::xpc_connection_cancel(self) };
+    }
+}
+
+/// Messaging.
+impl XPCConnection {
+    /// Sends `message` without waiting for a reply.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/xpc/xpc_connection_send_message(_:_:)).
+    #[inline]
+    #[doc(alias = "xpc_connection_send_message")]
+    pub fn send_message(&self, message: &XPCDictionary) {
+        unsafe { sys::xpc_connection_send_message(self, message) };
+    }
+
+    /// Sends `message`, then calls `handler` on `queue` with the reply.
+    ///
+    /// If `queue` is [`None`], `handler` is called on the connection's target
+    /// queue.
+    ///
+    /// It is safe to panic within `handler`. Panics will abort the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/xpc/xpc_connection_send_message_with_reply(_:_:_:_:)).
+    #[inline]
+    #[doc(alias = "xpc_connection_send_message_with_reply")]
+    pub fn send_message_with_reply<F>(
+        &self,
+        message: &XPCDictionary,
+        queue: Option<&DispatchQueue>,
+        handler: F,
+    ) where
+        F: FnOnce(Result<Arc<XPCDictionary>, XPCConnectionError>) + Send + 'static,
+    {
+        let handler = Cell::new(Some(handler));
+        let block = RcBlock::new(move |reply: *const XPCObject| {
+            if let Some(handler) = handler.take() {
+                handler(XPCConnectionError::result(unsafe { &*reply }));
+            }
+        });
+        unsafe {
+            sys::xpc_connection_send_message_with_reply(self, message, queue_ptr(queue), &block)
+        };
+    }
+
+    /// Sends `message` and blocks until the reply is received.
+    ///
+    /// This must not be called on the connection's target queue.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/xpc/xpc_connection_send_message_with_reply_sync(_:_:)).
+    #[inline]
+    #[doc(alias = "xpc_connection_send_message_with_reply_sync")]
+    pub fn send_message_with_reply_sync(
+        &self,
+        message: &XPCDictionary,
+    ) -> Result<Arc<XPCDictionary>, XPCConnectionError> {
+        unsafe {
+            let reply: Arc<XPCObject> = Arc::from_raw(
+                sys::xpc_connection_send_message_with_reply_sync(self, message),
+            );
+            XPCConnectionError::result(&reply)
+        }
+    }
+}
+
+/// Peer information.
+impl XPCConnection {
+    /// Returns the name of the service, or [`None`] for anonymous
+    /// connections and peers.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/xpc/xpc_connection_get_name(_:)).
+    #[inline]
+    #[doc(alias = "xpc_connection_get_name")]
+    pub fn name(&self) -> Option<&CStr> {
+        unsafe {
+            let name = sys::xpc_connection_get_name(self);
+            if name.is_null() {
+                None
+            } else {
+                Some(CStr::from_ptr(name))
+            }
+        }
+    }
+
+    /// Returns the process ID of the peer, or 0 if no message has been
+    /// received from it yet.
+    ///
+    /// Process IDs may be reused, so they should not be used for security
+    /// decisions.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/xpc/xpc_connection_get_pid(_:)).
+    #[inline]
+    #[doc(alias = "xpc_connection_get_pid")]
+    pub fn pid(&self) -> c_int {
+        unsafe { sys::xpc_connection_get_pid(self) }
+    }
+
+    /// Returns the effective user ID of the peer.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/xpc/xpc_connection_get_euid(_:)).
+    #[inline]
+    #[doc(alias = "xpc_connection_get_euid")]
+    pub fn euid(&self) -> u32 {
+        unsafe { sys::xpc_connection_get_euid(self) }
+    }
+}
+
+/// Options for [`XPCConnection::mach_service`].
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct XPCConnectionFlags(pub u64);
+
+impl ops::BitOr for XPCConnectionFlags {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl XPCConnectionFlags {
+    /// Connects to the service as a client.
+    pub const NONE: Self = Self(0);
+
+    /// Listens for connections to the service, which the process must be
+    /// registered with `launchd` to provide.
+    #[doc(alias = "XPC_CONNECTION_MACH_SERVICE_LISTENER")]
+    pub const LISTENER: Self = Self(sys::XPC_CONNECTION_MACH_SERVICE_LISTENER);
+
+    /// Connects to a service in the system domain, such as a privileged
+    /// helper tool, rather than the user's domain.
+    #[doc(alias = "XPC_CONNECTION_MACH_SERVICE_PRIVILEGED")]
+    pub const PRIVILEGED: Self = Self(sys::XPC_CONNECTION_MACH_SERVICE_PRIVILEGED);
+
+    /// Returns `true` if all flags in `other` are set in `self`.
+    #[inline]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+/// An event received by the handler of an [`XPCConnection`].
+#[derive(Clone, Debug)]
+pub enum XPCEvent {
+    /// A message from the peer.
+    Message(Arc<XPCDictionary>),
+    /// A new peer of a listener.
+    Connection(Arc<XPCConnection>),
+    /// A problem with the connection.
+    Error(XPCConnectionError),
+}
+
+impl XPCEvent {
+    #[inline]
+    fn from_object(object: &XPCObject) -> Self {
+        if let Some(connection) = object.as_connection() {
+            return Self::Connection(Arc::retain(connection));
+        }
+        match XPCConnectionError::result(object) {
+            Ok(message) => Self::Message(message),
+            Err(error) => Self::Error(error),
+        }
+    }
+}
+
+/// An error delivered to an [`XPCConnection`] instead of a message.
+///
+/// See [documentation](https://developer.apple.com/documentation/xpc/connection_errors).
+#[derive(Clone)]
+pub struct XPCConnectionError(Arc<XPCDictionary>);
+
+impl fmt::Debug for XPCConnectionError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("XPCConnectionError")
+            .field(&self.description())
+            .finish()
+    }
+}
+
+impl fmt::Display for XPCConnectionError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.description() {
+            Some(description) => f.write_str(&description.to_string_lossy()),
+            None => f.write_str("XPC connection error"),
+        }
+    }
+}
+
+impl Error for XPCConnectionError {}
+
+impl XPCConnectionError {
+    /// Returns `object` as a message, or as an error if it is one.
+    fn result(object: &XPCObject) -> Result<Arc<XPCDictionary>, Self> {
+        // Errors are dictionaries with their own type.
+        let is_error = object.is_type(unsafe { &sys::_xpc_type_error });
+        let dictionary =
+            unsafe { Arc::retain(&*(object as *const XPCObject as *const XPCDictionary)) };
+
+        if is_error {
+            Err(Self(dictionary))
+        } else {
+            Ok(dictionary)
+        }
+    }
+
+    #[inline]
+    fn is(&self, error: &XPCDictionary) -> bool {
+        ptr::eq(&*self.0, error)
+    }
+
+    /// Returns `true` if the peer exited or crashed.
+    ///
+    /// The connection is still usable, and the next message relaunches the
+    /// service if needed. Any state the peer had should be restored.
+    #[inline]
+    #[doc(alias = "XPC_ERROR_CONNECTION_INTERRUPTED")]
+    pub fn is_interrupted(&self) -> bool {
+        self.is(unsafe { &sys::_xpc_error_connection_interrupted })
+    }
+
+    /// Returns `true` if the connection was
+    /// [cancelled](XPCConnection::cancel) or the service could not be found.
+    ///
+    /// The connection cannot be used again.
+    #[inline]
+    #[doc(alias = "XPC_ERROR_CONNECTION_INVALID")]
+    pub fn is_invalid(&self) -> bool {
+        self.is(unsafe { &sys::_xpc_error_connection_invalid })
+    }
+
+    /// Returns `true` if the process is about to be terminated, such as
+    /// during shutdown, and should finish its work.
+    #[inline]
+    #[doc(alias = "XPC_ERROR_TERMINATION_IMMINENT")]
+    pub fn is_termination_imminent(&self) -> bool {
+        self.is(unsafe { &sys::_xpc_error_termination_imminent })
+    }
+
+    /// Returns a description of the error for debugging.
+    #[inline]
+    #[doc(alias = "XPC_ERROR_KEY_DESCRIPTION")]
+    pub fn description(&self) -> Option<&CStr> {
+        let key = unsafe { CStr::from_bytes_with_nul_unchecked(sys::XPC_ERROR_KEY_DESCRIPTION) };
+        self.0.get(key)?.as_c_str()
+    }
+}
+
+subclass! {
+    /// A reference to an [anonymous](XPCConnection::anonymous) listener,
+    /// which can be sent in messages so that other processes can
+    /// [connect](XPCConnection::from_endpoint) to it.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/xpc/xpc_endpoint_t).
+    #[derive(PartialEq, Hash)]
+    #[doc(alias = "xpc_endpoint_t")]
+    pub class XPCEndpoint: XPCObject;
+}
+
+impl fmt::Debug for XPCEndpoint {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
@@ -0,0 +1,120 @@
+use super::{sys, XPCConnection, XPCObject};
+use crate::core::Arc;
+use crate::objc::StackBlock;
+use std::{cell::RefCell, ffi::CStr, fmt, os::raw::c_char, ptr};
+
+subclass! {
+    /// A mutable dictionary of XPC objects keyed by C strings, which is the
+    /// type of every message.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/xpc/xpc_dictionary_create(_:_:_:)).
+    #[derive(PartialEq, Hash)]
+    pub class XPCDictionary: XPCObject;
+}
+
+impl fmt::Debug for XPCDictionary {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl XPCDictionary {
+    /// Creates an empty dictionary.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/xpc/xpc_dictionary_create(_:_:_:)).
+    #[inline]
+    #[doc(alias = "xpc_dictionary_create")]
+    pub fn new() -> Arc<Self> {
+        unsafe { Arc::from_raw(sys::xpc_dictionary_create(ptr::null(), ptr::null(), 0)) }
+    }
+
+    /// Creates a dictionary for replying to `self`, or returns [`None`] if
+    /// `self` is not a received message that expects a reply.
+    ///
+    /// The reply is sent with
+    /// [`remote_connection`](Self::remote_connection).
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/xpc/xpc_dictionary_create_reply(_:)).
+    #[inline]
+    #[doc(alias = "xpc_dictionary_create_reply")]
+    pub fn create_reply(&self) -> Option<Arc<Self>> {
+        unsafe {
+            let reply = sys::xpc_dictionary_create_reply(self);
+            if reply.is_null() {
+                None
+            } else {
+                Some(Arc::from_raw(reply))
+            }
+        }
+    }
+
+    /// Returns the connection that `self` was received over, or [`None`] if
+    /// it was not received as a message.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/xpc/xpc_dictionary_get_remote_connection(_:)).
+    #[inline]
+    #[doc(alias = "xpc_dictionary_get_remote_connection")]
+    pub fn remote_connection(&self) -> Option<&XPCConnection> {
+        unsafe { sys::xpc_dictionary_get_remote_connection(self).as_ref() }
+    }
+
+    /// Returns the number of entries.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/xpc/xpc_dictionary_get_count(_:)).
+    #[inline]
+    #[doc(alias = "xpc_dictionary_get_count")]
+    pub fn len(&self) -> usize {
+        unsafe { sys::xpc_dictionary_get_count(self) }
+    }
+
+    /// Returns `true` if there are no entries.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the value for `key`, or [`None`] if there is none.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/xpc/xpc_dictionary_get_value(_:_:)).
+    #[inline]
+    #[doc(alias = "xpc_dictionary_get_value")]
+    pub fn get(&self, key: &CStr) -> Option<&XPCObject> {
+        unsafe { sys::xpc_dictionary_get_value(self, key.as_ptr()).as_ref() }
+    }
+
+    /// Sets the value for `key`, replacing any existing value.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/xpc/xpc_dictionary_set_value(_:_:_:)).
+    #[inline]
+    #[doc(alias = "xpc_dictionary_set_value")]
+    pub fn insert(&self, key: &CStr, value: &XPCObject) {
+        unsafe { sys::xpc_dictionary_set_value(self, key.as_ptr(), value) }
+    }
+
+    /// Removes the value for `key`, if any.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/xpc/xpc_dictionary_set_value(_:_:_:)).
+    #[inline]
+    pub fn remove(&self, key: &CStr) {
+        unsafe { sys::xpc_dictionary_set_value(self, key.as_ptr(), ptr::null()) }
+    }
+
+    /// Calls `f` with each entry, in no particular order.
+    ///
+    /// It is safe to panic within `f`. Panics will abort the process.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/xpc/xpc_dictionary_apply(_:_:)).
+    #[doc(alias = "xpc_dictionary_apply")]
+    pub fn for_each<F>(&self, f: F)
+    where
+        F: FnMut(&CStr, &XPCObject),
+    {
+        let f = RefCell::new(f);
+        let applier = StackBlock::new(|key: *const c_char, value: *const XPCObject| {
+            unsafe { (f.borrow_mut())(CStr::from_ptr(key), &*value) };
+            true
+        });
+        unsafe { sys::xpc_dictionary_apply(self, &applier) };
+    }
+}
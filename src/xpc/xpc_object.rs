@@ -0,0 +1,306 @@
+use super::{sys, XPCArray, XPCConnection, XPCDictionary, XPCEndpoint};
+use crate::core::{Arc, ObjectType};
+use std::{
+    cell::UnsafeCell,
+    ffi::{c_void, CStr},
+    fmt, hash,
+    panic::RefUnwindSafe,
+    ptr::{self, NonNull},
+    slice,
+};
+
+/// The base type for XPC objects, which are the values that can be sent in
+/// messages.
+///
+/// Besides [dictionaries](XPCDictionary) and [arrays](XPCArray), values are
+/// immutable scalars such as strings, integers, and data, which are created
+/// with the `from_*` constructors and read with the `as_*` accessors.
+///
+/// See [documentation](https://developer.apple.com/documentation/xpc/xpc_object_t).
+#[repr(C)]
+#[doc(alias = "xpc_object_t")]
+pub struct XPCObject {
+    // Stores data that may be mutated behind a shared reference. Internal
+    // mutability triggers undefined behavior without `UnsafeCell`.
+    _data: UnsafeCell<[u8; 0]>,
+}
+
+impl ObjectType for XPCObject {
+    #[inline]
+    #[doc(alias = "xpc_retain")]
+    fn retain(obj: &Self) -> Arc<Self> {
+        unsafe { Arc::from_raw(sys::xpc_retain(obj)) }
+    }
+
+    #[inline]
+    #[doc(alias = "xpc_release")]
+    unsafe fn release(obj: NonNull<Self>) {
+        sys::xpc_release(obj.as_ptr());
+    }
+}
+
+unsafe impl Send for XPCObject {}
+unsafe impl Sync for XPCObject {}
+
+// Although this uses `UnsafeCell`, it does not point to any Rust types.
+impl RefUnwindSafe for XPCObject {}
+
+impl AsRef<XPCObject> for XPCObject {
+    #[inline]
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+impl PartialEq for XPCObject {
+    #[inline]
+    #[doc(alias = "xpc_equal")]
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { sys::xpc_equal(self, other) }
+    }
+}
+
+impl hash::Hash for XPCObject {
+    #[inline]
+    #[doc(alias = "xpc_hash")]
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        unsafe { sys::xpc_hash(self) }.hash(state);
+    }
+}
+
+impl fmt::Debug for XPCObject {
+    #[doc(alias = "xpc_copy_description")]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        extern "C" {
+            fn free(ptr: *mut c_void);
+        }
+
+        unsafe {
+            let description = sys::xpc_copy_description(self);
+            let result = f.write_str(&CStr::from_ptr(description).to_string_lossy());
+            free(description.cast());
+            result
+        }
+    }
+}
+
+/// Type information.
+impl XPCObject {
+    #[inline]
+    pub(super) fn is_type(&self, kind: &sys::_xpc_type_s) -> bool {
+        unsafe { ptr::eq(sys::xpc_get_type(self), kind) }
+    }
+
+    /// Returns the name of the type of `self`, such as `dictionary`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/xpc/xpc_type_get_name(_:)).
+    #[inline]
+    #[doc(alias = "xpc_get_type")]
+    #[doc(alias = "xpc_type_get_name")]
+    pub fn type_name(&self) -> &'static CStr {
+        unsafe { CStr::from_ptr(sys::xpc_type_get_name(sys::xpc_get_type(self))) }
+    }
+
+    /// Returns `self` as a dictionary, or [`None`] if it is another type.
+    #[inline]
+    pub fn as_dictionary(&self) -> Option<&XPCDictionary> {
+        if self.is_type(unsafe { &sys::_xpc_type_dictionary }) {
+            Some(unsafe { &*(self as *const Self as *const XPCDictionary) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns `self` as an array, or [`None`] if it is another type.
+    #[inline]
+    pub fn as_array(&self) -> Option<&XPCArray> {
+        if self.is_type(unsafe { &sys::_xpc_type_array }) {
+            Some(unsafe { &*(self as *const Self as *const XPCArray) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns `self` as a connection, or [`None`] if it is another type.
+    #[inline]
+    pub fn as_connection(&self) -> Option<&XPCConnection> {
+        if self.is_type(unsafe { &sys::_xpc_type_connection }) {
+            Some(unsafe { &*(self as *const Self as *const XPCConnection) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns `self` as an endpoint, or [`None`] if it is another type.
+    #[inline]
+    pub fn as_endpoint(&self) -> Option<&XPCEndpoint> {
+        if self.is_type(unsafe { &sys::_xpc_type_endpoint }) {
+            Some(unsafe { &*(self as *const Self as *const XPCEndpoint) })
+        } else {
+            None
+        }
+    }
+}
+
+/// Scalar values.
+impl XPCObject {
+    /// Returns the null object.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/xpc/xpc_null_create()).
+    #[inline]
+    #[doc(alias = "xpc_null_create")]
+    pub fn null() -> Arc<Self> {
+        unsafe { Arc::from_raw(sys::xpc_null_create()) }
+    }
+
+    /// Returns `true` if `self` is the null object.
+    #[inline]
+    pub fn is_null(&self) -> bool {
+        self.is_type(unsafe { &sys::_xpc_type_null })
+    }
+
+    /// Creates a boolean object.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/xpc/xpc_bool_create(_:)).
+    #[inline]
+    #[doc(alias = "xpc_bool_create")]
+    pub fn from_bool(value: bool) -> Arc<Self> {
+        unsafe { Arc::from_raw(sys::xpc_bool_create(value)) }
+    }
+
+    /// Returns the value of a boolean object, or [`None`] if `self` is
+    /// another type.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/xpc/xpc_bool_get_value(_:)).
+    #[inline]
+    #[doc(alias = "xpc_bool_get_value")]
+    pub fn as_bool(&self) -> Option<bool> {
+        if self.is_type(unsafe { &sys::_xpc_type_bool }) {
+            Some(unsafe { sys::xpc_bool_get_value(self) })
+        } else {
+            None
+        }
+    }
+
+    /// Creates a signed integer object.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/xpc/xpc_int64_create(_:)).
+    #[inline]
+    #[doc(alias = "xpc_int64_create")]
+    pub fn from_i64(value: i64) -> Arc<Self> {
+        unsafe { Arc::from_raw(sys::xpc_int64_create(value)) }
+    }
+
+    /// Returns the value of a signed integer object, or [`None`] if `self`
+    /// is another type.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/xpc/xpc_int64_get_value(_:)).
+    #[inline]
+    #[doc(alias = "xpc_int64_get_value")]
+    pub fn as_i64(&self) -> Option<i64> {
+        if self.is_type(unsafe { &sys::_xpc_type_int64 }) {
+            Some(unsafe { sys::xpc_int64_get_value(self) })
+        } else {
+            None
+        }
+    }
+
+    /// Creates an unsigned integer object.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/xpc/xpc_uint64_create(_:)).
+    #[inline]
+    #[doc(alias = "xpc_uint64_create")]
+    pub fn from_u64(value: u64) -> Arc<Self> {
+        unsafe { Arc::from_raw(sys::xpc_uint64_create(value)) }
+    }
+
+    /// Returns the value of an unsigned integer object, or [`None`] if `self`
+    /// is another type.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/xpc/xpc_uint64_get_value(_:)).
+    #[inline]
+    #[doc(alias = "xpc_uint64_get_value")]
+    pub fn as_u64(&self) -> Option<u64> {
+        if self.is_type(unsafe { &sys::_xpc_type_uint64 }) {
+            Some(unsafe { sys::xpc_uint64_get_value(self) })
+        } else {
+            None
+        }
+    }
+
+    /// Creates a floating-point number object.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/xpc/xpc_double_create(_:)).
+    #[inline]
+    #[doc(alias = "xpc_double_create")]
+    pub fn from_f64(value: f64) -> Arc<Self> {
+        unsafe { Arc::from_raw(sys::xpc_double_create(value)) }
+    }
+
+    /// Returns the value of a floating-point number object, or [`None`] if
+    /// `self` is another type.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/xpc/xpc_double_get_value(_:)).
+    #[inline]
+    #[doc(alias = "xpc_double_get_value")]
+    pub fn as_f64(&self) -> Option<f64> {
+        if self.is_type(unsafe { &sys::_xpc_type_double }) {
+            Some(unsafe { sys::xpc_double_get_value(self) })
+        } else {
+            None
+        }
+    }
+
+    /// Creates a string object by copying `value`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/xpc/xpc_string_create(_:)).
+    #[inline]
+    #[doc(alias = "xpc_string_create")]
+    pub fn from_c_str(value: &CStr) -> Arc<Self> {
+        unsafe { Arc::from_raw(sys::xpc_string_create(value.as_ptr())) }
+    }
+
+    /// Returns the value of a string object, or [`None`] if `self` is another
+    /// type.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/xpc/xpc_string_get_string_ptr(_:)).
+    #[inline]
+    #[doc(alias = "xpc_string_get_string_ptr")]
+    pub fn as_c_str(&self) -> Option<&CStr> {
+        if self.is_type(unsafe { &sys::_xpc_type_string }) {
+            Some(unsafe { CStr::from_ptr(sys::xpc_string_get_string_ptr(self)) })
+        } else {
+            None
+        }
+    }
+
+    /// Creates a data object by copying `bytes`.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/xpc/xpc_data_create(_:_:)).
+    #[inline]
+    #[doc(alias = "xpc_data_create")]
+    pub fn from_bytes(bytes: &[u8]) -> Arc<Self> {
+        unsafe { Arc::from_raw(sys::xpc_data_create(bytes.as_ptr().cast(), bytes.len())) }
+    }
+
+    /// Returns the contents of a data object, or [`None`] if `self` is
+    /// another type.
+    ///
+    /// See [documentation](https://developer.apple.com/documentation/xpc/xpc_data_get_bytes_ptr(_:)).
+    #[inline]
+    #[doc(alias = "xpc_data_get_bytes_ptr")]
+    #[doc(alias = "xpc_data_get_length")]
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        if !self.is_type(unsafe { &sys::_xpc_type_data }) {
+            return None;
+        }
+        unsafe {
+            let len = sys::xpc_data_get_length(self);
+            if len == 0 {
+                return Some(&[]);
+            }
+            let ptr = sys::xpc_data_get_bytes_ptr(self);
+            Some(slice::from_raw_parts(ptr.cast(), len))
+        }
+    }
+}